@@ -0,0 +1,356 @@
+/*
+ * Complex numbers with arbitrary precision binary floating point
+ * components
+ *
+ * A BigComplex is a pair of BigFloat numbers. The operations of a
+ * ComplexEnv round each component of their result to the precision of
+ * a BigFloatEnv, with its rounding mode and exponent range. Both
+ * components of the results of the arithmetic operations, sqrt, exp,
+ * log, pow, sin, cos and tan are correctly rounded, so an exactly
+ * representable component, e.g. the real part of sqrt(-3 + 4i) = 1 +
+ * 2i, is exact.
+ *
+ * The functions return principal values: the imaginary part of log(a)
+ * is arg(a) in [-pi, pi] and a^b = exp(b * log(a)). As in C99, the
+ * sign of a zero imaginary part selects the side of the branch cut on
+ * the negative real axis, e.g. sqrt(-4 - 0i) = 0 - 2i.
+ */
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::bigfloat::{BigFloat, BigFloatEnv};
+use crate::libbf::*;
+
+/// Complex number with BigFloat components.
+/* same layout as bf_complex_t */
+#[repr(C)]
+#[derive(Clone, PartialEq)]
+pub struct BigComplex {
+    re: BigFloat,
+    im: BigFloat,
+}
+
+impl BigComplex {
+    pub fn new(re: BigFloat, im: BigFloat) -> BigComplex {
+        BigComplex { re, im }
+    }
+
+    /// Return 0 + 0i.
+    pub fn zero() -> BigComplex {
+        BigComplex::new(BigFloat::zero(), BigFloat::zero())
+    }
+
+    /// Return the imaginary unit.
+    pub fn i() -> BigComplex {
+        BigComplex::new(BigFloat::zero(), BigFloat::from(1))
+    }
+
+    pub fn nan() -> BigComplex {
+        BigComplex::new(BigFloat::nan(), BigFloat::nan())
+    }
+
+    pub fn re(&self) -> &BigFloat {
+        &self.re
+    }
+
+    pub fn im(&self) -> &BigFloat {
+        &self.im
+    }
+
+    /// Return the real and the imaginary parts.
+    pub fn into_parts(self) -> (BigFloat, BigFloat) {
+        (self.re, self.im)
+    }
+
+    /// Return true if a component is NaN.
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Return true if both components are finite.
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    /// Return true if the imaginary part is zero.
+    pub fn is_real(&self) -> bool {
+        self.im.is_zero()
+    }
+
+    /// Return the complex conjugate. It is exact.
+    pub fn conj(&self) -> BigComplex {
+        BigComplex::new(self.re.clone(), -&self.im)
+    }
+
+    fn as_ptr(&self) -> *const bf_complex_t {
+        self as *const BigComplex as *const bf_complex_t
+    }
+
+    fn as_mut_ptr(&mut self) -> *mut bf_complex_t {
+        self as *mut BigComplex as *mut bf_complex_t
+    }
+}
+
+impl Default for BigComplex {
+    fn default() -> BigComplex {
+        BigComplex::zero()
+    }
+}
+
+impl From<BigFloat> for BigComplex {
+    fn from(re: BigFloat) -> BigComplex {
+        BigComplex::new(re, BigFloat::zero())
+    }
+}
+
+type BfComplexOp1Func =
+    unsafe fn(*mut bf_complex_t, *const bf_complex_t, limb_t, bf_flags_t) -> i32;
+type BfComplexOp2Func = unsafe fn(
+    *mut bf_complex_t,
+    *const bf_complex_t,
+    *const bf_complex_t,
+    limb_t,
+    bf_flags_t,
+) -> i32;
+
+/// Precision, rounding mode and exponent range of the components of
+/// the complex operations, with the status flags (`BF_ST_*`) they
+/// raised.
+#[derive(Clone, Debug)]
+pub struct ComplexEnv {
+    prec: limb_t,
+    flags: bf_flags_t,
+    status: i32,
+}
+
+impl ComplexEnv {
+    pub fn new(env: &BigFloatEnv) -> ComplexEnv {
+        ComplexEnv {
+            prec: env.prec(),
+            flags: env.flags(),
+            status: 0,
+        }
+    }
+
+    /// Return an environment with the default environment of the
+    /// current thread.
+    pub fn thread_default() -> ComplexEnv {
+        ComplexEnv::new(&BigFloatEnv::thread_default())
+    }
+
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Return the status flags raised since the last call to
+    /// `clear_status`.
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+
+    pub fn clear_status(&mut self) {
+        self.status = 0;
+    }
+
+    fn op1(&mut self, a: &BigComplex, func: BfComplexOp1Func) -> BigComplex {
+        let mut r = BigComplex::zero();
+        self.status |= unsafe { func(r.as_mut_ptr(), a.as_ptr(), self.prec, self.flags) };
+        r
+    }
+
+    fn op2(&mut self, a: &BigComplex, b: &BigComplex, func: BfComplexOp2Func) -> BigComplex {
+        let mut r = BigComplex::zero();
+        self.status |= unsafe {
+            func(
+                r.as_mut_ptr(),
+                a.as_ptr(),
+                b.as_ptr(),
+                self.prec,
+                self.flags,
+            )
+        };
+        r
+    }
+
+    /// Round both components of `a`.
+    pub fn round(&mut self, a: &BigComplex) -> BigComplex {
+        let mut r = a.clone();
+        unsafe {
+            self.status |= bf_round(r.re.as_mut_ptr(), self.prec, self.flags);
+            self.status |= bf_round(r.im.as_mut_ptr(), self.prec, self.flags);
+        }
+        r
+    }
+
+    pub fn add(&mut self, a: &BigComplex, b: &BigComplex) -> BigComplex {
+        self.op2(a, b, bf_complex_add)
+    }
+
+    pub fn sub(&mut self, a: &BigComplex, b: &BigComplex) -> BigComplex {
+        self.op2(a, b, bf_complex_sub)
+    }
+
+    pub fn mul(&mut self, a: &BigComplex, b: &BigComplex) -> BigComplex {
+        self.op2(a, b, bf_complex_mul)
+    }
+
+    /// The result is NaN if `b` is zero.
+    pub fn div(&mut self, a: &BigComplex, b: &BigComplex) -> BigComplex {
+        self.op2(a, b, bf_complex_div)
+    }
+
+    /// Return |a|.
+    pub fn abs(&mut self, a: &BigComplex) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { bf_complex_abs(r.as_mut_ptr(), a.as_ptr(), self.prec, self.flags) };
+        r
+    }
+
+    /// Return the argument of `a` in [-pi, pi], i.e. atan2(im, re).
+    pub fn arg(&mut self, a: &BigComplex) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { bf_complex_arg(r.as_mut_ptr(), a.as_ptr(), self.prec, self.flags) };
+        r
+    }
+
+    /// Principal square root, with a non negative real part.
+    pub fn sqrt(&mut self, a: &BigComplex) -> BigComplex {
+        self.op1(a, bf_complex_sqrt)
+    }
+
+    pub fn exp(&mut self, a: &BigComplex) -> BigComplex {
+        self.op1(a, bf_complex_exp)
+    }
+
+    /// Principal logarithm. log(0) = -Infinity + arg(0)i.
+    pub fn log(&mut self, a: &BigComplex) -> BigComplex {
+        self.op1(a, bf_complex_log)
+    }
+
+    /// Return exp(b * log(a)) with a^0 = 1. The result is NaN for 0^b
+    /// when the real part of `b` is not positive and when a component
+    /// of `a` or `b` is not finite.
+    pub fn pow(&mut self, a: &BigComplex, b: &BigComplex) -> BigComplex {
+        self.op2(a, b, bf_complex_pow)
+    }
+
+    pub fn sin(&mut self, a: &BigComplex) -> BigComplex {
+        self.op1(a, bf_complex_sin)
+    }
+
+    pub fn cos(&mut self, a: &BigComplex) -> BigComplex {
+        self.op1(a, bf_complex_cos)
+    }
+
+    pub fn tan(&mut self, a: &BigComplex) -> BigComplex {
+        self.op1(a, bf_complex_tan)
+    }
+}
+
+impl fmt::Display for BigComplex {
+    /// Written as in qjscalc, e.g. "1+2*I", "-I" or "3" for a zero
+    /// imaginary part. The precision of the formatter is the number of
+    /// digits after the decimal point of the components.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return f.write_str("NaN");
+        }
+        let component = |x: &BigFloat| match f.precision() {
+            Some(digits) => format!("{:.*}", digits, x),
+            None => x.to_string(),
+        };
+        if self.im.is_zero() {
+            return f.write_str(&component(&self.re));
+        }
+        let mut s = String::new();
+        if !self.re.is_zero() {
+            s.push_str(&component(&self.re));
+        }
+        if self.im == BigFloat::from(1) {
+            if !s.is_empty() {
+                s.push('+');
+            }
+            s.push('I');
+        } else if self.im == BigFloat::from(-1) {
+            s.push_str("-I");
+        } else {
+            let v = component(&self.im);
+            if !v.starts_with('-') && !s.is_empty() {
+                s.push('+');
+            }
+            s.push_str(&v);
+            s.push_str("*I");
+        }
+        f.write_str(&s)
+    }
+}
+
+impl fmt::Debug for BigComplex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for BigComplex {
+    type Output = BigComplex;
+
+    /// The negation is exact.
+    fn neg(self) -> BigComplex {
+        BigComplex::new(-self.re, -self.im)
+    }
+}
+
+impl Neg for &BigComplex {
+    type Output = BigComplex;
+
+    fn neg(self) -> BigComplex {
+        -self.clone()
+    }
+}
+
+/* binary operators with the precision of the default environment of
+the current thread */
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident) => {
+        impl $Op<&BigComplex> for &BigComplex {
+            type Output = BigComplex;
+
+            fn $op(self, rhs: &BigComplex) -> BigComplex {
+                ComplexEnv::thread_default().$op(self, rhs)
+            }
+        }
+
+        impl $Op<BigComplex> for &BigComplex {
+            type Output = BigComplex;
+
+            fn $op(self, rhs: BigComplex) -> BigComplex {
+                self.$op(&rhs)
+            }
+        }
+
+        impl $Op<&BigComplex> for BigComplex {
+            type Output = BigComplex;
+
+            fn $op(self, rhs: &BigComplex) -> BigComplex {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl $Op<BigComplex> for BigComplex {
+            type Output = BigComplex;
+
+            fn $op(self, rhs: BigComplex) -> BigComplex {
+                (&self).$op(&rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add);
+impl_binary_op!(Sub, sub);
+impl_binary_op!(Mul, mul);
+impl_binary_op!(Div, div);
@@ -0,0 +1,970 @@
+/*
+ * Arbitrary precision decimal floating point numbers
+ *
+ * BigDecimal is a safe wrapper of bfdec_t. The operations are rounded
+ * to a number of significant digits with the rounding mode of a
+ * BigDecimalEnv. An environment may also follow one of the IEEE
+ * 754-2008 decimal interchange formats: the results then have the
+ * exponent range of the format, with its subnormal numbers, and
+ * overflow to infinity or to the largest finite number depending on
+ * the rounding mode. The default environment of each thread follows
+ * decimal128.
+ *
+ * The decimal32, decimal64 and decimal128 encodings are supported
+ * with both the binary (BID) and the densely packed decimal (DPD)
+ * coefficient encoding. BigDecimal does not keep the quantum of the
+ * encoded numbers, so the encoder chooses the member of the cohort
+ * with the largest exponent.
+ */
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::ffi::CString;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use crate::bigfloat::{thread_context, OutOfMemory, Rounding};
+use crate::libbf::{
+    bf_flags_t, bf_free, bfdec_add, bfdec_atof, bfdec_cmp, bfdec_delete, bfdec_div, bfdec_ftoa,
+    bfdec_init, bfdec_is_finite, bfdec_is_nan, bfdec_is_zero, bfdec_mul, bfdec_neg, bfdec_pow_ui,
+    bfdec_rem, bfdec_rint, bfdec_round, bfdec_set, bfdec_set_inf, bfdec_set_nan, bfdec_set_si,
+    bfdec_set_ui, bfdec_set_zero, bfdec_sqrt, bfdec_sub, bfdec_t, limb_t, BF_FLAG_RADPNT_PREC,
+    BF_FTOA_FORCE_EXP, BF_FTOA_FORMAT_FIXED, BF_FTOA_FORMAT_FRAC, BF_FTOA_FORMAT_FREE, BF_PREC_INF,
+    BF_PREC_MAX, BF_RNDD, BF_RNDN, BF_RNDU, BF_RNDZ, BF_RND_MASK, BF_ST_INEXACT, BF_ST_MEM_ERROR,
+    BF_ST_OVERFLOW, BF_ST_UNDERFLOW,
+};
+
+/* number of decimal digits in a limb */
+const LIMB_DIGITS: i64 = 19;
+const DEC_BASE: u64 = 10_000_000_000_000_000_000;
+
+/// IEEE 754-2008 decimal interchange format.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecimalFormat {
+    Decimal32,
+    Decimal64,
+    Decimal128,
+}
+
+impl DecimalFormat {
+    /// Return the size of the encoding in bits.
+    pub fn bits(self) -> u32 {
+        match self {
+            DecimalFormat::Decimal32 => 32,
+            DecimalFormat::Decimal64 => 64,
+            DecimalFormat::Decimal128 => 128,
+        }
+    }
+
+    /// Return the number of digits of the coefficient.
+    pub fn digits(self) -> u32 {
+        match self {
+            DecimalFormat::Decimal32 => 7,
+            DecimalFormat::Decimal64 => 16,
+            DecimalFormat::Decimal128 => 34,
+        }
+    }
+
+    /// Return the largest exponent of the numbers written as d.ddd * 10^e.
+    pub fn emax(self) -> i64 {
+        3 << (self.exp_cont_bits() - 1)
+    }
+
+    /// Return the smallest exponent of the normal numbers written as
+    /// d.ddd * 10^e.
+    pub fn emin(self) -> i64 {
+        1 - self.emax()
+    }
+
+    /* number of bits of the trailing significand field */
+    fn trailing_bits(self) -> u32 {
+        (self.digits() - 1) / 3 * 10
+    }
+
+    /* number of bits of the exponent continuation field */
+    fn exp_cont_bits(self) -> u32 {
+        self.bits() - 6 - self.trailing_bits()
+    }
+
+    /* exponent of the least significant digit of the coefficient */
+    fn q_min(self) -> i64 {
+        self.emin() - self.digits() as i64 + 1
+    }
+
+    fn q_max(self) -> i64 {
+        self.emax() - self.digits() as i64 + 1
+    }
+}
+
+/// Encoding of the coefficient of the decimal interchange formats.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DecimalEncoding {
+    /// Binary integer decimal.
+    Bid,
+    /// Densely packed decimal.
+    Dpd,
+}
+
+/* encode 3 decimal digits (0 <= d < 1000) in a 10 bit declet */
+fn dpd_encode(d: u32) -> u32 {
+    let (d2, d1, d0) = (d / 100, d / 10 % 10, d % 10);
+    let (a, e, i) = (d2 >> 3, d1 >> 3, d0 >> 3);
+    let (bcd, fgh, jkm) = (d2 & 7, d1 & 7, d0 & 7);
+    let (fg, jk) = (fgh >> 1, jkm >> 1);
+    let (d, h, m) = (d2 & 1, d1 & 1, d0 & 1);
+    let (pqr, stu, wxy) = match (a, e, i) {
+        (0, 0, 0) => return (bcd << 7) | (fgh << 4) | jkm,
+        (0, 0, 1) => (bcd, fgh, m),
+        (0, 1, 0) => (bcd, (jk << 1) | h, 0b010 | m),
+        (0, 1, 1) => (bcd, 0b100 | h, 0b110 | m),
+        (1, 0, 0) => ((jk << 1) | d, fgh, 0b100 | m),
+        (1, 0, 1) => ((fg << 1) | d, 0b010 | h, 0b110 | m),
+        (1, 1, 0) => ((jk << 1) | d, h, 0b110 | m),
+        _ => (d, 0b110 | h, 0b110 | m),
+    };
+    (pqr << 7) | (stu << 4) | 8 | wxy
+}
+
+/* decode a declet. The non-canonical declets are accepted. */
+fn dpd_decode(b: u32) -> u32 {
+    let (pqr, stu, wxy) = (b >> 7, (b >> 4) & 7, b & 7);
+    let (pq, st) = (pqr >> 1, stu >> 1);
+    let (r, u, y) = (pqr & 1, stu & 1, wxy & 1);
+    let (d2, d1, d0) = if b & 8 == 0 {
+        (pqr, stu, wxy)
+    } else {
+        match (wxy >> 1, st) {
+            (0, _) => (pqr, stu, 8 + y),
+            (1, _) => (pqr, 8 + u, (st << 1) | y),
+            (2, _) => (8 + r, stu, (pq << 1) | y),
+            (_, 0) => (8 + r, 8 + u, (pq << 1) | y),
+            (_, 1) => (8 + r, (pq << 1) | u, 8 + y),
+            (_, 2) => (pqr, 8 + u, 8 + y),
+            _ => (8 + r, 8 + u, 8 + y),
+        }
+    };
+    d2 * 100 + d1 * 10 + d0
+}
+
+/// Number of significant digits and rounding mode of the BigDecimal
+/// operations, with the status flags (`BF_ST_*`) they raised.
+#[derive(Clone, Debug)]
+pub struct BigDecimalEnv {
+    prec: limb_t,
+    flags: bf_flags_t,
+    format: Option<DecimalFormat>,
+    status: i32,
+}
+
+impl Default for BigDecimalEnv {
+    /// decimal128 rounding to nearest.
+    fn default() -> BigDecimalEnv {
+        BigDecimalEnv::for_format(DecimalFormat::Decimal128)
+    }
+}
+
+thread_local! {
+    static DEFAULT_ENV: RefCell<BigDecimalEnv> = RefCell::new(BigDecimalEnv::default());
+}
+
+type BfdecOp2Func =
+    unsafe fn(*mut bfdec_t, *const bfdec_t, *const bfdec_t, limb_t, bf_flags_t) -> i32;
+
+impl BigDecimalEnv {
+    pub const PREC_MAX: u64 = BF_PREC_MAX;
+
+    /// Return an environment with a precision of `prec` digits,
+    /// rounding to nearest and an unbounded exponent range. `prec` is
+    /// clamped between 1 and `PREC_MAX`.
+    pub fn new(prec: u64) -> BigDecimalEnv {
+        let mut env = BigDecimalEnv {
+            prec: 1,
+            flags: BF_RNDN,
+            format: None,
+            status: 0,
+        };
+        env.set_prec(prec);
+        env
+    }
+
+    /// Return an environment with the precision and the exponent range
+    /// of `format`, rounding to nearest.
+    pub fn for_format(format: DecimalFormat) -> BigDecimalEnv {
+        BigDecimalEnv {
+            prec: format.digits() as limb_t,
+            flags: BF_RNDN,
+            format: Some(format),
+            status: 0,
+        }
+    }
+
+    /// Return a copy of the default environment of the current thread.
+    pub fn thread_default() -> BigDecimalEnv {
+        DEFAULT_ENV.with(|e| e.borrow().clone())
+    }
+
+    /// Replace the default environment of the current thread and
+    /// return the previous one.
+    pub fn set_thread_default(env: BigDecimalEnv) -> BigDecimalEnv {
+        DEFAULT_ENV.with(|e| e.replace(env))
+    }
+
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Set the number of significant digits, clamped between 1 and
+    /// `PREC_MAX`. The exponent range becomes unbounded.
+    pub fn set_prec(&mut self, prec: u64) {
+        self.prec = prec.clamp(1, BF_PREC_MAX);
+        self.format = None;
+    }
+
+    pub fn format(&self) -> Option<DecimalFormat> {
+        self.format
+    }
+
+    pub fn rounding(&self) -> Rounding {
+        Rounding::from_rnd(self.flags & BF_RND_MASK)
+    }
+
+    pub fn set_rounding(&mut self, rnd: Rounding) {
+        self.flags = (self.flags & !BF_RND_MASK) | rnd as bf_flags_t;
+    }
+
+    /// Return the status flags raised since the last call to
+    /// `clear_status`.
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+
+    pub fn clear_status(&mut self) {
+        self.status = 0;
+    }
+
+    /* compute 'func' rounded with the environment */
+    fn apply<F>(&mut self, func: F) -> BigDecimal
+    where
+        F: Fn(*mut bfdec_t, limb_t, bf_flags_t) -> i32,
+    {
+        let mut r = BigDecimal::zero();
+        let mut ret = func(&mut r.v, self.prec, self.flags);
+        if let Some(format) = self.format {
+            ret = round_to_format(&mut r, ret, format, self.flags, &func);
+        }
+        self.status |= ret;
+        r
+    }
+
+    fn op2(&mut self, a: &BigDecimal, b: &BigDecimal, func: BfdecOp2Func) -> BigDecimal {
+        self.apply(|r, prec, flags| unsafe { func(r, &a.v, &b.v, prec, flags) })
+    }
+
+    pub fn add(&mut self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.op2(a, b, bfdec_add)
+    }
+
+    pub fn sub(&mut self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.op2(a, b, bfdec_sub)
+    }
+
+    pub fn mul(&mut self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.op2(a, b, bfdec_mul)
+    }
+
+    pub fn div(&mut self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.op2(a, b, bfdec_div)
+    }
+
+    /// Remainder of the division of `a` by `b` truncated toward zero,
+    /// as the `%` operator.
+    pub fn rem(&mut self, a: &BigDecimal, b: &BigDecimal) -> BigDecimal {
+        self.apply(|r, prec, flags| unsafe {
+            bfdec_rem(r, &a.v, &b.v, prec, flags, BF_RNDZ as i32)
+        })
+    }
+
+    pub fn sqrt(&mut self, a: &BigDecimal) -> BigDecimal {
+        self.apply(|r, prec, flags| unsafe { bfdec_sqrt(r, &a.v, prec, flags) })
+    }
+
+    /// Return `a` to the power `n`. The power is computed exactly
+    /// before rounding.
+    pub fn powi(&mut self, a: &BigDecimal, n: i64) -> BigDecimal {
+        let mut p = BigDecimal::zero();
+        self.status |= unsafe { bfdec_pow_ui(&mut p.v, &a.v, n.unsigned_abs()) };
+        if n >= 0 {
+            self.round(&p)
+        } else {
+            self.div(&BigDecimal::from(1), &p)
+        }
+    }
+
+    /// Round `a` to the precision and exponent range of the
+    /// environment.
+    pub fn round(&mut self, a: &BigDecimal) -> BigDecimal {
+        self.apply(|r, prec, flags| unsafe { bfdec_set(r, &a.v) | bfdec_round(r, prec, flags) })
+    }
+
+    /// Parse the decimal number `s` and round it to the environment.
+    /// "Inf" and "NaN" are accepted with any case. If the memory limit
+    /// is exceeded, an error is returned and `BF_ST_MEM_ERROR` is
+    /// raised.
+    pub fn parse(&mut self, s: &str) -> Result<BigDecimal, ParseBigDecimalError> {
+        if s.is_empty() {
+            return Err(ParseBigDecimalError);
+        }
+        let cs = CString::new(s).map_err(|_| ParseBigDecimalError)?;
+        let mut a = BigDecimal::zero();
+        let mut next: *const c_char = std::ptr::null();
+        let status = unsafe { bfdec_atof(&mut a.v, cs.as_ptr(), &mut next, BF_PREC_INF, BF_RNDZ) };
+        let len = unsafe { next.offset_from(cs.as_ptr()) } as usize;
+        if status & BF_ST_MEM_ERROR != 0 {
+            self.status |= status;
+            return Err(ParseBigDecimalError);
+        }
+        if len != s.len() || (a.is_nan() && !s[s.len() - 3..].eq_ignore_ascii_case("nan")) {
+            return Err(ParseBigDecimalError);
+        }
+        let r = self.round(&a);
+        /* the rounding only returns NaN for NaN or a memory error */
+        if r.is_nan() && !a.is_nan() {
+            return Err(ParseBigDecimalError);
+        }
+        Ok(r)
+    }
+
+    /// Round `a` to decimal32 with the rounding mode of the
+    /// environment and return its encoding.
+    pub fn encode_decimal32(&mut self, a: &BigDecimal, enc: DecimalEncoding) -> u32 {
+        self.encode(a, DecimalFormat::Decimal32, enc) as u32
+    }
+
+    /// Round `a` to decimal64 with the rounding mode of the
+    /// environment and return its encoding.
+    pub fn encode_decimal64(&mut self, a: &BigDecimal, enc: DecimalEncoding) -> u64 {
+        self.encode(a, DecimalFormat::Decimal64, enc) as u64
+    }
+
+    /// Round `a` to decimal128 with the rounding mode of the
+    /// environment and return its encoding.
+    pub fn encode_decimal128(&mut self, a: &BigDecimal, enc: DecimalEncoding) -> u128 {
+        self.encode(a, DecimalFormat::Decimal128, enc)
+    }
+
+    fn encode(&mut self, a: &BigDecimal, format: DecimalFormat, enc: DecimalEncoding) -> u128 {
+        let mut env = BigDecimalEnv::for_format(format);
+        env.flags = self.flags;
+        let r = env.round(a);
+        self.status |= env.status;
+        r.encode(format, enc)
+    }
+}
+
+/* check the exponent range of the result 'r' of 'func' rounded to the
+precision of 'format'. The subnormal results are computed again with
+the precision of the subnormal numbers to avoid a double rounding. */
+fn round_to_format<F>(
+    r: &mut BigDecimal,
+    mut ret: i32,
+    format: DecimalFormat,
+    flags: bf_flags_t,
+    func: &F,
+) -> i32
+where
+    F: Fn(*mut bfdec_t, limb_t, bf_flags_t) -> i32,
+{
+    if !r.is_finite() || r.is_zero() {
+        return ret;
+    }
+    /* r = 0.ddd * 10^expn */
+    if r.v.expn <= format.emin() {
+        ret = func(
+            &mut r.v,
+            -format.q_min() as limb_t,
+            flags | BF_FLAG_RADPNT_PREC,
+        );
+        if ret & BF_ST_INEXACT != 0 {
+            ret |= BF_ST_UNDERFLOW;
+        }
+    } else if r.v.expn > format.emax() + 1 {
+        let rnd = flags & BF_RND_MASK;
+        let neg = r.is_sign_negative();
+        if rnd == BF_RNDZ || rnd == if neg { BF_RNDU } else { BF_RNDD } {
+            let max = 10u128.pow(format.digits()) - 1;
+            *r = BigDecimal::from_coefficient(neg, max, format.q_max());
+        } else {
+            unsafe { bfdec_set_inf(&mut r.v, neg as i32) };
+        }
+        ret |= BF_ST_OVERFLOW | BF_ST_INEXACT;
+    }
+    ret
+}
+
+/* run 'func' with the default environment of the current thread. The
+environment is not borrowed during the operation. */
+fn with_thread_default<F: FnOnce(&mut BigDecimalEnv) -> BigDecimal>(func: F) -> BigDecimal {
+    let mut env = BigDecimalEnv::thread_default();
+    env.clear_status();
+    let r = func(&mut env);
+    DEFAULT_ENV.with(|e| e.borrow_mut().status |= env.status);
+    r
+}
+
+/// Error returned when parsing a BigDecimal fails.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseBigDecimalError;
+
+impl fmt::Display for ParseBigDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid BigDecimal literal")
+    }
+}
+
+impl std::error::Error for ParseBigDecimalError {}
+
+/// Arbitrary precision decimal floating point number.
+pub struct BigDecimal {
+    v: bfdec_t,
+}
+
+impl BigDecimal {
+    /// Return +0.
+    pub fn zero() -> BigDecimal {
+        let mut v = bfdec_t {
+            ctx: std::ptr::null_mut(),
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: std::ptr::null_mut(),
+        };
+        unsafe { bfdec_init(thread_context(), &mut v) };
+        BigDecimal { v }
+    }
+
+    pub fn neg_zero() -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        unsafe { bfdec_set_zero(&mut r.v, 1) };
+        r
+    }
+
+    pub fn nan() -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        unsafe { bfdec_set_nan(&mut r.v) };
+        r
+    }
+
+    pub fn infinity() -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        unsafe { bfdec_set_inf(&mut r.v, 0) };
+        r
+    }
+
+    pub fn neg_infinity() -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        unsafe { bfdec_set_inf(&mut r.v, 1) };
+        r
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const bfdec_t {
+        &self.v
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut bfdec_t {
+        &mut self.v
+    }
+
+    /* return (-1)^neg * c * 10^q exactly */
+    fn from_coefficient(neg: bool, c: u128, q: i64) -> BigDecimal {
+        let mut r = BigDecimal::from((c / DEC_BASE as u128) as u64);
+        let lo = BigDecimal::from((c % DEC_BASE as u128) as u64);
+        let base = BigDecimal::from(DEC_BASE);
+        let p = r.as_mut_ptr();
+        unsafe {
+            bfdec_mul(p, p, &base.v, BF_PREC_INF, BF_RNDZ);
+            bfdec_add(p, p, &lo.v, BF_PREC_INF, BF_RNDZ);
+        }
+        if !r.is_zero() {
+            r.v.expn += q;
+        }
+        r.v.sign = neg as i32;
+        r
+    }
+
+    /* return (c, q) such as the number is c * 10^q with the smallest
+    integer c. The number must be finite, non zero and have at most 38
+    digits. */
+    fn coefficient(&self) -> (u128, i64) {
+        assert!(self.v.len <= 2);
+        let tab = unsafe { std::slice::from_raw_parts(self.v.tab, self.v.len as usize) };
+        let mut c = tab
+            .iter()
+            .rev()
+            .fold(0u128, |c, &l| c * DEC_BASE as u128 + l as u128);
+        let mut q = self.v.expn - LIMB_DIGITS * tab.len() as i64;
+        while c % 10 == 0 {
+            c /= 10;
+            q += 1;
+        }
+        (c, q)
+    }
+
+    /* the number must be rounded to 'format' */
+    fn encode(&self, format: DecimalFormat, enc: DecimalEncoding) -> u128 {
+        let k = format.bits();
+        let t = format.trailing_bits();
+        let w = format.exp_cont_bits();
+        let sign = (self.v.sign as u128) << (k - 1);
+        if self.is_nan() {
+            return 0x1f << (k - 6);
+        } else if self.is_infinite() {
+            return sign | (0x1e << (k - 6));
+        }
+        let (mut c, mut q) = if self.is_zero() {
+            (0, 0)
+        } else {
+            self.coefficient()
+        };
+        if q > format.q_max() {
+            c *= 10u128.pow((q - format.q_max()) as u32);
+            q = format.q_max();
+        }
+        let e = (q - format.q_min()) as u128;
+        match enc {
+            DecimalEncoding::Bid => {
+                if c >> (t + 3) == 0 {
+                    sign | (e << (t + 3)) | c
+                } else {
+                    sign | (3 << (k - 3)) | (e << (t + 1)) | (c & ((1 << (t + 1)) - 1))
+                }
+            }
+            DecimalEncoding::Dpd => {
+                let scale = 10u128.pow(format.digits() - 1);
+                let d0 = c / scale;
+                let mut rest = c % scale;
+                let mut trailing = 0;
+                for i in 0..t / 10 {
+                    trailing |= (dpd_encode((rest % 1000) as u32) as u128) << (10 * i);
+                    rest /= 1000;
+                }
+                let e_top = e >> w;
+                let g = if d0 < 8 {
+                    (e_top << 3) | d0
+                } else {
+                    0x18 | (e_top << 1) | (d0 & 1)
+                };
+                sign | (g << (k - 6)) | ((e & ((1 << w) - 1)) << t) | trailing
+            }
+        }
+    }
+
+    fn decode(bits: u128, format: DecimalFormat, enc: DecimalEncoding) -> BigDecimal {
+        let k = format.bits();
+        let t = format.trailing_bits();
+        let w = format.exp_cont_bits();
+        let neg = (bits >> (k - 1)) & 1 != 0;
+        let g = (bits >> (k - 6)) & 0x1f;
+        if g == 0x1f {
+            return BigDecimal::nan();
+        } else if g == 0x1e {
+            return if neg {
+                BigDecimal::neg_infinity()
+            } else {
+                BigDecimal::infinity()
+            };
+        }
+        let (e, c) = match enc {
+            DecimalEncoding::Bid => {
+                let e_mask = (1 << (w + 2)) - 1;
+                if (bits >> (k - 3)) & 3 == 3 {
+                    let c = (4 << (t + 1)) | (bits & ((1 << (t + 1)) - 1));
+                    ((bits >> (t + 1)) & e_mask, c)
+                } else {
+                    ((bits >> (t + 3)) & e_mask, bits & ((1 << (t + 3)) - 1))
+                }
+            }
+            DecimalEncoding::Dpd => {
+                let (e_top, d0) = if g >> 3 == 3 {
+                    ((g >> 1) & 3, 8 + (g & 1))
+                } else {
+                    (g >> 3, g & 7)
+                };
+                let mut c = d0;
+                for i in (0..t / 10).rev() {
+                    c = c * 1000 + dpd_decode(((bits >> (10 * i)) & 0x3ff) as u32) as u128;
+                }
+                ((e_top << w) | ((bits >> t) & ((1 << w) - 1)), c)
+            }
+        };
+        /* non-canonical coefficients are interpreted as zero */
+        let c = if c < 10u128.pow(format.digits()) {
+            c
+        } else {
+            0
+        };
+        BigDecimal::from_coefficient(neg, c, e as i64 + format.q_min())
+    }
+
+    /// Decode a decimal32 number. The conversion is exact.
+    pub fn decode_decimal32(bits: u32, enc: DecimalEncoding) -> BigDecimal {
+        BigDecimal::decode(bits as u128, DecimalFormat::Decimal32, enc)
+    }
+
+    /// Decode a decimal64 number. The conversion is exact.
+    pub fn decode_decimal64(bits: u64, enc: DecimalEncoding) -> BigDecimal {
+        BigDecimal::decode(bits as u128, DecimalFormat::Decimal64, enc)
+    }
+
+    /// Decode a decimal128 number. The conversion is exact.
+    pub fn decode_decimal128(bits: u128, enc: DecimalEncoding) -> BigDecimal {
+        BigDecimal::decode(bits, DecimalFormat::Decimal128, enc)
+    }
+
+    pub fn is_nan(&self) -> bool {
+        unsafe { bfdec_is_nan(&self.v) != 0 }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        unsafe { bfdec_is_finite(&self.v) != 0 }
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        !self.is_finite() && !self.is_nan()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        unsafe { bfdec_is_zero(&self.v) != 0 }
+    }
+
+    /// Return true if the number is finite and has no fractional part.
+    pub fn is_integer(&self) -> bool {
+        if !self.is_finite() {
+            return false;
+        }
+        let mut r = self.clone();
+        unsafe { bfdec_rint(&mut r.v, BF_RNDZ as i32) == 0 }
+    }
+
+    /// Return true for the negative numbers, -0 and -Infinity.
+    pub fn is_sign_negative(&self) -> bool {
+        self.v.sign != 0
+    }
+
+    pub fn abs(&self) -> BigDecimal {
+        let mut r = self.clone();
+        r.v.sign = 0;
+        r
+    }
+
+    /// Round to `digits` digits after the decimal point with `rnd`.
+    pub fn round_dp(&self, digits: u64, rnd: Rounding) -> BigDecimal {
+        let mut r = self.clone();
+        unsafe { bfdec_round(&mut r.v, digits, rnd as bf_flags_t | BF_FLAG_RADPNT_PREC) };
+        r
+    }
+
+    pub fn trunc(&self) -> BigDecimal {
+        self.round_dp(0, Rounding::TowardZero)
+    }
+
+    pub fn floor(&self) -> BigDecimal {
+        self.round_dp(0, Rounding::Down)
+    }
+
+    pub fn ceil(&self) -> BigDecimal {
+        self.round_dp(0, Rounding::Up)
+    }
+
+    /// Round to the nearest integer, the halfway cases away from zero.
+    pub fn round(&self) -> BigDecimal {
+        self.round_dp(0, Rounding::NearestAway)
+    }
+
+    /// Convert to an integer truncated toward zero. Return None for NaN
+    /// and the numbers which do not fit in an i64.
+    pub fn to_i64(&self) -> Option<i64> {
+        if !self.is_finite() {
+            return None;
+        } else if self.v.expn <= 0 {
+            return Some(0);
+        } else if self.v.expn > LIMB_DIGITS {
+            return None;
+        }
+        let top = unsafe { *self.v.tab.add(self.v.len as usize - 1) };
+        let v = top / 10u64.pow((LIMB_DIGITS - self.v.expn) as u32);
+        if self.is_sign_negative() {
+            if v <= 1 << 63 {
+                Some((v as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else if v <= i64::MAX as u64 {
+            Some(v as i64)
+        } else {
+            None
+        }
+    }
+
+    /// Convert to the nearest f64 (ties to even).
+    pub fn to_f64(&self) -> f64 {
+        self.ftoa(BF_PREC_INF, BF_FTOA_FORMAT_FREE)
+            .parse()
+            .unwrap_or(f64::NAN)
+    }
+
+    pub(crate) fn ftoa(&self, prec: limb_t, flags: bf_flags_t) -> String {
+        self.try_ftoa(prec, flags)
+            .expect("bfdec_ftoa: out of memory")
+    }
+
+    pub(crate) fn try_ftoa(&self, prec: limb_t, flags: bf_flags_t) -> Result<String, OutOfMemory> {
+        let ctx = thread_context();
+        unsafe {
+            let mut len = 0;
+            let buf = bfdec_ftoa(ctx, &mut len, &self.v, prec, flags);
+            if buf.is_null() {
+                return Err(OutOfMemory);
+            }
+            let bytes = std::slice::from_raw_parts(buf as *const u8, len as usize);
+            let s = String::from_utf8_lossy(bytes).into_owned();
+            bf_free(ctx, buf as *mut std::ffi::c_void);
+            Ok(s)
+        }
+    }
+
+    /// Return all the digits of the number as `to_string`, or an error
+    /// if the memory limit is exceeded.
+    pub fn try_to_string(&self) -> Result<String, OutOfMemory> {
+        self.try_ftoa(BF_PREC_INF, BF_FTOA_FORMAT_FREE)
+    }
+
+    /// Return a copy of the number, or an error if the memory limit is
+    /// exceeded.
+    pub fn try_clone(&self) -> Result<BigDecimal, OutOfMemory> {
+        let mut r = BigDecimal::zero();
+        if unsafe { bfdec_set(&mut r.v, &self.v) } != 0 {
+            return Err(OutOfMemory);
+        }
+        Ok(r)
+    }
+
+    /// Format the number with `digits` digits after the decimal
+    /// point.
+    pub fn to_fixed(&self, digits: u64, rnd: Rounding) -> String {
+        self.ftoa(digits, BF_FTOA_FORMAT_FRAC | rnd as bf_flags_t)
+    }
+
+    /// Format the number in exponential notation with `digits` digits
+    /// after the decimal point, e.g. "1.23e3".
+    pub fn to_exponential(&self, digits: u64, rnd: Rounding) -> String {
+        self.ftoa(
+            digits.saturating_add(1),
+            BF_FTOA_FORMAT_FIXED | BF_FTOA_FORCE_EXP | rnd as bf_flags_t,
+        )
+    }
+
+    /// Format the number with `digits` significant digits. The
+    /// exponential notation is used if too many zeros are needed.
+    /// Panics if `digits` is 0.
+    pub fn to_precision(&self, digits: u64, rnd: Rounding) -> String {
+        assert!(digits > 0, "invalid number of digits");
+        self.ftoa(digits, BF_FTOA_FORMAT_FIXED | rnd as bf_flags_t)
+    }
+}
+
+impl Drop for BigDecimal {
+    fn drop(&mut self) {
+        unsafe { bfdec_delete(&mut self.v) }
+    }
+}
+
+/* same threading model as BigFloat */
+unsafe impl Send for BigDecimal {}
+unsafe impl Sync for BigDecimal {}
+
+impl Clone for BigDecimal {
+    fn clone(&self) -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        r.clone_from(self);
+        r
+    }
+
+    fn clone_from(&mut self, source: &BigDecimal) {
+        if unsafe { bfdec_set(&mut self.v, &source.v) } != 0 {
+            panic!("BigDecimal: out of memory");
+        }
+    }
+}
+
+impl Default for BigDecimal {
+    fn default() -> BigDecimal {
+        BigDecimal::zero()
+    }
+}
+
+impl From<i64> for BigDecimal {
+    fn from(v: i64) -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        unsafe { bfdec_set_si(&mut r.v, v) };
+        r
+    }
+}
+
+impl From<u64> for BigDecimal {
+    fn from(v: u64) -> BigDecimal {
+        let mut r = BigDecimal::zero();
+        unsafe { bfdec_set_ui(&mut r.v, v) };
+        r
+    }
+}
+
+impl From<i32> for BigDecimal {
+    fn from(v: i32) -> BigDecimal {
+        BigDecimal::from(v as i64)
+    }
+}
+
+impl From<u32> for BigDecimal {
+    fn from(v: u32) -> BigDecimal {
+        BigDecimal::from(v as u64)
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = ParseBigDecimalError;
+
+    /// Parse a decimal number rounded to the default environment of the
+    /// current thread.
+    fn from_str(s: &str) -> Result<BigDecimal, ParseBigDecimalError> {
+        let mut env = BigDecimalEnv::thread_default();
+        env.clear_status();
+        let r = env.parse(s)?;
+        DEFAULT_ENV.with(|e| e.borrow_mut().status |= env.status);
+        Ok(r)
+    }
+}
+
+impl PartialEq for BigDecimal {
+    fn eq(&self, other: &BigDecimal) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &BigDecimal) -> Option<Ordering> {
+        match unsafe { bfdec_cmp(&self.v, &other.v) } {
+            2 => None,
+            res => Some(res.cmp(&0)),
+        }
+    }
+}
+
+impl fmt::Display for BigDecimal {
+    /// All the digits are written. The precision of the formatter is
+    /// the number of digits after the decimal point.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match f.precision() {
+            Some(digits) => self.to_fixed(digits as u64, Rounding::NearestEven),
+            None => self.ftoa(BF_PREC_INF, BF_FTOA_FORMAT_FREE),
+        };
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &s),
+        }
+    }
+}
+
+impl fmt::LowerExp for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match f.precision() {
+            Some(digits) => self.to_exponential(digits as u64, Rounding::NearestEven),
+            None => self.ftoa(BF_PREC_INF, BF_FTOA_FORMAT_FREE | BF_FTOA_FORCE_EXP),
+        };
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &s),
+        }
+    }
+}
+
+impl fmt::Debug for BigDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(mut self) -> BigDecimal {
+        unsafe { bfdec_neg(&mut self.v) };
+        self
+    }
+}
+
+impl Neg for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn neg(self) -> BigDecimal {
+        -self.clone()
+    }
+}
+
+/* binary operators rounded with the default environment of the
+current thread */
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<&BigDecimal> for &BigDecimal {
+            type Output = BigDecimal;
+
+            fn $op(self, rhs: &BigDecimal) -> BigDecimal {
+                with_thread_default(|env| env.$op(self, rhs))
+            }
+        }
+
+        impl $Op<BigDecimal> for &BigDecimal {
+            type Output = BigDecimal;
+
+            fn $op(self, rhs: BigDecimal) -> BigDecimal {
+                self.$op(&rhs)
+            }
+        }
+
+        impl $Op<&BigDecimal> for BigDecimal {
+            type Output = BigDecimal;
+
+            fn $op(self, rhs: &BigDecimal) -> BigDecimal {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl $Op<BigDecimal> for BigDecimal {
+            type Output = BigDecimal;
+
+            fn $op(self, rhs: BigDecimal) -> BigDecimal {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl $OpAssign<&BigDecimal> for BigDecimal {
+            fn $op_assign(&mut self, rhs: &BigDecimal) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+
+        impl $OpAssign<BigDecimal> for BigDecimal {
+            fn $op_assign(&mut self, rhs: BigDecimal) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
@@ -0,0 +1,984 @@
+/*
+ * Arbitrary precision binary floating point numbers
+ *
+ * BigFloat is a safe wrapper of bf_t which frees its limbs when it is
+ * dropped. The operations are rounded with the precision, exponent
+ * range and rounding mode of a BigFloatEnv which also accumulates
+ * the status flags, as the BigFloatEnv objects of qjscalc. The
+ * operators and the string conversions use the default environment
+ * of the current thread, which is initialized as in JS_NewContext()
+ * (113 bits of precision and 15 exponent bits with subnormal numbers,
+ * i.e. IEEE 754 binary128).
+ *
+ * Each thread has its own libbf context, in which the results of the
+ * operations are allocated. The numbers are Send and Sync: the
+ * operands can come from other threads, and the constants such as pi
+ * are computed only once for all the threads.
+ */
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::ffi::CString;
+use std::fmt;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::os::raw::c_char;
+use std::str::FromStr;
+use std::sync::atomic::Ordering as AtomicOrdering;
+
+use crate::libbf::{
+    bf_account_realloc, bf_acos, bf_acosh, bf_add, bf_asin, bf_asinh, bf_atan, bf_atan2, bf_atanh,
+    bf_atof, bf_cbrt, bf_clear_cache, bf_clear_shared_cache, bf_cmp, bf_const_euler, bf_const_log2,
+    bf_const_pi, bf_context_init, bf_context_t, bf_cos, bf_cosh, bf_delete, bf_div, bf_erf,
+    bf_erfc, bf_exp, bf_expm1, bf_flags_t, bf_free, bf_ftoa, bf_gamma, bf_get_exp_bits,
+    bf_get_float64, bf_get_int64, bf_hypot, bf_init, bf_is_finite, bf_is_nan, bf_is_zero,
+    bf_lgamma, bf_log, bf_log10, bf_log1p, bf_log2, bf_mul, bf_neg, bf_op2_func_t, bf_pow, bf_rem,
+    bf_rint, bf_rnd_t, bf_round, bf_set, bf_set_exp_bits, bf_set_float64, bf_set_inf, bf_set_nan,
+    bf_set_si, bf_set_ui, bf_set_zero, bf_sin, bf_sinh, bf_sqrt, bf_sub, bf_t, bf_tan, bf_tanh,
+    bf_zeta, limb_t, BFMemAccount, BF_EXP_BITS_MAX, BF_EXP_BITS_MIN, BF_FLAG_SUBNORMAL,
+    BF_FTOA_FORCE_EXP, BF_FTOA_FORMAT_FIXED, BF_FTOA_FORMAT_FRAC, BF_FTOA_FORMAT_FREE_MIN,
+    BF_PREC_MAX, BF_PREC_MIN, BF_RNDA, BF_RNDD, BF_RNDF, BF_RNDN, BF_RNDNA, BF_RNDU, BF_RNDZ,
+    BF_RND_MASK,
+};
+pub use crate::libbf::{
+    BF_ST_DIVIDE_ZERO, BF_ST_INEXACT, BF_ST_INVALID_OP, BF_ST_MEM_ERROR, BF_ST_OVERFLOW,
+    BF_ST_UNDERFLOW,
+};
+
+/// Rounding mode of the BigFloat operations.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// Round to nearest, ties to even.
+    NearestEven = BF_RNDN,
+    /// Round toward zero.
+    TowardZero = BF_RNDZ,
+    /// Round toward negative infinity.
+    Down = BF_RNDD,
+    /// Round toward positive infinity.
+    Up = BF_RNDU,
+    /// Round to nearest, ties away from zero.
+    NearestAway = BF_RNDNA,
+    /// Round away from zero.
+    AwayFromZero = BF_RNDA,
+    /// Faithful rounding: either `Down` or `Up`, the result is always
+    /// inexact.
+    Faithful = BF_RNDF,
+}
+
+impl Rounding {
+    pub(crate) fn from_rnd(rnd: bf_rnd_t) -> Rounding {
+        match rnd {
+            BF_RNDZ => Rounding::TowardZero,
+            BF_RNDD => Rounding::Down,
+            BF_RNDU => Rounding::Up,
+            BF_RNDNA => Rounding::NearestAway,
+            BF_RNDA => Rounding::AwayFromZero,
+            BF_RNDF => Rounding::Faithful,
+            _ => Rounding::NearestEven,
+        }
+    }
+}
+
+/* libbf context of the current thread with its memory account. The
+numbers keep a pointer to them, so they are never freed: only the
+constant caches are released when the thread exits. */
+struct ThreadContext(*mut bf_context_t, &'static BFMemAccount);
+
+impl Drop for ThreadContext {
+    fn drop(&mut self) {
+        unsafe { bf_clear_cache(self.0) }
+    }
+}
+
+thread_local! {
+    static BF_CONTEXT: ThreadContext = unsafe {
+        let account: &'static BFMemAccount = Box::leak(Box::new(BFMemAccount::new(usize::MAX)));
+        let s = Box::into_raw(Box::new(std::mem::zeroed::<bf_context_t>()));
+        bf_context_init(
+            s,
+            Some(bf_account_realloc),
+            account as *const BFMemAccount as *mut std::ffi::c_void,
+        );
+        ThreadContext(s, account)
+    };
+    static DEFAULT_ENV: RefCell<BigFloatEnv> = RefCell::new(BigFloatEnv::default());
+}
+
+pub(crate) fn thread_context() -> *mut bf_context_t {
+    BF_CONTEXT.with(|s| s.0)
+}
+
+fn thread_account() -> &'static BFMemAccount {
+    BF_CONTEXT.with(|s| s.1)
+}
+
+/// Memory allocated by the libbf context of a thread, in bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MemoryUsage {
+    pub current: usize,
+    pub peak: usize,
+}
+
+/// Limit the memory allocated by the libbf context of the current
+/// thread, which holds the numbers created by the thread, the constant
+/// caches and the multiplication buffers. The operations which would
+/// exceed it return NaN and raise `BF_ST_MEM_ERROR` in their
+/// environment; the operations without an environment (BigInt, clone,
+/// string conversions) panic, except their `try_` variants which
+/// return `OutOfMemory`. `None` removes the limit.
+pub fn set_memory_limit(limit: Option<usize>) {
+    let limit = limit.unwrap_or(usize::MAX);
+    thread_account().limit.store(limit, AtomicOrdering::Relaxed);
+}
+
+/// Return the memory limit of the current thread, if any.
+pub fn memory_limit() -> Option<usize> {
+    match thread_account().limit.load(AtomicOrdering::Relaxed) {
+        usize::MAX => None,
+        limit => Some(limit),
+    }
+}
+
+/// Return the memory currently allocated by the libbf context of the
+/// current thread and its peak since the thread started or the last
+/// call to `reset_peak_memory_usage`. The numbers freed by other
+/// threads are deducted from the context which allocated them.
+pub fn memory_usage() -> MemoryUsage {
+    let account = thread_account();
+    let current = account.cur.load(AtomicOrdering::Relaxed);
+    MemoryUsage {
+        current,
+        peak: account.peak.load(AtomicOrdering::Relaxed).max(current),
+    }
+}
+
+/// Set the peak memory usage of the current thread to its current
+/// usage.
+pub fn reset_peak_memory_usage() {
+    let account = thread_account();
+    account.peak.store(
+        account.cur.load(AtomicOrdering::Relaxed),
+        AtomicOrdering::Relaxed,
+    );
+}
+
+/// Free the values of the constants (pi, log(2)) shared by all the
+/// threads, and the caches and multiplication tables of the current
+/// thread.
+pub fn clear_cache() {
+    unsafe { bf_clear_cache(thread_context()) };
+    bf_clear_shared_cache();
+}
+
+/// Enable or disable the parallel multiplication of large numbers in
+/// the current thread. It is enabled by default and gives the same
+/// results as the serial one.
+#[cfg(feature = "parallel-ntt")]
+pub fn set_parallel_multiplication(enabled: bool) {
+    crate::libbf::bf_set_ntt_parallel(enabled);
+}
+
+/// Precision, exponent range and rounding mode of the BigFloat
+/// operations, with the status flags (`BF_ST_*`) they raised.
+#[derive(Clone, Debug)]
+pub struct BigFloatEnv {
+    prec: limb_t,
+    flags: bf_flags_t,
+    status: i32,
+}
+
+impl Default for BigFloatEnv {
+    /// IEEE 754 binary128: 113 bits of precision, 15 exponent bits and
+    /// subnormal numbers, rounding to nearest.
+    fn default() -> BigFloatEnv {
+        BigFloatEnv {
+            prec: 113,
+            flags: unsafe { bf_set_exp_bits(15) } | BF_RNDN | BF_FLAG_SUBNORMAL,
+            status: 0,
+        }
+    }
+}
+
+type BfOp1Func = unsafe fn(*mut bf_t, *const bf_t, limb_t, bf_flags_t) -> i32;
+
+impl BigFloatEnv {
+    pub const PREC_MIN: u64 = BF_PREC_MIN;
+    pub const PREC_MAX: u64 = BF_PREC_MAX;
+    pub const EXP_BITS_MIN: u32 = BF_EXP_BITS_MIN as u32;
+    pub const EXP_BITS_MAX: u32 = BF_EXP_BITS_MAX as u32;
+
+    /// Return an environment with a precision of `prec` bits, rounding
+    /// to nearest and the maximum exponent range without subnormal
+    /// numbers. `prec` is clamped between `PREC_MIN` and `PREC_MAX`.
+    pub fn new(prec: u64) -> BigFloatEnv {
+        let mut env = BigFloatEnv {
+            prec: BF_PREC_MIN,
+            flags: BF_RNDN,
+            status: 0,
+        };
+        env.set_prec(prec);
+        env
+    }
+
+    /// Return a copy of the default environment of the current thread.
+    pub fn thread_default() -> BigFloatEnv {
+        DEFAULT_ENV.with(|e| e.borrow().clone())
+    }
+
+    /// Replace the default environment of the current thread and
+    /// return the previous one.
+    pub fn set_thread_default(env: BigFloatEnv) -> BigFloatEnv {
+        DEFAULT_ENV.with(|e| e.replace(env))
+    }
+
+    pub fn prec(&self) -> u64 {
+        self.prec
+    }
+
+    /// Set the precision in bits. It is clamped between `PREC_MIN`
+    /// and `PREC_MAX`.
+    pub fn set_prec(&mut self, prec: u64) {
+        self.prec = prec.clamp(BF_PREC_MIN, BF_PREC_MAX);
+    }
+
+    pub fn rounding(&self) -> Rounding {
+        Rounding::from_rnd(self.flags & BF_RND_MASK)
+    }
+
+    pub fn set_rounding(&mut self, rnd: Rounding) {
+        self.flags = (self.flags & !BF_RND_MASK) | rnd as bf_rnd_t;
+    }
+
+    /// Return the number of bits of the exponent.
+    pub fn exp_bits(&self) -> u32 {
+        unsafe { bf_get_exp_bits(self.flags) as u32 }
+    }
+
+    /// Set the number of bits of the exponent. It is clamped between
+    /// `EXP_BITS_MIN` and `EXP_BITS_MAX`.
+    pub fn set_exp_bits(&mut self, exp_bits: u32) {
+        let exp_bits = exp_bits.clamp(Self::EXP_BITS_MIN, Self::EXP_BITS_MAX);
+        self.flags = (self.flags & (BF_RND_MASK | BF_FLAG_SUBNORMAL))
+            | unsafe { bf_set_exp_bits(exp_bits as i32) };
+    }
+
+    pub fn subnormal(&self) -> bool {
+        self.flags & BF_FLAG_SUBNORMAL != 0
+    }
+
+    pub fn set_subnormal(&mut self, subnormal: bool) {
+        if subnormal {
+            self.flags |= BF_FLAG_SUBNORMAL;
+        } else {
+            self.flags &= !BF_FLAG_SUBNORMAL;
+        }
+    }
+
+    /// Return the status flags raised since the last call to
+    /// `clear_status`.
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+
+    pub fn clear_status(&mut self) {
+        self.status = 0;
+    }
+
+    pub(crate) fn flags(&self) -> bf_flags_t {
+        self.flags
+    }
+
+    fn op1(&mut self, a: &BigFloat, func: BfOp1Func) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { func(&mut r.v, &a.v, self.prec, self.flags) };
+        r
+    }
+
+    fn op2(&mut self, a: &BigFloat, b: &BigFloat, func: bf_op2_func_t) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { func(&mut r.v, &a.v, &b.v, self.prec, self.flags) };
+        r
+    }
+
+    pub fn add(&mut self, a: &BigFloat, b: &BigFloat) -> BigFloat {
+        self.op2(a, b, bf_add)
+    }
+
+    pub fn sub(&mut self, a: &BigFloat, b: &BigFloat) -> BigFloat {
+        self.op2(a, b, bf_sub)
+    }
+
+    pub fn mul(&mut self, a: &BigFloat, b: &BigFloat) -> BigFloat {
+        self.op2(a, b, bf_mul)
+    }
+
+    pub fn div(&mut self, a: &BigFloat, b: &BigFloat) -> BigFloat {
+        self.op2(a, b, bf_div)
+    }
+
+    /// Remainder of the division of `a` by `b` truncated toward zero,
+    /// as the `%` operator.
+    pub fn rem(&mut self, a: &BigFloat, b: &BigFloat) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |=
+            unsafe { bf_rem(&mut r.v, &a.v, &b.v, self.prec, self.flags, BF_RNDZ as i32) };
+        r
+    }
+
+    pub fn pow(&mut self, a: &BigFloat, b: &BigFloat) -> BigFloat {
+        self.op2(a, b, bf_pow)
+    }
+
+    pub fn atan2(&mut self, y: &BigFloat, x: &BigFloat) -> BigFloat {
+        self.op2(y, x, bf_atan2)
+    }
+
+    pub fn sqrt(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_sqrt)
+    }
+
+    /// `sqrt(x * x + y * y)`, computed without intermediate overflow.
+    pub fn hypot(&mut self, x: &BigFloat, y: &BigFloat) -> BigFloat {
+        self.op2(x, y, bf_hypot)
+    }
+
+    pub fn cbrt(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_cbrt)
+    }
+
+    pub fn exp(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_exp)
+    }
+
+    pub fn log(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_log)
+    }
+
+    /// `exp(a) - 1`, accurate for `a` close to zero.
+    pub fn expm1(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_expm1)
+    }
+
+    /// `log(1 + a)`, accurate for `a` close to zero.
+    pub fn log1p(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_log1p)
+    }
+
+    /// The result is exact when `a` is a power of two.
+    pub fn log2(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_log2)
+    }
+
+    /// The result is exact when `a` is an integer power of ten.
+    pub fn log10(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_log10)
+    }
+
+    pub fn sin(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_sin)
+    }
+
+    pub fn cos(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_cos)
+    }
+
+    pub fn tan(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_tan)
+    }
+
+    pub fn asin(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_asin)
+    }
+
+    pub fn acos(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_acos)
+    }
+
+    pub fn atan(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_atan)
+    }
+
+    pub fn sinh(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_sinh)
+    }
+
+    pub fn cosh(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_cosh)
+    }
+
+    pub fn tanh(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_tanh)
+    }
+
+    pub fn asinh(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_asinh)
+    }
+
+    pub fn acosh(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_acosh)
+    }
+
+    pub fn atanh(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_atanh)
+    }
+
+    /// The gamma function. It is exact for the small positive integers.
+    pub fn gamma(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_gamma)
+    }
+
+    /// `log(|gamma(a)|)`, which does not overflow for large `a`.
+    pub fn lgamma(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_lgamma)
+    }
+
+    pub fn erf(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_erf)
+    }
+
+    /// `1 - erf(a)`, accurate for large `a` where it underflows in
+    /// double precision.
+    pub fn erfc(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_erfc)
+    }
+
+    /// The Riemann zeta function.
+    pub fn zeta(&mut self, a: &BigFloat) -> BigFloat {
+        self.op1(a, bf_zeta)
+    }
+
+    /// Round `a` to the precision and exponent range of the
+    /// environment.
+    pub fn round(&mut self, a: &BigFloat) -> BigFloat {
+        let mut r = a.clone();
+        self.status |= unsafe { bf_round(&mut r.v, self.prec, self.flags) };
+        r
+    }
+
+    pub fn const_pi(&mut self) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { bf_const_pi(&mut r.v, self.prec, self.flags) };
+        r
+    }
+
+    pub fn const_log2(&mut self) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { bf_const_log2(&mut r.v, self.prec, self.flags) };
+        r
+    }
+
+    /// Euler's constant `0.5772...`.
+    pub fn const_euler(&mut self) -> BigFloat {
+        let mut r = BigFloat::zero();
+        self.status |= unsafe { bf_const_euler(&mut r.v, self.prec, self.flags) };
+        r
+    }
+
+    /// Parse the number `s` written in base `radix` (between 2 and 36)
+    /// and round it to the environment. "Inf" and "NaN" are accepted
+    /// with any case. For the radix 10 and 16, a "0x" prefix selects
+    /// the hexadecimal notation. If the memory limit is exceeded, an
+    /// error is returned and `BF_ST_MEM_ERROR` is raised.
+    pub fn parse(&mut self, s: &str, radix: u32) -> Result<BigFloat, ParseBigFloatError> {
+        if !(2..=36).contains(&radix) || s.is_empty() {
+            return Err(ParseBigFloatError);
+        }
+        let cs = CString::new(s).map_err(|_| ParseBigFloatError)?;
+        let mut r = BigFloat::zero();
+        let mut next: *const c_char = std::ptr::null();
+        let status = unsafe {
+            bf_atof(
+                &mut r.v,
+                cs.as_ptr(),
+                &mut next,
+                radix as i32,
+                self.prec,
+                self.flags,
+            )
+        };
+        let len = unsafe { next.offset_from(cs.as_ptr()) } as usize;
+        if status & BF_ST_MEM_ERROR != 0 {
+            self.status |= status;
+            return Err(ParseBigFloatError);
+        }
+        if len != s.len() || (r.is_nan() && !s[s.len() - 3..].eq_ignore_ascii_case("nan")) {
+            return Err(ParseBigFloatError);
+        }
+        self.status |= status;
+        Ok(r)
+    }
+}
+
+/* run 'func' with the default environment of the current thread. The
+environment is not borrowed during the operation. */
+fn with_thread_default<F: FnOnce(&mut BigFloatEnv) -> BigFloat>(func: F) -> BigFloat {
+    let mut env = BigFloatEnv::thread_default();
+    env.clear_status();
+    let r = func(&mut env);
+    DEFAULT_ENV.with(|e| e.borrow_mut().status |= env.status);
+    r
+}
+
+/// Error returned when parsing a BigFloat fails.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseBigFloatError;
+
+impl fmt::Display for ParseBigFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid BigFloat literal")
+    }
+}
+
+impl std::error::Error for ParseBigFloatError {}
+
+/// Error returned by the `try_` operations when the memory limit of the
+/// thread is exceeded.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OutOfMemory;
+
+impl fmt::Display for OutOfMemory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of memory")
+    }
+}
+
+impl std::error::Error for OutOfMemory {}
+
+/// Arbitrary precision binary floating point number.
+/* same layout as bf_t */
+#[repr(transparent)]
+pub struct BigFloat {
+    v: bf_t,
+}
+
+impl BigFloat {
+    /// Return +0.
+    pub fn zero() -> BigFloat {
+        let mut v = bf_t {
+            ctx: std::ptr::null_mut(),
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: std::ptr::null_mut(),
+        };
+        unsafe { bf_init(thread_context(), &mut v) };
+        BigFloat { v }
+    }
+
+    pub fn neg_zero() -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_zero(&mut r.v, 1) };
+        r
+    }
+
+    pub fn nan() -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_nan(&mut r.v) };
+        r
+    }
+
+    pub fn infinity() -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_inf(&mut r.v, 0) };
+        r
+    }
+
+    pub fn neg_infinity() -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_inf(&mut r.v, 1) };
+        r
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const bf_t {
+        &self.v
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut bf_t {
+        &mut self.v
+    }
+
+    pub fn is_nan(&self) -> bool {
+        unsafe { bf_is_nan(&self.v) != 0 }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        unsafe { bf_is_finite(&self.v) != 0 }
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        !self.is_finite() && !self.is_nan()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        unsafe { bf_is_zero(&self.v) != 0 }
+    }
+
+    /// Return true if the number is finite and has no fractional part.
+    pub fn is_integer(&self) -> bool {
+        if !self.is_finite() {
+            return false;
+        }
+        let mut r = self.clone();
+        unsafe { bf_rint(&mut r.v, BF_RNDZ as i32) == 0 }
+    }
+
+    /// Return true for the negative numbers, -0, -Infinity and the NaN
+    /// with a sign bit.
+    pub fn is_sign_negative(&self) -> bool {
+        self.v.sign != 0
+    }
+
+    pub fn abs(&self) -> BigFloat {
+        let mut r = self.clone();
+        r.v.sign = 0;
+        r
+    }
+
+    /* round to an integer with 'rnd'. Always exact. */
+    fn rint(&self, rnd: bf_rnd_t) -> BigFloat {
+        let mut r = self.clone();
+        unsafe { bf_rint(&mut r.v, rnd as i32) };
+        r
+    }
+
+    pub fn trunc(&self) -> BigFloat {
+        self.rint(BF_RNDZ)
+    }
+
+    pub fn floor(&self) -> BigFloat {
+        self.rint(BF_RNDD)
+    }
+
+    pub fn ceil(&self) -> BigFloat {
+        self.rint(BF_RNDU)
+    }
+
+    /// Round to the nearest integer, the halfway cases away from zero.
+    pub fn round(&self) -> BigFloat {
+        self.rint(BF_RNDNA)
+    }
+
+    /// Convert to the nearest f64 (ties to even).
+    pub fn to_f64(&self) -> f64 {
+        let mut d = 0.0;
+        unsafe { bf_get_float64(thread_context(), &self.v, &mut d, BF_RNDN) };
+        d
+    }
+
+    /// Convert to an integer truncated toward zero. Return None for NaN
+    /// and the numbers which do not fit in an i64.
+    pub fn to_i64(&self) -> Option<i64> {
+        let mut v = 0;
+        if unsafe { bf_get_int64(&mut v, &self.v, 0) } & BF_ST_INVALID_OP != 0 {
+            None
+        } else {
+            Some(v)
+        }
+    }
+
+    pub(crate) fn ftoa(&self, radix: u32, prec: limb_t, flags: bf_flags_t) -> String {
+        self.try_ftoa(radix, prec, flags)
+            .expect("bf_ftoa: out of memory")
+    }
+
+    pub(crate) fn try_ftoa(
+        &self,
+        radix: u32,
+        prec: limb_t,
+        flags: bf_flags_t,
+    ) -> Result<String, OutOfMemory> {
+        assert!((2..=36).contains(&radix), "invalid radix");
+        let ctx = thread_context();
+        unsafe {
+            let mut len = 0;
+            let buf = bf_ftoa(ctx, &mut len, &self.v, radix as i32, prec, flags);
+            if buf.is_null() {
+                return Err(OutOfMemory);
+            }
+            let bytes = std::slice::from_raw_parts(buf as *const u8, len as usize);
+            let s = String::from_utf8_lossy(bytes).into_owned();
+            bf_free(ctx, buf as *mut std::ffi::c_void);
+            Ok(s)
+        }
+    }
+
+    /* number of significant bits of the mantissa */
+    pub(crate) fn mantissa_bits(&self) -> limb_t {
+        let tab = if self.v.len == 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.v.tab, self.v.len as usize) }
+        };
+        match tab.iter().position(|&l| l != 0) {
+            Some(i) => (tab.len() - i) as limb_t * 64 - tab[i].trailing_zeros() as limb_t,
+            None => 0,
+        }
+    }
+
+    /// Return the shortest representation of the number in base `radix`
+    /// (between 2 and 36) which is parsed back to the same number with
+    /// its precision or the precision of the default environment if it
+    /// is larger.
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        self.try_to_string_radix(radix)
+            .expect("bf_ftoa: out of memory")
+    }
+
+    /// Same as `to_string_radix` but return an error instead of
+    /// panicking if the memory limit is exceeded.
+    pub fn try_to_string_radix(&self, radix: u32) -> Result<String, OutOfMemory> {
+        let prec = self.mantissa_bits().max(BigFloatEnv::thread_default().prec);
+        let s = self.try_ftoa(radix, prec, BF_FTOA_FORMAT_FREE_MIN | BF_RNDN)?;
+        /* bf_ftoa() writes the exponent with 'e' below radix 10 but
+        bf_atof() only accepts it in radix 10 */
+        if radix < 10 {
+            Ok(s.replace('e', "@"))
+        } else {
+            Ok(s)
+        }
+    }
+
+    /// Return a copy of the number, or an error if the memory limit is
+    /// exceeded.
+    pub fn try_clone(&self) -> Result<BigFloat, OutOfMemory> {
+        let mut r = BigFloat::zero();
+        if unsafe { bf_set(&mut r.v, &self.v) } != 0 {
+            return Err(OutOfMemory);
+        }
+        Ok(r)
+    }
+
+    /// Format the number with `digits` digits after the decimal
+    /// point.
+    pub fn to_fixed(&self, digits: u64, rnd: Rounding) -> String {
+        self.ftoa(10, digits, BF_FTOA_FORMAT_FRAC | rnd as bf_flags_t)
+    }
+
+    /// Format the number in exponential notation with `digits` digits
+    /// after the decimal point, e.g. "1.23e3".
+    pub fn to_exponential(&self, digits: u64, rnd: Rounding) -> String {
+        self.ftoa(
+            10,
+            digits.saturating_add(1),
+            BF_FTOA_FORMAT_FIXED | BF_FTOA_FORCE_EXP | rnd as bf_flags_t,
+        )
+    }
+
+    /// Format the number with `digits` significant digits. The
+    /// exponential notation is used if too many zeros are needed.
+    /// Panics if `digits` is 0.
+    pub fn to_precision(&self, digits: u64, rnd: Rounding) -> String {
+        assert!(digits > 0, "invalid number of digits");
+        self.ftoa(10, digits, BF_FTOA_FORMAT_FIXED | rnd as bf_flags_t)
+    }
+}
+
+impl Drop for BigFloat {
+    fn drop(&mut self) {
+        unsafe { bf_delete(&mut self.v) }
+    }
+}
+
+/* The operations create their result and their temporary numbers in
+the context of the current thread and only read their operands, so a
+number can be used by any thread: the context which allocated it is
+only used to free or resize it, with a thread safe allocator. */
+unsafe impl Send for BigFloat {}
+unsafe impl Sync for BigFloat {}
+
+impl Clone for BigFloat {
+    fn clone(&self) -> BigFloat {
+        let mut r = BigFloat::zero();
+        r.clone_from(self);
+        r
+    }
+
+    fn clone_from(&mut self, source: &BigFloat) {
+        if unsafe { bf_set(&mut self.v, &source.v) } != 0 {
+            panic!("BigFloat: out of memory");
+        }
+    }
+}
+
+impl Default for BigFloat {
+    fn default() -> BigFloat {
+        BigFloat::zero()
+    }
+}
+
+impl From<i64> for BigFloat {
+    fn from(v: i64) -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_si(&mut r.v, v) };
+        r
+    }
+}
+
+impl From<u64> for BigFloat {
+    fn from(v: u64) -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_ui(&mut r.v, v) };
+        r
+    }
+}
+
+impl From<i32> for BigFloat {
+    fn from(v: i32) -> BigFloat {
+        BigFloat::from(v as i64)
+    }
+}
+
+impl From<u32> for BigFloat {
+    fn from(v: u32) -> BigFloat {
+        BigFloat::from(v as u64)
+    }
+}
+
+impl From<f64> for BigFloat {
+    /// The conversion is exact.
+    fn from(d: f64) -> BigFloat {
+        let mut r = BigFloat::zero();
+        unsafe { bf_set_float64(&mut r.v, d) };
+        r
+    }
+}
+
+impl FromStr for BigFloat {
+    type Err = ParseBigFloatError;
+
+    /// Parse a decimal number rounded to the default environment of the
+    /// current thread.
+    fn from_str(s: &str) -> Result<BigFloat, ParseBigFloatError> {
+        let mut env = BigFloatEnv::thread_default();
+        env.clear_status();
+        let r = env.parse(s, 10)?;
+        DEFAULT_ENV.with(|e| e.borrow_mut().status |= env.status);
+        Ok(r)
+    }
+}
+
+impl PartialEq for BigFloat {
+    fn eq(&self, other: &BigFloat) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &BigFloat) -> Option<Ordering> {
+        match unsafe { bf_cmp(&self.v, &other.v) } {
+            2 => None,
+            res => Some(res.cmp(&0)),
+        }
+    }
+}
+
+impl fmt::Display for BigFloat {
+    /// The precision of the formatter is the number of digits after
+    /// the decimal point.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match f.precision() {
+            Some(digits) => self.to_fixed(digits as u64, Rounding::NearestEven),
+            None => self.to_string_radix(10),
+        };
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &s),
+        }
+    }
+}
+
+impl fmt::LowerExp for BigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match f.precision() {
+            Some(digits) => self.to_exponential(digits as u64, Rounding::NearestEven),
+            None => {
+                let prec = self.mantissa_bits().max(BigFloatEnv::thread_default().prec);
+                self.ftoa(10, prec, BF_FTOA_FORMAT_FREE_MIN | BF_FTOA_FORCE_EXP)
+            }
+        };
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &s),
+        }
+    }
+}
+
+impl fmt::Debug for BigFloat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for BigFloat {
+    type Output = BigFloat;
+
+    fn neg(mut self) -> BigFloat {
+        unsafe { bf_neg(&mut self.v) };
+        self
+    }
+}
+
+impl Neg for &BigFloat {
+    type Output = BigFloat;
+
+    fn neg(self) -> BigFloat {
+        -self.clone()
+    }
+}
+
+/* binary operators rounded with the default environment of the
+current thread */
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<&BigFloat> for &BigFloat {
+            type Output = BigFloat;
+
+            fn $op(self, rhs: &BigFloat) -> BigFloat {
+                with_thread_default(|env| env.$op(self, rhs))
+            }
+        }
+
+        impl $Op<BigFloat> for &BigFloat {
+            type Output = BigFloat;
+
+            fn $op(self, rhs: BigFloat) -> BigFloat {
+                self.$op(&rhs)
+            }
+        }
+
+        impl $Op<&BigFloat> for BigFloat {
+            type Output = BigFloat;
+
+            fn $op(self, rhs: &BigFloat) -> BigFloat {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl $Op<BigFloat> for BigFloat {
+            type Output = BigFloat;
+
+            fn $op(self, rhs: BigFloat) -> BigFloat {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl $OpAssign<&BigFloat> for BigFloat {
+            fn $op_assign(&mut self, rhs: &BigFloat) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+
+        impl $OpAssign<BigFloat> for BigFloat {
+            fn $op_assign(&mut self, rhs: BigFloat) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
@@ -0,0 +1,888 @@
+/*
+ * Arbitrary precision integers
+ *
+ * BigInt is a safe wrapper of a bf_t which always holds a finite
+ * integer. The operations are computed with an infinite precision as
+ * the JS BigInt operators. The bitwise operations and the right shift
+ * act on the infinite two's complement representation, the division
+ * and the remainder truncate toward zero as for the primitive
+ * integers.
+ */
+use std::cmp::Ordering;
+use std::ffi::CString;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+};
+use std::os::raw::c_char;
+use std::str::FromStr;
+
+use crate::bigfloat::{thread_context, OutOfMemory};
+use crate::libbf::{
+    bf_add, bf_add_si, bf_atof, bf_cmp, bf_delete, bf_divrem, bf_free, bf_ftoa, bf_get_exp_min,
+    bf_get_float64, bf_init, bf_is_zero, bf_logic_and, bf_logic_or, bf_logic_xor, bf_mul,
+    bf_mul_2exp, bf_neg, bf_op2_func_t, bf_pow, bf_rem, bf_rint, bf_set, bf_set_si, bf_set_ui,
+    bf_sqrtrem, bf_sub, bf_t, BF_ATOF_NO_HEX, BF_ATOF_NO_NAN_INF, BF_DIVREM_EUCLIDIAN,
+    BF_FTOA_FORMAT_FRAC, BF_PREC_INF, BF_RNDD, BF_RNDN, BF_RNDZ, BF_ST_MEM_ERROR, BF_ST_OVERFLOW,
+};
+
+/* panic if the allocation of the result failed */
+fn check(ret: i32) {
+    if ret & BF_ST_MEM_ERROR != 0 {
+        panic!("BigInt: out of memory");
+    }
+}
+
+fn try_check(ret: i32) -> Result<(), OutOfMemory> {
+    if ret & BF_ST_MEM_ERROR != 0 {
+        Err(OutOfMemory)
+    } else {
+        Ok(())
+    }
+}
+
+/// Error returned when parsing a BigInt fails.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid BigInt literal")
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+/// Arbitrary precision integer.
+/* same layout as bf_t */
+#[repr(transparent)]
+pub struct BigInt {
+    v: bf_t,
+}
+
+impl BigInt {
+    pub fn zero() -> BigInt {
+        let mut v = bf_t {
+            ctx: std::ptr::null_mut(),
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: std::ptr::null_mut(),
+        };
+        unsafe { bf_init(thread_context(), &mut v) };
+        BigInt { v }
+    }
+
+    pub fn one() -> BigInt {
+        BigInt::from(1)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const bf_t {
+        &self.v
+    }
+
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut bf_t {
+        &mut self.v
+    }
+
+    fn op2(&self, b: &BigInt, func: bf_op2_func_t) -> BigInt {
+        let mut r = BigInt::zero();
+        check(unsafe { func(&mut r.v, &self.v, &b.v, BF_PREC_INF, BF_RNDZ) });
+        r
+    }
+
+    fn try_op2(&self, b: &BigInt, func: bf_op2_func_t) -> Result<BigInt, OutOfMemory> {
+        let mut r = BigInt::zero();
+        try_check(unsafe { func(&mut r.v, &self.v, &b.v, BF_PREC_INF, BF_RNDZ) })?;
+        Ok(r)
+    }
+
+    /* 'rnd' is BF_RNDZ, BF_RNDD or BF_DIVREM_EUCLIDIAN */
+    fn divrem(&self, b: &BigInt, rnd: i32) -> Option<(BigInt, BigInt)> {
+        if b.is_zero() {
+            return None;
+        }
+        let mut q = BigInt::zero();
+        let mut r = BigInt::zero();
+        check(unsafe { bf_divrem(&mut q.v, &mut r.v, &self.v, &b.v, BF_PREC_INF, BF_RNDZ, rnd) });
+        Some((q, r))
+    }
+
+    fn rem_rnd(&self, b: &BigInt, rnd: i32) -> Option<BigInt> {
+        if b.is_zero() {
+            return None;
+        }
+        let mut r = BigInt::zero();
+        check(unsafe { bf_rem(&mut r.v, &self.v, &b.v, BF_PREC_INF, BF_RNDZ, rnd) });
+        Some(r)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        unsafe { bf_is_zero(&self.v) != 0 }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.v.sign != 0 && !self.is_zero()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.v.sign == 0 && !self.is_zero()
+    }
+
+    /// Return -1, 0 or 1 depending on the sign of the number.
+    pub fn signum(&self) -> i32 {
+        if self.is_zero() {
+            0
+        } else if self.v.sign != 0 {
+            -1
+        } else {
+            1
+        }
+    }
+
+    pub fn abs(&self) -> BigInt {
+        let mut r = self.clone();
+        r.v.sign = 0;
+        r
+    }
+
+    /// Return the number of bits of the absolute value, 0 for zero.
+    pub fn bits(&self) -> u64 {
+        if self.is_zero() {
+            0
+        } else {
+            self.v.expn as u64
+        }
+    }
+
+    /// Return the number of trailing zero bits, or None for zero.
+    pub fn trailing_zeros(&self) -> Option<u64> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(unsafe { bf_get_exp_min(&self.v) } as u64)
+        }
+    }
+
+    /// Return the number shifted left by `n` bits, or None if the
+    /// result exceeds the exponent range of libbf.
+    pub fn checked_shl(&self, n: u64) -> Option<BigInt> {
+        let mut r = self.clone();
+        let ret = unsafe {
+            bf_mul_2exp(
+                &mut r.v,
+                n.min(i64::MAX as u64) as i64,
+                BF_PREC_INF,
+                BF_RNDZ,
+            )
+        };
+        check(ret);
+        if ret & BF_ST_OVERFLOW != 0 {
+            return None;
+        }
+        Some(r)
+    }
+
+    /// Return the bit `n` of the two's complement representation.
+    pub fn bit(&self, n: u64) -> bool {
+        (self >> n).trailing_zeros() == Some(0)
+    }
+
+    pub fn pow(&self, exp: u64) -> BigInt {
+        self.op2(&BigInt::from(exp), bf_pow)
+    }
+
+    /// Return the integer square root rounded down. Panics if the
+    /// number is negative.
+    pub fn isqrt(&self) -> BigInt {
+        self.sqrt_rem().0
+    }
+
+    /// Return the integer square root `s` rounded down and the
+    /// remainder `self - s * s`. Panics if the number is negative.
+    pub fn sqrt_rem(&self) -> (BigInt, BigInt) {
+        assert!(!self.is_negative(), "square root of a negative number");
+        let mut s = BigInt::zero();
+        let mut r = BigInt::zero();
+        check(unsafe { bf_sqrtrem(&mut s.v, &mut r.v, &self.v) });
+        (s, r)
+    }
+
+    /// Return the quotient truncated toward zero and the remainder,
+    /// which has the sign of `self`. Panics if `b` is zero.
+    pub fn div_rem(&self, b: &BigInt) -> (BigInt, BigInt) {
+        self.divrem(b, BF_RNDZ as i32)
+            .expect("attempt to divide by zero")
+    }
+
+    /// Return the quotient rounded down and the remainder, which has
+    /// the sign of `b`. Panics if `b` is zero.
+    pub fn div_rem_floor(&self, b: &BigInt) -> (BigInt, BigInt) {
+        self.divrem(b, BF_RNDD as i32)
+            .expect("attempt to divide by zero")
+    }
+
+    /// Return the quotient and the remainder of the euclidian division.
+    /// The remainder is always positive. Panics if `b` is zero.
+    pub fn div_rem_euclid(&self, b: &BigInt) -> (BigInt, BigInt) {
+        self.divrem(b, BF_DIVREM_EUCLIDIAN)
+            .expect("attempt to divide by zero")
+    }
+
+    /// Return the positive remainder of the euclidian division. Panics
+    /// if `b` is zero.
+    pub fn rem_euclid(&self, b: &BigInt) -> BigInt {
+        self.rem_rnd(b, BF_DIVREM_EUCLIDIAN)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+
+    /// Return the quotient truncated toward zero, or None if `b` is
+    /// zero.
+    pub fn checked_div(&self, b: &BigInt) -> Option<BigInt> {
+        self.divrem(b, BF_RNDZ as i32).map(|(q, _)| q)
+    }
+
+    /// Return the remainder of the truncated division, or None if `b`
+    /// is zero.
+    pub fn checked_rem(&self, b: &BigInt) -> Option<BigInt> {
+        self.rem_rnd(b, BF_RNDZ as i32)
+    }
+
+    /// Same as `+` but return an error instead of panicking if the
+    /// memory limit is exceeded.
+    pub fn try_add(&self, b: &BigInt) -> Result<BigInt, OutOfMemory> {
+        self.try_op2(b, bf_add)
+    }
+
+    /// Same as `-` but return an error instead of panicking if the
+    /// memory limit is exceeded.
+    pub fn try_sub(&self, b: &BigInt) -> Result<BigInt, OutOfMemory> {
+        self.try_op2(b, bf_sub)
+    }
+
+    /// Same as `*` but return an error instead of panicking if the
+    /// memory limit is exceeded.
+    pub fn try_mul(&self, b: &BigInt) -> Result<BigInt, OutOfMemory> {
+        self.try_op2(b, bf_mul)
+    }
+
+    /// Same as `pow` but return an error instead of panicking if the
+    /// memory limit is exceeded.
+    pub fn try_pow(&self, exp: u64) -> Result<BigInt, OutOfMemory> {
+        let mut e = BigInt::zero();
+        try_check(unsafe { bf_set_ui(&mut e.v, exp) })?;
+        self.try_op2(&e, bf_pow)
+    }
+
+    /// Same as `div_rem` but return an error instead of panicking if
+    /// the memory limit is exceeded. Panics if `b` is zero.
+    pub fn try_div_rem(&self, b: &BigInt) -> Result<(BigInt, BigInt), OutOfMemory> {
+        assert!(!b.is_zero(), "attempt to divide by zero");
+        let mut q = BigInt::zero();
+        let mut r = BigInt::zero();
+        try_check(unsafe {
+            bf_divrem(
+                &mut q.v,
+                &mut r.v,
+                &self.v,
+                &b.v,
+                BF_PREC_INF,
+                BF_RNDZ,
+                BF_RNDZ as i32,
+            )
+        })?;
+        Ok((q, r))
+    }
+
+    /// Return a copy of the number, or an error if the memory limit is
+    /// exceeded.
+    pub fn try_clone(&self) -> Result<BigInt, OutOfMemory> {
+        let mut r = BigInt::zero();
+        try_check(unsafe { bf_set(&mut r.v, &self.v) })?;
+        Ok(r)
+    }
+
+    /// Return the greatest common divisor of the absolute values. The
+    /// result is zero if both numbers are zero.
+    pub fn gcd(&self, b: &BigInt) -> BigInt {
+        let mut a = self.abs();
+        let mut b = b.abs();
+        if a < b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        /* Lehmer's algorithm (Knuth, TAOCP vol. 2, 4.5.2): the quotients
+        of the first Euclid steps are computed on the leading 63 bits
+        of a and b, then the steps are applied at once */
+        while !b.is_zero() {
+            let n = a.bits();
+            if n <= 63 {
+                let mut x = a.magnitude_u64().unwrap();
+                let mut y = b.magnitude_u64().unwrap();
+                while y != 0 {
+                    let r = x % y;
+                    x = y;
+                    y = r;
+                }
+                return BigInt::from(x);
+            }
+            let mut x = (&a >> (n - 63)).magnitude_u64().unwrap() as i128;
+            let mut y = (&b >> (n - 63)).magnitude_u64().unwrap() as i128;
+            /* the steps transform (a, b) into (p * a + q * b, r * a + s * b) */
+            let (mut p, mut q, mut r, mut s) = (1i128, 0i128, 0i128, 1i128);
+            while y + r != 0 && y + s != 0 {
+                let k = (x + p) / (y + r);
+                if k != (x + q) / (y + s) {
+                    break;
+                }
+                let t = p - k * r;
+                p = r;
+                r = t;
+                let t = q - k * s;
+                q = s;
+                s = t;
+                let t = x - k * y;
+                x = y;
+                y = t;
+            }
+            if q == 0 {
+                let t = &a % &b;
+                a = std::mem::replace(&mut b, t);
+            } else {
+                let t = &a * BigInt::from(p) + &b * BigInt::from(q);
+                b = &a * BigInt::from(r) + &b * BigInt::from(s);
+                a = t;
+            }
+        }
+        a
+    }
+
+    /// Return the least common multiple of the absolute values.
+    pub fn lcm(&self, b: &BigInt) -> BigInt {
+        if self.is_zero() || b.is_zero() {
+            return BigInt::zero();
+        }
+        (self / &self.gcd(b) * b).abs()
+    }
+
+    /// Return the inverse of the number modulo `m` in [0, |m|), or None
+    /// if `m` is zero or the number is not invertible.
+    pub fn mod_inverse(&self, m: &BigInt) -> Option<BigInt> {
+        if m.is_zero() {
+            return None;
+        }
+        let m = m.abs();
+        /* extended Euclid: a * x0 = r0 (mod m) */
+        let (mut r0, mut r1) = (self.rem_euclid(&m), m.clone());
+        let (mut x0, mut x1) = (BigInt::one(), BigInt::zero());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = std::mem::replace(&mut r1, r);
+            let x = &x0 - &q * &x1;
+            x0 = std::mem::replace(&mut x1, x);
+        }
+        if r0 != BigInt::one() {
+            return None;
+        }
+        Some(x0.rem_euclid(&m))
+    }
+
+    /// Return the number to the power `exp` modulo `m` in [0, |m|). A
+    /// negative exponent uses the modular inverse. Return None if `m`
+    /// is zero or if the exponent is negative and the number is not
+    /// invertible.
+    pub fn modpow(&self, exp: &BigInt, m: &BigInt) -> Option<BigInt> {
+        if m.is_zero() {
+            return None;
+        }
+        let m = m.abs();
+        let base = if exp.is_negative() {
+            self.mod_inverse(&m)?
+        } else {
+            self.rem_euclid(&m)
+        };
+        let mut r = BigInt::one().rem_euclid(&m);
+        /* left to right on the limbs of the exponent. The mantissa
+        ends with its last non zero limb, the following bits are zero. */
+        let mut n = exp.bits();
+        for &l in exp.limbs().iter().rev() {
+            for j in (0..64).rev() {
+                if n == 0 {
+                    break;
+                }
+                n -= 1;
+                r = (&r * &r).rem_euclid(&m);
+                if (l >> j) & 1 != 0 {
+                    r = (&r * &base).rem_euclid(&m);
+                }
+            }
+        }
+        for _ in 0..n {
+            r = (&r * &r).rem_euclid(&m);
+        }
+        Some(r)
+    }
+
+    /* limbs of the mantissa of the absolute value, the most
+    significant bit of the last one is the bit 'bits() - 1' */
+    fn limbs(&self) -> &[u64] {
+        if self.v.len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.v.tab, self.v.len as usize) }
+        }
+    }
+
+    /* return the absolute value if it fits in 64 bits */
+    fn magnitude_u64(&self) -> Option<u64> {
+        if self.is_zero() {
+            Some(0)
+        } else if self.v.expn > 64 {
+            None
+        } else {
+            /* the bits are in the most significant limb */
+            let top = unsafe { *self.v.tab.add(self.v.len as usize - 1) };
+            Some(top >> (64 - self.v.expn))
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        let v = self.magnitude_u64()?;
+        if self.is_negative() {
+            if v <= 1 << 63 {
+                Some((v as i64).wrapping_neg())
+            } else {
+                None
+            }
+        } else if v <= i64::MAX as u64 {
+            Some(v as i64)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        if self.is_negative() {
+            None
+        } else {
+            self.magnitude_u64()
+        }
+    }
+
+    /// Convert to the nearest f64 (ties to even).
+    pub fn to_f64(&self) -> f64 {
+        let mut d = 0.0;
+        unsafe { bf_get_float64(thread_context(), &self.v, &mut d, BF_RNDN) };
+        d
+    }
+
+    /// Parse an integer written in base `radix` (between 2 and 36) with
+    /// an optional sign. An error is also returned if the memory limit
+    /// is exceeded.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<BigInt, ParseBigIntError> {
+        assert!((2..=36).contains(&radix), "invalid radix");
+        let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+        if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+            return Err(ParseBigIntError);
+        }
+        let cs = CString::new(s).map_err(|_| ParseBigIntError)?;
+        let mut r = BigInt::zero();
+        let mut next: *const c_char = std::ptr::null();
+        let ret = unsafe {
+            bf_atof(
+                &mut r.v,
+                cs.as_ptr(),
+                &mut next,
+                radix as i32,
+                BF_PREC_INF,
+                BF_RNDZ | BF_ATOF_NO_HEX | BF_ATOF_NO_NAN_INF,
+            )
+        };
+        try_check(ret).map_err(|_| ParseBigIntError)?;
+        Ok(r)
+    }
+
+    /// Return the digits of the number in base `radix` (between 2 and
+    /// 36) with a leading '-' for the negative numbers.
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        self.try_to_string_radix(radix)
+            .expect("BigInt: out of memory")
+    }
+
+    /// Same as `to_string_radix` but return an error instead of
+    /// panicking if the memory limit is exceeded.
+    pub fn try_to_string_radix(&self, radix: u32) -> Result<String, OutOfMemory> {
+        assert!((2..=36).contains(&radix), "invalid radix");
+        if self.is_zero() {
+            return Ok("0".to_string());
+        }
+        let ctx = thread_context();
+        unsafe {
+            let mut len = 0;
+            let buf = bf_ftoa(
+                ctx,
+                &mut len,
+                &self.v,
+                radix as i32,
+                0,
+                BF_RNDZ | BF_FTOA_FORMAT_FRAC,
+            );
+            if buf.is_null() {
+                return Err(OutOfMemory);
+            }
+            let bytes = std::slice::from_raw_parts(buf as *const u8, len as usize);
+            let s = String::from_utf8_lossy(bytes).into_owned();
+            bf_free(ctx, buf as *mut std::ffi::c_void);
+            Ok(s)
+        }
+    }
+
+    /* write the digits in base 'radix' with the sign handled by the
+    formatter */
+    fn fmt_radix(&self, f: &mut fmt::Formatter<'_>, radix: u32, prefix: &str) -> fmt::Result {
+        let s = self.to_string_radix(radix);
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, prefix, digits),
+            None => f.pad_integral(true, prefix, &s),
+        }
+    }
+}
+
+impl Drop for BigInt {
+    fn drop(&mut self) {
+        unsafe { bf_delete(&mut self.v) }
+    }
+}
+
+/* same threading model as BigFloat */
+unsafe impl Send for BigInt {}
+unsafe impl Sync for BigInt {}
+
+impl Clone for BigInt {
+    fn clone(&self) -> BigInt {
+        let mut r = BigInt::zero();
+        r.clone_from(self);
+        r
+    }
+
+    fn clone_from(&mut self, source: &BigInt) {
+        check(unsafe { bf_set(&mut self.v, &source.v) });
+    }
+}
+
+impl Default for BigInt {
+    fn default() -> BigInt {
+        BigInt::zero()
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(v: i64) -> BigInt {
+        let mut r = BigInt::zero();
+        check(unsafe { bf_set_si(&mut r.v, v) });
+        r
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(v: u64) -> BigInt {
+        let mut r = BigInt::zero();
+        check(unsafe { bf_set_ui(&mut r.v, v) });
+        r
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(v: i32) -> BigInt {
+        BigInt::from(v as i64)
+    }
+}
+
+impl From<u32> for BigInt {
+    fn from(v: u32) -> BigInt {
+        BigInt::from(v as u64)
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(v: u128) -> BigInt {
+        (BigInt::from((v >> 64) as u64) << 64) + BigInt::from(v as u64)
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(v: i128) -> BigInt {
+        let r = BigInt::from(v.unsigned_abs());
+        if v < 0 {
+            -r
+        } else {
+            r
+        }
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<BigInt, ParseBigIntError> {
+        BigInt::from_str_radix(s, 10)
+    }
+}
+
+impl PartialEq for BigInt {
+    fn eq(&self, other: &BigInt) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigInt {}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        unsafe { bf_cmp(&self.v, &other.v) }.cmp(&0)
+    }
+}
+
+impl Hash for BigInt {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        /* the representation is normalized except the sign of zero */
+        self.signum().hash(state);
+        if !self.is_zero() {
+            self.v.expn.hash(state);
+            unsafe { std::slice::from_raw_parts(self.v.tab, self.v.len as usize) }.hash(state);
+        }
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 10, "")
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Binary for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 2, "0b")
+    }
+}
+
+impl fmt::Octal for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 8, "0o")
+    }
+}
+
+impl fmt::LowerHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_radix(f, 16, "0x")
+    }
+}
+
+impl fmt::UpperHex for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.to_string_radix(16).to_ascii_uppercase();
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "0x", digits),
+            None => f.pad_integral(true, "0x", &s),
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(mut self) -> BigInt {
+        unsafe { bf_neg(&mut self.v) };
+        self
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+impl Not for BigInt {
+    type Output = BigInt;
+
+    /// Return `-self - 1`.
+    fn not(self) -> BigInt {
+        !&self
+    }
+}
+
+impl Not for &BigInt {
+    type Output = BigInt;
+
+    fn not(self) -> BigInt {
+        let mut r = BigInt::zero();
+        check(unsafe { bf_add_si(&mut r.v, &self.v, 1, BF_PREC_INF, BF_RNDZ) });
+        -r
+    }
+}
+
+impl Shl<u64> for &BigInt {
+    type Output = BigInt;
+
+    /// Panics if the result exceeds the exponent range of libbf (about
+    /// 2^(2^60)), see `checked_shl`.
+    fn shl(self, n: u64) -> BigInt {
+        self.checked_shl(n)
+            .expect("attempt to shift left with overflow")
+    }
+}
+
+impl Shr<u64> for &BigInt {
+    type Output = BigInt;
+
+    /// Arithmetic shift: the result is rounded toward negative infinity.
+    fn shr(self, n: u64) -> BigInt {
+        let mut r = self.clone();
+        let n = n.min(i64::MAX as u64) as i64;
+        unsafe {
+            check(bf_mul_2exp(&mut r.v, -n, BF_PREC_INF, BF_RNDZ));
+            check(bf_rint(&mut r.v, BF_RNDD as i32));
+        }
+        r
+    }
+}
+
+impl Shl<u64> for BigInt {
+    type Output = BigInt;
+
+    fn shl(self, n: u64) -> BigInt {
+        &self << n
+    }
+}
+
+impl Shr<u64> for BigInt {
+    type Output = BigInt;
+
+    fn shr(self, n: u64) -> BigInt {
+        &self >> n
+    }
+}
+
+impl ShlAssign<u64> for BigInt {
+    fn shl_assign(&mut self, n: u64) {
+        *self = &*self << n;
+    }
+}
+
+impl ShrAssign<u64> for BigInt {
+    fn shr_assign(&mut self, n: u64) {
+        *self = &*self >> n;
+    }
+}
+
+fn add(a: &BigInt, b: &BigInt) -> BigInt {
+    a.op2(b, bf_add)
+}
+
+fn sub(a: &BigInt, b: &BigInt) -> BigInt {
+    a.op2(b, bf_sub)
+}
+
+fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    a.op2(b, bf_mul)
+}
+
+fn div(a: &BigInt, b: &BigInt) -> BigInt {
+    a.checked_div(b).expect("attempt to divide by zero")
+}
+
+fn rem(a: &BigInt, b: &BigInt) -> BigInt {
+    a.checked_rem(b)
+        .expect("attempt to calculate the remainder with a divisor of zero")
+}
+
+fn bitand(a: &BigInt, b: &BigInt) -> BigInt {
+    let mut r = BigInt::zero();
+    check(unsafe { bf_logic_and(&mut r.v, &a.v, &b.v) });
+    r
+}
+
+fn bitor(a: &BigInt, b: &BigInt) -> BigInt {
+    let mut r = BigInt::zero();
+    check(unsafe { bf_logic_or(&mut r.v, &a.v, &b.v) });
+    r
+}
+
+fn bitxor(a: &BigInt, b: &BigInt) -> BigInt {
+    let mut r = BigInt::zero();
+    check(unsafe { bf_logic_xor(&mut r.v, &a.v, &b.v) });
+    r
+}
+
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<&BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: &BigInt) -> BigInt {
+                $op(self, rhs)
+            }
+        }
+
+        impl $Op<BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: BigInt) -> BigInt {
+                $op(self, &rhs)
+            }
+        }
+
+        impl $Op<&BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: &BigInt) -> BigInt {
+                $op(&self, rhs)
+            }
+        }
+
+        impl $Op<BigInt> for BigInt {
+            type Output = BigInt;
+
+            fn $op(self, rhs: BigInt) -> BigInt {
+                $op(&self, &rhs)
+            }
+        }
+
+        impl $OpAssign<&BigInt> for BigInt {
+            fn $op_assign(&mut self, rhs: &BigInt) {
+                *self = $op(self, rhs);
+            }
+        }
+
+        impl $OpAssign<BigInt> for BigInt {
+            fn $op_assign(&mut self, rhs: BigInt) {
+                *self = $op(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
+impl_binary_op!(BitAnd, bitand, BitAndAssign, bitand_assign);
+impl_binary_op!(BitOr, bitor, BitOrAssign, bitor_assign);
+impl_binary_op!(BitXor, bitxor, BitXorAssign, bitxor_assign);
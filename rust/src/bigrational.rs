@@ -0,0 +1,526 @@
+/*
+ * Exact rational numbers
+ *
+ * BigRational is a fraction of two BigInt numbers, as the Fraction
+ * objects of qjscalc. It is always normalized: the denominator is
+ * positive and prime with the numerator, so the zero is 0/1. The
+ * operations are exact. The conversions to BigFloat are rounded with
+ * a BigFloatEnv, which raises BF_ST_INEXACT if the result is not
+ * exact, and the finite BigFloat numbers are converted exactly.
+ */
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+use crate::bigfloat::{BigFloat, BigFloatEnv, Rounding};
+use crate::bigint::{BigInt, ParseBigIntError};
+use crate::libbf::{bf_get_exp_min, bf_mul_2exp, bf_set, BF_PREC_INF, BF_RNDZ, BF_ST_MEM_ERROR};
+
+/* panic if the allocation of the result failed */
+fn check(ret: i32) {
+    if ret & BF_ST_MEM_ERROR != 0 {
+        panic!("BigRational: out of memory");
+    }
+}
+
+/// Error returned when parsing a BigRational fails.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseBigRationalError;
+
+impl fmt::Display for ParseBigRationalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid BigRational literal")
+    }
+}
+
+impl std::error::Error for ParseBigRationalError {}
+
+impl From<ParseBigIntError> for ParseBigRationalError {
+    fn from(_: ParseBigIntError) -> ParseBigRationalError {
+        ParseBigRationalError
+    }
+}
+
+/// Exact rational number.
+#[derive(Clone)]
+pub struct BigRational {
+    num: BigInt,
+    den: BigInt,
+}
+
+impl BigRational {
+    pub fn zero() -> BigRational {
+        BigRational::from(BigInt::zero())
+    }
+
+    pub fn one() -> BigRational {
+        BigRational::from(BigInt::one())
+    }
+
+    /// Return the normalized fraction `num / den`. Panics if `den` is
+    /// zero.
+    pub fn new(num: BigInt, den: BigInt) -> BigRational {
+        assert!(!den.is_zero(), "attempt to divide by zero");
+        let g = num.gcd(&den);
+        let (mut num, mut den) = if g == BigInt::one() {
+            (num, den)
+        } else {
+            (num / &g, den / &g)
+        };
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+        BigRational { num, den }
+    }
+
+    /* 'num' and 'den' are already normalized */
+    fn new_raw(num: BigInt, den: BigInt) -> BigRational {
+        BigRational { num, den }
+    }
+
+    pub fn numer(&self) -> &BigInt {
+        &self.num
+    }
+
+    /// Return the denominator, which is always positive.
+    pub fn denom(&self) -> &BigInt {
+        &self.den
+    }
+
+    /// Return the numerator and the denominator.
+    pub fn into_parts(self) -> (BigInt, BigInt) {
+        (self.num, self.den)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.num.is_negative()
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.num.is_positive()
+    }
+
+    /// Return -1, 0 or 1 depending on the sign of the number.
+    pub fn signum(&self) -> i32 {
+        self.num.signum()
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == BigInt::one()
+    }
+
+    pub fn abs(&self) -> BigRational {
+        BigRational::new_raw(self.num.abs(), self.den.clone())
+    }
+
+    /// Return `1 / self`, or None if the number is zero.
+    pub fn checked_recip(&self) -> Option<BigRational> {
+        if self.is_zero() {
+            None
+        } else if self.num.is_negative() {
+            Some(BigRational::new_raw(-&self.den, -&self.num))
+        } else {
+            Some(BigRational::new_raw(self.den.clone(), self.num.clone()))
+        }
+    }
+
+    /// Return `1 / self`. Panics if the number is zero.
+    pub fn recip(&self) -> BigRational {
+        self.checked_recip().expect("attempt to divide by zero")
+    }
+
+    /// Return the number to the power `exp`. Panics if the number is
+    /// zero and `exp` is negative.
+    pub fn pow(&self, exp: i64) -> BigRational {
+        let a = if exp < 0 { self.recip() } else { self.clone() };
+        let exp = exp.unsigned_abs();
+        BigRational::new_raw(a.num.pow(exp), a.den.pow(exp))
+    }
+
+    /// Return the largest integer less than or equal to the number.
+    pub fn floor(&self) -> BigInt {
+        self.num.div_rem_floor(&self.den).0
+    }
+
+    /// Return the smallest integer greater than or equal to the number.
+    pub fn ceil(&self) -> BigInt {
+        -(-&self.num).div_rem_floor(&self.den).0
+    }
+
+    /// Return the integer part, rounded toward zero.
+    pub fn trunc(&self) -> BigInt {
+        &self.num / &self.den
+    }
+
+    /// Round to the nearest integer, the halfway cases away from zero.
+    pub fn round(&self) -> BigInt {
+        /* floor((2 * |num| + den) / (2 * den)) */
+        let two = BigInt::from(2);
+        let r = (&self.num.abs() * &two + &self.den) / (&self.den * &two);
+        if self.is_negative() {
+            -r
+        } else {
+            r
+        }
+    }
+
+    /// Return `self - self.trunc()`.
+    pub fn fract(&self) -> BigRational {
+        BigRational::new_raw(&self.num % &self.den, self.den.clone())
+    }
+
+    /// Return the finite BigFloat `a` as a fraction, or None for NaN and
+    /// the infinities. The conversion is exact.
+    pub fn from_bigfloat(a: &BigFloat) -> Option<BigRational> {
+        if !a.is_finite() {
+            return None;
+        }
+        if a.is_zero() {
+            return Some(BigRational::zero());
+        }
+        let mut num = BigInt::zero();
+        let e = unsafe {
+            check(bf_set(num.as_mut_ptr(), a.as_ptr()));
+            bf_get_exp_min(a.as_ptr())
+        };
+        if e >= 0 {
+            return Some(BigRational::from(num));
+        }
+        /* the numerator is odd */
+        check(unsafe { bf_mul_2exp(num.as_mut_ptr(), -e, BF_PREC_INF, BF_RNDZ) });
+        Some(BigRational::new_raw(num, BigInt::one() << (-e) as u64))
+    }
+
+    /// Return `d` as a fraction, or None for NaN and the infinities.
+    pub fn from_f64(d: f64) -> Option<BigRational> {
+        BigRational::from_bigfloat(&BigFloat::from(d))
+    }
+
+    /// Round the number to the precision, exponent range and rounding
+    /// mode of `env`.
+    pub fn to_bigfloat(&self, env: &mut BigFloatEnv) -> BigFloat {
+        let mut a = BigFloat::zero();
+        let mut b = BigFloat::zero();
+        unsafe {
+            check(bf_set(a.as_mut_ptr(), self.num.as_ptr()));
+            check(bf_set(b.as_mut_ptr(), self.den.as_ptr()));
+        }
+        env.div(&a, &b)
+    }
+
+    /// Convert to the nearest f64 (ties to even).
+    pub fn to_f64(&self) -> f64 {
+        let mut env = BigFloatEnv::new(53);
+        env.set_exp_bits(11);
+        env.set_subnormal(true);
+        env.set_rounding(Rounding::NearestEven);
+        self.to_bigfloat(&mut env).to_f64()
+    }
+
+    /// Return the terms [a0; a1, a2, ...] of the regular continued
+    /// fraction of the number. The terms after a0 are positive and the
+    /// last one is larger than 1, except for the integers.
+    pub fn continued_fraction(&self) -> Vec<BigInt> {
+        let mut terms = Vec::new();
+        let (mut p, mut q) = (self.num.clone(), self.den.clone());
+        while !q.is_zero() {
+            let (a, r) = p.div_rem_floor(&q);
+            terms.push(a);
+            p = std::mem::replace(&mut q, r);
+        }
+        terms
+    }
+
+    /// Return the value of the continued fraction [a0; a1, a2, ...].
+    /// Panics if `terms` is empty or if a denominator is zero.
+    pub fn from_continued_fraction(terms: &[BigInt]) -> BigRational {
+        let (last, rest) = terms.split_last().expect("empty continued fraction");
+        let mut r = BigRational::from(last.clone());
+        for a in rest.iter().rev() {
+            r = r.recip() + BigRational::from(a.clone());
+        }
+        r
+    }
+
+    /// Return the closest fraction to the number whose denominator is at
+    /// most `max_den`. Panics if `max_den` is not positive.
+    pub fn approximate(&self, max_den: &BigInt) -> BigRational {
+        assert!(max_den.is_positive(), "invalid maximum denominator");
+        if self.den <= *max_den {
+            return self.clone();
+        }
+        /* convergents p0/q0 and p1/q1 */
+        let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+        let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+        let (mut n, mut d) = (self.num.clone(), self.den.clone());
+        loop {
+            let (a, r) = n.div_rem_floor(&d);
+            let q2 = &q0 + &a * &q1;
+            if q2 > *max_den {
+                break;
+            }
+            let p2 = &p0 + &a * &p1;
+            p0 = std::mem::replace(&mut p1, p2);
+            q0 = std::mem::replace(&mut q1, q2);
+            n = std::mem::replace(&mut d, r);
+        }
+        /* the best approximation is the last convergent or the largest
+        semiconvergent below max_den */
+        let k = (max_den - &q0) / &q1;
+        let bound1 = BigRational::new(&p0 + &k * &p1, &q0 + &k * &q1);
+        let bound2 = BigRational::new_raw(p1, q1);
+        if (&bound2 - self).abs() <= (&bound1 - self).abs() {
+            bound2
+        } else {
+            bound1
+        }
+    }
+
+    /// Parse a fraction "n/d" or an integer "n" in base `radix`
+    /// (between 2 and 36). Only the numerator can have a sign.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<BigRational, ParseBigRationalError> {
+        match s.split_once('/') {
+            Some((n, d)) => {
+                if d.starts_with(['+', '-']) {
+                    return Err(ParseBigRationalError);
+                }
+                let den = BigInt::from_str_radix(d, radix)?;
+                if den.is_zero() {
+                    return Err(ParseBigRationalError);
+                }
+                Ok(BigRational::new(BigInt::from_str_radix(n, radix)?, den))
+            }
+            None => Ok(BigRational::from(BigInt::from_str_radix(s, radix)?)),
+        }
+    }
+
+    /// Return "n/d" in base `radix` (between 2 and 36), or "n" for the
+    /// integers.
+    pub fn to_string_radix(&self, radix: u32) -> String {
+        let mut s = self.num.to_string_radix(radix);
+        if !self.is_integer() {
+            s.push('/');
+            s.push_str(&self.den.to_string_radix(radix));
+        }
+        s
+    }
+}
+
+impl Default for BigRational {
+    fn default() -> BigRational {
+        BigRational::zero()
+    }
+}
+
+impl From<BigInt> for BigRational {
+    fn from(v: BigInt) -> BigRational {
+        BigRational::new_raw(v, BigInt::one())
+    }
+}
+
+impl From<&BigInt> for BigRational {
+    fn from(v: &BigInt) -> BigRational {
+        BigRational::from(v.clone())
+    }
+}
+
+impl From<i64> for BigRational {
+    fn from(v: i64) -> BigRational {
+        BigRational::from(BigInt::from(v))
+    }
+}
+
+impl From<u64> for BigRational {
+    fn from(v: u64) -> BigRational {
+        BigRational::from(BigInt::from(v))
+    }
+}
+
+impl From<i32> for BigRational {
+    fn from(v: i32) -> BigRational {
+        BigRational::from(BigInt::from(v))
+    }
+}
+
+impl From<u32> for BigRational {
+    fn from(v: u32) -> BigRational {
+        BigRational::from(BigInt::from(v))
+    }
+}
+
+impl FromStr for BigRational {
+    type Err = ParseBigRationalError;
+
+    fn from_str(s: &str) -> Result<BigRational, ParseBigRationalError> {
+        BigRational::from_str_radix(s, 10)
+    }
+}
+
+impl PartialEq for BigRational {
+    fn eq(&self, other: &BigRational) -> bool {
+        /* the representation is normalized */
+        self.num == other.num && self.den == other.den
+    }
+}
+
+impl Eq for BigRational {}
+
+impl PartialOrd for BigRational {
+    fn partial_cmp(&self, other: &BigRational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigRational {
+    fn cmp(&self, other: &BigRational) -> Ordering {
+        if self.den == other.den {
+            self.num.cmp(&other.num)
+        } else {
+            (&self.num * &other.den).cmp(&(&other.num * &self.den))
+        }
+    }
+}
+
+impl Hash for BigRational {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.num.hash(state);
+        self.den.hash(state);
+    }
+}
+
+impl fmt::Display for BigRational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = self.to_string_radix(10);
+        match s.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &s),
+        }
+    }
+}
+
+impl fmt::Debug for BigRational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for BigRational {
+    type Output = BigRational;
+
+    fn neg(self) -> BigRational {
+        BigRational::new_raw(-self.num, self.den)
+    }
+}
+
+impl Neg for &BigRational {
+    type Output = BigRational;
+
+    fn neg(self) -> BigRational {
+        -self.clone()
+    }
+}
+
+/* a/b + c/d with the reduction of Knuth (TAOCP 4.5.1) */
+fn add(a: &BigRational, b: &BigRational) -> BigRational {
+    let g = a.den.gcd(&b.den);
+    if g == BigInt::one() {
+        return BigRational::new_raw(&a.num * &b.den + &b.num * &a.den, &a.den * &b.den);
+    }
+    let t = &a.num * (&b.den / &g) + &b.num * (&a.den / &g);
+    let g2 = t.gcd(&g);
+    if t.is_zero() {
+        BigRational::zero()
+    } else {
+        BigRational::new_raw(&t / &g2, (&a.den / &g) * (&b.den / &g2))
+    }
+}
+
+fn sub(a: &BigRational, b: &BigRational) -> BigRational {
+    add(a, &-b)
+}
+
+fn mul(a: &BigRational, b: &BigRational) -> BigRational {
+    if a.is_zero() || b.is_zero() {
+        return BigRational::zero();
+    }
+    let g1 = a.num.gcd(&b.den);
+    let g2 = b.num.gcd(&a.den);
+    BigRational::new_raw(
+        (&a.num / &g1) * (&b.num / &g2),
+        (&a.den / &g2) * (&b.den / &g1),
+    )
+}
+
+fn div(a: &BigRational, b: &BigRational) -> BigRational {
+    mul(a, &b.recip())
+}
+
+/* remainder of the division truncated toward zero, with the sign of
+'a' */
+fn rem(a: &BigRational, b: &BigRational) -> BigRational {
+    let q = div(a, b).trunc();
+    sub(a, &mul(b, &BigRational::from(q)))
+}
+
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $Op<&BigRational> for &BigRational {
+            type Output = BigRational;
+
+            fn $op(self, rhs: &BigRational) -> BigRational {
+                $op(self, rhs)
+            }
+        }
+
+        impl $Op<BigRational> for &BigRational {
+            type Output = BigRational;
+
+            fn $op(self, rhs: BigRational) -> BigRational {
+                $op(self, &rhs)
+            }
+        }
+
+        impl $Op<&BigRational> for BigRational {
+            type Output = BigRational;
+
+            fn $op(self, rhs: &BigRational) -> BigRational {
+                $op(&self, rhs)
+            }
+        }
+
+        impl $Op<BigRational> for BigRational {
+            type Output = BigRational;
+
+            fn $op(self, rhs: BigRational) -> BigRational {
+                $op(&self, &rhs)
+            }
+        }
+
+        impl $OpAssign<&BigRational> for BigRational {
+            fn $op_assign(&mut self, rhs: &BigRational) {
+                *self = $op(self, rhs);
+            }
+        }
+
+        impl $OpAssign<BigRational> for BigRational {
+            fn $op_assign(&mut self, rhs: BigRational) {
+                *self = $op(self, &rhs);
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign);
+impl_binary_op!(Div, div, DivAssign, div_assign);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign);
@@ -175,9 +175,12 @@ pub unsafe fn cstr_copy(mut dest: *mut c_char, src: *const c_char) -> *const c_c
 pub unsafe fn cstr_find_char(mut s: *const c_char, c: c_char) -> *const c_char {
     loop {
         let cur = *s;
-        if cur == c || cur == b'\0' as i8 {
+        if cur == c {
             return s;
         }
+        if cur == b'\0' as i8 {
+            return std::ptr::null();
+        }
         s = s.add(1);
     }
 }
@@ -237,6 +240,45 @@ pub unsafe fn global_realloc(ptr: *mut u8, new_size: usize) -> *mut u8 {
     std::alloc::realloc(ptr, layout, new_size)
 }
 
+/* realloc function backed by the Rust global allocator, for the C
+style allocation callbacks (DynBuf, CharRange, bf_context_t). The
+size of the block is stored before it so that it can be reallocated
+and freed with its layout. */
+pub(crate) unsafe fn sized_realloc(
+    _opaque: *mut std::ffi::c_void,
+    ptr: *mut std::ffi::c_void,
+    size: usize,
+) -> *mut std::ffi::c_void {
+    use std::alloc::{alloc, dealloc, realloc, Layout};
+    const HEADER_SIZE: usize = std::mem::size_of::<usize>();
+    let layout = |size: usize| Layout::from_size_align_unchecked(size + HEADER_SIZE, HEADER_SIZE);
+    let mut base = ptr as *mut u8;
+    if base.is_null() {
+        if size == 0 {
+            return std::ptr::null_mut();
+        }
+        base = alloc(layout(size));
+    } else {
+        base = base.sub(HEADER_SIZE);
+        let old_size = *(base as *const usize);
+        if size == 0 {
+            dealloc(base, layout(old_size));
+            return std::ptr::null_mut();
+        }
+        base = realloc(base, layout(old_size), size + HEADER_SIZE);
+    }
+    if base.is_null() {
+        return std::ptr::null_mut();
+    }
+    *(base as *mut usize) = size;
+    base.add(HEADER_SIZE) as *mut std::ffi::c_void
+}
+
+/* size of a non NULL block allocated by sized_realloc() */
+pub(crate) unsafe fn sized_alloc_size(ptr: *mut std::ffi::c_void) -> usize {
+    *(ptr as *const usize).sub(1)
+}
+
 #[inline]
 pub unsafe fn ptr_compare(mut a: *const u8, mut b: *const u8, len: usize) -> i32 {
     // FIXME: this is super slow and a primitive replacement for `libc::memcmp`!
@@ -0,0 +1,667 @@
+/*
+ * Conversions between the JS numbers and strings
+ *
+ * The conversions to strings implement Number.prototype.toString(),
+ * toFixed(), toExponential() and toPrecision() as js_dtoa() in
+ * quickjs.c. The digits are generated by the shortest round trip and
+ * exact algorithms of the core library instead of printf(), so the
+ * result does not depend on the C library. The core library rounds
+ * the ties to even while JS rounds them away from zero: the ties are
+ * detected exactly from the binary representation of the number, so
+ * no rounding mode change is needed. The results are stored inline
+ * and no memory is allocated.
+ *
+ * The conversions from strings implement the JS numeric literal
+ * grammar as js_atof(). The result is correctly rounded in all the
+ * radices and no memory is allocated either: the long decimal numbers
+ * are shortened to the digits which can change the rounding.
+ */
+use std::fmt;
+use std::fmt::Write as _;
+use std::ops::Deref;
+
+use crate::libregexp::lre_is_space;
+
+/// Maximum length of the result of the conversions to strings.
+pub const DTOA_BUF_SIZE: usize = 128;
+
+/// Result of a conversion to a string.
+#[derive(Clone, Copy)]
+pub struct DtoaBuf {
+    buf: [u8; DTOA_BUF_SIZE],
+    len: usize,
+}
+
+impl DtoaBuf {
+    fn new() -> DtoaBuf {
+        DtoaBuf {
+            buf: [0; DTOA_BUF_SIZE],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, c: u8) {
+        self.buf[self.len] = c;
+        self.len += 1;
+    }
+
+    fn push_bytes(&mut self, s: &[u8]) {
+        self.buf[self.len..self.len + s.len()].copy_from_slice(s);
+        self.len += s.len();
+    }
+
+    pub fn as_str(&self) -> &str {
+        /* only ASCII characters are written */
+        unsafe { std::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+impl fmt::Write for DtoaBuf {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.len + s.len() > DTOA_BUF_SIZE {
+            return Err(fmt::Error);
+        }
+        self.push_bytes(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl Deref for DtoaBuf {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for DtoaBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Debug for DtoaBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+const MAX_SAFE_INTEGER: f64 = 9007199254740991.0;
+
+/* return true if d * 10^k is an integer, i.e. if the finite number
+'d' is exactly written with k digits after the decimal point (k may
+be negative) */
+fn is_exact(d: f64, k: i32) -> bool {
+    let bits = d.to_bits();
+    let mut e = ((bits >> 52) & 0x7ff) as i32;
+    let mut m = bits & ((1 << 52) - 1);
+    if e == 0 {
+        e = 1;
+    } else {
+        m |= 1 << 52;
+    }
+    if m == 0 {
+        return true;
+    }
+    let tz = m.trailing_zeros();
+    m >>= tz;
+    e += tz as i32 - 1075;
+    /* d * 10^k = m * 2^(e + k) * 5^k with m odd */
+    if e + k < 0 {
+        false
+    } else if k >= 0 {
+        true
+    } else if -k > 22 {
+        /* 5^23 > 2^53 > m */
+        false
+    } else {
+        m % 5u64.pow(-k as u32) == 0
+    }
+}
+
+/* add one to the decimal digits of buf, skipping the decimal point.
+Return true if there is a carry out of the first digit. */
+fn round_up(buf: &mut [u8]) -> bool {
+    for c in buf.iter_mut().rev() {
+        match *c {
+            b'.' => {}
+            b'9' => *c = b'0',
+            _ => {
+                *c += 1;
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/* 'd' is written with n_digits significant digits (the shortest
+representation which is parsed back to d if n_digits = 0). Return the
+digits and the position of the decimal point 'decpt', i.e.
+d = 0.digits * 10^decpt. d must be finite and positive. */
+fn js_ecvt1(d: f64, n_digits: usize) -> (DtoaBuf, i32) {
+    let mut buf = DtoaBuf::new();
+    if n_digits == 0 {
+        write!(buf, "{:e}", d).unwrap();
+    } else {
+        write!(buf, "{:.*e}", n_digits - 1, d).unwrap();
+    }
+    let s = buf.as_str();
+    let e = s.find('e').unwrap();
+    let mut digits = DtoaBuf::new();
+    for &c in s[..e].as_bytes() {
+        if c != b'.' {
+            digits.push(c);
+        }
+    }
+    let decpt = s[e + 1..].parse::<i32>().unwrap() + 1;
+    (digits, decpt)
+}
+
+fn js_ecvt(d: f64, n_digits: usize) -> (DtoaBuf, i32) {
+    if n_digits == 0 {
+        let (digits, decpt) = js_ecvt1(d, 0);
+        /* If d is the middle of two numbers with k digits which are
+        both parsed back to d, JS selects the even one while the core
+        library may return the other one. */
+        let k = digits.len;
+        let (mut t, mut decpt1) = js_ecvt1(d, k + 1);
+        if t.buf[k] == b'5' && is_exact(d, k as i32 + 1 - decpt1) {
+            t.len = k;
+            if (t.buf[k - 1] - b'0') % 2 != 0 && round_up(&mut t.buf[..k]) {
+                t.buf[0] = b'1';
+                decpt1 += 1;
+            }
+            if t.buf[..k] != digits.buf[..k] {
+                let mut s = DtoaBuf::new();
+                write!(s, "0.{}e{}", t.as_str(), decpt1).unwrap();
+                if s.parse::<f64>() == Ok(d) {
+                    return (t, decpt1);
+                }
+            }
+        }
+        return (digits, decpt);
+    }
+    /* The JS rounding is specified as round to nearest ties away from
+    zero (RNDNA). There is a tie only if the number is exactly written
+    with n_digits + 1 digits, the last one being 5. */
+    let (mut digits, mut decpt) = js_ecvt1(d, n_digits + 1);
+    if digits.buf[n_digits] == b'5' && is_exact(d, n_digits as i32 + 1 - decpt) {
+        digits.len = n_digits;
+        if round_up(&mut digits.buf[..n_digits]) {
+            digits.buf[0] = b'1';
+            decpt += 1;
+        }
+        return (digits, decpt);
+    }
+    js_ecvt1(d, n_digits)
+}
+
+/* 'd' written with n_digits digits after the decimal point. d must be
+finite and positive. */
+fn js_fcvt(d: f64, n_digits: usize) -> DtoaBuf {
+    let mut buf = DtoaBuf::new();
+    /* same rounding as js_ecvt() */
+    write!(buf, "{:.*}", n_digits + 1, d).unwrap();
+    if buf.buf[buf.len - 1] == b'5' && is_exact(d, n_digits as i32 + 1) {
+        buf.len -= 1;
+        if n_digits == 0 {
+            /* remove the decimal point */
+            buf.len -= 1;
+        }
+        if round_up(&mut buf.buf[..buf.len]) {
+            buf.buf.copy_within(0..buf.len, 1);
+            buf.buf[0] = b'1';
+            buf.len += 1;
+        }
+        return buf;
+    }
+    buf.len = 0;
+    write!(buf, "{:.*}", n_digits, d).unwrap();
+    buf
+}
+
+/* use as many digits as necessary */
+const JS_DTOA_VAR_FORMAT: u32 = 0;
+/* use n_digits significant digits (1 <= n_digits <= 101) */
+const JS_DTOA_FIXED_FORMAT: u32 = 1;
+/* force fractional format: [-]dd.dd with n_digits fractional digits */
+const JS_DTOA_FRAC_FORMAT: u32 = 2;
+/* force exponential notation either in fixed or variable format */
+const JS_DTOA_FORCE_EXP: u32 = 1 << 2;
+
+fn js_dtoa(d: f64, n_digits: usize, flags: u32) -> DtoaBuf {
+    let mut buf = DtoaBuf::new();
+    if !d.is_finite() {
+        if d.is_nan() {
+            buf.push_bytes(b"NaN");
+        } else {
+            if d < 0.0 {
+                buf.push(b'-');
+            }
+            buf.push_bytes(b"Infinity");
+        }
+        return buf;
+    }
+    if flags == JS_DTOA_VAR_FORMAT && d.abs() <= MAX_SAFE_INTEGER && d == d.trunc() {
+        /* fast path for integers */
+        write!(buf, "{}", d as i64).unwrap();
+        return buf;
+    }
+    /* -0 is written as 0 */
+    if d < 0.0 {
+        buf.push(b'-');
+    }
+    let d = d.abs();
+    if flags == JS_DTOA_FRAC_FORMAT {
+        buf.push_bytes(js_fcvt(d, n_digits).as_bytes());
+        return buf;
+    }
+    let is_fixed = (flags & 3) == JS_DTOA_FIXED_FORMAT;
+    let n_max = if is_fixed { n_digits as i32 } else { 21 };
+    /* the number has k digits (k >= 1) */
+    let (digits, n) = js_ecvt(d, if is_fixed { n_digits } else { 0 });
+    let digits = digits.as_bytes();
+    let k = digits.len() as i32;
+    /* d = 10^(n-k) * digits */
+    if flags & JS_DTOA_FORCE_EXP == 0 && n >= 1 && n <= n_max {
+        if k <= n {
+            buf.push_bytes(digits);
+            for _ in 0..n - k {
+                buf.push(b'0');
+            }
+        } else {
+            buf.push_bytes(&digits[..n as usize]);
+            buf.push(b'.');
+            buf.push_bytes(&digits[n as usize..]);
+        }
+    } else if flags & JS_DTOA_FORCE_EXP == 0 && n >= -5 && n <= 0 {
+        buf.push_bytes(b"0.");
+        for _ in 0..-n {
+            buf.push(b'0');
+        }
+        buf.push_bytes(digits);
+    } else {
+        /* exponential notation */
+        buf.push(digits[0]);
+        if k > 1 {
+            buf.push(b'.');
+            buf.push_bytes(&digits[1..]);
+        }
+        write!(buf, "e{:+}", n - 1).unwrap();
+    }
+    buf
+}
+
+/// Convert `d` to a string as `Number.prototype.toString()`, with the
+/// shortest representation which is parsed back to `d`.
+pub fn to_string(d: f64) -> DtoaBuf {
+    js_dtoa(d, 0, JS_DTOA_VAR_FORMAT)
+}
+
+/// Convert `d` to a string with `digits` digits after the decimal
+/// point as `Number.prototype.toFixed()`. Panics if `digits` is larger
+/// than 100.
+pub fn to_fixed(d: f64, digits: u32) -> DtoaBuf {
+    assert!(digits <= 100, "invalid number of digits");
+    if d.abs() >= 1e21 {
+        to_string(d)
+    } else {
+        js_dtoa(d, digits as usize, JS_DTOA_FRAC_FORMAT)
+    }
+}
+
+/// Convert `d` to a string in exponential notation with `digits`
+/// digits after the decimal point, or as many digits as necessary if
+/// `digits` is None, as `Number.prototype.toExponential()`. Panics if
+/// `digits` is larger than 100.
+pub fn to_exponential(d: f64, digits: Option<u32>) -> DtoaBuf {
+    if !d.is_finite() {
+        return to_string(d);
+    }
+    match digits {
+        None => js_dtoa(d, 0, JS_DTOA_VAR_FORMAT | JS_DTOA_FORCE_EXP),
+        Some(f) => {
+            assert!(f <= 100, "invalid number of digits");
+            js_dtoa(d, f as usize + 1, JS_DTOA_FIXED_FORMAT | JS_DTOA_FORCE_EXP)
+        }
+    }
+}
+
+/// Convert `d` to a string with `digits` significant digits as
+/// `Number.prototype.toPrecision()`. Panics if `digits` is not between
+/// 1 and 100.
+pub fn to_precision(d: f64, digits: u32) -> DtoaBuf {
+    if !d.is_finite() {
+        return to_string(d);
+    }
+    assert!((1..=100).contains(&digits), "invalid number of digits");
+    js_dtoa(d, digits as usize, JS_DTOA_FIXED_FORMAT)
+}
+
+/// Accept only integers, without decimal point nor exponent.
+pub const ATOD_INT_ONLY: u32 = 1 << 0;
+/// Accept the 0o and 0b prefixes in addition to the 0x prefix if the
+/// radix is 0.
+pub const ATOD_ACCEPT_BIN_OCT: u32 = 1 << 2;
+/// Accept the 0 prefix as octal if the radix is 0 and the number is
+/// properly formed (Annex B).
+pub const ATOD_ACCEPT_LEGACY_OCTAL: u32 = 1 << 4;
+/// Accept `_` between digits as a digit separator.
+pub const ATOD_ACCEPT_UNDERSCORES: u32 = 1 << 5;
+/// Accept a radix prefix after the sign, e.g. "-0x1".
+pub const ATOD_ACCEPT_PREFIX_AFTER_SIGN: u32 = 1 << 10;
+
+fn to_digit(c: u8) -> u32 {
+    match c {
+        b'0'..=b'9' => (c - b'0') as u32,
+        b'A'..=b'Z' => (c - b'A') as u32 + 10,
+        b'a'..=b'z' => (c - b'a') as u32 + 10,
+        _ => 36,
+    }
+}
+
+/* round m * 2^e to the nearest float64 (ties to even). 'sticky' is true
+if non zero bits were truncated below m. */
+fn make_float64(mut m: u64, mut e: i64, sticky: bool) -> f64 {
+    if m == 0 {
+        return 0.0;
+    }
+    let shift = m.leading_zeros();
+    m <<= shift;
+    e -= shift as i64;
+    let mut mant = m >> 11;
+    let rest = m & 0x7ff;
+    if rest > 0x400 || (rest == 0x400 && (sticky || (mant & 1) != 0)) {
+        mant += 1;
+        if mant == 1 << 53 {
+            mant >>= 1;
+            e += 1;
+        }
+    }
+    /* m * 2^e = 1.xxx * 2^expn. The integers are never subnormal. */
+    let expn = e + 11 + 52;
+    if expn > 1023 {
+        return f64::INFINITY;
+    }
+    f64::from_bits(((expn + 1023) as u64) << 52 | (mant & ((1 << 52) - 1)))
+}
+
+/* integer written in base 'radix' with optional '_' separators */
+fn js_strtod_radix(p: &[u8], radix: u32) -> f64 {
+    if radix.is_power_of_two() {
+        /* correctly rounded */
+        let bits = radix.trailing_zeros();
+        let mut m: u64 = 0;
+        let mut e: i64 = 0;
+        let mut sticky = false;
+        for &c in p {
+            if c == b'_' {
+                continue;
+            }
+            let v = to_digit(c) as u64;
+            if m >> (64 - bits) == 0 {
+                m = (m << bits) | v;
+            } else {
+                sticky |= v != 0;
+                e += bits as i64;
+            }
+        }
+        make_float64(m, e, sticky)
+    } else {
+        /* The integer is computed exactly. The numbers of at least
+        2^1088 are larger than the largest float64 rounded up, so a
+        fixed number of limbs is enough. */
+        let mut tab = [0u64; 17];
+        let mut len = 0;
+        for &c in p {
+            if c == b'_' {
+                continue;
+            }
+            let mut carry = to_digit(c) as u64;
+            for l in tab[..len].iter_mut() {
+                let t = *l as u128 * radix as u128 + carry as u128;
+                *l = t as u64;
+                carry = (t >> 64) as u64;
+            }
+            if carry != 0 {
+                if len == tab.len() {
+                    return f64::INFINITY;
+                }
+                tab[len] = carry;
+                len += 1;
+            }
+        }
+        if len == 0 {
+            return 0.0;
+        }
+        /* the 64 most significant bits and the sticky bit */
+        let shift = tab[len - 1].leading_zeros();
+        let mut m = tab[len - 1] << shift;
+        let mut sticky = false;
+        if len >= 2 {
+            let low = tab[len - 2] << shift;
+            if shift != 0 {
+                m |= tab[len - 2] >> (64 - shift);
+            }
+            sticky = low != 0 || tab[..len - 2].iter().any(|&l| l != 0);
+        }
+        make_float64(m, (len as i64 - 1) * 64 - shift as i64, sticky)
+    }
+}
+
+/* maximum number of significant digits passed to the core parser. It
+is larger than the 767 significant digits of the longest float64 which
+is the middle of two consecutive float64. */
+const DEC_DIGITS_MAX: usize = 800;
+
+/* decimal number with optional '_' separators, following the JS
+grammar */
+fn js_strtod_dec(p: &[u8]) -> f64 {
+    /* The significant digits are copied without the separators and
+    the decimal point: the number is digits * 10^(e - len). The
+    following digits only matter if one of them is non zero, which is
+    recorded by a final '1'. */
+    let mut buf = [0u8; DEC_DIGITS_MAX + 32];
+    let mut len = 0;
+    let mut e: i64 = 0;
+    let mut is_frac = false;
+    let mut sticky = false;
+    let mut i = 0;
+    while i < p.len() {
+        match p[i] {
+            b'_' => {}
+            b'.' => is_frac = true,
+            b'e' | b'E' => break,
+            b'0' if len == 0 => {
+                if is_frac {
+                    e -= 1;
+                }
+            }
+            c => {
+                if len < DEC_DIGITS_MAX {
+                    buf[len] = c;
+                    len += 1;
+                } else {
+                    sticky |= c != b'0';
+                }
+                if !is_frac {
+                    e += 1;
+                }
+            }
+        }
+        i += 1;
+    }
+    if len == 0 {
+        return 0.0;
+    }
+    if sticky {
+        buf[len] = b'1';
+        len += 1;
+    }
+    /* exponent */
+    let mut exp: i64 = 0;
+    let mut exp_neg = false;
+    i += 1;
+    for &c in p.iter().skip(i) {
+        match c {
+            b'-' => exp_neg = true,
+            b'0'..=b'9' => {
+                /* the result is 0 or infinity long before the limit */
+                exp = (exp * 10 + (c - b'0') as i64).min(1 << 32);
+            }
+            _ => {}
+        }
+    }
+    e += if exp_neg { -exp } else { exp };
+    let mut s = DtoaBuf::new();
+    write!(s, "e{}", e - len as i64).unwrap();
+    buf[len..len + s.len].copy_from_slice(&s.buf[..s.len]);
+    len += s.len;
+    /* only ASCII digits were copied, so the core parser accepts it */
+    unsafe { std::str::from_utf8_unchecked(&buf[..len]) }
+        .parse::<f64>()
+        .unwrap_or(f64::NAN)
+}
+
+/// Parse the number at the start of `s` as js_atof(). `radix` is
+/// between 2 and 36, or 0 to accept the radix prefixes selected by
+/// `flags` and parse decimal numbers otherwise. "Infinity" is
+/// accepted unless `ATOD_INT_ONLY` is set. Return the number and the
+/// length of the parsed prefix of `s`, or None if `s` does not start
+/// with a number.
+pub fn atod(s: &str, mut radix: u32, flags: u32) -> Option<(f64, usize)> {
+    let p = s.as_bytes();
+    let at = |i: usize| p.get(i).copied().unwrap_or(0);
+    /* optional separator between digits */
+    let mut sep = if flags & ATOD_ACCEPT_UNDERSCORES != 0 {
+        b'_' as u32
+    } else {
+        256
+    };
+    let mut i = 0;
+    let mut is_neg = false;
+    let mut accept_prefix = true;
+    if at(0) == b'+' || at(0) == b'-' {
+        is_neg = at(0) == b'-';
+        i = 1;
+        accept_prefix = flags & ATOD_ACCEPT_PREFIX_AFTER_SIGN != 0;
+    }
+    if accept_prefix && at(i) == b'0' {
+        let c = at(i + 1);
+        let mut has_prefix = true;
+        if (c == b'x' || c == b'X') && (radix == 0 || radix == 16) {
+            i += 2;
+            radix = 16;
+        } else if (c == b'o' || c == b'O') && radix == 0 && flags & ATOD_ACCEPT_BIN_OCT != 0 {
+            i += 2;
+            radix = 8;
+        } else if (c == b'b' || c == b'B') && radix == 0 && flags & ATOD_ACCEPT_BIN_OCT != 0 {
+            i += 2;
+            radix = 2;
+        } else if c.is_ascii_digit() && radix == 0 && flags & ATOD_ACCEPT_LEGACY_OCTAL != 0 {
+            let mut j = i + 1;
+            while (b'0'..=b'7').contains(&at(j)) {
+                j += 1;
+            }
+            if at(j) == b'8' || at(j) == b'9' {
+                has_prefix = false;
+            } else {
+                sep = 256;
+                i += 1;
+                radix = 8;
+            }
+        } else {
+            has_prefix = false;
+        }
+        /* there must be a digit after the prefix */
+        if has_prefix && to_digit(at(i)) >= radix {
+            return None;
+        }
+    } else if flags & ATOD_INT_ONLY == 0 && p[i..].starts_with(b"Infinity") {
+        let d = if is_neg {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+        return Some((d, i + 8));
+    }
+    if radix == 0 {
+        radix = 10;
+    }
+    let mut is_float = false;
+    let start = i;
+    while to_digit(at(i)) < radix
+        || (at(i) as u32 == sep
+            && (radix != 10 || i != start + 1 || at(i - 1) != b'0')
+            && to_digit(at(i + 1)) < radix)
+    {
+        i += 1;
+    }
+    if flags & ATOD_INT_ONLY == 0 {
+        if at(i) == b'.' && (i > start || to_digit(at(i + 1)) < radix) {
+            is_float = true;
+            i += 1;
+            if at(i) as u32 == sep {
+                return None;
+            }
+            while to_digit(at(i)) < radix || (at(i) as u32 == sep && to_digit(at(i + 1)) < radix) {
+                i += 1;
+            }
+        }
+        let c = at(i);
+        if i > start
+            && (((c == b'e' || c == b'E') && radix == 10)
+                || ((c == b'p' || c == b'P') && (radix == 2 || radix == 8 || radix == 16)))
+        {
+            let mut j = i + 1;
+            is_float = true;
+            if at(j) == b'+' || at(j) == b'-' {
+                j += 1;
+            }
+            if at(j).is_ascii_digit() {
+                i = j + 1;
+                while at(i).is_ascii_digit() || (at(i) as u32 == sep && at(i + 1).is_ascii_digit())
+                {
+                    i += 1;
+                }
+            }
+        }
+    }
+    if i == start || (is_float && radix != 10) {
+        return None;
+    }
+    let d = if radix == 10 {
+        js_strtod_dec(&p[start..i])
+    } else {
+        js_strtod_radix(&p[start..i], radix)
+    };
+    Some((if is_neg { -d } else { d }, i))
+}
+
+fn is_space(c: char) -> bool {
+    unsafe { lre_is_space(c as i32) != 0 }
+}
+
+/// Convert `s` to a number as the `Number()` function: the white space
+/// is ignored, the empty string is 0 and NaN is returned if `s` is not
+/// a numeric literal.
+pub fn string_to_number(s: &str) -> f64 {
+    let s = s.trim_matches(is_space);
+    if s.is_empty() {
+        return 0.0;
+    }
+    match atod(s, 0, ATOD_ACCEPT_BIN_OCT) {
+        Some((d, len)) if len == s.len() => d,
+        _ => f64::NAN,
+    }
+}
+
+/// Parse the decimal number at the start of `s` as `parseFloat()`.
+/// Return NaN if `s` does not start with a number.
+pub fn parse_float(s: &str) -> f64 {
+    match atod(s.trim_start_matches(is_space), 10, 0) {
+        Some((d, _)) => d,
+        None => f64::NAN,
+    }
+}
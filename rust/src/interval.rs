@@ -0,0 +1,513 @@
+/*
+ * Interval arithmetic on arbitrary precision binary floating point
+ * numbers
+ *
+ * An Interval is a closed set of real numbers [lo, hi] whose bounds
+ * are BigFloat numbers. The operations of an IntervalEnv round the
+ * lower bound toward -Infinity and the upper bound toward +Infinity
+ * with the precision and exponent range of a BigFloatEnv, so that the
+ * result always contains the exact results of the operation for all
+ * the numbers of its operands.
+ *
+ * The bounds may be infinite, but an interval always contains a real
+ * number. The operations outside of their domain (e.g. the square
+ * root of a negative interval) return the NaN interval, which is
+ * propagated by the other operations. The part of an operand outside
+ * of the domain is ignored, as in IEEE 1788: sqrt([-1, 4]) = [0, 2].
+ */
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::bigfloat::{BigFloat, BigFloatEnv, ParseBigFloatError, Rounding};
+
+/// Closed interval of real numbers, or the NaN interval.
+#[derive(Clone)]
+pub struct Interval {
+    lo: BigFloat,
+    hi: BigFloat,
+}
+
+fn min(a: BigFloat, b: BigFloat) -> BigFloat {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max(a: BigFloat, b: BigFloat) -> BigFloat {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+impl Interval {
+    /// Return the interval [`lo`, `hi`]. Panics if a bound is NaN, if
+    /// `lo` > `hi` or if the interval contains no real number, i.e.
+    /// [+Infinity, +Infinity] or [-Infinity, -Infinity].
+    pub fn new(lo: BigFloat, hi: BigFloat) -> Interval {
+        assert!(
+            lo <= hi && lo != BigFloat::infinity() && hi != BigFloat::neg_infinity(),
+            "invalid interval"
+        );
+        Interval { lo, hi }
+    }
+
+    /// Return the interval containing only `x`. Panics if `x` is NaN or
+    /// infinite.
+    pub fn point(x: &BigFloat) -> Interval {
+        Interval::new(x.clone(), x.clone())
+    }
+
+    /// Return [-Infinity, +Infinity].
+    pub fn entire() -> Interval {
+        Interval {
+            lo: BigFloat::neg_infinity(),
+            hi: BigFloat::infinity(),
+        }
+    }
+
+    /// Return the NaN interval, result of the invalid operations.
+    pub fn nan() -> Interval {
+        Interval {
+            lo: BigFloat::nan(),
+            hi: BigFloat::nan(),
+        }
+    }
+
+    pub fn lo(&self) -> &BigFloat {
+        &self.lo
+    }
+
+    pub fn hi(&self) -> &BigFloat {
+        &self.hi
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.lo.is_nan()
+    }
+
+    /// Return true if both bounds are finite.
+    pub fn is_bounded(&self) -> bool {
+        self.lo.is_finite() && self.hi.is_finite()
+    }
+
+    pub fn contains(&self, x: &BigFloat) -> bool {
+        self.lo <= *x && *x <= self.hi
+    }
+
+    /// Return true if the interval contains 0.
+    pub fn contains_zero(&self) -> bool {
+        self.contains(&BigFloat::zero())
+    }
+
+    /* [0, 0], whatever the sign of the zeros */
+    fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+
+    /// Return true if all the numbers of `self` are lower than all the
+    /// numbers of `other`.
+    pub fn certainly_lt(&self, other: &Interval) -> bool {
+        self.hi < other.lo
+    }
+
+    pub fn certainly_le(&self, other: &Interval) -> bool {
+        self.hi <= other.lo
+    }
+
+    /// Return true if both intervals contain only the same number.
+    pub fn certainly_eq(&self, other: &Interval) -> bool {
+        self.lo == self.hi && other.lo == other.hi && self.lo == other.lo
+    }
+
+    /// Return true if a number of `self` is lower than a number of
+    /// `other`.
+    pub fn possibly_lt(&self, other: &Interval) -> bool {
+        self.lo < other.hi
+    }
+
+    pub fn possibly_le(&self, other: &Interval) -> bool {
+        self.lo <= other.hi
+    }
+
+    /// Return true if the intervals have a number in common.
+    pub fn possibly_eq(&self, other: &Interval) -> bool {
+        self.lo <= other.hi && other.lo <= self.hi
+    }
+}
+
+/// Precision and exponent range of the interval operations, with the
+/// status flags (`BF_ST_*`) they raised. The rounding mode of the
+/// BigFloatEnv it is created from is ignored.
+#[derive(Clone, Debug)]
+pub struct IntervalEnv {
+    down: BigFloatEnv,
+    up: BigFloatEnv,
+}
+
+impl IntervalEnv {
+    pub fn new(env: &BigFloatEnv) -> IntervalEnv {
+        let mut down = env.clone();
+        down.set_rounding(Rounding::Down);
+        down.clear_status();
+        let mut up = down.clone();
+        up.set_rounding(Rounding::Up);
+        IntervalEnv { down, up }
+    }
+
+    /// Return an environment with the default environment of the
+    /// current thread.
+    pub fn thread_default() -> IntervalEnv {
+        IntervalEnv::new(&BigFloatEnv::thread_default())
+    }
+
+    pub fn prec(&self) -> u64 {
+        self.down.prec()
+    }
+
+    /// Return the status flags raised since the last call to
+    /// `clear_status`.
+    pub fn status(&self) -> i32 {
+        self.down.status() | self.up.status()
+    }
+
+    pub fn clear_status(&mut self) {
+        self.down.clear_status();
+        self.up.clear_status();
+    }
+
+    /// Return the smallest interval with bounds in the precision of the
+    /// environment which contains `x`. Panics if `x` is NaN or infinite.
+    pub fn enclose(&mut self, x: &BigFloat) -> Interval {
+        Interval::new(self.down.round(x), self.up.round(x))
+    }
+
+    /// Parse the number `s` written in base `radix` as
+    /// `BigFloatEnv::parse` and return the smallest interval containing
+    /// it, e.g. a tight interval containing 1/10 for "0.1".
+    pub fn parse(&mut self, s: &str, radix: u32) -> Result<Interval, ParseBigFloatError> {
+        let lo = self.down.parse(s, radix)?;
+        let hi = self.up.parse(s, radix)?;
+        if lo.is_nan() || lo == BigFloat::infinity() || hi == BigFloat::neg_infinity() {
+            return Err(ParseBigFloatError);
+        }
+        Ok(Interval { lo, hi })
+    }
+
+    pub fn const_pi(&mut self) -> Interval {
+        Interval {
+            lo: self.down.const_pi(),
+            hi: self.up.const_pi(),
+        }
+    }
+
+    pub fn const_log2(&mut self) -> Interval {
+        Interval {
+            lo: self.down.const_log2(),
+            hi: self.up.const_log2(),
+        }
+    }
+
+    pub fn add(&mut self, a: &Interval, b: &Interval) -> Interval {
+        if a.is_nan() || b.is_nan() {
+            return Interval::nan();
+        }
+        Interval {
+            lo: self.down.add(&a.lo, &b.lo),
+            hi: self.up.add(&a.hi, &b.hi),
+        }
+    }
+
+    pub fn sub(&mut self, a: &Interval, b: &Interval) -> Interval {
+        if a.is_nan() || b.is_nan() {
+            return Interval::nan();
+        }
+        Interval {
+            lo: self.down.sub(&a.lo, &b.hi),
+            hi: self.up.sub(&a.hi, &b.lo),
+        }
+    }
+
+    pub fn mul(&mut self, a: &Interval, b: &Interval) -> Interval {
+        if a.is_nan() || b.is_nan() {
+            return Interval::nan();
+        }
+        /* the products of the bounds, with 0 * Infinity = 0 since the
+        infinite bounds are not in the intervals */
+        let mut lo = BigFloat::infinity();
+        let mut hi = BigFloat::neg_infinity();
+        for x in [&a.lo, &a.hi].iter() {
+            for y in [&b.lo, &b.hi].iter() {
+                if x.is_zero() || y.is_zero() {
+                    lo = min(lo, BigFloat::zero());
+                    hi = max(hi, BigFloat::zero());
+                } else {
+                    lo = min(lo, self.down.mul(x, y));
+                    hi = max(hi, self.up.mul(x, y));
+                }
+            }
+        }
+        Interval { lo, hi }
+    }
+
+    /// Return the NaN interval if `b` is [0, 0]. The result is
+    /// [-Infinity, +Infinity] if `b` contains 0, unless `a` is [0, 0].
+    pub fn div(&mut self, a: &Interval, b: &Interval) -> Interval {
+        if a.is_nan() || b.is_nan() || b.is_zero() {
+            return Interval::nan();
+        }
+        if a.is_zero() {
+            return Interval::point(&BigFloat::zero());
+        }
+        if b.contains_zero() {
+            return Interval::entire();
+        }
+        /* Infinity / Infinity is ignored: one bound of 'b' is finite
+        and the quotients by it give the infinite bounds of the
+        result */
+        let mut lo = BigFloat::infinity();
+        let mut hi = BigFloat::neg_infinity();
+        for x in [&a.lo, &a.hi].iter() {
+            for y in [&b.lo, &b.hi].iter() {
+                if !x.is_finite() && !y.is_finite() {
+                    continue;
+                }
+                lo = min(lo, self.down.div(x, y));
+                hi = max(hi, self.up.div(x, y));
+            }
+        }
+        Interval { lo, hi }
+    }
+
+    pub fn sqrt(&mut self, a: &Interval) -> Interval {
+        if a.is_nan() || a.hi < BigFloat::zero() {
+            return Interval::nan();
+        }
+        let lo = if a.lo.is_sign_negative() {
+            BigFloat::zero()
+        } else {
+            self.down.sqrt(&a.lo)
+        };
+        Interval {
+            lo,
+            hi: self.up.sqrt(&a.hi),
+        }
+    }
+
+    pub fn exp(&mut self, a: &Interval) -> Interval {
+        if a.is_nan() {
+            return Interval::nan();
+        }
+        Interval {
+            lo: self.down.exp(&a.lo),
+            hi: self.up.exp(&a.hi),
+        }
+    }
+
+    /// Return the NaN interval if `a` contains no positive number.
+    pub fn log(&mut self, a: &Interval) -> Interval {
+        if a.is_nan() || a.hi <= BigFloat::zero() {
+            return Interval::nan();
+        }
+        let lo = if a.lo <= BigFloat::zero() {
+            BigFloat::neg_infinity()
+        } else {
+            self.down.log(&a.lo)
+        };
+        Interval {
+            lo,
+            hi: self.up.log(&a.hi),
+        }
+    }
+
+    pub fn sin(&mut self, a: &Interval) -> Interval {
+        self.trig(a, true)
+    }
+
+    pub fn cos(&mut self, a: &Interval) -> Interval {
+        self.trig(a, false)
+    }
+
+    /* sin(x) reaches its extrema (-1)^k at x = (k + 1/2) * pi and cos(x)
+    at x = k * pi. Between them, the function is monotonic so the
+    bounds are reached at the bounds of 'a'. */
+    fn trig(&mut self, a: &Interval, is_sin: bool) -> Interval {
+        if a.is_nan() {
+            return Interval::nan();
+        }
+        let one = BigFloat::from(1);
+        if !a.is_bounded() {
+            return Interval::new(-&one, one);
+        }
+        let (kmin, kmax, kmin_is_even) = self.pi_multiples(a, is_sin);
+        if kmin < kmax {
+            return Interval::new(-&one, one);
+        }
+        let (lo, hi) = if is_sin {
+            (
+                min(self.down.sin(&a.lo), self.down.sin(&a.hi)),
+                max(self.up.sin(&a.lo), self.up.sin(&a.hi)),
+            )
+        } else {
+            (
+                min(self.down.cos(&a.lo), self.down.cos(&a.hi)),
+                max(self.up.cos(&a.lo), self.up.cos(&a.hi)),
+            )
+        };
+        if kmin == kmax {
+            if kmin_is_even {
+                return Interval::new(lo, one);
+            } else {
+                return Interval::new(-one, hi);
+            }
+        }
+        Interval::new(lo, hi)
+    }
+
+    /* Return the smallest and largest integers k such that
+    (k + offset) * pi may be in 'a', with an offset of 1/2 if 'half' is
+    true and 0 otherwise, and the parity of the smallest one. The
+    bounds of 'a' must be finite. The precision is increased with the
+    magnitude of the bounds so that the integer part of a / pi is
+    exact. */
+    fn pi_multiples(&mut self, a: &Interval, half: bool) -> (BigFloat, BigFloat, bool) {
+        let mag = max(a.lo.abs(), a.hi.abs());
+        let mut extra = 0;
+        if !mag.is_zero() {
+            let expn = unsafe { (*mag.as_ptr()).expn };
+            if expn > 0 {
+                extra = expn as u64;
+            }
+        }
+        let prec = (self.prec() + extra + 8).min(BigFloatEnv::PREC_MAX);
+        let mut env = IntervalEnv::new(&BigFloatEnv::new(prec));
+        let pi = env.const_pi();
+        let mut t = env.div(a, &pi);
+        if half {
+            t = env.sub(&t, &Interval::point(&BigFloat::from(0.5)));
+        }
+        let kmin = t.lo.ceil();
+        /* exact with the maximum exponent range */
+        let kmin_is_even = env.down.mul(&kmin, &BigFloat::from(0.5)).is_integer();
+        (kmin, t.hi.floor(), kmin_is_even)
+    }
+}
+
+impl PartialEq for Interval {
+    /// The intervals are equal if they have the same bounds. The NaN
+    /// interval is not equal to itself.
+    fn eq(&self, other: &Interval) -> bool {
+        self.lo == other.lo && self.hi == other.hi
+    }
+}
+
+impl PartialOrd for Interval {
+    /// `a < b` if all the numbers of `a` are lower than all the numbers
+    /// of `b`. The intervals which overlap are not ordered unless they
+    /// are equal.
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        if self == other {
+            Some(Ordering::Equal)
+        } else if self.certainly_lt(other) {
+            Some(Ordering::Less)
+        } else if other.certainly_lt(self) {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    /// The precision of the formatter is the number of digits after
+    /// the decimal point. The lower bound is then rounded down and the
+    /// upper bound rounded up, so that the printed interval contains
+    /// the interval.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_nan() {
+            return f.write_str("[NaN]");
+        }
+        match f.precision() {
+            Some(digits) => write!(
+                f,
+                "[{}, {}]",
+                self.lo.to_fixed(digits as u64, Rounding::Down),
+                self.hi.to_fixed(digits as u64, Rounding::Up)
+            ),
+            None => write!(f, "[{}, {}]", self.lo, self.hi),
+        }
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Interval;
+
+    /// The negation is exact.
+    fn neg(self) -> Interval {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Neg for &Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        -self.clone()
+    }
+}
+
+/* binary operators with the precision of the default environment of
+the current thread */
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident) => {
+        impl $Op<&Interval> for &Interval {
+            type Output = Interval;
+
+            fn $op(self, rhs: &Interval) -> Interval {
+                IntervalEnv::thread_default().$op(self, rhs)
+            }
+        }
+
+        impl $Op<Interval> for &Interval {
+            type Output = Interval;
+
+            fn $op(self, rhs: Interval) -> Interval {
+                self.$op(&rhs)
+            }
+        }
+
+        impl $Op<&Interval> for Interval {
+            type Output = Interval;
+
+            fn $op(self, rhs: &Interval) -> Interval {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl $Op<Interval> for Interval {
+            type Output = Interval;
+
+            fn $op(self, rhs: Interval) -> Interval {
+                (&self).$op(&rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add);
+impl_binary_op!(Sub, sub);
+impl_binary_op!(Mul, mul);
+impl_binary_op!(Div, div);
@@ -25,7 +25,27 @@ mod cutils;
 mod libbf;
 mod libregexp;
 mod libunicode;
+pub mod bigcomplex;
+pub mod bigdecimal;
+pub mod bigfloat;
+pub mod bigint;
+pub mod bigrational;
+pub mod dtoa;
+pub mod interval;
+pub mod matrix;
+pub mod numfmt;
+pub mod polynomial;
 pub mod quickjs;
+pub mod unicode_bidi;
+pub mod unicode_ident;
+pub mod unicode_linebreak;
+pub mod unicode_names;
+pub mod unicode_normalization;
+pub mod unicode_property;
+pub mod unicode_script;
+pub mod unicode_security;
+pub mod unicode_transcode;
+pub mod unicode_width;
 
 // #[cfg(not(target_arch = "wasm32"))]
 // pub mod quickjs_libc;
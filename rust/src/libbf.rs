@@ -1,8 +1,13 @@
-use std::{fmt::Write as _, process::abort};
+use std::{
+    fmt::Write as _,
+    process::abort,
+    sync::atomic::{AtomicPtr, AtomicUsize, Ordering},
+    sync::Mutex,
+};
 
 use crate::cutils::{
-    dbuf_error, dbuf_init2, dbuf_put, dbuf_putc, dbuf_putstr, dbuf_set_error, DynBuf,
-    DynBufReallocFunc, BOOL, FALSE, TRUE,
+    dbuf_error, dbuf_init2, dbuf_put, dbuf_putc, dbuf_putstr, dbuf_set_error, sized_alloc_size,
+    sized_realloc, DynBuf, DynBufReallocFunc, BOOL, FALSE, TRUE,
 };
 
 pub type intptr_t = isize;
@@ -20,6 +25,26 @@ pub struct bf_t {
     pub tab: *mut limb_t,
 }
 
+/* complex number with binary floating point components */
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct bf_complex_t {
+    pub re: bf_t,
+    pub im: bf_t,
+}
+
+/* A context holds the memory allocator, the caches of the constants
+and the NTT tables. It is not thread safe: a context and the numbers
+it allocates must be modified by one thread at a time. The operations
+only use the context of their result for the temporary numbers, the
+constants and the NTT tables, so the operands can be numbers of a
+context used by another thread as long as nobody modifies them. The
+functions without result number, such as bf_ftoa() and
+bf_get_float64(), take the context to use as argument. The memory
+allocator of a context must be thread safe if its numbers are
+freed or resized by other threads. The static tables are read only
+and the values of the constants are also kept in a process wide cache
+shared by all the contexts. */
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct bf_context_t {
@@ -27,7 +52,11 @@ pub struct bf_context_t {
     pub realloc_func: Option<bf_realloc_func_t>,
     pub log2_cache: BFConstCache,
     pub pi_cache: BFConstCache,
+    pub euler_cache: BFConstCache,
     pub ntt_state: *mut BFNTTState,
+    /* number of failed allocations, updated atomically since the NTT
+    buffers can be allocated by other threads */
+    pub alloc_failures: usize,
 }
 
 #[repr(C)]
@@ -76,6 +105,70 @@ pub const BF_RNDZ: bf_rnd_t = 1;
 pub const BF_RNDN: bf_rnd_t = 0;
 pub type bf_flags_t = u32;
 
+/* allow subnormal numbers. Only available if the number of exponent
+bits is <= BF_EXP_BITS_USER_MAX and prec != BF_PREC_INF. */
+pub const BF_FLAG_SUBNORMAL: bf_flags_t = 1 << 3;
+/* 'prec' is the precision after the radix point instead of the whole
+mantissa. Can only be used with bf_round() and
+bfdec_[add|sub|mul|div|sqrt|round](). */
+pub const BF_FLAG_RADPNT_PREC: bf_flags_t = 1 << 4;
+
+pub const BF_RND_MASK: bf_flags_t = 0x7;
+pub const BF_EXP_BITS_SHIFT: bf_flags_t = 5;
+pub const BF_EXP_BITS_MASK: bf_flags_t = 0x3f;
+/* shortcut for bf_set_exp_bits(BF_EXT_EXP_BITS_MAX) */
+pub const BF_FLAG_EXT_EXP: bf_flags_t = BF_EXP_BITS_MASK << BF_EXP_BITS_SHIFT;
+
+/* returned status */
+pub const BF_ST_INVALID_OP: i32 = 1 << 0;
+pub const BF_ST_DIVIDE_ZERO: i32 = 1 << 1;
+pub const BF_ST_OVERFLOW: i32 = 1 << 2;
+pub const BF_ST_UNDERFLOW: i32 = 1 << 3;
+pub const BF_ST_INEXACT: i32 = 1 << 4;
+/* indicate that a memory allocation error occured. NaN is returned */
+pub const BF_ST_MEM_ERROR: i32 = 1 << 5;
+
+/* additional flags for bf_atof */
+/* do not accept hex radix prefix (0x or 0X) if radix = 0 or radix = 16 */
+pub const BF_ATOF_NO_HEX: bf_flags_t = 1 << 16;
+/* accept binary (0b or 0B) or octal (0o or 0O) radix prefix if radix = 0 */
+pub const BF_ATOF_BIN_OCT: bf_flags_t = 1 << 17;
+/* Do not parse NaN or Inf */
+pub const BF_ATOF_NO_NAN_INF: bf_flags_t = 1 << 18;
+/* return the exponent separately */
+pub const BF_ATOF_EXPONENT: bf_flags_t = 1 << 19;
+
+pub const BF_FTOA_FORMAT_MASK: bf_flags_t = 3 << 16;
+/* fixed format: prec significant digits rounded with (flags &
+BF_RND_MASK). Exponential notation is used if too many zeros are
+needed. */
+pub const BF_FTOA_FORMAT_FIXED: bf_flags_t = 0 << 16;
+/* fractional format: prec digits after the decimal point rounded with
+(flags & BF_RND_MASK) */
+pub const BF_FTOA_FORMAT_FRAC: bf_flags_t = 1 << 16;
+/* free format: use as many digits as necessary so that bf_atof()
+return the same number when using precision 'prec' */
+pub const BF_FTOA_FORMAT_FREE: bf_flags_t = 2 << 16;
+/* same as BF_FTOA_FORMAT_FREE but uses the minimum number of digits */
+pub const BF_FTOA_FORMAT_FREE_MIN: bf_flags_t = 3 << 16;
+/* force exponential notation for fixed or free format */
+pub const BF_FTOA_FORCE_EXP: bf_flags_t = 1 << 20;
+/* add 0x prefix for base 16, 0o prefix for base 8 or 0b prefix for
+base 2 if non zero value */
+pub const BF_FTOA_ADD_PREFIX: bf_flags_t = 1 << 21;
+/* return "Infinity" instead of "Inf" and add a "+" for positive
+exponents */
+pub const BF_FTOA_JS_QUIRKS: bf_flags_t = 1 << 22;
+
+/* modulo 2^n instead of saturation. NaN and infinity return 0 */
+pub const BF_GET_INT_MOD: i32 = 1 << 0;
+
+/* (+/-1)^(+/-Inf) = NaN, 1^NaN = NaN */
+pub const BF_POW_JS_QUIRKS: bf_flags_t = 1 << 16;
+
+/* rounding mode of bf_divrem() for the euclidian division */
+pub const BF_DIVREM_EUCLIDIAN: i32 = BF_RNDF as i32;
+
 const LIMB_LOG2_BITS: u64 = 6;
 const LIMB_BITS: u64 = 1 << LIMB_LOG2_BITS;
 
@@ -87,18 +180,18 @@ const BF_DEC_BASE: u64 = 10_000_000_000_000_000_000;
 
 /* in bits */
 /* minimum number of bits for the exponent */
-const BF_EXP_BITS_MIN: u64 = 3;
+pub const BF_EXP_BITS_MIN: u64 = 3;
 /* maximum number of bits for the exponent */
-const BF_EXP_BITS_MAX: u64 = (LIMB_BITS - 3);
+pub const BF_EXP_BITS_MAX: u64 = (LIMB_BITS - 3);
 /* extended range for exponent, used internally */
 const BF_EXT_EXP_BITS_MAX: u64 = (BF_EXP_BITS_MAX + 1);
 /* minimum possible precision */
-const BF_PREC_MIN: u64 = 2;
+pub const BF_PREC_MIN: u64 = 2;
 /* minimum possible precision */
-const BF_PREC_MAX: u64 = (1 << (LIMB_BITS - 2)) - 2;
+pub const BF_PREC_MAX: u64 = (1 << (LIMB_BITS - 2)) - 2;
 /* some operations support infinite precision */
 /* infinite precision */
-const BF_PREC_INF: u64 = (BF_PREC_MAX + 1);
+pub const BF_PREC_INF: u64 = (BF_PREC_MAX + 1);
 
 const BF_CHKSUM_MOD: u64 = 975620677 * 9795002197;
 
@@ -133,6 +226,13 @@ precision 'prec'. For efficiency purposes, the final bf_round()
 does not need to be done in the function. */
 pub type ZivFunc =
     unsafe fn(_: *mut bf_t, _: *const bf_t, _: limb_t, _: *mut std::ffi::c_void) -> i32;
+/* same as ZivFunc for a complex result */
+pub type ZivComplexFunc = unsafe fn(
+    _: *mut bf_complex_t,
+    _: *const bf_complex_t,
+    _: limb_t,
+    _: *mut std::ffi::c_void,
+) -> i32;
 
 #[inline]
 unsafe fn clz64(mut a: u64) -> i32 {
@@ -179,7 +279,16 @@ pub unsafe fn bf_realloc(
     mut ptr: *mut std::ffi::c_void,
     mut size: usize,
 ) -> *mut std::ffi::c_void {
-    return (*s).realloc_func.expect("non-null function pointer")((*s).realloc_opaque, ptr, size);
+    let mut ret: *mut std::ffi::c_void =
+        (*s).realloc_func.expect("non-null function pointer")((*s).realloc_opaque, ptr, size);
+    if ret.is_null() && size != 0 {
+        AtomicUsize::from_ptr(&mut (*s).alloc_failures).fetch_add(1, Ordering::Relaxed);
+    }
+    return ret;
+}
+/* number of failed allocations of the context */
+unsafe fn bf_alloc_failures(mut s: *mut bf_context_t) -> usize {
+    return AtomicUsize::from_ptr(&mut (*s).alloc_failures).load(Ordering::Relaxed);
 }
 #[inline]
 unsafe fn bf_malloc(mut s: *mut bf_context_t, mut size: usize) -> *mut std::ffi::c_void {
@@ -259,10 +368,18 @@ pub unsafe fn bfdec_delete(mut r: *mut bfdec_t) {
     bf_delete(r as *mut bf_t);
 }
 #[inline]
+pub unsafe fn bfdec_is_finite(mut a: *const bfdec_t) -> i32 {
+    return ((*a).expn < BF_EXP_INF) as i32;
+}
+#[inline]
 pub unsafe fn bfdec_is_nan(mut a: *const bfdec_t) -> i32 {
     return ((*a).expn == 9223372036854775807) as i32;
 }
 #[inline]
+pub unsafe fn bfdec_is_zero(mut a: *const bfdec_t) -> i32 {
+    return ((*a).expn == BF_EXP_ZERO) as i32;
+}
+#[inline]
 pub unsafe fn bfdec_set_nan(mut r: *mut bfdec_t) {
     bf_set_nan(r as *mut bf_t);
 }
@@ -372,6 +489,69 @@ pub unsafe fn bf_context_init(
 pub unsafe fn bf_context_end(mut s: *mut bf_context_t) {
     bf_clear_cache(s);
 }
+/* Memory accounting allocator: 'opaque' points to a BFMemAccount,
+which may be shared by several contexts. The blocks are allocated
+with sized_realloc() so that their size is known when they are
+resized or freed. A request which would make the allocated size
+exceed the limit fails without modifying the block, so that the
+operation returns BF_ST_MEM_ERROR. The counters are atomic because
+a number can be freed by another thread than the one which
+allocated it. */
+pub struct BFMemAccount {
+    pub cur: AtomicUsize,
+    pub peak: AtomicUsize,
+    pub limit: AtomicUsize,
+}
+impl BFMemAccount {
+    pub const fn new(mut limit: usize) -> BFMemAccount {
+        BFMemAccount {
+            cur: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            limit: AtomicUsize::new(limit),
+        }
+    }
+}
+pub unsafe fn bf_account_realloc(
+    mut opaque: *mut std::ffi::c_void,
+    mut ptr: *mut std::ffi::c_void,
+    mut size: usize,
+) -> *mut std::ffi::c_void {
+    let mut a: &BFMemAccount = &*(opaque as *const BFMemAccount);
+    let mut old_size: usize = if ptr.is_null() {
+        0
+    } else {
+        sized_alloc_size(ptr)
+    };
+    if size <= old_size {
+        ptr = sized_realloc(0 as *mut std::ffi::c_void, ptr, size);
+        if !ptr.is_null() || size == 0 {
+            a.cur.fetch_sub(old_size - size, Ordering::Relaxed);
+        }
+        return ptr;
+    }
+    /* reserve the new bytes before the allocation */
+    let mut delta: usize = size - old_size;
+    let mut cur: usize = a.cur.load(Ordering::Relaxed);
+    loop {
+        if delta > a.limit.load(Ordering::Relaxed).saturating_sub(cur) {
+            return 0 as *mut std::ffi::c_void;
+        }
+        match a
+            .cur
+            .compare_exchange_weak(cur, cur + delta, Ordering::Relaxed, Ordering::Relaxed)
+        {
+            Ok(_) => break,
+            Err(c) => cur = c,
+        }
+    }
+    ptr = sized_realloc(0 as *mut std::ffi::c_void, ptr, size);
+    if ptr.is_null() {
+        a.cur.fetch_sub(delta, Ordering::Relaxed);
+    } else {
+        a.peak.fetch_max(cur + delta, Ordering::Relaxed);
+    }
+    return ptr;
+}
 pub unsafe fn bf_init(mut s: *mut bf_context_t, mut r: *mut bf_t) {
     (*r).ctx = s;
     (*r).sign = 0 as i32;
@@ -824,7 +1004,7 @@ unsafe fn __bf_round(
                 } /* cannot fail */
                 if i > 0 as i32 as i64 {
                     l = (l as u64).wrapping_sub(i as u64) as limb_t as limb_t;
-                    ((*r).tab as *mut u8).copy_from_nonoverlapping(
+                    ((*r).tab as *mut u8).copy_from(
                         (*r).tab.offset(i as isize) as *const u8,
                         (l as usize).wrapping_mul(std::mem::size_of::<limb_t>()),
                     );
@@ -2669,7 +2849,7 @@ pub unsafe fn bf_remquo(
     bf_delete(q);
     return ret;
 }
-static mut sqrt_table: [u16; 192] = [
+static sqrt_table: [u16; 192] = [
     128 as i32 as u16,
     128 as i32 as u16,
     129 as i32 as u16,
@@ -3224,7 +3404,7 @@ pub unsafe fn bf_sqrt(
     mut flags: bf_flags_t,
 ) -> i32 {
     let mut current_block: u64;
-    let mut s: *mut bf_context_t = (*a).ctx;
+    let mut s: *mut bf_context_t = (*r).ctx;
     let mut ret: i32 = 0;
     if r != a as *mut bf_t {
     } else {
@@ -3776,7 +3956,9 @@ pub unsafe fn bf_logic_xor(mut r: *mut bf_t, mut a: *const bf_t, mut b: *const b
 pub unsafe fn bf_logic_and(mut r: *mut bf_t, mut a: *const bf_t, mut b: *const bf_t) -> i32 {
     return bf_logic_op(r, a, b, 2 as i32);
 }
+/* 's' is the context of the temporary number */
 pub unsafe fn bf_get_float64(
+    mut s: *mut bf_context_t,
     mut a: *const bf_t,
     mut pres: *mut f64,
     mut rnd_mode: bf_rnd_t,
@@ -3798,8 +3980,12 @@ pub unsafe fn bf_get_float64(
             tab: 0 as *mut limb_t,
         };
         let mut b: *mut bf_t = &mut b_s;
-        bf_init((*a).ctx, b);
-        bf_set(b, a);
+        bf_init(s, b);
+        if bf_set(b, a) != 0 {
+            bf_delete(b);
+            *pres = f64::NAN;
+            return (1 as i32) << 5 as i32;
+        }
         if bf_is_finite(b) != 0 {
             ret = bf_round(
                 b,
@@ -4031,7 +4217,7 @@ pub unsafe fn bf_get_uint64(mut pres: *mut u64, mut a: *const bf_t) -> i32 {
     return ret;
 }
 /* base conversion from radix */
-static mut digits_per_limb_table: [u8; 35] = [
+static digits_per_limb_table: [u8; 35] = [
     64 as i32 as u8,
     40 as i32 as u8,
     32 as i32 as u8,
@@ -4220,6 +4406,10 @@ pub unsafe fn bf_mul_pow_radix(
     let mut extra_bits: slimb_t = 0;
     let mut prec1: slimb_t = 0;
     let mut ziv_extra_bits: slimb_t = 0;
+    let mut prec2: slimb_t = 0;
+    let mut e_min: slimb_t = 0;
+    let mut rnd_mode: bf_rnd_t = 0;
+    let mut is_rounded: i32 = 0;
     let mut B_s: bf_t = bf_t {
         ctx: 0 as *mut bf_context_t,
         sign: 0,
@@ -4315,13 +4505,34 @@ pub unsafe fn bf_mul_pow_radix(
             if ret & (1 as i32) << 5 as i32 != 0 {
                 break;
             }
+            /* the precision is smaller if the result is subnormal,
+            otherwise the ties at this precision would be rounded
+            twice */
+            prec2 = prec as slimb_t;
+            rnd_mode = (flags & 0x7 as i32 as u32) as bf_rnd_t;
+            is_rounded = 0 as i32;
+            if flags & ((1 as i32) << 3 as i32) as u32 != 0 && bf_is_finite(r) != 0 {
+                e_min = -(((1 as i32 as limb_t) << bf_get_exp_bits(flags) - 1 as i32) as slimb_t)
+                    + 3 as i32 as i64;
+                if (*r).expn < e_min {
+                    prec2 -= e_min - (*r).expn;
+                    if prec2 == -1
+                        && (rnd_mode as u32 == BF_RNDN as i32 as u32
+                            || rnd_mode as u32 == BF_RNDNA as i32 as u32)
+                    {
+                        /* 'r' is below half of the smallest subnormal
+                        number: the tie is the bit pattern 0.0111... */
+                        prec2 = 0 as i32 as slimb_t;
+                        rnd_mode = BF_RNDZ
+                    } else if prec2 < 0 {
+                        /* no representable number or tie near 'r' */
+                        is_rounded = 1 as i32
+                    }
+                }
+            }
             if ret & (1 as i32) << 4 as i32 != 0
-                && bf_can_round(
-                    r,
-                    prec as slimb_t,
-                    (flags & 0x7 as i32 as u32) as bf_rnd_t,
-                    prec1,
-                ) == 0
+                && is_rounded == 0
+                && bf_can_round(r, prec2, rnd_mode, prec1) == 0
                 && overflow == 0
             {
                 /* and more precision and retry */
@@ -4350,7 +4561,8 @@ unsafe fn to_digit(mut c: i32) -> i32 {
     };
 }
 /* add a limb at 'pos' and decrement pos. new space is created if
-needed. Return 0 if OK, -1 if memory error */
+needed. 'a' is the result of bf_atof or a temporary of its context.
+Return 0 if OK, -1 if memory error */
 unsafe fn bf_add_limb(mut a: *mut bf_t, mut ppos: *mut slimb_t, mut v: limb_t) -> i32 {
     let mut pos: slimb_t = 0;
     pos = *ppos;
@@ -4374,7 +4586,7 @@ unsafe fn bf_add_limb(mut a: *mut bf_t, mut ppos: *mut slimb_t, mut v: limb_t) -
         }
         (*a).tab = new_tab;
         d = new_size.wrapping_sub((*a).len);
-        ((*a).tab.offset(d as isize) as *mut u8).copy_from_nonoverlapping(
+        ((*a).tab.offset(d as isize) as *mut u8).copy_from(
             (*a).tab as *const u8,
             ((*a).len as usize).wrapping_mul(std::mem::size_of::<limb_t>()),
         );
@@ -4563,8 +4775,10 @@ unsafe fn bf_atof_internal(
                     shift = digits_per_limb
                 }
                 cur_limb = 0 as i32 as limb_t;
+                /* if the allocation fails, pos < 0 so that bf_add_limb()
+                retries it and reports the error */
                 bf_resize(a, 1 as i32 as limb_t);
-                pos = 0 as i32 as slimb_t;
+                pos = (*a).len as slimb_t - 1;
                 has_decpt = FALSE as i32;
                 digit_count = 0 as i32 as slimb_t;
                 int_len = digit_count;
@@ -4647,13 +4861,17 @@ unsafe fn bf_atof_internal(
                             _ => {
                                 /* reset the next limbs to zero (we prefer to reallocate in the
                                 renormalization) */
-                                ((*a).tab as *mut u8).write_bytes(
-                                    0,
-                                    (pos as usize + 1).wrapping_mul(std::mem::size_of::<limb_t>()),
-                                );
+                                if pos >= 0 {
+                                    ((*a).tab as *mut u8).write_bytes(
+                                        0,
+                                        (pos as usize + 1)
+                                            .wrapping_mul(std::mem::size_of::<limb_t>()),
+                                    );
+                                }
                                 if p == p_start {
                                     ret = 0 as i32;
-                                    if radix_bits == 0 {
+                                    /* 'a' is 'r' for the decimal numbers */
+                                    if a != r {
                                         bf_delete(a);
                                     }
                                     bf_set_nan(r);
@@ -4813,7 +5031,7 @@ unsafe fn bf_atof_internal(
                     11118440404757757489 => {}
                     _ => {
                         ret = (1 as i32) << 5 as i32;
-                        if radix_bits == 0 {
+                        if a != r {
                             bf_delete(a);
                         }
                         bf_set_nan(r);
@@ -4867,7 +5085,7 @@ pub unsafe fn bf_atof(
     );
 }
 /* base conversion to radix */
-static mut inv_log2_radix: [[u32; 3]; 35] = [
+static inv_log2_radix: [[u32; 3]; 35] = [
     [0x80000000 as u32, 0 as i32 as u32, 0 as i32 as u32],
     [
         0x50c24e60 as i32 as u32,
@@ -5032,7 +5250,7 @@ static mut inv_log2_radix: [[u32; 3]; 35] = [
         0x3fe16970 as i32 as u32,
     ],
 ];
-static mut log2_radix: [limb_t; 35] = [
+static log2_radix: [limb_t; 35] = [
     0x2000000000000000 as i64 as limb_t,
     0x32b803473f7ad0f4 as i64 as limb_t,
     0x4000000000000000 as i64 as limb_t,
@@ -5130,7 +5348,8 @@ pub unsafe fn bf_mul_log2_radix(
     }
     return a as slimb_t;
 }
-/* 'n' is the number of output limbs */
+/* 'n' is the number of output limbs. The temporary numbers are
+allocated in the context of 'pow_tab'. */
 unsafe fn bf_integer_to_radix_rec(
     mut pow_tab: *mut bf_t,
     mut out: *mut limb_t,
@@ -5196,8 +5415,8 @@ unsafe fn bf_integer_to_radix_rec(
         let mut B: *mut bf_t = 0 as *mut bf_t;
         let mut B_inv: *mut bf_t = 0 as *mut bf_t;
         let mut q_add: i32 = 0;
-        bf_init((*a).ctx, &mut Q);
-        bf_init((*a).ctx, &mut R);
+        bf_init((*pow_tab).ctx, &mut Q);
+        bf_init((*pow_tab).ctx, &mut R);
         n2 = (n0.wrapping_mul(2 as i32 as u64) >> level + 1 as i32)
             .wrapping_add(1 as i32 as u64)
             .wrapping_div(2 as i32 as u64);
@@ -5657,7 +5876,7 @@ unsafe fn output_digits(
         digits_per_limb = digits_per_limb_table[(radix - 2 as i32) as usize] as i32;
         radixl = get_limb_radix(radix);
         a = &mut a_s;
-        bf_init((*a1).ctx, a);
+        bf_init((*s).opaque as *mut bf_context_t, a);
         n = n_digits
             .wrapping_add(digits_per_limb as u64)
             .wrapping_sub(1 as i32 as u64)
@@ -5729,7 +5948,19 @@ unsafe fn bf_dbuf_realloc(
     return bf_realloc(s, ptr, size);
 }
 /* return the length in bytes. A trailing '\0' is added */
+/* the rounding flags to convert the absolute value of a number of sign
+'sign': the directed roundings are exchanged for a negative number */
+fn abs_rounding_flags(flags: bf_flags_t, sign: i32) -> bf_flags_t {
+    let rnd = (flags & 0x7) as bf_rnd_t;
+    if sign != 0 && (rnd == BF_RNDD || rnd == BF_RNDU) {
+        flags ^ (BF_RNDD ^ BF_RNDU) as bf_flags_t
+    } else {
+        flags
+    }
+}
+
 unsafe fn bf_ftoa_internal(
+    mut ctx: *mut bf_context_t,
     mut plen: *mut u64,
     mut a2: *const bf_t,
     mut radix: i32,
@@ -5746,7 +5977,6 @@ unsafe fn bf_ftoa_internal(
     };
     let mut a_0: *mut bf_t = 0 as *mut bf_t;
     let mut current_block: u64;
-    let mut ctx: *mut bf_context_t = (*a2).ctx;
     let mut s_s: DynBuf = DynBuf {
         buf: 0 as *mut u8,
         size: 0,
@@ -5932,6 +6162,7 @@ unsafe fn bf_ftoa_internal(
                     (*a).len = (*a2).len;
                     (*a).expn = (*a2).expn;
                     (*a).sign = 0 as i32;
+                    flags = abs_rounding_flags(flags, (*a2).sign);
                     /* one more digit for the rounding */
                     n = 1 as i32 as i64
                         + bf_mul_log2_radix(
@@ -5965,7 +6196,7 @@ unsafe fn bf_ftoa_internal(
                             pos = pos.wrapping_add(1)
                         }
                         if pos > start {
-                            ((*s).buf.offset(start as isize) as *mut u8).copy_from_nonoverlapping(
+                            ((*s).buf.offset(start as isize) as *mut u8).copy_from(
                                 (*s).buf.offset(pos as isize) as *const u8,
                                 (*s).size.wrapping_sub(pos) as usize,
                             );
@@ -6085,6 +6316,7 @@ unsafe fn bf_ftoa_internal(
                     (*a_0).len = (*a2).len;
                     (*a_0).expn = (*a2).expn;
                     (*a_0).sign = 0 as i32;
+                    flags = abs_rounding_flags(flags, (*a2).sign);
                     if fmt == (0 as i32) << 16 as i32 {
                         n_digits = prec as slimb_t;
                         n_max = n_digits;
@@ -6244,25 +6476,22 @@ unsafe fn bf_ftoa_internal(
                                     1 as i32 as limb_t,
                                     is_dec,
                                 );
+                                /* the allocation errors are checked with dbuf_error() */
                                 if radix_bits != 0 as i32 && radix <= 16 as i32 {
                                     let value = (n - 1 as i32 as i64) * radix_bits as i64;
                                     if flags & ((1 as i32) << 22 as i32) as u32 != 0 {
-                                        write!(&mut s_s, "p{:+}\0", value).unwrap();
+                                        let _ = write!(&mut s_s, "p{:+}", value);
                                     } else {
-                                        write!(&mut s_s, "p{}\0", value).unwrap();
+                                        let _ = write!(&mut s_s, "p{}", value);
                                     }
                                 } else {
-                                    let v1 = if radix <= 10 as i32 {
-                                        'e' as i32
-                                    } else {
-                                        '@' as i32
-                                    };
+                                    let v1 = if radix <= 10 as i32 { 'e' } else { '@' };
                                     let v2 = n - 1 as i32 as i64;
 
                                     if flags & ((1 as i32) << 22 as i32) as u32 != 0 {
-                                        write!(&mut s_s, "{}{:+}\x00", v1, v2).unwrap();
+                                        let _ = write!(&mut s_s, "{}{:+}", v1, v2);
                                     } else {
-                                        write!(&mut s_s, "{}{}\x00", v1, v2).unwrap();
+                                        let _ = write!(&mut s_s, "{}{}", v1, v2);
                                     }
                                 }
                             } else if n <= 0 as i32 as i64 {
@@ -6344,14 +6573,17 @@ unsafe fn bf_ftoa_internal(
     }
     return 0 as *mut std::os::raw::c_char;
 }
+/* The temporary numbers and the returned string are allocated in the
+context 's'. */
 pub unsafe fn bf_ftoa(
+    mut s: *mut bf_context_t,
     mut plen: *mut u64,
     mut a: *const bf_t,
     mut radix: i32,
     mut prec: limb_t,
     mut flags: bf_flags_t,
 ) -> *mut std::os::raw::c_char {
-    return bf_ftoa_internal(plen, a, radix, prec, flags, FALSE as i32);
+    return bf_ftoa_internal(s, plen, a, radix, prec, flags, FALSE as i32);
 }
 /* **************************************************************/
 /* transcendental functions */
@@ -6378,7 +6610,7 @@ unsafe fn bf_const_log2_rec(
                 .wrapping_mul(n1)
                 .wrapping_add(1 as i32 as u64),
         );
-        (*Q).expn += 2 as i32 as i64;
+        bf_mul_2exp(Q, 2, BF_PREC_INF, BF_RNDZ);
         bf_set(T, P);
     } else {
         let mut m: limb_t = 0;
@@ -6689,12 +6921,132 @@ unsafe fn bf_const_pi_internal(mut Q: *mut bf_t, mut prec: limb_t) {
     bf_delete(&mut P);
     bf_delete(&mut G);
 }
+/* Most precise value of a constant computed by any context. The
+contexts copy it instead of computing it again, so that the threads
+share the cost of the constants. */
+struct BFSharedConst {
+    prec: limb_t,
+    expn: slimb_t,
+    tab: Vec<limb_t>,
+}
+/* log2, pi and Euler's constant, allocated by the first thread which
+needs them */
+static shared_consts: AtomicPtr<[Mutex<BFSharedConst>; 3]> = AtomicPtr::new(0 as *mut _);
+fn get_shared_const(mut idx: usize) -> &'static Mutex<BFSharedConst> {
+    let mut p = shared_consts.load(Ordering::Acquire);
+    if p.is_null() {
+        let mut new = Box::into_raw(Box::new([
+            Mutex::new(BFSharedConst {
+                prec: 0,
+                expn: 0,
+                tab: Vec::new(),
+            }),
+            Mutex::new(BFSharedConst {
+                prec: 0,
+                expn: 0,
+                tab: Vec::new(),
+            }),
+            Mutex::new(BFSharedConst {
+                prec: 0,
+                expn: 0,
+                tab: Vec::new(),
+            }),
+        ]));
+        match shared_consts.compare_exchange(p, new, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => p = new,
+            Err(q) => {
+                /* another thread was faster */
+                drop(unsafe { Box::from_raw(new) });
+                p = q
+            }
+        }
+    }
+    return unsafe { &(*p)[idx] };
+}
+/* set 'c' to the value of the constant with at least 'prec1' bits,
+computing it only if no context did it before. The lock is not held
+during the computation: it may be long and the evaluation of the
+constant can itself use other shared constants. Several threads may
+then compute the same value, in which case the most precise result is
+kept. */
+unsafe fn bf_const_update(
+    mut c: *mut BFConstCache,
+    mut prec1: limb_t,
+    mut func: Option<unsafe fn(_: *mut bf_t, _: limb_t) -> ()>,
+    mut shared_idx: usize,
+) {
+    let mut v: *mut bf_t = &mut (*c).val;
+    {
+        let g = get_shared_const(shared_idx)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if g.prec >= prec1 {
+            if bf_resize(v, g.tab.len() as limb_t) != 0 {
+                bf_set_nan(v);
+            } else {
+                (*v).sign = 0;
+                (*v).expn = g.expn;
+                std::slice::from_raw_parts_mut((*v).tab, (*v).len as usize)
+                    .copy_from_slice(&g.tab);
+            }
+            (*c).prec = g.prec;
+            return;
+        }
+    }
+    /* With the parallel NTT, this thread may run other operations on
+    the same context while it waits for the jobs of a multiplication,
+    so the cache is only updated once the value is computed. */
+    let mut s: *mut bf_context_t = (*v).ctx;
+    let mut tmp_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut tmp: *mut bf_t = &mut tmp_s;
+    bf_init(s, tmp);
+    func.expect("non-null function pointer")(tmp, prec1);
+    if bf_is_finite(tmp) != 0 {
+        let mut g = get_shared_const(shared_idx)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if g.prec < prec1 {
+            g.prec = prec1;
+            g.expn = (*tmp).expn;
+            g.tab.clear();
+            g.tab
+                .extend_from_slice(std::slice::from_raw_parts((*tmp).tab, (*tmp).len as usize));
+        }
+    }
+    if (*c).prec < prec1 {
+        if (*v).ctx.is_null() {
+            /* the cache was cleared meanwhile */
+            bf_init(s, v);
+        }
+        bf_move(v, tmp);
+        (*c).prec = prec1;
+    } else {
+        bf_delete(tmp);
+    }
+}
+/* free the constants shared by the contexts */
+pub fn bf_clear_shared_cache() {
+    for idx in 0..3 {
+        let mut g = get_shared_const(idx)
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        g.prec = 0;
+        g.tab = Vec::new();
+    }
+}
 unsafe fn bf_const_get(
     mut T: *mut bf_t,
     mut prec: limb_t,
     mut flags: bf_flags_t,
     mut c: *mut BFConstCache,
     mut func: Option<unsafe fn(_: *mut bf_t, _: limb_t) -> ()>,
+    mut shared_idx: usize,
     mut sign: i32,
 ) -> i32 {
     let mut ziv_extra_bits: limb_t = 0;
@@ -6706,8 +7058,14 @@ unsafe fn bf_const_get(
             if (*c).val.len == 0 as i32 as u64 {
                 bf_init((*T).ctx, &mut (*c).val);
             }
-            func.expect("non-null function pointer")(&mut (*c).val, prec1);
-            (*c).prec = prec1
+            bf_const_update(c, prec1, func, shared_idx);
+            if bf_is_nan(&mut (*c).val) != 0 {
+                /* allocation error: retry at the next call */
+                (*c).prec = 0 as i32 as limb_t;
+                bf_set_nan(T);
+                return (1 as i32) << 5 as i32;
+            }
+            prec1 = (*c).prec
         } else {
             prec1 = (*c).prec
         }
@@ -6739,6 +7097,7 @@ pub unsafe fn bf_const_log2(mut T: *mut bf_t, mut prec: limb_t, mut flags: bf_fl
         flags,
         &mut (*s).log2_cache,
         Some(bf_const_log2_internal as unsafe fn(_: *mut bf_t, _: limb_t) -> ()),
+        0,
         0 as i32,
     );
 }
@@ -6756,6 +7115,7 @@ unsafe fn bf_const_pi_signed(
         flags,
         &mut (*s).pi_cache,
         Some(bf_const_pi_internal as unsafe fn(_: *mut bf_t, _: limb_t) -> ()),
+        1,
         sign,
     );
 }
@@ -6763,9 +7123,18 @@ pub unsafe fn bf_const_pi(mut T: *mut bf_t, mut prec: limb_t, mut flags: bf_flag
     return bf_const_pi_signed(T, 0 as i32, prec, flags);
 }
 pub unsafe fn bf_clear_cache(mut s: *mut bf_context_t) {
-    fft_clear_cache(s);
+    /* the NTT state is still used by the jobs of a multiplication for
+    which this thread is waiting */
+    #[cfg(feature = "parallel-ntt")]
+    let in_fft_mul = FFT_MUL_DEPTH.with(|d| d.get()) != 0;
+    #[cfg(not(feature = "parallel-ntt"))]
+    let in_fft_mul = false;
+    if !in_fft_mul {
+        fft_clear_cache(s);
+    }
     bf_const_free(&mut (*s).log2_cache);
     bf_const_free(&mut (*s).pi_cache);
+    bf_const_free(&mut (*s).euler_cache);
 }
 unsafe fn bf_ziv_rounding(
     mut r: *mut bf_t,
@@ -6788,7 +7157,14 @@ unsafe fn bf_ziv_rounding(
         ziv_extra_bits = 32 as i32 as slimb_t;
         loop {
             prec1 = prec.wrapping_add(ziv_extra_bits as u64) as slimb_t;
+            let mut alloc_failures: usize = bf_alloc_failures((*r).ctx);
             ret = f.expect("non-null function pointer")(r, a, prec1 as limb_t, opaque);
+            /* the functions do not always propagate the status of
+            their internal operations, so the allocation errors are
+            counted by the context */
+            if bf_alloc_failures((*r).ctx) != alloc_failures {
+                ret |= (1 as i32) << 5 as i32
+            }
             if ret & ((1 as i32) << 2 as i32 | (1 as i32) << 3 as i32 | (1 as i32) << 5 as i32) != 0
             {
                 //            printf("ziv_extra_bits=%" PRId64 "\n", (i64)ziv_extra_bits);
@@ -6834,8 +7210,12 @@ unsafe fn bf_add_epsilon(
     let mut T: *mut bf_t = &mut T_s;
     let mut ret: i32 = 0;
     /* small argument case: result = 1 + epsilon * sign(x) */
-    bf_init((*a).ctx, T);
-    bf_set_ui(T, 1 as i32 as u64);
+    bf_init((*r).ctx, T);
+    if bf_set_ui(T, 1 as i32 as u64) != 0 {
+        bf_delete(T);
+        bf_set_nan(r);
+        return (1 as i32) << 5 as i32;
+    }
     (*T).sign = e_sign;
     (*T).expn += e;
     ret = bf_add(r, r, T, prec, flags as bf_flags_t);
@@ -7174,7 +7554,11 @@ unsafe fn bf_log_internal(
         tab: 0 as *mut limb_t,
     };
     let mut U_0: *mut bf_t = &mut U_s_0;
-    bf_set(T, a);
+    if bf_set(T, a) != 0 {
+        bf_delete(T);
+        bf_set_nan(r);
+        return (1 as i32) << 5 as i32;
+    }
     n = (*T).expn;
     (*T).expn = 0 as i32 as slimb_t;
     /* U= ~ 2/3 */
@@ -7603,7 +7987,11 @@ pub unsafe fn bf_pow(
         return 0 as i32;
     }
     bf_init(s, T);
-    bf_set(T, x);
+    if bf_set(T, x) != 0 {
+        bf_delete(T);
+        bf_set_nan(r);
+        return (1 as i32) << 5 as i32;
+    }
     y_emin = bf_get_exp_min(y);
     y_is_int = (y_emin >= 0 as i32 as i64) as i32;
     rnd_mode = (flags & 0x7 as i32 as u32) as i32;
@@ -7652,11 +8040,16 @@ pub unsafe fn bf_pow(
         /* compute bounds of log(abs(x)) * y with a low precision */
         /* XXX: compute bf_log() once */
         /* XXX: add a fast test before this slow test */
-        bf_log(al, T, precl, BF_RNDD as i32 as bf_flags_t);
-        bf_log(ah, T, precl, BF_RNDU as i32 as bf_flags_t);
-        bf_mul(al, al, y, precl, (BF_RNDD as i32 ^ (*y).sign) as bf_flags_t);
-        bf_mul(ah, ah, y, precl, (BF_RNDU as i32 ^ (*y).sign) as bf_flags_t);
-        ret = check_exp_underflow_overflow(s, r, al, ah, prec, flags);
+        let mut ret1: i32 = bf_log(al, T, precl, BF_RNDD as i32 as bf_flags_t);
+        ret1 |= bf_log(ah, T, precl, BF_RNDU as i32 as bf_flags_t);
+        ret1 |= bf_mul(al, al, y, precl, (BF_RNDD as i32 ^ (*y).sign) as bf_flags_t);
+        ret1 |= bf_mul(ah, ah, y, precl, (BF_RNDU as i32 ^ (*y).sign) as bf_flags_t);
+        if ret1 & (1 as i32) << 5 as i32 != 0 {
+            bf_set_nan(r);
+            ret = (1 as i32) << 5 as i32
+        } else {
+            ret = check_exp_underflow_overflow(s, r, al, ah, prec, flags);
+        }
         bf_delete(al);
         bf_delete(ah);
         if !(ret != 0) {
@@ -7849,7 +8242,7 @@ unsafe fn bf_sincos(
     mut a: *const bf_t,
     mut prec: limb_t,
 ) -> i32 {
-    let mut s1: *mut bf_context_t = (*a).ctx;
+    let mut s1: *mut bf_context_t = if !s.is_null() { (*s).ctx } else { (*c).ctx };
     let mut T_s: bf_t = bf_t {
         ctx: 0 as *mut bf_context_t,
         sign: 0,
@@ -8730,55 +9123,4588 @@ pub unsafe fn bf_acos(
         TRUE as i32 as *mut std::ffi::c_void,
     );
 }
-#[inline]
-unsafe fn shld(mut a1: limb_t, mut a0: limb_t, mut shift: i64) -> limb_t {
-    if shift != 0 as i32 as i64 {
-        return a1 << shift | a0 >> ((1 as i32) << 6 as i32) as i64 - shift;
-    } else {
-        return a1;
+/* Return TRUE if f(a) = a + d with |d| < 2^e, d of constant sign, is
+rounded like a + 2^e (with the sign of d) at precision 'prec', i.e. if
+2^e is below the rounding bit and below the last bit of 'a'. */
+unsafe fn bf_is_small_correction(mut a: *const bf_t, mut e: slimb_t, mut prec: limb_t) -> BOOL {
+    return (e
+        < (*a).expn
+            - bf_max(
+                prec.wrapping_add(2) as slimb_t,
+                (*a).len.wrapping_mul(LIMB_BITS).wrapping_add(2) as slimb_t,
+            )) as BOOL;
+}
+/* e^|a|/2 is larger than the largest number if |a| > (e_max + 2) *
+log(2). Used by sinh() and cosh(). */
+unsafe fn check_hyperbolic_overflow(
+    mut s: *mut bf_context_t,
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut sign: i32,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut log2_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
     };
+    let mut log2: *mut bf_t = &mut log2_s;
+    let mut e_max: slimb_t = 0;
+    let mut res: i32 = 0;
+    if (*a).expn <= 0 {
+        return 0;
+    }
+    e_max = ((1 as limb_t) << (bf_get_exp_bits(flags) - 1)) as slimb_t;
+    bf_init(s, T);
+    bf_init(s, log2);
+    bf_const_log2(log2, LIMB_BITS, BF_RNDU);
+    bf_mul_ui(T, log2, (e_max + 2) as u64, LIMB_BITS, BF_RNDU);
+    res = bf_cmpu(a, T);
+    bf_delete(T);
+    bf_delete(log2);
+    if res > 0 {
+        return bf_set_overflow(r, sign, prec, flags);
+    }
+    return 0;
 }
-#[inline]
-unsafe fn fast_udiv(mut a: limb_t, mut s: *const FastDivData) -> limb_t {
-    let mut t0: limb_t = 0;
-    let mut t1: limb_t = 0;
-    let mut __t: u128 = 0;
-    __t = ((*s).m1 as u128).wrapping_mul(a as u128);
-    t0 = __t as limb_t;
-    t1 = (__t >> 64 as i32) as limb_t;
-    t0 = a.wrapping_sub(t1) >> (*s).shift1 as i32;
-    return t1.wrapping_add(t0) >> (*s).shift2 as i32;
+/* 'a' finite and non zero */
+unsafe fn bf_expm1_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut prec1: limb_t = 0;
+    /* compensate the cancellation in exp(a) - 1 for small 'a' */
+    prec1 = prec.wrapping_add(8);
+    if (*a).expn < 0 {
+        prec1 = prec1.wrapping_add(-(*a).expn as limb_t)
+    }
+    bf_init(s, T);
+    bf_exp(T, a, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add_si(r, T, -1, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    return BF_ST_INEXACT;
 }
-/* contains 10^i */
-static mp_pow_dec: [limb_t; 20] = [
-    1 as u32 as limb_t,
-    10 as u32 as limb_t,
-    100 as u32 as limb_t,
-    1000 as u32 as limb_t,
-    10000 as u32 as limb_t,
-    100000 as u32 as limb_t,
-    1000000 as u32 as limb_t,
-    10000000 as u32 as limb_t,
-    100000000 as u32 as limb_t,
-    1000000000 as u32 as limb_t,
-    10000000000 as u64,
-    100000000000 as u64,
-    1000000000000 as u64,
-    10000000000000 as u64,
-    100000000000000 as u64,
-    1000000000000000 as u64,
-    10000000000000000 as u64,
-    100000000000000000 as u64,
-    1000000000000000000 as u64,
-    10000000000000000000 as u64,
-];
-
-pub fn mp_pow_dec_get(index: usize) -> limb_t {
-    mp_pow_dec[index]
+/* exp(a) - 1 */
+pub unsafe fn bf_expm1(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut ret: i32 = 0;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            if (*a).sign != 0 {
+                bf_set_si(r, -1);
+            } else {
+                bf_set_inf(r, 0);
+            }
+        } else {
+            bf_set_zero(r, (*a).sign);
+        }
+        return 0;
+    }
+    if (*a).sign == 0 {
+        ret = check_exp_underflow_overflow(s, r, a, a, prec, flags);
+        if ret != 0 {
+            return ret;
+        }
+    }
+    /* small argument case: result = x + r(x) with 0 < r(x) < x^2 */
+    if bf_is_small_correction(a, 2 * (*a).expn, prec) != 0 {
+        bf_set(r, a);
+        return bf_add_epsilon(r, r, 2 * (*a).expn, 0, prec, flags as i32);
+    }
+    /* large negative argument case: result = -1 + exp(x) with
+    exp(x) < 2^-(prec + 2) */
+    if (*a).sign != 0 {
+        bf_init(s, T);
+        bf_set_ui(T, prec.wrapping_add(2));
+        res = bf_cmpu(a, T);
+        bf_delete(T);
+        if res >= 0 {
+            bf_set_si(r, -1);
+            return bf_add_epsilon(
+                r,
+                r,
+                prec.wrapping_add(2).wrapping_neg() as slimb_t,
+                0,
+                prec,
+                flags as i32,
+            );
+        }
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_expm1_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite, non zero and > -1 */
+unsafe fn bf_log1p_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut prec1: limb_t = 0;
+    /* keep the bits of 'a' in 1 + a when 'a' is small */
+    prec1 = prec.wrapping_add(8);
+    if (*a).expn < 0 {
+        prec1 = prec1.wrapping_add(-(*a).expn as limb_t)
+    }
+    bf_init(s, T);
+    bf_add_si(T, a, 1, prec1, BF_RNDN);
+    bf_log(r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    return BF_ST_INEXACT;
+}
+/* log(1 + a) */
+pub unsafe fn bf_log1p(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+            return 0;
+        } else if (*a).expn == BF_EXP_INF {
+            if (*a).sign != 0 {
+                bf_set_nan(r);
+                return BF_ST_INVALID_OP;
+            } else {
+                bf_set_inf(r, 0);
+                return 0;
+            }
+        } else {
+            bf_set_zero(r, (*a).sign);
+            return 0;
+        }
+    }
+    if (*a).sign != 0 {
+        bf_init(s, T);
+        bf_set_ui(T, 1);
+        res = bf_cmpu(a, T);
+        bf_delete(T);
+        if res > 0 {
+            bf_set_nan(r);
+            return BF_ST_INVALID_OP;
+        } else if res == 0 {
+            bf_set_inf(r, 1);
+            return 0;
+        }
+    }
+    /* small argument case: result = x - r(x) with 0 < r(x) < x^2 */
+    if bf_is_small_correction(a, 2 * (*a).expn, prec) != 0 {
+        bf_set(r, a);
+        return bf_add_epsilon(r, r, 2 * (*a).expn, 1, prec, flags as i32);
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_log1p_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite, > 0 and != 1. 'opaque' is the radix (2 or 10) */
+unsafe fn bf_log_radix_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut radix: limb_t = opaque as intptr_t as limb_t;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    prec1 = prec.wrapping_add(8);
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_log(T, a, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    if radix == 2 {
+        bf_const_log2(U, prec1, BF_RNDF);
+    } else {
+        bf_set_ui(r, radix);
+        bf_log(U, r, prec1, BF_RNDF);
+    }
+    bf_div(r, T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    return BF_ST_INEXACT;
+}
+/* log(a) / log(radix). The result is exact when 'a' is an integer power
+of 'radix'. */
+unsafe fn bf_log_radix(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut radix: limb_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut e: slimb_t = 0;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 || (*a).sign != 0 {
+        /* same special cases as log() */
+        return bf_log(r, a, prec, flags);
+    }
+    e = bf_get_exp_min(a);
+    if radix == 2 {
+        if e == (*a).expn - 1 {
+            bf_set_si(r, e);
+            return bf_round(r, prec, flags);
+        }
+    } else if e >= 0 && (((*a).expn - e) as f64 - e as f64 * 2.321928094887362).abs() <= 2.0 {
+        /* 10^e = 5^e * 2^e: the number of bits must match */
+        bf_init(s, T);
+        bf_init(s, U);
+        bf_set_ui(U, 5);
+        bf_pow_ui(T, U, e as limb_t, BF_PREC_INF, BF_RNDZ);
+        bf_mul_2exp(T, e, BF_PREC_INF, BF_RNDZ);
+        res = bf_cmp_eq(T, a);
+        bf_delete(T);
+        bf_delete(U);
+        if res != 0 {
+            bf_set_si(r, e);
+            return bf_round(r, prec, flags);
+        }
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_log_radix_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        radix as intptr_t as *mut std::ffi::c_void,
+    );
+}
+pub unsafe fn bf_log2(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    return bf_log_radix(r, a, 2, prec, flags);
+}
+pub unsafe fn bf_log10(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    return bf_log_radix(r, a, 10, prec, flags);
+}
+/* 'a' finite and non zero */
+unsafe fn bf_sinh_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut a_abs: bf_t = *a;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    /* sinh(|x|) = (E + E / (E + 1)) / 2 with E = expm1(|x|) to avoid
+    the cancellation for small |x| */
+    prec1 = prec.wrapping_add(8);
+    a_abs.sign = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_expm1(T, &a_abs, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add_si(U, T, 1, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_div(U, T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add(r, T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(r, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    (*r).sign = (*a).sign;
+    bf_delete(T);
+    bf_delete(U);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_sinh(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_inf(r, (*a).sign);
+        } else {
+            bf_set_zero(r, (*a).sign);
+        }
+        return 0;
+    }
+    /* small argument case: result = x + r(x) with r(x) = x^3/6 +
+    O(x^5). We assume |r(x)| < 2^(3*EXP(x)). */
+    let mut e: slimb_t = sat_add(2 * (*a).expn, (*a).expn);
+    if bf_is_small_correction(a, e, prec) != 0 {
+        bf_set(r, a);
+        return bf_add_epsilon(r, r, e, (*a).sign, prec, flags as i32);
+    }
+    ret = check_hyperbolic_overflow(s, r, a, (*a).sign, prec, flags);
+    if ret != 0 {
+        return ret;
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_sinh_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite and non zero */
+unsafe fn bf_cosh_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut a_abs: bf_t = *a;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    /* cosh(x) = (exp(|x|) + 1 / exp(|x|)) / 2 */
+    prec1 = prec.wrapping_add(8);
+    a_abs.sign = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_exp(T, &a_abs, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_set_ui(r, 1);
+    bf_div(U, r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add(r, T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(r, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_cosh(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_inf(r, 0);
+        } else {
+            bf_set_ui(r, 1);
+        }
+        return 0;
+    }
+    /* small argument case: result = 1 + r(x) with 0 < r(x) < x^2 */
+    if (*a).expn < 0 && -2 * (*a).expn >= prec.wrapping_add(2) as slimb_t {
+        bf_set_ui(r, 1);
+        return bf_add_epsilon(
+            r,
+            r,
+            prec.wrapping_add(2).wrapping_neg() as slimb_t,
+            0,
+            prec,
+            flags as i32,
+        );
+    }
+    ret = check_hyperbolic_overflow(s, r, a, 0, prec, flags);
+    if ret != 0 {
+        return ret;
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_cosh_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite, non zero and |a| < prec / 2 + 2 */
+unsafe fn bf_tanh_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    /* tanh(|x|) = E / (E + 2) with E = expm1(2 * |x|) */
+    prec1 = prec.wrapping_add(8);
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_set(U, a);
+    (*U).sign = 0;
+    bf_mul_2exp(U, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_expm1(T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add_si(U, T, 2, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_div(r, T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    (*r).sign = (*a).sign;
+    bf_delete(T);
+    bf_delete(U);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_tanh(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_si(r, if (*a).sign != 0 { -1 } else { 1 });
+        } else {
+            bf_set_zero(r, (*a).sign);
+        }
+        return 0;
+    }
+    /* small argument case: result = x - r(x) with r(x) = x^3/3 +
+    O(x^5). We assume |r(x)| < 2^(3*EXP(x)). */
+    let mut e: slimb_t = sat_add(2 * (*a).expn, (*a).expn);
+    if bf_is_small_correction(a, e, prec) != 0 {
+        bf_set(r, a);
+        return bf_add_epsilon(r, r, e, 1 - (*a).sign, prec, flags as i32);
+    }
+    /* large argument case: result = sign(x) * (1 - r(x)) with 0 < r(x) <
+    2 * exp(-2 * |x|) < 2^-(prec + 2) */
+    bf_init(s, T);
+    bf_set_ui(T, (prec / 2).wrapping_add(2));
+    res = bf_cmpu(a, T);
+    bf_delete(T);
+    if res >= 0 {
+        bf_set_si(r, if (*a).sign != 0 { -1 } else { 1 });
+        return bf_add_epsilon(
+            r,
+            r,
+            prec.wrapping_add(2).wrapping_neg() as slimb_t,
+            1 - (*a).sign,
+            prec,
+            flags as i32,
+        );
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_tanh_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* log(2 * |a|), used by asinh() and acosh() for large 'a' */
+unsafe fn bf_log_twice(mut r: *mut bf_t, mut a: *const bf_t, mut prec: limb_t) {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    bf_init(s, T);
+    bf_set(T, a);
+    (*T).sign = 0;
+    bf_mul_2exp(T, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_log(r, T, prec, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+}
+/* 'a' finite and non zero */
+unsafe fn bf_asinh_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    prec1 = prec.wrapping_add(8);
+    if 2 * ((*a).expn - 1) >= prec1.wrapping_add(2) as slimb_t {
+        /* asinh(|x|) = log(2 * |x|) + r(x) with 0 < r(x) < 1/(4 * x^2) */
+        bf_log_twice(r, a, prec1);
+    } else {
+        /* asinh(|x|) = log1p(|x| + x^2 / (1 + sqrt(1 + x^2))) */
+        bf_init(s, T);
+        bf_init(s, U);
+        bf_mul(T, a, a, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_add_si(U, T, 1, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_sqrt(r, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_add_si(U, r, 1, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_div(T, T, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_set(U, a);
+        (*U).sign = 0;
+        bf_add(U, U, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_log1p(r, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_delete(T);
+        bf_delete(U);
+    }
+    (*r).sign = (*a).sign;
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_asinh(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_inf(r, (*a).sign);
+        } else {
+            bf_set_zero(r, (*a).sign);
+        }
+        return 0;
+    }
+    /* small argument case: result = x - r(x) with r(x) = x^3/6 +
+    O(x^5). We assume |r(x)| < 2^(3*EXP(x)). */
+    let mut e: slimb_t = sat_add(2 * (*a).expn, (*a).expn);
+    if bf_is_small_correction(a, e, prec) != 0 {
+        bf_set(r, a);
+        return bf_add_epsilon(r, r, e, 1 - (*a).sign, prec, flags as i32);
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_asinh_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite and > 1 */
+unsafe fn bf_acosh_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    prec1 = prec.wrapping_add(8);
+    if 2 * ((*a).expn - 1) >= prec1.wrapping_add(2) as slimb_t {
+        /* acosh(x) = log(2 * x) - r(x) with 0 < r(x) < 1/(4 * x^2) */
+        bf_log_twice(r, a, prec1);
+    } else {
+        /* acosh(x) = log1p(t + sqrt(t * (t + 2))) with t = x - 1 to
+        avoid the cancellation when x is close to 1 */
+        bf_init(s, T);
+        bf_init(s, U);
+        bf_add_si(T, a, -1, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_add_si(U, T, 2, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_mul(U, U, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_sqrt(r, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_add(U, T, r, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_log1p(r, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_delete(T);
+        bf_delete(U);
+    }
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_acosh(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).expn == BF_EXP_NAN {
+        bf_set_nan(r);
+        return 0;
+    }
+    if (*a).sign != 0 || (*a).expn == BF_EXP_ZERO {
+        bf_set_nan(r);
+        return BF_ST_INVALID_OP;
+    }
+    if (*a).expn == BF_EXP_INF {
+        bf_set_inf(r, 0);
+        return 0;
+    }
+    bf_init(s, T);
+    if bf_set_ui(T, 1) != 0 {
+        bf_delete(T);
+        bf_set_nan(r);
+        return BF_ST_MEM_ERROR;
+    }
+    res = bf_cmpu(a, T);
+    bf_delete(T);
+    if res < 0 {
+        bf_set_nan(r);
+        return BF_ST_INVALID_OP;
+    } else if res == 0 {
+        bf_set_zero(r, 0);
+        return 0;
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_acosh_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite, non zero and |a| < 1 */
+unsafe fn bf_atanh_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut a_abs: bf_t = *a;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut prec1: limb_t = 0;
+    /* atanh(|x|) = log1p(2 * |x| / (1 - |x|)) / 2 */
+    prec1 = prec.wrapping_add(8);
+    a_abs.sign = 0;
+    bf_init(s, T);
+    bf_add_si(T, &a_abs, -1, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_neg(T);
+    bf_div(T, &a_abs, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(T, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_log1p(r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(r, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    (*r).sign = (*a).sign;
+    bf_delete(T);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_atanh(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+            return 0;
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_nan(r);
+            return BF_ST_INVALID_OP;
+        } else {
+            bf_set_zero(r, (*a).sign);
+            return 0;
+        }
+    }
+    bf_init(s, T);
+    bf_set_ui(T, 1);
+    res = bf_cmpu(a, T);
+    bf_delete(T);
+    if res > 0 {
+        bf_set_nan(r);
+        return BF_ST_INVALID_OP;
+    } else if res == 0 {
+        bf_set_inf(r, (*a).sign);
+        return 0;
+    }
+    /* small argument case: result = x + r(x) with r(x) = x^3/3 +
+    O(x^5). We assume |r(x)| < 2^(3*EXP(x)). */
+    let mut e: slimb_t = sat_add(2 * (*a).expn, (*a).expn);
+    if bf_is_small_correction(a, e, prec) != 0 {
+        bf_set(r, a);
+        return bf_add_epsilon(r, r, e, (*a).sign, prec, flags as i32);
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_atanh_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'a' finite and non zero */
+unsafe fn bf_cbrt_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut prec1: limb_t = 0;
+    let mut k: slimb_t = 0;
+    /* cbrt(|x|) = 2^k * exp(log(m) / 3) with |x| = 2^(3*k) * m and 1/2
+    <= m < 4 so that log(m) is small */
+    prec1 = prec.wrapping_add(8);
+    k = (*a).expn.div_euclid(3);
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_set(T, a);
+    (*T).sign = 0;
+    bf_mul_2exp(T, -(3 * k), BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_log(r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_set_ui(U, 3);
+    bf_div(T, r, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_exp(r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(r, k, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    (*r).sign = (*a).sign;
+    bf_delete(T);
+    bf_delete(U);
+    return BF_ST_INEXACT;
+}
+/* cube root. The result is exact when 'a' is the cube of a number with
+at most prec + 1 bits. */
+pub unsafe fn bf_cbrt(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut e: slimb_t = 0;
+    let mut m: slimb_t = 0;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_inf(r, (*a).sign);
+        } else {
+            bf_set_zero(r, (*a).sign);
+        }
+        return 0;
+    }
+    /* Ziv's loop does not terminate if the result is exact: a = M *
+    2^(3*k) with M odd must be the cube of a number with m bits. */
+    e = bf_get_exp_min(a);
+    m = ((*a).expn - e + 2) / 3;
+    if e.rem_euclid(3) == 0 && m as limb_t <= prec.wrapping_add(1) {
+        bf_init(s, T);
+        bf_init(s, U);
+        bf_cbrt_internal(T, a, (m + 8) as limb_t, 0 as *mut std::ffi::c_void);
+        bf_round(T, m as limb_t, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul(U, T, T, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_mul(U, U, T, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        res = bf_cmp_eq(U, a);
+        if res != 0 {
+            bf_set(r, T);
+        }
+        bf_delete(T);
+        bf_delete(U);
+        if res != 0 {
+            return bf_round(r, prec, flags);
+        }
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_cbrt_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 'x' and 'y' finite and non zero. sqrt(x^2 + y^2) is computed from
+the exact sum so the result is correctly rounded at precision
+'prec' and the status is 0 if it is exact. */
+unsafe fn bf_hypot_internal(
+    mut r: *mut bf_t,
+    mut x: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut y: *const bf_t = opaque as *const bf_t;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut ret: i32 = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    ret = bf_mul(T, x, x, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    ret |= bf_mul(U, y, y, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    ret |= bf_add(T, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    ret |= bf_sqrt(r, T, prec, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    return ret;
+}
+/* sqrt(x^2 + y^2) without intermediate overflow or underflow */
+pub unsafe fn bf_hypot(
+    mut r: *mut bf_t,
+    mut x: *const bf_t,
+    mut y: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut tmp: *const bf_t = 0 as *const bf_t;
+    let mut e: slimb_t = 0;
+    assert!(r as *const bf_t != x && r as *const bf_t != y);
+    if (*x).len == 0 || (*y).len == 0 {
+        if (*x).expn == BF_EXP_INF || (*y).expn == BF_EXP_INF {
+            bf_set_inf(r, 0);
+            return 0;
+        } else if (*x).expn == BF_EXP_NAN || (*y).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+            return 0;
+        }
+        /* one of the arguments is zero */
+        if (*x).expn == BF_EXP_ZERO {
+            bf_set(r, y);
+        } else {
+            bf_set(r, x);
+        }
+        (*r).sign = 0;
+        return bf_round(r, prec, flags);
+    }
+    if bf_cmpu(x, y) < 0 {
+        tmp = x;
+        x = y;
+        y = tmp
+    }
+    /* |y| much smaller than |x|: result = |x| + r(x, y) with 0 < r(x,
+    y) < y^2 / (2 * |x|) < 2^(2*EXP(y) - EXP(x)) */
+    e = sat_add(2 * (*y).expn, -(*x).expn);
+    if bf_is_small_correction(x, e, prec) != 0 {
+        bf_set(r, x);
+        (*r).sign = 0;
+        return bf_add_epsilon(r, r, e, 0, prec, flags as i32);
+    }
+    return bf_ziv_rounding(
+        r,
+        x,
+        prec,
+        flags,
+        Some(
+            bf_hypot_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        y as *mut std::ffi::c_void,
+    );
+}
+/* **************************************************************/
+/* special functions */
+/* r = a / b */
+unsafe fn bf_div_ui(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut b: u64,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut ret: i32 = 0;
+    bf_init((*r).ctx, T);
+    ret = bf_set_ui(T, b);
+    ret |= bf_div(r, a, T, prec, flags);
+    bf_delete(T);
+    return ret;
+}
+/* r = n1 * (n1 + 1) * ... * (n2 - 1), computed exactly by binary
+splitting */
+unsafe fn bf_prod_range(mut r: *mut bf_t, mut n1: limb_t, mut n2: limb_t) -> i32 {
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut ret: i32 = 0;
+    let mut i: limb_t = 0;
+    let mut m: limb_t = 0;
+    if n2.wrapping_sub(n1) <= 16 {
+        ret = bf_set_ui(r, 1);
+        i = n1;
+        while i < n2 {
+            ret |= bf_mul_ui(r, r, i, BF_PREC_INF, BF_RNDZ);
+            i += 1
+        }
+        return ret;
+    }
+    m = n1 + (n2 - n1) / 2;
+    bf_init((*r).ctx, T);
+    ret = bf_prod_range(r, n1, m);
+    ret |= bf_prod_range(T, m, n2);
+    ret |= bf_mul(r, r, T, BF_PREC_INF, BF_RNDZ);
+    bf_delete(T);
+    return ret;
+}
+/* return TRUE if a^2 >= n * log(2), i.e. if exp(-a^2) <= 2^-n */
+unsafe fn bf_sqr_ge_log2(mut s: *mut bf_context_t, mut a: *const bf_t, mut n: limb_t) -> BOOL {
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut res: BOOL = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_mul(T, a, a, LIMB_BITS, BF_RNDD | BF_FLAG_EXT_EXP);
+    bf_const_log2(U, LIMB_BITS, BF_RNDU);
+    bf_mul_ui(U, U, n, LIMB_BITS, BF_RNDU);
+    res = bf_cmp_le(U, T);
+    bf_delete(T);
+    bf_delete(U);
+    return res;
+}
+/* compute Euler's constant with faithful rounding at precision 'prec'
+using the algorithm B1 of Brent and McMillan: gamma = U / V - log(n)
+with an error < pi * exp(-4 * n), where V = sum(B_k), U = sum(B_k *
+H_k), B_k = (n^k / k!)^2 and H_k = 1 + 1/2 + ... + 1/k. */
+unsafe fn bf_const_euler_internal(mut T: *mut bf_t, mut prec: limb_t) {
+    let mut s: *mut bf_context_t = (*T).ctx;
+    let mut A_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut A: *mut bf_t = &mut A_s;
+    let mut B_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut B: *mut bf_t = &mut B_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut n: limb_t = 0;
+    let mut k: limb_t = 0;
+    let mut prec1: limb_t = 0;
+    /* 4 * n >= (prec + 4) * log(2) */
+    n = (prec + 4) * 45 / 256 + 2;
+    /* the terms are positive and there are less than 5 * n of them */
+    prec1 = prec + 2 * ceil_log2(5 * n) as limb_t + 16;
+    bf_init(s, A);
+    bf_init(s, B);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_set_zero(A, 0);
+    bf_set_ui(B, 1);
+    bf_set_zero(U, 0);
+    bf_set_ui(V, 1);
+    k = 1;
+    loop {
+        /* B_k = B_(k-1) * n^2 / k^2, A_k = B_k * H_k = (A_(k-1) * n^2 / k
+        + B_k) / k */
+        bf_mul_ui(B, B, n, prec1, BF_RNDN);
+        bf_mul_ui(B, B, n, prec1, BF_RNDN);
+        bf_div_ui(B, B, k, prec1, BF_RNDN);
+        bf_div_ui(B, B, k, prec1, BF_RNDN);
+        bf_mul_ui(A, A, n, prec1, BF_RNDN);
+        bf_mul_ui(A, A, n, prec1, BF_RNDN);
+        bf_div_ui(A, A, k, prec1, BF_RNDN);
+        bf_add(A, A, B, prec1, BF_RNDN);
+        bf_div_ui(A, A, k, prec1, BF_RNDN);
+        bf_add(U, U, A, prec1, BF_RNDN);
+        bf_add(V, V, B, prec1, BF_RNDN);
+        if bf_is_nan(B) != 0 {
+            break;
+        }
+        /* the next terms decrease faster than 2^-k */
+        if k > 2 * n && (*A).expn < (*V).expn - prec1 as slimb_t - 4 {
+            break;
+        }
+        k += 1
+    }
+    bf_div(T, U, V, prec1, BF_RNDN);
+    bf_set_ui(B, n);
+    bf_log(A, B, prec1, BF_RNDF);
+    bf_sub(T, T, A, prec, BF_RNDN);
+    bf_delete(A);
+    bf_delete(B);
+    bf_delete(U);
+    bf_delete(V);
+}
+pub unsafe fn bf_const_euler(mut T: *mut bf_t, mut prec: limb_t, mut flags: bf_flags_t) -> i32 {
+    let mut s: *mut bf_context_t = (*T).ctx;
+    return bf_const_get(
+        T,
+        prec,
+        flags,
+        &mut (*s).euler_cache,
+        Some(bf_const_euler_internal as unsafe fn(_: *mut bf_t, _: limb_t) -> ()),
+        2,
+        0,
+    );
+}
+/* return the coefficients B_2k / (2k * (2k - 1)) of the Stirling
+series for k = 1 ... n. They are computed from the tangent numbers T_k
+with the algorithm of Brent and Harvey. */
+unsafe fn bf_stirling_coefs(
+    mut s: *mut bf_context_t,
+    mut n: limb_t,
+    mut prec: limb_t,
+) -> Vec<bf_t> {
+    let mut tab: Vec<bf_t> = Vec::with_capacity(n as usize);
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut k: limb_t = 0;
+    let mut j: limb_t = 0;
+    let mut t: *mut bf_t = 0 as *mut bf_t;
+    for _ in 0..n {
+        tab.push(T_s);
+    }
+    t = tab.as_mut_ptr();
+    bf_init(s, T);
+    /* T_1 = 1, T_k = (k - 1) * T_(k-1) */
+    k = 0;
+    while k < n {
+        bf_init(s, t.offset(k as isize));
+        if k == 0 {
+            bf_set_ui(t, 1);
+        } else {
+            bf_mul_ui(
+                t.offset(k as isize),
+                t.offset(k as isize - 1),
+                k,
+                BF_PREC_INF,
+                BF_RNDZ,
+            );
+        }
+        k += 1
+    }
+    /* T_j = (j - k) * T_(j-1) + (j - k + 2) * T_j */
+    k = 2;
+    while k <= n {
+        j = k;
+        while j <= n {
+            bf_mul_ui(T, t.offset(j as isize - 2), j - k, BF_PREC_INF, BF_RNDZ);
+            bf_mul_ui(
+                t.offset(j as isize - 1),
+                t.offset(j as isize - 1),
+                j - k + 2,
+                BF_PREC_INF,
+                BF_RNDZ,
+            );
+            bf_add(
+                t.offset(j as isize - 1),
+                t.offset(j as isize - 1),
+                T,
+                BF_PREC_INF,
+                BF_RNDZ,
+            );
+            j += 1
+        }
+        k += 1
+    }
+    /* B_2k / (2k * (2k - 1)) = (-1)^(k-1) * T_k / ((2k - 1) * 4^k * (4^k - 1)) */
+    k = 1;
+    while k <= n {
+        bf_set_ui(T, 1);
+        bf_mul_2exp(T, 2 * k as slimb_t, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_add_si(T, T, -1, BF_PREC_INF, BF_RNDZ);
+        bf_mul_ui(T, T, 2 * k - 1, BF_PREC_INF, BF_RNDZ);
+        bf_mul_2exp(T, 2 * k as slimb_t, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_div(
+            t.offset(k as isize - 1),
+            t.offset(k as isize - 1),
+            T,
+            prec,
+            BF_RNDN,
+        );
+        (*t.offset(k as isize - 1)).sign = ((k - 1) & 1) as i32;
+        k += 1
+    }
+    bf_delete(T);
+    return tab;
+}
+/* number of bits of the integer part of |log(a)| for 'a' finite and
+non zero */
+unsafe fn bf_log_bits(mut a: *const bf_t) -> slimb_t {
+    /* |log(a)| < (|EXP(a)| + 1) * log(2) */
+    return ceil_log2(((*a).expn.wrapping_abs() as limb_t).wrapping_add(1)) as slimb_t;
+}
+/* log(|gamma(a)|) with an absolute error < 2^-prec for 'a' finite and
+not an integer <= 0. 'prec' is negative when only the most significant
+bits of a large result are needed. */
+unsafe fn bf_lgamma_abs(mut r: *mut bf_t, mut a: *const bf_t, mut prec: slimb_t) {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut Y_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut Y: *mut bf_t = &mut Y_s;
+    let mut p: limb_t = 0;
+    let mut n: slimb_t = 0;
+    let mut m: slimb_t = 0;
+    let mut e: slimb_t = 0;
+    let mut K: limb_t = 0;
+    let mut k: limb_t = 0;
+    let mut d: f64 = 0.;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_init(s, Y);
+    if (*a).expn <= bf_min(-prec - 1, -2) {
+        /* |a| < min(2^-(prec + 1), 1/4): log(|gamma(a)|) = -log(|a|) +
+        d with |d| < |a| */
+        bf_set(T, a);
+        (*T).sign = 0;
+        p = bf_max(prec + bf_log_bits(a) + 2, 16) as limb_t;
+        bf_log(r, T, p, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_neg(r);
+    } else if (*a).sign != 0 || (*a).expn < 0 {
+        /* a < 1/2: log(|gamma(a)|) = log(pi) - log(|sin(pi * a)|) -
+        log(gamma(1 - a)) */
+        bf_set(Y, a);
+        bf_neg(Y);
+        bf_add_si(Y, Y, 1, BF_PREC_INF, BF_RNDZ);
+        bf_lgamma_abs(r, Y, prec + 2);
+        bf_neg(r);
+        /* sin(pi * a) = +/-sin(pi * f) with f = a - round(a) computed
+        exactly, so that there is no cancellation near the poles */
+        bf_set(T, a);
+        bf_rint(T, BF_RNDN as i32);
+        bf_sub(U, a, T, BF_PREC_INF, BF_RNDZ);
+        p = bf_max(prec + 8, 16) as limb_t;
+        bf_const_pi(T, p, BF_RNDF);
+        bf_mul(T, T, U, p, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_sin(U, T, p, BF_RNDF | BF_FLAG_EXT_EXP);
+        (*U).sign = 0;
+        bf_log(
+            T,
+            U,
+            bf_max(prec + 6 + bf_log_bits(U), 16) as limb_t,
+            BF_RNDF | BF_FLAG_EXT_EXP,
+        );
+        bf_sub(r, r, T, BF_PREC_INF, BF_RNDZ);
+        bf_const_pi(T, p, BF_RNDF);
+        bf_log(U, T, p, BF_RNDF);
+        bf_add(r, r, U, BF_PREC_INF, BF_RNDZ);
+    } else {
+        /* shift the argument so that y = a + m >= n, where the Stirling
+        series gives the required precision with few terms */
+        n = bf_max(prec, 16);
+        bf_get_float64(s, a, &mut d, BF_RNDZ);
+        if d < n as f64 {
+            m = n - d as slimb_t;
+            bf_add_si(Y, a, m, BF_PREC_INF, BF_RNDZ);
+            /* log(gamma(a)) = log(gamma(y)) - log(a * (a + 1) * ... * (y - 1)) */
+            p = bf_max(prec + ceil_log2(m as limb_t) as slimb_t + 10, 16) as limb_t;
+            bf_set(V, a);
+            k = 1;
+            while k < m as limb_t {
+                bf_add_si(T, a, k as i64, BF_PREC_INF, BF_RNDZ);
+                bf_mul(V, V, T, p, BF_RNDN | BF_FLAG_EXT_EXP);
+                k += 1
+            }
+            bf_log(
+                T,
+                V,
+                bf_max(prec + 6 + bf_log_bits(V), 16) as limb_t,
+                BF_RNDF | BF_FLAG_EXT_EXP,
+            );
+            bf_set(V, T);
+        } else {
+            m = 0;
+            bf_set(Y, a);
+        }
+        if bf_is_nan(Y) != 0 {
+            /* memory error */
+            bf_set_nan(r);
+            bf_delete(T);
+            bf_delete(U);
+            bf_delete(V);
+            bf_delete(Y);
+            return;
+        }
+        /* log(gamma(y)) = (y - 1/2) * log(y) - y + log(2 * pi) / 2 +
+        sum(B_2k / (2k * (2k - 1) * y^(2k-1)), k = 1 ... K) + R with
+        |R| smaller than the first omitted term. (y - 1/2) * log(y) < 2^(EXP(y) + e). */
+        e = ceil_log2((*Y).expn as limb_t + 1) as slimb_t;
+        p = bf_max(prec + (*Y).expn + e + 10, 16) as limb_t;
+        bf_log(T, Y, p, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_set_si(U, -1);
+        bf_mul_2exp(U, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_add(U, Y, U, BF_PREC_INF, BF_RNDZ);
+        bf_mul(r, T, U, p, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_sub(r, r, Y, p, BF_RNDN | BF_FLAG_EXT_EXP);
+        p = bf_max(prec + 8, 16) as limb_t;
+        bf_const_pi(T, p, BF_RNDF);
+        bf_mul_2exp(T, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_log(U, T, p, BF_RNDF);
+        bf_mul_2exp(U, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_add(r, r, U, BF_PREC_INF, BF_RNDZ);
+        /* number of terms: |B_2k| < 4 * (2k)! / (2 * pi)^(2k) */
+        {
+            let mut ly: f64 = ((*Y).expn - 1) as f64;
+            let mut lf: f64 = 0.;
+            k = 1;
+            loop {
+                if 2.0 + lf - (2 * k) as f64 * 2.6514961294723187 - (2 * k - 1) as f64 * ly
+                    < -(prec + 4) as f64
+                {
+                    break;
+                }
+                lf += ((2 * k - 1) as f64 * (2 * k) as f64).log2();
+                k += 1
+            }
+            K = k - 1;
+        }
+        if K > 0 {
+            let mut tab: Vec<bf_t> = Vec::new();
+            let mut c: *mut bf_t = 0 as *mut bf_t;
+            p = bf_max(prec + 8 + 2 * ceil_log2(K + 1) as slimb_t, 16) as limb_t;
+            tab = bf_stirling_coefs(s, K, p);
+            /* T = 1 / y, U = 1 / y^2 */
+            bf_set_ui(U, 1);
+            bf_div(T, U, Y, p, BF_RNDN | BF_FLAG_EXT_EXP);
+            bf_mul(U, T, T, p, BF_RNDN | BF_FLAG_EXT_EXP);
+            bf_set_zero(Y, 0);
+            k = 0;
+            while k < K {
+                c = tab.as_mut_ptr().offset(k as isize);
+                bf_mul(c, c, T, p, BF_RNDN | BF_FLAG_EXT_EXP);
+                bf_add(Y, Y, c, p, BF_RNDN | BF_FLAG_EXT_EXP);
+                bf_mul(T, T, U, p, BF_RNDN | BF_FLAG_EXT_EXP);
+                k += 1
+            }
+            for t in tab.iter_mut() {
+                bf_delete(t);
+            }
+            bf_add(r, r, Y, BF_PREC_INF, BF_RNDZ);
+        }
+        if m > 0 {
+            bf_sub(r, r, V, BF_PREC_INF, BF_RNDZ);
+        }
+    }
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(V);
+    bf_delete(Y);
+}
+/* 'a' finite and not an integer <= 0. 'opaque' is NULL for gamma()
+and non NULL for lgamma(). */
+unsafe fn bf_gamma_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut ret: i32 = 0;
+    let mut w: slimb_t = 0;
+    let mut v: i64 = 0;
+    bf_init(s, T);
+    if opaque.is_null() {
+        /* gamma(a) = sign * exp(log(|gamma(a)|)): the absolute error
+        on the logarithm is the relative error on the result */
+        bf_lgamma_abs(T, a, prec as slimb_t + 6);
+        ret = bf_exp(r, T, prec + 6, BF_RNDF | BF_FLAG_EXT_EXP);
+        /* gamma(a) < 0 if floor(a) is negative and odd */
+        if (*a).sign != 0 {
+            bf_set(T, a);
+            bf_rint(T, BF_RNDD as i32);
+            bf_get_int64(&mut v, T, BF_GET_INT_MOD);
+            (*r).sign = (v & 1) as i32
+        }
+    } else {
+        /* the absolute error must be smaller than the result, which
+        can be close to zero near its roots */
+        w = prec as slimb_t + 8;
+        if (*a).sign == 0 && (*a).expn >= 5 {
+            /* log(gamma(a)) > a */
+            w -= (*a).expn - 1
+        }
+        loop {
+            bf_lgamma_abs(r, a, w);
+            if bf_is_nan(r) != 0 {
+                break;
+            }
+            if bf_is_zero(r) != 0 {
+                w = 2 * bf_max(w, prec as slimb_t)
+            } else if (*r).expn + w >= prec as slimb_t + 4 {
+                break;
+            } else {
+                w = prec as slimb_t - (*r).expn + 8
+            }
+        }
+    }
+    bf_delete(T);
+    return ret | BF_ST_INEXACT;
+}
+pub unsafe fn bf_gamma(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut ret: i32 = 0;
+    let mut n: i64 = 0;
+    let mut e: slimb_t = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            if (*a).sign != 0 {
+                bf_set_nan(r);
+                return BF_ST_INVALID_OP;
+            }
+            bf_set_inf(r, 0);
+        } else {
+            bf_set_inf(r, (*a).sign);
+            return BF_ST_DIVIDE_ZERO;
+        }
+        return 0;
+    }
+    if bf_get_exp_min(a) >= 0 {
+        /* integer */
+        if (*a).sign != 0 {
+            bf_set_nan(r);
+            return BF_ST_INVALID_OP;
+        }
+        /* gamma(n) = (n - 1)! is computed exactly when it may be
+        exactly representable or halfway at precision 'prec': (n - 1)!
+        / 2^v2((n - 1)!) has more than m * (log2(m) - 2.45) bits with m
+        = n - 1. */
+        if (*a).expn <= 62 {
+            bf_get_int64(&mut n, a, 0);
+            let mut m: f64 = (n - 1) as f64;
+            if n <= 65 || m * (m.log2() - 2.45) <= (prec + 2) as f64 {
+                ret = bf_prod_range(r, 1, n as limb_t);
+                if ret & BF_ST_MEM_ERROR != 0 {
+                    bf_set_nan(r);
+                    return ret;
+                }
+                return bf_round(r, prec, flags);
+            }
+        }
+    }
+    if (*a).expn >= 5 {
+        bf_init(s, T);
+        bf_init(s, U);
+        if (*a).sign == 0 {
+            /* log(gamma(a)) > a for a >= 16 */
+            ret = check_exp_underflow_overflow(s, r, a, a, prec, flags);
+        } else {
+            /* a <= -16: log(|gamma(a)|) = log(pi) - log(|sin(pi * f)|)
+            - log(gamma(1 - a)) < 2 - EXP(f) + a with f = a - round(a)
+            because |sin(pi * f)| >= 2 * |f| and log(gamma(1 - a)) > 1 -
+            a */
+            bf_set(T, a);
+            bf_rint(T, BF_RNDN as i32);
+            bf_sub(U, a, T, BF_PREC_INF, BF_RNDZ);
+            e = (*U).expn;
+            bf_add_si(T, a, bf_max(2 - e, 2), LIMB_BITS, BF_RNDU);
+            bf_set_inf(U, 1);
+            /* the result is negative if floor(a) is odd */
+            bf_set(r, a);
+            bf_rint(r, BF_RNDD as i32);
+            bf_get_int64(&mut n, r, BF_GET_INT_MOD);
+            if n & 1 != 0 {
+                /* round the absolute value in the opposite direction */
+                let mut rnd_mode: bf_rnd_t = (flags & BF_RND_MASK) as bf_rnd_t;
+                if rnd_mode == BF_RNDU {
+                    rnd_mode = BF_RNDD
+                } else if rnd_mode == BF_RNDD {
+                    rnd_mode = BF_RNDU
+                }
+                ret = check_exp_underflow_overflow(
+                    s,
+                    r,
+                    U,
+                    T,
+                    prec,
+                    (flags & !BF_RND_MASK) | rnd_mode as bf_flags_t,
+                );
+                (*r).sign = 1;
+            } else {
+                ret = check_exp_underflow_overflow(s, r, U, T, prec, flags);
+            }
+        }
+        bf_delete(T);
+        bf_delete(U);
+        if ret != 0 {
+            return ret;
+        }
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_gamma_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* log(|gamma(a)|) */
+pub unsafe fn bf_lgamma(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_inf(r, 0);
+        } else {
+            bf_set_inf(r, 0);
+            return BF_ST_DIVIDE_ZERO;
+        }
+        return 0;
+    }
+    if bf_get_exp_min(a) >= 0 {
+        /* integer */
+        if (*a).sign != 0 {
+            bf_set_inf(r, 0);
+            return BF_ST_DIVIDE_ZERO;
+        }
+        /* gamma(1) = gamma(2) = 1 */
+        if (*a).expn <= 2 && (*a).len == 1 && *(*a).tab << 1 == 0 {
+            bf_set_zero(r, 0);
+            return 0;
+        }
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_gamma_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        1 as *mut std::ffi::c_void,
+    );
+}
+/* erf(x) = 2 / sqrt(pi) * exp(-X) * sum(2^n * x^(2n+1) / (1 * 3 * ...
+* (2n+1)), n >= 0) for x >= 0 finite and X = x^2. The terms are positive
+so the relative error is < 2^-prec. */
+unsafe fn bf_erf_series(
+    mut r: *mut bf_t,
+    mut x: *const bf_t,
+    mut X: *const bf_t,
+    mut prec: limb_t,
+) {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut X2_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut X2: *mut bf_t = &mut X2_s;
+    let mut prec1: limb_t = 0;
+    let mut n: limb_t = 0;
+    let mut d: f64 = 0.;
+    bf_get_float64(s, X, &mut d, BF_RNDU);
+    /* the terms increase until n = X, then less than 2 * X + prec
+    terms are needed */
+    prec1 = prec + ceil_log2(prec + 3 * d as limb_t + 8) as limb_t + 8;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, X2);
+    bf_set(X2, X);
+    bf_mul_2exp(X2, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_set(T, x);
+    bf_set(r, x);
+    n = 0;
+    loop {
+        n += 1;
+        bf_mul(T, T, X2, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_div_ui(T, T, 2 * n + 1, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_add(r, r, T, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        if bf_is_nan(r) != 0 || bf_is_zero(T) != 0 {
+            break;
+        }
+        /* after n = 2 * X the remaining terms are smaller than 2 * T */
+        if n as f64 >= 2.0 * d && (*T).expn < (*r).expn - prec1 as slimb_t - 2 {
+            break;
+        }
+    }
+    bf_set(T, X);
+    bf_neg(T);
+    bf_exp(U, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul(r, r, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(r, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_const_pi(T, prec1, BF_RNDF);
+    bf_sqrt(U, T, prec1, BF_RNDF);
+    bf_div(r, r, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(X2);
+}
+/* erfc(x) = exp(-X) / (x * sqrt(pi)) * sum((-1)^n * 1 * 3 * ... * (2n -
+1) / (2 * X)^n, n >= 0) for x > 0 finite and X = x^2 >= (prec + 8) *
+log(2). The remainder is smaller than the first omitted term and the
+terms are smaller than 2^-(prec + 4) before they increase. */
+unsafe fn bf_erfc_asympt(
+    mut r: *mut bf_t,
+    mut x: *const bf_t,
+    mut X: *const bf_t,
+    mut prec: limb_t,
+) {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut X2_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut X2: *mut bf_t = &mut X2_s;
+    let mut prec1: limb_t = 0;
+    let mut n: limb_t = 0;
+    /* less than 2 * prec + 16 terms */
+    prec1 = prec + 2 * ceil_log2(2 * prec + 16) as limb_t + 8;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, X2);
+    bf_set(X2, X);
+    bf_mul_2exp(X2, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_set_ui(T, 1);
+    bf_set_ui(r, 1);
+    n = 0;
+    loop {
+        n += 1;
+        bf_mul_ui(T, T, 2 * n - 1, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_div(T, T, X2, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_neg(T);
+        bf_add(r, r, T, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        if bf_is_nan(r) != 0 || (*T).expn < -(prec as slimb_t) - 4 {
+            break;
+        }
+    }
+    bf_set(T, X);
+    bf_neg(T);
+    bf_exp(U, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_mul(r, r, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_const_pi(T, prec1, BF_RNDF);
+    bf_sqrt(U, T, prec1, BF_RNDF);
+    bf_mul(U, U, x, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_div(r, r, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(X2);
+}
+/* 'a' finite and non zero. 'opaque' is NULL for erf() and non NULL
+for erfc(). */
+unsafe fn bf_erf_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut x_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut x: *mut bf_t = &mut x_s;
+    let mut X_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut X: *mut bf_t = &mut X_s;
+    let mut is_erfc: BOOL = !opaque.is_null() as BOOL;
+    let mut use_asympt: BOOL = 0;
+    let mut d: f64 = 0.;
+    let mut c: limb_t = 0;
+    bf_init(s, T);
+    bf_init(s, x);
+    bf_init(s, X);
+    bf_set(x, a);
+    (*x).sign = 0;
+    bf_mul(X, x, x, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    use_asympt = bf_sqr_ge_log2(s, x, prec + 10);
+    if is_erfc == 0 || (*a).sign != 0 {
+        /* erf(|a|) */
+        if use_asympt != 0 {
+            bf_erfc_asympt(T, x, X, prec + 2);
+            bf_set_ui(r, 1);
+            bf_sub(r, r, T, BF_PREC_INF, BF_RNDZ);
+        } else {
+            bf_erf_series(r, x, X, prec + 2);
+        }
+        if is_erfc != 0 {
+            /* erfc(a) = 1 + erf(|a|) for a < 0 */
+            bf_add_si(r, r, 1, BF_PREC_INF, BF_RNDZ);
+        } else {
+            (*r).sign = (*a).sign
+        }
+    } else if use_asympt != 0 {
+        bf_erfc_asympt(r, x, X, prec + 2);
+    } else {
+        /* erfc(a) > 2 * exp(-X) / (sqrt(pi) * (a + sqrt(X + 2))) so 1 -
+        erf(a) cancels less than X * log2(e) + EXP(a) + 3 bits */
+        bf_get_float64(s, X, &mut d, BF_RNDU);
+        c = (d * 1.4427).ceil() as limb_t + bf_max((*a).expn, 0) as limb_t + 3;
+        bf_erf_series(T, x, X, prec + c + 2);
+        bf_set_ui(r, 1);
+        bf_sub(r, r, T, BF_PREC_INF, BF_RNDZ);
+    }
+    bf_delete(T);
+    bf_delete(x);
+    bf_delete(X);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_erf(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            bf_set_ui(r, 1);
+            (*r).sign = (*a).sign
+        } else {
+            bf_set_zero(r, (*a).sign);
+        }
+        return 0;
+    }
+    /* large argument case: result = sign(a) * (1 - erfc(|a|)) with 0 <
+    erfc(|a|) < exp(-a^2) <= 2^-(prec + 2) */
+    if bf_sqr_ge_log2(s, a, prec + 2) != 0 {
+        bf_set_ui(r, 1);
+        (*r).sign = (*a).sign;
+        return bf_add_epsilon(
+            r,
+            r,
+            prec.wrapping_add(2).wrapping_neg() as slimb_t,
+            (*a).sign ^ 1,
+            prec,
+            flags as i32,
+        );
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_erf_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* 1 - erf(a) */
+pub unsafe fn bf_erfc(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            if (*a).sign != 0 {
+                bf_set_ui(r, 2);
+            } else {
+                bf_set_zero(r, 0);
+            }
+        } else {
+            bf_set_ui(r, 1);
+        }
+        return 0;
+    }
+    /* small argument case: result = 1 - erf(a) with |erf(a)| < 2 * |a|
+    / sqrt(pi) < 2^(EXP(a) + 1) */
+    bf_set_ui(r, 1);
+    if bf_is_small_correction(r, (*a).expn + 1, prec) != 0 {
+        return bf_add_epsilon(r, r, (*a).expn + 1, (*a).sign ^ 1, prec, flags as i32);
+    }
+    if bf_sqr_ge_log2(s, a, prec + 2) != 0 {
+        if (*a).sign != 0 {
+            /* result = 2 - erfc(|a|) with 0 < erfc(|a|) < 2^-(prec + 2) */
+            bf_set_ui(r, 2);
+            return bf_add_epsilon(
+                r,
+                r,
+                prec.wrapping_add(2).wrapping_neg() as slimb_t,
+                1,
+                prec,
+                flags as i32,
+            );
+        }
+        /* erfc(a) < exp(-a^2) */
+        bf_init(s, T);
+        bf_mul(T, a, a, LIMB_BITS, BF_RNDD | BF_FLAG_EXT_EXP);
+        bf_neg(T);
+        ret = check_exp_underflow_overflow(s, r, T, T, prec, flags);
+        bf_delete(T);
+        if ret != 0 {
+            return ret;
+        }
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_erf_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        1 as *mut std::ffi::c_void,
+    );
+}
+/* zeta(a) with a relative error < 2^-prec for 'a' finite, >= 1/2 and
+!= 1 using the algorithm 2 of P. Borwein, "An efficient algorithm for
+the Riemann zeta function": zeta(a) = 1 / (d_n * (1 - 2^(1 - a))) *
+sum((-1)^k * (d_n - d_k) / (k + 1)^a, k = 0 ... n - 1) with an error <
+3 / ((3 + sqrt(8))^n * |1 - 2^(1 - a)|), d_k = sum(t_i, i = 0 ... k)
+and t_i = n * (n + i - 1)! * 4^i / ((n - i)! * (2i)!). */
+unsafe fn bf_zeta_borwein(mut r: *mut bf_t, mut a: *const bf_t, mut prec: limb_t) {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut E_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut E: *mut bf_t = &mut E_s;
+    let mut tab: Vec<bf_t> = Vec::new();
+    let mut t: *mut bf_t = 0 as *mut bf_t;
+    let mut pow_tab: Vec<bf_t> = Vec::new();
+    let mut pw: *mut bf_t = 0 as *mut bf_t;
+    let mut spf: Vec<limb_t> = Vec::new();
+    let mut n: limb_t = 0;
+    let mut i: limb_t = 0;
+    let mut j: limb_t = 0;
+    let mut k: limb_t = 0;
+    let mut bits: limb_t = 0;
+    let mut prec1: limb_t = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_init(s, E);
+    /* a > prec + 3: zeta(a) = 1 + d with 0 < d < 2^(1 - a) */
+    bf_set_ui(T, prec + 4);
+    if bf_cmp_le(T, a) != 0 {
+        bf_set_ui(r, 1);
+    } else {
+        /* |1 - 2^(1 - a)| > 2^(EXP(a - 1) - 3) for a <= 3/2 and > 1/4
+        otherwise */
+        bf_add_si(T, a, -1, BF_PREC_INF, BF_RNDZ);
+        bits = prec + 6 + bf_max(3 - (*T).expn, 2) as limb_t;
+        /* log2(3 + sqrt(8)) > 256 / 101 */
+        n = bits * 101 / 256 + 2;
+        prec1 = prec + 2 * ceil_log2(n) as limb_t + 10;
+        for _ in 0..=n {
+            tab.push(T_s);
+            pow_tab.push(T_s);
+        }
+        t = tab.as_mut_ptr();
+        pw = pow_tab.as_mut_ptr();
+        /* pw[j] = j^a. Only the powers of the primes are computed with
+        bf_pow(), the others are the product of the power of their
+        smallest prime factor p and of the power of j / p. */
+        spf.resize(n as usize + 1, 0);
+        i = 2;
+        while i <= n {
+            if spf[i as usize] == 0 {
+                j = i;
+                while j <= n {
+                    if spf[j as usize] == 0 {
+                        spf[j as usize] = i
+                    }
+                    j += i
+                }
+            }
+            i += 1
+        }
+        j = 1;
+        while j <= n {
+            bf_init(s, pw.offset(j as isize));
+            if j == 1 {
+                bf_set_ui(pw.offset(1), 1);
+            } else if spf[j as usize] == j {
+                bf_set_ui(U, j);
+                bf_pow(
+                    pw.offset(j as isize),
+                    U,
+                    a,
+                    prec1,
+                    BF_RNDF | BF_FLAG_EXT_EXP,
+                );
+            } else {
+                i = spf[j as usize];
+                bf_mul(
+                    pw.offset(j as isize),
+                    pw.offset(i as isize),
+                    pw.offset((j / i) as isize),
+                    prec1,
+                    BF_RNDN | BF_FLAG_EXT_EXP,
+                );
+            }
+            j += 1
+        }
+        /* t_0 = 1, t_i = t_(i-1) * 2 * (n + i - 1) * (n - i + 1) / (i * (2i - 1)) */
+        i = 0;
+        while i <= n {
+            bf_init(s, t.offset(i as isize));
+            if i == 0 {
+                bf_set_ui(t, 1);
+            } else {
+                bf_mul_ui(
+                    t.offset(i as isize),
+                    t.offset(i as isize - 1),
+                    2 * (n + i - 1),
+                    prec1,
+                    BF_RNDN | BF_FLAG_EXT_EXP,
+                );
+                bf_mul_ui(
+                    t.offset(i as isize),
+                    t.offset(i as isize),
+                    n - i + 1,
+                    prec1,
+                    BF_RNDN | BF_FLAG_EXT_EXP,
+                );
+                bf_div_ui(
+                    t.offset(i as isize),
+                    t.offset(i as isize),
+                    i,
+                    prec1,
+                    BF_RNDN | BF_FLAG_EXT_EXP,
+                );
+                bf_div_ui(
+                    t.offset(i as isize),
+                    t.offset(i as isize),
+                    2 * i - 1,
+                    prec1,
+                    BF_RNDN | BF_FLAG_EXT_EXP,
+                );
+            }
+            i += 1
+        }
+        /* E = d_n - d_k is a sum of positive terms */
+        bf_set_zero(E, 0);
+        bf_set_zero(r, 0);
+        k = n;
+        while k > 0 {
+            k -= 1;
+            bf_add(
+                E,
+                E,
+                t.offset(k as isize + 1),
+                prec1,
+                BF_RNDN | BF_FLAG_EXT_EXP,
+            );
+            bf_div(
+                U,
+                E,
+                pw.offset(k as isize + 1),
+                prec1,
+                BF_RNDN | BF_FLAG_EXT_EXP,
+            );
+            if k & 1 != 0 {
+                bf_neg(U);
+            }
+            bf_add(r, r, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        }
+        bf_add(E, E, t, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        for t in tab.iter_mut() {
+            bf_delete(t);
+        }
+        j = 1;
+        while j <= n {
+            bf_delete(pw.offset(j as isize));
+            j += 1
+        }
+        /* 1 - 2^(1 - a) = -expm1((1 - a) * log(2)) */
+        bf_const_log2(U, prec1, BF_RNDF);
+        bf_mul(U, U, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_neg(U);
+        bf_expm1(V, U, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_neg(V);
+        bf_mul(V, V, E, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_div(r, r, V, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    }
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(V);
+    bf_delete(E);
+}
+/* 'a' finite, not 0 or 1 and not a negative even integer */
+unsafe fn bf_zeta_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut Z_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut Z: *mut bf_t = &mut Z_s;
+    let mut ret: i32 = 0;
+    let mut p: limb_t = 0;
+    let mut v: i64 = 0;
+    if (*a).sign == 0 && (*a).expn >= 0 {
+        /* a >= 1/2 */
+        bf_zeta_borwein(r, a, prec + 2);
+        return BF_ST_INEXACT;
+    }
+    /* zeta(a) = 2^a * pi^(a - 1) * sin(pi * a / 2) * gamma(1 - a) *
+    zeta(1 - a) */
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_init(s, Z);
+    bf_set(U, a);
+    bf_neg(U);
+    bf_add_si(U, U, 1, BF_PREC_INF, BF_RNDZ);
+    bf_zeta_borwein(Z, U, prec + 4);
+    /* E = a * log(2) + (a - 1) * log(pi) + log(gamma(1 - a)) with an
+    absolute error < 2^-(prec + 4) */
+    bf_lgamma_abs(r, U, prec as slimb_t + 6);
+    p = (prec as slimb_t + bf_max((*a).expn, 0) + 14) as limb_t;
+    bf_const_log2(T, p, BF_RNDF);
+    bf_mul(T, T, a, p, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add(r, r, T, BF_PREC_INF, BF_RNDZ);
+    bf_const_pi(T, p, BF_RNDF);
+    bf_log(V, T, p, BF_RNDF);
+    bf_add_si(T, a, -1, BF_PREC_INF, BF_RNDZ);
+    bf_mul(V, V, T, p, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_add(r, r, V, BF_PREC_INF, BF_RNDZ);
+    bf_set(T, r);
+    ret = bf_exp(r, T, prec + 6, BF_RNDF | BF_FLAG_EXT_EXP);
+    /* sin(pi * a / 2) = (-1)^m * sin(pi * f) with a / 2 = m + f, m
+    integer and f computed exactly */
+    bf_set(T, a);
+    bf_mul_2exp(T, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    bf_set(U, T);
+    bf_rint(U, BF_RNDN as i32);
+    bf_get_int64(&mut v, U, BF_GET_INT_MOD);
+    bf_sub(T, T, U, BF_PREC_INF, BF_RNDZ);
+    p = prec + 8;
+    bf_const_pi(U, p, BF_RNDF);
+    bf_mul(U, U, T, p, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_sin(V, U, p, BF_RNDF | BF_FLAG_EXT_EXP);
+    if v & 1 != 0 {
+        bf_neg(V);
+    }
+    bf_mul(r, r, V, prec + 6, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_mul(r, r, Z, prec + 6, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(V);
+    bf_delete(Z);
+    return ret | BF_ST_INEXACT;
+}
+/* Riemann zeta function */
+pub unsafe fn bf_zeta(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut res: i32 = 0;
+    assert!(r as *const bf_t != a);
+    if (*a).len == 0 {
+        if (*a).expn == BF_EXP_NAN {
+            bf_set_nan(r);
+        } else if (*a).expn == BF_EXP_INF {
+            if (*a).sign != 0 {
+                bf_set_nan(r);
+                return BF_ST_INVALID_OP;
+            }
+            bf_set_ui(r, 1);
+        } else {
+            /* zeta(0) = -1/2 */
+            bf_set_si(r, -1);
+            bf_mul_2exp(r, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        }
+        return 0;
+    }
+    if bf_get_exp_min(a) >= 0 {
+        /* integer */
+        if (*a).sign == 0 && (*a).expn == 1 {
+            /* pole at 1 */
+            bf_set_inf(r, 0);
+            return BF_ST_DIVIDE_ZERO;
+        }
+        if (*a).sign != 0 && bf_get_exp_min(a) >= 1 {
+            /* trivial zeros at the negative even integers */
+            bf_set_zero(r, 0);
+            return 0;
+        }
+    }
+    if (*a).sign == 0 {
+        /* large argument case: result = 1 + d with 0 < d < 2^(1 - a) <
+        2^-(prec + 2) */
+        bf_init(s, T);
+        bf_set_ui(T, prec.wrapping_add(3));
+        res = bf_cmp_lt(T, a);
+        bf_delete(T);
+        if res != 0 {
+            bf_set_ui(r, 1);
+            return bf_add_epsilon(
+                r,
+                r,
+                prec.wrapping_add(2).wrapping_neg() as slimb_t,
+                0,
+                prec,
+                flags as i32,
+            );
+        }
+    }
+    /* small argument case: result = -1/2 + d with |d| < log(2 * pi) / 2
+     * |a| < 2^EXP(a), d of the sign of -a */
+    bf_set_si(r, -1);
+    bf_mul_2exp(r, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    if bf_is_small_correction(r, (*a).expn, prec) != 0 {
+        return bf_add_epsilon(r, r, (*a).expn, (*a).sign ^ 1, prec, flags as i32);
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(
+            bf_zeta_internal
+                as unsafe fn(
+                    _: *mut bf_t,
+                    _: *const bf_t,
+                    _: limb_t,
+                    _: *mut std::ffi::c_void,
+                ) -> i32,
+        ),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* **************************************************************/
+/* complex numbers */
+
+pub unsafe fn bf_complex_init(mut s: *mut bf_context_t, mut r: *mut bf_complex_t) {
+    bf_init(s, &mut (*r).re);
+    bf_init(s, &mut (*r).im);
+}
+pub unsafe fn bf_complex_delete(mut r: *mut bf_complex_t) {
+    bf_delete(&mut (*r).re);
+    bf_delete(&mut (*r).im);
+}
+pub unsafe fn bf_complex_set(mut r: *mut bf_complex_t, mut a: *const bf_complex_t) -> i32 {
+    let mut ret: i32 = 0;
+    ret = bf_set(&mut (*r).re, &(*a).re);
+    ret |= bf_set(&mut (*r).im, &(*a).im);
+    return ret;
+}
+unsafe fn bf_complex_set_nan(mut r: *mut bf_complex_t) {
+    bf_set_nan(&mut (*r).re);
+    bf_set_nan(&mut (*r).im);
+}
+unsafe fn bf_complex_is_finite(mut a: *const bf_complex_t) -> BOOL {
+    return (bf_is_finite(&(*a).re) != 0 && bf_is_finite(&(*a).im) != 0) as BOOL;
+}
+unsafe fn bf_complex_is_zero(mut a: *const bf_complex_t) -> BOOL {
+    return (bf_is_zero(&(*a).re) != 0 && bf_is_zero(&(*a).im) != 0) as BOOL;
+}
+/* change the directed rounding mode if the result is negative */
+unsafe fn bf_sign_rnd_flags(mut flags: bf_flags_t, mut sign: i32) -> bf_flags_t {
+    let mut rnd_mode: bf_rnd_t = flags & BF_RND_MASK;
+    if sign != 0 && (rnd_mode == BF_RNDD || rnd_mode == BF_RNDU) {
+        flags ^= 1
+    }
+    return flags;
+}
+/* Same as bf_ziv_rounding() for the two components of a complex
+result: 'f' must compute each component c with an error lower than
+2^(EXP(c) - prec) and a zero component must be exact. 'a' must be
+finite. */
+unsafe fn bf_complex_ziv_rounding(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+    mut f: Option<ZivComplexFunc>,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut rnd_mode: bf_rnd_t = flags & BF_RND_MASK;
+    let mut ret: i32 = 0;
+    let mut prec1: limb_t = 0;
+    let mut ziv_extra_bits: limb_t = 0;
+    if rnd_mode == BF_RNDF {
+        /* no need to iterate */
+        f.expect("non-null function pointer")(r, a, prec, opaque);
+        ret = 0
+    } else {
+        ziv_extra_bits = 32;
+        loop {
+            prec1 = prec + ziv_extra_bits;
+            let mut alloc_failures: usize = bf_alloc_failures((*r).re.ctx);
+            ret = f.expect("non-null function pointer")(r, a, prec1, opaque);
+            if bf_alloc_failures((*r).re.ctx) != alloc_failures {
+                ret |= BF_ST_MEM_ERROR
+            }
+            if ret & (BF_ST_OVERFLOW | BF_ST_UNDERFLOW | BF_ST_MEM_ERROR) != 0 {
+                return ret;
+            }
+            if ret & BF_ST_INEXACT == 0 {
+                ret = 0;
+                break;
+            } else if (bf_is_zero(&(*r).re) != 0
+                || bf_can_round(&(*r).re, prec as slimb_t, rnd_mode, prec1 as slimb_t) != 0)
+                && (bf_is_zero(&(*r).im) != 0
+                    || bf_can_round(&(*r).im, prec as slimb_t, rnd_mode, prec1 as slimb_t) != 0)
+            {
+                ret = BF_ST_INEXACT;
+                break;
+            } else {
+                ziv_extra_bits = ziv_extra_bits * 2
+            }
+        }
+    }
+    let mut ret2: i32 = ret;
+    if (*r).re.len != 0 {
+        ret2 |= __bf_round(&mut (*r).re, prec, flags, (*r).re.len, ret);
+    }
+    if (*r).im.len != 0 {
+        ret2 |= __bf_round(&mut (*r).im, prec, flags, (*r).im.len, ret);
+    }
+    return ret2;
+}
+pub unsafe fn bf_complex_add(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut b: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut ret: i32 = 0;
+    ret = bf_add(&mut (*r).re, &(*a).re, &(*b).re, prec, flags);
+    ret |= bf_add(&mut (*r).im, &(*a).im, &(*b).im, prec, flags);
+    return ret;
+}
+pub unsafe fn bf_complex_sub(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut b: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut ret: i32 = 0;
+    ret = bf_sub(&mut (*r).re, &(*a).re, &(*b).re, prec, flags);
+    ret |= bf_sub(&mut (*r).im, &(*a).im, &(*b).im, prec, flags);
+    return ret;
+}
+/* (x + yi)(u + vi) = (xu - yv) + (xv + yu)i. The products are exact so
+that each component is rounded once. */
+pub unsafe fn bf_complex_mul(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut b: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut W_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut W: *mut bf_t = &mut W_s;
+    let mut ret: i32 = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_init(s, W);
+    ret = bf_mul(
+        T,
+        &(*a).re,
+        &(*b).re,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_mul(
+        U,
+        &(*a).im,
+        &(*b).im,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_mul(
+        V,
+        &(*a).re,
+        &(*b).im,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_mul(
+        W,
+        &(*a).im,
+        &(*b).re,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_sub(&mut (*r).re, T, U, prec, flags);
+    ret |= bf_add(&mut (*r).im, V, W, prec, flags);
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(V);
+    bf_delete(W);
+    return ret;
+}
+/* (x + yi) / (u + vi) = ((xu + yv) + (yu - xv)i) / (u^2 + v^2). The
+numerators and the denominator are exact so that each component is
+rounded once. The result is NaN if 'b' is zero. */
+pub unsafe fn bf_complex_div(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut b: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut W_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut W: *mut bf_t = &mut W_s;
+    let mut ret: i32 = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_init(s, W);
+    ret = bf_mul(
+        T,
+        &(*b).re,
+        &(*b).re,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_mul(
+        U,
+        &(*b).im,
+        &(*b).im,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_add(W, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    ret |= bf_mul(
+        T,
+        &(*a).re,
+        &(*b).re,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_mul(
+        U,
+        &(*a).im,
+        &(*b).im,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_add(V, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    ret |= bf_mul(
+        T,
+        &(*a).im,
+        &(*b).re,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_mul(
+        U,
+        &(*a).re,
+        &(*b).im,
+        BF_PREC_INF,
+        BF_RNDZ | BF_FLAG_EXT_EXP,
+    );
+    ret |= bf_sub(T, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    if bf_is_zero(W) != 0 {
+        bf_complex_set_nan(r);
+        ret = BF_ST_INVALID_OP
+    } else {
+        ret |= bf_div(&mut (*r).re, V, W, prec, flags);
+        ret |= bf_div(&mut (*r).im, T, W, prec, flags);
+    }
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(V);
+    bf_delete(W);
+    return ret;
+}
+/* |a| */
+pub unsafe fn bf_complex_abs(
+    mut r: *mut bf_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    return bf_hypot(r, &(*a).re, &(*a).im, prec, flags);
+}
+/* argument of 'a' in [-pi, pi] */
+pub unsafe fn bf_complex_arg(
+    mut r: *mut bf_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    return bf_atan2(r, &(*a).im, &(*a).re, prec, flags);
+}
+/* r = exp(a) with the extended exponent range. When it is out of this
+range, r is set to a power of two which overflows or underflows with
+any exponent range of the final result. */
+unsafe fn bf_exp_ext(mut r: *mut bf_t, mut a: *const bf_t, mut prec: limb_t) -> i32 {
+    if (*a).len != 0 && (*a).expn > 60 {
+        bf_set_ui(r, 1);
+        (*r).expn = if (*a).sign != 0 { -(3 << 59) } else { 3 << 59 };
+        return BF_ST_INEXACT;
+    }
+    return bf_exp(r, a, prec, BF_RNDF | BF_FLAG_EXT_EXP);
+}
+/* sh = sinh(a) and ch = cosh(a) with a relative error of a few ulps
+and the extended exponent range */
+unsafe fn bf_sinh_cosh_ext(
+    mut sh: *mut bf_t,
+    mut ch: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+) {
+    let mut s: *mut bf_context_t = (*sh).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_set(T, a);
+    (*T).sign = 0;
+    if (*T).len != 0 && (*T).expn > 60 {
+        /* sinh(|a|) = cosh(|a|) = exp(|a|) / 2 out of the exponent
+        range */
+        bf_exp_ext(ch, T, prec);
+        bf_set(sh, ch);
+    } else {
+        /* U = exp(|a|) - 1 and T = U / (U + 1) = 1 - exp(-|a|) */
+        bf_expm1(U, T, prec, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_add_si(T, U, 1, prec, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_div(T, U, T, prec, BF_RNDN | BF_FLAG_EXT_EXP);
+        /* sinh(|a|) = (U + T) / 2 and cosh(|a|) = 1 + U * T / 2 */
+        bf_add(sh, U, T, prec, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul_2exp(sh, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_mul(T, U, T, prec, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul_2exp(T, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_add_si(ch, T, 1, prec, BF_RNDN | BF_FLAG_EXT_EXP);
+    }
+    (*sh).sign = (*a).sign;
+    bf_delete(T);
+    bf_delete(U);
+}
+/* exp(x + yi) = exp(x) * (cos(y) + sin(y)i) with y != 0 */
+unsafe fn bf_complex_exp_internal(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut E_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut E: *mut bf_t = &mut E_s;
+    let mut S_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut S: *mut bf_t = &mut S_s;
+    let mut C_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut C: *mut bf_t = &mut C_s;
+    let mut prec1: limb_t = prec + 8;
+    bf_init(s, E);
+    bf_init(s, S);
+    bf_init(s, C);
+    bf_exp_ext(E, &(*a).re, prec1);
+    bf_sincos(S, C, &(*a).im, prec1);
+    bf_mul(&mut (*r).re, E, C, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_mul(&mut (*r).im, E, S, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_delete(E);
+    bf_delete(S);
+    bf_delete(C);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_complex_exp(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_complex_t != a);
+    if bf_is_zero(&(*a).im) != 0 {
+        /* exp(x + 0i) = exp(x) + 0i */
+        bf_set(&mut (*r).im, &(*a).im);
+        return bf_exp(&mut (*r).re, &(*a).re, prec, flags);
+    }
+    if bf_complex_is_finite(a) == 0 {
+        bf_init(s, T);
+        bf_init(s, U);
+        ret = bf_exp(T, &(*a).re, prec, flags);
+        ret |= bf_cos(U, &(*a).im, prec, flags);
+        ret |= bf_mul(&mut (*r).re, T, U, prec, flags);
+        ret |= bf_sin(U, &(*a).im, prec, flags);
+        ret |= bf_mul(&mut (*r).im, T, U, prec, flags);
+        bf_delete(T);
+        bf_delete(U);
+        return ret;
+    }
+    return bf_complex_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(bf_complex_exp_internal as ZivComplexFunc),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* log(|x + yi|) with x = 'a' and y = 'opaque', a being non zero. Near
+|x + yi| = 1, it is computed as log1p(M^2 - 1 + m^2) / 2 where M and
+m are the largest and the smallest of |x| and |y|, the argument of
+log1p() being rounded once. */
+unsafe fn bf_complex_log_abs_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut b: *const bf_t = opaque as *const bf_t;
+    let mut M: *const bf_t = 0 as *const bf_t;
+    let mut m: *const bf_t = 0 as *const bf_t;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut V_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut V: *mut bf_t = &mut V_s;
+    let mut prec1: limb_t = prec + 8;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, V);
+    bf_hypot(T, a, b, 16, BF_RNDN | BF_FLAG_EXT_EXP);
+    if (*T).expn == 0 || (*T).expn == 1 {
+        /* 1/2 <= |x + yi| < 2 approximately */
+        if bf_cmpu(a, b) >= 0 {
+            M = a;
+            m = b
+        } else {
+            M = b;
+            m = a
+        }
+        bf_set(U, M);
+        (*U).sign = 0;
+        bf_add_si(V, U, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_add_si(U, U, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_mul(T, U, V, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_mul(U, m, m, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_add(V, T, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        if bf_is_zero(V) != 0 {
+            /* |x + yi| = 1 */
+            bf_set_zero(r, 0);
+            bf_delete(T);
+            bf_delete(U);
+            bf_delete(V);
+            return 0;
+        }
+        bf_log1p(r, V, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_mul_2exp(r, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    } else {
+        bf_hypot(T, a, b, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+        bf_log(r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    }
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(V);
+    return BF_ST_INEXACT;
+}
+/* principal value of the logarithm: log(a) = log(|a|) + arg(a)i */
+pub unsafe fn bf_complex_log(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_complex_t != a);
+    if bf_complex_is_zero(a) != 0 {
+        bf_set_inf(&mut (*r).re, 1);
+        ret = BF_ST_DIVIDE_ZERO
+    } else if bf_complex_is_finite(a) == 0 {
+        /* +Infinity, or NaN if a component is NaN and the other is
+        finite */
+        bf_hypot(&mut (*r).re, &(*a).re, &(*a).im, prec, flags);
+    } else {
+        ret = bf_ziv_rounding(
+            &mut (*r).re,
+            &(*a).re,
+            prec,
+            flags,
+            Some(bf_complex_log_abs_internal as ZivFunc),
+            &(*a).im as *const bf_t as *mut std::ffi::c_void,
+        )
+    }
+    ret |= bf_atan2(&mut (*r).im, &(*a).im, &(*a).re, prec, flags);
+    return ret;
+}
+/* sqrt(x + yi) with y != 0. t = sqrt((|x + yi| + |x|) / 2) has no
+cancellation and the other component is |y| / (2 * t). */
+unsafe fn bf_complex_sqrt_internal(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut ret: i32 = 0;
+    let mut prec1: limb_t = prec + 8;
+    bf_init(s, T);
+    bf_init(s, U);
+    ret = bf_hypot(T, &(*a).re, &(*a).im, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_set(U, &(*a).re);
+    (*U).sign = 0;
+    ret |= bf_add(T, T, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(T, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    ret |= bf_sqrt(U, T, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    ret |= bf_div(T, &(*a).im, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_mul_2exp(T, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    if (*a).re.sign == 0 {
+        bf_set(&mut (*r).re, U);
+        bf_set(&mut (*r).im, T);
+    } else {
+        (*T).sign = 0;
+        (*U).sign = (*a).im.sign;
+        bf_set(&mut (*r).re, T);
+        bf_set(&mut (*r).im, U);
+    }
+    bf_delete(T);
+    bf_delete(U);
+    return ret & BF_ST_INEXACT;
+}
+/* principal square root, with a non negative real part */
+pub unsafe fn bf_complex_sqrt(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_complex_t != a);
+    if (*a).im.len == 0 && (*a).im.expn == BF_EXP_INF {
+        /* sqrt(x + Infinity i) = Infinity + Infinity i, even if x is
+        NaN */
+        bf_set_inf(&mut (*r).re, 0);
+        bf_set(&mut (*r).im, &(*a).im);
+        return 0;
+    }
+    if bf_is_nan(&(*a).re) != 0 || bf_is_nan(&(*a).im) != 0 {
+        bf_complex_set_nan(r);
+        return 0;
+    }
+    if bf_is_zero(&(*a).im) != 0 || bf_is_finite(&(*a).re) == 0 {
+        if (*a).re.sign == 0 {
+            ret = bf_sqrt(&mut (*r).re, &(*a).re, prec, flags);
+            bf_set_zero(&mut (*r).im, (*a).im.sign);
+        } else {
+            bf_init(s, T);
+            bf_set(T, &(*a).re);
+            (*T).sign = 0;
+            bf_set_zero(&mut (*r).re, 0);
+            ret = bf_sqrt(
+                &mut (*r).im,
+                T,
+                prec,
+                bf_sign_rnd_flags(flags, (*a).im.sign),
+            );
+            (*r).im.sign = (*a).im.sign;
+            bf_delete(T);
+        }
+        return ret;
+    }
+    return bf_complex_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(bf_complex_sqrt_internal as ZivComplexFunc),
+        0 as *mut std::ffi::c_void,
+    );
+}
+/* x and y are not zero. 'opaque' is NULL for sin() and non NULL for
+cos(). */
+unsafe fn bf_complex_sin_cos_internal(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut S_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut S: *mut bf_t = &mut S_s;
+    let mut C_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut C: *mut bf_t = &mut C_s;
+    let mut SH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut SH: *mut bf_t = &mut SH_s;
+    let mut CH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut CH: *mut bf_t = &mut CH_s;
+    let mut prec1: limb_t = prec + 8;
+    bf_init(s, S);
+    bf_init(s, C);
+    bf_init(s, SH);
+    bf_init(s, CH);
+    bf_sincos(S, C, &(*a).re, prec1);
+    bf_sinh_cosh_ext(SH, CH, &(*a).im, prec1);
+    if opaque.is_null() {
+        bf_mul(&mut (*r).re, S, CH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul(&mut (*r).im, C, SH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    } else {
+        bf_mul(&mut (*r).re, C, CH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul(&mut (*r).im, S, SH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_neg(&mut (*r).im);
+    }
+    bf_delete(S);
+    bf_delete(C);
+    bf_delete(SH);
+    bf_delete(CH);
+    return BF_ST_INEXACT;
+}
+/* sin(x + yi) = sin(x)cosh(y) + cos(x)sinh(y)i and cos(x + yi) =
+cos(x)cosh(y) - sin(x)sinh(y)i */
+unsafe fn bf_complex_sin_cos(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+    mut is_cos: BOOL,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut x: *const bf_t = &(*a).re;
+    let mut y: *const bf_t = &(*a).im;
+    let mut S_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut S: *mut bf_t = &mut S_s;
+    let mut C_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut C: *mut bf_t = &mut C_s;
+    let mut SH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut SH: *mut bf_t = &mut SH_s;
+    let mut CH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut CH: *mut bf_t = &mut CH_s;
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_complex_t != a);
+    if bf_complex_is_finite(a) == 0 {
+        bf_init(s, S);
+        bf_init(s, C);
+        bf_init(s, SH);
+        bf_init(s, CH);
+        ret = bf_sin(S, x, prec, flags);
+        ret |= bf_cos(C, x, prec, flags);
+        ret |= bf_sinh(SH, y, prec, flags);
+        ret |= bf_cosh(CH, y, prec, flags);
+        if is_cos != 0 {
+            ret |= bf_mul(&mut (*r).re, C, CH, prec, flags);
+            ret |= bf_mul(&mut (*r).im, S, SH, prec, flags);
+            bf_neg(&mut (*r).im);
+        } else {
+            ret |= bf_mul(&mut (*r).re, S, CH, prec, flags);
+            ret |= bf_mul(&mut (*r).im, C, SH, prec, flags);
+        }
+        bf_delete(S);
+        bf_delete(C);
+        bf_delete(SH);
+        bf_delete(CH);
+        return ret;
+    }
+    if bf_is_zero(y) != 0 {
+        /* the imaginary part is a zero whose sign is given by cos(x)
+        or sin(x) */
+        bf_init(s, S);
+        if is_cos != 0 {
+            ret = bf_cos(&mut (*r).re, x, prec, flags);
+            bf_sin(S, x, LIMB_BITS as limb_t, BF_RNDN);
+            bf_mul(&mut (*r).im, S, y, prec, flags);
+            bf_neg(&mut (*r).im);
+        } else {
+            ret = bf_sin(&mut (*r).re, x, prec, flags);
+            bf_cos(S, x, LIMB_BITS as limb_t, BF_RNDN);
+            bf_mul(&mut (*r).im, S, y, prec, flags);
+        }
+        bf_delete(S);
+        return ret;
+    }
+    if bf_is_zero(x) != 0 {
+        if is_cos != 0 {
+            ret = bf_cosh(&mut (*r).re, y, prec, flags);
+            bf_set_zero(&mut (*r).im, (*x).sign ^ (*y).sign ^ 1);
+        } else {
+            bf_set_zero(&mut (*r).re, (*x).sign);
+            ret = bf_sinh(&mut (*r).im, y, prec, flags);
+        }
+        return ret;
+    }
+    return bf_complex_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(bf_complex_sin_cos_internal as ZivComplexFunc),
+        is_cos as intptr_t as *mut std::ffi::c_void,
+    );
+}
+pub unsafe fn bf_complex_sin(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    return bf_complex_sin_cos(r, a, prec, flags, FALSE as i32);
+}
+pub unsafe fn bf_complex_cos(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    return bf_complex_sin_cos(r, a, prec, flags, TRUE as i32);
+}
+/* tan(x + yi) = (sin(x)cos(x) + sinh(y)cosh(y)i) / (cos(x)^2 +
+sinh(y)^2) with x and y not zero. Only the real part is computed if
+'opaque' is not NULL, the imaginary part being set to zero. */
+unsafe fn bf_complex_tan_internal(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut S_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut S: *mut bf_t = &mut S_s;
+    let mut C_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut C: *mut bf_t = &mut C_s;
+    let mut SH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut SH: *mut bf_t = &mut SH_s;
+    let mut CH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut CH: *mut bf_t = &mut CH_s;
+    let mut prec1: limb_t = prec + 8;
+    bf_init(s, S);
+    bf_init(s, C);
+    bf_init(s, SH);
+    bf_init(s, CH);
+    bf_sincos(S, C, &(*a).re, prec1);
+    bf_mul(S, S, C, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    if (*a).im.expn > 59 {
+        /* the real part is 4 * sin(x)cos(x) * exp(-2|y|) out of the
+        exponent range */
+        bf_set(CH, &(*a).im);
+        (*CH).sign = 1;
+        bf_mul_2exp(CH, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_exp_ext(SH, CH, prec1);
+        bf_mul(&mut (*r).re, S, SH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul_2exp(&mut (*r).re, 2, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_set_zero(&mut (*r).im, 0);
+    } else {
+        bf_sinh_cosh_ext(SH, CH, &(*a).im, prec1);
+        bf_mul(CH, SH, CH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul(C, C, C, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_mul(SH, SH, SH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_add(C, C, SH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_div(&mut (*r).re, S, C, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        if opaque.is_null() {
+            bf_div(&mut (*r).im, CH, C, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        } else {
+            bf_set_zero(&mut (*r).im, 0);
+        }
+    }
+    bf_delete(S);
+    bf_delete(C);
+    bf_delete(SH);
+    bf_delete(CH);
+    return BF_ST_INEXACT;
+}
+pub unsafe fn bf_complex_tan(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut x: *const bf_t = &(*a).re;
+    let mut y: *const bf_t = &(*a).im;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut C2_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut C2: *mut bf_t = &mut C2_s;
+    let mut ret: i32 = 0;
+    let mut e_sign: i32 = 0;
+    assert!(r as *const bf_complex_t != a);
+    if bf_is_nan(x) != 0 || bf_is_nan(y) != 0 {
+        bf_complex_set_nan(r);
+        return 0;
+    }
+    if bf_is_finite(x) == 0 {
+        bf_complex_set_nan(r);
+        return BF_ST_INVALID_OP;
+    }
+    bf_init(s, T);
+    bf_init(s, C2);
+    if bf_is_finite(y) == 0 {
+        /* tan(x + Infinity i) = 0 + i with a zero of the sign of
+        sin(2x) */
+        bf_set(T, x);
+        bf_mul_2exp(T, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_sin(C2, T, LIMB_BITS as limb_t, BF_RNDN);
+        bf_set_zero(&mut (*r).re, (*C2).sign);
+        bf_set_si(&mut (*r).im, 1);
+        (*r).im.sign = (*y).sign;
+    } else if bf_is_zero(y) != 0 {
+        ret = bf_tan(&mut (*r).re, x, prec, flags);
+        bf_set_zero(&mut (*r).im, (*y).sign);
+    } else if bf_is_zero(x) != 0 {
+        bf_set_zero(&mut (*r).re, (*x).sign);
+        ret = bf_tanh(&mut (*r).im, y, prec, flags);
+    } else {
+        /* large |y| case: the imaginary part is sign(y) * (1 + d) with
+        d = -(cos(2x) + exp(-2|y|)) / (2 * (cos(x)^2 + sinh(y)^2)), so
+        |d| < 2^-(prec + 2) if |y| >= prec / 2 + 4. The sign of d is
+        known if cos(2x) > 0 or |cos(2x)| > exp(-2|y|). */
+        let mut use_epsilon: BOOL = FALSE as i32;
+        bf_set_ui(T, (prec / 2).wrapping_add(4));
+        if bf_cmpu(y, T) >= 0 {
+            bf_set(T, x);
+            bf_mul_2exp(T, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+            bf_cos(C2, T, LIMB_BITS as limb_t, BF_RNDN);
+            e_sign = (*C2).sign ^ (*y).sign ^ 1;
+            if (*C2).sign == 0 {
+                use_epsilon = TRUE as i32
+            } else {
+                /* |cos(2x)| > 2^(EXP(cos(2x)) - 2) >= exp(-2|y|) if
+                2|y| >= 2 - EXP(cos(2x)) */
+                bf_set_si(T, 2 - (*C2).expn);
+                bf_set(C2, y);
+                (*C2).sign = 0;
+                bf_mul_2exp(C2, 1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+                use_epsilon = bf_cmp_le(T, C2)
+            }
+        }
+        if use_epsilon != 0 {
+            ret = bf_complex_ziv_rounding(
+                r,
+                a,
+                prec,
+                flags,
+                Some(bf_complex_tan_internal as ZivComplexFunc),
+                1 as i32 as intptr_t as *mut std::ffi::c_void,
+            );
+            bf_set_si(&mut (*r).im, 1);
+            (*r).im.sign = (*y).sign;
+            ret |= bf_add_epsilon(
+                &mut (*r).im,
+                &(*r).im,
+                prec.wrapping_add(2).wrapping_neg() as slimb_t,
+                e_sign,
+                prec,
+                flags as i32,
+            );
+        } else {
+            ret = bf_complex_ziv_rounding(
+                r,
+                a,
+                prec,
+                flags,
+                Some(bf_complex_tan_internal as ZivComplexFunc),
+                0 as *mut std::ffi::c_void,
+            );
+        }
+    }
+    bf_delete(T);
+    bf_delete(C2);
+    return ret;
+}
+/* number of bits from the least significant non zero bit to the most
+significant bit of the components of 'a', which is finite and non
+zero */
+unsafe fn bf_complex_bits(mut a: *const bf_complex_t) -> slimb_t {
+    let mut e_max: slimb_t = 0;
+    let mut e_min: slimb_t = 0;
+    if bf_is_zero(&(*a).re) != 0 {
+        e_max = (*a).im.expn;
+        e_min = bf_get_exp_min(&(*a).im)
+    } else if bf_is_zero(&(*a).im) != 0 {
+        e_max = (*a).re.expn;
+        e_min = bf_get_exp_min(&(*a).re)
+    } else {
+        e_max = bf_max((*a).re.expn, (*a).im.expn);
+        e_min = bf_min(bf_get_exp_min(&(*a).re), bf_get_exp_min(&(*a).im))
+    }
+    return e_max - e_min;
+}
+/* replace 'a' by its square root and return TRUE if it is exactly
+representable. 'a' is finite and non zero. */
+unsafe fn bf_complex_sqrt_exact(mut a: *mut bf_complex_t) -> BOOL {
+    let mut s: *mut bf_context_t = (*a).re.ctx;
+    let mut R_s: bf_complex_t = bf_complex_t {
+        re: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+        im: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+    };
+    let mut R: *mut bf_complex_t = &mut R_s;
+    let mut P_s: bf_complex_t = bf_complex_t {
+        re: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+        im: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+    };
+    let mut P: *mut bf_complex_t = &mut P_s;
+    let mut res: BOOL = 0;
+    bf_complex_init(s, R);
+    bf_complex_init(s, P);
+    bf_complex_sqrt(R, a, (2 * bf_complex_bits(a) + 16) as limb_t, BF_RNDN);
+    bf_complex_mul(P, R, R, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    res = (bf_cmp_eq(&(*P).re, &(*a).re) != 0 && bf_cmp_eq(&(*P).im, &(*a).im) != 0) as BOOL;
+    if res != 0 {
+        bf_complex_set(a, R);
+    }
+    bf_complex_delete(R);
+    bf_complex_delete(P);
+    return res;
+}
+/* r = a^n computed exactly */
+unsafe fn bf_complex_pow_ui_exact(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut n: limb_t,
+) {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut P_s: bf_complex_t = bf_complex_t {
+        re: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+        im: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+    };
+    let mut P: *mut bf_complex_t = &mut P_s;
+    bf_complex_init(s, P);
+    bf_complex_set(P, a);
+    bf_set_ui(&mut (*r).re, 1);
+    bf_set_zero(&mut (*r).im, 0);
+    loop {
+        if n & 1 != 0 {
+            bf_complex_mul(r, r, P, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        }
+        n >>= 1;
+        if n == 0 {
+            break;
+        }
+        bf_complex_mul(P, P, P, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+    }
+    bf_complex_delete(P);
+}
+/* exp(a * pi), divided by sqrt(2) if 'opaque' is not NULL, with a non
+zero */
+unsafe fn bf_exp_pi_internal(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut prec1: limb_t = prec + 8 + bf_max((*a).expn + 2, 0) as limb_t;
+    bf_init(s, T);
+    bf_const_pi(T, prec1, BF_RNDF);
+    bf_mul(T, T, a, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    if !opaque.is_null() {
+        /* exp(a * pi - log(2) / 2) */
+        let mut U_s: bf_t = bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        };
+        let mut U: *mut bf_t = &mut U_s;
+        bf_init(s, U);
+        bf_const_log2(U, prec1, BF_RNDF);
+        bf_mul_2exp(U, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        bf_sub(T, T, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        bf_delete(U);
+    }
+    bf_exp(r, T, prec1, BF_RNDF | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    return BF_ST_INEXACT;
+}
+/* exp(a * pi), divided by sqrt(2) if 'is_sqrt2' is TRUE, with a
+finite */
+unsafe fn bf_exp_pi(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut is_sqrt2: BOOL,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut a_low_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut a_low: *mut bf_t = &mut a_low_s;
+    let mut a_high_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut a_high: *mut bf_t = &mut a_high_s;
+    let mut ret: i32 = 0;
+    if bf_is_zero(a) != 0 {
+        bf_set_ui(r, 1);
+        return 0;
+    }
+    /* 3a - 1 <= a * pi - log(sqrt(2)) and a * pi <= 4a for a > 0 */
+    bf_init(s, a_low);
+    bf_init(s, a_high);
+    bf_mul_ui(
+        a_low,
+        a,
+        if (*a).sign != 0 { 4 } else { 3 },
+        LIMB_BITS as limb_t,
+        BF_RNDD | BF_FLAG_EXT_EXP,
+    );
+    bf_add_si(
+        a_low,
+        a_low,
+        -1,
+        LIMB_BITS as limb_t,
+        BF_RNDD | BF_FLAG_EXT_EXP,
+    );
+    bf_mul_ui(
+        a_high,
+        a,
+        if (*a).sign != 0 { 3 } else { 4 },
+        LIMB_BITS as limb_t,
+        BF_RNDU | BF_FLAG_EXT_EXP,
+    );
+    ret = check_exp_underflow_overflow(s, r, a_low, a_high, prec, flags);
+    bf_delete(a_low);
+    bf_delete(a_high);
+    if ret != 0 {
+        return ret;
+    }
+    return bf_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(bf_exp_pi_internal as ZivFunc),
+        is_sqrt2 as intptr_t as *mut std::ffi::c_void,
+    );
+}
+/* a^w when the result may have exact or zero components, which
+happens when the angle of the result is a multiple of pi/4 or when 'w'
+is real and the result is an exact power of exact square roots of 'a'.
+The other results have irrational components. 'a' and 'w' are finite
+and non zero. Return FALSE if it is not such a case. */
+unsafe fn bf_complex_pow_exact(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut w: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+    mut pret: *mut i32,
+) -> BOOL {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut x: *const bf_t = &(*a).re;
+    let mut y: *const bf_t = &(*a).im;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut P_s: bf_complex_t = bf_complex_t {
+        re: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+        im: bf_t {
+            ctx: 0 as *mut bf_context_t,
+            sign: 0,
+            expn: 0,
+            len: 0,
+            tab: 0 as *mut limb_t,
+        },
+    };
+    let mut P: *mut bf_complex_t = &mut P_s;
+    /* components of exp(j * pi/4 i), multiplied by sqrt(2) for odd j */
+    static cs_tab: [[i32; 2]; 8] = [
+        [1, 0],
+        [1, 1],
+        [0, 1],
+        [-1, 1],
+        [-1, 0],
+        [-1, -1],
+        [0, -1],
+        [1, -1],
+    ];
+    let mut q: i32 = 0;
+    let mut j: i32 = 0;
+    let mut i: i32 = 0;
+    let mut k: slimb_t = 0;
+    let mut m: i64 = 0;
+    let mut ret: i32 = 0;
+    let mut res: BOOL = FALSE as i32;
+    /* a = |a| * exp(q * pi/4 i) if 'a' is on an axis or a diagonal */
+    if bf_is_zero(y) != 0 {
+        q = if (*x).sign == 0 {
+            0
+        } else if (*y).sign != 0 {
+            -4
+        } else {
+            4
+        }
+    } else if bf_is_zero(x) != 0 {
+        q = if (*y).sign != 0 { -2 } else { 2 }
+    } else if bf_cmpu(x, y) == 0 {
+        q = if (*x).sign != 0 { 3 } else { 1 };
+        if (*y).sign != 0 {
+            q = -q
+        }
+    } else {
+        q = 8
+    }
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_complex_init(s, P);
+    if q != 8 && bf_is_zero(&(*w).im) == 0 {
+        /* for a complex w, |a| = 1 is also needed: a^w = exp(-q *
+        Im(w) * pi/4) * exp(q * Re(w) * pi/4 i) */
+        bf_set_ui(T, 1);
+        if q & 1 != 0 || bf_cmpu(if bf_is_zero(x) != 0 { y } else { x }, T) != 0 {
+            q = 8
+        }
+    }
+    if q != 8 {
+        /* a^w = |a^w| * exp(j * pi/4 i) with j = q * Re(w) mod 8 */
+        bf_mul_si(
+            T,
+            &(*w).re,
+            q as i64,
+            BF_PREC_INF,
+            BF_RNDZ | BF_FLAG_EXT_EXP,
+        );
+        if bf_is_zero(T) != 0 || bf_get_exp_min(T) >= 0 {
+            bf_set(U, T);
+            bf_mul_2exp(U, -3, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+            bf_rint(U, BF_RNDD as i32);
+            bf_mul_2exp(U, 3, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+            bf_sub(T, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+            bf_get_int32(&mut j, T, 0);
+        } else {
+            j = -1
+        }
+        /* for a real w and an odd j, the exact components |a|^w /
+        sqrt(2) are found with the square roots below */
+        if j >= 0 && (j & 1 == 0 || bf_is_zero(&(*w).im) == 0) {
+            if bf_is_zero(&(*w).im) != 0 {
+                /* |a|^w = (|a|^2)^(w/2) is computed with a single
+                rounding */
+                bf_mul(T, x, x, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+                bf_mul(U, y, y, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+                bf_add(T, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+                bf_set(U, &(*w).re);
+                bf_mul_2exp(U, -1, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+            } else {
+                bf_mul_si(
+                    U,
+                    &(*w).im,
+                    -q as i64,
+                    BF_PREC_INF,
+                    BF_RNDZ | BF_FLAG_EXT_EXP,
+                );
+                bf_mul_2exp(U, -2, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+            }
+            i = 0;
+            while i < 2 {
+                let mut c: *mut bf_t = if i == 0 { &mut (*r).re } else { &mut (*r).im };
+                let mut sign: i32 = (cs_tab[j as usize][i as usize] < 0) as i32;
+                if cs_tab[j as usize][i as usize] == 0 {
+                    bf_set_zero(c, 0);
+                } else {
+                    if bf_is_zero(&(*w).im) != 0 {
+                        ret |= bf_pow(c, T, U, prec, bf_sign_rnd_flags(flags, sign));
+                    } else {
+                        ret |= bf_exp_pi(c, U, j & 1, prec, bf_sign_rnd_flags(flags, sign));
+                    }
+                    (*c).sign = sign
+                }
+                i += 1
+            }
+            res = TRUE as i32
+        }
+    }
+    if res == 0 && bf_is_zero(&(*w).im) != 0 {
+        /* w = m / 2^k: a^w = (a^(1/2^k))^m where the square roots are
+        the principal ones */
+        k = -bf_min(bf_get_exp_min(&(*w).re), 0);
+        bf_set(T, &(*w).re);
+        bf_mul_2exp(T, k, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+        if k <= 64 && bf_get_int64(&mut m, T, 0) == 0 {
+            bf_complex_set(P, a);
+            while k > 0 {
+                if bf_complex_sqrt_exact(P) == 0 {
+                    break;
+                }
+                k -= 1
+            }
+            /* the exact power is computed if it is not much larger than
+            the result */
+            if k == 0
+                && m.unsigned_abs() <= (prec + 256) / bf_max(bf_complex_bits(P), 1) as limb_t * 4
+                && m.unsigned_abs()
+                    <= ((1 as limb_t) << 58)
+                        / (bf_max((*P).re.expn.abs(), (*P).im.expn.abs()) + 1) as limb_t
+            {
+                bf_complex_pow_ui_exact(P, P, m.unsigned_abs());
+                if m < 0 {
+                    /* 1 / (u + vi) = (u - vi) / (u^2 + v^2) */
+                    bf_mul(
+                        T,
+                        &(*P).re,
+                        &(*P).re,
+                        BF_PREC_INF,
+                        BF_RNDZ | BF_FLAG_EXT_EXP,
+                    );
+                    bf_mul(
+                        U,
+                        &(*P).im,
+                        &(*P).im,
+                        BF_PREC_INF,
+                        BF_RNDZ | BF_FLAG_EXT_EXP,
+                    );
+                    bf_add(T, T, U, BF_PREC_INF, BF_RNDZ | BF_FLAG_EXT_EXP);
+                    bf_neg(&mut (*P).im);
+                    ret = bf_div(&mut (*r).re, &(*P).re, T, prec, flags);
+                    ret |= bf_div(&mut (*r).im, &(*P).im, T, prec, flags);
+                } else {
+                    bf_complex_set(r, P);
+                    ret = bf_round(&mut (*r).re, prec, flags);
+                    ret |= bf_round(&mut (*r).im, prec, flags);
+                }
+                res = TRUE as i32
+            }
+        }
+    }
+    bf_delete(T);
+    bf_delete(U);
+    bf_complex_delete(P);
+    *pret = ret;
+    return res;
+}
+/* a^w = exp(w * log(a)) for w = 'opaque'. 'a' and w are finite and non
+zero. */
+unsafe fn bf_complex_pow_internal(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut prec: limb_t,
+    mut opaque: *mut std::ffi::c_void,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).re.ctx;
+    let mut w: *const bf_complex_t = opaque as *const bf_complex_t;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut U_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut U: *mut bf_t = &mut U_s;
+    let mut L_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut L: *mut bf_t = &mut L_s;
+    let mut TH_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut TH: *mut bf_t = &mut TH_s;
+    let mut A_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut A: *mut bf_t = &mut A_s;
+    let mut B_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut B: *mut bf_t = &mut B_s;
+    let mut S_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut S: *mut bf_t = &mut S_s;
+    let mut C_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut C: *mut bf_t = &mut C_s;
+    let mut ret: i32 = 0;
+    let mut ret_b: i32 = 0;
+    let mut prec1: limb_t = prec + 16;
+    let mut e: slimb_t = 0;
+    bf_init(s, T);
+    bf_init(s, U);
+    bf_init(s, L);
+    bf_init(s, TH);
+    bf_init(s, A);
+    bf_init(s, B);
+    bf_init(s, S);
+    bf_init(s, C);
+    loop {
+        /* log(a) = L + TH i */
+        /* L has a small relative error even if |a| is close to 1 */
+        ret = bf_complex_log_abs_internal(
+            L,
+            &(*a).re,
+            prec1,
+            &(*a).im as *const bf_t as *mut std::ffi::c_void,
+        );
+        ret |= bf_atan2(TH, &(*a).im, &(*a).re, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        /* w * log(a) = A + B i */
+        ret |= bf_mul(T, &(*w).re, L, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        ret |= bf_mul(U, &(*w).im, TH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        ret |= bf_sub(A, T, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        ret_b = bf_mul(T, &(*w).re, TH, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        ret_b |= bf_mul(U, &(*w).im, L, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        ret_b |= bf_add(B, T, U, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+        ret |= ret_b;
+        if bf_is_zero(B) != 0 {
+            if ret_b & BF_ST_INEXACT != 0 {
+                /* cancellation */
+                prec1 += prec1 / 2;
+                continue;
+            }
+            bf_set_zero(S, 0);
+            bf_set_ui(C, 1);
+        } else {
+            ret |= bf_sincos(S, C, B, prec1);
+        }
+        /* the absolute error of A and B is lower than 2^(e - prec1)
+        with |w| < 2^e1 and |log(a)| < 2^(e2 + 1) */
+        e = bf_max((*w).re.expn, (*w).im.expn) + bf_max((*L).expn, 2) + 4;
+        /* relative error of the cosine and the sine */
+        if bf_is_zero(S) == 0 {
+            e -= bf_min((*S).expn, 0)
+        }
+        e -= bf_min((*C).expn, 0);
+        if (prec1 as slimb_t) >= prec as slimb_t + e + 10 {
+            break;
+        }
+        prec1 = (prec as slimb_t + e + 18) as limb_t
+    }
+    ret |= bf_exp_ext(T, A, prec1);
+    ret |= bf_mul(&mut (*r).re, T, C, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    ret |= bf_mul(&mut (*r).im, T, S, prec1, BF_RNDN | BF_FLAG_EXT_EXP);
+    bf_delete(T);
+    bf_delete(U);
+    bf_delete(L);
+    bf_delete(TH);
+    bf_delete(A);
+    bf_delete(B);
+    bf_delete(S);
+    bf_delete(C);
+    return ret & BF_ST_INEXACT;
+}
+/* principal value of a^b = exp(b * log(a)). a^0 = 1 and 0^b = 0 if
+the real part of b is positive. The result is NaN for the other powers
+of zero and if a component of 'a' or 'b' is not finite. */
+pub unsafe fn bf_complex_pow(
+    mut r: *mut bf_complex_t,
+    mut a: *const bf_complex_t,
+    mut b: *const bf_complex_t,
+    mut prec: limb_t,
+    mut flags: bf_flags_t,
+) -> i32 {
+    let mut ret: i32 = 0;
+    assert!(r as *const bf_complex_t != a && r as *const bf_complex_t != b);
+    if bf_complex_is_zero(b) != 0 {
+        bf_set_ui(&mut (*r).re, 1);
+        bf_set_zero(&mut (*r).im, 0);
+        return 0;
+    }
+    if bf_complex_is_finite(a) == 0 || bf_complex_is_finite(b) == 0 {
+        bf_complex_set_nan(r);
+        if bf_is_nan(&(*a).re) != 0
+            || bf_is_nan(&(*a).im) != 0
+            || bf_is_nan(&(*b).re) != 0
+            || bf_is_nan(&(*b).im) != 0
+        {
+            return 0;
+        }
+        return BF_ST_INVALID_OP;
+    }
+    if bf_complex_is_zero(a) != 0 {
+        if (*b).re.sign == 0 && bf_is_zero(&(*b).re) == 0 {
+            bf_set_zero(&mut (*r).re, 0);
+            bf_set_zero(&mut (*r).im, 0);
+            return 0;
+        }
+        bf_complex_set_nan(r);
+        return BF_ST_INVALID_OP;
+    }
+    if bf_complex_pow_exact(r, a, b, prec, flags, &mut ret) != 0 {
+        return ret;
+    }
+    return bf_complex_ziv_rounding(
+        r,
+        a,
+        prec,
+        flags,
+        Some(bf_complex_pow_internal as ZivComplexFunc),
+        b as *mut std::ffi::c_void,
+    );
+}
+/* polynomials */
+
+/* r = sum(a[i] * 2^(k * i), 0 <= i < len) where the sum only contains
+the coefficients whose sign is 'sign'. The coefficients are finite
+integers with |a[i]| < 2^k. Return 0 or BF_ST_MEM_ERROR. */
+unsafe fn bf_poly_pack(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut len: limb_t,
+    mut k: limb_t,
+    mut sign: i32,
+) -> i32 {
+    let mut n: limb_t = 0;
+    let mut i: limb_t = 0;
+    let mut j: limb_t = 0;
+    let mut pos: limb_t = 0;
+    let mut shift: i32 = 0;
+    let mut v: limb_t = 0;
+    let mut c: *const bf_t = 0 as *const bf_t;
+    n = (k * len + LIMB_BITS - 1) / LIMB_BITS + 1;
+    if bf_resize(r, n) != 0 {
+        bf_set_nan(r);
+        return BF_ST_MEM_ERROR;
+    }
+    (*r).tab.write_bytes(0, n as usize);
+    i = 0;
+    while i < len {
+        c = a.offset(i as isize);
+        if bf_is_zero(c) == 0 && (*c).sign == sign {
+            /* copy the bits of the integer to the bit position k * i */
+            j = 0;
+            while j * LIMB_BITS < (*c).expn as limb_t {
+                v = get_bits(
+                    (*c).tab,
+                    (*c).len,
+                    ((*c).len * LIMB_BITS) as slimb_t - (*c).expn + (j * LIMB_BITS) as slimb_t,
+                );
+                if (j + 1) * LIMB_BITS > (*c).expn as limb_t {
+                    v &= limb_mask(0, ((*c).expn as limb_t - j * LIMB_BITS - 1) as i32);
+                }
+                pos = k * i + j * LIMB_BITS;
+                shift = (pos % LIMB_BITS) as i32;
+                *(*r).tab.offset((pos / LIMB_BITS) as isize) |= v << shift;
+                if shift != 0 {
+                    *(*r).tab.offset((pos / LIMB_BITS + 1) as isize) |=
+                        v >> (LIMB_BITS as i32 - shift);
+                }
+                j += 1
+            }
+        }
+        i += 1
+    }
+    (*r).sign = 0;
+    (*r).expn = (n * LIMB_BITS) as slimb_t;
+    return bf_normalize_and_round(r, BF_PREC_INF, BF_RNDZ);
+}
+/* r[i] = i-th digit of the integer 'a' in base 2^k with the digits in
+[-2^(k - 1), 2^(k - 1)), for 0 <= i < len. The digits are assumed to
+be in (-2^(k - 2), 2^(k - 2)). Return 0 or BF_ST_MEM_ERROR. */
+unsafe fn bf_poly_unpack(
+    mut r: *mut bf_t,
+    mut len: limb_t,
+    mut a: *const bf_t,
+    mut k: limb_t,
+) -> i32 {
+    let mut n: limb_t = 0;
+    let mut i: limb_t = 0;
+    let mut j: limb_t = 0;
+    let mut carry: limb_t = 0;
+    let mut pos: slimb_t = 0;
+    let mut c: *mut bf_t = 0 as *mut bf_t;
+    let mut ret: i32 = 0;
+    n = (k + LIMB_BITS - 1) / LIMB_BITS;
+    i = 0;
+    while i < len {
+        c = r.offset(i as isize);
+        if bf_is_zero(a) != 0 {
+            bf_set_zero(c, 0);
+        } else {
+            if bf_resize(c, n) != 0 {
+                bf_set_nan(c);
+                return BF_ST_MEM_ERROR;
+            }
+            pos = ((*a).len * LIMB_BITS) as slimb_t - (*a).expn + (k * i) as slimb_t;
+            j = 0;
+            while j < n {
+                *(*c).tab.offset(j as isize) =
+                    get_bits((*a).tab, (*a).len, pos + (j * LIMB_BITS) as slimb_t);
+                j += 1
+            }
+            *(*c).tab.offset((n - 1) as isize) &= limb_mask(0, ((k - 1) % LIMB_BITS) as i32);
+            /* the digit is negative if its bit k - 1 is set once the
+            carry is added */
+            if get_bit((*c).tab, n, (k - 1) as slimb_t) != 0 {
+                /* 2^k - digit - carry */
+                mp_neg((*c).tab, (*c).tab, n as mp_size_t, carry);
+                *(*c).tab.offset((n - 1) as isize) &= limb_mask(0, ((k - 1) % LIMB_BITS) as i32);
+                (*c).sign = (*a).sign ^ 1;
+                carry = 1
+            } else {
+                mp_add_ui((*c).tab, carry, n);
+                (*c).sign = (*a).sign;
+                carry = 0
+            }
+            (*c).expn = (n * LIMB_BITS) as slimb_t;
+            ret |= bf_normalize_and_round(c, BF_PREC_INF, BF_RNDZ);
+            if bf_is_zero(c) != 0 {
+                (*c).sign = 0
+            }
+        }
+        i += 1
+    }
+    return ret;
+}
+/* r[0..a_len + b_len - 1] = product of the polynomials of integer
+coefficients a[0..a_len] and b[0..b_len], where a[i] is the
+coefficient of x^i. The coefficients are finite integers and 'r' does
+not overlap with 'a' and 'b'. The polynomials are evaluated at a large
+power of two (Kronecker substitution) so that the product is a single
+integer multiplication done by mp_mul() or fft_mul(). Return 0 or
+BF_ST_MEM_ERROR. */
+pub unsafe fn bf_poly_mul(
+    mut r: *mut bf_t,
+    mut a: *const bf_t,
+    mut a_len: limb_t,
+    mut b: *const bf_t,
+    mut b_len: limb_t,
+) -> i32 {
+    let mut s: *mut bf_context_t = (*r).ctx;
+    let mut A_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut A: *mut bf_t = &mut A_s;
+    let mut B_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut B: *mut bf_t = &mut B_s;
+    let mut T_s: bf_t = bf_t {
+        ctx: 0 as *mut bf_context_t,
+        sign: 0,
+        expn: 0,
+        len: 0,
+        tab: 0 as *mut limb_t,
+    };
+    let mut T: *mut bf_t = &mut T_s;
+    let mut a_bits: slimb_t = 0;
+    let mut b_bits: slimb_t = 0;
+    let mut i: limb_t = 0;
+    let mut k: limb_t = 0;
+    let mut ret: i32 = 0;
+    assert!(a_len >= 1 && b_len >= 1);
+    i = 0;
+    while i < a_len {
+        if bf_is_zero(a.offset(i as isize)) == 0 {
+            a_bits = bf_max(a_bits, (*a.offset(i as isize)).expn)
+        }
+        i += 1
+    }
+    i = 0;
+    while i < b_len {
+        if bf_is_zero(b.offset(i as isize)) == 0 {
+            b_bits = bf_max(b_bits, (*b.offset(i as isize)).expn)
+        }
+        i += 1
+    }
+    if a_bits == 0 || b_bits == 0 {
+        i = 0;
+        while i < a_len + b_len - 1 {
+            bf_set_zero(r.offset(i as isize), 0);
+            i += 1
+        }
+        return 0;
+    }
+    /* |r[i]| < min(a_len, b_len) * 2^(a_bits + b_bits) */
+    k = (a_bits + b_bits) as limb_t
+        + ceil_log2(bf_min(a_len as slimb_t, b_len as slimb_t) as limb_t) as limb_t
+        + 2;
+    bf_init(s, A);
+    bf_init(s, B);
+    bf_init(s, T);
+    ret |= bf_poly_pack(A, a, a_len, k, 0);
+    ret |= bf_poly_pack(T, a, a_len, k, 1);
+    ret |= bf_sub(A, A, T, BF_PREC_INF, BF_RNDZ);
+    if a == b && a_len == b_len {
+        ret |= bf_mul(T, A, A, BF_PREC_INF, BF_RNDZ);
+    } else {
+        ret |= bf_poly_pack(B, b, b_len, k, 0);
+        ret |= bf_poly_pack(T, b, b_len, k, 1);
+        ret |= bf_sub(B, B, T, BF_PREC_INF, BF_RNDZ);
+        ret |= bf_mul(T, A, B, BF_PREC_INF, BF_RNDZ);
+    }
+    if ret & BF_ST_MEM_ERROR == 0 {
+        ret |= bf_poly_unpack(r, a_len + b_len - 1, T, k);
+    }
+    bf_delete(A);
+    bf_delete(B);
+    bf_delete(T);
+    return ret & BF_ST_MEM_ERROR;
+}
+#[inline]
+unsafe fn shld(mut a1: limb_t, mut a0: limb_t, mut shift: i64) -> limb_t {
+    if shift != 0 as i32 as i64 {
+        return a1 << shift | a0 >> ((1 as i32) << 6 as i32) as i64 - shift;
+    } else {
+        return a1;
+    };
+}
+#[inline]
+unsafe fn fast_udiv(mut a: limb_t, mut s: *const FastDivData) -> limb_t {
+    let mut t0: limb_t = 0;
+    let mut t1: limb_t = 0;
+    let mut __t: u128 = 0;
+    __t = ((*s).m1 as u128).wrapping_mul(a as u128);
+    t0 = __t as limb_t;
+    t1 = (__t >> 64 as i32) as limb_t;
+    t0 = a.wrapping_sub(t1) >> (*s).shift1 as i32;
+    return t1.wrapping_add(t0) >> (*s).shift2 as i32;
+}
+/* contains 10^i */
+static mp_pow_dec: [limb_t; 20] = [
+    1 as u32 as limb_t,
+    10 as u32 as limb_t,
+    100 as u32 as limb_t,
+    1000 as u32 as limb_t,
+    10000 as u32 as limb_t,
+    100000 as u32 as limb_t,
+    1000000 as u32 as limb_t,
+    10000000 as u32 as limb_t,
+    100000000 as u32 as limb_t,
+    1000000000 as u32 as limb_t,
+    10000000000 as u64,
+    100000000000 as u64,
+    1000000000000 as u64,
+    10000000000000 as u64,
+    100000000000000 as u64,
+    1000000000000000 as u64,
+    10000000000000000 as u64,
+    100000000000000000 as u64,
+    1000000000000000000 as u64,
+    10000000000000000000 as u64,
+];
+
+pub fn mp_pow_dec_get(index: usize) -> limb_t {
+    mp_pow_dec[index]
 }
 
 /* precomputed from fast_udiv_init(10^i) */
-static mut mp_pow_div: [FastDivData; 20] = [
+static mp_pow_div: [FastDivData; 20] = [
     {
         let mut init = FastDivData {
             m1: 0x1 as i32 as limb_t,
@@ -10389,7 +15315,7 @@ unsafe fn __bfdec_round(
                 } /* cannot fail */
                 if i > 0 as i32 as i64 {
                     l = (l as u64).wrapping_sub(i as u64) as limb_t as limb_t;
-                    ((*r).tab as *mut u8).copy_from_nonoverlapping(
+                    ((*r).tab as *mut u8).copy_from(
                         (*r).tab.offset(i as isize) as *const u8,
                         (l as usize).wrapping_mul(::std::mem::size_of::<limb_t>()),
                     );
@@ -11359,7 +16285,7 @@ pub unsafe fn bfdec_sqrt(
     mut flags: bf_flags_t,
 ) -> i32 {
     let mut current_block: u64;
-    let mut s: *mut bf_context_t = (*a).ctx;
+    let mut s: *mut bf_context_t = (*r).ctx;
     let mut ret: i32 = 0;
     let mut k: i32 = 0;
     let mut a1: *mut limb_t = 0 as *mut limb_t;
@@ -11599,12 +16525,13 @@ pub unsafe fn bfdec_pow_ui(mut r: *mut bfdec_t, mut a: *const bfdec_t, mut b: li
     return ret;
 }
 pub unsafe fn bfdec_ftoa(
+    mut s: *mut bf_context_t,
     mut plen: *mut u64,
     mut a: *const bfdec_t,
     mut prec: limb_t,
     mut flags: bf_flags_t,
 ) -> *mut std::os::raw::c_char {
-    return bf_ftoa_internal(plen, a as *const bf_t, 10 as i32, prec, flags, TRUE as i32);
+    return bf_ftoa_internal(s, plen, a as *const bf_t, 10 as i32, prec, flags, TRUE as i32);
 }
 pub unsafe fn bfdec_atof(
     mut r: *mut bfdec_t,
@@ -11647,15 +16574,15 @@ unsafe fn put_bits(mut tab: *mut limb_t, mut len: limb_t, mut pos: slimb_t, mut
         }
     };
 }
-static mut ntt_int_bits: [i32; 5] = [307 as i32, 246 as i32, 185 as i32, 123 as i32, 61 as i32];
-static mut ntt_mods: [limb_t; 5] = [
+static ntt_int_bits: [i32; 5] = [307 as i32, 246 as i32, 185 as i32, 123 as i32, 61 as i32];
+static ntt_mods: [limb_t; 5] = [
     0x28d8000000000001 as i64 as limb_t,
     0x2a88000000000001 as i64 as limb_t,
     0x2ed8000000000001 as i64 as limb_t,
     0x3508000000000001 as i64 as limb_t,
     0x3aa8000000000001 as i64 as limb_t,
 ];
-static mut ntt_proot: [[limb_t; 5]; 2] = [
+static ntt_proot: [[limb_t; 5]; 2] = [
     [
         0x1b8ea61034a2bea7 as i64 as limb_t,
         0x21a9762de58206fb as i64 as limb_t,
@@ -11671,7 +16598,7 @@ static mut ntt_proot: [[limb_t; 5]; 2] = [
         0x36cd204f522a1379 as i64 as limb_t,
     ],
 ];
-static mut ntt_mods_cr: [limb_t; 10] = [
+static ntt_mods_cr: [limb_t; 10] = [
     0x8a9ed097b425eea as i64 as limb_t,
     0x18a44aaaaaaaaab3 as i64 as limb_t,
     0x2493f57f57f57f5d as i64 as limb_t,
@@ -12039,6 +16966,12 @@ unsafe fn ntt_fft_partial(
     let mut l: limb_t = 0;
     let mut buf2: *mut NTTLimb = 0 as *mut NTTLimb;
     let mut buf3: *mut NTTLimb = 0 as *mut NTTLimb;
+    #[cfg(feature = "parallel-ntt")]
+    {
+        if k2 != 0 && ntt_par_allowed() {
+            return ntt_fft_partial_par(s, buf1, k1, k2, n1, n2, inverse, m_idx);
+        }
+    }
     buf2 = 0 as *mut NTTLimb;
     buf3 = ntt_malloc(
         s,
@@ -12181,6 +17114,12 @@ unsafe fn ntt_conv(
     k2 = k - k1;
     n1 = (1 as i32 as limb_t) << k1;
     n2 = (1 as i32 as limb_t) << k2;
+    #[cfg(feature = "parallel-ntt")]
+    {
+        if k >= NTT_PAR_LEN_LOG2_MIN && ntt_par_allowed() {
+            return ntt_conv_par(s, buf1, buf2, k1, k2, k_tot, m_idx);
+        }
+    }
     if ntt_fft_partial(s, buf1, k1, k2, n1, n2, 0 as i32, m_idx) != 0 {
         return -(1 as i32);
     }
@@ -12192,14 +17131,17 @@ unsafe fn ntt_conv(
     } else {
         i = 0 as i32 as limb_t;
         while i < n1 {
-            ntt_conv(
+            if ntt_conv(
                 s,
                 buf1.offset(i.wrapping_mul(n2) as isize),
                 buf2.offset(i.wrapping_mul(n2) as isize),
                 k2,
                 k_tot,
                 m_idx,
-            );
+            ) != 0
+            {
+                return -(1 as i32);
+            }
             i = i.wrapping_add(1)
         }
     }
@@ -12208,6 +17150,298 @@ unsafe fn ntt_conv(
     }
     return 0 as i32;
 }
+/* Parallel NTT multiplication (cargo feature "parallel-ntt"). The
+moduli, the two forward transforms and the strips of the large
+transforms are computed by the rayon thread pool. The trigonometric
+tables are computed before, so that the threads only read the NTT
+state. The temporary buffers are allocated with the allocator of the
+context, which must then be thread safe. The operations are the same
+as in the serial code, so the result is identical.
+
+The jobs only use the buffers given to them and the NTT state, never
+the constant caches or the other fields of a context. A thread
+waiting for them in rayon runs other jobs if it is a worker of a
+rayon pool, so an operation of the thread local context of the
+wrappers can start while another one is in fft_mul(): the constant
+caches are only updated at the end of their computation and
+bf_clear_cache() keeps the NTT state while an fft_mul() of the thread
+is in progress. The trigonometric tables needed by the jobs are
+computed before they start and are never modified afterwards. */
+#[cfg(feature = "parallel-ntt")]
+const NTT_PAR_LEN_LOG2_MIN: i32 = 16;
+#[cfg(feature = "parallel-ntt")]
+thread_local! {
+    /* number of fft_mul() in progress in the current thread */
+    static FFT_MUL_DEPTH: std::cell::Cell<u32> = const { std::cell::Cell::new(0) };
+    /* false if the parallel transforms are disabled in the current thread */
+    static NTT_PARALLEL: std::cell::Cell<bool> = const { std::cell::Cell::new(true) };
+}
+#[cfg(feature = "parallel-ntt")]
+struct FFTMulGuard;
+#[cfg(feature = "parallel-ntt")]
+impl FFTMulGuard {
+    fn new() -> FFTMulGuard {
+        FFT_MUL_DEPTH.with(|d| d.set(d.get() + 1));
+        FFTMulGuard
+    }
+}
+#[cfg(feature = "parallel-ntt")]
+impl Drop for FFTMulGuard {
+    fn drop(&mut self) {
+        FFT_MUL_DEPTH.with(|d| d.set(d.get() - 1));
+    }
+}
+#[cfg(feature = "parallel-ntt")]
+fn ntt_par_allowed() -> bool {
+    NTT_PARALLEL.with(|p| p.get())
+}
+/* enable or disable the parallel transforms in the current thread. The
+result of the multiplications does not depend on it. */
+#[cfg(feature = "parallel-ntt")]
+pub fn bf_set_ntt_parallel(enabled: bool) {
+    NTT_PARALLEL.with(|p| p.set(enabled));
+}
+#[cfg(feature = "parallel-ntt")]
+#[derive(Copy, Clone)]
+struct SendPtr<T>(*mut T);
+#[cfg(feature = "parallel-ntt")]
+unsafe impl<T> Send for SendPtr<T> {}
+#[cfg(feature = "parallel-ntt")]
+unsafe impl<T> Sync for SendPtr<T> {}
+#[cfg(feature = "parallel-ntt")]
+impl<T> SendPtr<T> {
+    fn get(self) -> *mut T {
+        self.0
+    }
+}
+/* temporary buffers of a thread in ntt_fft_partial_par() */
+#[cfg(feature = "parallel-ntt")]
+struct NTTScratch {
+    s: *mut BFNTTState,
+    buf2: *mut NTTLimb,
+    buf3: *mut NTTLimb,
+}
+#[cfg(feature = "parallel-ntt")]
+impl NTTScratch {
+    unsafe fn new(mut s: *mut BFNTTState, mut len2: limb_t, mut len3: limb_t) -> NTTScratch {
+        NTTScratch {
+            s,
+            buf2: ntt_malloc(s, ::std::mem::size_of::<NTTLimb>() * len2 as usize) as *mut NTTLimb,
+            buf3: ntt_malloc(s, ::std::mem::size_of::<NTTLimb>() * len3 as usize) as *mut NTTLimb,
+        }
+    }
+}
+#[cfg(feature = "parallel-ntt")]
+impl Drop for NTTScratch {
+    fn drop(&mut self) {
+        unsafe {
+            ntt_free(self.s, self.buf2 as *mut std::ffi::c_void);
+            ntt_free(self.s, self.buf3 as *mut std::ffi::c_void);
+        }
+    }
+}
+/* transform the columns j to j + strip_len - 1 of buf1 as in
+ntt_fft_partial(). 'c_mul' is the twiddle factor of the column j. */
+#[cfg(feature = "parallel-ntt")]
+unsafe fn ntt_fft_strip(
+    mut s: *mut BFNTTState,
+    mut buf1: *mut NTTLimb,
+    mut buf2: *mut NTTLimb,
+    mut buf3: *mut NTTLimb,
+    mut k1: i32,
+    mut n1: limb_t,
+    mut n2: limb_t,
+    mut j: limb_t,
+    mut strip_len: limb_t,
+    mut c_mul: limb_t,
+    mut c0: limb_t,
+    mut inverse: i32,
+    mut m_idx: limb_t,
+) -> i32 {
+    let mut m: limb_t = ntt_mods[m_idx as usize];
+    let mut m_inv: limb_t = (*s).ntt_mods_div[m_idx as usize];
+    for i in 0..n1 {
+        for l in 0..strip_len {
+            *buf2.offset((i + l * n1) as isize) = *buf1.offset((i * n2 + j + l) as isize);
+        }
+    }
+    for l in 0..strip_len {
+        let mut col: *mut NTTLimb = buf2.offset((l * n1) as isize);
+        if inverse != 0 {
+            mul_trig(col, n1, c_mul, m, m_inv);
+        }
+        if ntt_fft(s, col, col, buf3, k1, inverse, m_idx as i32) != 0 {
+            return -1;
+        }
+        if inverse == 0 {
+            mul_trig(col, n1, c_mul, m, m_inv);
+        }
+        c_mul = mul_mod_fast(c_mul, c0, m, m_inv);
+    }
+    for i in 0..n1 {
+        for l in 0..strip_len {
+            *buf1.offset((i * n2 + j + l) as isize) = *buf2.offset((i + l * n1) as isize);
+        }
+    }
+    return 0;
+}
+#[cfg(feature = "parallel-ntt")]
+unsafe fn ntt_fft_partial_par(
+    mut s: *mut BFNTTState,
+    mut buf1: *mut NTTLimb,
+    mut k1: i32,
+    mut k2: i32,
+    mut n1: limb_t,
+    mut n2: limb_t,
+    mut inverse: i32,
+    mut m_idx: limb_t,
+) -> i32 {
+    use rayon::prelude::*;
+    let mut strip_len: limb_t = 16;
+    assert!((n2 % strip_len) == 0);
+    let mut m: limb_t = ntt_mods[m_idx as usize];
+    let mut m_inv: limb_t = (*s).ntt_mods_div[m_idx as usize];
+    let mut c0: limb_t = (*s).ntt_proot_pow[m_idx as usize][inverse as usize][(k1 + k2) as usize];
+    /* twiddle factor of the first column of each strip, computed as
+    in the serial loop */
+    let mut c_mul_tab: Vec<limb_t> = Vec::with_capacity((n2 / strip_len) as usize);
+    let mut c_mul: limb_t = 1;
+    for _ in 0..n2 / strip_len {
+        c_mul_tab.push(c_mul);
+        for _ in 0..strip_len {
+            c_mul = mul_mod_fast(c_mul, c0, m, m_inv);
+        }
+    }
+    let s_p = SendPtr(s);
+    let buf1_p = SendPtr(buf1);
+    let ok = c_mul_tab
+        .par_iter()
+        .enumerate()
+        .map_init(
+            || NTTScratch::new(s_p.get(), n1 * strip_len, n1),
+            |scratch, (idx, &c_mul)| {
+                !scratch.buf2.is_null()
+                    && !scratch.buf3.is_null()
+                    && ntt_fft_strip(
+                        s_p.get(),
+                        buf1_p.get(),
+                        scratch.buf2,
+                        scratch.buf3,
+                        k1,
+                        n1,
+                        n2,
+                        idx as limb_t * strip_len,
+                        strip_len,
+                        c_mul,
+                        c0,
+                        inverse,
+                        m_idx,
+                    ) == 0
+            },
+        )
+        .all(|ok| ok);
+    return if ok { 0 } else { -1 };
+}
+#[cfg(feature = "parallel-ntt")]
+unsafe fn ntt_conv_par(
+    mut s: *mut BFNTTState,
+    mut buf1: *mut NTTLimb,
+    mut buf2: *mut NTTLimb,
+    mut k1: i32,
+    mut k2: i32,
+    mut k_tot: i32,
+    mut m_idx: limb_t,
+) -> i32 {
+    use rayon::prelude::*;
+    let mut n1: limb_t = 1 << k1;
+    let mut n2: limb_t = 1 << k2;
+    let s_p = SendPtr(s);
+    let buf1_p = SendPtr(buf1);
+    let buf2_p = SendPtr(buf2);
+    let (ret1, ret2) = rayon::join(
+        || ntt_fft_partial(s_p.get(), buf1_p.get(), k1, k2, n1, n2, 0, m_idx),
+        || ntt_fft_partial(s_p.get(), buf2_p.get(), k1, k2, n1, n2, 0, m_idx),
+    );
+    if ret1 != 0 || ret2 != 0 {
+        return -1;
+    }
+    if k2 == 0 {
+        ntt_vec_mul(s, buf1, buf2, k1, k_tot, m_idx as i32);
+    } else {
+        let ok = (0..n1).into_par_iter().all(|i| {
+            ntt_conv(
+                s_p.get(),
+                buf1_p.get().offset((i * n2) as isize),
+                buf2_p.get().offset((i * n2) as isize),
+                k2,
+                k_tot,
+                m_idx,
+            ) == 0
+        });
+        if !ok {
+            return -1;
+        }
+    }
+    return ntt_fft_partial(s, buf1, k1, k2, n1, n2, 1, m_idx);
+}
+/* convolutions of the nb_mods moduli of fft_mul() in parallel. With
+reduced_mem, each modulus uses its own copy of 'b' instead of buf2. */
+#[cfg(feature = "parallel-ntt")]
+unsafe fn fft_mul_conv_par(
+    mut s: *mut BFNTTState,
+    mut buf1: *mut NTTLimb,
+    mut buf2: *mut NTTLimb,
+    mut fft_len_log2: i32,
+    mut b_tab: *mut limb_t,
+    mut b_len: limb_t,
+    mut dpl: i32,
+    mut nb_mods: i32,
+    mut reduced_mem: i32,
+) -> i32 {
+    use rayon::prelude::*;
+    let mut fft_len: limb_t = 1 << fft_len_log2;
+    for j in 0..nb_mods {
+        for inverse in 0..2 {
+            for l in 2..=bf_min(fft_len_log2 as slimb_t, 19) as i32 {
+                if get_trig(s, l, inverse, 5 - nb_mods + j).is_null() {
+                    return -1;
+                }
+            }
+        }
+    }
+    let s_p = SendPtr(s);
+    let buf1_p = SendPtr(buf1);
+    let buf2_p = SendPtr(buf2);
+    let b_tab_p = SendPtr(b_tab);
+    let ok = (0..nb_mods).into_par_iter().all(|j| {
+        let s = s_p.get();
+        let m_idx = 5 - nb_mods + j;
+        let mut tmp: *mut NTTLimb = 0 as *mut NTTLimb;
+        let ptr;
+        if reduced_mem != 0 {
+            tmp =
+                ntt_malloc(s, ::std::mem::size_of::<NTTLimb>() * fft_len as usize) as *mut NTTLimb;
+            if tmp.is_null() {
+                return false;
+            }
+            limb_to_ntt(s, tmp, fft_len, b_tab_p.get(), b_len, dpl, m_idx, 1);
+            ptr = tmp
+        } else {
+            ptr = buf2_p.get().offset((fft_len * j as limb_t) as isize)
+        }
+        let ret = ntt_conv(
+            s,
+            buf1_p.get().offset((fft_len * j as limb_t) as isize),
+            ptr,
+            fft_len_log2,
+            fft_len_log2,
+            m_idx as limb_t,
+        );
+        ntt_free(s, tmp as *mut std::ffi::c_void);
+        ret == 0
+    });
+    return if ok { 0 } else { -1 };
+}
 #[inline(never)]
 unsafe fn limb_to_ntt(
     mut s: *mut BFNTTState,
@@ -12617,6 +17851,8 @@ unsafe fn fft_mul(
     let mut buf1: *mut NTTLimb = 0 as *mut NTTLimb;
     let mut buf2: *mut NTTLimb = 0 as *mut NTTLimb;
     let mut ptr: *mut NTTLimb = 0 as *mut NTTLimb;
+    #[cfg(feature = "parallel-ntt")]
+    let _guard = FFTMulGuard::new();
     if ntt_static_init(s1) != 0 {
         return -(1 as i32);
     }
@@ -12706,6 +17942,29 @@ unsafe fn fft_mul(
     match current_block {
         7245201122033322888 => {
             j = 0 as i32;
+            #[cfg(feature = "parallel-ntt")]
+            {
+                if fft_len_log2 >= NTT_PAR_LEN_LOG2_MIN && ntt_par_allowed() {
+                    if fft_mul_conv_par(
+                        s,
+                        buf1,
+                        buf2,
+                        fft_len_log2,
+                        b_tab,
+                        b_len,
+                        dpl,
+                        nb_mods,
+                        reduced_mem,
+                    ) != 0
+                    {
+                        ntt_free(s, buf1 as *mut std::ffi::c_void);
+                        ntt_free(s, buf2 as *mut std::ffi::c_void);
+                        return -(1 as i32);
+                    }
+                    /* skip the serial loop */
+                    j = nb_mods
+                }
+            }
             loop {
                 if !(j < nb_mods) {
                     current_block = 1356832168064818221;
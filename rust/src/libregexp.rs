@@ -161,7 +161,7 @@ unsafe fn from_hex(mut c: i32) -> i32 {
     };
 }
 #[inline]
-unsafe fn lre_js_is_ident_next(mut c: i32) -> i32 {
+pub(crate) unsafe fn lre_js_is_ident_next(mut c: i32) -> i32 {
     if (c as u32) < 128 as i32 as u32 {
         return (lre_id_continue_table_ascii[(c >> 5 as i32) as usize] >> (c & 31 as i32)
             & 1 as i32 as u32) as i32;
@@ -171,7 +171,7 @@ unsafe fn lre_js_is_ident_next(mut c: i32) -> i32 {
     };
 }
 #[inline]
-unsafe fn lre_js_is_ident_first(mut c: i32) -> i32 {
+pub(crate) unsafe fn lre_js_is_ident_first(mut c: i32) -> i32 {
     if (c as u32) < 128 as i32 as u32 {
         return (lre_id_start_table_ascii[(c >> 5 as i32) as usize] >> (c & 31 as i32)
             & 1 as i32 as u32) as i32;
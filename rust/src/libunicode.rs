@@ -2,8 +2,10 @@ use std::process::abort;
 
 use crate::cutils::{
     cstr_find_char, cstr_len, dbuf_error, dbuf_init2, dbuf_put, dbuf_put_u32, dbuf_realloc,
-    global_realloc, ptr_compare, DynBuf, DynBufReallocFunc, PtrExt, BOOL, FALSE, TRUE,
+    global_realloc, ptr_compare, sized_realloc, DynBuf, DynBufReallocFunc, PtrExt, BOOL, FALSE,
+    TRUE,
 };
+use crate::unicode_transcode::{decode_wtf8_char, encode_wtf8_char};
 
 const POP_STACK_LEN_MAX: i32 = 4;
 
@@ -81,41 +83,6 @@ pub const DECOMP_TYPE_L4: C2RustUnnamed_5 = 4;
 pub const DECOMP_TYPE_L3: C2RustUnnamed_5 = 3;
 pub const DECOMP_TYPE_L2: C2RustUnnamed_5 = 2;
 pub const DECOMP_TYPE_C1: C2RustUnnamed_5 = 0;
-pub const UNICODE_SCRIPT_Inherited: C2RustUnnamed_2 = 55;
-pub const UNICODE_SCRIPT_Common: C2RustUnnamed_2 = 25;
-pub const UNICODE_SCRIPT_Unknown: C2RustUnnamed_2 = 0;
-pub const UNICODE_GC_Ll: C2RustUnnamed_1 = 2;
-pub const UNICODE_GC_Lu: C2RustUnnamed_1 = 1;
-pub const UNICODE_GC_LC: C2RustUnnamed_1 = 30;
-pub const UNICODE_GC_Cn: C2RustUnnamed_1 = 0;
-pub const UNICODE_GC_Co: C2RustUnnamed_1 = 29;
-pub const UNICODE_GC_Cs: C2RustUnnamed_1 = 28;
-pub const UNICODE_GC_Cf: C2RustUnnamed_1 = 27;
-pub const UNICODE_GC_Cc: C2RustUnnamed_1 = 26;
-pub const UNICODE_GC_Zp: C2RustUnnamed_1 = 25;
-pub const UNICODE_GC_Zl: C2RustUnnamed_1 = 24;
-pub const UNICODE_GC_Zs: C2RustUnnamed_1 = 23;
-pub const UNICODE_GC_Po: C2RustUnnamed_1 = 22;
-pub const UNICODE_GC_Pf: C2RustUnnamed_1 = 21;
-pub const UNICODE_GC_Pi: C2RustUnnamed_1 = 20;
-pub const UNICODE_GC_Pe: C2RustUnnamed_1 = 19;
-pub const UNICODE_GC_Ps: C2RustUnnamed_1 = 18;
-pub const UNICODE_GC_Pd: C2RustUnnamed_1 = 17;
-pub const UNICODE_GC_Pc: C2RustUnnamed_1 = 16;
-pub const UNICODE_GC_So: C2RustUnnamed_1 = 15;
-pub const UNICODE_GC_Sk: C2RustUnnamed_1 = 14;
-pub const UNICODE_GC_Sc: C2RustUnnamed_1 = 13;
-pub const UNICODE_GC_Sm: C2RustUnnamed_1 = 12;
-pub const UNICODE_GC_No: C2RustUnnamed_1 = 11;
-pub const UNICODE_GC_Nl: C2RustUnnamed_1 = 10;
-pub const UNICODE_GC_Nd: C2RustUnnamed_1 = 9;
-pub const UNICODE_GC_Me: C2RustUnnamed_1 = 8;
-pub const UNICODE_GC_Mc: C2RustUnnamed_1 = 7;
-pub const UNICODE_GC_Mn: C2RustUnnamed_1 = 6;
-pub const UNICODE_GC_Lo: C2RustUnnamed_1 = 5;
-pub const UNICODE_GC_Lm: C2RustUnnamed_1 = 4;
-pub const UNICODE_GC_Lt: C2RustUnnamed_1 = 3;
-pub const UNICODE_PROP_ID_Continue1: C2RustUnnamed_3 = 10;
 
 pub type OpcodePop = u32;
 pub const POP_PROP: OpcodePop = 1;
@@ -127,242 +94,6 @@ pub const POP_INTER: OpcodePop = 4;
 pub const POP_CASE: OpcodePop = 2;
 pub const POP_GC: OpcodePop = 0;
 
-pub const UNICODE_PROP_ID_Start: C2RustUnnamed_3 = 48;
-pub const UNICODE_PROP_ID_Continue: C2RustUnnamed_3 = 63;
-pub const UNICODE_PROP_Changes_When_NFKC_Casefolded1: C2RustUnnamed_3 = 15;
-pub const UNICODE_PROP_Changes_When_NFKC_Casefolded: C2RustUnnamed_3 = 58;
-pub const UNICODE_PROP_Changes_When_Casefolded1: C2RustUnnamed_3 = 14;
-pub const UNICODE_PROP_Changes_When_Casefolded: C2RustUnnamed_3 = 55;
-pub const UNICODE_PROP_Changes_When_Titlecased1: C2RustUnnamed_3 = 13;
-pub const UNICODE_PROP_Changes_When_Titlecased: C2RustUnnamed_3 = 59;
-pub const UNICODE_PROP_Changes_When_Casemapped: C2RustUnnamed_3 = 56;
-pub const UNICODE_PROP_Changes_When_Lowercased: C2RustUnnamed_3 = 57;
-pub const UNICODE_PROP_Changes_When_Uppercased: C2RustUnnamed_3 = 60;
-pub const UNICODE_PROP_XID_Continue1: C2RustUnnamed_3 = 12;
-pub const UNICODE_PROP_Pattern_White_Space: C2RustUnnamed_3 = 30;
-pub const UNICODE_PROP_Pattern_Syntax: C2RustUnnamed_3 = 29;
-pub const UNICODE_PROP_Other_ID_Continue: C2RustUnnamed_3 = 8;
-pub const UNICODE_PROP_Other_ID_Start: C2RustUnnamed_3 = 7;
-pub const UNICODE_PROP_XID_Continue: C2RustUnnamed_3 = 67;
-pub const UNICODE_PROP_XID_Start1: C2RustUnnamed_3 = 11;
-pub const UNICODE_PROP_XID_Start: C2RustUnnamed_3 = 68;
-pub const UNICODE_PROP_Other_Grapheme_Extend: C2RustUnnamed_3 = 5;
-pub const UNICODE_PROP_Grapheme_Extend: C2RustUnnamed_3 = 62;
-pub const UNICODE_PROP_Grapheme_Base: C2RustUnnamed_3 = 61;
-pub const UNICODE_PROP_Other_Alphabetic: C2RustUnnamed_3 = 2;
-pub const UNICODE_PROP_Other_Lowercase: C2RustUnnamed_3 = 3;
-pub const UNICODE_PROP_Other_Uppercase: C2RustUnnamed_3 = 4;
-pub const UNICODE_PROP_Alphabetic: C2RustUnnamed_3 = 51;
-pub const UNICODE_PROP_Cased: C2RustUnnamed_3 = 54;
-pub const UNICODE_PROP_Uppercase: C2RustUnnamed_3 = 66;
-pub const UNICODE_PROP_Lowercase: C2RustUnnamed_3 = 64;
-pub const UNICODE_PROP_Other_Math: C2RustUnnamed_3 = 1;
-pub const UNICODE_PROP_Math: C2RustUnnamed_3 = 65;
-pub const UNICODE_PROP_Assigned: C2RustUnnamed_3 = 53;
-pub const UNICODE_PROP_Any: C2RustUnnamed_3 = 52;
-pub const UNICODE_PROP_ASCII: C2RustUnnamed_3 = 50;
-pub const UNICODE_PROP_ASCII_Hex_Digit: C2RustUnnamed_3 = 16;
-pub type C2RustUnnamed_1 = u32;
-pub const UNICODE_GC_COUNT: C2RustUnnamed_1 = 38;
-pub const UNICODE_GC_C: C2RustUnnamed_1 = 37;
-pub const UNICODE_GC_Z: C2RustUnnamed_1 = 36;
-pub const UNICODE_GC_P: C2RustUnnamed_1 = 35;
-pub const UNICODE_GC_S: C2RustUnnamed_1 = 34;
-pub const UNICODE_GC_N: C2RustUnnamed_1 = 33;
-pub const UNICODE_GC_M: C2RustUnnamed_1 = 32;
-pub const UNICODE_GC_L: C2RustUnnamed_1 = 31;
-pub type C2RustUnnamed_2 = u32;
-pub const UNICODE_SCRIPT_COUNT: C2RustUnnamed_2 = 157;
-pub const UNICODE_SCRIPT_Zanabazar_Square: C2RustUnnamed_2 = 156;
-pub const UNICODE_SCRIPT_Yi: C2RustUnnamed_2 = 155;
-pub const UNICODE_SCRIPT_Yezidi: C2RustUnnamed_2 = 154;
-pub const UNICODE_SCRIPT_Warang_Citi: C2RustUnnamed_2 = 153;
-pub const UNICODE_SCRIPT_Wancho: C2RustUnnamed_2 = 152;
-pub const UNICODE_SCRIPT_Vai: C2RustUnnamed_2 = 151;
-pub const UNICODE_SCRIPT_Ugaritic: C2RustUnnamed_2 = 150;
-pub const UNICODE_SCRIPT_Tirhuta: C2RustUnnamed_2 = 149;
-pub const UNICODE_SCRIPT_Tifinagh: C2RustUnnamed_2 = 148;
-pub const UNICODE_SCRIPT_Tibetan: C2RustUnnamed_2 = 147;
-pub const UNICODE_SCRIPT_Thai: C2RustUnnamed_2 = 146;
-pub const UNICODE_SCRIPT_Thaana: C2RustUnnamed_2 = 145;
-pub const UNICODE_SCRIPT_Telugu: C2RustUnnamed_2 = 144;
-pub const UNICODE_SCRIPT_Tangut: C2RustUnnamed_2 = 143;
-pub const UNICODE_SCRIPT_Tamil: C2RustUnnamed_2 = 142;
-pub const UNICODE_SCRIPT_Takri: C2RustUnnamed_2 = 141;
-pub const UNICODE_SCRIPT_Tai_Viet: C2RustUnnamed_2 = 140;
-pub const UNICODE_SCRIPT_Tai_Tham: C2RustUnnamed_2 = 139;
-pub const UNICODE_SCRIPT_Tai_Le: C2RustUnnamed_2 = 138;
-pub const UNICODE_SCRIPT_Tagbanwa: C2RustUnnamed_2 = 137;
-pub const UNICODE_SCRIPT_Tagalog: C2RustUnnamed_2 = 136;
-pub const UNICODE_SCRIPT_Syriac: C2RustUnnamed_2 = 135;
-pub const UNICODE_SCRIPT_Syloti_Nagri: C2RustUnnamed_2 = 134;
-pub const UNICODE_SCRIPT_Sundanese: C2RustUnnamed_2 = 133;
-pub const UNICODE_SCRIPT_Soyombo: C2RustUnnamed_2 = 132;
-pub const UNICODE_SCRIPT_Sora_Sompeng: C2RustUnnamed_2 = 131;
-pub const UNICODE_SCRIPT_Sogdian: C2RustUnnamed_2 = 130;
-pub const UNICODE_SCRIPT_Sinhala: C2RustUnnamed_2 = 129;
-pub const UNICODE_SCRIPT_SignWriting: C2RustUnnamed_2 = 128;
-pub const UNICODE_SCRIPT_Siddham: C2RustUnnamed_2 = 127;
-pub const UNICODE_SCRIPT_Shavian: C2RustUnnamed_2 = 126;
-pub const UNICODE_SCRIPT_Sharada: C2RustUnnamed_2 = 125;
-pub const UNICODE_SCRIPT_Saurashtra: C2RustUnnamed_2 = 124;
-pub const UNICODE_SCRIPT_Samaritan: C2RustUnnamed_2 = 123;
-pub const UNICODE_SCRIPT_Runic: C2RustUnnamed_2 = 122;
-pub const UNICODE_SCRIPT_Rejang: C2RustUnnamed_2 = 121;
-pub const UNICODE_SCRIPT_Psalter_Pahlavi: C2RustUnnamed_2 = 120;
-pub const UNICODE_SCRIPT_Phoenician: C2RustUnnamed_2 = 119;
-pub const UNICODE_SCRIPT_Phags_Pa: C2RustUnnamed_2 = 118;
-pub const UNICODE_SCRIPT_Pau_Cin_Hau: C2RustUnnamed_2 = 117;
-pub const UNICODE_SCRIPT_Palmyrene: C2RustUnnamed_2 = 116;
-pub const UNICODE_SCRIPT_Pahawh_Hmong: C2RustUnnamed_2 = 115;
-pub const UNICODE_SCRIPT_Osmanya: C2RustUnnamed_2 = 114;
-pub const UNICODE_SCRIPT_Osage: C2RustUnnamed_2 = 113;
-pub const UNICODE_SCRIPT_Oriya: C2RustUnnamed_2 = 112;
-pub const UNICODE_SCRIPT_Old_Turkic: C2RustUnnamed_2 = 111;
-pub const UNICODE_SCRIPT_Old_South_Arabian: C2RustUnnamed_2 = 110;
-pub const UNICODE_SCRIPT_Old_Sogdian: C2RustUnnamed_2 = 109;
-pub const UNICODE_SCRIPT_Old_Persian: C2RustUnnamed_2 = 108;
-pub const UNICODE_SCRIPT_Old_Permic: C2RustUnnamed_2 = 107;
-pub const UNICODE_SCRIPT_Old_North_Arabian: C2RustUnnamed_2 = 106;
-pub const UNICODE_SCRIPT_Old_Italic: C2RustUnnamed_2 = 105;
-pub const UNICODE_SCRIPT_Old_Hungarian: C2RustUnnamed_2 = 104;
-pub const UNICODE_SCRIPT_Ol_Chiki: C2RustUnnamed_2 = 103;
-pub const UNICODE_SCRIPT_Ogham: C2RustUnnamed_2 = 102;
-pub const UNICODE_SCRIPT_Nyiakeng_Puachue_Hmong: C2RustUnnamed_2 = 101;
-pub const UNICODE_SCRIPT_Nushu: C2RustUnnamed_2 = 100;
-pub const UNICODE_SCRIPT_Nko: C2RustUnnamed_2 = 99;
-pub const UNICODE_SCRIPT_Newa: C2RustUnnamed_2 = 98;
-pub const UNICODE_SCRIPT_New_Tai_Lue: C2RustUnnamed_2 = 97;
-pub const UNICODE_SCRIPT_Nandinagari: C2RustUnnamed_2 = 96;
-pub const UNICODE_SCRIPT_Nabataean: C2RustUnnamed_2 = 95;
-pub const UNICODE_SCRIPT_Myanmar: C2RustUnnamed_2 = 94;
-pub const UNICODE_SCRIPT_Multani: C2RustUnnamed_2 = 93;
-pub const UNICODE_SCRIPT_Mro: C2RustUnnamed_2 = 92;
-pub const UNICODE_SCRIPT_Mongolian: C2RustUnnamed_2 = 91;
-pub const UNICODE_SCRIPT_Modi: C2RustUnnamed_2 = 90;
-pub const UNICODE_SCRIPT_Miao: C2RustUnnamed_2 = 89;
-pub const UNICODE_SCRIPT_Meroitic_Hieroglyphs: C2RustUnnamed_2 = 88;
-pub const UNICODE_SCRIPT_Meroitic_Cursive: C2RustUnnamed_2 = 87;
-pub const UNICODE_SCRIPT_Mende_Kikakui: C2RustUnnamed_2 = 86;
-pub const UNICODE_SCRIPT_Meetei_Mayek: C2RustUnnamed_2 = 85;
-pub const UNICODE_SCRIPT_Medefaidrin: C2RustUnnamed_2 = 84;
-pub const UNICODE_SCRIPT_Masaram_Gondi: C2RustUnnamed_2 = 83;
-pub const UNICODE_SCRIPT_Marchen: C2RustUnnamed_2 = 82;
-pub const UNICODE_SCRIPT_Manichaean: C2RustUnnamed_2 = 81;
-pub const UNICODE_SCRIPT_Mandaic: C2RustUnnamed_2 = 80;
-pub const UNICODE_SCRIPT_Malayalam: C2RustUnnamed_2 = 79;
-pub const UNICODE_SCRIPT_Mahajani: C2RustUnnamed_2 = 78;
-pub const UNICODE_SCRIPT_Makasar: C2RustUnnamed_2 = 77;
-pub const UNICODE_SCRIPT_Lydian: C2RustUnnamed_2 = 76;
-pub const UNICODE_SCRIPT_Lycian: C2RustUnnamed_2 = 75;
-pub const UNICODE_SCRIPT_Lisu: C2RustUnnamed_2 = 74;
-pub const UNICODE_SCRIPT_Linear_B: C2RustUnnamed_2 = 73;
-pub const UNICODE_SCRIPT_Linear_A: C2RustUnnamed_2 = 72;
-pub const UNICODE_SCRIPT_Limbu: C2RustUnnamed_2 = 71;
-pub const UNICODE_SCRIPT_Lepcha: C2RustUnnamed_2 = 70;
-pub const UNICODE_SCRIPT_Latin: C2RustUnnamed_2 = 69;
-pub const UNICODE_SCRIPT_Lao: C2RustUnnamed_2 = 68;
-pub const UNICODE_SCRIPT_Khudawadi: C2RustUnnamed_2 = 67;
-pub const UNICODE_SCRIPT_Khitan_Small_Script: C2RustUnnamed_2 = 66;
-pub const UNICODE_SCRIPT_Khojki: C2RustUnnamed_2 = 65;
-pub const UNICODE_SCRIPT_Khmer: C2RustUnnamed_2 = 64;
-pub const UNICODE_SCRIPT_Kharoshthi: C2RustUnnamed_2 = 63;
-pub const UNICODE_SCRIPT_Kayah_Li: C2RustUnnamed_2 = 62;
-pub const UNICODE_SCRIPT_Katakana: C2RustUnnamed_2 = 61;
-pub const UNICODE_SCRIPT_Kannada: C2RustUnnamed_2 = 60;
-pub const UNICODE_SCRIPT_Kaithi: C2RustUnnamed_2 = 59;
-pub const UNICODE_SCRIPT_Javanese: C2RustUnnamed_2 = 58;
-pub const UNICODE_SCRIPT_Inscriptional_Parthian: C2RustUnnamed_2 = 57;
-pub const UNICODE_SCRIPT_Inscriptional_Pahlavi: C2RustUnnamed_2 = 56;
-pub const UNICODE_SCRIPT_Imperial_Aramaic: C2RustUnnamed_2 = 54;
-pub const UNICODE_SCRIPT_Hiragana: C2RustUnnamed_2 = 53;
-pub const UNICODE_SCRIPT_Hebrew: C2RustUnnamed_2 = 52;
-pub const UNICODE_SCRIPT_Hatran: C2RustUnnamed_2 = 51;
-pub const UNICODE_SCRIPT_Hanunoo: C2RustUnnamed_2 = 50;
-pub const UNICODE_SCRIPT_Hanifi_Rohingya: C2RustUnnamed_2 = 49;
-pub const UNICODE_SCRIPT_Hangul: C2RustUnnamed_2 = 48;
-pub const UNICODE_SCRIPT_Han: C2RustUnnamed_2 = 47;
-pub const UNICODE_SCRIPT_Gurmukhi: C2RustUnnamed_2 = 46;
-pub const UNICODE_SCRIPT_Gunjala_Gondi: C2RustUnnamed_2 = 45;
-pub const UNICODE_SCRIPT_Gujarati: C2RustUnnamed_2 = 44;
-pub const UNICODE_SCRIPT_Greek: C2RustUnnamed_2 = 43;
-pub const UNICODE_SCRIPT_Grantha: C2RustUnnamed_2 = 42;
-pub const UNICODE_SCRIPT_Gothic: C2RustUnnamed_2 = 41;
-pub const UNICODE_SCRIPT_Glagolitic: C2RustUnnamed_2 = 40;
-pub const UNICODE_SCRIPT_Georgian: C2RustUnnamed_2 = 39;
-pub const UNICODE_SCRIPT_Ethiopic: C2RustUnnamed_2 = 38;
-pub const UNICODE_SCRIPT_Elymaic: C2RustUnnamed_2 = 37;
-pub const UNICODE_SCRIPT_Elbasan: C2RustUnnamed_2 = 36;
-pub const UNICODE_SCRIPT_Egyptian_Hieroglyphs: C2RustUnnamed_2 = 35;
-pub const UNICODE_SCRIPT_Duployan: C2RustUnnamed_2 = 34;
-pub const UNICODE_SCRIPT_Dogra: C2RustUnnamed_2 = 33;
-pub const UNICODE_SCRIPT_Dives_Akuru: C2RustUnnamed_2 = 32;
-pub const UNICODE_SCRIPT_Devanagari: C2RustUnnamed_2 = 31;
-pub const UNICODE_SCRIPT_Deseret: C2RustUnnamed_2 = 30;
-pub const UNICODE_SCRIPT_Cyrillic: C2RustUnnamed_2 = 29;
-pub const UNICODE_SCRIPT_Cypriot: C2RustUnnamed_2 = 28;
-pub const UNICODE_SCRIPT_Cuneiform: C2RustUnnamed_2 = 27;
-pub const UNICODE_SCRIPT_Coptic: C2RustUnnamed_2 = 26;
-pub const UNICODE_SCRIPT_Chorasmian: C2RustUnnamed_2 = 24;
-pub const UNICODE_SCRIPT_Cherokee: C2RustUnnamed_2 = 23;
-pub const UNICODE_SCRIPT_Cham: C2RustUnnamed_2 = 22;
-pub const UNICODE_SCRIPT_Chakma: C2RustUnnamed_2 = 21;
-pub const UNICODE_SCRIPT_Caucasian_Albanian: C2RustUnnamed_2 = 20;
-pub const UNICODE_SCRIPT_Carian: C2RustUnnamed_2 = 19;
-pub const UNICODE_SCRIPT_Canadian_Aboriginal: C2RustUnnamed_2 = 18;
-pub const UNICODE_SCRIPT_Buhid: C2RustUnnamed_2 = 17;
-pub const UNICODE_SCRIPT_Buginese: C2RustUnnamed_2 = 16;
-pub const UNICODE_SCRIPT_Braille: C2RustUnnamed_2 = 15;
-pub const UNICODE_SCRIPT_Brahmi: C2RustUnnamed_2 = 14;
-pub const UNICODE_SCRIPT_Bopomofo: C2RustUnnamed_2 = 13;
-pub const UNICODE_SCRIPT_Bhaiksuki: C2RustUnnamed_2 = 12;
-pub const UNICODE_SCRIPT_Bengali: C2RustUnnamed_2 = 11;
-pub const UNICODE_SCRIPT_Batak: C2RustUnnamed_2 = 10;
-pub const UNICODE_SCRIPT_Bassa_Vah: C2RustUnnamed_2 = 9;
-pub const UNICODE_SCRIPT_Bamum: C2RustUnnamed_2 = 8;
-pub const UNICODE_SCRIPT_Balinese: C2RustUnnamed_2 = 7;
-pub const UNICODE_SCRIPT_Avestan: C2RustUnnamed_2 = 6;
-pub const UNICODE_SCRIPT_Armenian: C2RustUnnamed_2 = 5;
-pub const UNICODE_SCRIPT_Arabic: C2RustUnnamed_2 = 4;
-pub const UNICODE_SCRIPT_Anatolian_Hieroglyphs: C2RustUnnamed_2 = 3;
-pub const UNICODE_SCRIPT_Ahom: C2RustUnnamed_2 = 2;
-pub const UNICODE_SCRIPT_Adlam: C2RustUnnamed_2 = 1;
-pub type C2RustUnnamed_3 = u32;
-pub const UNICODE_PROP_COUNT: C2RustUnnamed_3 = 70;
-pub const UNICODE_PROP_Cased1: C2RustUnnamed_3 = 69;
-pub const UNICODE_PROP_Case_Ignorable: C2RustUnnamed_3 = 49;
-pub const UNICODE_PROP_Default_Ignorable_Code_Point: C2RustUnnamed_3 = 47;
-pub const UNICODE_PROP_Extended_Pictographic: C2RustUnnamed_3 = 46;
-pub const UNICODE_PROP_Emoji_Presentation: C2RustUnnamed_3 = 45;
-pub const UNICODE_PROP_Emoji_Modifier_Base: C2RustUnnamed_3 = 44;
-pub const UNICODE_PROP_Emoji_Modifier: C2RustUnnamed_3 = 43;
-pub const UNICODE_PROP_Emoji_Component: C2RustUnnamed_3 = 42;
-pub const UNICODE_PROP_Emoji: C2RustUnnamed_3 = 41;
-pub const UNICODE_PROP_Bidi_Mirrored: C2RustUnnamed_3 = 40;
-pub const UNICODE_PROP_White_Space: C2RustUnnamed_3 = 39;
-pub const UNICODE_PROP_Variation_Selector: C2RustUnnamed_3 = 38;
-pub const UNICODE_PROP_Unified_Ideograph: C2RustUnnamed_3 = 37;
-pub const UNICODE_PROP_Terminal_Punctuation: C2RustUnnamed_3 = 36;
-pub const UNICODE_PROP_Soft_Dotted: C2RustUnnamed_3 = 35;
-pub const UNICODE_PROP_Sentence_Terminal: C2RustUnnamed_3 = 34;
-pub const UNICODE_PROP_Regional_Indicator: C2RustUnnamed_3 = 33;
-pub const UNICODE_PROP_Radical: C2RustUnnamed_3 = 32;
-pub const UNICODE_PROP_Quotation_Mark: C2RustUnnamed_3 = 31;
-pub const UNICODE_PROP_Noncharacter_Code_Point: C2RustUnnamed_3 = 28;
-pub const UNICODE_PROP_Logical_Order_Exception: C2RustUnnamed_3 = 27;
-pub const UNICODE_PROP_Join_Control: C2RustUnnamed_3 = 26;
-pub const UNICODE_PROP_Ideographic: C2RustUnnamed_3 = 25;
-pub const UNICODE_PROP_IDS_Trinary_Operator: C2RustUnnamed_3 = 24;
-pub const UNICODE_PROP_IDS_Binary_Operator: C2RustUnnamed_3 = 23;
-pub const UNICODE_PROP_Hex_Digit: C2RustUnnamed_3 = 22;
-pub const UNICODE_PROP_Extender: C2RustUnnamed_3 = 21;
-pub const UNICODE_PROP_Diacritic: C2RustUnnamed_3 = 20;
-pub const UNICODE_PROP_Deprecated: C2RustUnnamed_3 = 19;
-pub const UNICODE_PROP_Dash: C2RustUnnamed_3 = 18;
-pub const UNICODE_PROP_Bidi_Control: C2RustUnnamed_3 = 17;
-pub const UNICODE_PROP_Prepended_Concatenation_Mark: C2RustUnnamed_3 = 9;
-pub const UNICODE_PROP_Other_Default_Ignorable_Code_Point: C2RustUnnamed_3 = 6;
-pub const UNICODE_PROP_Hyphen: C2RustUnnamed_3 = 0;
 /*
  * Unicode utilities
  *
@@ -409,7 +140,7 @@ unsafe fn cr_add_point(mut cr: *mut CharRange, mut v: u32) -> i32 {
     return 0 as i32;
 }
 #[inline]
-unsafe fn cr_add_interval(mut cr: *mut CharRange, mut c1: u32, mut c2: u32) -> i32 {
+pub(crate) unsafe fn cr_add_interval(mut cr: *mut CharRange, mut c1: u32, mut c2: u32) -> i32 {
     if (*cr).len + 2 as i32 > (*cr).size {
         if cr_realloc(cr, (*cr).len + 2 as i32) != 0 {
             return -(1 as i32);
@@ -423,27040 +154,8 @@ unsafe fn cr_add_interval(mut cr: *mut CharRange, mut c1: u32, mut c2: u32) -> i
     *(*cr).points.offset(fresh2 as isize) = c2;
     return 0 as i32;
 }
-static mut case_conv_table1: [u32; 361] = [
-    0x209a30 as i32 as u32,
-    0x309a00 as i32 as u32,
-    0x5a8173 as i32 as u32,
-    0x601730 as i32 as u32,
-    0x6c0730 as i32 as u32,
-    0x6f81b3 as i32 as u32,
-    0x701700 as i32 as u32,
-    0x7c0700 as i32 as u32,
-    0x7f8100 as i32 as u32,
-    0x803040 as i32 as u32,
-    0x9801c3 as i32 as u32,
-    0x988190 as i32 as u32,
-    0x990640 as i32 as u32,
-    0x9c9040 as i32 as u32,
-    0xa481b4 as i32 as u32,
-    0xa52e40 as i32 as u32,
-    0xbc0130 as i32 as u32,
-    0xbc8640 as i32 as u32,
-    0xbf8170 as i32 as u32,
-    0xc00100 as i32 as u32,
-    0xc08130 as i32 as u32,
-    0xc10440 as i32 as u32,
-    0xc30130 as i32 as u32,
-    0xc38240 as i32 as u32,
-    0xc48230 as i32 as u32,
-    0xc58240 as i32 as u32,
-    0xc70130 as i32 as u32,
-    0xc78130 as i32 as u32,
-    0xc80130 as i32 as u32,
-    0xc88240 as i32 as u32,
-    0xc98130 as i32 as u32,
-    0xca0130 as i32 as u32,
-    0xca8100 as i32 as u32,
-    0xcb0130 as i32 as u32,
-    0xcb8130 as i32 as u32,
-    0xcc0240 as i32 as u32,
-    0xcd0100 as i32 as u32,
-    0xce0130 as i32 as u32,
-    0xce8130 as i32 as u32,
-    0xcf0100 as i32 as u32,
-    0xcf8130 as i32 as u32,
-    0xd00640 as i32 as u32,
-    0xd30130 as i32 as u32,
-    0xd38240 as i32 as u32,
-    0xd48130 as i32 as u32,
-    0xd60240 as i32 as u32,
-    0xd70130 as i32 as u32,
-    0xd78240 as i32 as u32,
-    0xd88230 as i32 as u32,
-    0xd98440 as i32 as u32,
-    0xdb8130 as i32 as u32,
-    0xdc0240 as i32 as u32,
-    0xde0240 as i32 as u32,
-    0xdf8100 as i32 as u32,
-    0xe20350 as i32 as u32,
-    0xe38350 as i32 as u32,
-    0xe50350 as i32 as u32,
-    0xe69040 as i32 as u32,
-    0xee8100 as i32 as u32,
-    0xef1240 as i32 as u32,
-    0xf801b4 as i32 as u32,
-    0xf88350 as i32 as u32,
-    0xfa0240 as i32 as u32,
-    0xfb0130 as i32 as u32,
-    0xfb8130 as i32 as u32,
-    0xfc2840 as i32 as u32,
-    0x1100130 as i32 as u32,
-    0x1111240 as i32 as u32,
-    0x11d0131 as i32 as u32,
-    0x11d8240 as i32 as u32,
-    0x11e8130 as i32 as u32,
-    0x11f0131 as i32 as u32,
-    0x11f8201 as i32 as u32,
-    0x1208240 as i32 as u32,
-    0x1218130 as i32 as u32,
-    0x1220130 as i32 as u32,
-    0x1228130 as i32 as u32,
-    0x1230a40 as i32 as u32,
-    0x1280101 as i32 as u32,
-    0x1288101 as i32 as u32,
-    0x1290101 as i32 as u32,
-    0x1298100 as i32 as u32,
-    0x12a0100 as i32 as u32,
-    0x12b0200 as i32 as u32,
-    0x12c8100 as i32 as u32,
-    0x12d8100 as i32 as u32,
-    0x12e0101 as i32 as u32,
-    0x1300100 as i32 as u32,
-    0x1308101 as i32 as u32,
-    0x1318100 as i32 as u32,
-    0x1328101 as i32 as u32,
-    0x1330101 as i32 as u32,
-    0x1340100 as i32 as u32,
-    0x1348100 as i32 as u32,
-    0x1350101 as i32 as u32,
-    0x1358101 as i32 as u32,
-    0x1360101 as i32 as u32,
-    0x1378100 as i32 as u32,
-    0x1388101 as i32 as u32,
-    0x1390100 as i32 as u32,
-    0x13a8100 as i32 as u32,
-    0x13e8101 as i32 as u32,
-    0x1400100 as i32 as u32,
-    0x1410101 as i32 as u32,
-    0x1418100 as i32 as u32,
-    0x1438101 as i32 as u32,
-    0x1440100 as i32 as u32,
-    0x1448100 as i32 as u32,
-    0x1450200 as i32 as u32,
-    0x1460100 as i32 as u32,
-    0x1490100 as i32 as u32,
-    0x14e8101 as i32 as u32,
-    0x14f0101 as i32 as u32,
-    0x1a28173 as i32 as u32,
-    0x1b80440 as i32 as u32,
-    0x1bb0240 as i32 as u32,
-    0x1bd8300 as i32 as u32,
-    0x1bf8130 as i32 as u32,
-    0x1c30130 as i32 as u32,
-    0x1c40330 as i32 as u32,
-    0x1c60130 as i32 as u32,
-    0x1c70230 as i32 as u32,
-    0x1c801d0 as i32 as u32,
-    0x1c89130 as i32 as u32,
-    0x1d18930 as i32 as u32,
-    0x1d60100 as i32 as u32,
-    0x1d68300 as i32 as u32,
-    0x1d801d3 as i32 as u32,
-    0x1d89100 as i32 as u32,
-    0x1e10173 as i32 as u32,
-    0x1e18900 as i32 as u32,
-    0x1e60100 as i32 as u32,
-    0x1e68200 as i32 as u32,
-    0x1e78130 as i32 as u32,
-    0x1e80173 as i32 as u32,
-    0x1e88173 as i32 as u32,
-    0x1ea8173 as i32 as u32,
-    0x1eb0173 as i32 as u32,
-    0x1eb8100 as i32 as u32,
-    0x1ec1840 as i32 as u32,
-    0x1f80173 as i32 as u32,
-    0x1f88173 as i32 as u32,
-    0x1f90100 as i32 as u32,
-    0x1f98100 as i32 as u32,
-    0x1fa01a0 as i32 as u32,
-    0x1fa8173 as i32 as u32,
-    0x1fb8240 as i32 as u32,
-    0x1fc8130 as i32 as u32,
-    0x1fd0240 as i32 as u32,
-    0x1fe8330 as i32 as u32,
-    0x2001030 as i32 as u32,
-    0x2082030 as i32 as u32,
-    0x2182000 as i32 as u32,
-    0x2281000 as i32 as u32,
-    0x2302240 as i32 as u32,
-    0x2453640 as i32 as u32,
-    0x2600130 as i32 as u32,
-    0x2608e40 as i32 as u32,
-    0x2678100 as i32 as u32,
-    0x2686040 as i32 as u32,
-    0x298a630 as i32 as u32,
-    0x2b0a600 as i32 as u32,
-    0x2c381b5 as i32 as u32,
-    0x8502631 as i32 as u32,
-    0x8638131 as i32 as u32,
-    0x8668131 as i32 as u32,
-    0x8682b00 as i32 as u32,
-    0x87e8300 as i32 as u32,
-    0x9d05011 as i32 as u32,
-    0x9f80610 as i32 as u32,
-    0x9fc0620 as i32 as u32,
-    0xe400174 as i32 as u32,
-    0xe408174 as i32 as u32,
-    0xe410174 as i32 as u32,
-    0xe418174 as i32 as u32,
-    0xe420174 as i32 as u32,
-    0xe428174 as i32 as u32,
-    0xe430174 as i32 as u32,
-    0xe438180 as i32 as u32,
-    0xe440180 as i32 as u32,
-    0xe482b30 as i32 as u32,
-    0xe5e8330 as i32 as u32,
-    0xebc8101 as i32 as u32,
-    0xebe8101 as i32 as u32,
-    0xec70101 as i32 as u32,
-    0xf007e40 as i32 as u32,
-    0xf3f1840 as i32 as u32,
-    0xf4b01b5 as i32 as u32,
-    0xf4b81b6 as i32 as u32,
-    0xf4c01b6 as i32 as u32,
-    0xf4c81b6 as i32 as u32,
-    0xf4d01b7 as i32 as u32,
-    0xf4d8180 as i32 as u32,
-    0xf4f0130 as i32 as u32,
-    0xf506040 as i32 as u32,
-    0xf800800 as i32 as u32,
-    0xf840830 as i32 as u32,
-    0xf880600 as i32 as u32,
-    0xf8c0630 as i32 as u32,
-    0xf900800 as i32 as u32,
-    0xf940830 as i32 as u32,
-    0xf980800 as i32 as u32,
-    0xf9c0830 as i32 as u32,
-    0xfa00600 as i32 as u32,
-    0xfa40630 as i32 as u32,
-    0xfa801b0 as i32 as u32,
-    0xfa88100 as i32 as u32,
-    0xfa901d3 as i32 as u32,
-    0xfa98100 as i32 as u32,
-    0xfaa01d3 as i32 as u32,
-    0xfaa8100 as i32 as u32,
-    0xfab01d3 as i32 as u32,
-    0xfab8100 as i32 as u32,
-    0xfac8130 as i32 as u32,
-    0xfad8130 as i32 as u32,
-    0xfae8130 as i32 as u32,
-    0xfaf8130 as i32 as u32,
-    0xfb00800 as i32 as u32,
-    0xfb40830 as i32 as u32,
-    0xfb80200 as i32 as u32,
-    0xfb90400 as i32 as u32,
-    0xfbb0200 as i32 as u32,
-    0xfbc0201 as i32 as u32,
-    0xfbd0201 as i32 as u32,
-    0xfbe0201 as i32 as u32,
-    0xfc008b7 as i32 as u32,
-    0xfc40867 as i32 as u32,
-    0xfc808b8 as i32 as u32,
-    0xfcc0868 as i32 as u32,
-    0xfd008b8 as i32 as u32,
-    0xfd40868 as i32 as u32,
-    0xfd80200 as i32 as u32,
-    0xfd901b9 as i32 as u32,
-    0xfd981b1 as i32 as u32,
-    0xfda01b9 as i32 as u32,
-    0xfdb01b1 as i32 as u32,
-    0xfdb81d7 as i32 as u32,
-    0xfdc0230 as i32 as u32,
-    0xfdd0230 as i32 as u32,
-    0xfde0161 as i32 as u32,
-    0xfdf0173 as i32 as u32,
-    0xfe101b9 as i32 as u32,
-    0xfe181b2 as i32 as u32,
-    0xfe201ba as i32 as u32,
-    0xfe301b2 as i32 as u32,
-    0xfe381d8 as i32 as u32,
-    0xfe40430 as i32 as u32,
-    0xfe60162 as i32 as u32,
-    0xfe80200 as i32 as u32,
-    0xfe901d0 as i32 as u32,
-    0xfe981d0 as i32 as u32,
-    0xfeb01b0 as i32 as u32,
-    0xfeb81d0 as i32 as u32,
-    0xfec0230 as i32 as u32,
-    0xfed0230 as i32 as u32,
-    0xff00201 as i32 as u32,
-    0xff101d3 as i32 as u32,
-    0xff181d3 as i32 as u32,
-    0xff201ba as i32 as u32,
-    0xff28101 as i32 as u32,
-    0xff301b0 as i32 as u32,
-    0xff381d3 as i32 as u32,
-    0xff40230 as i32 as u32,
-    0xff50230 as i32 as u32,
-    0xff60131 as i32 as u32,
-    0xff901ba as i32 as u32,
-    0xff981b2 as i32 as u32,
-    0xffa01bb as i32 as u32,
-    0xffb01b2 as i32 as u32,
-    0xffb81d9 as i32 as u32,
-    0xffc0230 as i32 as u32,
-    0xffd0230 as i32 as u32,
-    0xffe0162 as i32 as u32,
-    0x109301a0 as i32 as u32,
-    0x109501a0 as i32 as u32,
-    0x109581a0 as i32 as u32,
-    0x10990131 as i32 as u32,
-    0x10a70101 as i32 as u32,
-    0x10b01031 as i32 as u32,
-    0x10b81001 as i32 as u32,
-    0x10c18240 as i32 as u32,
-    0x125b1a31 as i32 as u32,
-    0x12681a01 as i32 as u32,
-    0x16002f31 as i32 as u32,
-    0x16182f01 as i32 as u32,
-    0x16300240 as i32 as u32,
-    0x16310130 as i32 as u32,
-    0x16318130 as i32 as u32,
-    0x16320130 as i32 as u32,
-    0x16328100 as i32 as u32,
-    0x16330100 as i32 as u32,
-    0x16338640 as i32 as u32,
-    0x16368130 as i32 as u32,
-    0x16370130 as i32 as u32,
-    0x16378130 as i32 as u32,
-    0x16380130 as i32 as u32,
-    0x16390240 as i32 as u32,
-    0x163a8240 as i32 as u32,
-    0x163f0230 as i32 as u32,
-    0x16406440 as i32 as u32,
-    0x16758440 as i32 as u32,
-    0x16790240 as i32 as u32,
-    0x16802600 as i32 as u32,
-    0x16938100 as i32 as u32,
-    0x16968100 as i32 as u32,
-    0x53202e40 as i32 as u32,
-    0x53401c40 as i32 as u32,
-    0x53910e40 as i32 as u32,
-    0x53993e40 as i32 as u32,
-    0x53bc8440 as i32 as u32,
-    0x53be8130 as i32 as u32,
-    0x53bf0a40 as i32 as u32,
-    0x53c58240 as i32 as u32,
-    0x53c68130 as i32 as u32,
-    0x53c80440 as i32 as u32,
-    0x53ca0101 as i32 as u32,
-    0x53cb1440 as i32 as u32,
-    0x53d50130 as i32 as u32,
-    0x53d58130 as i32 as u32,
-    0x53d60130 as i32 as u32,
-    0x53d68130 as i32 as u32,
-    0x53d70130 as i32 as u32,
-    0x53d80130 as i32 as u32,
-    0x53d88130 as i32 as u32,
-    0x53d90130 as i32 as u32,
-    0x53d98131 as i32 as u32,
-    0x53da0c40 as i32 as u32,
-    0x53e10240 as i32 as u32,
-    0x53e20131 as i32 as u32,
-    0x53e28130 as i32 as u32,
-    0x53e30130 as i32 as u32,
-    0x53e38440 as i32 as u32,
-    0x53fa8240 as i32 as u32,
-    0x55a98101 as i32 as u32,
-    0x55b85020 as i32 as u32,
-    0x7d8001b2 as i32 as u32,
-    0x7d8081b2 as i32 as u32,
-    0x7d8101b2 as i32 as u32,
-    0x7d8181da as i32 as u32,
-    0x7d8201da as i32 as u32,
-    0x7d8281b3 as i32 as u32,
-    0x7d8301b3 as i32 as u32,
-    0x7d8981bb as i32 as u32,
-    0x7d8a01bb as i32 as u32,
-    0x7d8a81bb as i32 as u32,
-    0x7d8b01bc as i32 as u32,
-    0x7d8b81bb as i32 as u32,
-    0x7f909a31 as i32 as u32,
-    0x7fa09a01 as i32 as u32,
-    0x82002831 as u32,
-    0x82142801 as u32,
-    0x82582431 as u32,
-    0x826c2401 as u32,
-    0x86403331 as u32,
-    0x86603301 as u32,
-    0x8c502031 as u32,
-    0x8c602001 as u32,
-    0xb7202031 as u32,
-    0xb7302001 as u32,
-    0xf4802231 as u32,
-    0xf4912201 as u32,
-];
-static mut case_conv_table2: [u8; 361] = [
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x9c as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x4d as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0x8f as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x53 as i32 as u8,
-    0x4a as i32 as u8,
-    0x51 as i32 as u8,
-    0 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x3a as i32 as u8,
-    0x54 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0x59 as i32 as u8,
-    0x3f as i32 as u8,
-    0x5d as i32 as u8,
-    0x5c as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x61 as i32 as u8,
-    0x63 as i32 as u8,
-    0x42 as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x66 as i32 as u8,
-    0 as i32 as u8,
-    0x68 as i32 as u8,
-    0 as i32 as u8,
-    0x6a as i32 as u8,
-    0 as i32 as u8,
-    0x6c as i32 as u8,
-    0 as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1a as i32 as u8,
-    0 as i32 as u8,
-    0x93 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x35 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x24 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x6b as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x24 as i32 as u8,
-    0x27 as i32 as u8,
-    0x14 as i32 as u8,
-    0x16 as i32 as u8,
-    0x18 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1c as i32 as u8,
-    0x3e as i32 as u8,
-    0x1e as i32 as u8,
-    0x3f as i32 as u8,
-    0x1f as i32 as u8,
-    0x39 as i32 as u8,
-    0x3d as i32 as u8,
-    0x22 as i32 as u8,
-    0x21 as i32 as u8,
-    0x41 as i32 as u8,
-    0x1e as i32 as u8,
-    0x40 as i32 as u8,
-    0x25 as i32 as u8,
-    0x25 as i32 as u8,
-    0x26 as i32 as u8,
-    0x28 as i32 as u8,
-    0x20 as i32 as u8,
-    0x2a as i32 as u8,
-    0x49 as i32 as u8,
-    0x2c as i32 as u8,
-    0x43 as i32 as u8,
-    0x2e as i32 as u8,
-    0x4b as i32 as u8,
-    0x30 as i32 as u8,
-    0x4c as i32 as u8,
-    0x32 as i32 as u8,
-    0x44 as i32 as u8,
-    0x42 as i32 as u8,
-    0x99 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x95 as i32 as u8,
-    0x8f as i32 as u8,
-    0x7d as i32 as u8,
-    0x7e as i32 as u8,
-    0x83 as i32 as u8,
-    0x84 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x82 as i32 as u8,
-    0x76 as i32 as u8,
-    0x77 as i32 as u8,
-    0x12 as i32 as u8,
-    0x7b as i32 as u8,
-    0xa3 as i32 as u8,
-    0x7c as i32 as u8,
-    0x78 as i32 as u8,
-    0x79 as i32 as u8,
-    0x8a as i32 as u8,
-    0x92 as i32 as u8,
-    0x98 as i32 as u8,
-    0xa6 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0x9a as i32 as u8,
-    0xa1 as i32 as u8,
-    0x93 as i32 as u8,
-    0x75 as i32 as u8,
-    0x33 as i32 as u8,
-    0x95 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x74 as i32 as u8,
-    0x99 as i32 as u8,
-    0x98 as i32 as u8,
-    0x97 as i32 as u8,
-    0x96 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9e as i32 as u8,
-    0 as i32 as u8,
-    0x9c as i32 as u8,
-    0 as i32 as u8,
-    0xa1 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x15 as i32 as u8,
-    0x2e as i32 as u8,
-    0x2f as i32 as u8,
-    0x30 as i32 as u8,
-    0xb4 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x4e as i32 as u8,
-    0xaa as i32 as u8,
-    0xa9 as i32 as u8,
-    0x12 as i32 as u8,
-    0x14 as i32 as u8,
-    0x1e as i32 as u8,
-    0x21 as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2a as i32 as u8,
-    0x34 as i32 as u8,
-    0x35 as i32 as u8,
-    0xa6 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x36 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x1 as i32 as u8,
-    0x5a as i32 as u8,
-    0xda as i32 as u8,
-    0x1d as i32 as u8,
-    0x36 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xc3 as i32 as u8,
-    0xc6 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xc8 as i32 as u8,
-    0xc7 as i32 as u8,
-    0xca as i32 as u8,
-    0xc9 as i32 as u8,
-    0xcc as i32 as u8,
-    0xcb as i32 as u8,
-    0xc4 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x45 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x42 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x46 as i32 as u8,
-    0xd8 as i32 as u8,
-    0xce as i32 as u8,
-    0xd0 as i32 as u8,
-    0xd2 as i32 as u8,
-    0xd4 as i32 as u8,
-    0xda as i32 as u8,
-    0xd9 as i32 as u8,
-    0xee as i32 as u8,
-    0xf6 as i32 as u8,
-    0xfe as i32 as u8,
-    0xe as i32 as u8,
-    0x7 as i32 as u8,
-    0xf as i32 as u8,
-    0x80 as i32 as u8,
-    0x9f as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xed as i32 as u8,
-    0 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe7 as i32 as u8,
-    0xdb as i32 as u8,
-    0xe6 as i32 as u8,
-    0x99 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x60 as i32 as u8,
-    0xdc as i32 as u8,
-    0x29 as i32 as u8,
-    0xfd as i32 as u8,
-    0x15 as i32 as u8,
-    0x12 as i32 as u8,
-    0x6 as i32 as u8,
-    0x16 as i32 as u8,
-    0xf8 as i32 as u8,
-    0xdd as i32 as u8,
-    0x6 as i32 as u8,
-    0x15 as i32 as u8,
-    0x12 as i32 as u8,
-    0x84 as i32 as u8,
-    0x8 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x16 as i32 as u8,
-    0xff as i32 as u8,
-    0xdf as i32 as u8,
-    0x3 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x40 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x60 as i32 as u8,
-    0xde as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6d as i32 as u8,
-    0x37 as i32 as u8,
-    0x38 as i32 as u8,
-    0x39 as i32 as u8,
-    0x15 as i32 as u8,
-    0x14 as i32 as u8,
-    0x17 as i32 as u8,
-    0x16 as i32 as u8,
-    0 as i32 as u8,
-    0x1a as i32 as u8,
-    0x19 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0xb7 as i32 as u8,
-    0x65 as i32 as u8,
-    0x44 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x4f as i32 as u8,
-    0x62 as i32 as u8,
-    0x4e as i32 as u8,
-    0x50 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xa4 as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5a as i32 as u8,
-    0 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0x5b as i32 as u8,
-    0x56 as i32 as u8,
-    0x58 as i32 as u8,
-    0x60 as i32 as u8,
-    0x5e as i32 as u8,
-    0x70 as i32 as u8,
-    0x69 as i32 as u8,
-    0x6f as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3b as i32 as u8,
-    0x67 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x8a as i32 as u8,
-    0x8b as i32 as u8,
-    0x8c as i32 as u8,
-    0xab as i32 as u8,
-    0xac as i32 as u8,
-    0x58 as i32 as u8,
-    0x58 as i32 as u8,
-    0xaf as i32 as u8,
-    0x94 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x6f as i32 as u8,
-    0xb2 as i32 as u8,
-    0x5c as i32 as u8,
-    0x5b as i32 as u8,
-    0x5e as i32 as u8,
-    0x5d as i32 as u8,
-    0x60 as i32 as u8,
-    0x5f as i32 as u8,
-    0x62 as i32 as u8,
-    0x61 as i32 as u8,
-    0x64 as i32 as u8,
-    0x63 as i32 as u8,
-    0x66 as i32 as u8,
-    0x65 as i32 as u8,
-    0x68 as i32 as u8,
-    0x67 as i32 as u8,
-];
-static mut case_conv_ext: [u16; 58] = [
-    0x399 as i32 as u16,
-    0x308 as i32 as u16,
-    0x301 as i32 as u16,
-    0x3a5 as i32 as u16,
-    0x313 as i32 as u16,
-    0x300 as i32 as u16,
-    0x342 as i32 as u16,
-    0x391 as i32 as u16,
-    0x397 as i32 as u16,
-    0x3a9 as i32 as u16,
-    0x46 as i32 as u16,
-    0x49 as i32 as u16,
-    0x4c as i32 as u16,
-    0x53 as i32 as u16,
-    0x69 as i32 as u16,
-    0x307 as i32 as u16,
-    0x2bc as i32 as u16,
-    0x4e as i32 as u16,
-    0x4a as i32 as u16,
-    0x30c as i32 as u16,
-    0x535 as i32 as u16,
-    0x552 as i32 as u16,
-    0x48 as i32 as u16,
-    0x331 as i32 as u16,
-    0x54 as i32 as u16,
-    0x57 as i32 as u16,
-    0x30a as i32 as u16,
-    0x59 as i32 as u16,
-    0x41 as i32 as u16,
-    0x2be as i32 as u16,
-    0x1f08 as i32 as u16,
-    0x1f80 as i32 as u16,
-    0x1f28 as i32 as u16,
-    0x1f90 as i32 as u16,
-    0x1f68 as i32 as u16,
-    0x1fa0 as i32 as u16,
-    0x1fba as i32 as u16,
-    0x386 as i32 as u16,
-    0x1fb3 as i32 as u16,
-    0x1fca as i32 as u16,
-    0x389 as i32 as u16,
-    0x1fc3 as i32 as u16,
-    0x3a1 as i32 as u16,
-    0x1ffa as i32 as u16,
-    0x38f as i32 as u16,
-    0x1ff3 as i32 as u16,
-    0x544 as i32 as u16,
-    0x546 as i32 as u16,
-    0x53b as i32 as u16,
-    0x54e as i32 as u16,
-    0x53d as i32 as u16,
-    0x3b8 as i32 as u16,
-    0x462 as i32 as u16,
-    0xa64a as i32 as u16,
-    0x1e60 as i32 as u16,
-    0x3c9 as i32 as u16,
-    0x6b as i32 as u16,
-    0xe5 as i32 as u16,
-];
-static mut unicode_prop_Cased1_table: [u8; 172] = [
-    0x40 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xfc as i32 as u8,
-    0x80 as i32 as u8,
-    0xd3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x85 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1 as i32 as u8,
-    0x15 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x39 as i32 as u8,
-    0x99 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9d as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0x94 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x82 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x62 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x57 as i32 as u8,
-    0x76 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x40 as i32 as u8,
-    0xdb as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8c as i32 as u8,
-    0xe4 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x28 as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x18 as i32 as u8,
-    0xb as i32 as u8,
-    0x24 as i32 as u8,
-    0x4b as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x79 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x91 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbd as i32 as u8,
-    0x88 as i32 as u8,
-    0x94 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x82 as i32 as u8,
-    0x43 as i32 as u8,
-    0x34 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x61 as i32 as u8,
-    0x28 as i32 as u8,
-    0x96 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x81 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x7 as i32 as u8,
-    0x59 as i32 as u8,
-    0x63 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-];
-static mut unicode_prop_Cased1_index: [u8; 18] = [
-    0xb9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1e as i32 as u8,
-    0x40 as i32 as u8,
-    0x9e as i32 as u8,
-    0xa6 as i32 as u8,
-    0x40 as i32 as u8,
-    0xba as i32 as u8,
-    0xd4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8a as i32 as u8,
-    0xf1 as i32 as u8,
-    0x1 as i32 as u8,
-];
-static mut unicode_prop_Case_Ignorable_table: [u8; 692] = [
-    0xa6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbf as i32 as u8,
-    0x19 as i32 as u8,
-    0x18 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfa as i32 as u8,
-    0x86 as i32 as u8,
-    0x40 as i32 as u8,
-    0xce as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xac as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xab as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x85 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x94 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x38 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0x9a as i32 as u8,
-    0xda as i32 as u8,
-    0x8a as i32 as u8,
-    0xb9 as i32 as u8,
-    0x8a as i32 as u8,
-    0x18 as i32 as u8,
-    0x8 as i32 as u8,
-    0x97 as i32 as u8,
-    0x97 as i32 as u8,
-    0xaa as i32 as u8,
-    0x82 as i32 as u8,
-    0xf6 as i32 as u8,
-    0xaf as i32 as u8,
-    0xb6 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3b as i32 as u8,
-    0x2 as i32 as u8,
-    0x86 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x81 as i32 as u8,
-    0x99 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb as i32 as u8,
-    0x9 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x20 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x81 as i32 as u8,
-    0x95 as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x80 as i32 as u8,
-    0xbc as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x14 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1e as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0x9c as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x1 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x93 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9b as i32 as u8,
-    0x81 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9c as i32 as u8,
-    0x80 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x10 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8a as i32 as u8,
-    0x88 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x8d as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x18 as i32 as u8,
-    0x28 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x98 as i32 as u8,
-    0xb as i32 as u8,
-    0x82 as i32 as u8,
-    0x8f as i32 as u8,
-    0x83 as i32 as u8,
-    0x8c as i32 as u8,
-    0x1 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xdd as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x5f as i32 as u8,
-    0x82 as i32 as u8,
-    0x43 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0x9c as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x81 as i32 as u8,
-    0xbf as i32 as u8,
-    0x8 as i32 as u8,
-    0x37 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8a as i32 as u8,
-    0x10 as i32 as u8,
-    0x20 as i32 as u8,
-    0xac as i32 as u8,
-    0x83 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x13 as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0x5 as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0xda as i32 as u8,
-    0x9 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3d as i32 as u8,
-    0x89 as i32 as u8,
-    0x8 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x90 as i32 as u8,
-    0xbe as i32 as u8,
-    0x83 as i32 as u8,
-    0xaf as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8b as i32 as u8,
-    0x81 as i32 as u8,
-    0x9f as i32 as u8,
-    0x19 as i32 as u8,
-    0x8 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x39 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbf as i32 as u8,
-    0x85 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8c as i32 as u8,
-    0x6 as i32 as u8,
-    0x18 as i32 as u8,
-    0x28 as i32 as u8,
-    0x11 as i32 as u8,
-    0xb1 as i32 as u8,
-    0xbe as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xa1 as i32 as u8,
-    0xde as i32 as u8,
-    0x4 as i32 as u8,
-    0x41 as i32 as u8,
-    0xbc as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8a as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x27 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8c as i32 as u8,
-    0xb2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x4b as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x82 as i32 as u8,
-    0xfc as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xdf as i32 as u8,
-    0x9f as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x1a as i32 as u8,
-    0x24 as i32 as u8,
-    0x80 as i32 as u8,
-    0xdc as i32 as u8,
-    0x85 as i32 as u8,
-    0xdc as i32 as u8,
-    0x82 as i32 as u8,
-    0x60 as i32 as u8,
-    0x6f as i32 as u8,
-    0x15 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x41 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x18 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x9b as i32 as u8,
-    0x83 as i32 as u8,
-    0xcf as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0xa1 as i32 as u8,
-    0xcd as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x82 as i32 as u8,
-    0xec as i32 as u8,
-    0xf as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0xc as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x96 as i32 as u8,
-    0x81 as i32 as u8,
-    0x99 as i32 as u8,
-    0x91 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x87 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8a as i32 as u8,
-    0xad as i32 as u8,
-    0x82 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x29 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xb2 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9a as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0xc as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4f as i32 as u8,
-    0x2f as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x92 as i32 as u8,
-    0x8f as i32 as u8,
-    0x42 as i32 as u8,
-    0x3d as i32 as u8,
-    0x8f as i32 as u8,
-    0x10 as i32 as u8,
-    0x8b as i32 as u8,
-    0x8f as i32 as u8,
-    0xa1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0xac as i32 as u8,
-    0x81 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x82 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x94 as i32 as u8,
-    0x84 as i32 as u8,
-    0x46 as i32 as u8,
-    0x85 as i32 as u8,
-    0x10 as i32 as u8,
-    0xc as i32 as u8,
-    0x83 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x13 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3c as i32 as u8,
-    0x83 as i32 as u8,
-    0x41 as i32 as u8,
-    0x82 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8a as i32 as u8,
-    0x40 as i32 as u8,
-    0xaf as i32 as u8,
-    0x80 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x8e as i32 as u8,
-    0xb7 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x82 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x20 as i32 as u8,
-    0x87 as i32 as u8,
-    0xbd as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x81 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x88 as i32 as u8,
-    0x89 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0xde as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x2 as i32 as u8,
-    0x87 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x32 as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x39 as i32 as u8,
-    0x10 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd3 as i32 as u8,
-    0x28 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xed as i32 as u8,
-    0x1d as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9a as i32 as u8,
-    0x81 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x39 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xe9 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x11 as i32 as u8,
-    0x18 as i32 as u8,
-    0x84 as i32 as u8,
-    0x41 as i32 as u8,
-    0x2 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1 as i32 as u8,
-    0x40 as i32 as u8,
-    0xff as i32 as u8,
-    0x8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8f as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x9f as i32 as u8,
-    0x89 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x29 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x29 as i32 as u8,
-    0x82 as i32 as u8,
-    0xad as i32 as u8,
-    0x8c as i32 as u8,
-    0x1 as i32 as u8,
-    0x41 as i32 as u8,
-    0x95 as i32 as u8,
-    0x30 as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x95 as i32 as u8,
-    0xe as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xf9 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc7 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x5a as i32 as u8,
-    0x81 as i32 as u8,
-    0x55 as i32 as u8,
-    0x3a as i32 as u8,
-    0x88 as i32 as u8,
-    0x60 as i32 as u8,
-    0x36 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x84 as i32 as u8,
-    0xba as i32 as u8,
-    0x86 as i32 as u8,
-    0x88 as i32 as u8,
-    0x83 as i32 as u8,
-    0x44 as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0xbe as i32 as u8,
-    0x90 as i32 as u8,
-    0xbf as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4c as i32 as u8,
-    0xb7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x83 as i32 as u8,
-    0x54 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8f as i32 as u8,
-    0xe as i32 as u8,
-    0x9d as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0x93 as i32 as u8,
-    0x82 as i32 as u8,
-    0x47 as i32 as u8,
-    0xba as i32 as u8,
-    0xb6 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x38 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8e as i32 as u8,
-    0x45 as i32 as u8,
-    0x4f as i32 as u8,
-    0x30 as i32 as u8,
-    0x90 as i32 as u8,
-    0xe as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8d as i32 as u8,
-    0x41 as i32 as u8,
-    0xad as i32 as u8,
-    0x83 as i32 as u8,
-    0x45 as i32 as u8,
-    0xdf as i32 as u8,
-    0x86 as i32 as u8,
-    0xec as i32 as u8,
-    0x87 as i32 as u8,
-    0x4a as i32 as u8,
-    0xae as i32 as u8,
-    0x84 as i32 as u8,
-    0x6c as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0xdf as i32 as u8,
-    0xff as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-];
-static mut unicode_prop_Case_Ignorable_index: [u8; 66] = [
-    0xbe as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xfe as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x52 as i32 as u8,
-    0xa as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc as i32 as u8,
-    0x20 as i32 as u8,
-    0x3b as i32 as u8,
-    0xe as i32 as u8,
-    0x40 as i32 as u8,
-    0x61 as i32 as u8,
-    0x10 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf as i32 as u8,
-    0x18 as i32 as u8,
-    0x20 as i32 as u8,
-    0x43 as i32 as u8,
-    0x1b as i32 as u8,
-    0x60 as i32 as u8,
-    0x79 as i32 as u8,
-    0x1d as i32 as u8,
-    0 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0xd as i32 as u8,
-    0xa6 as i32 as u8,
-    0x40 as i32 as u8,
-    0x2e as i32 as u8,
-    0xa9 as i32 as u8,
-    0x20 as i32 as u8,
-    0xde as i32 as u8,
-    0xaa as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0xff as i32 as u8,
-    0x20 as i32 as u8,
-    0xe7 as i32 as u8,
-    0xa as i32 as u8,
-    0x41 as i32 as u8,
-    0x82 as i32 as u8,
-    0x11 as i32 as u8,
-    0x21 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x14 as i32 as u8,
-    0x61 as i32 as u8,
-    0x44 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x48 as i32 as u8,
-    0x1d as i32 as u8,
-    0x21 as i32 as u8,
-    0xa4 as i32 as u8,
-    0xbc as i32 as u8,
-    0x1 as i32 as u8,
-    0x3e as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe as i32 as u8,
-];
-static mut unicode_prop_ID_Start_table: [u8; 1045] = [
-    0xc0 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x83 as i32 as u8,
-    0x8b as i32 as u8,
-    0x8d as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x80 as i32 as u8,
-    0x20 as i32 as u8,
-    0x9 as i32 as u8,
-    0x18 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8a as i32 as u8,
-    0x87 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x8 as i32 as u8,
-    0x85 as i32 as u8,
-    0xa8 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1b as i32 as u8,
-    0xac as i32 as u8,
-    0xaa as i32 as u8,
-    0xa2 as i32 as u8,
-    0x8 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0xe as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9d as i32 as u8,
-    0x9c as i32 as u8,
-    0xd8 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x88 as i32 as u8,
-    0xb as i32 as u8,
-    0x4 as i32 as u8,
-    0x95 as i32 as u8,
-    0x18 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x98 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8a as i32 as u8,
-    0xb4 as i32 as u8,
-    0x94 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0xbb as i32 as u8,
-    0xb5 as i32 as u8,
-    0x10 as i32 as u8,
-    0x91 as i32 as u8,
-    0x6 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8e as i32 as u8,
-    0x8f as i32 as u8,
-    0x1f as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x95 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8d as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x7 as i32 as u8,
-    0x2b as i32 as u8,
-    0x9 as i32 as u8,
-    0x95 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9e as i32 as u8,
-    0x18 as i32 as u8,
-    0x80 as i32 as u8,
-    0x92 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8f as i32 as u8,
-    0x88 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x10 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x81 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x39 as i32 as u8,
-    0x9 as i32 as u8,
-    0x95 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x10 as i32 as u8,
-    0x9d as i32 as u8,
-    0x8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x2a as i32 as u8,
-    0x10 as i32 as u8,
-    0x1a as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0x12 as i32 as u8,
-    0x8b as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x38 as i32 as u8,
-    0x10 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x10 as i32 as u8,
-    0x99 as i32 as u8,
-    0x14 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x3 as i32 as u8,
-    0x38 as i32 as u8,
-    0x10 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x4 as i32 as u8,
-    0x10 as i32 as u8,
-    0x9f as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0x81 as i32 as u8,
-    0x90 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8f as i32 as u8,
-    0x4 as i32 as u8,
-    0x17 as i32 as u8,
-    0x82 as i32 as u8,
-    0x97 as i32 as u8,
-    0x2c as i32 as u8,
-    0x91 as i32 as u8,
-    0x82 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0xe as i32 as u8,
-    0xb9 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x8b as i32 as u8,
-    0x86 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x97 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1 as i32 as u8,
-    0x20 as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x83 as i32 as u8,
-    0x9f as i32 as u8,
-    0x80 as i32 as u8,
-    0xbe as i32 as u8,
-    0x38 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x9a as i32 as u8,
-    0x84 as i32 as u8,
-    0xf2 as i32 as u8,
-    0xaa as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x2b as i32 as u8,
-    0x1a as i32 as u8,
-    0x2 as i32 as u8,
-    0xe as i32 as u8,
-    0x13 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x81 as i32 as u8,
-    0xaa as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4c as i32 as u8,
-    0x3 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8f as i32 as u8,
-    0xd5 as i32 as u8,
-    0xd as i32 as u8,
-    0x82 as i32 as u8,
-    0x42 as i32 as u8,
-    0x6b as i32 as u8,
-    0x81 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x84 as i32 as u8,
-    0xca as i32 as u8,
-    0x82 as i32 as u8,
-    0x8a as i32 as u8,
-    0x86 as i32 as u8,
-    0x8c as i32 as u8,
-    0x3 as i32 as u8,
-    0x8d as i32 as u8,
-    0x91 as i32 as u8,
-    0x8d as i32 as u8,
-    0x91 as i32 as u8,
-    0x8d as i32 as u8,
-    0x8c as i32 as u8,
-    0x2 as i32 as u8,
-    0x8e as i32 as u8,
-    0xb3 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x86 as i32 as u8,
-    0xa8 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9e as i32 as u8,
-    0xb0 as i32 as u8,
-    0x9d as i32 as u8,
-    0xc as i32 as u8,
-    0x8a as i32 as u8,
-    0xab as i32 as u8,
-    0x83 as i32 as u8,
-    0x99 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x96 as i32 as u8,
-    0x88 as i32 as u8,
-    0xb4 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xdc as i32 as u8,
-    0xae as i32 as u8,
-    0x90 as i32 as u8,
-    0x86 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x9d as i32 as u8,
-    0x8c as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0xab as i32 as u8,
-    0x99 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0x86 as i32 as u8,
-    0xaa as i32 as u8,
-    0xa as i32 as u8,
-    0xa8 as i32 as u8,
-    0x18 as i32 as u8,
-    0x28 as i32 as u8,
-    0xa as i32 as u8,
-    0x4 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbf as i32 as u8,
-    0xbf as i32 as u8,
-    0x41 as i32 as u8,
-    0x15 as i32 as u8,
-    0xd as i32 as u8,
-    0x81 as i32 as u8,
-    0xa5 as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x81 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x6 as i32 as u8,
-    0x13 as i32 as u8,
-    0xd as i32 as u8,
-    0x83 as i32 as u8,
-    0x8c as i32 as u8,
-    0x22 as i32 as u8,
-    0x6 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8c as i32 as u8,
-    0xe4 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0xd as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0xb as i32 as u8,
-    0x24 as i32 as u8,
-    0x18 as i32 as u8,
-    0x90 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x4a as i32 as u8,
-    0x76 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2b as i32 as u8,
-    0x11 as i32 as u8,
-    0x8b as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x8f as i32 as u8,
-    0x96 as i32 as u8,
-    0x88 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0x86 as i32 as u8,
-    0x42 as i32 as u8,
-    0x25 as i32 as u8,
-    0x82 as i32 as u8,
-    0x98 as i32 as u8,
-    0x88 as i32 as u8,
-    0x34 as i32 as u8,
-    0xc as i32 as u8,
-    0x83 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x80 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0xaa as i32 as u8,
-    0x80 as i32 as u8,
-    0xdd as i32 as u8,
-    0x90 as i32 as u8,
-    0x9f as i32 as u8,
-    0xaf as i32 as u8,
-    0x8f as i32 as u8,
-    0x41 as i32 as u8,
-    0xff as i32 as u8,
-    0x59 as i32 as u8,
-    0xbf as i32 as u8,
-    0xbf as i32 as u8,
-    0x60 as i32 as u8,
-    0x51 as i32 as u8,
-    0xfc as i32 as u8,
-    0x82 as i32 as u8,
-    0x44 as i32 as u8,
-    0x8c as i32 as u8,
-    0xc2 as i32 as u8,
-    0xad as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc as i32 as u8,
-    0x82 as i32 as u8,
-    0x8f as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x93 as i32 as u8,
-    0xae as i32 as u8,
-    0x8f as i32 as u8,
-    0x9e as i32 as u8,
-    0x81 as i32 as u8,
-    0xcf as i32 as u8,
-    0xa6 as i32 as u8,
-    0x88 as i32 as u8,
-    0x81 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x8c as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x9c as i32 as u8,
-    0xb3 as i32 as u8,
-    0x8d as i32 as u8,
-    0xb1 as i32 as u8,
-    0xbd as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x9b as i32 as u8,
-    0x89 as i32 as u8,
-    0x96 as i32 as u8,
-    0x98 as i32 as u8,
-    0x9c as i32 as u8,
-    0x86 as i32 as u8,
-    0xae as i32 as u8,
-    0x9b as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x20 as i32 as u8,
-    0x89 as i32 as u8,
-    0x89 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x96 as i32 as u8,
-    0x10 as i32 as u8,
-    0x87 as i32 as u8,
-    0x93 as i32 as u8,
-    0x96 as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb1 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x11 as i32 as u8,
-    0x8a as i32 as u8,
-    0x32 as i32 as u8,
-    0x8b as i32 as u8,
-    0x29 as i32 as u8,
-    0x29 as i32 as u8,
-    0x85 as i32 as u8,
-    0x88 as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0xaa as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x85 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x9c as i32 as u8,
-    0x60 as i32 as u8,
-    0x2b as i32 as u8,
-    0xa3 as i32 as u8,
-    0x8b as i32 as u8,
-    0x96 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x60 as i32 as u8,
-    0x21 as i32 as u8,
-    0x3 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6d as i32 as u8,
-    0x81 as i32 as u8,
-    0xe9 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8b as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xeb as i32 as u8,
-    0xa0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6a as i32 as u8,
-    0x91 as i32 as u8,
-    0xbf as i32 as u8,
-    0x81 as i32 as u8,
-    0xb5 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x8b as i32 as u8,
-    0xf3 as i32 as u8,
-    0x20 as i32 as u8,
-    0x40 as i32 as u8,
-    0x86 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-    0x8a as i32 as u8,
-    0xd8 as i32 as u8,
-    0x15 as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xa as i32 as u8,
-    0xa2 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x80 as i32 as u8,
-    0x92 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0xa1 as i32 as u8,
-    0xfa as i32 as u8,
-    0xc4 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xae as i32 as u8,
-    0x9f as i32 as u8,
-    0x8c as i32 as u8,
-    0x9d as i32 as u8,
-    0x84 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9d as i32 as u8,
-    0x81 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9d as i32 as u8,
-    0x91 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x87 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9b as i32 as u8,
-    0x41 as i32 as u8,
-    0x36 as i32 as u8,
-    0x88 as i32 as u8,
-    0x95 as i32 as u8,
-    0x89 as i32 as u8,
-    0x87 as i32 as u8,
-    0x40 as i32 as u8,
-    0x97 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0xab as i32 as u8,
-    0x1 as i32 as u8,
-    0x10 as i32 as u8,
-    0x81 as i32 as u8,
-    0x96 as i32 as u8,
-    0x89 as i32 as u8,
-    0x96 as i32 as u8,
-    0x88 as i32 as u8,
-    0x9e as i32 as u8,
-    0xc0 as i32 as u8,
-    0x92 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x95 as i32 as u8,
-    0x89 as i32 as u8,
-    0x99 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x29 as i32 as u8,
-    0xbf as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x18 as i32 as u8,
-    0x10 as i32 as u8,
-    0x9c as i32 as u8,
-    0xa9 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0x9c as i32 as u8,
-    0xa2 as i32 as u8,
-    0x38 as i32 as u8,
-    0x9b as i32 as u8,
-    0x9a as i32 as u8,
-    0xb5 as i32 as u8,
-    0x89 as i32 as u8,
-    0x95 as i32 as u8,
-    0x89 as i32 as u8,
-    0x92 as i32 as u8,
-    0x8c as i32 as u8,
-    0x91 as i32 as u8,
-    0xed as i32 as u8,
-    0xc8 as i32 as u8,
-    0xb6 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x8c as i32 as u8,
-    0xb2 as i32 as u8,
-    0x8c as i32 as u8,
-    0xa3 as i32 as u8,
-    0x41 as i32 as u8,
-    0x5b as i32 as u8,
-    0xa9 as i32 as u8,
-    0x29 as i32 as u8,
-    0xcd as i32 as u8,
-    0x9c as i32 as u8,
-    0x89 as i32 as u8,
-    0x7 as i32 as u8,
-    0x95 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x94 as i32 as u8,
-    0x9a as i32 as u8,
-    0x96 as i32 as u8,
-    0x8b as i32 as u8,
-    0xb4 as i32 as u8,
-    0xca as i32 as u8,
-    0xac as i32 as u8,
-    0x9f as i32 as u8,
-    0x98 as i32 as u8,
-    0x99 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x9c as i32 as u8,
-    0x1 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8b as i32 as u8,
-    0xaf as i32 as u8,
-    0x8d as i32 as u8,
-    0x83 as i32 as u8,
-    0x94 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0xd3 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x18 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x86 as i32 as u8,
-    0xae as i32 as u8,
-    0xa5 as i32 as u8,
-    0x39 as i32 as u8,
-    0x9 as i32 as u8,
-    0x95 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x10 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9d as i32 as u8,
-    0xb4 as i32 as u8,
-    0x91 as i32 as u8,
-    0x83 as i32 as u8,
-    0x93 as i32 as u8,
-    0x82 as i32 as u8,
-    0x9d as i32 as u8,
-    0xaf as i32 as u8,
-    0x93 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xb7 as i32 as u8,
-    0xae as i32 as u8,
-    0xa8 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xaf as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0xba as i32 as u8,
-    0xaa as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x9a as i32 as u8,
-    0x40 as i32 as u8,
-    0xe4 as i32 as u8,
-    0xab as i32 as u8,
-    0xf3 as i32 as u8,
-    0xbf as i32 as u8,
-    0x9e as i32 as u8,
-    0x39 as i32 as u8,
-    0x1 as i32 as u8,
-    0x38 as i32 as u8,
-    0x8 as i32 as u8,
-    0x97 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xdd as i32 as u8,
-    0x39 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x94 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0xad as i32 as u8,
-    0x92 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa1 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x9d as i32 as u8,
-    0xef as i32 as u8,
-    0x30 as i32 as u8,
-    0x8 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x94 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x28 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9f as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x46 as i32 as u8,
-    0x92 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbc as i32 as u8,
-    0x80 as i32 as u8,
-    0xce as i32 as u8,
-    0x43 as i32 as u8,
-    0x99 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xee as i32 as u8,
-    0x90 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x4a as i32 as u8,
-    0xbb as i32 as u8,
-    0x44 as i32 as u8,
-    0x2e as i32 as u8,
-    0x4f as i32 as u8,
-    0xd0 as i32 as u8,
-    0x42 as i32 as u8,
-    0x46 as i32 as u8,
-    0x60 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x42 as i32 as u8,
-    0x38 as i32 as u8,
-    0x86 as i32 as u8,
-    0x9e as i32 as u8,
-    0xf0 as i32 as u8,
-    0x9d as i32 as u8,
-    0x91 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8f as i32 as u8,
-    0x83 as i32 as u8,
-    0x9e as i32 as u8,
-    0x94 as i32 as u8,
-    0x84 as i32 as u8,
-    0x92 as i32 as u8,
-    0x42 as i32 as u8,
-    0xaf as i32 as u8,
-    0xbf as i32 as u8,
-    0xff as i32 as u8,
-    0xca as i32 as u8,
-    0x20 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x8c as i32 as u8,
-    0xbf as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x57 as i32 as u8,
-    0xf7 as i32 as u8,
-    0x87 as i32 as u8,
-    0x44 as i32 as u8,
-    0xd5 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x88 as i32 as u8,
-    0x60 as i32 as u8,
-    0x22 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x41 as i32 as u8,
-    0x1e as i32 as u8,
-    0xb0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x90 as i32 as u8,
-    0x1f as i32 as u8,
-    0x41 as i32 as u8,
-    0x8b as i32 as u8,
-    0x49 as i32 as u8,
-    0x3 as i32 as u8,
-    0xea as i32 as u8,
-    0x84 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x86 as i32 as u8,
-    0x89 as i32 as u8,
-    0x57 as i32 as u8,
-    0x65 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x81 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x7 as i32 as u8,
-    0x49 as i32 as u8,
-    0x33 as i32 as u8,
-    0xac as i32 as u8,
-    0x89 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x70 as i32 as u8,
-    0xab as i32 as u8,
-    0x45 as i32 as u8,
-    0x13 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xba as i32 as u8,
-    0xc3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x44 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x18 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x22 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x51 as i32 as u8,
-    0x43 as i32 as u8,
-    0x60 as i32 as u8,
-    0xa6 as i32 as u8,
-    0xdd as i32 as u8,
-    0xa1 as i32 as u8,
-    0x50 as i32 as u8,
-    0x34 as i32 as u8,
-    0x8a as i32 as u8,
-    0x40 as i32 as u8,
-    0xdd as i32 as u8,
-    0x81 as i32 as u8,
-    0x56 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x5d as i32 as u8,
-    0x30 as i32 as u8,
-    0x4c as i32 as u8,
-    0x1e as i32 as u8,
-    0x42 as i32 as u8,
-    0x1d as i32 as u8,
-    0x45 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x53 as i32 as u8,
-    0x4a as i32 as u8,
-];
-static mut unicode_prop_ID_Start_index: [u8; 99] = [
-    0xf6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0xb4 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0xba as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xe as i32 as u8,
-    0x20 as i32 as u8,
-    0x57 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0xeb as i32 as u8,
-    0x16 as i32 as u8,
-    0 as i32 as u8,
-    0xca as i32 as u8,
-    0x19 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x60 as i32 as u8,
-    0x80 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x2e as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x31 as i32 as u8,
-    0x20 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x20 as i32 as u8,
-    0xf0 as i32 as u8,
-    0xa9 as i32 as u8,
-    0 as i32 as u8,
-    0xe3 as i32 as u8,
-    0xab as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0xfd as i32 as u8,
-    0 as i32 as u8,
-    0xfb as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x37 as i32 as u8,
-    0x7 as i32 as u8,
-    0x61 as i32 as u8,
-    0x1 as i32 as u8,
-    0xa as i32 as u8,
-    0x1 as i32 as u8,
-    0x1d as i32 as u8,
-    0xf as i32 as u8,
-    0x21 as i32 as u8,
-    0x2c as i32 as u8,
-    0x12 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x14 as i32 as u8,
-    0x21 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x19 as i32 as u8,
-    0x21 as i32 as u8,
-    0x47 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1 as i32 as u8,
-    0x39 as i32 as u8,
-    0x6a as i32 as u8,
-    0x21 as i32 as u8,
-    0x9 as i32 as u8,
-    0x8d as i32 as u8,
-    0x1 as i32 as u8,
-    0xbc as i32 as u8,
-    0xd4 as i32 as u8,
-    0x1 as i32 as u8,
-    0xa9 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x21 as i32 as u8,
-    0x3a as i32 as u8,
-    0xee as i32 as u8,
-    0x1 as i32 as u8,
-    0xde as i32 as u8,
-    0xa6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x4b as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-];
-static mut unicode_prop_ID_Continue1_table: [u8; 626] = [
-    0xaf as i32 as u8,
-    0x89 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x47 as i32 as u8,
-    0xef as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfa as i32 as u8,
-    0x84 as i32 as u8,
-    0x41 as i32 as u8,
-    0x8 as i32 as u8,
-    0xac as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x8a as i32 as u8,
-    0xaf as i32 as u8,
-    0x9e as i32 as u8,
-    0x28 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x31 as i32 as u8,
-    0x29 as i32 as u8,
-    0x8 as i32 as u8,
-    0x19 as i32 as u8,
-    0x89 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0x9a as i32 as u8,
-    0xda as i32 as u8,
-    0x8a as i32 as u8,
-    0x8e as i32 as u8,
-    0x89 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x18 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0xaa as i32 as u8,
-    0x82 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x10 as i32 as u8,
-    0x91 as i32 as u8,
-    0x6 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x90 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8d as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0x9 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x8b as i32 as u8,
-    0x10 as i32 as u8,
-    0x8a as i32 as u8,
-    0x82 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-    0x93 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x89 as i32 as u8,
-    0x28 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9 as i32 as u8,
-    0x16 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0xba as i32 as u8,
-    0x22 as i32 as u8,
-    0x10 as i32 as u8,
-    0x83 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x89 as i32 as u8,
-    0x8f as i32 as u8,
-    0x84 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1e as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x90 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1e as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8f as i32 as u8,
-    0x83 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0x83 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x90 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x3d as i32 as u8,
-    0x89 as i32 as u8,
-    0x9 as i32 as u8,
-    0xbc as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8b as i32 as u8,
-    0x38 as i32 as u8,
-    0x89 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8a as i32 as u8,
-    0x29 as i32 as u8,
-    0x89 as i32 as u8,
-    0xbd as i32 as u8,
-    0xd as i32 as u8,
-    0x89 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x93 as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8b as i32 as u8,
-    0x1b as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x32 as i32 as u8,
-    0x83 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x42 as i32 as u8,
-    0xbe as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x88 as i32 as u8,
-    0x43 as i32 as u8,
-    0x9f as i32 as u8,
-    0x82 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0x9c as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x81 as i32 as u8,
-    0xbf as i32 as u8,
-    0x9f as i32 as u8,
-    0x88 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x8b as i32 as u8,
-    0x83 as i32 as u8,
-    0x8b as i32 as u8,
-    0x89 as i32 as u8,
-    0x89 as i32 as u8,
-    0xff as i32 as u8,
-    0x8a as i32 as u8,
-    0xbb as i32 as u8,
-    0x84 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9c as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x85 as i32 as u8,
-    0x89 as i32 as u8,
-    0x95 as i32 as u8,
-    0x8d as i32 as u8,
-    0x1 as i32 as u8,
-    0xbe as i32 as u8,
-    0x84 as i32 as u8,
-    0xae as i32 as u8,
-    0x90 as i32 as u8,
-    0x8a as i32 as u8,
-    0x89 as i32 as u8,
-    0x90 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8b as i32 as u8,
-    0x82 as i32 as u8,
-    0x9d as i32 as u8,
-    0x8c as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0xab as i32 as u8,
-    0x8d as i32 as u8,
-    0xaf as i32 as u8,
-    0x93 as i32 as u8,
-    0x87 as i32 as u8,
-    0x89 as i32 as u8,
-    0x85 as i32 as u8,
-    0x89 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x10 as i32 as u8,
-    0x94 as i32 as u8,
-    0x18 as i32 as u8,
-    0x28 as i32 as u8,
-    0xa as i32 as u8,
-    0x40 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x4 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3e as i32 as u8,
-    0x81 as i32 as u8,
-    0x92 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfa as i32 as u8,
-    0x8c as i32 as u8,
-    0x18 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8b as i32 as u8,
-    0x4b as i32 as u8,
-    0xfd as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xdf as i32 as u8,
-    0x9f as i32 as u8,
-    0x42 as i32 as u8,
-    0x29 as i32 as u8,
-    0x85 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x75 as i32 as u8,
-    0x84 as i32 as u8,
-    0x89 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x3 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9f as i32 as u8,
-    0x81 as i32 as u8,
-    0xcf as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x23 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x91 as i32 as u8,
-    0x89 as i32 as u8,
-    0x89 as i32 as u8,
-    0x85 as i32 as u8,
-    0x91 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8a as i32 as u8,
-    0x9b as i32 as u8,
-    0x87 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8c as i32 as u8,
-    0xab as i32 as u8,
-    0x83 as i32 as u8,
-    0xae as i32 as u8,
-    0x8d as i32 as u8,
-    0x8e as i32 as u8,
-    0x89 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x89 as i32 as u8,
-    0xae as i32 as u8,
-    0x8d as i32 as u8,
-    0x8b as i32 as u8,
-    0x7 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb1 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x24 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xeb as i32 as u8,
-    0x38 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4f as i32 as u8,
-    0x23 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8f as i32 as u8,
-    0x8f as i32 as u8,
-    0x11 as i32 as u8,
-    0x97 as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbf as i32 as u8,
-    0x89 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xbc as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x94 as i32 as u8,
-    0x84 as i32 as u8,
-    0x41 as i32 as u8,
-    0x24 as i32 as u8,
-    0x89 as i32 as u8,
-    0x45 as i32 as u8,
-    0x56 as i32 as u8,
-    0x10 as i32 as u8,
-    0xc as i32 as u8,
-    0x83 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x13 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3c as i32 as u8,
-    0x1f as i32 as u8,
-    0x89 as i32 as u8,
-    0x41 as i32 as u8,
-    0x70 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8a as i32 as u8,
-    0x40 as i32 as u8,
-    0xae as i32 as u8,
-    0x82 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x8e as i32 as u8,
-    0x9e as i32 as u8,
-    0x89 as i32 as u8,
-    0x8e as i32 as u8,
-    0x83 as i32 as u8,
-    0xac as i32 as u8,
-    0x8a as i32 as u8,
-    0xb4 as i32 as u8,
-    0x89 as i32 as u8,
-    0x2a as i32 as u8,
-    0xa3 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x21 as i32 as u8,
-    0xab as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x82 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8d as i32 as u8,
-    0x3b as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0xd1 as i32 as u8,
-    0x8b as i32 as u8,
-    0x28 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x8b as i32 as u8,
-    0x84 as i32 as u8,
-    0x89 as i32 as u8,
-    0x2b as i32 as u8,
-    0xb6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x9 as i32 as u8,
-    0x32 as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbf as i32 as u8,
-    0x91 as i32 as u8,
-    0x88 as i32 as u8,
-    0x89 as i32 as u8,
-    0x18 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x93 as i32 as u8,
-    0x8b as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x31 as i32 as u8,
-    0x88 as i32 as u8,
-    0x9a as i32 as u8,
-    0x81 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x90 as i32 as u8,
-    0x8e as i32 as u8,
-    0x89 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x87 as i32 as u8,
-    0x89 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x8e as i32 as u8,
-    0x83 as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x8e as i32 as u8,
-    0x40 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x89 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x28 as i32 as u8,
-    0x9 as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x89 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x31 as i32 as u8,
-    0x32 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x89 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8a as i32 as u8,
-    0xad as i32 as u8,
-    0x8f as i32 as u8,
-    0x41 as i32 as u8,
-    0x94 as i32 as u8,
-    0x38 as i32 as u8,
-    0x87 as i32 as u8,
-    0x8f as i32 as u8,
-    0x89 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0xf9 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x7 as i32 as u8,
-    0x89 as i32 as u8,
-    0xaf as i32 as u8,
-    0x20 as i32 as u8,
-    0x8 as i32 as u8,
-    0x27 as i32 as u8,
-    0x89 as i32 as u8,
-    0x41 as i32 as u8,
-    0x48 as i32 as u8,
-    0x83 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4b as i32 as u8,
-    0x68 as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0x85 as i32 as u8,
-    0x84 as i32 as u8,
-    0xba as i32 as u8,
-    0x86 as i32 as u8,
-    0x98 as i32 as u8,
-    0x89 as i32 as u8,
-    0x43 as i32 as u8,
-    0xf4 as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x33 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4c as i32 as u8,
-    0xaa as i32 as u8,
-    0x81 as i32 as u8,
-    0x54 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2f as i32 as u8,
-    0x39 as i32 as u8,
-    0x86 as i32 as u8,
-    0x9d as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0x93 as i32 as u8,
-    0x82 as i32 as u8,
-    0x45 as i32 as u8,
-    0x88 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x41 as i32 as u8,
-    0xff as i32 as u8,
-    0xb6 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x38 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8e as i32 as u8,
-    0x45 as i32 as u8,
-    0x4f as i32 as u8,
-    0x30 as i32 as u8,
-    0x90 as i32 as u8,
-    0xe as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x88 as i32 as u8,
-    0x89 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x8d as i32 as u8,
-    0x45 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x86 as i32 as u8,
-    0xec as i32 as u8,
-    0x34 as i32 as u8,
-    0x89 as i32 as u8,
-    0x52 as i32 as u8,
-    0x95 as i32 as u8,
-    0x89 as i32 as u8,
-    0x6c as i32 as u8,
-    0x5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-];
-static mut unicode_prop_ID_Continue1_index: [u8; 60] = [
-    0xfa as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0xf0 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x70 as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0xf4 as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0x4a as i32 as u8,
-    0x10 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1a as i32 as u8,
-    0x18 as i32 as u8,
-    0x20 as i32 as u8,
-    0x74 as i32 as u8,
-    0x1b as i32 as u8,
-    0x20 as i32 as u8,
-    0xdd as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0xc as i32 as u8,
-    0xa8 as i32 as u8,
-    0 as i32 as u8,
-    0x5a as i32 as u8,
-    0xaa as i32 as u8,
-    0x20 as i32 as u8,
-    0x1a as i32 as u8,
-    0xff as i32 as u8,
-    0 as i32 as u8,
-    0xad as i32 as u8,
-    0xe as i32 as u8,
-    0x1 as i32 as u8,
-    0x38 as i32 as u8,
-    0x12 as i32 as u8,
-    0x21 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x15 as i32 as u8,
-    0x21 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x19 as i32 as u8,
-    0x21 as i32 as u8,
-    0xaa as i32 as u8,
-    0x1d as i32 as u8,
-    0x21 as i32 as u8,
-    0x8c as i32 as u8,
-    0xd1 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4a as i32 as u8,
-    0xe1 as i32 as u8,
-    0x21 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe as i32 as u8,
-];
-static mut unicode_cc_table: [u8; 851] = [
-    0xb2 as i32 as u8,
-    0xcf as i32 as u8,
-    0xd4 as i32 as u8,
-    0 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0xe8 as i32 as u8,
-    0 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x4 as i32 as u8,
-    0xdc as i32 as u8,
-    0x1 as i32 as u8,
-    0xca as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0x1 as i32 as u8,
-    0xca as i32 as u8,
-    0xa as i32 as u8,
-    0xdc as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc7 as i32 as u8,
-    0 as i32 as u8,
-    0xf0 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc2 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x41 as i32 as u8,
-    0xe9 as i32 as u8,
-    0 as i32 as u8,
-    0xea as i32 as u8,
-    0x41 as i32 as u8,
-    0xe9 as i32 as u8,
-    0 as i32 as u8,
-    0xea as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0xcc as i32 as u8,
-    0xb0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xd8 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc3 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0xde as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc5 as i32 as u8,
-    0x5 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xde as i32 as u8,
-    0 as i32 as u8,
-    0xe4 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x49 as i32 as u8,
-    0xa as i32 as u8,
-    0x43 as i32 as u8,
-    0x13 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x17 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x18 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x17 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x42 as i32 as u8,
-    0x1e as i32 as u8,
-    0xaf as i32 as u8,
-    0x47 as i32 as u8,
-    0x1b as i32 as u8,
-    0xc1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc4 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x34 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc3 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0x24 as i32 as u8,
-    0x9d as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0x2 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x6f as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x97 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xaa as i32 as u8,
-    0x2 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xcd as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc2 as i32 as u8,
-    0x2 as i32 as u8,
-    0xdc as i32 as u8,
-    0x42 as i32 as u8,
-    0x1b as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc4 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x36 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xaf as i32 as u8,
-    0xc0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3d as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3d as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x4e as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x4e as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x86 as i32 as u8,
-    0 as i32 as u8,
-    0x54 as i32 as u8,
-    0 as i32 as u8,
-    0x5b as i32 as u8,
-    0xb0 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3c as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3c as i32 as u8,
-    0x1 as i32 as u8,
-    0x67 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x8c as i32 as u8,
-    0x3 as i32 as u8,
-    0x6b as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3b as i32 as u8,
-    0x1 as i32 as u8,
-    0x76 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x8c as i32 as u8,
-    0x3 as i32 as u8,
-    0x7a as i32 as u8,
-    0xb0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0xd8 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x41 as i32 as u8,
-    0x81 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x84 as i32 as u8,
-    0x3 as i32 as u8,
-    0x82 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb2 as i32 as u8,
-    0x9e as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x83 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9e as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x6c as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0xe4 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x5e as i32 as u8,
-    0 as i32 as u8,
-    0xde as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0xaa as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0x16 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x93 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xaf as i32 as u8,
-    0xc4 as i32 as u8,
-    0x5 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xa5 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc6 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8a as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x67 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x85 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x95 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xea as i32 as u8,
-    0 as i32 as u8,
-    0xd6 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0xca as i32 as u8,
-    0xe4 as i32 as u8,
-    0 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe4 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb2 as i32 as u8,
-    0x9f as i32 as u8,
-    0xc1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x83 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3 as i32 as u8,
-    0xcd as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x5c as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x2f as i32 as u8,
-    0xdf as i32 as u8,
-    0xb1 as i32 as u8,
-    0xf9 as i32 as u8,
-    0 as i32 as u8,
-    0xda as i32 as u8,
-    0 as i32 as u8,
-    0xe4 as i32 as u8,
-    0 as i32 as u8,
-    0xe8 as i32 as u8,
-    0 as i32 as u8,
-    0xde as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x6d as i32 as u8,
-    0xa3 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x9f as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xe3 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xa4 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x66 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9a as i32 as u8,
-    0xd1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0xdc as i32 as u8,
-    0xa4 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xbe as i32 as u8,
-    0xc0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x81 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x84 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x46 as i32 as u8,
-    0xff as i32 as u8,
-    0 as i32 as u8,
-    0x1a as i32 as u8,
-    0xb2 as i32 as u8,
-    0xd0 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x6 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x9c as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0x64 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xa7 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0x83 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x74 as i32 as u8,
-    0xc0 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb2 as i32 as u8,
-    0xc as i32 as u8,
-    0xc3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x52 as i32 as u8,
-    0xc1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x68 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0xdc as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0xc4 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x14 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x39 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x97 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x9c as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x96 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xca as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0x83 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x91 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x74 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xd1 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x45 as i32 as u8,
-    0x27 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xa as i32 as u8,
-    0xc6 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1 as i32 as u8,
-    0x6 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x44 as i32 as u8,
-    0x7b as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xc as i32 as u8,
-    0x95 as i32 as u8,
-    0x1 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x4 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x87 as i32 as u8,
-    0x7 as i32 as u8,
-    0xdc as i32 as u8,
-    0x81 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x1 as i32 as u8,
-    0xdc as i32 as u8,
-    0x9d as i32 as u8,
-    0xc3 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x63 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x5 as i32 as u8,
-    0x8a as i32 as u8,
-    0xc6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xd4 as i32 as u8,
-    0xc6 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x84 as i32 as u8,
-    0xc3 as i32 as u8,
-    0xb5 as i32 as u8,
-    0xaf as i32 as u8,
-    0x6 as i32 as u8,
-    0xdc as i32 as u8,
-    0xb0 as i32 as u8,
-    0x3c as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-];
-static mut unicode_cc_index: [u8; 81] = [
-    0x4d as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x53 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0xcd as i32 as u8,
-    0xb as i32 as u8,
-    0x20 as i32 as u8,
-    0x38 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0x73 as i32 as u8,
-    0xf as i32 as u8,
-    0x20 as i32 as u8,
-    0x5d as i32 as u8,
-    0x13 as i32 as u8,
-    0x20 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1a as i32 as u8,
-    0x20 as i32 as u8,
-    0xaa as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x1c as i32 as u8,
-    0 as i32 as u8,
-    0xfe as i32 as u8,
-    0x1d as i32 as u8,
-    0x20 as i32 as u8,
-    0x7f as i32 as u8,
-    0x2d as i32 as u8,
-    0x20 as i32 as u8,
-    0xf0 as i32 as u8,
-    0xa6 as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0xaa as i32 as u8,
-    0 as i32 as u8,
-    0xfe as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xab as i32 as u8,
-    0xe as i32 as u8,
-    0x1 as i32 as u8,
-    0x73 as i32 as u8,
-    0x11 as i32 as u8,
-    0x21 as i32 as u8,
-    0x70 as i32 as u8,
-    0x13 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1a as i32 as u8,
-    0x1 as i32 as u8,
-    0x9f as i32 as u8,
-    0xbc as i32 as u8,
-    0x1 as i32 as u8,
-    0x22 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4b as i32 as u8,
-    0xe9 as i32 as u8,
-    0x1 as i32 as u8,
-];
-static mut unicode_decomp_table1: [u32; 690] = [
-    0x280081 as i32 as u32,
-    0x2a0097 as i32 as u32,
-    0x2a8081 as i32 as u32,
-    0x2bc097 as i32 as u32,
-    0x2c8115 as i32 as u32,
-    0x2d0097 as i32 as u32,
-    0x2d4081 as i32 as u32,
-    0x2e0097 as i32 as u32,
-    0x2e4115 as i32 as u32,
-    0x2f0199 as i32 as u32,
-    0x302016 as i32 as u32,
-    0x400842 as i32 as u32,
-    0x448a42 as i32 as u32,
-    0x4a0442 as i32 as u32,
-    0x4c0096 as i32 as u32,
-    0x4c8117 as i32 as u32,
-    0x4d0242 as i32 as u32,
-    0x4e4342 as i32 as u32,
-    0x4fc12f as i32 as u32,
-    0x50c342 as i32 as u32,
-    0x5240bf as i32 as u32,
-    0x530342 as i32 as u32,
-    0x550942 as i32 as u32,
-    0x5a0842 as i32 as u32,
-    0x5e0096 as i32 as u32,
-    0x5e4342 as i32 as u32,
-    0x5fc081 as i32 as u32,
-    0x680142 as i32 as u32,
-    0x6bc142 as i32 as u32,
-    0x710185 as i32 as u32,
-    0x71c317 as i32 as u32,
-    0x734844 as i32 as u32,
-    0x778344 as i32 as u32,
-    0x798342 as i32 as u32,
-    0x7b02be as i32 as u32,
-    0x7c4197 as i32 as u32,
-    0x7d0142 as i32 as u32,
-    0x7e0444 as i32 as u32,
-    0x800e42 as i32 as u32,
-    0x878142 as i32 as u32,
-    0x898744 as i32 as u32,
-    0xac0483 as i32 as u32,
-    0xb60317 as i32 as u32,
-    0xb80283 as i32 as u32,
-    0xd00214 as i32 as u32,
-    0xd10096 as i32 as u32,
-    0xdd0080 as i32 as u32,
-    0xde8097 as i32 as u32,
-    0xdf8080 as i32 as u32,
-    0xe10097 as i32 as u32,
-    0xe1413e as i32 as u32,
-    0xe1c080 as i32 as u32,
-    0xe204be as i32 as u32,
-    0xea83ae as i32 as u32,
-    0xf282ae as i32 as u32,
-    0xf401ad as i32 as u32,
-    0xf4c12e as i32 as u32,
-    0xf54103 as i32 as u32,
-    0xfc0303 as i32 as u32,
-    0xfe4081 as i32 as u32,
-    0x100023e as i32 as u32,
-    0x101c0be as i32 as u32,
-    0x10301be as i32 as u32,
-    0x10640be as i32 as u32,
-    0x10e40be as i32 as u32,
-    0x114023e as i32 as u32,
-    0x115c0be as i32 as u32,
-    0x11701be as i32 as u32,
-    0x11d8144 as i32 as u32,
-    0x1304144 as i32 as u32,
-    0x1340244 as i32 as u32,
-    0x1358144 as i32 as u32,
-    0x1368344 as i32 as u32,
-    0x1388344 as i32 as u32,
-    0x13a8644 as i32 as u32,
-    0x13e0144 as i32 as u32,
-    0x161c085 as i32 as u32,
-    0x18882ae as i32 as u32,
-    0x19d422f as i32 as u32,
-    0x1b00184 as i32 as u32,
-    0x1b4c084 as i32 as u32,
-    0x24a4084 as i32 as u32,
-    0x24c4084 as i32 as u32,
-    0x24d0084 as i32 as u32,
-    0x256042e as i32 as u32,
-    0x272c12e as i32 as u32,
-    0x2770120 as i32 as u32,
-    0x277c084 as i32 as u32,
-    0x28cc084 as i32 as u32,
-    0x28d8084 as i32 as u32,
-    0x29641ae as i32 as u32,
-    0x2978084 as i32 as u32,
-    0x2d20084 as i32 as u32,
-    0x2d2c12e as i32 as u32,
-    0x2d70120 as i32 as u32,
-    0x2e50084 as i32 as u32,
-    0x2f281ae as i32 as u32,
-    0x3120084 as i32 as u32,
-    0x3300084 as i32 as u32,
-    0x331c122 as i32 as u32,
-    0x332812e as i32 as u32,
-    0x35281ae as i32 as u32,
-    0x3768084 as i32 as u32,
-    0x37701ae as i32 as u32,
-    0x38cc085 as i32 as u32,
-    0x3acc085 as i32 as u32,
-    0x3b7012f as i32 as u32,
-    0x3c30081 as i32 as u32,
-    0x3d0c084 as i32 as u32,
-    0x3d34084 as i32 as u32,
-    0x3d48084 as i32 as u32,
-    0x3d5c084 as i32 as u32,
-    0x3d70084 as i32 as u32,
-    0x3da4084 as i32 as u32,
-    0x3dcc084 as i32 as u32,
-    0x3dd412e as i32 as u32,
-    0x3ddc085 as i32 as u32,
-    0x3de0084 as i32 as u32,
-    0x3de4085 as i32 as u32,
-    0x3e04084 as i32 as u32,
-    0x3e4c084 as i32 as u32,
-    0x3e74084 as i32 as u32,
-    0x3e88084 as i32 as u32,
-    0x3e9c084 as i32 as u32,
-    0x3eb0084 as i32 as u32,
-    0x3ee4084 as i32 as u32,
-    0x4098084 as i32 as u32,
-    0x43f0081 as i32 as u32,
-    0x6c18484 as i32 as u32,
-    0x6c48084 as i32 as u32,
-    0x6cec184 as i32 as u32,
-    0x6d00120 as i32 as u32,
-    0x6d0c084 as i32 as u32,
-    0x74b0383 as i32 as u32,
-    0x74cc41f as i32 as u32,
-    0x74f1783 as i32 as u32,
-    0x75e0081 as i32 as u32,
-    0x766d283 as i32 as u32,
-    0x7801d44 as i32 as u32,
-    0x78e8942 as i32 as u32,
-    0x7931844 as i32 as u32,
-    0x79f0d42 as i32 as u32,
-    0x7a58216 as i32 as u32,
-    0x7a68085 as i32 as u32,
-    0x7a6c0be as i32 as u32,
-    0x7a80d44 as i32 as u32,
-    0x7aea044 as i32 as u32,
-    0x7c00122 as i32 as u32,
-    0x7c08344 as i32 as u32,
-    0x7c20122 as i32 as u32,
-    0x7c28344 as i32 as u32,
-    0x7c40122 as i32 as u32,
-    0x7c48244 as i32 as u32,
-    0x7c60122 as i32 as u32,
-    0x7c68244 as i32 as u32,
-    0x7c8113e as i32 as u32,
-    0x7d08244 as i32 as u32,
-    0x7d20122 as i32 as u32,
-    0x7d28244 as i32 as u32,
-    0x7d40122 as i32 as u32,
-    0x7d48344 as i32 as u32,
-    0x7d64c3e as i32 as u32,
-    0x7dc4080 as i32 as u32,
-    0x7dc80be as i32 as u32,
-    0x7dcc080 as i32 as u32,
-    0x7dd00be as i32 as u32,
-    0x7dd4080 as i32 as u32,
-    0x7dd80be as i32 as u32,
-    0x7ddc080 as i32 as u32,
-    0x7de00be as i32 as u32,
-    0x7de4080 as i32 as u32,
-    0x7de80be as i32 as u32,
-    0x7dec080 as i32 as u32,
-    0x7df00be as i32 as u32,
-    0x7df4080 as i32 as u32,
-    0x7e00820 as i32 as u32,
-    0x7e40820 as i32 as u32,
-    0x7e80820 as i32 as u32,
-    0x7ec05be as i32 as u32,
-    0x7eec080 as i32 as u32,
-    0x7ef00be as i32 as u32,
-    0x7ef4097 as i32 as u32,
-    0x7ef8080 as i32 as u32,
-    0x7efc117 as i32 as u32,
-    0x7f0443e as i32 as u32,
-    0x7f24080 as i32 as u32,
-    0x7f280be as i32 as u32,
-    0x7f2c080 as i32 as u32,
-    0x7f303be as i32 as u32,
-    0x7f4c080 as i32 as u32,
-    0x7f582ae as i32 as u32,
-    0x7f6c080 as i32 as u32,
-    0x7f7433e as i32 as u32,
-    0x7f8c080 as i32 as u32,
-    0x7f903ae as i32 as u32,
-    0x7fac080 as i32 as u32,
-    0x7fb013e as i32 as u32,
-    0x7fb8102 as i32 as u32,
-    0x7fc83be as i32 as u32,
-    0x7fe4080 as i32 as u32,
-    0x7fe80be as i32 as u32,
-    0x7fec080 as i32 as u32,
-    0x7ff00be as i32 as u32,
-    0x7ff4080 as i32 as u32,
-    0x7ff8097 as i32 as u32,
-    0x800011e as i32 as u32,
-    0x8008495 as i32 as u32,
-    0x8044081 as i32 as u32,
-    0x805c097 as i32 as u32,
-    0x8090081 as i32 as u32,
-    0x8094097 as i32 as u32,
-    0x8098099 as i32 as u32,
-    0x80bc081 as i32 as u32,
-    0x80cc085 as i32 as u32,
-    0x80d00b1 as i32 as u32,
-    0x80d8085 as i32 as u32,
-    0x80dc0b1 as i32 as u32,
-    0x80f0197 as i32 as u32,
-    0x811c197 as i32 as u32,
-    0x815c0b3 as i32 as u32,
-    0x817c081 as i32 as u32,
-    0x81c0595 as i32 as u32,
-    0x81ec081 as i32 as u32,
-    0x81f0215 as i32 as u32,
-    0x820051f as i32 as u32,
-    0x8228583 as i32 as u32,
-    0x8254415 as i32 as u32,
-    0x82a0097 as i32 as u32,
-    0x8400119 as i32 as u32,
-    0x8408081 as i32 as u32,
-    0x840c0bf as i32 as u32,
-    0x8414119 as i32 as u32,
-    0x841c081 as i32 as u32,
-    0x84240bf as i32 as u32,
-    0x842852d as i32 as u32,
-    0x8454081 as i32 as u32,
-    0x8458097 as i32 as u32,
-    0x8464295 as i32 as u32,
-    0x8480097 as i32 as u32,
-    0x8484099 as i32 as u32,
-    0x8488097 as i32 as u32,
-    0x8490081 as i32 as u32,
-    0x8498080 as i32 as u32,
-    0x84a0081 as i32 as u32,
-    0x84a8102 as i32 as u32,
-    0x84b0495 as i32 as u32,
-    0x84d421f as i32 as u32,
-    0x84e4081 as i32 as u32,
-    0x84ec099 as i32 as u32,
-    0x84f0283 as i32 as u32,
-    0x8514295 as i32 as u32,
-    0x8540119 as i32 as u32,
-    0x854809b as i32 as u32,
-    0x854c619 as i32 as u32,
-    0x857c097 as i32 as u32,
-    0x8580081 as i32 as u32,
-    0x8584097 as i32 as u32,
-    0x8588099 as i32 as u32,
-    0x858c097 as i32 as u32,
-    0x8590081 as i32 as u32,
-    0x8594097 as i32 as u32,
-    0x8598099 as i32 as u32,
-    0x859c09b as i32 as u32,
-    0x85a0097 as i32 as u32,
-    0x85a4081 as i32 as u32,
-    0x85a8097 as i32 as u32,
-    0x85ac099 as i32 as u32,
-    0x85b0295 as i32 as u32,
-    0x85c4097 as i32 as u32,
-    0x85c8099 as i32 as u32,
-    0x85cc097 as i32 as u32,
-    0x85d0081 as i32 as u32,
-    0x85d4097 as i32 as u32,
-    0x85d8099 as i32 as u32,
-    0x85dc09b as i32 as u32,
-    0x85e0097 as i32 as u32,
-    0x85e4081 as i32 as u32,
-    0x85e8097 as i32 as u32,
-    0x85ec099 as i32 as u32,
-    0x85f0215 as i32 as u32,
-    0x8624099 as i32 as u32,
-    0x866813e as i32 as u32,
-    0x86b80be as i32 as u32,
-    0x87341be as i32 as u32,
-    0x88100be as i32 as u32,
-    0x88240be as i32 as u32,
-    0x88300be as i32 as u32,
-    0x88901be as i32 as u32,
-    0x88b0085 as i32 as u32,
-    0x88b40b1 as i32 as u32,
-    0x88bc085 as i32 as u32,
-    0x88c00b1 as i32 as u32,
-    0x89040be as i32 as u32,
-    0x89100be as i32 as u32,
-    0x891c1be as i32 as u32,
-    0x89801be as i32 as u32,
-    0x89b42be as i32 as u32,
-    0x89d0144 as i32 as u32,
-    0x89e0144 as i32 as u32,
-    0x8a00144 as i32 as u32,
-    0x8a10144 as i32 as u32,
-    0x8a20144 as i32 as u32,
-    0x8ab023e as i32 as u32,
-    0x8b80244 as i32 as u32,
-    0x8ba8220 as i32 as u32,
-    0x8ca411e as i32 as u32,
-    0x918049f as i32 as u32,
-    0x91a4523 as i32 as u32,
-    0x91cc097 as i32 as u32,
-    0x91d04a5 as i32 as u32,
-    0x91f452b as i32 as u32,
-    0x921c09b as i32 as u32,
-    0x92204a1 as i32 as u32,
-    0x9244525 as i32 as u32,
-    0x926c099 as i32 as u32,
-    0x9270d25 as i32 as u32,
-    0x92d8d1f as i32 as u32,
-    0x9340d1f as i32 as u32,
-    0x93a8081 as i32 as u32,
-    0xa8300b3 as i32 as u32,
-    0xa9d0099 as i32 as u32,
-    0xa9d4097 as i32 as u32,
-    0xa9d8099 as i32 as u32,
-    0xab700be as i32 as u32,
-    0xb1f0115 as i32 as u32,
-    0xb5bc081 as i32 as u32,
-    0xba7c081 as i32 as u32,
-    0xbbcc081 as i32 as u32,
-    0xbc004ad as i32 as u32,
-    0xbc244ad as i32 as u32,
-    0xbc484ad as i32 as u32,
-    0xbc6f383 as i32 as u32,
-    0xbe0852d as i32 as u32,
-    0xbe31d03 as i32 as u32,
-    0xbf1882d as i32 as u32,
-    0xc000081 as i32 as u32,
-    0xc0d8283 as i32 as u32,
-    0xc130b84 as i32 as u32,
-    0xc194284 as i32 as u32,
-    0xc1c0122 as i32 as u32,
-    0xc1cc122 as i32 as u32,
-    0xc1d8122 as i32 as u32,
-    0xc1e4122 as i32 as u32,
-    0xc1f0122 as i32 as u32,
-    0xc250084 as i32 as u32,
-    0xc26c123 as i32 as u32,
-    0xc278084 as i32 as u32,
-    0xc27c085 as i32 as u32,
-    0xc2b0b84 as i32 as u32,
-    0xc314284 as i32 as u32,
-    0xc340122 as i32 as u32,
-    0xc34c122 as i32 as u32,
-    0xc358122 as i32 as u32,
-    0xc364122 as i32 as u32,
-    0xc370122 as i32 as u32,
-    0xc3d0084 as i32 as u32,
-    0xc3dc220 as i32 as u32,
-    0xc3f8084 as i32 as u32,
-    0xc3fc085 as i32 as u32,
-    0xc4c4a2d as i32 as u32,
-    0xc51451f as i32 as u32,
-    0xc53ca9f as i32 as u32,
-    0xc5915ad as i32 as u32,
-    0xc648703 as i32 as u32,
-    0xc800741 as i32 as u32,
-    0xc838089 as i32 as u32,
-    0xc83c129 as i32 as u32,
-    0xc8441a9 as i32 as u32,
-    0xc850089 as i32 as u32,
-    0xc854129 as i32 as u32,
-    0xc85c2a9 as i32 as u32,
-    0xc870089 as i32 as u32,
-    0xc87408f as i32 as u32,
-    0xc87808d as i32 as u32,
-    0xc881241 as i32 as u32,
-    0xc910203 as i32 as u32,
-    0xc940099 as i32 as u32,
-    0xc9444a3 as i32 as u32,
-    0xc968323 as i32 as u32,
-    0xc98072d as i32 as u32,
-    0xc9b84af as i32 as u32,
-    0xc9dc2a1 as i32 as u32,
-    0xc9f00b5 as i32 as u32,
-    0xc9f40b3 as i32 as u32,
-    0xc9f8085 as i32 as u32,
-    0xca01883 as i32 as u32,
-    0xcac4223 as i32 as u32,
-    0xcad4523 as i32 as u32,
-    0xcafc097 as i32 as u32,
-    0xcb004a1 as i32 as u32,
-    0xcb241a5 as i32 as u32,
-    0xcb30097 as i32 as u32,
-    0xcb34099 as i32 as u32,
-    0xcb38097 as i32 as u32,
-    0xcb3c099 as i32 as u32,
-    0xcb417ad as i32 as u32,
-    0xcbfc085 as i32 as u32,
-    0xcc001b3 as i32 as u32,
-    0xcc0c0b1 as i32 as u32,
-    0xcc100b3 as i32 as u32,
-    0xcc14131 as i32 as u32,
-    0xcc1c0b5 as i32 as u32,
-    0xcc200b3 as i32 as u32,
-    0xcc241b1 as i32 as u32,
-    0xcc30133 as i32 as u32,
-    0xcc38131 as i32 as u32,
-    0xcc40085 as i32 as u32,
-    0xcc440b1 as i32 as u32,
-    0xcc48133 as i32 as u32,
-    0xcc50085 as i32 as u32,
-    0xcc540b5 as i32 as u32,
-    0xcc580b7 as i32 as u32,
-    0xcc5c0b5 as i32 as u32,
-    0xcc600b1 as i32 as u32,
-    0xcc64135 as i32 as u32,
-    0xcc6c0b3 as i32 as u32,
-    0xcc701b1 as i32 as u32,
-    0xcc7c0b3 as i32 as u32,
-    0xcc800b5 as i32 as u32,
-    0xcc840b3 as i32 as u32,
-    0xcc881b1 as i32 as u32,
-    0xcc9422f as i32 as u32,
-    0xcca4131 as i32 as u32,
-    0xccac0b5 as i32 as u32,
-    0xccb00b1 as i32 as u32,
-    0xccb40b3 as i32 as u32,
-    0xccb80b5 as i32 as u32,
-    0xccbc0b1 as i32 as u32,
-    0xccc012f as i32 as u32,
-    0xccc80b5 as i32 as u32,
-    0xcccc0b3 as i32 as u32,
-    0xccd00b5 as i32 as u32,
-    0xccd40b1 as i32 as u32,
-    0xccd80b5 as i32 as u32,
-    0xccdc085 as i32 as u32,
-    0xcce02b1 as i32 as u32,
-    0xccf40b3 as i32 as u32,
-    0xccf80b1 as i32 as u32,
-    0xccfc085 as i32 as u32,
-    0xcd001b1 as i32 as u32,
-    0xcd0c0b3 as i32 as u32,
-    0xcd101b1 as i32 as u32,
-    0xcd1c0b5 as i32 as u32,
-    0xcd200b3 as i32 as u32,
-    0xcd24085 as i32 as u32,
-    0xcd280b5 as i32 as u32,
-    0xcd2c085 as i32 as u32,
-    0xcd30133 as i32 as u32,
-    0xcd381b1 as i32 as u32,
-    0xcd440b3 as i32 as u32,
-    0xcd48085 as i32 as u32,
-    0xcd4c0b1 as i32 as u32,
-    0xcd500b3 as i32 as u32,
-    0xcd54085 as i32 as u32,
-    0xcd580b5 as i32 as u32,
-    0xcd5c0b1 as i32 as u32,
-    0xcd60521 as i32 as u32,
-    0xcd88525 as i32 as u32,
-    0xcdb02a5 as i32 as u32,
-    0xcdc4099 as i32 as u32,
-    0xcdc8117 as i32 as u32,
-    0xcdd0099 as i32 as u32,
-    0xcdd4197 as i32 as u32,
-    0xcde0127 as i32 as u32,
-    0xcde8285 as i32 as u32,
-    0xcdfc089 as i32 as u32,
-    0xce0043f as i32 as u32,
-    0xce20099 as i32 as u32,
-    0xce2409b as i32 as u32,
-    0xce283bf as i32 as u32,
-    0xce44219 as i32 as u32,
-    0xce54205 as i32 as u32,
-    0xce6433f as i32 as u32,
-    0xce7c131 as i32 as u32,
-    0xce84085 as i32 as u32,
-    0xce881b1 as i32 as u32,
-    0xce94085 as i32 as u32,
-    0xce98107 as i32 as u32,
-    0xcea0089 as i32 as u32,
-    0xcea4097 as i32 as u32,
-    0xcea8219 as i32 as u32,
-    0xceb809d as i32 as u32,
-    0xcebc08d as i32 as u32,
-    0xcec083f as i32 as u32,
-    0xcf00105 as i32 as u32,
-    0xcf0809b as i32 as u32,
-    0xcf0c197 as i32 as u32,
-    0xcf1809b as i32 as u32,
-    0xcf1c099 as i32 as u32,
-    0xcf20517 as i32 as u32,
-    0xcf48099 as i32 as u32,
-    0xcf4c117 as i32 as u32,
-    0xcf54119 as i32 as u32,
-    0xcf5c097 as i32 as u32,
-    0xcf6009b as i32 as u32,
-    0xcf64099 as i32 as u32,
-    0xcf68217 as i32 as u32,
-    0xcf78119 as i32 as u32,
-    0xcf804a1 as i32 as u32,
-    0xcfa4525 as i32 as u32,
-    0xcfcc525 as i32 as u32,
-    0xcff4125 as i32 as u32,
-    0xcffc099 as i32 as u32,
-    0x29a70103 as i32 as u32,
-    0x29dc0081 as i32 as u32,
-    0x29fe0103 as i32 as u32,
-    0x2ad70203 as i32 as u32,
-    0x2ada4081 as i32 as u32,
-    0x3e401482 as i32 as u32,
-    0x3e4a7f82 as i32 as u32,
-    0x3e6a3f82 as i32 as u32,
-    0x3e8aa102 as i32 as u32,
-    0x3e9b0110 as i32 as u32,
-    0x3e9c2f82 as i32 as u32,
-    0x3eb3c590 as i32 as u32,
-    0x3ec00197 as i32 as u32,
-    0x3ec0c119 as i32 as u32,
-    0x3ec1413f as i32 as u32,
-    0x3ec4c2af as i32 as u32,
-    0x3ec74184 as i32 as u32,
-    0x3ec804ad as i32 as u32,
-    0x3eca4081 as i32 as u32,
-    0x3eca8304 as i32 as u32,
-    0x3ecc03a0 as i32 as u32,
-    0x3ece02a0 as i32 as u32,
-    0x3ecf8084 as i32 as u32,
-    0x3ed00120 as i32 as u32,
-    0x3ed0c120 as i32 as u32,
-    0x3ed184ae as i32 as u32,
-    0x3ed3c085 as i32 as u32,
-    0x3ed4312d as i32 as u32,
-    0x3ef4cbad as i32 as u32,
-    0x3efa892f as i32 as u32,
-    0x3eff022d as i32 as u32,
-    0x3f002f2f as i32 as u32,
-    0x3f1782a5 as i32 as u32,
-    0x3f18c0b1 as i32 as u32,
-    0x3f1907af as i32 as u32,
-    0x3f1cffaf as i32 as u32,
-    0x3f3c81a5 as i32 as u32,
-    0x3f3d64af as i32 as u32,
-    0x3f542031 as i32 as u32,
-    0x3f649b31 as i32 as u32,
-    0x3f7c0131 as i32 as u32,
-    0x3f7c83b3 as i32 as u32,
-    0x3f7e40b1 as i32 as u32,
-    0x3f7e80bd as i32 as u32,
-    0x3f7ec0bb as i32 as u32,
-    0x3f7f00b3 as i32 as u32,
-    0x3f840503 as i32 as u32,
-    0x3f8c01ad as i32 as u32,
-    0x3f8cc315 as i32 as u32,
-    0x3f8e462d as i32 as u32,
-    0x3f91cc03 as i32 as u32,
-    0x3f97c695 as i32 as u32,
-    0x3f9c01af as i32 as u32,
-    0x3f9d0085 as i32 as u32,
-    0x3f9d852f as i32 as u32,
-    0x3fa03aad as i32 as u32,
-    0x3fbd442f as i32 as u32,
-    0x3fc06f1f as i32 as u32,
-    0x3fd7c11f as i32 as u32,
-    0x3fd85fad as i32 as u32,
-    0x3fe80081 as i32 as u32,
-    0x3fe84f1f as i32 as u32,
-    0x3ff0831f as i32 as u32,
-    0x3ff2831f as i32 as u32,
-    0x3ff4831f as i32 as u32,
-    0x3ff6819f as i32 as u32,
-    0x3ff80783 as i32 as u32,
-    0x44268192 as i32 as u32,
-    0x442ac092 as i32 as u32,
-    0x444b8112 as i32 as u32,
-    0x44d2c112 as i32 as u32,
-    0x452ec212 as i32 as u32,
-    0x456e8112 as i32 as u32,
-    0x464e0092 as i32 as u32,
-    0x74578392 as i32 as u32,
-    0x746ec312 as i32 as u32,
-    0x75000d1f as i32 as u32,
-    0x75068d1f as i32 as u32,
-    0x750d0d1f as i32 as u32,
-    0x7513839f as i32 as u32,
-    0x7515891f as i32 as u32,
-    0x751a0d1f as i32 as u32,
-    0x75208d1f as i32 as u32,
-    0x75271015 as i32 as u32,
-    0x752f439f as i32 as u32,
-    0x7531459f as i32 as u32,
-    0x75340d1f as i32 as u32,
-    0x753a8d1f as i32 as u32,
-    0x75410395 as i32 as u32,
-    0x7543441f as i32 as u32,
-    0x7545839f as i32 as u32,
-    0x75478d1f as i32 as u32,
-    0x754e0795 as i32 as u32,
-    0x7552839f as i32 as u32,
-    0x75548d1f as i32 as u32,
-    0x755b0d1f as i32 as u32,
-    0x75618d1f as i32 as u32,
-    0x75680d1f as i32 as u32,
-    0x756e8d1f as i32 as u32,
-    0x75750d1f as i32 as u32,
-    0x757b8d1f as i32 as u32,
-    0x75820d1f as i32 as u32,
-    0x75888d1f as i32 as u32,
-    0x758f0d1f as i32 as u32,
-    0x75958d1f as i32 as u32,
-    0x759c0d1f as i32 as u32,
-    0x75a28d1f as i32 as u32,
-    0x75a90103 as i32 as u32,
-    0x75aa089f as i32 as u32,
-    0x75ae4081 as i32 as u32,
-    0x75ae839f as i32 as u32,
-    0x75b04081 as i32 as u32,
-    0x75b08c9f as i32 as u32,
-    0x75b6c081 as i32 as u32,
-    0x75b7032d as i32 as u32,
-    0x75b8889f as i32 as u32,
-    0x75bcc081 as i32 as u32,
-    0x75bd039f as i32 as u32,
-    0x75bec081 as i32 as u32,
-    0x75bf0c9f as i32 as u32,
-    0x75c54081 as i32 as u32,
-    0x75c5832d as i32 as u32,
-    0x75c7089f as i32 as u32,
-    0x75cb4081 as i32 as u32,
-    0x75cb839f as i32 as u32,
-    0x75cd4081 as i32 as u32,
-    0x75cd8c9f as i32 as u32,
-    0x75d3c081 as i32 as u32,
-    0x75d4032d as i32 as u32,
-    0x75d5889f as i32 as u32,
-    0x75d9c081 as i32 as u32,
-    0x75da039f as i32 as u32,
-    0x75dbc081 as i32 as u32,
-    0x75dc0c9f as i32 as u32,
-    0x75e24081 as i32 as u32,
-    0x75e2832d as i32 as u32,
-    0x75e4089f as i32 as u32,
-    0x75e84081 as i32 as u32,
-    0x75e8839f as i32 as u32,
-    0x75ea4081 as i32 as u32,
-    0x75ea8c9f as i32 as u32,
-    0x75f0c081 as i32 as u32,
-    0x75f1042d as i32 as u32,
-    0x75f3851f as i32 as u32,
-    0x75f6051f as i32 as u32,
-    0x75f8851f as i32 as u32,
-    0x75fb051f as i32 as u32,
-    0x75fd851f as i32 as u32,
-    0x7b80022d as i32 as u32,
-    0x7b814dad as i32 as u32,
-    0x7b884203 as i32 as u32,
-    0x7b89c081 as i32 as u32,
-    0x7b8a452d as i32 as u32,
-    0x7b8d0403 as i32 as u32,
-    0x7b908081 as i32 as u32,
-    0x7b91dc03 as i32 as u32,
-    0x7ba0052d as i32 as u32,
-    0x7ba2c8ad as i32 as u32,
-    0x7ba84483 as i32 as u32,
-    0x7baac8ad as i32 as u32,
-    0x7c400097 as i32 as u32,
-    0x7c404521 as i32 as u32,
-    0x7c440d25 as i32 as u32,
-    0x7c4a8087 as i32 as u32,
-    0x7c4ac115 as i32 as u32,
-    0x7c4b4117 as i32 as u32,
-    0x7c4c0d1f as i32 as u32,
-    0x7c528217 as i32 as u32,
-    0x7c538099 as i32 as u32,
-    0x7c53c097 as i32 as u32,
-    0x7c5a8197 as i32 as u32,
-    0x7c640097 as i32 as u32,
-    0x7c80012f as i32 as u32,
-    0x7c808081 as i32 as u32,
-    0x7c841603 as i32 as u32,
-    0x7c9004c1 as i32 as u32,
-    0x7c940103 as i32 as u32,
-    0x7efc051f as i32 as u32,
-    0xbe0001ac as u32,
-    0xbe00d110 as u32,
-    0xbe0947ac as u32,
-    0xbe0d3910 as u32,
-    0xbe29872c as u32,
-    0xbe2d022c as u32,
-    0xbe2e3790 as u32,
-    0xbe49ff90 as u32,
-    0xbe69bc10 as u32,
-];
-static mut unicode_decomp_table2: [u16; 690] = [
-    0x20 as i32 as u16,
-    0 as i32 as u16,
-    0x61 as i32 as u16,
-    0x2 as i32 as u16,
-    0x4 as i32 as u16,
-    0x6 as i32 as u16,
-    0x3bc as i32 as u16,
-    0x8 as i32 as u16,
-    0xa as i32 as u16,
-    0xc as i32 as u16,
-    0x15 as i32 as u16,
-    0x95 as i32 as u16,
-    0xa5 as i32 as u16,
-    0xb9 as i32 as u16,
-    0xc1 as i32 as u16,
-    0xc3 as i32 as u16,
-    0xc7 as i32 as u16,
-    0xcb as i32 as u16,
-    0xd1 as i32 as u16,
-    0xd7 as i32 as u16,
-    0xdd as i32 as u16,
-    0xe0 as i32 as u16,
-    0xe6 as i32 as u16,
-    0xf8 as i32 as u16,
-    0x108 as i32 as u16,
-    0x10a as i32 as u16,
-    0x73 as i32 as u16,
-    0x110 as i32 as u16,
-    0x112 as i32 as u16,
-    0x114 as i32 as u16,
-    0x120 as i32 as u16,
-    0x12c as i32 as u16,
-    0x144 as i32 as u16,
-    0x14d as i32 as u16,
-    0x153 as i32 as u16,
-    0x162 as i32 as u16,
-    0x168 as i32 as u16,
-    0x16a as i32 as u16,
-    0x176 as i32 as u16,
-    0x192 as i32 as u16,
-    0x194 as i32 as u16,
-    0x1a9 as i32 as u16,
-    0x1bb as i32 as u16,
-    0x1c7 as i32 as u16,
-    0x1d1 as i32 as u16,
-    0x1d5 as i32 as u16,
-    0x2b9 as i32 as u16,
-    0x1d7 as i32 as u16,
-    0x3b as i32 as u16,
-    0x1d9 as i32 as u16,
-    0x1db as i32 as u16,
-    0xb7 as i32 as u16,
-    0x1e1 as i32 as u16,
-    0x1fc as i32 as u16,
-    0x20c as i32 as u16,
-    0x218 as i32 as u16,
-    0x21d as i32 as u16,
-    0x223 as i32 as u16,
-    0x227 as i32 as u16,
-    0x3a3 as i32 as u16,
-    0x233 as i32 as u16,
-    0x23f as i32 as u16,
-    0x242 as i32 as u16,
-    0x24b as i32 as u16,
-    0x24e as i32 as u16,
-    0x251 as i32 as u16,
-    0x25d as i32 as u16,
-    0x260 as i32 as u16,
-    0x269 as i32 as u16,
-    0x26c as i32 as u16,
-    0x26f as i32 as u16,
-    0x275 as i32 as u16,
-    0x278 as i32 as u16,
-    0x281 as i32 as u16,
-    0x28a as i32 as u16,
-    0x29c as i32 as u16,
-    0x29f as i32 as u16,
-    0x2a3 as i32 as u16,
-    0x2af as i32 as u16,
-    0x2b9 as i32 as u16,
-    0x2c5 as i32 as u16,
-    0x2c9 as i32 as u16,
-    0x2cd as i32 as u16,
-    0x2d1 as i32 as u16,
-    0x2d5 as i32 as u16,
-    0x2e7 as i32 as u16,
-    0x2ed as i32 as u16,
-    0x2f1 as i32 as u16,
-    0x2f5 as i32 as u16,
-    0x2f9 as i32 as u16,
-    0x2fd as i32 as u16,
-    0x305 as i32 as u16,
-    0x309 as i32 as u16,
-    0x30d as i32 as u16,
-    0x313 as i32 as u16,
-    0x317 as i32 as u16,
-    0x31b as i32 as u16,
-    0x323 as i32 as u16,
-    0x327 as i32 as u16,
-    0x32b as i32 as u16,
-    0x32f as i32 as u16,
-    0x335 as i32 as u16,
-    0x33d as i32 as u16,
-    0x341 as i32 as u16,
-    0x349 as i32 as u16,
-    0x34d as i32 as u16,
-    0x351 as i32 as u16,
-    0xf0b as i32 as u16,
-    0x357 as i32 as u16,
-    0x35b as i32 as u16,
-    0x35f as i32 as u16,
-    0x363 as i32 as u16,
-    0x367 as i32 as u16,
-    0x36b as i32 as u16,
-    0x36f as i32 as u16,
-    0x373 as i32 as u16,
-    0x379 as i32 as u16,
-    0x37d as i32 as u16,
-    0x381 as i32 as u16,
-    0x385 as i32 as u16,
-    0x389 as i32 as u16,
-    0x38d as i32 as u16,
-    0x391 as i32 as u16,
-    0x395 as i32 as u16,
-    0x399 as i32 as u16,
-    0x39d as i32 as u16,
-    0x3a1 as i32 as u16,
-    0x10dc as i32 as u16,
-    0x3a5 as i32 as u16,
-    0x3c9 as i32 as u16,
-    0x3cd as i32 as u16,
-    0x3d9 as i32 as u16,
-    0x3dd as i32 as u16,
-    0x3e1 as i32 as u16,
-    0x3ef as i32 as u16,
-    0x3f1 as i32 as u16,
-    0x43d as i32 as u16,
-    0x44f as i32 as u16,
-    0x499 as i32 as u16,
-    0x4f0 as i32 as u16,
-    0x502 as i32 as u16,
-    0x54a as i32 as u16,
-    0x564 as i32 as u16,
-    0x56c as i32 as u16,
-    0x570 as i32 as u16,
-    0x573 as i32 as u16,
-    0x59a as i32 as u16,
-    0x5fa as i32 as u16,
-    0x5fe as i32 as u16,
-    0x607 as i32 as u16,
-    0x60b as i32 as u16,
-    0x614 as i32 as u16,
-    0x618 as i32 as u16,
-    0x61e as i32 as u16,
-    0x622 as i32 as u16,
-    0x628 as i32 as u16,
-    0x68e as i32 as u16,
-    0x694 as i32 as u16,
-    0x698 as i32 as u16,
-    0x69e as i32 as u16,
-    0x6a2 as i32 as u16,
-    0x6ab as i32 as u16,
-    0x3ac as i32 as u16,
-    0x6f3 as i32 as u16,
-    0x3ad as i32 as u16,
-    0x6f6 as i32 as u16,
-    0x3ae as i32 as u16,
-    0x6f9 as i32 as u16,
-    0x3af as i32 as u16,
-    0x6fc as i32 as u16,
-    0x3cc as i32 as u16,
-    0x6ff as i32 as u16,
-    0x3cd as i32 as u16,
-    0x702 as i32 as u16,
-    0x3ce as i32 as u16,
-    0x705 as i32 as u16,
-    0x709 as i32 as u16,
-    0x70d as i32 as u16,
-    0x711 as i32 as u16,
-    0x386 as i32 as u16,
-    0x732 as i32 as u16,
-    0x735 as i32 as u16,
-    0x3b9 as i32 as u16,
-    0x737 as i32 as u16,
-    0x73b as i32 as u16,
-    0x388 as i32 as u16,
-    0x753 as i32 as u16,
-    0x389 as i32 as u16,
-    0x756 as i32 as u16,
-    0x390 as i32 as u16,
-    0x76b as i32 as u16,
-    0x38a as i32 as u16,
-    0x777 as i32 as u16,
-    0x3b0 as i32 as u16,
-    0x789 as i32 as u16,
-    0x38e as i32 as u16,
-    0x799 as i32 as u16,
-    0x79f as i32 as u16,
-    0x7a3 as i32 as u16,
-    0x38c as i32 as u16,
-    0x7b8 as i32 as u16,
-    0x38f as i32 as u16,
-    0x7bb as i32 as u16,
-    0xb4 as i32 as u16,
-    0x7be as i32 as u16,
-    0x7c0 as i32 as u16,
-    0x7c2 as i32 as u16,
-    0x2010 as i32 as u16,
-    0x7cb as i32 as u16,
-    0x2e as i32 as u16,
-    0x7cd as i32 as u16,
-    0x7cf as i32 as u16,
-    0x20 as i32 as u16,
-    0x7d2 as i32 as u16,
-    0x7d6 as i32 as u16,
-    0x7db as i32 as u16,
-    0x7df as i32 as u16,
-    0x7e4 as i32 as u16,
-    0x7ea as i32 as u16,
-    0x7f0 as i32 as u16,
-    0x20 as i32 as u16,
-    0x7f6 as i32 as u16,
-    0x2212 as i32 as u16,
-    0x801 as i32 as u16,
-    0x805 as i32 as u16,
-    0x807 as i32 as u16,
-    0x81d as i32 as u16,
-    0x825 as i32 as u16,
-    0x827 as i32 as u16,
-    0x43 as i32 as u16,
-    0x82d as i32 as u16,
-    0x830 as i32 as u16,
-    0x190 as i32 as u16,
-    0x836 as i32 as u16,
-    0x839 as i32 as u16,
-    0x4e as i32 as u16,
-    0x845 as i32 as u16,
-    0x847 as i32 as u16,
-    0x84c as i32 as u16,
-    0x84e as i32 as u16,
-    0x851 as i32 as u16,
-    0x5a as i32 as u16,
-    0x3a9 as i32 as u16,
-    0x5a as i32 as u16,
-    0x853 as i32 as u16,
-    0x857 as i32 as u16,
-    0x860 as i32 as u16,
-    0x69 as i32 as u16,
-    0x862 as i32 as u16,
-    0x865 as i32 as u16,
-    0x86f as i32 as u16,
-    0x874 as i32 as u16,
-    0x87a as i32 as u16,
-    0x87e as i32 as u16,
-    0x8a2 as i32 as u16,
-    0x49 as i32 as u16,
-    0x8a4 as i32 as u16,
-    0x8a6 as i32 as u16,
-    0x8a9 as i32 as u16,
-    0x56 as i32 as u16,
-    0x8ab as i32 as u16,
-    0x8ad as i32 as u16,
-    0x8b0 as i32 as u16,
-    0x8b4 as i32 as u16,
-    0x58 as i32 as u16,
-    0x8b6 as i32 as u16,
-    0x8b8 as i32 as u16,
-    0x8bb as i32 as u16,
-    0x8c0 as i32 as u16,
-    0x8c2 as i32 as u16,
-    0x8c5 as i32 as u16,
-    0x76 as i32 as u16,
-    0x8c7 as i32 as u16,
-    0x8c9 as i32 as u16,
-    0x8cc as i32 as u16,
-    0x8d0 as i32 as u16,
-    0x78 as i32 as u16,
-    0x8d2 as i32 as u16,
-    0x8d4 as i32 as u16,
-    0x8d7 as i32 as u16,
-    0x8db as i32 as u16,
-    0x8de as i32 as u16,
-    0x8e4 as i32 as u16,
-    0x8e7 as i32 as u16,
-    0x8f0 as i32 as u16,
-    0x8f3 as i32 as u16,
-    0x8f6 as i32 as u16,
-    0x8f9 as i32 as u16,
-    0x902 as i32 as u16,
-    0x906 as i32 as u16,
-    0x90b as i32 as u16,
-    0x90f as i32 as u16,
-    0x914 as i32 as u16,
-    0x917 as i32 as u16,
-    0x91a as i32 as u16,
-    0x923 as i32 as u16,
-    0x92c as i32 as u16,
-    0x93b as i32 as u16,
-    0x93e as i32 as u16,
-    0x941 as i32 as u16,
-    0x944 as i32 as u16,
-    0x947 as i32 as u16,
-    0x94a as i32 as u16,
-    0x956 as i32 as u16,
-    0x95c as i32 as u16,
-    0x960 as i32 as u16,
-    0x962 as i32 as u16,
-    0x964 as i32 as u16,
-    0x968 as i32 as u16,
-    0x96a as i32 as u16,
-    0x970 as i32 as u16,
-    0x978 as i32 as u16,
-    0x97c as i32 as u16,
-    0x980 as i32 as u16,
-    0x986 as i32 as u16,
-    0x989 as i32 as u16,
-    0x98f as i32 as u16,
-    0x991 as i32 as u16,
-    0x30 as i32 as u16,
-    0x993 as i32 as u16,
-    0x999 as i32 as u16,
-    0x99c as i32 as u16,
-    0x99e as i32 as u16,
-    0x9a1 as i32 as u16,
-    0x9a4 as i32 as u16,
-    0x2d61 as i32 as u16,
-    0x6bcd as i32 as u16,
-    0x9f9f as i32 as u16,
-    0x9a6 as i32 as u16,
-    0x9b1 as i32 as u16,
-    0x9bc as i32 as u16,
-    0x9c7 as i32 as u16,
-    0xa95 as i32 as u16,
-    0xaa1 as i32 as u16,
-    0xb15 as i32 as u16,
-    0x20 as i32 as u16,
-    0xb27 as i32 as u16,
-    0xb31 as i32 as u16,
-    0xb8d as i32 as u16,
-    0xba1 as i32 as u16,
-    0xba5 as i32 as u16,
-    0xba9 as i32 as u16,
-    0xbad as i32 as u16,
-    0xbb1 as i32 as u16,
-    0xbb5 as i32 as u16,
-    0xbb9 as i32 as u16,
-    0xbbd as i32 as u16,
-    0xbc1 as i32 as u16,
-    0xbc5 as i32 as u16,
-    0xc21 as i32 as u16,
-    0xc35 as i32 as u16,
-    0xc39 as i32 as u16,
-    0xc3d as i32 as u16,
-    0xc41 as i32 as u16,
-    0xc45 as i32 as u16,
-    0xc49 as i32 as u16,
-    0xc4d as i32 as u16,
-    0xc51 as i32 as u16,
-    0xc55 as i32 as u16,
-    0xc59 as i32 as u16,
-    0xc6f as i32 as u16,
-    0xc71 as i32 as u16,
-    0xc73 as i32 as u16,
-    0xca0 as i32 as u16,
-    0xcbc as i32 as u16,
-    0xcdc as i32 as u16,
-    0xce4 as i32 as u16,
-    0xcec as i32 as u16,
-    0xcf4 as i32 as u16,
-    0xcfc as i32 as u16,
-    0xd04 as i32 as u16,
-    0xd0c as i32 as u16,
-    0xd14 as i32 as u16,
-    0xd22 as i32 as u16,
-    0xd2e as i32 as u16,
-    0xd7a as i32 as u16,
-    0xd82 as i32 as u16,
-    0xd85 as i32 as u16,
-    0xd89 as i32 as u16,
-    0xd8d as i32 as u16,
-    0xd9d as i32 as u16,
-    0xdb1 as i32 as u16,
-    0xdb5 as i32 as u16,
-    0xdbc as i32 as u16,
-    0xdc2 as i32 as u16,
-    0xdc6 as i32 as u16,
-    0xe28 as i32 as u16,
-    0xe2c as i32 as u16,
-    0xe30 as i32 as u16,
-    0xe32 as i32 as u16,
-    0xe36 as i32 as u16,
-    0xe3c as i32 as u16,
-    0xe3e as i32 as u16,
-    0xe41 as i32 as u16,
-    0xe43 as i32 as u16,
-    0xe46 as i32 as u16,
-    0xe77 as i32 as u16,
-    0xe7b as i32 as u16,
-    0xe89 as i32 as u16,
-    0xe8e as i32 as u16,
-    0xe94 as i32 as u16,
-    0xe9c as i32 as u16,
-    0xea3 as i32 as u16,
-    0xea9 as i32 as u16,
-    0xeb4 as i32 as u16,
-    0xebe as i32 as u16,
-    0xec6 as i32 as u16,
-    0xeca as i32 as u16,
-    0xecf as i32 as u16,
-    0xed9 as i32 as u16,
-    0xedd as i32 as u16,
-    0xee4 as i32 as u16,
-    0xeec as i32 as u16,
-    0xef3 as i32 as u16,
-    0xef8 as i32 as u16,
-    0xf04 as i32 as u16,
-    0xf0a as i32 as u16,
-    0xf15 as i32 as u16,
-    0xf1b as i32 as u16,
-    0xf22 as i32 as u16,
-    0xf28 as i32 as u16,
-    0xf33 as i32 as u16,
-    0xf3d as i32 as u16,
-    0xf45 as i32 as u16,
-    0xf4c as i32 as u16,
-    0xf51 as i32 as u16,
-    0xf57 as i32 as u16,
-    0xf5e as i32 as u16,
-    0xf63 as i32 as u16,
-    0xf69 as i32 as u16,
-    0xf70 as i32 as u16,
-    0xf76 as i32 as u16,
-    0xf7d as i32 as u16,
-    0xf82 as i32 as u16,
-    0xf89 as i32 as u16,
-    0xf8d as i32 as u16,
-    0xf9e as i32 as u16,
-    0xfa4 as i32 as u16,
-    0xfa9 as i32 as u16,
-    0xfad as i32 as u16,
-    0xfb8 as i32 as u16,
-    0xfbe as i32 as u16,
-    0xfc9 as i32 as u16,
-    0xfd0 as i32 as u16,
-    0xfd6 as i32 as u16,
-    0xfda as i32 as u16,
-    0xfe1 as i32 as u16,
-    0xfe5 as i32 as u16,
-    0xfef as i32 as u16,
-    0xffa as i32 as u16,
-    0x1000 as i32 as u16,
-    0x1004 as i32 as u16,
-    0x1009 as i32 as u16,
-    0x100f as i32 as u16,
-    0x1013 as i32 as u16,
-    0x101a as i32 as u16,
-    0x101f as i32 as u16,
-    0x1023 as i32 as u16,
-    0x1029 as i32 as u16,
-    0x102f as i32 as u16,
-    0x1032 as i32 as u16,
-    0x1036 as i32 as u16,
-    0x1039 as i32 as u16,
-    0x103f as i32 as u16,
-    0x1045 as i32 as u16,
-    0x1059 as i32 as u16,
-    0x1061 as i32 as u16,
-    0x1079 as i32 as u16,
-    0x107c as i32 as u16,
-    0x1080 as i32 as u16,
-    0x1095 as i32 as u16,
-    0x10a1 as i32 as u16,
-    0x10b1 as i32 as u16,
-    0x10c3 as i32 as u16,
-    0x10cb as i32 as u16,
-    0x10cf as i32 as u16,
-    0x10da as i32 as u16,
-    0x10de as i32 as u16,
-    0x10ea as i32 as u16,
-    0x10f2 as i32 as u16,
-    0x10f4 as i32 as u16,
-    0x1100 as i32 as u16,
-    0x1105 as i32 as u16,
-    0x1111 as i32 as u16,
-    0x1141 as i32 as u16,
-    0x1149 as i32 as u16,
-    0x114d as i32 as u16,
-    0x1153 as i32 as u16,
-    0x1157 as i32 as u16,
-    0x115a as i32 as u16,
-    0x116e as i32 as u16,
-    0x1171 as i32 as u16,
-    0x1175 as i32 as u16,
-    0x117b as i32 as u16,
-    0x117d as i32 as u16,
-    0x1181 as i32 as u16,
-    0x1184 as i32 as u16,
-    0x118c as i32 as u16,
-    0x1192 as i32 as u16,
-    0x1196 as i32 as u16,
-    0x119c as i32 as u16,
-    0x11a2 as i32 as u16,
-    0x11a8 as i32 as u16,
-    0x11ab as i32 as u16,
-    0xa76f as i32 as u16,
-    0x11af as i32 as u16,
-    0x11b3 as i32 as u16,
-    0x28d as i32 as u16,
-    0x11bb as i32 as u16,
-    0x120d as i32 as u16,
-    0x130b as i32 as u16,
-    0x1409 as i32 as u16,
-    0x148d as i32 as u16,
-    0x1492 as i32 as u16,
-    0x1550 as i32 as u16,
-    0x1569 as i32 as u16,
-    0x156f as i32 as u16,
-    0x1575 as i32 as u16,
-    0x157b as i32 as u16,
-    0x1587 as i32 as u16,
-    0x1593 as i32 as u16,
-    0x2b as i32 as u16,
-    0x159e as i32 as u16,
-    0x15b6 as i32 as u16,
-    0x15ba as i32 as u16,
-    0x15be as i32 as u16,
-    0x15c2 as i32 as u16,
-    0x15c6 as i32 as u16,
-    0x15ca as i32 as u16,
-    0x15de as i32 as u16,
-    0x15e2 as i32 as u16,
-    0x1646 as i32 as u16,
-    0x165f as i32 as u16,
-    0x1685 as i32 as u16,
-    0x168b as i32 as u16,
-    0x1749 as i32 as u16,
-    0x174f as i32 as u16,
-    0x1754 as i32 as u16,
-    0x1774 as i32 as u16,
-    0x1874 as i32 as u16,
-    0x187a as i32 as u16,
-    0x190e as i32 as u16,
-    0x19d0 as i32 as u16,
-    0x1a74 as i32 as u16,
-    0x1a7c as i32 as u16,
-    0x1a9a as i32 as u16,
-    0x1a9f as i32 as u16,
-    0x1ab3 as i32 as u16,
-    0x1abd as i32 as u16,
-    0x1ac3 as i32 as u16,
-    0x1ad7 as i32 as u16,
-    0x1adc as i32 as u16,
-    0x1ae2 as i32 as u16,
-    0x1af0 as i32 as u16,
-    0x1b20 as i32 as u16,
-    0x1b2d as i32 as u16,
-    0x1b35 as i32 as u16,
-    0x1b39 as i32 as u16,
-    0x1b4f as i32 as u16,
-    0x1bc6 as i32 as u16,
-    0x1bd8 as i32 as u16,
-    0x1bda as i32 as u16,
-    0x1bdc as i32 as u16,
-    0x3164 as i32 as u16,
-    0x1c1d as i32 as u16,
-    0x1c1f as i32 as u16,
-    0x1c21 as i32 as u16,
-    0x1c23 as i32 as u16,
-    0x1c25 as i32 as u16,
-    0x1c27 as i32 as u16,
-    0x1c45 as i32 as u16,
-    0x1c53 as i32 as u16,
-    0x1c58 as i32 as u16,
-    0x1c61 as i32 as u16,
-    0x1c6a as i32 as u16,
-    0x1c7c as i32 as u16,
-    0x1c85 as i32 as u16,
-    0x1c8a as i32 as u16,
-    0x1caa as i32 as u16,
-    0x1cc5 as i32 as u16,
-    0x1cc7 as i32 as u16,
-    0x1cc9 as i32 as u16,
-    0x1ccb as i32 as u16,
-    0x1ccd as i32 as u16,
-    0x1ccf as i32 as u16,
-    0x1cd1 as i32 as u16,
-    0x1cd3 as i32 as u16,
-    0x1cf3 as i32 as u16,
-    0x1cf5 as i32 as u16,
-    0x1cf7 as i32 as u16,
-    0x1cf9 as i32 as u16,
-    0x1cfb as i32 as u16,
-    0x1d02 as i32 as u16,
-    0x1d04 as i32 as u16,
-    0x1d06 as i32 as u16,
-    0x1d08 as i32 as u16,
-    0x1d17 as i32 as u16,
-    0x1d19 as i32 as u16,
-    0x1d1b as i32 as u16,
-    0x1d1d as i32 as u16,
-    0x1d1f as i32 as u16,
-    0x1d21 as i32 as u16,
-    0x1d23 as i32 as u16,
-    0x1d25 as i32 as u16,
-    0x1d27 as i32 as u16,
-    0x1d29 as i32 as u16,
-    0x1d2b as i32 as u16,
-    0x1d2d as i32 as u16,
-    0x1d2f as i32 as u16,
-    0x1d31 as i32 as u16,
-    0x1d33 as i32 as u16,
-    0x1d37 as i32 as u16,
-    0x3f4 as i32 as u16,
-    0x1d39 as i32 as u16,
-    0x2207 as i32 as u16,
-    0x1d3b as i32 as u16,
-    0x2202 as i32 as u16,
-    0x1d3d as i32 as u16,
-    0x1d45 as i32 as u16,
-    0x3f4 as i32 as u16,
-    0x1d47 as i32 as u16,
-    0x2207 as i32 as u16,
-    0x1d49 as i32 as u16,
-    0x2202 as i32 as u16,
-    0x1d4b as i32 as u16,
-    0x1d53 as i32 as u16,
-    0x3f4 as i32 as u16,
-    0x1d55 as i32 as u16,
-    0x2207 as i32 as u16,
-    0x1d57 as i32 as u16,
-    0x2202 as i32 as u16,
-    0x1d59 as i32 as u16,
-    0x1d61 as i32 as u16,
-    0x3f4 as i32 as u16,
-    0x1d63 as i32 as u16,
-    0x2207 as i32 as u16,
-    0x1d65 as i32 as u16,
-    0x2202 as i32 as u16,
-    0x1d67 as i32 as u16,
-    0x1d6f as i32 as u16,
-    0x3f4 as i32 as u16,
-    0x1d71 as i32 as u16,
-    0x2207 as i32 as u16,
-    0x1d73 as i32 as u16,
-    0x2202 as i32 as u16,
-    0x1d75 as i32 as u16,
-    0x1d7f as i32 as u16,
-    0x1d81 as i32 as u16,
-    0x1d83 as i32 as u16,
-    0x1d85 as i32 as u16,
-    0x1d87 as i32 as u16,
-    0x1d89 as i32 as u16,
-    0x1d8f as i32 as u16,
-    0x1dac as i32 as u16,
-    0x62d as i32 as u16,
-    0x1db4 as i32 as u16,
-    0x1dc0 as i32 as u16,
-    0x62c as i32 as u16,
-    0x1dd0 as i32 as u16,
-    0x1e40 as i32 as u16,
-    0x1e4c as i32 as u16,
-    0x1e5f as i32 as u16,
-    0x1e71 as i32 as u16,
-    0x1e84 as i32 as u16,
-    0x1e86 as i32 as u16,
-    0x1e8a as i32 as u16,
-    0x1e90 as i32 as u16,
-    0x1e96 as i32 as u16,
-    0x1e98 as i32 as u16,
-    0x1e9c as i32 as u16,
-    0x1e9e as i32 as u16,
-    0x1ea6 as i32 as u16,
-    0x1ea9 as i32 as u16,
-    0x1eab as i32 as u16,
-    0x1eb1 as i32 as u16,
-    0x1eb3 as i32 as u16,
-    0x30b5 as i32 as u16,
-    0x1eb9 as i32 as u16,
-    0x1f11 as i32 as u16,
-    0x1f27 as i32 as u16,
-    0x1f2b as i32 as u16,
-    0x1f2d as i32 as u16,
-    0x1f32 as i32 as u16,
-    0x1f7f as i32 as u16,
-    0x1f90 as i32 as u16,
-    0x2091 as i32 as u16,
-    0x20a1 as i32 as u16,
-    0x20a7 as i32 as u16,
-    0x21a1 as i32 as u16,
-    0x22bf as i32 as u16,
-];
-static mut unicode_decomp_data: [u8; 9165] = [
-    0x20 as i32 as u8,
-    0x88 as i32 as u8,
-    0x20 as i32 as u8,
-    0x84 as i32 as u8,
-    0x32 as i32 as u8,
-    0x33 as i32 as u8,
-    0x20 as i32 as u8,
-    0x81 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x31 as i32 as u8,
-    0x6f as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x34 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x32 as i32 as u8,
-    0x33 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x34 as i32 as u8,
-    0x41 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x82 as i32 as u8,
-    0x41 as i32 as u8,
-    0x83 as i32 as u8,
-    0x41 as i32 as u8,
-    0x88 as i32 as u8,
-    0x41 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x43 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x81 as i32 as u8,
-    0x45 as i32 as u8,
-    0x82 as i32 as u8,
-    0x45 as i32 as u8,
-    0x88 as i32 as u8,
-    0x49 as i32 as u8,
-    0x80 as i32 as u8,
-    0x49 as i32 as u8,
-    0x81 as i32 as u8,
-    0x49 as i32 as u8,
-    0x82 as i32 as u8,
-    0x49 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x83 as i32 as u8,
-    0x4f as i32 as u8,
-    0x80 as i32 as u8,
-    0x4f as i32 as u8,
-    0x81 as i32 as u8,
-    0x4f as i32 as u8,
-    0x82 as i32 as u8,
-    0x4f as i32 as u8,
-    0x83 as i32 as u8,
-    0x4f as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x55 as i32 as u8,
-    0x80 as i32 as u8,
-    0x55 as i32 as u8,
-    0x81 as i32 as u8,
-    0x55 as i32 as u8,
-    0x82 as i32 as u8,
-    0x55 as i32 as u8,
-    0x88 as i32 as u8,
-    0x59 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0x81 as i32 as u8,
-    0x61 as i32 as u8,
-    0x82 as i32 as u8,
-    0x61 as i32 as u8,
-    0x83 as i32 as u8,
-    0x61 as i32 as u8,
-    0x88 as i32 as u8,
-    0x61 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x63 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x65 as i32 as u8,
-    0x80 as i32 as u8,
-    0x65 as i32 as u8,
-    0x81 as i32 as u8,
-    0x65 as i32 as u8,
-    0x82 as i32 as u8,
-    0x65 as i32 as u8,
-    0x88 as i32 as u8,
-    0x69 as i32 as u8,
-    0x80 as i32 as u8,
-    0x69 as i32 as u8,
-    0x81 as i32 as u8,
-    0x69 as i32 as u8,
-    0x82 as i32 as u8,
-    0x69 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x6e as i32 as u8,
-    0x83 as i32 as u8,
-    0x6f as i32 as u8,
-    0x80 as i32 as u8,
-    0x6f as i32 as u8,
-    0x81 as i32 as u8,
-    0x6f as i32 as u8,
-    0x82 as i32 as u8,
-    0x6f as i32 as u8,
-    0x83 as i32 as u8,
-    0x6f as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x75 as i32 as u8,
-    0x80 as i32 as u8,
-    0x75 as i32 as u8,
-    0x81 as i32 as u8,
-    0x75 as i32 as u8,
-    0x82 as i32 as u8,
-    0x75 as i32 as u8,
-    0x88 as i32 as u8,
-    0x79 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x79 as i32 as u8,
-    0x88 as i32 as u8,
-    0x41 as i32 as u8,
-    0x84 as i32 as u8,
-    0x41 as i32 as u8,
-    0x86 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x43 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x82 as i32 as u8,
-    0x43 as i32 as u8,
-    0x87 as i32 as u8,
-    0x43 as i32 as u8,
-    0x8c as i32 as u8,
-    0x44 as i32 as u8,
-    0x8c as i32 as u8,
-    0x45 as i32 as u8,
-    0x84 as i32 as u8,
-    0x45 as i32 as u8,
-    0x86 as i32 as u8,
-    0x45 as i32 as u8,
-    0x87 as i32 as u8,
-    0x45 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x45 as i32 as u8,
-    0x8c as i32 as u8,
-    0x47 as i32 as u8,
-    0x82 as i32 as u8,
-    0x47 as i32 as u8,
-    0x86 as i32 as u8,
-    0x47 as i32 as u8,
-    0x87 as i32 as u8,
-    0x47 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x48 as i32 as u8,
-    0x82 as i32 as u8,
-    0x49 as i32 as u8,
-    0x83 as i32 as u8,
-    0x49 as i32 as u8,
-    0x84 as i32 as u8,
-    0x49 as i32 as u8,
-    0x86 as i32 as u8,
-    0x49 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x49 as i32 as u8,
-    0x87 as i32 as u8,
-    0x49 as i32 as u8,
-    0x4a as i32 as u8,
-    0x69 as i32 as u8,
-    0x6a as i32 as u8,
-    0x4a as i32 as u8,
-    0x82 as i32 as u8,
-    0x4b as i32 as u8,
-    0xa7 as i32 as u8,
-    0x4c as i32 as u8,
-    0x81 as i32 as u8,
-    0x4c as i32 as u8,
-    0xa7 as i32 as u8,
-    0x4c as i32 as u8,
-    0x8c as i32 as u8,
-    0x4c as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x6b as i32 as u8,
-    0x20 as i32 as u8,
-    0x6b as i32 as u8,
-    0x4e as i32 as u8,
-    0x81 as i32 as u8,
-    0x4e as i32 as u8,
-    0xa7 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8c as i32 as u8,
-    0xbc as i32 as u8,
-    0x2 as i32 as u8,
-    0x6e as i32 as u8,
-    0x4f as i32 as u8,
-    0x84 as i32 as u8,
-    0x4f as i32 as u8,
-    0x86 as i32 as u8,
-    0x4f as i32 as u8,
-    0x8b as i32 as u8,
-    0x52 as i32 as u8,
-    0x81 as i32 as u8,
-    0x52 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x52 as i32 as u8,
-    0x8c as i32 as u8,
-    0x53 as i32 as u8,
-    0x81 as i32 as u8,
-    0x53 as i32 as u8,
-    0x82 as i32 as u8,
-    0x53 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x53 as i32 as u8,
-    0x8c as i32 as u8,
-    0x54 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x54 as i32 as u8,
-    0x8c as i32 as u8,
-    0x55 as i32 as u8,
-    0x83 as i32 as u8,
-    0x55 as i32 as u8,
-    0x84 as i32 as u8,
-    0x55 as i32 as u8,
-    0x86 as i32 as u8,
-    0x55 as i32 as u8,
-    0x8a as i32 as u8,
-    0x55 as i32 as u8,
-    0x8b as i32 as u8,
-    0x55 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x57 as i32 as u8,
-    0x82 as i32 as u8,
-    0x59 as i32 as u8,
-    0x82 as i32 as u8,
-    0x59 as i32 as u8,
-    0x88 as i32 as u8,
-    0x5a as i32 as u8,
-    0x81 as i32 as u8,
-    0x5a as i32 as u8,
-    0x87 as i32 as u8,
-    0x5a as i32 as u8,
-    0x8c as i32 as u8,
-    0x4f as i32 as u8,
-    0x9b as i32 as u8,
-    0x55 as i32 as u8,
-    0x9b as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x7d as i32 as u8,
-    0x1 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x7e as i32 as u8,
-    0x1 as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x7e as i32 as u8,
-    0x1 as i32 as u8,
-    0x4c as i32 as u8,
-    0x4a as i32 as u8,
-    0x4c as i32 as u8,
-    0x6a as i32 as u8,
-    0x6c as i32 as u8,
-    0x6a as i32 as u8,
-    0x4e as i32 as u8,
-    0x4a as i32 as u8,
-    0x4e as i32 as u8,
-    0x6a as i32 as u8,
-    0x6e as i32 as u8,
-    0x6a as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0xdc as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc4 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x26 as i32 as u8,
-    0x2 as i32 as u8,
-    0x84 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x47 as i32 as u8,
-    0x8c as i32 as u8,
-    0x4b as i32 as u8,
-    0x8c as i32 as u8,
-    0x4f as i32 as u8,
-    0xa8 as i32 as u8,
-    0xea as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0xeb as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8c as i32 as u8,
-    0x92 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8c as i32 as u8,
-    0x6a as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x44 as i32 as u8,
-    0x5a as i32 as u8,
-    0x44 as i32 as u8,
-    0x7a as i32 as u8,
-    0x64 as i32 as u8,
-    0x7a as i32 as u8,
-    0x47 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4e as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd8 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x8f as i32 as u8,
-    0x41 as i32 as u8,
-    0x91 as i32 as u8,
-    0x45 as i32 as u8,
-    0x8f as i32 as u8,
-    0x45 as i32 as u8,
-    0x91 as i32 as u8,
-    0x49 as i32 as u8,
-    0x8f as i32 as u8,
-    0x49 as i32 as u8,
-    0x91 as i32 as u8,
-    0x4f as i32 as u8,
-    0x8f as i32 as u8,
-    0x4f as i32 as u8,
-    0x91 as i32 as u8,
-    0x52 as i32 as u8,
-    0x8f as i32 as u8,
-    0x52 as i32 as u8,
-    0x91 as i32 as u8,
-    0x55 as i32 as u8,
-    0x8f as i32 as u8,
-    0x55 as i32 as u8,
-    0x91 as i32 as u8,
-    0x53 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x54 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x48 as i32 as u8,
-    0x8c as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xa7 as i32 as u8,
-    0xd6 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0xd5 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x2e as i32 as u8,
-    0x2 as i32 as u8,
-    0x84 as i32 as u8,
-    0x59 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x68 as i32 as u8,
-    0 as i32 as u8,
-    0x66 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6a as i32 as u8,
-    0 as i32 as u8,
-    0x72 as i32 as u8,
-    0 as i32 as u8,
-    0x79 as i32 as u8,
-    0x2 as i32 as u8,
-    0x7b as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2 as i32 as u8,
-    0x77 as i32 as u8,
-    0 as i32 as u8,
-    0x79 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x86 as i32 as u8,
-    0x20 as i32 as u8,
-    0x87 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8a as i32 as u8,
-    0x20 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x20 as i32 as u8,
-    0x83 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8b as i32 as u8,
-    0x63 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6c as i32 as u8,
-    0 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0x78 as i32 as u8,
-    0 as i32 as u8,
-    0x95 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x93 as i32 as u8,
-    0x88 as i32 as u8,
-    0x81 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa8 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x95 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x97 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x99 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9f as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xca as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x98 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb0 as i32 as u8,
-    0 as i32 as u8,
-    0xb4 as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0xca as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x7 as i32 as u8,
-    0xbe as i32 as u8,
-    0 as i32 as u8,
-    0xc4 as i32 as u8,
-    0 as i32 as u8,
-    0xc8 as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd as i32 as u8,
-    0x13 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd1 as i32 as u8,
-    0 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xba as i32 as u8,
-    0x3 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x98 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x15 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x15 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1a as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x18 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x18 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x38 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x35 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x35 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x33 as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x56 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x3a as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x38 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x74 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8f as i32 as u8,
-    0x16 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x10 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x10 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x15 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x16 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x17 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x18 as i32 as u8,
-    0x4 as i32 as u8,
-    0x84 as i32 as u8,
-    0x18 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2d as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4 as i32 as u8,
-    0x84 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8b as i32 as u8,
-    0x27 as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2b as i32 as u8,
-    0x4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x65 as i32 as u8,
-    0x5 as i32 as u8,
-    0x82 as i32 as u8,
-    0x5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x2d as i32 as u8,
-    0x21 as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0x2e as i32 as u8,
-    0x23 as i32 as u8,
-    0x2d as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x4d as i32 as u8,
-    0x21 as i32 as u8,
-    0x4d as i32 as u8,
-    0xa0 as i32 as u8,
-    0x4d as i32 as u8,
-    0x23 as i32 as u8,
-    0x4d as i32 as u8,
-    0xd5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x54 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x6 as i32 as u8,
-    0x54 as i32 as u8,
-    0x6 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x54 as i32 as u8,
-    0x6 as i32 as u8,
-    0x28 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3c as i32 as u8,
-    0x9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3c as i32 as u8,
-    0x9 as i32 as u8,
-    0x33 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3c as i32 as u8,
-    0x9 as i32 as u8,
-    0x15 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x1 as i32 as u8,
-    0x27 as i32 as u8,
-    0x2 as i32 as u8,
-    0x27 as i32 as u8,
-    0x7 as i32 as u8,
-    0x27 as i32 as u8,
-    0xc as i32 as u8,
-    0x27 as i32 as u8,
-    0xd as i32 as u8,
-    0x27 as i32 as u8,
-    0x16 as i32 as u8,
-    0x27 as i32 as u8,
-    0x1a as i32 as u8,
-    0x27 as i32 as u8,
-    0xbe as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x19 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x9 as i32 as u8,
-    0xbc as i32 as u8,
-    0x9 as i32 as u8,
-    0xaf as i32 as u8,
-    0x9 as i32 as u8,
-    0xbc as i32 as u8,
-    0x9 as i32 as u8,
-    0x32 as i32 as u8,
-    0xa as i32 as u8,
-    0x3c as i32 as u8,
-    0xa as i32 as u8,
-    0x38 as i32 as u8,
-    0xa as i32 as u8,
-    0x3c as i32 as u8,
-    0xa as i32 as u8,
-    0x16 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x26 as i32 as u8,
-    0x6 as i32 as u8,
-    0x26 as i32 as u8,
-    0x2b as i32 as u8,
-    0xa as i32 as u8,
-    0x3c as i32 as u8,
-    0xa as i32 as u8,
-    0x47 as i32 as u8,
-    0xb as i32 as u8,
-    0x56 as i32 as u8,
-    0xb as i32 as u8,
-    0x3e as i32 as u8,
-    0xb as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x19 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb as i32 as u8,
-    0x3c as i32 as u8,
-    0xb as i32 as u8,
-    0x92 as i32 as u8,
-    0xb as i32 as u8,
-    0xd7 as i32 as u8,
-    0xb as i32 as u8,
-    0xbe as i32 as u8,
-    0xb as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x19 as i32 as u8,
-    0x46 as i32 as u8,
-    0xc as i32 as u8,
-    0x56 as i32 as u8,
-    0xc as i32 as u8,
-    0xbf as i32 as u8,
-    0xc as i32 as u8,
-    0xd5 as i32 as u8,
-    0xc as i32 as u8,
-    0xc6 as i32 as u8,
-    0xc as i32 as u8,
-    0xd5 as i32 as u8,
-    0xc as i32 as u8,
-    0xc2 as i32 as u8,
-    0xc as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3e as i32 as u8,
-    0xd as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x19 as i32 as u8,
-    0xd9 as i32 as u8,
-    0xd as i32 as u8,
-    0xca as i32 as u8,
-    0xd as i32 as u8,
-    0xca as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0x5 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0x15 as i32 as u8,
-    0x4d as i32 as u8,
-    0xe as i32 as u8,
-    0x32 as i32 as u8,
-    0xe as i32 as u8,
-    0xcd as i32 as u8,
-    0xe as i32 as u8,
-    0xb2 as i32 as u8,
-    0xe as i32 as u8,
-    0x99 as i32 as u8,
-    0xe as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x8 as i32 as u8,
-    0x42 as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x4c as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x51 as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x56 as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x5b as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x40 as i32 as u8,
-    0xf as i32 as u8,
-    0xb5 as i32 as u8,
-    0xf as i32 as u8,
-    0x71 as i32 as u8,
-    0xf as i32 as u8,
-    0x72 as i32 as u8,
-    0xf as i32 as u8,
-    0x71 as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf as i32 as u8,
-    0xb2 as i32 as u8,
-    0xf as i32 as u8,
-    0x81 as i32 as u8,
-    0xf as i32 as u8,
-    0xb3 as i32 as u8,
-    0xf as i32 as u8,
-    0x80 as i32 as u8,
-    0xf as i32 as u8,
-    0xb3 as i32 as u8,
-    0xf as i32 as u8,
-    0x81 as i32 as u8,
-    0xf as i32 as u8,
-    0x71 as i32 as u8,
-    0xf as i32 as u8,
-    0x80 as i32 as u8,
-    0xf as i32 as u8,
-    0x92 as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x9c as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0xa1 as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0xa6 as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0xab as i32 as u8,
-    0xf as i32 as u8,
-    0xb7 as i32 as u8,
-    0xf as i32 as u8,
-    0x90 as i32 as u8,
-    0xf as i32 as u8,
-    0xb5 as i32 as u8,
-    0xf as i32 as u8,
-    0x25 as i32 as u8,
-    0x10 as i32 as u8,
-    0x2e as i32 as u8,
-    0x10 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xd as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0x11 as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3a as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3c as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3e as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0x42 as i32 as u8,
-    0x1b as i32 as u8,
-    0x35 as i32 as u8,
-    0x1b as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x1 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x50 as i32 as u8,
-    0 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0x54 as i32 as u8,
-    0 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x50 as i32 as u8,
-    0x2 as i32 as u8,
-    0x51 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x62 as i32 as u8,
-    0 as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5b as i32 as u8,
-    0x2 as i32 as u8,
-    0x5c as i32 as u8,
-    0x2 as i32 as u8,
-    0x67 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x4b as i32 as u8,
-    0x1 as i32 as u8,
-    0x6f as i32 as u8,
-    0 as i32 as u8,
-    0x54 as i32 as u8,
-    0x2 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1d as i32 as u8,
-    0x17 as i32 as u8,
-    0x1d as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x74 as i32 as u8,
-    0 as i32 as u8,
-    0x75 as i32 as u8,
-    0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x6f as i32 as u8,
-    0x2 as i32 as u8,
-    0x76 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x1d as i32 as u8,
-    0xb2 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x3 as i32 as u8,
-    0x69 as i32 as u8,
-    0 as i32 as u8,
-    0x72 as i32 as u8,
-    0 as i32 as u8,
-    0x75 as i32 as u8,
-    0 as i32 as u8,
-    0x76 as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x3 as i32 as u8,
-    0x52 as i32 as u8,
-    0x2 as i32 as u8,
-    0x63 as i32 as u8,
-    0 as i32 as u8,
-    0x55 as i32 as u8,
-    0x2 as i32 as u8,
-    0xf0 as i32 as u8,
-    0 as i32 as u8,
-    0x5c as i32 as u8,
-    0x2 as i32 as u8,
-    0x66 as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0x2 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2 as i32 as u8,
-    0x65 as i32 as u8,
-    0x2 as i32 as u8,
-    0x68 as i32 as u8,
-    0x2 as i32 as u8,
-    0x69 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6a as i32 as u8,
-    0x2 as i32 as u8,
-    0x7b as i32 as u8,
-    0x1d as i32 as u8,
-    0x9d as i32 as u8,
-    0x2 as i32 as u8,
-    0x6d as i32 as u8,
-    0x2 as i32 as u8,
-    0x85 as i32 as u8,
-    0x1d as i32 as u8,
-    0x9f as i32 as u8,
-    0x2 as i32 as u8,
-    0x71 as i32 as u8,
-    0x2 as i32 as u8,
-    0x70 as i32 as u8,
-    0x2 as i32 as u8,
-    0x72 as i32 as u8,
-    0x2 as i32 as u8,
-    0x73 as i32 as u8,
-    0x2 as i32 as u8,
-    0x74 as i32 as u8,
-    0x2 as i32 as u8,
-    0x75 as i32 as u8,
-    0x2 as i32 as u8,
-    0x78 as i32 as u8,
-    0x2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2 as i32 as u8,
-    0x83 as i32 as u8,
-    0x2 as i32 as u8,
-    0xab as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8a as i32 as u8,
-    0x2 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1d as i32 as u8,
-    0x8b as i32 as u8,
-    0x2 as i32 as u8,
-    0x8c as i32 as u8,
-    0x2 as i32 as u8,
-    0x7a as i32 as u8,
-    0 as i32 as u8,
-    0x90 as i32 as u8,
-    0x2 as i32 as u8,
-    0x91 as i32 as u8,
-    0x2 as i32 as u8,
-    0x92 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0xc7 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0xad as i32 as u8,
-    0x12 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x12 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xad as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x2 as i32 as u8,
-    0x86 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0xae as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xcf as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x4c as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x36 as i32 as u8,
-    0x1e as i32 as u8,
-    0x84 as i32 as u8,
-    0x4c as i32 as u8,
-    0xb1 as i32 as u8,
-    0x4c as i32 as u8,
-    0xad as i32 as u8,
-    0x4d as i32 as u8,
-    0x81 as i32 as u8,
-    0x4d as i32 as u8,
-    0x87 as i32 as u8,
-    0x4d as i32 as u8,
-    0xa3 as i32 as u8,
-    0x4e as i32 as u8,
-    0x87 as i32 as u8,
-    0x4e as i32 as u8,
-    0xa3 as i32 as u8,
-    0x4e as i32 as u8,
-    0xb1 as i32 as u8,
-    0x4e as i32 as u8,
-    0xad as i32 as u8,
-    0xd5 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd5 as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x4c as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4c as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x50 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x50 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x5a as i32 as u8,
-    0x1e as i32 as u8,
-    0x84 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x5a as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x62 as i32 as u8,
-    0x1e as i32 as u8,
-    0x87 as i32 as u8,
-    0x54 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x54 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x54 as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x54 as i32 as u8,
-    0 as i32 as u8,
-    0xad as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0xad as i32 as u8,
-    0x68 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x6a as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x56 as i32 as u8,
-    0x83 as i32 as u8,
-    0x56 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x57 as i32 as u8,
-    0x80 as i32 as u8,
-    0x57 as i32 as u8,
-    0x81 as i32 as u8,
-    0x57 as i32 as u8,
-    0x88 as i32 as u8,
-    0x57 as i32 as u8,
-    0x87 as i32 as u8,
-    0x57 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x58 as i32 as u8,
-    0x87 as i32 as u8,
-    0x58 as i32 as u8,
-    0x88 as i32 as u8,
-    0x59 as i32 as u8,
-    0x87 as i32 as u8,
-    0x5a as i32 as u8,
-    0x82 as i32 as u8,
-    0x5a as i32 as u8,
-    0xa3 as i32 as u8,
-    0x5a as i32 as u8,
-    0xb1 as i32 as u8,
-    0x68 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x74 as i32 as u8,
-    0x88 as i32 as u8,
-    0x77 as i32 as u8,
-    0x8a as i32 as u8,
-    0x79 as i32 as u8,
-    0x8a as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0xbe as i32 as u8,
-    0x2 as i32 as u8,
-    0x7f as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1e as i32 as u8,
-    0x82 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1e as i32 as u8,
-    0x86 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xca as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xca as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xca as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0xca as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x1e as i32 as u8,
-    0x82 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0xd4 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd4 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0xd4 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xcc as i32 as u8,
-    0x1e as i32 as u8,
-    0x82 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x59 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x59 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x59 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x59 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x95 as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-    0x18 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x18 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x97 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0x97 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x28 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x29 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x28 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x29 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x28 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x29 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x31 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x31 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x31 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x99 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0x99 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x38 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x39 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x38 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x39 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x38 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x39 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xbf as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0xbf as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x40 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x9f as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-    0x48 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x48 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3 as i32 as u8,
-    0x50 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x50 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x50 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x61 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x61 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0x68 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x69 as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x68 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x69 as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x68 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0x69 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xbf as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x45 as i32 as u8,
-    0x3 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x45 as i32 as u8,
-    0x3 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1f as i32 as u8,
-    0x45 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x86 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc5 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xac as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc5 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0x86 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x93 as i32 as u8,
-    0x20 as i32 as u8,
-    0x93 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xa8 as i32 as u8,
-    0 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x74 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc5 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xae as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc5 as i32 as u8,
-    0x95 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xbf as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0xbf as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0xbf as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x86 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0xca as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x42 as i32 as u8,
-    0xca as i32 as u8,
-    0x42 as i32 as u8,
-    0x99 as i32 as u8,
-    0x6 as i32 as u8,
-    0x99 as i32 as u8,
-    0x4 as i32 as u8,
-    0x99 as i32 as u8,
-    0 as i32 as u8,
-    0xfe as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0xfe as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0xfe as i32 as u8,
-    0x1f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x86 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0xcb as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x13 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x14 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x42 as i32 as u8,
-    0xcb as i32 as u8,
-    0x42 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x6 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x4 as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x94 as i32 as u8,
-    0xa8 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x85 as i32 as u8,
-    0x3 as i32 as u8,
-    0x60 as i32 as u8,
-    0 as i32 as u8,
-    0x7c as i32 as u8,
-    0x1f as i32 as u8,
-    0xc5 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xce as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc5 as i32 as u8,
-    0x9f as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x94 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x2e as i32 as u8,
-    0x2e as i32 as u8,
-    0x2e as i32 as u8,
-    0x2e as i32 as u8,
-    0x2e as i32 as u8,
-    0x32 as i32 as u8,
-    0x20 as i32 as u8,
-    0x32 as i32 as u8,
-    0x20 as i32 as u8,
-    0x32 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x20 as i32 as u8,
-    0x35 as i32 as u8,
-    0x20 as i32 as u8,
-    0x35 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x85 as i32 as u8,
-    0x3f as i32 as u8,
-    0x3f as i32 as u8,
-    0x3f as i32 as u8,
-    0x21 as i32 as u8,
-    0x21 as i32 as u8,
-    0x3f as i32 as u8,
-    0x32 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0x69 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x34 as i32 as u8,
-    0x35 as i32 as u8,
-    0x36 as i32 as u8,
-    0x37 as i32 as u8,
-    0x38 as i32 as u8,
-    0x39 as i32 as u8,
-    0x2b as i32 as u8,
-    0x3d as i32 as u8,
-    0x28 as i32 as u8,
-    0x29 as i32 as u8,
-    0x6e as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x22 as i32 as u8,
-    0x3d as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x6f as i32 as u8,
-    0 as i32 as u8,
-    0x78 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x2 as i32 as u8,
-    0x68 as i32 as u8,
-    0x6b as i32 as u8,
-    0x6c as i32 as u8,
-    0x6d as i32 as u8,
-    0x6e as i32 as u8,
-    0x70 as i32 as u8,
-    0x73 as i32 as u8,
-    0x74 as i32 as u8,
-    0x52 as i32 as u8,
-    0x73 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2f as i32 as u8,
-    0x63 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2f as i32 as u8,
-    0x73 as i32 as u8,
-    0xb0 as i32 as u8,
-    0 as i32 as u8,
-    0x43 as i32 as u8,
-    0x63 as i32 as u8,
-    0x2f as i32 as u8,
-    0x6f as i32 as u8,
-    0x63 as i32 as u8,
-    0x2f as i32 as u8,
-    0x75 as i32 as u8,
-    0xb0 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0xdf as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x24 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6f as i32 as u8,
-    0x50 as i32 as u8,
-    0x51 as i32 as u8,
-    0x52 as i32 as u8,
-    0x52 as i32 as u8,
-    0x52 as i32 as u8,
-    0x53 as i32 as u8,
-    0x4d as i32 as u8,
-    0x54 as i32 as u8,
-    0x45 as i32 as u8,
-    0x4c as i32 as u8,
-    0x54 as i32 as u8,
-    0x4d as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x42 as i32 as u8,
-    0x43 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x45 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0x4d as i32 as u8,
-    0x6f as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x46 as i32 as u8,
-    0x41 as i32 as u8,
-    0x58 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x11 as i32 as u8,
-    0x22 as i32 as u8,
-    0x44 as i32 as u8,
-    0x64 as i32 as u8,
-    0x65 as i32 as u8,
-    0x69 as i32 as u8,
-    0x6a as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x37 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x39 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x31 as i32 as u8,
-    0x30 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x33 as i32 as u8,
-    0x32 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x33 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x32 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x33 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x34 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x36 as i32 as u8,
-    0x35 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x36 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x33 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x35 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x37 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x31 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x56 as i32 as u8,
-    0x56 as i32 as u8,
-    0x49 as i32 as u8,
-    0x56 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x56 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x58 as i32 as u8,
-    0x58 as i32 as u8,
-    0x49 as i32 as u8,
-    0x58 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x4c as i32 as u8,
-    0x43 as i32 as u8,
-    0x44 as i32 as u8,
-    0x4d as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x76 as i32 as u8,
-    0x76 as i32 as u8,
-    0x69 as i32 as u8,
-    0x76 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x76 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x78 as i32 as u8,
-    0x78 as i32 as u8,
-    0x69 as i32 as u8,
-    0x78 as i32 as u8,
-    0x69 as i32 as u8,
-    0x69 as i32 as u8,
-    0x6c as i32 as u8,
-    0x63 as i32 as u8,
-    0x64 as i32 as u8,
-    0x6d as i32 as u8,
-    0x30 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x33 as i32 as u8,
-    0x90 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x92 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x94 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xb as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x23 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x2b as i32 as u8,
-    0x22 as i32 as u8,
-    0x2b as i32 as u8,
-    0x22 as i32 as u8,
-    0x2b as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2e as i32 as u8,
-    0x22 as i32 as u8,
-    0x2e as i32 as u8,
-    0x22 as i32 as u8,
-    0x2e as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3c as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x43 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x45 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x48 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3d as i32 as u8,
-    0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x4d as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3e as i32 as u8,
-    0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x64 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x65 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x72 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x76 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x7a as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x86 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xab as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x7c as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x91 as i32 as u8,
-    0x22 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x22 as i32 as u8,
-    0x38 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x32 as i32 as u8,
-    0x30 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x32 as i32 as u8,
-    0x30 as i32 as u8,
-    0x29 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x32 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2e as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x2b as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3a as i32 as u8,
-    0x3a as i32 as u8,
-    0x3d as i32 as u8,
-    0x3d as i32 as u8,
-    0x3d as i32 as u8,
-    0x3d as i32 as u8,
-    0x3d as i32 as u8,
-    0x3d as i32 as u8,
-    0xdd as i32 as u8,
-    0x2a as i32 as u8,
-    0xb8 as i32 as u8,
-    0x6a as i32 as u8,
-    0x56 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x36 as i32 as u8,
-    0x3f as i32 as u8,
-    0x59 as i32 as u8,
-    0x85 as i32 as u8,
-    0x8c as i32 as u8,
-    0xa0 as i32 as u8,
-    0xba as i32 as u8,
-    0x3f as i32 as u8,
-    0x51 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x2c as i32 as u8,
-    0x43 as i32 as u8,
-    0x57 as i32 as u8,
-    0x6c as i32 as u8,
-    0xa1 as i32 as u8,
-    0xb6 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x9b as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0x5e as i32 as u8,
-    0x7a as i32 as u8,
-    0x7f as i32 as u8,
-    0x9d as i32 as u8,
-    0xa6 as i32 as u8,
-    0xc1 as i32 as u8,
-    0xce as i32 as u8,
-    0xe7 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x53 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x53 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x53 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x56 as i32 as u8,
-    0x1f as i32 as u8,
-    0x57 as i32 as u8,
-    0xeb as i32 as u8,
-    0x58 as i32 as u8,
-    0x2 as i32 as u8,
-    0x59 as i32 as u8,
-    0xa as i32 as u8,
-    0x59 as i32 as u8,
-    0x15 as i32 as u8,
-    0x59 as i32 as u8,
-    0x27 as i32 as u8,
-    0x59 as i32 as u8,
-    0x73 as i32 as u8,
-    0x59 as i32 as u8,
-    0x50 as i32 as u8,
-    0x5b as i32 as u8,
-    0x80 as i32 as u8,
-    0x5b as i32 as u8,
-    0xf8 as i32 as u8,
-    0x5b as i32 as u8,
-    0xf as i32 as u8,
-    0x5c as i32 as u8,
-    0x22 as i32 as u8,
-    0x5c as i32 as u8,
-    0x38 as i32 as u8,
-    0x5c as i32 as u8,
-    0x6e as i32 as u8,
-    0x5c as i32 as u8,
-    0x71 as i32 as u8,
-    0x5c as i32 as u8,
-    0xdb as i32 as u8,
-    0x5d as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5d as i32 as u8,
-    0xf1 as i32 as u8,
-    0x5d as i32 as u8,
-    0xfe as i32 as u8,
-    0x5d as i32 as u8,
-    0x72 as i32 as u8,
-    0x5e as i32 as u8,
-    0x7a as i32 as u8,
-    0x5e as i32 as u8,
-    0x7f as i32 as u8,
-    0x5e as i32 as u8,
-    0xf4 as i32 as u8,
-    0x5e as i32 as u8,
-    0xfe as i32 as u8,
-    0x5e as i32 as u8,
-    0xb as i32 as u8,
-    0x5f as i32 as u8,
-    0x13 as i32 as u8,
-    0x5f as i32 as u8,
-    0x50 as i32 as u8,
-    0x5f as i32 as u8,
-    0x61 as i32 as u8,
-    0x5f as i32 as u8,
-    0x73 as i32 as u8,
-    0x5f as i32 as u8,
-    0xc3 as i32 as u8,
-    0x5f as i32 as u8,
-    0x8 as i32 as u8,
-    0x62 as i32 as u8,
-    0x36 as i32 as u8,
-    0x62 as i32 as u8,
-    0x4b as i32 as u8,
-    0x62 as i32 as u8,
-    0x2f as i32 as u8,
-    0x65 as i32 as u8,
-    0x34 as i32 as u8,
-    0x65 as i32 as u8,
-    0x87 as i32 as u8,
-    0x65 as i32 as u8,
-    0x97 as i32 as u8,
-    0x65 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x65 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x65 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x65 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x66 as i32 as u8,
-    0x8 as i32 as u8,
-    0x67 as i32 as u8,
-    0x28 as i32 as u8,
-    0x67 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6b as i32 as u8,
-    0x62 as i32 as u8,
-    0x6b as i32 as u8,
-    0x79 as i32 as u8,
-    0x6b as i32 as u8,
-    0xb3 as i32 as u8,
-    0x6b as i32 as u8,
-    0xcb as i32 as u8,
-    0x6b as i32 as u8,
-    0xd4 as i32 as u8,
-    0x6b as i32 as u8,
-    0xdb as i32 as u8,
-    0x6b as i32 as u8,
-    0xf as i32 as u8,
-    0x6c as i32 as u8,
-    0x14 as i32 as u8,
-    0x6c as i32 as u8,
-    0x34 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6b as i32 as u8,
-    0x70 as i32 as u8,
-    0x2a as i32 as u8,
-    0x72 as i32 as u8,
-    0x36 as i32 as u8,
-    0x72 as i32 as u8,
-    0x3b as i32 as u8,
-    0x72 as i32 as u8,
-    0x3f as i32 as u8,
-    0x72 as i32 as u8,
-    0x47 as i32 as u8,
-    0x72 as i32 as u8,
-    0x59 as i32 as u8,
-    0x72 as i32 as u8,
-    0x5b as i32 as u8,
-    0x72 as i32 as u8,
-    0xac as i32 as u8,
-    0x72 as i32 as u8,
-    0x84 as i32 as u8,
-    0x73 as i32 as u8,
-    0x89 as i32 as u8,
-    0x73 as i32 as u8,
-    0xdc as i32 as u8,
-    0x74 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x74 as i32 as u8,
-    0x18 as i32 as u8,
-    0x75 as i32 as u8,
-    0x1f as i32 as u8,
-    0x75 as i32 as u8,
-    0x28 as i32 as u8,
-    0x75 as i32 as u8,
-    0x30 as i32 as u8,
-    0x75 as i32 as u8,
-    0x8b as i32 as u8,
-    0x75 as i32 as u8,
-    0x92 as i32 as u8,
-    0x75 as i32 as u8,
-    0x76 as i32 as u8,
-    0x76 as i32 as u8,
-    0x7d as i32 as u8,
-    0x76 as i32 as u8,
-    0xae as i32 as u8,
-    0x76 as i32 as u8,
-    0xbf as i32 as u8,
-    0x76 as i32 as u8,
-    0xee as i32 as u8,
-    0x76 as i32 as u8,
-    0xdb as i32 as u8,
-    0x77 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x77 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x77 as i32 as u8,
-    0x3a as i32 as u8,
-    0x79 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x79 as i32 as u8,
-    0xbe as i32 as u8,
-    0x79 as i32 as u8,
-    0x74 as i32 as u8,
-    0x7a as i32 as u8,
-    0xcb as i32 as u8,
-    0x7a as i32 as u8,
-    0xf9 as i32 as u8,
-    0x7a as i32 as u8,
-    0x73 as i32 as u8,
-    0x7c as i32 as u8,
-    0xf8 as i32 as u8,
-    0x7c as i32 as u8,
-    0x36 as i32 as u8,
-    0x7f as i32 as u8,
-    0x51 as i32 as u8,
-    0x7f as i32 as u8,
-    0x8a as i32 as u8,
-    0x7f as i32 as u8,
-    0xbd as i32 as u8,
-    0x7f as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc as i32 as u8,
-    0x80 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x33 as i32 as u8,
-    0x80 as i32 as u8,
-    0x7f as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x10 as i32 as u8,
-    0x19 as i32 as u8,
-    0x29 as i32 as u8,
-    0x38 as i32 as u8,
-    0x3c as i32 as u8,
-    0x8b as i32 as u8,
-    0x8f as i32 as u8,
-    0x95 as i32 as u8,
-    0x4d as i32 as u8,
-    0x86 as i32 as u8,
-    0x6b as i32 as u8,
-    0x86 as i32 as u8,
-    0x40 as i32 as u8,
-    0x88 as i32 as u8,
-    0x4c as i32 as u8,
-    0x88 as i32 as u8,
-    0x63 as i32 as u8,
-    0x88 as i32 as u8,
-    0x7e as i32 as u8,
-    0x89 as i32 as u8,
-    0x8b as i32 as u8,
-    0x89 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x8a as i32 as u8,
-    0x37 as i32 as u8,
-    0x8c as i32 as u8,
-    0x46 as i32 as u8,
-    0x8c as i32 as u8,
-    0x55 as i32 as u8,
-    0x8c as i32 as u8,
-    0x78 as i32 as u8,
-    0x8c as i32 as u8,
-    0x9d as i32 as u8,
-    0x8c as i32 as u8,
-    0x64 as i32 as u8,
-    0x8d as i32 as u8,
-    0x70 as i32 as u8,
-    0x8d as i32 as u8,
-    0xb3 as i32 as u8,
-    0x8d as i32 as u8,
-    0xab as i32 as u8,
-    0x8e as i32 as u8,
-    0xca as i32 as u8,
-    0x8e as i32 as u8,
-    0x9b as i32 as u8,
-    0x8f as i32 as u8,
-    0xb0 as i32 as u8,
-    0x8f as i32 as u8,
-    0xb5 as i32 as u8,
-    0x8f as i32 as u8,
-    0x91 as i32 as u8,
-    0x90 as i32 as u8,
-    0x49 as i32 as u8,
-    0x91 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x91 as i32 as u8,
-    0xcc as i32 as u8,
-    0x91 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x91 as i32 as u8,
-    0x77 as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x1c as i32 as u8,
-    0x96 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x96 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x96 as i32 as u8,
-    0x51 as i32 as u8,
-    0x97 as i32 as u8,
-    0x5e as i32 as u8,
-    0x97 as i32 as u8,
-    0x62 as i32 as u8,
-    0x97 as i32 as u8,
-    0x69 as i32 as u8,
-    0x97 as i32 as u8,
-    0xcb as i32 as u8,
-    0x97 as i32 as u8,
-    0xed as i32 as u8,
-    0x97 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x97 as i32 as u8,
-    0x1 as i32 as u8,
-    0x98 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x98 as i32 as u8,
-    0xdb as i32 as u8,
-    0x98 as i32 as u8,
-    0xdf as i32 as u8,
-    0x98 as i32 as u8,
-    0x96 as i32 as u8,
-    0x99 as i32 as u8,
-    0x99 as i32 as u8,
-    0x99 as i32 as u8,
-    0xac as i32 as u8,
-    0x99 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x9a as i32 as u8,
-    0xd8 as i32 as u8,
-    0x9a as i32 as u8,
-    0xdf as i32 as u8,
-    0x9a as i32 as u8,
-    0x25 as i32 as u8,
-    0x9b as i32 as u8,
-    0x2f as i32 as u8,
-    0x9b as i32 as u8,
-    0x32 as i32 as u8,
-    0x9b as i32 as u8,
-    0x3c as i32 as u8,
-    0x9b as i32 as u8,
-    0x5a as i32 as u8,
-    0x9b as i32 as u8,
-    0xe5 as i32 as u8,
-    0x9c as i32 as u8,
-    0x75 as i32 as u8,
-    0x9e as i32 as u8,
-    0x7f as i32 as u8,
-    0x9e as i32 as u8,
-    0xa5 as i32 as u8,
-    0x9e as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1e as i32 as u8,
-    0x28 as i32 as u8,
-    0x2c as i32 as u8,
-    0x54 as i32 as u8,
-    0x58 as i32 as u8,
-    0x69 as i32 as u8,
-    0x6e as i32 as u8,
-    0x7b as i32 as u8,
-    0x96 as i32 as u8,
-    0xa5 as i32 as u8,
-    0xad as i32 as u8,
-    0xe8 as i32 as u8,
-    0xf7 as i32 as u8,
-    0xfb as i32 as u8,
-    0x12 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x44 as i32 as u8,
-    0x53 as i32 as u8,
-    0x45 as i32 as u8,
-    0x53 as i32 as u8,
-    0x4b as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4d as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4f as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x51 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x53 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x55 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5b as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5d as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x64 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x66 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x68 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x6f as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x72 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x75 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x78 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x7b as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x46 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x9d as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0x88 as i32 as u8,
-    0x30 as i32 as u8,
-    0x8a as i32 as u8,
-    0x30 as i32 as u8,
-    0xab as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xad as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xaf as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xbb as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xbd as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xbf as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xcf as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xdb as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xef as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xfd as i32 as u8,
-    0x30 as i32 as u8,
-    0x99 as i32 as u8,
-    0x30 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x30 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xaa as i32 as u8,
-    0x2 as i32 as u8,
-    0xac as i32 as u8,
-    0xad as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xb1 as i32 as u8,
-    0xb2 as i32 as u8,
-    0xb3 as i32 as u8,
-    0xb4 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x1a as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x21 as i32 as u8,
-    0x9 as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x14 as i32 as u8,
-    0x11 as i32 as u8,
-    0x4c as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb3 as i32 as u8,
-    0xb4 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xba as i32 as u8,
-    0xbf as i32 as u8,
-    0xc3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x8 as i32 as u8,
-    0xc9 as i32 as u8,
-    0xcb as i32 as u8,
-    0x9 as i32 as u8,
-    0xa as i32 as u8,
-    0xc as i32 as u8,
-    0xe as i32 as u8,
-    0xf as i32 as u8,
-    0x13 as i32 as u8,
-    0x15 as i32 as u8,
-    0x17 as i32 as u8,
-    0x18 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1b as i32 as u8,
-    0x1e as i32 as u8,
-    0x22 as i32 as u8,
-    0x2c as i32 as u8,
-    0x33 as i32 as u8,
-    0x38 as i32 as u8,
-    0xdd as i32 as u8,
-    0xde as i32 as u8,
-    0x43 as i32 as u8,
-    0x44 as i32 as u8,
-    0x45 as i32 as u8,
-    0x70 as i32 as u8,
-    0x71 as i32 as u8,
-    0x74 as i32 as u8,
-    0x7d as i32 as u8,
-    0x7e as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x8d as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8c as i32 as u8,
-    0x4e as i32 as u8,
-    0x9 as i32 as u8,
-    0x4e as i32 as u8,
-    0xdb as i32 as u8,
-    0x56 as i32 as u8,
-    0xa as i32 as u8,
-    0x4e as i32 as u8,
-    0x2d as i32 as u8,
-    0x4e as i32 as u8,
-    0xb as i32 as u8,
-    0x4e as i32 as u8,
-    0x32 as i32 as u8,
-    0x75 as i32 as u8,
-    0x59 as i32 as u8,
-    0x4e as i32 as u8,
-    0x19 as i32 as u8,
-    0x4e as i32 as u8,
-    0x1 as i32 as u8,
-    0x4e as i32 as u8,
-    0x29 as i32 as u8,
-    0x59 as i32 as u8,
-    0x30 as i32 as u8,
-    0x57 as i32 as u8,
-    0xba as i32 as u8,
-    0x4e as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x2 as i32 as u8,
-    0x11 as i32 as u8,
-    0x3 as i32 as u8,
-    0x11 as i32 as u8,
-    0x5 as i32 as u8,
-    0x11 as i32 as u8,
-    0x6 as i32 as u8,
-    0x11 as i32 as u8,
-    0x7 as i32 as u8,
-    0x11 as i32 as u8,
-    0x9 as i32 as u8,
-    0x11 as i32 as u8,
-    0xb as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0x11 as i32 as u8,
-    0xe as i32 as u8,
-    0x11 as i32 as u8,
-    0xf as i32 as u8,
-    0x11 as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x11 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0x11 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xe as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xc as i32 as u8,
-    0x11 as i32 as u8,
-    0x6e as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x11 as i32 as u8,
-    0x69 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0x11 as i32 as u8,
-    0x65 as i32 as u8,
-    0x11 as i32 as u8,
-    0xab as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x11 as i32 as u8,
-    0x69 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0x11 as i32 as u8,
-    0x6e as i32 as u8,
-    0x11 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8c as i32 as u8,
-    0x4e as i32 as u8,
-    0x9 as i32 as u8,
-    0x4e as i32 as u8,
-    0xdb as i32 as u8,
-    0x56 as i32 as u8,
-    0x94 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6d as i32 as u8,
-    0x51 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6b as i32 as u8,
-    0x51 as i32 as u8,
-    0x5d as i32 as u8,
-    0x4e as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x8 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6b as i32 as u8,
-    0x70 as i32 as u8,
-    0x34 as i32 as u8,
-    0x6c as i32 as u8,
-    0x28 as i32 as u8,
-    0x67 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x91 as i32 as u8,
-    0x1f as i32 as u8,
-    0x57 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0x2a as i32 as u8,
-    0x68 as i32 as u8,
-    0x9 as i32 as u8,
-    0x67 as i32 as u8,
-    0x3e as i32 as u8,
-    0x79 as i32 as u8,
-    0xd as i32 as u8,
-    0x54 as i32 as u8,
-    0x79 as i32 as u8,
-    0x72 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x8c as i32 as u8,
-    0x5d as i32 as u8,
-    0x79 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x52 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x4e as i32 as u8,
-    0x7c as i32 as u8,
-    0x54 as i32 as u8,
-    0x66 as i32 as u8,
-    0x5b as i32 as u8,
-    0xe3 as i32 as u8,
-    0x76 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4f as i32 as u8,
-    0xc7 as i32 as u8,
-    0x8c as i32 as u8,
-    0x54 as i32 as u8,
-    0x53 as i32 as u8,
-    0x6d as i32 as u8,
-    0x79 as i32 as u8,
-    0x11 as i32 as u8,
-    0x4f as i32 as u8,
-    0xea as i32 as u8,
-    0x81 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4f as i32 as u8,
-    0x55 as i32 as u8,
-    0x7c as i32 as u8,
-    0x5e as i32 as u8,
-    0x87 as i32 as u8,
-    0x65 as i32 as u8,
-    0x8f as i32 as u8,
-    0x7b as i32 as u8,
-    0x50 as i32 as u8,
-    0x54 as i32 as u8,
-    0x45 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x33 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0xc as i32 as u8,
-    0xe as i32 as u8,
-    0xf as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2 as i32 as u8,
-    0x61 as i32 as u8,
-    0x3 as i32 as u8,
-    0x61 as i32 as u8,
-    0x5 as i32 as u8,
-    0x61 as i32 as u8,
-    0x6 as i32 as u8,
-    0x61 as i32 as u8,
-    0x7 as i32 as u8,
-    0x61 as i32 as u8,
-    0x9 as i32 as u8,
-    0x61 as i32 as u8,
-    0xb as i32 as u8,
-    0x61 as i32 as u8,
-    0xc as i32 as u8,
-    0x61 as i32 as u8,
-    0xe as i32 as u8,
-    0x11 as i32 as u8,
-    0x61 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xe as i32 as u8,
-    0x61 as i32 as u8,
-    0xb7 as i32 as u8,
-    0 as i32 as u8,
-    0x69 as i32 as u8,
-    0xb as i32 as u8,
-    0x11 as i32 as u8,
-    0x1 as i32 as u8,
-    0x63 as i32 as u8,
-    0 as i32 as u8,
-    0x69 as i32 as u8,
-    0xb as i32 as u8,
-    0x11 as i32 as u8,
-    0x6e as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8c as i32 as u8,
-    0x4e as i32 as u8,
-    0x9 as i32 as u8,
-    0x4e as i32 as u8,
-    0xdb as i32 as u8,
-    0x56 as i32 as u8,
-    0x94 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6d as i32 as u8,
-    0x51 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6b as i32 as u8,
-    0x51 as i32 as u8,
-    0x5d as i32 as u8,
-    0x4e as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x8 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6b as i32 as u8,
-    0x70 as i32 as u8,
-    0x34 as i32 as u8,
-    0x6c as i32 as u8,
-    0x28 as i32 as u8,
-    0x67 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x91 as i32 as u8,
-    0x1f as i32 as u8,
-    0x57 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0x2a as i32 as u8,
-    0x68 as i32 as u8,
-    0x9 as i32 as u8,
-    0x67 as i32 as u8,
-    0x3e as i32 as u8,
-    0x79 as i32 as u8,
-    0xd as i32 as u8,
-    0x54 as i32 as u8,
-    0x79 as i32 as u8,
-    0x72 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x8c as i32 as u8,
-    0x5d as i32 as u8,
-    0x79 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x52 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x79 as i32 as u8,
-    0x37 as i32 as u8,
-    0x75 as i32 as u8,
-    0x73 as i32 as u8,
-    0x59 as i32 as u8,
-    0x69 as i32 as u8,
-    0x90 as i32 as u8,
-    0x2a as i32 as u8,
-    0x51 as i32 as u8,
-    0x70 as i32 as u8,
-    0x53 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x6c as i32 as u8,
-    0x5 as i32 as u8,
-    0x98 as i32 as u8,
-    0x11 as i32 as u8,
-    0x4f as i32 as u8,
-    0x99 as i32 as u8,
-    0x51 as i32 as u8,
-    0x63 as i32 as u8,
-    0x6b as i32 as u8,
-    0xa as i32 as u8,
-    0x4e as i32 as u8,
-    0x2d as i32 as u8,
-    0x4e as i32 as u8,
-    0xb as i32 as u8,
-    0x4e as i32 as u8,
-    0xe6 as i32 as u8,
-    0x5d as i32 as u8,
-    0xf3 as i32 as u8,
-    0x53 as i32 as u8,
-    0x3b as i32 as u8,
-    0x53 as i32 as u8,
-    0x97 as i32 as u8,
-    0x5b as i32 as u8,
-    0x66 as i32 as u8,
-    0x5b as i32 as u8,
-    0xe3 as i32 as u8,
-    0x76 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4f as i32 as u8,
-    0xc7 as i32 as u8,
-    0x8c as i32 as u8,
-    0x54 as i32 as u8,
-    0x53 as i32 as u8,
-    0x1c as i32 as u8,
-    0x59 as i32 as u8,
-    0x33 as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x30 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x67 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x67 as i32 as u8,
-    0x48 as i32 as u8,
-    0x67 as i32 as u8,
-    0x65 as i32 as u8,
-    0x72 as i32 as u8,
-    0x67 as i32 as u8,
-    0x65 as i32 as u8,
-    0x56 as i32 as u8,
-    0x4c as i32 as u8,
-    0x54 as i32 as u8,
-    0x44 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0x11 as i32 as u8,
-    0x13 as i32 as u8,
-    0x15 as i32 as u8,
-    0x17 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1d as i32 as u8,
-    0x1f as i32 as u8,
-    0x22 as i32 as u8,
-    0x24 as i32 as u8,
-    0x26 as i32 as u8,
-    0x28 as i32 as u8,
-    0x29 as i32 as u8,
-    0x2a as i32 as u8,
-    0x2b as i32 as u8,
-    0x2c as i32 as u8,
-    0x2d as i32 as u8,
-    0x30 as i32 as u8,
-    0x33 as i32 as u8,
-    0x36 as i32 as u8,
-    0x39 as i32 as u8,
-    0x3c as i32 as u8,
-    0x3d as i32 as u8,
-    0x3e as i32 as u8,
-    0x3f as i32 as u8,
-    0x40 as i32 as u8,
-    0x42 as i32 as u8,
-    0x44 as i32 as u8,
-    0x46 as i32 as u8,
-    0x47 as i32 as u8,
-    0x48 as i32 as u8,
-    0x49 as i32 as u8,
-    0x4a as i32 as u8,
-    0x4b as i32 as u8,
-    0x4d as i32 as u8,
-    0x4e as i32 as u8,
-    0x4f as i32 as u8,
-    0x50 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8c as i32 as u8,
-    0x54 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x5b as i32 as u8,
-    0x27 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4a as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x52 as i32 as u8,
-    0x39 as i32 as u8,
-    0x1 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x5a as i32 as u8,
-    0x49 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x4f as i32 as u8,
-    0xc as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x4f as i32 as u8,
-    0x1d as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4f as i32 as u8,
-    0xa8 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x7 as i32 as u8,
-    0x54 as i32 as u8,
-    0x21 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x54 as i32 as u8,
-    0x3 as i32 as u8,
-    0x54 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4f as i32 as u8,
-    0x15 as i32 as u8,
-    0x6 as i32 as u8,
-    0x58 as i32 as u8,
-    0x3c as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0xab as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0x18 as i32 as u8,
-    0x1d as i32 as u8,
-    0 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3f as i32 as u8,
-    0x51 as i32 as u8,
-    0xac as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x32 as i32 as u8,
-    0xae as i32 as u8,
-    0x30 as i32 as u8,
-    0xac as i32 as u8,
-    0x30 as i32 as u8,
-    0xae as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x4e as i32 as u8,
-    0xad as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x3d as i32 as u8,
-    0x4f as i32 as u8,
-    0x1 as i32 as u8,
-    0x3e as i32 as u8,
-    0x13 as i32 as u8,
-    0x4f as i32 as u8,
-    0xad as i32 as u8,
-    0x30 as i32 as u8,
-    0xed as i32 as u8,
-    0x30 as i32 as u8,
-    0xad as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3c as i32 as u8,
-    0x33 as i32 as u8,
-    0xad as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0x34 as i32 as u8,
-    0x4f as i32 as u8,
-    0x1b as i32 as u8,
-    0x3e as i32 as u8,
-    0xad as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0x42 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1b as i32 as u8,
-    0xb0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x39 as i32 as u8,
-    0x30 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0xc as i32 as u8,
-    0x45 as i32 as u8,
-    0x3c as i32 as u8,
-    0x24 as i32 as u8,
-    0x4f as i32 as u8,
-    0xb as i32 as u8,
-    0x47 as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0x49 as i32 as u8,
-    0xaf as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0x4d as i32 as u8,
-    0x1e as i32 as u8,
-    0xb1 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x4b as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3a as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4b as i32 as u8,
-    0x2c as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x47 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0xc as i32 as u8,
-    0x47 as i32 as u8,
-    0x2b as i32 as u8,
-    0xb0 as i32 as u8,
-    0x30 as i32 as u8,
-    0x7 as i32 as u8,
-    0x3a as i32 as u8,
-    0x43 as i32 as u8,
-    0 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3a as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3a as i32 as u8,
-    0xf as i32 as u8,
-    0x7 as i32 as u8,
-    0x43 as i32 as u8,
-    0 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x34 as i32 as u8,
-    0x11 as i32 as u8,
-    0x3c as i32 as u8,
-    0x13 as i32 as u8,
-    0x17 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1f as i32 as u8,
-    0x24 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0xbb as i32 as u8,
-    0x30 as i32 as u8,
-    0x16 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x38 as i32 as u8,
-    0xd as i32 as u8,
-    0xc4 as i32 as u8,
-    0x30 as i32 as u8,
-    0xd as i32 as u8,
-    0x38 as i32 as u8,
-    0 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x2c as i32 as u8,
-    0x1c as i32 as u8,
-    0x1b as i32 as u8,
-    0xa2 as i32 as u8,
-    0x30 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x17 as i32 as u8,
-    0x26 as i32 as u8,
-    0x49 as i32 as u8,
-    0xaf as i32 as u8,
-    0x30 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x3c as i32 as u8,
-    0xb3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x38 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x48 as i32 as u8,
-    0x22 as i32 as u8,
-    0x28 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x59 as i32 as u8,
-    0x25 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x1c as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0x44 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x1e as i32 as u8,
-    0xaf as i32 as u8,
-    0x30 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x10 as i32 as u8,
-    0x4d as i32 as u8,
-    0x3c as i32 as u8,
-    0xda as i32 as u8,
-    0x30 as i32 as u8,
-    0xbd as i32 as u8,
-    0x30 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x22 as i32 as u8,
-    0x13 as i32 as u8,
-    0x1a as i32 as u8,
-    0x20 as i32 as u8,
-    0x33 as i32 as u8,
-    0xc as i32 as u8,
-    0x22 as i32 as u8,
-    0x3b as i32 as u8,
-    0x1 as i32 as u8,
-    0x22 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x44 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x39 as i32 as u8,
-    0 as i32 as u8,
-    0x4f as i32 as u8,
-    0x24 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x30 as i32 as u8,
-    0x14 as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0xdb as i32 as u8,
-    0x30 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x30 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x14 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x33 as i32 as u8,
-    0x22 as i32 as u8,
-    0x12 as i32 as u8,
-    0x33 as i32 as u8,
-    0x2a as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3a as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x49 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3a as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x3a as i32 as u8,
-    0x1f as i32 as u8,
-    0x2b as i32 as u8,
-    0x3a as i32 as u8,
-    0x47 as i32 as u8,
-    0xb as i32 as u8,
-    0xb7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x27 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3c as i32 as u8,
-    0xaf as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0x44 as i32 as u8,
-    0xdf as i32 as u8,
-    0x30 as i32 as u8,
-    0xea as i32 as u8,
-    0x30 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x30 as i32 as u8,
-    0xf as i32 as u8,
-    0x1a as i32 as u8,
-    0 as i32 as u8,
-    0x2c as i32 as u8,
-    0x1b as i32 as u8,
-    0xe1 as i32 as u8,
-    0x30 as i32 as u8,
-    0xac as i32 as u8,
-    0x30 as i32 as u8,
-    0xac as i32 as u8,
-    0x30 as i32 as u8,
-    0x35 as i32 as u8,
-    0 as i32 as u8,
-    0x1c as i32 as u8,
-    0x47 as i32 as u8,
-    0x35 as i32 as u8,
-    0x50 as i32 as u8,
-    0x1c as i32 as u8,
-    0x3f as i32 as u8,
-    0xa2 as i32 as u8,
-    0x30 as i32 as u8,
-    0x42 as i32 as u8,
-    0x5a as i32 as u8,
-    0x27 as i32 as u8,
-    0x42 as i32 as u8,
-    0x5a as i32 as u8,
-    0x49 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x51 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x28 as i32 as u8,
-    0xea as i32 as u8,
-    0x30 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x30 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x30 as i32 as u8,
-    0x17 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x30 as i32 as u8,
-    0x15 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x15 as i32 as u8,
-    0xec as i32 as u8,
-    0x30 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x30 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3a as i32 as u8,
-    0x41 as i32 as u8,
-    0x16 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x70 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x70 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x70 as i32 as u8,
-    0x68 as i32 as u8,
-    0x50 as i32 as u8,
-    0x61 as i32 as u8,
-    0x64 as i32 as u8,
-    0x61 as i32 as u8,
-    0x41 as i32 as u8,
-    0x55 as i32 as u8,
-    0x62 as i32 as u8,
-    0x61 as i32 as u8,
-    0x72 as i32 as u8,
-    0x6f as i32 as u8,
-    0x56 as i32 as u8,
-    0x70 as i32 as u8,
-    0x63 as i32 as u8,
-    0x64 as i32 as u8,
-    0x6d as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0x73 as i32 as u8,
-    0x5e as i32 as u8,
-    0x10 as i32 as u8,
-    0x62 as i32 as u8,
-    0x2d as i32 as u8,
-    0x66 as i32 as u8,
-    0x8c as i32 as u8,
-    0x54 as i32 as u8,
-    0x27 as i32 as u8,
-    0x59 as i32 as u8,
-    0x63 as i32 as u8,
-    0x6b as i32 as u8,
-    0xe as i32 as u8,
-    0x66 as i32 as u8,
-    0xbb as i32 as u8,
-    0x6c as i32 as u8,
-    0x2a as i32 as u8,
-    0x68 as i32 as u8,
-    0xf as i32 as u8,
-    0x5f as i32 as u8,
-    0x1a as i32 as u8,
-    0x4f as i32 as u8,
-    0x3e as i32 as u8,
-    0x79 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0x42 as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0x42 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x42 as i32 as u8,
-    0x63 as i32 as u8,
-    0x61 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6b as i32 as u8,
-    0x63 as i32 as u8,
-    0x61 as i32 as u8,
-    0x6c as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x46 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x67 as i32 as u8,
-    0x48 as i32 as u8,
-    0 as i32 as u8,
-    0x7a as i32 as u8,
-    0x6b as i32 as u8,
-    0x48 as i32 as u8,
-    0x7a as i32 as u8,
-    0x4d as i32 as u8,
-    0x48 as i32 as u8,
-    0x7a as i32 as u8,
-    0x47 as i32 as u8,
-    0x48 as i32 as u8,
-    0x7a as i32 as u8,
-    0x54 as i32 as u8,
-    0x48 as i32 as u8,
-    0x7a as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x21 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x21 as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x21 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x21 as i32 as u8,
-    0x66 as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x6d as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0x63 as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0x63 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0x4f as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0 as i32 as u8,
-    0x63 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0xa as i32 as u8,
-    0x4f as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0x50 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0x50 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0xb3 as i32 as u8,
-    0 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0xb3 as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x15 as i32 as u8,
-    0x22 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x15 as i32 as u8,
-    0x22 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0 as i32 as u8,
-    0x50 as i32 as u8,
-    0x61 as i32 as u8,
-    0x6b as i32 as u8,
-    0x50 as i32 as u8,
-    0x61 as i32 as u8,
-    0x4d as i32 as u8,
-    0x50 as i32 as u8,
-    0x61 as i32 as u8,
-    0x47 as i32 as u8,
-    0x50 as i32 as u8,
-    0x61 as i32 as u8,
-    0x72 as i32 as u8,
-    0x61 as i32 as u8,
-    0x64 as i32 as u8,
-    0x72 as i32 as u8,
-    0x61 as i32 as u8,
-    0x64 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x73 as i32 as u8,
-    0x72 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x15 as i32 as u8,
-    0x22 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x73 as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0x73 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x73 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x73 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x56 as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0x56 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x56 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x56 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x56 as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0x56 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0x6e as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x57 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0x57 as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6d as i32 as u8,
-    0x2e as i32 as u8,
-    0x42 as i32 as u8,
-    0x71 as i32 as u8,
-    0x63 as i32 as u8,
-    0x63 as i32 as u8,
-    0x63 as i32 as u8,
-    0x64 as i32 as u8,
-    0x43 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6b as i32 as u8,
-    0x67 as i32 as u8,
-    0x43 as i32 as u8,
-    0x6f as i32 as u8,
-    0x2e as i32 as u8,
-    0x64 as i32 as u8,
-    0x42 as i32 as u8,
-    0x47 as i32 as u8,
-    0x79 as i32 as u8,
-    0x68 as i32 as u8,
-    0x61 as i32 as u8,
-    0x48 as i32 as u8,
-    0x50 as i32 as u8,
-    0x69 as i32 as u8,
-    0x6e as i32 as u8,
-    0x4b as i32 as u8,
-    0x4b as i32 as u8,
-    0x4b as i32 as u8,
-    0x4d as i32 as u8,
-    0x6b as i32 as u8,
-    0x74 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6d as i32 as u8,
-    0x6c as i32 as u8,
-    0x6e as i32 as u8,
-    0x6c as i32 as u8,
-    0x6f as i32 as u8,
-    0x67 as i32 as u8,
-    0x6c as i32 as u8,
-    0x78 as i32 as u8,
-    0x6d as i32 as u8,
-    0x62 as i32 as u8,
-    0x6d as i32 as u8,
-    0x69 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6d as i32 as u8,
-    0x6f as i32 as u8,
-    0x6c as i32 as u8,
-    0x50 as i32 as u8,
-    0x48 as i32 as u8,
-    0x70 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6d as i32 as u8,
-    0x2e as i32 as u8,
-    0x50 as i32 as u8,
-    0x50 as i32 as u8,
-    0x4d as i32 as u8,
-    0x50 as i32 as u8,
-    0x52 as i32 as u8,
-    0x73 as i32 as u8,
-    0x72 as i32 as u8,
-    0x53 as i32 as u8,
-    0x76 as i32 as u8,
-    0x57 as i32 as u8,
-    0x62 as i32 as u8,
-    0x56 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6d as i32 as u8,
-    0x41 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6d as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0x33 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x65 as i32 as u8,
-    0x67 as i32 as u8,
-    0x61 as i32 as u8,
-    0x6c as i32 as u8,
-    0x4a as i32 as u8,
-    0x4 as i32 as u8,
-    0x4c as i32 as u8,
-    0x4 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x53 as i32 as u8,
-    0x1 as i32 as u8,
-    0x27 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x37 as i32 as u8,
-    0xab as i32 as u8,
-    0x6b as i32 as u8,
-    0x2 as i32 as u8,
-    0x52 as i32 as u8,
-    0xab as i32 as u8,
-    0x48 as i32 as u8,
-    0x8c as i32 as u8,
-    0xf4 as i32 as u8,
-    0x66 as i32 as u8,
-    0xca as i32 as u8,
-    0x8e as i32 as u8,
-    0xc8 as i32 as u8,
-    0x8c as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6e as i32 as u8,
-    0x32 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe5 as i32 as u8,
-    0x53 as i32 as u8,
-    0x9c as i32 as u8,
-    0x9f as i32 as u8,
-    0x9c as i32 as u8,
-    0x9f as i32 as u8,
-    0x51 as i32 as u8,
-    0x59 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x91 as i32 as u8,
-    0x87 as i32 as u8,
-    0x55 as i32 as u8,
-    0x48 as i32 as u8,
-    0x59 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x61 as i32 as u8,
-    0x69 as i32 as u8,
-    0x76 as i32 as u8,
-    0x85 as i32 as u8,
-    0x7f as i32 as u8,
-    0x3f as i32 as u8,
-    0x86 as i32 as u8,
-    0xba as i32 as u8,
-    0x87 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8f as i32 as u8,
-    0x90 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6a as i32 as u8,
-    0x1b as i32 as u8,
-    0x6d as i32 as u8,
-    0xd9 as i32 as u8,
-    0x70 as i32 as u8,
-    0xde as i32 as u8,
-    0x73 as i32 as u8,
-    0x3d as i32 as u8,
-    0x84 as i32 as u8,
-    0x6a as i32 as u8,
-    0x91 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x99 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4e as i32 as u8,
-    0x75 as i32 as u8,
-    0x53 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6b as i32 as u8,
-    0x1b as i32 as u8,
-    0x72 as i32 as u8,
-    0x2d as i32 as u8,
-    0x86 as i32 as u8,
-    0x1e as i32 as u8,
-    0x9e as i32 as u8,
-    0x50 as i32 as u8,
-    0x5d as i32 as u8,
-    0xeb as i32 as u8,
-    0x6f as i32 as u8,
-    0xcd as i32 as u8,
-    0x85 as i32 as u8,
-    0x64 as i32 as u8,
-    0x89 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x62 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1f as i32 as u8,
-    0x88 as i32 as u8,
-    0xca as i32 as u8,
-    0x5e as i32 as u8,
-    0x17 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6a as i32 as u8,
-    0x6d as i32 as u8,
-    0xfc as i32 as u8,
-    0x72 as i32 as u8,
-    0xce as i32 as u8,
-    0x90 as i32 as u8,
-    0x86 as i32 as u8,
-    0x4f as i32 as u8,
-    0xb7 as i32 as u8,
-    0x51 as i32 as u8,
-    0xde as i32 as u8,
-    0x52 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x64 as i32 as u8,
-    0xd3 as i32 as u8,
-    0x6a as i32 as u8,
-    0x10 as i32 as u8,
-    0x72 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x76 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x6 as i32 as u8,
-    0x86 as i32 as u8,
-    0x5c as i32 as u8,
-    0x86 as i32 as u8,
-    0xef as i32 as u8,
-    0x8d as i32 as u8,
-    0x32 as i32 as u8,
-    0x97 as i32 as u8,
-    0x6f as i32 as u8,
-    0x9b as i32 as u8,
-    0xfa as i32 as u8,
-    0x9d as i32 as u8,
-    0x8c as i32 as u8,
-    0x78 as i32 as u8,
-    0x7f as i32 as u8,
-    0x79 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x7d as i32 as u8,
-    0xc9 as i32 as u8,
-    0x83 as i32 as u8,
-    0x4 as i32 as u8,
-    0x93 as i32 as u8,
-    0x7f as i32 as u8,
-    0x9e as i32 as u8,
-    0xd6 as i32 as u8,
-    0x8a as i32 as u8,
-    0xdf as i32 as u8,
-    0x58 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5f as i32 as u8,
-    0x60 as i32 as u8,
-    0x7c as i32 as u8,
-    0x7e as i32 as u8,
-    0x80 as i32 as u8,
-    0x62 as i32 as u8,
-    0x72 as i32 as u8,
-    0xca as i32 as u8,
-    0x78 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x8c as i32 as u8,
-    0xf7 as i32 as u8,
-    0x96 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x58 as i32 as u8,
-    0x62 as i32 as u8,
-    0x5c as i32 as u8,
-    0x13 as i32 as u8,
-    0x6a as i32 as u8,
-    0xda as i32 as u8,
-    0x6d as i32 as u8,
-    0xf as i32 as u8,
-    0x6f as i32 as u8,
-    0x2f as i32 as u8,
-    0x7d as i32 as u8,
-    0x37 as i32 as u8,
-    0x7e as i32 as u8,
-    0x4b as i32 as u8,
-    0x96 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x52 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0xdc as i32 as u8,
-    0x51 as i32 as u8,
-    0xcc as i32 as u8,
-    0x51 as i32 as u8,
-    0x1c as i32 as u8,
-    0x7a as i32 as u8,
-    0xbe as i32 as u8,
-    0x7d as i32 as u8,
-    0xf1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x75 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0xcf as i32 as u8,
-    0x62 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6a as i32 as u8,
-    0xfe as i32 as u8,
-    0x8a as i32 as u8,
-    0x39 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe7 as i32 as u8,
-    0x5b as i32 as u8,
-    0x12 as i32 as u8,
-    0x60 as i32 as u8,
-    0x87 as i32 as u8,
-    0x73 as i32 as u8,
-    0x70 as i32 as u8,
-    0x75 as i32 as u8,
-    0x17 as i32 as u8,
-    0x53 as i32 as u8,
-    0xfb as i32 as u8,
-    0x78 as i32 as u8,
-    0xbf as i32 as u8,
-    0x4f as i32 as u8,
-    0xa9 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd as i32 as u8,
-    0x4e as i32 as u8,
-    0xcc as i32 as u8,
-    0x6c as i32 as u8,
-    0x78 as i32 as u8,
-    0x65 as i32 as u8,
-    0x22 as i32 as u8,
-    0x7d as i32 as u8,
-    0xc3 as i32 as u8,
-    0x53 as i32 as u8,
-    0x5e as i32 as u8,
-    0x58 as i32 as u8,
-    0x1 as i32 as u8,
-    0x77 as i32 as u8,
-    0x49 as i32 as u8,
-    0x84 as i32 as u8,
-    0xaa as i32 as u8,
-    0x8a as i32 as u8,
-    0xba as i32 as u8,
-    0x6b as i32 as u8,
-    0xb0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x88 as i32 as u8,
-    0x6c as i32 as u8,
-    0xfe as i32 as u8,
-    0x62 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x82 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x63 as i32 as u8,
-    0x65 as i32 as u8,
-    0x75 as i32 as u8,
-    0xae as i32 as u8,
-    0x4e as i32 as u8,
-    0x69 as i32 as u8,
-    0x51 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x51 as i32 as u8,
-    0x81 as i32 as u8,
-    0x68 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x7c as i32 as u8,
-    0x6f as i32 as u8,
-    0x82 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x8a as i32 as u8,
-    0xcf as i32 as u8,
-    0x91 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x52 as i32 as u8,
-    0x42 as i32 as u8,
-    0x54 as i32 as u8,
-    0x73 as i32 as u8,
-    0x59 as i32 as u8,
-    0xec as i32 as u8,
-    0x5e as i32 as u8,
-    0xc5 as i32 as u8,
-    0x65 as i32 as u8,
-    0xfe as i32 as u8,
-    0x6f as i32 as u8,
-    0x2a as i32 as u8,
-    0x79 as i32 as u8,
-    0xad as i32 as u8,
-    0x95 as i32 as u8,
-    0x6a as i32 as u8,
-    0x9a as i32 as u8,
-    0x97 as i32 as u8,
-    0x9e as i32 as u8,
-    0xce as i32 as u8,
-    0x9e as i32 as u8,
-    0x9b as i32 as u8,
-    0x52 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x66 as i32 as u8,
-    0x77 as i32 as u8,
-    0x6b as i32 as u8,
-    0x62 as i32 as u8,
-    0x8f as i32 as u8,
-    0x74 as i32 as u8,
-    0x5e as i32 as u8,
-    0x90 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x62 as i32 as u8,
-    0x9a as i32 as u8,
-    0x64 as i32 as u8,
-    0x23 as i32 as u8,
-    0x6f as i32 as u8,
-    0x49 as i32 as u8,
-    0x71 as i32 as u8,
-    0x89 as i32 as u8,
-    0x74 as i32 as u8,
-    0xca as i32 as u8,
-    0x79 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x7d as i32 as u8,
-    0x6f as i32 as u8,
-    0x80 as i32 as u8,
-    0x26 as i32 as u8,
-    0x8f as i32 as u8,
-    0xee as i32 as u8,
-    0x84 as i32 as u8,
-    0x23 as i32 as u8,
-    0x90 as i32 as u8,
-    0x4a as i32 as u8,
-    0x93 as i32 as u8,
-    0x17 as i32 as u8,
-    0x52 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x52 as i32 as u8,
-    0xbd as i32 as u8,
-    0x54 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x70 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x88 as i32 as u8,
-    0xaa as i32 as u8,
-    0x8a as i32 as u8,
-    0xc9 as i32 as u8,
-    0x5e as i32 as u8,
-    0xf5 as i32 as u8,
-    0x5f as i32 as u8,
-    0x7b as i32 as u8,
-    0x63 as i32 as u8,
-    0xae as i32 as u8,
-    0x6b as i32 as u8,
-    0x3e as i32 as u8,
-    0x7c as i32 as u8,
-    0x75 as i32 as u8,
-    0x73 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x4e as i32 as u8,
-    0xf9 as i32 as u8,
-    0x56 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x5b as i32 as u8,
-    0xba as i32 as u8,
-    0x5d as i32 as u8,
-    0x1c as i32 as u8,
-    0x60 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x73 as i32 as u8,
-    0x69 as i32 as u8,
-    0x74 as i32 as u8,
-    0x9a as i32 as u8,
-    0x7f as i32 as u8,
-    0x46 as i32 as u8,
-    0x80 as i32 as u8,
-    0x34 as i32 as u8,
-    0x92 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x96 as i32 as u8,
-    0x48 as i32 as u8,
-    0x97 as i32 as u8,
-    0x18 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8b as i32 as u8,
-    0x4f as i32 as u8,
-    0xae as i32 as u8,
-    0x79 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x91 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x60 as i32 as u8,
-    0x86 as i32 as u8,
-    0x4e as i32 as u8,
-    0xda as i32 as u8,
-    0x50 as i32 as u8,
-    0xee as i32 as u8,
-    0x5b as i32 as u8,
-    0x3f as i32 as u8,
-    0x5c as i32 as u8,
-    0x99 as i32 as u8,
-    0x65 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6a as i32 as u8,
-    0xce as i32 as u8,
-    0x71 as i32 as u8,
-    0x42 as i32 as u8,
-    0x76 as i32 as u8,
-    0xfc as i32 as u8,
-    0x84 as i32 as u8,
-    0x7c as i32 as u8,
-    0x90 as i32 as u8,
-    0x8d as i32 as u8,
-    0x9f as i32 as u8,
-    0x88 as i32 as u8,
-    0x66 as i32 as u8,
-    0x2e as i32 as u8,
-    0x96 as i32 as u8,
-    0x89 as i32 as u8,
-    0x52 as i32 as u8,
-    0x7b as i32 as u8,
-    0x67 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x67 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6d as i32 as u8,
-    0x9c as i32 as u8,
-    0x6e as i32 as u8,
-    0x9 as i32 as u8,
-    0x74 as i32 as u8,
-    0x59 as i32 as u8,
-    0x75 as i32 as u8,
-    0x6b as i32 as u8,
-    0x78 as i32 as u8,
-    0x10 as i32 as u8,
-    0x7d as i32 as u8,
-    0x5e as i32 as u8,
-    0x98 as i32 as u8,
-    0x6d as i32 as u8,
-    0x51 as i32 as u8,
-    0x2e as i32 as u8,
-    0x62 as i32 as u8,
-    0x78 as i32 as u8,
-    0x96 as i32 as u8,
-    0x2b as i32 as u8,
-    0x50 as i32 as u8,
-    0x19 as i32 as u8,
-    0x5d as i32 as u8,
-    0xea as i32 as u8,
-    0x6d as i32 as u8,
-    0x2a as i32 as u8,
-    0x8f as i32 as u8,
-    0x8b as i32 as u8,
-    0x5f as i32 as u8,
-    0x44 as i32 as u8,
-    0x61 as i32 as u8,
-    0x17 as i32 as u8,
-    0x68 as i32 as u8,
-    0x87 as i32 as u8,
-    0x73 as i32 as u8,
-    0x86 as i32 as u8,
-    0x96 as i32 as u8,
-    0x29 as i32 as u8,
-    0x52 as i32 as u8,
-    0xf as i32 as u8,
-    0x54 as i32 as u8,
-    0x65 as i32 as u8,
-    0x5c as i32 as u8,
-    0x13 as i32 as u8,
-    0x66 as i32 as u8,
-    0x4e as i32 as u8,
-    0x67 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x68 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6 as i32 as u8,
-    0x74 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x75 as i32 as u8,
-    0x79 as i32 as u8,
-    0x7f as i32 as u8,
-    0xcf as i32 as u8,
-    0x88 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x88 as i32 as u8,
-    0xcc as i32 as u8,
-    0x91 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x96 as i32 as u8,
-    0x3f as i32 as u8,
-    0x53 as i32 as u8,
-    0xba as i32 as u8,
-    0x6e as i32 as u8,
-    0x1d as i32 as u8,
-    0x54 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x71 as i32 as u8,
-    0x98 as i32 as u8,
-    0x74 as i32 as u8,
-    0xfa as i32 as u8,
-    0x85 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x96 as i32 as u8,
-    0x57 as i32 as u8,
-    0x9c as i32 as u8,
-    0x9f as i32 as u8,
-    0x9e as i32 as u8,
-    0x97 as i32 as u8,
-    0x67 as i32 as u8,
-    0xcb as i32 as u8,
-    0x6d as i32 as u8,
-    0xe8 as i32 as u8,
-    0x81 as i32 as u8,
-    0xcb as i32 as u8,
-    0x7a as i32 as u8,
-    0x20 as i32 as u8,
-    0x7b as i32 as u8,
-    0x92 as i32 as u8,
-    0x7c as i32 as u8,
-    0xc0 as i32 as u8,
-    0x72 as i32 as u8,
-    0x99 as i32 as u8,
-    0x70 as i32 as u8,
-    0x58 as i32 as u8,
-    0x8b as i32 as u8,
-    0xc0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x36 as i32 as u8,
-    0x83 as i32 as u8,
-    0x3a as i32 as u8,
-    0x52 as i32 as u8,
-    0x7 as i32 as u8,
-    0x52 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x5e as i32 as u8,
-    0xd3 as i32 as u8,
-    0x62 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x7c as i32 as u8,
-    0x85 as i32 as u8,
-    0x5b as i32 as u8,
-    0x1e as i32 as u8,
-    0x6d as i32 as u8,
-    0xb4 as i32 as u8,
-    0x66 as i32 as u8,
-    0x3b as i32 as u8,
-    0x8f as i32 as u8,
-    0x4c as i32 as u8,
-    0x88 as i32 as u8,
-    0x4d as i32 as u8,
-    0x96 as i32 as u8,
-    0x8b as i32 as u8,
-    0x89 as i32 as u8,
-    0xd3 as i32 as u8,
-    0x5e as i32 as u8,
-    0x40 as i32 as u8,
-    0x51 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x55 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5a as i32 as u8,
-    0x58 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x74 as i32 as u8,
-    0x66 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xde as i32 as u8,
-    0x51 as i32 as u8,
-    0x2a as i32 as u8,
-    0x73 as i32 as u8,
-    0xca as i32 as u8,
-    0x76 as i32 as u8,
-    0x3c as i32 as u8,
-    0x79 as i32 as u8,
-    0x5e as i32 as u8,
-    0x79 as i32 as u8,
-    0x65 as i32 as u8,
-    0x79 as i32 as u8,
-    0x8f as i32 as u8,
-    0x79 as i32 as u8,
-    0x56 as i32 as u8,
-    0x97 as i32 as u8,
-    0xbe as i32 as u8,
-    0x7c as i32 as u8,
-    0xbd as i32 as u8,
-    0x7f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x86 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x90 as i32 as u8,
-    0xfd as i32 as u8,
-    0x90 as i32 as u8,
-    0xef as i32 as u8,
-    0x98 as i32 as u8,
-    0xfc as i32 as u8,
-    0x98 as i32 as u8,
-    0x28 as i32 as u8,
-    0x99 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x9d as i32 as u8,
-    0xde as i32 as u8,
-    0x90 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x96 as i32 as u8,
-    0xae as i32 as u8,
-    0x4f as i32 as u8,
-    0xe7 as i32 as u8,
-    0x50 as i32 as u8,
-    0x4d as i32 as u8,
-    0x51 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x52 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x52 as i32 as u8,
-    0x51 as i32 as u8,
-    0x53 as i32 as u8,
-    0x9d as i32 as u8,
-    0x55 as i32 as u8,
-    0x6 as i32 as u8,
-    0x56 as i32 as u8,
-    0x68 as i32 as u8,
-    0x56 as i32 as u8,
-    0x40 as i32 as u8,
-    0x58 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x58 as i32 as u8,
-    0x64 as i32 as u8,
-    0x5c as i32 as u8,
-    0x6e as i32 as u8,
-    0x5c as i32 as u8,
-    0x94 as i32 as u8,
-    0x60 as i32 as u8,
-    0x68 as i32 as u8,
-    0x61 as i32 as u8,
-    0x8e as i32 as u8,
-    0x61 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x61 as i32 as u8,
-    0x4f as i32 as u8,
-    0x65 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x65 as i32 as u8,
-    0x91 as i32 as u8,
-    0x66 as i32 as u8,
-    0x85 as i32 as u8,
-    0x68 as i32 as u8,
-    0x77 as i32 as u8,
-    0x6d as i32 as u8,
-    0x1a as i32 as u8,
-    0x6e as i32 as u8,
-    0x22 as i32 as u8,
-    0x6f as i32 as u8,
-    0x6e as i32 as u8,
-    0x71 as i32 as u8,
-    0x2b as i32 as u8,
-    0x72 as i32 as u8,
-    0x22 as i32 as u8,
-    0x74 as i32 as u8,
-    0x91 as i32 as u8,
-    0x78 as i32 as u8,
-    0x3e as i32 as u8,
-    0x79 as i32 as u8,
-    0x49 as i32 as u8,
-    0x79 as i32 as u8,
-    0x48 as i32 as u8,
-    0x79 as i32 as u8,
-    0x50 as i32 as u8,
-    0x79 as i32 as u8,
-    0x56 as i32 as u8,
-    0x79 as i32 as u8,
-    0x5d as i32 as u8,
-    0x79 as i32 as u8,
-    0x8d as i32 as u8,
-    0x79 as i32 as u8,
-    0x8e as i32 as u8,
-    0x79 as i32 as u8,
-    0x40 as i32 as u8,
-    0x7a as i32 as u8,
-    0x81 as i32 as u8,
-    0x7a as i32 as u8,
-    0xc0 as i32 as u8,
-    0x7b as i32 as u8,
-    0xf4 as i32 as u8,
-    0x7d as i32 as u8,
-    0x9 as i32 as u8,
-    0x7e as i32 as u8,
-    0x41 as i32 as u8,
-    0x7e as i32 as u8,
-    0x72 as i32 as u8,
-    0x7f as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xed as i32 as u8,
-    0x81 as i32 as u8,
-    0x79 as i32 as u8,
-    0x82 as i32 as u8,
-    0x79 as i32 as u8,
-    0x82 as i32 as u8,
-    0x57 as i32 as u8,
-    0x84 as i32 as u8,
-    0x10 as i32 as u8,
-    0x89 as i32 as u8,
-    0x96 as i32 as u8,
-    0x89 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8b as i32 as u8,
-    0x39 as i32 as u8,
-    0x8b as i32 as u8,
-    0xd3 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8 as i32 as u8,
-    0x8d as i32 as u8,
-    0xb6 as i32 as u8,
-    0x8f as i32 as u8,
-    0x38 as i32 as u8,
-    0x90 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x96 as i32 as u8,
-    0xff as i32 as u8,
-    0x97 as i32 as u8,
-    0x3b as i32 as u8,
-    0x98 as i32 as u8,
-    0x75 as i32 as u8,
-    0x60 as i32 as u8,
-    0xee as i32 as u8,
-    0x42 as i32 as u8,
-    0x18 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2 as i32 as u8,
-    0x26 as i32 as u8,
-    0x4e as i32 as u8,
-    0xb5 as i32 as u8,
-    0x51 as i32 as u8,
-    0x68 as i32 as u8,
-    0x51 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4f as i32 as u8,
-    0x45 as i32 as u8,
-    0x51 as i32 as u8,
-    0x80 as i32 as u8,
-    0x51 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x52 as i32 as u8,
-    0xfa as i32 as u8,
-    0x52 as i32 as u8,
-    0x9d as i32 as u8,
-    0x55 as i32 as u8,
-    0x55 as i32 as u8,
-    0x55 as i32 as u8,
-    0x99 as i32 as u8,
-    0x55 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x55 as i32 as u8,
-    0x5a as i32 as u8,
-    0x58 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x58 as i32 as u8,
-    0x44 as i32 as u8,
-    0x59 as i32 as u8,
-    0x54 as i32 as u8,
-    0x59 as i32 as u8,
-    0x62 as i32 as u8,
-    0x5a as i32 as u8,
-    0x28 as i32 as u8,
-    0x5b as i32 as u8,
-    0xd2 as i32 as u8,
-    0x5e as i32 as u8,
-    0xd9 as i32 as u8,
-    0x5e as i32 as u8,
-    0x69 as i32 as u8,
-    0x5f as i32 as u8,
-    0xad as i32 as u8,
-    0x5f as i32 as u8,
-    0xd8 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4e as i32 as u8,
-    0x61 as i32 as u8,
-    0x8 as i32 as u8,
-    0x61 as i32 as u8,
-    0x8e as i32 as u8,
-    0x61 as i32 as u8,
-    0x60 as i32 as u8,
-    0x61 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x61 as i32 as u8,
-    0x34 as i32 as u8,
-    0x62 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x63 as i32 as u8,
-    0x1c as i32 as u8,
-    0x64 as i32 as u8,
-    0x52 as i32 as u8,
-    0x64 as i32 as u8,
-    0x56 as i32 as u8,
-    0x65 as i32 as u8,
-    0x74 as i32 as u8,
-    0x66 as i32 as u8,
-    0x17 as i32 as u8,
-    0x67 as i32 as u8,
-    0x1b as i32 as u8,
-    0x67 as i32 as u8,
-    0x56 as i32 as u8,
-    0x67 as i32 as u8,
-    0x79 as i32 as u8,
-    0x6b as i32 as u8,
-    0xba as i32 as u8,
-    0x6b as i32 as u8,
-    0x41 as i32 as u8,
-    0x6d as i32 as u8,
-    0xdb as i32 as u8,
-    0x6e as i32 as u8,
-    0xcb as i32 as u8,
-    0x6e as i32 as u8,
-    0x22 as i32 as u8,
-    0x6f as i32 as u8,
-    0x1e as i32 as u8,
-    0x70 as i32 as u8,
-    0x6e as i32 as u8,
-    0x71 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x77 as i32 as u8,
-    0x35 as i32 as u8,
-    0x72 as i32 as u8,
-    0xaf as i32 as u8,
-    0x72 as i32 as u8,
-    0x2a as i32 as u8,
-    0x73 as i32 as u8,
-    0x71 as i32 as u8,
-    0x74 as i32 as u8,
-    0x6 as i32 as u8,
-    0x75 as i32 as u8,
-    0x3b as i32 as u8,
-    0x75 as i32 as u8,
-    0x1d as i32 as u8,
-    0x76 as i32 as u8,
-    0x1f as i32 as u8,
-    0x76 as i32 as u8,
-    0xca as i32 as u8,
-    0x76 as i32 as u8,
-    0xdb as i32 as u8,
-    0x76 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x76 as i32 as u8,
-    0x4a as i32 as u8,
-    0x77 as i32 as u8,
-    0x40 as i32 as u8,
-    0x77 as i32 as u8,
-    0xcc as i32 as u8,
-    0x78 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x7a as i32 as u8,
-    0xc0 as i32 as u8,
-    0x7b as i32 as u8,
-    0x7b as i32 as u8,
-    0x7c as i32 as u8,
-    0x5b as i32 as u8,
-    0x7d as i32 as u8,
-    0xf4 as i32 as u8,
-    0x7d as i32 as u8,
-    0x3e as i32 as u8,
-    0x7f as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x52 as i32 as u8,
-    0x83 as i32 as u8,
-    0xef as i32 as u8,
-    0x83 as i32 as u8,
-    0x79 as i32 as u8,
-    0x87 as i32 as u8,
-    0x41 as i32 as u8,
-    0x89 as i32 as u8,
-    0x86 as i32 as u8,
-    0x89 as i32 as u8,
-    0x96 as i32 as u8,
-    0x89 as i32 as u8,
-    0xbf as i32 as u8,
-    0x8a as i32 as u8,
-    0xf8 as i32 as u8,
-    0x8a as i32 as u8,
-    0xcb as i32 as u8,
-    0x8a as i32 as u8,
-    0x1 as i32 as u8,
-    0x8b as i32 as u8,
-    0xfe as i32 as u8,
-    0x8a as i32 as u8,
-    0xed as i32 as u8,
-    0x8a as i32 as u8,
-    0x39 as i32 as u8,
-    0x8b as i32 as u8,
-    0x8a as i32 as u8,
-    0x8b as i32 as u8,
-    0x8 as i32 as u8,
-    0x8d as i32 as u8,
-    0x38 as i32 as u8,
-    0x8f as i32 as u8,
-    0x72 as i32 as u8,
-    0x90 as i32 as u8,
-    0x99 as i32 as u8,
-    0x91 as i32 as u8,
-    0x76 as i32 as u8,
-    0x92 as i32 as u8,
-    0x7c as i32 as u8,
-    0x96 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x96 as i32 as u8,
-    0x56 as i32 as u8,
-    0x97 as i32 as u8,
-    0xdb as i32 as u8,
-    0x97 as i32 as u8,
-    0xff as i32 as u8,
-    0x97 as i32 as u8,
-    0xb as i32 as u8,
-    0x98 as i32 as u8,
-    0x3b as i32 as u8,
-    0x98 as i32 as u8,
-    0x12 as i32 as u8,
-    0x9b as i32 as u8,
-    0x9c as i32 as u8,
-    0x9f as i32 as u8,
-    0x4a as i32 as u8,
-    0x28 as i32 as u8,
-    0x44 as i32 as u8,
-    0x28 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x33 as i32 as u8,
-    0x9d as i32 as u8,
-    0x3b as i32 as u8,
-    0x18 as i32 as u8,
-    0x40 as i32 as u8,
-    0x39 as i32 as u8,
-    0x40 as i32 as u8,
-    0x49 as i32 as u8,
-    0x52 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5c as i32 as u8,
-    0xd3 as i32 as u8,
-    0x7e as i32 as u8,
-    0x43 as i32 as u8,
-    0x9f as i32 as u8,
-    0x8e as i32 as u8,
-    0x9f as i32 as u8,
-    0x2a as i32 as u8,
-    0xa0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x66 as i32 as u8,
-    0x66 as i32 as u8,
-    0x66 as i32 as u8,
-    0x69 as i32 as u8,
-    0x66 as i32 as u8,
-    0x6c as i32 as u8,
-    0x66 as i32 as u8,
-    0x66 as i32 as u8,
-    0x69 as i32 as u8,
-    0x66 as i32 as u8,
-    0x66 as i32 as u8,
-    0x6c as i32 as u8,
-    0x7f as i32 as u8,
-    0x1 as i32 as u8,
-    0x74 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0x74 as i32 as u8,
-    0x65 as i32 as u8,
-    0x5 as i32 as u8,
-    0xf as i32 as u8,
-    0x11 as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0x19 as i32 as u8,
-    0x11 as i32 as u8,
-    0xf as i32 as u8,
-    0x8 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0xb as i32 as u8,
-    0xc as i32 as u8,
-    0xd as i32 as u8,
-    0x18 as i32 as u8,
-    0x1a as i32 as u8,
-    0xe9 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x49 as i32 as u8,
-    0xfb as i32 as u8,
-    0xc1 as i32 as u8,
-    0x5 as i32 as u8,
-    0x49 as i32 as u8,
-    0xfb as i32 as u8,
-    0xc2 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xbc as i32 as u8,
-    0x5 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x5 as i32 as u8,
-    0xbc as i32 as u8,
-    0x5 as i32 as u8,
-    0xde as i32 as u8,
-    0x5 as i32 as u8,
-    0xbc as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xbc as i32 as u8,
-    0x5 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x5 as i32 as u8,
-    0xbc as i32 as u8,
-    0x5 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x5 as i32 as u8,
-    0x2d as i32 as u8,
-    0x3 as i32 as u8,
-    0x2e as i32 as u8,
-    0x3 as i32 as u8,
-    0x2f as i32 as u8,
-    0x3 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3 as i32 as u8,
-    0x31 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1c as i32 as u8,
-    0 as i32 as u8,
-    0x18 as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2b as i32 as u8,
-    0x6 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xdc as i32 as u8,
-    0x5 as i32 as u8,
-    0x71 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0xf as i32 as u8,
-    0xf as i32 as u8,
-    0xf as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0xe as i32 as u8,
-    0xe as i32 as u8,
-    0xe as i32 as u8,
-    0xe as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x33 as i32 as u8,
-    0x33 as i32 as u8,
-    0x33 as i32 as u8,
-    0x33 as i32 as u8,
-    0x35 as i32 as u8,
-    0x35 as i32 as u8,
-    0x35 as i32 as u8,
-    0x35 as i32 as u8,
-    0x13 as i32 as u8,
-    0x13 as i32 as u8,
-    0x13 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x15 as i32 as u8,
-    0x15 as i32 as u8,
-    0x15 as i32 as u8,
-    0x15 as i32 as u8,
-    0x16 as i32 as u8,
-    0x16 as i32 as u8,
-    0x16 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1c as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x17 as i32 as u8,
-    0x17 as i32 as u8,
-    0x27 as i32 as u8,
-    0x27 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x38 as i32 as u8,
-    0x38 as i32 as u8,
-    0x38 as i32 as u8,
-    0x38 as i32 as u8,
-    0x3e as i32 as u8,
-    0x3e as i32 as u8,
-    0x3e as i32 as u8,
-    0x3e as i32 as u8,
-    0x42 as i32 as u8,
-    0x42 as i32 as u8,
-    0x42 as i32 as u8,
-    0x42 as i32 as u8,
-    0x40 as i32 as u8,
-    0x40 as i32 as u8,
-    0x40 as i32 as u8,
-    0x40 as i32 as u8,
-    0x49 as i32 as u8,
-    0x49 as i32 as u8,
-    0x4a as i32 as u8,
-    0x4a as i32 as u8,
-    0x4a as i32 as u8,
-    0x4a as i32 as u8,
-    0x4f as i32 as u8,
-    0x4f as i32 as u8,
-    0x50 as i32 as u8,
-    0x50 as i32 as u8,
-    0x50 as i32 as u8,
-    0x50 as i32 as u8,
-    0x4d as i32 as u8,
-    0x4d as i32 as u8,
-    0x4d as i32 as u8,
-    0x4d as i32 as u8,
-    0x61 as i32 as u8,
-    0x61 as i32 as u8,
-    0x62 as i32 as u8,
-    0x62 as i32 as u8,
-    0x49 as i32 as u8,
-    0x6 as i32 as u8,
-    0x64 as i32 as u8,
-    0x64 as i32 as u8,
-    0x64 as i32 as u8,
-    0x64 as i32 as u8,
-    0x7e as i32 as u8,
-    0x7e as i32 as u8,
-    0x7d as i32 as u8,
-    0x7d as i32 as u8,
-    0x7f as i32 as u8,
-    0x7f as i32 as u8,
-    0x2e as i32 as u8,
-    0x82 as i32 as u8,
-    0x82 as i32 as u8,
-    0x7c as i32 as u8,
-    0x7c as i32 as u8,
-    0x80 as i32 as u8,
-    0x80 as i32 as u8,
-    0x87 as i32 as u8,
-    0x87 as i32 as u8,
-    0x87 as i32 as u8,
-    0x87 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xaf as i32 as u8,
-    0 as i32 as u8,
-    0xaf as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0xa1 as i32 as u8,
-    0 as i32 as u8,
-    0xa1 as i32 as u8,
-    0 as i32 as u8,
-    0xa0 as i32 as u8,
-    0 as i32 as u8,
-    0xa0 as i32 as u8,
-    0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0xaa as i32 as u8,
-    0 as i32 as u8,
-    0xaa as i32 as u8,
-    0 as i32 as u8,
-    0xaa as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0xcc as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0x24 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x7 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2 as i32 as u8,
-    0x23 as i32 as u8,
-    0x2 as i32 as u8,
-    0x24 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4 as i32 as u8,
-    0x7 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4 as i32 as u8,
-    0x24 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1f as i32 as u8,
-    0x5 as i32 as u8,
-    0x23 as i32 as u8,
-    0x5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x1f as i32 as u8,
-    0x8 as i32 as u8,
-    0x6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0xd as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x7 as i32 as u8,
-    0xd as i32 as u8,
-    0x8 as i32 as u8,
-    0xd as i32 as u8,
-    0x1f as i32 as u8,
-    0xf as i32 as u8,
-    0x7 as i32 as u8,
-    0xf as i32 as u8,
-    0x1f as i32 as u8,
-    0x10 as i32 as u8,
-    0x6 as i32 as u8,
-    0x10 as i32 as u8,
-    0x7 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1f as i32 as u8,
-    0x11 as i32 as u8,
-    0x7 as i32 as u8,
-    0x11 as i32 as u8,
-    0x1f as i32 as u8,
-    0x12 as i32 as u8,
-    0x1f as i32 as u8,
-    0x13 as i32 as u8,
-    0x6 as i32 as u8,
-    0x13 as i32 as u8,
-    0x1f as i32 as u8,
-    0x14 as i32 as u8,
-    0x6 as i32 as u8,
-    0x14 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1b as i32 as u8,
-    0x6 as i32 as u8,
-    0x1b as i32 as u8,
-    0x7 as i32 as u8,
-    0x1b as i32 as u8,
-    0x8 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1f as i32 as u8,
-    0x1b as i32 as u8,
-    0x23 as i32 as u8,
-    0x1b as i32 as u8,
-    0x24 as i32 as u8,
-    0x1c as i32 as u8,
-    0x7 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1f as i32 as u8,
-    0x1c as i32 as u8,
-    0x23 as i32 as u8,
-    0x1c as i32 as u8,
-    0x24 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1 as i32 as u8,
-    0x1d as i32 as u8,
-    0x6 as i32 as u8,
-    0x1d as i32 as u8,
-    0x7 as i32 as u8,
-    0x1d as i32 as u8,
-    0x8 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x1d as i32 as u8,
-    0x1f as i32 as u8,
-    0x1d as i32 as u8,
-    0x23 as i32 as u8,
-    0x1d as i32 as u8,
-    0x24 as i32 as u8,
-    0x1e as i32 as u8,
-    0x6 as i32 as u8,
-    0x1e as i32 as u8,
-    0x7 as i32 as u8,
-    0x1e as i32 as u8,
-    0x8 as i32 as u8,
-    0x1e as i32 as u8,
-    0x1f as i32 as u8,
-    0x1e as i32 as u8,
-    0x23 as i32 as u8,
-    0x1e as i32 as u8,
-    0x24 as i32 as u8,
-    0x1f as i32 as u8,
-    0x6 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7 as i32 as u8,
-    0x1f as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1f as i32 as u8,
-    0x1f as i32 as u8,
-    0x23 as i32 as u8,
-    0x1f as i32 as u8,
-    0x24 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x7 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0x23 as i32 as u8,
-    0x20 as i32 as u8,
-    0x24 as i32 as u8,
-    0x21 as i32 as u8,
-    0x6 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x23 as i32 as u8,
-    0x21 as i32 as u8,
-    0x24 as i32 as u8,
-    0x24 as i32 as u8,
-    0x6 as i32 as u8,
-    0x24 as i32 as u8,
-    0x7 as i32 as u8,
-    0x24 as i32 as u8,
-    0x8 as i32 as u8,
-    0x24 as i32 as u8,
-    0x1f as i32 as u8,
-    0x24 as i32 as u8,
-    0x23 as i32 as u8,
-    0x24 as i32 as u8,
-    0x24 as i32 as u8,
-    0xa as i32 as u8,
-    0x4a as i32 as u8,
-    0xb as i32 as u8,
-    0x4a as i32 as u8,
-    0x23 as i32 as u8,
-    0x4a as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x4c as i32 as u8,
-    0x6 as i32 as u8,
-    0x51 as i32 as u8,
-    0x6 as i32 as u8,
-    0x51 as i32 as u8,
-    0x6 as i32 as u8,
-    0xff as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x26 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0x24 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x2 as i32 as u8,
-    0xc as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x2 as i32 as u8,
-    0x23 as i32 as u8,
-    0x2 as i32 as u8,
-    0x24 as i32 as u8,
-    0x4 as i32 as u8,
-    0xb as i32 as u8,
-    0x4 as i32 as u8,
-    0xc as i32 as u8,
-    0x4 as i32 as u8,
-    0x1f as i32 as u8,
-    0x26 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x4 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4 as i32 as u8,
-    0x24 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb as i32 as u8,
-    0x5 as i32 as u8,
-    0xc as i32 as u8,
-    0x5 as i32 as u8,
-    0x1f as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0x23 as i32 as u8,
-    0x5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x1b as i32 as u8,
-    0x23 as i32 as u8,
-    0x1b as i32 as u8,
-    0x24 as i32 as u8,
-    0x1c as i32 as u8,
-    0x23 as i32 as u8,
-    0x1c as i32 as u8,
-    0x24 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x1d as i32 as u8,
-    0x1f as i32 as u8,
-    0x1d as i32 as u8,
-    0x23 as i32 as u8,
-    0x1d as i32 as u8,
-    0x24 as i32 as u8,
-    0x1e as i32 as u8,
-    0x1f as i32 as u8,
-    0x1e as i32 as u8,
-    0x23 as i32 as u8,
-    0x1e as i32 as u8,
-    0x24 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0xb as i32 as u8,
-    0x20 as i32 as u8,
-    0xc as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x23 as i32 as u8,
-    0x20 as i32 as u8,
-    0x24 as i32 as u8,
-    0x23 as i32 as u8,
-    0x4a as i32 as u8,
-    0x24 as i32 as u8,
-    0xb as i32 as u8,
-    0x24 as i32 as u8,
-    0xc as i32 as u8,
-    0x24 as i32 as u8,
-    0x1f as i32 as u8,
-    0x24 as i32 as u8,
-    0x20 as i32 as u8,
-    0x24 as i32 as u8,
-    0x23 as i32 as u8,
-    0x24 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x7 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4 as i32 as u8,
-    0x7 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x21 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1f as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x1f as i32 as u8,
-    0x8 as i32 as u8,
-    0x6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0xd as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x7 as i32 as u8,
-    0xd as i32 as u8,
-    0x8 as i32 as u8,
-    0xd as i32 as u8,
-    0x1f as i32 as u8,
-    0xf as i32 as u8,
-    0x7 as i32 as u8,
-    0xf as i32 as u8,
-    0x8 as i32 as u8,
-    0xf as i32 as u8,
-    0x1f as i32 as u8,
-    0x10 as i32 as u8,
-    0x6 as i32 as u8,
-    0x10 as i32 as u8,
-    0x7 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1f as i32 as u8,
-    0x11 as i32 as u8,
-    0x7 as i32 as u8,
-    0x12 as i32 as u8,
-    0x1f as i32 as u8,
-    0x13 as i32 as u8,
-    0x6 as i32 as u8,
-    0x13 as i32 as u8,
-    0x1f as i32 as u8,
-    0x14 as i32 as u8,
-    0x6 as i32 as u8,
-    0x14 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1b as i32 as u8,
-    0x6 as i32 as u8,
-    0x1b as i32 as u8,
-    0x7 as i32 as u8,
-    0x1b as i32 as u8,
-    0x8 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1f as i32 as u8,
-    0x1c as i32 as u8,
-    0x7 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1f as i32 as u8,
-    0x1d as i32 as u8,
-    0x6 as i32 as u8,
-    0x1d as i32 as u8,
-    0x7 as i32 as u8,
-    0x1d as i32 as u8,
-    0x8 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x1d as i32 as u8,
-    0x1f as i32 as u8,
-    0x1e as i32 as u8,
-    0x6 as i32 as u8,
-    0x1e as i32 as u8,
-    0x7 as i32 as u8,
-    0x1e as i32 as u8,
-    0x8 as i32 as u8,
-    0x1e as i32 as u8,
-    0x1f as i32 as u8,
-    0x1e as i32 as u8,
-    0x21 as i32 as u8,
-    0x1f as i32 as u8,
-    0x6 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7 as i32 as u8,
-    0x1f as i32 as u8,
-    0x8 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x7 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0x21 as i32 as u8,
-    0x21 as i32 as u8,
-    0x6 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x4a as i32 as u8,
-    0x24 as i32 as u8,
-    0x6 as i32 as u8,
-    0x24 as i32 as u8,
-    0x7 as i32 as u8,
-    0x24 as i32 as u8,
-    0x8 as i32 as u8,
-    0x24 as i32 as u8,
-    0x1f as i32 as u8,
-    0x24 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x21 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1f as i32 as u8,
-    0x5 as i32 as u8,
-    0x21 as i32 as u8,
-    0xd as i32 as u8,
-    0x1f as i32 as u8,
-    0xd as i32 as u8,
-    0x21 as i32 as u8,
-    0xe as i32 as u8,
-    0x1f as i32 as u8,
-    0xe as i32 as u8,
-    0x21 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x1d as i32 as u8,
-    0x1f as i32 as u8,
-    0x1e as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0x21 as i32 as u8,
-    0x24 as i32 as u8,
-    0x1f as i32 as u8,
-    0x24 as i32 as u8,
-    0x21 as i32 as u8,
-    0x40 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6 as i32 as u8,
-    0x51 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x10 as i32 as u8,
-    0x22 as i32 as u8,
-    0x10 as i32 as u8,
-    0x23 as i32 as u8,
-    0x12 as i32 as u8,
-    0x22 as i32 as u8,
-    0x12 as i32 as u8,
-    0x23 as i32 as u8,
-    0x13 as i32 as u8,
-    0x22 as i32 as u8,
-    0x13 as i32 as u8,
-    0x23 as i32 as u8,
-    0xc as i32 as u8,
-    0x22 as i32 as u8,
-    0xc as i32 as u8,
-    0x23 as i32 as u8,
-    0xd as i32 as u8,
-    0x22 as i32 as u8,
-    0xd as i32 as u8,
-    0x23 as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x6 as i32 as u8,
-    0x23 as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5 as i32 as u8,
-    0x23 as i32 as u8,
-    0x7 as i32 as u8,
-    0x22 as i32 as u8,
-    0x7 as i32 as u8,
-    0x23 as i32 as u8,
-    0xe as i32 as u8,
-    0x22 as i32 as u8,
-    0xe as i32 as u8,
-    0x23 as i32 as u8,
-    0xf as i32 as u8,
-    0x22 as i32 as u8,
-    0xf as i32 as u8,
-    0x23 as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x7 as i32 as u8,
-    0xd as i32 as u8,
-    0x1e as i32 as u8,
-    0xd as i32 as u8,
-    0xa as i32 as u8,
-    0xc as i32 as u8,
-    0xa as i32 as u8,
-    0xe as i32 as u8,
-    0xa as i32 as u8,
-    0xf as i32 as u8,
-    0xa as i32 as u8,
-    0x10 as i32 as u8,
-    0x22 as i32 as u8,
-    0x10 as i32 as u8,
-    0x23 as i32 as u8,
-    0x12 as i32 as u8,
-    0x22 as i32 as u8,
-    0x12 as i32 as u8,
-    0x23 as i32 as u8,
-    0x13 as i32 as u8,
-    0x22 as i32 as u8,
-    0x13 as i32 as u8,
-    0x23 as i32 as u8,
-    0xc as i32 as u8,
-    0x22 as i32 as u8,
-    0xc as i32 as u8,
-    0x23 as i32 as u8,
-    0xd as i32 as u8,
-    0x22 as i32 as u8,
-    0xd as i32 as u8,
-    0x23 as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x6 as i32 as u8,
-    0x23 as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5 as i32 as u8,
-    0x23 as i32 as u8,
-    0x7 as i32 as u8,
-    0x22 as i32 as u8,
-    0x7 as i32 as u8,
-    0x23 as i32 as u8,
-    0xe as i32 as u8,
-    0x22 as i32 as u8,
-    0xe as i32 as u8,
-    0x23 as i32 as u8,
-    0xf as i32 as u8,
-    0x22 as i32 as u8,
-    0xf as i32 as u8,
-    0x23 as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x7 as i32 as u8,
-    0xd as i32 as u8,
-    0x1e as i32 as u8,
-    0xd as i32 as u8,
-    0xa as i32 as u8,
-    0xc as i32 as u8,
-    0xa as i32 as u8,
-    0xe as i32 as u8,
-    0xa as i32 as u8,
-    0xf as i32 as u8,
-    0xa as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x7 as i32 as u8,
-    0xd as i32 as u8,
-    0x1e as i32 as u8,
-    0xc as i32 as u8,
-    0x20 as i32 as u8,
-    0xd as i32 as u8,
-    0x20 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1e as i32 as u8,
-    0xc as i32 as u8,
-    0x5 as i32 as u8,
-    0xc as i32 as u8,
-    0x6 as i32 as u8,
-    0xc as i32 as u8,
-    0x7 as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x7 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1e as i32 as u8,
-    0x11 as i32 as u8,
-    0x1e as i32 as u8,
-    0 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x24 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x4 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x20 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1f as i32 as u8,
-    0x9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x9 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x9 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x9 as i32 as u8,
-    0x1b as i32 as u8,
-    0x2 as i32 as u8,
-    0x9 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0x9 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xb as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xa as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0xa as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0xa as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa as i32 as u8,
-    0x1b as i32 as u8,
-    0x4 as i32 as u8,
-    0xa as i32 as u8,
-    0x1b as i32 as u8,
-    0x4 as i32 as u8,
-    0xa as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xa as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xc as i32 as u8,
-    0x3 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0xc as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0xd as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0xd as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0xd as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xd as i32 as u8,
-    0x1b as i32 as u8,
-    0x20 as i32 as u8,
-    0xf as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0xf as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xf as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0xf as i32 as u8,
-    0x1b as i32 as u8,
-    0x1f as i32 as u8,
-    0x10 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0x10 as i32 as u8,
-    0x1b as i32 as u8,
-    0x20 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1f as i32 as u8,
-    0x17 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0x17 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0x18 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x18 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0x1a as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1a as i32 as u8,
-    0x3 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1a as i32 as u8,
-    0x3 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1a as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1a as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1a as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1a as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1a as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0x3 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1b as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1b as i32 as u8,
-    0x4 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1b as i32 as u8,
-    0x4 as i32 as u8,
-    0x1b as i32 as u8,
-    0x28 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1d as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1d as i32 as u8,
-    0x4 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x5 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x5 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x21 as i32 as u8,
-    0x1e as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1e as i32 as u8,
-    0x1d as i32 as u8,
-    0x21 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x21 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x21 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x21 as i32 as u8,
-    0xb as i32 as u8,
-    0x6 as i32 as u8,
-    0x21 as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0xc as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0xe as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1c as i32 as u8,
-    0x4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1e as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1d as i32 as u8,
-    0x5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x5 as i32 as u8,
-    0x1d as i32 as u8,
-    0x11 as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1d as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1c as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1b as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x1c as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1d as i32 as u8,
-    0x4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1b as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x11 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1d as i32 as u8,
-    0xd as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0xb as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1e as i32 as u8,
-    0x4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x35 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0x9d as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0x9d as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1 as i32 as u8,
-    0xa as i32 as u8,
-    0x1e as i32 as u8,
-    0x6 as i32 as u8,
-    0x1e as i32 as u8,
-    0x8 as i32 as u8,
-    0xe as i32 as u8,
-    0x1d as i32 as u8,
-    0x12 as i32 as u8,
-    0x1e as i32 as u8,
-    0xa as i32 as u8,
-    0xc as i32 as u8,
-    0x21 as i32 as u8,
-    0x1d as i32 as u8,
-    0x12 as i32 as u8,
-    0x1d as i32 as u8,
-    0x23 as i32 as u8,
-    0x20 as i32 as u8,
-    0x21 as i32 as u8,
-    0xc as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x35 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0x14 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe as i32 as u8,
-    0x1d as i32 as u8,
-    0x22 as i32 as u8,
-    0xff as i32 as u8,
-    0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x1d as i32 as u8,
-    0x20 as i32 as u8,
-    0xff as i32 as u8,
-    0x12 as i32 as u8,
-    0x1d as i32 as u8,
-    0x23 as i32 as u8,
-    0x20 as i32 as u8,
-    0xff as i32 as u8,
-    0x21 as i32 as u8,
-    0xc as i32 as u8,
-    0x1d as i32 as u8,
-    0x1e as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1d as i32 as u8,
-    0xff as i32 as u8,
-    0x5 as i32 as u8,
-    0x1d as i32 as u8,
-    0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0xa as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3a as i32 as u8,
-    0 as i32 as u8,
-    0x3b as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x30 as i32 as u8,
-    0x17 as i32 as u8,
-    0x30 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0x13 as i32 as u8,
-    0x20 as i32 as u8,
-    0x12 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0x5f as i32 as u8,
-    0x28 as i32 as u8,
-    0x29 as i32 as u8,
-    0x7b as i32 as u8,
-    0x7d as i32 as u8,
-    0x8 as i32 as u8,
-    0x30 as i32 as u8,
-    0xc as i32 as u8,
-    0xd as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x5b as i32 as u8,
-    0 as i32 as u8,
-    0x5d as i32 as u8,
-    0 as i32 as u8,
-    0x3e as i32 as u8,
-    0x20 as i32 as u8,
-    0x3e as i32 as u8,
-    0x20 as i32 as u8,
-    0x3e as i32 as u8,
-    0x20 as i32 as u8,
-    0x3e as i32 as u8,
-    0x20 as i32 as u8,
-    0x5f as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3b as i32 as u8,
-    0 as i32 as u8,
-    0x3a as i32 as u8,
-    0 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x20 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x7b as i32 as u8,
-    0 as i32 as u8,
-    0x7d as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x30 as i32 as u8,
-    0x15 as i32 as u8,
-    0x30 as i32 as u8,
-    0x23 as i32 as u8,
-    0x26 as i32 as u8,
-    0x2a as i32 as u8,
-    0x2b as i32 as u8,
-    0x2d as i32 as u8,
-    0x3c as i32 as u8,
-    0x3e as i32 as u8,
-    0x3d as i32 as u8,
-    0 as i32 as u8,
-    0x5c as i32 as u8,
-    0x24 as i32 as u8,
-    0x25 as i32 as u8,
-    0x40 as i32 as u8,
-    0x40 as i32 as u8,
-    0x6 as i32 as u8,
-    0xff as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0xff as i32 as u8,
-    0xc as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x4d as i32 as u8,
-    0x6 as i32 as u8,
-    0x40 as i32 as u8,
-    0x6 as i32 as u8,
-    0xff as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xe as i32 as u8,
-    0xff as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0xff as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0x10 as i32 as u8,
-    0xff as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xff as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x21 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x7 as i32 as u8,
-    0x7 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0xa as i32 as u8,
-    0xb as i32 as u8,
-    0xb as i32 as u8,
-    0xb as i32 as u8,
-    0xb as i32 as u8,
-    0xc as i32 as u8,
-    0xc as i32 as u8,
-    0xc as i32 as u8,
-    0xc as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xe as i32 as u8,
-    0xe as i32 as u8,
-    0xf as i32 as u8,
-    0xf as i32 as u8,
-    0x10 as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x13 as i32 as u8,
-    0x13 as i32 as u8,
-    0x13 as i32 as u8,
-    0x14 as i32 as u8,
-    0x14 as i32 as u8,
-    0x14 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x15 as i32 as u8,
-    0x15 as i32 as u8,
-    0x15 as i32 as u8,
-    0x16 as i32 as u8,
-    0x16 as i32 as u8,
-    0x16 as i32 as u8,
-    0x16 as i32 as u8,
-    0x17 as i32 as u8,
-    0x17 as i32 as u8,
-    0x17 as i32 as u8,
-    0x17 as i32 as u8,
-    0x18 as i32 as u8,
-    0x18 as i32 as u8,
-    0x18 as i32 as u8,
-    0x18 as i32 as u8,
-    0x19 as i32 as u8,
-    0x19 as i32 as u8,
-    0x19 as i32 as u8,
-    0x19 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0x21 as i32 as u8,
-    0x21 as i32 as u8,
-    0x21 as i32 as u8,
-    0x21 as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x22 as i32 as u8,
-    0x23 as i32 as u8,
-    0x23 as i32 as u8,
-    0x23 as i32 as u8,
-    0x23 as i32 as u8,
-    0x24 as i32 as u8,
-    0x24 as i32 as u8,
-    0x24 as i32 as u8,
-    0x24 as i32 as u8,
-    0x25 as i32 as u8,
-    0x25 as i32 as u8,
-    0x25 as i32 as u8,
-    0x25 as i32 as u8,
-    0x26 as i32 as u8,
-    0x26 as i32 as u8,
-    0x26 as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0x27 as i32 as u8,
-    0x28 as i32 as u8,
-    0x28 as i32 as u8,
-    0x29 as i32 as u8,
-    0x29 as i32 as u8,
-    0x29 as i32 as u8,
-    0x29 as i32 as u8,
-    0x22 as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1 as i32 as u8,
-    0x22 as i32 as u8,
-    0x3 as i32 as u8,
-    0x22 as i32 as u8,
-    0x3 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x29 as i32 as u8,
-    0x1 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0xfa as i32 as u8,
-    0xf1 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0xa4 as i32 as u8,
-    0xa6 as i32 as u8,
-    0xa8 as i32 as u8,
-    0xe2 as i32 as u8,
-    0xe4 as i32 as u8,
-    0xe6 as i32 as u8,
-    0xc2 as i32 as u8,
-    0xfb as i32 as u8,
-    0xa1 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xa5 as i32 as u8,
-    0xa7 as i32 as u8,
-    0xa9 as i32 as u8,
-    0xaa as i32 as u8,
-    0xac as i32 as u8,
-    0xae as i32 as u8,
-    0xb0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0xb4 as i32 as u8,
-    0xb6 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xba as i32 as u8,
-    0xbc as i32 as u8,
-    0xbe as i32 as u8,
-    0xc0 as i32 as u8,
-    0xc3 as i32 as u8,
-    0xc5 as i32 as u8,
-    0xc7 as i32 as u8,
-    0xc9 as i32 as u8,
-    0xca as i32 as u8,
-    0xcb as i32 as u8,
-    0xcc as i32 as u8,
-    0xcd as i32 as u8,
-    0xce as i32 as u8,
-    0xd1 as i32 as u8,
-    0xd4 as i32 as u8,
-    0xd7 as i32 as u8,
-    0xda as i32 as u8,
-    0xdd as i32 as u8,
-    0xde as i32 as u8,
-    0xdf as i32 as u8,
-    0xe0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0xe3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe7 as i32 as u8,
-    0xe8 as i32 as u8,
-    0xe9 as i32 as u8,
-    0xea as i32 as u8,
-    0xeb as i32 as u8,
-    0xec as i32 as u8,
-    0xee as i32 as u8,
-    0xf2 as i32 as u8,
-    0x98 as i32 as u8,
-    0x99 as i32 as u8,
-    0x31 as i32 as u8,
-    0x31 as i32 as u8,
-    0x4f as i32 as u8,
-    0x31 as i32 as u8,
-    0x55 as i32 as u8,
-    0x31 as i32 as u8,
-    0x5b as i32 as u8,
-    0x31 as i32 as u8,
-    0x61 as i32 as u8,
-    0x31 as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0 as i32 as u8,
-    0xac as i32 as u8,
-    0 as i32 as u8,
-    0xaf as i32 as u8,
-    0 as i32 as u8,
-    0xa6 as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x25 as i32 as u8,
-    0x90 as i32 as u8,
-    0x21 as i32 as u8,
-    0x91 as i32 as u8,
-    0x21 as i32 as u8,
-    0x92 as i32 as u8,
-    0x21 as i32 as u8,
-    0x93 as i32 as u8,
-    0x21 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x25 as i32 as u8,
-    0xcb as i32 as u8,
-    0x25 as i32 as u8,
-    0x99 as i32 as u8,
-    0x10 as i32 as u8,
-    0xba as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9b as i32 as u8,
-    0x10 as i32 as u8,
-    0xba as i32 as u8,
-    0x10 as i32 as u8,
-    0x5 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x10 as i32 as u8,
-    0xba as i32 as u8,
-    0x10 as i32 as u8,
-    0x5 as i32 as u8,
-    0x31 as i32 as u8,
-    0x11 as i32 as u8,
-    0x27 as i32 as u8,
-    0x11 as i32 as u8,
-    0x32 as i32 as u8,
-    0x11 as i32 as u8,
-    0x27 as i32 as u8,
-    0x11 as i32 as u8,
-    0x55 as i32 as u8,
-    0x47 as i32 as u8,
-    0x13 as i32 as u8,
-    0x3e as i32 as u8,
-    0x13 as i32 as u8,
-    0x47 as i32 as u8,
-    0x13 as i32 as u8,
-    0x57 as i32 as u8,
-    0x13 as i32 as u8,
-    0x55 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x14 as i32 as u8,
-    0xba as i32 as u8,
-    0x14 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x14 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x14 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x14 as i32 as u8,
-    0xbd as i32 as u8,
-    0x14 as i32 as u8,
-    0x55 as i32 as u8,
-    0x50 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x15 as i32 as u8,
-    0xaf as i32 as u8,
-    0x15 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x15 as i32 as u8,
-    0xaf as i32 as u8,
-    0x15 as i32 as u8,
-    0x55 as i32 as u8,
-    0x35 as i32 as u8,
-    0x19 as i32 as u8,
-    0x30 as i32 as u8,
-    0x19 as i32 as u8,
-    0x5 as i32 as u8,
-    0x57 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x65 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x58 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x65 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6e as i32 as u8,
-    0xd1 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x70 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x71 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x72 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x55 as i32 as u8,
-    0x55 as i32 as u8,
-    0x55 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb9 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x65 as i32 as u8,
-    0xd1 as i32 as u8,
-    0xba as i32 as u8,
-    0xd1 as i32 as u8,
-    0x65 as i32 as u8,
-    0xd1 as i32 as u8,
-    0xbb as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6e as i32 as u8,
-    0xd1 as i32 as u8,
-    0xbc as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6e as i32 as u8,
-    0xd1 as i32 as u8,
-    0xbb as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6f as i32 as u8,
-    0xd1 as i32 as u8,
-    0xbc as i32 as u8,
-    0xd1 as i32 as u8,
-    0x6f as i32 as u8,
-    0xd1 as i32 as u8,
-    0x55 as i32 as u8,
-    0x55 as i32 as u8,
-    0x55 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x69 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x43 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4a as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x4f as i32 as u8,
-    0x50 as i32 as u8,
-    0x51 as i32 as u8,
-    0 as i32 as u8,
-    0x53 as i32 as u8,
-    0x54 as i32 as u8,
-    0x55 as i32 as u8,
-    0x56 as i32 as u8,
-    0x57 as i32 as u8,
-    0x58 as i32 as u8,
-    0x59 as i32 as u8,
-    0x5a as i32 as u8,
-    0x61 as i32 as u8,
-    0x62 as i32 as u8,
-    0x63 as i32 as u8,
-    0x64 as i32 as u8,
-    0 as i32 as u8,
-    0x66 as i32 as u8,
-    0x68 as i32 as u8,
-    0 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x44 as i32 as u8,
-    0x45 as i32 as u8,
-    0x46 as i32 as u8,
-    0x47 as i32 as u8,
-    0x4a as i32 as u8,
-    0 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x44 as i32 as u8,
-    0x45 as i32 as u8,
-    0x46 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x49 as i32 as u8,
-    0x4a as i32 as u8,
-    0x4b as i32 as u8,
-    0x4c as i32 as u8,
-    0x4d as i32 as u8,
-    0 as i32 as u8,
-    0x4f as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x31 as i32 as u8,
-    0x1 as i32 as u8,
-    0x37 as i32 as u8,
-    0x2 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb as i32 as u8,
-    0xc as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x5 as i32 as u8,
-    0x8 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0x1e as i32 as u8,
-    0x8 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd as i32 as u8,
-    0x20 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1b as i32 as u8,
-    0x1c as i32 as u8,
-    0x9 as i32 as u8,
-    0xf as i32 as u8,
-    0x17 as i32 as u8,
-    0xb as i32 as u8,
-    0x18 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0xc as i32 as u8,
-    0xe as i32 as u8,
-    0x10 as i32 as u8,
-    0x44 as i32 as u8,
-    0x90 as i32 as u8,
-    0x77 as i32 as u8,
-    0x45 as i32 as u8,
-    0x28 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2c as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0x33 as i32 as u8,
-    0x6 as i32 as u8,
-    0x17 as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe as i32 as u8,
-    0x2 as i32 as u8,
-    0xf as i32 as u8,
-    0x34 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0x2b as i32 as u8,
-    0x6 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3a as i32 as u8,
-    0x6 as i32 as u8,
-    0x2d as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4a as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x44 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x6 as i32 as u8,
-    0x33 as i32 as u8,
-    0x6 as i32 as u8,
-    0x39 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x35 as i32 as u8,
-    0x6 as i32 as u8,
-    0x42 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x34 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3a as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xba as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x6f as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2c as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2d as i32 as u8,
-    0x6 as i32 as u8,
-    0x37 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4a as i32 as u8,
-    0x6 as i32 as u8,
-    0x43 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0x6 as i32 as u8,
-    0x46 as i32 as u8,
-    0x6 as i32 as u8,
-    0x33 as i32 as u8,
-    0x6 as i32 as u8,
-    0x39 as i32 as u8,
-    0x6 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6 as i32 as u8,
-    0x35 as i32 as u8,
-    0x6 as i32 as u8,
-    0x42 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x34 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0x2b as i32 as u8,
-    0x6 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0x6 as i32 as u8,
-    0x38 as i32 as u8,
-    0x6 as i32 as u8,
-    0x3a as i32 as u8,
-    0x6 as i32 as u8,
-    0x6e as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x5 as i32 as u8,
-    0x8 as i32 as u8,
-    0x20 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb as i32 as u8,
-    0x6 as i32 as u8,
-    0x10 as i32 as u8,
-    0x23 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1b as i32 as u8,
-    0x1c as i32 as u8,
-    0x9 as i32 as u8,
-    0xf as i32 as u8,
-    0x17 as i32 as u8,
-    0xb as i32 as u8,
-    0x18 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0xc as i32 as u8,
-    0xe as i32 as u8,
-    0x10 as i32 as u8,
-    0x28 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2c as i32 as u8,
-    0x6 as i32 as u8,
-    0x2f as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x48 as i32 as u8,
-    0x6 as i32 as u8,
-    0x32 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2d as i32 as u8,
-    0x6 as i32 as u8,
-    0x37 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4a as i32 as u8,
-    0x6 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0x1a as i32 as u8,
-    0x1b as i32 as u8,
-    0x1c as i32 as u8,
-    0x9 as i32 as u8,
-    0xf as i32 as u8,
-    0x17 as i32 as u8,
-    0xb as i32 as u8,
-    0x18 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0xc as i32 as u8,
-    0xe as i32 as u8,
-    0x10 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2e as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x30 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x15 as i32 as u8,
-    0x30 as i32 as u8,
-    0x43 as i32 as u8,
-    0x52 as i32 as u8,
-    0x43 as i32 as u8,
-    0x44 as i32 as u8,
-    0x57 as i32 as u8,
-    0x5a as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x48 as i32 as u8,
-    0x56 as i32 as u8,
-    0x4d as i32 as u8,
-    0x56 as i32 as u8,
-    0x53 as i32 as u8,
-    0x44 as i32 as u8,
-    0x53 as i32 as u8,
-    0x53 as i32 as u8,
-    0x50 as i32 as u8,
-    0x50 as i32 as u8,
-    0x56 as i32 as u8,
-    0x57 as i32 as u8,
-    0x43 as i32 as u8,
-    0x4d as i32 as u8,
-    0x43 as i32 as u8,
-    0x4d as i32 as u8,
-    0x44 as i32 as u8,
-    0x4d as i32 as u8,
-    0x52 as i32 as u8,
-    0x44 as i32 as u8,
-    0x4a as i32 as u8,
-    0x4b as i32 as u8,
-    0x30 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x68 as i32 as u8,
-    0x68 as i32 as u8,
-    0x4b as i32 as u8,
-    0x62 as i32 as u8,
-    0x57 as i32 as u8,
-    0x5b as i32 as u8,
-    0xcc as i32 as u8,
-    0x53 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x30 as i32 as u8,
-    0x8c as i32 as u8,
-    0x4e as i32 as u8,
-    0x1a as i32 as u8,
-    0x59 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x89 as i32 as u8,
-    0x29 as i32 as u8,
-    0x59 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x4e as i32 as u8,
-    0x20 as i32 as u8,
-    0x66 as i32 as u8,
-    0x21 as i32 as u8,
-    0x71 as i32 as u8,
-    0x99 as i32 as u8,
-    0x65 as i32 as u8,
-    0x4d as i32 as u8,
-    0x52 as i32 as u8,
-    0x8c as i32 as u8,
-    0x5f as i32 as u8,
-    0x8d as i32 as u8,
-    0x51 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x1d as i32 as u8,
-    0x52 as i32 as u8,
-    0x42 as i32 as u8,
-    0x7d as i32 as u8,
-    0x1f as i32 as u8,
-    0x75 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x8c as i32 as u8,
-    0xf0 as i32 as u8,
-    0x58 as i32 as u8,
-    0x39 as i32 as u8,
-    0x54 as i32 as u8,
-    0x14 as i32 as u8,
-    0x6f as i32 as u8,
-    0x95 as i32 as u8,
-    0x62 as i32 as u8,
-    0x55 as i32 as u8,
-    0x63 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0x9 as i32 as u8,
-    0x4e as i32 as u8,
-    0x4a as i32 as u8,
-    0x90 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x5d as i32 as u8,
-    0x2d as i32 as u8,
-    0x4e as i32 as u8,
-    0xf3 as i32 as u8,
-    0x53 as i32 as u8,
-    0x7 as i32 as u8,
-    0x63 as i32 as u8,
-    0x70 as i32 as u8,
-    0x8d as i32 as u8,
-    0x53 as i32 as u8,
-    0x62 as i32 as u8,
-    0x81 as i32 as u8,
-    0x79 as i32 as u8,
-    0x7a as i32 as u8,
-    0x7a as i32 as u8,
-    0x8 as i32 as u8,
-    0x54 as i32 as u8,
-    0x80 as i32 as u8,
-    0x6e as i32 as u8,
-    0x9 as i32 as u8,
-    0x67 as i32 as u8,
-    0x8 as i32 as u8,
-    0x67 as i32 as u8,
-    0x33 as i32 as u8,
-    0x75 as i32 as u8,
-    0x72 as i32 as u8,
-    0x52 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x55 as i32 as u8,
-    0x4d as i32 as u8,
-    0x91 as i32 as u8,
-    0x14 as i32 as u8,
-    0x30 as i32 as u8,
-    0x15 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2c as i32 as u8,
-    0x67 as i32 as u8,
-    0x9 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8c as i32 as u8,
-    0x4e as i32 as u8,
-    0x89 as i32 as u8,
-    0x5b as i32 as u8,
-    0xb9 as i32 as u8,
-    0x70 as i32 as u8,
-    0x53 as i32 as u8,
-    0x62 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x76 as i32 as u8,
-    0xdd as i32 as u8,
-    0x52 as i32 as u8,
-    0x57 as i32 as u8,
-    0x65 as i32 as u8,
-    0x97 as i32 as u8,
-    0x5f as i32 as u8,
-    0xef as i32 as u8,
-    0x53 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x4e as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4f as i32 as u8,
-    0xae as i32 as u8,
-    0x4f as i32 as u8,
-    0xbb as i32 as u8,
-    0x4f as i32 as u8,
-    0x2 as i32 as u8,
-    0x50 as i32 as u8,
-    0x7a as i32 as u8,
-    0x50 as i32 as u8,
-    0x99 as i32 as u8,
-    0x50 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x50 as i32 as u8,
-    0xcf as i32 as u8,
-    0x50 as i32 as u8,
-    0x9e as i32 as u8,
-    0x34 as i32 as u8,
-    0x3a as i32 as u8,
-    0x6 as i32 as u8,
-    0x4d as i32 as u8,
-    0x51 as i32 as u8,
-    0x54 as i32 as u8,
-    0x51 as i32 as u8,
-    0x64 as i32 as u8,
-    0x51 as i32 as u8,
-    0x77 as i32 as u8,
-    0x51 as i32 as u8,
-    0x1c as i32 as u8,
-    0x5 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x34 as i32 as u8,
-    0x67 as i32 as u8,
-    0x51 as i32 as u8,
-    0x8d as i32 as u8,
-    0x51 as i32 as u8,
-    0x4b as i32 as u8,
-    0x5 as i32 as u8,
-    0x97 as i32 as u8,
-    0x51 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x51 as i32 as u8,
-    0xcc as i32 as u8,
-    0x4e as i32 as u8,
-    0xac as i32 as u8,
-    0x51 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x51 as i32 as u8,
-    0xdf as i32 as u8,
-    0x91 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x51 as i32 as u8,
-    0x3 as i32 as u8,
-    0x52 as i32 as u8,
-    0xdf as i32 as u8,
-    0x34 as i32 as u8,
-    0x3b as i32 as u8,
-    0x52 as i32 as u8,
-    0x46 as i32 as u8,
-    0x52 as i32 as u8,
-    0x72 as i32 as u8,
-    0x52 as i32 as u8,
-    0x77 as i32 as u8,
-    0x52 as i32 as u8,
-    0x15 as i32 as u8,
-    0x35 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x80 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x33 as i32 as u8,
-    0x3e as i32 as u8,
-    0x3f as i32 as u8,
-    0x50 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8a as i32 as u8,
-    0x93 as i32 as u8,
-    0xac as i32 as u8,
-    0xb6 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x2c as i32 as u8,
-    0xa as i32 as u8,
-    0x70 as i32 as u8,
-    0x70 as i32 as u8,
-    0xca as i32 as u8,
-    0x53 as i32 as u8,
-    0xdf as i32 as u8,
-    0x53 as i32 as u8,
-    0x63 as i32 as u8,
-    0xb as i32 as u8,
-    0xeb as i32 as u8,
-    0x53 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x53 as i32 as u8,
-    0x6 as i32 as u8,
-    0x54 as i32 as u8,
-    0x9e as i32 as u8,
-    0x54 as i32 as u8,
-    0x38 as i32 as u8,
-    0x54 as i32 as u8,
-    0x48 as i32 as u8,
-    0x54 as i32 as u8,
-    0x68 as i32 as u8,
-    0x54 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x54 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x54 as i32 as u8,
-    0x10 as i32 as u8,
-    0x55 as i32 as u8,
-    0x53 as i32 as u8,
-    0x55 as i32 as u8,
-    0x63 as i32 as u8,
-    0x55 as i32 as u8,
-    0x84 as i32 as u8,
-    0x55 as i32 as u8,
-    0x84 as i32 as u8,
-    0x55 as i32 as u8,
-    0x99 as i32 as u8,
-    0x55 as i32 as u8,
-    0xab as i32 as u8,
-    0x55 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x55 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x55 as i32 as u8,
-    0x16 as i32 as u8,
-    0x57 as i32 as u8,
-    0x6 as i32 as u8,
-    0x56 as i32 as u8,
-    0x17 as i32 as u8,
-    0x57 as i32 as u8,
-    0x51 as i32 as u8,
-    0x56 as i32 as u8,
-    0x74 as i32 as u8,
-    0x56 as i32 as u8,
-    0x7 as i32 as u8,
-    0x52 as i32 as u8,
-    0xee as i32 as u8,
-    0x58 as i32 as u8,
-    0xce as i32 as u8,
-    0x57 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x57 as i32 as u8,
-    0xd as i32 as u8,
-    0x58 as i32 as u8,
-    0x8b as i32 as u8,
-    0x57 as i32 as u8,
-    0x32 as i32 as u8,
-    0x58 as i32 as u8,
-    0x31 as i32 as u8,
-    0x58 as i32 as u8,
-    0xac as i32 as u8,
-    0x58 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x14 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x58 as i32 as u8,
-    0xf7 as i32 as u8,
-    0x58 as i32 as u8,
-    0x6 as i32 as u8,
-    0x59 as i32 as u8,
-    0x1a as i32 as u8,
-    0x59 as i32 as u8,
-    0x22 as i32 as u8,
-    0x59 as i32 as u8,
-    0x62 as i32 as u8,
-    0x59 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x16 as i32 as u8,
-    0xea as i32 as u8,
-    0x16 as i32 as u8,
-    0xec as i32 as u8,
-    0x59 as i32 as u8,
-    0x1b as i32 as u8,
-    0x5a as i32 as u8,
-    0x27 as i32 as u8,
-    0x5a as i32 as u8,
-    0xd8 as i32 as u8,
-    0x59 as i32 as u8,
-    0x66 as i32 as u8,
-    0x5a as i32 as u8,
-    0xee as i32 as u8,
-    0x36 as i32 as u8,
-    0xfc as i32 as u8,
-    0x36 as i32 as u8,
-    0x8 as i32 as u8,
-    0x5b as i32 as u8,
-    0x3e as i32 as u8,
-    0x5b as i32 as u8,
-    0x3e as i32 as u8,
-    0x5b as i32 as u8,
-    0xc8 as i32 as u8,
-    0x19 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x5b as i32 as u8,
-    0xd8 as i32 as u8,
-    0x5b as i32 as u8,
-    0xe7 as i32 as u8,
-    0x5b as i32 as u8,
-    0xf3 as i32 as u8,
-    0x5b as i32 as u8,
-    0x18 as i32 as u8,
-    0x1b as i32 as u8,
-    0xff as i32 as u8,
-    0x5b as i32 as u8,
-    0x6 as i32 as u8,
-    0x5c as i32 as u8,
-    0x53 as i32 as u8,
-    0x5f as i32 as u8,
-    0x22 as i32 as u8,
-    0x5c as i32 as u8,
-    0x81 as i32 as u8,
-    0x37 as i32 as u8,
-    0x60 as i32 as u8,
-    0x5c as i32 as u8,
-    0x6e as i32 as u8,
-    0x5c as i32 as u8,
-    0xc0 as i32 as u8,
-    0x5c as i32 as u8,
-    0x8d as i32 as u8,
-    0x5c as i32 as u8,
-    0xe4 as i32 as u8,
-    0x1d as i32 as u8,
-    0x43 as i32 as u8,
-    0x5d as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1d as i32 as u8,
-    0x6e as i32 as u8,
-    0x5d as i32 as u8,
-    0x6b as i32 as u8,
-    0x5d as i32 as u8,
-    0x7c as i32 as u8,
-    0x5d as i32 as u8,
-    0xe1 as i32 as u8,
-    0x5d as i32 as u8,
-    0xe2 as i32 as u8,
-    0x5d as i32 as u8,
-    0x2f as i32 as u8,
-    0x38 as i32 as u8,
-    0xfd as i32 as u8,
-    0x5d as i32 as u8,
-    0x28 as i32 as u8,
-    0x5e as i32 as u8,
-    0x3d as i32 as u8,
-    0x5e as i32 as u8,
-    0x69 as i32 as u8,
-    0x5e as i32 as u8,
-    0x62 as i32 as u8,
-    0x38 as i32 as u8,
-    0x83 as i32 as u8,
-    0x21 as i32 as u8,
-    0x7c as i32 as u8,
-    0x38 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x5e as i32 as u8,
-    0xb3 as i32 as u8,
-    0x5e as i32 as u8,
-    0xb6 as i32 as u8,
-    0x5e as i32 as u8,
-    0xca as i32 as u8,
-    0x5e as i32 as u8,
-    0x92 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xfe as i32 as u8,
-    0x5e as i32 as u8,
-    0x31 as i32 as u8,
-    0x23 as i32 as u8,
-    0x31 as i32 as u8,
-    0x23 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5f as i32 as u8,
-    0x22 as i32 as u8,
-    0x5f as i32 as u8,
-    0xc7 as i32 as u8,
-    0x38 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x32 as i32 as u8,
-    0xda as i32 as u8,
-    0x61 as i32 as u8,
-    0x62 as i32 as u8,
-    0x5f as i32 as u8,
-    0x6b as i32 as u8,
-    0x5f as i32 as u8,
-    0xe3 as i32 as u8,
-    0x38 as i32 as u8,
-    0x9a as i32 as u8,
-    0x5f as i32 as u8,
-    0xcd as i32 as u8,
-    0x5f as i32 as u8,
-    0xd7 as i32 as u8,
-    0x5f as i32 as u8,
-    0xf9 as i32 as u8,
-    0x5f as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x3a as i32 as u8,
-    0x39 as i32 as u8,
-    0x1c as i32 as u8,
-    0x39 as i32 as u8,
-    0x94 as i32 as u8,
-    0x60 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x26 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x60 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x48 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4 as i32 as u8,
-    0x32 as i32 as u8,
-    0x46 as i32 as u8,
-    0x6a as i32 as u8,
-    0x5c as i32 as u8,
-    0x67 as i32 as u8,
-    0x96 as i32 as u8,
-    0xaa as i32 as u8,
-    0xae as i32 as u8,
-    0xc8 as i32 as u8,
-    0xd3 as i32 as u8,
-    0x5d as i32 as u8,
-    0x62 as i32 as u8,
-    0 as i32 as u8,
-    0x54 as i32 as u8,
-    0x77 as i32 as u8,
-    0xf3 as i32 as u8,
-    0xc as i32 as u8,
-    0x2b as i32 as u8,
-    0x3d as i32 as u8,
-    0x63 as i32 as u8,
-    0xfc as i32 as u8,
-    0x62 as i32 as u8,
-    0x68 as i32 as u8,
-    0x63 as i32 as u8,
-    0x83 as i32 as u8,
-    0x63 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x63 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x2b as i32 as u8,
-    0x22 as i32 as u8,
-    0x64 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x63 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x63 as i32 as u8,
-    0x2e as i32 as u8,
-    0x3a as i32 as u8,
-    0x69 as i32 as u8,
-    0x64 as i32 as u8,
-    0x7e as i32 as u8,
-    0x64 as i32 as u8,
-    0x9d as i32 as u8,
-    0x64 as i32 as u8,
-    0x77 as i32 as u8,
-    0x64 as i32 as u8,
-    0x6c as i32 as u8,
-    0x3a as i32 as u8,
-    0x4f as i32 as u8,
-    0x65 as i32 as u8,
-    0x6c as i32 as u8,
-    0x65 as i32 as u8,
-    0xa as i32 as u8,
-    0x30 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x65 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x66 as i32 as u8,
-    0x49 as i32 as u8,
-    0x66 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3b as i32 as u8,
-    0x91 as i32 as u8,
-    0x66 as i32 as u8,
-    0x8 as i32 as u8,
-    0x3b as i32 as u8,
-    0xe4 as i32 as u8,
-    0x3a as i32 as u8,
-    0x92 as i32 as u8,
-    0x51 as i32 as u8,
-    0x95 as i32 as u8,
-    0x51 as i32 as u8,
-    0 as i32 as u8,
-    0x67 as i32 as u8,
-    0x9c as i32 as u8,
-    0x66 as i32 as u8,
-    0xad as i32 as u8,
-    0x80 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x43 as i32 as u8,
-    0x17 as i32 as u8,
-    0x67 as i32 as u8,
-    0x1b as i32 as u8,
-    0x67 as i32 as u8,
-    0x21 as i32 as u8,
-    0x67 as i32 as u8,
-    0x5e as i32 as u8,
-    0x67 as i32 as u8,
-    0x53 as i32 as u8,
-    0x67 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x33 as i32 as u8,
-    0x49 as i32 as u8,
-    0x3b as i32 as u8,
-    0xfa as i32 as u8,
-    0x67 as i32 as u8,
-    0x85 as i32 as u8,
-    0x67 as i32 as u8,
-    0x52 as i32 as u8,
-    0x68 as i32 as u8,
-    0x85 as i32 as u8,
-    0x68 as i32 as u8,
-    0x6d as i32 as u8,
-    0x34 as i32 as u8,
-    0x8e as i32 as u8,
-    0x68 as i32 as u8,
-    0x1f as i32 as u8,
-    0x68 as i32 as u8,
-    0x14 as i32 as u8,
-    0x69 as i32 as u8,
-    0x9d as i32 as u8,
-    0x3b as i32 as u8,
-    0x42 as i32 as u8,
-    0x69 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x69 as i32 as u8,
-    0xea as i32 as u8,
-    0x69 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x6a as i32 as u8,
-    0xa3 as i32 as u8,
-    0x36 as i32 as u8,
-    0xdb as i32 as u8,
-    0x6a as i32 as u8,
-    0x18 as i32 as u8,
-    0x3c as i32 as u8,
-    0x21 as i32 as u8,
-    0x6b as i32 as u8,
-    0xa7 as i32 as u8,
-    0x38 as i32 as u8,
-    0x54 as i32 as u8,
-    0x6b as i32 as u8,
-    0x4e as i32 as u8,
-    0x3c as i32 as u8,
-    0x72 as i32 as u8,
-    0x6b as i32 as u8,
-    0x9f as i32 as u8,
-    0x6b as i32 as u8,
-    0xba as i32 as u8,
-    0x6b as i32 as u8,
-    0xbb as i32 as u8,
-    0x6b as i32 as u8,
-    0x8d as i32 as u8,
-    0x3a as i32 as u8,
-    0xb as i32 as u8,
-    0x1d as i32 as u8,
-    0xfa as i32 as u8,
-    0x3a as i32 as u8,
-    0x4e as i32 as u8,
-    0x6c as i32 as u8,
-    0xbc as i32 as u8,
-    0x3c as i32 as u8,
-    0xbf as i32 as u8,
-    0x6c as i32 as u8,
-    0xcd as i32 as u8,
-    0x6c as i32 as u8,
-    0x67 as i32 as u8,
-    0x6c as i32 as u8,
-    0x16 as i32 as u8,
-    0x6d as i32 as u8,
-    0x3e as i32 as u8,
-    0x6d as i32 as u8,
-    0x77 as i32 as u8,
-    0x6d as i32 as u8,
-    0x41 as i32 as u8,
-    0x6d as i32 as u8,
-    0x69 as i32 as u8,
-    0x6d as i32 as u8,
-    0x78 as i32 as u8,
-    0x6d as i32 as u8,
-    0x85 as i32 as u8,
-    0x6d as i32 as u8,
-    0x1e as i32 as u8,
-    0x3d as i32 as u8,
-    0x34 as i32 as u8,
-    0x6d as i32 as u8,
-    0x2f as i32 as u8,
-    0x6e as i32 as u8,
-    0x6e as i32 as u8,
-    0x6e as i32 as u8,
-    0x33 as i32 as u8,
-    0x3d as i32 as u8,
-    0xcb as i32 as u8,
-    0x6e as i32 as u8,
-    0xc7 as i32 as u8,
-    0x6e as i32 as u8,
-    0xd1 as i32 as u8,
-    0x3e as i32 as u8,
-    0xf9 as i32 as u8,
-    0x6d as i32 as u8,
-    0x6e as i32 as u8,
-    0x6f as i32 as u8,
-    0x5e as i32 as u8,
-    0x3f as i32 as u8,
-    0x8e as i32 as u8,
-    0x3f as i32 as u8,
-    0xc6 as i32 as u8,
-    0x6f as i32 as u8,
-    0x39 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1e as i32 as u8,
-    0x70 as i32 as u8,
-    0x1b as i32 as u8,
-    0x70 as i32 as u8,
-    0x96 as i32 as u8,
-    0x3d as i32 as u8,
-    0x4a as i32 as u8,
-    0x70 as i32 as u8,
-    0x7d as i32 as u8,
-    0x70 as i32 as u8,
-    0x77 as i32 as u8,
-    0x70 as i32 as u8,
-    0xad as i32 as u8,
-    0x70 as i32 as u8,
-    0x25 as i32 as u8,
-    0x5 as i32 as u8,
-    0x45 as i32 as u8,
-    0x71 as i32 as u8,
-    0x63 as i32 as u8,
-    0x42 as i32 as u8,
-    0x9c as i32 as u8,
-    0x71 as i32 as u8,
-    0xab as i32 as u8,
-    0x43 as i32 as u8,
-    0x28 as i32 as u8,
-    0x72 as i32 as u8,
-    0x35 as i32 as u8,
-    0x72 as i32 as u8,
-    0x50 as i32 as u8,
-    0x72 as i32 as u8,
-    0x8 as i32 as u8,
-    0x46 as i32 as u8,
-    0x80 as i32 as u8,
-    0x72 as i32 as u8,
-    0x95 as i32 as u8,
-    0x72 as i32 as u8,
-    0x35 as i32 as u8,
-    0x47 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x20 as i32 as u8,
-    0x14 as i32 as u8,
-    0x48 as i32 as u8,
-    0x7a as i32 as u8,
-    0x73 as i32 as u8,
-    0x8b as i32 as u8,
-    0x73 as i32 as u8,
-    0xac as i32 as u8,
-    0x3e as i32 as u8,
-    0xa5 as i32 as u8,
-    0x73 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3e as i32 as u8,
-    0xb8 as i32 as u8,
-    0x3e as i32 as u8,
-    0x47 as i32 as u8,
-    0x74 as i32 as u8,
-    0x5c as i32 as u8,
-    0x74 as i32 as u8,
-    0x71 as i32 as u8,
-    0x74 as i32 as u8,
-    0x85 as i32 as u8,
-    0x74 as i32 as u8,
-    0xca as i32 as u8,
-    0x74 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3f as i32 as u8,
-    0x24 as i32 as u8,
-    0x75 as i32 as u8,
-    0x36 as i32 as u8,
-    0x4c as i32 as u8,
-    0x3e as i32 as u8,
-    0x75 as i32 as u8,
-    0x92 as i32 as u8,
-    0x4c as i32 as u8,
-    0x70 as i32 as u8,
-    0x75 as i32 as u8,
-    0x9f as i32 as u8,
-    0x21 as i32 as u8,
-    0x10 as i32 as u8,
-    0x76 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x4f as i32 as u8,
-    0xb8 as i32 as u8,
-    0x4f as i32 as u8,
-    0x44 as i32 as u8,
-    0x50 as i32 as u8,
-    0xfc as i32 as u8,
-    0x3f as i32 as u8,
-    0x8 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x76 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x50 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x50 as i32 as u8,
-    0x19 as i32 as u8,
-    0x51 as i32 as u8,
-    0x33 as i32 as u8,
-    0x51 as i32 as u8,
-    0x1e as i32 as u8,
-    0x77 as i32 as u8,
-    0x1f as i32 as u8,
-    0x77 as i32 as u8,
-    0x1f as i32 as u8,
-    0x77 as i32 as u8,
-    0x4a as i32 as u8,
-    0x77 as i32 as u8,
-    0x39 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8b as i32 as u8,
-    0x77 as i32 as u8,
-    0x46 as i32 as u8,
-    0x40 as i32 as u8,
-    0x96 as i32 as u8,
-    0x40 as i32 as u8,
-    0x1d as i32 as u8,
-    0x54 as i32 as u8,
-    0x4e as i32 as u8,
-    0x78 as i32 as u8,
-    0x8c as i32 as u8,
-    0x78 as i32 as u8,
-    0xcc as i32 as u8,
-    0x78 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x40 as i32 as u8,
-    0x26 as i32 as u8,
-    0x56 as i32 as u8,
-    0x56 as i32 as u8,
-    0x79 as i32 as u8,
-    0x9a as i32 as u8,
-    0x56 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x56 as i32 as u8,
-    0x8f as i32 as u8,
-    0x79 as i32 as u8,
-    0xeb as i32 as u8,
-    0x79 as i32 as u8,
-    0x2f as i32 as u8,
-    0x41 as i32 as u8,
-    0x40 as i32 as u8,
-    0x7a as i32 as u8,
-    0x4a as i32 as u8,
-    0x7a as i32 as u8,
-    0x4f as i32 as u8,
-    0x7a as i32 as u8,
-    0x7c as i32 as u8,
-    0x59 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x5a as i32 as u8,
-    0xa7 as i32 as u8,
-    0x5a as i32 as u8,
-    0xee as i32 as u8,
-    0x7a as i32 as u8,
-    0x2 as i32 as u8,
-    0x42 as i32 as u8,
-    0xab as i32 as u8,
-    0x5b as i32 as u8,
-    0xc6 as i32 as u8,
-    0x7b as i32 as u8,
-    0xc9 as i32 as u8,
-    0x7b as i32 as u8,
-    0x27 as i32 as u8,
-    0x42 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5c as i32 as u8,
-    0xd2 as i32 as u8,
-    0x7c as i32 as u8,
-    0xa0 as i32 as u8,
-    0x42 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x7c as i32 as u8,
-    0xe3 as i32 as u8,
-    0x7c as i32 as u8,
-    0 as i32 as u8,
-    0x7d as i32 as u8,
-    0x86 as i32 as u8,
-    0x5f as i32 as u8,
-    0x63 as i32 as u8,
-    0x7d as i32 as u8,
-    0x1 as i32 as u8,
-    0x43 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x7d as i32 as u8,
-    0x2 as i32 as u8,
-    0x7e as i32 as u8,
-    0x45 as i32 as u8,
-    0x7e as i32 as u8,
-    0x34 as i32 as u8,
-    0x43 as i32 as u8,
-    0x28 as i32 as u8,
-    0x62 as i32 as u8,
-    0x47 as i32 as u8,
-    0x62 as i32 as u8,
-    0x59 as i32 as u8,
-    0x43 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x62 as i32 as u8,
-    0x7a as i32 as u8,
-    0x7f as i32 as u8,
-    0x3e as i32 as u8,
-    0x63 as i32 as u8,
-    0x95 as i32 as u8,
-    0x7f as i32 as u8,
-    0xfa as i32 as u8,
-    0x7f as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xda as i32 as u8,
-    0x64 as i32 as u8,
-    0x23 as i32 as u8,
-    0x65 as i32 as u8,
-    0x60 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x65 as i32 as u8,
-    0x70 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5f as i32 as u8,
-    0x33 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x43 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb as i32 as u8,
-    0x44 as i32 as u8,
-    0x3e as i32 as u8,
-    0x81 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x5a as i32 as u8,
-    0xa7 as i32 as u8,
-    0x67 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x67 as i32 as u8,
-    0x93 as i32 as u8,
-    0x33 as i32 as u8,
-    0x9c as i32 as u8,
-    0x33 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4 as i32 as u8,
-    0x82 as i32 as u8,
-    0x9e as i32 as u8,
-    0x8f as i32 as u8,
-    0x6b as i32 as u8,
-    0x44 as i32 as u8,
-    0x91 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8b as i32 as u8,
-    0x82 as i32 as u8,
-    0x9d as i32 as u8,
-    0x82 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x52 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x82 as i32 as u8,
-    0xbd as i32 as u8,
-    0x82 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x82 as i32 as u8,
-    0x3c as i32 as u8,
-    0x6b as i32 as u8,
-    0xe5 as i32 as u8,
-    0x82 as i32 as u8,
-    0x1d as i32 as u8,
-    0x83 as i32 as u8,
-    0x63 as i32 as u8,
-    0x83 as i32 as u8,
-    0xad as i32 as u8,
-    0x83 as i32 as u8,
-    0x23 as i32 as u8,
-    0x83 as i32 as u8,
-    0xbd as i32 as u8,
-    0x83 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x83 as i32 as u8,
-    0x57 as i32 as u8,
-    0x84 as i32 as u8,
-    0x53 as i32 as u8,
-    0x83 as i32 as u8,
-    0xca as i32 as u8,
-    0x83 as i32 as u8,
-    0xcc as i32 as u8,
-    0x83 as i32 as u8,
-    0xdc as i32 as u8,
-    0x83 as i32 as u8,
-    0x36 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6b as i32 as u8,
-    0x6d as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2a as i32 as u8,
-    0xa0 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x80 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x20 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8a as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0xaa as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x6c as i32 as u8,
-    0x2b as i32 as u8,
-    0x45 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x84 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x16 as i32 as u8,
-    0x85 as i32 as u8,
-    0xca as i32 as u8,
-    0x73 as i32 as u8,
-    0x64 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2c as i32 as u8,
-    0x6f as i32 as u8,
-    0x5d as i32 as u8,
-    0x45 as i32 as u8,
-    0x61 as i32 as u8,
-    0x45 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x6f as i32 as u8,
-    0xd2 as i32 as u8,
-    0x70 as i32 as u8,
-    0x6b as i32 as u8,
-    0x45 as i32 as u8,
-    0x50 as i32 as u8,
-    0x86 as i32 as u8,
-    0x5c as i32 as u8,
-    0x86 as i32 as u8,
-    0x67 as i32 as u8,
-    0x86 as i32 as u8,
-    0x69 as i32 as u8,
-    0x86 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x86 as i32 as u8,
-    0x88 as i32 as u8,
-    0x86 as i32 as u8,
-    0xe as i32 as u8,
-    0x87 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x86 as i32 as u8,
-    0x79 as i32 as u8,
-    0x87 as i32 as u8,
-    0x28 as i32 as u8,
-    0x87 as i32 as u8,
-    0x6b as i32 as u8,
-    0x87 as i32 as u8,
-    0x86 as i32 as u8,
-    0x87 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x45 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0xf9 as i32 as u8,
-    0x45 as i32 as u8,
-    0x60 as i32 as u8,
-    0x88 as i32 as u8,
-    0x63 as i32 as u8,
-    0x88 as i32 as u8,
-    0x67 as i32 as u8,
-    0x76 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x88 as i32 as u8,
-    0xde as i32 as u8,
-    0x88 as i32 as u8,
-    0x35 as i32 as u8,
-    0x46 as i32 as u8,
-    0xfa as i32 as u8,
-    0x88 as i32 as u8,
-    0xbb as i32 as u8,
-    0x34 as i32 as u8,
-    0xae as i32 as u8,
-    0x78 as i32 as u8,
-    0x66 as i32 as u8,
-    0x79 as i32 as u8,
-    0xbe as i32 as u8,
-    0x46 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x46 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x8a as i32 as u8,
-    0xed as i32 as u8,
-    0x8a as i32 as u8,
-    0x8a as i32 as u8,
-    0x8b as i32 as u8,
-    0x55 as i32 as u8,
-    0x8c as i32 as u8,
-    0xa8 as i32 as u8,
-    0x7c as i32 as u8,
-    0xab as i32 as u8,
-    0x8c as i32 as u8,
-    0xc1 as i32 as u8,
-    0x8c as i32 as u8,
-    0x1b as i32 as u8,
-    0x8d as i32 as u8,
-    0x77 as i32 as u8,
-    0x8d as i32 as u8,
-    0x2f as i32 as u8,
-    0x7f as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0xcb as i32 as u8,
-    0x8d as i32 as u8,
-    0xbc as i32 as u8,
-    0x8d as i32 as u8,
-    0xf0 as i32 as u8,
-    0x8d as i32 as u8,
-    0xde as i32 as u8,
-    0x8 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x8e as i32 as u8,
-    0x38 as i32 as u8,
-    0x8f as i32 as u8,
-    0xd2 as i32 as u8,
-    0x85 as i32 as u8,
-    0xed as i32 as u8,
-    0x85 as i32 as u8,
-    0x94 as i32 as u8,
-    0x90 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x90 as i32 as u8,
-    0x11 as i32 as u8,
-    0x91 as i32 as u8,
-    0x2e as i32 as u8,
-    0x87 as i32 as u8,
-    0x1b as i32 as u8,
-    0x91 as i32 as u8,
-    0x38 as i32 as u8,
-    0x92 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x92 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x92 as i32 as u8,
-    0x7c as i32 as u8,
-    0x92 as i32 as u8,
-    0xf9 as i32 as u8,
-    0x93 as i32 as u8,
-    0x15 as i32 as u8,
-    0x94 as i32 as u8,
-    0xfa as i32 as u8,
-    0x8b as i32 as u8,
-    0x8b as i32 as u8,
-    0x95 as i32 as u8,
-    0x95 as i32 as u8,
-    0x49 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x95 as i32 as u8,
-    0x77 as i32 as u8,
-    0x8d as i32 as u8,
-    0xe6 as i32 as u8,
-    0x49 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x96 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x5d as i32 as u8,
-    0x23 as i32 as u8,
-    0x97 as i32 as u8,
-    0x45 as i32 as u8,
-    0x91 as i32 as u8,
-    0x1a as i32 as u8,
-    0x92 as i32 as u8,
-    0x6e as i32 as u8,
-    0x4a as i32 as u8,
-    0x76 as i32 as u8,
-    0x4a as i32 as u8,
-    0xe0 as i32 as u8,
-    0x97 as i32 as u8,
-    0xa as i32 as u8,
-    0x94 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x4a as i32 as u8,
-    0x96 as i32 as u8,
-    0x94 as i32 as u8,
-    0xb as i32 as u8,
-    0x98 as i32 as u8,
-    0xb as i32 as u8,
-    0x98 as i32 as u8,
-    0x29 as i32 as u8,
-    0x98 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x95 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x98 as i32 as u8,
-    0x33 as i32 as u8,
-    0x4b as i32 as u8,
-    0x29 as i32 as u8,
-    0x99 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x99 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x99 as i32 as u8,
-    0xfe as i32 as u8,
-    0x99 as i32 as u8,
-    0xce as i32 as u8,
-    0x4b as i32 as u8,
-    0x30 as i32 as u8,
-    0x9b as i32 as u8,
-    0x12 as i32 as u8,
-    0x9b as i32 as u8,
-    0x40 as i32 as u8,
-    0x9c as i32 as u8,
-    0xfd as i32 as u8,
-    0x9c as i32 as u8,
-    0xce as i32 as u8,
-    0x4c as i32 as u8,
-    0xed as i32 as u8,
-    0x4c as i32 as u8,
-    0x67 as i32 as u8,
-    0x9d as i32 as u8,
-    0xce as i32 as u8,
-    0xa0 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x4c as i32 as u8,
-    0x5 as i32 as u8,
-    0xa1 as i32 as u8,
-    0xe as i32 as u8,
-    0xa2 as i32 as u8,
-    0x91 as i32 as u8,
-    0xa2 as i32 as u8,
-    0xbb as i32 as u8,
-    0x9e as i32 as u8,
-    0x56 as i32 as u8,
-    0x4d as i32 as u8,
-    0xf9 as i32 as u8,
-    0x9e as i32 as u8,
-    0xfe as i32 as u8,
-    0x9e as i32 as u8,
-    0x5 as i32 as u8,
-    0x9f as i32 as u8,
-    0xf as i32 as u8,
-    0x9f as i32 as u8,
-    0x16 as i32 as u8,
-    0x9f as i32 as u8,
-    0x3b as i32 as u8,
-    0x9f as i32 as u8,
-    0 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x88 as i32 as u8,
-    0xa0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_comp_table: [u16; 945] = [
-    0x4a01 as i32 as u16,
-    0x49c0 as i32 as u16,
-    0x4a02 as i32 as u16,
-    0x280 as i32 as u16,
-    0x281 as i32 as u16,
-    0x282 as i32 as u16,
-    0x283 as i32 as u16,
-    0x2c0 as i32 as u16,
-    0x2c2 as i32 as u16,
-    0xa00 as i32 as u16,
-    0x284 as i32 as u16,
-    0x2442 as i32 as u16,
-    0x285 as i32 as u16,
-    0x7c0 as i32 as u16,
-    0x980 as i32 as u16,
-    0x982 as i32 as u16,
-    0x2440 as i32 as u16,
-    0x2280 as i32 as u16,
-    0x2c4 as i32 as u16,
-    0x2282 as i32 as u16,
-    0x2284 as i32 as u16,
-    0x2286 as i32 as u16,
-    0x2c6 as i32 as u16,
-    0x2c8 as i32 as u16,
-    0x2ca as i32 as u16,
-    0x2cc as i32 as u16,
-    0x287 as i32 as u16,
-    0x228a as i32 as u16,
-    0x2ce as i32 as u16,
-    0x228c as i32 as u16,
-    0x2290 as i32 as u16,
-    0x2292 as i32 as u16,
-    0x228e as i32 as u16,
-    0x288 as i32 as u16,
-    0x289 as i32 as u16,
-    0x28a as i32 as u16,
-    0x2482 as i32 as u16,
-    0x300 as i32 as u16,
-    0x302 as i32 as u16,
-    0x304 as i32 as u16,
-    0x28b as i32 as u16,
-    0x2480 as i32 as u16,
-    0x308 as i32 as u16,
-    0x984 as i32 as u16,
-    0x986 as i32 as u16,
-    0x2458 as i32 as u16,
-    0xa02 as i32 as u16,
-    0x306 as i32 as u16,
-    0x2298 as i32 as u16,
-    0x229a as i32 as u16,
-    0x229e as i32 as u16,
-    0x900 as i32 as u16,
-    0x30a as i32 as u16,
-    0x22a0 as i32 as u16,
-    0x30c as i32 as u16,
-    0x30e as i32 as u16,
-    0x840 as i32 as u16,
-    0x310 as i32 as u16,
-    0x312 as i32 as u16,
-    0x22a2 as i32 as u16,
-    0x22a6 as i32 as u16,
-    0x9c0 as i32 as u16,
-    0x22a4 as i32 as u16,
-    0x22a8 as i32 as u16,
-    0x22aa as i32 as u16,
-    0x28c as i32 as u16,
-    0x28d as i32 as u16,
-    0x28e as i32 as u16,
-    0x340 as i32 as u16,
-    0x342 as i32 as u16,
-    0x344 as i32 as u16,
-    0x380 as i32 as u16,
-    0x28f as i32 as u16,
-    0x248e as i32 as u16,
-    0x7c2 as i32 as u16,
-    0x988 as i32 as u16,
-    0x98a as i32 as u16,
-    0x2490 as i32 as u16,
-    0x346 as i32 as u16,
-    0x22ac as i32 as u16,
-    0x400 as i32 as u16,
-    0x22b0 as i32 as u16,
-    0x842 as i32 as u16,
-    0x22b2 as i32 as u16,
-    0x402 as i32 as u16,
-    0x22b4 as i32 as u16,
-    0x440 as i32 as u16,
-    0x444 as i32 as u16,
-    0x22b6 as i32 as u16,
-    0x442 as i32 as u16,
-    0x22c2 as i32 as u16,
-    0x22c0 as i32 as u16,
-    0x22c4 as i32 as u16,
-    0x22c6 as i32 as u16,
-    0x22c8 as i32 as u16,
-    0x940 as i32 as u16,
-    0x4c0 as i32 as u16,
-    0x291 as i32 as u16,
-    0x22ca as i32 as u16,
-    0x4c4 as i32 as u16,
-    0x22cc as i32 as u16,
-    0x4c2 as i32 as u16,
-    0x22d0 as i32 as u16,
-    0x22ce as i32 as u16,
-    0x292 as i32 as u16,
-    0x293 as i32 as u16,
-    0x294 as i32 as u16,
-    0x295 as i32 as u16,
-    0x540 as i32 as u16,
-    0x542 as i32 as u16,
-    0xa08 as i32 as u16,
-    0x296 as i32 as u16,
-    0x2494 as i32 as u16,
-    0x544 as i32 as u16,
-    0x7c4 as i32 as u16,
-    0x98c as i32 as u16,
-    0x98e as i32 as u16,
-    0x6c0 as i32 as u16,
-    0x2492 as i32 as u16,
-    0x844 as i32 as u16,
-    0x2308 as i32 as u16,
-    0x230a as i32 as u16,
-    0x580 as i32 as u16,
-    0x230c as i32 as u16,
-    0x584 as i32 as u16,
-    0x990 as i32 as u16,
-    0x992 as i32 as u16,
-    0x230e as i32 as u16,
-    0x582 as i32 as u16,
-    0x2312 as i32 as u16,
-    0x586 as i32 as u16,
-    0x588 as i32 as u16,
-    0x2314 as i32 as u16,
-    0x58c as i32 as u16,
-    0x2316 as i32 as u16,
-    0x998 as i32 as u16,
-    0x58a as i32 as u16,
-    0x231e as i32 as u16,
-    0x590 as i32 as u16,
-    0x2320 as i32 as u16,
-    0x99a as i32 as u16,
-    0x58e as i32 as u16,
-    0x2324 as i32 as u16,
-    0x2322 as i32 as u16,
-    0x299 as i32 as u16,
-    0x29a as i32 as u16,
-    0x29b as i32 as u16,
-    0x5c0 as i32 as u16,
-    0x5c2 as i32 as u16,
-    0x5c4 as i32 as u16,
-    0x29c as i32 as u16,
-    0x24ac as i32 as u16,
-    0x5c6 as i32 as u16,
-    0x5c8 as i32 as u16,
-    0x7c6 as i32 as u16,
-    0x994 as i32 as u16,
-    0x996 as i32 as u16,
-    0x700 as i32 as u16,
-    0x24aa as i32 as u16,
-    0x2326 as i32 as u16,
-    0x5ca as i32 as u16,
-    0x232a as i32 as u16,
-    0x2328 as i32 as u16,
-    0x2340 as i32 as u16,
-    0x2342 as i32 as u16,
-    0x2344 as i32 as u16,
-    0x2346 as i32 as u16,
-    0x5cc as i32 as u16,
-    0x234a as i32 as u16,
-    0x2348 as i32 as u16,
-    0x234c as i32 as u16,
-    0x234e as i32 as u16,
-    0x2350 as i32 as u16,
-    0x24b8 as i32 as u16,
-    0x29d as i32 as u16,
-    0x5ce as i32 as u16,
-    0x24be as i32 as u16,
-    0xa0c as i32 as u16,
-    0x2352 as i32 as u16,
-    0x600 as i32 as u16,
-    0x24bc as i32 as u16,
-    0x24ba as i32 as u16,
-    0x640 as i32 as u16,
-    0x2354 as i32 as u16,
-    0x642 as i32 as u16,
-    0x644 as i32 as u16,
-    0x2356 as i32 as u16,
-    0x2358 as i32 as u16,
-    0x2a0 as i32 as u16,
-    0x2a1 as i32 as u16,
-    0x2a2 as i32 as u16,
-    0x2a3 as i32 as u16,
-    0x2c1 as i32 as u16,
-    0x2c3 as i32 as u16,
-    0xa01 as i32 as u16,
-    0x2a4 as i32 as u16,
-    0x2443 as i32 as u16,
-    0x2a5 as i32 as u16,
-    0x7c1 as i32 as u16,
-    0x981 as i32 as u16,
-    0x983 as i32 as u16,
-    0x2441 as i32 as u16,
-    0x2281 as i32 as u16,
-    0x2c5 as i32 as u16,
-    0x2283 as i32 as u16,
-    0x2285 as i32 as u16,
-    0x2287 as i32 as u16,
-    0x2c7 as i32 as u16,
-    0x2c9 as i32 as u16,
-    0x2cb as i32 as u16,
-    0x2cd as i32 as u16,
-    0x2a7 as i32 as u16,
-    0x228b as i32 as u16,
-    0x2cf as i32 as u16,
-    0x228d as i32 as u16,
-    0x2291 as i32 as u16,
-    0x2293 as i32 as u16,
-    0x228f as i32 as u16,
-    0x2a8 as i32 as u16,
-    0x2a9 as i32 as u16,
-    0x2aa as i32 as u16,
-    0x2483 as i32 as u16,
-    0x301 as i32 as u16,
-    0x303 as i32 as u16,
-    0x305 as i32 as u16,
-    0x2ab as i32 as u16,
-    0x2481 as i32 as u16,
-    0x309 as i32 as u16,
-    0x985 as i32 as u16,
-    0x987 as i32 as u16,
-    0x2459 as i32 as u16,
-    0xa03 as i32 as u16,
-    0x307 as i32 as u16,
-    0x2299 as i32 as u16,
-    0x229b as i32 as u16,
-    0x229f as i32 as u16,
-    0x901 as i32 as u16,
-    0x30b as i32 as u16,
-    0x22a1 as i32 as u16,
-    0x30d as i32 as u16,
-    0x30f as i32 as u16,
-    0x841 as i32 as u16,
-    0x311 as i32 as u16,
-    0x313 as i32 as u16,
-    0x22a3 as i32 as u16,
-    0x22a7 as i32 as u16,
-    0x9c1 as i32 as u16,
-    0x22a5 as i32 as u16,
-    0x22a9 as i32 as u16,
-    0x22ab as i32 as u16,
-    0x2380 as i32 as u16,
-    0x2ac as i32 as u16,
-    0x2ad as i32 as u16,
-    0x2ae as i32 as u16,
-    0x341 as i32 as u16,
-    0x343 as i32 as u16,
-    0x345 as i32 as u16,
-    0x2af as i32 as u16,
-    0x248f as i32 as u16,
-    0x7c3 as i32 as u16,
-    0x989 as i32 as u16,
-    0x98b as i32 as u16,
-    0x2491 as i32 as u16,
-    0x347 as i32 as u16,
-    0x22ad as i32 as u16,
-    0x401 as i32 as u16,
-    0x884 as i32 as u16,
-    0x22b1 as i32 as u16,
-    0x843 as i32 as u16,
-    0x22b3 as i32 as u16,
-    0x403 as i32 as u16,
-    0x22b5 as i32 as u16,
-    0x441 as i32 as u16,
-    0x445 as i32 as u16,
-    0x22b7 as i32 as u16,
-    0x443 as i32 as u16,
-    0x22c3 as i32 as u16,
-    0x22c1 as i32 as u16,
-    0x22c5 as i32 as u16,
-    0x22c7 as i32 as u16,
-    0x22c9 as i32 as u16,
-    0x941 as i32 as u16,
-    0x4c1 as i32 as u16,
-    0x2b1 as i32 as u16,
-    0x22cb as i32 as u16,
-    0x4c5 as i32 as u16,
-    0x22cd as i32 as u16,
-    0x4c3 as i32 as u16,
-    0x22d1 as i32 as u16,
-    0x22cf as i32 as u16,
-    0x2b2 as i32 as u16,
-    0x2b3 as i32 as u16,
-    0x2b4 as i32 as u16,
-    0x2b5 as i32 as u16,
-    0x541 as i32 as u16,
-    0x543 as i32 as u16,
-    0xa09 as i32 as u16,
-    0x2b6 as i32 as u16,
-    0x2495 as i32 as u16,
-    0x545 as i32 as u16,
-    0x7c5 as i32 as u16,
-    0x98d as i32 as u16,
-    0x98f as i32 as u16,
-    0x6c1 as i32 as u16,
-    0x2493 as i32 as u16,
-    0x845 as i32 as u16,
-    0x2309 as i32 as u16,
-    0x230b as i32 as u16,
-    0x581 as i32 as u16,
-    0x230d as i32 as u16,
-    0x585 as i32 as u16,
-    0x991 as i32 as u16,
-    0x993 as i32 as u16,
-    0x230f as i32 as u16,
-    0x583 as i32 as u16,
-    0x2313 as i32 as u16,
-    0x587 as i32 as u16,
-    0x589 as i32 as u16,
-    0x2315 as i32 as u16,
-    0x58d as i32 as u16,
-    0x2317 as i32 as u16,
-    0x999 as i32 as u16,
-    0x58b as i32 as u16,
-    0x231f as i32 as u16,
-    0x2381 as i32 as u16,
-    0x591 as i32 as u16,
-    0x2321 as i32 as u16,
-    0x99b as i32 as u16,
-    0x58f as i32 as u16,
-    0x2325 as i32 as u16,
-    0x2323 as i32 as u16,
-    0x2b9 as i32 as u16,
-    0x2ba as i32 as u16,
-    0x2bb as i32 as u16,
-    0x5c1 as i32 as u16,
-    0x5c3 as i32 as u16,
-    0x5c5 as i32 as u16,
-    0x2bc as i32 as u16,
-    0x24ad as i32 as u16,
-    0x5c7 as i32 as u16,
-    0x5c9 as i32 as u16,
-    0x7c7 as i32 as u16,
-    0x995 as i32 as u16,
-    0x997 as i32 as u16,
-    0x701 as i32 as u16,
-    0x24ab as i32 as u16,
-    0x2327 as i32 as u16,
-    0x5cb as i32 as u16,
-    0x232b as i32 as u16,
-    0x2329 as i32 as u16,
-    0x2341 as i32 as u16,
-    0x2343 as i32 as u16,
-    0x2345 as i32 as u16,
-    0x2347 as i32 as u16,
-    0x5cd as i32 as u16,
-    0x234b as i32 as u16,
-    0x2349 as i32 as u16,
-    0x2382 as i32 as u16,
-    0x234d as i32 as u16,
-    0x234f as i32 as u16,
-    0x2351 as i32 as u16,
-    0x24b9 as i32 as u16,
-    0x2bd as i32 as u16,
-    0x5cf as i32 as u16,
-    0x24bf as i32 as u16,
-    0xa0d as i32 as u16,
-    0x2353 as i32 as u16,
-    0x2bf as i32 as u16,
-    0x24bd as i32 as u16,
-    0x2383 as i32 as u16,
-    0x24bb as i32 as u16,
-    0x641 as i32 as u16,
-    0x2355 as i32 as u16,
-    0x643 as i32 as u16,
-    0x645 as i32 as u16,
-    0x2357 as i32 as u16,
-    0x2359 as i32 as u16,
-    0x3101 as i32 as u16,
-    0xc80 as i32 as u16,
-    0x2e00 as i32 as u16,
-    0x2446 as i32 as u16,
-    0x2444 as i32 as u16,
-    0x244a as i32 as u16,
-    0x2448 as i32 as u16,
-    0x800 as i32 as u16,
-    0x942 as i32 as u16,
-    0x944 as i32 as u16,
-    0x804 as i32 as u16,
-    0x2288 as i32 as u16,
-    0x2486 as i32 as u16,
-    0x2484 as i32 as u16,
-    0x248a as i32 as u16,
-    0x2488 as i32 as u16,
-    0x22ae as i32 as u16,
-    0x2498 as i32 as u16,
-    0x2496 as i32 as u16,
-    0x249c as i32 as u16,
-    0x249a as i32 as u16,
-    0x2300 as i32 as u16,
-    0xa06 as i32 as u16,
-    0x2302 as i32 as u16,
-    0xa04 as i32 as u16,
-    0x946 as i32 as u16,
-    0x7ce as i32 as u16,
-    0x7ca as i32 as u16,
-    0x7c8 as i32 as u16,
-    0x7cc as i32 as u16,
-    0x2447 as i32 as u16,
-    0x2445 as i32 as u16,
-    0x244b as i32 as u16,
-    0x2449 as i32 as u16,
-    0x801 as i32 as u16,
-    0x943 as i32 as u16,
-    0x945 as i32 as u16,
-    0x805 as i32 as u16,
-    0x2289 as i32 as u16,
-    0x2487 as i32 as u16,
-    0x2485 as i32 as u16,
-    0x248b as i32 as u16,
-    0x2489 as i32 as u16,
-    0x22af as i32 as u16,
-    0x2499 as i32 as u16,
-    0x2497 as i32 as u16,
-    0x249d as i32 as u16,
-    0x249b as i32 as u16,
-    0x2301 as i32 as u16,
-    0xa07 as i32 as u16,
-    0x2303 as i32 as u16,
-    0xa05 as i32 as u16,
-    0x947 as i32 as u16,
-    0x7cf as i32 as u16,
-    0x7cb as i32 as u16,
-    0x7c9 as i32 as u16,
-    0x7cd as i32 as u16,
-    0x2450 as i32 as u16,
-    0x244e as i32 as u16,
-    0x2454 as i32 as u16,
-    0x2452 as i32 as u16,
-    0x2451 as i32 as u16,
-    0x244f as i32 as u16,
-    0x2455 as i32 as u16,
-    0x2453 as i32 as u16,
-    0x2294 as i32 as u16,
-    0x2296 as i32 as u16,
-    0x2295 as i32 as u16,
-    0x2297 as i32 as u16,
-    0x2304 as i32 as u16,
-    0x2306 as i32 as u16,
-    0x2305 as i32 as u16,
-    0x2307 as i32 as u16,
-    0x2318 as i32 as u16,
-    0x2319 as i32 as u16,
-    0x231a as i32 as u16,
-    0x231b as i32 as u16,
-    0x232c as i32 as u16,
-    0x232d as i32 as u16,
-    0x232e as i32 as u16,
-    0x232f as i32 as u16,
-    0x2400 as i32 as u16,
-    0x24a2 as i32 as u16,
-    0x24a0 as i32 as u16,
-    0x24a6 as i32 as u16,
-    0x24a4 as i32 as u16,
-    0x24a8 as i32 as u16,
-    0x24a3 as i32 as u16,
-    0x24a1 as i32 as u16,
-    0x24a7 as i32 as u16,
-    0x24a5 as i32 as u16,
-    0x24a9 as i32 as u16,
-    0x24b0 as i32 as u16,
-    0x24ae as i32 as u16,
-    0x24b4 as i32 as u16,
-    0x24b2 as i32 as u16,
-    0x24b6 as i32 as u16,
-    0x24b1 as i32 as u16,
-    0x24af as i32 as u16,
-    0x24b5 as i32 as u16,
-    0x24b3 as i32 as u16,
-    0x24b7 as i32 as u16,
-    0x882 as i32 as u16,
-    0x880 as i32 as u16,
-    0x881 as i32 as u16,
-    0x802 as i32 as u16,
-    0x803 as i32 as u16,
-    0x229c as i32 as u16,
-    0x229d as i32 as u16,
-    0xa0a as i32 as u16,
-    0xa0b as i32 as u16,
-    0x883 as i32 as u16,
-    0xb40 as i32 as u16,
-    0x2c8a as i32 as u16,
-    0xc81 as i32 as u16,
-    0x2c89 as i32 as u16,
-    0x2c88 as i32 as u16,
-    0x2540 as i32 as u16,
-    0x2541 as i32 as u16,
-    0x2d00 as i32 as u16,
-    0x2e07 as i32 as u16,
-    0xd00 as i32 as u16,
-    0x2640 as i32 as u16,
-    0x2641 as i32 as u16,
-    0x2e80 as i32 as u16,
-    0xd01 as i32 as u16,
-    0x26c8 as i32 as u16,
-    0x26c9 as i32 as u16,
-    0x2f00 as i32 as u16,
-    0x2f84 as i32 as u16,
-    0xd02 as i32 as u16,
-    0x2f83 as i32 as u16,
-    0x2f82 as i32 as u16,
-    0xd40 as i32 as u16,
-    0x26d8 as i32 as u16,
-    0x26d9 as i32 as u16,
-    0x3186 as i32 as u16,
-    0xd04 as i32 as u16,
-    0x2740 as i32 as u16,
-    0x2741 as i32 as u16,
-    0x3100 as i32 as u16,
-    0x3086 as i32 as u16,
-    0xd06 as i32 as u16,
-    0x3085 as i32 as u16,
-    0x3084 as i32 as u16,
-    0xd41 as i32 as u16,
-    0x2840 as i32 as u16,
-    0x3200 as i32 as u16,
-    0xd07 as i32 as u16,
-    0x284f as i32 as u16,
-    0x2850 as i32 as u16,
-    0x3280 as i32 as u16,
-    0x2c84 as i32 as u16,
-    0x2e03 as i32 as u16,
-    0x2857 as i32 as u16,
-    0xd42 as i32 as u16,
-    0x2c81 as i32 as u16,
-    0x2c80 as i32 as u16,
-    0x24c0 as i32 as u16,
-    0x24c1 as i32 as u16,
-    0x2c86 as i32 as u16,
-    0x2c83 as i32 as u16,
-    0x28c0 as i32 as u16,
-    0xd43 as i32 as u16,
-    0x25c0 as i32 as u16,
-    0x25c1 as i32 as u16,
-    0x2940 as i32 as u16,
-    0xd44 as i32 as u16,
-    0x26c0 as i32 as u16,
-    0x26c1 as i32 as u16,
-    0x2e05 as i32 as u16,
-    0x2e02 as i32 as u16,
-    0x29c0 as i32 as u16,
-    0xd45 as i32 as u16,
-    0x2f05 as i32 as u16,
-    0x2f04 as i32 as u16,
-    0xd80 as i32 as u16,
-    0x26d0 as i32 as u16,
-    0x26d1 as i32 as u16,
-    0x2f80 as i32 as u16,
-    0x2a40 as i32 as u16,
-    0xd82 as i32 as u16,
-    0x26e0 as i32 as u16,
-    0x26e1 as i32 as u16,
-    0x3080 as i32 as u16,
-    0x3081 as i32 as u16,
-    0x2ac0 as i32 as u16,
-    0xd83 as i32 as u16,
-    0x3004 as i32 as u16,
-    0x3003 as i32 as u16,
-    0xd81 as i32 as u16,
-    0x27c0 as i32 as u16,
-    0x27c1 as i32 as u16,
-    0x3082 as i32 as u16,
-    0x2b40 as i32 as u16,
-    0xd84 as i32 as u16,
-    0x2847 as i32 as u16,
-    0x2848 as i32 as u16,
-    0x3184 as i32 as u16,
-    0x3181 as i32 as u16,
-    0x2f06 as i32 as u16,
-    0xd08 as i32 as u16,
-    0x2f81 as i32 as u16,
-    0x3005 as i32 as u16,
-    0xd46 as i32 as u16,
-    0x3083 as i32 as u16,
-    0x3182 as i32 as u16,
-    0xe00 as i32 as u16,
-    0xe01 as i32 as u16,
-    0xf40 as i32 as u16,
-    0x1180 as i32 as u16,
-    0x1182 as i32 as u16,
-    0xf03 as i32 as u16,
-    0xf00 as i32 as u16,
-    0x11c0 as i32 as u16,
-    0xf01 as i32 as u16,
-    0x1140 as i32 as u16,
-    0x1202 as i32 as u16,
-    0x1204 as i32 as u16,
-    0xf81 as i32 as u16,
-    0x1240 as i32 as u16,
-    0xfc0 as i32 as u16,
-    0x1242 as i32 as u16,
-    0xf80 as i32 as u16,
-    0x1244 as i32 as u16,
-    0x1284 as i32 as u16,
-    0xf82 as i32 as u16,
-    0x1286 as i32 as u16,
-    0x1288 as i32 as u16,
-    0x128a as i32 as u16,
-    0x12c0 as i32 as u16,
-    0x1282 as i32 as u16,
-    0x1181 as i32 as u16,
-    0x1183 as i32 as u16,
-    0x1043 as i32 as u16,
-    0x1040 as i32 as u16,
-    0x11c1 as i32 as u16,
-    0x1041 as i32 as u16,
-    0x1141 as i32 as u16,
-    0x1203 as i32 as u16,
-    0x1205 as i32 as u16,
-    0x10c1 as i32 as u16,
-    0x1241 as i32 as u16,
-    0x1000 as i32 as u16,
-    0x1243 as i32 as u16,
-    0x10c0 as i32 as u16,
-    0x1245 as i32 as u16,
-    0x1285 as i32 as u16,
-    0x10c2 as i32 as u16,
-    0x1287 as i32 as u16,
-    0x1289 as i32 as u16,
-    0x128b as i32 as u16,
-    0x12c1 as i32 as u16,
-    0x1283 as i32 as u16,
-    0x1080 as i32 as u16,
-    0x1100 as i32 as u16,
-    0x1101 as i32 as u16,
-    0x1200 as i32 as u16,
-    0x1201 as i32 as u16,
-    0x1280 as i32 as u16,
-    0x1281 as i32 as u16,
-    0x1340 as i32 as u16,
-    0x1341 as i32 as u16,
-    0x1343 as i32 as u16,
-    0x1342 as i32 as u16,
-    0x1344 as i32 as u16,
-    0x13c2 as i32 as u16,
-    0x1400 as i32 as u16,
-    0x13c0 as i32 as u16,
-    0x1440 as i32 as u16,
-    0x1480 as i32 as u16,
-    0x14c0 as i32 as u16,
-    0x1540 as i32 as u16,
-    0x1541 as i32 as u16,
-    0x1740 as i32 as u16,
-    0x1700 as i32 as u16,
-    0x1741 as i32 as u16,
-    0x17c0 as i32 as u16,
-    0x1800 as i32 as u16,
-    0x1802 as i32 as u16,
-    0x1801 as i32 as u16,
-    0x1840 as i32 as u16,
-    0x1880 as i32 as u16,
-    0x1900 as i32 as u16,
-    0x18c0 as i32 as u16,
-    0x18c1 as i32 as u16,
-    0x1901 as i32 as u16,
-    0x1940 as i32 as u16,
-    0x1942 as i32 as u16,
-    0x1941 as i32 as u16,
-    0x1980 as i32 as u16,
-    0x19c0 as i32 as u16,
-    0x19c2 as i32 as u16,
-    0x19c1 as i32 as u16,
-    0x1c80 as i32 as u16,
-    0x1cc0 as i32 as u16,
-    0x1dc0 as i32 as u16,
-    0x1f80 as i32 as u16,
-    0x2000 as i32 as u16,
-    0x2002 as i32 as u16,
-    0x2004 as i32 as u16,
-    0x2006 as i32 as u16,
-    0x2008 as i32 as u16,
-    0x2040 as i32 as u16,
-    0x2080 as i32 as u16,
-    0x2082 as i32 as u16,
-    0x20c0 as i32 as u16,
-    0x20c1 as i32 as u16,
-    0x2100 as i32 as u16,
-    0x22b8 as i32 as u16,
-    0x22b9 as i32 as u16,
-    0x2310 as i32 as u16,
-    0x2311 as i32 as u16,
-    0x231c as i32 as u16,
-    0x231d as i32 as u16,
-    0x244c as i32 as u16,
-    0x2456 as i32 as u16,
-    0x244d as i32 as u16,
-    0x2457 as i32 as u16,
-    0x248c as i32 as u16,
-    0x248d as i32 as u16,
-    0x249e as i32 as u16,
-    0x249f as i32 as u16,
-    0x2500 as i32 as u16,
-    0x2502 as i32 as u16,
-    0x2504 as i32 as u16,
-    0x2bc0 as i32 as u16,
-    0x2501 as i32 as u16,
-    0x2503 as i32 as u16,
-    0x2505 as i32 as u16,
-    0x2bc1 as i32 as u16,
-    0x2bc2 as i32 as u16,
-    0x2bc3 as i32 as u16,
-    0x2bc4 as i32 as u16,
-    0x2bc5 as i32 as u16,
-    0x2bc6 as i32 as u16,
-    0x2bc7 as i32 as u16,
-    0x2580 as i32 as u16,
-    0x2582 as i32 as u16,
-    0x2584 as i32 as u16,
-    0x2bc8 as i32 as u16,
-    0x2581 as i32 as u16,
-    0x2583 as i32 as u16,
-    0x2585 as i32 as u16,
-    0x2bc9 as i32 as u16,
-    0x2bca as i32 as u16,
-    0x2bcb as i32 as u16,
-    0x2bcc as i32 as u16,
-    0x2bcd as i32 as u16,
-    0x2bce as i32 as u16,
-    0x2bcf as i32 as u16,
-    0x2600 as i32 as u16,
-    0x2602 as i32 as u16,
-    0x2601 as i32 as u16,
-    0x2603 as i32 as u16,
-    0x2680 as i32 as u16,
-    0x2682 as i32 as u16,
-    0x2681 as i32 as u16,
-    0x2683 as i32 as u16,
-    0x26c2 as i32 as u16,
-    0x26c4 as i32 as u16,
-    0x26c6 as i32 as u16,
-    0x2c00 as i32 as u16,
-    0x26c3 as i32 as u16,
-    0x26c5 as i32 as u16,
-    0x26c7 as i32 as u16,
-    0x2c01 as i32 as u16,
-    0x2c02 as i32 as u16,
-    0x2c03 as i32 as u16,
-    0x2c04 as i32 as u16,
-    0x2c05 as i32 as u16,
-    0x2c06 as i32 as u16,
-    0x2c07 as i32 as u16,
-    0x26ca as i32 as u16,
-    0x26cc as i32 as u16,
-    0x26ce as i32 as u16,
-    0x2c08 as i32 as u16,
-    0x26cb as i32 as u16,
-    0x26cd as i32 as u16,
-    0x26cf as i32 as u16,
-    0x2c09 as i32 as u16,
-    0x2c0a as i32 as u16,
-    0x2c0b as i32 as u16,
-    0x2c0c as i32 as u16,
-    0x2c0d as i32 as u16,
-    0x2c0e as i32 as u16,
-    0x2c0f as i32 as u16,
-    0x26d2 as i32 as u16,
-    0x26d4 as i32 as u16,
-    0x26d6 as i32 as u16,
-    0x26d3 as i32 as u16,
-    0x26d5 as i32 as u16,
-    0x26d7 as i32 as u16,
-    0x26da as i32 as u16,
-    0x26dc as i32 as u16,
-    0x26de as i32 as u16,
-    0x26db as i32 as u16,
-    0x26dd as i32 as u16,
-    0x26df as i32 as u16,
-    0x2700 as i32 as u16,
-    0x2702 as i32 as u16,
-    0x2701 as i32 as u16,
-    0x2703 as i32 as u16,
-    0x2780 as i32 as u16,
-    0x2782 as i32 as u16,
-    0x2781 as i32 as u16,
-    0x2783 as i32 as u16,
-    0x2800 as i32 as u16,
-    0x2802 as i32 as u16,
-    0x2804 as i32 as u16,
-    0x2801 as i32 as u16,
-    0x2803 as i32 as u16,
-    0x2805 as i32 as u16,
-    0x2842 as i32 as u16,
-    0x2844 as i32 as u16,
-    0x2846 as i32 as u16,
-    0x2849 as i32 as u16,
-    0x284b as i32 as u16,
-    0x284d as i32 as u16,
-    0x2c40 as i32 as u16,
-    0x284a as i32 as u16,
-    0x284c as i32 as u16,
-    0x284e as i32 as u16,
-    0x2c41 as i32 as u16,
-    0x2c42 as i32 as u16,
-    0x2c43 as i32 as u16,
-    0x2c44 as i32 as u16,
-    0x2c45 as i32 as u16,
-    0x2c46 as i32 as u16,
-    0x2c47 as i32 as u16,
-    0x2851 as i32 as u16,
-    0x2853 as i32 as u16,
-    0x2855 as i32 as u16,
-    0x2c48 as i32 as u16,
-    0x2852 as i32 as u16,
-    0x2854 as i32 as u16,
-    0x2856 as i32 as u16,
-    0x2c49 as i32 as u16,
-    0x2c4a as i32 as u16,
-    0x2c4b as i32 as u16,
-    0x2c4c as i32 as u16,
-    0x2c4d as i32 as u16,
-    0x2c4e as i32 as u16,
-    0x2c4f as i32 as u16,
-    0x2c82 as i32 as u16,
-    0x2e01 as i32 as u16,
-    0x3180 as i32 as u16,
-    0x2c87 as i32 as u16,
-    0x2f01 as i32 as u16,
-    0x2f02 as i32 as u16,
-    0x2f03 as i32 as u16,
-    0x2e06 as i32 as u16,
-    0x3185 as i32 as u16,
-    0x3000 as i32 as u16,
-    0x3001 as i32 as u16,
-    0x3002 as i32 as u16,
-    0x4640 as i32 as u16,
-    0x4641 as i32 as u16,
-    0x4680 as i32 as u16,
-    0x46c0 as i32 as u16,
-    0x46c2 as i32 as u16,
-    0x46c1 as i32 as u16,
-    0x4700 as i32 as u16,
-    0x4740 as i32 as u16,
-    0x4780 as i32 as u16,
-    0x47c0 as i32 as u16,
-    0x47c2 as i32 as u16,
-    0x4900 as i32 as u16,
-    0x4940 as i32 as u16,
-    0x4980 as i32 as u16,
-    0x4982 as i32 as u16,
-    0x4a00 as i32 as u16,
-    0x49c2 as i32 as u16,
-    0x4a03 as i32 as u16,
-    0x4a04 as i32 as u16,
-    0x4a40 as i32 as u16,
-    0x4a41 as i32 as u16,
-    0x4a80 as i32 as u16,
-    0x4a81 as i32 as u16,
-    0x4ac0 as i32 as u16,
-    0x4ac1 as i32 as u16,
-    0x4bc0 as i32 as u16,
-    0x4bc1 as i32 as u16,
-    0x4b00 as i32 as u16,
-    0x4b01 as i32 as u16,
-    0x4b40 as i32 as u16,
-    0x4b41 as i32 as u16,
-    0x4bc2 as i32 as u16,
-    0x4bc3 as i32 as u16,
-    0x4b80 as i32 as u16,
-    0x4b81 as i32 as u16,
-    0x4b82 as i32 as u16,
-    0x4b83 as i32 as u16,
-    0x4c00 as i32 as u16,
-    0x4c01 as i32 as u16,
-    0x4c02 as i32 as u16,
-    0x4c03 as i32 as u16,
-    0x5600 as i32 as u16,
-    0x5440 as i32 as u16,
-    0x5442 as i32 as u16,
-    0x5444 as i32 as u16,
-    0x5446 as i32 as u16,
-    0x5448 as i32 as u16,
-    0x544a as i32 as u16,
-    0x544c as i32 as u16,
-    0x544e as i32 as u16,
-    0x5450 as i32 as u16,
-    0x5452 as i32 as u16,
-    0x5454 as i32 as u16,
-    0x5456 as i32 as u16,
-    0x5480 as i32 as u16,
-    0x5482 as i32 as u16,
-    0x5484 as i32 as u16,
-    0x54c0 as i32 as u16,
-    0x54c1 as i32 as u16,
-    0x5500 as i32 as u16,
-    0x5501 as i32 as u16,
-    0x5540 as i32 as u16,
-    0x5541 as i32 as u16,
-    0x5580 as i32 as u16,
-    0x5581 as i32 as u16,
-    0x55c0 as i32 as u16,
-    0x55c1 as i32 as u16,
-    0x5680 as i32 as u16,
-    0x58c0 as i32 as u16,
-    0x5700 as i32 as u16,
-    0x5702 as i32 as u16,
-    0x5704 as i32 as u16,
-    0x5706 as i32 as u16,
-    0x5708 as i32 as u16,
-    0x570a as i32 as u16,
-    0x570c as i32 as u16,
-    0x570e as i32 as u16,
-    0x5710 as i32 as u16,
-    0x5712 as i32 as u16,
-    0x5714 as i32 as u16,
-    0x5716 as i32 as u16,
-    0x5740 as i32 as u16,
-    0x5742 as i32 as u16,
-    0x5744 as i32 as u16,
-    0x5780 as i32 as u16,
-    0x5781 as i32 as u16,
-    0x57c0 as i32 as u16,
-    0x57c1 as i32 as u16,
-    0x5800 as i32 as u16,
-    0x5801 as i32 as u16,
-    0x5840 as i32 as u16,
-    0x5841 as i32 as u16,
-    0x5880 as i32 as u16,
-    0x5881 as i32 as u16,
-    0x5900 as i32 as u16,
-    0x5901 as i32 as u16,
-    0x5902 as i32 as u16,
-    0x5903 as i32 as u16,
-    0x5940 as i32 as u16,
-    0x8e80 as i32 as u16,
-    0x8e82 as i32 as u16,
-    0x8ec0 as i32 as u16,
-    0x8f00 as i32 as u16,
-    0x8f01 as i32 as u16,
-    0x8f40 as i32 as u16,
-    0x8f41 as i32 as u16,
-    0x8f81 as i32 as u16,
-    0x8f80 as i32 as u16,
-    0x8f83 as i32 as u16,
-    0x8fc0 as i32 as u16,
-    0x8fc1 as i32 as u16,
-    0x9000 as i32 as u16,
-];
-static mut unicode_gc_name_table: [std::os::raw::c_char; 660] = unsafe {
-    *::std::mem::transmute::<&[u8; 660],
-                                 &[std::os::raw::c_char; 660]>(b"Cn,Unassigned\x00Lu,Uppercase_Letter\x00Ll,Lowercase_Letter\x00Lt,Titlecase_Letter\x00Lm,Modifier_Letter\x00Lo,Other_Letter\x00Mn,Nonspacing_Mark\x00Mc,Spacing_Mark\x00Me,Enclosing_Mark\x00Nd,Decimal_Number,digit\x00Nl,Letter_Number\x00No,Other_Number\x00Sm,Math_Symbol\x00Sc,Currency_Symbol\x00Sk,Modifier_Symbol\x00So,Other_Symbol\x00Pc,Connector_Punctuation\x00Pd,Dash_Punctuation\x00Ps,Open_Punctuation\x00Pe,Close_Punctuation\x00Pi,Initial_Punctuation\x00Pf,Final_Punctuation\x00Po,Other_Punctuation\x00Zs,Space_Separator\x00Zl,Line_Separator\x00Zp,Paragraph_Separator\x00Cc,Control,cntrl\x00Cf,Format\x00Cs,Surrogate\x00Co,Private_Use\x00LC,Cased_Letter\x00L,Letter\x00M,Mark,Combining_Mark\x00N,Number\x00S,Symbol\x00P,Punctuation,punct\x00Z,Separator\x00C,Other\x00\x00")
-};
-static mut unicode_gc_table: [u8; 3790] = [
-    0xfa as i32 as u8,
-    0x18 as i32 as u8,
-    0x17 as i32 as u8,
-    0x56 as i32 as u8,
-    0xd as i32 as u8,
-    0x56 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x16 as i32 as u8,
-    0xc as i32 as u8,
-    0x16 as i32 as u8,
-    0x11 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x36 as i32 as u8,
-    0x4c as i32 as u8,
-    0x36 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x16 as i32 as u8,
-    0x13 as i32 as u8,
-    0xe as i32 as u8,
-    0x10 as i32 as u8,
-    0xe as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0xc as i32 as u8,
-    0x13 as i32 as u8,
-    0xc as i32 as u8,
-    0xfa as i32 as u8,
-    0x19 as i32 as u8,
-    0x17 as i32 as u8,
-    0x16 as i32 as u8,
-    0x6d as i32 as u8,
-    0xf as i32 as u8,
-    0x16 as i32 as u8,
-    0xe as i32 as u8,
-    0xf as i32 as u8,
-    0x5 as i32 as u8,
-    0x14 as i32 as u8,
-    0xc as i32 as u8,
-    0x1b as i32 as u8,
-    0xf as i32 as u8,
-    0xe as i32 as u8,
-    0xf as i32 as u8,
-    0xc as i32 as u8,
-    0x2b as i32 as u8,
-    0xe as i32 as u8,
-    0x2 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe as i32 as u8,
-    0xb as i32 as u8,
-    0x5 as i32 as u8,
-    0x15 as i32 as u8,
-    0x4b as i32 as u8,
-    0x16 as i32 as u8,
-    0xe1 as i32 as u8,
-    0xf as i32 as u8,
-    0xc as i32 as u8,
-    0xc1 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x10 as i32 as u8,
-    0xc as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xff as i32 as u8,
-    0x30 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x27 as i32 as u8,
-    0xbf as i32 as u8,
-    0x22 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5f as i32 as u8,
-    0x5f as i32 as u8,
-    0x21 as i32 as u8,
-    0x22 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x41 as i32 as u8,
-    0x42 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x9f as i32 as u8,
-    0x7f as i32 as u8,
-    0x2 as i32 as u8,
-    0x5f as i32 as u8,
-    0x5f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5f as i32 as u8,
-    0x3f as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x3f as i32 as u8,
-    0x22 as i32 as u8,
-    0x65 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0xa as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x32 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x22 as i32 as u8,
-    0x5f as i32 as u8,
-    0x41 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x3c as i32 as u8,
-    0x5 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x13 as i32 as u8,
-    0xe4 as i32 as u8,
-    0xa as i32 as u8,
-    0x6e as i32 as u8,
-    0xe4 as i32 as u8,
-    0x4 as i32 as u8,
-    0xee as i32 as u8,
-    0x6 as i32 as u8,
-    0x84 as i32 as u8,
-    0xce as i32 as u8,
-    0x4 as i32 as u8,
-    0xe as i32 as u8,
-    0x4 as i32 as u8,
-    0xee as i32 as u8,
-    0x9 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x68 as i32 as u8,
-    0x7f as i32 as u8,
-    0x4 as i32 as u8,
-    0xe as i32 as u8,
-    0x3f as i32 as u8,
-    0x20 as i32 as u8,
-    0x4 as i32 as u8,
-    0x42 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1 as i32 as u8,
-    0x60 as i32 as u8,
-    0x2e as i32 as u8,
-    0x1 as i32 as u8,
-    0x16 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1b as i32 as u8,
-    0x3f as i32 as u8,
-    0x2 as i32 as u8,
-    0x41 as i32 as u8,
-    0x42 as i32 as u8,
-    0xff as i32 as u8,
-    0x10 as i32 as u8,
-    0x62 as i32 as u8,
-    0x3f as i32 as u8,
-    0xc as i32 as u8,
-    0x5f as i32 as u8,
-    0x3f as i32 as u8,
-    0x2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x2b as i32 as u8,
-    0xe2 as i32 as u8,
-    0x28 as i32 as u8,
-    0xff as i32 as u8,
-    0x1a as i32 as u8,
-    0xf as i32 as u8,
-    0x86 as i32 as u8,
-    0x28 as i32 as u8,
-    0xff as i32 as u8,
-    0x2f as i32 as u8,
-    0xff as i32 as u8,
-    0x6 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x58 as i32 as u8,
-    0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1e as i32 as u8,
-    0x20 as i32 as u8,
-    0x4 as i32 as u8,
-    0xb6 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x21 as i32 as u8,
-    0x16 as i32 as u8,
-    0x11 as i32 as u8,
-    0x20 as i32 as u8,
-    0x2f as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x25 as i32 as u8,
-    0x11 as i32 as u8,
-    0x6 as i32 as u8,
-    0x16 as i32 as u8,
-    0x26 as i32 as u8,
-    0x16 as i32 as u8,
-    0x26 as i32 as u8,
-    0x16 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x13 as i32 as u8,
-    0x60 as i32 as u8,
-    0x65 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xbb as i32 as u8,
-    0x4c as i32 as u8,
-    0x36 as i32 as u8,
-    0xd as i32 as u8,
-    0x36 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x18 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe6 as i32 as u8,
-    0xd as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x76 as i32 as u8,
-    0x25 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5b as i32 as u8,
-    0x16 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x1b as i32 as u8,
-    0xf as i32 as u8,
-    0xa6 as i32 as u8,
-    0x24 as i32 as u8,
-    0x26 as i32 as u8,
-    0xf as i32 as u8,
-    0x66 as i32 as u8,
-    0x25 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x45 as i32 as u8,
-    0x2f as i32 as u8,
-    0x5 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x13 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x51 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x19 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x24 as i32 as u8,
-    0xf as i32 as u8,
-    0x56 as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2d as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0x66 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x46 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x20 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x11 as i32 as u8,
-    0x46 as i32 as u8,
-    0x20 as i32 as u8,
-    0x16 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2d as i32 as u8,
-    0xe5 as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xa as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x1b as i32 as u8,
-    0xe6 as i32 as u8,
-    0x18 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x47 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x26 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x16 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x25 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x47 as i32 as u8,
-    0x66 as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x60 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x25 as i32 as u8,
-    0x2d as i32 as u8,
-    0xab as i32 as u8,
-    0xf as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x60 as i32 as u8,
-    0x25 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x26 as i32 as u8,
-    0x60 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0x46 as i32 as u8,
-    0x40 as i32 as u8,
-    0x6 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x26 as i32 as u8,
-    0x45 as i32 as u8,
-    0x6 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x47 as i32 as u8,
-    0x86 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x25 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x16 as i32 as u8,
-    0xd as i32 as u8,
-    0xc0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa6 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x25 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x66 as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x60 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xf as i32 as u8,
-    0x5 as i32 as u8,
-    0xab as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x40 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x40 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x40 as i32 as u8,
-    0x25 as i32 as u8,
-    0x40 as i32 as u8,
-    0x45 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x60 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x40 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4b as i32 as u8,
-    0xaf as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0x80 as i32 as u8,
-    0x6 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8 as i32 as u8,
-    0x40 as i32 as u8,
-    0x5 as i32 as u8,
-    0x46 as i32 as u8,
-    0x67 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0x66 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x25 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xcb as i32 as u8,
-    0xf as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x87 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x27 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x21 as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0x47 as i32 as u8,
-    0x66 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xf as i32 as u8,
-    0x60 as i32 as u8,
-    0x45 as i32 as u8,
-    0x7 as i32 as u8,
-    0xcb as i32 as u8,
-    0x45 as i32 as u8,
-    0x26 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xeb as i32 as u8,
-    0x1 as i32 as u8,
-    0xf as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xa as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x40 as i32 as u8,
-    0x6 as i32 as u8,
-    0x60 as i32 as u8,
-    0x47 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0xe7 as i32 as u8,
-    0 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x28 as i32 as u8,
-    0x6 as i32 as u8,
-    0x25 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x60 as i32 as u8,
-    0xd as i32 as u8,
-    0xa5 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1d as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x25 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x65 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x18 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4f as i32 as u8,
-    0xf6 as i32 as u8,
-    0x7 as i32 as u8,
-    0xf as i32 as u8,
-    0x16 as i32 as u8,
-    0x4f as i32 as u8,
-    0x26 as i32 as u8,
-    0xaf as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xeb as i32 as u8,
-    0x2 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x60 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x86 as i32 as u8,
-    0x16 as i32 as u8,
-    0x26 as i32 as u8,
-    0x85 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1c as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xaf as i32 as u8,
-    0 as i32 as u8,
-    0x2f as i32 as u8,
-    0x96 as i32 as u8,
-    0x6f as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1d as i32 as u8,
-    0xe5 as i32 as u8,
-    0x23 as i32 as u8,
-    0x27 as i32 as u8,
-    0x66 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb6 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x65 as i32 as u8,
-    0x46 as i32 as u8,
-    0x5 as i32 as u8,
-    0x47 as i32 as u8,
-    0x25 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x45 as i32 as u8,
-    0x66 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1e as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x23 as i32 as u8,
-    0x16 as i32 as u8,
-    0x4 as i32 as u8,
-    0x42 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x31 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x3b as i32 as u8,
-    0x20 as i32 as u8,
-    0x46 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x1 as i32 as u8,
-    0xeb as i32 as u8,
-    0xc as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8 as i32 as u8,
-    0xef as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x4e as i32 as u8,
-    0x20 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x11 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x81 as i32 as u8,
-    0xe4 as i32 as u8,
-    0xf as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x9 as i32 as u8,
-    0x17 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x43 as i32 as u8,
-    0x56 as i32 as u8,
-    0x4a as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0x46 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xa as i32 as u8,
-    0x46 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xa as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2c as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc6 as i32 as u8,
-    0xe7 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x56 as i32 as u8,
-    0x4 as i32 as u8,
-    0x56 as i32 as u8,
-    0xd as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xeb as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x11 as i32 as u8,
-    0x76 as i32 as u8,
-    0x46 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1b as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2d as i32 as u8,
-    0xc0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1a as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x3e as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x17 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x67 as i32 as u8,
-    0x26 as i32 as u8,
-    0x47 as i32 as u8,
-    0x60 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x46 as i32 as u8,
-    0x60 as i32 as u8,
-    0xf as i32 as u8,
-    0x40 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x20 as i32 as u8,
-    0x85 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x12 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-    0x1a as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2d as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0xa7 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x4 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x37 as i32 as u8,
-    0x66 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x86 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x87 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xd6 as i32 as u8,
-    0xef as i32 as u8,
-    0x2 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1 as i32 as u8,
-    0xef as i32 as u8,
-    0x1 as i32 as u8,
-    0x40 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x7 as i32 as u8,
-    0x66 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x46 as i32 as u8,
-    0x25 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x47 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x46 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0x76 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1c as i32 as u8,
-    0xe7 as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x40 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x40 as i32 as u8,
-    0x45 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x23 as i32 as u8,
-    0x20 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf6 as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0x46 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x65 as i32 as u8,
-    0x6 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x25 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x24 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x37 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1a as i32 as u8,
-    0xe4 as i32 as u8,
-    0x1d as i32 as u8,
-    0xe6 as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0xff as i32 as u8,
-    0x80 as i32 as u8,
-    0xe as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xff as i32 as u8,
-    0x5a as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x3f as i32 as u8,
-    0xc2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xe3 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xe3 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0xe3 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0x61 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe as i32 as u8,
-    0x2 as i32 as u8,
-    0x4e as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0x61 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4e as i32 as u8,
-    0x62 as i32 as u8,
-    0x20 as i32 as u8,
-    0x22 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4e as i32 as u8,
-    0x20 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x22 as i32 as u8,
-    0x61 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0xf7 as i32 as u8,
-    0x3 as i32 as u8,
-    0x9b as i32 as u8,
-    0xb1 as i32 as u8,
-    0x36 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x12 as i32 as u8,
-    0x34 as i32 as u8,
-    0x15 as i32 as u8,
-    0x12 as i32 as u8,
-    0x14 as i32 as u8,
-    0xf6 as i32 as u8,
-    0 as i32 as u8,
-    0x18 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9b as i32 as u8,
-    0x17 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x76 as i32 as u8,
-    0x30 as i32 as u8,
-    0x56 as i32 as u8,
-    0xc as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x3 as i32 as u8,
-    0xc as i32 as u8,
-    0x16 as i32 as u8,
-    0x10 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x17 as i32 as u8,
-    0x9b as i32 as u8,
-    0 as i32 as u8,
-    0xfb as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x4 as i32 as u8,
-    0x20 as i32 as u8,
-    0xab as i32 as u8,
-    0x4c as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x4 as i32 as u8,
-    0xeb as i32 as u8,
-    0x2 as i32 as u8,
-    0x4c as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0xed as i32 as u8,
-    0x18 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x68 as i32 as u8,
-    0x6 as i32 as u8,
-    0x48 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x2f as i32 as u8,
-    0x1 as i32 as u8,
-    0x6f as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x2 as i32 as u8,
-    0x41 as i32 as u8,
-    0x22 as i32 as u8,
-    0x41 as i32 as u8,
-    0x2 as i32 as u8,
-    0xf as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0xc as i32 as u8,
-    0x81 as i32 as u8,
-    0xaf as i32 as u8,
-    0x1 as i32 as u8,
-    0xf as i32 as u8,
-    0x1 as i32 as u8,
-    0xf as i32 as u8,
-    0x1 as i32 as u8,
-    0xf as i32 as u8,
-    0x61 as i32 as u8,
-    0xf as i32 as u8,
-    0x2 as i32 as u8,
-    0x61 as i32 as u8,
-    0x2 as i32 as u8,
-    0x65 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2f as i32 as u8,
-    0x22 as i32 as u8,
-    0x21 as i32 as u8,
-    0x8c as i32 as u8,
-    0x3f as i32 as u8,
-    0x42 as i32 as u8,
-    0xf as i32 as u8,
-    0xc as i32 as u8,
-    0x2f as i32 as u8,
-    0x2 as i32 as u8,
-    0xf as i32 as u8,
-    0xeb as i32 as u8,
-    0x8 as i32 as u8,
-    0xea as i32 as u8,
-    0x1b as i32 as u8,
-    0x3f as i32 as u8,
-    0x6a as i32 as u8,
-    0xb as i32 as u8,
-    0x2f as i32 as u8,
-    0x60 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8f as i32 as u8,
-    0x2c as i32 as u8,
-    0x6f as i32 as u8,
-    0xc as i32 as u8,
-    0x2f as i32 as u8,
-    0xc as i32 as u8,
-    0x2f as i32 as u8,
-    0xc as i32 as u8,
-    0xcf as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x17 as i32 as u8,
-    0x2c as i32 as u8,
-    0x2f as i32 as u8,
-    0xc as i32 as u8,
-    0xf as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x17 as i32 as u8,
-    0xec as i32 as u8,
-    0x80 as i32 as u8,
-    0x84 as i32 as u8,
-    0xef as i32 as u8,
-    0 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xef as i32 as u8,
-    0xc as i32 as u8,
-    0x2c as i32 as u8,
-    0xcf as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xef as i32 as u8,
-    0x49 as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x16 as i32 as u8,
-    0xec as i32 as u8,
-    0x11 as i32 as u8,
-    0xef as i32 as u8,
-    0x20 as i32 as u8,
-    0xac as i32 as u8,
-    0xef as i32 as u8,
-    0x3d as i32 as u8,
-    0xe0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xef as i32 as u8,
-    0x3 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xd as i32 as u8,
-    0xeb as i32 as u8,
-    0x34 as i32 as u8,
-    0xef as i32 as u8,
-    0x46 as i32 as u8,
-    0xeb as i32 as u8,
-    0xe as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x2f as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x1 as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x2e as i32 as u8,
-    0xec as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x67 as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x70 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xeb as i32 as u8,
-    0x16 as i32 as u8,
-    0xef as i32 as u8,
-    0x24 as i32 as u8,
-    0x8c as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xec as i32 as u8,
-    0x17 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xec as i32 as u8,
-    0x8 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x78 as i32 as u8,
-    0xec as i32 as u8,
-    0x7b as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xec as i32 as u8,
-    0x37 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xec as i32 as u8,
-    0x18 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0xec as i32 as u8,
-    0x80 as i32 as u8,
-    0x7a as i32 as u8,
-    0xef as i32 as u8,
-    0x28 as i32 as u8,
-    0xec as i32 as u8,
-    0xd as i32 as u8,
-    0x2f as i32 as u8,
-    0xac as i32 as u8,
-    0xef as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0xef as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x61 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0x5f as i32 as u8,
-    0x21 as i32 as u8,
-    0x22 as i32 as u8,
-    0xdf as i32 as u8,
-    0x41 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3f as i32 as u8,
-    0x2 as i32 as u8,
-    0x3f as i32 as u8,
-    0x82 as i32 as u8,
-    0x24 as i32 as u8,
-    0x41 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x5a as i32 as u8,
-    0x2 as i32 as u8,
-    0xaf as i32 as u8,
-    0x7f as i32 as u8,
-    0x46 as i32 as u8,
-    0x3f as i32 as u8,
-    0x80 as i32 as u8,
-    0x76 as i32 as u8,
-    0xb as i32 as u8,
-    0x36 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1e as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x30 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x18 as i32 as u8,
-    0x36 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x56 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x16 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x11 as i32 as u8,
-    0x36 as i32 as u8,
-    0x11 as i32 as u8,
-    0x16 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x36 as i32 as u8,
-    0x14 as i32 as u8,
-    0x15 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x96 as i32 as u8,
-    0x4 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x31 as i32 as u8,
-    0x76 as i32 as u8,
-    0x11 as i32 as u8,
-    0x16 as i32 as u8,
-    0x12 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x2f as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x25 as i32 as u8,
-    0xef as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x51 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe0 as i32 as u8,
-    0x12 as i32 as u8,
-    0xef as i32 as u8,
-    0x4 as i32 as u8,
-    0x60 as i32 as u8,
-    0x17 as i32 as u8,
-    0x56 as i32 as u8,
-    0xf as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x2f as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0x33 as i32 as u8,
-    0xf as i32 as u8,
-    0xea as i32 as u8,
-    0x1 as i32 as u8,
-    0x66 as i32 as u8,
-    0x27 as i32 as u8,
-    0x11 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2f as i32 as u8,
-    0x4a as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x2f as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x4e as i32 as u8,
-    0x20 as i32 as u8,
-    0x26 as i32 as u8,
-    0x2e as i32 as u8,
-    0x24 as i32 as u8,
-    0x5 as i32 as u8,
-    0x11 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x52 as i32 as u8,
-    0x16 as i32 as u8,
-    0x44 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x56 as i32 as u8,
-    0 as i32 as u8,
-    0x2f as i32 as u8,
-    0x6b as i32 as u8,
-    0xef as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x18 as i32 as u8,
-    0xef as i32 as u8,
-    0x1c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8 as i32 as u8,
-    0xef as i32 as u8,
-    0x17 as i32 as u8,
-    0 as i32 as u8,
-    0xeb as i32 as u8,
-    0x2 as i32 as u8,
-    0xef as i32 as u8,
-    0x16 as i32 as u8,
-    0xeb as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0xeb as i32 as u8,
-    0x7 as i32 as u8,
-    0xef as i32 as u8,
-    0x18 as i32 as u8,
-    0xeb as i32 as u8,
-    0x2 as i32 as u8,
-    0xef as i32 as u8,
-    0x1f as i32 as u8,
-    0xeb as i32 as u8,
-    0x7 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x99 as i32 as u8,
-    0x38 as i32 as u8,
-    0xef as i32 as u8,
-    0x38 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x75 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xd as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x83 as i32 as u8,
-    0xef as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-    0x2f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x84 as i32 as u8,
-    0x4 as i32 as u8,
-    0x56 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x25 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xc as i32 as u8,
-    0xff as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x48 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x16 as i32 as u8,
-    0x4 as i32 as u8,
-    0xff as i32 as u8,
-    0x14 as i32 as u8,
-    0x24 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x3e as i32 as u8,
-    0xea as i32 as u8,
-    0x2 as i32 as u8,
-    0x26 as i32 as u8,
-    0xb6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xee as i32 as u8,
-    0xf as i32 as u8,
-    0xe4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2e as i32 as u8,
-    0xff as i32 as u8,
-    0x6 as i32 as u8,
-    0x22 as i32 as u8,
-    0xff as i32 as u8,
-    0x36 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe2 as i32 as u8,
-    0 as i32 as u8,
-    0x9f as i32 as u8,
-    0xff as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x2e as i32 as u8,
-    0x7f as i32 as u8,
-    0x5 as i32 as u8,
-    0x7f as i32 as u8,
-    0x22 as i32 as u8,
-    0xff as i32 as u8,
-    0xd as i32 as u8,
-    0x61 as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2 as i32 as u8,
-    0xff as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5f as i32 as u8,
-    0x41 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x22 as i32 as u8,
-    0x3f as i32 as u8,
-    0x5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x2 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x45 as i32 as u8,
-    0x6 as i32 as u8,
-    0x65 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6f as i32 as u8,
-    0x6 as i32 as u8,
-    0x40 as i32 as u8,
-    0xab as i32 as u8,
-    0x2f as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2c as i32 as u8,
-    0x76 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2a as i32 as u8,
-    0xe7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0xa as i32 as u8,
-    0xa5 as i32 as u8,
-    0x56 as i32 as u8,
-    0x5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x25 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x14 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x15 as i32 as u8,
-    0x40 as i32 as u8,
-    0x46 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x66 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x47 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x60 as i32 as u8,
-    0x36 as i32 as u8,
-    0x85 as i32 as u8,
-    0x6 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x21 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x45 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0x76 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8 as i32 as u8,
-    0x4 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x4f as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2a as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x46 as i32 as u8,
-    0x25 as i32 as u8,
-    0x26 as i32 as u8,
-    0x85 as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x10 as i32 as u8,
-    0x25 as i32 as u8,
-    0x4 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0x36 as i32 as u8,
-    0x5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x23 as i32 as u8,
-    0xe as i32 as u8,
-    0x64 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x2e as i32 as u8,
-    0x60 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x48 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1b as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x16 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xab as i32 as u8,
-    0x1c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0x60 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x29 as i32 as u8,
-    0x60 as i32 as u8,
-    0xfc as i32 as u8,
-    0x87 as i32 as u8,
-    0x78 as i32 as u8,
-    0xfd as i32 as u8,
-    0x98 as i32 as u8,
-    0x78 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x62 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1e as i32 as u8,
-    0xc2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x82 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0xc as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x64 as i32 as u8,
-    0xee as i32 as u8,
-    0x8 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x9 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x38 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2e as i32 as u8,
-    0xe0 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x4 as i32 as u8,
-    0xd as i32 as u8,
-    0xf as i32 as u8,
-    0x20 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x8 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x16 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x16 as i32 as u8,
-    0x31 as i32 as u8,
-    0x30 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x36 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x76 as i32 as u8,
-    0x50 as i32 as u8,
-    0x56 as i32 as u8,
-    0 as i32 as u8,
-    0x76 as i32 as u8,
-    0x11 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x56 as i32 as u8,
-    0xc as i32 as u8,
-    0x11 as i32 as u8,
-    0x4c as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xd as i32 as u8,
-    0x36 as i32 as u8,
-    0x60 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x7f as i32 as u8,
-    0x20 as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x56 as i32 as u8,
-    0xd as i32 as u8,
-    0x56 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x16 as i32 as u8,
-    0xc as i32 as u8,
-    0x16 as i32 as u8,
-    0x11 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x36 as i32 as u8,
-    0x4c as i32 as u8,
-    0x36 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0x16 as i32 as u8,
-    0x13 as i32 as u8,
-    0xe as i32 as u8,
-    0x10 as i32 as u8,
-    0xe as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0x12 as i32 as u8,
-    0xc as i32 as u8,
-    0x13 as i32 as u8,
-    0xc as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x16 as i32 as u8,
-    0x12 as i32 as u8,
-    0x13 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x25 as i32 as u8,
-    0x24 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x17 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x45 as i32 as u8,
-    0x40 as i32 as u8,
-    0x2d as i32 as u8,
-    0xc as i32 as u8,
-    0xe as i32 as u8,
-    0xf as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0x6c as i32 as u8,
-    0x2f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5b as i32 as u8,
-    0x2f as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1a as i32 as u8,
-    0xe5 as i32 as u8,
-    0x73 as i32 as u8,
-    0x80 as i32 as u8,
-    0x56 as i32 as u8,
-    0x60 as i32 as u8,
-    0xeb as i32 as u8,
-    0x25 as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-    0x1 as i32 as u8,
-    0xea as i32 as u8,
-    0x2d as i32 as u8,
-    0x6b as i32 as u8,
-    0xef as i32 as u8,
-    0x9 as i32 as u8,
-    0x2b as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x27 as i32 as u8,
-    0xef as i32 as u8,
-    0x25 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7a as i32 as u8,
-    0xe5 as i32 as u8,
-    0x15 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x29 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0xeb as i32 as u8,
-    0x13 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x18 as i32 as u8,
-    0x6b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xc as i32 as u8,
-    0xa as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1e as i32 as u8,
-    0x86 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x60 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x8a as i32 as u8,
-    0xe0 as i32 as u8,
-    0x22 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x46 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1c as i32 as u8,
-    0x60 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1c as i32 as u8,
-    0x60 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x10 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x40 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xeb as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0x2f as i32 as u8,
-    0xcb as i32 as u8,
-    0xe5 as i32 as u8,
-    0x17 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xeb as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x28 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0xab as i32 as u8,
-    0x40 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x38 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x30 as i32 as u8,
-    0x60 as i32 as u8,
-    0x2b as i32 as u8,
-    0x25 as i32 as u8,
-    0xeb as i32 as u8,
-    0x8 as i32 as u8,
-    0x20 as i32 as u8,
-    0xeb as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x80 as i32 as u8,
-    0x66 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x15 as i32 as u8,
-    0x20 as i32 as u8,
-    0x46 as i32 as u8,
-    0x60 as i32 as u8,
-    0x6 as i32 as u8,
-    0xeb as i32 as u8,
-    0x1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x15 as i32 as u8,
-    0x2b as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x15 as i32 as u8,
-    0x4b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x18 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0xe5 as i32 as u8,
-    0x14 as i32 as u8,
-    0x26 as i32 as u8,
-    0x60 as i32 as u8,
-    0x8b as i32 as u8,
-    0xd6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2e as i32 as u8,
-    0x40 as i32 as u8,
-    0xd6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0x20 as i32 as u8,
-    0xeb as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0xeb as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xa as i32 as u8,
-    0xc0 as i32 as u8,
-    0x76 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xcb as i32 as u8,
-    0xe0 as i32 as u8,
-    0x48 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x41 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe1 as i32 as u8,
-    0x2b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x2b as i32 as u8,
-    0xc0 as i32 as u8,
-    0xab as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x66 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0xeb as i32 as u8,
-    0x17 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x22 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x11 as i32 as u8,
-    0x20 as i32 as u8,
-    0x25 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x46 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x15 as i32 as u8,
-    0xeb as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x6b as i32 as u8,
-    0x96 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe5 as i32 as u8,
-    0xd as i32 as u8,
-    0xcb as i32 as u8,
-    0xe0 as i32 as u8,
-    0xc as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2d as i32 as u8,
-    0xe6 as i32 as u8,
-    0x7 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x60 as i32 as u8,
-    0xeb as i32 as u8,
-    0xc as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x46 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x25 as i32 as u8,
-    0x47 as i32 as u8,
-    0x66 as i32 as u8,
-    0x27 as i32 as u8,
-    0x26 as i32 as u8,
-    0x36 as i32 as u8,
-    0x1b as i32 as u8,
-    0x76 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1b as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x46 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x86 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x76 as i32 as u8,
-    0x5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1b as i32 as u8,
-    0x6 as i32 as u8,
-    0x36 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x28 as i32 as u8,
-    0x47 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x27 as i32 as u8,
-    0x65 as i32 as u8,
-    0x76 as i32 as u8,
-    0x66 as i32 as u8,
-    0x16 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x5 as i32 as u8,
-    0x56 as i32 as u8,
-    0 as i32 as u8,
-    0xeb as i32 as u8,
-    0xc as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x11 as i32 as u8,
-    0x47 as i32 as u8,
-    0x46 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x39 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x16 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x47 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x25 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x67 as i32 as u8,
-    0x20 as i32 as u8,
-    0x27 as i32 as u8,
-    0x20 as i32 as u8,
-    0x47 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x85 as i32 as u8,
-    0x27 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x40 as i32 as u8,
-    0x86 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2d as i32 as u8,
-    0x47 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x46 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x65 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x36 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x6 as i32 as u8,
-    0x45 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x28 as i32 as u8,
-    0x47 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x67 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x25 as i32 as u8,
-    0x16 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1e as i32 as u8,
-    0xe5 as i32 as u8,
-    0x27 as i32 as u8,
-    0x47 as i32 as u8,
-    0x66 as i32 as u8,
-    0x20 as i32 as u8,
-    0x67 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0xf6 as i32 as u8,
-    0xf as i32 as u8,
-    0x65 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1a as i32 as u8,
-    0xe5 as i32 as u8,
-    0x28 as i32 as u8,
-    0x47 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x56 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xb as i32 as u8,
-    0xe5 as i32 as u8,
-    0x23 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x27 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2e as i32 as u8,
-    0xe5 as i32 as u8,
-    0x13 as i32 as u8,
-    0x20 as i32 as u8,
-    0x46 as i32 as u8,
-    0x27 as i32 as u8,
-    0x66 as i32 as u8,
-    0x7 as i32 as u8,
-    0x86 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2b as i32 as u8,
-    0x56 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x38 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x47 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5c as i32 as u8,
-    0xe1 as i32 as u8,
-    0x18 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x18 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xeb as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x10 as i32 as u8,
-    0xa7 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x20 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x56 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3e as i32 as u8,
-    0xe5 as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1f as i32 as u8,
-    0x47 as i32 as u8,
-    0x66 as i32 as u8,
-    0x20 as i32 as u8,
-    0x26 as i32 as u8,
-    0x67 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x20 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x5 as i32 as u8,
-    0x66 as i32 as u8,
-    0xf6 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x27 as i32 as u8,
-    0x46 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x56 as i32 as u8,
-    0x5 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x15 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x31 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x7f as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1d as i32 as u8,
-    0x7 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xeb as i32 as u8,
-    0xb as i32 as u8,
-    0x40 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe6 as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0x7 as i32 as u8,
-    0x26 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1e as i32 as u8,
-    0xa6 as i32 as u8,
-    0x40 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x18 as i32 as u8,
-    0x87 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x6 as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0xe5 as i32 as u8,
-    0xb as i32 as u8,
-    0x26 as i32 as u8,
-    0x27 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2f as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7 as i32 as u8,
-    0xeb as i32 as u8,
-    0xd as i32 as u8,
-    0xef as i32 as u8,
-    0 as i32 as u8,
-    0x6d as i32 as u8,
-    0xef as i32 as u8,
-    0x9 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x83 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5e as i32 as u8,
-    0xea as i32 as u8,
-    0x67 as i32 as u8,
-    0 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8a as i32 as u8,
-    0x34 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa7 as i32 as u8,
-    0 as i32 as u8,
-    0xfb as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x3f as i32 as u8,
-    0xe5 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbf as i32 as u8,
-    0xe0 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x31 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x17 as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x60 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x58 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x16 as i32 as u8,
-    0x20 as i32 as u8,
-    0x86 as i32 as u8,
-    0x16 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x28 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x96 as i32 as u8,
-    0x6f as i32 as u8,
-    0x64 as i32 as u8,
-    0x16 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0xcb as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xb as i32 as u8,
-    0xe0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x28 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x18 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x18 as i32 as u8,
-    0xeb as i32 as u8,
-    0xf as i32 as u8,
-    0x76 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5d as i32 as u8,
-    0xe5 as i32 as u8,
-    0x43 as i32 as u8,
-    0x60 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x2f as i32 as u8,
-    0xc0 as i32 as u8,
-    0x66 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x38 as i32 as u8,
-    0x24 as i32 as u8,
-    0x16 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x97 as i32 as u8,
-    0x70 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x84 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe0 as i32 as u8,
-    0x22 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x6f as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x29 as i32 as u8,
-    0x45 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x65 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x7c as i32 as u8,
-    0xe5 as i32 as u8,
-    0x63 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x20 as i32 as u8,
-    0xf as i32 as u8,
-    0x26 as i32 as u8,
-    0x16 as i32 as u8,
-    0x7b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x92 as i32 as u8,
-    0xd4 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x6e as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xef as i32 as u8,
-    0x1f as i32 as u8,
-    0x20 as i32 as u8,
-    0xef as i32 as u8,
-    0x34 as i32 as u8,
-    0x27 as i32 as u8,
-    0x46 as i32 as u8,
-    0x4f as i32 as u8,
-    0xa7 as i32 as u8,
-    0xfb as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0 as i32 as u8,
-    0x2f as i32 as u8,
-    0xc6 as i32 as u8,
-    0xef as i32 as u8,
-    0x16 as i32 as u8,
-    0x66 as i32 as u8,
-    0xef as i32 as u8,
-    0x33 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xf as i32 as u8,
-    0xef as i32 as u8,
-    0x3a as i32 as u8,
-    0x46 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x12 as i32 as u8,
-    0xeb as i32 as u8,
-    0xc as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xef as i32 as u8,
-    0x4f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xeb as i32 as u8,
-    0x11 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7f as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0xa as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1 as i32 as u8,
-    0x20 as i32 as u8,
-    0x21 as i32 as u8,
-    0x20 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0x62 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0xc2 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0x21 as i32 as u8,
-    0 as i32 as u8,
-    0x61 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x12 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x14 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xe2 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xa2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xe2 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xa2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xe2 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xa2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xe2 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xa2 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xe2 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0xa2 as i32 as u8,
-    0x3f as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2a as i32 as u8,
-    0xef as i32 as u8,
-    0x81 as i32 as u8,
-    0x78 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x2f as i32 as u8,
-    0x6f as i32 as u8,
-    0xe6 as i32 as u8,
-    0x2a as i32 as u8,
-    0xef as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xef as i32 as u8,
-    0x6 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2f as i32 as u8,
-    0x96 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x86 as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x7 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x84 as i32 as u8,
-    0xc8 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x9 as i32 as u8,
-    0x20 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4d as i32 as u8,
-    0xe5 as i32 as u8,
-    0x25 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc6 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x60 as i32 as u8,
-    0x5 as i32 as u8,
-    0xf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe8 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x24 as i32 as u8,
-    0x66 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd as i32 as u8,
-    0xe0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x78 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3d as i32 as u8,
-    0x20 as i32 as u8,
-    0xeb as i32 as u8,
-    0x1 as i32 as u8,
-    0xc6 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x21 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x1a as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1a as i32 as u8,
-    0xc6 as i32 as u8,
-    0x4 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x60 as i32 as u8,
-    0x36 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0xeb as i32 as u8,
-    0x33 as i32 as u8,
-    0xf as i32 as u8,
-    0x4b as i32 as u8,
-    0xd as i32 as u8,
-    0x6b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x44 as i32 as u8,
-    0xeb as i32 as u8,
-    0x25 as i32 as u8,
-    0xf as i32 as u8,
-    0xeb as i32 as u8,
-    0x7 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3a as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x13 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x60 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0x25 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x20 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0xc5 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x65 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x9 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2c as i32 as u8,
-    0x2c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x86 as i32 as u8,
-    0xef as i32 as u8,
-    0x24 as i32 as u8,
-    0x60 as i32 as u8,
-    0xef as i32 as u8,
-    0x5c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xef as i32 as u8,
-    0x7 as i32 as u8,
-    0x20 as i32 as u8,
-    0xef as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x7 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x1d as i32 as u8,
-    0xe0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xeb as i32 as u8,
-    0x5 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x30 as i32 as u8,
-    0xef as i32 as u8,
-    0x15 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xef as i32 as u8,
-    0x24 as i32 as u8,
-    0x60 as i32 as u8,
-    0xef as i32 as u8,
-    0x1 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xaf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x12 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0x73 as i32 as u8,
-    0x8e as i32 as u8,
-    0xef as i32 as u8,
-    0x82 as i32 as u8,
-    0x50 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-    0x5 as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-    0x6c as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xef as i32 as u8,
-    0x51 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xef as i32 as u8,
-    0x4 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xc as i32 as u8,
-    0xef as i32 as u8,
-    0x4 as i32 as u8,
-    0x60 as i32 as u8,
-    0xef as i32 as u8,
-    0x30 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x2 as i32 as u8,
-    0xa0 as i32 as u8,
-    0xef as i32 as u8,
-    0x20 as i32 as u8,
-    0xe0 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x16 as i32 as u8,
-    0x20 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x46 as i32 as u8,
-    0xef as i32 as u8,
-    0x71 as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x4a as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x7f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xef as i32 as u8,
-    0x6 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8f as i32 as u8,
-    0x40 as i32 as u8,
-    0x4f as i32 as u8,
-    0x80 as i32 as u8,
-    0xcf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xef as i32 as u8,
-    0x11 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xcf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xcf as i32 as u8,
-    0xe0 as i32 as u8,
-    0x21 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0xef as i32 as u8,
-    0x2f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1d as i32 as u8,
-    0xe9 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x7e as i32 as u8,
-    0xe5 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x66 as i32 as u8,
-    0x56 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1a as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8f as i32 as u8,
-    0xad as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x56 as i32 as u8,
-    0x20 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x95 as i32 as u8,
-    0xfa as i32 as u8,
-    0xe0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x9c as i32 as u8,
-    0xa9 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8b as i32 as u8,
-    0x97 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x96 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x5a as i32 as u8,
-    0xe5 as i32 as u8,
-    0x92 as i32 as u8,
-    0xc3 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xca as i32 as u8,
-    0xac as i32 as u8,
-    0x2e as i32 as u8,
-    0x1b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x16 as i32 as u8,
-    0xfb as i32 as u8,
-    0x58 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x78 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x68 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xbd as i32 as u8,
-    0x88 as i32 as u8,
-    0xfd as i32 as u8,
-    0xc0 as i32 as u8,
-    0xbf as i32 as u8,
-    0x76 as i32 as u8,
-    0x20 as i32 as u8,
-    0xfd as i32 as u8,
-    0xc0 as i32 as u8,
-    0xbf as i32 as u8,
-    0x76 as i32 as u8,
-    0x20 as i32 as u8,
-];
-static mut unicode_script_name_table: [std::os::raw::c_char; 2277] = unsafe {
-    *::std::mem::transmute::<&[u8; 2277],
-                                 &[std::os::raw::c_char; 2277]>(b"Adlam,Adlm\x00Ahom,Ahom\x00Anatolian_Hieroglyphs,Hluw\x00Arabic,Arab\x00Armenian,Armn\x00Avestan,Avst\x00Balinese,Bali\x00Bamum,Bamu\x00Bassa_Vah,Bass\x00Batak,Batk\x00Bengali,Beng\x00Bhaiksuki,Bhks\x00Bopomofo,Bopo\x00Brahmi,Brah\x00Braille,Brai\x00Buginese,Bugi\x00Buhid,Buhd\x00Canadian_Aboriginal,Cans\x00Carian,Cari\x00Caucasian_Albanian,Aghb\x00Chakma,Cakm\x00Cham,Cham\x00Cherokee,Cher\x00Chorasmian,Chrs\x00Common,Zyyy\x00Coptic,Copt,Qaac\x00Cuneiform,Xsux\x00Cypriot,Cprt\x00Cyrillic,Cyrl\x00Deseret,Dsrt\x00Devanagari,Deva\x00Dives_Akuru,Diak\x00Dogra,Dogr\x00Duployan,Dupl\x00Egyptian_Hieroglyphs,Egyp\x00Elbasan,Elba\x00Elymaic,Elym\x00Ethiopic,Ethi\x00Georgian,Geor\x00Glagolitic,Glag\x00Gothic,Goth\x00Grantha,Gran\x00Greek,Grek\x00Gujarati,Gujr\x00Gunjala_Gondi,Gong\x00Gurmukhi,Guru\x00Han,Hani\x00Hangul,Hang\x00Hanifi_Rohingya,Rohg\x00Hanunoo,Hano\x00Hatran,Hatr\x00Hebrew,Hebr\x00Hiragana,Hira\x00Imperial_Aramaic,Armi\x00Inherited,Zinh,Qaai\x00Inscriptional_Pahlavi,Phli\x00Inscriptional_Parthian,Prti\x00Javanese,Java\x00Kaithi,Kthi\x00Kannada,Knda\x00Katakana,Kana\x00Kayah_Li,Kali\x00Kharoshthi,Khar\x00Khmer,Khmr\x00Khojki,Khoj\x00Khitan_Small_Script,Kits\x00Khudawadi,Sind\x00Lao,Laoo\x00Latin,Latn\x00Lepcha,Lepc\x00Limbu,Limb\x00Linear_A,Lina\x00Linear_B,Linb\x00Lisu,Lisu\x00Lycian,Lyci\x00Lydian,Lydi\x00Makasar,Maka\x00Mahajani,Mahj\x00Malayalam,Mlym\x00Mandaic,Mand\x00Manichaean,Mani\x00Marchen,Marc\x00Masaram_Gondi,Gonm\x00Medefaidrin,Medf\x00Meetei_Mayek,Mtei\x00Mende_Kikakui,Mend\x00Meroitic_Cursive,Merc\x00Meroitic_Hieroglyphs,Mero\x00Miao,Plrd\x00Modi,Modi\x00Mongolian,Mong\x00Mro,Mroo\x00Multani,Mult\x00Myanmar,Mymr\x00Nabataean,Nbat\x00Nandinagari,Nand\x00New_Tai_Lue,Talu\x00Newa,Newa\x00Nko,Nkoo\x00Nushu,Nshu\x00Nyiakeng_Puachue_Hmong,Hmnp\x00Ogham,Ogam\x00Ol_Chiki,Olck\x00Old_Hungarian,Hung\x00Old_Italic,Ital\x00Old_North_Arabian,Narb\x00Old_Permic,Perm\x00Old_Persian,Xpeo\x00Old_Sogdian,Sogo\x00Old_South_Arabian,Sarb\x00Old_Turkic,Orkh\x00Oriya,Orya\x00Osage,Osge\x00Osmanya,Osma\x00Pahawh_Hmong,Hmng\x00Palmyrene,Palm\x00Pau_Cin_Hau,Pauc\x00Phags_Pa,Phag\x00Phoenician,Phnx\x00Psalter_Pahlavi,Phlp\x00Rejang,Rjng\x00Runic,Runr\x00Samaritan,Samr\x00Saurashtra,Saur\x00Sharada,Shrd\x00Shavian,Shaw\x00Siddham,Sidd\x00SignWriting,Sgnw\x00Sinhala,Sinh\x00Sogdian,Sogd\x00Sora_Sompeng,Sora\x00Soyombo,Soyo\x00Sundanese,Sund\x00Syloti_Nagri,Sylo\x00Syriac,Syrc\x00Tagalog,Tglg\x00Tagbanwa,Tagb\x00Tai_Le,Tale\x00Tai_Tham,Lana\x00Tai_Viet,Tavt\x00Takri,Takr\x00Tamil,Taml\x00Tangut,Tang\x00Telugu,Telu\x00Thaana,Thaa\x00Thai,Thai\x00Tibetan,Tibt\x00Tifinagh,Tfng\x00Tirhuta,Tirh\x00Ugaritic,Ugar\x00Vai,Vaii\x00Wancho,Wcho\x00Warang_Citi,Wara\x00Yezidi,Yezi\x00Yi,Yiii\x00Zanabazar_Square,Zanb\x00\x00")
-};
-static mut unicode_script_table: [u8; 2609] = [
-    0xc0 as i32 as u8,
-    0x19 as i32 as u8,
-    0x99 as i32 as u8,
-    0x45 as i32 as u8,
-    0x85 as i32 as u8,
-    0x19 as i32 as u8,
-    0x99 as i32 as u8,
-    0x45 as i32 as u8,
-    0xae as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x8e as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x84 as i32 as u8,
-    0x19 as i32 as u8,
-    0x96 as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9e as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x60 as i32 as u8,
-    0x45 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x19 as i32 as u8,
-    0x84 as i32 as u8,
-    0x45 as i32 as u8,
-    0x84 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd as i32 as u8,
-    0x93 as i32 as u8,
-    0x19 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xf as i32 as u8,
-    0x37 as i32 as u8,
-    0x83 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x93 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0xbe as i32 as u8,
-    0x2b as i32 as u8,
-    0x8d as i32 as u8,
-    0x1a as i32 as u8,
-    0x8f as i32 as u8,
-    0x2b as i32 as u8,
-    0xe0 as i32 as u8,
-    0x24 as i32 as u8,
-    0x1d as i32 as u8,
-    0x81 as i32 as u8,
-    0x37 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x48 as i32 as u8,
-    0x1d as i32 as u8,
-    0 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x34 as i32 as u8,
-    0x7 as i32 as u8,
-    0x9a as i32 as u8,
-    0x34 as i32 as u8,
-    0x3 as i32 as u8,
-    0x85 as i32 as u8,
-    0x34 as i32 as u8,
-    0xa as i32 as u8,
-    0x84 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x85 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8d as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9f as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x89 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8a as i32 as u8,
-    0x37 as i32 as u8,
-    0x99 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x37 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xb as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8d as i32 as u8,
-    0x87 as i32 as u8,
-    0 as i32 as u8,
-    0xbb as i32 as u8,
-    0x87 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x87 as i32 as u8,
-    0xaf as i32 as u8,
-    0x4 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x91 as i32 as u8,
-    0xd as i32 as u8,
-    0xba as i32 as u8,
-    0x63 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x63 as i32 as u8,
-    0xad as i32 as u8,
-    0x7b as i32 as u8,
-    0x1 as i32 as u8,
-    0x8e as i32 as u8,
-    0x7b as i32 as u8,
-    0 as i32 as u8,
-    0x9b as i32 as u8,
-    0x50 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x50 as i32 as u8,
-    0 as i32 as u8,
-    0x8a as i32 as u8,
-    0x87 as i32 as u8,
-    0x34 as i32 as u8,
-    0x94 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x91 as i32 as u8,
-    0x4 as i32 as u8,
-    0xa as i32 as u8,
-    0x8e as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9c as i32 as u8,
-    0x4 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x1f as i32 as u8,
-    0x83 as i32 as u8,
-    0x37 as i32 as u8,
-    0x8e as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x99 as i32 as u8,
-    0x1f as i32 as u8,
-    0x83 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x95 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb as i32 as u8,
-    0x2 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x98 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2e as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2e as i32 as u8,
-    0x1 as i32 as u8,
-    0x95 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2e as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2e as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2e as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2e as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6 as i32 as u8,
-    0x83 as i32 as u8,
-    0x2e as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2e as i32 as u8,
-    0x6 as i32 as u8,
-    0x90 as i32 as u8,
-    0x2e as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x95 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2c as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2c as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2c as i32 as u8,
-    0xe as i32 as u8,
-    0x83 as i32 as u8,
-    0x2c as i32 as u8,
-    0x1 as i32 as u8,
-    0x8b as i32 as u8,
-    0x2c as i32 as u8,
-    0x6 as i32 as u8,
-    0x86 as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1 as i32 as u8,
-    0x95 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x70 as i32 as u8,
-    0x6 as i32 as u8,
-    0x82 as i32 as u8,
-    0x70 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x70 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x70 as i32 as u8,
-    0x1 as i32 as u8,
-    0x91 as i32 as u8,
-    0x70 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x8b as i32 as u8,
-    0x8e as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x8e as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0xd as i32 as u8,
-    0x94 as i32 as u8,
-    0x8e as i32 as u8,
-    0x4 as i32 as u8,
-    0x8c as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x96 as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x90 as i32 as u8,
-    0x2 as i32 as u8,
-    0x87 as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x90 as i32 as u8,
-    0x6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x90 as i32 as u8,
-    0x4 as i32 as u8,
-    0x83 as i32 as u8,
-    0x90 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x90 as i32 as u8,
-    0x6 as i32 as u8,
-    0x88 as i32 as u8,
-    0x90 as i32 as u8,
-    0x8c as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x96 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x3c as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x3c as i32 as u8,
-    0x6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3c as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x3c as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3c as i32 as u8,
-    0xc as i32 as u8,
-    0x8c as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x4f as i32 as u8,
-    0x3 as i32 as u8,
-    0x8f as i32 as u8,
-    0x4f as i32 as u8,
-    0x1 as i32 as u8,
-    0x99 as i32 as u8,
-    0x4f as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x91 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2 as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3 as i32 as u8,
-    0x85 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x81 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x81 as i32 as u8,
-    0x5 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb as i32 as u8,
-    0xb9 as i32 as u8,
-    0x92 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9b as i32 as u8,
-    0x92 as i32 as u8,
-    0x24 as i32 as u8,
-    0x81 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x96 as i32 as u8,
-    0x44 as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x44 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x44 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x44 as i32 as u8,
-    0x1f as i32 as u8,
-    0xc7 as i32 as u8,
-    0x93 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x93 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x93 as i32 as u8,
-    0 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x93 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x93 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x93 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x93 as i32 as u8,
-    0x24 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3f as i32 as u8,
-    0x5e as i32 as u8,
-    0xa5 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x27 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x27 as i32 as u8,
-    0x1 as i32 as u8,
-    0xaa as i32 as u8,
-    0x27 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x83 as i32 as u8,
-    0x27 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x9f as i32 as u8,
-    0x30 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8e as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9f as i32 as u8,
-    0x26 as i32 as u8,
-    0x2 as i32 as u8,
-    0x99 as i32 as u8,
-    0x26 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x17 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x17 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x12 as i32 as u8,
-    0x9c as i32 as u8,
-    0x66 as i32 as u8,
-    0x2 as i32 as u8,
-    0xca as i32 as u8,
-    0x7a as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8a as i32 as u8,
-    0x7a as i32 as u8,
-    0x6 as i32 as u8,
-    0x8c as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x88 as i32 as u8,
-    0xa as i32 as u8,
-    0x94 as i32 as u8,
-    0x32 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8 as i32 as u8,
-    0x93 as i32 as u8,
-    0x11 as i32 as u8,
-    0xb as i32 as u8,
-    0x8c as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0xb as i32 as u8,
-    0xdd as i32 as u8,
-    0x40 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0x5 as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0x5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x5b as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5b as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x88 as i32 as u8,
-    0x5b as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x5b as i32 as u8,
-    0x5 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x5b as i32 as u8,
-    0x6 as i32 as u8,
-    0xaa as i32 as u8,
-    0x5b as i32 as u8,
-    0x4 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x12 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9e as i32 as u8,
-    0x47 as i32 as u8,
-    0 as i32 as u8,
-    0x8b as i32 as u8,
-    0x47 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8b as i32 as u8,
-    0x47 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x47 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8b as i32 as u8,
-    0x47 as i32 as u8,
-    0x9d as i32 as u8,
-    0x8a as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0x8a as i32 as u8,
-    0xa as i32 as u8,
-    0xab as i32 as u8,
-    0x61 as i32 as u8,
-    0x3 as i32 as u8,
-    0x99 as i32 as u8,
-    0x61 as i32 as u8,
-    0x5 as i32 as u8,
-    0x8a as i32 as u8,
-    0x61 as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x61 as i32 as u8,
-    0x9f as i32 as u8,
-    0x40 as i32 as u8,
-    0x9b as i32 as u8,
-    0x10 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x10 as i32 as u8,
-    0xbe as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x9c as i32 as u8,
-    0x8b as i32 as u8,
-    0x1 as i32 as u8,
-    0x8a as i32 as u8,
-    0x8b as i32 as u8,
-    0x5 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8b as i32 as u8,
-    0x5 as i32 as u8,
-    0x8d as i32 as u8,
-    0x8b as i32 as u8,
-    0x1 as i32 as u8,
-    0x90 as i32 as u8,
-    0x37 as i32 as u8,
-    0x3e as i32 as u8,
-    0xcb as i32 as u8,
-    0x7 as i32 as u8,
-    0x3 as i32 as u8,
-    0xac as i32 as u8,
-    0x7 as i32 as u8,
-    0x2 as i32 as u8,
-    0xbf as i32 as u8,
-    0x85 as i32 as u8,
-    0xb3 as i32 as u8,
-    0xa as i32 as u8,
-    0x7 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa as i32 as u8,
-    0xb7 as i32 as u8,
-    0x46 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8e as i32 as u8,
-    0x46 as i32 as u8,
-    0x2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x46 as i32 as u8,
-    0xaf as i32 as u8,
-    0x67 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1d as i32 as u8,
-    0x6 as i32 as u8,
-    0xaa as i32 as u8,
-    0x27 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x27 as i32 as u8,
-    0x87 as i32 as u8,
-    0x85 as i32 as u8,
-    0x7 as i32 as u8,
-    0x82 as i32 as u8,
-    0x37 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8c as i32 as u8,
-    0x37 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x86 as i32 as u8,
-    0x37 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x37 as i32 as u8,
-    0x85 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x37 as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x37 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x4 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x45 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0x1d as i32 as u8,
-    0xb0 as i32 as u8,
-    0x45 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2b as i32 as u8,
-    0x83 as i32 as u8,
-    0x45 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2b as i32 as u8,
-    0x8c as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1d as i32 as u8,
-    0xc5 as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0xb9 as i32 as u8,
-    0x37 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x37 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x9f as i32 as u8,
-    0x45 as i32 as u8,
-    0x95 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x9e as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x8d as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x92 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x8b as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x37 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x8a as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8a as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x8e as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x45 as i32 as u8,
-    0x2 as i32 as u8,
-    0x9f as i32 as u8,
-    0x19 as i32 as u8,
-    0xf as i32 as u8,
-    0xa0 as i32 as u8,
-    0x37 as i32 as u8,
-    0xe as i32 as u8,
-    0xa5 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x45 as i32 as u8,
-    0x85 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x9a as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x90 as i32 as u8,
-    0x19 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x45 as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x36 as i32 as u8,
-    0x19 as i32 as u8,
-    0x18 as i32 as u8,
-    0x8a as i32 as u8,
-    0x19 as i32 as u8,
-    0x14 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x3f as i32 as u8,
-    0x19 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x9f as i32 as u8,
-    0xf as i32 as u8,
-    0xe2 as i32 as u8,
-    0x13 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9f as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x19 as i32 as u8,
-    0xae as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xae as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x9f as i32 as u8,
-    0x45 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x1a as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x1a as i32 as u8,
-    0xa5 as i32 as u8,
-    0x27 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x27 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x27 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x94 as i32 as u8,
-    0x6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x94 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x96 as i32 as u8,
-    0x26 as i32 as u8,
-    0x8 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x9f as i32 as u8,
-    0x1d as i32 as u8,
-    0xd2 as i32 as u8,
-    0x19 as i32 as u8,
-    0x2c as i32 as u8,
-    0x99 as i32 as u8,
-    0x2f as i32 as u8,
-    0 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x2f as i32 as u8,
-    0xb as i32 as u8,
-    0xe0 as i32 as u8,
-    0x75 as i32 as u8,
-    0x2f as i32 as u8,
-    0x19 as i32 as u8,
-    0x8b as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2f as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2f as i32 as u8,
-    0x98 as i32 as u8,
-    0x19 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2f as i32 as u8,
-    0x83 as i32 as u8,
-    0x37 as i32 as u8,
-    0x81 as i32 as u8,
-    0x30 as i32 as u8,
-    0x87 as i32 as u8,
-    0x19 as i32 as u8,
-    0x83 as i32 as u8,
-    0x2f as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x35 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x37 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x82 as i32 as u8,
-    0x35 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x3d as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x82 as i32 as u8,
-    0x3d as i32 as u8,
-    0x4 as i32 as u8,
-    0xaa as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0xdd as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x19 as i32 as u8,
-    0x9f as i32 as u8,
-    0xd as i32 as u8,
-    0xa3 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0x8f as i32 as u8,
-    0x3d as i32 as u8,
-    0x9e as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0xbf as i32 as u8,
-    0x19 as i32 as u8,
-    0x9e as i32 as u8,
-    0x30 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x19 as i32 as u8,
-    0xae as i32 as u8,
-    0x3d as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x3d as i32 as u8,
-    0xe0 as i32 as u8,
-    0x47 as i32 as u8,
-    0x19 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x5f as i32 as u8,
-    0x2f as i32 as u8,
-    0xbf as i32 as u8,
-    0x19 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x9c as i32 as u8,
-    0x2f as i32 as u8,
-    0x2 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x2c as i32 as u8,
-    0x9b as i32 as u8,
-    0x2 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x9b as i32 as u8,
-    0x8 as i32 as u8,
-    0xaf as i32 as u8,
-    0x4a as i32 as u8,
-    0xe0 as i32 as u8,
-    0xcb as i32 as u8,
-    0x97 as i32 as u8,
-    0x13 as i32 as u8,
-    0xdf as i32 as u8,
-    0x1d as i32 as u8,
-    0xd7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x19 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x45 as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x45 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x45 as i32 as u8,
-    0x29 as i32 as u8,
-    0x8a as i32 as u8,
-    0x45 as i32 as u8,
-    0xac as i32 as u8,
-    0x86 as i32 as u8,
-    0x2 as i32 as u8,
-    0x89 as i32 as u8,
-    0x19 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x76 as i32 as u8,
-    0x7 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x7c as i32 as u8,
-    0x7 as i32 as u8,
-    0x8b as i32 as u8,
-    0x7c as i32 as u8,
-    0x5 as i32 as u8,
-    0x9f as i32 as u8,
-    0x1f as i32 as u8,
-    0xad as i32 as u8,
-    0x3e as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3e as i32 as u8,
-    0xa3 as i32 as u8,
-    0x79 as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x79 as i32 as u8,
-    0x9c as i32 as u8,
-    0x30 as i32 as u8,
-    0x2 as i32 as u8,
-    0xcd as i32 as u8,
-    0x3a as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3a as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3a as i32 as u8,
-    0x9e as i32 as u8,
-    0x5e as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x16 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8d as i32 as u8,
-    0x16 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x16 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x16 as i32 as u8,
-    0x9f as i32 as u8,
-    0x5e as i32 as u8,
-    0xc2 as i32 as u8,
-    0x8c as i32 as u8,
-    0x17 as i32 as u8,
-    0x84 as i32 as u8,
-    0x8c as i32 as u8,
-    0x96 as i32 as u8,
-    0x55 as i32 as u8,
-    0x9 as i32 as u8,
-    0x85 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x26 as i32 as u8,
-    0x8 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x26 as i32 as u8,
-    0 as i32 as u8,
-    0xaa as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x88 as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0x83 as i32 as u8,
-    0x45 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0xcf as i32 as u8,
-    0x17 as i32 as u8,
-    0xad as i32 as u8,
-    0x55 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x55 as i32 as u8,
-    0x5 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x43 as i32 as u8,
-    0x30 as i32 as u8,
-    0xb as i32 as u8,
-    0x96 as i32 as u8,
-    0x30 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x30 as i32 as u8,
-    0x70 as i32 as u8,
-    0x10 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xe1 as i32 as u8,
-    0xd as i32 as u8,
-    0x2f as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2f as i32 as u8,
-    0x25 as i32 as u8,
-    0x86 as i32 as u8,
-    0x45 as i32 as u8,
-    0xb as i32 as u8,
-    0x84 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x99 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x34 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x34 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x4 as i32 as u8,
-    0x10 as i32 as u8,
-    0xe1 as i32 as u8,
-    0xa as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0xf as i32 as u8,
-    0xbf as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x27 as i32 as u8,
-    0x8d as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8f as i32 as u8,
-    0x37 as i32 as u8,
-    0x89 as i32 as u8,
-    0x19 as i32 as u8,
-    0x5 as i32 as u8,
-    0x8d as i32 as u8,
-    0x37 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1d as i32 as u8,
-    0xa2 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x92 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x9f as i32 as u8,
-    0x19 as i32 as u8,
-    0x99 as i32 as u8,
-    0x45 as i32 as u8,
-    0x85 as i32 as u8,
-    0x19 as i32 as u8,
-    0x99 as i32 as u8,
-    0x45 as i32 as u8,
-    0x8a as i32 as u8,
-    0x19 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3d as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0xac as i32 as u8,
-    0x3d as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9e as i32 as u8,
-    0x30 as i32 as u8,
-    0x2 as i32 as u8,
-    0x85 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x30 as i32 as u8,
-    0x2 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9 as i32 as u8,
-    0x84 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8b as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x99 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x92 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x49 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8d as i32 as u8,
-    0x49 as i32 as u8,
-    0x21 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x1a as i32 as u8,
-    0x49 as i32 as u8,
-    0x4 as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0xac as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0x88 as i32 as u8,
-    0x19 as i32 as u8,
-    0xce as i32 as u8,
-    0x2b as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2b as i32 as u8,
-    0x2e as i32 as u8,
-    0xac as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x37 as i32 as u8,
-    0x60 as i32 as u8,
-    0x21 as i32 as u8,
-    0x9c as i32 as u8,
-    0x4b as i32 as u8,
-    0x2 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x13 as i32 as u8,
-    0xe as i32 as u8,
-    0x80 as i32 as u8,
-    0x37 as i32 as u8,
-    0x9a as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x69 as i32 as u8,
-    0x8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x69 as i32 as u8,
-    0x9a as i32 as u8,
-    0x29 as i32 as u8,
-    0x4 as i32 as u8,
-    0xaa as i32 as u8,
-    0x6b as i32 as u8,
-    0x4 as i32 as u8,
-    0x9d as i32 as u8,
-    0x96 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x6c as i32 as u8,
-    0x3 as i32 as u8,
-    0x8d as i32 as u8,
-    0x6c as i32 as u8,
-    0x29 as i32 as u8,
-    0xcf as i32 as u8,
-    0x1e as i32 as u8,
-    0xaf as i32 as u8,
-    0x7e as i32 as u8,
-    0x9d as i32 as u8,
-    0x72 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x72 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x71 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x71 as i32 as u8,
-    0x3 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x24 as i32 as u8,
-    0x7 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x14 as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x14 as i32 as u8,
-    0x60 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe0 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x48 as i32 as u8,
-    0x8 as i32 as u8,
-    0x95 as i32 as u8,
-    0x48 as i32 as u8,
-    0x9 as i32 as u8,
-    0x87 as i32 as u8,
-    0x48 as i32 as u8,
-    0x60 as i32 as u8,
-    0x37 as i32 as u8,
-    0x85 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1c as i32 as u8,
-    0 as i32 as u8,
-    0xab as i32 as u8,
-    0x1c as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1c as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x1c as i32 as u8,
-    0x95 as i32 as u8,
-    0x36 as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x36 as i32 as u8,
-    0x9f as i32 as u8,
-    0x74 as i32 as u8,
-    0x9e as i32 as u8,
-    0x5f as i32 as u8,
-    0x7 as i32 as u8,
-    0x88 as i32 as u8,
-    0x5f as i32 as u8,
-    0x2f as i32 as u8,
-    0x92 as i32 as u8,
-    0x33 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x33 as i32 as u8,
-    0x4 as i32 as u8,
-    0x84 as i32 as u8,
-    0x33 as i32 as u8,
-    0x9b as i32 as u8,
-    0x77 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x77 as i32 as u8,
-    0x99 as i32 as u8,
-    0x4c as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4c as i32 as u8,
-    0x3f as i32 as u8,
-    0x9f as i32 as u8,
-    0x58 as i32 as u8,
-    0x97 as i32 as u8,
-    0x57 as i32 as u8,
-    0x3 as i32 as u8,
-    0x93 as i32 as u8,
-    0x57 as i32 as u8,
-    0x1 as i32 as u8,
-    0xad as i32 as u8,
-    0x57 as i32 as u8,
-    0x83 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3f as i32 as u8,
-    0x4 as i32 as u8,
-    0x87 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0x9c as i32 as u8,
-    0x3f as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x3f as i32 as u8,
-    0x3 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3f as i32 as u8,
-    0x6 as i32 as u8,
-    0x88 as i32 as u8,
-    0x3f as i32 as u8,
-    0x6 as i32 as u8,
-    0x9f as i32 as u8,
-    0x6e as i32 as u8,
-    0x9f as i32 as u8,
-    0x6a as i32 as u8,
-    0x1f as i32 as u8,
-    0xa6 as i32 as u8,
-    0x51 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8b as i32 as u8,
-    0x51 as i32 as u8,
-    0x8 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x6 as i32 as u8,
-    0x2 as i32 as u8,
-    0x86 as i32 as u8,
-    0x6 as i32 as u8,
-    0x95 as i32 as u8,
-    0x39 as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x39 as i32 as u8,
-    0x92 as i32 as u8,
-    0x38 as i32 as u8,
-    0x4 as i32 as u8,
-    0x87 as i32 as u8,
-    0x38 as i32 as u8,
-    0x91 as i32 as u8,
-    0x78 as i32 as u8,
-    0x6 as i32 as u8,
-    0x83 as i32 as u8,
-    0x78 as i32 as u8,
-    0xb as i32 as u8,
-    0x86 as i32 as u8,
-    0x78 as i32 as u8,
-    0x4f as i32 as u8,
-    0xc8 as i32 as u8,
-    0x6f as i32 as u8,
-    0x36 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x68 as i32 as u8,
-    0xc as i32 as u8,
-    0xb2 as i32 as u8,
-    0x68 as i32 as u8,
-    0x6 as i32 as u8,
-    0x85 as i32 as u8,
-    0x68 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x31 as i32 as u8,
-    0x7 as i32 as u8,
-    0x89 as i32 as u8,
-    0x31 as i32 as u8,
-    0x60 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x9e as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9a as i32 as u8,
-    0x4d as i32 as u8,
-    0xa7 as i32 as u8,
-    0x6d as i32 as u8,
-    0x7 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x82 as i32 as u8,
-    0x55 as i32 as u8,
-    0x9b as i32 as u8,
-    0x18 as i32 as u8,
-    0x13 as i32 as u8,
-    0x96 as i32 as u8,
-    0x25 as i32 as u8,
-    0x8 as i32 as u8,
-    0xcd as i32 as u8,
-    0xe as i32 as u8,
-    0x3 as i32 as u8,
-    0x9d as i32 as u8,
-    0xe as i32 as u8,
-    0xe as i32 as u8,
-    0x80 as i32 as u8,
-    0xe as i32 as u8,
-    0xc1 as i32 as u8,
-    0x3b as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x3b as i32 as u8,
-    0x1 as i32 as u8,
-    0x98 as i32 as u8,
-    0x83 as i32 as u8,
-    0x6 as i32 as u8,
-    0x89 as i32 as u8,
-    0x83 as i32 as u8,
-    0x5 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x15 as i32 as u8,
-    0 as i32 as u8,
-    0x91 as i32 as u8,
-    0x15 as i32 as u8,
-    0x7 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x4e as i32 as u8,
-    0x8 as i32 as u8,
-    0xdf as i32 as u8,
-    0x7d as i32 as u8,
-    0 as i32 as u8,
-    0x93 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa as i32 as u8,
-    0x91 as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0xab as i32 as u8,
-    0x41 as i32 as u8,
-    0x40 as i32 as u8,
-    0x86 as i32 as u8,
-    0x5d as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5d as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x5d as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x5d as i32 as u8,
-    0 as i32 as u8,
-    0x8a as i32 as u8,
-    0x5d as i32 as u8,
-    0x5 as i32 as u8,
-    0xba as i32 as u8,
-    0x43 as i32 as u8,
-    0x4 as i32 as u8,
-    0x89 as i32 as u8,
-    0x43 as i32 as u8,
-    0x5 as i32 as u8,
-    0x83 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1 as i32 as u8,
-    0x95 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x37 as i32 as u8,
-    0x88 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2a as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x2a as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x2a as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x2a as i32 as u8,
-    0x2 as i32 as u8,
-    0x84 as i32 as u8,
-    0x2a as i32 as u8,
-    0x60 as i32 as u8,
-    0x2a as i32 as u8,
-    0xdb as i32 as u8,
-    0x62 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x62 as i32 as u8,
-    0x1d as i32 as u8,
-    0xc7 as i32 as u8,
-    0x95 as i32 as u8,
-    0x7 as i32 as u8,
-    0x89 as i32 as u8,
-    0x95 as i32 as u8,
-    0x60 as i32 as u8,
-    0x45 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x7f as i32 as u8,
-    0x1 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x7f as i32 as u8,
-    0x21 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x5a as i32 as u8,
-    0xa as i32 as u8,
-    0x89 as i32 as u8,
-    0x5a as i32 as u8,
-    0x5 as i32 as u8,
-    0x8c as i32 as u8,
-    0x5b as i32 as u8,
-    0x12 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x8d as i32 as u8,
-    0x6 as i32 as u8,
-    0x89 as i32 as u8,
-    0x8d as i32 as u8,
-    0x35 as i32 as u8,
-    0x9a as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8e as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8f as i32 as u8,
-    0x2 as i32 as u8,
-    0x60 as i32 as u8,
-    0x5f as i32 as u8,
-    0xbb as i32 as u8,
-    0x21 as i32 as u8,
-    0x60 as i32 as u8,
-    0x3 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x99 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x86 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x9d as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x20 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8b as i32 as u8,
-    0x20 as i32 as u8,
-    0x8 as i32 as u8,
-    0x89 as i32 as u8,
-    0x20 as i32 as u8,
-    0x45 as i32 as u8,
-    0x87 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1 as i32 as u8,
-    0xad as i32 as u8,
-    0x60 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8a as i32 as u8,
-    0x60 as i32 as u8,
-    0x1a as i32 as u8,
-    0xc7 as i32 as u8,
-    0x9c as i32 as u8,
-    0x7 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x84 as i32 as u8,
-    0x1c as i32 as u8,
-    0xb8 as i32 as u8,
-    0x75 as i32 as u8,
-    0x60 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x88 as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0xac as i32 as u8,
-    0xc as i32 as u8,
-    0 as i32 as u8,
-    0x8d as i32 as u8,
-    0xc as i32 as u8,
-    0x9 as i32 as u8,
-    0x9c as i32 as u8,
-    0xc as i32 as u8,
-    0x2 as i32 as u8,
-    0x9f as i32 as u8,
-    0x52 as i32 as u8,
-    0x1 as i32 as u8,
-    0x95 as i32 as u8,
-    0x52 as i32 as u8,
-    0 as i32 as u8,
-    0x8d as i32 as u8,
-    0x52 as i32 as u8,
-    0x48 as i32 as u8,
-    0x86 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0xab as i32 as u8,
-    0x53 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x53 as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x53 as i32 as u8,
-    0x7 as i32 as u8,
-    0x89 as i32 as u8,
-    0x53 as i32 as u8,
-    0x5 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x2d as i32 as u8,
-    0x6 as i32 as u8,
-    0x89 as i32 as u8,
-    0x2d as i32 as u8,
-    0x60 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x98 as i32 as u8,
-    0x4d as i32 as u8,
-    0x60 as i32 as u8,
-    0x56 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4a as i32 as u8,
-    0xe as i32 as u8,
-    0xb1 as i32 as u8,
-    0x8e as i32 as u8,
-    0xc as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0xe3 as i32 as u8,
-    0x39 as i32 as u8,
-    0x1b as i32 as u8,
-    0x60 as i32 as u8,
-    0x5 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xe as i32 as u8,
-    0x1b as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x1b as i32 as u8,
-    0xa as i32 as u8,
-    0xe0 as i32 as u8,
-    0x63 as i32 as u8,
-    0x1b as i32 as u8,
-    0x6a as i32 as u8,
-    0x5b as i32 as u8,
-    0xe3 as i32 as u8,
-    0xce as i32 as u8,
-    0x23 as i32 as u8,
-    0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x23 as i32 as u8,
-    0x6f as i32 as u8,
-    0x66 as i32 as u8,
-    0xe1 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x70 as i32 as u8,
-    0x11 as i32 as u8,
-    0x58 as i32 as u8,
-    0xe1 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x6 as i32 as u8,
-    0x9e as i32 as u8,
-    0x5c as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x5c as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x5c as i32 as u8,
-    0x5f as i32 as u8,
-    0x9d as i32 as u8,
-    0x9 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x9 as i32 as u8,
-    0x9 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x73 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x73 as i32 as u8,
-    0 as i32 as u8,
-    0x94 as i32 as u8,
-    0x73 as i32 as u8,
-    0x4 as i32 as u8,
-    0x92 as i32 as u8,
-    0x73 as i32 as u8,
-    0x62 as i32 as u8,
-    0x4f as i32 as u8,
-    0xda as i32 as u8,
-    0x54 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4 as i32 as u8,
-    0xca as i32 as u8,
-    0x59 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x59 as i32 as u8,
-    0x6 as i32 as u8,
-    0x90 as i32 as u8,
-    0x59 as i32 as u8,
-    0x3f as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0x64 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xa as i32 as u8,
-    0x81 as i32 as u8,
-    0x2f as i32 as u8,
-    0xd as i32 as u8,
-    0xf0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x97 as i32 as u8,
-    0x8f as i32 as u8,
-    0x7 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x9f as i32 as u8,
-    0x8f as i32 as u8,
-    0xe1 as i32 as u8,
-    0x75 as i32 as u8,
-    0x42 as i32 as u8,
-    0x29 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8f as i32 as u8,
-    0x70 as i32 as u8,
-    0x12 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x3d as i32 as u8,
-    0xe0 as i32 as u8,
-    0xbd as i32 as u8,
-    0x35 as i32 as u8,
-    0x30 as i32 as u8,
-    0x82 as i32 as u8,
-    0x35 as i32 as u8,
-    0x10 as i32 as u8,
-    0x83 as i32 as u8,
-    0x3d as i32 as u8,
-    0x7 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x2b as i32 as u8,
-    0x64 as i32 as u8,
-    0x68 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xa as i32 as u8,
-    0x22 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8c as i32 as u8,
-    0x22 as i32 as u8,
-    0x2 as i32 as u8,
-    0x88 as i32 as u8,
-    0x22 as i32 as u8,
-    0x6 as i32 as u8,
-    0x89 as i32 as u8,
-    0x22 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x22 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0x70 as i32 as u8,
-    0x2 as i32 as u8,
-    0xfb as i32 as u8,
-    0xe0 as i32 as u8,
-    0x95 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0xbd as i32 as u8,
-    0x19 as i32 as u8,
-    0x82 as i32 as u8,
-    0x37 as i32 as u8,
-    0x90 as i32 as u8,
-    0x19 as i32 as u8,
-    0x87 as i32 as u8,
-    0x37 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x86 as i32 as u8,
-    0x37 as i32 as u8,
-    0x9d as i32 as u8,
-    0x19 as i32 as u8,
-    0x83 as i32 as u8,
-    0x37 as i32 as u8,
-    0xba as i32 as u8,
-    0x19 as i32 as u8,
-    0x16 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x2b as i32 as u8,
-    0x60 as i32 as u8,
-    0x39 as i32 as u8,
-    0x93 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0xd6 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8 as i32 as u8,
-    0x98 as i32 as u8,
-    0x19 as i32 as u8,
-    0x60 as i32 as u8,
-    0x26 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x8b as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x87 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x9b as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0xe0 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x19 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0xe as i32 as u8,
-    0x84 as i32 as u8,
-    0x80 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x64 as i32 as u8,
-    0xef as i32 as u8,
-    0x86 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x90 as i32 as u8,
-    0x28 as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x28 as i32 as u8,
-    0x60 as i32 as u8,
-    0x74 as i32 as u8,
-    0xac as i32 as u8,
-    0x65 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8d as i32 as u8,
-    0x65 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x65 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x65 as i32 as u8,
-    0x61 as i32 as u8,
-    0xf as i32 as u8,
-    0xb9 as i32 as u8,
-    0x98 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x64 as i32 as u8,
-    0x9f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x64 as i32 as u8,
-    0x56 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8f as i32 as u8,
-    0x56 as i32 as u8,
-    0x28 as i32 as u8,
-    0xcb as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x89 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1 as i32 as u8,
-    0x62 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x19 as i32 as u8,
-    0x4b as i32 as u8,
-    0xbc as i32 as u8,
-    0x19 as i32 as u8,
-    0x60 as i32 as u8,
-    0x61 as i32 as u8,
-    0x83 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x9a as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x83 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x86 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x83 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x90 as i32 as u8,
-    0x4 as i32 as u8,
-    0x4 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x84 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x90 as i32 as u8,
-    0x4 as i32 as u8,
-    0x33 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4 as i32 as u8,
-    0x60 as i32 as u8,
-    0xad as i32 as u8,
-    0xab as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0x8e as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8e as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x19 as i32 as u8,
-    0x9 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x4d as i32 as u8,
-    0x19 as i32 as u8,
-    0x37 as i32 as u8,
-    0x99 as i32 as u8,
-    0x19 as i32 as u8,
-    0x80 as i32 as u8,
-    0x35 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0xc as i32 as u8,
-    0xab as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0x88 as i32 as u8,
-    0x19 as i32 as u8,
-    0x6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0xd as i32 as u8,
-    0x85 as i32 as u8,
-    0x19 as i32 as u8,
-    0x60 as i32 as u8,
-    0x39 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x77 as i32 as u8,
-    0x19 as i32 as u8,
-    0x7 as i32 as u8,
-    0x8c as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0x8c as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x13 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0xd8 as i32 as u8,
-    0x19 as i32 as u8,
-    0x6 as i32 as u8,
-    0x8b as i32 as u8,
-    0x19 as i32 as u8,
-    0x13 as i32 as u8,
-    0x8b as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x19 as i32 as u8,
-    0x7 as i32 as u8,
-    0x89 as i32 as u8,
-    0x19 as i32 as u8,
-    0x5 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x19 as i32 as u8,
-    0x7 as i32 as u8,
-    0x9d as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x19 as i32 as u8,
-    0x4d as i32 as u8,
-    0xe0 as i32 as u8,
-    0x18 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0x8d as i32 as u8,
-    0x19 as i32 as u8,
-    0x1 as i32 as u8,
-    0x84 as i32 as u8,
-    0x19 as i32 as u8,
-    0x2 as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0x4 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8 as i32 as u8,
-    0x98 as i32 as u8,
-    0x19 as i32 as u8,
-    0x6 as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0x8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x19 as i32 as u8,
-    0xc as i32 as u8,
-    0x86 as i32 as u8,
-    0x19 as i32 as u8,
-    0x28 as i32 as u8,
-    0xe0 as i32 as u8,
-    0x32 as i32 as u8,
-    0x19 as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x19 as i32 as u8,
-    0x24 as i32 as u8,
-    0x89 as i32 as u8,
-    0x19 as i32 as u8,
-    0x63 as i32 as u8,
-    0xa5 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x96 as i32 as u8,
-    0x7d as i32 as u8,
-    0x2f as i32 as u8,
-    0x21 as i32 as u8,
-    0xef as i32 as u8,
-    0xd4 as i32 as u8,
-    0x2f as i32 as u8,
-    0xa as i32 as u8,
-    0xe0 as i32 as u8,
-    0x7d as i32 as u8,
-    0x2f as i32 as u8,
-    0x1 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2f as i32 as u8,
-    0xd as i32 as u8,
-    0xf0 as i32 as u8,
-    0xc as i32 as u8,
-    0xd0 as i32 as u8,
-    0x2f as i32 as u8,
-    0x6b as i32 as u8,
-    0xbe as i32 as u8,
-    0xe1 as i32 as u8,
-    0xbd as i32 as u8,
-    0x2f as i32 as u8,
-    0x65 as i32 as u8,
-    0x81 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xea as i32 as u8,
-    0x2f as i32 as u8,
-    0x7a as i32 as u8,
-    0xdc as i32 as u8,
-    0x55 as i32 as u8,
-    0x80 as i32 as u8,
-    0x19 as i32 as u8,
-    0x1d as i32 as u8,
-    0xdf as i32 as u8,
-    0x19 as i32 as u8,
-    0x60 as i32 as u8,
-    0x1f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x37 as i32 as u8,
-];
-static mut unicode_script_ext_table: [u8; 799] = [
-    0x82 as i32 as u8,
-    0xc1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2b as i32 as u8,
-    0x1c as i32 as u8,
-    0 as i32 as u8,
-    0xc as i32 as u8,
-    0x1 as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0x92 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x6b as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x28 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x45 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x28 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x31 as i32 as u8,
-    0x87 as i32 as u8,
-    0x91 as i32 as u8,
-    0x9a as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x31 as i32 as u8,
-    0x87 as i32 as u8,
-    0x91 as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x87 as i32 as u8,
-    0x91 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x31 as i32 as u8,
-    0x87 as i32 as u8,
-    0x91 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x50 as i32 as u8,
-    0x51 as i32 as u8,
-    0x78 as i32 as u8,
-    0x31 as i32 as u8,
-    0x82 as i32 as u8,
-    0x87 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x87 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x91 as i32 as u8,
-    0x9a as i32 as u8,
-    0x5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x87 as i32 as u8,
-    0x62 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x31 as i32 as u8,
-    0x81 as i32 as u8,
-    0xfb as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0xd as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x3c as i32 as u8,
-    0x45 as i32 as u8,
-    0x4f as i32 as u8,
-    0x70 as i32 as u8,
-    0x7d as i32 as u8,
-    0x8e as i32 as u8,
-    0x90 as i32 as u8,
-    0x95 as i32 as u8,
-    0 as i32 as u8,
-    0xc as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x3c as i32 as u8,
-    0x45 as i32 as u8,
-    0x4f as i32 as u8,
-    0x70 as i32 as u8,
-    0x8e as i32 as u8,
-    0x90 as i32 as u8,
-    0x95 as i32 as u8,
-    0x10 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2d as i32 as u8,
-    0x53 as i32 as u8,
-    0x2a as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x3c as i32 as u8,
-    0x4e as i32 as u8,
-    0x4f as i32 as u8,
-    0x60 as i32 as u8,
-    0x70 as i32 as u8,
-    0x43 as i32 as u8,
-    0x81 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8d as i32 as u8,
-    0x8e as i32 as u8,
-    0x90 as i32 as u8,
-    0x95 as i32 as u8,
-    0 as i32 as u8,
-    0x15 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2d as i32 as u8,
-    0x53 as i32 as u8,
-    0x2a as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x3c as i32 as u8,
-    0x47 as i32 as u8,
-    0x4e as i32 as u8,
-    0x4f as i32 as u8,
-    0x60 as i32 as u8,
-    0x70 as i32 as u8,
-    0x43 as i32 as u8,
-    0x81 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8d as i32 as u8,
-    0x8e as i32 as u8,
-    0x90 as i32 as u8,
-    0x95 as i32 as u8,
-    0x9 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x3b as i32 as u8,
-    0x4e as i32 as u8,
-    0x75 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb as i32 as u8,
-    0x15 as i32 as u8,
-    0x86 as i32 as u8,
-    0x75 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2e as i32 as u8,
-    0x5d as i32 as u8,
-    0x75 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2c as i32 as u8,
-    0x41 as i32 as u8,
-    0x80 as i32 as u8,
-    0x75 as i32 as u8,
-    0 as i32 as u8,
-    0xd as i32 as u8,
-    0x2 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x71 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3c as i32 as u8,
-    0x60 as i32 as u8,
-    0x82 as i32 as u8,
-    0xcf as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x15 as i32 as u8,
-    0x5e as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x27 as i32 as u8,
-    0x45 as i32 as u8,
-    0x85 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x11 as i32 as u8,
-    0x32 as i32 as u8,
-    0x89 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4a as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5b as i32 as u8,
-    0x76 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5b as i32 as u8,
-    0x76 as i32 as u8,
-    0x84 as i32 as u8,
-    0x49 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7d as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7d as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x1f as i32 as u8,
-    0x3c as i32 as u8,
-    0x4f as i32 as u8,
-    0x70 as i32 as u8,
-    0x8e as i32 as u8,
-    0x90 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7d as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x7d as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x60 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x3c as i32 as u8,
-    0x60 as i32 as u8,
-    0x70 as i32 as u8,
-    0x90 as i32 as u8,
-    0x95 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x3c as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x60 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2b as i32 as u8,
-    0x35 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x87 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x45 as i32 as u8,
-    0x5b as i32 as u8,
-    0x80 as i32 as u8,
-    0x3f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1f as i32 as u8,
-    0x2a as i32 as u8,
-    0x45 as i32 as u8,
-    0x8c as i32 as u8,
-    0xd1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x28 as i32 as u8,
-    0x81 as i32 as u8,
-    0x3c as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x9b as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x9b as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x7 as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x9b as i32 as u8,
-    0x3 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0xd as i32 as u8,
-    0x2f as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x4 as i32 as u8,
-    0x2 as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x58 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x59 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x9b as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x80 as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0xf as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x23 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x3b as i32 as u8,
-    0 as i32 as u8,
-    0x27 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x37 as i32 as u8,
-    0 as i32 as u8,
-    0x30 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0xe as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x32 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x57 as i32 as u8,
-    0 as i32 as u8,
-    0x18 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x5f as i32 as u8,
-    0 as i32 as u8,
-    0x1e as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0xc0 as i32 as u8,
-    0x31 as i32 as u8,
-    0xef as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1d as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0xf as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2f as i32 as u8,
-    0x45 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa7 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xe as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x41 as i32 as u8,
-    0x3c as i32 as u8,
-    0x3b as i32 as u8,
-    0x4e as i32 as u8,
-    0x4f as i32 as u8,
-    0x5a as i32 as u8,
-    0x60 as i32 as u8,
-    0x43 as i32 as u8,
-    0x8d as i32 as u8,
-    0x95 as i32 as u8,
-    0x2 as i32 as u8,
-    0xd as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x41 as i32 as u8,
-    0x3c as i32 as u8,
-    0x3b as i32 as u8,
-    0x4e as i32 as u8,
-    0x5a as i32 as u8,
-    0x60 as i32 as u8,
-    0x43 as i32 as u8,
-    0x8d as i32 as u8,
-    0x95 as i32 as u8,
-    0x3 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0x21 as i32 as u8,
-    0x2c as i32 as u8,
-    0x2e as i32 as u8,
-    0x41 as i32 as u8,
-    0x3b as i32 as u8,
-    0x4e as i32 as u8,
-    0x5a as i32 as u8,
-    0x43 as i32 as u8,
-    0x8d as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0x36 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0xb as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1f as i32 as u8,
-    0x8e as i32 as u8,
-    0x39 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3e as i32 as u8,
-    0x45 as i32 as u8,
-    0x5e as i32 as u8,
-    0x80 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x10 as i32 as u8,
-    0x3a as i32 as u8,
-    0xc0 as i32 as u8,
-    0x13 as i32 as u8,
-    0xa1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x91 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x91 as i32 as u8,
-    0x46 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x6 as i32 as u8,
-    0xd as i32 as u8,
-    0x30 as i32 as u8,
-    0x2f as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x9b as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x2c as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x35 as i32 as u8,
-    0x3d as i32 as u8,
-    0x80 as i32 as u8,
-    0xdf as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1c as i32 as u8,
-    0x49 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x2c as i32 as u8,
-    0x3 as i32 as u8,
-    0x1c as i32 as u8,
-    0x48 as i32 as u8,
-    0x49 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1c as i32 as u8,
-    0x49 as i32 as u8,
-    0x81 as i32 as u8,
-    0x1f as i32 as u8,
-    0 as i32 as u8,
-    0x1b as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x1a as i32 as u8,
-    0x8f as i32 as u8,
-    0x84 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8e as i32 as u8,
-    0x36 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8e as i32 as u8,
-    0x8c as i32 as u8,
-    0x12 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8e as i32 as u8,
-    0xc0 as i32 as u8,
-    0x5c as i32 as u8,
-    0x4b as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x22 as i32 as u8,
-    0x96 as i32 as u8,
-    0x3b as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0x9e as i32 as u8,
-    0x5d as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x2f as i32 as u8,
-    0xce as i32 as u8,
-    0xcd as i32 as u8,
-    0x2d as i32 as u8,
-    0 as i32 as u8,
-];
-static mut unicode_prop_Hyphen_table: [u8; 28] = [
-    0xac as i32 as u8,
-    0x80 as i32 as u8,
-    0xfe as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0xdb as i32 as u8,
-    0x80 as i32 as u8,
-    0x52 as i32 as u8,
-    0x7a as i32 as u8,
-    0x80 as i32 as u8,
-    0x48 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4e as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcd as i32 as u8,
-    0x66 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Other_Math_table: [u8; 200] = [
-    0xdd as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x70 as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x5c as i32 as u8,
-    0x1f as i32 as u8,
-    0x80 as i32 as u8,
-    0x9a as i32 as u8,
-    0x82 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x9f as i32 as u8,
-    0x83 as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x81 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x18 as i32 as u8,
-    0x11 as i32 as u8,
-    0x1c as i32 as u8,
-    0x91 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x28 as i32 as u8,
-    0x11 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x13 as i32 as u8,
-    0x24 as i32 as u8,
-    0xca as i32 as u8,
-    0x21 as i32 as u8,
-    0x18 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb as i32 as u8,
-    0xb as i32 as u8,
-    0x91 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0x41 as i32 as u8,
-    0x21 as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xbc as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x88 as i32 as u8,
-    0x24 as i32 as u8,
-    0x21 as i32 as u8,
-    0x9 as i32 as u8,
-    0x14 as i32 as u8,
-    0x8d as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x85 as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb8 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9c as i32 as u8,
-    0x83 as i32 as u8,
-    0x88 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x55 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9e as i32 as u8,
-    0x89 as i32 as u8,
-    0x41 as i32 as u8,
-    0x92 as i32 as u8,
-    0x95 as i32 as u8,
-    0xbe as i32 as u8,
-    0x83 as i32 as u8,
-    0x9f as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x62 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd2 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xd4 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x81 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x7 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x55 as i32 as u8,
-    0xff as i32 as u8,
-    0x18 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x22 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-];
-static mut unicode_prop_Other_Alphabetic_table: [u8; 411] = [
-    0x43 as i32 as u8,
-    0x44 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x69 as i32 as u8,
-    0x8d as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x8a as i32 as u8,
-    0xaf as i32 as u8,
-    0x8c as i32 as u8,
-    0x6 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x33 as i32 as u8,
-    0x19 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0x8f as i32 as u8,
-    0xe5 as i32 as u8,
-    0x8a as i32 as u8,
-    0xe4 as i32 as u8,
-    0xa as i32 as u8,
-    0x88 as i32 as u8,
-    0x2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x8b as i32 as u8,
-    0x16 as i32 as u8,
-    0x85 as i32 as u8,
-    0x93 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x9 as i32 as u8,
-    0x8e as i32 as u8,
-    0x1 as i32 as u8,
-    0x22 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x23 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x82 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x38 as i32 as u8,
-    0x10 as i32 as u8,
-    0x81 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0x95 as i32 as u8,
-    0x13 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x80 as i32 as u8,
-    0xba as i32 as u8,
-    0x22 as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0x17 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0x17 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0x9b as i32 as u8,
-    0x83 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x30 as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9c as i32 as u8,
-    0x82 as i32 as u8,
-    0xca as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0x87 as i32 as u8,
-    0x91 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbc as i32 as u8,
-    0x1 as i32 as u8,
-    0x86 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x28 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x90 as i32 as u8,
-    0x8a as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0xed as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x96 as i32 as u8,
-    0x1b as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x32 as i32 as u8,
-    0x83 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x89 as i32 as u8,
-    0x83 as i32 as u8,
-    0x46 as i32 as u8,
-    0x73 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x81 as i32 as u8,
-    0x9d as i32 as u8,
-    0x81 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x92 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbb as i32 as u8,
-    0x81 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x8b as i32 as u8,
-    0x83 as i32 as u8,
-    0x88 as i32 as u8,
-    0x40 as i32 as u8,
-    0xdd as i32 as u8,
-    0x84 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x93 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbe as i32 as u8,
-    0x84 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8e as i32 as u8,
-    0xbb as i32 as u8,
-    0x82 as i32 as u8,
-    0x9d as i32 as u8,
-    0x88 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x8a as i32 as u8,
-    0xb1 as i32 as u8,
-    0x92 as i32 as u8,
-    0x41 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8d as i32 as u8,
-    0x46 as i32 as u8,
-    0xc0 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x48 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x9f as i32 as u8,
-    0x60 as i32 as u8,
-    0x78 as i32 as u8,
-    0x73 as i32 as u8,
-    0x87 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x61 as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x84 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x84 as i32 as u8,
-    0x9b as i32 as u8,
-    0x8b as i32 as u8,
-    0xac as i32 as u8,
-    0x83 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8b as i32 as u8,
-    0xa4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x8d as i32 as u8,
-    0x8b as i32 as u8,
-    0x7 as i32 as u8,
-    0x81 as i32 as u8,
-    0xac as i32 as u8,
-    0x82 as i32 as u8,
-    0xb1 as i32 as u8,
-    0 as i32 as u8,
-    0x11 as i32 as u8,
-    0xc as i32 as u8,
-    0x80 as i32 as u8,
-    0xab as i32 as u8,
-    0x24 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xec as i32 as u8,
-    0x87 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4f as i32 as u8,
-    0x32 as i32 as u8,
-    0x80 as i32 as u8,
-    0x48 as i32 as u8,
-    0x56 as i32 as u8,
-    0x84 as i32 as u8,
-    0x46 as i32 as u8,
-    0x85 as i32 as u8,
-    0x10 as i32 as u8,
-    0xc as i32 as u8,
-    0x83 as i32 as u8,
-    0x43 as i32 as u8,
-    0x13 as i32 as u8,
-    0x83 as i32 as u8,
-    0x41 as i32 as u8,
-    0x82 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x52 as i32 as u8,
-    0x82 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x8d as i32 as u8,
-    0xbb as i32 as u8,
-    0x80 as i32 as u8,
-    0xac as i32 as u8,
-    0x88 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x82 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x8b as i32 as u8,
-    0x91 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x82 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8c as i32 as u8,
-    0x8d as i32 as u8,
-    0x81 as i32 as u8,
-    0xdb as i32 as u8,
-    0x88 as i32 as u8,
-    0x8 as i32 as u8,
-    0x28 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x89 as i32 as u8,
-    0x96 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x2 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x91 as i32 as u8,
-    0x40 as i32 as u8,
-    0xec as i32 as u8,
-    0x31 as i32 as u8,
-    0x86 as i32 as u8,
-    0x9c as i32 as u8,
-    0x81 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x8e as i32 as u8,
-    0 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x8a as i32 as u8,
-    0xe6 as i32 as u8,
-    0x8d as i32 as u8,
-    0x41 as i32 as u8,
-    0 as i32 as u8,
-    0x8c as i32 as u8,
-    0x40 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x28 as i32 as u8,
-    0x9 as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8d as i32 as u8,
-    0x31 as i32 as u8,
-    0x2b as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x89 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x20 as i32 as u8,
-    0x83 as i32 as u8,
-    0x91 as i32 as u8,
-    0x8a as i32 as u8,
-    0xad as i32 as u8,
-    0x8d as i32 as u8,
-    0x41 as i32 as u8,
-    0x96 as i32 as u8,
-    0x38 as i32 as u8,
-    0x86 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0xf9 as i32 as u8,
-    0x2a as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x10 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8 as i32 as u8,
-    0x83 as i32 as u8,
-    0x41 as i32 as u8,
-    0x5b as i32 as u8,
-    0x83 as i32 as u8,
-    0x60 as i32 as u8,
-    0x50 as i32 as u8,
-    0x57 as i32 as u8,
-    0 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x33 as i32 as u8,
-    0xdc as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4c as i32 as u8,
-    0xab as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x23 as i32 as u8,
-    0x60 as i32 as u8,
-    0x30 as i32 as u8,
-    0x90 as i32 as u8,
-    0xe as i32 as u8,
-    0x1 as i32 as u8,
-    0x4 as i32 as u8,
-    0x49 as i32 as u8,
-    0x1b as i32 as u8,
-    0x80 as i32 as u8,
-    0x47 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-];
-static mut unicode_prop_Other_Lowercase_table: [u8; 51] = [
-    0x40 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x88 as i32 as u8,
-    0x31 as i32 as u8,
-    0x9d as i32 as u8,
-    0x84 as i32 as u8,
-    0xdf as i32 as u8,
-    0x80 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x59 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xbe as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xa1 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x42 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8c as i32 as u8,
-    0x40 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x8f as i32 as u8,
-    0x43 as i32 as u8,
-    0x4f as i32 as u8,
-    0x99 as i32 as u8,
-    0x47 as i32 as u8,
-    0x91 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x7a as i32 as u8,
-    0x1d as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x86 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x61 as i32 as u8,
-    0x83 as i32 as u8,
-];
-static mut unicode_prop_Other_Uppercase_table: [u8; 15] = [
-    0x60 as i32 as u8,
-    0x21 as i32 as u8,
-    0x5f as i32 as u8,
-    0x8f as i32 as u8,
-    0x43 as i32 as u8,
-    0x45 as i32 as u8,
-    0x99 as i32 as u8,
-    0x61 as i32 as u8,
-    0xcc as i32 as u8,
-    0x5f as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x99 as i32 as u8,
-];
-static mut unicode_prop_Other_Grapheme_Extend_table: [u8; 65] = [
-    0x49 as i32 as u8,
-    0xbd as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x65 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x81 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x4d as i32 as u8,
-    0x54 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x50 as i32 as u8,
-    0x20 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcf as i32 as u8,
-    0x6d as i32 as u8,
-    0x81 as i32 as u8,
-    0x53 as i32 as u8,
-    0x9d as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x57 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x7f as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x33 as i32 as u8,
-    0x7 as i32 as u8,
-    0x84 as i32 as u8,
-    0x6c as i32 as u8,
-    0x2e as i32 as u8,
-    0xac as i32 as u8,
-    0xdf as i32 as u8,
-];
-static mut unicode_prop_Other_Default_Ignorable_Code_Point_table: [u8; 32] = [
-    0x43 as i32 as u8,
-    0x4e as i32 as u8,
-    0x80 as i32 as u8,
-    0x4e as i32 as u8,
-    0xe as i32 as u8,
-    0x81 as i32 as u8,
-    0x46 as i32 as u8,
-    0x52 as i32 as u8,
-    0x81 as i32 as u8,
-    0x48 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x50 as i32 as u8,
-    0xfd as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xce as i32 as u8,
-    0x3a as i32 as u8,
-    0x80 as i32 as u8,
-    0xce as i32 as u8,
-    0x88 as i32 as u8,
-    0x6d as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x9d as i32 as u8,
-    0xdf as i32 as u8,
-    0xff as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-    0x4e as i32 as u8,
-    0xf as i32 as u8,
-];
-static mut unicode_prop_Other_ID_Start_table: [u8; 11] = [
-    0x58 as i32 as u8,
-    0x84 as i32 as u8,
-    0x81 as i32 as u8,
-    0x48 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4f as i32 as u8,
-    0x6b as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Other_ID_Continue_table: [u8; 12] = [
-    0x40 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xce as i32 as u8,
-    0x80 as i32 as u8,
-    0x4f as i32 as u8,
-    0xe0 as i32 as u8,
-    0x88 as i32 as u8,
-    0x46 as i32 as u8,
-    0x67 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Prepended_Concatenation_Mark_table: [u8; 17] = [
-    0x45 as i32 as u8,
-    0xff as i32 as u8,
-    0x85 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0x7 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_XID_Start1_table: [u8; 31] = [
-    0x43 as i32 as u8,
-    0x79 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4a as i32 as u8,
-    0xb7 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfe as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x21 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcb as i32 as u8,
-    0xc0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x41 as i32 as u8,
-    0x95 as i32 as u8,
-    0x81 as i32 as u8,
-    0xf3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x1e as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_XID_Continue1_table: [u8; 23] = [
-    0x43 as i32 as u8,
-    0x79 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x2d as i32 as u8,
-    0x1f as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcb as i32 as u8,
-    0xc0 as i32 as u8,
-    0x85 as i32 as u8,
-    0x41 as i32 as u8,
-    0x95 as i32 as u8,
-    0x81 as i32 as u8,
-    0xf3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Changes_When_Titlecased1_table: [u8; 22] = [
-    0x41 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4e as i32 as u8,
-    0xdc as i32 as u8,
-    0xaa as i32 as u8,
-    0xa as i32 as u8,
-    0x4e as i32 as u8,
-    0x87 as i32 as u8,
-    0x3f as i32 as u8,
-    0x3f as i32 as u8,
-    0x87 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Changes_When_Casefolded1_table: [u8; 33] = [
-    0x40 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0xcf as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0x3c as i32 as u8,
-    0x80 as i32 as u8,
-    0x59 as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x3f as i32 as u8,
-    0x3f as i32 as u8,
-    0x87 as i32 as u8,
-    0x89 as i32 as u8,
-    0x11 as i32 as u8,
-    0x5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xdb as i32 as u8,
-    0x7 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8b as i32 as u8,
-    0x84 as i32 as u8,
-];
-static mut unicode_prop_Changes_When_NFKC_Casefolded1_table: [u8; 441] = [
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x6 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x12 as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-    0x9f as i32 as u8,
-    0x80 as i32 as u8,
-    0xcf as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfb as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbb as i32 as u8,
-    0x88 as i32 as u8,
-    0x9e as i32 as u8,
-    0x29 as i32 as u8,
-    0x84 as i32 as u8,
-    0xda as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x4 as i32 as u8,
-    0x2 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x82 as i32 as u8,
-    0x9c as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x83 as i32 as u8,
-    0x42 as i32 as u8,
-    0xde as i32 as u8,
-    0x87 as i32 as u8,
-    0xfb as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfc as i32 as u8,
-    0x81 as i32 as u8,
-    0x42 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfe as i32 as u8,
-    0x80 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x81 as i32 as u8,
-    0xad as i32 as u8,
-    0x80 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0 as i32 as u8,
-    0x26 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x41 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x46 as i32 as u8,
-    0x52 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x83 as i32 as u8,
-    0x45 as i32 as u8,
-    0x1c as i32 as u8,
-    0x10 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xa1 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd5 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x3f as i32 as u8,
-    0x3f as i32 as u8,
-    0x87 as i32 as u8,
-    0x89 as i32 as u8,
-    0x11 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x29 as i32 as u8,
-    0x4 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x11 as i32 as u8,
-    0x11 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0x8f as i32 as u8,
-    0 as i32 as u8,
-    0x20 as i32 as u8,
-    0x8b as i32 as u8,
-    0x12 as i32 as u8,
-    0x2a as i32 as u8,
-    0x8 as i32 as u8,
-    0xb as i32 as u8,
-    0 as i32 as u8,
-    0x7 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x6 as i32 as u8,
-    0x92 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9a as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x18 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8a as i32 as u8,
-    0x1 as i32 as u8,
-    0xc as i32 as u8,
-    0xa as i32 as u8,
-    0 as i32 as u8,
-    0x10 as i32 as u8,
-    0x11 as i32 as u8,
-    0x2 as i32 as u8,
-    0x6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x1c as i32 as u8,
-    0x85 as i32 as u8,
-    0x8f as i32 as u8,
-    0x8f as i32 as u8,
-    0x8f as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf7 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x34 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x99 as i32 as u8,
-    0x9a as i32 as u8,
-    0x45 as i32 as u8,
-    0x20 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x82 as i32 as u8,
-    0xe4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x9e as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x2e as i32 as u8,
-    0x80 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x40 as i32 as u8,
-    0xd5 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb4 as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0xdf as i32 as u8,
-    0x9 as i32 as u8,
-    0x80 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0xb0 as i32 as u8,
-    0xdd as i32 as u8,
-    0x82 as i32 as u8,
-    0x8d as i32 as u8,
-    0xdf as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x87 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x7f as i32 as u8,
-    0x60 as i32 as u8,
-    0x72 as i32 as u8,
-    0x9b as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x86 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x61 as i32 as u8,
-    0x83 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4d as i32 as u8,
-    0x95 as i32 as u8,
-    0x41 as i32 as u8,
-    0xd as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xe9 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8b as i32 as u8,
-    0x24 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xeb as i32 as u8,
-    0xa0 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6a as i32 as u8,
-    0x91 as i32 as u8,
-    0xbf as i32 as u8,
-    0x81 as i32 as u8,
-    0xb5 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x99 as i32 as u8,
-    0x95 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x92 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1a as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9f as i32 as u8,
-    0x99 as i32 as u8,
-    0x40 as i32 as u8,
-    0x83 as i32 as u8,
-    0x15 as i32 as u8,
-    0xd as i32 as u8,
-    0xd as i32 as u8,
-    0xa as i32 as u8,
-    0x16 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x60 as i32 as u8,
-    0xbc as i32 as u8,
-    0xa6 as i32 as u8,
-    0x83 as i32 as u8,
-    0x54 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x86 as i32 as u8,
-    0x8d as i32 as u8,
-    0x87 as i32 as u8,
-    0xbf as i32 as u8,
-    0x85 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3e as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x1 as i32 as u8,
-    0x8 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x3 as i32 as u8,
-    0xf as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x3 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x16 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x53 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x23 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x55 as i32 as u8,
-    0xff as i32 as u8,
-    0x18 as i32 as u8,
-    0x9a as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x22 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x42 as i32 as u8,
-    0x43 as i32 as u8,
-    0x8a as i32 as u8,
-    0x84 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x9f as i32 as u8,
-    0x99 as i32 as u8,
-    0x82 as i32 as u8,
-    0xa2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xee as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0xab as i32 as u8,
-    0x83 as i32 as u8,
-    0x88 as i32 as u8,
-    0x31 as i32 as u8,
-    0x49 as i32 as u8,
-    0x9d as i32 as u8,
-    0x89 as i32 as u8,
-    0x60 as i32 as u8,
-    0xfc as i32 as u8,
-    0x5 as i32 as u8,
-    0x42 as i32 as u8,
-    0x1d as i32 as u8,
-    0x6b as i32 as u8,
-    0x5 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x4f as i32 as u8,
-    0xff as i32 as u8,
-];
-static mut unicode_prop_ASCII_Hex_Digit_table: [u8; 5] = [
-    0xaf as i32 as u8,
-    0x89 as i32 as u8,
-    0x35 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-];
-static mut unicode_prop_Bidi_Control_table: [u8; 10] = [
-    0x46 as i32 as u8,
-    0x1b as i32 as u8,
-    0x80 as i32 as u8,
-    0x59 as i32 as u8,
-    0xf0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x99 as i32 as u8,
-    0x84 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x83 as i32 as u8,
-];
-static mut unicode_prop_Dash_table: [u8; 53] = [
-    0xac as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x5b as i32 as u8,
-    0x80 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4e as i32 as u8,
-    0x40 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x48 as i32 as u8,
-    0x8 as i32 as u8,
-    0x85 as i32 as u8,
-    0xbc as i32 as u8,
-    0x80 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x85 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4c as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xda as i32 as u8,
-    0x80 as i32 as u8,
-    0x92 as i32 as u8,
-    0x80 as i32 as u8,
-    0xee as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcd as i32 as u8,
-    0x8f as i32 as u8,
-    0x81 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4f as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Deprecated_table: [u8; 23] = [
-    0x41 as i32 as u8,
-    0x48 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0x49 as i32 as u8,
-    0x2 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x48 as i32 as u8,
-    0x28 as i32 as u8,
-    0x81 as i32 as u8,
-    0x48 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x85 as i32 as u8,
-    0x42 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x6d as i32 as u8,
-    0xdc as i32 as u8,
-    0xd5 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Diacritic_table: [u8; 358] = [
-    0xdd as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x5 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9e as i32 as u8,
-    0x7 as i32 as u8,
-    0x25 as i32 as u8,
-    0x90 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfc as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x90 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0x85 as i32 as u8,
-    0x3b as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x85 as i32 as u8,
-    0xb as i32 as u8,
-    0xa as i32 as u8,
-    0x82 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x9a as i32 as u8,
-    0xda as i32 as u8,
-    0x8a as i32 as u8,
-    0xb9 as i32 as u8,
-    0x8a as i32 as u8,
-    0xa1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x9b as i32 as u8,
-    0xbc as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x2 as i32 as u8,
-    0x83 as i32 as u8,
-    0x9b as i32 as u8,
-    0x80 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xed as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xed as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0x82 as i32 as u8,
-    0xbb as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xf6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfe as i32 as u8,
-    0x80 as i32 as u8,
-    0xed as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xec as i32 as u8,
-    0x81 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xfb as i32 as u8,
-    0x80 as i32 as u8,
-    0xfb as i32 as u8,
-    0x28 as i32 as u8,
-    0x80 as i32 as u8,
-    0xea as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x84 as i32 as u8,
-    0xca as i32 as u8,
-    0x81 as i32 as u8,
-    0x9a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x10 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbd as i32 as u8,
-    0x80 as i32 as u8,
-    0xef as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xa7 as i32 as u8,
-    0xb as i32 as u8,
-    0x84 as i32 as u8,
-    0x98 as i32 as u8,
-    0x30 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x42 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x44 as i32 as u8,
-    0x68 as i32 as u8,
-    0x8a as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x5a as i32 as u8,
-    0x82 as i32 as u8,
-    0x41 as i32 as u8,
-    0x38 as i32 as u8,
-    0x39 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaf as i32 as u8,
-    0x8d as i32 as u8,
-    0xf5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x88 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbf as i32 as u8,
-    0x85 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x98 as i32 as u8,
-    0x18 as i32 as u8,
-    0x28 as i32 as u8,
-    0xa as i32 as u8,
-    0xb1 as i32 as u8,
-    0xbe as i32 as u8,
-    0xd8 as i32 as u8,
-    0x8b as i32 as u8,
-    0xa4 as i32 as u8,
-    0x22 as i32 as u8,
-    0x82 as i32 as u8,
-    0x41 as i32 as u8,
-    0xbc as i32 as u8,
-    0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8a as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x81 as i32 as u8,
-    0x4c as i32 as u8,
-    0xef as i32 as u8,
-    0x82 as i32 as u8,
-    0x41 as i32 as u8,
-    0x3c as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xf9 as i32 as u8,
-    0x85 as i32 as u8,
-    0xe8 as i32 as u8,
-    0x83 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x75 as i32 as u8,
-    0x71 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0x81 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0xa1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x82 as i32 as u8,
-    0xec as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9a as i32 as u8,
-    0x91 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x94 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x83 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x84 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0xff as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4f as i32 as u8,
-    0x2f as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x41 as i32 as u8,
-    0xd as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0xac as i32 as u8,
-    0x81 as i32 as u8,
-    0xc2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xfb as i32 as u8,
-    0x80 as i32 as u8,
-    0x48 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3a as i32 as u8,
-    0x85 as i32 as u8,
-    0x42 as i32 as u8,
-    0x1d as i32 as u8,
-    0x8a as i32 as u8,
-    0x41 as i32 as u8,
-    0x67 as i32 as u8,
-    0x81 as i32 as u8,
-    0xf7 as i32 as u8,
-    0x81 as i32 as u8,
-    0xbd as i32 as u8,
-    0x80 as i32 as u8,
-    0xcb as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0xe7 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0x97 as i32 as u8,
-    0x32 as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0xcc as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfa as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfa as i32 as u8,
-    0x81 as i32 as u8,
-    0xfd as i32 as u8,
-    0x80 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x81 as i32 as u8,
-    0xf2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x1 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9b as i32 as u8,
-    0x80 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4d as i32 as u8,
-    0x57 as i32 as u8,
-    0x84 as i32 as u8,
-    0xba as i32 as u8,
-    0x86 as i32 as u8,
-    0x44 as i32 as u8,
-    0x57 as i32 as u8,
-    0x90 as i32 as u8,
-    0xcf as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x61 as i32 as u8,
-    0x74 as i32 as u8,
-    0x12 as i32 as u8,
-    0x2f as i32 as u8,
-    0x39 as i32 as u8,
-    0x86 as i32 as u8,
-    0x9d as i32 as u8,
-    0x83 as i32 as u8,
-    0x4f as i32 as u8,
-    0x81 as i32 as u8,
-    0x86 as i32 as u8,
-    0x41 as i32 as u8,
-    0xb4 as i32 as u8,
-    0x83 as i32 as u8,
-    0x45 as i32 as u8,
-    0xdf as i32 as u8,
-    0x86 as i32 as u8,
-    0xec as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-];
-static mut unicode_prop_Extender_table: [u8; 89] = [
-    0x40 as i32 as u8,
-    0xb6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x17 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x6d as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x59 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xef as i32 as u8,
-    0x80 as i32 as u8,
-    0xfe as i32 as u8,
-    0x80 as i32 as u8,
-    0x49 as i32 as u8,
-    0x42 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x62 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x53 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaa as i32 as u8,
-    0x84 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x81 as i32 as u8,
-    0xdc as i32 as u8,
-    0x82 as i32 as u8,
-    0x60 as i32 as u8,
-    0x6f as i32 as u8,
-    0x15 as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0xc1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xeb as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x54 as i32 as u8,
-    0x7a as i32 as u8,
-    0x80 as i32 as u8,
-    0x53 as i32 as u8,
-    0xeb as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x67 as i32 as u8,
-    0x82 as i32 as u8,
-    0x44 as i32 as u8,
-    0xce as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x50 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x44 as i32 as u8,
-    0x9b as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x71 as i32 as u8,
-    0x57 as i32 as u8,
-    0x81 as i32 as u8,
-    0x48 as i32 as u8,
-    0x5 as i32 as u8,
-    0x82 as i32 as u8,
-];
-static mut unicode_prop_Hex_Digit_table: [u8; 12] = [
-    0xaf as i32 as u8,
-    0x89 as i32 as u8,
-    0x35 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-    0x60 as i32 as u8,
-    0xfe as i32 as u8,
-    0xa8 as i32 as u8,
-    0x89 as i32 as u8,
-    0x35 as i32 as u8,
-    0x99 as i32 as u8,
-    0x85 as i32 as u8,
-];
-static mut unicode_prop_IDS_Binary_Operator_table: [u8; 5] = [
-    0x60 as i32 as u8,
-    0x2f as i32 as u8,
-    0xef as i32 as u8,
-    0x9 as i32 as u8,
-    0x87 as i32 as u8,
-];
-static mut unicode_prop_IDS_Trinary_Operator_table: [u8; 4] = [
-    0x60 as i32 as u8,
-    0x2f as i32 as u8,
-    0xf1 as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Ideographic_table: [u8; 66] = [
-    0x60 as i32 as u8,
-    0x30 as i32 as u8,
-    0x5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x98 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8d as i32 as u8,
-    0x82 as i32 as u8,
-    0x43 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x59 as i32 as u8,
-    0xbf as i32 as u8,
-    0xbf as i32 as u8,
-    0x60 as i32 as u8,
-    0x51 as i32 as u8,
-    0xfc as i32 as u8,
-    0x60 as i32 as u8,
-    0x59 as i32 as u8,
-    0x2 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6d as i32 as u8,
-    0x81 as i32 as u8,
-    0xe9 as i32 as u8,
-    0x60 as i32 as u8,
-    0x75 as i32 as u8,
-    0x9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9a as i32 as u8,
-    0x57 as i32 as u8,
-    0xf7 as i32 as u8,
-    0x87 as i32 as u8,
-    0x44 as i32 as u8,
-    0xd5 as i32 as u8,
-    0xa9 as i32 as u8,
-    0x88 as i32 as u8,
-    0x60 as i32 as u8,
-    0x24 as i32 as u8,
-    0x66 as i32 as u8,
-    0x41 as i32 as u8,
-    0x8b as i32 as u8,
-    0x60 as i32 as u8,
-    0x4d as i32 as u8,
-    0x3 as i32 as u8,
-    0x60 as i32 as u8,
-    0xa6 as i32 as u8,
-    0xdd as i32 as u8,
-    0xa1 as i32 as u8,
-    0x50 as i32 as u8,
-    0x34 as i32 as u8,
-    0x8a as i32 as u8,
-    0x40 as i32 as u8,
-    0xdd as i32 as u8,
-    0x81 as i32 as u8,
-    0x56 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x5d as i32 as u8,
-    0x30 as i32 as u8,
-    0x4c as i32 as u8,
-    0x1e as i32 as u8,
-    0x42 as i32 as u8,
-    0x1d as i32 as u8,
-    0x45 as i32 as u8,
-    0xe1 as i32 as u8,
-    0x53 as i32 as u8,
-    0x4a as i32 as u8,
-];
-static mut unicode_prop_Join_Control_table: [u8; 4] = [
-    0x60 as i32 as u8,
-    0x20 as i32 as u8,
-    0xb as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Logical_Order_Exception_table: [u8; 15] = [
-    0x4e as i32 as u8,
-    0x3f as i32 as u8,
-    0x84 as i32 as u8,
-    0xfa as i32 as u8,
-    0x84 as i32 as u8,
-    0x4a as i32 as u8,
-    0xef as i32 as u8,
-    0x11 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x90 as i32 as u8,
-    0xf9 as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Noncharacter_Code_Point_table: [u8; 71] = [
-    0x60 as i32 as u8,
-    0xfd as i32 as u8,
-    0xcf as i32 as u8,
-    0x9f as i32 as u8,
-    0x42 as i32 as u8,
-    0xd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xff as i32 as u8,
-    0xfd as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Pattern_Syntax_table: [u8; 58] = [
-    0xa0 as i32 as u8,
-    0x8e as i32 as u8,
-    0x89 as i32 as u8,
-    0x86 as i32 as u8,
-    0x99 as i32 as u8,
-    0x18 as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x83 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0xb as i32 as u8,
-    0x3 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x5f as i32 as u8,
-    0x17 as i32 as u8,
-    0x97 as i32 as u8,
-    0x87 as i32 as u8,
-    0x8e as i32 as u8,
-    0x81 as i32 as u8,
-    0x92 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x41 as i32 as u8,
-    0x30 as i32 as u8,
-    0x42 as i32 as u8,
-    0xcf as i32 as u8,
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x42 as i32 as u8,
-    0x75 as i32 as u8,
-    0x9d as i32 as u8,
-    0x44 as i32 as u8,
-    0x6b as i32 as u8,
-    0x41 as i32 as u8,
-    0xff as i32 as u8,
-    0xff as i32 as u8,
-    0x41 as i32 as u8,
-    0x80 as i32 as u8,
-    0x13 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcd as i32 as u8,
-    0xc as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Pattern_White_Space_table: [u8; 11] = [
-    0x88 as i32 as u8,
-    0x84 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5f as i32 as u8,
-    0x87 as i32 as u8,
-    0x81 as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Quotation_Mark_table: [u8; 31] = [
-    0xa1 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x82 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x5f as i32 as u8,
-    0x5b as i32 as u8,
-    0x87 as i32 as u8,
-    0x98 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4e as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc8 as i32 as u8,
-    0x83 as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x60 as i32 as u8,
-    0xce as i32 as u8,
-    0x20 as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbc as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Radical_table: [u8; 9] = [
-    0x60 as i32 as u8,
-    0x2e as i32 as u8,
-    0x7f as i32 as u8,
-    0x99 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x8b as i32 as u8,
-    0x40 as i32 as u8,
-    0xd5 as i32 as u8,
-];
-static mut unicode_prop_Regional_Indicator_table: [u8; 4] = [
-    0x61 as i32 as u8,
-    0xf1 as i32 as u8,
-    0xe5 as i32 as u8,
-    0x99 as i32 as u8,
-];
-static mut unicode_prop_Sentence_Terminal_table: [u8; 188] = [
-    0xa0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0x45 as i32 as u8,
-    0x48 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x93 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaa as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x80 as i32 as u8,
-    0xbc as i32 as u8,
-    0 as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x24 as i32 as u8,
-    0x81 as i32 as u8,
-    0x46 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x15 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xcb as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x39 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x61 as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0xad as i32 as u8,
-    0x9 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xda as i32 as u8,
-    0x81 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0xbb as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4d as i32 as u8,
-    0xe3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x74 as i32 as u8,
-    0xfb as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xd as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x7d as i32 as u8,
-    0x81 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x81 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x92 as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0x8f as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x52 as i32 as u8,
-    0x65 as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x4a as i32 as u8,
-    0xf3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x44 as i32 as u8,
-    0xfc as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0xec as i32 as u8,
-    0x81 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x83 as i32 as u8,
-    0xfe as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x81 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0xeb as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x74 as i32 as u8,
-    0xc as i32 as u8,
-    0x8e as i32 as u8,
-    0xe8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x42 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfa as i32 as u8,
-    0x81 as i32 as u8,
-    0xd6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x42 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4b as i32 as u8,
-    0x74 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x84 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x52 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4e as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5d as i32 as u8,
-    0xe7 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Soft_Dotted_table: [u8; 71] = [
-    0xe8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x18 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9d as i32 as u8,
-    0x80 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x3f as i32 as u8,
-    0x80 as i32 as u8,
-    0xe1 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x59 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x83 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9c as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x4b as i32 as u8,
-    0x31 as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0xa7 as i32 as u8,
-    0xa4 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x81 as i32 as u8,
-];
-static mut unicode_prop_Terminal_Punctuation_table: [u8; 241] = [
-    0xa0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x3d as i32 as u8,
-    0x7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xb3 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaa as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0xea as i32 as u8,
-    0x81 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x8e as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x4 as i32 as u8,
-    0x81 as i32 as u8,
-    0x44 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xab as i32 as u8,
-    0x3 as i32 as u8,
-    0x85 as i32 as u8,
-    0x41 as i32 as u8,
-    0x36 as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0x14 as i32 as u8,
-    0x87 as i32 as u8,
-    0x43 as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0xfb as i32 as u8,
-    0x82 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9c as i32 as u8,
-    0x12 as i32 as u8,
-    0x80 as i32 as u8,
-    0xa6 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x39 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x61 as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0xad as i32 as u8,
-    0x8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0xda as i32 as u8,
-    0x84 as i32 as u8,
-    0xbd as i32 as u8,
-    0x81 as i32 as u8,
-    0x43 as i32 as u8,
-    0xbb as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0x4d as i32 as u8,
-    0xe3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x74 as i32 as u8,
-    0xfa as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xc as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x84 as i32 as u8,
-    0x41 as i32 as u8,
-    0x7d as i32 as u8,
-    0x81 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x81 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x96 as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0x92 as i32 as u8,
-    0x82 as i32 as u8,
-    0xfe as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0x52 as i32 as u8,
-    0x63 as i32 as u8,
-    0x10 as i32 as u8,
-    0x83 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0x39 as i32 as u8,
-    0x80 as i32 as u8,
-    0xaf as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0x85 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x35 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x97 as i32 as u8,
-    0x85 as i32 as u8,
-    0xc3 as i32 as u8,
-    0x85 as i32 as u8,
-    0xd8 as i32 as u8,
-    0x83 as i32 as u8,
-    0x43 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x84 as i32 as u8,
-    0x40 as i32 as u8,
-    0xec as i32 as u8,
-    0x86 as i32 as u8,
-    0xef as i32 as u8,
-    0x83 as i32 as u8,
-    0xfe as i32 as u8,
-    0x82 as i32 as u8,
-    0x40 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x81 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x84 as i32 as u8,
-    0xeb as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8b as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x65 as i32 as u8,
-    0x1a as i32 as u8,
-    0x8e as i32 as u8,
-    0xe8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x82 as i32 as u8,
-    0x42 as i32 as u8,
-    0x4 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xfa as i32 as u8,
-    0x81 as i32 as u8,
-    0xd6 as i32 as u8,
-    0xb as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x9d as i32 as u8,
-    0x82 as i32 as u8,
-    0xac as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x84 as i32 as u8,
-    0x81 as i32 as u8,
-    0x45 as i32 as u8,
-    0x76 as i32 as u8,
-    0x84 as i32 as u8,
-    0x60 as i32 as u8,
-    0x45 as i32 as u8,
-    0xf8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0x84 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc0 as i32 as u8,
-    0x82 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x51 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0x4e as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5d as i32 as u8,
-    0xe6 as i32 as u8,
-    0x83 as i32 as u8,
-];
-static mut unicode_prop_Unified_Ideograph_table: [u8; 42] = [
-    0x60 as i32 as u8,
-    0x33 as i32 as u8,
-    0xff as i32 as u8,
-    0x59 as i32 as u8,
-    0xbf as i32 as u8,
-    0xbf as i32 as u8,
-    0x60 as i32 as u8,
-    0x51 as i32 as u8,
-    0xfc as i32 as u8,
-    0x60 as i32 as u8,
-    0x5a as i32 as u8,
-    0x10 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x82 as i32 as u8,
-    0x61 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x60 as i32 as u8,
-    0xa6 as i32 as u8,
-    0xdd as i32 as u8,
-    0xa1 as i32 as u8,
-    0x50 as i32 as u8,
-    0x34 as i32 as u8,
-    0x8a as i32 as u8,
-    0x40 as i32 as u8,
-    0xdd as i32 as u8,
-    0x81 as i32 as u8,
-    0x56 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x5d as i32 as u8,
-    0x30 as i32 as u8,
-    0x54 as i32 as u8,
-    0x1e as i32 as u8,
-    0x53 as i32 as u8,
-    0x4a as i32 as u8,
-];
-static mut unicode_prop_Variation_Selector_table: [u8; 12] = [
-    0x58 as i32 as u8,
-    0xa as i32 as u8,
-    0x82 as i32 as u8,
-    0x60 as i32 as u8,
-    0xe5 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x8f as i32 as u8,
-    0x6d as i32 as u8,
-    0x2 as i32 as u8,
-    0xef as i32 as u8,
-    0x40 as i32 as u8,
-    0xef as i32 as u8,
-];
-static mut unicode_prop_White_Space_table: [u8; 22] = [
-    0x88 as i32 as u8,
-    0x84 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0xe3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x80 as i32 as u8,
-    0x55 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0x49 as i32 as u8,
-    0x7e as i32 as u8,
-    0x8a as i32 as u8,
-    0x9c as i32 as u8,
-    0xc as i32 as u8,
-    0x80 as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x4f as i32 as u8,
-    0x9f as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Bidi_Mirrored_table: [u8; 171] = [
-    0xa7 as i32 as u8,
-    0x81 as i32 as u8,
-    0x91 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9c as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0xac as i32 as u8,
-    0x80 as i32 as u8,
-    0x8e as i32 as u8,
-    0x80 as i32 as u8,
-    0x4e as i32 as u8,
-    0x7d as i32 as u8,
-    0x83 as i32 as u8,
-    0x47 as i32 as u8,
-    0x5c as i32 as u8,
-    0x81 as i32 as u8,
-    0x49 as i32 as u8,
-    0x9b as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbf as i32 as u8,
-    0x1a as i32 as u8,
-    0x2a as i32 as u8,
-    0x2 as i32 as u8,
-    0xa as i32 as u8,
-    0x18 as i32 as u8,
-    0x18 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x88 as i32 as u8,
-    0x20 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x23 as i32 as u8,
-    0x88 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x39 as i32 as u8,
-    0x9e as i32 as u8,
-    0xb as i32 as u8,
-    0x20 as i32 as u8,
-    0x88 as i32 as u8,
-    0x9 as i32 as u8,
-    0x92 as i32 as u8,
-    0x21 as i32 as u8,
-    0x88 as i32 as u8,
-    0x21 as i32 as u8,
-    0xb as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8f as i32 as u8,
-    0x3b as i32 as u8,
-    0x93 as i32 as u8,
-    0xe as i32 as u8,
-    0x81 as i32 as u8,
-    0x44 as i32 as u8,
-    0x3c as i32 as u8,
-    0x8d as i32 as u8,
-    0xc9 as i32 as u8,
-    0x1 as i32 as u8,
-    0x18 as i32 as u8,
-    0x8 as i32 as u8,
-    0x14 as i32 as u8,
-    0x1c as i32 as u8,
-    0x12 as i32 as u8,
-    0x8d as i32 as u8,
-    0x41 as i32 as u8,
-    0x92 as i32 as u8,
-    0x95 as i32 as u8,
-    0xd as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x38 as i32 as u8,
-    0x35 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1c as i32 as u8,
-    0x1 as i32 as u8,
-    0xc as i32 as u8,
-    0x18 as i32 as u8,
-    0x2 as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x29 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x92 as i32 as u8,
-    0x3 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x21 as i32 as u8,
-    0x2a as i32 as u8,
-    0x97 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0xb as i32 as u8,
-    0x18 as i32 as u8,
-    0x9 as i32 as u8,
-    0xb as i32 as u8,
-    0xaa as i32 as u8,
-    0xf as i32 as u8,
-    0x80 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x20 as i32 as u8,
-    0 as i32 as u8,
-    0x14 as i32 as u8,
-    0x22 as i32 as u8,
-    0x18 as i32 as u8,
-    0x14 as i32 as u8,
-    0 as i32 as u8,
-    0x40 as i32 as u8,
-    0xff as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x2 as i32 as u8,
-    0x1a as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x9 as i32 as u8,
-    0x89 as i32 as u8,
-    0x41 as i32 as u8,
-    0xdd as i32 as u8,
-    0x89 as i32 as u8,
-    0xf as i32 as u8,
-    0x60 as i32 as u8,
-    0xce as i32 as u8,
-    0x3c as i32 as u8,
-    0x2c as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x91 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9b as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x9c as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x60 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x76 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x80 as i32 as u8,
-];
-static mut unicode_prop_Emoji_table: [u8; 238] = [
-    0xa2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x89 as i32 as u8,
-    0xee as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5f as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x85 as i32 as u8,
-    0x8e as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6e as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xa5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8a as i32 as u8,
-    0x1a as i32 as u8,
-    0x40 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x18 as i32 as u8,
-    0x84 as i32 as u8,
-    0x88 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x9 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x2 as i32 as u8,
-    0xf as i32 as u8,
-    0x14 as i32 as u8,
-    0 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8b as i32 as u8,
-    0x8a as i32 as u8,
-    0x9 as i32 as u8,
-    0 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x91 as i32 as u8,
-    0x1 as i32 as u8,
-    0x81 as i32 as u8,
-    0x91 as i32 as u8,
-    0x28 as i32 as u8,
-    0 as i32 as u8,
-    0xa as i32 as u8,
-    0xc as i32 as u8,
-    0x1 as i32 as u8,
-    0xb as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0xc as i32 as u8,
-    0x9 as i32 as u8,
-    0x4 as i32 as u8,
-    0x8 as i32 as u8,
-    0 as i32 as u8,
-    0x81 as i32 as u8,
-    0x93 as i32 as u8,
-    0xc as i32 as u8,
-    0x28 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x1 as i32 as u8,
-    0x28 as i32 as u8,
-    0x1 as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x10 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x81 as i32 as u8,
-    0xaf as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x73 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xce as i32 as u8,
-    0x82 as i32 as u8,
-    0x92 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x58 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0xbd as i32 as u8,
-    0x69 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xca as i32 as u8,
-    0x99 as i32 as u8,
-    0x1 as i32 as u8,
-    0x96 as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xad as i32 as u8,
-    0xa1 as i32 as u8,
-    0x81 as i32 as u8,
-    0xef as i32 as u8,
-    0x9 as i32 as u8,
-    0x2 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd2 as i32 as u8,
-    0xa as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6 as i32 as u8,
-    0x80 as i32 as u8,
-    0xbe as i32 as u8,
-    0x8a as i32 as u8,
-    0x28 as i32 as u8,
-    0x97 as i32 as u8,
-    0x31 as i32 as u8,
-    0xf as i32 as u8,
-    0x8b as i32 as u8,
-    0x1 as i32 as u8,
-    0x19 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8c as i32 as u8,
-    0x9 as i32 as u8,
-    0x7 as i32 as u8,
-    0x81 as i32 as u8,
-    0x88 as i32 as u8,
-    0x4 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8b as i32 as u8,
-    0x17 as i32 as u8,
-    0x11 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x5 as i32 as u8,
-    0x2 as i32 as u8,
-    0x5 as i32 as u8,
-    0xd5 as i32 as u8,
-    0xaf as i32 as u8,
-    0xc5 as i32 as u8,
-    0x27 as i32 as u8,
-    0xa as i32 as u8,
-    0x3d as i32 as u8,
-    0x10 as i32 as u8,
-    0x1 as i32 as u8,
-    0x10 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x8b as i32 as u8,
-    0x41 as i32 as u8,
-    0x1f as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb2 as i32 as u8,
-    0xef as i32 as u8,
-    0x22 as i32 as u8,
-    0x14 as i32 as u8,
-    0x86 as i32 as u8,
-    0x88 as i32 as u8,
-    0x98 as i32 as u8,
-    0x36 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x86 as i32 as u8,
-];
-static mut unicode_prop_Emoji_Component_table: [u8; 28] = [
-    0xa2 as i32 as u8,
-    0x5 as i32 as u8,
-    0x4 as i32 as u8,
-    0x89 as i32 as u8,
-    0x5f as i32 as u8,
-    0xd2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xdd as i32 as u8,
-    0x2a as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xf3 as i32 as u8,
-    0xd5 as i32 as u8,
-    0x99 as i32 as u8,
-    0x41 as i32 as u8,
-    0xfa as i32 as u8,
-    0x84 as i32 as u8,
-    0x45 as i32 as u8,
-    0xaf as i32 as u8,
-    0x83 as i32 as u8,
-    0x6c as i32 as u8,
-    0x6 as i32 as u8,
-    0x6b as i32 as u8,
-    0xdf as i32 as u8,
-];
-static mut unicode_prop_Emoji_Modifier_table: [u8; 4] = [
-    0x61 as i32 as u8,
-    0xf3 as i32 as u8,
-    0xfa as i32 as u8,
-    0x84 as i32 as u8,
-];
-static mut unicode_prop_Emoji_Modifier_Base_table: [u8; 66] = [
-    0x60 as i32 as u8,
-    0x26 as i32 as u8,
-    0x1c as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xda as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x83 as i32 as u8,
-    0x61 as i32 as u8,
-    0xcc as i32 as u8,
-    0x76 as i32 as u8,
-    0x80 as i32 as u8,
-    0xbb as i32 as u8,
-    0x11 as i32 as u8,
-    0x1 as i32 as u8,
-    0x82 as i32 as u8,
-    0xf4 as i32 as u8,
-    0x9 as i32 as u8,
-    0x8a as i32 as u8,
-    0x94 as i32 as u8,
-    0x92 as i32 as u8,
-    0x10 as i32 as u8,
-    0x1a as i32 as u8,
-    0x2 as i32 as u8,
-    0x30 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc8 as i32 as u8,
-    0xb as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xad as i32 as u8,
-    0x12 as i32 as u8,
-    0x84 as i32 as u8,
-    0xd2 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8f as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x3e as i32 as u8,
-    0x1 as i32 as u8,
-    0x7 as i32 as u8,
-    0x3d as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x89 as i32 as u8,
-    0xa as i32 as u8,
-    0xb7 as i32 as u8,
-    0x80 as i32 as u8,
-    0xbc as i32 as u8,
-    0x8 as i32 as u8,
-    0x8 as i32 as u8,
-    0x80 as i32 as u8,
-    0x90 as i32 as u8,
-    0x10 as i32 as u8,
-    0x8c as i32 as u8,
-];
-static mut unicode_prop_Emoji_Presentation_table: [u8; 144] = [
-    0x60 as i32 as u8,
-    0x23 as i32 as u8,
-    0x19 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xcc as i32 as u8,
-    0x1a as i32 as u8,
-    0x1 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x8 as i32 as u8,
-    0x81 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x8b as i32 as u8,
-    0xaa as i32 as u8,
-    0x80 as i32 as u8,
-    0x92 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8c as i32 as u8,
-    0x7 as i32 as u8,
-    0x81 as i32 as u8,
-    0x90 as i32 as u8,
-    0xc as i32 as u8,
-    0xf as i32 as u8,
-    0x4 as i32 as u8,
-    0x80 as i32 as u8,
-    0x94 as i32 as u8,
-    0x6 as i32 as u8,
-    0x8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x1 as i32 as u8,
-    0x6 as i32 as u8,
-    0x3 as i32 as u8,
-    0x81 as i32 as u8,
-    0x9b as i32 as u8,
-    0x80 as i32 as u8,
-    0xa2 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x10 as i32 as u8,
-    0x80 as i32 as u8,
-    0xbc as i32 as u8,
-    0x82 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x43 as i32 as u8,
-    0x5a as i32 as u8,
-    0x81 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0xc4 as i32 as u8,
-    0xad as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xc9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xbd as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xca as i32 as u8,
-    0x99 as i32 as u8,
-    0 as i32 as u8,
-    0x97 as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x1 as i32 as u8,
-    0x20 as i32 as u8,
-    0x82 as i32 as u8,
-    0x94 as i32 as u8,
-    0x81 as i32 as u8,
-    0x40 as i32 as u8,
-    0xad as i32 as u8,
-    0xa0 as i32 as u8,
-    0x8b as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x8b as i32 as u8,
-    0xaa as i32 as u8,
-    0x1c as i32 as u8,
-    0x8b as i32 as u8,
-    0x90 as i32 as u8,
-    0x10 as i32 as u8,
-    0x82 as i32 as u8,
-    0xc6 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xba as i32 as u8,
-    0x81 as i32 as u8,
-    0xbe as i32 as u8,
-    0x8c as i32 as u8,
-    0x18 as i32 as u8,
-    0x97 as i32 as u8,
-    0x91 as i32 as u8,
-    0x80 as i32 as u8,
-    0x99 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0xd5 as i32 as u8,
-    0xd4 as i32 as u8,
-    0xaf as i32 as u8,
-    0xc5 as i32 as u8,
-    0x28 as i32 as u8,
-    0x12 as i32 as u8,
-    0xa as i32 as u8,
-    0x92 as i32 as u8,
-    0xe as i32 as u8,
-    0x88 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe2 as i32 as u8,
-    0x8b as i32 as u8,
-    0x41 as i32 as u8,
-    0x1f as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd1 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb2 as i32 as u8,
-    0xef as i32 as u8,
-    0x22 as i32 as u8,
-    0x14 as i32 as u8,
-    0x86 as i32 as u8,
-    0x88 as i32 as u8,
-    0x98 as i32 as u8,
-    0x36 as i32 as u8,
-    0x88 as i32 as u8,
-    0x82 as i32 as u8,
-    0x8c as i32 as u8,
-    0x86 as i32 as u8,
-];
-static mut unicode_prop_Extended_Pictographic_table: [u8; 156] = [
-    0x40 as i32 as u8,
-    0xa8 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x5f as i32 as u8,
-    0x8c as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xd7 as i32 as u8,
-    0x80 as i32 as u8,
-    0x95 as i32 as u8,
-    0x80 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x85 as i32 as u8,
-    0x8e as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0x6e as i32 as u8,
-    0x81 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0xde as i32 as u8,
-    0x80 as i32 as u8,
-    0xc5 as i32 as u8,
-    0x80 as i32 as u8,
-    0x98 as i32 as u8,
-    0x8a as i32 as u8,
-    0x1a as i32 as u8,
-    0x40 as i32 as u8,
-    0xc6 as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0xe6 as i32 as u8,
-    0x81 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0xb9 as i32 as u8,
-    0x18 as i32 as u8,
-    0x28 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0xf1 as i32 as u8,
-    0x89 as i32 as u8,
-    0xf5 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8a as i32 as u8,
-    0 as i32 as u8,
-    0 as i32 as u8,
-    0x28 as i32 as u8,
-    0x10 as i32 as u8,
-    0x28 as i32 as u8,
-    0x89 as i32 as u8,
-    0x81 as i32 as u8,
-    0x8e as i32 as u8,
-    0x1 as i32 as u8,
-    0x3 as i32 as u8,
-    0 as i32 as u8,
-    0x3 as i32 as u8,
-    0x10 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8a as i32 as u8,
-    0x84 as i32 as u8,
-    0xac as i32 as u8,
-    0x82 as i32 as u8,
-    0x88 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x8d as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0x73 as i32 as u8,
-    0x81 as i32 as u8,
-    0x41 as i32 as u8,
-    0xce as i32 as u8,
-    0x82 as i32 as u8,
-    0x92 as i32 as u8,
-    0x81 as i32 as u8,
-    0xb2 as i32 as u8,
-    0x3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x44 as i32 as u8,
-    0xd9 as i32 as u8,
-    0x80 as i32 as u8,
-    0x8b as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0x58 as i32 as u8,
-    0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x61 as i32 as u8,
-    0xbd as i32 as u8,
-    0x65 as i32 as u8,
-    0x40 as i32 as u8,
-    0xff as i32 as u8,
-    0x8c as i32 as u8,
-    0x82 as i32 as u8,
-    0x9e as i32 as u8,
-    0x80 as i32 as u8,
-    0xbb as i32 as u8,
-    0x85 as i32 as u8,
-    0x8b as i32 as u8,
-    0x81 as i32 as u8,
-    0x8d as i32 as u8,
-    0x1 as i32 as u8,
-    0x89 as i32 as u8,
-    0x91 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x9a as i32 as u8,
-    0x8e as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x93 as i32 as u8,
-    0x1 as i32 as u8,
-    0x88 as i32 as u8,
-    0x3 as i32 as u8,
-    0x88 as i32 as u8,
-    0x41 as i32 as u8,
-    0xb1 as i32 as u8,
-    0x84 as i32 as u8,
-    0x41 as i32 as u8,
-    0x3d as i32 as u8,
-    0x87 as i32 as u8,
-    0x41 as i32 as u8,
-    0x9 as i32 as u8,
-    0xaf as i32 as u8,
-    0xff as i32 as u8,
-    0xf3 as i32 as u8,
-    0x8b as i32 as u8,
-    0xd4 as i32 as u8,
-    0xaa as i32 as u8,
-    0x8b as i32 as u8,
-    0x83 as i32 as u8,
-    0xb7 as i32 as u8,
-    0x87 as i32 as u8,
-    0x89 as i32 as u8,
-    0x85 as i32 as u8,
-    0xa7 as i32 as u8,
-    0x87 as i32 as u8,
-    0x9d as i32 as u8,
-    0xd1 as i32 as u8,
-    0x8b as i32 as u8,
-    0xae as i32 as u8,
-    0x80 as i32 as u8,
-    0x89 as i32 as u8,
-    0x80 as i32 as u8,
-    0x41 as i32 as u8,
-    0xb8 as i32 as u8,
-    0x40 as i32 as u8,
-    0xff as i32 as u8,
-    0x43 as i32 as u8,
-    0xfd as i32 as u8,
-];
-static mut unicode_prop_Default_Ignorable_Code_Point_table: [u8; 51] = [
-    0x40 as i32 as u8,
-    0xac as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xa0 as i32 as u8,
-    0x80 as i32 as u8,
-    0x42 as i32 as u8,
-    0xcb as i32 as u8,
-    0x80 as i32 as u8,
-    0x4b as i32 as u8,
-    0x41 as i32 as u8,
-    0x81 as i32 as u8,
-    0x46 as i32 as u8,
-    0x52 as i32 as u8,
-    0x81 as i32 as u8,
-    0xd4 as i32 as u8,
-    0x83 as i32 as u8,
-    0x47 as i32 as u8,
-    0xfb as i32 as u8,
-    0x84 as i32 as u8,
-    0x99 as i32 as u8,
-    0x84 as i32 as u8,
-    0xb0 as i32 as u8,
-    0x8f as i32 as u8,
-    0x50 as i32 as u8,
-    0xf3 as i32 as u8,
-    0x80 as i32 as u8,
-    0x60 as i32 as u8,
-    0xcc as i32 as u8,
-    0x9a as i32 as u8,
-    0x8f as i32 as u8,
-    0x40 as i32 as u8,
-    0xee as i32 as u8,
-    0x80 as i32 as u8,
-    0x40 as i32 as u8,
-    0x9f as i32 as u8,
-    0x80 as i32 as u8,
-    0xce as i32 as u8,
-    0x88 as i32 as u8,
-    0x60 as i32 as u8,
-    0xbc as i32 as u8,
-    0xa6 as i32 as u8,
-    0x83 as i32 as u8,
-    0x54 as i32 as u8,
-    0xce as i32 as u8,
-    0x87 as i32 as u8,
-    0x6c as i32 as u8,
-    0x2e as i32 as u8,
-    0x84 as i32 as u8,
-    0x4f as i32 as u8,
-    0xff as i32 as u8,
-];
-static mut unicode_prop_name_table: [std::os::raw::c_char; 1042] = unsafe {
-    *::std::mem::transmute::<&[u8; 1042],
-                                 &[std::os::raw::c_char; 1042]>(b"ASCII_Hex_Digit,AHex\x00Bidi_Control,Bidi_C\x00Dash\x00Deprecated,Dep\x00Diacritic,Dia\x00Extender,Ext\x00Hex_Digit,Hex\x00IDS_Binary_Operator,IDSB\x00IDS_Trinary_Operator,IDST\x00Ideographic,Ideo\x00Join_Control,Join_C\x00Logical_Order_Exception,LOE\x00Noncharacter_Code_Point,NChar\x00Pattern_Syntax,Pat_Syn\x00Pattern_White_Space,Pat_WS\x00Quotation_Mark,QMark\x00Radical\x00Regional_Indicator,RI\x00Sentence_Terminal,STerm\x00Soft_Dotted,SD\x00Terminal_Punctuation,Term\x00Unified_Ideograph,UIdeo\x00Variation_Selector,VS\x00White_Space,space\x00Bidi_Mirrored,Bidi_M\x00Emoji\x00Emoji_Component,EComp\x00Emoji_Modifier,EMod\x00Emoji_Modifier_Base,EBase\x00Emoji_Presentation,EPres\x00Extended_Pictographic,ExtPict\x00Default_Ignorable_Code_Point,DI\x00ID_Start,IDS\x00Case_Ignorable,CI\x00ASCII\x00Alphabetic,Alpha\x00Any\x00Assigned\x00Cased\x00Changes_When_Casefolded,CWCF\x00Changes_When_Casemapped,CWCM\x00Changes_When_Lowercased,CWL\x00Changes_When_NFKC_Casefolded,CWKCF\x00Changes_When_Titlecased,CWT\x00Changes_When_Uppercased,CWU\x00Grapheme_Base,Gr_Base\x00Grapheme_Extend,Gr_Ext\x00ID_Continue,IDC\x00Lowercase,Lower\x00Math\x00Uppercase,Upper\x00XID_Continue,XIDC\x00XID_Start,XIDS\x00\x00")
-};
-static mut unicode_prop_table: [*const u8; 50] = unsafe {
-    [
-        unicode_prop_Hyphen_table.as_ptr(),
-        unicode_prop_Other_Math_table.as_ptr(),
-        unicode_prop_Other_Alphabetic_table.as_ptr(),
-        unicode_prop_Other_Lowercase_table.as_ptr(),
-        unicode_prop_Other_Uppercase_table.as_ptr(),
-        unicode_prop_Other_Grapheme_Extend_table.as_ptr(),
-        unicode_prop_Other_Default_Ignorable_Code_Point_table.as_ptr(),
-        unicode_prop_Other_ID_Start_table.as_ptr(),
-        unicode_prop_Other_ID_Continue_table.as_ptr(),
-        unicode_prop_Prepended_Concatenation_Mark_table.as_ptr(),
-        unicode_prop_ID_Continue1_table.as_ptr(),
-        unicode_prop_XID_Start1_table.as_ptr(),
-        unicode_prop_XID_Continue1_table.as_ptr(),
-        unicode_prop_Changes_When_Titlecased1_table.as_ptr(),
-        unicode_prop_Changes_When_Casefolded1_table.as_ptr(),
-        unicode_prop_Changes_When_NFKC_Casefolded1_table.as_ptr(),
-        unicode_prop_ASCII_Hex_Digit_table.as_ptr(),
-        unicode_prop_Bidi_Control_table.as_ptr(),
-        unicode_prop_Dash_table.as_ptr(),
-        unicode_prop_Deprecated_table.as_ptr(),
-        unicode_prop_Diacritic_table.as_ptr(),
-        unicode_prop_Extender_table.as_ptr(),
-        unicode_prop_Hex_Digit_table.as_ptr(),
-        unicode_prop_IDS_Binary_Operator_table.as_ptr(),
-        unicode_prop_IDS_Trinary_Operator_table.as_ptr(),
-        unicode_prop_Ideographic_table.as_ptr(),
-        unicode_prop_Join_Control_table.as_ptr(),
-        unicode_prop_Logical_Order_Exception_table.as_ptr(),
-        unicode_prop_Noncharacter_Code_Point_table.as_ptr(),
-        unicode_prop_Pattern_Syntax_table.as_ptr(),
-        unicode_prop_Pattern_White_Space_table.as_ptr(),
-        unicode_prop_Quotation_Mark_table.as_ptr(),
-        unicode_prop_Radical_table.as_ptr(),
-        unicode_prop_Regional_Indicator_table.as_ptr(),
-        unicode_prop_Sentence_Terminal_table.as_ptr(),
-        unicode_prop_Soft_Dotted_table.as_ptr(),
-        unicode_prop_Terminal_Punctuation_table.as_ptr(),
-        unicode_prop_Unified_Ideograph_table.as_ptr(),
-        unicode_prop_Variation_Selector_table.as_ptr(),
-        unicode_prop_White_Space_table.as_ptr(),
-        unicode_prop_Bidi_Mirrored_table.as_ptr(),
-        unicode_prop_Emoji_table.as_ptr(),
-        unicode_prop_Emoji_Component_table.as_ptr(),
-        unicode_prop_Emoji_Modifier_table.as_ptr(),
-        unicode_prop_Emoji_Modifier_Base_table.as_ptr(),
-        unicode_prop_Emoji_Presentation_table.as_ptr(),
-        unicode_prop_Extended_Pictographic_table.as_ptr(),
-        unicode_prop_Default_Ignorable_Code_Point_table.as_ptr(),
-        unicode_prop_ID_Start_table.as_ptr(),
-        unicode_prop_Case_Ignorable_table.as_ptr(),
-    ]
-};
-// Initialized in run_static_initializers
-static mut unicode_prop_len_table: [u16; 50] = [0; 50];
+/* generated from the Unicode Character Database by unicode_gen */
+include!("libunicode_table.rs");
 /* conv_type:
    0 = to upper
    1 = to lower
@@ -27488,9 +187,7 @@ pub unsafe fn lre_case_conv(mut res: *mut u32, mut c: u32, mut conv_type: i32) -
         let mut idx_max: i32 = 0;
         is_lower = (conv_type != 0 as i32) as i32 as u32;
         idx_min = 0 as i32;
-        idx_max = (::std::mem::size_of::<[u32; 361]>() as u64)
-            .wrapping_div(::std::mem::size_of::<u32>() as u64)
-            .wrapping_sub(1 as i32 as u64) as i32;
+        idx_max = case_conv_table1.len() as i32 - 1;
         while idx_min <= idx_max {
             idx = ((idx_max + idx_min) as u32).wrapping_div(2 as i32 as u32) as i32;
             v = case_conv_table1[idx as usize];
@@ -27608,7 +305,7 @@ unsafe fn get_le24(mut ptr: *const u8) -> u32 {
         as u32;
 }
 /* return -1 if not in table, otherwise the offset in the block */
-unsafe fn get_index_pos(
+pub(crate) unsafe fn get_index_pos(
     mut pcode: *mut u32,
     mut c: u32,
     mut index_table: *const u8,
@@ -27646,7 +343,7 @@ unsafe fn get_index_pos(
     *pcode = v & (((1 as i32) << 21 as i32) - 1 as i32) as u32;
     return (((idx_min + 1 as i32) * 32 as i32) as u32).wrapping_add(v >> 21 as i32) as i32;
 }
-unsafe fn lre_is_in_table(
+pub(crate) unsafe fn lre_is_in_table(
     mut c: u32,
     mut table: *const u8,
     mut index_table: *const u8,
@@ -27703,6 +400,55 @@ unsafe fn lre_is_in_table(
         bit ^= 1 as i32 as u32
     }
 }
+/* return the value of the run containing 'c' in a table using the
+general category encoding ((len << 5) | value, with extended
+lengths). 'index_table' is built in the same way as for the
+property tables. Return 0 if 'c' is outside the table. */
+pub(crate) unsafe fn lre_get_table_value(
+    c: u32,
+    table: *const u8,
+    index_table: *const u8,
+    index_table_len: i32,
+) -> u32 {
+    let mut code: u32 = 0;
+    let pos = get_index_pos(&mut code, c, index_table, index_table_len);
+    if pos < 0 {
+        return 0;
+    }
+    let mut p = table.offset(pos as isize);
+    loop {
+        let b = *p as u32;
+        p = p.offset(1);
+        let mut n = b >> 5;
+        let v = b & 0x1f;
+        if n == 7 {
+            n = *p as u32;
+            p = p.offset(1);
+            if n < 128 {
+                n += 7;
+            } else if n < 128 + 64 {
+                n = (n - 128) << 8 | *p as u32;
+                p = p.offset(1);
+                n += 7 + 128;
+            } else {
+                n = (n - 128 - 64) << 16 | (*p as u32) << 8 | *p.offset(1) as u32;
+                p = p.offset(2);
+                n += 7 + 128 + (1 << 14);
+            }
+        }
+        code += n + 1;
+        if c < code {
+            return v;
+        }
+    }
+}
+/* test 'c' against a property table. Only the tables of
+'unicode_prop_table' are supported. */
+pub(crate) unsafe fn lre_is_in_prop(c: u32, prop_idx: i32) -> BOOL {
+    let tab = unicode_prop_table[prop_idx as usize];
+    let index = unicode_prop_index_table[prop_idx as usize];
+    lre_is_in_table(c, tab.as_ptr(), index.as_ptr(), (index.len() / 3) as i32)
+}
 pub unsafe fn lre_is_cased(mut c: u32) -> i32 {
     let mut v: u32 = 0;
     let mut code: u32 = 0;
@@ -27711,9 +457,7 @@ pub unsafe fn lre_is_cased(mut c: u32) -> i32 {
     let mut idx_min: i32 = 0;
     let mut idx_max: i32 = 0;
     idx_min = 0 as i32;
-    idx_max = (::std::mem::size_of::<[u32; 361]>() as u64)
-        .wrapping_div(::std::mem::size_of::<u32>() as u64)
-        .wrapping_sub(1 as i32 as u64) as i32;
+    idx_max = case_conv_table1.len() as i32 - 1;
     while idx_min <= idx_max {
         idx = ((idx_max + idx_min) as u32).wrapping_div(2 as i32 as u32) as i32;
         v = case_conv_table1[idx as usize];
@@ -27731,7 +475,7 @@ pub unsafe fn lre_is_cased(mut c: u32) -> i32 {
         c,
         unicode_prop_Cased1_table.as_ptr(),
         unicode_prop_Cased1_index.as_ptr(),
-        (::std::mem::size_of::<[u8; 18]>() as u64).wrapping_div(3 as i32 as u64) as i32,
+        (unicode_prop_Cased1_index.len() / 3) as i32,
     );
 }
 pub unsafe fn lre_is_case_ignorable(mut c: u32) -> i32 {
@@ -27739,7 +483,7 @@ pub unsafe fn lre_is_case_ignorable(mut c: u32) -> i32 {
         c,
         unicode_prop_Case_Ignorable_table.as_ptr(),
         unicode_prop_Case_Ignorable_index.as_ptr(),
-        (::std::mem::size_of::<[u8; 66]>() as u64).wrapping_div(3 as i32 as u64) as i32,
+        (unicode_prop_Case_Ignorable_index.len() / 3) as i32,
     );
 }
 unsafe fn cr_default_realloc(
@@ -27940,7 +684,7 @@ pub unsafe fn lre_is_id_start(mut c: u32) -> i32 {
         c,
         unicode_prop_ID_Start_table.as_ptr(),
         unicode_prop_ID_Start_index.as_ptr(),
-        (::std::mem::size_of::<[u8; 99]>() as u64).wrapping_div(3 as i32 as u64) as i32,
+        (unicode_prop_ID_Start_index.len() / 3) as i32,
     );
 }
 pub unsafe fn lre_is_id_continue(mut c: u32) -> i32 {
@@ -27949,7 +693,7 @@ pub unsafe fn lre_is_id_continue(mut c: u32) -> i32 {
             c,
             unicode_prop_ID_Continue1_table.as_ptr(),
             unicode_prop_ID_Continue1_index.as_ptr(),
-            (::std::mem::size_of::<[u8; 60]>() as u64).wrapping_div(3 as i32 as u64) as i32,
+            (unicode_prop_ID_Continue1_index.len() / 3) as i32,
         ) != 0) as i32;
 }
 unsafe fn unicode_get_short_code(mut c: u32) -> u32 {
@@ -28182,9 +926,7 @@ unsafe fn unicode_decomp_char(mut res: *mut u32, mut c: u32, mut is_compat1: BOO
     let mut idx_max: i32 = 0;
     let mut idx: i32 = 0;
     idx_min = 0 as i32;
-    idx_max = (::std::mem::size_of::<[u32; 690]>() as u64)
-        .wrapping_div(::std::mem::size_of::<u32>() as u64)
-        .wrapping_sub(1 as i32 as u64) as i32;
+    idx_max = unicode_decomp_table1.len() as i32 - 1;
     while idx_min <= idx_max {
         idx = (idx_max + idx_min) / 2 as i32;
         v = unicode_decomp_table1[idx as usize];
@@ -28222,9 +964,7 @@ unsafe fn unicode_compose_pair(mut c0: u32, mut c1: u32) -> i32 {
     let mut d: i32 = 0;
     let mut pair: [u32; 2] = [0; 2];
     idx_min = 0 as i32;
-    idx_max = (::std::mem::size_of::<[u16; 945]>() as u64)
-        .wrapping_div(::std::mem::size_of::<u16>() as u64)
-        .wrapping_sub(1 as i32 as u64) as i32;
+    idx_max = unicode_comp_table.len() as i32 - 1;
     while idx_min <= idx_max {
         idx = (idx_max + idx_min) / 2 as i32;
         idx1 = unicode_comp_table[idx as usize] as u32;
@@ -28252,7 +992,7 @@ unsafe fn unicode_compose_pair(mut c0: u32, mut c1: u32) -> i32 {
     return 0 as i32;
 }
 /* return the combining class of character c (between 0 and 255) */
-unsafe fn unicode_get_cc(mut c: u32) -> i32 {
+pub(crate) unsafe fn unicode_get_cc(mut c: u32) -> i32 {
     let mut code: u32 = 0;
     let mut n: u32 = 0;
     let mut type_0: u32 = 0;
@@ -28265,7 +1005,7 @@ unsafe fn unicode_get_cc(mut c: u32) -> i32 {
         &mut code,
         c,
         unicode_cc_index.as_ptr(),
-        (::std::mem::size_of::<[u8; 81]>() as u64).wrapping_div(3 as i32 as u64) as i32,
+        (unicode_cc_index.len() / 3) as i32,
     );
     if pos < 0 as i32 {
         return 0 as i32;
@@ -28396,7 +1136,7 @@ unsafe fn to_nfd_rec(
     }
 }
 /* return 0 if not found */
-unsafe fn compose_pair(mut c0: u32, mut c1: u32) -> i32 {
+pub(crate) unsafe fn compose_pair(mut c0: u32, mut c1: u32) -> i32 {
     /* Hangul composition */
     if c0 >= 0x1100 as i32 as u32
         && c0 < (0x1100 as i32 + 19 as i32) as u32
@@ -28418,7 +1158,7 @@ unsafe fn compose_pair(mut c0: u32, mut c1: u32) -> i32 {
             .wrapping_sub(0xac00 as i32 as u32)
             .wrapping_rem(28 as i32 as u32)
             == 0 as i32 as u32
-        && c1 >= 0x11a7 as i32 as u32
+        && c1 > 0x11a7 as i32 as u32
         && c1 < (0x11a7 as i32 + 28 as i32) as u32
     {
         return c0.wrapping_add(c1).wrapping_sub(0x11a7 as i32 as u32) as i32;
@@ -28558,8 +1298,182 @@ pub unsafe fn unicode_normalize(
     *pdst = 0 as *mut u32;
     return -(1 as i32);
 }
+/* same as unicode_normalize() with Rust buffers. Return None if the
+allocation of the result failed. */
+pub(crate) fn unicode_normalize_vec(
+    src: &[u32],
+    n_type: UnicodeNormalizationEnum,
+) -> Option<Vec<u32>> {
+    if src.is_empty() {
+        return Some(Vec::new());
+    }
+    unsafe {
+        let mut dst: *mut u32 = std::ptr::null_mut();
+        let len = unicode_normalize(
+            &mut dst,
+            src.as_ptr(),
+            src.len() as i32,
+            n_type,
+            std::ptr::null_mut(),
+            Some(sized_realloc),
+        );
+        if len < 0 {
+            return None;
+        }
+        let res = std::slice::from_raw_parts(dst, len as usize).to_vec();
+        sized_realloc(std::ptr::null_mut(), dst as *mut std::ffi::c_void, 0);
+        Some(res)
+    }
+}
+
+/* append the full decomposition of 'c' to 'dst' (same as
+to_nfd_rec() for one character, without reordering) */
+pub(crate) fn unicode_decompose_vec(dst: &mut Vec<u32>, c: u32, is_compat: bool) {
+    if (0xac00..0xd7a4).contains(&c) {
+        /* Hangul decomposition */
+        let c = c - 0xac00;
+        dst.push(0x1100 + c / 588);
+        dst.push(0x1161 + (c % 588) / 28);
+        if c % 28 != 0 {
+            dst.push(0x11a7 + c % 28);
+        }
+        return;
+    }
+    /* UNICODE_DECOMP_LEN_MAX = 18 */
+    let mut res: [u32; 18] = [0; 18];
+    let l = unsafe { unicode_decomp_char(res.as_mut_ptr(), c, is_compat as BOOL) };
+    if l == 0 {
+        dst.push(c);
+    } else {
+        for &c1 in &res[..l as usize] {
+            unicode_decompose_vec(dst, c1, is_compat);
+        }
+    }
+}
+
+/* return the General_Category property of 'c' */
+pub(crate) fn unicode_get_gc(c: u32) -> UnicodeGCEnum {
+    let mut code: u32 = 0;
+    let pos = unsafe {
+        get_index_pos(
+            &mut code,
+            c,
+            unicode_gc_index.as_ptr(),
+            (unicode_gc_index.len() / 3) as i32,
+        )
+    };
+    if pos < 0 {
+        return UNICODE_GC_Cn;
+    }
+    let p = &unicode_gc_table;
+    let mut pos = pos as usize;
+    loop {
+        let b = p[pos] as u32;
+        pos += 1;
+        let mut n = b >> 5;
+        let v = b & 0x1f;
+        if n == 7 {
+            n = p[pos] as u32;
+            pos += 1;
+            if n < 128 {
+                n += 7;
+            } else if n < 128 + 64 {
+                n = (n - 128) << 8 | p[pos] as u32;
+                pos += 1;
+                n += 7 + 128;
+            } else {
+                n = (n - 128 - 64) << 16 | (p[pos] as u32) << 8 | p[pos + 1] as u32;
+                pos += 2;
+                n += 7 + 128 + (1 << 14);
+            }
+        }
+        let c0 = code;
+        code += n + 1;
+        if c < code {
+            /* alternating Lu/Ll run */
+            if v == 31 {
+                return UNICODE_GC_Lu + ((c - c0) & 1);
+            }
+            return v;
+        }
+    }
+}
+
+/* return the Script property of 'c' */
+pub(crate) fn unicode_get_script(c: u32) -> UnicodeScriptEnum {
+    let p = &unicode_script_table;
+    let mut pos = 0;
+    let mut code: u32 = 0;
+    while pos < p.len() {
+        let b = p[pos] as u32;
+        pos += 1;
+        let mut n = b & 0x7f;
+        if n >= 112 {
+            n = ((n - 112) << 16 | (p[pos] as u32) << 8 | p[pos + 1] as u32) + 96 + (1 << 12);
+            pos += 2;
+        } else if n >= 96 {
+            n = ((n - 96) << 8 | p[pos] as u32) + 96;
+            pos += 1;
+        }
+        let mut v = UNICODE_SCRIPT_Unknown;
+        if b >> 7 != 0 {
+            v = p[pos] as u32;
+            pos += 1;
+        }
+        code += n + 1;
+        if c < code {
+            return v;
+        }
+    }
+    UNICODE_SCRIPT_Unknown
+}
+
+/* return the Script_Extensions property of 'c' as a list of scripts,
+or an empty list if it is the Script property */
+pub(crate) fn unicode_get_script_ext(c: u32) -> &'static [u8] {
+    let p = &unicode_script_ext_table;
+    let mut pos = 0;
+    let mut code: u32 = 0;
+    while pos < p.len() {
+        let b = p[pos] as u32;
+        pos += 1;
+        let n = if b < 128 {
+            b
+        } else if b < 128 + 64 {
+            pos += 1;
+            ((b - 128) << 8 | p[pos - 1] as u32) + 128
+        } else {
+            pos += 2;
+            ((b - 128 - 64) << 16 | (p[pos - 2] as u32) << 8 | p[pos - 1] as u32) + 128 + (1 << 14)
+        };
+        let v_len = p[pos] as usize;
+        pos += 1;
+        code += n + 1;
+        if c < code {
+            return &p[pos..pos + v_len];
+        }
+        pos += v_len;
+    }
+    &[]
+}
+
+/* return the names of 'script' separated by commas (long name
+first), or None if it is Unknown or out of range */
+pub(crate) fn unicode_get_script_name(script: UnicodeScriptEnum) -> Option<&'static str> {
+    if script == UNICODE_SCRIPT_Unknown {
+        return None;
+    }
+    let names = unicode_script_name_table
+        .split(|&b| b == 0)
+        .nth((script - UNICODE_SCRIPT_Unknown - 1) as usize)?;
+    if names.is_empty() {
+        return None;
+    }
+    std::str::from_utf8(names).ok()
+}
+
 /* char ranges for various unicode properties */
-unsafe fn unicode_find_name(
+pub(crate) unsafe fn unicode_find_name(
     mut name_table: *const std::os::raw::c_char,
     mut name: *const std::os::raw::c_char,
 ) -> i32 {
@@ -28629,7 +1543,10 @@ pub unsafe fn unicode_script(
     };
     let mut cr2: *mut CharRange = &mut cr2_s;
     let mut is_common: BOOL = 0;
-    script_idx = unicode_find_name(unicode_script_name_table.as_ptr(), script_name);
+    script_idx = unicode_find_name(
+        unicode_script_name_table.as_ptr() as *const std::os::raw::c_char,
+        script_name,
+    );
     if script_idx < 0 as i32 {
         return -(2 as i32);
     }
@@ -28645,10 +1562,9 @@ pub unsafe fn unicode_script(
         cr1 = cr
     }
     p = unicode_script_table.as_ptr();
-    p_end = unicode_script_table.as_ptr().offset(
-        (::std::mem::size_of::<[u8; 2609]>() as u64)
-            .wrapping_div(::std::mem::size_of::<u8>() as u64) as isize,
-    );
+    p_end = unicode_script_table
+        .as_ptr()
+        .offset(unicode_script_table.len() as isize);
     c = 0 as i32 as u32;
     loop {
         if !(p < p_end) {
@@ -28700,11 +1616,9 @@ pub unsafe fn unicode_script(
             if is_ext != 0 {
                 /* add the script extensions */
                 p = unicode_script_ext_table.as_ptr();
-                p_end = unicode_script_ext_table.as_ptr().offset(
-                    (::std::mem::size_of::<[u8; 799]>() as u64)
-                        .wrapping_div(::std::mem::size_of::<u8>() as u64)
-                        as isize,
-                );
+                p_end = unicode_script_ext_table
+                    .as_ptr()
+                    .offset(unicode_script_ext_table.len() as isize);
                 c = 0 as i32 as u32;
                 's_208: loop {
                     if !(p < p_end) {
@@ -28834,10 +1748,9 @@ unsafe fn unicode_general_category1(mut cr: *mut CharRange, mut gc_mask: u32) ->
     let mut n: u32 = 0;
     let mut v: u32 = 0;
     p = unicode_gc_table.as_ptr();
-    p_end = unicode_gc_table.as_ptr().offset(
-        (::std::mem::size_of::<[u8; 3790]>() as u64)
-            .wrapping_div(::std::mem::size_of::<u8>() as u64) as isize,
-    );
+    p_end = unicode_gc_table
+        .as_ptr()
+        .offset(unicode_gc_table.len() as isize);
     c = 0 as i32 as u32;
     while p < p_end {
         let fresh25 = p;
@@ -28921,8 +1834,8 @@ unsafe fn unicode_prop1(mut cr: *mut CharRange, mut prop_idx: i32) -> i32 {
     let mut c0: u32 = 0;
     let mut b: u32 = 0;
     let mut bit: u32 = 0;
-    p = unicode_prop_table[prop_idx as usize];
-    p_end = p.offset(unicode_prop_len_table[prop_idx as usize] as i32 as isize);
+    p = unicode_prop_table[prop_idx as usize].as_ptr();
+    p_end = p.offset(unicode_prop_table[prop_idx as usize].len() as isize);
     c = 0 as i32 as u32;
     bit = 0 as i32 as u32;
     while p < p_end {
@@ -29026,10 +1939,7 @@ unsafe fn unicode_case1(mut cr: *mut CharRange, mut case_mask: i32) -> i32 {
         i = i.wrapping_add(1)
     }
     idx = 0 as i32 as u32;
-    while (idx as u64)
-        < (::std::mem::size_of::<[u32; 361]>() as u64)
-            .wrapping_div(::std::mem::size_of::<u32>() as u64)
-    {
+    while (idx as usize) < case_conv_table1.len() {
         v = case_conv_table1[idx as usize];
         type_0 = v >> 32 as i32 - 17 as i32 - 7 as i32 - 4 as i32 & 0xf as i32 as u32;
         code = v >> 32 as i32 - 17 as i32;
@@ -29318,7 +2228,10 @@ pub unsafe fn unicode_general_category(
 ) -> i32 {
     let mut gc_idx: i32 = 0;
     let mut gc_mask: u32 = 0;
-    gc_idx = unicode_find_name(unicode_gc_name_table.as_ptr(), gc_name);
+    gc_idx = unicode_find_name(
+        unicode_gc_name_table.as_ptr() as *const std::os::raw::c_char,
+        gc_name,
+    );
     if gc_idx < 0 as i32 {
         return -(2 as i32);
     }
@@ -29337,7 +2250,10 @@ pub unsafe fn unicode_prop(
 ) -> i32 {
     let mut prop_idx: i32 = 0;
     let mut ret: i32 = 0;
-    prop_idx = unicode_find_name(unicode_prop_name_table.as_ptr(), prop_name);
+    prop_idx = unicode_find_name(
+        unicode_prop_name_table.as_ptr() as *const std::os::raw::c_char,
+        prop_name,
+    );
     if prop_idx < 0 as i32 {
         return -(2 as i32);
     }
@@ -29606,10 +2522,7 @@ pub unsafe fn unicode_prop(
             )
         }
         _ => {
-            if prop_idx as u64
-                >= (::std::mem::size_of::<[*const u8; 50]>() as u64)
-                    .wrapping_div(::std::mem::size_of::<*const u8>() as u64)
-            {
+            if prop_idx as usize >= unicode_prop_table.len() {
                 return -1;
             }
             ret = unicode_prop1(cr, prop_idx)
@@ -29618,233 +2531,32 @@ pub unsafe fn unicode_prop(
     return ret;
 }
 
-/* Note: at most 31 bits are encoded. At most UTF8_CHAR_LEN_MAX bytes
-are output. */
+/* Encode 'c' in UTF-8 (WTF-8 for the surrogates) in 'buf', which must
+have room for 4 bytes. Return the number of bytes, 0 if 'c' is above
+0x10ffff. */
 pub unsafe fn unicode_to_utf8(mut buf: *mut u8, mut c: u32) -> i32 {
-    let mut q: *mut u8 = buf;
-    if c < 0x80 as i32 as u32 {
-        let fresh2 = q;
-        q = q.offset(1);
-        *fresh2 = c as u8
-    } else {
-        if c < 0x800 as i32 as u32 {
-            let fresh3 = q;
-            q = q.offset(1);
-            *fresh3 = (c >> 6 as i32 | 0xc0 as i32 as u32) as u8
-        } else {
-            if c < 0x10000 as i32 as u32 {
-                let fresh4 = q;
-                q = q.offset(1);
-                *fresh4 = (c >> 12 as i32 | 0xe0 as i32 as u32) as u8
-            } else {
-                if c < 0x200000 as i32 as u32 {
-                    let fresh5 = q;
-                    q = q.offset(1);
-                    *fresh5 = (c >> 18 as i32 | 0xf0 as i32 as u32) as u8
-                } else {
-                    if c < 0x4000000 as i32 as u32 {
-                        let fresh6 = q;
-                        q = q.offset(1);
-                        *fresh6 = (c >> 24 as i32 | 0xf8 as i32 as u32) as u8
-                    } else if c < 0x80000000 as u32 {
-                        let fresh7 = q;
-                        q = q.offset(1);
-                        *fresh7 = (c >> 30 as i32 | 0xfc as i32 as u32) as u8;
-                        let fresh8 = q;
-                        q = q.offset(1);
-                        *fresh8 = (c >> 24 as i32 & 0x3f as i32 as u32 | 0x80 as i32 as u32) as u8
-                    } else {
-                        return 0 as i32;
-                    }
-                    let fresh9 = q;
-                    q = q.offset(1);
-                    *fresh9 = (c >> 18 as i32 & 0x3f as i32 as u32 | 0x80 as i32 as u32) as u8
-                }
-                let fresh10 = q;
-                q = q.offset(1);
-                *fresh10 = (c >> 12 as i32 & 0x3f as i32 as u32 | 0x80 as i32 as u32) as u8
-            }
-            let fresh11 = q;
-            q = q.offset(1);
-            *fresh11 = (c >> 6 as i32 & 0x3f as i32 as u32 | 0x80 as i32 as u32) as u8
-        }
-        let fresh12 = q;
-        q = q.offset(1);
-        *fresh12 = (c & 0x3f as i32 as u32 | 0x80 as i32 as u32) as u8
-    }
-    return (q as *const u8).wrapping_offset_from(buf) as i64 as i32;
+    let buf = std::slice::from_raw_parts_mut(buf, 4);
+    return encode_wtf8_char(buf, c) as i32;
 }
 
-static mut utf8_min_code: [u32; 5] = [
-    0x80 as i32 as u32,
-    0x800 as i32 as u32,
-    0x10000 as i32 as u32,
-    0x200000 as i32 as u32,
-    0x4000000 as i32 as u32,
-];
-static mut utf8_first_code_mask: [std::os::raw::c_uchar; 5] = [
-    0x1f as i32 as std::os::raw::c_uchar,
-    0xf as i32 as std::os::raw::c_uchar,
-    0x7 as i32 as std::os::raw::c_uchar,
-    0x3 as i32 as std::os::raw::c_uchar,
-    0x1 as i32 as std::os::raw::c_uchar,
-];
-
 /* return -1 if error. *pp is not updated in this case. max_len must
-be >= 1. The maximum length for a UTF8 byte sequence is 6 bytes. */
+be >= 1. The bytes after a NUL byte are not read. */
 pub unsafe fn unicode_from_utf8(mut p: *const u8, mut max_len: i32, mut pp: *mut *const u8) -> i32 {
-    let mut l: i32 = 0;
-    let mut c: i32 = 0;
-    let mut b: i32 = 0;
-    let mut i: i32 = 0;
-    let fresh13 = p;
-    p = p.offset(1);
-    c = *fresh13 as i32;
-    if c < 0x80 as i32 {
-        *pp = p;
-        return c;
-    }
-    match c {
-        192 | 193 | 194 | 195 | 196 | 197 | 198 | 199 | 200 | 201 | 202 | 203 | 204 | 205 | 206
-        | 207 | 208 | 209 | 210 | 211 | 212 | 213 | 214 | 215 | 216 | 217 | 218 | 219 | 220
-        | 221 | 222 | 223 => l = 1 as i32,
-        224 | 225 | 226 | 227 | 228 | 229 | 230 | 231 | 232 | 233 | 234 | 235 | 236 | 237 | 238
-        | 239 => l = 2 as i32,
-        240 | 241 | 242 | 243 | 244 | 245 | 246 | 247 => l = 3 as i32,
-        248 | 249 | 250 | 251 => l = 4 as i32,
-        252 | 253 => l = 5 as i32,
-        _ => return -(1 as i32),
-    }
-    /* check that we have enough characters */
-    if l > max_len - 1 as i32 {
-        return -(1 as i32);
-    }
-    c &= utf8_first_code_mask[(l - 1 as i32) as usize] as i32;
-    i = 0 as i32;
-    while i < l {
-        let fresh14 = p;
-        p = p.offset(1);
-        b = *fresh14 as i32;
-        if b < 0x80 as i32 || b >= 0xc0 as i32 {
-            return -(1 as i32);
+    let mut buf: [u8; 4] = [0; 4];
+    let mut len: usize = 0;
+    while len < (max_len as usize).min(4) {
+        buf[len] = *p.add(len);
+        len += 1;
+        if buf[len - 1] == 0 {
+            break;
         }
-        c = c << 6 as i32 | b & 0x3f as i32;
-        i += 1
     }
-    if (c as u32) < utf8_min_code[(l - 1 as i32) as usize] {
-        return -(1 as i32);
+    match decode_wtf8_char(&buf[..len]) {
+        Some((c, len)) => {
+            *pp = p.add(len);
+            return c as i32;
+        }
+        None => return -1,
     }
-    *pp = p;
-    return c;
-}
-
-unsafe fn run_static_initializers() {
-    unicode_prop_len_table = [
-        (::std::mem::size_of::<[u8; 28]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 200]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 411]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 51]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 15]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 65]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 32]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 11]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 12]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 17]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 626]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 31]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 23]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 22]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 33]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 441]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 5]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 10]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 53]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 23]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 358]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 89]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 12]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 5]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 4]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 66]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 4]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 15]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 71]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 58]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 11]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 31]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 9]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 4]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 188]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 71]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 241]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 42]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 12]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 22]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 171]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 238]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 28]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 4]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 66]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 144]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 156]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 51]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-        (::std::mem::size_of::<[u8; 1045]>() as u64)
-            .wrapping_div(::std::mem::size_of::<u8>() as u64) as u16,
-        (::std::mem::size_of::<[u8; 692]>() as u64).wrapping_div(::std::mem::size_of::<u8>() as u64)
-            as u16,
-    ]
 }
-#[used]
-#[cfg_attr(target_os = "linux", link_section = ".init_array")]
-#[cfg_attr(target_os = "windows", link_section = ".CRT$XIB")]
-#[cfg_attr(target_os = "macos", link_section = "__DATA,__mod_init_func")]
-static INIT_ARRAY: [unsafe fn(); 1] = [run_static_initializers];
 /* CONFIG_ALL_UNICODE */
@@ -0,0 +1,628 @@
+/*
+ * Dense matrices
+ *
+ * Matrix is a dense matrix with BigRational or BigFloat entries stored
+ * by rows, as the Matrix objects of qjscalc. The operations on
+ * rational matrices are exact: the products and the determinants are
+ * computed on integer matrices (with the fraction free elimination of
+ * Bareiss for the determinants) to avoid the normalization of the
+ * intermediate fractions. The operations on BigFloat matrices use the
+ * operations of a BigFloatEnv. Their products are dot products
+ * computed with guard bits and rounded once, and their LU
+ * decompositions use partial pivoting.
+ */
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::bigfloat::{BigFloat, BigFloatEnv};
+use crate::bigint::BigInt;
+use crate::bigrational::BigRational;
+use crate::polynomial::Scalar;
+
+/// Dense matrix.
+#[derive(Clone, PartialEq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T: Scalar> Matrix<T> {
+    pub fn zero(rows: usize, cols: usize) -> Matrix<T> {
+        Matrix {
+            rows,
+            cols,
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// Return the identity matrix of size `n`.
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut r = Matrix::zero(n, n);
+        for i in 0..n {
+            r[(i, i)] = T::one();
+        }
+        r
+    }
+
+    /// Return the matrix with the entries `data` stored by rows. Panics
+    /// if `data` does not have `rows * cols` entries.
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Matrix<T> {
+        assert!(data.len() == rows * cols, "invalid matrix size");
+        Matrix { rows, cols, data }
+    }
+
+    /// Return the matrix with the rows `rows`. Panics if the rows do not
+    /// have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        assert!(rows.iter().all(|r| r.len() == cols), "invalid matrix size");
+        Matrix {
+            rows: rows.len(),
+            cols,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    /// Return the entries stored by rows.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.cols {
+            for i in 0..self.rows {
+                data.push(self[(i, j)].clone());
+            }
+        }
+        Matrix::from_vec(self.cols, self.rows, data)
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        for k in 0..self.cols {
+            self.data.swap(i * self.cols + k, j * self.cols + k);
+        }
+    }
+
+    fn check_square(&self) -> usize {
+        assert!(self.is_square(), "matrix is not square");
+        self.rows
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols, "index out of bounds");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+/// LU decomposition with row pivoting of a square matrix A: the rows of
+/// A taken in the order of `perm()` are the product of the lower
+/// triangular matrix `l()`, which has a unit diagonal, and the upper
+/// triangular matrix `u()`.
+#[derive(Clone)]
+pub struct Lu<T> {
+    lu: Matrix<T>,
+    perm: Vec<usize>,
+    odd: bool,
+}
+
+impl<T: Scalar> Lu<T> {
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.lu.rows;
+        let mut r = Matrix::identity(n);
+        for i in 0..n {
+            for j in 0..i {
+                r[(i, j)] = self.lu[(i, j)].clone();
+            }
+        }
+        r
+    }
+
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.lu.rows;
+        let mut r = Matrix::zero(n, n);
+        for i in 0..n {
+            for j in i..n {
+                r[(i, j)] = self.lu[(i, j)].clone();
+            }
+        }
+        r
+    }
+
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+}
+
+/* return the rows as integers with their common denominators */
+fn row_integers(a: &Matrix<BigRational>) -> (Vec<BigInt>, Vec<BigInt>) {
+    let mut data = Vec::with_capacity(a.data.len());
+    let mut dens = Vec::with_capacity(a.rows);
+    for i in 0..a.rows {
+        let row = a.row(i);
+        let den = row.iter().fold(BigInt::one(), |d, c| d.lcm(c.denom()));
+        data.extend(row.iter().map(|c| c.numer() * (&den / c.denom())));
+        dens.push(den);
+    }
+    (data, dens)
+}
+
+/* fraction free elimination of Bareiss on the first 'n' columns of the
+n x cols integer matrix 'm'. After step k, the entries m[i][j] for i,
+j > k are determinants of (k + 2) x (k + 2) minors, so the divisions
+by the previous pivot are exact. Return None if the first 'n' columns
+are singular, otherwise whether an odd number of rows were swapped.
+The last pivot is then the determinant of the first 'n' columns up to
+the sign. */
+fn bareiss(m: &mut [BigInt], n: usize, cols: usize) -> Option<bool> {
+    let mut odd = false;
+    let mut prev = BigInt::one();
+    for k in 0..n {
+        if m[k * cols + k].is_zero() {
+            let p = (k + 1..n).find(|&i| !m[i * cols + k].is_zero())?;
+            for j in k..cols {
+                m.swap(k * cols + j, p * cols + j);
+            }
+            odd = !odd;
+        }
+        for i in k + 1..n {
+            for j in k + 1..cols {
+                let t = &m[i * cols + j] * &m[k * cols + k] - &m[i * cols + k] * &m[k * cols + j];
+                m[i * cols + j] = &t / &prev;
+            }
+            m[i * cols + k] = BigInt::zero();
+        }
+        prev = m[k * cols + k].clone();
+    }
+    Some(odd)
+}
+
+impl Matrix<BigRational> {
+    /// Return the determinant. Panics if the matrix is not square.
+    pub fn det(&self) -> BigRational {
+        let n = self.check_square();
+        if n == 0 {
+            return BigRational::one();
+        }
+        let (mut m, dens) = row_integers(self);
+        let den = dens.iter().fold(BigInt::one(), |d, c| d * c);
+        match bareiss(&mut m, n, n) {
+            Some(odd) => {
+                let d = m.pop().unwrap();
+                BigRational::new(if odd { -d } else { d }, den)
+            }
+            None => BigRational::zero(),
+        }
+    }
+
+    /// Return the LU decomposition, or None if the matrix is singular.
+    /// Panics if the matrix is not square.
+    pub fn lu(&self) -> Option<Lu<BigRational>> {
+        let n = self.check_square();
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+        for i in 0..n {
+            let p = (i..n).find(|&j| !lu[(j, i)].is_zero())?;
+            if p != i {
+                lu.swap_rows(i, p);
+                perm.swap(i, p);
+                odd = !odd;
+            }
+            let inv = lu[(i, i)].recip();
+            for j in i + 1..n {
+                if lu[(j, i)].is_zero() {
+                    continue;
+                }
+                let f = &lu[(j, i)] * &inv;
+                for k in i + 1..n {
+                    let t = &lu[(i, k)] * &f;
+                    lu[(j, k)] -= t;
+                }
+                lu[(j, i)] = f;
+            }
+        }
+        Some(Lu { lu, perm, odd })
+    }
+
+    /// Return the solution X of `self * X = b`, or None if the matrix
+    /// is singular. Panics if the matrix is not square or if `b` does
+    /// not have the same number of rows.
+    pub fn solve(&self, b: &Matrix<BigRational>) -> Option<Matrix<BigRational>> {
+        let n = self.check_square();
+        assert!(b.rows == n, "incompatible matrix dimensions");
+        let cols = n + b.cols;
+        let mut data = Vec::with_capacity(n * cols);
+        for i in 0..n {
+            data.extend_from_slice(self.row(i));
+            data.extend_from_slice(b.row(i));
+        }
+        /* the rows of the augmented matrix are scaled to integers and
+        triangularized. With d the last pivot, the entries of d * X are
+        integers (Cramer's rule), so the back substitution is done on
+        integers with exact divisions. */
+        let (mut m, _) = row_integers(&Matrix::from_vec(n, cols, data));
+        bareiss(&mut m, n, cols)?;
+        let mut x = Matrix::zero(n, b.cols);
+        if n == 0 {
+            return Some(x);
+        }
+        let d = m[(n - 1) * cols + n - 1].clone();
+        let mut y = vec![BigInt::zero(); n];
+        for c in 0..b.cols {
+            for i in (0..n).rev() {
+                let mut s = &d * &m[i * cols + n + c];
+                for j in i + 1..n {
+                    s -= &m[i * cols + j] * &y[j];
+                }
+                y[i] = &s / &m[i * cols + i];
+            }
+            for i in 0..n {
+                x[(i, c)] = BigRational::new(y[i].clone(), d.clone());
+            }
+        }
+        Some(x)
+    }
+
+    /// Return the inverse, or None if the matrix is singular. Panics if
+    /// the matrix is not square.
+    pub fn inverse(&self) -> Option<Matrix<BigRational>> {
+        self.solve(&Matrix::identity(self.rows))
+    }
+
+    /// Round the entries with `env`.
+    pub fn to_bigfloat(&self, env: &mut BigFloatEnv) -> Matrix<BigFloat> {
+        Matrix::from_vec(
+            self.rows,
+            self.cols,
+            self.data.iter().map(|c| c.to_bigfloat(env)).collect(),
+        )
+    }
+}
+
+impl Lu<BigRational> {
+    /// Return the solution X of `A * X = b`. Panics if `b` does not have
+    /// the same number of rows as A.
+    pub fn solve(&self, b: &Matrix<BigRational>) -> Matrix<BigRational> {
+        let n = self.lu.rows;
+        assert!(b.rows == n, "incompatible matrix dimensions");
+        let mut x = Matrix::zero(n, b.cols);
+        for c in 0..b.cols {
+            /* L * y = P * b */
+            for i in 0..n {
+                let mut s = b[(self.perm[i], c)].clone();
+                for j in 0..i {
+                    s -= &self.lu[(i, j)] * &x[(j, c)];
+                }
+                x[(i, c)] = s;
+            }
+            /* U * x = y */
+            for i in (0..n).rev() {
+                let mut s = x[(i, c)].clone();
+                for j in i + 1..n {
+                    s -= &self.lu[(i, j)] * &x[(j, c)];
+                }
+                x[(i, c)] = s / &self.lu[(i, i)];
+            }
+        }
+        x
+    }
+
+    pub fn det(&self) -> BigRational {
+        let d = (0..self.lu.rows).fold(BigRational::one(), |d, i| d * &self.lu[(i, i)]);
+        if self.odd {
+            -d
+        } else {
+            d
+        }
+    }
+}
+
+impl Matrix<BigFloat> {
+    /// Return the sum with each entry rounded with `env`. Panics if the
+    /// dimensions are different.
+    pub fn add(&self, b: &Matrix<BigFloat>, env: &mut BigFloatEnv) -> Matrix<BigFloat> {
+        assert!(
+            self.rows == b.rows && self.cols == b.cols,
+            "incompatible matrix dimensions"
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(&b.data)
+            .map(|(x, y)| env.add(x, y))
+            .collect();
+        Matrix::from_vec(self.rows, self.cols, data)
+    }
+
+    pub fn sub(&self, b: &Matrix<BigFloat>, env: &mut BigFloatEnv) -> Matrix<BigFloat> {
+        assert!(
+            self.rows == b.rows && self.cols == b.cols,
+            "incompatible matrix dimensions"
+        );
+        let data = self
+            .data
+            .iter()
+            .zip(&b.data)
+            .map(|(x, y)| env.sub(x, y))
+            .collect();
+        Matrix::from_vec(self.rows, self.cols, data)
+    }
+
+    /// Return the product with each entry rounded with `env`. The dot
+    /// products are computed with 64 guard bits and rounded once.
+    /// Panics if the number of columns of `self` is not the number of
+    /// rows of `b`.
+    pub fn mul(&self, b: &Matrix<BigFloat>, env: &mut BigFloatEnv) -> Matrix<BigFloat> {
+        assert!(self.cols == b.rows, "incompatible matrix dimensions");
+        let mut wenv = BigFloatEnv::new(
+            (env.prec() + 64 + 64 - (self.cols as u64).leading_zeros() as u64)
+                .min(BigFloatEnv::PREC_MAX),
+        );
+        let mut r = Matrix::zero(self.rows, b.cols);
+        for i in 0..self.rows {
+            for j in 0..b.cols {
+                let mut s = BigFloat::zero();
+                for k in 0..self.cols {
+                    let t = wenv.mul(&self[(i, k)], &b[(k, j)]);
+                    s = wenv.add(&s, &t);
+                }
+                r[(i, j)] = env.round(&s);
+            }
+        }
+        r
+    }
+
+    /// Return the LU decomposition computed with the operations of
+    /// `env`, or None if a pivot is zero. Panics if the matrix is not
+    /// square.
+    pub fn lu(&self, env: &mut BigFloatEnv) -> Option<Lu<BigFloat>> {
+        let n = self.check_square();
+        let mut lu = self.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+        for i in 0..n {
+            /* the pivot is the entry of largest absolute value */
+            let mut p = i;
+            for j in i + 1..n {
+                if lu[(j, i)].abs() > lu[(p, i)].abs() {
+                    p = j;
+                }
+            }
+            if lu[(p, i)].is_zero() || lu[(p, i)].is_nan() {
+                return None;
+            }
+            if p != i {
+                lu.swap_rows(i, p);
+                perm.swap(i, p);
+                odd = !odd;
+            }
+            for j in i + 1..n {
+                let f = env.div(&lu[(j, i)], &lu[(i, i)]);
+                for k in i + 1..n {
+                    let t = env.mul(&lu[(i, k)], &f);
+                    lu[(j, k)] = env.sub(&lu[(j, k)], &t);
+                }
+                lu[(j, i)] = f;
+            }
+        }
+        Some(Lu { lu, perm, odd })
+    }
+
+    /// Return the determinant computed with the LU decomposition.
+    /// Panics if the matrix is not square.
+    pub fn det(&self, env: &mut BigFloatEnv) -> BigFloat {
+        match self.lu(env) {
+            Some(lu) => lu.det(env),
+            None => BigFloat::zero(),
+        }
+    }
+
+    /// Return the solution X of `self * X = b`, or None if the LU
+    /// decomposition fails. Panics if the matrix is not square or if
+    /// `b` does not have the same number of rows.
+    pub fn solve(&self, b: &Matrix<BigFloat>, env: &mut BigFloatEnv) -> Option<Matrix<BigFloat>> {
+        Some(self.lu(env)?.solve(b, env))
+    }
+
+    /// Return the inverse, or None if the LU decomposition fails.
+    /// Panics if the matrix is not square.
+    pub fn inverse(&self, env: &mut BigFloatEnv) -> Option<Matrix<BigFloat>> {
+        self.solve(&Matrix::identity(self.rows), env)
+    }
+}
+
+impl Lu<BigFloat> {
+    /// Return the solution X of `A * X = b` computed with the
+    /// operations of `env`. Panics if `b` does not have the same number
+    /// of rows as A.
+    pub fn solve(&self, b: &Matrix<BigFloat>, env: &mut BigFloatEnv) -> Matrix<BigFloat> {
+        let n = self.lu.rows;
+        assert!(b.rows == n, "incompatible matrix dimensions");
+        let mut x = Matrix::zero(n, b.cols);
+        for c in 0..b.cols {
+            for i in 0..n {
+                let mut s = b[(self.perm[i], c)].clone();
+                for j in 0..i {
+                    let t = env.mul(&self.lu[(i, j)], &x[(j, c)]);
+                    s = env.sub(&s, &t);
+                }
+                x[(i, c)] = s;
+            }
+            for i in (0..n).rev() {
+                let mut s = x[(i, c)].clone();
+                for j in i + 1..n {
+                    let t = env.mul(&self.lu[(i, j)], &x[(j, c)]);
+                    s = env.sub(&s, &t);
+                }
+                x[(i, c)] = env.div(&s, &self.lu[(i, i)]);
+            }
+        }
+        x
+    }
+
+    pub fn det(&self, env: &mut BigFloatEnv) -> BigFloat {
+        let mut d = BigFloat::from(1);
+        for i in 0..self.lu.rows {
+            d = env.mul(&d, &self.lu[(i, i)]);
+        }
+        if self.odd {
+            -d
+        } else {
+            d
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    /// Written as in qjscalc, e.g. "[[1, 2], [3, 4]]".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for i in 0..self.rows {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str("[")?;
+            for j in 0..self.cols {
+                if j != 0 {
+                    f.write_str(", ")?;
+                }
+                fmt::Display::fmt(&self.data[i * self.cols + j], f)?;
+            }
+            f.write_str("]")?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl<T: fmt::Display> fmt::Debug for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for Matrix<BigRational> {
+    type Output = Matrix<BigRational>;
+
+    fn neg(self) -> Matrix<BigRational> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Neg for &Matrix<BigRational> {
+    type Output = Matrix<BigRational>;
+
+    fn neg(self) -> Matrix<BigRational> {
+        -self.clone()
+    }
+}
+
+fn add(a: &Matrix<BigRational>, b: &Matrix<BigRational>) -> Matrix<BigRational> {
+    assert!(
+        a.rows == b.rows && a.cols == b.cols,
+        "incompatible matrix dimensions"
+    );
+    let data = a.data.iter().zip(&b.data).map(|(x, y)| x + y).collect();
+    Matrix::from_vec(a.rows, a.cols, data)
+}
+
+fn sub(a: &Matrix<BigRational>, b: &Matrix<BigRational>) -> Matrix<BigRational> {
+    assert!(
+        a.rows == b.rows && a.cols == b.cols,
+        "incompatible matrix dimensions"
+    );
+    let data = a.data.iter().zip(&b.data).map(|(x, y)| x - y).collect();
+    Matrix::from_vec(a.rows, a.cols, data)
+}
+
+/* the rows of 'a' and the columns of 'b' are converted to integers, so
+that the dot products are computed on integers */
+fn mul(a: &Matrix<BigRational>, b: &Matrix<BigRational>) -> Matrix<BigRational> {
+    assert!(a.cols == b.rows, "incompatible matrix dimensions");
+    let (a1, da) = row_integers(a);
+    let (b1, db) = row_integers(&b.transpose());
+    let n = a.cols;
+    let mut data = Vec::with_capacity(a.rows * b.cols);
+    for i in 0..a.rows {
+        for j in 0..b.cols {
+            let mut s = BigInt::zero();
+            for k in 0..n {
+                s += &a1[i * n + k] * &b1[j * n + k];
+            }
+            data.push(BigRational::new(s, &da[i] * &db[j]));
+        }
+    }
+    Matrix::from_vec(a.rows, b.cols, data)
+}
+
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident) => {
+        impl $Op<&Matrix<BigRational>> for &Matrix<BigRational> {
+            type Output = Matrix<BigRational>;
+
+            fn $op(self, rhs: &Matrix<BigRational>) -> Matrix<BigRational> {
+                $op(self, rhs)
+            }
+        }
+
+        impl $Op<Matrix<BigRational>> for &Matrix<BigRational> {
+            type Output = Matrix<BigRational>;
+
+            fn $op(self, rhs: Matrix<BigRational>) -> Matrix<BigRational> {
+                $op(self, &rhs)
+            }
+        }
+
+        impl $Op<&Matrix<BigRational>> for Matrix<BigRational> {
+            type Output = Matrix<BigRational>;
+
+            fn $op(self, rhs: &Matrix<BigRational>) -> Matrix<BigRational> {
+                $op(&self, rhs)
+            }
+        }
+
+        impl $Op<Matrix<BigRational>> for Matrix<BigRational> {
+            type Output = Matrix<BigRational>;
+
+            fn $op(self, rhs: Matrix<BigRational>) -> Matrix<BigRational> {
+                $op(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add);
+impl_binary_op!(Sub, sub);
+impl_binary_op!(Mul, mul);
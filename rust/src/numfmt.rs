@@ -0,0 +1,521 @@
+/*
+ * Locale independent number formatting
+ *
+ * NumberFormat formats BigFloat and BigDecimal numbers in any radix
+ * from 2 to 36 with the options of the numeric core of
+ * Intl.NumberFormat: digit grouping, custom separators, minimum and
+ * maximum fraction or significant digits, scientific and engineering
+ * notations. The digits are computed by bf_ftoa() or bfdec_ftoa()
+ * with the rounding mode of the format, so they are correctly
+ * rounded; only their layout is done here. The exponent is a power of
+ * the radix written in decimal, as with the '@' marker of libbf.
+ *
+ * NumberFormat::parse() reads back the strings produced with the same
+ * options.
+ */
+use crate::bigdecimal::{BigDecimal, BigDecimalEnv, ParseBigDecimalError};
+use crate::bigfloat::{BigFloat, BigFloatEnv, ParseBigFloatError, Rounding};
+use crate::libbf::{
+    bf_flags_t, limb_t, BF_FTOA_FORCE_EXP, BF_FTOA_FORMAT_FIXED, BF_FTOA_FORMAT_FRAC,
+    BF_FTOA_FORMAT_FREE, BF_FTOA_FORMAT_FREE_MIN, BF_PREC_INF, BF_RNDN,
+};
+
+/// Notation of the formatted numbers.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Notation {
+    /// Positional notation, e.g. "1234.5".
+    Standard,
+    /// One nonzero digit before the decimal separator and an exponent,
+    /// e.g. "1.2345e3".
+    Scientific,
+    /// An exponent multiple of 3 and one to three digits before the
+    /// decimal separator, e.g. "12.345e3".
+    Engineering,
+}
+
+/// Builder of the number formatting options.
+///
+/// The significant digits take precedence over the fraction digits
+/// when both are set. When none are set, the shortest representation
+/// which parses back to the same number is written.
+#[derive(Clone, Debug)]
+pub struct NumberFormat {
+    radix: u32,
+    notation: Notation,
+    rnd: Rounding,
+    min_integer_digits: u64,
+    fraction_digits: Option<(u64, u64)>,
+    significant_digits: Option<(u64, u64)>,
+    group_separator: Option<char>,
+    group_size: usize,
+    secondary_group_size: usize,
+    decimal_separator: char,
+    exponent_marker: Option<String>,
+    plus_sign: bool,
+    uppercase: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> NumberFormat {
+        NumberFormat::new()
+    }
+}
+
+/* result of the scanning of a formatted number */
+enum Scanned {
+    NaN,
+    Infinity(bool),
+    /* number in the syntax of bf_atof() */
+    Number(String),
+}
+
+/* |a| = 0.d1d2...dn * radix^point. 'digits' has no leading or
+trailing zero and is empty if a = 0. */
+struct Digits {
+    neg: bool,
+    digits: Vec<u8>,
+    point: i64,
+}
+
+impl NumberFormat {
+    /// Return the format of the decimal numbers in standard notation
+    /// with their shortest representation, without grouping and
+    /// rounding to nearest.
+    pub fn new() -> NumberFormat {
+        NumberFormat {
+            radix: 10,
+            notation: Notation::Standard,
+            rnd: Rounding::NearestEven,
+            min_integer_digits: 1,
+            fraction_digits: None,
+            significant_digits: None,
+            group_separator: None,
+            group_size: 3,
+            secondary_group_size: 3,
+            decimal_separator: '.',
+            exponent_marker: None,
+            plus_sign: false,
+            uppercase: false,
+        }
+    }
+
+    /// Set the radix, between 2 and 36. BigDecimal numbers can only be
+    /// formatted in radix 10.
+    pub fn radix(mut self, radix: u32) -> NumberFormat {
+        assert!((2..=36).contains(&radix), "invalid radix");
+        self.radix = radix;
+        self
+    }
+
+    pub fn notation(mut self, notation: Notation) -> NumberFormat {
+        self.notation = notation;
+        self
+    }
+
+    /// Set the rounding mode of the digits.
+    pub fn rounding(mut self, rnd: Rounding) -> NumberFormat {
+        self.rnd = rnd;
+        self
+    }
+
+    /// Pad the integer part with zeros to `digits` digits in standard
+    /// notation.
+    pub fn min_integer_digits(mut self, digits: u64) -> NumberFormat {
+        self.min_integer_digits = digits;
+        self
+    }
+
+    /// Round to `max` digits after the decimal separator and write at
+    /// least `min` of them. Panics if `min` > `max`.
+    pub fn fraction_digits(mut self, min: u64, max: u64) -> NumberFormat {
+        assert!(min <= max, "invalid number of fraction digits");
+        self.fraction_digits = Some((min, max));
+        self
+    }
+
+    /// Round to `max` significant digits and write at least `min` of
+    /// them. Panics if `min` is 0 or `min` > `max`.
+    pub fn significant_digits(mut self, min: u64, max: u64) -> NumberFormat {
+        assert!(
+            min > 0 && min <= max,
+            "invalid number of significant digits"
+        );
+        self.significant_digits = Some((min, max));
+        self
+    }
+
+    /// Separate the groups of `size` digits of the integer part with
+    /// `sep`, e.g. "1,234,567" with (',', 3). The separator must not be
+    /// the decimal separator.
+    pub fn grouping(mut self, sep: char, size: usize) -> NumberFormat {
+        self.group_separator = Some(sep);
+        self.group_size = size;
+        self.secondary_group_size = size;
+        self
+    }
+
+    /// Set the size of the groups after the first one, e.g. 2 for
+    /// "12,34,567".
+    pub fn secondary_grouping(mut self, size: usize) -> NumberFormat {
+        self.secondary_group_size = size;
+        self
+    }
+
+    pub fn decimal_separator(mut self, sep: char) -> NumberFormat {
+        self.decimal_separator = sep;
+        self
+    }
+
+    /// Set the string written before the exponent. The default is "e"
+    /// up to radix 10 and "@" above, so that the marker is not a digit.
+    pub fn exponent_marker(mut self, marker: &str) -> NumberFormat {
+        self.exponent_marker = Some(marker.to_string());
+        self
+    }
+
+    /// Write a '+' before the positive numbers.
+    pub fn plus_sign(mut self, plus_sign: bool) -> NumberFormat {
+        self.plus_sign = plus_sign;
+        self
+    }
+
+    /// Write the digits above 9 in upper case.
+    pub fn uppercase(mut self, uppercase: bool) -> NumberFormat {
+        self.uppercase = uppercase;
+        self
+    }
+
+    fn marker(&self) -> &str {
+        match &self.exponent_marker {
+            Some(marker) => marker,
+            None if self.radix <= 10 => "e",
+            None => "@",
+        }
+    }
+
+    /// Format a BigFloat.
+    pub fn format(&self, a: &BigFloat) -> String {
+        let neg = a.is_sign_negative();
+        if a.is_nan() {
+            return "NaN".to_string();
+        } else if a.is_infinite() {
+            return self.format_infinity(neg);
+        }
+        let prec = a.mantissa_bits().max(BigFloatEnv::thread_default().prec());
+        let d = self.digits(
+            |prec, flags| a.ftoa(self.radix, prec, flags),
+            prec,
+            BF_FTOA_FORMAT_FREE_MIN | BF_RNDN,
+        );
+        self.layout(d)
+    }
+
+    /// Format a BigDecimal. Panics if the radix is not 10.
+    pub fn format_decimal(&self, a: &BigDecimal) -> String {
+        assert!(self.radix == 10, "invalid radix");
+        let neg = a.is_sign_negative();
+        if a.is_nan() {
+            return "NaN".to_string();
+        } else if a.is_infinite() {
+            return self.format_infinity(neg);
+        }
+        let d = self.digits(
+            |prec, flags| a.ftoa(prec, flags),
+            BF_PREC_INF,
+            BF_FTOA_FORMAT_FREE,
+        );
+        self.layout(d)
+    }
+
+    fn format_infinity(&self, neg: bool) -> String {
+        let mut s = String::new();
+        self.push_sign(&mut s, neg);
+        s.push_str("Infinity");
+        s
+    }
+
+    fn push_sign(&self, s: &mut String, neg: bool) {
+        if neg {
+            s.push('-');
+        } else if self.plus_sign {
+            s.push('+');
+        }
+    }
+
+    /* compute the rounded digits with 'ftoa'. 'free_prec' and
+    'free_flags' give the shortest representation. */
+    fn digits<F>(&self, ftoa: F, free_prec: limb_t, free_flags: bf_flags_t) -> Digits
+    where
+        F: Fn(limb_t, bf_flags_t) -> String,
+    {
+        let rnd = self.rnd as bf_flags_t;
+        let exp_flags = BF_FTOA_FORMAT_FIXED | BF_FTOA_FORCE_EXP | rnd;
+        let (mut d, min_len) = if let Some((min, max)) = self.significant_digits {
+            (self.split_exp(&ftoa(max, exp_flags)), min as i64)
+        } else if let Some((min, max)) = self.fraction_digits {
+            match self.notation {
+                Notation::Standard => {
+                    let d = split_fixed(&ftoa(max, BF_FTOA_FORMAT_FRAC | rnd));
+                    let min_len = d.point + min as i64;
+                    (d, min_len)
+                }
+                Notation::Scientific => (self.split_exp(&ftoa(max + 1, exp_flags)), 1 + min as i64),
+                Notation::Engineering => {
+                    /* the number of digits depends on the exponent after
+                    the rounding, which is larger only if a carry
+                    propagated to a new leading digit */
+                    let mut d = self.split_exp(&ftoa(max + 1, exp_flags));
+                    loop {
+                        let n = max + eng_integer_digits(d.point);
+                        let d1 = self.split_exp(&ftoa(n, exp_flags));
+                        let done = d1.point == d.point;
+                        d = d1;
+                        if done {
+                            break;
+                        }
+                    }
+                    let min_len = eng_integer_digits(d.point) as i64 + min as i64;
+                    (d, min_len)
+                }
+            }
+        } else {
+            (
+                self.split_exp(&ftoa(free_prec, free_flags | BF_FTOA_FORCE_EXP)),
+                0,
+            )
+        };
+        while (d.digits.len() as i64) < min_len {
+            d.digits.push(b'0');
+        }
+        d
+    }
+
+    /* split the output of bf_ftoa() in exponential notation:
+    "[-]d[.ddd]" followed by 'e' or '@' and the exponent of the first
+    digit, or by 'p' and the exponent in bits for the power of two
+    radixes up to 16. */
+    fn split_exp(&self, s: &str) -> Digits {
+        let radix_bits = if self.radix.is_power_of_two() && self.radix <= 16 {
+            self.radix.trailing_zeros() as i64
+        } else {
+            0
+        };
+        let marker = if radix_bits != 0 {
+            'p'
+        } else if self.radix <= 10 {
+            'e'
+        } else {
+            '@'
+        };
+        let pos = s.rfind(marker).expect("bf_ftoa: exponent expected");
+        let mut e: i64 = s[pos + 1..].parse().expect("bf_ftoa: invalid exponent");
+        if radix_bits != 0 {
+            e /= radix_bits;
+        }
+        let mut d = split_fixed(&s[..pos]);
+        if !d.digits.is_empty() {
+            /* split_fixed() removed the leading zeros */
+            d.point += e;
+        }
+        d
+    }
+
+    fn layout(&self, d: Digits) -> String {
+        let mut s = String::new();
+        self.push_sign(&mut s, d.neg);
+        let mut digits = d.digits;
+        if digits.is_empty() {
+            digits.push(b'0');
+        }
+        if self.uppercase {
+            digits.make_ascii_uppercase();
+        }
+        let n = digits.len() as i64;
+        /* number of digits before the decimal separator and exponent */
+        let (int_len, e) = match self.notation {
+            Notation::Standard => (d.point, 0),
+            Notation::Scientific => (1, d.point - 1),
+            Notation::Engineering => {
+                let int_len = eng_integer_digits(d.point) as i64;
+                (int_len, d.point - int_len)
+            }
+        };
+        let mut int_part = Vec::new();
+        if int_len > 0 {
+            int_part.extend_from_slice(&digits[..int_len.min(n) as usize]);
+            int_part.resize(int_len as usize, b'0');
+        }
+        let min_int = if self.notation == Notation::Standard {
+            self.min_integer_digits.max(1)
+        } else {
+            1
+        };
+        while (int_part.len() as u64) < min_int {
+            int_part.insert(0, b'0');
+        }
+        self.push_grouped(&mut s, &int_part);
+        if int_len < n {
+            s.push(self.decimal_separator);
+            for _ in int_len..0 {
+                s.push('0');
+            }
+            let frac = &digits[int_len.max(0) as usize..];
+            s.push_str(std::str::from_utf8(frac).unwrap());
+        }
+        if self.notation != Notation::Standard {
+            s.push_str(self.marker());
+            s.push_str(&e.to_string());
+        }
+        s
+    }
+
+    fn push_grouped(&self, s: &mut String, int_part: &[u8]) {
+        let sep = match self.group_separator {
+            Some(sep) if self.group_size != 0 => sep,
+            _ => {
+                s.push_str(std::str::from_utf8(int_part).unwrap());
+                return;
+            }
+        };
+        /* group boundaries counted from the decimal separator */
+        let mut bounds = Vec::new();
+        let mut pos = self.group_size;
+        while pos < int_part.len() {
+            bounds.push(int_part.len() - pos);
+            if self.secondary_group_size == 0 {
+                break;
+            }
+            pos += self.secondary_group_size;
+        }
+        for (i, &c) in int_part.iter().enumerate() {
+            if bounds.contains(&i) {
+                s.push(sep);
+            }
+            s.push(c as char);
+        }
+    }
+
+    /* convert 's' to the syntax of bf_atof() */
+    fn scan(&self, s: &str) -> Option<Scanned> {
+        let (neg, body) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if body.eq_ignore_ascii_case("nan") && body.len() == s.len() {
+            return Some(Scanned::NaN);
+        } else if body.eq_ignore_ascii_case("infinity") || body.eq_ignore_ascii_case("inf") {
+            return Some(Scanned::Infinity(neg));
+        }
+        let mut r = String::new();
+        if neg {
+            r.push('-');
+        }
+        let chars: Vec<char> = body.chars().collect();
+        let is_digit = |i: usize| chars.get(i).map_or(false, |c| c.is_digit(self.radix));
+        let mut i = 0;
+        let mut n_digits = 0;
+        /* integer part, the group separators must be between digits */
+        while i < chars.len() {
+            if is_digit(i) {
+                r.push(chars[i]);
+                n_digits += 1;
+            } else if Some(chars[i]) != self.group_separator
+                || i == 0
+                || !is_digit(i - 1)
+                || !is_digit(i + 1)
+            {
+                break;
+            }
+            i += 1;
+        }
+        if n_digits == 0 {
+            r.push('0');
+        }
+        if chars.get(i) == Some(&self.decimal_separator) {
+            r.push('.');
+            i += 1;
+            while is_digit(i) {
+                r.push(chars[i]);
+                n_digits += 1;
+                i += 1;
+            }
+        }
+        if n_digits == 0 {
+            return None;
+        }
+        let rest: String = chars[i..].iter().collect();
+        if !rest.is_empty() {
+            let marker = self.marker();
+            let e = match rest.get(..marker.len()) {
+                Some(m) if m.eq_ignore_ascii_case(marker) => &rest[marker.len()..],
+                _ => return None,
+            };
+            let e_digits = e.strip_prefix(|c| c == '+' || c == '-').unwrap_or(e);
+            if e_digits.is_empty() || !e_digits.bytes().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            r.push(if self.radix == 10 { 'e' } else { '@' });
+            r.push_str(e);
+        }
+        Some(Scanned::Number(r))
+    }
+
+    /// Parse a number written with the options of the format and round
+    /// it to `env`. "NaN", "Infinity" and "Inf" are accepted with any
+    /// case.
+    pub fn parse(&self, s: &str, env: &mut BigFloatEnv) -> Result<BigFloat, ParseBigFloatError> {
+        match self.scan(s).ok_or(ParseBigFloatError)? {
+            Scanned::NaN => Ok(BigFloat::nan()),
+            Scanned::Infinity(false) => Ok(BigFloat::infinity()),
+            Scanned::Infinity(true) => Ok(BigFloat::neg_infinity()),
+            Scanned::Number(r) => env.parse(&r, self.radix),
+        }
+    }
+
+    /// Parse a decimal number written with the options of the format
+    /// and round it to `env`. Panics if the radix is not 10.
+    pub fn parse_decimal(
+        &self,
+        s: &str,
+        env: &mut BigDecimalEnv,
+    ) -> Result<BigDecimal, ParseBigDecimalError> {
+        assert!(self.radix == 10, "invalid radix");
+        match self.scan(s).ok_or(ParseBigDecimalError)? {
+            Scanned::NaN => Ok(BigDecimal::nan()),
+            Scanned::Infinity(false) => Ok(BigDecimal::infinity()),
+            Scanned::Infinity(true) => Ok(BigDecimal::neg_infinity()),
+            Scanned::Number(r) => env.parse(&r),
+        }
+    }
+}
+
+/* number of digits before the decimal separator in engineering
+notation */
+fn eng_integer_digits(point: i64) -> u64 {
+    1 + (point - 1).rem_euclid(3) as u64
+}
+
+/* split "[-]ddd[.ddd]" */
+fn split_fixed(s: &str) -> Digits {
+    let (neg, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+    let (int_part, frac_part) = match s.find('.') {
+        Some(pos) => (&s[..pos], &s[pos + 1..]),
+        None => (s, ""),
+    };
+    let mut digits: Vec<u8> = int_part.bytes().chain(frac_part.bytes()).collect();
+    let mut point = int_part.len() as i64;
+    let n_zeros = digits.iter().take_while(|&&c| c == b'0').count();
+    digits.drain(..n_zeros);
+    point -= n_zeros as i64;
+    while digits.last() == Some(&b'0') {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        point = 1;
+    }
+    Digits { neg, digits, point }
+}
@@ -0,0 +1,767 @@
+/*
+ * Polynomials
+ *
+ * Polynomial is a dense polynomial with BigRational or BigFloat
+ * coefficients, as the Polynomial objects of qjscalc. The coefficient
+ * of x^i is at index i and the leading coefficient is never zero, so
+ * the zero polynomial has no coefficients. The operations on rational
+ * coefficients are exact. The operations on BigFloat coefficients
+ * round each coefficient of the result with a BigFloatEnv.
+ *
+ * The products are computed with a single integer multiplication of
+ * the polynomials evaluated at a large power of two (bf_poly_mul), so
+ * the large products use the FFT multiplication of libbf. The roots
+ * are found with the Aberth-Ehrlich iteration on the square free
+ * factors of the polynomial, at a working precision which is doubled
+ * until the roots are known to the precision of the BigFloatEnv.
+ */
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::bigcomplex::{BigComplex, ComplexEnv};
+use crate::bigfloat::{BigFloat, BigFloatEnv};
+use crate::bigint::BigInt;
+use crate::bigrational::BigRational;
+use crate::libbf::{
+    bf_get_exp_min, bf_mul_2exp, bf_poly_mul, bf_set, bf_t, limb_t, BF_PREC_INF, BF_RNDZ,
+    BF_ST_MEM_ERROR,
+};
+
+/* panic if the allocation of the result failed */
+fn check(ret: i32) {
+    if ret & BF_ST_MEM_ERROR != 0 {
+        panic!("Polynomial: out of memory");
+    }
+}
+
+/// Coefficients of the polynomials and entries of the matrices.
+pub trait Scalar: Clone + PartialEq {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+impl Scalar for BigRational {
+    fn zero() -> BigRational {
+        BigRational::zero()
+    }
+
+    fn one() -> BigRational {
+        BigRational::one()
+    }
+
+    fn is_zero(&self) -> bool {
+        BigRational::is_zero(self)
+    }
+}
+
+impl Scalar for BigFloat {
+    fn zero() -> BigFloat {
+        BigFloat::zero()
+    }
+
+    fn one() -> BigFloat {
+        BigFloat::from(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        BigFloat::is_zero(self)
+    }
+}
+
+/// Dense polynomial.
+#[derive(Clone, PartialEq)]
+pub struct Polynomial<T> {
+    coefs: Vec<T>,
+}
+
+impl<T: Scalar> Polynomial<T> {
+    /// Return the polynomial `coefs[0] + coefs[1] * x + ...`.
+    pub fn new(mut coefs: Vec<T>) -> Polynomial<T> {
+        while coefs.last().is_some_and(|c| c.is_zero()) {
+            coefs.pop();
+        }
+        Polynomial { coefs }
+    }
+
+    pub fn zero() -> Polynomial<T> {
+        Polynomial { coefs: Vec::new() }
+    }
+
+    /// Return the polynomial `x`.
+    pub fn x() -> Polynomial<T> {
+        Polynomial::new(vec![T::zero(), T::one()])
+    }
+
+    pub fn constant(c: T) -> Polynomial<T> {
+        Polynomial::new(vec![c])
+    }
+
+    /// Return the coefficients, from the constant term to the leading
+    /// coefficient.
+    pub fn coefs(&self) -> &[T] {
+        &self.coefs
+    }
+
+    pub fn into_coefs(self) -> Vec<T> {
+        self.coefs
+    }
+
+    /// Return the coefficient of `x^i`.
+    pub fn coef(&self, i: usize) -> T {
+        self.coefs.get(i).cloned().unwrap_or_else(T::zero)
+    }
+
+    /// Return the degree, or None for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefs.is_empty()
+    }
+
+    pub fn leading_coef(&self) -> Option<&T> {
+        self.coefs.last()
+    }
+}
+
+/* product of polynomials with integer coefficients */
+fn mul_int(a: &[BigInt], b: &[BigInt]) -> Vec<BigInt> {
+    let mut r: Vec<BigInt> = (0..a.len() + b.len() - 1).map(|_| BigInt::zero()).collect();
+    check(unsafe {
+        bf_poly_mul(
+            r.as_mut_ptr() as *mut bf_t,
+            a.as_ptr() as *const bf_t,
+            a.len() as limb_t,
+            b.as_ptr() as *const bf_t,
+            b.len() as limb_t,
+        )
+    });
+    r
+}
+
+/* exponent of the most significant bit plus one, or i64::MIN for zero */
+fn exponent(a: &BigFloat) -> i64 {
+    if a.is_zero() {
+        i64::MIN
+    } else {
+        unsafe { (*a.as_ptr()).expn }
+    }
+}
+
+/* |z| < 2^magnitude(z) <= 2 * |z| */
+fn magnitude(z: &BigComplex) -> i64 {
+    exponent(z.re()).max(exponent(z.im()))
+}
+
+impl Polynomial<BigRational> {
+    /* return the integer coefficients and their common denominator */
+    fn to_integer(&self) -> (Vec<BigInt>, BigInt) {
+        let den = self
+            .coefs
+            .iter()
+            .fold(BigInt::one(), |d, c| d.lcm(c.denom()));
+        let num = self
+            .coefs
+            .iter()
+            .map(|c| c.numer() * (&den / c.denom()))
+            .collect();
+        (num, den)
+    }
+
+    /// Return the value at `x` with the Horner scheme.
+    pub fn eval(&self, x: &BigRational) -> BigRational {
+        self.coefs
+            .iter()
+            .rev()
+            .fold(BigRational::zero(), |r, c| r * x + c)
+    }
+
+    pub fn derivative(&self) -> Polynomial<BigRational> {
+        Polynomial::new(
+            self.coefs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| c * BigRational::from(i as u64))
+                .collect(),
+        )
+    }
+
+    /// Return the quotient and the remainder of the division by `b`,
+    /// or None if `b` is zero.
+    pub fn checked_div_rem(
+        &self,
+        b: &Polynomial<BigRational>,
+    ) -> Option<(Polynomial<BigRational>, Polynomial<BigRational>)> {
+        let n2 = b.coefs.len().checked_sub(1)?;
+        if self.coefs.len() <= n2 {
+            return Some((Polynomial::zero(), self.clone()));
+        }
+        /* pseudo division of the integer polynomials A = da * self and
+        B = db * b: lc^k * A = Q * B + R where lc is the leading
+        coefficient of B, so that the fractions are only normalized
+        at the end */
+        let (a, da) = self.to_integer();
+        let (b, db) = b.to_integer();
+        let lc = &b[n2];
+        let unit = lc.abs() == BigInt::one();
+        let mut r = a;
+        let mut q = vec![BigInt::zero(); r.len() - n2];
+        let mut scale = BigInt::one();
+        for i in (0..q.len()).rev() {
+            let c = std::mem::take(&mut r[i + n2]);
+            if c.is_zero() {
+                continue;
+            }
+            if unit {
+                let c = if lc.is_negative() { -c } else { c };
+                for j in 0..n2 {
+                    r[i + j] -= &b[j] * &c;
+                }
+                q[i] = c;
+            } else {
+                for x in r[..i + n2].iter_mut().chain(&mut q[i + 1..]) {
+                    *x *= lc;
+                }
+                for j in 0..n2 {
+                    r[i + j] -= &b[j] * &c;
+                }
+                q[i] = c;
+                scale *= lc;
+            }
+        }
+        let den = &scale * &da;
+        let q = q
+            .into_iter()
+            .map(|c| BigRational::new(c * &db, den.clone()))
+            .collect();
+        let r = r
+            .into_iter()
+            .map(|c| BigRational::new(c, den.clone()))
+            .collect();
+        Some((Polynomial::new(q), Polynomial::new(r)))
+    }
+
+    /// Return the quotient and the remainder of the division by `b`.
+    /// Panics if `b` is zero.
+    pub fn div_rem(
+        &self,
+        b: &Polynomial<BigRational>,
+    ) -> (Polynomial<BigRational>, Polynomial<BigRational>) {
+        self.checked_div_rem(b).expect("attempt to divide by zero")
+    }
+
+    /// Return the monic greatest common divisor, or zero if both
+    /// polynomials are zero.
+    pub fn gcd(&self, b: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+        let mut a = self.clone();
+        let mut b = b.clone();
+        /* the remainders are made monic to limit the growth of the
+        coefficients */
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1.monic();
+            a = std::mem::replace(&mut b, r);
+        }
+        a.monic()
+    }
+
+    fn monic(self) -> Polynomial<BigRational> {
+        match self.leading_coef() {
+            Some(c) if *c != BigRational::one() => {
+                let inv = c.recip();
+                Polynomial::new(self.coefs.iter().map(|c| c * &inv).collect())
+            }
+            _ => self,
+        }
+    }
+
+    /* square free factorization (Yun's algorithm): return the monic
+    factors f_i with self = c * prod(f_i^i) */
+    fn square_free(&self) -> Vec<(Polynomial<BigRational>, usize)> {
+        let mut factors = Vec::new();
+        let d = self.derivative();
+        let g = self.gcd(&d);
+        let mut w = self / &g;
+        let mut z = &d / &g - w.derivative();
+        let mut i = 1;
+        while w.degree().unwrap_or(0) > 0 {
+            let f = w.gcd(&z);
+            w = &w / &f;
+            z = &z / &f - w.derivative();
+            if f.degree().unwrap_or(0) > 0 {
+                factors.push((f, i));
+            }
+            i += 1;
+        }
+        factors
+    }
+
+    /// Return the complex roots, repeated with their multiplicity and
+    /// sorted by real and imaginary parts. The roots of degree 1
+    /// factors are correctly rounded with `env`. The other roots are
+    /// computed with about 24 extra bits before being rounded with
+    /// `env`, and their components below that accuracy are zero. The
+    /// zero polynomial has no roots.
+    pub fn roots(&self, env: &mut BigFloatEnv) -> Vec<BigComplex> {
+        let mut roots = Vec::new();
+        if self.degree().unwrap_or(0) == 0 {
+            return roots;
+        }
+        for (f, m) in self.square_free() {
+            let mut f = f;
+            if f.coefs[0].is_zero() {
+                /* f is monic, square free and f(0) = 0 */
+                f = Polynomial::new(f.coefs[1..].to_vec());
+                roots.extend((0..m).map(|_| BigComplex::zero()));
+            }
+            if f.degree() == Some(0) {
+                continue;
+            }
+            let r = if f.degree() == Some(1) {
+                vec![BigComplex::from((-&f.coefs[0]).to_bigfloat(env))]
+            } else {
+                aberth(&f, env)
+            };
+            for z in r {
+                roots.extend((0..m).map(|_| z.clone()));
+            }
+        }
+        roots.sort_by(|a, b| {
+            (a.re(), a.im())
+                .partial_cmp(&(b.re(), b.im()))
+                .unwrap_or(Ordering::Equal)
+        });
+        roots
+    }
+
+    /// Round the coefficients with `env`.
+    pub fn to_bigfloat(&self, env: &mut BigFloatEnv) -> Polynomial<BigFloat> {
+        Polynomial::new(self.coefs.iter().map(|c| c.to_bigfloat(env)).collect())
+    }
+}
+
+/* roots of the monic square free polynomial 'p' of degree >= 2 with a
+non zero constant term. The Aberth iteration is run until its steps
+no longer decrease, which happens when the roots are known to about
+the working precision minus the logarithm of their condition number.
+The working precision is doubled until the steps are smaller than the
+target precision. The roots are simple so it always terminates. */
+fn aberth(p: &Polynomial<BigRational>, env: &mut BigFloatEnv) -> Vec<BigComplex> {
+    let n = p.coefs.len() - 1;
+    let target = (env.prec() + 32) as i64;
+    let max_iter = 200 + 20 * n;
+    let mut z: Vec<BigComplex> = Vec::new();
+    let mut wp = 64;
+    loop {
+        let mut fenv = BigFloatEnv::new(wp);
+        let mut cenv = ComplexEnv::new(&fenv);
+        let c: Vec<BigComplex> = p
+            .coefs
+            .iter()
+            .map(|c| BigComplex::from(c.to_bigfloat(&mut fenv)))
+            .collect();
+        if z.is_empty() {
+            /* the roots are inside the disk of radius
+            2 * max(|c_i|^(1 / (n - i))) for a monic polynomial */
+            let e = (0..n)
+                .filter(|&i| !c[i].is_zero())
+                .map(|i| (magnitude(&c[i]) + (n - i) as i64 - 1).div_euclid((n - i) as i64) + 1)
+                .max()
+                .unwrap();
+            let pi = fenv.const_pi();
+            let two_pi = fenv.mul(&pi, &BigFloat::from(2));
+            for k in 0..n {
+                /* the angles are shifted to avoid the symmetries of the
+                polynomial */
+                let t = fenv.mul(&two_pi, &BigFloat::from(k as u64));
+                let t = fenv.div(&t, &BigFloat::from(n as u64));
+                let t = fenv.add(&t, &BigFloat::from(0.4));
+                let mut re = fenv.cos(&t);
+                let mut im = fenv.sin(&t);
+                unsafe {
+                    check(bf_mul_2exp(re.as_mut_ptr(), e, BF_PREC_INF, BF_RNDZ));
+                    check(bf_mul_2exp(im.as_mut_ptr(), e, BF_PREC_INF, BF_RNDZ));
+                }
+                z.push(BigComplex::new(re, im));
+            }
+        }
+        let one = BigComplex::from(BigFloat::from(1));
+        /* 'step' is the largest exponent of the relative steps and
+        'best' the smallest value of 'step' so far */
+        let mut step = i64::MAX;
+        let mut best = i64::MAX;
+        let mut stalled = 0;
+        for _ in 0..max_iter {
+            step = i64::MIN;
+            for k in 0..n {
+                /* v = p(z_k) and dv = p'(z_k) */
+                let mut v = c[n].clone();
+                let mut dv = BigComplex::zero();
+                for ci in c[..n].iter().rev() {
+                    let t = cenv.mul(&dv, &z[k]);
+                    dv = cenv.add(&t, &v);
+                    let t = cenv.mul(&v, &z[k]);
+                    v = cenv.add(&t, ci);
+                }
+                if v.is_zero() {
+                    /* p(z_k) may only be below the working precision:
+                    z_k is not known beyond it */
+                    step = step.max(-(wp as i64));
+                    continue;
+                }
+                let w = if dv.is_zero() {
+                    /* move away from a critical point */
+                    let eps = BigComplex::from(BigFloat::from(1.0 / 256.0));
+                    let t = cenv.mul(&z[k], &eps);
+                    cenv.add(&t, &eps)
+                } else {
+                    /* w = N / (1 - N * sum(1 / (z_k - z_j), j != k))
+                    with the Newton correction N = p(z_k) / p'(z_k) */
+                    let ratio = cenv.div(&v, &dv);
+                    let mut s = BigComplex::zero();
+                    for j in (0..n).filter(|&j| j != k) {
+                        let d = cenv.sub(&z[k], &z[j]);
+                        if !d.is_zero() {
+                            let t = cenv.div(&one, &d);
+                            s = cenv.add(&s, &t);
+                        }
+                    }
+                    let t = cenv.mul(&ratio, &s);
+                    let den = cenv.sub(&one, &t);
+                    if den.is_zero() {
+                        ratio
+                    } else {
+                        cenv.div(&ratio, &den)
+                    }
+                };
+                step = step.max(magnitude(&w).saturating_sub(magnitude(&z[k])));
+                z[k] = cenv.sub(&z[k], &w);
+            }
+            if step < -(wp as i64 - 8) {
+                break;
+            }
+            if step < best {
+                best = step;
+                stalled = 0;
+            } else {
+                stalled += 1;
+                if stalled >= 4 {
+                    break;
+                }
+            }
+        }
+        if step < -target {
+            break;
+        }
+        wp *= 2;
+    }
+    z.into_iter()
+        .map(|z| {
+            /* the components below the accuracy of the root are
+            rounding noise */
+            let eps = magnitude(&z) - (target - 8);
+            let mut round = |a: &BigFloat| {
+                if exponent(a) < eps {
+                    BigFloat::zero()
+                } else {
+                    env.round(a)
+                }
+            };
+            BigComplex::new(round(z.re()), round(z.im()))
+        })
+        .collect()
+}
+
+impl Polynomial<BigFloat> {
+    /// Return the sum with each coefficient rounded with `env`.
+    pub fn add(&self, b: &Polynomial<BigFloat>, env: &mut BigFloatEnv) -> Polynomial<BigFloat> {
+        let n = self.coefs.len().max(b.coefs.len());
+        Polynomial::new((0..n).map(|i| env.add(&self.coef(i), &b.coef(i))).collect())
+    }
+
+    pub fn sub(&self, b: &Polynomial<BigFloat>, env: &mut BigFloatEnv) -> Polynomial<BigFloat> {
+        let n = self.coefs.len().max(b.coefs.len());
+        Polynomial::new((0..n).map(|i| env.sub(&self.coef(i), &b.coef(i))).collect())
+    }
+
+    /* return the coefficients as integers times a common power of two,
+    or None if a coefficient is not finite */
+    fn to_integer(&self) -> Option<(Vec<BigInt>, i64)> {
+        if self.coefs.iter().any(|c| !c.is_finite()) {
+            return None;
+        }
+        let e = self
+            .coefs
+            .iter()
+            .filter(|c| !c.is_zero())
+            .map(|c| unsafe { bf_get_exp_min(c.as_ptr()) })
+            .min()
+            .unwrap_or(0);
+        let num = self
+            .coefs
+            .iter()
+            .map(|c| {
+                let mut r = BigInt::zero();
+                unsafe {
+                    check(bf_set(r.as_mut_ptr(), c.as_ptr()));
+                    check(bf_mul_2exp(r.as_mut_ptr(), -e, BF_PREC_INF, BF_RNDZ));
+                }
+                r
+            })
+            .collect();
+        Some((num, e))
+    }
+
+    /* number of bits of the integer coefficients of to_integer() */
+    fn int_bits(&self) -> u64 {
+        let nz = || self.coefs.iter().filter(|c| !c.is_zero());
+        match nz().map(exponent).max() {
+            Some(e) => {
+                (e - nz()
+                    .map(|c| unsafe { bf_get_exp_min(c.as_ptr()) })
+                    .min()
+                    .unwrap()) as u64
+            }
+            None => 0,
+        }
+    }
+
+    /// Return the product with each coefficient rounded with `env`.
+    /// The coefficients are correctly rounded, except when the
+    /// exponents of the coefficients of an operand span more than
+    /// 16 times the precision, in which case they are computed with
+    /// rounded operations.
+    pub fn mul(&self, b: &Polynomial<BigFloat>, env: &mut BigFloatEnv) -> Polynomial<BigFloat> {
+        if self.is_zero() || b.is_zero() {
+            return Polynomial::zero();
+        }
+        let max_bits = 16 * env.prec() + 1024;
+        if self.int_bits() <= max_bits && b.int_bits() <= max_bits {
+            if let (Some((a1, e1)), Some((b1, e2))) = (self.to_integer(), b.to_integer()) {
+                let coefs = mul_int(&a1, &b1)
+                    .iter()
+                    .map(|c| {
+                        let mut r = BigFloat::zero();
+                        unsafe {
+                            check(bf_set(r.as_mut_ptr(), c.as_ptr()));
+                            check(bf_mul_2exp(r.as_mut_ptr(), e1 + e2, BF_PREC_INF, BF_RNDZ));
+                        }
+                        env.round(&r)
+                    })
+                    .collect();
+                return Polynomial::new(coefs);
+            }
+        }
+        let mut r = vec![BigFloat::zero(); self.coefs.len() + b.coefs.len() - 1];
+        for (i, x) in self.coefs.iter().enumerate() {
+            for (j, y) in b.coefs.iter().enumerate() {
+                let t = env.mul(x, y);
+                r[i + j] = env.add(&r[i + j], &t);
+            }
+        }
+        Polynomial::new(r)
+    }
+
+    /// Return the quotient and the remainder of the division by `b`,
+    /// computed with the operations of `env`. Panics if `b` is zero.
+    pub fn div_rem(
+        &self,
+        b: &Polynomial<BigFloat>,
+        env: &mut BigFloatEnv,
+    ) -> (Polynomial<BigFloat>, Polynomial<BigFloat>) {
+        let n2 = b
+            .coefs
+            .len()
+            .checked_sub(1)
+            .expect("attempt to divide by zero");
+        if self.coefs.len() <= n2 {
+            return (Polynomial::zero(), self.clone());
+        }
+        let mut r = self.coefs.clone();
+        let mut q = vec![BigFloat::zero(); self.coefs.len() - n2];
+        for i in (0..q.len()).rev() {
+            let c = env.div(&r[i + n2], &b.coefs[n2]);
+            for j in 0..n2 {
+                let t = env.mul(&b.coefs[j], &c);
+                r[i + j] = env.sub(&r[i + j], &t);
+            }
+            r[i + n2] = BigFloat::zero();
+            q[i] = c;
+        }
+        (Polynomial::new(q), Polynomial::new(r))
+    }
+
+    /// Return the value at `x` with the Horner scheme.
+    pub fn eval(&self, x: &BigFloat, env: &mut BigFloatEnv) -> BigFloat {
+        let mut r = BigFloat::zero();
+        for c in self.coefs.iter().rev() {
+            let t = env.mul(&r, x);
+            r = env.add(&t, c);
+        }
+        r
+    }
+
+    pub fn derivative(&self, env: &mut BigFloatEnv) -> Polynomial<BigFloat> {
+        Polynomial::new(
+            self.coefs
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, c)| env.mul(c, &BigFloat::from(i as u64)))
+                .collect(),
+        )
+    }
+
+    /// Return the complex roots as `Polynomial::<BigRational>::roots`
+    /// with the exact values of the coefficients. The roots are NaN if
+    /// a coefficient is not finite.
+    pub fn roots(&self, env: &mut BigFloatEnv) -> Vec<BigComplex> {
+        let coefs: Option<Vec<BigRational>> =
+            self.coefs.iter().map(BigRational::from_bigfloat).collect();
+        match coefs {
+            Some(coefs) => Polynomial::new(coefs).roots(env),
+            None => vec![BigComplex::nan(); self.coefs.len().saturating_sub(1)],
+        }
+    }
+}
+
+impl<T: Scalar + fmt::Display> fmt::Display for Polynomial<T> {
+    /// Written as in qjscalc, e.g. "x^2-2*x+1/2", from the leading
+    /// term to the constant term.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("0");
+        }
+        let mut s = String::new();
+        for (i, c) in self.coefs.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+            let v = c.to_string();
+            let (neg, v) = match v.strip_prefix('-') {
+                Some(v) => (true, v),
+                None => (false, v.as_str()),
+            };
+            if neg {
+                s.push('-');
+            } else if !s.is_empty() {
+                s.push('+');
+            }
+            if i == 0 || v != "1" {
+                s.push_str(v);
+                if i != 0 {
+                    s.push('*');
+                }
+            }
+            if i >= 1 {
+                s.push('x');
+            }
+            if i >= 2 {
+                s.push_str(&format!("^{}", i));
+            }
+        }
+        f.write_str(&s)
+    }
+}
+
+impl<T: Scalar + fmt::Display> fmt::Debug for Polynomial<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Neg for Polynomial<BigRational> {
+    type Output = Polynomial<BigRational>;
+
+    fn neg(self) -> Polynomial<BigRational> {
+        Polynomial {
+            coefs: self.coefs.into_iter().map(|c| -c).collect(),
+        }
+    }
+}
+
+impl Neg for &Polynomial<BigRational> {
+    type Output = Polynomial<BigRational>;
+
+    fn neg(self) -> Polynomial<BigRational> {
+        -self.clone()
+    }
+}
+
+fn add(a: &Polynomial<BigRational>, b: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+    let n = a.coefs.len().max(b.coefs.len());
+    Polynomial::new((0..n).map(|i| a.coef(i) + b.coef(i)).collect())
+}
+
+fn sub(a: &Polynomial<BigRational>, b: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+    let n = a.coefs.len().max(b.coefs.len());
+    Polynomial::new((0..n).map(|i| a.coef(i) - b.coef(i)).collect())
+}
+
+fn mul(a: &Polynomial<BigRational>, b: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+    if a.is_zero() || b.is_zero() {
+        return Polynomial::zero();
+    }
+    let (a1, d1) = a.to_integer();
+    let (b1, d2) = b.to_integer();
+    let den = &d1 * &d2;
+    Polynomial::new(
+        mul_int(&a1, &b1)
+            .into_iter()
+            .map(|c| BigRational::new(c, den.clone()))
+            .collect(),
+    )
+}
+
+fn div(a: &Polynomial<BigRational>, b: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+    a.div_rem(b).0
+}
+
+fn rem(a: &Polynomial<BigRational>, b: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+    a.div_rem(b).1
+}
+
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident) => {
+        impl $Op<&Polynomial<BigRational>> for &Polynomial<BigRational> {
+            type Output = Polynomial<BigRational>;
+
+            fn $op(self, rhs: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+                $op(self, rhs)
+            }
+        }
+
+        impl $Op<Polynomial<BigRational>> for &Polynomial<BigRational> {
+            type Output = Polynomial<BigRational>;
+
+            fn $op(self, rhs: Polynomial<BigRational>) -> Polynomial<BigRational> {
+                $op(self, &rhs)
+            }
+        }
+
+        impl $Op<&Polynomial<BigRational>> for Polynomial<BigRational> {
+            type Output = Polynomial<BigRational>;
+
+            fn $op(self, rhs: &Polynomial<BigRational>) -> Polynomial<BigRational> {
+                $op(&self, rhs)
+            }
+        }
+
+        impl $Op<Polynomial<BigRational>> for Polynomial<BigRational> {
+            type Output = Polynomial<BigRational>;
+
+            fn $op(self, rhs: Polynomial<BigRational>) -> Polynomial<BigRational> {
+                $op(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add);
+impl_binary_op!(Sub, sub);
+impl_binary_op!(Mul, mul);
+impl_binary_op!(Div, div);
+impl_binary_op!(Rem, rem);
@@ -2,6 +2,7 @@ use c2rust_asm_casts;
 use c2rust_asm_casts::AsmCastTrait;
 
 use crate::cutils::PtrExt;
+use crate::libunicode::{unicode_from_utf8, unicode_to_utf8};
 
 extern "C" {
     pub type _IO_wide_data;
@@ -158,8 +159,6 @@ extern "C" {
     fn dbuf_putstr(s: *mut DynBuf, str: *const std::os::raw::c_char) -> i32;
     fn dbuf_printf(s: *mut DynBuf, fmt: *const std::os::raw::c_char, _: ...) -> i32;
     fn dbuf_free(s: *mut DynBuf);
-    fn unicode_to_utf8(buf: *mut uint8_t, c: u32) -> i32;
-    fn unicode_from_utf8(p: *const uint8_t, max_len: i32, pp: *mut *const uint8_t) -> i32;
     fn JS_GetRuntimeOpaque(rt: *mut JSRuntime) -> *mut std::ffi::c_void;
     fn JS_SetRuntimeOpaque(rt: *mut JSRuntime, opaque: *mut std::ffi::c_void);
     fn JS_MarkValue(rt: *mut JSRuntime, val: JSValue, mark_func: Option<JS_MarkFunc>);
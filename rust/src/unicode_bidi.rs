@@ -0,0 +1,765 @@
+/*
+ * Unicode Bidirectional Algorithm (UAX #9)
+ *
+ * The Bidi_Class property is stored with the same run length
+ * encoding as the general category table. The algorithm resolves
+ * the embedding levels of a paragraph (rules P2 to I2) and reorders
+ * its lines (rules L1 and L2). Mirroring (L4) is left to the caller.
+ */
+use std::os::raw::c_char;
+
+use crate::libunicode::{cr_add_interval, lre_get_table_value, unicode_find_name, CharRange};
+
+/* Tables generated by unicode_gen from the Unicode 13.0.0 data
+(UnicodeData.txt and BidiBrackets.txt). Unassigned code points use
+the default values of DerivedBidiClass.txt.
+
+unicode_bidi_class_table uses the same encoding as unicode_gc_table.
+unicode_bidi_bracket_table contains the opening paired brackets with
+their closing bracket. */
+include!("unicode_bidi_table.rs");
+
+/// Return the Bidi_Class property of `c`.
+pub fn unicode_get_bidi_class(c: u32) -> UnicodeBidiClassEnum {
+    unsafe {
+        lre_get_table_value(
+            c,
+            unicode_bidi_class_table.as_ptr(),
+            unicode_bidi_class_index.as_ptr(),
+            (unicode_bidi_class_index.len() / 3) as i32,
+        )
+    }
+}
+
+/* 'cr' must be initialized and empty. Return 0 if OK, -1 if error, -2
+if not found */
+pub unsafe fn unicode_bidi_class(cr: *mut CharRange, class_name: *const c_char) -> i32 {
+    let class_idx = unicode_find_name(
+        unicode_bidi_class_name_table.as_ptr() as *const c_char,
+        class_name,
+    );
+    if class_idx < 0 {
+        return -2;
+    }
+    let tab = &unicode_bidi_class_table;
+    let mut p = 0;
+    let mut c: u32 = 0;
+    while p < tab.len() {
+        let b = tab[p] as u32;
+        p += 1;
+        let mut n = b >> 5;
+        let v = b & 0x1f;
+        if n == 7 {
+            n = tab[p] as u32;
+            p += 1;
+            if n < 128 {
+                n += 7;
+            } else if n < 128 + 64 {
+                n = (n - 128) << 8 | tab[p] as u32;
+                p += 1;
+                n += 7 + 128;
+            } else {
+                n = (n - 128 - 64) << 16 | (tab[p] as u32) << 8 | tab[p + 1] as u32;
+                p += 2;
+                n += 7 + 128 + (1 << 14);
+            }
+        }
+        let c0 = c;
+        c += n + 1;
+        if v == class_idx as u32 && cr_add_interval(cr, c0, c) != 0 {
+            return -1;
+        }
+    }
+    0
+}
+
+/* return the closing bracket if 'c' is an opening paired bracket,
+the opening bracket ORed with BRACKET_CLOSE if 'c' is a closing
+paired bracket and 0 otherwise */
+pub(crate) const BRACKET_CLOSE: u32 = 1 << 31;
+
+pub(crate) fn bidi_paired_bracket(c: u32) -> u32 {
+    /* canonical equivalents of U+3008 and U+3009 */
+    let c = match c {
+        0x2329 => 0x3008,
+        0x232a => 0x3009,
+        _ => c,
+    };
+    if c > 0xffff {
+        return 0;
+    }
+    for pair in unicode_bidi_bracket_table.iter() {
+        if pair[0] as u32 == c {
+            return pair[1] as u32;
+        } else if pair[1] as u32 == c {
+            return pair[0] as u32 | BRACKET_CLOSE;
+        }
+    }
+    0
+}
+
+const BIDI_MAX_DEPTH: u8 = 125;
+/* maximum number of nested bracket pairs (BD16) */
+const BIDI_MAX_BRACKET_PAIRS: usize = 63;
+
+#[inline]
+fn is_removed_by_x9(t: UnicodeBidiClassEnum) -> bool {
+    matches!(
+        t,
+        BIDI_RLE | BIDI_LRE | BIDI_RLO | BIDI_LRO | BIDI_PDF | BIDI_BN
+    )
+}
+
+#[inline]
+fn is_isolate_initiator(t: UnicodeBidiClassEnum) -> bool {
+    matches!(t, BIDI_LRI | BIDI_RLI | BIDI_FSI)
+}
+
+#[inline]
+fn is_neutral_or_isolate(t: UnicodeBidiClassEnum) -> bool {
+    matches!(
+        t,
+        BIDI_B | BIDI_S | BIDI_WS | BIDI_ON | BIDI_LRI | BIDI_RLI | BIDI_FSI | BIDI_PDI
+    )
+}
+
+/* strong direction used by the N rules: EN and AN count as R */
+#[inline]
+fn strong_dir(t: UnicodeBidiClassEnum) -> Option<UnicodeBidiClassEnum> {
+    match t {
+        BIDI_L => Some(BIDI_L),
+        BIDI_R | BIDI_AL | BIDI_EN | BIDI_AN => Some(BIDI_R),
+        _ => None,
+    }
+}
+
+#[inline]
+fn level_dir(level: u8) -> UnicodeBidiClassEnum {
+    if level & 1 != 0 {
+        BIDI_R
+    } else {
+        BIDI_L
+    }
+}
+
+/* BD9: return the index of the matching PDI of each isolate
+initiator, or 'len' if none. */
+fn bidi_match_isolates(types: &[UnicodeBidiClassEnum]) -> Vec<usize> {
+    let len = types.len();
+    let mut matching = vec![len; len];
+    let mut stack: Vec<usize> = Vec::new();
+    for (i, &t) in types.iter().enumerate() {
+        if is_isolate_initiator(t) {
+            stack.push(i);
+        } else if t == BIDI_PDI {
+            if let Some(j) = stack.pop() {
+                matching[j] = i;
+            }
+        } else if t == BIDI_B {
+            stack.clear();
+        }
+    }
+    matching
+}
+
+/* P2, P3: return the level of the first strong character, skipping
+the isolated sequences, or None if there is none */
+fn bidi_first_strong_level(
+    types: &[UnicodeBidiClassEnum],
+    matching_pdi: &[usize],
+    start: usize,
+    end: usize,
+) -> Option<u8> {
+    let mut i = start;
+    while i < end {
+        match types[i] {
+            BIDI_L => return Some(0),
+            BIDI_R | BIDI_AL => return Some(1),
+            BIDI_B => return None,
+            t if is_isolate_initiator(t) => {
+                i = matching_pdi[i];
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+#[derive(Copy, Clone)]
+struct BidiStackEntry {
+    level: u8,
+    override_status: Option<UnicodeBidiClassEnum>,
+    isolate: bool,
+}
+
+/* X1 - X8: compute the explicit embedding levels. 'types' is updated
+with the overrides. */
+fn bidi_explicit_levels(
+    types: &mut [UnicodeBidiClassEnum],
+    matching_pdi: &[usize],
+    para_level: u8,
+) -> Vec<u8> {
+    let len = types.len();
+    let mut levels = vec![para_level; len];
+    let mut stack: Vec<BidiStackEntry> = Vec::with_capacity(BIDI_MAX_DEPTH as usize + 2);
+    let mut overflow_isolate = 0;
+    let mut overflow_embedding = 0;
+    let mut valid_isolate = 0;
+
+    stack.push(BidiStackEntry {
+        level: para_level,
+        override_status: None,
+        isolate: false,
+    });
+    for i in 0..len {
+        let t = types[i];
+        let top = *stack.last().unwrap();
+        match t {
+            BIDI_RLE | BIDI_LRE | BIDI_RLO | BIDI_LRO => {
+                /* X2 - X5 */
+                let is_rtl = t == BIDI_RLE || t == BIDI_RLO;
+                let level = if is_rtl {
+                    (top.level + 1) | 1
+                } else {
+                    (top.level + 2) & !1
+                };
+                levels[i] = top.level;
+                if level <= BIDI_MAX_DEPTH && overflow_isolate == 0 && overflow_embedding == 0 {
+                    stack.push(BidiStackEntry {
+                        level,
+                        override_status: match t {
+                            BIDI_RLO => Some(BIDI_R),
+                            BIDI_LRO => Some(BIDI_L),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolate == 0 {
+                    overflow_embedding += 1;
+                }
+            }
+            BIDI_RLI | BIDI_LRI | BIDI_FSI => {
+                /* X5a - X5c */
+                levels[i] = top.level;
+                if let Some(o) = top.override_status {
+                    types[i] = o;
+                }
+                let is_rtl = if t == BIDI_FSI {
+                    bidi_first_strong_level(types, matching_pdi, i + 1, matching_pdi[i]) == Some(1)
+                } else {
+                    t == BIDI_RLI
+                };
+                let level = if is_rtl {
+                    (top.level + 1) | 1
+                } else {
+                    (top.level + 2) & !1
+                };
+                if level <= BIDI_MAX_DEPTH && overflow_isolate == 0 && overflow_embedding == 0 {
+                    valid_isolate += 1;
+                    stack.push(BidiStackEntry {
+                        level,
+                        override_status: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolate += 1;
+                }
+            }
+            BIDI_PDI => {
+                /* X6a */
+                if overflow_isolate > 0 {
+                    overflow_isolate -= 1;
+                } else if valid_isolate > 0 {
+                    overflow_embedding = 0;
+                    while !stack.last().unwrap().isolate {
+                        stack.pop();
+                    }
+                    stack.pop();
+                    valid_isolate -= 1;
+                }
+                let top = *stack.last().unwrap();
+                levels[i] = top.level;
+                if let Some(o) = top.override_status {
+                    types[i] = o;
+                }
+            }
+            BIDI_PDF => {
+                /* X7 */
+                levels[i] = top.level;
+                if overflow_isolate > 0 {
+                } else if overflow_embedding > 0 {
+                    overflow_embedding -= 1;
+                } else if !top.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            BIDI_B => {
+                /* X8 */
+                levels[i] = para_level;
+            }
+            BIDI_BN => {
+                levels[i] = top.level;
+            }
+            _ => {
+                /* X6 */
+                levels[i] = top.level;
+                if let Some(o) = top.override_status {
+                    types[i] = o;
+                }
+            }
+        }
+    }
+    levels
+}
+
+/* BD13, X10: compute the isolating run sequences as lists of
+indexes. The characters removed by X9 are not included. */
+fn bidi_run_sequences(
+    classes: &[UnicodeBidiClassEnum],
+    levels: &[u8],
+    matching_pdi: &[usize],
+) -> Vec<Vec<usize>> {
+    let len = classes.len();
+    /* level runs */
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    let mut cur: Vec<usize> = Vec::new();
+    for i in 0..len {
+        if is_removed_by_x9(classes[i]) {
+            continue;
+        }
+        if let Some(&last) = cur.last() {
+            if levels[last] != levels[i] {
+                runs.push(std::mem::replace(&mut cur, Vec::new()));
+            }
+        }
+        cur.push(i);
+    }
+    if !cur.is_empty() {
+        runs.push(cur);
+    }
+
+    /* index of the run starting at each position */
+    let mut run_of_start = vec![usize::MAX; len];
+    for (k, run) in runs.iter().enumerate() {
+        run_of_start[run[0]] = k;
+    }
+    let mut is_matched_pdi = vec![false; len];
+    for &m in matching_pdi {
+        if m < len {
+            is_matched_pdi[m] = true;
+        }
+    }
+
+    let mut sequences = Vec::new();
+    for k in 0..runs.len() {
+        if is_matched_pdi[runs[k][0]] {
+            /* continuation of a previous sequence */
+            continue;
+        }
+        let mut seq: Vec<usize> = Vec::new();
+        let mut r = k;
+        loop {
+            seq.extend_from_slice(&runs[r]);
+            let last = *seq.last().unwrap();
+            if !is_isolate_initiator(classes[last]) || matching_pdi[last] >= len {
+                break;
+            }
+            r = run_of_start[matching_pdi[last]];
+            if r == usize::MAX {
+                break;
+            }
+        }
+        sequences.push(seq);
+    }
+    sequences
+}
+
+/* W1 - W7, N0 - N2 and I1 - I2 on an isolating run sequence */
+fn bidi_resolve_sequence(
+    text: &[u32],
+    orig_types: &[UnicodeBidiClassEnum],
+    types: &mut [UnicodeBidiClassEnum],
+    levels: &mut [u8],
+    explicit_levels: &[u8],
+    para_level: u8,
+    seq: &[usize],
+) {
+    let len = types.len();
+    let level = explicit_levels[seq[0]];
+
+    /* sos and eos: they depend on the levels of the neighbouring
+    sequences before I1 and I2, which may already have been applied to
+    them */
+    let first = seq[0];
+    let mut prev_level = para_level;
+    let mut j = first;
+    while j > 0 {
+        j -= 1;
+        if !is_removed_by_x9(orig_types[j]) {
+            prev_level = explicit_levels[j];
+            break;
+        }
+    }
+    let last = *seq.last().unwrap();
+    let mut next_level = para_level;
+    if !is_isolate_initiator(orig_types[last]) {
+        for j in last + 1..len {
+            if !is_removed_by_x9(orig_types[j]) {
+                next_level = explicit_levels[j];
+                break;
+            }
+        }
+    }
+    let sos = level_dir(level.max(prev_level));
+    let eos = level_dir(level.max(next_level));
+
+    /* W1 */
+    let mut prev = sos;
+    for &i in seq {
+        if types[i] == BIDI_NSM {
+            types[i] = if is_isolate_initiator(prev) || prev == BIDI_PDI {
+                BIDI_ON
+            } else {
+                prev
+            };
+        }
+        prev = types[i];
+    }
+    /* W2, W3 */
+    let mut last_strong = sos;
+    for &i in seq {
+        match types[i] {
+            BIDI_L | BIDI_R => last_strong = types[i],
+            BIDI_AL => {
+                last_strong = BIDI_AL;
+                types[i] = BIDI_R;
+            }
+            BIDI_EN if last_strong == BIDI_AL => types[i] = BIDI_AN,
+            _ => {}
+        }
+    }
+    /* W4 */
+    for k in 1..seq.len().saturating_sub(1) {
+        let t = types[seq[k]];
+        let tp = types[seq[k - 1]];
+        let tn = types[seq[k + 1]];
+        if t == BIDI_ES && tp == BIDI_EN && tn == BIDI_EN {
+            types[seq[k]] = BIDI_EN;
+        } else if t == BIDI_CS && tp == tn && (tp == BIDI_EN || tp == BIDI_AN) {
+            types[seq[k]] = tp;
+        }
+    }
+    /* W5 */
+    let mut k = 0;
+    while k < seq.len() {
+        if types[seq[k]] != BIDI_ET {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        while k < seq.len() && types[seq[k]] == BIDI_ET {
+            k += 1;
+        }
+        let adjacent_en = (start > 0 && types[seq[start - 1]] == BIDI_EN)
+            || (k < seq.len() && types[seq[k]] == BIDI_EN);
+        if adjacent_en {
+            for &i in &seq[start..k] {
+                types[i] = BIDI_EN;
+            }
+        }
+    }
+    /* W6 */
+    for &i in seq {
+        if matches!(types[i], BIDI_ES | BIDI_ET | BIDI_CS) {
+            types[i] = BIDI_ON;
+        }
+    }
+    /* W7 */
+    let mut last_strong = sos;
+    for &i in seq {
+        match types[i] {
+            BIDI_L | BIDI_R => last_strong = types[i],
+            BIDI_EN if last_strong == BIDI_L => types[i] = BIDI_L,
+            _ => {}
+        }
+    }
+
+    /* N0 */
+    bidi_resolve_brackets(text, orig_types, types, seq, sos, level_dir(level));
+
+    /* N1, N2 */
+    let mut k = 0;
+    while k < seq.len() {
+        if !is_neutral_or_isolate(types[seq[k]]) {
+            k += 1;
+            continue;
+        }
+        let start = k;
+        while k < seq.len() && is_neutral_or_isolate(types[seq[k]]) {
+            k += 1;
+        }
+        let before = if start == 0 {
+            sos
+        } else {
+            strong_dir(types[seq[start - 1]]).unwrap_or(sos)
+        };
+        let after = if k == seq.len() {
+            eos
+        } else {
+            strong_dir(types[seq[k]]).unwrap_or(eos)
+        };
+        let dir = if before == after {
+            before
+        } else {
+            level_dir(level)
+        };
+        for &i in &seq[start..k] {
+            types[i] = dir;
+        }
+    }
+
+    /* I1, I2 */
+    for &i in seq {
+        let t = types[i];
+        if levels[i] & 1 == 0 {
+            match t {
+                BIDI_R => levels[i] += 1,
+                BIDI_AN | BIDI_EN => levels[i] += 2,
+                _ => {}
+            }
+        } else if matches!(t, BIDI_L | BIDI_EN | BIDI_AN) {
+            levels[i] += 1;
+        }
+    }
+}
+
+/* N0: paired brackets */
+fn bidi_resolve_brackets(
+    text: &[u32],
+    orig_types: &[UnicodeBidiClassEnum],
+    types: &mut [UnicodeBidiClassEnum],
+    seq: &[usize],
+    sos: UnicodeBidiClassEnum,
+    embedding_dir: UnicodeBidiClassEnum,
+) {
+    /* BD16: positions in 'seq' of the bracket pairs */
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut stack: Vec<(u32, usize)> = Vec::new();
+    for (k, &i) in seq.iter().enumerate() {
+        if types[i] != BIDI_ON {
+            continue;
+        }
+        let b = bidi_paired_bracket(text[i]);
+        if b == 0 {
+            continue;
+        }
+        if b & BRACKET_CLOSE == 0 {
+            if stack.len() == BIDI_MAX_BRACKET_PAIRS {
+                break;
+            }
+            stack.push((b, k));
+        } else {
+            let c = bidi_paired_bracket(b & !BRACKET_CLOSE);
+            if let Some(pos) = stack.iter().rposition(|&(close, _)| close == c) {
+                pairs.push((stack[pos].1, k));
+                stack.truncate(pos);
+            }
+        }
+    }
+    pairs.sort_unstable();
+
+    for &(open, close) in &pairs {
+        let mut found_embedding = false;
+        let mut found_opposite = false;
+        for &i in &seq[open + 1..close] {
+            match strong_dir(types[i]) {
+                Some(d) if d == embedding_dir => {
+                    found_embedding = true;
+                    break;
+                }
+                Some(_) => found_opposite = true,
+                None => {}
+            }
+        }
+        let dir = if found_embedding {
+            embedding_dir
+        } else if found_opposite {
+            let mut context = sos;
+            for &i in seq[..open].iter().rev() {
+                if let Some(d) = strong_dir(types[i]) {
+                    context = d;
+                    break;
+                }
+            }
+            if context != embedding_dir {
+                context
+            } else {
+                embedding_dir
+            }
+        } else {
+            continue;
+        };
+        for &k in &[open, close] {
+            types[seq[k]] = dir;
+            /* the NSMs following a bracket take its type */
+            for &i in &seq[k + 1..] {
+                if orig_types[i] != BIDI_NSM {
+                    break;
+                }
+                types[i] = dir;
+            }
+        }
+    }
+}
+
+/// Result of the resolution of the embedding levels of a paragraph
+/// (UAX #9 rules P2 to I2).
+pub struct BidiParagraph {
+    /// Paragraph embedding level
+    pub level: u8,
+    /// Resolved embedding level of each character
+    pub levels: Vec<u8>,
+    /* original bidi classes, needed by the line rules */
+    classes: Vec<UnicodeBidiClassEnum>,
+}
+
+impl BidiParagraph {
+    /// Resolve the embedding levels of `text`, which must contain a
+    /// single paragraph. If `level` is None, the paragraph level is
+    /// computed from the first strong character.
+    pub fn new(text: &[u32], level: Option<u8>) -> Self {
+        let classes: Vec<UnicodeBidiClassEnum> =
+            text.iter().map(|&c| unicode_get_bidi_class(c)).collect();
+        let matching_pdi = bidi_match_isolates(&classes);
+        let para_level = match level {
+            Some(l) => l.min(BIDI_MAX_DEPTH),
+            None => bidi_first_strong_level(&classes, &matching_pdi, 0, classes.len()).unwrap_or(0),
+        };
+        let mut types = classes.clone();
+        let mut levels = bidi_explicit_levels(&mut types, &matching_pdi, para_level);
+        let explicit_levels = levels.clone();
+        for seq in bidi_run_sequences(&classes, &explicit_levels, &matching_pdi) {
+            bidi_resolve_sequence(
+                text,
+                &classes,
+                &mut types,
+                &mut levels,
+                &explicit_levels,
+                para_level,
+                &seq,
+            );
+        }
+        /* the characters removed by X9 get the level of the previous
+        character so that they are kept in place by the reordering */
+        for i in 0..classes.len() {
+            if is_removed_by_x9(classes[i]) {
+                levels[i] = if i == 0 { para_level } else { levels[i - 1] };
+            }
+        }
+        BidiParagraph {
+            level: para_level,
+            levels,
+            classes,
+        }
+    }
+
+    /// Return the levels of the line `start..end` after applying the
+    /// rule L1.
+    pub fn line_levels(&self, start: usize, end: usize) -> Vec<u8> {
+        let mut levels = self.levels[start..end].to_vec();
+        let classes = &self.classes[start..end];
+        /* L1: separators and trailing whitespace */
+        let mut is_trailing = true;
+        for i in (0..levels.len()).rev() {
+            match classes[i] {
+                BIDI_S | BIDI_B => {
+                    levels[i] = self.level;
+                    is_trailing = true;
+                }
+                BIDI_WS | BIDI_FSI | BIDI_LRI | BIDI_RLI | BIDI_PDI => {
+                    if is_trailing {
+                        levels[i] = self.level;
+                    }
+                }
+                t if is_removed_by_x9(t) => {
+                    if is_trailing {
+                        levels[i] = self.level;
+                    }
+                }
+                _ => is_trailing = false,
+            }
+        }
+        levels
+    }
+
+    /// Return the indexes of the characters of the line `start..end`
+    /// in visual order (rules L1 and L2).
+    pub fn visual_order(&self, start: usize, end: usize) -> Vec<usize> {
+        let levels = self.line_levels(start, end);
+        let mut order: Vec<usize> = (start..end).collect();
+        bidi_reorder_levels(&levels, &mut order);
+        order
+    }
+}
+
+/* L2: from the highest level down to the lowest odd level, reverse
+any contiguous sequence of characters at that level or higher */
+fn bidi_reorder_levels(levels: &[u8], order: &mut [usize]) {
+    let max_level = match levels.iter().max() {
+        Some(&l) => l,
+        None => return,
+    };
+    let min_odd_level = levels.iter().fold(
+        max_level | 1,
+        |m, &l| {
+            if l & 1 != 0 && l < m {
+                l
+            } else {
+                m
+            }
+        },
+    );
+    let mut level = max_level;
+    while level >= min_odd_level && level > 0 {
+        let mut i = 0;
+        while i < levels.len() {
+            if levels[i] < level {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < levels.len() && levels[i] >= level {
+                i += 1;
+            }
+            order[start..i].reverse();
+        }
+        level -= 1;
+    }
+}
+
+/// Return `s` in visual order. Each paragraph is reordered as a
+/// single line. The paragraph level is computed from the text if
+/// `level` is None.
+pub fn bidi_reorder(s: &str, level: Option<u8>) -> String {
+    let text: Vec<u32> = s.chars().map(|c| c as u32).collect();
+    let mut res = String::with_capacity(s.len());
+    let mut start = 0;
+    while start < text.len() {
+        let mut end = start;
+        while end < text.len() && unicode_get_bidi_class(text[end]) != BIDI_B {
+            end += 1;
+        }
+        if end < text.len() {
+            /* the paragraph separator is kept with its paragraph */
+            end += 1;
+        }
+        let para = BidiParagraph::new(&text[start..end], level);
+        for i in para.visual_order(0, end - start) {
+            res.push(std::char::from_u32(text[start + i]).unwrap());
+        }
+        start = end;
+    }
+    res
+}
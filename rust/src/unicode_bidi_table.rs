@@ -0,0 +1,241 @@
+/* Compressed unicode tables */
+/* Automatically generated file - do not edit */
+
+pub type UnicodeBidiClassEnum = u32;
+pub const BIDI_L: UnicodeBidiClassEnum = 0;
+pub const BIDI_R: UnicodeBidiClassEnum = 1;
+pub const BIDI_AL: UnicodeBidiClassEnum = 2;
+pub const BIDI_EN: UnicodeBidiClassEnum = 3;
+pub const BIDI_ES: UnicodeBidiClassEnum = 4;
+pub const BIDI_ET: UnicodeBidiClassEnum = 5;
+pub const BIDI_AN: UnicodeBidiClassEnum = 6;
+pub const BIDI_CS: UnicodeBidiClassEnum = 7;
+pub const BIDI_NSM: UnicodeBidiClassEnum = 8;
+pub const BIDI_BN: UnicodeBidiClassEnum = 9;
+pub const BIDI_B: UnicodeBidiClassEnum = 10;
+pub const BIDI_S: UnicodeBidiClassEnum = 11;
+pub const BIDI_WS: UnicodeBidiClassEnum = 12;
+pub const BIDI_ON: UnicodeBidiClassEnum = 13;
+pub const BIDI_LRE: UnicodeBidiClassEnum = 14;
+pub const BIDI_LRO: UnicodeBidiClassEnum = 15;
+pub const BIDI_RLE: UnicodeBidiClassEnum = 16;
+pub const BIDI_RLO: UnicodeBidiClassEnum = 17;
+pub const BIDI_PDF: UnicodeBidiClassEnum = 18;
+pub const BIDI_LRI: UnicodeBidiClassEnum = 19;
+pub const BIDI_RLI: UnicodeBidiClassEnum = 20;
+pub const BIDI_FSI: UnicodeBidiClassEnum = 21;
+pub const BIDI_PDI: UnicodeBidiClassEnum = 22;
+pub const BIDI_COUNT: UnicodeBidiClassEnum = 23;
+
+static unicode_bidi_class_name_table: [u8; 507] = *b"\
+    L,Left_To_Right\0\
+    R,Right_To_Left\0\
+    AL,Arabic_Letter\0\
+    EN,European_Number\0\
+    ES,European_Separator\0\
+    ET,European_Terminator\0\
+    AN,Arabic_Number\0\
+    CS,Common_Separator\0\
+    NSM,Nonspacing_Mark\0\
+    BN,Boundary_Neutral\0\
+    B,Paragraph_Separator\0\
+    S,Segment_Separator\0\
+    WS,White_Space\0\
+    ON,Other_Neutral\0\
+    LRE,Left_To_Right_Embedding\0\
+    LRO,Left_To_Right_Override\0\
+    RLE,Right_To_Left_Embedding\0\
+    RLO,Right_To_Left_Override\0\
+    PDF,Pop_Directional_Format\0\
+    LRI,Left_To_Right_Isolate\0\
+    RLI,Right_To_Left_Isolate\0\
+    FSI,First_Strong_Isolate\0\
+    PDI,Pop_Directional_Isolate\0\
+    \0";
+
+static unicode_bidi_class_table: [u8; 1701] = [
+    0xe9, 0x01, 0x0b, 0x0a, 0x0b, 0x0c, 0x0a, 0xe9, 0x06, 0x4a, 0x0b, 0x0c, 0x2d, 0x45, 0x8d, 0x04,
+    0x07, 0x04, 0x27, 0xe3, 0x02, 0x07, 0xad, 0xe0, 0x12, 0xad, 0xe0, 0x12, 0x6d, 0xa9, 0x0a, 0xe9,
+    0x12, 0x07, 0x0d, 0x65, 0x6d, 0x00, 0x2d, 0x09, 0x2d, 0x25, 0x23, 0x0d, 0x00, 0x4d, 0x03, 0x00,
+    0x8d, 0xe0, 0x0f, 0x0d, 0xe0, 0x17, 0x0d, 0xe0, 0x81, 0x39, 0x2d, 0xc0, 0xed, 0x06, 0x20, 0xed,
+    0x06, 0x80, 0xed, 0x01, 0x00, 0xed, 0x09, 0xe8, 0x68, 0x60, 0x2d, 0xe0, 0x00, 0x0d, 0x80, 0x2d,
+    0x00, 0x0d, 0xe0, 0x66, 0x0d, 0xe0, 0x80, 0x04, 0xc8, 0xe0, 0x80, 0x78, 0x0d, 0x20, 0x2d, 0x05,
+    0x01, 0xe8, 0x25, 0x01, 0x08, 0x01, 0x28, 0x01, 0x28, 0x01, 0x08, 0xe1, 0x30, 0xa6, 0x2d, 0x02,
+    0x25, 0x02, 0x07, 0x02, 0x2d, 0xe8, 0x03, 0xe2, 0x28, 0xe8, 0x0d, 0xe6, 0x02, 0x05, 0x26, 0x42,
+    0x08, 0xe2, 0x5d, 0xc8, 0x06, 0x0d, 0xa8, 0x22, 0x28, 0x0d, 0x68, 0x22, 0xe3, 0x02, 0xe2, 0x0f,
+    0x08, 0xe2, 0x16, 0xe8, 0x13, 0xe2, 0x53, 0xe8, 0x03, 0xe2, 0x07, 0xe1, 0x23, 0xe8, 0x01, 0x21,
+    0x6d, 0x41, 0x08, 0xe1, 0x10, 0x68, 0x01, 0xe8, 0x01, 0x01, 0x48, 0x01, 0x88, 0xe1, 0x23, 0x48,
+    0x61, 0xe2, 0x6b, 0xe8, 0x07, 0x06, 0xe8, 0x18, 0xe0, 0x2f, 0x08, 0x00, 0x08, 0x60, 0xe8, 0x00,
+    0x60, 0x08, 0x40, 0xc8, 0xe0, 0x02, 0x28, 0xe0, 0x15, 0x08, 0xe0, 0x32, 0x08, 0x60, 0x68, 0xe0,
+    0x00, 0x08, 0xe0, 0x0c, 0x28, 0xe0, 0x06, 0x25, 0xc0, 0x05, 0x20, 0x08, 0x20, 0x28, 0xe0, 0x31,
+    0x08, 0x60, 0x28, 0x60, 0x28, 0x20, 0x48, 0x40, 0x08, 0xe0, 0x16, 0x28, 0x40, 0x08, 0xe0, 0x03,
+    0x28, 0xe0, 0x31, 0x08, 0x60, 0x88, 0x00, 0x28, 0x60, 0x08, 0xe0, 0x0c, 0x28, 0xe0, 0x05, 0x05,
+    0xe0, 0x00, 0xa8, 0x00, 0x08, 0xe0, 0x32, 0x08, 0x20, 0x08, 0x00, 0x68, 0xe0, 0x00, 0x08, 0xc0,
+    0x28, 0xe0, 0x03, 0x28, 0xe0, 0x16, 0x08, 0xe0, 0x35, 0x08, 0xe0, 0x04, 0x08, 0xe0, 0x1d, 0xad,
+    0x05, 0x0d, 0x80, 0x08, 0x40, 0x08, 0xe0, 0x31, 0x48, 0x80, 0x48, 0x00, 0x68, 0xc0, 0x28, 0xe0,
+    0x03, 0x28, 0xe0, 0x0c, 0xcd, 0x20, 0x08, 0xe0, 0x32, 0x08, 0xe0, 0x07, 0x28, 0xe0, 0x0c, 0x28,
+    0xe0, 0x14, 0x28, 0xe0, 0x31, 0x28, 0x60, 0x68, 0xe0, 0x00, 0x08, 0xe0, 0x0c, 0x28, 0xe0, 0x15,
+    0x08, 0xe0, 0x40, 0x08, 0xc0, 0x48, 0x00, 0x08, 0xe0, 0x52, 0x08, 0x20, 0xc8, 0x60, 0x05, 0xc0,
+    0xe8, 0x00, 0xe0, 0x5a, 0x08, 0x20, 0xe8, 0x01, 0xe0, 0x03, 0xa8, 0xe0, 0x42, 0x28, 0xe0, 0x13,
+    0x08, 0x00, 0x08, 0x00, 0x08, 0x6d, 0xe0, 0x2b, 0xe8, 0x06, 0x00, 0x88, 0x00, 0x28, 0x80, 0xe8,
+    0x03, 0x00, 0xe8, 0x1c, 0xe0, 0x01, 0x08, 0xe0, 0x5e, 0x68, 0x00, 0xa8, 0x00, 0x28, 0x20, 0x28,
+    0xe0, 0x11, 0x28, 0x60, 0x48, 0xe0, 0x08, 0x68, 0xe0, 0x05, 0x08, 0x20, 0x28, 0xa0, 0x08, 0xe0,
+    0x07, 0x08, 0xe0, 0x82, 0x37, 0x48, 0xe0, 0x28, 0xed, 0x02, 0xe0, 0x5e, 0x0d, 0xe0, 0x81, 0xf7,
+    0x0c, 0xe0, 0x12, 0x2d, 0xe0, 0x6d, 0x48, 0xe0, 0x15, 0x28, 0xe0, 0x16, 0x28, 0xe0, 0x16, 0x28,
+    0xe0, 0x38, 0x28, 0x00, 0xc8, 0xe0, 0x00, 0x08, 0x20, 0xe8, 0x03, 0xc0, 0x05, 0x00, 0x08, 0xe0,
+    0x0a, 0xed, 0x02, 0xa0, 0xed, 0x03, 0x48, 0x09, 0xe0, 0x6e, 0x28, 0xe0, 0x1a, 0x08, 0xe0, 0x6e,
+    0x48, 0x60, 0x28, 0xe0, 0x01, 0x08, 0xa0, 0x48, 0x60, 0x0d, 0x40, 0x2d, 0xe0, 0x80, 0x10, 0xed,
+    0x1a, 0xe0, 0x0f, 0x28, 0x20, 0x08, 0xe0, 0x32, 0x08, 0x00, 0xc8, 0x00, 0x08, 0x00, 0x08, 0x20,
+    0xe8, 0x00, 0xa0, 0xe8, 0x02, 0x20, 0x08, 0xe0, 0x28, 0xe8, 0x09, 0xe0, 0x37, 0x68, 0xe0, 0x28,
+    0x08, 0x00, 0x88, 0x00, 0x08, 0x80, 0x08, 0xe0, 0x20, 0xe8, 0x01, 0xe0, 0x04, 0x28, 0xe0, 0x18,
+    0x68, 0x20, 0x28, 0x00, 0x48, 0xe0, 0x30, 0x08, 0x00, 0x28, 0x40, 0x08, 0x00, 0x48, 0xe0, 0x32,
+    0xe8, 0x00, 0x20, 0x28, 0xe0, 0x80, 0x10, 0x48, 0x00, 0xe8, 0x05, 0x00, 0xc8, 0x60, 0x08, 0xa0,
+    0x08, 0x40, 0x28, 0xe0, 0x80, 0x3e, 0xe8, 0x32, 0x00, 0x88, 0xe0, 0x81, 0x35, 0x0d, 0x00, 0x4d,
+    0xe0, 0x03, 0x4d, 0xe0, 0x05, 0x4d, 0xe0, 0x05, 0x4d, 0xe0, 0x05, 0x2d, 0x00, 0xec, 0x03, 0x49,
+    0x00, 0x01, 0xed, 0x10, 0x0c, 0x0a, 0x0e, 0x10, 0x12, 0x0f, 0x11, 0x07, 0x85, 0xed, 0x07, 0x07,
+    0xed, 0x12, 0x0c, 0xa9, 0x13, 0x14, 0x15, 0x16, 0xa9, 0x03, 0x40, 0xa3, 0x24, 0x4d, 0x00, 0xe3,
+    0x02, 0x24, 0x4d, 0xe0, 0x09, 0xe5, 0x28, 0xe8, 0x19, 0xe0, 0x07, 0x2d, 0x00, 0x6d, 0x00, 0x2d,
+    0xe0, 0x02, 0x0d, 0x00, 0x4d, 0x80, 0xad, 0x00, 0x0d, 0x00, 0x0d, 0x00, 0x0d, 0x60, 0x05, 0xe0,
+    0x03, 0x2d, 0x60, 0x8d, 0x80, 0x6d, 0x20, 0xed, 0x08, 0xe0, 0x21, 0x4d, 0x60, 0xed, 0x7a, 0x04,
+    0x05, 0xed, 0x80, 0x9a, 0xe0, 0x3d, 0xed, 0x12, 0x00, 0xed, 0x80, 0x09, 0xe0, 0x11, 0xed, 0x03,
+    0xe0, 0x0d, 0xed, 0x20, 0xe3, 0x0c, 0xe0, 0x46, 0xed, 0x81, 0x3a, 0x00, 0xed, 0x80, 0xcb, 0xe0,
+    0x80, 0x78, 0xed, 0x81, 0xec, 0x20, 0xed, 0x18, 0x00, 0xed, 0x61, 0xe0, 0x80, 0x5d, 0xad, 0x60,
+    0x48, 0xc0, 0xcd, 0xe0, 0x77, 0x08, 0xe0, 0x58, 0xe8, 0x18, 0xed, 0x4b, 0xe0, 0x25, 0xed, 0x12,
+    0x00, 0xed, 0x51, 0xe0, 0x04, 0xed, 0x80, 0x4e, 0xe0, 0x12, 0xed, 0x04, 0x60, 0x0c, 0x6d, 0x40,
+    0xed, 0x11, 0xe0, 0x01, 0x68, 0x20, 0x0d, 0x80, 0x2d, 0x80, 0x4d, 0xe0, 0x51, 0x28, 0x2d, 0x40,
+    0x0d, 0xe0, 0x52, 0x0d, 0xe0, 0x80, 0x3c, 0xed, 0x1c, 0xe0, 0x31, 0x2d, 0xe0, 0x29, 0xed, 0x08,
+    0xe0, 0x14, 0x4d, 0xe0, 0x2a, 0xed, 0x07, 0xe0, 0x04, 0x6d, 0xe0, 0x80, 0x1f, 0x6d, 0xe0, 0x5b,
+    0x2d, 0xe0, 0x17, 0x0d, 0xe0, 0x99, 0x38, 0xed, 0x38, 0xe0, 0xc0, 0x16, 0x08, 0xed, 0x2f, 0xe0,
+    0x80, 0xbe, 0x4d, 0xe0, 0x57, 0x68, 0x0d, 0xe8, 0x02, 0x2d, 0xe0, 0x16, 0x28, 0xe0, 0x48, 0x28,
+    0xe0, 0x06, 0xed, 0x1a, 0xe0, 0x5e, 0x0d, 0xe0, 0x71, 0x08, 0x40, 0x08, 0x60, 0x08, 0xe0, 0x11,
+    0x28, 0x00, 0x6d, 0x08, 0xe0, 0x03, 0x25, 0xe0, 0x32, 0x6d, 0xe0, 0x44, 0x28, 0xe0, 0x12, 0xe8,
+    0x0a, 0xe0, 0x05, 0x08, 0xe0, 0x1e, 0xe8, 0x00, 0xe0, 0x11, 0xe8, 0x03, 0xe0, 0x26, 0x48, 0xe0,
+    0x28, 0x08, 0x20, 0x68, 0x20, 0x28, 0xe0, 0x1f, 0x08, 0xe0, 0x3b, 0xa8, 0x20, 0x28, 0x20, 0x28,
+    0xe0, 0x04, 0x08, 0xe0, 0x00, 0x08, 0xe0, 0x27, 0x08, 0xe0, 0x2b, 0x08, 0x00, 0x48, 0x20, 0x28,
+    0x80, 0x28, 0x00, 0x08, 0xe0, 0x22, 0x28, 0xe0, 0x00, 0x08, 0xe0, 0x6b, 0x2d, 0xe0, 0x71, 0x08,
+    0x20, 0x08, 0x60, 0x08, 0xe0, 0xc0, 0x0e, 0xa7, 0x01, 0x08, 0xe1, 0x02, 0x04, 0xe1, 0x1e, 0xe2,
+    0x81, 0x66, 0x2d, 0xe2, 0x80, 0x08, 0xe9, 0x18, 0xe2, 0x05, 0x0d, 0x22, 0xe8, 0x08, 0xed, 0x02,
+    0xa0, 0xe8, 0x08, 0xed, 0x18, 0x07, 0x0d, 0x07, 0x00, 0x0d, 0x07, 0xed, 0x01, 0x05, 0x2d, 0x24,
+    0x4d, 0x00, 0x0d, 0x25, 0x0d, 0x60, 0xe2, 0x80, 0x07, 0x09, 0x00, 0x2d, 0x45, 0x8d, 0x04, 0x07,
+    0x04, 0x27, 0xe3, 0x02, 0x07, 0xad, 0xe0, 0x12, 0xad, 0xe0, 0x12, 0xed, 0x03, 0xe0, 0x72, 0x25,
+    0x4d, 0x25, 0x00, 0xcd, 0x00, 0xe9, 0x01, 0x8d, 0x29, 0xe0, 0x80, 0x79, 0x0d, 0xe0, 0x36, 0xed,
+    0x45, 0x40, 0xed, 0x05, 0x40, 0x0d, 0xe0, 0x54, 0x08, 0xe0, 0x80, 0x5a, 0x08, 0xe3, 0x13, 0xe0,
+    0x72, 0x88, 0xe0, 0x83, 0xfd, 0xe1, 0x80, 0x97, 0x0d, 0xe1, 0x80, 0x59, 0x48, 0x01, 0x28, 0x81,
+    0x68, 0xe1, 0x20, 0x48, 0x61, 0x08, 0xe1, 0x80, 0x1d, 0x28, 0xe1, 0x4a, 0xcd, 0xe1, 0x81, 0x38,
+    0xe2, 0x1c, 0x68, 0xe2, 0x00, 0xe6, 0x02, 0xa2, 0xe1, 0x80, 0x98, 0xe6, 0x17, 0xe1, 0x24, 0x28,
+    0xe1, 0x0b, 0xe0, 0x38, 0xe1, 0x28, 0xe2, 0x0e, 0xe8, 0x03, 0xe2, 0x17, 0xe1, 0x80, 0x08, 0x00,
+    0x08, 0xe0, 0x2e, 0xe8, 0x07, 0xe0, 0x03, 0xed, 0x0c, 0xe0, 0x11, 0x48, 0xe0, 0x29, 0x68, 0x20,
+    0x28, 0xe0, 0x3d, 0x48, 0xe0, 0x1c, 0x88, 0x00, 0xe8, 0x00, 0xe0, 0x36, 0x08, 0xe0, 0x04, 0x28,
+    0xe0, 0x2c, 0xe8, 0x01, 0xe0, 0x02, 0x68, 0x20, 0x08, 0xe0, 0x57, 0x48, 0x20, 0x08, 0x00, 0x28,
+    0xa0, 0x08, 0xe0, 0x80, 0x18, 0x08, 0x40, 0xe8, 0x00, 0xe0, 0x0d, 0x28, 0xe0, 0x31, 0x28, 0x40,
+    0x08, 0xe0, 0x1d, 0xc8, 0x40, 0x88, 0xe0, 0x80, 0x3b, 0xe8, 0x00, 0x20, 0x48, 0x00, 0x08, 0xe0,
+    0x0f, 0x08, 0xe0, 0x4c, 0xa8, 0x00, 0x08, 0x60, 0x28, 0x00, 0x28, 0xe0, 0x80, 0x66, 0x68, 0xa0,
+    0x28, 0x00, 0x28, 0xe0, 0x13, 0x28, 0xe0, 0x4d, 0xe8, 0x00, 0x20, 0x08, 0x00, 0x28, 0xe0, 0x17,
+    0xed, 0x05, 0xe0, 0x36, 0x08, 0x00, 0x08, 0x20, 0xa8, 0x00, 0x08, 0xe0, 0x5d, 0x48, 0x20, 0x68,
+    0x00, 0x88, 0xe0, 0x80, 0x7b, 0xe8, 0x01, 0x00, 0x28, 0xe0, 0x80, 0x78, 0x28, 0x00, 0x08, 0x60,
+    0x08, 0xe0, 0x80, 0x08, 0x68, 0x20, 0x28, 0x60, 0x08, 0xe0, 0x18, 0xa8, 0x20, 0x28, 0xe0, 0x20,
+    0xa8, 0x20, 0x68, 0xe0, 0x00, 0x08, 0xe0, 0x01, 0xa8, 0x20, 0x48, 0xe0, 0x26, 0xe8, 0x05, 0x00,
+    0x28, 0xe0, 0x81, 0x0e, 0xc8, 0x00, 0xa8, 0xe0, 0x4c, 0xe8, 0x0e, 0x20, 0xc8, 0x00, 0x28, 0x00,
+    0x28, 0xe0, 0x72, 0xa8, 0x40, 0x08, 0x00, 0x28, 0x00, 0xc8, 0x00, 0x08, 0xe0, 0x40, 0x28, 0x40,
+    0x08, 0x00, 0x08, 0xe0, 0x80, 0xd3, 0x28, 0xe0, 0x80, 0x58, 0xed, 0x00, 0x65, 0xed, 0x09, 0xe0,
+    0xc0, 0x0a, 0x76, 0x88, 0xe0, 0x33, 0xc8, 0xe0, 0x83, 0x90, 0x08, 0xe0, 0x37, 0x68, 0xe0, 0x47,
+    0x0d, 0x00, 0x08, 0xe0, 0xc0, 0x0c, 0x30, 0x28, 0x00, 0x69, 0xe0, 0x94, 0x3b, 0x48, 0xe0, 0x01,
+    0xe9, 0x00, 0xe8, 0x00, 0x20, 0xc8, 0xe0, 0x16, 0x68, 0xe0, 0x4a, 0xed, 0x3a, 0x48, 0x0d, 0xe0,
+    0x80, 0x32, 0xed, 0x4f, 0xe0, 0x82, 0xfc, 0x0d, 0xe0, 0x31, 0x0d, 0xe0, 0x31, 0x0d, 0xe0, 0x31,
+    0x0d, 0xe0, 0x31, 0x0d, 0xe0, 0x02, 0xe3, 0x2a, 0xe0, 0x81, 0x78, 0xe8, 0x2f, 0x60, 0xe8, 0x2a,
+    0xe0, 0x00, 0x08, 0xe0, 0x06, 0x08, 0xe0, 0x0e, 0x88, 0x00, 0xe8, 0x07, 0xe0, 0x84, 0xc8, 0xc8,
+    0x00, 0xe8, 0x09, 0x20, 0xc8, 0x00, 0x28, 0x00, 0x88, 0xe0, 0x80, 0x7d, 0xc8, 0xe0, 0x81, 0x2d,
+    0x68, 0xe0, 0x07, 0x05, 0xe0, 0x84, 0x78, 0xe1, 0x80, 0x48, 0xc8, 0xe1, 0x65, 0xc8, 0xe1, 0x82,
+    0x9d, 0xe2, 0x48, 0xe1, 0x38, 0xe2, 0x48, 0xe1, 0x80, 0x28, 0xe2, 0x80, 0x68, 0x2d, 0xe2, 0x06,
+    0xe1, 0x80, 0x78, 0xed, 0x24, 0x60, 0xed, 0x5c, 0xe0, 0x04, 0xed, 0x07, 0x20, 0xed, 0x07, 0x00,
+    0xed, 0x07, 0x00, 0xed, 0x1d, 0xe0, 0x02, 0xe3, 0x03, 0x8d, 0xe0, 0x17, 0x0d, 0xe0, 0x32, 0xad,
+    0xe0, 0x35, 0x0d, 0xe0, 0x80, 0x2a, 0xad, 0xe0, 0x80, 0x12, 0xed, 0x83, 0x50, 0xe0, 0x00, 0xed,
+    0x05, 0x40, 0xed, 0x05, 0x40, 0xed, 0x6c, 0xe0, 0x04, 0xed, 0x51, 0xc0, 0xed, 0x04, 0xe0, 0x0c,
+    0xed, 0x04, 0x60, 0xed, 0x30, 0xe0, 0x00, 0xed, 0x02, 0xa0, 0xed, 0x20, 0xe0, 0x00, 0xed, 0x16,
+    0x20, 0x2d, 0xe0, 0x46, 0xed, 0x71, 0x00, 0xed, 0x4a, 0x00, 0xed, 0x7f, 0xe0, 0x04, 0xed, 0x06,
+    0x20, 0x8d, 0x40, 0x4d, 0x80, 0xcd, 0xe0, 0x01, 0xed, 0x11, 0xc0, 0xcd, 0xe0, 0x01, 0x4d, 0xe0,
+    0x05, 0xcd, 0xe0, 0x21, 0xed, 0x80, 0x0b, 0x00, 0xed, 0x2f, 0xe0, 0x1d, 0xe3, 0x02, 0xe0, 0x83,
+    0x7c, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76,
+    0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29,
+    0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0,
+    0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0xe9, 0x80, 0x7a,
+    0xe8, 0x80, 0x68, 0xe9, 0x8d, 0x88, 0xe0, 0xc0, 0xaf, 0x76, 0x29, 0xe0, 0xc0, 0xbf, 0x76, 0x29,
+    0xe0, 0xc0, 0xbf, 0x76, 0x29,
+];
+
+static unicode_bidi_class_index: [u8; 162] = [
+    0xa0, 0x00, 0x20, 0xe0, 0x02, 0x20, 0x90, 0x05, 0x00, 0x70, 0x06, 0x00, 0xf6, 0x07, 0x00, 0x49,
+    0x09, 0x00, 0x3c, 0x0a, 0x00, 0xf2, 0x0a, 0x00, 0xf9, 0x0b, 0x00, 0xe4, 0x0c, 0x00, 0x47, 0x0e,
+    0x00, 0x98, 0x0f, 0x20, 0x9d, 0x10, 0x20, 0x74, 0x17, 0x00, 0x20, 0x19, 0x00, 0x65, 0x1a, 0x00,
+    0xa2, 0x1b, 0x00, 0xf4, 0x1c, 0x00, 0x0e, 0x20, 0x00, 0x8a, 0x20, 0x20, 0x3a, 0x21, 0x20, 0x4b,
+    0x24, 0x00, 0xef, 0x2c, 0x00, 0x08, 0x30, 0x00, 0x60, 0x32, 0x00, 0x0d, 0xa6, 0x40, 0x25, 0xa8,
+    0x00, 0xb3, 0xa9, 0x20, 0xb9, 0xaa, 0x00, 0x3e, 0xfd, 0x40, 0x64, 0xfe, 0x00, 0xe2, 0xff, 0x00,
+    0x76, 0x03, 0x21, 0x00, 0x0d, 0x01, 0x01, 0x10, 0x01, 0x82, 0x11, 0x01, 0x40, 0x13, 0x01, 0xbc,
+    0x15, 0x01, 0x26, 0x17, 0x01, 0x33, 0x1a, 0x01, 0xb5, 0x1c, 0x01, 0xf0, 0x6a, 0x61, 0x73, 0xd1,
+    0x01, 0x89, 0xd7, 0x01, 0x07, 0xe0, 0x01, 0x70, 0xec, 0x21, 0xc1, 0xf0, 0x01, 0xed, 0xf6, 0x21,
+    0xae, 0xf8, 0x01, 0xd0, 0xfa, 0x21, 0xfe, 0xff, 0x04, 0xfe, 0xff, 0x6b, 0x00, 0x00, 0x10, 0x00,
+    0x00, 0x11,
+];
+
+static unicode_bidi_bracket_table: [[u16; 2]; 60] = [
+    [0x0028, 0x0029],
+    [0x005b, 0x005d],
+    [0x007b, 0x007d],
+    [0x0f3a, 0x0f3b],
+    [0x0f3c, 0x0f3d],
+    [0x169b, 0x169c],
+    [0x2045, 0x2046],
+    [0x207d, 0x207e],
+    [0x208d, 0x208e],
+    [0x2308, 0x2309],
+    [0x230a, 0x230b],
+    [0x2329, 0x232a],
+    [0x2768, 0x2769],
+    [0x276a, 0x276b],
+    [0x276c, 0x276d],
+    [0x276e, 0x276f],
+    [0x2770, 0x2771],
+    [0x2772, 0x2773],
+    [0x2774, 0x2775],
+    [0x27c5, 0x27c6],
+    [0x27e6, 0x27e7],
+    [0x27e8, 0x27e9],
+    [0x27ea, 0x27eb],
+    [0x27ec, 0x27ed],
+    [0x27ee, 0x27ef],
+    [0x2983, 0x2984],
+    [0x2985, 0x2986],
+    [0x2987, 0x2988],
+    [0x2989, 0x298a],
+    [0x298b, 0x298c],
+    [0x298d, 0x2990],
+    [0x298f, 0x298e],
+    [0x2991, 0x2992],
+    [0x2993, 0x2994],
+    [0x2995, 0x2996],
+    [0x2997, 0x2998],
+    [0x29d8, 0x29d9],
+    [0x29da, 0x29db],
+    [0x29fc, 0x29fd],
+    [0x2e22, 0x2e23],
+    [0x2e24, 0x2e25],
+    [0x2e26, 0x2e27],
+    [0x2e28, 0x2e29],
+    [0x3008, 0x3009],
+    [0x300a, 0x300b],
+    [0x300c, 0x300d],
+    [0x300e, 0x300f],
+    [0x3010, 0x3011],
+    [0x3014, 0x3015],
+    [0x3016, 0x3017],
+    [0x3018, 0x3019],
+    [0x301a, 0x301b],
+    [0xfe59, 0xfe5a],
+    [0xfe5b, 0xfe5c],
+    [0xfe5d, 0xfe5e],
+    [0xff08, 0xff09],
+    [0xff3b, 0xff3d],
+    [0xff5b, 0xff5d],
+    [0xff5f, 0xff60],
+    [0xff62, 0xff63],
+];
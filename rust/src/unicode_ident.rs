@@ -0,0 +1,254 @@
+/*
+ * Identifier validation
+ *
+ * Two profiles are supported: the ECMAScript IdentifierName syntax
+ * and the UAX #31 default identifiers (XID_Start XID_Continue*). The
+ * identifiers can optionally be normalized to NFKC (UAX #31 R4) and
+ * checked against the spoofing techniques of UTS #39: mixing of
+ * scripts beyond the Highly Restrictive level, invisible characters
+ * and repeated nonspacing marks.
+ */
+use crate::libregexp::{lre_js_is_ident_first, lre_js_is_ident_next};
+use crate::libunicode::{
+    lre_is_id_continue, lre_is_id_start, lre_is_in_prop, unicode_get_cc, unicode_get_script,
+    unicode_get_script_ext, unicode_normalize_vec, UNICODE_PROP_Default_Ignorable_Code_Point,
+    UNICODE_PROP_XID_Continue1, UNICODE_PROP_XID_Start1, UNICODE_SCRIPT_Bopomofo,
+    UNICODE_SCRIPT_Common, UNICODE_SCRIPT_Han, UNICODE_SCRIPT_Hangul, UNICODE_SCRIPT_Hiragana,
+    UNICODE_SCRIPT_Inherited, UNICODE_SCRIPT_Katakana, UNICODE_SCRIPT_Latin, UnicodeScriptEnum,
+    UNICODE_NFKC, UNICODE_SCRIPT_COUNT,
+};
+
+pub type UnicodeIdentProfileEnum = u32;
+/* ECMAScript IdentifierName */
+pub const IDENT_PROFILE_ECMASCRIPT: UnicodeIdentProfileEnum = 0;
+/* UAX #31 default identifiers */
+pub const IDENT_PROFILE_UAX31: UnicodeIdentProfileEnum = 1;
+
+/* normalize the identifier to NFKC before validating it */
+pub const IDENT_FLAG_NFKC: u32 = 1 << 0;
+/* reject the identifiers which may be used for spoofing */
+pub const IDENT_FLAG_SPOOF_CHECK: u32 = 1 << 1;
+
+const ZWNJ: u32 = 0x200c;
+const ZWJ: u32 = 0x200d;
+/* canonical combining class of the viramas */
+const CC_VIRAMA: i32 = 9;
+
+/// Reason why an identifier is rejected.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IdentError {
+    /// The identifier is empty.
+    Empty,
+    /// The character cannot appear at this position.
+    InvalidChar(u32),
+    /// The character is invisible and is not in a context where it
+    /// is needed.
+    InvisibleChar(u32),
+    /// The same nonspacing mark is repeated.
+    RepeatedMark(u32),
+    /// The identifier mixes scripts which are not commonly used
+    /// together.
+    MixedScript,
+    /// The memory allocation of the normalized identifier failed.
+    OutOfMemory,
+}
+
+/// Return true if `c` can start an identifier.
+pub fn is_id_start(c: u32, profile: UnicodeIdentProfileEnum) -> bool {
+    match profile {
+        IDENT_PROFILE_ECMASCRIPT => unsafe { lre_js_is_ident_first(c as i32) != 0 },
+        _ => {
+            /* XID_Start is stored as the difference with ID_Start */
+            let id_start = unsafe { lre_is_id_start(c) != 0 };
+            id_start ^ has_prop(c, UNICODE_PROP_XID_Start1)
+        }
+    }
+}
+
+/// Return true if `c` can appear after the first character of an
+/// identifier. For the UAX #31 profile, ZWNJ and ZWJ are excluded:
+/// `validate_identifier` only accepts them after a virama (UAX #31
+/// A1 and A2).
+pub fn is_id_continue(c: u32, profile: UnicodeIdentProfileEnum) -> bool {
+    match profile {
+        IDENT_PROFILE_ECMASCRIPT => unsafe { lre_js_is_ident_next(c as i32) != 0 },
+        _ => {
+            let id_continue = unsafe { lre_is_id_continue(c) != 0 };
+            id_continue ^ has_prop(c, UNICODE_PROP_XID_Continue1)
+        }
+    }
+}
+
+fn has_prop(c: u32, prop_idx: u32) -> bool {
+    unsafe { lre_is_in_prop(c, prop_idx as i32) != 0 }
+}
+
+fn is_virama(c: u32) -> bool {
+    unsafe { unicode_get_cc(c) == CC_VIRAMA }
+}
+
+/* Set of scripts. The augmented script sets of UTS #39 add the
+Jpan, Kore and Hanb writing systems after the scripts. */
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ScriptSet([u64; 3]);
+
+pub(crate) const SCRIPT_Jpan: u32 = UNICODE_SCRIPT_COUNT;
+pub(crate) const SCRIPT_Kore: u32 = UNICODE_SCRIPT_COUNT + 1;
+pub(crate) const SCRIPT_Hanb: u32 = UNICODE_SCRIPT_COUNT + 2;
+
+impl ScriptSet {
+    pub(crate) const EMPTY: ScriptSet = ScriptSet([0; 3]);
+    pub(crate) const ALL: ScriptSet = ScriptSet([!0; 3]);
+
+    pub(crate) fn has(&self, script: u32) -> bool {
+        (self.0[(script >> 6) as usize] >> (script & 63)) & 1 != 0
+    }
+
+    pub(crate) fn add(&mut self, script: u32) {
+        self.0[(script >> 6) as usize] |= 1 << (script & 63);
+    }
+
+    pub(crate) fn intersect(&mut self, other: &ScriptSet) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a &= b;
+        }
+    }
+
+    pub(crate) fn union(&mut self, other: &ScriptSet) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a |= b;
+        }
+    }
+
+    pub(crate) fn remove(&mut self, other: &ScriptSet) {
+        for (a, b) in self.0.iter_mut().zip(other.0.iter()) {
+            *a &= !b;
+        }
+    }
+
+    /* return the smallest script of the set */
+    pub(crate) fn first(&self) -> Option<u32> {
+        let i = self.0.iter().position(|&v| v != 0)?;
+        Some((i as u32) << 6 | self.0[i].trailing_zeros())
+    }
+}
+
+/* Return the augmented script set of 'c' or None if it is used with
+all the scripts (Common and Inherited) */
+pub(crate) fn augmented_script_set(c: u32) -> Option<ScriptSet> {
+    let mut set = ScriptSet::EMPTY;
+    let ext = unicode_get_script_ext(c);
+    if ext.is_empty() {
+        let script = unicode_get_script(c);
+        if script == UNICODE_SCRIPT_Common || script == UNICODE_SCRIPT_Inherited {
+            return None;
+        }
+        set.add(script);
+    } else {
+        for &script in ext {
+            set.add(script as UnicodeScriptEnum);
+        }
+    }
+    if set.has(UNICODE_SCRIPT_Han) {
+        set.add(SCRIPT_Jpan);
+        set.add(SCRIPT_Kore);
+        set.add(SCRIPT_Hanb);
+    }
+    if set.has(UNICODE_SCRIPT_Hiragana) || set.has(UNICODE_SCRIPT_Katakana) {
+        set.add(SCRIPT_Jpan);
+    }
+    if set.has(UNICODE_SCRIPT_Hangul) {
+        set.add(SCRIPT_Kore);
+    }
+    if set.has(UNICODE_SCRIPT_Bopomofo) {
+        set.add(SCRIPT_Hanb);
+    }
+    Some(set)
+}
+
+/* Return the resolved script set of 'buf' and the resolved script set
+of its characters which are not Latin */
+pub(crate) fn resolved_script_sets(buf: &[u32]) -> (ScriptSet, ScriptSet) {
+    let mut resolved = ScriptSet::ALL;
+    let mut resolved_non_latin = ScriptSet::ALL;
+    for &c in buf {
+        if let Some(set) = augmented_script_set(c) {
+            resolved.intersect(&set);
+            if !set.has(UNICODE_SCRIPT_Latin) {
+                resolved_non_latin.intersect(&set);
+            }
+        }
+    }
+    (resolved, resolved_non_latin)
+}
+
+/* UTS #39 Highly Restrictive level: the resolved script set is not
+empty, or the characters are Latin and from one of the Jpan, Kore
+or Hanb writing systems. */
+pub(crate) fn is_highly_restrictive(buf: &[u32]) -> bool {
+    let (resolved, resolved_non_latin) = resolved_script_sets(buf);
+    resolved != ScriptSet::EMPTY
+        || resolved_non_latin.has(SCRIPT_Jpan)
+        || resolved_non_latin.has(SCRIPT_Kore)
+        || resolved_non_latin.has(SCRIPT_Hanb)
+}
+
+fn check_spoofing(buf: &[u32]) -> Result<(), IdentError> {
+    for (i, &c) in buf.iter().enumerate() {
+        let prev = if i > 0 { buf[i - 1] } else { 0 };
+        if c == ZWNJ || c == ZWJ {
+            if !is_virama(prev) {
+                return Err(IdentError::InvisibleChar(c));
+            }
+        } else if has_prop(c, UNICODE_PROP_Default_Ignorable_Code_Point) {
+            return Err(IdentError::InvisibleChar(c));
+        } else if c == prev && unsafe { unicode_get_cc(c) } != 0 {
+            return Err(IdentError::RepeatedMark(c));
+        }
+    }
+    if !is_highly_restrictive(buf) {
+        return Err(IdentError::MixedScript);
+    }
+    Ok(())
+}
+
+/// Validate the identifier `s` with the syntax of `profile` and
+/// return it, normalized to NFKC if `flags` contains
+/// `IDENT_FLAG_NFKC`. The identifier is validated after
+/// normalization. With `IDENT_FLAG_SPOOF_CHECK`, the identifiers
+/// containing invisible characters or mixing scripts beyond the UTS
+/// #39 Highly Restrictive level are rejected.
+pub fn validate_identifier(
+    s: &str,
+    profile: UnicodeIdentProfileEnum,
+    flags: u32,
+) -> Result<String, IdentError> {
+    let mut buf: Vec<u32> = s.chars().map(|ch| ch as u32).collect();
+    if flags & IDENT_FLAG_NFKC != 0 {
+        buf = unicode_normalize_vec(&buf, UNICODE_NFKC).ok_or(IdentError::OutOfMemory)?;
+    }
+    let first = *buf.first().ok_or(IdentError::Empty)?;
+    if !is_id_start(first, profile) {
+        return Err(IdentError::InvalidChar(first));
+    }
+    for i in 1..buf.len() {
+        let c = buf[i];
+        let is_valid = match c {
+            ZWNJ | ZWJ if profile == IDENT_PROFILE_UAX31 => is_virama(buf[i - 1]),
+            _ => is_id_continue(c, profile),
+        };
+        if !is_valid {
+            return Err(IdentError::InvalidChar(c));
+        }
+    }
+    if flags & IDENT_FLAG_SPOOF_CHECK != 0 {
+        check_spoofing(&buf)?;
+    }
+    Ok(buf.iter().filter_map(|&c| char::from_u32(c)).collect())
+}
+
+/// Return true if `s` is a valid identifier for `profile`, without
+/// normalization nor spoofing checks.
+pub fn is_identifier(s: &str, profile: UnicodeIdentProfileEnum) -> bool {
+    validate_identifier(s, profile, 0).is_ok()
+}
@@ -0,0 +1,479 @@
+/*
+ * Unicode Line Breaking Algorithm (UAX #14)
+ *
+ * The break opportunities are computed with the pair rules LB2 to
+ * LB31 and the default resolution of LB1: the Complex_Context
+ * characters (Thai, Lao, Khmer, Myanmar...) are treated as
+ * Alphabetic since no dictionary is available. In Thai and Lao, the
+ * line can still be broken at the syllable boundaries which are
+ * given by the spelling: before a leading vowel and after a final
+ * vowel or a repetition mark. The other words are only split by
+ * wrap_lines() when they do not fit on a line. The CJK tailoring
+ * resolves the Ambiguous and Conditional_Japanese_Starter characters
+ * to Ideographic.
+ */
+use std::os::raw::c_char;
+
+use crate::libunicode::{
+    cr_add_interval, get_index_pos, lre_is_in_prop, unicode_find_name, unicode_get_gc, CharRange,
+    UNICODE_GC_Cn, UNICODE_GC_Mc, UNICODE_GC_Mn, UNICODE_PROP_Extended_Pictographic,
+};
+use crate::unicode_width::{
+    display_width, display_width_cjk, unicode_char_width, unicode_east_asian_width, UNICODE_EAW_F,
+    UNICODE_EAW_H, UNICODE_EAW_W,
+};
+
+/* Tables generated by unicode_gen from the Unicode 13.0.0 data
+(LineBreak.txt). Unassigned code points use the default values of
+LineBreak.txt.
+
+unicode_line_break_table is run length encoded as unicode_gc_table
+but with two bits for the run length and six bits for the class
+((n << 6) | v, n < 3). The Hangul LV syllables (H2) are stored as
+H3 and recognized from their syllable index. */
+include!("unicode_linebreak_table.rs");
+
+const HANGUL_S_BASE: u32 = 0xac00;
+const HANGUL_S_COUNT: u32 = 11172;
+const HANGUL_T_COUNT: u32 = 28;
+const ZWJ: u32 = 0x200d;
+
+/* decode the run at 'tab[*p]'. Return its length and its value */
+fn get_run(tab: &[u8], p: &mut usize) -> (u32, u32) {
+    let b = tab[*p] as u32;
+    *p += 1;
+    let mut n = b >> 6;
+    let v = b & 0x3f;
+    if n == 3 {
+        n = tab[*p] as u32;
+        *p += 1;
+        if n < 128 {
+            n += 3;
+        } else if n < 128 + 64 {
+            n = (n - 128) << 8 | tab[*p] as u32;
+            *p += 1;
+            n += 3 + 128;
+        } else {
+            n = (n - 128 - 64) << 16 | (tab[*p] as u32) << 8 | tab[*p + 1] as u32;
+            *p += 2;
+            n += 3 + 128 + (1 << 14);
+        }
+    }
+    (n + 1, v)
+}
+
+fn is_hangul_lv(c: u32) -> bool {
+    (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&c)
+        && (c - HANGUL_S_BASE).is_multiple_of(HANGUL_T_COUNT)
+}
+
+/// Return the Line_Break property of `c`.
+pub fn unicode_get_line_break(c: u32) -> UnicodeLineBreakEnum {
+    let tab = &unicode_line_break_table;
+    let mut code: u32 = 0;
+    let pos = unsafe {
+        get_index_pos(
+            &mut code,
+            c,
+            unicode_line_break_index.as_ptr(),
+            (unicode_line_break_index.len() / 3) as i32,
+        )
+    };
+    if pos < 0 {
+        return LB_XX;
+    }
+    let mut p = pos as usize;
+    loop {
+        let (n, v) = get_run(tab, &mut p);
+        code += n;
+        if c < code {
+            return if v == LB_H3 && is_hangul_lv(c) {
+                LB_H2
+            } else {
+                v
+            };
+        }
+    }
+}
+
+/* 'cr' must be initialized and empty. Return 0 if OK, -1 if error, -2
+if not found */
+pub unsafe fn unicode_line_break(cr: *mut CharRange, class_name: *const c_char) -> i32 {
+    let class_idx = unicode_find_name(
+        unicode_line_break_name_table.as_ptr() as *const c_char,
+        class_name,
+    );
+    if class_idx < 0 {
+        return -2;
+    }
+    let class_idx = class_idx as u32;
+    let tab = &unicode_line_break_table;
+    let mut p = 0;
+    let mut c: u32 = 0;
+    while p < tab.len() {
+        let (n, v) = get_run(tab, &mut p);
+        let c0 = c;
+        c += n;
+        if v == LB_H3 && (class_idx == LB_H2 || class_idx == LB_H3) {
+            /* the LV syllables are interleaved with the LVT syllables */
+            let mut c1 = c0;
+            while c1 < c {
+                let next = c1 - (c1 - HANGUL_S_BASE) % HANGUL_T_COUNT + HANGUL_T_COUNT;
+                let lv = if is_hangul_lv(c1) { 1 } else { 0 };
+                let (start, end) = if class_idx == LB_H2 {
+                    (c1, c1 + lv)
+                } else {
+                    (c1 + lv, next.min(c))
+                };
+                if start < end && cr_add_interval(cr, start, end) != 0 {
+                    return -1;
+                }
+                c1 = next;
+            }
+        } else if v == class_idx && cr_add_interval(cr, c0, c) != 0 {
+            return -1;
+        }
+    }
+    0
+}
+
+/* LB1: resolve the classes which have no pair rules */
+fn resolve_class(c: u32, is_cjk: bool) -> UnicodeLineBreakEnum {
+    match unicode_get_line_break(c) {
+        LB_AI | LB_CJ if is_cjk => LB_ID,
+        LB_AI | LB_SG | LB_XX => LB_AL,
+        LB_CJ => LB_NS,
+        LB_SA => match unicode_get_gc(c) {
+            UNICODE_GC_Mn | UNICODE_GC_Mc => LB_CM,
+            _ => LB_AL,
+        },
+        cl => cl,
+    }
+}
+
+/* Thai and Lao syllable boundary between the Complex_Context
+characters 'prev' and 'c'. The leading vowels (e.g. U+0E40 SARA E)
+start a syllable. SARA A, SARA AM and the repetition marks end it. */
+fn is_sa_break(prev: u32, c: u32) -> bool {
+    if unicode_get_line_break(prev) != LB_SA || unicode_get_line_break(c) != LB_SA {
+        return false;
+    }
+    let is_leading_vowel = |c: u32| matches!(c, 0x0e40..=0x0e44 | 0x0ec0..=0x0ec4);
+    match c {
+        /* the abbreviation and repetition marks end the word */
+        0x0e2f | 0x0e46 | 0x0eaf | 0x0ec6 => false,
+        _ if is_leading_vowel(c) => !is_leading_vowel(prev),
+        _ => matches!(prev, 0x0e30 | 0x0e33 | 0x0e46 | 0x0eb0 | 0x0eb3 | 0x0ec6),
+    }
+}
+
+fn is_east_asian(c: u32) -> bool {
+    matches!(
+        unicode_east_asian_width(c),
+        UNICODE_EAW_F | UNICODE_EAW_W | UNICODE_EAW_H
+    )
+}
+
+/// Kind of a line break opportunity.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BreakOpportunity {
+    /// The line must be broken, e.g. after a newline.
+    Mandatory,
+    /// The line can be broken.
+    Allowed,
+}
+
+/// Iterator over the line break opportunities of a string.
+pub struct LineBreaks<'a> {
+    s: &'a str,
+    pos: usize,
+    is_cjk: bool,
+    /* class of the previous character after LB9 and LB10 */
+    prev: UnicodeLineBreakEnum,
+    /* class of the character before 'prev' */
+    prev2: UnicodeLineBreakEnum,
+    /* class of the last character which is not a space */
+    before_sp: UnicodeLineBreakEnum,
+    /* character of class 'prev' */
+    prev_c: u32,
+    /* the previous character is a ZWJ, possibly attached by LB9 */
+    prev_is_zwj: bool,
+    /* number of consecutive regional indicators */
+    ri_count: usize,
+}
+
+fn line_breaks1(s: &str, is_cjk: bool) -> LineBreaks<'_> {
+    LineBreaks {
+        s,
+        pos: 0,
+        is_cjk,
+        prev: LB_XX,
+        prev2: LB_XX,
+        before_sp: LB_XX,
+        prev_c: 0,
+        prev_is_zwj: false,
+        ri_count: 0,
+    }
+}
+
+/// Return the line break opportunities of `s` as the byte offsets
+/// before which the line can be broken. The end of a non empty
+/// string is always a mandatory break (LB3).
+pub fn line_breaks(s: &str) -> LineBreaks<'_> {
+    line_breaks1(s, false)
+}
+
+/// Same as `line_breaks` but the Ambiguous and
+/// Conditional_Japanese_Starter characters are treated as
+/// ideographs, as in CJK texts.
+pub fn line_breaks_cjk(s: &str) -> LineBreaks<'_> {
+    line_breaks1(s, true)
+}
+
+impl<'a> LineBreaks<'a> {
+    /* Return the break opportunity before 'c' of class 'cl', or None
+    if the line cannot be broken. 'prev' is not LB_XX. */
+    fn break_before(&self, c: u32, cl: UnicodeLineBreakEnum) -> Option<BreakOpportunity> {
+        let prev = self.prev;
+        /* LB4, LB5 */
+        match prev {
+            LB_CR if cl == LB_LF => return None,
+            LB_BK | LB_CR | LB_LF | LB_NL => return Some(BreakOpportunity::Mandatory),
+            _ => {}
+        }
+        let is_allowed = match (prev, cl) {
+            /* LB6, LB7 */
+            (_, LB_BK) | (_, LB_CR) | (_, LB_LF) | (_, LB_NL) | (_, LB_SP) | (_, LB_ZW) => false,
+            /* LB8 */
+            _ if self.before_sp == LB_ZW => true,
+            /* LB8a */
+            _ if self.prev_is_zwj => false,
+            /* LB11 */
+            (LB_WJ, _) | (_, LB_WJ) => false,
+            /* LB12, LB12a */
+            (LB_GL, _) => false,
+            (_, LB_GL) if !matches!(prev, LB_SP | LB_BA | LB_HY) => false,
+            /* LB13 */
+            (_, LB_CL) | (_, LB_CP) | (_, LB_EX) | (_, LB_IS) | (_, LB_SY) => false,
+            /* LB14 to LB17: the spaces are skipped */
+            _ if self.before_sp == LB_OP => false,
+            (_, LB_OP) if self.before_sp == LB_QU => false,
+            (_, LB_NS) if self.before_sp == LB_CL || self.before_sp == LB_CP => false,
+            (_, LB_B2) if self.before_sp == LB_B2 => false,
+            /* LB18 */
+            (LB_SP, _) => true,
+            /* LB19, LB20 */
+            (LB_QU, _) | (_, LB_QU) => false,
+            (LB_CB, _) | (_, LB_CB) => true,
+            /* LB21, LB21a, LB21b */
+            (_, LB_BA) | (_, LB_HY) | (_, LB_NS) | (LB_BB, _) => false,
+            (LB_HY, _) | (LB_BA, _) if self.prev2 == LB_HL => false,
+            (LB_SY, LB_HL) => false,
+            /* LB22 */
+            (_, LB_IN) => false,
+            /* LB23, LB23a, LB24 */
+            (LB_AL, LB_NU) | (LB_HL, LB_NU) | (LB_NU, LB_AL) | (LB_NU, LB_HL) => false,
+            (LB_PR, LB_ID) | (LB_PR, LB_EB) | (LB_PR, LB_EM) => false,
+            (LB_ID, LB_PO) | (LB_EB, LB_PO) | (LB_EM, LB_PO) => false,
+            (LB_PR, LB_AL) | (LB_PR, LB_HL) | (LB_PO, LB_AL) | (LB_PO, LB_HL) => false,
+            (LB_AL, LB_PR) | (LB_HL, LB_PR) | (LB_AL, LB_PO) | (LB_HL, LB_PO) => false,
+            /* LB25 */
+            (LB_CL, LB_PO) | (LB_CP, LB_PO) | (LB_CL, LB_PR) | (LB_CP, LB_PR) => false,
+            (LB_NU, LB_PO) | (LB_NU, LB_PR) | (LB_PO, LB_OP) | (LB_PO, LB_NU) => false,
+            (LB_PR, LB_OP) | (LB_PR, LB_NU) | (LB_HY, LB_NU) | (LB_IS, LB_NU) => false,
+            (LB_NU, LB_NU) | (LB_SY, LB_NU) => false,
+            /* LB26, LB27 */
+            (LB_JL, LB_JL) | (LB_JL, LB_JV) | (LB_JL, LB_H2) | (LB_JL, LB_H3) => false,
+            (LB_JV, LB_JV) | (LB_JV, LB_JT) | (LB_H2, LB_JV) | (LB_H2, LB_JT) => false,
+            (LB_JT, LB_JT) | (LB_H3, LB_JT) => false,
+            (LB_JL, LB_PO) | (LB_JV, LB_PO) | (LB_JT, LB_PO) | (LB_H2, LB_PO) => false,
+            (LB_H3, LB_PO) => false,
+            (LB_PR, LB_JL) | (LB_PR, LB_JV) | (LB_PR, LB_JT) | (LB_PR, LB_H2) => false,
+            (LB_PR, LB_H3) => false,
+            /* LB1: the Complex_Context syllables */
+            (LB_AL, LB_AL) if is_sa_break(self.prev_c, c) => true,
+            /* LB28, LB29 */
+            (LB_AL, LB_AL) | (LB_AL, LB_HL) | (LB_HL, LB_AL) | (LB_HL, LB_HL) => false,
+            (LB_IS, LB_AL) | (LB_IS, LB_HL) => false,
+            /* LB30 */
+            (LB_AL, LB_OP) | (LB_HL, LB_OP) | (LB_NU, LB_OP) => is_east_asian(c),
+            (LB_CP, LB_AL) | (LB_CP, LB_HL) | (LB_CP, LB_NU) => is_east_asian(self.prev_c),
+            /* LB30a */
+            (LB_RI, LB_RI) => self.ri_count.is_multiple_of(2),
+            /* LB30b */
+            (LB_EB, LB_EM) => false,
+            (_, LB_EM) => {
+                !(unicode_get_gc(self.prev_c) == UNICODE_GC_Cn
+                    && unsafe {
+                        lre_is_in_prop(self.prev_c, UNICODE_PROP_Extended_Pictographic as i32) != 0
+                    })
+            }
+            /* LB31 */
+            _ => true,
+        };
+        if is_allowed {
+            Some(BreakOpportunity::Allowed)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for LineBreaks<'a> {
+    type Item = (usize, BreakOpportunity);
+
+    fn next(&mut self) -> Option<(usize, BreakOpportunity)> {
+        if self.pos >= self.s.len() {
+            if self.prev == LB_XX {
+                return None;
+            }
+            /* LB3 */
+            self.prev = LB_XX;
+            return Some((self.s.len(), BreakOpportunity::Mandatory));
+        }
+        let text = &self.s[self.pos..];
+        for (i, ch) in text.char_indices() {
+            let c = ch as u32;
+            let mut cl = resolve_class(c, self.is_cjk);
+            let mut res = None;
+            if self.prev != LB_XX {
+                /* LB9: attach the combining marks to their base */
+                if (cl == LB_CM || cl == LB_ZWJ)
+                    && !matches!(self.prev, LB_BK | LB_CR | LB_LF | LB_NL | LB_SP | LB_ZW)
+                {
+                    self.prev_is_zwj = c == ZWJ;
+                    continue;
+                }
+                /* LB10 */
+                if cl == LB_CM || cl == LB_ZWJ {
+                    cl = LB_AL;
+                }
+                res = self.break_before(c, cl);
+            } else if cl == LB_CM || cl == LB_ZWJ {
+                cl = LB_AL;
+            }
+            self.prev2 = self.prev;
+            self.prev = cl;
+            self.prev_c = c;
+            self.prev_is_zwj = c == ZWJ;
+            if cl != LB_SP {
+                self.before_sp = cl;
+            }
+            self.ri_count = if cl == LB_RI { self.ri_count + 1 } else { 0 };
+            if let Some(op) = res {
+                self.pos += i + ch.len_utf8();
+                return Some((self.pos - ch.len_utf8(), op));
+            }
+        }
+        self.pos = self.s.len();
+        self.next()
+    }
+}
+
+fn is_trailing_space(ch: char) -> bool {
+    matches!(
+        unicode_get_line_break(ch as u32),
+        LB_SP | LB_BK | LB_CR | LB_LF | LB_NL
+    )
+}
+
+struct LineWrapper<'a> {
+    s: &'a str,
+    max_width: usize,
+    is_cjk: bool,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> LineWrapper<'a> {
+    fn width(&self, s: &str) -> usize {
+        if self.is_cjk {
+            display_width_cjk(s)
+        } else {
+            display_width(s)
+        }
+    }
+
+    /* Add the line 's[start..end]' without its trailing spaces. A
+    line which is too wide is split between two characters. Return
+    the start of the last line if it is not finished. */
+    fn push(&mut self, start: usize, end: usize, is_last: bool) -> usize {
+        let line = self.s[start..end].trim_end_matches(is_trailing_space);
+        let mut line_start = 0;
+        if self.width(line) > self.max_width {
+            let mut prev_c = 0;
+            for (i, ch) in line.char_indices() {
+                let c = ch as u32;
+                let is_base = prev_c != ZWJ && unicode_char_width(c, self.is_cjk) != Some(0);
+                prev_c = c;
+                if is_base
+                    && i > line_start
+                    && self.width(&line[line_start..i + ch.len_utf8()]) > self.max_width
+                {
+                    self.lines.push(&line[line_start..i]);
+                    line_start = i;
+                }
+            }
+        }
+        if is_last {
+            self.lines.push(&line[line_start..]);
+            end
+        } else {
+            start + line_start
+        }
+    }
+}
+
+fn wrap_lines1(s: &str, max_width: usize, is_cjk: bool) -> Vec<&str> {
+    let mut w = LineWrapper {
+        s,
+        max_width,
+        is_cjk,
+        lines: Vec::new(),
+    };
+    let mut line_start = 0;
+    let mut last_break = 0;
+    let mut line_width = 0;
+    let breaks = if is_cjk {
+        line_breaks_cjk(s)
+    } else {
+        line_breaks(s)
+    };
+    for (pos, op) in breaks {
+        let segment = &s[last_break..pos];
+        if last_break > line_start
+            && line_width + w.width(segment.trim_end_matches(is_trailing_space)) > max_width
+        {
+            w.push(line_start, last_break, true);
+            line_start = last_break;
+            line_width = 0;
+        }
+        if op == BreakOpportunity::Mandatory {
+            w.push(line_start, pos, true);
+            line_start = pos;
+            line_width = 0;
+        } else if line_start == last_break {
+            /* the first segment of the line may be too wide */
+            line_start = w.push(line_start, pos, false);
+            line_width = w.width(&s[line_start..pos]);
+        } else {
+            line_width += w.width(segment);
+        }
+        last_break = pos;
+    }
+    w.lines
+}
+
+/// Split `s` into lines of at most `max_width` terminal cells, as
+/// computed by `display_width`. The lines are broken at the line
+/// break opportunities of `s`, their trailing spaces and line
+/// terminators are removed. A word which does not fit on a line is
+/// split between two characters.
+pub fn wrap_lines(s: &str, max_width: usize) -> Vec<&str> {
+    wrap_lines1(s, max_width, false)
+}
+
+/// Same as `wrap_lines` but the text is measured with
+/// `display_width_cjk` and broken as with `line_breaks_cjk`.
+pub fn wrap_lines_cjk(s: &str, max_width: usize) -> Vec<&str> {
+    wrap_lines1(s, max_width, true)
+}
@@ -0,0 +1,360 @@
+/* Compressed unicode tables */
+/* Automatically generated file - do not edit */
+
+pub type UnicodeLineBreakEnum = u32;
+pub const LB_XX: UnicodeLineBreakEnum = 0;
+pub const LB_BK: UnicodeLineBreakEnum = 1;
+pub const LB_CR: UnicodeLineBreakEnum = 2;
+pub const LB_LF: UnicodeLineBreakEnum = 3;
+pub const LB_CM: UnicodeLineBreakEnum = 4;
+pub const LB_NL: UnicodeLineBreakEnum = 5;
+pub const LB_SG: UnicodeLineBreakEnum = 6;
+pub const LB_WJ: UnicodeLineBreakEnum = 7;
+pub const LB_ZW: UnicodeLineBreakEnum = 8;
+pub const LB_GL: UnicodeLineBreakEnum = 9;
+pub const LB_SP: UnicodeLineBreakEnum = 10;
+pub const LB_ZWJ: UnicodeLineBreakEnum = 11;
+pub const LB_B2: UnicodeLineBreakEnum = 12;
+pub const LB_BA: UnicodeLineBreakEnum = 13;
+pub const LB_BB: UnicodeLineBreakEnum = 14;
+pub const LB_HY: UnicodeLineBreakEnum = 15;
+pub const LB_CB: UnicodeLineBreakEnum = 16;
+pub const LB_CL: UnicodeLineBreakEnum = 17;
+pub const LB_CP: UnicodeLineBreakEnum = 18;
+pub const LB_EX: UnicodeLineBreakEnum = 19;
+pub const LB_IN: UnicodeLineBreakEnum = 20;
+pub const LB_NS: UnicodeLineBreakEnum = 21;
+pub const LB_OP: UnicodeLineBreakEnum = 22;
+pub const LB_QU: UnicodeLineBreakEnum = 23;
+pub const LB_IS: UnicodeLineBreakEnum = 24;
+pub const LB_NU: UnicodeLineBreakEnum = 25;
+pub const LB_PO: UnicodeLineBreakEnum = 26;
+pub const LB_PR: UnicodeLineBreakEnum = 27;
+pub const LB_SY: UnicodeLineBreakEnum = 28;
+pub const LB_AI: UnicodeLineBreakEnum = 29;
+pub const LB_AL: UnicodeLineBreakEnum = 30;
+pub const LB_CJ: UnicodeLineBreakEnum = 31;
+pub const LB_EB: UnicodeLineBreakEnum = 32;
+pub const LB_EM: UnicodeLineBreakEnum = 33;
+pub const LB_H2: UnicodeLineBreakEnum = 34;
+pub const LB_H3: UnicodeLineBreakEnum = 35;
+pub const LB_HL: UnicodeLineBreakEnum = 36;
+pub const LB_ID: UnicodeLineBreakEnum = 37;
+pub const LB_JL: UnicodeLineBreakEnum = 38;
+pub const LB_JV: UnicodeLineBreakEnum = 39;
+pub const LB_JT: UnicodeLineBreakEnum = 40;
+pub const LB_RI: UnicodeLineBreakEnum = 41;
+pub const LB_SA: UnicodeLineBreakEnum = 42;
+pub const LB_COUNT: UnicodeLineBreakEnum = 43;
+
+static unicode_line_break_name_table: [u8; 613] = *b"\
+    XX,Unknown\0\
+    BK,Mandatory_Break\0\
+    CR,Carriage_Return\0\
+    LF,Line_Feed\0\
+    CM,Combining_Mark\0\
+    NL,Next_Line\0\
+    SG,Surrogate\0\
+    WJ,Word_Joiner\0\
+    ZW,ZWSpace\0\
+    GL,Glue\0\
+    SP,Space\0\
+    ZWJ\0\
+    B2,Break_Both\0\
+    BA,Break_After\0\
+    BB,Break_Before\0\
+    HY,Hyphen\0\
+    CB,Contingent_Break\0\
+    CL,Close_Punctuation\0\
+    CP,Close_Parenthesis\0\
+    EX,Exclamation\0\
+    IN,Inseparable,Inseperable\0\
+    NS,Nonstarter\0\
+    OP,Open_Punctuation\0\
+    QU,Quotation\0\
+    IS,Infix_Numeric\0\
+    NU,Numeric\0\
+    PO,Postfix_Numeric\0\
+    PR,Prefix_Numeric\0\
+    SY,Break_Symbols\0\
+    AI,Ambiguous\0\
+    AL,Alphabetic\0\
+    CJ,Conditional_Japanese_Starter\0\
+    EB,E_Base\0\
+    EM,E_Modifier\0\
+    H2\0\
+    H3\0\
+    HL,Hebrew_Letter\0\
+    ID,Ideographic\0\
+    JL\0\
+    JV\0\
+    JT\0\
+    RI,Regional_Indicator\0\
+    SA,Complex_Context\0\
+    \0";
+
+static unicode_line_break_table: [u8; 3783] = [
+    0xc4, 0x05, 0x0d, 0x03, 0x41, 0x02, 0xc4, 0x0e, 0x0a, 0x13, 0x17, 0x1e, 0x1b, 0x1a, 0x1e, 0x17,
+    0x16, 0x12, 0x1e, 0x1b, 0x18, 0x0f, 0x18, 0x1c, 0xd9, 0x06, 0x58, 0x9e, 0x13, 0xde, 0x17, 0x16,
+    0x1b, 0x12, 0xde, 0x19, 0x16, 0x0d, 0x11, 0x1e, 0xc4, 0x02, 0x05, 0xc4, 0x16, 0x09, 0x16, 0x1a,
+    0x9b, 0x1e, 0x5d, 0x1e, 0x1d, 0x17, 0x1e, 0x0d, 0x5e, 0x1a, 0x1b, 0x5d, 0x0e, 0x1e, 0xdd, 0x01,
+    0x17, 0x9d, 0x16, 0xde, 0x13, 0x1d, 0xde, 0x1b, 0x1d, 0xde, 0x81, 0x4b, 0x1d, 0x0e, 0x9d, 0x0e,
+    0x1d, 0x5e, 0x1d, 0xde, 0x03, 0xdd, 0x00, 0x1e, 0x1d, 0x1e, 0x0e, 0xde, 0x1c, 0xc4, 0x4b, 0x09,
+    0xc4, 0x08, 0xc9, 0x03, 0xc4, 0x09, 0xde, 0x04, 0x40, 0xde, 0x00, 0x18, 0x1e, 0xc0, 0x00, 0xde,
+    0x03, 0x00, 0x1e, 0x00, 0xde, 0x10, 0x00, 0xde, 0x80, 0x5c, 0xc4, 0x03, 0xde, 0x80, 0x22, 0x00,
+    0xde, 0x22, 0x40, 0xde, 0x2c, 0x18, 0x0d, 0x40, 0x5e, 0x1b, 0x00, 0xc4, 0x29, 0x0d, 0x04, 0x1e,
+    0x44, 0x1e, 0x44, 0x13, 0x04, 0xc0, 0x04, 0xe4, 0x17, 0xc0, 0x00, 0xe4, 0x00, 0x5e, 0xc0, 0x07,
+    0xde, 0x05, 0x9a, 0x58, 0x5e, 0xc4, 0x07, 0x13, 0x04, 0x00, 0x53, 0xde, 0x27, 0xc4, 0x11, 0xd9,
+    0x06, 0x1a, 0x59, 0x9e, 0x04, 0xde, 0x5f, 0x13, 0x1e, 0xc4, 0x03, 0x5e, 0xc4, 0x02, 0x5e, 0x44,
+    0x1e, 0xc4, 0x00, 0x5e, 0xd9, 0x06, 0xde, 0x10, 0x00, 0x5e, 0x04, 0xde, 0x1a, 0xc4, 0x17, 0x40,
+    0xde, 0x55, 0xc4, 0x07, 0x1e, 0xc0, 0x0a, 0xd9, 0x06, 0xde, 0x1d, 0xc4, 0x05, 0xde, 0x00, 0x18,
+    0x13, 0x1e, 0x40, 0x04, 0x5b, 0xde, 0x12, 0xc4, 0x00, 0x1e, 0xc4, 0x05, 0x1e, 0x84, 0x1e, 0xc4,
+    0x01, 0x40, 0xde, 0x0b, 0x00, 0xde, 0x15, 0x84, 0x40, 0x1e, 0x00, 0xde, 0x07, 0xc0, 0x31, 0xde,
+    0x11, 0x00, 0xde, 0x0e, 0xc0, 0x07, 0xc4, 0x0b, 0x1e, 0xc4, 0x1d, 0xde, 0x32, 0x84, 0x1e, 0xc4,
+    0x0e, 0x1e, 0xc4, 0x03, 0xde, 0x06, 0x44, 0x4d, 0xd9, 0x06, 0xde, 0x0d, 0x84, 0x00, 0xde, 0x04,
+    0x40, 0x5e, 0x40, 0xde, 0x12, 0x00, 0xde, 0x03, 0x00, 0x1e, 0x80, 0xde, 0x00, 0x40, 0x04, 0x1e,
+    0xc4, 0x03, 0x40, 0x44, 0x40, 0x84, 0x1e, 0xc0, 0x04, 0x04, 0xc0, 0x00, 0x5e, 0x00, 0x9e, 0x44,
+    0x40, 0xd9, 0x06, 0x5e, 0x5a, 0xde, 0x01, 0x1a, 0x1e, 0x1b, 0x5e, 0x04, 0x40, 0x84, 0x00, 0xde,
+    0x02, 0xc0, 0x00, 0x5e, 0x40, 0xde, 0x12, 0x00, 0xde, 0x03, 0x00, 0x5e, 0x00, 0x5e, 0x00, 0x5e,
+    0x40, 0x04, 0x00, 0xc4, 0x01, 0xc0, 0x00, 0x44, 0x40, 0x84, 0x80, 0x04, 0xc0, 0x03, 0xde, 0x00,
+    0x00, 0x1e, 0xc0, 0x03, 0xd9, 0x06, 0x44, 0x9e, 0x04, 0x1e, 0xc0, 0x06, 0x84, 0x00, 0xde, 0x05,
+    0x00, 0x9e, 0x00, 0xde, 0x12, 0x00, 0xde, 0x03, 0x00, 0x5e, 0x00, 0xde, 0x01, 0x40, 0x04, 0x1e,
+    0xc4, 0x04, 0x00, 0x84, 0x00, 0x84, 0x40, 0x1e, 0xc0, 0x0b, 0x5e, 0x44, 0x40, 0xd9, 0x06, 0x1e,
+    0x1b, 0xc0, 0x03, 0x1e, 0xc4, 0x02, 0x00, 0x84, 0x00, 0xde, 0x04, 0x40, 0x5e, 0x40, 0xde, 0x12,
+    0x00, 0xde, 0x03, 0x00, 0x5e, 0x00, 0xde, 0x01, 0x40, 0x04, 0x1e, 0xc4, 0x03, 0x40, 0x44, 0x40,
+    0x84, 0xc0, 0x03, 0x84, 0xc0, 0x00, 0x5e, 0x00, 0x9e, 0x44, 0x40, 0xd9, 0x06, 0xde, 0x04, 0xc0,
+    0x06, 0x04, 0x1e, 0x00, 0xde, 0x02, 0x80, 0x9e, 0x00, 0xde, 0x00, 0x80, 0x5e, 0x00, 0x1e, 0x00,
+    0x5e, 0x80, 0x5e, 0x80, 0x9e, 0x80, 0xde, 0x08, 0xc0, 0x00, 0xc4, 0x01, 0x80, 0x84, 0x00, 0xc4,
+    0x00, 0x40, 0x1e, 0xc0, 0x02, 0x04, 0xc0, 0x0a, 0xd9, 0x06, 0xde, 0x05, 0x1b, 0x1e, 0xc0, 0x01,
+    0xc4, 0x01, 0xde, 0x04, 0x00, 0x9e, 0x00, 0xde, 0x13, 0x00, 0xde, 0x0c, 0x80, 0x1e, 0xc4, 0x03,
+    0x00, 0x84, 0x00, 0xc4, 0x00, 0xc0, 0x03, 0x44, 0x00, 0x9e, 0xc0, 0x01, 0x5e, 0x44, 0x40, 0xd9,
+    0x06, 0xc0, 0x03, 0x0e, 0xde, 0x05, 0x84, 0x0e, 0xde, 0x04, 0x00, 0x9e, 0x00, 0xde, 0x13, 0x00,
+    0xde, 0x06, 0x00, 0xde, 0x01, 0x40, 0x04, 0x1e, 0xc4, 0x03, 0x00, 0x84, 0x00, 0xc4, 0x00, 0xc0,
+    0x03, 0x44, 0xc0, 0x03, 0x1e, 0x00, 0x5e, 0x44, 0x40, 0xd9, 0x06, 0x00, 0x5e, 0xc0, 0x09, 0xc4,
+    0x00, 0xde, 0x05, 0x00, 0x9e, 0x00, 0xde, 0x25, 0x44, 0x1e, 0xc4, 0x03, 0x00, 0x84, 0x00, 0xc4,
+    0x00, 0x5e, 0xc0, 0x00, 0x9e, 0x04, 0xde, 0x06, 0x44, 0x40, 0xd9, 0x06, 0xde, 0x05, 0x1a, 0xde,
+    0x02, 0x00, 0x84, 0x00, 0xde, 0x0e, 0x80, 0xde, 0x14, 0x00, 0xde, 0x05, 0x00, 0x1e, 0x40, 0xde,
+    0x03, 0x80, 0x04, 0xc0, 0x00, 0xc4, 0x02, 0x00, 0x04, 0x00, 0xc4, 0x04, 0xc0, 0x02, 0xd9, 0x06,
+    0x40, 0x44, 0x1e, 0xc0, 0x08, 0xea, 0x36, 0xc0, 0x00, 0x1b, 0xea, 0x0b, 0x1e, 0xd9, 0x06, 0x4d,
+    0xc0, 0x21, 0x6a, 0x00, 0x2a, 0x00, 0xea, 0x01, 0x00, 0xea, 0x14, 0x00, 0x2a, 0x00, 0xea, 0x13,
+    0x40, 0xea, 0x01, 0x00, 0x2a, 0x00, 0xea, 0x02, 0x40, 0xd9, 0x06, 0x40, 0xea, 0x00, 0xc0, 0x1c,
+    0x1e, 0xce, 0x00, 0x1e, 0x4e, 0x09, 0x4e, 0x0d, 0x09, 0xd3, 0x01, 0x09, 0x1e, 0x13, 0x9e, 0x44,
+    0xde, 0x02, 0xd9, 0x06, 0xde, 0x06, 0x0d, 0x04, 0x1e, 0x04, 0x1e, 0x04, 0x16, 0x11, 0x16, 0x11,
+    0x44, 0xde, 0x04, 0x00, 0xde, 0x20, 0xc0, 0x00, 0xc4, 0x0a, 0x0d, 0xc4, 0x01, 0x0d, 0x44, 0xde,
+    0x01, 0xc4, 0x07, 0x00, 0xc4, 0x20, 0x00, 0x4d, 0xde, 0x02, 0x04, 0xde, 0x02, 0x00, 0x5e, 0x4e,
+    0x0d, 0x0e, 0xde, 0x01, 0x49, 0xc0, 0x21, 0xea, 0x3c, 0xd9, 0x06, 0x4d, 0xde, 0x00, 0xea, 0x3c,
+    0xd9, 0x06, 0xea, 0x02, 0xde, 0x22, 0x00, 0x1e, 0xc0, 0x01, 0x1e, 0x40, 0xde, 0x2c, 0xe6, 0x5c,
+    0xe7, 0x44, 0xe8, 0x54, 0xde, 0x45, 0x00, 0xde, 0x00, 0x40, 0xde, 0x03, 0x00, 0x1e, 0x00, 0xde,
+    0x00, 0x40, 0xde, 0x25, 0x00, 0xde, 0x00, 0x40, 0xde, 0x1d, 0x00, 0xde, 0x00, 0x40, 0xde, 0x03,
+    0x00, 0x1e, 0x00, 0xde, 0x00, 0x40, 0xde, 0x0b, 0x00, 0xde, 0x35, 0x00, 0xde, 0x00, 0x40, 0xde,
+    0x3f, 0x40, 0x84, 0x1e, 0x0d, 0xde, 0x17, 0x80, 0xde, 0x16, 0xc0, 0x02, 0xde, 0x52, 0x40, 0xde,
+    0x02, 0x40, 0x0d, 0xde, 0x81, 0xfb, 0x0d, 0xde, 0x16, 0x16, 0x11, 0x80, 0xde, 0x47, 0x8d, 0xde,
+    0x07, 0xc0, 0x03, 0xde, 0x09, 0x00, 0xde, 0x00, 0x84, 0xc0, 0x07, 0xde, 0x0e, 0x84, 0x4d, 0xc0,
+    0x05, 0xde, 0x0e, 0x44, 0xc0, 0x08, 0xde, 0x09, 0x00, 0x9e, 0x00, 0x44, 0xc0, 0x08, 0xea, 0x50,
+    0x4d, 0x15, 0x2a, 0x0d, 0x1e, 0x0d, 0x1b, 0x6a, 0x40, 0xd9, 0x06, 0xc0, 0x02, 0xde, 0x06, 0xc0,
+    0x02, 0x5e, 0x53, 0x4d, 0x0e, 0x1e, 0x53, 0x1e, 0x84, 0x09, 0x00, 0xd9, 0x06, 0xc0, 0x02, 0xde,
+    0x55, 0xc0, 0x03, 0xde, 0x01, 0x44, 0xde, 0x1e, 0x04, 0x1e, 0xc0, 0x01, 0xde, 0x42, 0xc0, 0x06,
+    0xde, 0x1b, 0x00, 0xc4, 0x08, 0xc0, 0x00, 0xc4, 0x08, 0xc0, 0x00, 0x1e, 0x80, 0x53, 0xd9, 0x06,
+    0xea, 0x1a, 0x40, 0xea, 0x01, 0xc0, 0x07, 0xea, 0x28, 0xc0, 0x00, 0xea, 0x16, 0xc0, 0x02, 0xd9,
+    0x06, 0x2a, 0x80, 0x6a, 0xde, 0x33, 0xc4, 0x01, 0x40, 0x5e, 0xea, 0x3b, 0x00, 0xea, 0x19, 0x40,
+    0x04, 0xd9, 0x06, 0xc0, 0x02, 0xd9, 0x06, 0xc0, 0x02, 0xea, 0x0a, 0x40, 0xc4, 0x0d, 0xc0, 0x3b,
+    0xc4, 0x01, 0xde, 0x2b, 0xc4, 0x0d, 0xde, 0x03, 0xc0, 0x00, 0xd9, 0x06, 0x4d, 0x1e, 0xcd, 0x00,
+    0xde, 0x06, 0xc4, 0x05, 0xde, 0x05, 0x80, 0x84, 0xde, 0x1a, 0xc4, 0x09, 0x5e, 0xd9, 0x06, 0xde,
+    0x28, 0xc4, 0x0a, 0xc0, 0x04, 0xde, 0x24, 0xc4, 0x10, 0x80, 0xcd, 0x01, 0xd9, 0x06, 0x80, 0x9e,
+    0xd9, 0x06, 0xde, 0x20, 0x4d, 0xde, 0x05, 0xc0, 0x03, 0xde, 0x27, 0x40, 0xde, 0x07, 0xc0, 0x04,
+    0x84, 0x1e, 0xc4, 0x11, 0xde, 0x00, 0x04, 0xde, 0x02, 0x04, 0x5e, 0x84, 0x1e, 0xc0, 0x01, 0xde,
+    0x80, 0x3c, 0xc4, 0x36, 0x00, 0xc4, 0x01, 0xde, 0x80, 0x92, 0x40, 0xde, 0x02, 0x40, 0xde, 0x22,
+    0x40, 0xde, 0x02, 0x40, 0xde, 0x04, 0x00, 0x1e, 0x00, 0x1e, 0x00, 0x1e, 0x00, 0xde, 0x1b, 0x40,
+    0xde, 0x31, 0x00, 0xde, 0x0b, 0x00, 0xde, 0x0a, 0x40, 0xde, 0x02, 0x00, 0xde, 0x0f, 0x40, 0x9e,
+    0x00, 0xde, 0x03, 0x0e, 0x1e, 0x00, 0xcd, 0x03, 0x09, 0x8d, 0x08, 0x04, 0x0b, 0x44, 0x0d, 0x09,
+    0x4d, 0x0c, 0x5d, 0x1e, 0x57, 0x16, 0x97, 0x16, 0x17, 0x5d, 0x5e, 0x94, 0x0d, 0x41, 0xc4, 0x01,
+    0x09, 0xda, 0x04, 0x1e, 0x57, 0x1d, 0x55, 0xde, 0x02, 0x18, 0x16, 0x11, 0x95, 0xde, 0x08, 0x0d,
+    0x1e, 0xcd, 0x00, 0x1e, 0x8d, 0x07, 0xde, 0x00, 0x00, 0xc4, 0x06, 0x5e, 0x40, 0x1d, 0xde, 0x04,
+    0x16, 0x11, 0x1d, 0x1e, 0xdd, 0x00, 0xde, 0x04, 0x16, 0x11, 0x00, 0xde, 0x09, 0x80, 0xdb, 0x03,
+    0x1a, 0xdb, 0x0a, 0x1a, 0xdb, 0x00, 0x1a, 0x5b, 0x1a, 0xdb, 0x0d, 0xc4, 0x1d, 0xc0, 0x0b, 0x9e,
+    0x1a, 0x1e, 0x1d, 0x9e, 0x1a, 0xde, 0x05, 0x1d, 0x5e, 0x1b, 0xde, 0x06, 0x5d, 0xde, 0x04, 0x1d,
+    0xde, 0x24, 0x5d, 0xde, 0x01, 0x1d, 0x5e, 0x1d, 0x1e, 0xdd, 0x08, 0xde, 0x00, 0xdd, 0x06, 0xde,
+    0x0b, 0x1d, 0x5e, 0xc0, 0x00, 0xdd, 0x06, 0xde, 0x34, 0x1d, 0x1e, 0x1d, 0xde, 0x27, 0x1d, 0x1e,
+    0x5d, 0x9e, 0x5d, 0x5e, 0x1d, 0x9e, 0x1d, 0x1e, 0x1d, 0x5b, 0x1e, 0x1d, 0xde, 0x00, 0x1d, 0x5e,
+    0xdd, 0x00, 0x5e, 0x1d, 0x1e, 0x1d, 0x1e, 0xdd, 0x02, 0x1e, 0x1d, 0xde, 0x01, 0xdd, 0x00, 0xde,
+    0x00, 0x5d, 0xde, 0x06, 0x1d, 0x9e, 0x1d, 0xde, 0x01, 0x1d, 0xde, 0x09, 0x5d, 0x5e, 0xdd, 0x00,
+    0x5e, 0x5d, 0x5e, 0x5d, 0xde, 0x0e, 0x5d, 0x5e, 0x5d, 0xde, 0x09, 0x1d, 0x9e, 0x1d, 0xde, 0x07,
+    0x1d, 0xde, 0x15, 0x1d, 0xde, 0x2b, 0x14, 0xde, 0x14, 0x16, 0x11, 0x16, 0x11, 0xde, 0x02, 0x1d,
+    0xde, 0x03, 0x65, 0xde, 0x09, 0x16, 0x11, 0xde, 0x80, 0x41, 0xe5, 0x00, 0xde, 0x2f, 0xc0, 0x15,
+    0xde, 0x07, 0xc0, 0x11, 0xdd, 0x80, 0x1b, 0x1e, 0xdd, 0x48, 0xde, 0x00, 0xdd, 0x21, 0xde, 0x07,
+    0xdd, 0x0c, 0x5e, 0xdd, 0x00, 0xde, 0x06, 0x5d, 0x1e, 0xdd, 0x03, 0xde, 0x04, 0x5d, 0x5e, 0x5d,
+    0xde, 0x00, 0x5d, 0x5e, 0x5d, 0xde, 0x00, 0x9d, 0x5e, 0x1d, 0x5e, 0xdd, 0x00, 0xde, 0x0c, 0xdd,
+    0x00, 0xde, 0x05, 0x1d, 0xde, 0x0c, 0xe5, 0x00, 0x1e, 0x5d, 0x5e, 0x1d, 0xde, 0x00, 0x5d, 0xde,
+    0x00, 0x65, 0x5d, 0x25, 0x1e, 0xa5, 0x20, 0x65, 0xde, 0x15, 0xa5, 0xde, 0x00, 0x1d, 0x1e, 0x1d,
+    0xde, 0x19, 0x5d, 0x1e, 0x9d, 0x1e, 0x1d, 0x25, 0x5d, 0x1e, 0x5d, 0x1e, 0x1d, 0xde, 0x0b, 0x25,
+    0xde, 0x1a, 0x5d, 0xde, 0x19, 0xe5, 0x08, 0xdd, 0x00, 0x25, 0x1e, 0xa5, 0x1d, 0x65, 0x9d, 0x65,
+    0x5d, 0x25, 0x5d, 0xa5, 0x1e, 0x1d, 0xde, 0x00, 0x5d, 0x25, 0xdd, 0x02, 0xe5, 0x01, 0x1d, 0x65,
+    0x20, 0x25, 0x5d, 0xe5, 0x04, 0x9e, 0x65, 0xe0, 0x00, 0xde, 0x45, 0x1d, 0x9e, 0xd7, 0x02, 0x1e,
+    0x53, 0x25, 0x9e, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16,
+    0x11, 0xdd, 0x1a, 0xde, 0x2d, 0x16, 0x11, 0xde, 0x1b, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16,
+    0x11, 0x16, 0x11, 0xde, 0x81, 0x0f, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11,
+    0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0xde, 0x3b, 0x16, 0x11,
+    0x16, 0x11, 0xde, 0x1c, 0x16, 0x11, 0xde, 0x80, 0xd3, 0xdd, 0x01, 0xde, 0x16, 0x40, 0xde, 0x1c,
+    0x00, 0xde, 0x80, 0x14, 0x00, 0xde, 0x2b, 0x00, 0xde, 0x80, 0x0b, 0x84, 0x5e, 0xc0, 0x01, 0x13,
+    0x8d, 0x1e, 0x13, 0x0d, 0xde, 0x22, 0x00, 0x1e, 0xc0, 0x01, 0x1e, 0x40, 0xde, 0x34, 0xc0, 0x03,
+    0x1e, 0x0d, 0xc0, 0x0a, 0x04, 0xde, 0x13, 0xc0, 0x05, 0xde, 0x03, 0x00, 0xde, 0x03, 0x00, 0xde,
+    0x03, 0x00, 0xde, 0x03, 0x00, 0xde, 0x03, 0x00, 0xde, 0x03, 0x00, 0xde, 0x03, 0x00, 0xde, 0x03,
+    0x00, 0xc4, 0x1c, 0xd7, 0x0a, 0xcd, 0x04, 0x1e, 0x0d, 0x16, 0x0d, 0x5e, 0x57, 0x5e, 0x57, 0x16,
+    0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0xcd, 0x00, 0x13, 0x1e, 0x4d, 0x1e, 0x4d, 0xde, 0x01,
+    0x4c, 0x8d, 0x1e, 0x4d, 0x16, 0xcd, 0x04, 0x1e, 0x0d, 0x1e, 0x4d, 0x9e, 0xc0, 0x29, 0xe5, 0x16,
+    0x00, 0xe5, 0x55, 0xc0, 0x08, 0xe5, 0x80, 0x52, 0xc0, 0x16, 0xe5, 0x08, 0xc0, 0x00, 0x0d, 0x51,
+    0x65, 0x15, 0x65, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x65, 0x16, 0x11,
+    0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x15, 0x16, 0x51, 0xe5, 0x06, 0xc4, 0x02, 0xe5, 0x01, 0x04,
+    0xe5, 0x01, 0x55, 0xa5, 0x00, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0xe5, 0x15,
+    0x1f, 0xe5, 0x1b, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0xe5, 0x02, 0x1f, 0xe5, 0x02, 0x5f, 0x40, 0x44,
+    0xd5, 0x00, 0x25, 0x15, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0xe5, 0x15, 0x1f,
+    0xe5, 0x1b, 0x1f, 0x25, 0x1f, 0x25, 0x1f, 0xe5, 0x02, 0x1f, 0xe5, 0x02, 0x5f, 0xe5, 0x00, 0x15,
+    0x1f, 0x55, 0x25, 0xc0, 0x01, 0xe5, 0x27, 0x00, 0xe5, 0x5a, 0x00, 0xe5, 0x50, 0xc0, 0x08, 0xdf,
+    0x0c, 0xe5, 0x1b, 0x00, 0xe5, 0x24, 0xdd, 0x04, 0xe5, 0x9a, 0xec, 0xde, 0x3c, 0xe5, 0xc0, 0x11,
+    0x91, 0x15, 0xe5, 0x83, 0xf3, 0x80, 0xe5, 0x33, 0xc0, 0x05, 0xde, 0x2a, 0x4d, 0xde, 0x80, 0x89,
+    0x0d, 0x13, 0x0d, 0xde, 0x0c, 0xd9, 0x06, 0x5e, 0xc0, 0x10, 0xde, 0x2b, 0xc4, 0x00, 0x1e, 0xc4,
+    0x06, 0xde, 0x1c, 0x44, 0xde, 0x4c, 0x44, 0x1e, 0xcd, 0x01, 0xc0, 0x04, 0xde, 0x80, 0x3c, 0x40,
+    0xde, 0x05, 0xc0, 0x26, 0xde, 0x09, 0x04, 0x9e, 0x04, 0xde, 0x00, 0x04, 0xde, 0x13, 0xc4, 0x01,
+    0xde, 0x00, 0x04, 0x80, 0xde, 0x04, 0x1a, 0x1e, 0xc0, 0x02, 0xde, 0x30, 0x4e, 0x53, 0xc0, 0x04,
+    0x44, 0xde, 0x2e, 0xc4, 0x0e, 0xc0, 0x04, 0x4d, 0xd9, 0x06, 0xc0, 0x02, 0xc4, 0x0e, 0xde, 0x06,
+    0x0e, 0x5e, 0x04, 0xd9, 0x06, 0xde, 0x18, 0xc4, 0x04, 0x4d, 0xde, 0x13, 0xc4, 0x09, 0xc0, 0x07,
+    0x1e, 0xe6, 0x19, 0x80, 0xc4, 0x00, 0xde, 0x2b, 0xc4, 0x0a, 0xde, 0x02, 0x8d, 0xde, 0x00, 0x00,
+    0x1e, 0xd9, 0x06, 0xc0, 0x00, 0x5e, 0xea, 0x0c, 0xd9, 0x06, 0xea, 0x01, 0x00, 0xde, 0x25, 0xc4,
+    0x0a, 0xc0, 0x05, 0x9e, 0x04, 0xde, 0x04, 0x44, 0x40, 0xd9, 0x06, 0x40, 0x1e, 0x8d, 0xea, 0x5f,
+    0xc0, 0x14, 0xea, 0x01, 0xde, 0x07, 0xc4, 0x01, 0x4d, 0x9e, 0x44, 0xc0, 0x06, 0xde, 0x02, 0x40,
+    0xde, 0x02, 0x40, 0xde, 0x02, 0xc0, 0x05, 0xde, 0x03, 0x00, 0xde, 0x03, 0x00, 0xde, 0x38, 0xc0,
+    0x00, 0xde, 0x6f, 0xc4, 0x04, 0x0d, 0x44, 0x40, 0xd9, 0x06, 0xc0, 0x02, 0xe3, 0xab, 0x20, 0xc0,
+    0x08, 0xe7, 0x13, 0xc0, 0x00, 0xe8, 0x2d, 0xc0, 0x00, 0xc6, 0x87, 0x7c, 0xc0, 0x98, 0x7c, 0xe5,
+    0x81, 0x7c, 0xde, 0x03, 0xc0, 0x08, 0xde, 0x01, 0xc0, 0x01, 0x24, 0x04, 0xe4, 0x06, 0x1e, 0xe4,
+    0x09, 0x00, 0xe4, 0x01, 0x00, 0x24, 0x00, 0x64, 0x00, 0x64, 0x00, 0xe4, 0x06, 0xde, 0x6e, 0xc0,
+    0x0d, 0xde, 0x80, 0xe7, 0x11, 0x16, 0xc0, 0x0c, 0xde, 0x3c, 0x40, 0xde, 0x32, 0xc0, 0x24, 0xde,
+    0x08, 0x1a, 0x1e, 0x40, 0xc4, 0x0c, 0x18, 0x51, 0x58, 0x53, 0x16, 0x11, 0x14, 0xc0, 0x02, 0xc4,
+    0x0c, 0xe5, 0x01, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16, 0x11, 0x16,
+    0x11, 0x16, 0x11, 0x65, 0x16, 0x11, 0xe5, 0x03, 0x11, 0x25, 0x11, 0x00, 0x55, 0x53, 0x25, 0x16,
+    0x11, 0x16, 0x11, 0x16, 0x11, 0xe5, 0x04, 0x00, 0x25, 0x1b, 0x1a, 0x25, 0xc0, 0x00, 0xde, 0x01,
+    0x00, 0xde, 0x80, 0x03, 0x40, 0x07, 0x00, 0x13, 0x65, 0x1b, 0x1a, 0x65, 0x16, 0x11, 0x65, 0x11,
+    0x25, 0x11, 0xe5, 0x07, 0x55, 0xa5, 0x13, 0xe5, 0x17, 0x16, 0x25, 0x11, 0xe5, 0x19, 0x16, 0x25,
+    0x11, 0x25, 0x16, 0x51, 0x16, 0x51, 0x15, 0x25, 0xdf, 0x06, 0xe5, 0x29, 0x55, 0xe5, 0x1b, 0x80,
+    0xe5, 0x02, 0x40, 0xe5, 0x02, 0x40, 0xe5, 0x02, 0x40, 0xa5, 0x80, 0x1a, 0x1b, 0xa5, 0x5b, 0x00,
+    0xde, 0x03, 0xc0, 0x06, 0x84, 0x10, 0x1d, 0x40, 0xde, 0x08, 0x00, 0xde, 0x16, 0x00, 0xde, 0x0f,
+    0x00, 0x5e, 0x00, 0xde, 0x0b, 0x40, 0xde, 0x0a, 0xc0, 0x1e, 0xde, 0x77, 0xc0, 0x01, 0x8d, 0xc0,
+    0x00, 0xde, 0x29, 0x80, 0xde, 0x54, 0x00, 0xde, 0x09, 0x80, 0x1e, 0xc0, 0x2b, 0xde, 0x29, 0x04,
+    0xc0, 0x7e, 0xde, 0x19, 0x80, 0xde, 0x2d, 0xc0, 0x0b, 0x04, 0xde, 0x17, 0xc0, 0x00, 0xde, 0x20,
+    0xc0, 0x05, 0xde, 0x1a, 0xc0, 0x01, 0xde, 0x22, 0xc4, 0x01, 0xc0, 0x01, 0xde, 0x1a, 0x00, 0x0d,
+    0xde, 0x20, 0xc0, 0x00, 0xde, 0x04, 0x0d, 0xde, 0x01, 0xc0, 0x26, 0xde, 0x80, 0x1a, 0x40, 0xd9,
+    0x06, 0xc0, 0x02, 0xde, 0x20, 0xc0, 0x00, 0xde, 0x20, 0xc0, 0x00, 0xde, 0x24, 0xc0, 0x04, 0xde,
+    0x30, 0xc0, 0x07, 0x1e, 0xc0, 0x80, 0x0c, 0xde, 0x80, 0xb3, 0xc0, 0x05, 0xde, 0x12, 0xc0, 0x06,
+    0xde, 0x04, 0xc0, 0x80, 0x14, 0xde, 0x02, 0x40, 0x1e, 0x00, 0xde, 0x28, 0x00, 0x5e, 0x80, 0x1e,
+    0x40, 0xde, 0x13, 0x00, 0x0d, 0xde, 0x43, 0xc0, 0x04, 0xde, 0x05, 0xc0, 0x2c, 0xde, 0x0f, 0x00,
+    0x5e, 0xc0, 0x01, 0xde, 0x1d, 0x80, 0x0d, 0xde, 0x16, 0xc0, 0x01, 0x1e, 0xc0, 0x3c, 0xde, 0x34,
+    0xc0, 0x00, 0xde, 0x10, 0x40, 0xde, 0x2b, 0x84, 0x00, 0x44, 0xc0, 0x01, 0xc4, 0x00, 0xde, 0x00,
+    0x00, 0x9e, 0x00, 0xde, 0x19, 0x40, 0x84, 0xc0, 0x00, 0x04, 0xde, 0x05, 0xc0, 0x03, 0xcd, 0x04,
+    0x1e, 0xc0, 0x03, 0xde, 0x3c, 0xc0, 0x1c, 0xde, 0x21, 0x44, 0xc0, 0x00, 0xde, 0x01, 0xcd, 0x02,
+    0x14, 0xc0, 0x05, 0xde, 0x32, 0x80, 0xcd, 0x03, 0xde, 0x12, 0x40, 0xde, 0x17, 0xc0, 0x01, 0xde,
+    0x16, 0xc0, 0x03, 0xde, 0x00, 0xc0, 0x08, 0xde, 0x03, 0xc0, 0x4c, 0xde, 0x45, 0xc0, 0x33, 0xde,
+    0x2f, 0xc0, 0x09, 0xde, 0x2f, 0xc0, 0x03, 0xde, 0x26, 0xc4, 0x00, 0xc0, 0x04, 0xd9, 0x06, 0xc0,
+    0x80, 0xa2, 0xde, 0x1b, 0x00, 0xde, 0x26, 0x00, 0x44, 0x0d, 0x40, 0x5e, 0xc0, 0x4a, 0xde, 0x24,
+    0xc0, 0x04, 0xde, 0x12, 0xc4, 0x07, 0xde, 0x05, 0xc0, 0x52, 0xde, 0x18, 0xc0, 0x10, 0xde, 0x13,
+    0xc0, 0x05, 0x84, 0xde, 0x31, 0xc4, 0x0b, 0x4d, 0xde, 0x01, 0xc0, 0x00, 0xde, 0x10, 0xd9, 0x06,
+    0xc0, 0x0b, 0xc4, 0x00, 0xde, 0x29, 0xc4, 0x07, 0x9e, 0xcd, 0x00, 0xc0, 0x07, 0x1e, 0x40, 0xde,
+    0x15, 0xc0, 0x03, 0xd9, 0x06, 0xc0, 0x02, 0x84, 0xde, 0x20, 0xc4, 0x0a, 0x00, 0xd9, 0x06, 0xcd,
+    0x00, 0x1e, 0x44, 0x1e, 0xc0, 0x04, 0xde, 0x1f, 0x04, 0x1e, 0x0e, 0x1e, 0xc0, 0x05, 0x84, 0xde,
+    0x2c, 0xc4, 0x0a, 0xde, 0x00, 0x4d, 0x1e, 0x0d, 0xc4, 0x00, 0x1e, 0x44, 0xd9, 0x06, 0x1e, 0x0e,
+    0x1e, 0x8d, 0x00, 0xde, 0x10, 0xc0, 0x07, 0xde, 0x0e, 0x00, 0xde, 0x15, 0xc4, 0x08, 0x4d, 0x1e,
+    0x4d, 0x1e, 0x04, 0xc0, 0x3d, 0xde, 0x03, 0x00, 0x1e, 0x00, 0xde, 0x00, 0x00, 0xde, 0x0b, 0x00,
+    0xde, 0x06, 0x0d, 0xc0, 0x02, 0xde, 0x2b, 0xc4, 0x08, 0xc0, 0x01, 0xd9, 0x06, 0xc0, 0x02, 0xc4,
+    0x00, 0x00, 0xde, 0x04, 0x40, 0x5e, 0x40, 0xde, 0x12, 0x00, 0xde, 0x03, 0x00, 0x5e, 0x00, 0xde,
+    0x01, 0x00, 0x44, 0x1e, 0xc4, 0x03, 0x40, 0x44, 0x40, 0x84, 0x40, 0x1e, 0xc0, 0x02, 0x04, 0xc0,
+    0x01, 0xde, 0x01, 0x44, 0x40, 0xc4, 0x03, 0x80, 0xc4, 0x01, 0xc0, 0x80, 0x07, 0xde, 0x31, 0xc4,
+    0x0e, 0xde, 0x00, 0xcd, 0x00, 0x1e, 0xd9, 0x06, 0x4d, 0x00, 0x1e, 0x04, 0x9e, 0xc0, 0x1a, 0xde,
+    0x2c, 0xc4, 0x10, 0xde, 0x00, 0xc0, 0x04, 0xd9, 0x06, 0xc0, 0x80, 0x22, 0xde, 0x2b, 0xc4, 0x03,
+    0x40, 0xc4, 0x05, 0x0e, 0x4d, 0x53, 0x9e, 0xcd, 0x0b, 0xde, 0x00, 0x44, 0xc0, 0x1e, 0xde, 0x2c,
+    0xc4, 0x0d, 0x4d, 0x5e, 0xc0, 0x07, 0xd9, 0x06, 0xc0, 0x02, 0xce, 0x09, 0xc0, 0x0f, 0xde, 0x27,
+    0xc4, 0x09, 0x1e, 0xc0, 0x03, 0xd9, 0x06, 0xc0, 0x32, 0xea, 0x17, 0x40, 0xea, 0x0b, 0xc0, 0x00,
+    0xd9, 0x06, 0x6a, 0x8d, 0x2a, 0xc0, 0x80, 0x3c, 0xde, 0x28, 0xc4, 0x0b, 0x1e, 0xc0, 0x60, 0xde,
+    0x3c, 0xd9, 0x06, 0xde, 0x05, 0xc0, 0x08, 0xde, 0x04, 0x40, 0x1e, 0x40, 0xde, 0x04, 0x00, 0x5e,
+    0x00, 0xde, 0x14, 0xc4, 0x02, 0x00, 0x44, 0x40, 0xc4, 0x00, 0x1e, 0x04, 0x1e, 0x44, 0x8d, 0xc0,
+    0x05, 0xd9, 0x06, 0xc0, 0x42, 0xde, 0x04, 0x40, 0xde, 0x23, 0xc4, 0x03, 0x40, 0xc4, 0x03, 0x1e,
+    0x0e, 0x1e, 0x04, 0xc0, 0x17, 0x1e, 0xc4, 0x06, 0xde, 0x24, 0xc4, 0x03, 0x1e, 0xc4, 0x00, 0x0e,
+    0x1e, 0xcd, 0x00, 0x0e, 0x1e, 0x04, 0xc0, 0x04, 0x1e, 0xc4, 0x07, 0xde, 0x2a, 0xc4, 0x0c, 0x8d,
+    0x1e, 0x8e, 0x4d, 0xc0, 0x19, 0xde, 0x35, 0xc0, 0x80, 0x83, 0xde, 0x05, 0x00, 0xde, 0x21, 0xc4,
+    0x04, 0x00, 0xc4, 0x04, 0x1e, 0xcd, 0x01, 0xc0, 0x06, 0xd9, 0x06, 0xde, 0x0f, 0x80, 0x0e, 0x13,
+    0xde, 0x1a, 0x40, 0xc4, 0x12, 0x00, 0xc4, 0x0a, 0xc0, 0x45, 0xde, 0x03, 0x00, 0x5e, 0x00, 0xde,
+    0x22, 0xc4, 0x02, 0x80, 0x04, 0x00, 0x44, 0x00, 0xc4, 0x03, 0x1e, 0x04, 0xc0, 0x04, 0xd9, 0x06,
+    0xc0, 0x02, 0xde, 0x02, 0x00, 0x5e, 0x00, 0xde, 0x1c, 0xc4, 0x01, 0x00, 0x44, 0x00, 0xc4, 0x01,
+    0x1e, 0xc0, 0x03, 0xd9, 0x06, 0xc0, 0x80, 0xb2, 0xde, 0x0f, 0xc4, 0x00, 0x5e, 0xc0, 0x80, 0x33,
+    0x1e, 0xc0, 0x0b, 0xde, 0x19, 0xda, 0x00, 0xde, 0x0d, 0xc0, 0x09, 0x0d, 0xde, 0x83, 0x16, 0xc0,
+    0x62, 0xde, 0x6b, 0x00, 0xcd, 0x01, 0xc0, 0x07, 0xde, 0x80, 0x40, 0xc0, 0x8a, 0x38, 0xde, 0x81,
+    0xd4, 0x96, 0x91, 0xde, 0x20, 0x11, 0x9e, 0x16, 0x11, 0x16, 0x11, 0xde, 0x80, 0x6b, 0x16, 0x51,
+    0xde, 0x80, 0x2f, 0x00, 0xc9, 0x03, 0x16, 0x11, 0xc0, 0x8f, 0x43, 0xde, 0x81, 0x4a, 0x16, 0x11,
+    0xde, 0x73, 0xc0, 0xa1, 0x35, 0xde, 0x81, 0xb5, 0xc0, 0x03, 0xde, 0x1b, 0x00, 0xd9, 0x06, 0xc0,
+    0x00, 0x4d, 0xc0, 0x5c, 0xde, 0x1a, 0x40, 0xc4, 0x01, 0x0d, 0xc0, 0x06, 0xde, 0x2c, 0xc4, 0x03,
+    0x8d, 0xde, 0x06, 0x0d, 0x1e, 0xc0, 0x06, 0xd9, 0x06, 0x00, 0xde, 0x03, 0x00, 0xde, 0x11, 0xc0,
+    0x01, 0xde, 0x0f, 0xc0, 0x82, 0x2c, 0xde, 0x53, 0x4d, 0x5e, 0xc0, 0x61, 0xde, 0x47, 0xc0, 0x00,
+    0x04, 0x1e, 0xc4, 0x33, 0xc0, 0x03, 0xc4, 0x00, 0xde, 0x09, 0xc0, 0x3c, 0xd5, 0x00, 0x09, 0xc0,
+    0x07, 0x44, 0xc0, 0x0a, 0xe5, 0x97, 0x74, 0xc0, 0x04, 0xe5, 0x82, 0x7c, 0xde, 0x81, 0x52, 0xc0,
+    0x26, 0xe5, 0x05, 0xc0, 0xa2, 0x73, 0xe5, 0x80, 0x9b, 0xc0, 0x2d, 0x9f, 0xc0, 0x0d, 0xdf, 0x00,
+    0xc0, 0x04, 0xe5, 0x81, 0x08, 0xc0, 0x88, 0x80, 0xde, 0x67, 0xc0, 0x01, 0xde, 0x09, 0x80, 0xde,
+    0x05, 0xc0, 0x03, 0xde, 0x06, 0x40, 0x1e, 0x44, 0x0d, 0xc4, 0x00, 0xc0, 0x92, 0xd8, 0xde, 0x80,
+    0x72, 0xc0, 0x06, 0xde, 0x23, 0x40, 0xde, 0x38, 0xc4, 0x01, 0x9e, 0xc4, 0x12, 0x5e, 0xc4, 0x03,
+    0xde, 0x1a, 0xc4, 0x00, 0xde, 0x37, 0xc0, 0x13, 0xde, 0x3e, 0x84, 0x1e, 0xc0, 0x80, 0x16, 0xde,
+    0x10, 0xc0, 0x08, 0xde, 0x53, 0xc0, 0x05, 0xde, 0x15, 0xc0, 0x80, 0x03, 0xde, 0x51, 0x00, 0xde,
+    0x43, 0x00, 0x5e, 0x40, 0x1e, 0x40, 0x5e, 0x40, 0xde, 0x00, 0x00, 0xde, 0x08, 0x00, 0x1e, 0x00,
+    0xde, 0x03, 0x00, 0xde, 0x3d, 0x00, 0xde, 0x00, 0x40, 0xde, 0x04, 0x00, 0xde, 0x03, 0x00, 0xde,
+    0x18, 0x00, 0xde, 0x00, 0x00, 0xde, 0x01, 0x00, 0x1e, 0x80, 0xde, 0x03, 0x00, 0xde, 0x80, 0xd0,
+    0x40, 0xde, 0x80, 0xa0, 0x40, 0xd9, 0x2e, 0xde, 0x81, 0x7c, 0xc4, 0x33, 0xde, 0x00, 0xc4, 0x2e,
+    0xde, 0x04, 0x04, 0xde, 0x0a, 0x04, 0x5e, 0xcd, 0x00, 0x1e, 0xc0, 0x0b, 0xc4, 0x01, 0x00, 0xc4,
+    0x0b, 0xc0, 0x84, 0xcc, 0xc4, 0x03, 0x00, 0xc4, 0x0d, 0x40, 0xc4, 0x03, 0x00, 0x44, 0x00, 0xc4,
+    0x01, 0xc0, 0x80, 0x51, 0xde, 0x29, 0x80, 0xc4, 0x03, 0xde, 0x03, 0x40, 0xd9, 0x06, 0xc0, 0x00,
+    0x5e, 0xc0, 0x80, 0xec, 0xde, 0x28, 0xc4, 0x00, 0xd9, 0x06, 0xc0, 0x01, 0x1b, 0xc0, 0x84, 0x7c,
+    0xde, 0x80, 0x41, 0x40, 0xde, 0x05, 0xc4, 0x03, 0xc0, 0x25, 0xde, 0x40, 0xc4, 0x03, 0x1e, 0xc0,
+    0x00, 0xd9, 0x06, 0xc0, 0x00, 0x56, 0xc0, 0x82, 0x8d, 0xde, 0x37, 0x1a, 0x9e, 0x1a, 0xde, 0x00,
+    0xc0, 0x48, 0xde, 0x39, 0xc0, 0x80, 0x3e, 0xde, 0x00, 0x00, 0xde, 0x17, 0x00, 0x5e, 0x00, 0x1e,
+    0x40, 0x1e, 0x00, 0xde, 0x06, 0x00, 0xde, 0x00, 0x00, 0x1e, 0x00, 0x1e, 0xc0, 0x02, 0x1e, 0xc0,
+    0x00, 0x1e, 0x00, 0x1e, 0x00, 0x1e, 0x00, 0x9e, 0x00, 0x5e, 0x00, 0x1e, 0x40, 0x1e, 0x00, 0x1e,
+    0x00, 0x1e, 0x00, 0x1e, 0x00, 0x1e, 0x00, 0x5e, 0x00, 0x1e, 0x40, 0xde, 0x00, 0x00, 0xde, 0x03,
+    0x00, 0xde, 0x00, 0x00, 0xde, 0x00, 0x00, 0x1e, 0x00, 0xde, 0x06, 0x00, 0xde, 0x0d, 0xc0, 0x01,
+    0x9e, 0x00, 0xde, 0x01, 0x00, 0xde, 0x0d, 0xc0, 0x30, 0x5e, 0xc0, 0x80, 0x8a, 0xe5, 0x80, 0x7c,
+    0xdd, 0x09, 0xa5, 0xdd, 0x1a, 0x5e, 0xdd, 0x36, 0x9e, 0xa5, 0xdd, 0x39, 0xe5, 0x35, 0xe9, 0x16,
+    0xe5, 0x81, 0x01, 0x20, 0xe5, 0x12, 0x5e, 0xe5, 0x13, 0x5e, 0xe5, 0x01, 0x1e, 0xe5, 0x01, 0xa0,
+    0x65, 0x20, 0x65, 0xa0, 0xe5, 0x2a, 0xe1, 0x01, 0xe5, 0x3e, 0x60, 0x65, 0xe0, 0x07, 0xe5, 0x11,
+    0xe0, 0x0f, 0xa5, 0x20, 0xe5, 0x00, 0xa0, 0x25, 0xa0, 0xe5, 0x03, 0x20, 0x25, 0x20, 0xe5, 0x0a,
+    0x1e, 0x25, 0x1e, 0x25, 0x1e, 0xe5, 0x01, 0x20, 0xe5, 0x00, 0x1e, 0x25, 0x5e, 0xe5, 0x49, 0xde,
+    0x03, 0xe5, 0x0c, 0xde, 0x0a, 0xe5, 0x09, 0xde, 0x14, 0xe5, 0x26, 0x60, 0xe5, 0x00, 0x20, 0xe5,
+    0x11, 0x20, 0xe5, 0x00, 0x60, 0xe5, 0x39, 0xde, 0x04, 0xe5, 0x14, 0xde, 0x02, 0xe5, 0x47, 0xa0,
+    0xa5, 0xe0, 0x01, 0xde, 0x22, 0x97, 0x95, 0xde, 0x00, 0xe5, 0x1f, 0x20, 0xe5, 0x0c, 0xa0, 0xe5,
+    0x05, 0x20, 0xe5, 0x07, 0x20, 0xe5, 0x2f, 0xde, 0x70, 0xe5, 0x08, 0xde, 0x51, 0xe5, 0x27, 0xde,
+    0x08, 0xe5, 0x00, 0xde, 0x34, 0xe5, 0x04, 0xde, 0x06, 0xe5, 0x02, 0xde, 0x24, 0xe5, 0x04, 0xde,
+    0x1a, 0xe5, 0x4e, 0xde, 0x08, 0x20, 0x65, 0x20, 0xe5, 0x04, 0xe0, 0x04, 0xe5, 0x02, 0x20, 0xe5,
+    0x05, 0xe0, 0x06, 0x65, 0xa0, 0xe5, 0x34, 0x20, 0xe5, 0x39, 0x60, 0x25, 0x60, 0x25, 0x20, 0xe5,
+    0x0d, 0xa0, 0x25, 0xe0, 0x09, 0xe5, 0x1e, 0xde, 0x50, 0xe5, 0x80, 0x28, 0xde, 0x80, 0x0f, 0x00,
+    0xde, 0x33, 0xc0, 0x21, 0xd9, 0x06, 0xc0, 0x02, 0xe5, 0x83, 0x7a, 0x40, 0xe5, 0xc0, 0xbf, 0x7a,
+    0x40, 0xe5, 0xc0, 0xbf, 0x7a, 0xc0, 0xc9, 0xbf, 0x7f, 0x04, 0xc0, 0x1a, 0xc4, 0x5c, 0xc0, 0x7c,
+    0xc4, 0x80, 0x6c, 0xc0, 0xc2, 0xbd, 0x8c,
+];
+
+static unicode_line_break_index: [u8; 357] = [
+    0x5c, 0x00, 0x00, 0xbb, 0x00, 0x00, 0x50, 0x03, 0x00, 0x31, 0x05, 0x00, 0x00, 0x06, 0x00, 0xe9,
+    0x06, 0x00, 0xf9, 0x07, 0x00, 0xb5, 0x08, 0x20, 0x8d, 0x09, 0x00, 0xe4, 0x09, 0x00, 0x3a, 0x0a,
+    0x00, 0x8e, 0x0a, 0x00, 0xf1, 0x0a, 0x00, 0x4b, 0x0b, 0x00, 0x9e, 0x0b, 0x00, 0x00, 0x0c, 0x00,
+    0x70, 0x0c, 0x20, 0xd5, 0x0c, 0x20, 0x4e, 0x0d, 0x20, 0xc7, 0x0d, 0x20, 0x5c, 0x0e, 0x00, 0x00,
+    0x0f, 0x00, 0x3e, 0x0f, 0x00, 0xd2, 0x0f, 0x00, 0x60, 0x11, 0x00, 0xbf, 0x12, 0x00, 0xfe, 0x13,
+    0x20, 0x40, 0x17, 0x20, 0x00, 0x18, 0x20, 0x00, 0x19, 0x00, 0xda, 0x19, 0x20, 0x00, 0x1b, 0x00,
+    0xe6, 0x1b, 0x20, 0xd0, 0x1c, 0x00, 0x46, 0x1f, 0x00, 0xf5, 0x1f, 0x00, 0x2f, 0x20, 0x00, 0x7d,
+    0x20, 0x00, 0x03, 0x21, 0x00, 0x89, 0x21, 0x20, 0x1d, 0x22, 0x00, 0x68, 0x22, 0x00, 0x13, 0x23,
+    0x00, 0x80, 0x25, 0x00, 0xe6, 0x25, 0x20, 0x43, 0x26, 0x00, 0xda, 0x26, 0x00, 0x62, 0x27, 0x00,
+    0xed, 0x27, 0x00, 0xda, 0x29, 0x00, 0xfa, 0x2c, 0x00, 0xb7, 0x2d, 0x20, 0x23, 0x2e, 0x00, 0x9a,
+    0x2e, 0x00, 0x16, 0x30, 0x00, 0x63, 0x30, 0x00, 0xc4, 0x30, 0x00, 0x00, 0x32, 0x20, 0x0d, 0xa6,
+    0x00, 0xc2, 0xa7, 0x00, 0x80, 0xa8, 0x00, 0x5f, 0xa9, 0x00, 0x37, 0xaa, 0x20, 0x09, 0xab, 0x00,
+    0xb0, 0xd7, 0x20, 0x37, 0xfb, 0x20, 0xfc, 0xfd, 0x20, 0x42, 0xfe, 0x00, 0x75, 0xfe, 0x00, 0x5d,
+    0xff, 0x00, 0xe8, 0xff, 0x00, 0x07, 0x01, 0x21, 0x24, 0x03, 0x01, 0xaa, 0x04, 0x21, 0x60, 0x07,
+    0x01, 0xf4, 0x08, 0x01, 0x14, 0x0a, 0x01, 0xf6, 0x0a, 0x01, 0xb3, 0x0c, 0x21, 0x28, 0x0f, 0x01,
+    0x70, 0x10, 0x01, 0x44, 0x11, 0x21, 0xdc, 0x11, 0x01, 0x9f, 0x12, 0x01, 0x3a, 0x13, 0x21, 0x47,
+    0x14, 0x21, 0xb6, 0x15, 0x01, 0xab, 0x16, 0x01, 0xe0, 0x18, 0x21, 0x50, 0x19, 0x21, 0x40, 0x1a,
+    0x01, 0x37, 0x1c, 0x21, 0x31, 0x1d, 0x21, 0x98, 0x1d, 0x01, 0x00, 0x24, 0x21, 0x7c, 0x33, 0x01,
+    0x6e, 0x6a, 0x21, 0x7d, 0x6b, 0x21, 0xf0, 0x6f, 0x21, 0x68, 0xb1, 0x01, 0xf6, 0xd0, 0x21, 0xf4,
+    0xd2, 0x21, 0xbd, 0xd4, 0x01, 0xa6, 0xd6, 0x01, 0xb0, 0xda, 0x21, 0x4e, 0xe1, 0x01, 0x50, 0xe9,
+    0x21, 0x25, 0xee, 0x01, 0x5a, 0xee, 0x01, 0xa1, 0xee, 0x01, 0x00, 0xf2, 0x01, 0x66, 0xf4, 0x01,
+    0x07, 0xf5, 0x21, 0x48, 0xf6, 0x01, 0x0c, 0xf8, 0x21, 0x30, 0xf9, 0x21, 0x94, 0xfb, 0x01, 0x00,
+    0x01, 0x0e, 0x00, 0x00, 0x11,
+];
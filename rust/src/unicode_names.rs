@@ -0,0 +1,235 @@
+/*
+ * Unicode character names
+ *
+ * The names are compressed with a lexicon of the words they contain.
+ * Each name is encoded as the number of leading words shared with
+ * the previous name of its block followed by the indexes of the
+ * remaining words. The names of the Hangul syllables and of the
+ * ideographs are computed (Unicode chapter 4.8, rules NR1 and NR2).
+ */
+
+/* Tables generated by unicode_gen from the Unicode 13.0.0 data
+(UnicodeData.txt and NameAliases.txt).
+
+unicode_name_lexicon contains the words of the names, the last
+character of each word has its bit 7 set. The words taking the most
+space come first. unicode_name_lexicon_index contains the offset of
+every 16th word.
+
+unicode_name_data: for each name, a byte (shared << 4) | n, followed
+by an extra byte n - 15 if n = 15, then by the indexes of the n new
+words. Indexes below 0xc0 use one byte, the other ones two bytes
+(0xc0 + (idx - 0xc0) >> 8, (idx - 0xc0) & 0xff).
+
+unicode_name_block_table: (first code point | (count - 1) << 21,
+offset in unicode_name_data) for runs of at most 32 consecutive
+named code points.
+
+unicode_name_hex_range_table: ranges of code points named with their
+hexadecimal value.
+
+unicode_name_sorted_table: the names of unicode_name_block_table
+sorted in byte order, as (block index << 5) | (index in the block).
+
+unicode_name_alias_table: (alias, code point) for the formal name
+aliases, sorted by alias. */
+include!("unicode_names_table.rs");
+
+static hangul_jamo_l_table: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+static hangul_jamo_v_table: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+static hangul_jamo_t_table: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+const HANGUL_SBASE: u32 = 0xac00;
+const HANGUL_VCOUNT: u32 = 21;
+const HANGUL_TCOUNT: u32 = 28;
+const HANGUL_SCOUNT: u32 = 19 * 21 * 28;
+const HANGUL_PREFIX: &str = "HANGUL SYLLABLE ";
+
+/* word with a 1 byte code in unicode_name_data */
+const NAME_WORD_SHORT_COUNT: u32 = 0xc0;
+
+/* append the word 'idx' of the lexicon to 'buf' */
+fn name_append_word(buf: &mut String, idx: u32) {
+    let mut p = unicode_name_lexicon_index[(idx / 16) as usize] as usize;
+    for _ in 0..idx % 16 {
+        while unicode_name_lexicon[p] & 0x80 == 0 {
+            p += 1;
+        }
+        p += 1;
+    }
+    loop {
+        let b = unicode_name_lexicon[p];
+        buf.push((b & 0x7f) as char);
+        if b & 0x80 != 0 {
+            break;
+        }
+        p += 1;
+    }
+}
+
+/* decode the name at offset 'p' of unicode_name_data. 'words'
+contains the words of the previous name of the block and is
+updated. Return the offset of the next name. */
+fn name_decode(mut p: usize, words: &mut Vec<u32>) -> usize {
+    let b = unicode_name_data[p] as usize;
+    p += 1;
+    let shared = b >> 4;
+    let mut n = b & 0xf;
+    if n == 0xf {
+        n += unicode_name_data[p] as usize;
+        p += 1;
+    }
+    words.truncate(shared);
+    for _ in 0..n {
+        let mut idx = unicode_name_data[p] as u32;
+        p += 1;
+        if idx >= NAME_WORD_SHORT_COUNT {
+            idx = ((idx - NAME_WORD_SHORT_COUNT) << 8 | unicode_name_data[p] as u32)
+                + NAME_WORD_SHORT_COUNT;
+            p += 1;
+        }
+        words.push(idx);
+    }
+    p
+}
+
+fn name_block_bounds(k: usize) -> (u32, u32, usize) {
+    let v = unicode_name_block_table[k];
+    let first = v[0] & 0x1fffff;
+    (first, first + (v[0] >> 21) + 1, v[1] as usize)
+}
+
+/* return the name 'i' of the block 'k' */
+fn name_get(k: usize, i: u32) -> String {
+    let (_, _, mut p) = name_block_bounds(k);
+    let mut words = Vec::new();
+    for _ in 0..=i {
+        p = name_decode(p, &mut words);
+    }
+    let mut name = String::new();
+    for (j, &idx) in words.iter().enumerate() {
+        if j != 0 {
+            name.push(' ');
+        }
+        name_append_word(&mut name, idx);
+    }
+    name
+}
+
+/// Return the Unicode name of `c`, including the algorithmic names of
+/// the Hangul syllables and of the ideographs. The control characters
+/// and the unassigned code points have no name.
+pub fn char_name(c: u32) -> Option<String> {
+    if (HANGUL_SBASE..HANGUL_SBASE + HANGUL_SCOUNT).contains(&c) {
+        let s = c - HANGUL_SBASE;
+        let mut name = String::from(HANGUL_PREFIX);
+        name.push_str(hangul_jamo_l_table[(s / (HANGUL_VCOUNT * HANGUL_TCOUNT)) as usize]);
+        name.push_str(hangul_jamo_v_table[(s / HANGUL_TCOUNT % HANGUL_VCOUNT) as usize]);
+        name.push_str(hangul_jamo_t_table[(s % HANGUL_TCOUNT) as usize]);
+        return Some(name);
+    }
+    for &(first, last, prefix) in unicode_name_hex_range_table.iter() {
+        if c >= first && c <= last {
+            return Some(format!("{}{:04X}", prefix, c));
+        }
+    }
+
+    /* find the block containing 'c' */
+    let k = match unicode_name_block_table.binary_search_by(|v| (v[0] & 0x1fffff).cmp(&c)) {
+        Ok(k) => k,
+        Err(0) => return None,
+        Err(k) => k - 1,
+    };
+    let (first, end, _) = name_block_bounds(k);
+    if c >= end {
+        return None;
+    }
+    Some(name_get(k, c - first))
+}
+
+fn hangul_from_name(s: &str) -> Option<u32> {
+    for (l, ls) in hangul_jamo_l_table.iter().enumerate() {
+        let s1 = match s.strip_prefix(ls) {
+            Some(s1) => s1,
+            None => continue,
+        };
+        for (v, vs) in hangul_jamo_v_table.iter().enumerate() {
+            let s2 = match s1.strip_prefix(vs) {
+                Some(s2) => s2,
+                None => continue,
+            };
+            if let Some(t) = hangul_jamo_t_table.iter().position(|ts| *ts == s2) {
+                return Some(
+                    HANGUL_SBASE + (l as u32 * HANGUL_VCOUNT + v as u32) * HANGUL_TCOUNT + t as u32,
+                );
+            }
+        }
+    }
+    None
+}
+
+/// Return the code point whose Unicode name or formal name alias
+/// (NameAliases.txt) is `name`. The comparison ignores the case and
+/// underscores may be used instead of spaces.
+pub fn char_from_name(name: &str) -> Option<u32> {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|ch| {
+            if ch == '_' {
+                ' '
+            } else {
+                ch.to_ascii_uppercase()
+            }
+        })
+        .collect();
+    if !name.is_ascii() {
+        return None;
+    }
+    let name = name
+        .split(' ')
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    if let Some(s) = name.strip_prefix(HANGUL_PREFIX) {
+        if let Some(c) = hangul_from_name(s) {
+            return Some(c);
+        }
+    }
+    for &(first, last, prefix) in unicode_name_hex_range_table.iter() {
+        if let Some(s) = name.strip_prefix(prefix) {
+            /* from_str_radix() also accepts a sign */
+            if s.len() < 4 || s.len() > 5 || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+                continue;
+            }
+            match u32::from_str_radix(s, 16) {
+                Ok(c) if c >= first && c <= last => return Some(c),
+                _ => {}
+            }
+        }
+    }
+
+    let found = unicode_name_sorted_table.binary_search_by(|&v| {
+        name_get((v >> 5) as usize, (v & 0x1f) as u32)
+            .as_str()
+            .cmp(&name)
+    });
+    if let Ok(i) = found {
+        let v = unicode_name_sorted_table[i];
+        let (first, _, _) = name_block_bounds((v >> 5) as usize);
+        return Some(first + (v & 0x1f) as u32);
+    }
+    unicode_name_alias_table
+        .binary_search_by(|&(alias, _)| alias.cmp(&name))
+        .ok()
+        .map(|i| unicode_name_alias_table[i].1)
+}
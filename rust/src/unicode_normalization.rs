@@ -0,0 +1,40 @@
+/*
+ * Canonical and compatibility decomposition, canonical composition
+ * and combining classes of single characters
+ *
+ * These are the primitives of unicode_normalize(). The Hangul
+ * syllables are decomposed and composed algorithmically (Unicode
+ * chapter 3.12). The composition only returns primary composites:
+ * the composition exclusions are never produced.
+ */
+use crate::libunicode::{self, compose_pair, unicode_decompose_vec};
+
+pub type UnicodeDecompositionEnum = u32;
+pub const DECOMP_CANONICAL: UnicodeDecompositionEnum = 0;
+pub const DECOMP_COMPATIBILITY: UnicodeDecompositionEnum = 1;
+
+/// Return the full decomposition of `c`, or `c` alone if it has no
+/// decomposition of the type `decomp_type`. The decomposition is
+/// applied recursively but the combining marks are not reordered.
+pub fn decompose(c: u32, decomp_type: UnicodeDecompositionEnum) -> Vec<u32> {
+    let mut res = Vec::new();
+    unicode_decompose_vec(&mut res, c, decomp_type == DECOMP_COMPATIBILITY);
+    res
+}
+
+/// Return the primary composite of the pair (`a`, `b`), if any.
+pub fn compose(a: u32, b: u32) -> Option<u32> {
+    /* the non-starter decompositions (e.g. U+0344) are excluded */
+    if unicode_get_cc(a) != 0 {
+        return None;
+    }
+    match unsafe { compose_pair(a, b) } {
+        0 => None,
+        c => Some(c as u32),
+    }
+}
+
+/// Return the canonical combining class of `c` (between 0 and 255).
+pub fn unicode_get_cc(c: u32) -> u8 {
+    unsafe { libunicode::unicode_get_cc(c) as u8 }
+}
@@ -0,0 +1,322 @@
+/*
+ * Point lookups of the Unicode binary properties and of the general
+ * category
+ *
+ * The properties are tested directly on the compressed tables with
+ * their indexes. The derived properties are computed from the general
+ * category and from the contributory properties as in unicode_prop().
+ */
+use crate::libunicode::{
+    lre_case_conv, lre_is_in_prop, unicode_get_gc, UNICODE_GC_Cc, UNICODE_GC_Cf, UNICODE_GC_Cn,
+    UNICODE_GC_Co, UNICODE_GC_Cs, UNICODE_GC_Ll, UNICODE_GC_Lm, UNICODE_GC_Lo, UNICODE_GC_Lt,
+    UNICODE_GC_Lu, UNICODE_GC_Mc, UNICODE_GC_Me, UNICODE_GC_Mn, UNICODE_GC_Nd, UNICODE_GC_Nl,
+    UNICODE_GC_No, UNICODE_GC_Pc, UNICODE_GC_Pd, UNICODE_GC_Pe, UNICODE_GC_Pf, UNICODE_GC_Pi,
+    UNICODE_GC_Po, UNICODE_GC_Ps, UNICODE_GC_Sc, UNICODE_GC_Sk, UNICODE_GC_Sm, UNICODE_GC_So,
+    UNICODE_GC_Zl, UNICODE_GC_Zp, UNICODE_GC_Zs, UNICODE_PROP_ASCII_Hex_Digit,
+    UNICODE_PROP_Alphabetic, UNICODE_PROP_Any, UNICODE_PROP_Assigned, UNICODE_PROP_Bidi_Control,
+    UNICODE_PROP_Bidi_Mirrored, UNICODE_PROP_Case_Ignorable, UNICODE_PROP_Cased,
+    UNICODE_PROP_Changes_When_Casefolded, UNICODE_PROP_Changes_When_Casefolded1,
+    UNICODE_PROP_Changes_When_Casemapped, UNICODE_PROP_Changes_When_Lowercased,
+    UNICODE_PROP_Changes_When_NFKC_Casefolded, UNICODE_PROP_Changes_When_NFKC_Casefolded1,
+    UNICODE_PROP_Changes_When_Titlecased, UNICODE_PROP_Changes_When_Titlecased1,
+    UNICODE_PROP_Changes_When_Uppercased, UNICODE_PROP_Dash,
+    UNICODE_PROP_Default_Ignorable_Code_Point, UNICODE_PROP_Deprecated, UNICODE_PROP_Diacritic,
+    UNICODE_PROP_Emoji, UNICODE_PROP_Emoji_Component, UNICODE_PROP_Emoji_Modifier,
+    UNICODE_PROP_Emoji_Modifier_Base, UNICODE_PROP_Emoji_Presentation,
+    UNICODE_PROP_Extended_Pictographic, UNICODE_PROP_Extender, UNICODE_PROP_Grapheme_Base,
+    UNICODE_PROP_Grapheme_Extend, UNICODE_PROP_Hex_Digit, UNICODE_PROP_IDS_Binary_Operator,
+    UNICODE_PROP_IDS_Trinary_Operator, UNICODE_PROP_ID_Continue, UNICODE_PROP_ID_Continue1,
+    UNICODE_PROP_ID_Start, UNICODE_PROP_Ideographic, UNICODE_PROP_Join_Control,
+    UNICODE_PROP_Logical_Order_Exception, UNICODE_PROP_Lowercase, UNICODE_PROP_Math,
+    UNICODE_PROP_Noncharacter_Code_Point, UNICODE_PROP_Other_Alphabetic,
+    UNICODE_PROP_Other_Grapheme_Extend, UNICODE_PROP_Other_ID_Continue,
+    UNICODE_PROP_Other_ID_Start, UNICODE_PROP_Other_Lowercase, UNICODE_PROP_Other_Math,
+    UNICODE_PROP_Other_Uppercase, UNICODE_PROP_Pattern_Syntax, UNICODE_PROP_Pattern_White_Space,
+    UNICODE_PROP_Quotation_Mark, UNICODE_PROP_Radical, UNICODE_PROP_Regional_Indicator,
+    UNICODE_PROP_Sentence_Terminal, UNICODE_PROP_Soft_Dotted, UNICODE_PROP_Terminal_Punctuation,
+    UNICODE_PROP_Unified_Ideograph, UNICODE_PROP_Uppercase, UNICODE_PROP_Variation_Selector,
+    UNICODE_PROP_White_Space, UNICODE_PROP_XID_Continue, UNICODE_PROP_XID_Continue1,
+    UNICODE_PROP_XID_Start, UNICODE_PROP_XID_Start1, UNICODE_PROP_ASCII,
+};
+
+/// Binary properties which can be tested with `has_property`. They
+/// are the properties of `unicode_prop_name_table`.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Property {
+    ASCII_Hex_Digit = UNICODE_PROP_ASCII_Hex_Digit,
+    Bidi_Control = UNICODE_PROP_Bidi_Control,
+    Dash = UNICODE_PROP_Dash,
+    Deprecated = UNICODE_PROP_Deprecated,
+    Diacritic = UNICODE_PROP_Diacritic,
+    Extender = UNICODE_PROP_Extender,
+    Hex_Digit = UNICODE_PROP_Hex_Digit,
+    IDS_Binary_Operator = UNICODE_PROP_IDS_Binary_Operator,
+    IDS_Trinary_Operator = UNICODE_PROP_IDS_Trinary_Operator,
+    Ideographic = UNICODE_PROP_Ideographic,
+    Join_Control = UNICODE_PROP_Join_Control,
+    Logical_Order_Exception = UNICODE_PROP_Logical_Order_Exception,
+    Noncharacter_Code_Point = UNICODE_PROP_Noncharacter_Code_Point,
+    Pattern_Syntax = UNICODE_PROP_Pattern_Syntax,
+    Pattern_White_Space = UNICODE_PROP_Pattern_White_Space,
+    Quotation_Mark = UNICODE_PROP_Quotation_Mark,
+    Radical = UNICODE_PROP_Radical,
+    Regional_Indicator = UNICODE_PROP_Regional_Indicator,
+    Sentence_Terminal = UNICODE_PROP_Sentence_Terminal,
+    Soft_Dotted = UNICODE_PROP_Soft_Dotted,
+    Terminal_Punctuation = UNICODE_PROP_Terminal_Punctuation,
+    Unified_Ideograph = UNICODE_PROP_Unified_Ideograph,
+    Variation_Selector = UNICODE_PROP_Variation_Selector,
+    White_Space = UNICODE_PROP_White_Space,
+    Bidi_Mirrored = UNICODE_PROP_Bidi_Mirrored,
+    Emoji = UNICODE_PROP_Emoji,
+    Emoji_Component = UNICODE_PROP_Emoji_Component,
+    Emoji_Modifier = UNICODE_PROP_Emoji_Modifier,
+    Emoji_Modifier_Base = UNICODE_PROP_Emoji_Modifier_Base,
+    Emoji_Presentation = UNICODE_PROP_Emoji_Presentation,
+    Extended_Pictographic = UNICODE_PROP_Extended_Pictographic,
+    Default_Ignorable_Code_Point = UNICODE_PROP_Default_Ignorable_Code_Point,
+    ID_Start = UNICODE_PROP_ID_Start,
+    Case_Ignorable = UNICODE_PROP_Case_Ignorable,
+    ASCII = UNICODE_PROP_ASCII,
+    Alphabetic = UNICODE_PROP_Alphabetic,
+    Any = UNICODE_PROP_Any,
+    Assigned = UNICODE_PROP_Assigned,
+    Cased = UNICODE_PROP_Cased,
+    Changes_When_Casefolded = UNICODE_PROP_Changes_When_Casefolded,
+    Changes_When_Casemapped = UNICODE_PROP_Changes_When_Casemapped,
+    Changes_When_Lowercased = UNICODE_PROP_Changes_When_Lowercased,
+    Changes_When_NFKC_Casefolded = UNICODE_PROP_Changes_When_NFKC_Casefolded,
+    Changes_When_Titlecased = UNICODE_PROP_Changes_When_Titlecased,
+    Changes_When_Uppercased = UNICODE_PROP_Changes_When_Uppercased,
+    Grapheme_Base = UNICODE_PROP_Grapheme_Base,
+    Grapheme_Extend = UNICODE_PROP_Grapheme_Extend,
+    ID_Continue = UNICODE_PROP_ID_Continue,
+    Lowercase = UNICODE_PROP_Lowercase,
+    Math = UNICODE_PROP_Math,
+    Uppercase = UNICODE_PROP_Uppercase,
+    XID_Continue = UNICODE_PROP_XID_Continue,
+    XID_Start = UNICODE_PROP_XID_Start,
+}
+
+/// General_Category values.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GeneralCategory {
+    Cn = UNICODE_GC_Cn,
+    Lu = UNICODE_GC_Lu,
+    Ll = UNICODE_GC_Ll,
+    Lt = UNICODE_GC_Lt,
+    Lm = UNICODE_GC_Lm,
+    Lo = UNICODE_GC_Lo,
+    Mn = UNICODE_GC_Mn,
+    Mc = UNICODE_GC_Mc,
+    Me = UNICODE_GC_Me,
+    Nd = UNICODE_GC_Nd,
+    Nl = UNICODE_GC_Nl,
+    No = UNICODE_GC_No,
+    Sm = UNICODE_GC_Sm,
+    Sc = UNICODE_GC_Sc,
+    Sk = UNICODE_GC_Sk,
+    So = UNICODE_GC_So,
+    Pc = UNICODE_GC_Pc,
+    Pd = UNICODE_GC_Pd,
+    Ps = UNICODE_GC_Ps,
+    Pe = UNICODE_GC_Pe,
+    Pi = UNICODE_GC_Pi,
+    Pf = UNICODE_GC_Pf,
+    Po = UNICODE_GC_Po,
+    Zs = UNICODE_GC_Zs,
+    Zl = UNICODE_GC_Zl,
+    Zp = UNICODE_GC_Zp,
+    Cc = UNICODE_GC_Cc,
+    Cf = UNICODE_GC_Cf,
+    Cs = UNICODE_GC_Cs,
+    Co = UNICODE_GC_Co,
+}
+
+static general_category_table: [GeneralCategory; 30] = [
+    GeneralCategory::Cn,
+    GeneralCategory::Lu,
+    GeneralCategory::Ll,
+    GeneralCategory::Lt,
+    GeneralCategory::Lm,
+    GeneralCategory::Lo,
+    GeneralCategory::Mn,
+    GeneralCategory::Mc,
+    GeneralCategory::Me,
+    GeneralCategory::Nd,
+    GeneralCategory::Nl,
+    GeneralCategory::No,
+    GeneralCategory::Sm,
+    GeneralCategory::Sc,
+    GeneralCategory::Sk,
+    GeneralCategory::So,
+    GeneralCategory::Pc,
+    GeneralCategory::Pd,
+    GeneralCategory::Ps,
+    GeneralCategory::Pe,
+    GeneralCategory::Pi,
+    GeneralCategory::Pf,
+    GeneralCategory::Po,
+    GeneralCategory::Zs,
+    GeneralCategory::Zl,
+    GeneralCategory::Zp,
+    GeneralCategory::Cc,
+    GeneralCategory::Cf,
+    GeneralCategory::Cs,
+    GeneralCategory::Co,
+];
+
+impl GeneralCategory {
+    /// Return true if the category is in the Letter group (L).
+    pub fn is_letter(self) -> bool {
+        (self as u32) >= UNICODE_GC_Lu && (self as u32) <= UNICODE_GC_Lo
+    }
+
+    /// Return true if the category is in the Mark group (M).
+    pub fn is_mark(self) -> bool {
+        (self as u32) >= UNICODE_GC_Mn && (self as u32) <= UNICODE_GC_Me
+    }
+
+    /// Return true if the category is in the Number group (N).
+    pub fn is_number(self) -> bool {
+        (self as u32) >= UNICODE_GC_Nd && (self as u32) <= UNICODE_GC_No
+    }
+
+    /// Return true if the category is in the Symbol group (S).
+    pub fn is_symbol(self) -> bool {
+        (self as u32) >= UNICODE_GC_Sm && (self as u32) <= UNICODE_GC_So
+    }
+
+    /// Return true if the category is in the Punctuation group (P).
+    pub fn is_punctuation(self) -> bool {
+        (self as u32) >= UNICODE_GC_Pc && (self as u32) <= UNICODE_GC_Po
+    }
+
+    /// Return true if the category is in the Separator group (Z).
+    pub fn is_separator(self) -> bool {
+        (self as u32) >= UNICODE_GC_Zs && (self as u32) <= UNICODE_GC_Zp
+    }
+
+    /// Return true if the category is in the Other group (C).
+    pub fn is_other(self) -> bool {
+        self == GeneralCategory::Cn || (self as u32) >= UNICODE_GC_Cc
+    }
+}
+
+/// Return the General_Category property of `c`.
+pub fn general_category(c: u32) -> GeneralCategory {
+    general_category_table[unicode_get_gc(c) as usize]
+}
+
+fn gc_in(c: u32, gc_mask: u32) -> bool {
+    (gc_mask >> unicode_get_gc(c)) & 1 != 0
+}
+
+fn prop1(c: u32, prop_idx: u32) -> bool {
+    unsafe { lre_is_in_prop(c, prop_idx as i32) != 0 }
+}
+
+/* conv_type: 0 = to upper, 1 = to lower, 2 = case folding */
+fn changes_when(c: u32, conv_type: i32) -> bool {
+    let mut res: [u32; 3] = [0; 3];
+    let len = unsafe { lre_case_conv(res.as_mut_ptr(), c, conv_type) };
+    len != 1 || res[0] != c
+}
+
+const GC_MASK_LETTER_NL: u32 = 1 << UNICODE_GC_Lu
+    | 1 << UNICODE_GC_Ll
+    | 1 << UNICODE_GC_Lt
+    | 1 << UNICODE_GC_Lm
+    | 1 << UNICODE_GC_Lo
+    | 1 << UNICODE_GC_Nl;
+
+/// Return true if `c` has the binary property `prop`.
+pub fn has_property(c: u32, prop: Property) -> bool {
+    if c > 0x10ffff {
+        return false;
+    }
+    let prop_idx = prop as u32;
+    match prop {
+        Property::ASCII => c < 0x80,
+        Property::Any => true,
+        Property::Assigned => unicode_get_gc(c) != UNICODE_GC_Cn,
+        Property::Math => unicode_get_gc(c) == UNICODE_GC_Sm || prop1(c, UNICODE_PROP_Other_Math),
+        Property::Lowercase => {
+            unicode_get_gc(c) == UNICODE_GC_Ll || prop1(c, UNICODE_PROP_Other_Lowercase)
+        }
+        Property::Uppercase => {
+            unicode_get_gc(c) == UNICODE_GC_Lu || prop1(c, UNICODE_PROP_Other_Uppercase)
+        }
+        Property::Cased => {
+            gc_in(
+                c,
+                1 << UNICODE_GC_Lu | 1 << UNICODE_GC_Ll | 1 << UNICODE_GC_Lt,
+            ) || prop1(c, UNICODE_PROP_Other_Uppercase)
+                || prop1(c, UNICODE_PROP_Other_Lowercase)
+        }
+        Property::Alphabetic => {
+            gc_in(c, GC_MASK_LETTER_NL)
+                || prop1(c, UNICODE_PROP_Other_Uppercase)
+                || prop1(c, UNICODE_PROP_Other_Lowercase)
+                || prop1(c, UNICODE_PROP_Other_Alphabetic)
+        }
+        Property::Grapheme_Base => {
+            !(gc_in(
+                c,
+                1 << UNICODE_GC_Cc
+                    | 1 << UNICODE_GC_Cf
+                    | 1 << UNICODE_GC_Cs
+                    | 1 << UNICODE_GC_Co
+                    | 1 << UNICODE_GC_Cn
+                    | 1 << UNICODE_GC_Zl
+                    | 1 << UNICODE_GC_Zp
+                    | 1 << UNICODE_GC_Me
+                    | 1 << UNICODE_GC_Mn,
+            ) || prop1(c, UNICODE_PROP_Other_Grapheme_Extend))
+        }
+        Property::Grapheme_Extend => {
+            gc_in(c, 1 << UNICODE_GC_Me | 1 << UNICODE_GC_Mn)
+                || prop1(c, UNICODE_PROP_Other_Grapheme_Extend)
+        }
+        Property::XID_Start => {
+            (gc_in(c, GC_MASK_LETTER_NL) || prop1(c, UNICODE_PROP_Other_ID_Start))
+                && !(prop1(c, UNICODE_PROP_Pattern_Syntax)
+                    || prop1(c, UNICODE_PROP_Pattern_White_Space)
+                    || prop1(c, UNICODE_PROP_XID_Start1))
+        }
+        Property::XID_Continue => {
+            (gc_in(
+                c,
+                GC_MASK_LETTER_NL
+                    | 1 << UNICODE_GC_Mn
+                    | 1 << UNICODE_GC_Mc
+                    | 1 << UNICODE_GC_Nd
+                    | 1 << UNICODE_GC_Pc,
+            ) || prop1(c, UNICODE_PROP_Other_ID_Start)
+                || prop1(c, UNICODE_PROP_Other_ID_Continue))
+                && !(prop1(c, UNICODE_PROP_Pattern_Syntax)
+                    || prop1(c, UNICODE_PROP_Pattern_White_Space)
+                    || prop1(c, UNICODE_PROP_XID_Continue1))
+        }
+        Property::ID_Continue => {
+            prop1(c, UNICODE_PROP_ID_Start) ^ prop1(c, UNICODE_PROP_ID_Continue1)
+        }
+        Property::Changes_When_Uppercased => changes_when(c, 0),
+        Property::Changes_When_Lowercased => changes_when(c, 1),
+        Property::Changes_When_Casemapped => {
+            changes_when(c, 0) || changes_when(c, 1) || changes_when(c, 2)
+        }
+        Property::Changes_When_Titlecased => {
+            changes_when(c, 0) ^ prop1(c, UNICODE_PROP_Changes_When_Titlecased1)
+        }
+        Property::Changes_When_Casefolded => {
+            changes_when(c, 2) ^ prop1(c, UNICODE_PROP_Changes_When_Casefolded1)
+        }
+        Property::Changes_When_NFKC_Casefolded => {
+            changes_when(c, 2) ^ prop1(c, UNICODE_PROP_Changes_When_NFKC_Casefolded1)
+        }
+        _ => prop1(c, prop_idx),
+    }
+}
@@ -0,0 +1,241 @@
+/*
+ * Script property point lookups and script run segmentation
+ *
+ * The runs follow the usual rules of the text shaping engines: the
+ * Common and Inherited characters belong to the surrounding run, the
+ * Script_Extensions of a character are intersected with the scripts
+ * of the run and a closing bracket takes the script of its opening
+ * bracket.
+ */
+use crate::libunicode::{unicode_get_script, unicode_get_script_ext, unicode_get_script_name};
+pub use crate::libunicode::{
+    UNICODE_SCRIPT_Adlam, UNICODE_SCRIPT_Ahom, UNICODE_SCRIPT_Anatolian_Hieroglyphs,
+    UNICODE_SCRIPT_Arabic, UNICODE_SCRIPT_Armenian, UNICODE_SCRIPT_Avestan,
+    UNICODE_SCRIPT_Balinese, UNICODE_SCRIPT_Bamum, UNICODE_SCRIPT_Bassa_Vah, UNICODE_SCRIPT_Batak,
+    UNICODE_SCRIPT_Bengali, UNICODE_SCRIPT_Bhaiksuki, UNICODE_SCRIPT_Bopomofo,
+    UNICODE_SCRIPT_Brahmi, UNICODE_SCRIPT_Braille, UNICODE_SCRIPT_Buginese, UNICODE_SCRIPT_Buhid,
+    UNICODE_SCRIPT_Canadian_Aboriginal, UNICODE_SCRIPT_Carian, UNICODE_SCRIPT_Caucasian_Albanian,
+    UNICODE_SCRIPT_Chakma, UNICODE_SCRIPT_Cham, UNICODE_SCRIPT_Cherokee, UNICODE_SCRIPT_Chorasmian,
+    UNICODE_SCRIPT_Common, UNICODE_SCRIPT_Coptic, UNICODE_SCRIPT_Cuneiform, UNICODE_SCRIPT_Cypriot,
+    UNICODE_SCRIPT_Cyrillic, UNICODE_SCRIPT_Deseret, UNICODE_SCRIPT_Devanagari,
+    UNICODE_SCRIPT_Dives_Akuru, UNICODE_SCRIPT_Dogra, UNICODE_SCRIPT_Duployan,
+    UNICODE_SCRIPT_Egyptian_Hieroglyphs, UNICODE_SCRIPT_Elbasan, UNICODE_SCRIPT_Elymaic,
+    UNICODE_SCRIPT_Ethiopic, UNICODE_SCRIPT_Georgian, UNICODE_SCRIPT_Glagolitic,
+    UNICODE_SCRIPT_Gothic, UNICODE_SCRIPT_Grantha, UNICODE_SCRIPT_Greek, UNICODE_SCRIPT_Gujarati,
+    UNICODE_SCRIPT_Gunjala_Gondi, UNICODE_SCRIPT_Gurmukhi, UNICODE_SCRIPT_Han,
+    UNICODE_SCRIPT_Hangul, UNICODE_SCRIPT_Hanifi_Rohingya, UNICODE_SCRIPT_Hanunoo,
+    UNICODE_SCRIPT_Hatran, UNICODE_SCRIPT_Hebrew, UNICODE_SCRIPT_Hiragana,
+    UNICODE_SCRIPT_Imperial_Aramaic, UNICODE_SCRIPT_Inherited,
+    UNICODE_SCRIPT_Inscriptional_Pahlavi, UNICODE_SCRIPT_Inscriptional_Parthian,
+    UNICODE_SCRIPT_Javanese, UNICODE_SCRIPT_Kaithi, UNICODE_SCRIPT_Kannada,
+    UNICODE_SCRIPT_Katakana, UNICODE_SCRIPT_Kayah_Li, UNICODE_SCRIPT_Kharoshthi,
+    UNICODE_SCRIPT_Khitan_Small_Script, UNICODE_SCRIPT_Khmer, UNICODE_SCRIPT_Khojki,
+    UNICODE_SCRIPT_Khudawadi, UNICODE_SCRIPT_Lao, UNICODE_SCRIPT_Latin, UNICODE_SCRIPT_Lepcha,
+    UNICODE_SCRIPT_Limbu, UNICODE_SCRIPT_Linear_A, UNICODE_SCRIPT_Linear_B, UNICODE_SCRIPT_Lisu,
+    UNICODE_SCRIPT_Lycian, UNICODE_SCRIPT_Lydian, UNICODE_SCRIPT_Mahajani, UNICODE_SCRIPT_Makasar,
+    UNICODE_SCRIPT_Malayalam, UNICODE_SCRIPT_Mandaic, UNICODE_SCRIPT_Manichaean,
+    UNICODE_SCRIPT_Marchen, UNICODE_SCRIPT_Masaram_Gondi, UNICODE_SCRIPT_Medefaidrin,
+    UNICODE_SCRIPT_Meetei_Mayek, UNICODE_SCRIPT_Mende_Kikakui, UNICODE_SCRIPT_Meroitic_Cursive,
+    UNICODE_SCRIPT_Meroitic_Hieroglyphs, UNICODE_SCRIPT_Miao, UNICODE_SCRIPT_Modi,
+    UNICODE_SCRIPT_Mongolian, UNICODE_SCRIPT_Mro, UNICODE_SCRIPT_Multani, UNICODE_SCRIPT_Myanmar,
+    UNICODE_SCRIPT_Nabataean, UNICODE_SCRIPT_Nandinagari, UNICODE_SCRIPT_New_Tai_Lue,
+    UNICODE_SCRIPT_Newa, UNICODE_SCRIPT_Nko, UNICODE_SCRIPT_Nushu,
+    UNICODE_SCRIPT_Nyiakeng_Puachue_Hmong, UNICODE_SCRIPT_Ogham, UNICODE_SCRIPT_Ol_Chiki,
+    UNICODE_SCRIPT_Old_Hungarian, UNICODE_SCRIPT_Old_Italic, UNICODE_SCRIPT_Old_North_Arabian,
+    UNICODE_SCRIPT_Old_Permic, UNICODE_SCRIPT_Old_Persian, UNICODE_SCRIPT_Old_Sogdian,
+    UNICODE_SCRIPT_Old_South_Arabian, UNICODE_SCRIPT_Old_Turkic, UNICODE_SCRIPT_Oriya,
+    UNICODE_SCRIPT_Osage, UNICODE_SCRIPT_Osmanya, UNICODE_SCRIPT_Pahawh_Hmong,
+    UNICODE_SCRIPT_Palmyrene, UNICODE_SCRIPT_Pau_Cin_Hau, UNICODE_SCRIPT_Phags_Pa,
+    UNICODE_SCRIPT_Phoenician, UNICODE_SCRIPT_Psalter_Pahlavi, UNICODE_SCRIPT_Rejang,
+    UNICODE_SCRIPT_Runic, UNICODE_SCRIPT_Samaritan, UNICODE_SCRIPT_Saurashtra,
+    UNICODE_SCRIPT_Sharada, UNICODE_SCRIPT_Shavian, UNICODE_SCRIPT_Siddham,
+    UNICODE_SCRIPT_SignWriting, UNICODE_SCRIPT_Sinhala, UNICODE_SCRIPT_Sogdian,
+    UNICODE_SCRIPT_Sora_Sompeng, UNICODE_SCRIPT_Soyombo, UNICODE_SCRIPT_Sundanese,
+    UNICODE_SCRIPT_Syloti_Nagri, UNICODE_SCRIPT_Syriac, UNICODE_SCRIPT_Tagalog,
+    UNICODE_SCRIPT_Tagbanwa, UNICODE_SCRIPT_Tai_Le, UNICODE_SCRIPT_Tai_Tham,
+    UNICODE_SCRIPT_Tai_Viet, UNICODE_SCRIPT_Takri, UNICODE_SCRIPT_Tamil, UNICODE_SCRIPT_Tangut,
+    UNICODE_SCRIPT_Telugu, UNICODE_SCRIPT_Thaana, UNICODE_SCRIPT_Thai, UNICODE_SCRIPT_Tibetan,
+    UNICODE_SCRIPT_Tifinagh, UNICODE_SCRIPT_Tirhuta, UNICODE_SCRIPT_Ugaritic,
+    UNICODE_SCRIPT_Unknown, UNICODE_SCRIPT_Vai, UNICODE_SCRIPT_Wancho, UNICODE_SCRIPT_Warang_Citi,
+    UNICODE_SCRIPT_Yezidi, UNICODE_SCRIPT_Yi, UNICODE_SCRIPT_Zanabazar_Square, UnicodeScriptEnum,
+    UNICODE_SCRIPT_COUNT,
+};
+use crate::unicode_bidi::{bidi_paired_bracket, BRACKET_CLOSE};
+use crate::unicode_ident::ScriptSet;
+
+/// Return the Script property of `c`.
+pub fn script_of(c: u32) -> UnicodeScriptEnum {
+    unicode_get_script(c)
+}
+
+/// Return the Script_Extensions property of `c`. It contains the
+/// Script property of `c` if it has no explicit extensions.
+pub fn script_extensions_of(c: u32) -> Vec<UnicodeScriptEnum> {
+    let ext = unicode_get_script_ext(c);
+    if ext.is_empty() {
+        vec![unicode_get_script(c)]
+    } else {
+        ext.iter()
+            .map(|&script| script as UnicodeScriptEnum)
+            .collect()
+    }
+}
+
+/// Return the long name of `script` (e.g. "Latin").
+pub fn script_name(script: UnicodeScriptEnum) -> Option<&'static str> {
+    if script == UNICODE_SCRIPT_Unknown {
+        return Some("Unknown");
+    }
+    unicode_get_script_name(script)?.split(',').next()
+}
+
+/// Return the ISO 15924 code of `script` (e.g. "Latn").
+pub fn script_short_name(script: UnicodeScriptEnum) -> Option<&'static str> {
+    if script == UNICODE_SCRIPT_Unknown {
+        return Some("Zzzz");
+    }
+    unicode_get_script_name(script)?.split(',').nth(1)
+}
+
+/// Return the script whose long name, ISO 15924 code or alias is
+/// `name`.
+pub fn script_from_name(name: &str) -> Option<UnicodeScriptEnum> {
+    if name == "Unknown" || name == "Zzzz" {
+        return Some(UNICODE_SCRIPT_Unknown);
+    }
+    (UNICODE_SCRIPT_Unknown + 1..UNICODE_SCRIPT_COUNT).find(|&script| {
+        unicode_get_script_name(script).is_some_and(|names| names.split(',').any(|n| n == name))
+    })
+}
+
+/* Return the set of scripts of 'c' or None if it takes the script of
+the surrounding text */
+fn script_set(c: u32) -> Option<ScriptSet> {
+    let mut set = ScriptSet::EMPTY;
+    let ext = unicode_get_script_ext(c);
+    if ext.is_empty() {
+        let script = unicode_get_script(c);
+        if script == UNICODE_SCRIPT_Common || script == UNICODE_SCRIPT_Inherited {
+            return None;
+        }
+        set.add(script);
+    } else {
+        for &script in ext {
+            set.add(script as UnicodeScriptEnum);
+        }
+    }
+    Some(set)
+}
+
+/// A maximal substring whose characters can be written with a single
+/// script. `start` and `end` are byte offsets.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ScriptRun {
+    pub start: usize,
+    pub end: usize,
+    pub script: UnicodeScriptEnum,
+}
+
+/* opening bracket waiting for its closing bracket */
+struct OpenBracket {
+    closing: u32,
+    run_idx: usize,
+    script: UnicodeScriptEnum,
+}
+
+/* maximum number of nested opening brackets which are remembered */
+const SCRIPT_RUN_MAX_BRACKETS: usize = 64;
+
+/// Iterator over the script runs of a string.
+pub struct ScriptRuns<'a> {
+    s: &'a str,
+    pos: usize,
+    run_idx: usize,
+    brackets: Vec<OpenBracket>,
+}
+
+/// Split `s` into script runs. A run containing only Common and
+/// Inherited characters has the Common script.
+pub fn script_runs(s: &str) -> ScriptRuns<'_> {
+    ScriptRuns {
+        s,
+        pos: 0,
+        run_idx: 0,
+        brackets: Vec::new(),
+    }
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = ScriptRun;
+
+    fn next(&mut self) -> Option<ScriptRun> {
+        let start = self.pos;
+        if start >= self.s.len() {
+            return None;
+        }
+        let text = &self.s[start..];
+        let mut scripts: Option<ScriptSet> = None;
+        let mut end = self.s.len();
+        for (i, ch) in text.char_indices() {
+            let c = ch as u32;
+            let bracket = bidi_paired_bracket(c);
+            let mut set = script_set(c);
+            let mut matched = None;
+            if bracket & BRACKET_CLOSE != 0 {
+                let closing = bidi_paired_bracket(bracket & !BRACKET_CLOSE);
+                matched = self.brackets.iter().rposition(|b| b.closing == closing);
+                if let Some(k) = matched {
+                    let b = &self.brackets[k];
+                    if set.is_none()
+                        && b.run_idx != self.run_idx
+                        && b.script != UNICODE_SCRIPT_Common
+                    {
+                        let mut bracket_set = ScriptSet::EMPTY;
+                        bracket_set.add(b.script);
+                        set = Some(bracket_set);
+                    }
+                }
+            }
+            if let Some(set) = set {
+                let mut s = scripts.unwrap_or(ScriptSet::ALL);
+                s.intersect(&set);
+                if s == ScriptSet::EMPTY {
+                    end = start + i;
+                    break;
+                }
+                scripts = Some(s);
+            }
+            if let Some(k) = matched {
+                self.brackets.truncate(k);
+            } else if bracket != 0 && bracket & BRACKET_CLOSE == 0 {
+                if self.brackets.len() == SCRIPT_RUN_MAX_BRACKETS {
+                    self.brackets.remove(0);
+                }
+                self.brackets.push(OpenBracket {
+                    closing: bracket,
+                    run_idx: self.run_idx,
+                    script: UNICODE_SCRIPT_Common,
+                });
+            }
+        }
+        /* the script of the run is the first Script property value of its
+        characters which is compatible with all the characters */
+        let script = match scripts {
+            None => UNICODE_SCRIPT_Common,
+            Some(s) => self.s[start..end]
+                .chars()
+                .map(|ch| unicode_get_script(ch as u32))
+                .find(|&script| s.has(script))
+                .or_else(|| s.first())
+                .unwrap_or(UNICODE_SCRIPT_Common),
+        };
+        for b in self.brackets.iter_mut() {
+            if b.run_idx == self.run_idx {
+                b.script = script;
+            }
+        }
+        self.pos = end;
+        self.run_idx += 1;
+        Some(ScriptRun { start, end, script })
+    }
+}
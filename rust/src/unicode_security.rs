@@ -0,0 +1,261 @@
+/*
+ * Unicode security mechanisms (UTS #39)
+ *
+ * Confusable detection with the skeletons of the strings, detection of
+ * the whole-script confusables and classification of the strings in
+ * restriction levels.
+ */
+use std::alloc::{handle_alloc_error, Layout};
+
+use crate::libunicode::{
+    unicode_normalize_vec, UNICODE_SCRIPT_Arabic, UNICODE_SCRIPT_Armenian, UNICODE_SCRIPT_Bengali,
+    UNICODE_SCRIPT_Bopomofo, UNICODE_SCRIPT_Devanagari, UNICODE_SCRIPT_Ethiopic,
+    UNICODE_SCRIPT_Georgian, UNICODE_SCRIPT_Gujarati, UNICODE_SCRIPT_Gurmukhi, UNICODE_SCRIPT_Han,
+    UNICODE_SCRIPT_Hangul, UNICODE_SCRIPT_Hebrew, UNICODE_SCRIPT_Hiragana, UNICODE_SCRIPT_Kannada,
+    UNICODE_SCRIPT_Katakana, UNICODE_SCRIPT_Khmer, UNICODE_SCRIPT_Lao, UNICODE_SCRIPT_Malayalam,
+    UNICODE_SCRIPT_Myanmar, UNICODE_SCRIPT_Oriya, UNICODE_SCRIPT_Sinhala, UNICODE_SCRIPT_Tamil,
+    UNICODE_SCRIPT_Telugu, UNICODE_SCRIPT_Thaana, UNICODE_SCRIPT_Thai, UNICODE_SCRIPT_Tibetan,
+    UNICODE_NFD,
+};
+use crate::unicode_ident::{
+    augmented_script_set, is_highly_restrictive, resolved_script_sets, ScriptSet,
+};
+
+/* Tables generated by unicode_gen from the UTS #39 data files
+(confusables.txt and IdentifierStatus.txt), restricted to the
+characters assigned in Unicode 13.0.0.
+
+confusable_index: for each character having a prototype, sorted by
+code point, (c << 14) | offset of its prototype in confusable_data.
+The prototype ends at the offset of the next entry. The last entry
+only gives the end of the data.
+
+confusable_data: the prototypes, in UTF-16.
+
+identifier_allowed_table: ranges of the characters whose
+Identifier_Status is Allowed. */
+include!("unicode_security_table.rs");
+
+pub type RestrictionLevelEnum = u32;
+/* only ASCII characters */
+pub const RESTRICTION_LEVEL_ASCII_ONLY: RestrictionLevelEnum = 0;
+/* the resolved script set is not empty */
+pub const RESTRICTION_LEVEL_SINGLE_SCRIPT: RestrictionLevelEnum = 1;
+/* Latin with Han, Hiragana, Katakana, Hangul or Bopomofo */
+pub const RESTRICTION_LEVEL_HIGHLY_RESTRICTIVE: RestrictionLevelEnum = 2;
+/* Latin with one other Recommended script except Cyrillic and Greek */
+pub const RESTRICTION_LEVEL_MODERATELY_RESTRICTIVE: RestrictionLevelEnum = 3;
+/* any mix of scripts */
+pub const RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE: RestrictionLevelEnum = 4;
+/* characters which are not allowed in identifiers */
+pub const RESTRICTION_LEVEL_UNRESTRICTED: RestrictionLevelEnum = 5;
+
+pub type ConfusableTypeEnum = u32;
+/* the resolved script sets of the strings intersect */
+pub const CONFUSABLE_SINGLE_SCRIPT: ConfusableTypeEnum = 0;
+/* the resolved script sets do not intersect */
+pub const CONFUSABLE_MIXED_SCRIPT: ConfusableTypeEnum = 1;
+/* mixed-script confusables whose strings are each single-script */
+pub const CONFUSABLE_WHOLE_SCRIPT: ConfusableTypeEnum = 2;
+
+/* Recommended scripts of UAX #31 (table 7) other than Common,
+Inherited, Latin, Cyrillic and Greek */
+static moderately_restrictive_scripts: [u32; 26] = [
+    UNICODE_SCRIPT_Arabic,
+    UNICODE_SCRIPT_Armenian,
+    UNICODE_SCRIPT_Bengali,
+    UNICODE_SCRIPT_Bopomofo,
+    UNICODE_SCRIPT_Devanagari,
+    UNICODE_SCRIPT_Ethiopic,
+    UNICODE_SCRIPT_Georgian,
+    UNICODE_SCRIPT_Gujarati,
+    UNICODE_SCRIPT_Gurmukhi,
+    UNICODE_SCRIPT_Han,
+    UNICODE_SCRIPT_Hangul,
+    UNICODE_SCRIPT_Hebrew,
+    UNICODE_SCRIPT_Hiragana,
+    UNICODE_SCRIPT_Kannada,
+    UNICODE_SCRIPT_Katakana,
+    UNICODE_SCRIPT_Khmer,
+    UNICODE_SCRIPT_Lao,
+    UNICODE_SCRIPT_Malayalam,
+    UNICODE_SCRIPT_Myanmar,
+    UNICODE_SCRIPT_Oriya,
+    UNICODE_SCRIPT_Sinhala,
+    UNICODE_SCRIPT_Tamil,
+    UNICODE_SCRIPT_Telugu,
+    UNICODE_SCRIPT_Thaana,
+    UNICODE_SCRIPT_Thai,
+    UNICODE_SCRIPT_Tibetan,
+];
+
+const CONFUSABLE_OFFSET_BITS: u32 = 14;
+
+/* Return the prototype of 'c' in UTF-16 or None if 'c' is its own
+prototype */
+fn confusable_prototype(c: u32) -> Option<&'static [u16]> {
+    let n = confusable_index.len() - 1;
+    let idx = confusable_index[..n]
+        .binary_search_by_key(&c, |&v| v >> CONFUSABLE_OFFSET_BITS)
+        .ok()?;
+    let mask = (1 << CONFUSABLE_OFFSET_BITS) - 1;
+    let start = (confusable_index[idx] & mask) as usize;
+    let end = (confusable_index[idx + 1] & mask) as usize;
+    Some(&confusable_data[start..end])
+}
+
+fn push_prototype(buf: &mut Vec<u32>, c: u32) {
+    match confusable_prototype(c) {
+        Some(proto) => {
+            buf.extend(char::decode_utf16(proto.iter().cloned()).map(|r| r.unwrap() as u32))
+        }
+        None => buf.push(c),
+    }
+}
+
+/* the failed allocations of the normalization are reported like
+those of the other buffers of these functions */
+fn nfd_vec(buf: &[u32]) -> Vec<u32> {
+    unicode_normalize_vec(buf, UNICODE_NFD)
+        .unwrap_or_else(|| handle_alloc_error(Layout::array::<u32>(buf.len()).unwrap()))
+}
+
+fn to_nfd(s: &str) -> Vec<u32> {
+    let buf: Vec<u32> = s.chars().map(|ch| ch as u32).collect();
+    nfd_vec(&buf)
+}
+
+fn skeleton_vec(s: &str) -> Vec<u32> {
+    let mut buf = Vec::new();
+    for c in to_nfd(s) {
+        push_prototype(&mut buf, c);
+    }
+    nfd_vec(&buf)
+}
+
+/// Return the skeleton of `s` (UTS #39 section 4): the string is
+/// converted to NFD, each character is replaced by its prototype and
+/// the result is converted to NFD again. Two strings are confusable
+/// if they have the same skeleton.
+pub fn skeleton(s: &str) -> String {
+    skeleton_vec(s)
+        .iter()
+        .filter_map(|&c| char::from_u32(c))
+        .collect()
+}
+
+/// Return true if `a` and `b` are visually confusable.
+pub fn are_confusable(a: &str, b: &str) -> bool {
+    skeleton_vec(a) == skeleton_vec(b)
+}
+
+/// Return the type of confusability of `a` and `b`, or None if they
+/// are not confusable.
+pub fn confusable_type(a: &str, b: &str) -> Option<ConfusableTypeEnum> {
+    if !are_confusable(a, b) {
+        return None;
+    }
+    let (mut resolved_a, _) = resolved_script_sets(&to_nfd(a));
+    let (resolved_b, _) = resolved_script_sets(&to_nfd(b));
+    let is_single_script = resolved_a != ScriptSet::EMPTY && resolved_b != ScriptSet::EMPTY;
+    resolved_a.intersect(&resolved_b);
+    if resolved_a != ScriptSet::EMPTY {
+        Some(CONFUSABLE_SINGLE_SCRIPT)
+    } else if is_single_script {
+        Some(CONFUSABLE_WHOLE_SCRIPT)
+    } else {
+        Some(CONFUSABLE_MIXED_SCRIPT)
+    }
+}
+
+/// Return true if the Identifier_Status of `c` is Allowed (UTS #39
+/// section 3.1).
+pub fn is_identifier_allowed(c: u32) -> bool {
+    identifier_allowed_table
+        .binary_search_by(|r| {
+            if r[1] < c {
+                std::cmp::Ordering::Less
+            } else if r[0] > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/* Return the union of the augmented script sets of the allowed
+characters having the same prototype as 'c', or None if 'c' is
+Common or Inherited */
+fn confusable_script_set(c: u32) -> Option<ScriptSet> {
+    let mut set = augmented_script_set(c)?;
+    let mut proto = Vec::new();
+    push_prototype(&mut proto, c);
+    if let [p] = proto[..] {
+        if p != c && is_identifier_allowed(p) {
+            if let Some(s) = augmented_script_set(p) {
+                set.union(&s);
+            }
+        }
+    }
+    let mut other = Vec::new();
+    for &v in &confusable_index[..confusable_index.len() - 1] {
+        let d = v >> CONFUSABLE_OFFSET_BITS;
+        if d == c || !is_identifier_allowed(d) {
+            continue;
+        }
+        other.clear();
+        push_prototype(&mut other, d);
+        if other == proto {
+            if let Some(s) = augmented_script_set(d) {
+                set.union(&s);
+            }
+        }
+    }
+    Some(set)
+}
+
+/// Return true if `s` is single-script and every of its characters
+/// has a confusable in the same other script, so that a whole-script
+/// confusable of `s` can be written (UTS #39 section 4.1).
+pub fn has_whole_script_confusable(s: &str) -> bool {
+    let buf = to_nfd(s);
+    let (resolved, _) = resolved_script_sets(&buf);
+    if resolved == ScriptSet::EMPTY {
+        return false;
+    }
+    let mut scripts = ScriptSet::ALL;
+    for &c in &buf {
+        if let Some(set) = confusable_script_set(c) {
+            scripts.intersect(&set);
+        }
+    }
+    scripts.remove(&resolved);
+    scripts != ScriptSet::EMPTY
+}
+
+/// Return the restriction level of `s` (UTS #39 section 5.2). The
+/// levels are ordered from the most to the least restrictive.
+pub fn restriction_level(s: &str) -> RestrictionLevelEnum {
+    let buf: Vec<u32> = s.chars().map(|ch| ch as u32).collect();
+    if !buf.iter().all(|&c| is_identifier_allowed(c)) {
+        return RESTRICTION_LEVEL_UNRESTRICTED;
+    }
+    if buf.iter().all(|&c| c < 0x80) {
+        return RESTRICTION_LEVEL_ASCII_ONLY;
+    }
+    let (resolved, resolved_non_latin) = resolved_script_sets(&buf);
+    if resolved != ScriptSet::EMPTY {
+        return RESTRICTION_LEVEL_SINGLE_SCRIPT;
+    }
+    if is_highly_restrictive(&buf) {
+        return RESTRICTION_LEVEL_HIGHLY_RESTRICTIVE;
+    }
+    if moderately_restrictive_scripts
+        .iter()
+        .any(|&script| resolved_non_latin.has(script))
+    {
+        return RESTRICTION_LEVEL_MODERATELY_RESTRICTIVE;
+    }
+    RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE
+}
@@ -0,0 +1,1942 @@
+/* Compressed unicode tables */
+/* Automatically generated file - do not edit */
+
+static confusable_index: [u32; 6315] = [
+    0x00088000, 0x00094002, 0x000c0005, 0x000c4006, 0x00124007, 0x00180008, 0x001b4009, 0x001f000b,
+    0x0028000c, 0x0028800d, 0x0029400f, 0x002bc011, 0x002d0012, 0x002d4013, 0x002e0014, 0x00318015,
+    0x0031c017, 0x00340019, 0x0035c01b, 0x0036001c, 0x0039801e, 0x0039c020, 0x003c0022, 0x003d8024,
+    0x003e0025, 0x00440027, 0x00444029, 0x0046802b, 0x0046c02c, 0x0049802d, 0x0049c02f, 0x004c4031,
+    0x004c8032, 0x004cc034, 0x004fc036, 0x00500038, 0x0050403a, 0x0050803c, 0x0051803e, 0x0052403f,
+    0x00540041, 0x00548042, 0x0054c044, 0x0058c046, 0x00598047, 0x0059c049, 0x005fc04b, 0x0060004c,
+    0x0060404e, 0x00608050, 0x0060c052, 0x00610054, 0x0061c055, 0x00624057, 0x00628059, 0x0063005b,
+    0x0063405d, 0x0064405e, 0x00648060, 0x0064c062, 0x00658064, 0x0065c065, 0x00660067, 0x00664069,
+    0x0066806b, 0x0066c06d, 0x0067406f, 0x00678071, 0x0067c073, 0x00680075, 0x00684077, 0x00690079,
+    0x0069407b, 0x0069807d, 0x0069c07e, 0x006b007f, 0x006b4081, 0x006b8083, 0x006cc085, 0x006d0087,
+    0x006d4089, 0x006d808b, 0x006dc08d, 0x006ec08e, 0x006f0090, 0x006f4091, 0x006fc092, 0x00700093,
+    0x00704094, 0x0070c096, 0x00710097, 0x00714099, 0x0071809b, 0x0071c09d, 0x0072009f, 0x007240a1,
+    0x007280a3, 0x0072c0a5, 0x007300a7, 0x007340a9, 0x007380aa, 0x0073c0ab, 0x007400ac, 0x007440ad,
+    0x007480ae, 0x0074c0af, 0x007500b0, 0x007900b1, 0x007940b3, 0x007980b5, 0x0079c0b6, 0x007c40b7,
+    0x007c80b9, 0x007cc0bb, 0x007d40bd, 0x007f80be, 0x008680c1, 0x0086c0c2, 0x008700c3, 0x008880c4,
+    0x0088c0c5, 0x008900c6, 0x008940c8, 0x008980ca, 0x0089c0cb, 0x008f00cc, 0x008f80ce, 0x009040d0,
+    0x009100d1, 0x009180d3, 0x0091c0d5, 0x009200d7, 0x009240d9, 0x009340db, 0x009380dd, 0x0093c0df,
+    0x009440e1, 0x0094c0e2, 0x009580e4, 0x0095c0e6, 0x009640e8, 0x009680e9, 0x0096c0eb, 0x009800ec,
+    0x009840ee, 0x0098c0ef, 0x009980f0, 0x009a00f2, 0x009a40f4, 0x009a80f5, 0x009ac0f6, 0x009b40f8,
+    0x009b80fa, 0x009bc0fc, 0x009c40fd, 0x009cc100, 0x009d4102, 0x009d8104, 0x009f0106, 0x009f4108,
+    0x00a0810a, 0x00a2c10c, 0x00a3c10d, 0x00a4010e, 0x00a48110, 0x00a50111, 0x00a80112, 0x00a8c114,
+    0x00a90116, 0x00a94118, 0x00a9811a, 0x00a9c11c, 0x00aa011e, 0x00aa4120, 0x00aa8122, 0x00aac124,
+    0x00acc126, 0x00ae4127, 0x00ae8128, 0x00aec12a, 0x00af012b, 0x00af412c, 0x00af812d, 0x00afc12e,
+    0x00b0812f, 0x00b0c130, 0x00b10131, 0x00b18132, 0x00b20133, 0x00b28134, 0x00b2c135, 0x00b40136,
+    0x00b4c137, 0x00b5c138, 0x00b60139, 0x00b6413a, 0x00b6813b, 0x00b6c13c, 0x00b7013d, 0x00b7413e,
+    0x00b84140, 0x00b88141, 0x00b90142, 0x00bb8143, 0x00bd0145, 0x00bd8146, 0x00be0148, 0x00bec149,
+    0x00c1414a, 0x00c3014b, 0x00c3414c, 0x00c4014d, 0x00c4414f, 0x00c54150, 0x00c5c151, 0x00c80152,
+    0x00c84153, 0x00c88154, 0x00c9c155, 0x00cd8156, 0x00cdc157, 0x00ce4158, 0x00d00159, 0x00d0415a,
+    0x00d0815b, 0x00d0c15c, 0x00d1415d, 0x00d1c15e, 0x00d5c15f, 0x00d60160, 0x00d98161, 0x00db8162,
+    0x00dc0163, 0x00dd0164, 0x00dd4165, 0x00dd8166, 0x00ddc167, 0x00de8168, 0x00dec169, 0x00df416a,
+    0x00df816b, 0x00dfc16c, 0x00e1016d, 0x00e1c16e, 0x00e4416f, 0x00e48170, 0x00e54171, 0x00e58172,
+    0x00e5c173, 0x00e60174, 0x00e64176, 0x00e68177, 0x00e6c178, 0x00e70179, 0x00e7417a, 0x00e7c17b,
+    0x00e8417c, 0x00e8c17d, 0x00e9017e, 0x00e9417f, 0x00e9c180, 0x00ec4181, 0x00ec8182, 0x00ecc183,
+    0x00ed0184, 0x00ed4185, 0x00edc186, 0x00ee0188, 0x00ee418a, 0x00ee818b, 0x00ef418c, 0x00efc18d,
+    0x00f0418e, 0x00f0c18f, 0x00f10190, 0x00f14191, 0x00f18192, 0x00f40193, 0x00f44194, 0x00f48196,
+    0x00f54197, 0x00f58198, 0x00f6c199, 0x00f7019a, 0x00fa019b, 0x00fa419c, 0x00fc019d, 0x00fc419e,
+    0x00fc819f, 0x00fcc1a0, 0x00fd01a1, 0x00fd41a3, 0x00fdc1a4, 0x00fe01a5, 0x00fe41a6, 0x00fe81a7,
+    0x00ff41a8, 0x00ffc1a9, 0x010101aa, 0x010141ab, 0x010181ac, 0x010201ad, 0x010401ae, 0x010441af,
+    0x010481b1, 0x0104c1b2, 0x010541b3, 0x0105c1b4, 0x010641b5, 0x010681b6, 0x0106c1b7, 0x010701b8,
+    0x010741b9, 0x010781ba, 0x0107c1bb, 0x010801bc, 0x010841bd, 0x010881be, 0x0108c1bf, 0x010901c0,
+    0x010941c1, 0x010ac1c2, 0x010b01c4, 0x010b81c5, 0x010c01c7, 0x010c41c8, 0x010c81c9, 0x010cc1ca,
+    0x010d41cb, 0x010dc1cc, 0x010e01cd, 0x010e81ce, 0x010f01cf, 0x010f41d0, 0x010f81d1, 0x010fc1d2,
+    0x011001d3, 0x011041d4, 0x011081d5, 0x0110c1d6, 0x011101d7, 0x011141d8, 0x011281d9, 0x0112c1db,
+    0x011301dd, 0x0113c1de, 0x011501df, 0x011541e0, 0x011581e1, 0x011601e2, 0x0116c1e3, 0x011741e5,
+    0x011841e6, 0x011881e7, 0x0118c1e9, 0x011c01eb, 0x011c41ec, 0x011c81ed, 0x011cc1ef, 0x011d01f1,
+    0x011d41f2, 0x011f01f3, 0x011f41f6, 0x012281f9, 0x0122c1fb, 0x012301fd, 0x012341ff, 0x01240201,
+    0x01244203, 0x01248205, 0x0124c207, 0x01258209, 0x0125c20b, 0x0126020d, 0x0126420f, 0x01268211,
+    0x0126c213, 0x01278215, 0x0127c217, 0x01288219, 0x0128c21b, 0x012a821d, 0x012ac21f, 0x012b0221,
+    0x012b4223, 0x012b8225, 0x012bc226, 0x012c0227, 0x012c4229, 0x012c822b, 0x012ec22d, 0x012f422e,
+    0x012f822f, 0x012fc231, 0x01300233, 0x01314234, 0x01318236, 0x0131c238, 0x0132023a, 0x0132423c,
+    0x0132823e, 0x0132c240, 0x01330241, 0x01334242, 0x01338244, 0x0133c246, 0x01350247, 0x01354249,
+    0x0136024b, 0x0136424c, 0x0138024d, 0x0138424e, 0x013a024f, 0x013a4251, 0x01404253, 0x01428254,
+    0x01430255, 0x01434256, 0x01440257, 0x01444258, 0x0146c259, 0x0147025a, 0x0147425b, 0x014ec25c,
+    0x0151025d, 0x0152825e, 0x0153025f, 0x01534260, 0x0153c261, 0x0154c262, 0x01554263, 0x01568264,
+    0x01574265, 0x01584266, 0x0158c267, 0x01598268, 0x015b8269, 0x015c026a, 0x015d426b, 0x015e026c,
+    0x015e826d, 0x015f026e, 0x015f426f, 0x01604270, 0x01610271, 0x01614272, 0x0161c273, 0x01624275,
+    0x01670276, 0x01674277, 0x01690278, 0x016a0279, 0x016b427a, 0x016b827b, 0x016bc27c, 0x016d027d,
+    0x016e427e, 0x016e827f, 0x01700280, 0x01704281, 0x01708282, 0x0170c283, 0x01710284, 0x01714285,
+    0x01754286, 0x01760287, 0x01764288, 0x0177c289, 0x0178428a, 0x017c028b, 0x017c428d, 0x017c828f,
+    0x017cc291, 0x017d0292, 0x01824294, 0x01828298, 0x0183429d, 0x0183c29e, 0x0186029f, 0x018642a0,
+    0x018682a1, 0x0188c2a2, 0x018902a4, 0x018942a6, 0x018982a8, 0x0189c2aa, 0x018ac2ab, 0x018d02ad,
+    0x018f42af, 0x018fc2b1, 0x0191c2b3, 0x019282b4, 0x0192c2b5, 0x019382b6, 0x0193c2b7, 0x019482b8,
+    0x0194c2b9, 0x019582ba, 0x0195c2bb, 0x019602bc, 0x019642bd, 0x019682be, 0x0196c2bf, 0x019702c0,
+    0x019742c1, 0x0197c2c2, 0x019802c3, 0x019842c4, 0x019942c5, 0x0199c2c6, 0x019a02c7, 0x019a82c8,
+    0x019ac2cb, 0x019b02cc, 0x019b42cd, 0x019b82ce, 0x019bc2cf, 0x019c82d0, 0x019cc2d2, 0x019d42d4,
+    0x019d82d6, 0x019dc2d8, 0x019e02db, 0x019e42dd, 0x019f82df, 0x01a042e1, 0x01a142e3, 0x01a202e5,
+    0x01a2c2e7, 0x01a382e9, 0x01a442eb, 0x01a482ed, 0x01a602ef, 0x01a782f1, 0x01a7c2f3, 0x01a902f5,
+    0x01a9c2f7, 0x01aa02f8, 0x01aa42fa, 0x01aa82fb, 0x01ab42fc, 0x01ad02fe, 0x01ad4300, 0x01adc302,
+    0x01ae8304, 0x01aec305, 0x01af4307, 0x01af8309, 0x01b0430a, 0x01b0830b, 0x01b0c30c, 0x01b1830d,
+    0x01b1c30f, 0x01b20311, 0x01b24313, 0x01b2c315, 0x01b30317, 0x01b38318, 0x01b4031a, 0x01b4431b,
+    0x01b4831d, 0x01b5031e, 0x01b5431f, 0x01b7c320, 0x01ba0321, 0x01bb0323, 0x01bb8324, 0x01bbc326,
+    0x01bc0328, 0x01bc4329, 0x01bc832a, 0x01bcc32b, 0x01bd032c, 0x01bd432d, 0x01bd832e, 0x01bdc32f,
+    0x01be0330, 0x01be4331, 0x01bf4332, 0x01bf8334, 0x01bfc336, 0x01c04338, 0x01c08339, 0x01c0c33a,
+    0x01c1033b, 0x01d0033c, 0x01d0433d, 0x01d0833e, 0x01d1c33f, 0x01d44340, 0x01d58342, 0x01d88344,
+    0x01d8c345, 0x01d9c347, 0x01da0348, 0x01da434a, 0x01db034c, 0x01dc434e, 0x01dc8350, 0x01df8352,
+    0x01f00354, 0x01f28355, 0x01fac356, 0x01fb4357, 0x01fb8358, 0x01fcc359, 0x01fd035a, 0x01fd435b,
+    0x01fe835c, 0x0228435d, 0x0229035f, 0x0229c361, 0x022a0363, 0x022a4365, 0x022b8366, 0x022bc369,
+    0x022c036c, 0x022c436d, 0x022c836e, 0x022d8370, 0x022dc372, 0x022e4375, 0x022e8378, 0x022ec37b,
+    0x022f037c, 0x022f437d, 0x0239437e, 0x023a037f, 0x023a8380, 0x023ac381, 0x023b4382, 0x023b8383,
+    0x023c0384, 0x023c4385, 0x023c8386, 0x023cc387, 0x023e0388, 0x023e4389, 0x023e838a, 0x023fc38b,
+    0x0240038c, 0x0240438d, 0x0240838f, 0x0240c390, 0x02410391, 0x02418393, 0x02420395, 0x02434398,
+    0x0243839a, 0x0244039c, 0x0244439e, 0x024483a0, 0x0244c3a3, 0x024503a6, 0x024f03a9, 0x025483aa,
+    0x0254c3ab, 0x025503ac, 0x025943ad, 0x025983af, 0x0259c3b0, 0x025f43b1, 0x026043b2, 0x026183b4,
+    0x026f03b6, 0x027803b7, 0x027843b9, 0x027983bb, 0x027a83bc, 0x027b43bd, 0x028083be, 0x0280c3bf,
+    0x028183c0, 0x0281c3c2, 0x028203c4, 0x028243c6, 0x028283c8, 0x0283c3ca, 0x028403cc, 0x028503ce,
+    0x028f03d0, 0x0292c3d1, 0x029343d2, 0x029983d3, 0x0299c3d4, 0x029a83d5, 0x02a043d6, 0x02a083d8,
+    0x02a0c3d9, 0x02a183da, 0x02a343dc, 0x02a3c3de, 0x02a403e0, 0x02a443e2, 0x02a4c3e5, 0x02a503e8,
+    0x02af03eb, 0x02af43ec, 0x02b043ed, 0x02b083ee, 0x02b343ef, 0x02b983f0, 0x02ba03f1, 0x02ba43f2,
+    0x02ba83f3, 0x02bb83f4, 0x02bc03f5, 0x02c043f6, 0x02c0c3f8, 0x02c183f9, 0x02c803fb, 0x02cf03fc,
+    0x02d983fd, 0x02da03fe, 0x02e083ff, 0x02e28400, 0x02e70402, 0x02ec0403, 0x02ef8404, 0x02f20405,
+    0x02f28406, 0x02f2c408, 0x02f3040a, 0x02f3440c, 0x02f5c40d, 0x02f9840e, 0x02f9c40f, 0x02fa0410,
+    0x02fa8411, 0x02fac412, 0x02fb0414, 0x02fb4416, 0x02fb8417, 0x02fc0418, 0x02fc8419, 0x02fd041b,
+    0x02fd441d, 0x02fdc41e, 0x02fe0420, 0x02fe8421, 0x03000423, 0x03008425, 0x0300c426, 0x0304c427,
+    0x03050429, 0x0308042b, 0x0308842d, 0x0309442f, 0x030b4431, 0x030b8433, 0x030dc435, 0x030e4437,
+    0x03108439, 0x0311043b, 0x0318043d, 0x0318443f, 0x03198441, 0x03204442, 0x03208444, 0x0320c445,
+    0x03214446, 0x03218447, 0x0321c448, 0x03248449, 0x0324c44a, 0x0325044c, 0x0327044e, 0x0327844f,
+    0x0328c450, 0x032bc451, 0x032c4452, 0x032c8453, 0x03384454, 0x03398456, 0x0339c457, 0x033a0458,
+    0x033bc459, 0x0340445a, 0x0340845c, 0x0340c45d, 0x0342045e, 0x03424460, 0x03428461, 0x03430463,
+    0x03440465, 0x0344c467, 0x03450469, 0x0346446b, 0x0347046d, 0x0348046e, 0x0348c46f, 0x034c4470,
+    0x034d0471, 0x034d8472, 0x034e8473, 0x034fc475, 0x03500476, 0x03508477, 0x0350c478, 0x03520479,
+    0x0353847b, 0x0356847c, 0x0357c47f, 0x03584482, 0x03598483, 0x035a8484, 0x035ac486, 0x035b0489,
+    0x035b448c, 0x035b848d, 0x035bc490, 0x035d8492, 0x035e4495, 0x035ec497, 0x035f0499, 0x0360849b,
+    0x0360c49c, 0x037a449d, 0x037a849f, 0x037ac4a0, 0x037bc4a1, 0x0380c4a3, 0x0382c4a4, 0x0383c4a5,
+    0x038504a6, 0x038544a7, 0x0385c4a8, 0x038844a9, 0x038984aa, 0x038cc4ab, 0x039044ad, 0x039144af,
+    0x039344b0, 0x039404b1, 0x03a204b2, 0x03a344b3, 0x03a684b4, 0x03a6c4b5, 0x03a744b6, 0x03a784b7,
+    0x03a7c4b8, 0x03acc4b9, 0x03ae04bb, 0x03ae44bc, 0x03b204bd, 0x03b244be, 0x03b284bf, 0x03b2c4c0,
+    0x03b344c1, 0x03b404c2, 0x03b704c3, 0x03b744c5, 0x03c004c7, 0x03c084ca, 0x03c0c4ce, 0x03c304d2,
+    0x03c384d3, 0x03c6c4d5, 0x03c784d7, 0x03c7c4d9, 0x03cdc4db, 0x03da84dc, 0x03ddc4dd, 0x03de44e0,
+    0x03f384e3, 0x03f544e5, 0x03f584e6, 0x040004e7, 0x040404e9, 0x040744eb, 0x0407c4ec, 0x040a44ee,
+    0x040a84f0, 0x040d84f5, 0x040e04f6, 0x041004f7, 0x0412c4f8, 0x041944fa, 0x041984fb, 0x041bc4fd,
+    0x041c0500, 0x041f8502, 0x04204504, 0x04278506, 0x04280508, 0x0439c509, 0x043cc50a, 0x043fc50b,
+    0x0440450c, 0x0441050e, 0x04420510, 0x04428512, 0x04434514, 0x0444c516, 0x04450518, 0x0445451a,
+    0x0445851c, 0x0445c51e, 0x04460520, 0x04464522, 0x04468524, 0x0446c526, 0x04470528, 0x0447452a,
+    0x0447852c, 0x0447c52e, 0x04480530, 0x04484532, 0x04488534, 0x0448c537, 0x0449053a, 0x0449453d,
+    0x04498540, 0x0449c543, 0x044a0545, 0x044a4547, 0x044a8549, 0x044ac54b, 0x044b054d, 0x044b4550,
+    0x044b8552, 0x044bc554, 0x044c0556, 0x044c4558, 0x044c855a, 0x044cc55c, 0x044d055f, 0x044d4562,
+    0x044d8564, 0x044dc566, 0x044e0568, 0x044e456a, 0x044e856c, 0x044ec56e, 0x044f4570, 0x044fc572,
+    0x04504574, 0x04508576, 0x0450c578, 0x0451057a, 0x0451457c, 0x0451857e, 0x0451c580, 0x04520582,
+    0x04524584, 0x04528586, 0x0452c588, 0x0453458a, 0x0453c58c, 0x0454458e, 0x04548590, 0x0454c592,
+    0x04558594, 0x0455c596, 0x04560598, 0x0456859a, 0x0456c59c, 0x0457059e, 0x045745a0, 0x045785a2,
+    0x045885a4, 0x045905a6, 0x045985a8, 0x045a05aa, 0x045a85ac, 0x045ac5ae, 0x045b05b1, 0x045bc5b3,
+    0x045c05b5, 0x045c45b8, 0x045cc5ba, 0x045d05bb, 0x045d45bd, 0x045d85be, 0x045dc5c0, 0x045e05c2,
+    0x045e45c4, 0x045e85c6, 0x045ec5c8, 0x045f05ca, 0x045f45cc, 0x045f85ce, 0x045fc5d0, 0x046005d2,
+    0x046045d5, 0x046085d8, 0x0460c5da, 0x046105dc, 0x046145de, 0x046185e1, 0x0461c5e3, 0x046205e5,
+    0x046245e7, 0x046285e9, 0x0462c5ec, 0x046305ef, 0x046345f2, 0x046385f4, 0x0463c5f6, 0x046405f8,
+    0x046445fb, 0x046485fd, 0x0464c600, 0x04650602, 0x04654604, 0x04658606, 0x0465c608, 0x0466060b,
+    0x0466460d, 0x0466860f, 0x0466c611, 0x04670613, 0x04674615, 0x0467c617, 0x04680619, 0x0468461b,
+    0x0468861d, 0x0468c61f, 0x04690621, 0x04694623, 0x04698625, 0x0469c627, 0x046a062a, 0x046a462b,
+    0x046a862d, 0x046ac62f, 0x046b0630, 0x046b4632, 0x046b8634, 0x046bc635, 0x046c0636, 0x046c4638,
+    0x046c863a, 0x046cc63c, 0x046d063e, 0x046d4640, 0x046d8642, 0x046dc644, 0x046e0645, 0x046e4646,
+    0x046e8648, 0x046ec649, 0x046f064b, 0x046f464c, 0x046f864d, 0x046fc64e, 0x0470064f, 0x04704650,
+    0x04708651, 0x0470c652, 0x04710654, 0x04714657, 0x04718659, 0x0471c65b, 0x0472065d, 0x0472465f,
+    0x04728661, 0x0472c663, 0x04730665, 0x04734668, 0x0473866a, 0x0473c66c, 0x0474066f, 0x04744671,
+    0x04748674, 0x0474c677, 0x0475067a, 0x0475467d, 0x04758680, 0x0475c683, 0x04760685, 0x04764687,
+    0x04768689, 0x0476c68b, 0x0477068d, 0x0477468f, 0x04778691, 0x0477c694, 0x04780696, 0x04784698,
+    0x0478869a, 0x0478c69c, 0x0479069e, 0x047946a0, 0x047986a2, 0x0479c6a4, 0x047a06a6, 0x047a46a8,
+    0x047a86aa, 0x047ac6ac, 0x047b06ad, 0x047b46af, 0x047b86b2, 0x047bc6b4, 0x047c06b6, 0x047c46b7,
+    0x047c86b9, 0x047cc6bb, 0x047d06bd, 0x047d46bf, 0x047d86c1, 0x047dc6c3, 0x047e06c5, 0x047e46c7,
+    0x047e86c8, 0x047ec6ca, 0x047f06cc, 0x047f46ce, 0x047f86d0, 0x047fc6d2, 0x048006d4, 0x0488c6d5,
+    0x049006d6, 0x049806d7, 0x04a506d8, 0x04b406d9, 0x04e806da, 0x04e846db, 0x04e886dc, 0x04e906dd,
+    0x04e946df, 0x04ea06e0, 0x04ea46e1, 0x04ea86e2, 0x04eac6e3, 0x04eb06e4, 0x04eb86e5, 0x04ec06e6,
+    0x04ec46e7, 0x04ecc6e8, 0x04edc6e9, 0x04eec6ea, 0x04ef46eb, 0x04ef86ec, 0x04efc6ee, 0x04f006ef,
+    0x04f086f0, 0x04f0c6f1, 0x04f1c6f2, 0x04f2c6f3, 0x04f306f4, 0x04f386f6, 0x04f3c6f7, 0x04f486f8,
+    0x04f506f9, 0x04f546fa, 0x04f646fb, 0x04f686fc, 0x04f786fd, 0x04f7c6fe, 0x04f886ff, 0x04f98700,
+    0x04f9c701, 0x04fac702, 0x04fb8704, 0x04fc0705, 0x04fc8706, 0x04fcc708, 0x04fd0709, 0x04fec70a,
+    0x04ff070b, 0x0500070c, 0x0500c70d, 0x0503070e, 0x05034710, 0x05038712, 0x0503c714, 0x05040716,
+    0x05044718, 0x0504871a, 0x0504c71c, 0x0505071e, 0x05054720, 0x0505c722, 0x05060724, 0x05064726,
+    0x05068728, 0x0509c72a, 0x050ac72b, 0x050b072d, 0x050b472f, 0x050b8731, 0x050bc733, 0x050c4734,
+    0x050cc735, 0x050dc736, 0x050e0738, 0x050e8739, 0x050ec73b, 0x050f073d, 0x050f473f, 0x050f8741,
+    0x050fc743, 0x05100745, 0x05104747, 0x05108749, 0x0510c74b, 0x0511074d, 0x0511474f, 0x05118751,
+    0x0511c753, 0x05128755, 0x05130756, 0x05138757, 0x05150758, 0x0515c75a, 0x0516075c, 0x0516475e,
+    0x05168760, 0x0516c762, 0x05170764, 0x05174766, 0x05178768, 0x0517c76a, 0x0518076c, 0x0518476e,
+    0x05188770, 0x0518c772, 0x05190774, 0x0519c776, 0x051a0778, 0x051a477a, 0x051a877c, 0x051b477e,
+    0x051bc77f, 0x051c8780, 0x051cc781, 0x051d0783, 0x051d4785, 0x051d8787, 0x051dc789, 0x051e078b,
+    0x051e478d, 0x051e878f, 0x051ec791, 0x051f0793, 0x051f4795, 0x051f8797, 0x051fc799, 0x0520079b,
+    0x0520479e, 0x052147a1, 0x052187a3, 0x0521c7a5, 0x052207a7, 0x052347a9, 0x052487aa, 0x0524c7ac,
+    0x052507ae, 0x052547b0, 0x052587b2, 0x0525c7b4, 0x052607b6, 0x052647b8, 0x052687ba, 0x0526c7bc,
+    0x052707be, 0x052747c0, 0x052787c2, 0x0527c7c4, 0x052947c6, 0x052a87c7, 0x052b07c8, 0x052b47ca,
+    0x052b87cc, 0x052bc7ce, 0x052c07d0, 0x052c47d2, 0x052c87d4, 0x052cc7d6, 0x052d07d8, 0x052d47da,
+    0x052d87dc, 0x052dc7de, 0x052e07e0, 0x052e47e2, 0x052fc7e4, 0x053247e5, 0x053287e7, 0x0532c7e9,
+    0x053307eb, 0x053347ed, 0x053387ef, 0x053447f1, 0x053707f2, 0x053747f4, 0x053787f6, 0x0537c7f8,
+    0x053807fa, 0x053847fc, 0x053887fe, 0x0538c800, 0x05390802, 0x05394804, 0x05398806, 0x0539c808,
+    0x053a080a, 0x053a480c, 0x053d880e, 0x053dc810, 0x053e0812, 0x053e4814, 0x053e8816, 0x053ec818,
+    0x053f081a, 0x053f481c, 0x053f881e, 0x053fc820, 0x05400822, 0x05404824, 0x05408826, 0x0540c828,
+    0x0543082a, 0x0543482c, 0x0543882e, 0x0543c830, 0x0545c832, 0x05460834, 0x05464836, 0x05468838,
+    0x0546c83a, 0x0547083c, 0x0547483e, 0x05478840, 0x0547c842, 0x05480844, 0x05484846, 0x05488848,
+    0x0548c84a, 0x0549084c, 0x054bc84e, 0x054c0850, 0x054c4852, 0x054c8854, 0x054cc856, 0x054d0858,
+    0x054d485a, 0x054d885c, 0x054dc85e, 0x054e0860, 0x054e4862, 0x054e8864, 0x054ec866, 0x054f0868,
+    0x0550086a, 0x0550486b, 0x0553886c, 0x0553c86e, 0x0556c870, 0x05570872, 0x055a0874, 0x055a4876,
+    0x055dc878, 0x055f0879, 0x055f487a, 0x055f887b, 0x055fc87d, 0x0560087f, 0x05604881, 0x05608883,
+    0x0560c885, 0x05610887, 0x0561488a, 0x0561c88c, 0x0563888d, 0x0563c88f, 0x05640891, 0x05644893,
+    0x05648895, 0x0564c897, 0x05650899, 0x056bc89b, 0x056d089c, 0x056d489d, 0x056dc89e, 0x0571089f,
+    0x057148a0, 0x057788a1, 0x057a88a2, 0x057bc8a3, 0x057c08a4, 0x057dc8a5, 0x058088a6, 0x0580c8a7,
+    0x058108a8, 0x0581c8a9, 0x058888aa, 0x0588c8ab, 0x058908ac, 0x058b88ad, 0x058bc8ae, 0x058d08af,
+    0x058d48b0, 0x059b48b1, 0x059b88b2, 0x059bc8b3, 0x059c08b5, 0x059c48b7, 0x059c88b9, 0x059cc8bb,
+    0x059d08bd, 0x059d48bf, 0x059d88c1, 0x059dc8c3, 0x059e08c5, 0x059e48c7, 0x059e88c9, 0x059ec8cb,
+    0x059f08cd, 0x059f48cf, 0x05a008d1, 0x05ac88d2, 0x05adc8d3, 0x05b048d4, 0x05b088d5, 0x05b308d6,
+    0x05b548d7, 0x05b588d8, 0x05b608d9, 0x05b848da, 0x05bac8db, 0x05bb08dc, 0x05bb48dd, 0x05bc08de,
+    0x05cd48df, 0x05e8c8e0, 0x05edc8e1, 0x05ee08e2, 0x05ee48e3, 0x05ee88e4, 0x05f188e5, 0x05f2c8e6,
+    0x05f4c8e7, 0x05f508e8, 0x05f548e9, 0x05f648ea, 0x05f688eb, 0x0600c8ec, 0x060248ed, 0x061548ee,
+    0x062588ef, 0x062cc8f0, 0x062d88f2, 0x062e48f4, 0x063088f6, 0x063188f8, 0x0631c8fa, 0x063208fc,
+    0x063248fe, 0x06328900, 0x0632c902, 0x06330904, 0x06334906, 0x06338908, 0x0633c90a, 0x0634090c,
+    0x0634490e, 0x06348910, 0x0634c912, 0x0636c914, 0x06370915, 0x06374917, 0x06380919, 0x0638c91b,
+    0x0639091d, 0x0639491f, 0x063a0921, 0x063a8923, 0x063b4925, 0x063c0927, 0x063c8929, 0x0674092b,
+    0x0674492c, 0x06a0092d, 0x06a4092e, 0x06aa492f, 0x06aac931, 0x06ad0933, 0x06adc934, 0x06d48935,
+    0x06d4c936, 0x06d60937, 0x06d70938, 0x06d7c939, 0x070f093b, 0x071fc93d, 0x0734093f, 0x07348940,
+    0x0734c941, 0x07354943, 0x07360944, 0x07364945, 0x07368946, 0x07370947, 0x07374948, 0x07378949,
+    0x073b494a, 0x0741094b, 0x0742094c, 0x0742c94d, 0x0743494e, 0x0743c94f, 0x07440950, 0x07444951,
+    0x07450952, 0x07470954, 0x07480955, 0x07484956, 0x07488957, 0x07490958, 0x07498959, 0x0749c95a,
+    0x074a095b, 0x074a495c, 0x074ac95d, 0x074f895e, 0x0754895f, 0x075ac960, 0x075b8962, 0x075bc964,
+    0x075c0967, 0x075c8969, 0x075cc96b, 0x075d096d, 0x075d496f, 0x075d8971, 0x075e0973, 0x075ec974,
+    0x075f0976, 0x075f4978, 0x075f897a, 0x075fc97c, 0x0760c97e, 0x0763097f, 0x07640980, 0x0767c981,
+    0x07688982, 0x076e8983, 0x076ec984, 0x077b8985, 0x0790c986, 0x07a68987, 0x07a74988, 0x07a78989,
+    0x07bfc98a, 0x07df498b, 0x07ef498c, 0x07ef898d, 0x07efc98e, 0x07f0098f, 0x07fbc990, 0x07fd8991,
+    0x07ff4992, 0x07ff8993, 0x08000994, 0x08004995, 0x08008996, 0x0800c997, 0x08010998, 0x08014999,
+    0x0801899a, 0x0801c99b, 0x0802099c, 0x0802499d, 0x0802899e, 0x0804099f, 0x080449a0, 0x080489a1,
+    0x0804c9a2, 0x080509a3, 0x080549a4, 0x080589a5, 0x080609a7, 0x080649a8, 0x080689a9, 0x0806c9aa,
+    0x080709ab, 0x080749ad, 0x0807c9af, 0x080889b1, 0x080909b2, 0x080949b3, 0x080989b5, 0x0809c9b8,
+    0x080a09b9, 0x080a49ba, 0x080bc9bb, 0x080c09bc, 0x080c49c0, 0x080c89c5, 0x080cc9c6, 0x080d09c8,
+    0x080d49cb, 0x080d89cc, 0x080dc9ce, 0x080e49d1, 0x080e89d2, 0x080f09d3, 0x080f89d5, 0x081049d6,
+    0x0810c9d7, 0x081109d8, 0x0811c9d9, 0x081209db, 0x081249dd, 0x081389df, 0x081489e0, 0x0814c9e3,
+    0x0815c9e4, 0x081689e8, 0x081749e9, 0x081789ea, 0x0817c9eb, 0x081c09ec, 0x081e49ed, 0x082849ee,
+    0x082909f0, 0x082949f1, 0x082a09f4, 0x082a49f6, 0x082ac9f8, 0x082b09fb, 0x082b49fc, 0x082b89fe,
+    0x082d8a00, 0x082f4a02, 0x0836ca03, 0x08400a04, 0x08404a07, 0x08408a0a, 0x0840ca0b, 0x08414a0d,
+    0x08418a10, 0x0841ca13, 0x08420a14, 0x08424a15, 0x08428a17, 0x0842ca18, 0x08430a19, 0x08434a1a,
+    0x08438a1b, 0x0843ca1c, 0x08440a1e, 0x08444a1f, 0x08448a20, 0x0844ca21, 0x08454a22, 0x08458a23,
+    0x08464a25, 0x08468a26, 0x0846ca27, 0x08470a28, 0x08474a29, 0x08484a2a, 0x08490a2d, 0x08498a2e,
+    0x0849ca2f, 0x084a0a30, 0x084a4a31, 0x084a8a32, 0x084b0a33, 0x084b4a34, 0x084b8a35, 0x084bca36,
+    0x084c0a37, 0x084c4a38, 0x084cca39, 0x084d0a3a, 0x084d4a3b, 0x084d8a3c, 0x084dca3d, 0x084e0a3e,
+    0x084e4a3f, 0x084eca40, 0x084f0a43, 0x084f4a44, 0x084f8a45, 0x084fca46, 0x08500a47, 0x08504a48,
+    0x08508a49, 0x0850ca4a, 0x08514a4c, 0x08518a4d, 0x0851ca4e, 0x08520a4f, 0x08524a50, 0x08580a51,
+    0x08584a52, 0x08588a54, 0x0858ca57, 0x08590a59, 0x08594a5a, 0x08598a5c, 0x0859ca5f, 0x085a0a63,
+    0x085a4a65, 0x085a8a66, 0x085aca68, 0x085b0a6b, 0x085b4a6c, 0x085b8a6d, 0x085bca6e, 0x085c0a6f,
+    0x085c4a70, 0x085c8a72, 0x085cca75, 0x085d0a77, 0x085d4a78, 0x085d8a7a, 0x085dca7d, 0x085e0a81,
+    0x085e4a83, 0x085e8a84, 0x085eca86, 0x085f0a89, 0x085f4a8a, 0x085f8a8b, 0x085fca8c, 0x0860ca8e,
+    0x08610a8f, 0x08644a90, 0x08654a91, 0x086d4a92, 0x086e8a93, 0x086f8a95, 0x086fca96, 0x08800a97,
+    0x0880ca98, 0x08818a99, 0x0883ca9a, 0x08844a9b, 0x08848a9c, 0x08850a9d, 0x08854a9f, 0x08858aa0,
+    0x0885caa1, 0x08860aa2, 0x08864aa3, 0x08878aa4, 0x0888caa6, 0x08894aa7, 0x088a0aa9, 0x088a4aaa,
+    0x088a8aab, 0x088acaac, 0x088b0aad, 0x088b4aaf, 0x088bcab2, 0x088c0ab4, 0x088d8ab7, 0x088e0ab8,
+    0x088f0aba, 0x08940abb, 0x08944abd, 0x0895cac0, 0x08964ac2, 0x08968ac4, 0x08978ac6, 0x0898cac8,
+    0x089a8ac9, 0x089acacb, 0x08a08acd, 0x08a0cace, 0x08a54acf, 0x08a58ad1, 0x08a64ad3, 0x08a74ad4,
+    0x08a90ad6, 0x08a94ad7, 0x08b00ad8, 0x08b04ad9, 0x08b08ada, 0x08b0cadb, 0x08b10adc, 0x08b14add,
+    0x08b20ade, 0x08b58adf, 0x08b5cae1, 0x08b60ae3, 0x08b64ae6, 0x08bb8ae9, 0x08bbcaea, 0x08bd0aed,
+    0x08bfcaee, 0x08c00aef, 0x08c94af0, 0x08ca4af1, 0x08ca8af2, 0x08d04af3, 0x08d64af4, 0x08d68af6,
+    0x08d70af8, 0x08d7cafa, 0x08d84afb, 0x08d88afd, 0x08d8caff, 0x08d90b01, 0x08d94b03, 0x08da0b04,
+    0x08da4b06, 0x08dacb07, 0x08db0b09, 0x08dccb0b, 0x08dd0b0c, 0x08dd4b0d, 0x08dd8b0e, 0x08ddcb10,
+    0x08de0b12, 0x08de4b14, 0x08de8b16, 0x08dfcb17, 0x08e70b18, 0x08e7cb19, 0x08e88b1a, 0x08e94b1b,
+    0x08ea8b1c, 0x08eb8b1d, 0x08f04b1e, 0x08f08b1f, 0x08f0cb20, 0x08f18b21, 0x08fa0b22, 0x08ff0b24,
+    0x08ff4b25, 0x08ff8b26, 0x09128b27, 0x09180b29, 0x09184b2a, 0x09188b2b, 0x0918cb2c, 0x09190b2d,
+    0x09194b2e, 0x09198b2f, 0x0919cb30, 0x091a0b31, 0x091a4b32, 0x091d0b33, 0x091d4b36, 0x091d8b39,
+    0x091dcb3c, 0x091e0b3f, 0x091e4b42, 0x091e8b45, 0x091ecb48, 0x091f0b4b, 0x091f4b4e, 0x091f8b52,
+    0x091fcb56, 0x09200b5a, 0x09204b5e, 0x09208b62, 0x0920cb66, 0x09210b6a, 0x09214b6e, 0x09218b72,
+    0x0921cb76, 0x09220b7a, 0x09224b7c, 0x09228b7e, 0x0922cb80, 0x09230b82, 0x09234b84, 0x09238b86,
+    0x0923cb88, 0x09240b8a, 0x09244b8c, 0x09248b8f, 0x0924cb92, 0x09250b95, 0x09254b98, 0x09258b9b,
+    0x0925cb9e, 0x09260ba1, 0x09264ba4, 0x09268ba7, 0x0926cbaa, 0x09270bad, 0x09274bb0, 0x09278bb3,
+    0x0927cbb6, 0x09280bb9, 0x09284bbc, 0x09288bbf, 0x0928cbc2, 0x09290bc5, 0x09294bc8, 0x09298bcb,
+    0x0929cbce, 0x092a0bd1, 0x092a4bd5, 0x092a8bd8, 0x092acbdb, 0x092b0bde, 0x092b4be1, 0x092b8be4,
+    0x092bcbe7, 0x092c0bea, 0x092c4bed, 0x092c8bf0, 0x092ccbf3, 0x092d0bf6, 0x092d4bf9, 0x092e0bfc,
+    0x09314bfd, 0x0931cbfe, 0x0936cbff, 0x093a8c00, 0x09400c02, 0x09404c03, 0x0940cc04, 0x0943cc05,
+    0x0948cc06, 0x095c4c07, 0x095ccc08, 0x09620c09, 0x09640c0a, 0x09650c0b, 0x0965cc0c, 0x09674c0d,
+    0x09680c0e, 0x096c4c0f, 0x096ccc10, 0x096dcc11, 0x096e0c12, 0x096e8c13, 0x096f4c14, 0x09704c16,
+    0x0971cc17, 0x09728c18, 0x0972cc19, 0x09738c1a, 0x09780c1b, 0x09798c1c, 0x09824c1d, 0x09840c1e,
+    0x09894c1f, 0x098c0c21, 0x098e0c22, 0x09938c23, 0x09988c24, 0x099a4c25, 0x099a8c29, 0x09ab0c2f,
+    0x09da0c30, 0x09da4c31, 0x09db8c32, 0x09dbcc33, 0x09dc8c34, 0x09dccc35, 0x09dd0c36, 0x09dd4c37,
+    0x09e54c38, 0x09e58c39, 0x09e5cc3a, 0x09f08c3b, 0x09f20c3c, 0x09f24c3e, 0x09f2cc40, 0x09f34c41,
+    0x09f64c42, 0x09fa0c43, 0x09fa4c44, 0x0a4acc45, 0x0a4b0c46, 0x0a58cc47, 0x0a594c49, 0x0a5b8c4b,
+    0x0a5bcc4d, 0x0a664c4f, 0x0a6c0c50, 0x0a6f8c51, 0x0a710c52, 0x0a714c53, 0x0a71cc54, 0x0a758c55,
+    0x0a764c57, 0x0a7d0c58, 0x0a7d4c5a, 0x0a7d8c5b, 0x0a7e0c5d, 0x0a7e4c5e, 0x0a800c5f, 0x0a804c60,
+    0x0a808c62, 0x0a80cc63, 0x0a810c64, 0x0a814c65, 0x0a818c66, 0x0a830c67, 0x0a874c6b, 0x0a880c6c,
+    0x0a884c6e, 0x0a888c6f, 0x0a88cc71, 0x0a890c73, 0x0a894c75, 0x0a898c77, 0x0a89cc79, 0x0a8a4c7b,
+    0x0a8a8c7d, 0x0a8bcc7f, 0x0a8c0c80, 0x0a8f4c82, 0x0a8f8c83, 0x0a8fcc84, 0x0a9a8c85, 0x0a9b8c87,
+    0x0a9d0c89, 0x0a9d4c8c, 0x0a9d8c8e, 0x0aa94c91, 0x0aaa8c93, 0x0aaacc94, 0x0ab5cc95, 0x0abecc97,
+    0x0abf4c9a, 0x0afb0c9c, 0x0afb4c9d, 0x0afb8c9e, 0x0afbcc9f, 0x0b19cca0, 0x0b1a4ca2, 0x0b210ca4,
+    0x0b214ca5, 0x0b218ca6, 0x0b220ca7, 0x0b224ca8, 0x0b238ca9, 0x0b248caa, 0x0b250cab, 0x0b254cac,
+    0x0b258cad, 0x0b260cae, 0x0b268caf, 0x0b278cb0, 0x0b27ccb1, 0x0b280cb2, 0x0b288cb3, 0x0b28ccb4,
+    0x0b290cb5, 0x0b294cb6, 0x0b298cb7, 0x0b2a0cb8, 0x0b2a8cb9, 0x0b2accba, 0x0b2b0cbb, 0x0b2b4cbc,
+    0x0b2b8cbd, 0x0b2c4cbe, 0x0b2d0cbf, 0x0b2e8cc1, 0x0b2f0cc2, 0x0b2f4cc3, 0x0b318cc4, 0x0b328cc5,
+    0x0b330cc6, 0x0b334cc7, 0x0b340cc8, 0x0b344cc9, 0x0b348cca, 0x0b370ccb, 0x0b390ccc, 0x0b3a4ccd,
+    0x0b3e4cce, 0x0b4c4cd0, 0x0b4dccd2, 0x0b4e0cd3, 0x0b4e4cd4, 0x0b4e8cd5, 0x0b504cd6, 0x0b520cd8,
+    0x0b524cdb, 0x0b53ccdc, 0x0b544cdd, 0x0b550cde, 0x0b554cdf, 0x0b564ce0, 0x0b574ce1, 0x0b580ce2,
+    0x0b58cce3, 0x0b7a0ce4, 0x0b7a8ce5, 0x0b7b4ce6, 0x0b7bcce7, 0x0b7d8ce8, 0x0b7dcce9, 0x0b868cea,
+    0x0b878cec, 0x0b87ccee, 0x0b898cf0, 0x0b89ccf1, 0x0b8a0cf2, 0x0b8a4cf4, 0x0b8a8cf6, 0x0b8accf7,
+    0x0b8b0cf8, 0x0b8b8cf9, 0x0b8c0cfa, 0x0b8c4cfb, 0x0b8c8cfc, 0x0b8d4cfd, 0x0b8e4cfe, 0x0b8f4cff,
+    0x0b8fcd00, 0x0b900d01, 0x0ba08d02, 0x0ba0cd03, 0x0ba14d04, 0x0ba24d05, 0x0ba2cd06, 0x0ba38d07,
+    0x0ba3cd08, 0x0ba40d09, 0x0ba48d0a, 0x0ba4cd0b, 0x0ba50d0c, 0x0ba58d0d, 0x0ba5cd0e, 0x0ba60d0f,
+    0x0ba64d10, 0x0ba6cd11, 0x0ba78d12, 0x0ba7cd13, 0x0ba80d14, 0x0ba84d15, 0x0ba88d16, 0x0ba8cd17,
+    0x0ba90d18, 0x0ba98d19, 0x0baa0d1a, 0x0baacd1b, 0x0bab4d1c, 0x0babcd1d, 0x0bac4d1e, 0x0bac8d1f,
+    0x0bae4d20, 0x0bae8d21, 0x0baf8d22, 0x0bafcd23, 0x0bb00d24, 0x0bb04d25, 0x0bb08d26, 0x0bb0cd27,
+    0x0bb10d28, 0x0bb14d29, 0x0bb20d2a, 0x0bb24d2b, 0x0bb2cd2c, 0x0bb30d2d, 0x0bb34d2e, 0x0bb3cd2f,
+    0x0bb40d30, 0x0bb44d31, 0x0bb48d32, 0x0bb4cd33, 0x0bb50d34, 0x0bb58d35, 0x0bb60d36, 0x0bb64d37,
+    0x0bb68d38, 0x0bb6cd39, 0x0bb70d3a, 0x0bb74d3b, 0x0bb7cd3c, 0x0bb80d3d, 0x0bb88d3e, 0x0bb90d3f,
+    0x0bb94d40, 0x0bba0d41, 0x0bba4d42, 0x0bbacd43, 0x0bbb0d44, 0x0bbb4d45, 0x0bbb8d46, 0x0bbbcd47,
+    0x0bbc0d48, 0x0bbc8d49, 0x0bbccd4a, 0x0bc00d4b, 0x0bc04d4c, 0x0bc08d4d, 0x0bc0cd4e, 0x0bc10d4f,
+    0x0bc14d50, 0x0bc18d51, 0x0bc1cd52, 0x0bc20d53, 0x0bc24d54, 0x0bc28d55, 0x0bc2cd56, 0x0bc30d57,
+    0x0bc34d58, 0x0bc38d59, 0x0bc3cd5a, 0x0bc40d5b, 0x0bc44d5c, 0x0bc48d5d, 0x0bc4cd5e, 0x0bc50d5f,
+    0x0bc54d60, 0x0bc58d61, 0x0bc5cd62, 0x0bc60d63, 0x0bc64d64, 0x0bc68d65, 0x0bc6cd66, 0x0bc70d67,
+    0x0bc74d68, 0x0bc78d69, 0x0bc7cd6a, 0x0bc80d6b, 0x0bc84d6c, 0x0bc88d6d, 0x0bc8cd6e, 0x0bc90d6f,
+    0x0bc94d70, 0x0bc98d71, 0x0bc9cd72, 0x0bca0d73, 0x0bca4d74, 0x0bca8d75, 0x0bcacd76, 0x0bcb0d77,
+    0x0bcb4d78, 0x0bcb8d79, 0x0bcbcd7a, 0x0bcc0d7b, 0x0bcc4d7c, 0x0bcc8d7d, 0x0bcccd7e, 0x0bcd0d7f,
+    0x0bcd4d80, 0x0bcd8d81, 0x0bcdcd82, 0x0bce0d83, 0x0bce4d84, 0x0bce8d85, 0x0bcecd86, 0x0bcf0d87,
+    0x0bcf4d88, 0x0bcf8d89, 0x0bcfcd8a, 0x0bd00d8b, 0x0bd04d8c, 0x0bd08d8d, 0x0bd0cd8e, 0x0bd10d8f,
+    0x0bd14d90, 0x0bd18d91, 0x0bd1cd92, 0x0bd20d93, 0x0bd24d94, 0x0bd28d95, 0x0bd2cd96, 0x0bd30d97,
+    0x0bd34d98, 0x0bd38d99, 0x0bd3cd9a, 0x0bd40d9b, 0x0bd44d9c, 0x0bd48d9d, 0x0bd4cd9e, 0x0bd50d9f,
+    0x0bd54da0, 0x0bd58da1, 0x0bd5cda2, 0x0bd60da3, 0x0bd64da4, 0x0bd68da5, 0x0bd6cda6, 0x0bd70da7,
+    0x0bd74da8, 0x0bd78da9, 0x0bd7cdaa, 0x0bd80dab, 0x0bd84dac, 0x0bd88dad, 0x0bd8cdae, 0x0bd90daf,
+    0x0bd94db0, 0x0bd98db1, 0x0bd9cdb2, 0x0bda0db3, 0x0bda4db4, 0x0bda8db5, 0x0bdacdb6, 0x0bdb0db7,
+    0x0bdb4db8, 0x0bdb8db9, 0x0bdbcdba, 0x0bdc0dbb, 0x0bdc4dbc, 0x0bdc8dbd, 0x0bdccdbe, 0x0bdd0dbf,
+    0x0bdd4dc0, 0x0bdd8dc1, 0x0bddcdc2, 0x0bde0dc3, 0x0bde4dc4, 0x0bde8dc5, 0x0bdecdc6, 0x0bdf0dc7,
+    0x0bdf4dc8, 0x0bdf8dc9, 0x0bdfcdca, 0x0be00dcb, 0x0be04dcc, 0x0be08dcd, 0x0be0cdce, 0x0be10dcf,
+    0x0be14dd0, 0x0be18dd1, 0x0be1cdd2, 0x0be20dd3, 0x0be24dd4, 0x0be28dd5, 0x0be2cdd6, 0x0be30dd7,
+    0x0be34dd8, 0x0be38dd9, 0x0be3cdda, 0x0be40ddb, 0x0be44ddc, 0x0be48ddd, 0x0be4cdde, 0x0be50ddf,
+    0x0be54de0, 0x0be58de1, 0x0be5cde2, 0x0be60de3, 0x0be64de4, 0x0be68de5, 0x0be6cde6, 0x0be70de7,
+    0x0be74de8, 0x0be78de9, 0x0be7cdea, 0x0be80deb, 0x0be84dec, 0x0be88ded, 0x0be8cdee, 0x0be90def,
+    0x0be94df0, 0x0be98df1, 0x0be9cdf2, 0x0bea0df3, 0x0bea4df4, 0x0bea8df5, 0x0beacdf6, 0x0beb0df7,
+    0x0beb4df8, 0x0beb8df9, 0x0bebcdfa, 0x0bec0dfb, 0x0bec4dfc, 0x0bec8dfd, 0x0beccdfe, 0x0bed0dff,
+    0x0bed4e00, 0x0bed8e01, 0x0bedce02, 0x0bee0e03, 0x0bee4e04, 0x0bee8e05, 0x0beece06, 0x0bef0e07,
+    0x0bef4e08, 0x0bef8e09, 0x0befce0a, 0x0bf00e0b, 0x0bf04e0c, 0x0bf08e0d, 0x0bf0ce0e, 0x0bf10e0f,
+    0x0bf14e10, 0x0bf18e11, 0x0bf1ce12, 0x0bf20e13, 0x0bf24e14, 0x0bf28e15, 0x0bf2ce16, 0x0bf30e17,
+    0x0bf34e18, 0x0bf38e19, 0x0bf3ce1a, 0x0bf40e1b, 0x0bf44e1c, 0x0bf48e1d, 0x0bf4ce1e, 0x0bf50e1f,
+    0x0bf54e20, 0x0c008e21, 0x0c00ce22, 0x0c01ce24, 0x0c020e25, 0x0c024e26, 0x0c048e27, 0x0c050e28,
+    0x0c054e29, 0x0c068e2a, 0x0c06ce2b, 0x0c0b0e2c, 0x0c0b4e2d, 0x0c0cce2e, 0x0c0d8e2f, 0x0c0e0e30,
+    0x0c0e4e31, 0x0c0e8e32, 0x0c13ce33, 0x0c268e34, 0x0c26ce35, 0x0c270e36, 0x0c280e37, 0x0c290e38,
+    0x0c2a0e39, 0x0c2ace3a, 0x0c2fce3b, 0x0c320e3c, 0x0c32ce3d, 0x0c338e3e, 0x0c33ce3f, 0x0c360e40,
+    0x0c3b4e41, 0x0c3ece42, 0x0c498e43, 0x0c4c4e44, 0x0c4c8e45, 0x0c4cce47, 0x0c4d0e49, 0x0c4d4e4a,
+    0x0c4d8e4c, 0x0c4dce4e, 0x0c4e0e4f, 0x0c4e4e51, 0x0c4e8e52, 0x0c4ece54, 0x0c4f0e56, 0x0c4f4e58,
+    0x0c4f8e5a, 0x0c4fce5c, 0x0c500e5e, 0x0c504e60, 0x0c508e61, 0x0c50ce62, 0x0c510e64, 0x0c514e66,
+    0x0c518e67, 0x0c51ce69, 0x0c520e6a, 0x0c524e6b, 0x0c528e6d, 0x0c52ce6e, 0x0c530e6f, 0x0c534e70,
+    0x0c538e71, 0x0c53ce72, 0x0c540e73, 0x0c544e75, 0x0c548e76, 0x0c54ce78, 0x0c550e79, 0x0c554e7b,
+    0x0c558e7c, 0x0c55ce7e, 0x0c560e7f, 0x0c564e81, 0x0c568e84, 0x0c56ce86, 0x0c570e87, 0x0c574e88,
+    0x0c578e8a, 0x0c57ce8d, 0x0c580e8f, 0x0c584e90, 0x0c588e91, 0x0c58ce93, 0x0c590e94, 0x0c594e95,
+    0x0c598e97, 0x0c59ce99, 0x0c5a0e9b, 0x0c5a4e9d, 0x0c5a8ea0, 0x0c5acea2, 0x0c5b0ea5, 0x0c5b4ea7,
+    0x0c5b8ea9, 0x0c5bceab, 0x0c5c0ead, 0x0c5c4eaf, 0x0c5c8eb1, 0x0c5cceb3, 0x0c5d0eb5, 0x0c5d4eb8,
+    0x0c5d8ebb, 0x0c5dcebd, 0x0c5e0ebf, 0x0c5e4ec1, 0x0c5e8ec4, 0x0c5ecec6, 0x0c5f0ec8, 0x0c5f4eca,
+    0x0c5f8ecc, 0x0c5fcece, 0x0c600ecf, 0x0c604ed1, 0x0c608ed2, 0x0c60ced4, 0x0c610ed6, 0x0c614ed8,
+    0x0c618eda, 0x0c61cedb, 0x0c620edd, 0x0c624ee0, 0x0c628ee2, 0x0c62cee4, 0x0c630ee7, 0x0c634ee9,
+    0x0c638eea, 0x0c740eec, 0x0c744eed, 0x0c74ceee, 0x0c750eef, 0x0c758ef0, 0x0c768ef1, 0x0c76cef2,
+    0x0c77cef3, 0x0c780ef4, 0x0c800ef5, 0x0c804ef8, 0x0c808efb, 0x0c80cefe, 0x0c810f01, 0x0c814f04,
+    0x0c818f07, 0x0c81cf0a, 0x0c820f0d, 0x0c824f10, 0x0c828f13, 0x0c82cf16, 0x0c830f19, 0x0c834f1c,
+    0x0c838f1f, 0x0c83cf22, 0x0c840f25, 0x0c844f28, 0x0c848f2b, 0x0c84cf2e, 0x0c850f31, 0x0c854f34,
+    0x0c858f37, 0x0c85cf3a, 0x0c860f3d, 0x0c864f40, 0x0c868f43, 0x0c86cf46, 0x0c870f49, 0x0c874f4c,
+    0x0c878f50, 0x0c880f54, 0x0c884f57, 0x0c888f5a, 0x0c88cf5d, 0x0c890f60, 0x0c894f63, 0x0c898f66,
+    0x0c89cf69, 0x0c8a0f6c, 0x0c8a4f6f, 0x0c8a8f72, 0x0c8acf75, 0x0c8b0f78, 0x0c8b4f7b, 0x0c8b8f7e,
+    0x0c8bcf81, 0x0c8c0f84, 0x0c8c4f87, 0x0c8c8f8a, 0x0c8ccf8d, 0x0c8d0f90, 0x0c8d4f93, 0x0c8d8f96,
+    0x0c8dcf99, 0x0c8e0f9c, 0x0c8e4f9f, 0x0c8e8fa2, 0x0c8ecfa5, 0x0c8f0fa8, 0x0c8f4fab, 0x0c8f8fae,
+    0x0c8fcfb1, 0x0c900fb4, 0x0c904fb7, 0x0c908fba, 0x0c90cfbd, 0x0cb00fc0, 0x0cb04fc2, 0x0cb08fc4,
+    0x0cb0cfc6, 0x0cb10fc8, 0x0cb14fca, 0x0cb18fcc, 0x0cb1cfce, 0x0cb20fd0, 0x0cb24fd2, 0x0cb28fd5,
+    0x0cb2cfd8, 0x0cd60fdb, 0x0cd64fdd, 0x0cd68fdf, 0x0cd6cfe1, 0x0cd70fe3, 0x0cd74fe5, 0x0cd78fe7,
+    0x0cd7cfe9, 0x0cd80feb, 0x0cd84fed, 0x0cd88fef, 0x0cd8cff2, 0x0cd90ff5, 0x0cd94ff8, 0x0cd98ffb,
+    0x0cd9cffe, 0x0cda1001, 0x0cda5004, 0x0cda9007, 0x0cdad00a, 0x0cdb100d, 0x0cdb5010, 0x0cdb9013,
+    0x0cdbd016, 0x0cdc1019, 0x0cf8101c, 0x0cf8501e, 0x0cf89020, 0x0cf8d022, 0x0cf91024, 0x0cf95026,
+    0x0cf99028, 0x0cf9d02a, 0x0cfa102c, 0x0cfa502e, 0x0cfa9031, 0x0cfad034, 0x0cfb1037, 0x0cfb503a,
+    0x0cfb903d, 0x0cfbd040, 0x0cfc1043, 0x0cfc5046, 0x0cfc9049, 0x0cfcd04c, 0x0cfd104f, 0x0cfd5052,
+    0x0cfd9055, 0x0cfdd058, 0x0cfe105b, 0x0cfe505e, 0x0cfe9061, 0x0cfed064, 0x0cff1067, 0x0cff506a,
+    0x0cff906d, 0x0e6cd070, 0x10e6d071, 0x11081072, 0x13801073, 0x138d9074, 0x138fd075, 0x14009076,
+    0x140f1077, 0x1557d078, 0x15b5d079, 0x161ad07a, 0x163ad07b, 0x163fd07c, 0x16c0107d, 0x178c907e,
+    0x1794107f, 0x188e1080, 0x19025081, 0x1998d082, 0x199a5083, 0x19bd9084, 0x19c99085, 0x19ffd086,
+    0x1a7a5087, 0x1a89d088, 0x1bd65089, 0x1e13d08a, 0x1f5d908b, 0x2029908c, 0x2032908d, 0x2034108e,
+    0x203d908f, 0x20405090, 0x2049d091, 0x20505092, 0x2069d093, 0x214fd094, 0x21905095, 0x22879096,
+    0x229f5097, 0x22e3d098, 0x2318d099, 0x2361909a, 0x237e909b, 0x23a6d09c, 0x23c9d09d, 0x2437909e,
+    0x24eb909f, 0x25ae10a0, 0x2790d0a1, 0x27b490a2, 0x27f0d0a3, 0x292510a4, 0x292710a5, 0x292790a6,
+    0x2929d0a7, 0x292a10a8, 0x292b10a9, 0x292c10aa, 0x292e90ab, 0x292f90ac, 0x292fd0ad, 0x293010ae,
+    0x293090af, 0x293410b0, 0x293450b1, 0x293490b2, 0x2934d0b3, 0x293510b4, 0x293590b5, 0x2935d0b6,
+    0x293650b7, 0x293690b8, 0x2936d0b9, 0x293710ba, 0x293750bb, 0x293790bc, 0x2937d0bd, 0x293810be,
+    0x293850bf, 0x293890c0, 0x2938d0c1, 0x293950c2, 0x293990c3, 0x2939d0c4, 0x293a90c5, 0x293ad0c6,
+    0x293b10c7, 0x293b50c8, 0x293b90c9, 0x293bd0ca, 0x293c10cb, 0x293c50cc, 0x293c90cd, 0x293cd0ce,
+    0x293d10cf, 0x293d50d0, 0x293dd0d1, 0x293e10d2, 0x293e50d3, 0x293e90d4, 0x293ed0d6, 0x293f50d8,
+    0x293f90d9, 0x293fd0db, 0x298390dc, 0x299110dd, 0x299150de, 0x2991d0df, 0x299350e0, 0x299410e1,
+    0x299450e3, 0x299a10e6, 0x299bd0e7, 0x299f10e8, 0x299f90e9, 0x29a550ea, 0x29a610ec, 0x29a650ee,
+    0x29a690f0, 0x29a850f2, 0x29ac10f3, 0x29ac50f4, 0x29b350f5, 0x29b390f6, 0x29b6d0f7, 0x29b7d0f8,
+    0x29bad0f9, 0x29bbd0fa, 0x29bc10fb, 0x29bc50fc, 0x29bd10fd, 0x29c510ff, 0x29c59100, 0x29ca1101,
+    0x29ca5103, 0x29cc5105, 0x29cc9106, 0x29ccd108, 0x29cd110a, 0x29cd510c, 0x29cd910e, 0x29cdd110,
+    0x29ce1112, 0x29ce5114, 0x29ce9116, 0x29ced118, 0x29cf111a, 0x29cf511c, 0x29d0111e, 0x29d29120,
+    0x29d2d122, 0x29d39124, 0x29d3d126, 0x29d69128, 0x29d85129, 0x29da912b, 0x29dad12c, 0x29db912d,
+    0x29ddd12e, 0x29de1130, 0x29de9131, 0x29e25132, 0x29e31133, 0x29e3d134, 0x29e55135, 0x29e61136,
+    0x29e65137, 0x29e69138, 0x29e6d13a, 0x29e7513c, 0x29e7913d, 0x29e7d13e, 0x29ead13f, 0x29ec5140,
+    0x29ec9141, 0x29ecd142, 0x29ed1143, 0x29ed5144, 0x29ed9145, 0x29edd146, 0x29fdd147, 0x2a0c1148,
+    0x2a581149, 0x2a58514b, 0x2a58914d, 0x2a58d14f, 0x2a591151, 0x2a595153, 0x2a599156, 0x2a59d158,
+    0x2a5a115b, 0x2a5a515d, 0x2a5a915f, 0x2a5ad162, 0x2a5b1165, 0x2a5b5167, 0x2a5b9169, 0x2a5bd16b,
+    0x2a5c116d, 0x2a5c516f, 0x2a5c9171, 0x2a5cd174, 0x2a5d1176, 0x2a5d5178, 0x2a5d917b, 0x2a5dd17d,
+    0x2a5e117f, 0x2a5e5182, 0x2a5e9184, 0x2a5ed186, 0x2a5f1188, 0x2a64918a, 0x2a68d18b, 0x2a71918c,
+    0x2a73d18d, 0x2a94d18e, 0x2a95918f, 0x2acc9190, 0x2acd5191, 0x2acf5192, 0x2acf9193, 0x2acfd195,
+    0x2ad05197, 0x2ad0919a, 0x2ad1d19d, 0x2ad2119e, 0x2ad3519f, 0x2ad391a0, 0x2ad491a1, 0x2ad4d1a2,
+    0x2ad551a3, 0x2ad691a4, 0x2ad811a5, 0x2ad891a6, 0x2ad8d1a8, 0x2adc11aa, 0x2adc51ab, 0x2adc91ac,
+    0x2add11ad, 0x2add51af, 0x2ade91b0, 0x2aded1b1, 0x2adf11b2, 0x2adf91b3, 0x2ae011b4, 0x2ae051b5,
+    0x2ae0d1b6, 0x2ae1d1b7, 0x2ae2d1b8, 0x2ae391b9, 0x2ae411bb, 0x2ae4d1bc, 0x2ae6d1bd, 0x2ae711be,
+    0x2ae7d1c0, 0x2ae891c1, 0x2aea51c2, 0x2aea91c3, 0x2aeb91c4, 0x2aebd1c5, 0x2aec91c6, 0x2aed91c7,
+    0x2aeed1c8, 0x35ec11ca, 0x35ec51cc, 0x35ec91cf, 0x35ecd1d1, 0x35ed11d4, 0x35ed51d6, 0x35ed91d8,
+    0x35edd1db, 0x35ee11de, 0x35ee51e0, 0x35ee91e2, 0x35eed1e4, 0x35ef11e7, 0x35ef51e9, 0x35ef91ec,
+    0x35efd1ef, 0x35f011f1, 0x35f051f4, 0x35f091f7, 0x35f0d1f9, 0x35f111fb, 0x35f151fd, 0x35f191ff,
+    0x35f2d202, 0x35f31204, 0x35f35206, 0x35f39208, 0x35f3d20b, 0x35f4120d, 0x35f4520f, 0x35f49212,
+    0x35f4d214, 0x35f51216, 0x35f55218, 0x35f5921b, 0x35f5d21e, 0x35f61221, 0x35f65224, 0x35f69227,
+    0x35f6d22a, 0x35f7122c, 0x35f7522f, 0x35f79231, 0x35f7d233, 0x35f81236, 0x35f85238, 0x35f8923b,
+    0x35f8d23d, 0x35f9123f, 0x35f95242, 0x35f99244, 0x35f9d246, 0x35fa1249, 0x35fa524b, 0x35fa924d,
+    0x35fad24f, 0x35fb1252, 0x35fb5255, 0x35fb9258, 0x35fbd25a, 0x35fc125c, 0x35fc525e, 0x35fc9260,
+    0x35fcd262, 0x35fd1264, 0x35fd5267, 0x35fd9269, 0x35fdd26b, 0x35fe126d, 0x35fe5270, 0x35fe9272,
+    0x35fed274, 0x3e401276, 0x3e405277, 0x3e409278, 0x3e40d279, 0x3e41127a, 0x3e41527b, 0x3e41927c,
+    0x3e41d27d, 0x3e42127e, 0x3e42527f, 0x3e429280, 0x3e42d281, 0x3e431282, 0x3e435283, 0x3e439284,
+    0x3e43d285, 0x3e441286, 0x3e445287, 0x3e449288, 0x3e44d289, 0x3e45128a, 0x3e45528b, 0x3e45928c,
+    0x3e45d28d, 0x3e46128e, 0x3e46528f, 0x3e469290, 0x3e46d291, 0x3e471292, 0x3e475293, 0x3e479294,
+    0x3e47d295, 0x3e481296, 0x3e485297, 0x3e489298, 0x3e48d299, 0x3e49129a, 0x3e49529b, 0x3e49929c,
+    0x3e49d29d, 0x3e4a129e, 0x3e4a529f, 0x3e4a92a0, 0x3e4ad2a1, 0x3e4b12a2, 0x3e4b52a3, 0x3e4b92a4,
+    0x3e4bd2a5, 0x3e4c12a6, 0x3e4c52a7, 0x3e4c92a8, 0x3e4cd2a9, 0x3e4d12aa, 0x3e4d52ab, 0x3e4d92ac,
+    0x3e4dd2ad, 0x3e4e12ae, 0x3e4e52af, 0x3e4e92b0, 0x3e4ed2b1, 0x3e4f12b2, 0x3e4f52b3, 0x3e4f92b4,
+    0x3e4fd2b5, 0x3e5012b6, 0x3e5052b7, 0x3e5092b8, 0x3e50d2b9, 0x3e5112ba, 0x3e5152bb, 0x3e5192bc,
+    0x3e51d2bd, 0x3e5212be, 0x3e5252bf, 0x3e5292c0, 0x3e52d2c1, 0x3e5312c2, 0x3e5352c3, 0x3e5392c4,
+    0x3e53d2c5, 0x3e5412c6, 0x3e5452c7, 0x3e5492c8, 0x3e54d2c9, 0x3e5512ca, 0x3e5552cb, 0x3e5592cc,
+    0x3e55d2cd, 0x3e5612ce, 0x3e5652cf, 0x3e5692d0, 0x3e56d2d1, 0x3e5712d2, 0x3e5752d3, 0x3e5792d4,
+    0x3e57d2d5, 0x3e5812d6, 0x3e5852d7, 0x3e5892d8, 0x3e58d2d9, 0x3e5912da, 0x3e5952db, 0x3e5992dc,
+    0x3e59d2dd, 0x3e5a12de, 0x3e5a52df, 0x3e5a92e0, 0x3e5ad2e1, 0x3e5b12e2, 0x3e5b52e3, 0x3e5b92e4,
+    0x3e5bd2e5, 0x3e5c12e6, 0x3e5c52e7, 0x3e5c92e8, 0x3e5cd2e9, 0x3e5d12ea, 0x3e5d52eb, 0x3e5d92ec,
+    0x3e5dd2ed, 0x3e5e12ee, 0x3e5e52ef, 0x3e5e92f0, 0x3e5ed2f1, 0x3e5f12f2, 0x3e5f52f3, 0x3e5f92f4,
+    0x3e5fd2f5, 0x3e6012f6, 0x3e6052f7, 0x3e6092f8, 0x3e60d2f9, 0x3e6112fa, 0x3e6152fb, 0x3e6192fc,
+    0x3e61d2fd, 0x3e6212fe, 0x3e6252ff, 0x3e629300, 0x3e62d301, 0x3e631302, 0x3e635303, 0x3e639304,
+    0x3e63d305, 0x3e641306, 0x3e645307, 0x3e649308, 0x3e64d309, 0x3e65130a, 0x3e65530b, 0x3e65930c,
+    0x3e65d30d, 0x3e66130e, 0x3e66530f, 0x3e669310, 0x3e66d311, 0x3e671312, 0x3e675313, 0x3e679314,
+    0x3e67d315, 0x3e681316, 0x3e685317, 0x3e689318, 0x3e68d319, 0x3e69131a, 0x3e69531b, 0x3e69931c,
+    0x3e69d31d, 0x3e6a131e, 0x3e6a531f, 0x3e6a9320, 0x3e6ad321, 0x3e6b1322, 0x3e6b5323, 0x3e6b9324,
+    0x3e6bd325, 0x3e6c1326, 0x3e6c5327, 0x3e6c9328, 0x3e6cd329, 0x3e6d132a, 0x3e6d532b, 0x3e6d932c,
+    0x3e6dd32d, 0x3e6e132e, 0x3e6e532f, 0x3e6e9330, 0x3e6ed331, 0x3e6f1332, 0x3e6f5333, 0x3e6f9334,
+    0x3e6fd335, 0x3e701336, 0x3e705337, 0x3e709338, 0x3e70d339, 0x3e71133a, 0x3e71533b, 0x3e71933c,
+    0x3e71d33d, 0x3e72133e, 0x3e72533f, 0x3e729340, 0x3e72d341, 0x3e731342, 0x3e735343, 0x3e739344,
+    0x3e73d345, 0x3e741346, 0x3e745347, 0x3e749348, 0x3e74d349, 0x3e75134a, 0x3e75534b, 0x3e75934c,
+    0x3e75d34d, 0x3e76134e, 0x3e76534f, 0x3e769350, 0x3e76d351, 0x3e771352, 0x3e775353, 0x3e779354,
+    0x3e77d355, 0x3e781356, 0x3e785357, 0x3e789358, 0x3e78d359, 0x3e79135a, 0x3e79535b, 0x3e79935c,
+    0x3e79d35d, 0x3e7a135e, 0x3e7a535f, 0x3e7a9360, 0x3e7ad361, 0x3e7b1362, 0x3e7b5363, 0x3e7b9364,
+    0x3e7bd365, 0x3e7c1366, 0x3e7c5367, 0x3e7c9368, 0x3e7cd369, 0x3e7d136a, 0x3e7d536b, 0x3e7d936c,
+    0x3e7dd36d, 0x3e7e136e, 0x3e7e536f, 0x3e7e9370, 0x3e7ed371, 0x3e7f1372, 0x3e7f5373, 0x3e7f9374,
+    0x3e7fd375, 0x3e801376, 0x3e805377, 0x3e809378, 0x3e80d379, 0x3e81137a, 0x3e81537b, 0x3e81937c,
+    0x3e81d37d, 0x3e82137e, 0x3e82537f, 0x3e829380, 0x3e82d381, 0x3e831382, 0x3e835383, 0x3e841384,
+    0x3e849385, 0x3e855386, 0x3e859387, 0x3e85d388, 0x3e861389, 0x3e86538a, 0x3e86938b, 0x3e86d38c,
+    0x3e87138d, 0x3e87538e, 0x3e87938f, 0x3e881390, 0x3e889391, 0x3e895392, 0x3e899393, 0x3e8a9394,
+    0x3e8ad395, 0x3e8b1396, 0x3e8b5397, 0x3e8b9398, 0x3e8bd399, 0x3e8c139a, 0x3e8c539b, 0x3e8c939c,
+    0x3e8cd39d, 0x3e8d139e, 0x3e8d539f, 0x3e8d93a0, 0x3e8dd3a1, 0x3e8e13a2, 0x3e8e53a3, 0x3e8e93a4,
+    0x3e8ed3a5, 0x3e8f13a6, 0x3e8f53a7, 0x3e8f93a8, 0x3e8fd3a9, 0x3e9013aa, 0x3e9053ab, 0x3e9093ac,
+    0x3e90d3ad, 0x3e9113ae, 0x3e9153af, 0x3e9193b0, 0x3e91d3b1, 0x3e9213b2, 0x3e9253b3, 0x3e9293b4,
+    0x3e92d3b5, 0x3e9313b6, 0x3e9353b7, 0x3e9393b8, 0x3e93d3b9, 0x3e9413ba, 0x3e9453bb, 0x3e9493bc,
+    0x3e94d3bd, 0x3e9513be, 0x3e9553bf, 0x3e9593c0, 0x3e95d3c1, 0x3e9613c2, 0x3e9653c3, 0x3e9693c4,
+    0x3e96d3c5, 0x3e9713c6, 0x3e9753c7, 0x3e9793c8, 0x3e97d3c9, 0x3e9813ca, 0x3e9853cb, 0x3e9893cc,
+    0x3e98d3cd, 0x3e9913ce, 0x3e9953cf, 0x3e9993d0, 0x3e99d3d1, 0x3e9a13d2, 0x3e9a53d3, 0x3e9a93d4,
+    0x3e9ad3d5, 0x3e9b13d6, 0x3e9b53d8, 0x3e9c13d9, 0x3e9c53da, 0x3e9c93db, 0x3e9cd3dc, 0x3e9d13dd,
+    0x3e9d53de, 0x3e9d93df, 0x3e9dd3e0, 0x3e9e13e1, 0x3e9e53e2, 0x3e9e93e3, 0x3e9ed3e4, 0x3e9f13e5,
+    0x3e9f53e6, 0x3e9f93e7, 0x3e9fd3e8, 0x3ea013e9, 0x3ea053ea, 0x3ea093eb, 0x3ea0d3ec, 0x3ea113ed,
+    0x3ea153ee, 0x3ea193ef, 0x3ea1d3f0, 0x3ea213f1, 0x3ea253f2, 0x3ea293f3, 0x3ea2d3f4, 0x3ea313f5,
+    0x3ea353f6, 0x3ea393f7, 0x3ea3d3f8, 0x3ea413f9, 0x3ea453fa, 0x3ea493fb, 0x3ea4d3fc, 0x3ea513fd,
+    0x3ea553fe, 0x3ea593ff, 0x3ea5d400, 0x3ea61401, 0x3ea65402, 0x3ea69403, 0x3ea6d404, 0x3ea71405,
+    0x3ea75406, 0x3ea79407, 0x3ea7d408, 0x3ea81409, 0x3ea8540a, 0x3ea8940b, 0x3ea8d40c, 0x3ea9140d,
+    0x3ea9540e, 0x3ea9940f, 0x3ea9d410, 0x3eaa1411, 0x3eaa5412, 0x3eaa9413, 0x3eaad414, 0x3eab1415,
+    0x3eab5416, 0x3eab9417, 0x3eabd418, 0x3eac1419, 0x3eac541a, 0x3eac941b, 0x3eacd41c, 0x3ead141d,
+    0x3ead541e, 0x3ead941f, 0x3eadd420, 0x3eae1421, 0x3eae5422, 0x3eae9423, 0x3eaed424, 0x3eaf1425,
+    0x3eaf5426, 0x3eaf9427, 0x3eafd428, 0x3eb01429, 0x3eb0542a, 0x3eb0942b, 0x3eb0d42c, 0x3eb1142d,
+    0x3eb1542e, 0x3eb1942f, 0x3eb1d430, 0x3eb21431, 0x3eb25432, 0x3eb29433, 0x3eb2d434, 0x3eb31435,
+    0x3eb35436, 0x3eb39437, 0x3eb3d438, 0x3eb4143a, 0x3eb4543c, 0x3eb4943e, 0x3eb4d43f, 0x3eb51440,
+    0x3eb55441, 0x3eb59443, 0x3eb5d445, 0x3eb61447, 0x3eb65448, 0x3ec01449, 0x3ec0544b, 0x3ec0944d,
+    0x3ec0d44f, 0x3ec11452, 0x3ec19455, 0x3ec4d457, 0x3ec51459, 0x3ec5545b, 0x3ec5945d, 0x3ec5d45f,
+    0x3ec81461, 0x3ec85462, 0x3ec89463, 0x3ec8d464, 0x3ec91465, 0x3ec95466, 0x3ec99467, 0x3ec9d468,
+    0x3eca1469, 0x3eca546a, 0x3ecad46c, 0x3ecb546d, 0x3ecbd46e, 0x3ecc146f, 0x3ece5470, 0x3ed25471,
+    0x3ed3d472, 0x3ed41474, 0x3ed45475, 0x3ed49476, 0x3ed4d477, 0x3ed51478, 0x3ed55479, 0x3ed5947a,
+    0x3ed5d47c, 0x3ed6147e, 0x3ed65480, 0x3ed69482, 0x3ed6d483, 0x3ed71484, 0x3ed75485, 0x3ed79486,
+    0x3ed7d487, 0x3ed81488, 0x3ed85489, 0x3ed8948a, 0x3ed8d48b, 0x3ed9148c, 0x3ed9548d, 0x3ed9948e,
+    0x3ed9d490, 0x3eda1492, 0x3eda5494, 0x3eda9496, 0x3edad498, 0x3edb149a, 0x3edb549c, 0x3edb949e,
+    0x3edbd49f, 0x3edc14a0, 0x3edc54a1, 0x3edc94a2, 0x3edcd4a3, 0x3edd14a4, 0x3edd54a5, 0x3edd94a6,
+    0x3eddd4a7, 0x3ede14a8, 0x3ede54a9, 0x3ede94aa, 0x3eded4ab, 0x3edf14ac, 0x3edf54ad, 0x3edf94ae,
+    0x3edfd4af, 0x3ee014b0, 0x3ee054b1, 0x3ee094b2, 0x3ee0d4b3, 0x3ee114b4, 0x3ee154b5, 0x3ee194b6,
+    0x3ee1d4b8, 0x3ee214ba, 0x3ee254bc, 0x3ee294be, 0x3ee2d4c0, 0x3ee314c2, 0x3ee354c4, 0x3ee394c6,
+    0x3ee3d4c7, 0x3ee414c8, 0x3ee454c9, 0x3ee494ca, 0x3ee4d4cb, 0x3ee514cc, 0x3ee554cd, 0x3ee594ce,
+    0x3ee5d4cf, 0x3ee614d0, 0x3ee654d1, 0x3ee694d2, 0x3ee6d4d3, 0x3ee714d4, 0x3ee754d5, 0x3ee794d6,
+    0x3ee7d4d7, 0x3ee814d8, 0x3ee854da, 0x3ee894dc, 0x3ee8d4de, 0x3ee914e0, 0x3ee954e1, 0x3ee994e2,
+    0x3ee9d4e3, 0x3eea14e4, 0x3eea54e5, 0x3eea94e6, 0x3eead4e7, 0x3eeb14e8, 0x3eeb54e9, 0x3eeb94ea,
+    0x3eebd4eb, 0x3eec14ec, 0x3eec54ed, 0x3ef4d4ee, 0x3ef514f0, 0x3ef554f2, 0x3ef594f4, 0x3ef5d4f6,
+    0x3ef614f8, 0x3ef654fa, 0x3ef694fc, 0x3ef6d4fe, 0x3ef71500, 0x3ef75502, 0x3ef79505, 0x3ef7d507,
+    0x3ef81509, 0x3ef8550a, 0x3ef8950b, 0x3ef8d50d, 0x3ef9150f, 0x3ef95510, 0x3ef99511, 0x3ef9d512,
+    0x3efa1513, 0x3efa5514, 0x3efa9515, 0x3efad518, 0x3efb151b, 0x3efb551e, 0x3efb9521, 0x3efbd524,
+    0x3efc1527, 0x3efc552b, 0x3efc952f, 0x3efcd533, 0x3efd1537, 0x3efd553b, 0x3efd953f, 0x3efdd542,
+    0x3efe1545, 0x3efe5548, 0x3efe954b, 0x3efed54e, 0x3eff1551, 0x3eff5552, 0x3eff9553, 0x3effd554,
+    0x3f001555, 0x3f005558, 0x3f00955b, 0x3f00d55e, 0x3f011561, 0x3f015564, 0x3f019566, 0x3f01d568,
+    0x3f02156a, 0x3f02556c, 0x3f02956e, 0x3f02d570, 0x3f031572, 0x3f035574, 0x3f039576, 0x3f03d578,
+    0x3f04157a, 0x3f04557c, 0x3f04957f, 0x3f04d582, 0x3f051585, 0x3f055588, 0x3f05958a, 0x3f05d58c,
+    0x3f06158e, 0x3f065590, 0x3f069592, 0x3f06d594, 0x3f071596, 0x3f075598, 0x3f07959a, 0x3f07d59c,
+    0x3f08159e, 0x3f0855a0, 0x3f0895a2, 0x3f08d5a4, 0x3f0915a6, 0x3f0955a8, 0x3f0995aa, 0x3f09d5ac,
+    0x3f0a15ae, 0x3f0a55b0, 0x3f0a95b2, 0x3f0ad5b4, 0x3f0b15b6, 0x3f0b55b8, 0x3f0b95ba, 0x3f0bd5bc,
+    0x3f0c15be, 0x3f0c55c0, 0x3f0c95c2, 0x3f0cd5c4, 0x3f0d15c6, 0x3f0d55c8, 0x3f0d95ca, 0x3f0dd5cc,
+    0x3f0e15ce, 0x3f0e55d0, 0x3f0e95d2, 0x3f0ed5d4, 0x3f0f15d6, 0x3f0f55d8, 0x3f0f95da, 0x3f0fd5dc,
+    0x3f1015de, 0x3f1055e0, 0x3f1095e2, 0x3f10d5e4, 0x3f1115e6, 0x3f1155e8, 0x3f1195ea, 0x3f11d5ec,
+    0x3f1215ee, 0x3f1255f0, 0x3f1295f2, 0x3f12d5f4, 0x3f1315f6, 0x3f1355f8, 0x3f1395fa, 0x3f13d5fc,
+    0x3f1415fe, 0x3f145600, 0x3f149602, 0x3f14d604, 0x3f151606, 0x3f155608, 0x3f15960a, 0x3f15d60c,
+    0x3f16160e, 0x3f165610, 0x3f169612, 0x3f16d614, 0x3f171616, 0x3f175618, 0x3f17961a, 0x3f17d61c,
+    0x3f18161e, 0x3f185620, 0x3f189622, 0x3f18d624, 0x3f191626, 0x3f195629, 0x3f19962c, 0x3f19d62f,
+    0x3f1a1632, 0x3f1a5635, 0x3f1a9638, 0x3f1ad63a, 0x3f1b163c, 0x3f1b563e, 0x3f1b9640, 0x3f1bd642,
+    0x3f1c1644, 0x3f1c5646, 0x3f1c9648, 0x3f1cd64a, 0x3f1d164c, 0x3f1d564e, 0x3f1d9650, 0x3f1dd653,
+    0x3f1e1656, 0x3f1e5659, 0x3f1e965c, 0x3f1ed65f, 0x3f1f1662, 0x3f1f5664, 0x3f1f9666, 0x3f1fd668,
+    0x3f20166a, 0x3f20566c, 0x3f20966e, 0x3f20d670, 0x3f211672, 0x3f215674, 0x3f219676, 0x3f21d678,
+    0x3f22167a, 0x3f22567c, 0x3f22967e, 0x3f22d680, 0x3f231682, 0x3f235684, 0x3f239686, 0x3f23d688,
+    0x3f24168a, 0x3f24568c, 0x3f24968e, 0x3f24d690, 0x3f251692, 0x3f255694, 0x3f259696, 0x3f25d698,
+    0x3f26169b, 0x3f26569e, 0x3f2696a1, 0x3f26d6a4, 0x3f2716a7, 0x3f2756a9, 0x3f2796ab, 0x3f27d6ad,
+    0x3f2816af, 0x3f2856b1, 0x3f2896b3, 0x3f28d6b5, 0x3f2916b7, 0x3f2956b9, 0x3f2996bb, 0x3f29d6be,
+    0x3f2a16c0, 0x3f2a56c2, 0x3f2a96c4, 0x3f2ad6c6, 0x3f2b16c8, 0x3f2b56ca, 0x3f2b96cc, 0x3f2bd6ce,
+    0x3f2c16d0, 0x3f2c56d2, 0x3f2c96d4, 0x3f2cd6d6, 0x3f2d16d8, 0x3f2d56da, 0x3f2d96dc, 0x3f2dd6de,
+    0x3f2e16e0, 0x3f2e56e2, 0x3f2e96e4, 0x3f2ed6e6, 0x3f2f16e8, 0x3f2f56ea, 0x3f2f96ec, 0x3f2fd6ee,
+    0x3f3016f0, 0x3f3056f2, 0x3f3096f4, 0x3f30d6f6, 0x3f3116f8, 0x3f3156fa, 0x3f3196fc, 0x3f31d6fe,
+    0x3f321700, 0x3f325702, 0x3f329704, 0x3f32d706, 0x3f331708, 0x3f33570a, 0x3f33970c, 0x3f33d70e,
+    0x3f341710, 0x3f345712, 0x3f349714, 0x3f34d716, 0x3f351718, 0x3f35571a, 0x3f35971c, 0x3f35d71e,
+    0x3f361720, 0x3f365722, 0x3f369724, 0x3f36d726, 0x3f371728, 0x3f37572a, 0x3f37972c, 0x3f37d72e,
+    0x3f381731, 0x3f385734, 0x3f389736, 0x3f38d738, 0x3f39173a, 0x3f39573c, 0x3f39973f, 0x3f39d742,
+    0x3f3a1744, 0x3f3a5746, 0x3f3a9749, 0x3f3ad74c, 0x3f3b174e, 0x3f3b5750, 0x3f3b9752, 0x3f3bd754,
+    0x3f3c1756, 0x3f3c5758, 0x3f3c975a, 0x3f3cd75c, 0x3f3d175e, 0x3f3d5760, 0x3f3d9762, 0x3f3dd764,
+    0x3f3e1766, 0x3f3e5768, 0x3f3e976a, 0x3f3ed76c, 0x3f3f176e, 0x3f3f5770, 0x3f3f9773, 0x3f3fd776,
+    0x3f401778, 0x3f40577a, 0x3f40977c, 0x3f40d77e, 0x3f411780, 0x3f415782, 0x3f419784, 0x3f41d786,
+    0x3f421788, 0x3f42578a, 0x3f42978d, 0x3f42d790, 0x3f431793, 0x3f435796, 0x3f439799, 0x3f43d79b,
+    0x3f44179d, 0x3f44579f, 0x3f4497a1, 0x3f44d7a3, 0x3f4517a5, 0x3f4557a7, 0x3f4597a9, 0x3f45d7ab,
+    0x3f4617ad, 0x3f4657af, 0x3f4697b2, 0x3f46d7b5, 0x3f4717b7, 0x3f4757b9, 0x3f4797bb, 0x3f47d7bd,
+    0x3f4817bf, 0x3f4857c1, 0x3f4897c3, 0x3f48d7c5, 0x3f4917c7, 0x3f4957c9, 0x3f4997cc, 0x3f49d7cf,
+    0x3f4a17d2, 0x3f4a57d5, 0x3f4a97d8, 0x3f4ad7da, 0x3f4b17dc, 0x3f4b57de, 0x3f4b97e1, 0x3f4bd7e4,
+    0x3f4c17e7, 0x3f4c57ea, 0x3f4c97ec, 0x3f4cd7ef, 0x3f4d17f1, 0x3f4d57f3, 0x3f4d97f5, 0x3f4dd7f7,
+    0x3f4e17fa, 0x3f4e57fd, 0x3f4e9800, 0x3f4ed802, 0x3f4f1804, 0x3f4f5806, 0x3f4f9808, 0x3f4fd809,
+    0x3f54180a, 0x3f54580d, 0x3f549810, 0x3f54d813, 0x3f551816, 0x3f555819, 0x3f55981c, 0x3f55d81f,
+    0x3f561822, 0x3f565825, 0x3f569828, 0x3f56d82b, 0x3f57182e, 0x3f575831, 0x3f579834, 0x3f57d837,
+    0x3f58183a, 0x3f58583d, 0x3f589840, 0x3f58d843, 0x3f591846, 0x3f595849, 0x3f59984c, 0x3f59d84f,
+    0x3f5a1853, 0x3f5a5857, 0x3f5a985b, 0x3f5ad85f, 0x3f5b1863, 0x3f5b5867, 0x3f5b986b, 0x3f5bd86e,
+    0x3f5c1871, 0x3f5c5874, 0x3f5c9877, 0x3f5cd87a, 0x3f5d187d, 0x3f5d5880, 0x3f5d9883, 0x3f5dd886,
+    0x3f5e1889, 0x3f5e588c, 0x3f5e988f, 0x3f5ed892, 0x3f5f1895, 0x3f5f5898, 0x3f5f989b, 0x3f5fd89e,
+    0x3f6018a1, 0x3f6058a4, 0x3f6098a7, 0x3f60d8aa, 0x3f6118ad, 0x3f6158b0, 0x3f6198b3, 0x3f61d8b6,
+    0x3f6218b9, 0x3f6258bc, 0x3f6298bf, 0x3f62d8c2, 0x3f6318c5, 0x3f6358c8, 0x3f6398cb, 0x3f63d8ce,
+    0x3f6498d1, 0x3f64d8d4, 0x3f6518d7, 0x3f6558da, 0x3f6598dd, 0x3f65d8e0, 0x3f6618e3, 0x3f6658e6,
+    0x3f6698e9, 0x3f66d8ec, 0x3f6718ef, 0x3f6758f2, 0x3f6798f5, 0x3f67d8f8, 0x3f6818fb, 0x3f6858fe,
+    0x3f689901, 0x3f68d904, 0x3f691907, 0x3f69590a, 0x3f69990d, 0x3f69d910, 0x3f6a1913, 0x3f6a5916,
+    0x3f6a9919, 0x3f6ad91d, 0x3f6b1920, 0x3f6b5923, 0x3f6b9926, 0x3f6bd929, 0x3f6c192c, 0x3f6c592f,
+    0x3f6c9932, 0x3f6cd935, 0x3f6d1938, 0x3f6d593b, 0x3f6d993e, 0x3f6dd941, 0x3f6e1944, 0x3f6e5947,
+    0x3f6e994a, 0x3f6ed94d, 0x3f6f1950, 0x3f6f5953, 0x3f6f9956, 0x3f6fd959, 0x3f70195c, 0x3f70595f,
+    0x3f709962, 0x3f70d965, 0x3f711968, 0x3f71596b, 0x3f71996e, 0x3f71d971, 0x3f7c1974, 0x3f7c5977,
+    0x3f7c997a, 0x3f7cd980, 0x3f7d1984, 0x3f7d5988, 0x3f7d998c, 0x3f7dd990, 0x3f7e1994, 0x3f7e5998,
+    0x3f7e999b, 0x3f7ed9ad, 0x3f7f19b5, 0x3f8659b9, 0x3f8c19ba, 0x3f8c59bb, 0x3f8d19bc, 0x3f8d59bd,
+    0x3f8d99be, 0x3f8dd9bf, 0x3f8e19c0, 0x3f8e59c1, 0x3f8e99c2, 0x3f9259c3, 0x3f9299c4, 0x3f92d9c5,
+    0x3f9319c6, 0x3f9359c7, 0x3f9399c8, 0x3f93d9c9, 0x3f9619ca, 0x3f9a19cb, 0x3fa019cc, 0x3fa059cd,
+    0x3fa099ce, 0x3fa0d9cf, 0x3fa119d1, 0x3fa159d3, 0x3fa199d5, 0x3fa1d9d7, 0x3fa219d9, 0x3fa259db,
+    0x3fa299dd, 0x3fa2d9df, 0x3fa319e1, 0x3fa359e3, 0x3fa399e4, 0x3fa3d9e5, 0x3fa419e6, 0x3fa459e7,
+    0x3fa499e8, 0x3fa4d9e9, 0x3fa519ea, 0x3fa559eb, 0x3fa599ec, 0x3fa5d9ed, 0x3fa619ee, 0x3fa659ef,
+    0x3fa699f1, 0x3fa6d9f3, 0x3fa719f5, 0x3fa759f7, 0x3fa799f8, 0x3fa7d9f9, 0x3fa819fa, 0x3fa859fb,
+    0x3fa899fc, 0x3fa8d9fd, 0x3fa919fe, 0x3fa959ff, 0x3fa99a00, 0x3fa9da01, 0x3faa1a02, 0x3faa5a03,
+    0x3faa9a04, 0x3faada05, 0x3fab1a06, 0x3fab5a07, 0x3fab9a08, 0x3fabda09, 0x3fac1a0a, 0x3fac5a0b,
+    0x3fac9a0c, 0x3facda0d, 0x3fad1a0e, 0x3fad5a0f, 0x3fad9a11, 0x3fadda13, 0x3fae1a15, 0x3fae5a17,
+    0x3fae9a18, 0x3faeda19, 0x3faf1a1a, 0x3faf5a1b, 0x3faf9a1c, 0x3fafda1d, 0x3fb01a1e, 0x3fb05a1f,
+    0x3fb09a20, 0x3fb0da21, 0x3fb11a22, 0x3fb15a23, 0x3fb19a24, 0x3fb1da25, 0x3fb21a26, 0x3fb25a27,
+    0x3fb29a28, 0x3fb2da29, 0x3fb31a2a, 0x3fb35a2b, 0x3fb39a2c, 0x3fb3da2d, 0x3fb41a2e, 0x3fb45a2f,
+    0x3fb49a30, 0x3fb4da31, 0x3fb51a32, 0x3fb55a33, 0x3fb59a34, 0x3fb5da35, 0x3fb61a36, 0x3fb65a37,
+    0x3fb69a38, 0x3fb6da39, 0x3fb71a3a, 0x3fb75a3b, 0x3fb79a3c, 0x3fb7da3d, 0x3fb81a3e, 0x3fb85a3f,
+    0x3fb89a40, 0x3fb8da41, 0x3fb91a42, 0x3fb95a43, 0x3fb99a44, 0x3fb9da45, 0x3fba1a46, 0x3fba5a47,
+    0x3fba9a48, 0x3fbada49, 0x3fbb1a4a, 0x3fbb5a4b, 0x3fbb9a4c, 0x3fbbda4d, 0x3fbc1a4e, 0x3fbc5a4f,
+    0x3fbc9a50, 0x3fbcda51, 0x3fbd1a52, 0x3fbd5a53, 0x3fbd9a55, 0x3fbdda57, 0x3fbe1a5a, 0x3fbe5a5d,
+    0x3fbe9a60, 0x3fbeda63, 0x3fbf1a65, 0x3fc05a67, 0x3fc09a68, 0x3fc1da6a, 0x3fc35a6b, 0x3fc69a6c,
+    0x3fc85a6d, 0x3fc89a6e, 0x3fc8da6f, 0x3fc95a70, 0x3fca1a71, 0x3fca5a72, 0x3fca9a73, 0x3fcada74,
+    0x3fcb5a75, 0x3fcb9a76, 0x3fcbda77, 0x3fcc1a78, 0x3fccda79, 0x3fcd1a7a, 0x3fce1a7b, 0x3fce5a7c,
+    0x3fce9a7d, 0x3fceda7e, 0x3fcf1a7f, 0x3fcf5a80, 0x3fcf9a81, 0x3fd01a82, 0x3fd05a83, 0x3fd0da84,
+    0x3fd15a85, 0x3fd1da86, 0x3fd21a87, 0x3fd25a88, 0x3fd29a89, 0x3fd31a8a, 0x3fd3da8b, 0x3fd41a8c,
+    0x3fd4da8d, 0x3fd59a8e, 0x3fd61a8f, 0x3fd65a90, 0x3fd71a91, 0x3fd79a92, 0x3fd95a93, 0x3ff8da94,
+    0x3ffa1a95, 0x3ffb5a96, 0x40405a97, 0x40639a98, 0x40659a9a, 0x4065da9c, 0x40661a9e, 0x40665aa4,
+    0x40681aa8, 0x40a09aa9, 0x40a15aaa, 0x40a19aab, 0x40a1daac, 0x40a29aad, 0x40a35aae, 0x40a41aaf,
+    0x40a49ab0, 0x40a51ab1, 0x40a55ab2, 0x40a59ab3, 0x40a5dab4, 0x40a6dab5, 0x40a81ab6, 0x40a85ab7,
+    0x40a89ab8, 0x40a8dab9, 0x40a95aba, 0x40aadabb, 0x40ab5abc, 0x40ac1abd, 0x40ac5abe, 0x40ac9abf,
+    0x40acdac0, 0x40ad1ac1, 0x40ad5ac2, 0x40ad9ac3, 0x40ae1ac4, 0x40b3dac5, 0x40b85ac6, 0x40b91ac7,
+    0x40ba1ac8, 0x40bc9ac9, 0x40bd5aca, 0x40c05acb, 0x40c09acc, 0x40c25acd, 0x40c45ace, 0x40c49acf,
+    0x40c55ad0, 0x40c5dad1, 0x40c69ad2, 0x40c7dad3, 0x40c81ad4, 0x40c89ad5, 0x40f45ad6, 0x40f4dad8,
+    0x41005ada, 0x41011adb, 0x41045adc, 0x41055add, 0x4106dade, 0x4107dadf, 0x41081ae0, 0x4108dae1,
+    0x41095ae2, 0x410a5ae3, 0x410a9ae4, 0x410b1ae5, 0x410f5ae6, 0x410fdae7, 0x41109ae8, 0x4110dae9,
+    0x41121aea, 0x4112daeb, 0x41135aec, 0x41281aed, 0x412c1aef, 0x412d1af0, 0x412f1af1, 0x41309af2,
+    0x4130daf3, 0x41311af4, 0x41335af5, 0x41339af6, 0x41341af7, 0x41345af8, 0x41349af9, 0x41361afa,
+    0x4136dafb, 0x413a9afc, 0x413adafd, 0x413d9afe, 0x413e5aff, 0x4144db00, 0x41459b01, 0x41461b02,
+    0x41471b03, 0x41475b04, 0x41495b05, 0x41499b06, 0x4149db07, 0x428e9b08, 0x42941b09, 0x4295db0a,
+    0x433e9b0e, 0x433f1b10, 0x442edb12, 0x4471db13, 0x44729b14, 0x4472db15, 0x4476db16, 0x44771b17,
+    0x44779b18, 0x44c01b19, 0x4504db1a, 0x45065b20, 0x45091b26, 0x450a9b2c, 0x450b5b32, 0x450bdb38,
+    0x45131b3e, 0x45249b42, 0x45251b43, 0x45259b44, 0x45261b45, 0x45265b46, 0x4526db47, 0x45275b48,
+    0x45279b49, 0x4527db4a, 0x45281b4b, 0x45285b4c, 0x45289b4d, 0x4528db4e, 0x4529db4f, 0x452a1b50,
+    0x452a5b51, 0x452a9b52, 0x452adb53, 0x452b5b54, 0x452b9b55, 0x452c1b56, 0x452c5b57, 0x452e5b58,
+    0x452f1b59, 0x452f5b5a, 0x452f9b5b, 0x452fdb5c, 0x45305b5e, 0x45309b5f, 0x4530db60, 0x45311b61,
+    0x45315b62, 0x45341b64, 0x45345b65, 0x45349b66, 0x45359b67, 0x45761b68, 0x45765b6a, 0x45769b6c,
+    0x4576db6e, 0x45771b70, 0x45775b72, 0x45909b74, 0x45c01b78, 0x45c19b7a, 0x45c29b7b, 0x45c39b7c,
+    0x45c3db7d, 0x46281b7e, 0x46289b7f, 0x4628db80, 0x46291b81, 0x46299b82, 0x462a1b83, 0x462a5b84,
+    0x462b1b85, 0x462b9b86, 0x462bdb87, 0x462c9b88, 0x462d5b89, 0x462ddb8a, 0x462e1b8b, 0x462edb8c,
+    0x462f1b8d, 0x46301b8e, 0x46305b8f, 0x46309b90, 0x4630db91, 0x46311b92, 0x46319b93, 0x46321b94,
+    0x46329b95, 0x46331b96, 0x46339b97, 0x46355b98, 0x46359b99, 0x4635db9a, 0x46361b9b, 0x46371b9c,
+    0x46381b9d, 0x4638db9e, 0x46391ba0, 0x46395ba1, 0x46399ba2, 0x463a5ba3, 0x463b1ba4, 0x463bdba5,
+    0x463c9ba6, 0x46b99ba7, 0x46b9dbab, 0x46ba1baf, 0x46ba5bb3, 0x46ba9bb9, 0x46bb1bbf, 0x46bb5bc3,
+    0x46bb9bc7, 0x46bd1bcd, 0x46bd5bd1, 0x46bd9bd5, 0x46bddbd9, 0x46be1bdf, 0x47109be5, 0x472c9be9,
+    0x480e1beb, 0x4cbe5bed, 0x5bc1dbef, 0x5bc21bf0, 0x5bc29bf1, 0x5bc59bf2, 0x5bc69bf3, 0x5bc71bf4,
+    0x5bc99bf5, 0x5bca1bf6, 0x5bcb5bf7, 0x5bcd5bf8, 0x5bce9bf9, 0x5bcedbfa, 0x5bcf5bfb, 0x5bcfdbfc,
+    0x5bd01bfd, 0x5bd09bfe, 0x5bd0dbff, 0x5bd45c00, 0x5bd49c01, 0x74451c02, 0x745b5c03, 0x74809c04,
+    0x74819c05, 0x7482dc06, 0x74835c07, 0x7483dc08, 0x74849c09, 0x7484dc0a, 0x74851c0b, 0x74855c0d,
+    0x74859c0e, 0x7485dc0f, 0x74869c10, 0x7486dc12, 0x74871c13, 0x74885c14, 0x74889c15, 0x748a9c16,
+    0x748adc17, 0x748c1c18, 0x748d9c19, 0x748ddc1a, 0x748e1c1b, 0x748e5c1c, 0x748e9c1d, 0x748edc1e,
+    0x748fdc1f, 0x74915c20, 0x75001c21, 0x75005c22, 0x75009c23, 0x7500dc24, 0x75011c25, 0x75015c26,
+    0x75019c27, 0x7501dc28, 0x75021c29, 0x75025c2a, 0x75029c2b, 0x7502dc2c, 0x75031c2d, 0x75035c2e,
+    0x75039c2f, 0x7503dc30, 0x75041c31, 0x75045c32, 0x75049c33, 0x7504dc34, 0x75051c35, 0x75055c36,
+    0x75059c37, 0x7505dc38, 0x75061c39, 0x75065c3a, 0x75069c3b, 0x7506dc3c, 0x75071c3d, 0x75075c3e,
+    0x75079c3f, 0x7507dc40, 0x75081c41, 0x75085c42, 0x75089c43, 0x7508dc44, 0x75091c45, 0x75095c46,
+    0x75099c47, 0x7509dc49, 0x750a1c4a, 0x750a5c4b, 0x750a9c4c, 0x750adc4d, 0x750b1c4e, 0x750b5c4f,
+    0x750b9c50, 0x750bdc51, 0x750c1c52, 0x750c5c53, 0x750c9c54, 0x750cdc55, 0x750d1c56, 0x750d5c57,
+    0x750d9c58, 0x750ddc59, 0x750e1c5a, 0x750e5c5b, 0x750e9c5c, 0x750edc5d, 0x750f1c5e, 0x750f5c5f,
+    0x750f9c60, 0x750fdc61, 0x75101c62, 0x75105c63, 0x75109c64, 0x7510dc65, 0x75111c66, 0x75115c67,
+    0x75119c68, 0x7511dc69, 0x75121c6a, 0x75125c6b, 0x75129c6c, 0x7512dc6d, 0x75131c6e, 0x75135c6f,
+    0x75139c70, 0x7513dc71, 0x75141c72, 0x75145c73, 0x75149c74, 0x7514dc75, 0x75151c76, 0x75159c77,
+    0x7515dc78, 0x75161c79, 0x75165c7a, 0x75169c7b, 0x7516dc7d, 0x75171c7e, 0x75175c7f, 0x75179c80,
+    0x7517dc81, 0x75181c82, 0x75185c83, 0x75189c84, 0x7518dc85, 0x75191c86, 0x75195c87, 0x75199c88,
+    0x7519dc89, 0x751a1c8a, 0x751a5c8b, 0x751a9c8c, 0x751adc8d, 0x751b1c8e, 0x751b5c8f, 0x751b9c90,
+    0x751bdc91, 0x751c1c92, 0x751c5c93, 0x751c9c94, 0x751cdc95, 0x751d1c96, 0x751d5c97, 0x751d9c98,
+    0x751ddc99, 0x751e1c9a, 0x751e5c9b, 0x751e9c9c, 0x751edc9d, 0x751f1c9e, 0x751f5c9f, 0x751f9ca0,
+    0x751fdca1, 0x75201ca2, 0x75205ca3, 0x75209ca4, 0x7520dca5, 0x75211ca6, 0x75215ca7, 0x75219ca8,
+    0x7521dca9, 0x75221caa, 0x75225cab, 0x75229cac, 0x7522dcad, 0x75231cae, 0x75235caf, 0x75239cb0,
+    0x7523dcb2, 0x75241cb3, 0x75245cb4, 0x75249cb5, 0x7524dcb6, 0x75251cb7, 0x75255cb8, 0x75259cb9,
+    0x7525dcba, 0x75261cbb, 0x75265cbc, 0x75269cbd, 0x7526dcbe, 0x75271cbf, 0x75279cc0, 0x7527dcc1,
+    0x75289cc2, 0x75295cc3, 0x75299cc4, 0x752a5cc5, 0x752a9cc6, 0x752adcc7, 0x752b1cc8, 0x752b9cc9,
+    0x752bdcca, 0x752c1ccb, 0x752c5ccc, 0x752c9ccd, 0x752cdcce, 0x752d1ccf, 0x752d5cd0, 0x752d9cd1,
+    0x752ddcd2, 0x752e1cd3, 0x752e5cd4, 0x752edcd5, 0x752f5cd6, 0x752f9cd7, 0x752fdcd8, 0x75301cd9,
+    0x75305cda, 0x75309cdb, 0x7530dcdd, 0x75315cde, 0x75319cdf, 0x7531dce0, 0x75321ce1, 0x75325ce2,
+    0x75329ce3, 0x7532dce4, 0x75331ce5, 0x75335ce6, 0x75339ce7, 0x7533dce8, 0x75341ce9, 0x75345cea,
+    0x75349ceb, 0x7534dcec, 0x75351ced, 0x75355cee, 0x75359cef, 0x7535dcf0, 0x75361cf1, 0x75365cf2,
+    0x75369cf3, 0x7536dcf4, 0x75371cf5, 0x75375cf6, 0x75379cf7, 0x7537dcf8, 0x75381cf9, 0x75385cfa,
+    0x75389cfb, 0x7538dcfc, 0x75391cfd, 0x75395cfe, 0x75399cff, 0x7539dd00, 0x753a1d01, 0x753a5d02,
+    0x753a9d03, 0x753add04, 0x753b1d05, 0x753b5d06, 0x753b9d07, 0x753bdd08, 0x753c1d09, 0x753c5d0a,
+    0x753c9d0b, 0x753cdd0c, 0x753d1d0d, 0x753d5d0e, 0x753d9d0f, 0x753ddd11, 0x753e1d12, 0x753e5d13,
+    0x753e9d14, 0x753edd15, 0x753f1d16, 0x753f5d17, 0x753f9d18, 0x753fdd19, 0x75401d1a, 0x75405d1b,
+    0x75409d1c, 0x7540dd1d, 0x75411d1e, 0x75415d1f, 0x7541dd20, 0x75421d21, 0x75425d22, 0x75429d23,
+    0x75435d24, 0x75439d25, 0x7543dd26, 0x75441d27, 0x75445d28, 0x75449d29, 0x7544dd2a, 0x75451d2b,
+    0x75459d2c, 0x7545dd2d, 0x75461d2e, 0x75465d2f, 0x75469d30, 0x7546dd31, 0x75471d32, 0x75479d33,
+    0x7547dd34, 0x75481d35, 0x75485d36, 0x75489d37, 0x7548dd38, 0x75491d39, 0x75495d3a, 0x75499d3b,
+    0x7549dd3c, 0x754a1d3d, 0x754a5d3e, 0x754a9d3f, 0x754add41, 0x754b1d42, 0x754b5d43, 0x754b9d44,
+    0x754bdd45, 0x754c1d46, 0x754c5d47, 0x754c9d48, 0x754cdd49, 0x754d1d4a, 0x754d5d4b, 0x754d9d4c,
+    0x754ddd4d, 0x754e1d4e, 0x754e5d4f, 0x754edd50, 0x754f1d51, 0x754f5d52, 0x754f9d53, 0x75501d54,
+    0x75505d55, 0x75509d56, 0x7550dd57, 0x75511d58, 0x75519d59, 0x75529d5a, 0x7552dd5b, 0x75531d5c,
+    0x75535d5d, 0x75539d5e, 0x7553dd5f, 0x75541d60, 0x75549d61, 0x7554dd62, 0x75551d63, 0x75555d64,
+    0x75559d65, 0x7555dd66, 0x75561d67, 0x75565d68, 0x75569d69, 0x7556dd6a, 0x75571d6b, 0x75575d6c,
+    0x75579d6d, 0x7557dd6f, 0x75581d70, 0x75585d71, 0x75589d72, 0x7558dd73, 0x75591d74, 0x75595d75,
+    0x75599d76, 0x7559dd77, 0x755a1d78, 0x755a5d79, 0x755a9d7a, 0x755add7b, 0x755b1d7c, 0x755b5d7d,
+    0x755b9d7e, 0x755bdd7f, 0x755c1d80, 0x755c5d81, 0x755c9d82, 0x755cdd83, 0x755d1d84, 0x755d5d85,
+    0x755d9d86, 0x755ddd87, 0x755e1d88, 0x755e5d89, 0x755e9d8a, 0x755edd8b, 0x755f1d8c, 0x755f5d8d,
+    0x755f9d8e, 0x755fdd8f, 0x75601d90, 0x75605d91, 0x75609d92, 0x7560dd93, 0x75611d94, 0x75615d95,
+    0x75619d96, 0x7561dd97, 0x75621d98, 0x75625d99, 0x75629d9a, 0x7562dd9b, 0x75631d9c, 0x75635d9d,
+    0x75639d9e, 0x7563dd9f, 0x75641da0, 0x75645da1, 0x75649da2, 0x7564dda4, 0x75651da5, 0x75655da6,
+    0x75659da7, 0x7565dda8, 0x75661da9, 0x75665daa, 0x75669dab, 0x7566ddac, 0x75671dad, 0x75675dae,
+    0x75679daf, 0x7567ddb0, 0x75681db1, 0x75685db2, 0x75689db3, 0x7568ddb4, 0x75691db5, 0x75695db6,
+    0x75699db7, 0x7569ddb8, 0x756a1db9, 0x756a5dba, 0x756a9dbb, 0x756addbc, 0x756b1dbd, 0x756b5dbe,
+    0x756b9dbf, 0x756bddc0, 0x756c1dc1, 0x756c5dc2, 0x756c9dc3, 0x756cddc4, 0x756d1dc5, 0x756d5dc6,
+    0x756d9dc7, 0x756dddc8, 0x756e1dc9, 0x756e5dca, 0x756e9dcb, 0x756eddcc, 0x756f1dcd, 0x756f5dce,
+    0x756f9dcf, 0x756fddd0, 0x75701dd1, 0x75705dd2, 0x75709dd3, 0x7570ddd4, 0x75711dd5, 0x75715dd6,
+    0x75719dd7, 0x7571ddd9, 0x75721dda, 0x75725ddb, 0x75729ddc, 0x7572dddd, 0x75731dde, 0x75735ddf,
+    0x75739de0, 0x7573dde1, 0x75741de2, 0x75745de3, 0x75749de4, 0x7574dde5, 0x75751de6, 0x75755de7,
+    0x75759de8, 0x7575dde9, 0x75761dea, 0x75765deb, 0x75769dec, 0x7576dded, 0x75771dee, 0x75775def,
+    0x75779df0, 0x7577ddf1, 0x75781df2, 0x75785df3, 0x75789df4, 0x7578ddf5, 0x75791df6, 0x75795df7,
+    0x75799df8, 0x7579ddf9, 0x757a1dfa, 0x757a5dfb, 0x757a9dfc, 0x757addfd, 0x757b1dfe, 0x757b5dff,
+    0x757b9e00, 0x757bde01, 0x757c1e02, 0x757c5e03, 0x757c9e04, 0x757cde05, 0x757d1e06, 0x757d5e07,
+    0x757d9e08, 0x757dde09, 0x757e1e0a, 0x757e5e0b, 0x757e9e0c, 0x757ede0e, 0x757f1e0f, 0x757f5e10,
+    0x757f9e11, 0x757fde12, 0x75801e13, 0x75805e14, 0x75809e15, 0x7580de16, 0x75811e17, 0x75815e18,
+    0x75819e19, 0x7581de1a, 0x75821e1b, 0x75825e1c, 0x75829e1d, 0x7582de1e, 0x75831e1f, 0x75835e20,
+    0x75839e21, 0x7583de22, 0x75841e23, 0x75845e24, 0x75849e25, 0x7584de26, 0x75851e27, 0x75855e28,
+    0x75859e29, 0x7585de2a, 0x75861e2b, 0x75865e2c, 0x75869e2d, 0x7586de2e, 0x75871e2f, 0x75875e30,
+    0x75879e31, 0x7587de32, 0x75881e33, 0x75885e34, 0x75889e35, 0x7588de36, 0x75891e37, 0x75895e38,
+    0x75899e39, 0x7589de3a, 0x758a1e3b, 0x758a5e3c, 0x758a9e3d, 0x758ade3e, 0x758b1e3f, 0x758b5e40,
+    0x758b9e41, 0x758bde43, 0x758c1e44, 0x758c5e45, 0x758c9e46, 0x758cde47, 0x758d1e48, 0x758d5e49,
+    0x758d9e4a, 0x758dde4b, 0x758e1e4c, 0x758e5e4d, 0x758e9e4e, 0x758ede4f, 0x758f1e50, 0x758f5e51,
+    0x758f9e52, 0x758fde53, 0x75901e54, 0x75905e55, 0x75909e56, 0x7590de57, 0x75911e58, 0x75915e59,
+    0x75919e5a, 0x7591de5b, 0x75921e5c, 0x75925e5d, 0x75929e5e, 0x7592de5f, 0x75931e60, 0x75935e61,
+    0x75939e62, 0x7593de63, 0x75941e64, 0x75945e65, 0x75949e66, 0x7594de67, 0x75951e68, 0x75955e69,
+    0x75959e6a, 0x7595de6b, 0x75961e6c, 0x75965e6d, 0x75969e6e, 0x7596de6f, 0x75971e70, 0x75975e71,
+    0x75979e72, 0x7597de73, 0x75981e74, 0x75985e75, 0x75989e76, 0x7598de78, 0x75991e79, 0x75995e7a,
+    0x75999e7b, 0x7599de7c, 0x759a1e7d, 0x759a5e7e, 0x759a9e7f, 0x759ade80, 0x759b1e81, 0x759b5e82,
+    0x759b9e83, 0x759bde84, 0x759c1e85, 0x759c5e86, 0x759c9e87, 0x759cde88, 0x759d1e89, 0x759d5e8a,
+    0x759d9e8b, 0x759dde8c, 0x759e1e8d, 0x759e5e8e, 0x759e9e8f, 0x759ede90, 0x759f1e91, 0x759f5e92,
+    0x759f9e93, 0x759fde94, 0x75a01e95, 0x75a05e96, 0x75a09e97, 0x75a0de98, 0x75a11e99, 0x75a15e9a,
+    0x75a19e9b, 0x75a1de9c, 0x75a21e9d, 0x75a25e9e, 0x75a29e9f, 0x75a2dea0, 0x75a31ea1, 0x75a35ea2,
+    0x75a39ea3, 0x75a3dea4, 0x75a41ea5, 0x75a45ea6, 0x75a49ea7, 0x75a4dea8, 0x75a51ea9, 0x75a55eaa,
+    0x75a59eab, 0x75a5dead, 0x75a61eae, 0x75a65eaf, 0x75a69eb0, 0x75a6deb1, 0x75a71eb2, 0x75a75eb3,
+    0x75a79eb4, 0x75a7deb5, 0x75a81eb6, 0x75a85eb7, 0x75a89eb8, 0x75a8deb9, 0x75a91eba, 0x75a95ebb,
+    0x75aa1ebc, 0x75aa5ebd, 0x75aa9ebe, 0x75aadebf, 0x75ab1ec0, 0x75ab5ec1, 0x75ab9ec2, 0x75abdec3,
+    0x75ac1ec5, 0x75ac5ec6, 0x75ac9ec7, 0x75acdec8, 0x75ad1ec9, 0x75ad5eca, 0x75ad9ecb, 0x75addecc,
+    0x75ae1ecd, 0x75ae5ece, 0x75ae9ed0, 0x75aeded1, 0x75af1ed2, 0x75af5ed3, 0x75af9ed4, 0x75afded5,
+    0x75b01ed6, 0x75b05ed7, 0x75b09ed8, 0x75b0ded9, 0x75b11eda, 0x75b15edb, 0x75b19edc, 0x75b1dedd,
+    0x75b21ede, 0x75b25ee0, 0x75b29ee2, 0x75b2dee3, 0x75b31ee4, 0x75b35ee5, 0x75b39ee6, 0x75b3dee7,
+    0x75b41ee8, 0x75b45ee9, 0x75b49eea, 0x75b4deeb, 0x75b51eec, 0x75b55eed, 0x75b59eee, 0x75b5deef,
+    0x75b61ef0, 0x75b65ef1, 0x75b69ef2, 0x75b6def3, 0x75b71ef4, 0x75b75ef5, 0x75b79ef7, 0x75b7def8,
+    0x75b81ef9, 0x75b85efa, 0x75b89efb, 0x75b8defc, 0x75b91efd, 0x75b95efe, 0x75b99eff, 0x75b9df00,
+    0x75ba1f01, 0x75ba5f02, 0x75ba9f04, 0x75badf05, 0x75bb1f06, 0x75bb5f07, 0x75bb9f08, 0x75bbdf09,
+    0x75bc1f0a, 0x75bc5f0b, 0x75bc9f0c, 0x75bcdf0d, 0x75bd1f0f, 0x75bd5f10, 0x75bd9f11, 0x75bddf12,
+    0x75be1f13, 0x75be5f14, 0x75be9f15, 0x75bedf16, 0x75bf1f17, 0x75bf5f18, 0x75bf9f19, 0x75bfdf1a,
+    0x75c01f1b, 0x75c05f1c, 0x75c09f1d, 0x75c0df1f, 0x75c11f21, 0x75c15f22, 0x75c19f23, 0x75c1df24,
+    0x75c21f25, 0x75c25f26, 0x75c29f27, 0x75c2df28, 0x75c31f29, 0x75c35f2a, 0x75c39f2b, 0x75c3df2c,
+    0x75c41f2d, 0x75c45f2e, 0x75c49f2f, 0x75c4df30, 0x75c51f31, 0x75c55f32, 0x75c59f33, 0x75c5df34,
+    0x75c61f36, 0x75c65f37, 0x75c69f38, 0x75c6df39, 0x75c71f3a, 0x75c75f3b, 0x75c79f3c, 0x75c7df3d,
+    0x75c81f3e, 0x75c85f3f, 0x75c89f40, 0x75c8df41, 0x75c91f43, 0x75c95f44, 0x75c99f45, 0x75c9df46,
+    0x75ca1f47, 0x75ca5f48, 0x75ca9f49, 0x75cadf4a, 0x75cb1f4b, 0x75cb5f4c, 0x75cb9f4e, 0x75cbdf4f,
+    0x75cc1f50, 0x75cc5f51, 0x75cc9f52, 0x75ccdf53, 0x75cd1f54, 0x75cd5f55, 0x75cd9f56, 0x75cddf57,
+    0x75ce1f58, 0x75ce5f59, 0x75ce9f5a, 0x75cedf5b, 0x75cf1f5c, 0x75cf5f5e, 0x75cf9f60, 0x75cfdf61,
+    0x75d01f62, 0x75d05f63, 0x75d09f64, 0x75d0df65, 0x75d11f66, 0x75d15f67, 0x75d19f68, 0x75d1df69,
+    0x75d21f6a, 0x75d25f6b, 0x75d29f6c, 0x75d2df6d, 0x75d31f6e, 0x75d35f6f, 0x75d39f70, 0x75d3df71,
+    0x75d41f72, 0x75d45f73, 0x75d49f75, 0x75d4df76, 0x75d51f77, 0x75d55f78, 0x75d59f79, 0x75d5df7a,
+    0x75d61f7b, 0x75d65f7c, 0x75d69f7d, 0x75d6df7e, 0x75d71f7f, 0x75d75f80, 0x75d79f82, 0x75d7df83,
+    0x75d81f84, 0x75d85f85, 0x75d89f86, 0x75d8df87, 0x75d91f88, 0x75d95f89, 0x75d99f8a, 0x75d9df8b,
+    0x75da1f8d, 0x75da5f8e, 0x75da9f8f, 0x75dadf90, 0x75db1f91, 0x75db5f92, 0x75db9f93, 0x75dbdf94,
+    0x75dc1f95, 0x75dc5f96, 0x75dc9f97, 0x75dcdf98, 0x75dd1f99, 0x75dd5f9a, 0x75dd9f9b, 0x75dddf9d,
+    0x75de1f9f, 0x75de5fa0, 0x75de9fa1, 0x75dedfa2, 0x75df1fa3, 0x75df5fa4, 0x75df9fa5, 0x75dfdfa6,
+    0x75e01fa7, 0x75e05fa8, 0x75e09fa9, 0x75e0dfaa, 0x75e11fab, 0x75e15fac, 0x75e19fad, 0x75e1dfae,
+    0x75e21faf, 0x75e25fb0, 0x75e29fb1, 0x75e2dfb2, 0x75e31fb4, 0x75e35fb5, 0x75e39fb6, 0x75e3dfb7,
+    0x75e41fb8, 0x75e45fb9, 0x75e49fba, 0x75e4dfbb, 0x75e51fbc, 0x75e55fbd, 0x75e59fbe, 0x75e5dfbf,
+    0x75e61fc1, 0x75e65fc2, 0x75e69fc3, 0x75e6dfc4, 0x75e71fc5, 0x75e75fc6, 0x75e79fc7, 0x75e7dfc8,
+    0x75e81fc9, 0x75e85fca, 0x75e89fcc, 0x75e8dfcd, 0x75e91fce, 0x75e95fcf, 0x75e99fd0, 0x75e9dfd1,
+    0x75ea1fd2, 0x75ea5fd3, 0x75ea9fd4, 0x75eadfd5, 0x75eb1fd6, 0x75eb5fd7, 0x75eb9fd8, 0x75ebdfd9,
+    0x75ec1fda, 0x75ec5fdc, 0x75ec9fde, 0x75ecdfdf, 0x75ed1fe0, 0x75ed5fe1, 0x75ed9fe2, 0x75eddfe3,
+    0x75ee1fe4, 0x75ee5fe5, 0x75ee9fe6, 0x75eedfe7, 0x75ef1fe8, 0x75ef5fe9, 0x75ef9fea, 0x75efdfeb,
+    0x75f01fec, 0x75f05fed, 0x75f09fee, 0x75f0dfef, 0x75f11ff0, 0x75f15ff1, 0x75f19ff3, 0x75f1dff4,
+    0x75f21ff5, 0x75f25ff6, 0x75f29ff7, 0x75f2dff8, 0x75f39ff9, 0x75f3dffa, 0x75f41ffb, 0x75f45ffc,
+    0x75f49ffd, 0x75f4dffe, 0x75f51fff, 0x75f56000, 0x75f5a001, 0x75f5e002, 0x75f62003, 0x75f66004,
+    0x75f6a005, 0x75f6e006, 0x75f72007, 0x75f76008, 0x75f7a009, 0x75f7e00a, 0x75f8200b, 0x75f8600c,
+    0x75f8a00d, 0x75f8e00e, 0x75f9200f, 0x75f96010, 0x75f9a011, 0x75f9e012, 0x75fa2013, 0x75fa6014,
+    0x75faa015, 0x75fae016, 0x75fb2017, 0x75fb6018, 0x75fba019, 0x75fbe01a, 0x75fc201b, 0x75fc601c,
+    0x75fca01d, 0x75fce01e, 0x75fd201f, 0x75fd6020, 0x75fda021, 0x75fde022, 0x75fe2023, 0x75fe6024,
+    0x75fea025, 0x75fee026, 0x75ff2027, 0x75ff6028, 0x75ffa029, 0x75ffe02a, 0x7a31e02b, 0x7a32202c,
+    0x7a32602d, 0x7a32e02e, 0x7a33202f, 0x7a336030, 0x7b802032, 0x7b806033, 0x7b80a034, 0x7b80e035,
+    0x7b816036, 0x7b81a037, 0x7b81e038, 0x7b822039, 0x7b82603a, 0x7b82a03b, 0x7b82e03c, 0x7b83203d,
+    0x7b83603e, 0x7b83a03f, 0x7b83e040, 0x7b842041, 0x7b846042, 0x7b84a043, 0x7b84e044, 0x7b852045,
+    0x7b856047, 0x7b85a048, 0x7b85e04a, 0x7b86204b, 0x7b86604c, 0x7b86a04d, 0x7b86e04e, 0x7b87204f,
+    0x7b876050, 0x7b87a051, 0x7b87e052, 0x7b886053, 0x7b88a054, 0x7b892055, 0x7b89e056, 0x7b8a6057,
+    0x7b8aa058, 0x7b8ae059, 0x7b8b205a, 0x7b8b605b, 0x7b8ba05c, 0x7b8be05d, 0x7b8c205e, 0x7b8c605f,
+    0x7b8ca060, 0x7b8d2061, 0x7b8d6063, 0x7b8da064, 0x7b8de066, 0x7b8e6067, 0x7b8ee068, 0x7b90a069,
+    0x7b91e06a, 0x7b92606b, 0x7b92e06c, 0x7b93606d, 0x7b93a06e, 0x7b93e06f, 0x7b946070, 0x7b94a071,
+    0x7b952072, 0x7b95e074, 0x7b966075, 0x7b96e076, 0x7b976077, 0x7b97e078, 0x7b986079, 0x7b98a07a,
+    0x7b99207b, 0x7b99e07c, 0x7b9a207d, 0x7b9a607e, 0x7b9aa07f, 0x7b9b2080, 0x7b9b6081, 0x7b9ba082,
+    0x7b9be083, 0x7b9c2084, 0x7b9c6085, 0x7b9ca086, 0x7b9d2087, 0x7b9d6089, 0x7b9da08a, 0x7b9de08c,
+    0x7b9e608d, 0x7b9ea08e, 0x7b9ee08f, 0x7b9f2090, 0x7b9fa091, 0x7ba02092, 0x7ba06093, 0x7ba0a094,
+    0x7ba0e095, 0x7ba12096, 0x7ba16097, 0x7ba1a098, 0x7ba1e099, 0x7ba2209a, 0x7ba2609b, 0x7ba2e09c,
+    0x7ba3209d, 0x7ba3609e, 0x7ba3a09f, 0x7ba3e0a0, 0x7ba420a1, 0x7ba460a2, 0x7ba4a0a3, 0x7ba4e0a4,
+    0x7ba520a5, 0x7ba560a7, 0x7ba5a0a8, 0x7ba5e0aa, 0x7ba620ab, 0x7ba660ac, 0x7ba6a0ad, 0x7ba6e0ae,
+    0x7ba860af, 0x7ba8a0b0, 0x7ba8e0b1, 0x7ba960b2, 0x7ba9a0b3, 0x7ba9e0b4, 0x7baa20b5, 0x7baa60b6,
+    0x7baae0b7, 0x7bab20b8, 0x7bab60b9, 0x7baba0ba, 0x7babe0bb, 0x7bac20bc, 0x7bac60bd, 0x7baca0be,
+    0x7bace0bf, 0x7bad20c0, 0x7bad60c2, 0x7bada0c3, 0x7bade0c5, 0x7bae20c6, 0x7bae60c7, 0x7baea0c8,
+    0x7baee0c9, 0x7c4020ca, 0x7c4060cc, 0x7c40a0ce, 0x7c40e0d0, 0x7c4120d2, 0x7c4160d4, 0x7c41a0d6,
+    0x7c41e0d8, 0x7c4220da, 0x7c4260dc, 0x7c42a0de, 0x7c43e0e0, 0x7c4420e2, 0x7c4460e5, 0x7c44a0e8,
+    0x7c44e0eb, 0x7c4520ee, 0x7c4560f1, 0x7c45a0f4, 0x7c45e0f7, 0x7c4620fa, 0x7c4660fd, 0x7c46a100,
+    0x7c46e103, 0x7c472106, 0x7c476109, 0x7c47a10c, 0x7c47e10f, 0x7c482112, 0x7c486115, 0x7c48a118,
+    0x7c48e11b, 0x7c49211e, 0x7c496121, 0x7c49a124, 0x7c49e127, 0x7c4a212a, 0x7c4a612d, 0x7c4aa130,
+    0x7c5b6133, 0x7c5ba136, 0x7c902138, 0x7c90613b, 0x7c90a13e, 0x7c90e141, 0x7c912144, 0x7c916147,
+    0x7c91a14a, 0x7c91e14d, 0x7c922150, 0x7cc4a153, 0x7cc62154, 0x7cc66155, 0x7dc02156, 0x7dc06158,
+    0x7dc0a159, 0x7dc1215a, 0x7dc1e15c, 0x7dc2215e, 0x7dc2a160, 0x7dc52161, 0x7dca2163, 0x7dcea165,
+    0x7dd32166, 0x7dd52167, 0x7dd56168, 0x7dd72169, 0x7dd7a16c, 0x7dda216d, 0x7ddae16e, 0x7ddb2170,
+    0x7ddc6172, 0x7efc2173, 0x7efc6174, 0x7efca175, 0x7efce176, 0x7efd2177, 0x7efd6178, 0x7efda179,
+    0x7efde17a, 0x7efe217b, 0x7efe617c, 0x87fa217d, 0xbe00217e, 0xbe00617f, 0xbe00a180, 0xbe00e181,
+    0xbe012183, 0xbe016184, 0xbe01a185, 0xbe01e186, 0xbe022187, 0xbe026188, 0xbe02a189, 0xbe02e18a,
+    0xbe03218b, 0xbe03618c, 0xbe03a18e, 0xbe03e18f, 0xbe042190, 0xbe046191, 0xbe04a192, 0xbe04e194,
+    0xbe052195, 0xbe056196, 0xbe05a197, 0xbe05e199, 0xbe06219a, 0xbe06619b, 0xbe06a19c, 0xbe06e19d,
+    0xbe07219e, 0xbe0761a0, 0xbe07a1a1, 0xbe07e1a2, 0xbe0821a3, 0xbe0861a4, 0xbe08a1a5, 0xbe08e1a6,
+    0xbe0921a7, 0xbe0961a8, 0xbe09a1a9, 0xbe09e1aa, 0xbe0a21ab, 0xbe0a61ac, 0xbe0aa1ad, 0xbe0ae1ae,
+    0xbe0b21af, 0xbe0b61b0, 0xbe0ba1b1, 0xbe0be1b2, 0xbe0c21b3, 0xbe0c61b4, 0xbe0ca1b5, 0xbe0ce1b6,
+    0xbe0d21b7, 0xbe0d61b9, 0xbe0da1ba, 0xbe0de1bb, 0xbe0e21bc, 0xbe0e61be, 0xbe0ea1bf, 0xbe0ee1c0,
+    0xbe0f21c1, 0xbe0f61c2, 0xbe0fa1c3, 0xbe0fe1c4, 0xbe1021c5, 0xbe1061c6, 0xbe10a1c7, 0xbe10e1c8,
+    0xbe1121c9, 0xbe1161ca, 0xbe11a1cb, 0xbe11e1cc, 0xbe1221cd, 0xbe1261ce, 0xbe12a1cf, 0xbe12e1d0,
+    0xbe1321d1, 0xbe1361d2, 0xbe13a1d3, 0xbe13e1d4, 0xbe1421d5, 0xbe1461d6, 0xbe14a1d7, 0xbe14e1d8,
+    0xbe1521d9, 0xbe1561da, 0xbe15a1db, 0xbe15e1dc, 0xbe1621dd, 0xbe1661de, 0xbe16a1e0, 0xbe16e1e1,
+    0xbe1721e2, 0xbe1761e3, 0xbe17a1e4, 0xbe17e1e5, 0xbe1821e6, 0xbe1861e8, 0xbe18a1ea, 0xbe18e1eb,
+    0xbe1921ec, 0xbe1961ed, 0xbe19a1ee, 0xbe19e1ef, 0xbe1a21f0, 0xbe1a61f1, 0xbe1aa1f2, 0xbe1ae1f3,
+    0xbe1b21f4, 0xbe1b61f6, 0xbe1ba1f7, 0xbe1be1f8, 0xbe1c21f9, 0xbe1c61fa, 0xbe1ca1fc, 0xbe1ce1fd,
+    0xbe1d21fe, 0xbe1d61ff, 0xbe1da200, 0xbe1de201, 0xbe1e2202, 0xbe1e6203, 0xbe1ea204, 0xbe1ee205,
+    0xbe1f2207, 0xbe1f6208, 0xbe1fa20a, 0xbe1fe20b, 0xbe20220c, 0xbe20620d, 0xbe20a20e, 0xbe20e20f,
+    0xbe212210, 0xbe216211, 0xbe21a212, 0xbe21e213, 0xbe222214, 0xbe226215, 0xbe22a217, 0xbe22e218,
+    0xbe232219, 0xbe23621a, 0xbe23a21b, 0xbe23e21c, 0xbe24221e, 0xbe24621f, 0xbe24a221, 0xbe24e223,
+    0xbe252224, 0xbe256225, 0xbe25a226, 0xbe25e227, 0xbe262229, 0xbe26622b, 0xbe26a22c, 0xbe26e22d,
+    0xbe27222e, 0xbe27622f, 0xbe27a230, 0xbe27e231, 0xbe282232, 0xbe286233, 0xbe28a234, 0xbe28e235,
+    0xbe292236, 0xbe296238, 0xbe29a239, 0xbe29e23a, 0xbe2a223b, 0xbe2a623c, 0xbe2aa23d, 0xbe2ae23e,
+    0xbe2b223f, 0xbe2b6240, 0xbe2ba241, 0xbe2be242, 0xbe2c2243, 0xbe2c6244, 0xbe2ca245, 0xbe2ce246,
+    0xbe2d2247, 0xbe2d6248, 0xbe2da249, 0xbe2de24a, 0xbe2e224b, 0xbe2e624d, 0xbe2ea24e, 0xbe2ee24f,
+    0xbe2f2250, 0xbe2f6251, 0xbe2fa252, 0xbe2fe254, 0xbe302255, 0xbe306256, 0xbe30a257, 0xbe30e258,
+    0xbe312259, 0xbe31625a, 0xbe31a25b, 0xbe31e25c, 0xbe32225d, 0xbe32625e, 0xbe32a25f, 0xbe32e261,
+    0xbe332262, 0xbe336263, 0xbe33a264, 0xbe33e265, 0xbe342266, 0xbe346267, 0xbe34a268, 0xbe34e269,
+    0xbe35226a, 0xbe35626b, 0xbe35a26c, 0xbe35e26d, 0xbe36226e, 0xbe36626f, 0xbe36a270, 0xbe36e271,
+    0xbe372272, 0xbe376273, 0xbe37a275, 0xbe37e276, 0xbe382277, 0xbe386278, 0xbe38a279, 0xbe38e27a,
+    0xbe39227c, 0xbe39627d, 0xbe39a27e, 0xbe39e27f, 0xbe3a2280, 0xbe3a6281, 0xbe3aa282, 0xbe3ae283,
+    0xbe3b2284, 0xbe3b6286, 0xbe3ba287, 0xbe3be288, 0xbe3c2289, 0xbe3c628b, 0xbe3ca28c, 0xbe3ce28d,
+    0xbe3d228e, 0xbe3d628f, 0xbe3da290, 0xbe3de291, 0xbe3e2293, 0xbe3e6295, 0xbe3ea297, 0xbe3ee298,
+    0xbe3f229a, 0xbe3f629b, 0xbe3fa29c, 0xbe3fe29d, 0xbe40229e, 0xbe40629f, 0xbe40a2a0, 0xbe40e2a1,
+    0xbe4122a2, 0xbe4162a3, 0xbe41a2a4, 0xbe41e2a6, 0xbe4222a7, 0xbe4262a8, 0xbe42a2a9, 0xbe42e2aa,
+    0xbe4322ab, 0xbe4362ac, 0xbe43a2ae, 0xbe43e2af, 0xbe4422b0, 0xbe4462b2, 0xbe44a2b4, 0xbe44e2b5,
+    0xbe4522b6, 0xbe4562b7, 0xbe45a2b8, 0xbe45e2b9, 0xbe4622ba, 0xbe4662bb, 0xbe46a2bc, 0xbe46e2bd,
+    0xbe4722bf, 0xbe4762c0, 0xbe47a2c2, 0xbe47e2c3, 0xbe4822c5, 0xbe4862c6, 0xbe48a2c7, 0xbe48e2c8,
+    0xbe4922ca, 0xbe4962cb, 0xbe49a2cc, 0xbe49e2ce, 0xbe4a22d0, 0xbe4a62d1, 0xbe4aa2d2, 0xbe4ae2d3,
+    0xbe4b22d4, 0xbe4b62d5, 0xbe4ba2d6, 0xbe4be2d7, 0xbe4c22d8, 0xbe4c62d9, 0xbe4ca2da, 0xbe4ce2db,
+    0xbe4d22dc, 0xbe4d62dd, 0xbe4da2df, 0xbe4de2e0, 0xbe4e22e2, 0xbe4e62e3, 0xbe4ea2e5, 0xbe4ee2e6,
+    0xbe4f22e8, 0xbe4f62ea, 0xbe4fa2ec, 0xbe4fe2ed, 0xbe5022ee, 0xbe5062ef, 0xbe50a2f1, 0xbe50e2f3,
+    0xbe5122f5, 0xbe5162f7, 0xbe51a2f8, 0xbe51e2f9, 0xbe5222fa, 0xbe5262fb, 0xbe52a2fc, 0xbe52e2fd,
+    0xbe5322fe, 0xbe5362ff, 0xbe53a301, 0xbe53e302, 0xbe542303, 0xbe546304, 0xbe54a305, 0xbe54e307,
+    0xbe552308, 0xbe55630a, 0xbe55a30c, 0xbe55e30d, 0xbe56230e, 0xbe56630f, 0xbe56a310, 0xbe56e311,
+    0xbe572312, 0xbe576314, 0xbe57a316, 0xbe57e318, 0xbe582319, 0xbe58631a, 0xbe58a31c, 0xbe58e31d,
+    0xbe59231e, 0xbe59631f, 0xbe59a321, 0xbe59e322, 0xbe5a2323, 0xbe5a6324, 0xbe5aa325, 0xbe5ae326,
+    0xbe5b2328, 0xbe5b6329, 0xbe5ba32a, 0xbe5be32b, 0xbe5c232c, 0xbe5c632d, 0xbe5ca32e, 0xbe5ce330,
+    0xbe5d2332, 0xbe5d6333, 0xbe5da335, 0xbe5de336, 0xbe5e2338, 0xbe5e6339, 0xbe5ea33a, 0xbe5ee33b,
+    0xbe5f233d, 0xbe5f633f, 0xbe5fa340, 0xbe5fe342, 0xbe602343, 0xbe606345, 0xbe60a346, 0xbe60e347,
+    0xbe612348, 0xbe616349, 0xbe61a34a, 0xbe61e34b, 0xbe62234d, 0xbe62634f, 0xbe62a351, 0xbe62e353,
+    0xbe632354, 0xbe636355, 0xbe63a356, 0xbe63e357, 0xbe642358, 0xbe646359, 0xbe64a35a, 0xbe64e35b,
+    0xbe65235c, 0xbe65635d, 0xbe65a35e, 0xbe65e35f, 0xbe662361, 0xbe666362, 0xbe66a363, 0xbe66e364,
+    0xbe672365, 0xbe676366, 0xbe67a367, 0xbe67e368, 0xbe682369, 0xbe68636a, 0xbe68a36b, 0xbe68e36c,
+    0xbe69236d, 0xbe69636f, 0xbe69a371, 0xbe69e373, 0xbe6a2374, 0xbe6a6375, 0xbe6aa376, 0xbe6ae377,
+    0xbe6b2379, 0xbe6b637a, 0xbe6ba37c, 0xbe6be37d, 0xbe6c237e, 0xbe6c6380, 0xbe6ca382, 0xbe6ce383,
+    0xbe6d2384, 0xbe6d6385, 0xbe6da386, 0xbe6de387, 0xbe6e2388, 0xbe6e6389, 0xbe6ea38a, 0xbe6ee38b,
+    0xbe6f238c, 0xbe6f638d, 0xbe6fa38e, 0xbe6fe38f, 0xbe702390, 0xbe706391, 0xbe70a392, 0xbe70e393,
+    0xbe712394, 0xbe716395, 0xbe71a397, 0xbe71e398, 0xbe722399, 0xbe72639a, 0xbe72a39b, 0xbe72e39c,
+    0xbe73239e, 0xbe7363a0, 0xbe73a3a1, 0xbe73e3a2, 0xbe7423a3, 0xbe7463a4, 0xbe74a3a5, 0xbe74e3a6,
+    0xbe7523a8, 0xbe7563a9, 0xbe75a3aa, 0xbe75e3ab, 0xbe7623ac, 0xbe7663ae, 0xbe76a3b0, 0xbe76e3b1,
+    0xbe7723b2, 0xbe7763b3, 0xbe77a3b5, 0xbe77e3b6, 0xbe7823b7, 0xbe7863b9, 0xbe78a3bb, 0xbe78e3bc,
+    0xbe7923bd, 0xbe7963be, 0xbe79a3c0, 0xbe79e3c1, 0xbe7a23c2, 0xbe7a63c3, 0xbe7aa3c4, 0xbe7ae3c5,
+    0xbe7b23c6, 0xbe7b63c7, 0xbe7ba3c9, 0xbe7be3ca, 0xbe7c23cb, 0xbe7c63cc, 0xbe7ca3ce, 0xbe7ce3cf,
+    0xbe7d23d0, 0xbe7d63d1, 0xbe7da3d2, 0xbe7de3d4, 0xbe7e23d6, 0xbe7e63d7, 0xbe7ea3d8, 0xbe7ee3d9,
+    0xbe7f23db, 0xbe7f63dc, 0xbe7fa3de, 0xbe7fe3df, 0xbe8023e0, 0xbe8063e1, 0xbe80a3e3, 0xbe80e3e4,
+    0xbe8123e5, 0xbe8163e6, 0xbe81a3e7, 0xbe81e3e8, 0xbe8223e9, 0xbe8263ea, 0xbe82a3ec, 0xbe82e3ed,
+    0xbe8323ee, 0xbe8363ef, 0xbe83a3f0, 0xbe83e3f1, 0xbe8423f2, 0xbe8463f4, 0xbe84a3f5, 0xbe84e3f7,
+    0xbe8523f9, 0xbe8563fb, 0xbe85a3fc, 0xbe85e3fd, 0xbe8623fe, 0xbe8663ff, 0xbe86a400, 0xbe86e401,
+    0xbe872402, 0xbe876403, 0xffffe405,
+];
+
+static confusable_data: [u16; 9221] = [
+    0x0027, 0x0027, 0x00ba, 0x002f, 0x2080, 0x004f, 0x006c, 0x006c, 0x0027, 0x0072, 0x006e, 0x006c,
+    0x0020, 0x0063, 0x0338, 0x0059, 0x0335, 0x02c9, 0x0027, 0x03bc, 0x002c, 0x0041, 0x0045, 0x0043,
+    0x0326, 0x0044, 0x0335, 0x0078, 0x004f, 0x0338, 0x0061, 0x0065, 0x0063, 0x0326, 0x2202, 0x0335,
+    0x0629, 0x006f, 0x0338, 0x0044, 0x0335, 0x0064, 0x0335, 0x0114, 0x0115, 0x0048, 0x0335, 0x0068,
+    0x0335, 0x0069, 0x006c, 0x004a, 0x0069, 0x006a, 0x006c, 0x00b7, 0x006c, 0x00b7, 0x004c, 0x0338,
+    0x006c, 0x0338, 0x0272, 0x0027, 0x006e, 0x00d6, 0x004f, 0x0045, 0x006f, 0x0065, 0x01ab, 0x0054,
+    0x0335, 0x0074, 0x0335, 0x0066, 0x0062, 0x0335, 0x0027, 0x0042, 0x0062, 0x0304, 0x0062, 0x0304,
+    0x0062, 0x0043, 0x0027, 0x0044, 0x0335, 0x0027, 0x0044, 0x0064, 0x0304, 0x0067, 0x0046, 0x0326,
+    0x0066, 0x0326, 0x0047, 0x0027, 0x006c, 0x006c, 0x0335, 0x004b, 0x0027, 0x006b, 0x0314, 0x006c,
+    0x0335, 0x03bb, 0x0338, 0x004e, 0x0326, 0x006e, 0x0329, 0x004f, 0x0335, 0x004f, 0x0027, 0x006f,
+    0x0027, 0x0027, 0x0050, 0x0070, 0x0314, 0x0052, 0x0032, 0x0027, 0x0054, 0x0074, 0x0314, 0x0054,
+    0x0328, 0x0027, 0x0059, 0x0079, 0x0314, 0x005a, 0x0335, 0x007a, 0x0335, 0x0033, 0x0032, 0x0335,
+    0x0035, 0x0073, 0x00fe, 0x006c, 0x006c, 0x006c, 0x0021, 0x0044, 0x017d, 0x0044, 0x017e, 0x0064,
+    0x017e, 0x004c, 0x004a, 0x004c, 0x006a, 0x006c, 0x006a, 0x004e, 0x004a, 0x004e, 0x006a, 0x006e,
+    0x006a, 0x0102, 0x0103, 0x012c, 0x012d, 0x014e, 0x014f, 0x016c, 0x016d, 0x0047, 0x0335, 0x0067,
+    0x0335, 0x011e, 0x011f, 0x0044, 0x005a, 0x0044, 0x007a, 0x0064, 0x007a, 0x0123, 0x004f, 0x0338,
+    0x0301, 0x0162, 0x01ab, 0x0033, 0x0038, 0x0038, 0x005a, 0x0326, 0x007a, 0x0326, 0x00c5, 0x00e5,
+    0x0063, 0x0338, 0x0054, 0x0338, 0x003f, 0x0055, 0x0335, 0x0045, 0x0338, 0x0065, 0x0338, 0x004a,
+    0x0335, 0x006a, 0x0335, 0x0072, 0x0335, 0x0059, 0x0335, 0x0079, 0x0335, 0x0061, 0x0062, 0x0314,
+    0x0064, 0x0328, 0x0064, 0x0314, 0x01dd, 0x01dd, 0x02de, 0xa793, 0x0067, 0x0314, 0x0067, 0x0079,
+    0x0068, 0x0314, 0x0069, 0x0335, 0x0069, 0x0069, 0x006c, 0x0334, 0x006c, 0x0328, 0x006c, 0x021d,
+    0x0077, 0x0072, 0x006e, 0x0326, 0x006e, 0x0328, 0x006f, 0x0335, 0x006f, 0x1d07, 0x0072, 0x0329,
+    0x0072, 0x0328, 0x0073, 0x0328, 0x0075, 0x0079, 0x007a, 0x0328, 0x021d, 0x003f, 0x0071, 0x0314,
+    0x0064, 0x007a, 0x0064, 0x021d, 0x0064, 0x0291, 0x0074, 0x0073, 0x0074, 0x0283, 0x0074, 0x0255,
+    0x0066, 0x014b, 0x006c, 0x0073, 0x006c, 0x007a, 0x18f4, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027,
+    0x0027, 0x0027, 0x0559, 0x003c, 0x003e, 0x005e, 0x005e, 0x0027, 0x0027, 0x0027, 0x003a, 0x0559,
+    0x002d, 0x02c7, 0x0971, 0x00b0, 0x0069, 0x007e, 0x0027, 0x0027, 0x18f3, 0x18f5, 0x02c1, 0x0027,
+    0x0027, 0x0027, 0x0027, 0x0027, 0x003a, 0x02ea, 0x0304, 0x0306, 0x0670, 0x0306, 0x0307, 0x0302,
+    0x0313, 0x0650, 0x0331, 0x0326, 0x0328, 0x0326, 0x0335, 0x0338, 0x0326, 0x0300, 0x0301, 0x0303,
+    0x0313, 0x0328, 0x0333, 0x0350, 0x0307, 0x030a, 0x0306, 0x2c75, 0x0027, 0x02cf, 0x0418, 0x1d0e,
+    0x0069, 0x0254, 0xa73f, 0x003b, 0x004a, 0x0027, 0x00b7, 0x0041, 0x0042, 0x0045, 0x005a, 0x0048,
+    0x004f, 0x0335, 0x006c, 0x004b, 0x0245, 0x004d, 0x004e, 0x004f, 0x0050, 0x01a9, 0x0054, 0x0059,
+    0x0058, 0x0061, 0x00df, 0x0079, 0x1e9f, 0xa793, 0x006e, 0x0329, 0x004f, 0x0335, 0x0069, 0x0138,
+    0x0076, 0x006f, 0x0070, 0x006f, 0x1d1b, 0x0075, 0x0278, 0x00df, 0x004f, 0x0335, 0x0059, 0x0278,
+    0x03c0, 0x03c2, 0x0046, 0x0032, 0x01a8, 0x0138, 0x0070, 0x0063, 0x006a, 0x004f, 0x0335, 0xa793,
+    0x00de, 0x00fe, 0x0043, 0x004d, 0x0186, 0xa73e, 0xa792, 0x0053, 0x006c, 0x004a, 0x0041, 0x0062,
+    0x0304, 0x0042, 0x0393, 0x0045, 0x0033, 0x040d, 0x004b, 0x0245, 0x004d, 0x0048, 0x004f, 0x03a0,
+    0x0050, 0x0043, 0x0054, 0x0059, 0x03a6, 0x0058, 0x0062, 0x006c, 0x0062, 0x006c, 0x004f, 0x0061,
+    0x0036, 0x0299, 0x0072, 0x0065, 0x025c, 0x1d0e, 0x0138, 0x028d, 0x029c, 0x006f, 0x03c0, 0x0070,
+    0x0063, 0x1d1b, 0x0079, 0x0278, 0x0078, 0x02c9, 0x0062, 0x0185, 0x0069, 0x0185, 0x1d19, 0xa793,
+    0x0073, 0x0069, 0x006a, 0x0068, 0x0335, 0x0439, 0x0077, 0x0062, 0x0335, 0x0062, 0x0335, 0x03a8,
+    0x03c8, 0x004f, 0x0335, 0x006f, 0x0335, 0x0056, 0x0076, 0x0460, 0x0486, 0x0487, 0x0077, 0x0486,
+    0x0487, 0x040d, 0x0326, 0x0439, 0x0326, 0x0062, 0x0335, 0x0062, 0x0335, 0x0393, 0x0027, 0x0072,
+    0x0027, 0x0393, 0x0335, 0x0072, 0x0335, 0x0416, 0x0329, 0x0436, 0x0329, 0x0033, 0x0326, 0x025c,
+    0x0326, 0x004b, 0x0329, 0x0138, 0x0329, 0x004b, 0x0335, 0x0138, 0x0335, 0x0048, 0x0329, 0x029c,
+    0x0329, 0x0043, 0x0326, 0x0063, 0x0326, 0x0054, 0x0329, 0x1d1b, 0x0329, 0x0059, 0x0079, 0x0059,
+    0x0335, 0x0079, 0x0335, 0x0058, 0x0329, 0x0068, 0x0065, 0x04bc, 0x0328, 0x0065, 0x0328, 0x006c,
+    0x0245, 0x0326, 0x043b, 0x0326, 0x0048, 0x0326, 0x029c, 0x0326, 0x0048, 0x0326, 0x029c, 0x0326,
+    0x04b6, 0x04b7, 0x004d, 0x0326, 0x028d, 0x0326, 0x0069, 0x0041, 0x0045, 0x0061, 0x0065, 0x018f,
+    0x01dd, 0x0033, 0x021d, 0x004f, 0x0335, 0x006f, 0x0335, 0x0064, 0x01f6, 0x0047, 0x0262, 0x0190,
+    0xa793, 0x0071, 0x0057, 0x0077, 0x12ae, 0x1206, 0x1323, 0x1261, 0x0055, 0x0053, 0x03a6, 0x004f,
+    0x0027, 0x0027, 0x0077, 0x0071, 0x0071, 0x1e9f, 0x0068, 0x0237, 0x006e, 0x0270, 0x006e, 0x0075,
+    0x0067, 0x0066, 0x006f, 0x0565, 0x0582, 0x003a, 0x0301, 0x0301, 0x059a, 0x0599, 0x0596, 0x0598,
+    0x030a, 0x0323, 0x0307, 0x0307, 0x006c, 0x0307, 0x0307, 0x003a, 0x0307, 0x0323, 0x006c, 0x0076,
+    0x0027, 0x006c, 0x006f, 0x006c, 0x006c, 0x006c, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027,
+    0x00ba, 0x002f, 0x2080, 0x2080, 0x00ba, 0x002f, 0x2080, 0x2080, 0x2080, 0x002c, 0x0639, 0x0301,
+    0x0313, 0x0650, 0x006c, 0x0674, 0x0648, 0x0674, 0x006c, 0x0655, 0x0649, 0x0674, 0x006c, 0x0649,
+    0x06db, 0x0633, 0x06db, 0x0649, 0x0302, 0x0649, 0x06db, 0x006f, 0x0649, 0x030b, 0x0301, 0x0313,
+    0x030a, 0x0303, 0x0329, 0x0312, 0x0306, 0x0304, 0x0306, 0x0302, 0x0323, 0x0314, 0x0655, 0x002e,
+    0x006c, 0x006f, 0x0056, 0x0245, 0x00ba, 0x002f, 0x2080, 0x002c, 0x060c, 0x002a, 0x0649, 0x06a1,
+    0x006c, 0x0674, 0x006c, 0x0655, 0x006c, 0x0674, 0x0648, 0x0674, 0x0648, 0x0313, 0x0674, 0x0649,
+    0x0674, 0x0649, 0x0615, 0x0649, 0x06db, 0x062d, 0x0654, 0x062d, 0x06db, 0x062f, 0x0615, 0x068a,
+    0x0615, 0x062f, 0x06db, 0x0631, 0x0615, 0x0631, 0x0306, 0x0631, 0x06db, 0x0635, 0x06db, 0x0637,
+    0x06db, 0x06a1, 0x06db, 0x0641, 0x06a1, 0x06db, 0x0643, 0x0643, 0x0643, 0x06db, 0x06af, 0x06db,
+    0x0644, 0x0306, 0x0644, 0x06db, 0x0649, 0x0649, 0x0615, 0x0649, 0x06db, 0x006f, 0x006f, 0x06c0,
+    0x0629, 0x0648, 0x0306, 0x0648, 0x0313, 0x0648, 0x0670, 0x0648, 0x0302, 0x0648, 0x06db, 0x0649,
+    0x0649, 0x0306, 0x067b, 0x0649, 0x06db, 0x0649, 0x002d, 0x006f, 0x030a, 0x0306, 0x0307, 0x0307,
+    0x062f, 0x0302, 0x0631, 0x0302, 0x002e, 0x006c, 0x0662, 0x0663, 0x0664, 0x006f, 0x0666, 0x0056,
+    0x0245, 0x0669, 0x0621, 0x0348, 0x0645, 0x0348, 0x006f, 0x0302, 0x002e, 0x002e, 0x003a, 0x003a,
+    0x0307, 0x0307, 0x073c, 0x0301, 0x0628, 0x06db, 0x0649, 0x0306, 0x06ac, 0x0643, 0x06db, 0x0754,
+    0x0646, 0x0615, 0x0646, 0x0306, 0x0631, 0x0654, 0x0697, 0x0615, 0x062d, 0x0654, 0x0633, 0x0302,
+    0x004f, 0x006c, 0x0304, 0x0307, 0x0302, 0x0308, 0x0027, 0x0027, 0x005f, 0x0628, 0x0654, 0x06a2,
+    0x06db, 0x0645, 0x06db, 0x0649, 0x0654, 0x0754, 0x062f, 0x0324, 0x0323, 0x0635, 0x0324, 0x0323,
+    0x06af, 0x0648, 0x0632, 0x0302, 0x0628, 0x06e2, 0x0649, 0x06db, 0x06e2, 0x0631, 0x0306, 0x0307,
+    0x0649, 0x0306, 0x0307, 0x06a1, 0x06a1, 0x0649, 0x064c, 0x064c, 0x0307, 0x0308, 0x0323, 0x0324,
+    0x030b, 0x064c, 0x064d, 0x0313, 0x0350, 0x0354, 0x0355, 0x0350, 0x0352, 0x0306, 0x0307, 0x0307,
+    0x003a, 0x0905, 0x0946, 0x0905, 0x093e, 0x0930, 0x094d, 0x0907, 0x090f, 0x0945, 0x090f, 0x0946,
+    0x090f, 0x0947, 0x0905, 0x0949, 0x0905, 0x093e, 0x0946, 0x0905, 0x093e, 0x0947, 0x0905, 0x093e,
+    0x0948, 0x0323, 0x0331, 0x0300, 0x0301, 0x0964, 0x0964, 0x006f, 0x0669, 0x003f, 0x0306, 0x0307,
+    0x0985, 0x09be, 0x0323, 0x098b, 0x09c3, 0x098b, 0x09c3, 0x004f, 0x0038, 0x0039, 0x0307, 0x0983,
+    0x0a05, 0x0a3e, 0x0a72, 0x0a3f, 0x0a72, 0x0a40, 0x0a73, 0x0a41, 0x0a73, 0x0a42, 0x0a72, 0x0a47,
+    0x0a05, 0x0a48, 0x0a05, 0x0a4c, 0x0323, 0x0946, 0x094d, 0x006f, 0x0039, 0x0038, 0x0306, 0x0307,
+    0x0307, 0x003a, 0x0a85, 0x0abe, 0x0a85, 0x0ac5, 0x0a85, 0x0ac7, 0x0a85, 0x0ac8, 0x0a85, 0x0abe,
+    0x0ac5, 0x0a85, 0x0abe, 0x0ac7, 0x0a85, 0x0abe, 0x0ac8, 0x0323, 0x093d, 0x0941, 0x0942, 0x094d,
+    0x006f, 0x0968, 0x0969, 0x096a, 0x096e, 0x0970, 0x0306, 0x0307, 0x0038, 0x0b05, 0x0b3e, 0x004f,
+    0x0323, 0x004f, 0x0039, 0x030a, 0x0b89, 0x0bb3, 0x0b90, 0x0b88, 0x0b88, 0x0ba9, 0x0bc6, 0x0b88,
+    0x0bc7, 0x0b88, 0x0bc6, 0x0bb3, 0x0307, 0x0bb3, 0x006f, 0x0b95, 0x0b89, 0x0b9a, 0x0b88, 0x0bc1,
+    0x0b9a, 0x0bc1, 0x0b8e, 0x0b85, 0x0baf, 0x0b9a, 0x0bc2, 0x0bae, 0x0bc0, 0x0bf3, 0x0b8e, 0x0bb5,
+    0x0bb7, 0x0ba8, 0x0bc0, 0x0306, 0x0307, 0x006f, 0x0983, 0x0c12, 0x0c55, 0x0c12, 0x0c4c, 0x0c30,
+    0x05bc, 0x0c21, 0x0323, 0x0c27, 0x05bc, 0x0c2c, 0x0323, 0x0c35, 0x0c41, 0x0c35, 0x0323, 0x0c35,
+    0x0c3e, 0x0c41, 0x0c3e, 0x0c43, 0x0c3e, 0x0c0b, 0x0c3e, 0x0c0c, 0x0c3e, 0x006f, 0x0306, 0x0307,
+    0x006f, 0x0983, 0x0c05, 0x0c06, 0x0c07, 0x0c12, 0x0c12, 0x0c55, 0x0c12, 0x0c4c, 0x0c1c, 0x0c1e,
+    0x0c23, 0x0c2f, 0x0c31, 0x0c32, 0x0c8c, 0x0cbe, 0x006f, 0x0c67, 0x0c68, 0x0c6f, 0x0306, 0x0307,
+    0x006f, 0x0983, 0x0d07, 0x0d57, 0x0b89, 0x0b89, 0x0d57, 0x0d28, 0x0d41, 0x0d0e, 0x0d46, 0x0d12,
+    0x0d3e, 0x0d12, 0x0d57, 0x0d28, 0x0d41, 0x0b90, 0x006f, 0x0ba3, 0x0d30, 0x0bb4, 0x0bb6, 0x0b9f,
+    0x0bbf, 0x0bbf, 0x0bbf, 0x0d41, 0x0d41, 0x0d46, 0x0d46, 0x0971, 0x0d28, 0x0d4d, 0x0d2e, 0x006f,
+    0x0d30, 0x006f, 0x0d1e, 0x006f, 0x0d30, 0x0d4d, 0x0d26, 0x0d4d, 0x0d30, 0x0d28, 0x0d4d, 0x0d28,
+    0x0039, 0x0d35, 0x0d4d, 0x0d30, 0x0d28, 0x0d4d, 0x0d39, 0x0d4d, 0x0d2e, 0x0d28, 0x0d41, 0x0d28,
+    0x0d4d, 0x0d30, 0x0d4d, 0x006f, 0x0983, 0x0de8, 0x0dcf, 0x0da2, 0x0daf, 0x0de8, 0x0dd3, 0x0e02,
+    0x0e0a, 0x0e0e, 0x0e04, 0x0e04, 0x0e11, 0x0e06, 0x0e20, 0x030a, 0x0e32, 0x0e40, 0x0e40, 0x0e32,
+    0x030a, 0x006f, 0x0e08, 0x0e22, 0x0e1a, 0x0e1b, 0x0e1d, 0x0e1e, 0x0e1f, 0x030a, 0x0eb2, 0x0e38,
+    0x0e39, 0x0e48, 0x0e49, 0x0e4a, 0x0e4b, 0x030a, 0x006f, 0x0eab, 0x0e99, 0x0eab, 0x0ea1, 0x0f68,
+    0x0f7c, 0x0f7e, 0x0f60, 0x0f74, 0x0f82, 0x0f7f, 0x0f60, 0x0f74, 0x0f82, 0x0f14, 0x0f0b, 0x0f0d,
+    0x0f0d, 0x0f1a, 0x0f1a, 0x0f1d, 0x0f1d, 0x0f1a, 0x0f1d, 0x0325, 0x0f62, 0x0fb2, 0x0f71, 0x0f80,
+    0x0fb3, 0x0f71, 0x0f80, 0x0f1d, 0x0f1a, 0x5350, 0x534d, 0x1002, 0x102c, 0x006f, 0x102c, 0x006f,
+    0x1015, 0x102c, 0x101e, 0x103c, 0x101e, 0x103c, 0x1031, 0x102c, 0x103a, 0x030a, 0x0983, 0x006f,
+    0x104a, 0x104a, 0x1041, 0x1015, 0x103e, 0x1015, 0x102c, 0x103e, 0x1003, 0x103e, 0x107d, 0x103e,
+    0x1002, 0x103e, 0x1083, 0x030a, 0xa786, 0x0079, 0x021d, 0x006f, 0x1100, 0x1100, 0x1103, 0x1103,
+    0x1107, 0x1107, 0x1109, 0x1109, 0x110c, 0x110c, 0x1102, 0x1100, 0x1102, 0x1102, 0x1102, 0x1103,
+    0x1102, 0x1107, 0x1103, 0x1100, 0x1105, 0x1102, 0x1105, 0x1105, 0x1105, 0x1112, 0x1105, 0x110b,
+    0x1106, 0x1107, 0x1106, 0x110b, 0x1107, 0x1100, 0x1107, 0x1102, 0x1107, 0x1103, 0x1107, 0x1109,
+    0x1107, 0x1109, 0x1100, 0x1107, 0x1109, 0x1103, 0x1107, 0x1109, 0x1107, 0x1107, 0x1109, 0x1109,
+    0x1107, 0x1109, 0x110c, 0x1107, 0x110c, 0x1107, 0x110e, 0x1107, 0x1110, 0x1107, 0x1111, 0x1107,
+    0x110b, 0x1107, 0x1107, 0x110b, 0x1109, 0x1100, 0x1109, 0x1102, 0x1109, 0x1103, 0x1109, 0x1105,
+    0x1109, 0x1106, 0x1109, 0x1107, 0x1109, 0x1107, 0x1100, 0x1109, 0x1109, 0x1109, 0x1109, 0x110b,
+    0x1109, 0x110c, 0x1109, 0x110e, 0x1109, 0x110f, 0x1109, 0x1110, 0x1109, 0x1111, 0x1105, 0x1112,
+    0x113c, 0x113c, 0x113e, 0x113e, 0x110b, 0x1100, 0x110b, 0x1103, 0x110b, 0x1106, 0x110b, 0x1107,
+    0x110b, 0x1109, 0x110b, 0x1140, 0x110b, 0x110b, 0x110b, 0x110c, 0x110b, 0x110e, 0x110b, 0x1110,
+    0x110b, 0x1111, 0x110c, 0x110b, 0x114e, 0x114e, 0x1150, 0x1150, 0x110e, 0x110f, 0x110e, 0x1112,
+    0x1111, 0x1107, 0x1111, 0x110b, 0x1112, 0x1112, 0x1100, 0x1103, 0x1102, 0x1109, 0x1102, 0x110c,
+    0x1102, 0x1112, 0x1103, 0x1105, 0x1161, 0x4e28, 0x1163, 0x4e28, 0x1165, 0x4e28, 0x1167, 0x4e28,
+    0x1169, 0x1161, 0x1169, 0x1161, 0x4e28, 0x1169, 0x4e28, 0x116e, 0x1165, 0x116e, 0x1165, 0x4e28,
+    0x116e, 0x4e28, 0x30fc, 0x30fc, 0x4e28, 0x4e28, 0x1161, 0x1169, 0x1161, 0x116e, 0x1163, 0x1169,
+    0x1163, 0x116d, 0x1165, 0x1169, 0x1165, 0x116e, 0x1165, 0x30fc, 0x1167, 0x1169, 0x1167, 0x116e,
+    0x1169, 0x1165, 0x1169, 0x1165, 0x4e28, 0x1169, 0x1167, 0x4e28, 0x1169, 0x1169, 0x1169, 0x116e,
+    0x116d, 0x1163, 0x116d, 0x1163, 0x4e28, 0x116d, 0x1163, 0x116d, 0x1169, 0x116d, 0x4e28, 0x116e,
+    0x1161, 0x116e, 0x1161, 0x4e28, 0x116e, 0x1165, 0x30fc, 0x116e, 0x1167, 0x4e28, 0x116e, 0x116e,
+    0x1172, 0x1161, 0x1172, 0x1165, 0x1172, 0x1165, 0x4e28, 0x1172, 0x1167, 0x1172, 0x1167, 0x4e28,
+    0x1172, 0x116e, 0x1172, 0x4e28, 0x30fc, 0x116e, 0x30fc, 0x30fc, 0x30fc, 0x4e28, 0x116e, 0x4e28,
+    0x1161, 0x4e28, 0x1163, 0x4e28, 0x1169, 0x4e28, 0x116e, 0x4e28, 0x30fc, 0x4e28, 0x119e, 0x119e,
+    0x1165, 0x119e, 0x116e, 0x119e, 0x4e28, 0x119e, 0x119e, 0x1161, 0x30fc, 0x1163, 0x116e, 0x1167,
+    0x1163, 0x1169, 0x1163, 0x1169, 0x1163, 0x4e28, 0x1100, 0x1100, 0x1100, 0x1100, 0x1109, 0x1102,
+    0x1102, 0x110c, 0x1102, 0x1112, 0x1103, 0x1105, 0x1105, 0x1100, 0x1105, 0x1106, 0x1105, 0x1107,
+    0x1105, 0x1109, 0x1105, 0x1110, 0x1105, 0x1111, 0x1105, 0x1112, 0x1106, 0x1107, 0x1107, 0x1109,
+    0x1109, 0x1109, 0x1109, 0x110b, 0x110c, 0x110e, 0x110f, 0x1110, 0x1111, 0x1112, 0x1100, 0x1105,
+    0x1100, 0x1109, 0x1100, 0x1102, 0x1100, 0x1102, 0x1103, 0x1102, 0x1109, 0x1102, 0x1140, 0x1102,
+    0x1110, 0x1103, 0x1100, 0x1103, 0x1105, 0x1105, 0x1100, 0x1109, 0x1105, 0x1102, 0x1105, 0x1103,
+    0x1105, 0x1103, 0x1112, 0x1105, 0x1105, 0x1105, 0x1106, 0x1100, 0x1105, 0x1106, 0x1109, 0x1105,
+    0x1107, 0x1109, 0x1105, 0x1107, 0x1112, 0x1105, 0x1107, 0x110b, 0x1105, 0x1109, 0x1109, 0x1105,
+    0x1140, 0x1105, 0x110f, 0x1105, 0x1159, 0x1106, 0x1100, 0x1106, 0x1105, 0x1106, 0x1107, 0x1106,
+    0x1109, 0x1106, 0x1109, 0x1109, 0x1106, 0x1140, 0x1106, 0x110e, 0x1106, 0x1112, 0x1106, 0x110b,
+    0x1107, 0x1105, 0x1107, 0x1111, 0x1107, 0x1112, 0x1107, 0x110b, 0x1109, 0x1100, 0x1109, 0x1103,
+    0x1109, 0x1105, 0x1109, 0x1107, 0x1140, 0x110b, 0x1100, 0x110b, 0x1100, 0x1100, 0x110b, 0x110b,
+    0x110b, 0x110f, 0x114c, 0x110b, 0x1109, 0x110b, 0x1140, 0x1111, 0x1107, 0x1111, 0x110b, 0x1112,
+    0x1102, 0x1112, 0x1105, 0x1112, 0x1106, 0x1112, 0x1107, 0x1159, 0x1100, 0x1102, 0x1100, 0x1107,
+    0x1100, 0x110e, 0x1100, 0x110f, 0x1100, 0x1112, 0x1102, 0x1102, 0x0055, 0x0270, 0x03a6, 0x0548,
+    0x0571, 0x004f, 0x0044, 0x0052, 0x0054, 0x004f, 0x0027, 0x0069, 0x2c75, 0x0059, 0x0041, 0x004a,
+    0x0045, 0x003f, 0x2c75, 0x0393, 0x0057, 0x004d, 0x0048, 0x0059, 0x004f, 0x0335, 0x01ab, 0x0047,
+    0x0068, 0x005a, 0x0460, 0x0190, 0x0055, 0x0335, 0x0034, 0x0062, 0x0052, 0x0057, 0x0053, 0x0056,
+    0x0053, 0x004c, 0x0043, 0x0050, 0x004b, 0x0064, 0x004f, 0x0335, 0x0036, 0x00df, 0x0068, 0x0314,
+    0x0047, 0x0042, 0x0262, 0x0299, 0x003d, 0x0394, 0x00b7, 0x1401, 0x1401, 0x00b7, 0x00b7, 0x0394,
+    0x0394, 0x00b7, 0x00b7, 0x1404, 0x1404, 0x00b7, 0x00b7, 0x1405, 0x1405, 0x00b7, 0x00b7, 0x1406,
+    0x1406, 0x00b7, 0x00b7, 0x140a, 0x140a, 0x00b7, 0x00b7, 0x140b, 0x140b, 0x00b7, 0x00b7, 0x1401,
+    0x1420, 0x0394, 0x1420, 0x1405, 0x1420, 0x140a, 0x1420, 0x0056, 0x0245, 0x003e, 0x00b7, 0x003e,
+    0x003c, 0x00b7, 0x0056, 0x0056, 0x00b7, 0x00b7, 0x0245, 0x0245, 0x00b7, 0x00b7, 0x1432, 0x1432,
+    0x00b7, 0x00b7, 0x003e, 0x003e, 0x00b7, 0x00b7, 0x1434, 0x1434, 0x00b7, 0x00b7, 0x003c, 0x003c,
+    0x00b7, 0x00b7, 0x1439, 0x1439, 0x00b7, 0x0027, 0x0055, 0x0548, 0x00b7, 0x1450, 0x00b7, 0x0055,
+    0x0055, 0x00b7, 0x00b7, 0x0548, 0x0548, 0x00b7, 0x00b7, 0x144f, 0x144f, 0x00b7, 0x00b7, 0x1450,
+    0x1450, 0x00b7, 0x00b7, 0x1451, 0x1451, 0x00b7, 0x00b7, 0x1455, 0x1455, 0x00b7, 0x00b7, 0x1456,
+    0x1456, 0x00b7, 0x0055, 0x0027, 0x0548, 0x0027, 0x1450, 0x0027, 0x1455, 0x0027, 0x0050, 0x0064,
+    0x0062, 0x0062, 0x0307, 0x00b7, 0x146b, 0x146b, 0x00b7, 0x00b7, 0x0050, 0x0070, 0x00b7, 0x00b7,
+    0x146e, 0x146e, 0x00b7, 0x00b7, 0x0064, 0x0064, 0x00b7, 0x00b7, 0x1470, 0x1470, 0x00b7, 0x00b7,
+    0x0062, 0x0062, 0x00b7, 0x00b7, 0x0062, 0x0307, 0x0062, 0x0307, 0x00b7, 0x146b, 0x0027, 0x0050,
+    0x0027, 0x0064, 0x0027, 0x0062, 0x0027, 0x004a, 0x00b7, 0x1489, 0x1489, 0x00b7, 0x00b7, 0x148b,
+    0x148b, 0x00b7, 0x00b7, 0x148c, 0x148c, 0x00b7, 0x00b7, 0x004a, 0x004a, 0x00b7, 0x00b7, 0x148e,
+    0x148e, 0x00b7, 0x00b7, 0x1490, 0x1490, 0x00b7, 0x00b7, 0x1491, 0x1491, 0x00b7, 0x0393, 0x004c,
+    0x00b7, 0x14a3, 0x14a3, 0x00b7, 0x00b7, 0x0393, 0x0393, 0x00b7, 0x00b7, 0x14a6, 0x14a6, 0x00b7,
+    0x00b7, 0x14a7, 0x14a7, 0x00b7, 0x00b7, 0x14a8, 0x14a8, 0x00b7, 0x00b7, 0x004c, 0x006c, 0x00b7,
+    0x00b7, 0x14ab, 0x14ab, 0x00b7, 0x0032, 0x00b7, 0x14c0, 0x14c0, 0x00b7, 0x00b7, 0x14c7, 0x14c7,
+    0x00b7, 0x00b7, 0x14c8, 0x14c8, 0x00b7, 0x1421, 0x00b7, 0x14d3, 0x14d3, 0x00b7, 0x00b7, 0x14d5,
+    0x14d5, 0x00b7, 0x00b7, 0x14d6, 0x14d6, 0x00b7, 0x00b7, 0x14d7, 0x14d7, 0x00b7, 0x00b7, 0x14d8,
+    0x14d8, 0x00b7, 0x00b7, 0x14da, 0x14da, 0x00b7, 0x00b7, 0x14db, 0x14db, 0x00b7, 0x00b7, 0x14ed,
+    0x14ed, 0x00b7, 0x00b7, 0x14ef, 0x14ef, 0x00b7, 0x00b7, 0x14f0, 0x14f0, 0x00b7, 0x00b7, 0x14f1,
+    0x14f1, 0x00b7, 0x00b7, 0x14f2, 0x14f2, 0x00b7, 0x00b7, 0x14f4, 0x14f4, 0x00b7, 0x00b7, 0x14f5,
+    0x14f5, 0x00b7, 0x150b, 0x003c, 0x150b, 0x1455, 0x150b, 0x0062, 0x150b, 0x1490, 0x00b7, 0x1510,
+    0x1510, 0x00b7, 0x00b7, 0x1511, 0x1511, 0x00b7, 0x00b7, 0x1512, 0x1512, 0x00b7, 0x00b7, 0x1513,
+    0x1513, 0x00b7, 0x00b7, 0x1514, 0x1514, 0x00b7, 0x00b7, 0x1515, 0x1515, 0x00b7, 0x00b7, 0x1516,
+    0x1516, 0x00b7, 0x00b7, 0x0034, 0x0034, 0x00b7, 0x00b7, 0x1528, 0x1528, 0x00b7, 0x00b7, 0x1529,
+    0x1529, 0x00b7, 0x00b7, 0x152a, 0x152a, 0x00b7, 0x00b7, 0x152b, 0x152b, 0x00b7, 0x00b7, 0x152d,
+    0x152d, 0x00b7, 0x00b7, 0x152e, 0x152e, 0x00b7, 0x1429, 0x0078, 0x00b7, 0x154c, 0x154c, 0x00b7,
+    0x00b7, 0x155a, 0x155a, 0x00b7, 0x00b7, 0x1567, 0x1567, 0x00b7, 0x1e9f, 0x0048, 0x0078, 0x1550,
+    0x146c, 0x1550, 0x0050, 0x1550, 0x146e, 0x1550, 0x0064, 0x1550, 0x1470, 0x1550, 0x0062, 0x1550,
+    0x0062, 0x0307, 0x1550, 0x1483, 0x0052, 0x1595, 0x148a, 0x1595, 0x148b, 0x1595, 0x148c, 0x1595,
+    0x004a, 0x1595, 0x148e, 0x1595, 0x1490, 0x1595, 0x1491, 0x0062, 0x0046, 0x2132, 0xa7fb, 0x2c6f,
+    0x0041, 0x0044, 0x0044, 0x0460, 0x004d, 0x0042, 0x1490, 0x1489, 0x14d3, 0x14da, 0x1543, 0x1546,
+    0x154a, 0x01b1, 0x03a9, 0x01b1, 0x03a9, 0x0058, 0x0078, 0x1550, 0x146b, 0x1595, 0x1489, 0x1596,
+    0x148b, 0x1596, 0x148c, 0x1596, 0x004a, 0x1596, 0x148e, 0x1596, 0x1490, 0x1596, 0x1491, 0x15a7,
+    0x00b7, 0x15a8, 0x00b7, 0x15a9, 0x00b7, 0x15aa, 0x00b7, 0x15ab, 0x00b7, 0x15ac, 0x00b7, 0x15ad,
+    0x00b7, 0x0020, 0x003c, 0x0058, 0x006c, 0x16bd, 0x0027, 0x004b, 0x004d, 0x03a8, 0x16bc, 0x00b7,
+    0x003a, 0x002b, 0x03a6, 0x002f, 0x17a2, 0x0e34, 0x0e35, 0x0e36, 0x0e37, 0x030a, 0x0e48, 0x030a,
+    0x0e2f, 0x0e5a, 0x0e4f, 0x0e5b, 0x003a, 0x003a, 0x1835, 0x185c, 0x00b7, 0x18b1, 0x00b7, 0x18b4,
+    0x00b7, 0x18b8, 0x00b7, 0x18c0, 0x00b7, 0x14c2, 0x14c2, 0x00b7, 0x00b7, 0x14c3, 0x14c3, 0x00b7,
+    0x00b7, 0x14c4, 0x14c4, 0x00b7, 0x00b7, 0x14c5, 0x14c5, 0x00b7, 0x00b7, 0x1543, 0x00b7, 0x1546,
+    0x00b7, 0x1547, 0x00b7, 0x1548, 0x00b7, 0x1549, 0x00b7, 0x154b, 0x18f5, 0x18df, 0x141e, 0x141e,
+    0x18df, 0x1543, 0x00b7, 0x155e, 0x00b7, 0x1566, 0x00b7, 0x156b, 0x00b7, 0x1586, 0x00b7, 0x1597,
+    0x00b7, 0x0460, 0x00b7, 0x15f4, 0x00b7, 0x161b, 0x00b7, 0x199e, 0x19b1, 0x1a45, 0x1a45, 0x1aa8,
+    0x1aa8, 0x1aaa, 0x1aa8, 0x06db, 0x0328, 0x1b0d, 0x1b11, 0x1b28, 0x1b50, 0x1b5e, 0x1b5e, 0x1c3b,
+    0x1c3b, 0x1c7e, 0x1c7e, 0x0302, 0x0304, 0x0027, 0x0027, 0x032b, 0x032e, 0x032d, 0x030e, 0x0329,
+    0x0323, 0x0324, 0x0316, 0x0063, 0x025c, 0x0138, 0x028d, 0x006f, 0x0254, 0x006f, 0x01dd, 0x006f,
+    0x0075, 0x0076, 0x0077, 0x007a, 0x01a8, 0x0072, 0x028c, 0x03c0, 0x1d18, 0x043b, 0x18d6, 0x00ba,
+    0x0075, 0x0065, 0x0066, 0x0334, 0x0072, 0x006e, 0x0334, 0x006e, 0x0334, 0x0072, 0x0334, 0x027e,
+    0x0334, 0x0073, 0x0334, 0x0074, 0x0334, 0x007a, 0x0334, 0x1d34, 0x0069, 0x0335, 0x0069, 0x0335,
+    0x0070, 0x0335, 0x0075, 0x0335, 0x028a, 0x0335, 0x0067, 0x0079, 0x024b, 0x1d4b, 0x1d4d, 0x18d4,
+    0x1646, 0x2dec, 0xab51, 0x1ea3, 0x0066, 0x00df, 0x0079, 0x1ff4, 0x0027, 0x0069, 0x0027, 0x007e,
+    0x0027, 0x13ef, 0x0027, 0x0027, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020, 0x0020,
+    0x0020, 0x0020, 0x0020, 0x002d, 0x002d, 0x002d, 0x002d, 0x30fc, 0x30fc, 0x006c, 0x006c, 0x0027,
+    0x0027, 0x002c, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x00b7, 0x002e, 0x002e,
+    0x002e, 0x002e, 0x002e, 0x002e, 0x00b7, 0x0020, 0x0020, 0x0020, 0x00ba, 0x002f, 0x2080, 0x2080,
+    0x00ba, 0x002f, 0x2080, 0x2080, 0x2080, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x0027,
+    0x0027, 0x0027, 0x0027, 0x0027, 0x0027, 0x003c, 0x003e, 0x0021, 0x0021, 0x02c9, 0x002f, 0x002d,
+    0x002f, 0x003f, 0x003f, 0x003f, 0x0021, 0x0021, 0x003f, 0x002a, 0x00ba, 0x002f, 0x2080, 0x007e,
+    0x0027, 0x0027, 0x0027, 0x0027, 0x003a, 0x2d57, 0x2d42, 0x0020, 0x00ba, 0xa770, 0x0043, 0x20eb,
+    0x00a3, 0x0072, 0x006e, 0x0338, 0x0052, 0x0073, 0x0057, 0x0335, 0x0064, 0x0335, 0x0331, 0xa792,
+    0x004b, 0x0335, 0x0054, 0x20eb, 0x006c, 0x0074, 0x0554, 0x06db, 0x0061, 0x002f, 0x0063, 0x0061,
+    0x002f, 0x0073, 0x0043, 0x00b0, 0x0043, 0x0063, 0x002f, 0x006f, 0x0063, 0x002f, 0x0075, 0x0190,
+    0x042d, 0x00b0, 0x0046, 0x0067, 0x0048, 0x0048, 0x0048, 0x0068, 0x0068, 0x0335, 0x006c, 0x006c,
+    0x004c, 0x006c, 0x004e, 0x004e, 0x006f, 0x0050, 0x0051, 0x0052, 0x0052, 0x0052, 0x0054, 0x0045,
+    0x004c, 0x005a, 0x03a9, 0x01b1, 0x005a, 0x027f, 0x004b, 0x0042, 0x0043, 0x0065, 0x0065, 0x0045,
+    0x0046, 0x004d, 0x006f, 0x05d0, 0x05d1, 0x05d2, 0x05d3, 0x0069, 0x0046, 0x0041, 0x0058, 0x03c0,
+    0x0079, 0x0393, 0x03a0, 0x01a9, 0xa4e8, 0xa4f6, 0xd81b, 0xdf00, 0x0044, 0x0064, 0x0065, 0x0069,
+    0x006a, 0x006c, 0x006c, 0x006c, 0x006c, 0x006c, 0x006c, 0x006c, 0x0056, 0x0056, 0x0056, 0x006c,
+    0x0056, 0x006c, 0x006c, 0x0056, 0x006c, 0x006c, 0x006c, 0x006c, 0x0058, 0x0058, 0x0058, 0x006c,
+    0x0058, 0x006c, 0x006c, 0x004c, 0x0043, 0x0044, 0x004d, 0x0069, 0x0069, 0x0069, 0x0069, 0x0069,
+    0x0069, 0x0069, 0x0076, 0x0076, 0x0076, 0x0069, 0x0076, 0x0069, 0x0069, 0x0076, 0x0069, 0x0069,
+    0x0069, 0x0069, 0x0078, 0x0078, 0x0078, 0x0069, 0x0078, 0x0069, 0x0069, 0x006c, 0x0063, 0x0064,
+    0x0072, 0x006e, 0x0186, 0x0254, 0x16cf, 0x16e8, 0x21b2, 0xd83c, 0xdd0e, 0x16da, 0x16d0, 0x2c6f,
+    0x018e, 0x0394, 0x03a0, 0x01a9, 0x002d, 0x002b, 0x0307, 0x002f, 0x005c, 0x002a, 0x00b0, 0x00b7,
+    0x006f, 0x006f, 0x006c, 0x006c, 0x006c, 0x0076, 0x0548, 0x0055, 0x0283, 0x0283, 0x0283, 0x0283,
+    0x0283, 0x0283, 0x222e, 0x222e, 0x222e, 0x222e, 0x222e, 0x003a, 0x002d, 0x0307, 0x007e, 0x003d,
+    0x0307, 0x003d, 0x0307, 0x0323, 0x003d, 0x030a, 0x003d, 0x0302, 0x003d, 0x0306, 0x003d, 0x036b,
+    0x2261, 0x003c, 0x003c, 0x003e, 0x003e, 0x1455, 0x1450, 0xd800, 0xdea8, 0x004f, 0x0335, 0x0298,
+    0x004f, 0x0335, 0x0054, 0xa4d5, 0x2227, 0x0076, 0x0548, 0x0055, 0x16dc, 0x00b7, 0x16de, 0x003c,
+    0x00b7, 0x00b7, 0x003e, 0x003c, 0x003c, 0x003c, 0x003e, 0x003e, 0x003e, 0x2d57, 0x00b7, 0x00b7,
+    0x00b7, 0xa793, 0x0045, 0x2205, 0x2324, 0x276c, 0x276d, 0x303c, 0x0394, 0x0332, 0x16dc, 0x0332,
+    0x00b0, 0x0332, 0x229b, 0x0054, 0x0308, 0x2207, 0x0308, 0x22c6, 0x0308, 0x00b0, 0x0308, 0x0629,
+    0x007e, 0x0308, 0x1435, 0x2207, 0x0334, 0x004f, 0x0335, 0x0069, 0x0070, 0x03c9, 0x0061, 0x0332,
+    0xa793, 0x0332, 0x0069, 0x0332, 0x03c9, 0x0332, 0x0061, 0x16bd, 0x4e28, 0x4e28, 0x4e28, 0x4e28,
+    0x4e28, 0x4e28, 0x2355, 0x234e, 0x234b, 0x236d, 0x2081, 0x2080, 0x23fb, 0x006c, 0x263e, 0x005c,
+    0x005c, 0x2780, 0x2781, 0x2782, 0x2783, 0x2784, 0x2785, 0x2786, 0x2787, 0x2788, 0x2789, 0x0028,
+    0x006c, 0x0029, 0x0028, 0x0032, 0x0029, 0x0028, 0x0033, 0x0029, 0x0028, 0x0034, 0x0029, 0x0028,
+    0x0035, 0x0029, 0x0028, 0x0036, 0x0029, 0x0028, 0x0037, 0x0029, 0x0028, 0x0038, 0x0029, 0x0028,
+    0x0039, 0x0029, 0x0028, 0x006c, 0x004f, 0x0029, 0x0028, 0x006c, 0x006c, 0x0029, 0x0028, 0x006c,
+    0x0032, 0x0029, 0x0028, 0x006c, 0x0033, 0x0029, 0x0028, 0x006c, 0x0034, 0x0029, 0x0028, 0x006c,
+    0x0035, 0x0029, 0x0028, 0x006c, 0x0036, 0x0029, 0x0028, 0x006c, 0x0037, 0x0029, 0x0028, 0x006c,
+    0x0038, 0x0029, 0x0028, 0x006c, 0x0039, 0x0029, 0x0028, 0x0032, 0x004f, 0x0029, 0x006c, 0x002e,
+    0x0032, 0x002e, 0x0033, 0x002e, 0x0034, 0x002e, 0x0035, 0x002e, 0x0036, 0x002e, 0x0037, 0x002e,
+    0x0038, 0x002e, 0x0039, 0x002e, 0x006c, 0x004f, 0x002e, 0x006c, 0x006c, 0x002e, 0x006c, 0x0032,
+    0x002e, 0x006c, 0x0033, 0x002e, 0x006c, 0x0034, 0x002e, 0x006c, 0x0035, 0x002e, 0x006c, 0x0036,
+    0x002e, 0x006c, 0x0037, 0x002e, 0x006c, 0x0038, 0x002e, 0x006c, 0x0039, 0x002e, 0x0032, 0x004f,
+    0x002e, 0x0028, 0x0061, 0x0029, 0x0028, 0x0062, 0x0029, 0x0028, 0x0063, 0x0029, 0x0028, 0x0064,
+    0x0029, 0x0028, 0x0065, 0x0029, 0x0028, 0x0066, 0x0029, 0x0028, 0x0067, 0x0029, 0x0028, 0x0068,
+    0x0029, 0x0028, 0x0069, 0x0029, 0x0028, 0x006a, 0x0029, 0x0028, 0x006b, 0x0029, 0x0028, 0x006c,
+    0x0029, 0x0028, 0x0072, 0x006e, 0x0029, 0x0028, 0x006e, 0x0029, 0x0028, 0x006f, 0x0029, 0x0028,
+    0x0070, 0x0029, 0x0028, 0x0071, 0x0029, 0x0028, 0x0072, 0x0029, 0x0028, 0x0073, 0x0029, 0x0028,
+    0x0074, 0x0029, 0x0028, 0x0075, 0x0029, 0x0028, 0x0076, 0x0029, 0x0028, 0x0077, 0x0029, 0x0028,
+    0x0078, 0x0029, 0x0028, 0x0079, 0x0029, 0x0028, 0x007a, 0x0029, 0x00a9, 0x2117, 0x00ae, 0x24be,
+    0xd83c, 0xdd0d, 0x30fc, 0x30fc, 0x2502, 0x250c, 0x251c, 0x002f, 0x0058, 0x220e, 0x258c, 0x02c9,
+    0x2596, 0x2598, 0x220e, 0x23e5, 0x0394, 0x22b3, 0x25b6, 0x25b6, 0xd800, 0xdebc, 0x22b2, 0x16dc,
+    0x16dc, 0x00b0, 0x233e, 0x2312, 0x00b0, 0x0298, 0x25a1, 0xd802, 0xdd9e, 0x2cb6, 0x2388, 0x224f,
+    0x16dc, 0xd834, 0xdd58, 0xd834, 0xdd65, 0xd834, 0xdd58, 0xd834, 0xdd65, 0xd834, 0xdd6e, 0x0970,
+    0x0028, 0x0029, 0x003c, 0x003e, 0x0028, 0x0029, 0x007b, 0x007d, 0x002b, 0x002d, 0x00f7, 0xa4d5,
+    0x005c, 0x1455, 0x1450, 0x002f, 0x002f, 0x005c, 0x0054, 0x276c, 0x276d, 0x0078, 0x0078, 0x16d0,
+    0x16da, 0x21c3, 0x21c2, 0x16d0, 0x21c2, 0x21c3, 0x16da, 0x2d42, 0x2349, 0x233e, 0x303c, 0x2342,
+    0x233b, 0xd800, 0xdec0, 0x299a, 0x003a, 0x2192, 0x005c, 0x002f, 0x0304, 0x002f, 0x005c, 0x0298,
+    0xd800, 0xdea8, 0x2297, 0x228d, 0x228e, 0x2293, 0x2294, 0x0283, 0x0283, 0x0283, 0x0283, 0x16de,
+    0x003e, 0x003e, 0x16da, 0x002b, 0x030a, 0x002b, 0x0302, 0x002b, 0x0303, 0x002b, 0x0323, 0x002b,
+    0x0330, 0x002b, 0x2082, 0x002d, 0x0313, 0x002d, 0x0323, 0x0078, 0x0078, 0x0307, 0x2319, 0x2a1f,
+    0x2210, 0x007e, 0x0307, 0x003d, 0x20f0, 0x003a, 0x003a, 0x003d, 0x003d, 0x003d, 0x003d, 0x003d,
+    0x003d, 0x003e, 0x003c, 0x15d5, 0x15d2, 0x1450, 0x1455, 0x002f, 0x002f, 0x002f, 0x002f, 0x002f,
+    0x219e, 0x219f, 0x21a0, 0x21a1, 0x0048, 0x0329, 0x004b, 0x0329, 0x0393, 0x0072, 0x0394, 0xa792,
+    0xa793, 0x0048, 0x006c, 0x004b, 0x0138, 0x03bb, 0x004d, 0x004e, 0x004f, 0x006f, 0x03a0, 0x0050,
+    0x0070, 0x0043, 0x0063, 0x0054, 0x0059, 0x03a6, 0x0278, 0x0058, 0x03c7, 0x03a8, 0x03c9, 0x003c,
+    0x00b7, 0x002d, 0x0428, 0x0448, 0x002f, 0x0039, 0x0033, 0x021d, 0x004c, 0x029f, 0x0036, 0x03ec,
+    0x03d7, 0x2627, 0x005c, 0x005c, 0x004f, 0x0335, 0x0245, 0x0056, 0x0045, 0x018e, 0x004f, 0x0338,
+    0x00b7, 0x00b7, 0x00b7, 0x01a9, 0x006c, 0x0021, 0x004f, 0x0051, 0x0298, 0x0058, 0x0394, 0x16ef,
+    0x1ddf, 0x030a, 0x0368, 0x036f, 0x0363, 0x0364, 0x002d, 0x0308, 0x007e, 0x0307, 0x007e, 0x0323,
+    0x1455, 0x1450, 0x0028, 0x0028, 0x0029, 0x0029, 0x2235, 0x2234, 0x2237, 0x061f, 0x00b0, 0x00b7,
+    0x060c, 0x061b, 0x1e9f, 0x2d42, 0x00b6, 0x003d, 0x4e5b, 0x4e5a, 0x4ebb, 0x5202, 0x353e, 0x5140,
+    0x5c23, 0x5c22, 0x5df3, 0x5e7a, 0x5f51, 0x5fc4, 0x38fa, 0x624c, 0x6535, 0x65e1, 0x6b7a, 0x6bcd,
+    0x6c11, 0x6c35, 0x6c3a, 0x706c, 0x722b, 0x4e2c, 0x72ad, 0x7f52, 0x793b, 0x7cf9, 0x7f53, 0x7f52,
+    0x8002, 0x8080, 0x8279, 0x8279, 0x8279, 0x864e, 0x8864, 0x8980, 0x897f, 0x89c1, 0x8ba0, 0x8d1d,
+    0x8f66, 0x8fb6, 0x8fb6, 0x961d, 0x9485, 0x9577, 0x9578, 0x957f, 0x95e8, 0x961d, 0x9752, 0x97e6,
+    0x9875, 0x98ce, 0x98de, 0x98df, 0x98e0, 0x9963, 0x9a6c, 0x9b3c, 0x9c7c, 0x9ea6, 0x9ec4, 0x6589,
+    0x9f50, 0x6b6f, 0x9f7f, 0x7adc, 0x9f99, 0x4e80, 0x9f9f, 0x30fc, 0x4e28, 0x005c, 0x002f, 0x4e59,
+    0x4e85, 0x4e8c, 0x4ea0, 0x4eba, 0x513f, 0x5165, 0x516b, 0x5182, 0x5196, 0x51ab, 0x51e0, 0x51f5,
+    0x5200, 0x529b, 0x52f9, 0x5315, 0x531a, 0x5338, 0x5341, 0x535c, 0x5369, 0x5382, 0x53b6, 0x53c8,
+    0x53e3, 0x53e3, 0x571f, 0x571f, 0x5902, 0x590a, 0x5915, 0x5927, 0x5973, 0x5b50, 0x5b80, 0x5bf8,
+    0x5c0f, 0x5c22, 0x5c38, 0x5c6e, 0x5c71, 0x5ddb, 0x5de5, 0x5df1, 0x5dfe, 0x5e72, 0x5e7a, 0x5e7f,
+    0x5ef4, 0x5efe, 0x5f0b, 0x5f13, 0x5f50, 0x5f61, 0x5f73, 0x5fc3, 0x6208, 0x6236, 0x624b, 0x652f,
+    0x6534, 0x6587, 0x6597, 0x65a4, 0x65b9, 0x65e0, 0x65e5, 0x66f0, 0x6708, 0x6728, 0x6b20, 0x6b62,
+    0x6b79, 0x6bb3, 0x6bcb, 0x6bd4, 0x6bdb, 0x6c0f, 0x6c14, 0x6c34, 0x706b, 0x722a, 0x7236, 0x723b,
+    0x723f, 0x7247, 0x7259, 0x725b, 0x72ac, 0x7384, 0x7389, 0x74dc, 0x74e6, 0x7518, 0x751f, 0x7528,
+    0x7530, 0x758b, 0x7592, 0x7676, 0x767d, 0x76ae, 0x76bf, 0x76ee, 0x77db, 0x77e2, 0x77f3, 0x793a,
+    0x79b8, 0x79be, 0x7a74, 0x7acb, 0x7af9, 0x7c73, 0x7cf8, 0x7f36, 0x7f51, 0x7f8a, 0x7fbd, 0x8001,
+    0x800c, 0x8012, 0x8033, 0x807f, 0x8089, 0x81e3, 0x81ea, 0x81f3, 0x81fc, 0x820c, 0x821b, 0x821f,
+    0x826e, 0x8272, 0x8278, 0x864d, 0x866b, 0x8840, 0x884c, 0x8863, 0x897e, 0x898b, 0x89d2, 0x8a00,
+    0x8c37, 0x8c46, 0x8c55, 0x8c78, 0x8c9d, 0x8d64, 0x8d70, 0x8db3, 0x8eab, 0x8eca, 0x8f9b, 0x8fb0,
+    0x8fb5, 0x9091, 0x9149, 0x91c6, 0x91cc, 0x91d1, 0x9577, 0x9580, 0x961c, 0x96b6, 0x96b9, 0x96e8,
+    0x9751, 0x975e, 0x9762, 0x9769, 0x97cb, 0x97ed, 0x97f3, 0x9801, 0x98a8, 0x98db, 0x98df, 0x9996,
+    0x9999, 0x99ac, 0x9aa8, 0x9ad8, 0x9adf, 0x9b25, 0x9b2f, 0x9b32, 0x9b3c, 0x9b5a, 0x9ce5, 0x9e75,
+    0x9e7f, 0x9ea5, 0x9ebb, 0x9ec3, 0x9ecd, 0x9ed1, 0x9ef9, 0x9efd, 0x9f0e, 0x9f13, 0x9f20, 0x9f3b,
+    0x9f4a, 0x9f52, 0x9f8d, 0x9f9c, 0x9fa0, 0x02f3, 0x0027, 0x0027, 0x004f, 0x276c, 0x276d, 0x20b8,
+    0x0028, 0x0029, 0x27e6, 0x27e7, 0x0309, 0x0325, 0x002f, 0x20b8, 0x5341, 0x5344, 0x5345, 0x276c,
+    0x030a, 0xff9e, 0xff9f, 0x003d, 0x4ebb, 0x5de5, 0x529b, 0x5915, 0x535c, 0x4e8c, 0x002f, 0x516b,
+    0x3078, 0x53e3, 0x00b7, 0x513f, 0x1100, 0x1100, 0x1100, 0x1100, 0x1109, 0x1102, 0x1102, 0x110c,
+    0x1102, 0x1112, 0x1103, 0x1103, 0x1103, 0x1105, 0x1105, 0x1100, 0x1105, 0x1106, 0x1105, 0x1107,
+    0x1105, 0x1109, 0x1105, 0x1110, 0x1105, 0x1111, 0x1105, 0x1112, 0x1106, 0x1107, 0x1107, 0x1107,
+    0x1107, 0x1109, 0x1109, 0x1109, 0x1109, 0x110b, 0x110c, 0x110c, 0x110c, 0x110e, 0x110f, 0x1110,
+    0x1111, 0x1112, 0x1161, 0x1161, 0x4e28, 0x1163, 0x1163, 0x4e28, 0x1165, 0x1165, 0x4e28, 0x1167,
+    0x1167, 0x4e28, 0x1169, 0x1169, 0x1161, 0x1169, 0x1161, 0x4e28, 0x1169, 0x4e28, 0x116d, 0x116e,
+    0x116e, 0x1165, 0x116e, 0x1165, 0x4e28, 0x116e, 0x4e28, 0x1172, 0x30fc, 0x30fc, 0x4e28, 0x4e28,
+    0x1160, 0x1102, 0x1102, 0x1102, 0x1103, 0x1102, 0x1109, 0x1102, 0x1140, 0x1105, 0x1100, 0x1109,
+    0x1105, 0x1103, 0x1105, 0x1107, 0x1109, 0x1105, 0x1140, 0x1105, 0x1159, 0x1106, 0x1107, 0x1106,
+    0x1109, 0x1106, 0x1140, 0x1106, 0x110b, 0x1107, 0x1100, 0x1107, 0x1103, 0x1107, 0x1109, 0x1100,
+    0x1107, 0x1109, 0x1103, 0x1107, 0x110c, 0x1107, 0x1110, 0x1107, 0x110b, 0x1107, 0x1107, 0x110b,
+    0x1109, 0x1100, 0x1109, 0x1102, 0x1109, 0x1103, 0x1109, 0x1107, 0x1109, 0x110c, 0x1140, 0x110b,
+    0x110b, 0x114c, 0x110b, 0x1109, 0x110b, 0x1140, 0x1111, 0x110b, 0x1112, 0x1112, 0x1159, 0x116d,
+    0x1163, 0x116d, 0x1163, 0x4e28, 0x116d, 0x4e28, 0x1172, 0x1167, 0x1172, 0x1167, 0x4e28, 0x1172,
+    0x4e28, 0x119e, 0x119e, 0x4e28, 0x30fc, 0x4e28, 0x002f, 0x005c, 0x4e5b, 0x4e85, 0x276c, 0x4e5a,
+    0x4e59, 0x0028, 0x1100, 0x0029, 0x0028, 0x1102, 0x0029, 0x0028, 0x1103, 0x0029, 0x0028, 0x1105,
+    0x0029, 0x0028, 0x1106, 0x0029, 0x0028, 0x1107, 0x0029, 0x0028, 0x1109, 0x0029, 0x0028, 0x110b,
+    0x0029, 0x0028, 0x110c, 0x0029, 0x0028, 0x110e, 0x0029, 0x0028, 0x110f, 0x0029, 0x0028, 0x1110,
+    0x0029, 0x0028, 0x1111, 0x0029, 0x0028, 0x1112, 0x0029, 0x0028, 0xac00, 0x0029, 0x0028, 0xb098,
+    0x0029, 0x0028, 0xb2e4, 0x0029, 0x0028, 0xb77c, 0x0029, 0x0028, 0xb9c8, 0x0029, 0x0028, 0xbc14,
+    0x0029, 0x0028, 0xc0ac, 0x0029, 0x0028, 0xc544, 0x0029, 0x0028, 0xc790, 0x0029, 0x0028, 0xcc28,
+    0x0029, 0x0028, 0xce74, 0x0029, 0x0028, 0xd0c0, 0x0029, 0x0028, 0xd30c, 0x0029, 0x0028, 0xd558,
+    0x0029, 0x0028, 0xc8fc, 0x0029, 0x0028, 0xc624, 0xc804, 0x0029, 0x0028, 0xc624, 0xd6c4, 0x0029,
+    0x0028, 0x30fc, 0x0029, 0x0028, 0x4e8c, 0x0029, 0x0028, 0x4e09, 0x0029, 0x0028, 0x56db, 0x0029,
+    0x0028, 0x4e94, 0x0029, 0x0028, 0x516d, 0x0029, 0x0028, 0x4e03, 0x0029, 0x0028, 0x516b, 0x0029,
+    0x0028, 0x4e5d, 0x0029, 0x0028, 0x5341, 0x0029, 0x0028, 0x6708, 0x0029, 0x0028, 0x706b, 0x0029,
+    0x0028, 0x6c34, 0x0029, 0x0028, 0x6728, 0x0029, 0x0028, 0x91d1, 0x0029, 0x0028, 0x571f, 0x0029,
+    0x0028, 0x65e5, 0x0029, 0x0028, 0x682a, 0x0029, 0x0028, 0x6709, 0x0029, 0x0028, 0x793e, 0x0029,
+    0x0028, 0x540d, 0x0029, 0x0028, 0x7279, 0x0029, 0x0028, 0x8ca1, 0x0029, 0x0028, 0x795d, 0x0029,
+    0x0028, 0x52b4, 0x0029, 0x0028, 0x4ee3, 0x0029, 0x0028, 0x547c, 0x0029, 0x0028, 0x5b66, 0x0029,
+    0x0028, 0x76e3, 0x0029, 0x0028, 0x4f01, 0x0029, 0x0028, 0x8cc7, 0x0029, 0x0028, 0x5354, 0x0029,
+    0x0028, 0x796d, 0x0029, 0x0028, 0x4f11, 0x0029, 0x0028, 0x81ea, 0x0029, 0x0028, 0x81f3, 0x0029,
+    0x006c, 0x6708, 0x0032, 0x6708, 0x0033, 0x6708, 0x0034, 0x6708, 0x0035, 0x6708, 0x0036, 0x6708,
+    0x0037, 0x6708, 0x0038, 0x6708, 0x0039, 0x6708, 0x006c, 0x004f, 0x6708, 0x006c, 0x006c, 0x6708,
+    0x006c, 0x0032, 0x6708, 0x004f, 0x70b9, 0x006c, 0x70b9, 0x0032, 0x70b9, 0x0033, 0x70b9, 0x0034,
+    0x70b9, 0x0035, 0x70b9, 0x0036, 0x70b9, 0x0037, 0x70b9, 0x0038, 0x70b9, 0x0039, 0x70b9, 0x006c,
+    0x004f, 0x70b9, 0x006c, 0x006c, 0x70b9, 0x006c, 0x0032, 0x70b9, 0x006c, 0x0033, 0x70b9, 0x006c,
+    0x0034, 0x70b9, 0x006c, 0x0035, 0x70b9, 0x006c, 0x0036, 0x70b9, 0x006c, 0x0037, 0x70b9, 0x006c,
+    0x0038, 0x70b9, 0x006c, 0x0039, 0x70b9, 0x0032, 0x004f, 0x70b9, 0x0032, 0x006c, 0x70b9, 0x0032,
+    0x0032, 0x70b9, 0x0032, 0x0033, 0x70b9, 0x0032, 0x0034, 0x70b9, 0x006c, 0x65e5, 0x0032, 0x65e5,
+    0x0033, 0x65e5, 0x0034, 0x65e5, 0x0035, 0x65e5, 0x0036, 0x65e5, 0x0037, 0x65e5, 0x0038, 0x65e5,
+    0x0039, 0x65e5, 0x006c, 0x004f, 0x65e5, 0x006c, 0x006c, 0x65e5, 0x006c, 0x0032, 0x65e5, 0x006c,
+    0x0033, 0x65e5, 0x006c, 0x0034, 0x65e5, 0x006c, 0x0035, 0x65e5, 0x006c, 0x0036, 0x65e5, 0x006c,
+    0x0037, 0x65e5, 0x006c, 0x0038, 0x65e5, 0x006c, 0x0039, 0x65e5, 0x0032, 0x004f, 0x65e5, 0x0032,
+    0x006c, 0x65e5, 0x0032, 0x0032, 0x65e5, 0x0032, 0x0033, 0x65e5, 0x0032, 0x0034, 0x65e5, 0x0032,
+    0x0035, 0x65e5, 0x0032, 0x0036, 0x65e5, 0x0032, 0x0037, 0x65e5, 0x0032, 0x0038, 0x65e5, 0x0032,
+    0x0039, 0x65e5, 0x0033, 0x004f, 0x65e5, 0x0033, 0x006c, 0x65e5, 0x363d, 0x3588, 0x3b3b, 0x30fc,
+    0x005c, 0x002f, 0x4f75, 0x5024, 0x5553, 0x53e3, 0x5861, 0x571f, 0x58ab, 0x5aaf, 0x5e21, 0x3b3a,
+    0x6236, 0x3a41, 0x403f, 0x665a, 0x3ada, 0x4443, 0x676e, 0x3ba3, 0x699d, 0x6e88, 0x7814, 0x7d55,
+    0x670c, 0x6710, 0x670f, 0x3b35, 0x6713, 0x6718, 0x80fc, 0x6723, 0x848d, 0x8637, 0x46b6, 0x8a2e,
+    0x8b86, 0x8c5c, 0x8d7f, 0x8de5, 0x8e97, 0x8eff, 0x90ce, 0x93ad, 0x96b7, 0x9e42, 0x9ed1, 0x4039,
+    0xa2cd, 0xa0c0, 0xa04a, 0xa458, 0xa132, 0xa050, 0xa3c2, 0xa3bf, 0xa2b1, 0xa259, 0xa3ab, 0xa3b5,
+    0x0042, 0x0050, 0x0064, 0x0044, 0x0054, 0x0047, 0x004b, 0x004a, 0x0043, 0x0186, 0x005a, 0x0046,
+    0x2132, 0x004d, 0x004e, 0x004c, 0x0053, 0x0052, 0x0245, 0x0056, 0x0048, 0x0057, 0x0058, 0x0059,
+    0x1660, 0x0041, 0x2c6f, 0x0045, 0x018e, 0x006c, 0x004f, 0x0055, 0x0548, 0x15e1, 0x002e, 0x002c,
+    0x002e, 0x002e, 0x002e, 0x002c, 0x003a, 0x002d, 0x002e, 0x003d, 0x002e, 0x0032, 0x01a8, 0x0069,
+    0x03c9, 0x042a, 0x006c, 0x02c9, 0x0062, 0x0069, 0x0298, 0x20e9, 0x0306, 0x02c7, 0x0068, 0x0314,
+    0x004f, 0x004f, 0x006f, 0x006f, 0xd800, 0xdea8, 0x0418, 0x16b9, 0x2c75, 0x02a1, 0x0245, 0x03a0,
+    0x0056, 0x003f, 0x0032, 0x0302, 0x0304, 0xa6f3, 0xa6f3, 0x02eb, 0x02ea, 0x0054, 0x0033, 0x0074,
+    0x021d, 0x0073, 0x0041, 0x0041, 0x0061, 0x0061, 0x0041, 0x004f, 0x0061, 0x006f, 0x0041, 0x0055,
+    0x0061, 0x0075, 0x0041, 0x0056, 0x0061, 0x0076, 0x0041, 0x0056, 0x0061, 0x0076, 0x0041, 0x0059,
+    0x0061, 0x0079, 0x004b, 0x0335, 0x004f, 0x0335, 0x006f, 0x0335, 0x004f, 0x004f, 0x006f, 0x006f,
+    0x0032, 0x0077, 0x0326, 0x0033, 0x021d, 0x0039, 0x0074, 0x0066, 0x0026, 0xa779, 0x003a, 0x0027,
+    0x00b7, 0xa727, 0x0046, 0x0066, 0xd801, 0xdc12, 0xd801, 0xdc3a, 0x029a, 0xa4e4, 0x0075, 0x0033,
+    0xa4d5, 0x004a, 0x0058, 0x0042, 0x00df, 0xa64c, 0x03c9, 0x30fc, 0x0964, 0x1103, 0x1106, 0x1103,
+    0x1107, 0x1103, 0x1109, 0x1103, 0x110c, 0x1105, 0x1100, 0x1105, 0x1100, 0x1100, 0x1105, 0x1103,
+    0x1105, 0x1103, 0x1103, 0x1105, 0x1106, 0x1105, 0x1107, 0x1105, 0x1107, 0x1107, 0x1105, 0x1107,
+    0x110b, 0x1105, 0x1109, 0x1105, 0x110c, 0x1105, 0x110f, 0x1106, 0x1100, 0x1106, 0x1103, 0x1106,
+    0x1109, 0x1107, 0x1109, 0x1110, 0x1107, 0x110f, 0x1107, 0x1112, 0x1109, 0x1109, 0x1107, 0x110b,
+    0x1105, 0x110b, 0x1112, 0x110c, 0x110c, 0x1112, 0x1110, 0x1110, 0x1111, 0x1112, 0x1112, 0x1109,
+    0x1159, 0x1159, 0x2c3f, 0xa99d, 0xa9d0, 0x0662, 0xaa01, 0xaa23, 0x0065, 0x0066, 0x006f, 0x006f,
+    0x0338, 0x0254, 0x0338, 0x01dd, 0x006f, 0x0338, 0x01dd, 0x006f, 0x0335, 0x0072, 0x0072, 0x0283,
+    0x0075, 0x0075, 0x03c7, 0x03c7, 0x0079, 0x0459, 0x0254, 0x0065, 0x0075, 0x006f, 0x1d05, 0x0280,
+    0x1d1b, 0x006f, 0x031b, 0x0069, 0x1d00, 0x1d0a, 0x1d07, 0x0242, 0x2c76, 0x0072, 0x0077, 0x028d,
+    0x029c, 0x006f, 0x0335, 0x0262, 0x007a, 0xa793, 0x0075, 0x0335, 0x0185, 0x0280, 0x0076, 0x0073,
+    0x029f, 0x0063, 0x1d18, 0x0138, 0x006f, 0x0335, 0x1169, 0x1167, 0x1169, 0x1169, 0x4e28, 0x116d,
+    0x1161, 0x116d, 0x1161, 0x4e28, 0x116d, 0x1165, 0x116e, 0x1167, 0x116e, 0x4e28, 0x4e28, 0x1172,
+    0x1161, 0x4e28, 0x1172, 0x1169, 0x30fc, 0x1161, 0x30fc, 0x1165, 0x30fc, 0x1165, 0x4e28, 0x30fc,
+    0x1169, 0x4e28, 0x1163, 0x1169, 0x4e28, 0x1163, 0x4e28, 0x4e28, 0x1167, 0x4e28, 0x1167, 0x4e28,
+    0x4e28, 0x1169, 0x4e28, 0x4e28, 0x116d, 0x4e28, 0x1172, 0x4e28, 0x4e28, 0x119e, 0x1161, 0x119e,
+    0x1165, 0x4e28, 0x1102, 0x1105, 0x1102, 0x110e, 0x1103, 0x1103, 0x1103, 0x1103, 0x1107, 0x1103,
+    0x1107, 0x1103, 0x1109, 0x1103, 0x1109, 0x1100, 0x1103, 0x110c, 0x1103, 0x110e, 0x1103, 0x1110,
+    0x1105, 0x1100, 0x1100, 0x1105, 0x1100, 0x1112, 0x1105, 0x1105, 0x110f, 0x1105, 0x1106, 0x1112,
+    0x1105, 0x1107, 0x1103, 0x1105, 0x1107, 0x1111, 0x1105, 0x114c, 0x1105, 0x1159, 0x1112, 0x1105,
+    0x110b, 0x1106, 0x1102, 0x1106, 0x1102, 0x1102, 0x1106, 0x1106, 0x1106, 0x1107, 0x1109, 0x1106,
+    0x110c, 0x1107, 0x1103, 0x1107, 0x1105, 0x1111, 0x1107, 0x1106, 0x1107, 0x1107, 0x1107, 0x1109,
+    0x1103, 0x1107, 0x110c, 0x1107, 0x110e, 0x1109, 0x1106, 0x1109, 0x1107, 0x110b, 0x1109, 0x1109,
+    0x1100, 0x1109, 0x1109, 0x1103, 0x1109, 0x1140, 0x1109, 0x110c, 0x1109, 0x110e, 0x1109, 0x1110,
+    0x1105, 0x1112, 0x1140, 0x1107, 0x1140, 0x1107, 0x110b, 0x114c, 0x1106, 0x114c, 0x1112, 0x110c,
+    0x1107, 0x110c, 0x1107, 0x1107, 0x110c, 0x110c, 0x1111, 0x1109, 0x1111, 0x1110, 0x8c48, 0x66f4,
+    0x8eca, 0x8cc8, 0x6ed1, 0x4e32, 0x53e5, 0x9f9c, 0x9f9c, 0x5951, 0x91d1, 0x5587, 0x5948, 0x61f6,
+    0x7669, 0x7f85, 0x863f, 0x87ba, 0x88f8, 0x908f, 0x6a02, 0x6d1b, 0x70d9, 0x73de, 0x843d, 0x916a,
+    0x99f1, 0x4e82, 0x5375, 0x6b04, 0x721b, 0x862d, 0x9e1e, 0x5d50, 0x6feb, 0x85cd, 0x8964, 0x62c9,
+    0x81d8, 0x881f, 0x5eca, 0x6717, 0x6d6a, 0x72fc, 0x90ce, 0x4f86, 0x51b7, 0x52de, 0x64c4, 0x6ad3,
+    0x7210, 0x76e7, 0x8001, 0x8606, 0x865c, 0x8def, 0x9732, 0x9b6f, 0x9dfa, 0x788c, 0x797f, 0x7da0,
+    0x83c9, 0x9304, 0x9e7f, 0x8ad6, 0x58df, 0x5f04, 0x7c60, 0x807e, 0x7262, 0x78ca, 0x8cc2, 0x96f7,
+    0x58d8, 0x5c62, 0x6a13, 0x6dda, 0x6f0f, 0x7d2f, 0x7e37, 0x964b, 0x52d2, 0x808b, 0x51dc, 0x51cc,
+    0x7a1c, 0x7dbe, 0x83f1, 0x9675, 0x8b80, 0x62cf, 0x6a02, 0x8afe, 0x4e39, 0x5be7, 0x6012, 0x7387,
+    0x7570, 0x5317, 0x78fb, 0x4fbf, 0x5fa9, 0x4e0d, 0x6ccc, 0x6578, 0x7d22, 0x53c3, 0x585e, 0x7701,
+    0x8449, 0x8aaa, 0x6bba, 0x8fb0, 0x6c88, 0x62fe, 0x82e5, 0x63a0, 0x7565, 0x4eae, 0x5169, 0x51c9,
+    0x6881, 0x7ce7, 0x826f, 0x8ad2, 0x91cf, 0x52f5, 0x5442, 0x5973, 0x5eec, 0x65c5, 0x6ffe, 0x792a,
+    0x95ad, 0x9a6a, 0x9e97, 0x9ece, 0x529b, 0x66c6, 0x6b77, 0x8f62, 0x5e74, 0x6190, 0x6200, 0x649a,
+    0x6f23, 0x7149, 0x7489, 0x79ca, 0x7df4, 0x806f, 0x8f26, 0x84ee, 0x9023, 0x934a, 0x5217, 0x52a3,
+    0x54bd, 0x70c8, 0x88c2, 0x8aaa, 0x5ec9, 0x5ff5, 0x637b, 0x6bae, 0x7c3e, 0x7375, 0x4ee4, 0x56f9,
+    0x5be7, 0x5dba, 0x601c, 0x73b2, 0x7469, 0x7f9a, 0x8046, 0x9234, 0x96f6, 0x9748, 0x9818, 0x4f8b,
+    0x79ae, 0x91b4, 0x96b7, 0x60e1, 0x4e86, 0x50da, 0x5bee, 0x5c3f, 0x6599, 0x6a02, 0x71ce, 0x7642,
+    0x84fc, 0x907c, 0x9f8d, 0x6688, 0x962e, 0x5289, 0x677b, 0x67f3, 0x6d41, 0x6e9c, 0x7409, 0x7559,
+    0x786b, 0x7d10, 0x985e, 0x516d, 0x622e, 0x9678, 0x502b, 0x5d19, 0x6dea, 0x8f2a, 0x5f8b, 0x6144,
+    0x6817, 0x7387, 0x9686, 0x5229, 0x540f, 0x5c65, 0x6613, 0x674e, 0x68a8, 0x6ce5, 0x7406, 0x75e2,
+    0x7f79, 0x88cf, 0x88e1, 0x91cc, 0x96e2, 0x533f, 0x6eba, 0x541d, 0x71d0, 0x7498, 0x85fa, 0x96a3,
+    0x9c57, 0x9e9f, 0x6797, 0x6dcb, 0x81e8, 0x7acb, 0x7b20, 0x7c92, 0x72c0, 0x7099, 0x8b58, 0x4ec0,
+    0x8336, 0x523a, 0x5207, 0x5ea6, 0x62d3, 0x7cd6, 0x5b85, 0x6d1e, 0x66b4, 0x8f3b, 0x884c, 0x964d,
+    0x898b, 0x5ed3, 0x5140, 0x55c0, 0x585a, 0x6674, 0x51de, 0x732a, 0x76ca, 0x793c, 0x795e, 0x7965,
+    0x798f, 0x9756, 0x7cbe, 0x7fbd, 0x8612, 0x8af8, 0x9038, 0x90fd, 0x98ef, 0x98fc, 0x9928, 0x9db4,
+    0x90ce, 0x96b7, 0x4fae, 0x50e7, 0x514d, 0x52c9, 0x52e4, 0x5351, 0x559d, 0x5606, 0x5668, 0x5840,
+    0x58a8, 0x5c64, 0x5c6e, 0x6094, 0x6168, 0x618e, 0x61f2, 0x654f, 0x65e2, 0x6691, 0x6885, 0x6d77,
+    0x6e1a, 0x6f22, 0x716e, 0x722b, 0x7422, 0x7891, 0x793e, 0x7949, 0x7948, 0x7950, 0x7956, 0x795d,
+    0x798d, 0x798e, 0x7a40, 0x7a81, 0x7bc0, 0x7df4, 0x7e09, 0x7e41, 0x7f72, 0x8005, 0x81ed, 0x8279,
+    0x8279, 0x8457, 0x8910, 0x8996, 0x8b01, 0x8b39, 0x8cd3, 0x8d08, 0x8fb6, 0x9038, 0x96e3, 0x97ff,
+    0x983b, 0x6075, 0xd850, 0xdeee, 0x8218, 0x4e26, 0x51b5, 0x5168, 0x4f80, 0x5145, 0x5180, 0x52c7,
+    0x52fa, 0x559d, 0x5555, 0x5599, 0x55e2, 0x585a, 0x58b3, 0x5944, 0x5954, 0x5a62, 0x5b28, 0x5ed2,
+    0x5ed9, 0x5f69, 0x5fad, 0x60d8, 0x614e, 0x6108, 0x618e, 0x6160, 0x61f2, 0x6234, 0x63c4, 0x641c,
+    0x6452, 0x6556, 0x6674, 0x6717, 0x671b, 0x6756, 0x6b79, 0x6bba, 0x6d41, 0x6edb, 0x6ecb, 0x6f22,
+    0x701e, 0x716e, 0x77a7, 0x7235, 0x72af, 0x732a, 0x7471, 0x7506, 0x753b, 0x761d, 0x761f, 0x76ca,
+    0x76db, 0x76f4, 0x774a, 0x7740, 0x78cc, 0x7ab1, 0x7bc0, 0x7c7b, 0x7d5b, 0x7df4, 0x7f3e, 0x8005,
+    0x8352, 0x83ef, 0x8779, 0x8941, 0x8986, 0x8996, 0x8abf, 0x8af8, 0x8acb, 0x8b01, 0x8afe, 0x8aed,
+    0x8b39, 0x8b8a, 0x8d08, 0x8f38, 0x9072, 0x9199, 0x9276, 0x967c, 0x96e3, 0x9756, 0x97db, 0x97ff,
+    0x980b, 0x983b, 0x9b12, 0x9f9c, 0xd84a, 0xdc4a, 0xd84a, 0xdc44, 0xd84c, 0xdfd5, 0x3b9d, 0x4018,
+    0x4039, 0xd854, 0xde49, 0xd857, 0xdcd0, 0xd85f, 0xded3, 0x9f43, 0x9f8e, 0x0066, 0x0066, 0x0066,
+    0x0069, 0x0066, 0x006c, 0x0066, 0x0066, 0x0069, 0x0066, 0x0066, 0x006c, 0x0073, 0x0074, 0x0574,
+    0x0576, 0x0574, 0x0565, 0x0574, 0x056b, 0x057e, 0x0576, 0x0574, 0x056d, 0x05e2, 0x05d0, 0x05d3,
+    0x05d4, 0x05db, 0x05dc, 0x05dd, 0x05e8, 0x05ea, 0x002d, 0x0307, 0xfb2a, 0xfb2c, 0xfb2e, 0xfb2e,
+    0xfb1d, 0xfb2a, 0x05d0, 0x05dc, 0x0671, 0x0671, 0x067b, 0x067b, 0x067b, 0x067b, 0x0649, 0x06db,
+    0x0649, 0x06db, 0x0649, 0x06db, 0x0649, 0x06db, 0x0680, 0x0680, 0x0680, 0x0680, 0x067a, 0x067a,
+    0x067a, 0x067a, 0x067f, 0x067f, 0x067f, 0x067f, 0x0649, 0x0615, 0x0649, 0x0615, 0x0649, 0x0615,
+    0x0649, 0x0615, 0x06a1, 0x06db, 0x06a1, 0x06db, 0x06a1, 0x06db, 0x06a1, 0x06db, 0x06a6, 0x06a6,
+    0x06a6, 0x06a6, 0x0684, 0x0684, 0x0684, 0x0684, 0x0683, 0x0683, 0x0683, 0x0683, 0x0686, 0x0686,
+    0x0686, 0x0686, 0x0687, 0x0687, 0x0687, 0x0687, 0x068d, 0x068d, 0x068c, 0x068c, 0x062f, 0x06db,
+    0x062f, 0x06db, 0x062f, 0x0615, 0x062f, 0x0615, 0x0631, 0x06db, 0x0631, 0x06db, 0x0631, 0x0615,
+    0x0631, 0x0615, 0x0643, 0x0643, 0x0643, 0x0643, 0x06af, 0x06af, 0x06af, 0x06af, 0x06b3, 0x06b3,
+    0x06b3, 0x06b3, 0x06b1, 0x06b1, 0x06b1, 0x06b1, 0x0649, 0x0649, 0x0649, 0x0615, 0x0649, 0x0615,
+    0x0649, 0x0615, 0x0649, 0x0615, 0x06c0, 0x06c0, 0x006f, 0x006f, 0x006f, 0x006f, 0x006f, 0x006f,
+    0x006f, 0x006f, 0x0649, 0x0649, 0x06d3, 0x06d3, 0x0643, 0x06db, 0x0643, 0x06db, 0x0643, 0x06db,
+    0x0643, 0x06db, 0x0648, 0x0313, 0x0648, 0x0313, 0x0648, 0x0306, 0x0648, 0x0306, 0x0648, 0x0670,
+    0x0648, 0x0670, 0x0648, 0x0313, 0x0674, 0x0648, 0x06db, 0x0648, 0x06db, 0x06c5, 0x06c5, 0x0648,
+    0x0302, 0x0648, 0x0302, 0x067b, 0x067b, 0x067b, 0x067b, 0x0649, 0x0649, 0x0649, 0x0674, 0x006c,
+    0x0649, 0x0674, 0x006c, 0x0649, 0x0674, 0x006f, 0x0649, 0x0674, 0x006f, 0x0649, 0x0674, 0x0648,
+    0x0649, 0x0674, 0x0648, 0x0649, 0x0674, 0x0648, 0x0313, 0x0649, 0x0674, 0x0648, 0x0313, 0x0649,
+    0x0674, 0x0648, 0x0306, 0x0649, 0x0674, 0x0648, 0x0306, 0x0649, 0x0674, 0x0648, 0x0670, 0x0649,
+    0x0674, 0x0648, 0x0670, 0x0649, 0x0674, 0x067b, 0x0649, 0x0674, 0x067b, 0x0649, 0x0674, 0x067b,
+    0x0649, 0x0674, 0x0649, 0x0649, 0x0674, 0x0649, 0x0649, 0x0674, 0x0649, 0x0649, 0x0649, 0x0649,
+    0x0649, 0x0649, 0x0674, 0x062c, 0x0649, 0x0674, 0x062d, 0x0649, 0x0674, 0x0645, 0x0649, 0x0674,
+    0x0649, 0x0649, 0x0674, 0x0649, 0x0628, 0x062c, 0x0628, 0x062d, 0x0628, 0x062e, 0x0628, 0x0645,
+    0x0628, 0x0649, 0x0628, 0x0649, 0x062a, 0x062c, 0x062a, 0x062d, 0x062a, 0x062e, 0x062a, 0x0645,
+    0x062a, 0x0649, 0x062a, 0x0649, 0x0649, 0x06db, 0x062c, 0x0649, 0x06db, 0x0645, 0x0649, 0x06db,
+    0x0649, 0x0649, 0x06db, 0x0649, 0x062c, 0x062d, 0x062c, 0x0645, 0x062d, 0x062c, 0x062d, 0x0645,
+    0x062e, 0x062c, 0x062e, 0x062d, 0x062e, 0x0645, 0x0633, 0x062c, 0x0633, 0x062d, 0x0633, 0x062e,
+    0x0633, 0x0645, 0x0635, 0x062d, 0x0635, 0x0645, 0x0636, 0x062c, 0x0636, 0x062d, 0x0636, 0x062e,
+    0x0636, 0x0645, 0x0637, 0x062d, 0x0637, 0x0645, 0x0638, 0x0645, 0x0639, 0x062c, 0x0639, 0x0645,
+    0x063a, 0x062c, 0x063a, 0x0645, 0x0641, 0x062c, 0x0641, 0x062d, 0x0641, 0x062e, 0x0641, 0x0645,
+    0x0641, 0x0649, 0x0641, 0x0649, 0x0642, 0x062d, 0x0642, 0x0645, 0x0642, 0x0649, 0x0642, 0x0649,
+    0x0643, 0x006c, 0x0643, 0x062c, 0x0643, 0x062d, 0x0643, 0x062e, 0x0643, 0x0644, 0x0643, 0x0645,
+    0x0643, 0x0649, 0x0643, 0x0649, 0x0644, 0x062c, 0x0644, 0x062d, 0x0644, 0x062e, 0x0644, 0x0645,
+    0x0644, 0x0649, 0x0644, 0x0649, 0x0645, 0x062c, 0x0645, 0x062d, 0x0645, 0x062e, 0x0645, 0x0645,
+    0x0645, 0x0649, 0x0645, 0x0649, 0x0628, 0x062e, 0x0646, 0x062d, 0x0646, 0x062e, 0x0646, 0x0645,
+    0x0646, 0x0649, 0x0646, 0x0649, 0x006f, 0x062c, 0x006f, 0x0645, 0x006f, 0x0649, 0x006f, 0x0649,
+    0x0649, 0x062c, 0x0649, 0x062d, 0x0649, 0x062e, 0x0649, 0x0645, 0x0649, 0x0649, 0x0649, 0x0649,
+    0x0630, 0x0670, 0x0631, 0x0670, 0x0649, 0x0670, 0xfe72, 0x0651, 0xfe74, 0x0651, 0xfe76, 0x0651,
+    0xfe78, 0x0651, 0xfe7a, 0x0651, 0xfe7c, 0x0670, 0x0649, 0x0674, 0x0631, 0x0649, 0x0674, 0x0632,
+    0x0649, 0x0674, 0x0645, 0x0649, 0x0674, 0x0646, 0x0649, 0x0674, 0x0649, 0x0649, 0x0674, 0x0649,
+    0x0628, 0x0631, 0x0628, 0x0632, 0x0628, 0x0645, 0x0628, 0x0646, 0x0628, 0x0649, 0x0628, 0x0649,
+    0x062a, 0x0631, 0x062a, 0x0632, 0x062a, 0x0645, 0x062a, 0x0646, 0x062a, 0x0649, 0x062a, 0x0649,
+    0x0649, 0x06db, 0x0631, 0x0649, 0x06db, 0x0632, 0x0649, 0x06db, 0x0645, 0x0649, 0x06db, 0x0646,
+    0x0649, 0x06db, 0x0649, 0x0649, 0x06db, 0x0649, 0x0641, 0x0649, 0x0641, 0x0649, 0x0642, 0x0649,
+    0x0642, 0x0649, 0x0643, 0x006c, 0x0643, 0x0644, 0x0643, 0x0645, 0x0643, 0x0649, 0x0643, 0x0649,
+    0x0644, 0x0645, 0x0644, 0x0649, 0x0644, 0x0649, 0x0645, 0x006c, 0x0645, 0x0645, 0x0646, 0x0631,
+    0x0646, 0x0632, 0x0646, 0x0645, 0x0646, 0x0646, 0x0646, 0x0649, 0x0646, 0x0649, 0x0649, 0x0670,
+    0x0649, 0x0631, 0x0649, 0x0632, 0x0649, 0x0645, 0x0649, 0x0646, 0x0649, 0x0649, 0x0649, 0x0649,
+    0x0649, 0x0674, 0x062c, 0x0649, 0x0674, 0x062d, 0x0649, 0x0674, 0x062e, 0x0649, 0x0674, 0x0645,
+    0x0649, 0x0674, 0x006f, 0x0628, 0x062c, 0x0628, 0x062d, 0x0628, 0x062e, 0x0628, 0x0645, 0x0628,
+    0x006f, 0x062a, 0x062c, 0x062a, 0x062d, 0x062a, 0x062e, 0x062a, 0x0645, 0x062a, 0x006f, 0x0649,
+    0x06db, 0x0645, 0x062c, 0x062d, 0x062c, 0x0645, 0x062d, 0x062c, 0x062d, 0x0645, 0x062e, 0x062c,
+    0x062e, 0x0645, 0x0633, 0x062c, 0x0633, 0x062d, 0x0633, 0x062e, 0x0633, 0x0645, 0x0635, 0x062d,
+    0x0635, 0x062e, 0x0635, 0x0645, 0x0636, 0x062c, 0x0636, 0x062d, 0x0636, 0x062e, 0x0636, 0x0645,
+    0x0637, 0x062d, 0x0638, 0x0645, 0x0639, 0x062c, 0x0639, 0x0645, 0x063a, 0x062c, 0x063a, 0x0645,
+    0x0641, 0x062c, 0x0641, 0x062d, 0x0641, 0x062e, 0x0641, 0x0645, 0x0642, 0x062d, 0x0642, 0x0645,
+    0x0643, 0x062c, 0x0643, 0x062d, 0x0643, 0x062e, 0x0643, 0x0644, 0x0643, 0x0645, 0x0644, 0x062c,
+    0x0644, 0x062d, 0x0644, 0x062e, 0x0644, 0x0645, 0x0644, 0x006f, 0x0645, 0x062c, 0x0645, 0x062d,
+    0x0645, 0x062e, 0x0645, 0x0645, 0x0628, 0x062e, 0x0646, 0x062d, 0x0646, 0x062e, 0x0646, 0x0645,
+    0x0646, 0x006f, 0x006f, 0x062c, 0x006f, 0x0645, 0x006f, 0x0670, 0x0649, 0x062c, 0x0649, 0x062d,
+    0x0649, 0x062e, 0x0649, 0x0645, 0x0649, 0x006f, 0x0649, 0x0674, 0x0645, 0x0649, 0x0674, 0x006f,
+    0x0628, 0x0645, 0x0628, 0x006f, 0x062a, 0x0645, 0x062a, 0x006f, 0x0649, 0x06db, 0x0645, 0x0649,
+    0x06db, 0x006f, 0x0633, 0x0645, 0x0633, 0x006f, 0x0633, 0x06db, 0x0645, 0x0633, 0x06db, 0x006f,
+    0x0643, 0x0644, 0x0643, 0x0645, 0x0644, 0x0645, 0x0646, 0x0645, 0x0646, 0x006f, 0x0649, 0x0645,
+    0x0649, 0x006f, 0xfe77, 0x0651, 0xfe79, 0x0651, 0xfe7b, 0x0651, 0x0637, 0x0649, 0x0637, 0x0649,
+    0x0639, 0x0649, 0x0639, 0x0649, 0x063a, 0x0649, 0x063a, 0x0649, 0x0633, 0x0649, 0x0633, 0x0649,
+    0x0633, 0x06db, 0x0649, 0x0633, 0x06db, 0x0649, 0x062d, 0x0649, 0x062d, 0x0649, 0x062c, 0x0649,
+    0x062c, 0x0649, 0x062e, 0x0649, 0x062e, 0x0649, 0x0635, 0x0649, 0x0635, 0x0649, 0x0636, 0x0649,
+    0x0636, 0x0649, 0x0633, 0x06db, 0x062c, 0x0633, 0x06db, 0x062d, 0x0633, 0x06db, 0x062e, 0x0633,
+    0x06db, 0x0645, 0x0633, 0x06db, 0x0631, 0x0633, 0x0631, 0x0635, 0x0631, 0x0636, 0x0631, 0x0637,
+    0x0649, 0x0637, 0x0649, 0x0639, 0x0649, 0x0639, 0x0649, 0x063a, 0x0649, 0x063a, 0x0649, 0x0633,
+    0x0649, 0x0633, 0x0649, 0x0633, 0x06db, 0x0649, 0x0633, 0x06db, 0x0649, 0x062d, 0x0649, 0x062d,
+    0x0649, 0x062c, 0x0649, 0x062c, 0x0649, 0x062e, 0x0649, 0x062e, 0x0649, 0x0635, 0x0649, 0x0635,
+    0x0649, 0x0636, 0x0649, 0x0636, 0x0649, 0x0633, 0x06db, 0x062c, 0x0633, 0x06db, 0x062d, 0x0633,
+    0x06db, 0x062e, 0x0633, 0x06db, 0x0645, 0x0633, 0x06db, 0x0631, 0x0633, 0x0631, 0x0635, 0x0631,
+    0x0636, 0x0631, 0x0633, 0x06db, 0x062c, 0x0633, 0x06db, 0x062d, 0x0633, 0x06db, 0x062e, 0x0633,
+    0x06db, 0x0645, 0x0633, 0x006f, 0x0633, 0x06db, 0x006f, 0x0637, 0x0645, 0x0633, 0x062c, 0x0633,
+    0x062d, 0x0633, 0x062e, 0x0633, 0x06db, 0x062c, 0x0633, 0x06db, 0x062d, 0x0633, 0x06db, 0x062e,
+    0x0637, 0x0645, 0x0638, 0x0645, 0x006c, 0x030b, 0x006c, 0x030b, 0x0028, 0x0029, 0x062a, 0x062c,
+    0x0645, 0x062a, 0x062d, 0x062c, 0x062a, 0x062d, 0x062c, 0x062a, 0x062d, 0x0645, 0x062a, 0x062e,
+    0x0645, 0x062a, 0x0645, 0x062c, 0x062a, 0x0645, 0x062d, 0x062a, 0x0645, 0x062e, 0x062c, 0x0645,
+    0x062d, 0x062c, 0x0645, 0x062d, 0x062d, 0x0645, 0x0649, 0x062d, 0x0645, 0x0649, 0x0633, 0x062d,
+    0x062c, 0x0633, 0x062c, 0x062d, 0x0633, 0x062c, 0x0649, 0x0633, 0x0645, 0x062d, 0x0633, 0x0645,
+    0x062d, 0x0633, 0x0645, 0x062c, 0x0633, 0x0645, 0x0645, 0x0633, 0x0645, 0x0645, 0x0635, 0x062d,
+    0x062d, 0x0635, 0x062d, 0x062d, 0x0635, 0x0645, 0x0645, 0x0633, 0x06db, 0x062d, 0x0645, 0x0633,
+    0x06db, 0x062d, 0x0645, 0x0633, 0x06db, 0x062c, 0x0649, 0x0633, 0x06db, 0x0645, 0x062e, 0x0633,
+    0x06db, 0x0645, 0x062e, 0x0633, 0x06db, 0x0645, 0x0645, 0x0633, 0x06db, 0x0645, 0x0645, 0x0636,
+    0x062d, 0x0649, 0x0636, 0x062e, 0x0645, 0x0636, 0x062e, 0x0645, 0x0637, 0x0645, 0x062d, 0x0637,
+    0x0645, 0x062d, 0x0637, 0x0645, 0x0645, 0x0637, 0x0645, 0x0649, 0x0639, 0x062c, 0x0645, 0x0639,
+    0x0645, 0x0645, 0x0639, 0x0645, 0x0645, 0x0639, 0x0645, 0x0649, 0x063a, 0x0645, 0x0645, 0x063a,
+    0x0645, 0x0649, 0x063a, 0x0645, 0x0649, 0x0641, 0x062e, 0x0645, 0x0641, 0x062e, 0x0645, 0x0642,
+    0x0645, 0x062d, 0x0642, 0x0645, 0x0645, 0x0644, 0x062d, 0x0645, 0x0644, 0x062d, 0x0649, 0x0644,
+    0x062d, 0x0649, 0x0644, 0x062c, 0x062c, 0x0644, 0x062c, 0x062c, 0x0644, 0x062e, 0x0645, 0x0644,
+    0x062e, 0x0645, 0x0644, 0x0645, 0x062d, 0x0644, 0x0645, 0x062d, 0x0645, 0x062d, 0x062c, 0x0645,
+    0x062d, 0x0645, 0x0645, 0x062d, 0x0649, 0x0645, 0x062c, 0x062d, 0x0645, 0x062c, 0x0645, 0x0645,
+    0x062e, 0x062c, 0x0645, 0x062e, 0x0645, 0x0645, 0x062c, 0x062e, 0x006f, 0x0645, 0x062c, 0x006f,
+    0x0645, 0x0645, 0x0646, 0x062d, 0x0645, 0x0646, 0x062d, 0x0649, 0x0646, 0x062c, 0x0645, 0x0646,
+    0x062c, 0x0645, 0x0646, 0x062c, 0x0649, 0x0646, 0x0645, 0x0649, 0x0646, 0x0645, 0x0649, 0x0649,
+    0x0645, 0x0645, 0x0649, 0x0645, 0x0645, 0x0628, 0x062e, 0x0649, 0x062a, 0x062c, 0x0649, 0x062a,
+    0x062c, 0x0649, 0x062a, 0x062e, 0x0649, 0x062a, 0x062e, 0x0649, 0x062a, 0x0645, 0x0649, 0x062a,
+    0x0645, 0x0649, 0x062c, 0x0645, 0x0649, 0x062c, 0x062d, 0x0649, 0x062c, 0x0645, 0x0649, 0x0633,
+    0x062e, 0x0649, 0x0635, 0x062d, 0x0649, 0x0633, 0x06db, 0x062d, 0x0649, 0x0636, 0x062d, 0x0649,
+    0x0644, 0x062c, 0x0649, 0x0644, 0x0645, 0x0649, 0x0649, 0x062d, 0x0649, 0x0649, 0x062c, 0x0649,
+    0x0649, 0x0645, 0x0649, 0x0645, 0x0645, 0x0649, 0x0642, 0x0645, 0x0649, 0x0646, 0x062d, 0x0649,
+    0x0642, 0x0645, 0x062d, 0x0644, 0x062d, 0x0645, 0x0639, 0x0645, 0x0649, 0x0643, 0x0645, 0x0649,
+    0x0646, 0x062c, 0x062d, 0x0645, 0x062e, 0x0649, 0x0644, 0x062c, 0x0645, 0x0643, 0x0645, 0x0645,
+    0x0644, 0x062c, 0x0645, 0x0646, 0x062c, 0x062d, 0x062c, 0x062d, 0x0649, 0x062d, 0x062c, 0x0649,
+    0x0645, 0x062c, 0x0649, 0x0641, 0x0645, 0x0649, 0x0628, 0x062d, 0x0649, 0x0643, 0x0645, 0x0645,
+    0x0639, 0x062c, 0x0645, 0x0635, 0x0645, 0x0645, 0x0633, 0x062e, 0x0649, 0x0646, 0x062c, 0x0649,
+    0x0635, 0x0644, 0x0649, 0x0642, 0x0644, 0x0649, 0x006c, 0x0644, 0x0644, 0x0651, 0x0670, 0x006f,
+    0x006c, 0x0643, 0x0628, 0x0631, 0x0645, 0x062d, 0x0645, 0x062f, 0x0635, 0x0644, 0x0639, 0x0645,
+    0x0631, 0x0633, 0x0648, 0x0644, 0x0639, 0x0644, 0x0649, 0x006f, 0x0648, 0x0633, 0x0644, 0x0645,
+    0x0635, 0x0644, 0x0649, 0x0635, 0x0644, 0x0649, 0x0020, 0x006c, 0x0644, 0x0644, 0x006f, 0x0020,
+    0x0639, 0x0644, 0x0649, 0x006f, 0x0020, 0x0648, 0x0633, 0x0644, 0x0645, 0x062c, 0x0644, 0x0020,
+    0x062c, 0x0644, 0x006c, 0x0644, 0x006f, 0x0631, 0x0649, 0x006c, 0x0644, 0x2d57, 0x003a, 0x2502,
+    0x2307, 0x23dc, 0x23dd, 0x23de, 0x23df, 0x23e0, 0x23e1, 0x02c9, 0x02c9, 0x02c9, 0x02c9, 0x005f,
+    0x005f, 0x005f, 0x002d, 0x005c, 0x0621, 0x0622, 0x0622, 0x006c, 0x0674, 0x006c, 0x0674, 0x0648,
+    0x0674, 0x0648, 0x0674, 0x006c, 0x0655, 0x006c, 0x0655, 0x0649, 0x0674, 0x0649, 0x0674, 0x0649,
+    0x0674, 0x0649, 0x0674, 0x006c, 0x006c, 0x0628, 0x0628, 0x0628, 0x0628, 0x0629, 0x0629, 0x062a,
+    0x062a, 0x062a, 0x062a, 0x0649, 0x06db, 0x0649, 0x06db, 0x0649, 0x06db, 0x0649, 0x06db, 0x062c,
+    0x062c, 0x062c, 0x062c, 0x062d, 0x062d, 0x062d, 0x062d, 0x062e, 0x062e, 0x062e, 0x062e, 0x062f,
+    0x062f, 0x0630, 0x0630, 0x0631, 0x0631, 0x0632, 0x0632, 0x0633, 0x0633, 0x0633, 0x0633, 0x0633,
+    0x06db, 0x0633, 0x06db, 0x0633, 0x06db, 0x0633, 0x06db, 0x0635, 0x0635, 0x0635, 0x0635, 0x0636,
+    0x0636, 0x0636, 0x0636, 0x0637, 0x0637, 0x0637, 0x0637, 0x0638, 0x0638, 0x0638, 0x0638, 0x0639,
+    0x0639, 0x0639, 0x0639, 0x063a, 0x063a, 0x063a, 0x063a, 0x0641, 0x0641, 0x0641, 0x0641, 0x0642,
+    0x0642, 0x0642, 0x0642, 0x0643, 0x0643, 0x0643, 0x0643, 0x0644, 0x0644, 0x0644, 0x0644, 0x0645,
+    0x0645, 0x0645, 0x0645, 0x0646, 0x0646, 0x0646, 0x0646, 0x006f, 0x006f, 0x006f, 0x006f, 0x0648,
+    0x0648, 0x0649, 0x0649, 0x0649, 0x0649, 0x0649, 0x0649, 0x0644, 0x0622, 0x0644, 0x0622, 0x0644,
+    0x006c, 0x0674, 0x0644, 0x006c, 0x0674, 0x0644, 0x006c, 0x0655, 0x0644, 0x006c, 0x0655, 0x0644,
+    0x006c, 0x0644, 0x006c, 0x0021, 0x0027, 0x0027, 0x0027, 0x30fc, 0x003a, 0x0041, 0x0042, 0x0043,
+    0x0045, 0x0048, 0x006c, 0x004a, 0x004b, 0x004d, 0x004e, 0x004f, 0x0050, 0x0053, 0x0054, 0x0058,
+    0x0059, 0x005a, 0x0028, 0x005c, 0x0029, 0xfe3f, 0x0027, 0x0061, 0x0063, 0x0065, 0x0067, 0x0068,
+    0x0069, 0x006a, 0x006c, 0x006f, 0x0070, 0x0073, 0x0076, 0x0078, 0x0079, 0x2502, 0x301c, 0x00b7,
+    0x02c9, 0x006c, 0x25aa, 0x00b7, 0x004e, 0x030a, 0x0058, 0x0335, 0x0056, 0x0335, 0x006c, 0x0335,
+    0x006c, 0x0335, 0x0053, 0x0335, 0x006c, 0x0335, 0x006c, 0x0335, 0x2ce8, 0x0042, 0x0394, 0x0045,
+    0x0046, 0x006c, 0x0245, 0x0058, 0x004f, 0x16dc, 0x0050, 0x0053, 0x0054, 0x002b, 0x0041, 0x0042,
+    0x0043, 0x0394, 0x0046, 0x004f, 0x03d8, 0x004d, 0x0054, 0x0059, 0x03a6, 0x0058, 0x03a8, 0x03a9,
+    0x2d40, 0x0048, 0x062f, 0x0648, 0x0637, 0x0635, 0x005a, 0x0042, 0x0043, 0x006c, 0x004d, 0x03d8,
+    0x0054, 0x0058, 0x0038, 0x002a, 0x006c, 0x0058, 0xd800, 0xdf82, 0xd800, 0xdf93, 0x0190, 0x004f,
+    0xa4f6, 0x0043, 0x004c, 0x2c70, 0x0053, 0x0186, 0x0418, 0xa793, 0x029a, 0x006f, 0x0063, 0x0277,
+    0x025e, 0x029f, 0x0073, 0x0254, 0x1d0e, 0xd801, 0xdc86, 0x0245, 0x0052, 0x04c3, 0x004f, 0x0298,
+    0x00de, 0x040b, 0x0055, 0x16e6, 0x03a8, 0x0037, 0x028c, 0x03bb, 0x006f, 0xa669, 0x0075, 0x03c8,
+    0x004e, 0x004f, 0x004b, 0x0043, 0x0056, 0x0046, 0x004c, 0x0058, 0x0323, 0x002e, 0xd802, 0xde56,
+    0xd802, 0xde56, 0xd803, 0xdca5, 0xd803, 0xdc82, 0x0970, 0x0970, 0x0323, 0x093a, 0xa8fc, 0xa8fb,
+    0x2248, 0x030a, 0xd805, 0xdc34, 0xd805, 0xdc42, 0xd805, 0xdc12, 0xd805, 0xdc34, 0xd805, 0xdc42,
+    0xd805, 0xdc18, 0xd805, 0xdc34, 0xd805, 0xdc42, 0xd805, 0xdc23, 0xd805, 0xdc34, 0xd805, 0xdc42,
+    0xd805, 0xdc29, 0xd805, 0xdc34, 0xd805, 0xdc42, 0xd805, 0xdc2c, 0xd805, 0xdc34, 0xd805, 0xdc42,
+    0xd805, 0xdc2e, 0xd805, 0xdc4b, 0xd805, 0xdc4b, 0x0998, 0x099a, 0x099c, 0x099e, 0x099f, 0x09a1,
+    0x09b2, 0x09a4, 0x09a5, 0x09a6, 0x09a7, 0x09a8, 0x09aa, 0x09ae, 0x09af, 0x09ac, 0x09a3, 0x09b0,
+    0x09b7, 0x09b8, 0x09be, 0x09bf, 0x09c7, 0x09cb, 0x09d7, 0x09cc, 0x0306, 0x0307, 0x0983, 0x09cd,
+    0x0323, 0x09bd, 0x0077, 0x0307, 0x004f, 0x09e7, 0x09e8, 0x09ec, 0xd805, 0xdd82, 0xd805, 0xdd82,
+    0xd805, 0xdd83, 0xd805, 0xdd84, 0xd805, 0xddb2, 0xd805, 0xddb3, 0xd805, 0xde41, 0xd805, 0xde41,
+    0x0072, 0x006e, 0x0076, 0x0077, 0x0077, 0x0077, 0x0056, 0x0046, 0x004c, 0x0059, 0x0045, 0x2207,
+    0x005a, 0x0039, 0x0045, 0x0034, 0x004c, 0x004f, 0x16dc, 0x0055, 0x0035, 0x0054, 0x0076, 0x0073,
+    0x0046, 0x0069, 0x007a, 0x0037, 0x006f, 0x0033, 0x0039, 0xa793, 0x0036, 0x0039, 0x006f, 0x0075,
+    0x0079, 0x004f, 0x0072, 0x006e, 0x0669, 0x005a, 0x0057, 0x0043, 0x0058, 0x0057, 0x0043, 0xd806,
+    0xdee5, 0xd806, 0xdeef, 0xd806, 0xdee5, 0xd806, 0xdef0, 0xd806, 0xdee5, 0xd806, 0xdee5, 0xd806,
+    0xdee5, 0xd806, 0xdee5, 0xd806, 0xdeef, 0xd806, 0xdee5, 0xd806, 0xdee5, 0xd806, 0xdef0, 0xd806,
+    0xdeeb, 0xd806, 0xdeef, 0xd806, 0xdeeb, 0xd806, 0xdeeb, 0xd806, 0xdeeb, 0xd806, 0xdeeb, 0xd806,
+    0xdeef, 0xd806, 0xdef3, 0xd806, 0xdeef, 0xd806, 0xdef3, 0xd806, 0xdef0, 0xd806, 0xdef3, 0xd806,
+    0xdef3, 0xd806, 0xdef3, 0xd806, 0xdef3, 0xd806, 0xdeef, 0xd806, 0xdef3, 0xd806, 0xdef3, 0xd806,
+    0xdef0, 0xd807, 0xdc41, 0xd807, 0xdc41, 0xd807, 0xdcaa, 0xd800, 0xdf9a, 0xd802, 0xdd9e, 0x0393,
+    0x0056, 0x0054, 0x004c, 0x0394, 0xa658, 0xa4f6, 0x006c, 0x0190, 0x0052, 0x0053, 0x0033, 0x0245,
+    0x003e, 0x0041, 0x0055, 0x0059, 0x0027, 0x0027, 0x007b, 0x002e, 0x04fe, 0x0033, 0x0418, 0x0056,
+    0x005c, 0x0037, 0x0046, 0xd800, 0xdebc, 0xa4f6, 0x0052, 0x2c6f, 0x004f, 0x0335, 0x2144, 0xa4d5,
+    0x0190, 0x0460, 0x004c, 0xa4f6, 0xa7fb, 0x003c, 0x003e, 0x228f, 0x2290, 0x002f, 0x005c, 0x16cb,
+    0x0548, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b,
+    0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069,
+    0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074,
+    0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046,
+    0x0047, 0x0048, 0x006c, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052,
+    0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064,
+    0x0065, 0x0066, 0x0067, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x006f, 0x0070,
+    0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e,
+    0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a,
+    0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c,
+    0x0072, 0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0043, 0x0044, 0x0047, 0x004a, 0x004b, 0x004e, 0x004f, 0x0050,
+    0x0051, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0066, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x0070, 0x0071,
+    0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043,
+    0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061,
+    0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072,
+    0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007a, 0x0041, 0x0042, 0x0044, 0x0045, 0x0046, 0x0047, 0x004a, 0x004b, 0x004c, 0x004d,
+    0x004e, 0x004f, 0x0050, 0x0051, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x0061,
+    0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072,
+    0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007a, 0x0041, 0x0042, 0x0044, 0x0045, 0x0046, 0x0047, 0x006c, 0x004a, 0x004b, 0x004c,
+    0x004d, 0x004f, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x0061, 0x0062, 0x0063,
+    0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e,
+    0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a,
+    0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b, 0x004c,
+    0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a,
+    0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075,
+    0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x006c, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053,
+    0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065,
+    0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x006f, 0x0070,
+    0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042,
+    0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e,
+    0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a,
+    0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c,
+    0x0072, 0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c,
+    0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055,
+    0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072,
+    0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0041, 0x0042, 0x0043, 0x0044,
+    0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b, 0x004c, 0x004d, 0x004e, 0x004f, 0x0050,
+    0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058, 0x0059, 0x005a, 0x0061, 0x0062,
+    0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069, 0x006a, 0x006b, 0x006c, 0x0072, 0x006e,
+    0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078, 0x0079,
+    0x007a, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047, 0x0048, 0x006c, 0x004a, 0x004b,
+    0x004c, 0x004d, 0x004e, 0x004f, 0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005a, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067, 0x0068, 0x0069,
+    0x006a, 0x006b, 0x006c, 0x0072, 0x006e, 0x006e, 0x006f, 0x0070, 0x0071, 0x0072, 0x0073, 0x0074,
+    0x0075, 0x0076, 0x0077, 0x0078, 0x0079, 0x007a, 0x0069, 0x0237, 0x0041, 0x0042, 0x0393, 0x0394,
+    0x0045, 0x005a, 0x0048, 0x004f, 0x0335, 0x006c, 0x004b, 0x0245, 0x004d, 0x004e, 0x039e, 0x004f,
+    0x03a0, 0x0050, 0x004f, 0x0335, 0x01a9, 0x0054, 0x0059, 0x03a6, 0x0058, 0x03a8, 0x03a9, 0x2207,
+    0x0061, 0x00df, 0x0079, 0x1e9f, 0xa793, 0x03b6, 0x006e, 0x0329, 0x004f, 0x0335, 0x0069, 0x0138,
+    0x03bb, 0x03bc, 0x0076, 0x03be, 0x006f, 0x03c0, 0x0070, 0x03c2, 0x006f, 0x1d1b, 0x0075, 0x0278,
+    0x03c7, 0x03c8, 0x03c9, 0x2202, 0xa793, 0x004f, 0x0335, 0x0138, 0x0278, 0x0070, 0x03c0, 0x0041,
+    0x0042, 0x0393, 0x0394, 0x0045, 0x005a, 0x0048, 0x004f, 0x0335, 0x006c, 0x004b, 0x0245, 0x004d,
+    0x004e, 0x039e, 0x004f, 0x03a0, 0x0050, 0x004f, 0x0335, 0x01a9, 0x0054, 0x0059, 0x03a6, 0x0058,
+    0x03a8, 0x03a9, 0x2207, 0x0061, 0x00df, 0x0079, 0x1e9f, 0xa793, 0x03b6, 0x006e, 0x0329, 0x004f,
+    0x0335, 0x0069, 0x0138, 0x03bb, 0x03bc, 0x0076, 0x03be, 0x006f, 0x03c0, 0x0070, 0x03c2, 0x006f,
+    0x1d1b, 0x0075, 0x0278, 0x03c7, 0x03c8, 0x03c9, 0x2202, 0xa793, 0x004f, 0x0335, 0x0138, 0x0278,
+    0x0070, 0x03c0, 0x0041, 0x0042, 0x0393, 0x0394, 0x0045, 0x005a, 0x0048, 0x004f, 0x0335, 0x006c,
+    0x004b, 0x0245, 0x004d, 0x004e, 0x039e, 0x004f, 0x03a0, 0x0050, 0x004f, 0x0335, 0x01a9, 0x0054,
+    0x0059, 0x03a6, 0x0058, 0x03a8, 0x03a9, 0x2207, 0x0061, 0x00df, 0x0079, 0x1e9f, 0xa793, 0x03b6,
+    0x006e, 0x0329, 0x004f, 0x0335, 0x0069, 0x0138, 0x03bb, 0x03bc, 0x0076, 0x03be, 0x006f, 0x03c0,
+    0x0070, 0x03c2, 0x006f, 0x1d1b, 0x0075, 0x0278, 0x03c7, 0x03c8, 0x03c9, 0x2202, 0xa793, 0x004f,
+    0x0335, 0x0138, 0x0278, 0x0070, 0x03c0, 0x0041, 0x0042, 0x0393, 0x0394, 0x0045, 0x005a, 0x0048,
+    0x004f, 0x0335, 0x006c, 0x004b, 0x0245, 0x004d, 0x004e, 0x039e, 0x004f, 0x03a0, 0x0050, 0x004f,
+    0x0335, 0x01a9, 0x0054, 0x0059, 0x03a6, 0x0058, 0x03a8, 0x03a9, 0x2207, 0x0061, 0x00df, 0x0079,
+    0x1e9f, 0xa793, 0x03b6, 0x006e, 0x0329, 0x004f, 0x0335, 0x0069, 0x0138, 0x03bb, 0x03bc, 0x0076,
+    0x03be, 0x006f, 0x03c0, 0x0070, 0x03c2, 0x006f, 0x1d1b, 0x0075, 0x0278, 0x03c7, 0x03c8, 0x03c9,
+    0x2202, 0xa793, 0x004f, 0x0335, 0x0138, 0x0278, 0x0070, 0x03c0, 0x0041, 0x0042, 0x0393, 0x0394,
+    0x0045, 0x005a, 0x0048, 0x004f, 0x0335, 0x006c, 0x004b, 0x0245, 0x004d, 0x004e, 0x039e, 0x004f,
+    0x03a0, 0x0050, 0x004f, 0x0335, 0x01a9, 0x0054, 0x0059, 0x03a6, 0x0058, 0x03a8, 0x03a9, 0x2207,
+    0x0061, 0x00df, 0x0079, 0x1e9f, 0xa793, 0x03b6, 0x006e, 0x0329, 0x004f, 0x0335, 0x0069, 0x0138,
+    0x03bb, 0x03bc, 0x0076, 0x03be, 0x006f, 0x03c0, 0x0070, 0x03c2, 0x006f, 0x1d1b, 0x0075, 0x0278,
+    0x03c7, 0x03c8, 0x03c9, 0x2202, 0xa793, 0x004f, 0x0335, 0x0138, 0x0278, 0x0070, 0x03c0, 0x0046,
+    0x03dd, 0x004f, 0x006c, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x004f,
+    0x006c, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x004f, 0x006c, 0x0032,
+    0x0033, 0x0034, 0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x004f, 0x006c, 0x0032, 0x0033, 0x0034,
+    0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x004f, 0x006c, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036,
+    0x0037, 0x0038, 0x0039, 0x006c, 0x2220, 0x0663, 0x0038, 0x2202, 0x2202, 0x0335, 0x006c, 0x0628,
+    0x062c, 0x062f, 0x0648, 0x0632, 0x062d, 0x0637, 0x0649, 0x0643, 0x0644, 0x0645, 0x0646, 0x0633,
+    0x0639, 0x0641, 0x0635, 0x0642, 0x0631, 0x0633, 0x06db, 0x062a, 0x0649, 0x06db, 0x062e, 0x0630,
+    0x0636, 0x0638, 0x063a, 0x0649, 0x0649, 0x06a1, 0x06a1, 0x0628, 0x062c, 0x006f, 0x062d, 0x0649,
+    0x0643, 0x0644, 0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0633, 0x06db, 0x062a,
+    0x0649, 0x06db, 0x062e, 0x0636, 0x063a, 0x062c, 0x062d, 0x0649, 0x0644, 0x0646, 0x0633, 0x0639,
+    0x0635, 0x0642, 0x0633, 0x06db, 0x062e, 0x0636, 0x063a, 0x0649, 0x06a1, 0x0628, 0x062c, 0x006f,
+    0x062d, 0x0637, 0x0649, 0x0643, 0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0633,
+    0x06db, 0x062a, 0x0649, 0x06db, 0x062e, 0x0636, 0x0638, 0x063a, 0x0649, 0x06a1, 0x006c, 0x0628,
+    0x062c, 0x062f, 0x006f, 0x0648, 0x0632, 0x062d, 0x0637, 0x0649, 0x0644, 0x0645, 0x0646, 0x0633,
+    0x0639, 0x0641, 0x0635, 0x0642, 0x0631, 0x0633, 0x06db, 0x062a, 0x0649, 0x06db, 0x062e, 0x0630,
+    0x0636, 0x0638, 0x063a, 0x0628, 0x062c, 0x062f, 0x0648, 0x0632, 0x062d, 0x0637, 0x0649, 0x0644,
+    0x0645, 0x0646, 0x0633, 0x0639, 0x0641, 0x0635, 0x0642, 0x0631, 0x0633, 0x06db, 0x062a, 0x0649,
+    0x06db, 0x062e, 0x0630, 0x0636, 0x0638, 0x063a, 0x004f, 0x002e, 0x004f, 0x002c, 0x006c, 0x002c,
+    0x0032, 0x002c, 0x0033, 0x002c, 0x0034, 0x002c, 0x0035, 0x002c, 0x0036, 0x002c, 0x0037, 0x002c,
+    0x0038, 0x002c, 0x0039, 0x002c, 0x0024, 0x20e0, 0x0028, 0x0041, 0x0029, 0x0028, 0x0042, 0x0029,
+    0x0028, 0x0043, 0x0029, 0x0028, 0x0044, 0x0029, 0x0028, 0x0045, 0x0029, 0x0028, 0x0046, 0x0029,
+    0x0028, 0x0047, 0x0029, 0x0028, 0x0048, 0x0029, 0x0028, 0x006c, 0x0029, 0x0028, 0x004a, 0x0029,
+    0x0028, 0x004b, 0x0029, 0x0028, 0x004c, 0x0029, 0x0028, 0x004d, 0x0029, 0x0028, 0x004e, 0x0029,
+    0x0028, 0x004f, 0x0029, 0x0028, 0x0050, 0x0029, 0x0028, 0x0051, 0x0029, 0x0028, 0x0052, 0x0029,
+    0x0028, 0x0053, 0x0029, 0x0028, 0x0054, 0x0029, 0x0028, 0x0055, 0x0029, 0x0028, 0x0056, 0x0029,
+    0x0028, 0x0057, 0x0029, 0x0028, 0x0058, 0x0029, 0x0028, 0x0059, 0x0029, 0x0028, 0x005a, 0x0029,
+    0x0028, 0x0053, 0x0029, 0x33c4, 0x0009, 0x20dd, 0x0043, 0x20e0, 0x0028, 0x672c, 0x0029, 0x0028,
+    0x4e09, 0x0029, 0x0028, 0x4e8c, 0x0029, 0x0028, 0x5b89, 0x0029, 0x0028, 0x70b9, 0x0029, 0x0028,
+    0x6253, 0x0029, 0x0028, 0x76d7, 0x0029, 0x0028, 0x52dd, 0x0029, 0x0028, 0x6557, 0x0029, 0x263d,
+    0x263e, 0x263d, 0x0051, 0x0045, 0xa658, 0x0394, 0xd800, 0xdebc, 0x0041, 0x0052, 0x0056, 0x1de4,
+    0x2629, 0x004f, 0x0335, 0xd800, 0xdea8, 0x29df, 0x0043, 0x16dc, 0x22a1, 0x0073, 0x0073, 0x0073,
+    0x224f, 0x0054, 0x004d, 0x0042, 0x0056, 0x0042, 0x22a0, 0x004f, 0x006c, 0x0032, 0x0033, 0x0034,
+    0x0035, 0x0036, 0x0037, 0x0038, 0x0039, 0x276c, 0x4e3d, 0x4e38, 0x4e41, 0xd840, 0xdd22, 0x4f60,
+    0x4fae, 0x4fbb, 0x4f75, 0x507a, 0x5099, 0x50e7, 0x50cf, 0x349e, 0xd841, 0xde3a, 0x514d, 0x5154,
+    0x5164, 0x5177, 0xd841, 0xdd1c, 0x34b9, 0x5167, 0x518d, 0xd841, 0xdd4b, 0x5197, 0x51a4, 0x4ecc,
+    0x51ac, 0x51b5, 0xd864, 0xdddf, 0x51f5, 0x5203, 0x34df, 0x523b, 0x5246, 0x5272, 0x5277, 0x3515,
+    0x52c7, 0x52c9, 0x52e4, 0x52fa, 0x5305, 0x5306, 0x5317, 0x5349, 0x5351, 0x535a, 0x5373, 0x537d,
+    0x537f, 0x537f, 0x537f, 0xd842, 0xde2c, 0x7070, 0x53ca, 0x53df, 0xd842, 0xdf63, 0x53eb, 0x53f1,
+    0x5406, 0x549e, 0x5438, 0x5448, 0x5468, 0x54a2, 0x54f6, 0x5510, 0x5553, 0x5563, 0x5584, 0x5584,
+    0x5599, 0x55ab, 0x55b3, 0x55c2, 0x5716, 0x5606, 0x5717, 0x5651, 0x5674, 0x5207, 0x58ee, 0x57ce,
+    0x57f4, 0x580d, 0x578b, 0x5832, 0x5831, 0x58ac, 0xd845, 0xdce4, 0x58f2, 0x58f7, 0x5906, 0x591a,
+    0x5922, 0x5962, 0xd845, 0xdea8, 0xd845, 0xdeea, 0x59ec, 0x5a1b, 0x5a27, 0x59d8, 0x5a66, 0x36ee,
+    0x36fc, 0x5b08, 0x5b3e, 0x5b3e, 0xd846, 0xddc8, 0x5bc3, 0x5bd8, 0x5be7, 0x5bf3, 0xd846, 0xdf18,
+    0x5bff, 0x5c06, 0x5f53, 0x5c22, 0x3781, 0x5c60, 0x5c6e, 0x5cc0, 0x5c8d, 0xd847, 0xdde4, 0x5d43,
+    0xd847, 0xdde6, 0x5d6e, 0x5d6b, 0x5d7c, 0x5de1, 0x5de2, 0x382f, 0x5dfd, 0x5e28, 0x5e3d, 0x5e69,
+    0x3862, 0xd848, 0xdd83, 0x387c, 0x5eb0, 0x5eb3, 0x5eb6, 0x5eca, 0xd868, 0xdf92, 0x5efe, 0xd848,
+    0xdf31, 0xd848, 0xdf31, 0x8201, 0x5f22, 0x5f22, 0x38c7, 0xd84c, 0xdeb8, 0xd858, 0xddda, 0x5f62,
+    0x5f6b, 0x38e3, 0x5f9a, 0x5fcd, 0x5fd7, 0x5ff9, 0x6081, 0x393a, 0x391c, 0x6094, 0xd849, 0xded4,
+    0x60c7, 0x6148, 0x614c, 0x614e, 0x614c, 0x617a, 0x618e, 0x61b2, 0x61a4, 0x61af, 0x61de, 0x61f2,
+    0x61f6, 0x6210, 0x621b, 0x625d, 0x62b1, 0x62d4, 0x6350, 0xd84a, 0xdf0c, 0x633d, 0x62fc, 0x6368,
+    0x6383, 0x63e4, 0xd84a, 0xdff1, 0x6422, 0x63c5, 0x63a9, 0x3a2e, 0x6469, 0x647e, 0x649d, 0x6477,
+    0x3a6c, 0x654f, 0x656c, 0xd84c, 0xdc0a, 0x65e3, 0x66f8, 0x6649, 0x3b19, 0x6691, 0x3b08, 0x3ae4,
+    0x5192, 0x5195, 0x6700, 0x669c, 0x80ad, 0x43d9, 0x6717, 0x671b, 0x6721, 0x675e, 0x6753, 0xd84c,
+    0xdfc3, 0x3b49, 0x67fa, 0x6785, 0x6852, 0x6885, 0xd84d, 0xdc6d, 0x688e, 0x681f, 0x6914, 0x3b9d,
+    0x6942, 0x69a3, 0x69ea, 0x6aa8, 0xd84d, 0xdea3, 0x6adb, 0x3c18, 0x6b21, 0xd84e, 0xdca7, 0x6b54,
+    0x3c4e, 0x6b72, 0x6b9f, 0x6bba, 0x6bbb, 0xd84e, 0xde8d, 0xd847, 0xdd0b, 0xd84e, 0xdefa, 0x6c4e,
+    0xd84f, 0xdcbc, 0x6cbf, 0x6ccd, 0x6c67, 0x6d16, 0x6d3e, 0x6d77, 0x6d41, 0x6d69, 0x6d78, 0x6d85,
+    0xd84f, 0xdd1e, 0x6d34, 0x6e2f, 0x6e6e, 0x3d33, 0x6ecb, 0x6ec7, 0xd84f, 0xded1, 0x6df9, 0x6f6e,
+    0xd84f, 0xdf5e, 0xd84f, 0xdf8e, 0x6fc6, 0x7039, 0x701e, 0x701b, 0x3d96, 0x704a, 0x707d, 0x7077,
+    0x70ad, 0xd841, 0xdd25, 0x7145, 0xd850, 0xde63, 0x719c, 0xd850, 0xdfab, 0x7228, 0x7235, 0x7250,
+    0xd851, 0xde08, 0x7280, 0x7295, 0xd851, 0xdf35, 0xd852, 0xdc14, 0x737a, 0x738b, 0x3eac, 0x73a5,
+    0x3eb8, 0x3eb8, 0x7447, 0x745c, 0x7471, 0x7485, 0x74ca, 0x3f1b, 0x7524, 0xd853, 0xdc36, 0x753e,
+    0xd853, 0xdc92, 0x7570, 0xd848, 0xdd9f, 0x7610, 0xd853, 0xdfa1, 0xd853, 0xdfb8, 0xd854, 0xdc44,
+    0x3ffc, 0x4008, 0x76f4, 0xd854, 0xdcf3, 0xd854, 0xdcf2, 0xd854, 0xdd19, 0xd854, 0xdd33, 0x771e,
+    0x771f, 0x771f, 0x774a, 0x4039, 0x778b, 0x4046, 0x4096, 0xd855, 0xdc1d, 0x784e, 0x788c, 0x78cc,
+    0x40e3, 0xd855, 0xde26, 0x7956, 0xd855, 0xde9a, 0xd855, 0xdec5, 0x798f, 0x79eb, 0x412f, 0x7a40,
+    0x7a4a, 0x7a4f, 0xd856, 0xdd7c, 0xd856, 0xdea7, 0xd856, 0xdea7, 0x7aee, 0x4202, 0xd856, 0xdfab,
+    0x7bc6, 0x7bc9, 0x4227, 0xd857, 0xdc80, 0x7cd2, 0x42a0, 0x7ce8, 0x7ce3, 0x7d00, 0xd857, 0xdf86,
+    0x7d63, 0x4301, 0x7dc7, 0x7e02, 0x7e45, 0x4334, 0xd858, 0xde28, 0xd858, 0xde47, 0x4359, 0xd858,
+    0xded9, 0x7f7a, 0xd858, 0xdf3e, 0x7f95, 0x7ffa, 0x8005, 0xd859, 0xdcda, 0xd859, 0xdd23, 0x8060,
+    0xd859, 0xdda8, 0x8070, 0xd84c, 0xdf5f, 0x43d5, 0x80b2, 0x8103, 0x440b, 0x813e, 0x5ab5, 0xd859,
+    0xdfa7, 0xd859, 0xdfb5, 0xd84c, 0xdf93, 0xd84c, 0xdf9c, 0x8201, 0x8204, 0x8f9e, 0x446b, 0x8291,
+    0x828b, 0x829d, 0x52b3, 0x82b1, 0x82b3, 0x82bd, 0x82e6, 0xd85a, 0xdf3c, 0x82e5, 0x831d, 0x8363,
+    0x83ad, 0x8323, 0x83bd, 0x83e7, 0x8457, 0x8353, 0x83ca, 0x83cc, 0x83dc, 0xd85b, 0xdc36, 0xd85b,
+    0xdd6b, 0xd85b, 0xdcd5, 0x452b, 0x84f1, 0x84f3, 0x8516, 0xd85c, 0xdfca, 0x8564, 0xd85b, 0xdf2c,
+    0x455d, 0x4561, 0xd85b, 0xdfb1, 0xd85c, 0xdcd2, 0x456b, 0x8650, 0x865c, 0x8667, 0x8669, 0x86a9,
+    0x8688, 0x870e, 0x86e2, 0x8779, 0x8728, 0x876b, 0x8786, 0x45d7, 0x87e1, 0x8801, 0x45f9, 0x8860,
+    0x8863, 0xd85d, 0xde67, 0x88d7, 0x88de, 0x4635, 0x88fa, 0x34bb, 0xd85e, 0xdcae, 0xd85e, 0xdd66,
+    0x46be, 0x46c7, 0x8aa0, 0x8aed, 0x8b8a, 0x8c55, 0xd85f, 0xdca8, 0x8cab, 0x8cc1, 0x8d1b, 0x8d77,
+    0xd85f, 0xdf2f, 0xd842, 0xdc04, 0x8dcb, 0x8dbc, 0x8df0, 0xd842, 0xdcde, 0x8ed4, 0x8f38, 0xd861,
+    0xddd2, 0xd861, 0xdded, 0x9094, 0x90f1, 0x9111, 0xd861, 0xdf2e, 0x911b, 0x9238, 0x92d7, 0x92d8,
+    0x927c, 0x93f9, 0x9415, 0xd862, 0xdffa, 0x958b, 0x4995, 0x95b7, 0xd863, 0xdd77, 0x49e6, 0x96c3,
+    0x5db2, 0x9723, 0xd864, 0xdd45, 0xd864, 0xde1a, 0x4a6e, 0x4a76, 0x97e0, 0xd865, 0xdc0a, 0x4ab2,
+    0xd865, 0xdc96, 0x980b, 0x980b, 0x9829, 0xd865, 0xddb6, 0x98e2, 0x4b33, 0x9929, 0x99a7, 0x99c2,
+    0x99fe, 0x4bce, 0xd866, 0xdf30, 0x9b12, 0x9c40, 0x9cfd, 0x4cce, 0x4ced, 0x9d67, 0xd868, 0xdcce,
+    0x4cf8, 0xd868, 0xdd05, 0xd868, 0xde0e, 0xd868, 0xde91, 0x9ebb, 0x4d56, 0x9ef9, 0x9efe, 0x9f05,
+    0x9f0f, 0x9f16, 0x9f3b, 0xd869, 0xde00,
+];
+
+static identifier_allowed_table: [[u32; 2]; 372] = [
+    [0x00027, 0x00027],
+    [0x0002d, 0x0002e],
+    [0x00030, 0x0003a],
+    [0x00041, 0x0005a],
+    [0x0005f, 0x0005f],
+    [0x00061, 0x0007a],
+    [0x000b7, 0x000b7],
+    [0x000c0, 0x000d6],
+    [0x000d8, 0x000f6],
+    [0x000f8, 0x00131],
+    [0x00134, 0x0013e],
+    [0x00141, 0x00148],
+    [0x0014a, 0x0017e],
+    [0x0018f, 0x0018f],
+    [0x001a0, 0x001a1],
+    [0x001af, 0x001b0],
+    [0x001cd, 0x001dc],
+    [0x001de, 0x001e3],
+    [0x001e6, 0x001f0],
+    [0x001f4, 0x001f5],
+    [0x001f8, 0x0021b],
+    [0x0021e, 0x0021f],
+    [0x00226, 0x00233],
+    [0x00259, 0x00259],
+    [0x002bb, 0x002bc],
+    [0x002ec, 0x002ec],
+    [0x00300, 0x00304],
+    [0x00306, 0x0030c],
+    [0x0030f, 0x00311],
+    [0x00313, 0x00314],
+    [0x0031b, 0x0031b],
+    [0x00323, 0x00328],
+    [0x0032d, 0x0032e],
+    [0x00330, 0x00331],
+    [0x00335, 0x00335],
+    [0x00338, 0x00339],
+    [0x00342, 0x00342],
+    [0x00345, 0x00345],
+    [0x00375, 0x00375],
+    [0x0037b, 0x0037d],
+    [0x00386, 0x00386],
+    [0x00388, 0x0038a],
+    [0x0038c, 0x0038c],
+    [0x0038e, 0x003a1],
+    [0x003a3, 0x003ce],
+    [0x003fc, 0x0045f],
+    [0x0048a, 0x004ff],
+    [0x00510, 0x00529],
+    [0x0052e, 0x0052f],
+    [0x00531, 0x00556],
+    [0x00559, 0x00559],
+    [0x00561, 0x00586],
+    [0x0058a, 0x0058a],
+    [0x005b4, 0x005b4],
+    [0x005d0, 0x005ea],
+    [0x005ef, 0x005f4],
+    [0x00620, 0x0063f],
+    [0x00641, 0x00655],
+    [0x00660, 0x00669],
+    [0x00670, 0x00672],
+    [0x00674, 0x00674],
+    [0x00679, 0x0068d],
+    [0x0068f, 0x006a0],
+    [0x006a2, 0x006d3],
+    [0x006d5, 0x006d5],
+    [0x006e5, 0x006e6],
+    [0x006ee, 0x006ff],
+    [0x00750, 0x007b1],
+    [0x008a0, 0x008ac],
+    [0x008b2, 0x008b2],
+    [0x008b6, 0x008c7],
+    [0x00901, 0x0094d],
+    [0x0094f, 0x00950],
+    [0x00956, 0x00957],
+    [0x00960, 0x00963],
+    [0x00966, 0x0096f],
+    [0x00971, 0x00977],
+    [0x00979, 0x0097f],
+    [0x00981, 0x00983],
+    [0x00985, 0x0098c],
+    [0x0098f, 0x00990],
+    [0x00993, 0x009a8],
+    [0x009aa, 0x009b0],
+    [0x009b2, 0x009b2],
+    [0x009b6, 0x009b9],
+    [0x009bc, 0x009c4],
+    [0x009c7, 0x009c8],
+    [0x009cb, 0x009ce],
+    [0x009d7, 0x009d7],
+    [0x009e0, 0x009e3],
+    [0x009e6, 0x009f1],
+    [0x009fe, 0x009fe],
+    [0x00a01, 0x00a03],
+    [0x00a05, 0x00a0a],
+    [0x00a0f, 0x00a10],
+    [0x00a13, 0x00a28],
+    [0x00a2a, 0x00a30],
+    [0x00a32, 0x00a32],
+    [0x00a35, 0x00a35],
+    [0x00a38, 0x00a39],
+    [0x00a3c, 0x00a3c],
+    [0x00a3e, 0x00a42],
+    [0x00a47, 0x00a48],
+    [0x00a4b, 0x00a4d],
+    [0x00a5c, 0x00a5c],
+    [0x00a66, 0x00a74],
+    [0x00a81, 0x00a83],
+    [0x00a85, 0x00a8d],
+    [0x00a8f, 0x00a91],
+    [0x00a93, 0x00aa8],
+    [0x00aaa, 0x00ab0],
+    [0x00ab2, 0x00ab3],
+    [0x00ab5, 0x00ab9],
+    [0x00abc, 0x00ac5],
+    [0x00ac7, 0x00ac9],
+    [0x00acb, 0x00acd],
+    [0x00ad0, 0x00ad0],
+    [0x00ae0, 0x00ae3],
+    [0x00ae6, 0x00aef],
+    [0x00afa, 0x00aff],
+    [0x00b01, 0x00b03],
+    [0x00b05, 0x00b0c],
+    [0x00b0f, 0x00b10],
+    [0x00b13, 0x00b28],
+    [0x00b2a, 0x00b30],
+    [0x00b32, 0x00b33],
+    [0x00b35, 0x00b39],
+    [0x00b3c, 0x00b43],
+    [0x00b47, 0x00b48],
+    [0x00b4b, 0x00b4d],
+    [0x00b55, 0x00b57],
+    [0x00b5f, 0x00b61],
+    [0x00b66, 0x00b6f],
+    [0x00b71, 0x00b71],
+    [0x00b82, 0x00b83],
+    [0x00b85, 0x00b8a],
+    [0x00b8e, 0x00b90],
+    [0x00b92, 0x00b95],
+    [0x00b99, 0x00b9a],
+    [0x00b9c, 0x00b9c],
+    [0x00b9e, 0x00b9f],
+    [0x00ba3, 0x00ba4],
+    [0x00ba8, 0x00baa],
+    [0x00bae, 0x00bb9],
+    [0x00bbe, 0x00bc2],
+    [0x00bc6, 0x00bc8],
+    [0x00bca, 0x00bcd],
+    [0x00bd0, 0x00bd0],
+    [0x00bd7, 0x00bd7],
+    [0x00be6, 0x00bef],
+    [0x00c01, 0x00c0c],
+    [0x00c0e, 0x00c10],
+    [0x00c12, 0x00c28],
+    [0x00c2a, 0x00c33],
+    [0x00c35, 0x00c39],
+    [0x00c3d, 0x00c44],
+    [0x00c46, 0x00c48],
+    [0x00c4a, 0x00c4d],
+    [0x00c55, 0x00c56],
+    [0x00c60, 0x00c61],
+    [0x00c66, 0x00c6f],
+    [0x00c80, 0x00c80],
+    [0x00c82, 0x00c83],
+    [0x00c85, 0x00c8c],
+    [0x00c8e, 0x00c90],
+    [0x00c92, 0x00ca8],
+    [0x00caa, 0x00cb3],
+    [0x00cb5, 0x00cb9],
+    [0x00cbc, 0x00cc4],
+    [0x00cc6, 0x00cc8],
+    [0x00cca, 0x00ccd],
+    [0x00cd5, 0x00cd6],
+    [0x00ce0, 0x00ce3],
+    [0x00ce6, 0x00cef],
+    [0x00cf1, 0x00cf2],
+    [0x00d00, 0x00d00],
+    [0x00d02, 0x00d03],
+    [0x00d05, 0x00d0c],
+    [0x00d0e, 0x00d10],
+    [0x00d12, 0x00d3a],
+    [0x00d3d, 0x00d43],
+    [0x00d46, 0x00d48],
+    [0x00d4a, 0x00d4e],
+    [0x00d54, 0x00d57],
+    [0x00d60, 0x00d61],
+    [0x00d66, 0x00d6f],
+    [0x00d7a, 0x00d7f],
+    [0x00d82, 0x00d83],
+    [0x00d85, 0x00d8e],
+    [0x00d91, 0x00d96],
+    [0x00d9a, 0x00da5],
+    [0x00da7, 0x00db1],
+    [0x00db3, 0x00dbb],
+    [0x00dbd, 0x00dbd],
+    [0x00dc0, 0x00dc6],
+    [0x00dca, 0x00dca],
+    [0x00dcf, 0x00dd4],
+    [0x00dd6, 0x00dd6],
+    [0x00dd8, 0x00dde],
+    [0x00df2, 0x00df2],
+    [0x00e01, 0x00e32],
+    [0x00e34, 0x00e3a],
+    [0x00e40, 0x00e4e],
+    [0x00e50, 0x00e59],
+    [0x00e81, 0x00e82],
+    [0x00e84, 0x00e84],
+    [0x00e86, 0x00e8a],
+    [0x00e8c, 0x00ea3],
+    [0x00ea5, 0x00ea5],
+    [0x00ea7, 0x00eb2],
+    [0x00eb4, 0x00ebd],
+    [0x00ec0, 0x00ec4],
+    [0x00ec6, 0x00ec6],
+    [0x00ec8, 0x00ecd],
+    [0x00ed0, 0x00ed9],
+    [0x00ede, 0x00edf],
+    [0x00f00, 0x00f00],
+    [0x00f0b, 0x00f0b],
+    [0x00f20, 0x00f29],
+    [0x00f35, 0x00f35],
+    [0x00f37, 0x00f37],
+    [0x00f3e, 0x00f42],
+    [0x00f44, 0x00f47],
+    [0x00f49, 0x00f4c],
+    [0x00f4e, 0x00f51],
+    [0x00f53, 0x00f56],
+    [0x00f58, 0x00f5b],
+    [0x00f5d, 0x00f68],
+    [0x00f6a, 0x00f6c],
+    [0x00f71, 0x00f72],
+    [0x00f74, 0x00f74],
+    [0x00f7a, 0x00f80],
+    [0x00f82, 0x00f84],
+    [0x00f86, 0x00f92],
+    [0x00f94, 0x00f97],
+    [0x00f99, 0x00f9c],
+    [0x00f9e, 0x00fa1],
+    [0x00fa3, 0x00fa6],
+    [0x00fa8, 0x00fab],
+    [0x00fad, 0x00fb8],
+    [0x00fba, 0x00fbc],
+    [0x00fc6, 0x00fc6],
+    [0x01000, 0x01049],
+    [0x01050, 0x0109d],
+    [0x010c7, 0x010c7],
+    [0x010cd, 0x010cd],
+    [0x010d0, 0x010f0],
+    [0x010f7, 0x010fa],
+    [0x010fd, 0x010ff],
+    [0x01200, 0x01248],
+    [0x0124a, 0x0124d],
+    [0x01250, 0x01256],
+    [0x01258, 0x01258],
+    [0x0125a, 0x0125d],
+    [0x01260, 0x01288],
+    [0x0128a, 0x0128d],
+    [0x01290, 0x012b0],
+    [0x012b2, 0x012b5],
+    [0x012b8, 0x012be],
+    [0x012c0, 0x012c0],
+    [0x012c2, 0x012c5],
+    [0x012c8, 0x012d6],
+    [0x012d8, 0x01310],
+    [0x01312, 0x01315],
+    [0x01318, 0x0135a],
+    [0x0135d, 0x0135f],
+    [0x01380, 0x0138f],
+    [0x01780, 0x017a2],
+    [0x017a5, 0x017a7],
+    [0x017a9, 0x017b3],
+    [0x017b6, 0x017cd],
+    [0x017d0, 0x017d0],
+    [0x017d2, 0x017d2],
+    [0x017d7, 0x017d7],
+    [0x017dc, 0x017dc],
+    [0x017e0, 0x017e9],
+    [0x01c90, 0x01cba],
+    [0x01cbd, 0x01cbf],
+    [0x01e00, 0x01e99],
+    [0x01e9e, 0x01e9e],
+    [0x01ea0, 0x01ef9],
+    [0x01f00, 0x01f15],
+    [0x01f18, 0x01f1d],
+    [0x01f20, 0x01f45],
+    [0x01f48, 0x01f4d],
+    [0x01f50, 0x01f57],
+    [0x01f59, 0x01f59],
+    [0x01f5b, 0x01f5b],
+    [0x01f5d, 0x01f5d],
+    [0x01f5f, 0x01f70],
+    [0x01f72, 0x01f72],
+    [0x01f74, 0x01f74],
+    [0x01f76, 0x01f76],
+    [0x01f78, 0x01f78],
+    [0x01f7a, 0x01f7a],
+    [0x01f7c, 0x01f7c],
+    [0x01f80, 0x01fb4],
+    [0x01fb6, 0x01fba],
+    [0x01fbc, 0x01fbc],
+    [0x01fc2, 0x01fc4],
+    [0x01fc6, 0x01fc8],
+    [0x01fca, 0x01fca],
+    [0x01fcc, 0x01fcc],
+    [0x01fd0, 0x01fd2],
+    [0x01fd6, 0x01fda],
+    [0x01fe0, 0x01fe2],
+    [0x01fe4, 0x01fea],
+    [0x01fec, 0x01fec],
+    [0x01ff2, 0x01ff4],
+    [0x01ff6, 0x01ff8],
+    [0x01ffa, 0x01ffa],
+    [0x01ffc, 0x01ffc],
+    [0x02010, 0x02010],
+    [0x02019, 0x02019],
+    [0x02027, 0x02027],
+    [0x02d27, 0x02d27],
+    [0x02d2d, 0x02d2d],
+    [0x02d80, 0x02d96],
+    [0x02da0, 0x02da6],
+    [0x02da8, 0x02dae],
+    [0x02db0, 0x02db6],
+    [0x02db8, 0x02dbe],
+    [0x02dc0, 0x02dc6],
+    [0x02dc8, 0x02dce],
+    [0x02dd0, 0x02dd6],
+    [0x02dd8, 0x02dde],
+    [0x03005, 0x03007],
+    [0x03041, 0x03096],
+    [0x03099, 0x0309a],
+    [0x0309d, 0x0309e],
+    [0x030a0, 0x030fe],
+    [0x03105, 0x0312d],
+    [0x0312f, 0x0312f],
+    [0x031a0, 0x031bf],
+    [0x03400, 0x04dbf],
+    [0x04e00, 0x09ffc],
+    [0x0a67f, 0x0a67f],
+    [0x0a717, 0x0a71f],
+    [0x0a788, 0x0a788],
+    [0x0a78d, 0x0a78d],
+    [0x0a792, 0x0a793],
+    [0x0a7aa, 0x0a7aa],
+    [0x0a7c2, 0x0a7ca],
+    [0x0a9e7, 0x0a9fe],
+    [0x0aa60, 0x0aa76],
+    [0x0aa7a, 0x0aa7f],
+    [0x0ab01, 0x0ab06],
+    [0x0ab09, 0x0ab0e],
+    [0x0ab11, 0x0ab16],
+    [0x0ab20, 0x0ab26],
+    [0x0ab28, 0x0ab2e],
+    [0x0ab66, 0x0ab67],
+    [0x0ac00, 0x0d7a3],
+    [0x0fa0e, 0x0fa0f],
+    [0x0fa11, 0x0fa11],
+    [0x0fa13, 0x0fa14],
+    [0x0fa1f, 0x0fa1f],
+    [0x0fa21, 0x0fa21],
+    [0x0fa23, 0x0fa24],
+    [0x0fa27, 0x0fa29],
+    [0x11301, 0x11301],
+    [0x11303, 0x11303],
+    [0x1133b, 0x1133c],
+    [0x16ff0, 0x16ff1],
+    [0x1b150, 0x1b152],
+    [0x1b164, 0x1b167],
+    [0x20000, 0x2a6dd],
+    [0x2a700, 0x2b734],
+    [0x2b740, 0x2b81d],
+    [0x2b820, 0x2cea1],
+    [0x2ceb0, 0x2ebe0],
+    [0x30000, 0x3134a],
+];
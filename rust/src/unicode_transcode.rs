@@ -0,0 +1,419 @@
+/*
+ * Bulk conversions between UTF-8, UTF-16 (LE/BE), Latin-1 and WTF-8
+ *
+ * JS strings are sequences of UTF-16 code units which may contain
+ * lone surrogates. The conversions to and from the other encodings
+ * take a policy telling what to do with them: fail, replace them by
+ * U+FFFD or preserve them with the WTF-8 encoding. The ASCII runs are
+ * converted one 64 bit word at a time.
+ */
+
+pub type SurrogatePolicyEnum = u32;
+/* fail on the lone surrogates */
+pub const SURROGATE_ERROR: SurrogatePolicyEnum = 0;
+/* replace the lone surrogates by U+FFFD */
+pub const SURROGATE_REPLACE: SurrogatePolicyEnum = 1;
+/* keep the lone surrogates (WTF-8 encoding) */
+pub const SURROGATE_PRESERVE: SurrogatePolicyEnum = 2;
+
+const REPLACEMENT_CHAR: u32 = 0xfffd;
+
+/// Reason why a conversion failed. The offsets are counted in units
+/// of the source (bytes or UTF-16 code units).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TranscodeError {
+    /// Invalid or truncated UTF-8 sequence.
+    InvalidUtf8(usize),
+    /// Lone surrogate with the `SURROGATE_ERROR` policy.
+    LoneSurrogate(usize),
+    /// Character which cannot be represented in Latin-1.
+    Unrepresentable(usize),
+    /// The UTF-16 byte stream has an odd length.
+    TruncatedInput(usize),
+}
+
+const ASCII_MASK8: u64 = 0x8080_8080_8080_8080;
+const ASCII_MASK16: u64 = 0xff80_ff80_ff80_ff80;
+
+/* return the length of the ASCII prefix of 'src', in 8 byte steps */
+fn ascii_prefix_len(src: &[u8]) -> usize {
+    let mut pos = 0;
+    for chunk in src.chunks_exact(8) {
+        let mut word = [0; 8];
+        word.copy_from_slice(chunk);
+        if u64::from_ne_bytes(word) & ASCII_MASK8 != 0 {
+            break;
+        }
+        pos += 8;
+    }
+    pos
+}
+
+/* return the length of the ASCII prefix of 'src', in 4 code unit
+steps */
+fn ascii_prefix_len16(src: &[u16]) -> usize {
+    let mut pos = 0;
+    for chunk in src.chunks_exact(4) {
+        let word = (chunk[0] as u64)
+            | (chunk[1] as u64) << 16
+            | (chunk[2] as u64) << 32
+            | (chunk[3] as u64) << 48;
+        if word & ASCII_MASK16 != 0 {
+            break;
+        }
+        pos += 4;
+    }
+    pos
+}
+
+#[inline]
+fn is_surrogate(c: u32) -> bool {
+    (0xd800..=0xdfff).contains(&c)
+}
+
+#[inline]
+fn is_lead_surrogate(c: u32) -> bool {
+    (0xd800..=0xdbff).contains(&c)
+}
+
+/* Decode the UTF-8 sequence at the start of 'src'. The encoded
+surrogates are accepted (WTF-8). Return the code point and the
+length of the sequence, or the length of the maximal invalid subpart
+(Unicode 3.9, U+FFFD substitution). */
+fn decode_wtf8(src: &[u8]) -> Result<(u32, usize), usize> {
+    let b0 = src[0] as u32;
+    if b0 < 0x80 {
+        return Ok((b0, 1));
+    }
+    let (len, min, max) = match b0 {
+        0xc2..=0xdf => (2, 0x80, 0xbf),
+        0xe0 => (3, 0xa0, 0xbf),
+        0xe1..=0xef => (3, 0x80, 0xbf),
+        0xf0 => (4, 0x90, 0xbf),
+        0xf1..=0xf3 => (4, 0x80, 0xbf),
+        0xf4 => (4, 0x80, 0x8f),
+        _ => return Err(1),
+    };
+    let mut c = b0 & (0x7f >> len);
+    for i in 1..len {
+        let b = match src.get(i) {
+            Some(&b) => b as u32,
+            None => return Err(i),
+        };
+        let (lo, hi) = if i == 1 { (min, max) } else { (0x80, 0xbf) };
+        if b < lo || b > hi {
+            return Err(i);
+        }
+        c = (c << 6) | (b & 0x3f);
+    }
+    Ok((c, len))
+}
+
+fn push_utf16(dst: &mut Vec<u16>, c: u32) {
+    if c < 0x10000 {
+        dst.push(c as u16);
+    } else {
+        let c = c - 0x10000;
+        dst.push((0xd800 | (c >> 10)) as u16);
+        dst.push((0xdc00 | (c & 0x3ff)) as u16);
+    }
+}
+
+/// Decode the UTF-8 or WTF-8 sequence at the start of `src`. Return
+/// the code point and the length of the sequence, or `None` if it is
+/// invalid, truncated or if `src` is empty.
+pub fn decode_wtf8_char(src: &[u8]) -> Option<(u32, usize)> {
+    if src.is_empty() {
+        return None;
+    }
+    decode_wtf8(src).ok()
+}
+
+/// Encode `c` in UTF-8 at the start of `buf`, the surrogates being
+/// encoded as in WTF-8. Return the number of bytes, or 0 if `c` is
+/// above U+10FFFF. Panics if `buf` is too short.
+pub fn encode_wtf8_char(buf: &mut [u8], c: u32) -> usize {
+    if c < 0x80 {
+        buf[0] = c as u8;
+        1
+    } else if c < 0x800 {
+        buf[..2].copy_from_slice(&[(0xc0 | (c >> 6)) as u8, (0x80 | (c & 0x3f)) as u8]);
+        2
+    } else if c < 0x10000 {
+        buf[..3].copy_from_slice(&[
+            (0xe0 | (c >> 12)) as u8,
+            (0x80 | ((c >> 6) & 0x3f)) as u8,
+            (0x80 | (c & 0x3f)) as u8,
+        ]);
+        3
+    } else if c <= 0x10ffff {
+        buf[..4].copy_from_slice(&[
+            (0xf0 | (c >> 18)) as u8,
+            (0x80 | ((c >> 12) & 0x3f)) as u8,
+            (0x80 | ((c >> 6) & 0x3f)) as u8,
+            (0x80 | (c & 0x3f)) as u8,
+        ]);
+        4
+    } else {
+        0
+    }
+}
+
+/* encode 'c' in UTF-8. The surrogates are encoded as in WTF-8. */
+fn push_utf8(dst: &mut Vec<u8>, c: u32) {
+    let mut buf = [0; 4];
+    let len = encode_wtf8_char(&mut buf, c);
+    dst.extend_from_slice(&buf[..len]);
+}
+
+/* Decode the WTF-8 sequences of 'src' starting at 'pos' and call
+'put' for each code point until an ASCII character is found. The
+encoded surrogates are handled with 'policy'. A trail surrogate
+following a lead surrogate is an invalid sequence because the pair
+should have been encoded as a single code point. The invalid sequences
+are errors if 'is_strict' is true and are replaced by U+FFFD
+otherwise. Return the position after the last decoded sequence. */
+fn decode_wtf8_run(
+    src: &[u8],
+    mut pos: usize,
+    policy: SurrogatePolicyEnum,
+    is_strict: bool,
+    mut put: impl FnMut(u32),
+) -> Result<usize, TranscodeError> {
+    let mut is_after_lead = false;
+    while pos < src.len() {
+        let r = match decode_wtf8(&src[pos..]) {
+            Ok((c, len)) if is_after_lead && is_surrogate(c) && !is_lead_surrogate(c) => Err(len),
+            r => r,
+        };
+        let (c, len) = match r {
+            Ok((c, len)) if is_surrogate(c) => {
+                is_after_lead = is_lead_surrogate(c);
+                match policy {
+                    SURROGATE_ERROR => return Err(TranscodeError::LoneSurrogate(pos)),
+                    SURROGATE_REPLACE => (REPLACEMENT_CHAR, len),
+                    _ => (c, len),
+                }
+            }
+            Ok(r) => {
+                is_after_lead = false;
+                r
+            }
+            Err(_) if is_strict => return Err(TranscodeError::InvalidUtf8(pos)),
+            Err(len) => {
+                is_after_lead = false;
+                (REPLACEMENT_CHAR, len)
+            }
+        };
+        put(c);
+        pos += len;
+        /* go back to the fast path after an ASCII character */
+        if c < 0x80 {
+            break;
+        }
+    }
+    Ok(pos)
+}
+
+/* Convert UTF-8 or WTF-8 to UTF-16. The invalid sequences are
+errors if 'is_strict' is true and are replaced by U+FFFD otherwise. */
+fn wtf8_to_utf16_1(
+    src: &[u8],
+    policy: SurrogatePolicyEnum,
+    is_strict: bool,
+) -> Result<Vec<u16>, TranscodeError> {
+    let mut dst = Vec::with_capacity(src.len());
+    let mut pos = 0;
+    while pos < src.len() {
+        let n = ascii_prefix_len(&src[pos..]);
+        dst.extend(src[pos..pos + n].iter().map(|&b| b as u16));
+        pos += n;
+        pos = decode_wtf8_run(src, pos, policy, is_strict, |c| push_utf16(&mut dst, c))?;
+    }
+    Ok(dst)
+}
+
+/// Convert UTF-8 to UTF-16. The invalid sequences are replaced by
+/// U+FFFD unless `policy` is `SURROGATE_ERROR`. The encoded
+/// surrogates (WTF-8) are handled with `policy`.
+pub fn utf8_to_utf16(src: &[u8], policy: SurrogatePolicyEnum) -> Result<Vec<u16>, TranscodeError> {
+    wtf8_to_utf16_1(src, policy, policy == SURROGATE_ERROR)
+}
+
+/// Convert UTF-16 to UTF-8. With `SURROGATE_PRESERVE`, the result is
+/// WTF-8 and is not valid UTF-8 if the source has lone surrogates.
+pub fn utf16_to_utf8(src: &[u16], policy: SurrogatePolicyEnum) -> Result<Vec<u8>, TranscodeError> {
+    let mut dst = Vec::with_capacity(src.len() + src.len() / 2);
+    let mut pos = 0;
+    while pos < src.len() {
+        let n = ascii_prefix_len16(&src[pos..]);
+        dst.extend(src[pos..pos + n].iter().map(|&c| c as u8));
+        pos += n;
+        while pos < src.len() {
+            let mut c = src[pos] as u32;
+            pos += 1;
+            if is_surrogate(c) {
+                let next = src.get(pos).map_or(0, |&c| c as u32);
+                if c < 0xdc00 && (0xdc00..=0xdfff).contains(&next) {
+                    c = 0x10000 + ((c - 0xd800) << 10) + (next - 0xdc00);
+                    pos += 1;
+                } else {
+                    match policy {
+                        SURROGATE_ERROR => return Err(TranscodeError::LoneSurrogate(pos - 1)),
+                        SURROGATE_REPLACE => c = REPLACEMENT_CHAR,
+                        _ => {}
+                    }
+                }
+            }
+            push_utf8(&mut dst, c);
+            if c < 0x80 {
+                break;
+            }
+        }
+    }
+    Ok(dst)
+}
+
+/// Convert UTF-16 to WTF-8. The lone surrogates are preserved.
+pub fn utf16_to_wtf8(src: &[u16]) -> Vec<u8> {
+    utf16_to_utf8(src, SURROGATE_PRESERVE).unwrap()
+}
+
+/// Convert WTF-8 to UTF-16. The encoded surrogates are converted to
+/// lone surrogates. An encoded lead surrogate followed by an encoded
+/// trail surrogate is invalid, as in WTF-8 the pairs are encoded as a
+/// single code point.
+pub fn wtf8_to_utf16(src: &[u8]) -> Result<Vec<u16>, TranscodeError> {
+    wtf8_to_utf16_1(src, SURROGATE_PRESERVE, true)
+}
+
+/* Convert WTF-8 to UTF-8: the encoded surrogates are handled with
+'policy', which must not be SURROGATE_PRESERVE. */
+fn wtf8_to_utf8_1(
+    src: &[u8],
+    policy: SurrogatePolicyEnum,
+    is_strict: bool,
+) -> Result<String, TranscodeError> {
+    /* usual case: no surrogates nor invalid sequences */
+    if let Ok(s) = std::str::from_utf8(src) {
+        return Ok(s.to_string());
+    }
+    let mut dst = Vec::with_capacity(src.len());
+    let mut pos = 0;
+    while pos < src.len() {
+        let n = ascii_prefix_len(&src[pos..]);
+        dst.extend_from_slice(&src[pos..pos + n]);
+        pos += n;
+        pos = decode_wtf8_run(src, pos, policy, is_strict, |c| push_utf8(&mut dst, c))?;
+    }
+    /* the surrogates were replaced or are errors */
+    Ok(unsafe { String::from_utf8_unchecked(dst) })
+}
+
+/// Convert WTF-8 to a string. The encoded surrogates are errors.
+pub fn wtf8_to_utf8(src: &[u8]) -> Result<String, TranscodeError> {
+    wtf8_to_utf8_1(src, SURROGATE_ERROR, true)
+}
+
+/// Convert WTF-8 to a string. The encoded surrogates and the invalid
+/// sequences are replaced by U+FFFD.
+pub fn wtf8_to_utf8_lossy(src: &[u8]) -> String {
+    wtf8_to_utf8_1(src, SURROGATE_REPLACE, false).unwrap()
+}
+
+/// Convert UTF-16 to a string, applying `policy` to the lone
+/// surrogates. `SURROGATE_PRESERVE` is not possible and behaves as
+/// `SURROGATE_REPLACE`.
+pub fn utf16_to_string(src: &[u16], policy: SurrogatePolicyEnum) -> Result<String, TranscodeError> {
+    let policy = if policy == SURROGATE_ERROR {
+        SURROGATE_ERROR
+    } else {
+        SURROGATE_REPLACE
+    };
+    let buf = utf16_to_utf8(src, policy)?;
+    /* the result is valid UTF-8 because there are no lone surrogates */
+    Ok(unsafe { String::from_utf8_unchecked(buf) })
+}
+
+/// Decode UTF-16 code units from bytes in little or big endian order.
+pub fn utf16_from_bytes(src: &[u8], big_endian: bool) -> Result<Vec<u16>, TranscodeError> {
+    if src.len() & 1 != 0 {
+        return Err(TranscodeError::TruncatedInput(src.len() - 1));
+    }
+    let dst = src
+        .chunks_exact(2)
+        .map(|b| {
+            if big_endian {
+                u16::from_be_bytes([b[0], b[1]])
+            } else {
+                u16::from_le_bytes([b[0], b[1]])
+            }
+        })
+        .collect();
+    Ok(dst)
+}
+
+/// Encode UTF-16 code units to bytes in little or big endian order.
+pub fn utf16_to_bytes(src: &[u16], big_endian: bool) -> Vec<u8> {
+    let mut dst = Vec::with_capacity(src.len() * 2);
+    for &c in src {
+        if big_endian {
+            dst.extend_from_slice(&c.to_be_bytes());
+        } else {
+            dst.extend_from_slice(&c.to_le_bytes());
+        }
+    }
+    dst
+}
+
+/// Convert Latin-1 to UTF-8.
+pub fn latin1_to_utf8(src: &[u8]) -> String {
+    let mut dst = Vec::with_capacity(src.len());
+    let mut pos = 0;
+    while pos < src.len() {
+        let n = ascii_prefix_len(&src[pos..]);
+        dst.extend_from_slice(&src[pos..pos + n]);
+        pos += n;
+        while pos < src.len() {
+            let c = src[pos] as u32;
+            pos += 1;
+            push_utf8(&mut dst, c);
+            if c < 0x80 {
+                break;
+            }
+        }
+    }
+    /* Latin-1 characters are always valid */
+    unsafe { String::from_utf8_unchecked(dst) }
+}
+
+/// Convert Latin-1 to UTF-16.
+pub fn latin1_to_utf16(src: &[u8]) -> Vec<u16> {
+    src.iter().map(|&b| b as u16).collect()
+}
+
+/// Return true if all the code units of `src` are Latin-1 characters,
+/// so that the string can be stored with 8 bit characters.
+pub fn utf16_is_latin1(src: &[u16]) -> bool {
+    src.iter().fold(0, |acc, &c| acc | c) < 0x100
+}
+
+/// Convert UTF-16 to Latin-1.
+pub fn utf16_to_latin1(src: &[u16]) -> Result<Vec<u8>, TranscodeError> {
+    match src.iter().position(|&c| c >= 0x100) {
+        Some(pos) => Err(TranscodeError::Unrepresentable(pos)),
+        None => Ok(src.iter().map(|&c| c as u8).collect()),
+    }
+}
+
+/// Convert UTF-8 to Latin-1.
+pub fn utf8_to_latin1(src: &str) -> Result<Vec<u8>, TranscodeError> {
+    let mut dst = Vec::with_capacity(src.len());
+    for (pos, ch) in src.char_indices() {
+        if ch as u32 >= 0x100 {
+            return Err(TranscodeError::Unrepresentable(pos));
+        }
+        dst.push(ch as u8);
+    }
+    Ok(dst)
+}
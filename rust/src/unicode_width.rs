@@ -0,0 +1,146 @@
+/*
+ * Unicode display width
+ *
+ * The width of a character in a terminal cell grid is derived from
+ * its East_Asian_Width property (UAX #11): Wide and Fullwidth
+ * characters take two cells, Ambiguous characters take one cell
+ * except in CJK contexts. Combining marks, format characters and
+ * default ignorable code points take no cell.
+ */
+use crate::libunicode::{
+    lre_get_table_value, lre_is_in_prop, lre_is_in_table, UNICODE_PROP_Emoji,
+    UNICODE_PROP_Emoji_Modifier, UNICODE_PROP_Emoji_Modifier_Base, UNICODE_PROP_Emoji_Presentation,
+    UNICODE_PROP_Extended_Pictographic,
+};
+
+const ZWJ: u32 = 0x200d;
+const VS15: u32 = 0xfe0e;
+const VS16: u32 = 0xfe0f;
+
+/* Tables generated by unicode_gen from the Unicode 13.0.0 data
+(EastAsianWidth.txt, UnicodeData.txt, PropList.txt and
+DerivedCoreProperties.txt). Unassigned code points use the default
+values of EastAsianWidth.txt.
+
+unicode_eaw_table uses the same encoding as unicode_gc_table.
+unicode_prop_Zero_Width_table contains the characters of general
+category Mn, Me and Cf (except the prepended concatenation marks
+and U+00AD), the Hangul medial vowels and final consonants and the
+default ignorable code points. */
+include!("unicode_width_table.rs");
+
+/// Return the East_Asian_Width property of `c`.
+pub fn unicode_east_asian_width(c: u32) -> UnicodeEAWEnum {
+    unsafe {
+        lre_get_table_value(
+            c,
+            unicode_eaw_table.as_ptr(),
+            unicode_eaw_index.as_ptr(),
+            (unicode_eaw_index.len() / 3) as i32,
+        )
+    }
+}
+
+fn is_zero_width(c: u32) -> bool {
+    unsafe {
+        lre_is_in_table(
+            c,
+            unicode_prop_Zero_Width_table.as_ptr(),
+            unicode_prop_Zero_Width_index.as_ptr(),
+            (unicode_prop_Zero_Width_index.len() / 3) as i32,
+        ) != 0
+    }
+}
+
+fn has_prop(c: u32, prop_idx: u32) -> bool {
+    unsafe { lre_is_in_prop(c, prop_idx as i32) != 0 }
+}
+
+/// Return the number of terminal cells used by `c` taken in
+/// isolation, or `None` for the control characters. If `is_cjk` is
+/// true, the characters of ambiguous width are considered wide.
+pub fn unicode_char_width(c: u32, is_cjk: bool) -> Option<usize> {
+    if c == 0 {
+        return Some(0);
+    }
+    if c < 0x20 || (0x7f..0xa0).contains(&c) {
+        return None;
+    }
+    if c < 0x7f {
+        return Some(1);
+    }
+    if is_zero_width(c) {
+        return Some(0);
+    }
+    match unicode_east_asian_width(c) {
+        UNICODE_EAW_W | UNICODE_EAW_F => Some(2),
+        UNICODE_EAW_A if is_cjk => Some(2),
+        _ => Some(1),
+    }
+}
+
+fn str_width(s: &str, is_cjk: bool) -> usize {
+    let mut width = 0;
+    /* last character which used at least one cell */
+    let mut base: u32 = 0;
+    let mut base_width = 0;
+    let mut is_joined = false;
+
+    for ch in s.chars() {
+        let c = ch as u32;
+        if c == ZWJ {
+            is_joined = base != 0 && has_prop(base, UNICODE_PROP_Extended_Pictographic);
+            continue;
+        }
+        if is_joined {
+            is_joined = false;
+            if has_prop(c, UNICODE_PROP_Extended_Pictographic) {
+                /* emoji ZWJ sequence: rendered as a single glyph */
+                base = c;
+                continue;
+            }
+        }
+        if base != 0 {
+            match c {
+                VS16 if base_width == 1 && has_prop(base, UNICODE_PROP_Emoji) => {
+                    /* emoji presentation */
+                    width += 1;
+                    base_width = 2;
+                    continue;
+                }
+                VS15 if base_width == 2 && has_prop(base, UNICODE_PROP_Emoji_Presentation) => {
+                    /* text presentation */
+                    width -= 1;
+                    base_width = 1;
+                    continue;
+                }
+                _ => {}
+            }
+            if has_prop(c, UNICODE_PROP_Emoji_Modifier)
+                && has_prop(base, UNICODE_PROP_Emoji_Modifier_Base)
+            {
+                continue;
+            }
+        }
+        let w = unicode_char_width(c, is_cjk).unwrap_or(0);
+        if w != 0 {
+            base = c;
+            base_width = w;
+            width += w;
+        }
+    }
+    width
+}
+
+/// Return the number of terminal cells used to display `s`. Control
+/// characters are ignored, combining sequences and emoji sequences
+/// are counted as a single glyph.
+pub fn display_width(s: &str) -> usize {
+    str_width(s, false)
+}
+
+/// Same as `display_width` but the characters of ambiguous width are
+/// considered wide, as in CJK terminals.
+pub fn display_width_cjk(s: &str) -> usize {
+    str_width(s, true)
+}
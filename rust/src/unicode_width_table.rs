@@ -0,0 +1,121 @@
+/* Compressed unicode tables */
+/* Automatically generated file - do not edit */
+
+pub type UnicodeEAWEnum = u32;
+pub const UNICODE_EAW_N: UnicodeEAWEnum = 0;
+pub const UNICODE_EAW_A: UnicodeEAWEnum = 1;
+pub const UNICODE_EAW_H: UnicodeEAWEnum = 2;
+pub const UNICODE_EAW_F: UnicodeEAWEnum = 3;
+pub const UNICODE_EAW_Na: UnicodeEAWEnum = 4;
+pub const UNICODE_EAW_W: UnicodeEAWEnum = 5;
+pub const UNICODE_EAW_COUNT: UnicodeEAWEnum = 6;
+
+static unicode_eaw_table: [u8; 825] = [
+    0xe0, 0x18, 0xe4, 0x57, 0xe0, 0x1a, 0x01, 0x24, 0x01, 0x24, 0x21, 0x00, 0x01, 0x00, 0x04, 0x21,
+    0x04, 0x81, 0x00, 0x81, 0x00, 0x61, 0xa0, 0x01, 0xe0, 0x01, 0x01, 0xa0, 0x21, 0x80, 0x61, 0x60,
+    0x01, 0x00, 0x41, 0x00, 0x21, 0x20, 0x01, 0x00, 0x21, 0x40, 0x61, 0x00, 0x01, 0x00, 0x01, 0x20,
+    0x01, 0xe0, 0x07, 0x01, 0x00, 0x01, 0xc0, 0x01, 0xe0, 0x02, 0x21, 0x40, 0x01, 0x80, 0x41, 0x60,
+    0x01, 0xa0, 0x61, 0x00, 0x01, 0x40, 0x61, 0x00, 0x01, 0x60, 0x21, 0xe0, 0x0a, 0x21, 0x40, 0x01,
+    0xe0, 0x5a, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    0x01, 0xe0, 0x6c, 0x01, 0xe0, 0x07, 0x01, 0xe0, 0x5a, 0x01, 0x20, 0x01, 0x00, 0x41, 0x00, 0x01,
+    0x20, 0x01, 0xc0, 0x61, 0x00, 0x01, 0x00, 0x01, 0xe0, 0x18, 0xe1, 0x68, 0xe0, 0x19, 0xe1, 0x09,
+    0x00, 0xc1, 0xc0, 0xe1, 0x09, 0x00, 0xc1, 0xe0, 0x2f, 0x01, 0xe0, 0x06, 0xe1, 0x38, 0x00, 0x01,
+    0xe0, 0x8c, 0x26, 0xe5, 0x58, 0xe0, 0x8e, 0x28, 0x01, 0x20, 0x61, 0x00, 0x21, 0x20, 0x21, 0x20,
+    0x41, 0x00, 0x61, 0xe0, 0x00, 0x01, 0x00, 0x21, 0x00, 0x01, 0x80, 0x01, 0x20, 0x01, 0xe0, 0x2d,
+    0x01, 0xe0, 0x02, 0x01, 0x00, 0x61, 0xe0, 0x1c, 0x02, 0x20, 0x01, 0xe0, 0x4e, 0x01, 0x00, 0x01,
+    0x40, 0x01, 0xe0, 0x01, 0x01, 0x20, 0x01, 0xe0, 0x02, 0x21, 0x40, 0x01, 0x60, 0x01, 0xe0, 0x1f,
+    0x21, 0xa0, 0x61, 0x00, 0xe1, 0x04, 0x60, 0xe1, 0x02, 0xe0, 0x07, 0x01, 0xa0, 0xe1, 0x02, 0xe0,
+    0x16, 0x21, 0xe0, 0x10, 0x01, 0x00, 0x01, 0xe0, 0x0a, 0x01, 0xe0, 0x10, 0x01, 0x00, 0x21, 0x40,
+    0x21, 0x20, 0x01, 0x40, 0x01, 0x00, 0x01, 0x40, 0x01, 0x60, 0x01, 0x20, 0x61, 0x20, 0x01, 0x00,
+    0x01, 0x00, 0xa1, 0x00, 0x01, 0x80, 0x61, 0x60, 0x21, 0xe0, 0x02, 0x01, 0x40, 0x01, 0x80, 0x01,
+    0xe0, 0x05, 0x21, 0x20, 0x61, 0x20, 0x21, 0x20, 0x21, 0xe0, 0x0a, 0x21, 0x20, 0x21, 0xe0, 0x05,
+    0x01, 0x40, 0x01, 0xe0, 0x03, 0x01, 0xe0, 0x11, 0x01, 0xe0, 0x4a, 0x01, 0xc0, 0x25, 0xe0, 0x05,
+    0x25, 0xe0, 0x80, 0x36, 0x65, 0x40, 0x05, 0x20, 0x05, 0xe0, 0x64, 0xe1, 0x80, 0x02, 0x00, 0xe1,
+    0x59, 0x60, 0xe1, 0x1c, 0xe0, 0x04, 0xe1, 0x08, 0x20, 0x61, 0xe0, 0x02, 0x21, 0x00, 0xc1, 0xe0,
+    0x00, 0x21, 0x20, 0x21, 0x60, 0x21, 0x20, 0x21, 0x60, 0x41, 0x20, 0x01, 0x20, 0x61, 0xe0, 0x08,
+    0x61, 0xe0, 0x01, 0x01, 0xe0, 0x05, 0x25, 0xa0, 0x21, 0x20, 0x01, 0x60, 0x21, 0x60, 0x25, 0xa0,
+    0x01, 0x00, 0x01, 0xe0, 0x19, 0x01, 0x00, 0x01, 0x80, 0xe5, 0x04, 0xe0, 0x04, 0x21, 0x00, 0x41,
+    0x00, 0x61, 0x00, 0x21, 0x00, 0x01, 0xe0, 0x07, 0x05, 0xe0, 0x0b, 0x05, 0xe0, 0x02, 0x21, 0x00,
+    0x05, 0xe0, 0x00, 0x25, 0xe0, 0x09, 0x25, 0x01, 0x60, 0x25, 0xe1, 0x00, 0x05, 0x81, 0x05, 0xe1,
+    0x05, 0x00, 0x01, 0x60, 0x21, 0x05, 0xc1, 0x25, 0x01, 0x05, 0x61, 0x05, 0x21, 0x05, 0x21, 0x80,
+    0x05, 0x60, 0x25, 0xe0, 0x14, 0x05, 0xe0, 0x0c, 0x01, 0xe0, 0x06, 0x05, 0x00, 0x05, 0x60, 0x45,
+    0x00, 0x05, 0xe0, 0x16, 0xe1, 0x02, 0xe0, 0x0d, 0x45, 0xe0, 0x10, 0x05, 0xe0, 0x06, 0x05, 0xe0,
+    0x1e, 0xe4, 0x00, 0xe0, 0x81, 0x0f, 0x24, 0xe0, 0x81, 0x0c, 0x25, 0xe0, 0x2b, 0x05, 0x60, 0x05,
+    0x61, 0xe0, 0x82, 0x9e, 0xe5, 0x12, 0x00, 0xe5, 0x51, 0xe0, 0x04, 0xe5, 0x80, 0x4e, 0xe0, 0x12,
+    0xe5, 0x04, 0x60, 0x03, 0xe5, 0x36, 0x20, 0xe5, 0x4e, 0x20, 0xe5, 0x5f, 0x80, 0xe5, 0x23, 0x00,
+    0xe5, 0x56, 0x00, 0xe5, 0x4c, 0xe0, 0x04, 0xe5, 0x27, 0x00, 0xe5, 0x20, 0xe1, 0x00, 0xe5, 0x9a,
+    0xe8, 0xe0, 0x38, 0xe5, 0xc0, 0x16, 0x05, 0x40, 0xe5, 0x2f, 0xe0, 0x84, 0x11, 0xe5, 0x15, 0xe0,
+    0x81, 0xfb, 0xe5, 0xab, 0x1c, 0xe0, 0x87, 0xd4, 0xe1, 0x98, 0x78, 0xe5, 0x81, 0x78, 0xe0, 0x82,
+    0x78, 0xe1, 0x08, 0xe5, 0x02, 0xe0, 0x0e, 0xe5, 0x1b, 0x00, 0xe5, 0x0b, 0x00, 0x65, 0xe0, 0x80,
+    0x0d, 0xe3, 0x58, 0xe2, 0x56, 0x40, 0xa2, 0x20, 0xa2, 0x20, 0xa2, 0x20, 0x42, 0x40, 0xc3, 0x00,
+    0xc2, 0xe0, 0x06, 0x01, 0xe0, 0xc0, 0x2f, 0x5a, 0x85, 0xe0, 0x03, 0x25, 0xe0, 0x06, 0xe5, 0x97,
+    0x70, 0xe0, 0x00, 0xe5, 0x84, 0x4e, 0xe0, 0x22, 0xe5, 0x01, 0xe0, 0xa2, 0x6f, 0xe5, 0x80, 0x97,
+    0xe0, 0x29, 0x45, 0xe0, 0x09, 0x65, 0xe0, 0x00, 0xe5, 0x81, 0x04, 0xe0, 0xbc, 0x80, 0x05, 0xe0,
+    0x80, 0x42, 0x05, 0xe0, 0x28, 0xe1, 0x03, 0x80, 0xe1, 0x16, 0x20, 0xe1, 0x32, 0xa0, 0xe1, 0x16,
+    0x05, 0x21, 0xe5, 0x02, 0xe1, 0x0a, 0xe0, 0x4b, 0x45, 0xe0, 0x05, 0xe5, 0x24, 0x60, 0xe5, 0x01,
+    0xc0, 0x25, 0xe0, 0x06, 0xa5, 0xe0, 0x80, 0x12, 0xe5, 0x19, 0xe0, 0x04, 0xe5, 0x01, 0x00, 0xe5,
+    0x3e, 0x00, 0xe5, 0x0e, 0xe0, 0x04, 0xe5, 0x23, 0x60, 0x85, 0xe0, 0x04, 0xe5, 0x09, 0x40, 0x05,
+    0x40, 0xe5, 0x3f, 0x00, 0x05, 0x00, 0xe5, 0x80, 0x33, 0x20, 0xe5, 0x37, 0xe0, 0x05, 0x65, 0x00,
+    0xe5, 0x10, 0xe0, 0x0a, 0x05, 0xe0, 0x12, 0x25, 0xe0, 0x05, 0x05, 0xe0, 0x4e, 0xe5, 0x4d, 0xe0,
+    0x28, 0xe5, 0x3e, 0xa0, 0x05, 0x40, 0x45, 0x20, 0x45, 0xe0, 0x0b, 0x25, 0xc0, 0xe5, 0x01, 0xe0,
+    0x80, 0x5b, 0xe5, 0x04, 0xe0, 0x80, 0x98, 0xe5, 0x27, 0x00, 0xe5, 0x02, 0x00, 0xe5, 0x2a, 0x00,
+    0xe5, 0x4a, 0x00, 0xe5, 0x2b, 0xe0, 0x68, 0x85, 0x40, 0x45, 0x80, 0xc5, 0xe0, 0x01, 0xe5, 0x11,
+    0xc0, 0xc5, 0xe0, 0x01, 0x45, 0xe0, 0x05, 0xc5, 0xe0, 0x84, 0xa1, 0xe5, 0xc0, 0xbf, 0x76, 0x20,
+    0xe5, 0xc0, 0xbf, 0x76, 0xe0, 0xc9, 0xc0, 0x7a, 0xe1, 0x80, 0x68, 0xe0, 0xc0, 0xbd, 0x88, 0xe1,
+    0xc0, 0xbf, 0x76, 0x20, 0xe1, 0xc0, 0xbf, 0x76, 0x20,
+];
+
+static unicode_eaw_index: [u8; 78] = [
+    0xe6, 0x00, 0x00, 0x38, 0x01, 0x00, 0xdc, 0x01, 0x00, 0xa2, 0x03, 0x00, 0x20, 0x20, 0x00, 0x06,
+    0x21, 0x00, 0xb8, 0x21, 0x20, 0x25, 0x22, 0x00, 0x95, 0x22, 0x00, 0x4c, 0x25, 0x20, 0xe2, 0x25,
+    0x00, 0x66, 0x26, 0x00, 0xe2, 0x26, 0x20, 0x56, 0x27, 0x00, 0x56, 0x2b, 0x00, 0x31, 0x31, 0x00,
+    0x00, 0xac, 0x40, 0x01, 0xff, 0x20, 0xf8, 0x87, 0x21, 0xcf, 0xf0, 0x41, 0x49, 0xf2, 0x01, 0xf5,
+    0xf3, 0x01, 0x80, 0xf6, 0x21, 0x7a, 0xf9, 0x01, 0x00, 0x00, 0x03, 0x00, 0x00, 0x11,
+];
+
+static unicode_prop_Zero_Width_table: [u8; 585] = [
+    0x42, 0xff, 0xef, 0x41, 0x12, 0x86, 0x41, 0x06, 0xac, 0x00, 0x01, 0x01, 0x00, 0xc7, 0x8a, 0x00,
+    0xad, 0x94, 0x8f, 0x80, 0xe4, 0x31, 0x29, 0x08, 0x83, 0xa2, 0x80, 0x9d, 0x9a, 0xda, 0x8a, 0xb9,
+    0x88, 0x88, 0x80, 0x97, 0x18, 0x88, 0x02, 0x04, 0xaa, 0x82, 0xf6, 0x8e, 0x80, 0x9f, 0xb6, 0x00,
+    0x03, 0x3b, 0x02, 0x86, 0x89, 0x81, 0x9c, 0x80, 0xb9, 0x03, 0x1f, 0x80, 0x93, 0x81, 0x99, 0x01,
+    0x81, 0xb8, 0x03, 0x0b, 0x09, 0x12, 0x80, 0x9d, 0x0a, 0x80, 0x8a, 0x81, 0xb8, 0x03, 0x20, 0x0b,
+    0x80, 0x93, 0x81, 0x95, 0x28, 0x80, 0xb9, 0x01, 0x00, 0x1f, 0x06, 0x81, 0x8a, 0x81, 0x9d, 0x80,
+    0xbc, 0x80, 0x8b, 0x80, 0xb1, 0x02, 0x80, 0xb8, 0x14, 0x10, 0x1e, 0x81, 0x8a, 0x81, 0x9c, 0x80,
+    0xb9, 0x01, 0x05, 0x04, 0x81, 0x93, 0x81, 0x9b, 0x81, 0xb8, 0x0b, 0x1f, 0x80, 0x93, 0x81, 0x9c,
+    0x80, 0xc7, 0x06, 0x10, 0x80, 0xd9, 0x01, 0x86, 0x8b, 0x87, 0xe1, 0x01, 0x88, 0x8a, 0x85, 0xc9,
+    0x81, 0x9a, 0x00, 0x00, 0x80, 0xb6, 0x8d, 0x04, 0x01, 0x84, 0x8a, 0x80, 0xa3, 0x88, 0x80, 0xe5,
+    0x83, 0x05, 0x01, 0x09, 0x98, 0x0b, 0x82, 0x8f, 0x83, 0x8c, 0x01, 0x0d, 0x80, 0x8e, 0x80, 0x40,
+    0xc0, 0x40, 0xa0, 0x41, 0x5c, 0x82, 0x43, 0xb1, 0x82, 0x9c, 0x82, 0x9c, 0x81, 0x9d, 0x81, 0xbf,
+    0x81, 0x06, 0x38, 0x81, 0x8a, 0x88, 0x80, 0xac, 0x83, 0xf5, 0x81, 0xa1, 0x80, 0xf5, 0x13, 0x81,
+    0x88, 0x05, 0x82, 0x40, 0xda, 0x09, 0x80, 0xb9, 0x00, 0x30, 0x00, 0x01, 0x3d, 0x89, 0x08, 0xaf,
+    0x90, 0xbe, 0x83, 0xaf, 0x00, 0x20, 0x04, 0x80, 0xa7, 0x88, 0x8b, 0x81, 0x9f, 0x19, 0x08, 0x82,
+    0xb7, 0x00, 0x0a, 0x00, 0x82, 0xb9, 0x39, 0x81, 0x40, 0x97, 0x10, 0x8c, 0x06, 0x18, 0x28, 0x11,
+    0x40, 0xc5, 0xb9, 0x04, 0x42, 0x0a, 0x84, 0x99, 0x84, 0xb0, 0x8f, 0xdf, 0xa0, 0x4b, 0xfd, 0x82,
+    0x40, 0x8c, 0x80, 0xdf, 0x9f, 0x42, 0x29, 0x83, 0xea, 0x81, 0x40, 0xc8, 0x80, 0x60, 0x75, 0x09,
+    0x83, 0x80, 0x89, 0x9f, 0x81, 0xcf, 0x81, 0x41, 0x0f, 0x02, 0x03, 0x80, 0x98, 0x0c, 0x80, 0x40,
+    0x96, 0x81, 0x99, 0x91, 0x8c, 0x80, 0xa5, 0x87, 0x98, 0x8a, 0xad, 0x82, 0xaf, 0x01, 0x19, 0x81,
+    0xa6, 0x80, 0xc2, 0x29, 0x09, 0x81, 0x8b, 0x07, 0x80, 0xae, 0x80, 0xb2, 0x00, 0x11, 0x0c, 0x08,
+    0x80, 0xa9, 0x0f, 0x80, 0x40, 0xed, 0x01, 0x03, 0x80, 0x60, 0x2b, 0xc1, 0x96, 0x83, 0xb0, 0x60,
+    0x23, 0x21, 0x80, 0x42, 0xe0, 0x8f, 0x8f, 0x8f, 0x40, 0xce, 0x80, 0x40, 0x9f, 0x80, 0xce, 0x8b,
+    0x42, 0x00, 0x80, 0x40, 0xe1, 0x80, 0x40, 0x94, 0x84, 0x46, 0x85, 0x10, 0x0c, 0x83, 0xa7, 0x13,
+    0x80, 0x40, 0xa4, 0x81, 0x42, 0x3c, 0x83, 0x41, 0x82, 0x81, 0x40, 0x98, 0x8a, 0x40, 0xaf, 0x80,
+    0xb5, 0x8e, 0xb7, 0x82, 0xb0, 0x19, 0x81, 0xc4, 0x82, 0xa3, 0x20, 0x87, 0xbd, 0x80, 0x8b, 0x81,
+    0xb3, 0x88, 0x89, 0x19, 0x80, 0xde, 0x11, 0x00, 0x0d, 0x80, 0x40, 0x9f, 0x02, 0x87, 0x94, 0x81,
+    0xb8, 0x0a, 0x80, 0xa4, 0x32, 0x84, 0x40, 0xc2, 0x39, 0x10, 0x80, 0x96, 0x80, 0xd3, 0x28, 0x03,
+    0x81, 0x01, 0x40, 0xed, 0x1d, 0x08, 0x81, 0x9a, 0x81, 0xd4, 0x39, 0x00, 0x81, 0xe9, 0x00, 0x01,
+    0x28, 0x80, 0xe4, 0x11, 0x18, 0x84, 0x41, 0x02, 0x88, 0x01, 0x40, 0xff, 0x08, 0x03, 0x80, 0x40,
+    0x8f, 0x83, 0x09, 0x18, 0x9f, 0x89, 0xa7, 0x29, 0x1f, 0x80, 0x88, 0x29, 0x82, 0xad, 0x8c, 0x01,
+    0x41, 0x95, 0x30, 0x28, 0x80, 0xd1, 0x95, 0x0e, 0x01, 0x01, 0xf9, 0x2a, 0x00, 0x08, 0x30, 0x80,
+    0xc7, 0x0a, 0x00, 0x80, 0x41, 0x5a, 0x81, 0x55, 0x3a, 0x88, 0x60, 0x36, 0xb6, 0x84, 0xba, 0x86,
+    0x44, 0x17, 0x80, 0xbe, 0x83, 0xd0, 0x80, 0x60, 0x4c, 0xb7, 0x08, 0x83, 0x54, 0xc2, 0x82, 0x88,
+    0x8f, 0x0e, 0x9d, 0x83, 0x40, 0x93, 0x82, 0x47, 0xba, 0xb6, 0x83, 0xb1, 0x38, 0x8d, 0x80, 0x95,
+    0x20, 0x8e, 0x45, 0x4f, 0x30, 0x90, 0x0e, 0x01, 0x04, 0x41, 0x04, 0x86, 0x41, 0xb4, 0x83, 0x45,
+    0xdf, 0x86, 0xec, 0x86, 0x6c, 0x16, 0xb4, 0x4f, 0xff,
+];
+
+static unicode_prop_Zero_Width_index: [u8; 57] = [
+    0xf4, 0x07, 0x20, 0x03, 0x0a, 0x20, 0x83, 0x0b, 0x00, 0x82, 0x0d, 0x20, 0x31, 0x10, 0x20, 0xb6,
+    0x17, 0x20, 0xc1, 0x1a, 0x20, 0xfa, 0x1c, 0x00, 0x73, 0xa6, 0x20, 0xbe, 0xa9, 0x00, 0x1f, 0xfb,
+    0x60, 0x40, 0x0a, 0x21, 0x82, 0x11, 0x01, 0xc1, 0x14, 0x21, 0xd8, 0x19, 0x41, 0x48, 0x1d, 0x01,
+    0x83, 0xd1, 0x21, 0xd7, 0xe8, 0x41, 0x00, 0x10, 0x0e,
+];
@@ -0,0 +1,144 @@
+use slimjs::bigcomplex::{BigComplex, ComplexEnv};
+use slimjs::bigfloat::{BigFloat, BigFloatEnv};
+
+mod common;
+use common::Lcg;
+
+fn c(re: f64, im: f64) -> BigComplex {
+    BigComplex::new(BigFloat::from(re), BigFloat::from(im))
+}
+
+/* equal components with the same signs, zeros included */
+fn same(a: &BigComplex, b: &BigComplex) -> bool {
+    let same1 = |x: &BigFloat, y: &BigFloat| {
+        (x.is_nan() && y.is_nan()) || (x == y && x.is_sign_negative() == y.is_sign_negative())
+    };
+    same1(a.re(), b.re()) && same1(a.im(), b.im())
+}
+
+fn env53() -> ComplexEnv {
+    ComplexEnv::new(&BigFloatEnv::new(53))
+}
+
+#[test]
+fn branch_cuts() {
+    let mut env = env53();
+    let pi = BigFloatEnv::new(53).const_pi();
+    /* the sign of the zero imaginary part selects the side of the cut */
+    assert!(same(&env.sqrt(&c(-4.0, 0.0)), &c(0.0, 2.0)));
+    assert!(same(&env.sqrt(&c(-4.0, -0.0)), &c(0.0, -2.0)));
+    assert!(same(&env.sqrt(&c(-3.0, 4.0)), &c(1.0, 2.0)));
+    assert!(same(&env.sqrt(&c(-3.0, -4.0)), &c(1.0, -2.0)));
+    assert!(same(
+        &env.log(&c(-1.0, 0.0)),
+        &BigComplex::new(BigFloat::zero(), pi.clone())
+    ));
+    assert!(same(
+        &env.log(&c(-1.0, -0.0)),
+        &BigComplex::new(BigFloat::zero(), -&pi)
+    ));
+    assert!(env.arg(&c(-2.0, 0.0)) == pi);
+    assert!(env.arg(&c(-2.0, -0.0)) == -&pi);
+    let h = c(0.5, 0.0);
+    assert!(same(&env.pow(&c(-4.0, 0.0), &h), &c(0.0, 2.0)));
+    assert!(same(&env.pow(&c(-4.0, -0.0), &h), &c(0.0, -2.0)));
+    /* principal cube root of -8 */
+    let third = env.div(&c(1.0, 0.0), &c(3.0, 0.0));
+    let r = env.pow(&c(-8.0, 0.0), &third);
+    let mut benv = BigFloatEnv::new(53);
+    let sqrt3 = benv.sqrt(&BigFloat::from(3));
+    assert!((r.re().to_f64() - 1.0).abs() < 1e-15);
+    assert!((r.im().to_f64() - sqrt3.to_f64()).abs() < 1e-15);
+
+    /* the values on each side of the cut are the limits from above and
+    below */
+    let mut rng = Lcg(1);
+    for _ in 0..200 {
+        let x = -(rng.next() as f64) / (1u64 << 47) as f64;
+        let eps = 1e-300;
+        for &(im, side) in &[(0.0, eps), (-0.0, -eps)] {
+            let a = env.sqrt(&c(x, im));
+            let b = env.sqrt(&c(x, side));
+            assert!((a.im().to_f64() - b.im().to_f64()).abs() <= 1e-15 * b.im().to_f64().abs());
+            assert_eq!(a.im().is_sign_negative(), side < 0.0, "{}", x);
+            let a = env.log(&c(x, im));
+            let b = env.log(&c(x, side));
+            assert!(a.im() == b.im(), "{}", x);
+        }
+    }
+}
+
+#[test]
+fn conjugate_symmetry() {
+    /* f(conj(z)) = conj(f(z)) on both sides of the cuts and for the
+    signed zeros */
+    let mut env = env53();
+    let mut rng = Lcg(2);
+    let mut values = Vec::new();
+    for &re in &[0.0, -0.0, 1.0, -1.0, -4.0, 2.5] {
+        for &im in &[0.0, -0.0, 1.0, -3.0] {
+            values.push(c(re, im));
+        }
+    }
+    for _ in 0..200 {
+        let re = (rng.next() as f64 - (1u64 << 52) as f64) / (1u64 << 49) as f64;
+        let im = (rng.next() as f64 - (1u64 << 52) as f64) / (1u64 << 49) as f64;
+        values.push(c(re, im));
+    }
+    for z in &values {
+        let fs: [fn(&mut ComplexEnv, &BigComplex) -> BigComplex; 6] = [
+            ComplexEnv::sqrt,
+            ComplexEnv::exp,
+            ComplexEnv::log,
+            ComplexEnv::sin,
+            ComplexEnv::cos,
+            ComplexEnv::tan,
+        ];
+        for (i, f) in fs.iter().enumerate() {
+            let a = f(&mut env, &z.conj());
+            let b = f(&mut env, z).conj();
+            assert!(same(&a, &b), "f{} {} {}", i, z, a);
+        }
+        assert!(env.arg(&z.conj()) == -&env.arg(z), "{}", z);
+    }
+}
+
+#[test]
+fn signed_zeros() {
+    let mut env = env53();
+    let pi = BigFloatEnv::new(53).const_pi();
+    /* atan2 of the signed zeros */
+    assert!(env.arg(&c(0.0, 0.0)).is_zero() && !env.arg(&c(0.0, 0.0)).is_sign_negative());
+    assert!(env.arg(&c(0.0, -0.0)).is_zero() && env.arg(&c(0.0, -0.0)).is_sign_negative());
+    assert!(env.arg(&c(-0.0, 0.0)) == pi);
+    assert!(env.arg(&c(-0.0, -0.0)) == -&pi);
+    let l = env.log(&c(-0.0, -0.0));
+    assert!(l.re().is_infinite() && l.re().is_sign_negative() && *l.im() == -&pi);
+    let l = env.log(&c(0.0, -0.0));
+    assert!(same(
+        &l,
+        &BigComplex::new(BigFloat::neg_infinity(), BigFloat::neg_zero())
+    ));
+
+    /* the real part of sqrt is +0 and the imaginary part keeps its sign */
+    assert!(same(&env.sqrt(&c(-0.0, 0.0)), &c(0.0, 0.0)));
+    assert!(same(&env.sqrt(&c(-0.0, -0.0)), &c(0.0, -0.0)));
+    assert!(same(&env.sqrt(&c(4.0, -0.0)), &c(2.0, -0.0)));
+    assert!(same(&env.exp(&c(0.0, -0.0)), &c(1.0, -0.0)));
+    assert!(same(&env.exp(&c(-0.0, 0.0)), &c(1.0, 0.0)));
+    assert!(same(&env.sin(&c(-0.0, -0.0)), &c(-0.0, -0.0)));
+    assert!(same(&env.tan(&c(0.0, -0.0)), &c(0.0, -0.0)));
+    /* cos(x + 0i) = cos(x) - sin(x) sinh(0) i */
+    assert!(same(&env.cos(&c(0.0, 0.0)), &c(1.0, -0.0)));
+    assert!(!env.cos(&c(4.0, 0.0)).im().is_sign_negative());
+    assert!(env.cos(&c(-4.0, 0.0)).im().is_sign_negative());
+
+    /* exact products and quotients */
+    let i = BigComplex::i();
+    assert!(same(&env.mul(&i, &i), &c(-1.0, 0.0)));
+    assert!(same(&env.div(&c(1.0, 0.0), &i), &c(0.0, -1.0)));
+    assert!(same(&env.pow(&i, &c(2.0, 0.0)), &c(-1.0, 0.0)));
+    assert!(same(&env.pow(&c(0.0, 0.0), &c(0.5, 0.0)), &c(0.0, 0.0)));
+    assert!(env.pow(&c(0.0, 0.0), &c(-1.0, 0.0)).is_nan());
+    assert!(env.div(&c(1.0, 0.0), &c(0.0, -0.0)).is_nan());
+}
@@ -0,0 +1,128 @@
+/* IEEE 754-2008 decimal formats: tests/data/decimal_encodings.txt is
+generated by tests/data/decimal_encodings.py */
+use slimjs::bigdecimal::{BigDecimal, BigDecimalEnv, DecimalEncoding, DecimalFormat};
+use slimjs::bigfloat::Rounding;
+
+fn rounding(name: &str) -> Rounding {
+    match name {
+        "NearestEven" => Rounding::NearestEven,
+        "TowardZero" => Rounding::TowardZero,
+        "Down" => Rounding::Down,
+        "Up" => Rounding::Up,
+        "NearestAway" => Rounding::NearestAway,
+        "AwayFromZero" => Rounding::AwayFromZero,
+        _ => panic!("unknown rounding {}", name),
+    }
+}
+
+fn encode(
+    env: &mut BigDecimalEnv,
+    a: &BigDecimal,
+    format: DecimalFormat,
+    enc: DecimalEncoding,
+) -> u128 {
+    match format {
+        DecimalFormat::Decimal32 => env.encode_decimal32(a, enc) as u128,
+        DecimalFormat::Decimal64 => env.encode_decimal64(a, enc) as u128,
+        DecimalFormat::Decimal128 => env.encode_decimal128(a, enc),
+    }
+}
+
+fn decode(bits: u128, format: DecimalFormat, enc: DecimalEncoding) -> BigDecimal {
+    match format {
+        DecimalFormat::Decimal32 => BigDecimal::decode_decimal32(bits as u32, enc),
+        DecimalFormat::Decimal64 => BigDecimal::decode_decimal64(bits as u64, enc),
+        DecimalFormat::Decimal128 => BigDecimal::decode_decimal128(bits, enc),
+    }
+}
+
+fn same(a: &BigDecimal, b: &BigDecimal) -> bool {
+    (a.is_nan() && b.is_nan()) || (a == b && a.is_sign_negative() == b.is_sign_negative())
+}
+
+#[test]
+fn decimal_encodings() {
+    let data = include_str!("data/decimal_encodings.txt");
+    let mut parse_env = BigDecimalEnv::new(100);
+    let mut count = 0;
+    for line in data.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(' ').collect();
+        let format = match fields[0] {
+            "decimal32" => DecimalFormat::Decimal32,
+            "decimal64" => DecimalFormat::Decimal64,
+            _ => DecimalFormat::Decimal128,
+        };
+        let rnd = rounding(fields[1]);
+        let a = parse_env.parse(fields[2]).unwrap();
+        let dpd = u128::from_str_radix(fields[3], 16).unwrap();
+        let bid = u128::from_str_radix(fields[4], 16).unwrap();
+
+        let mut env = BigDecimalEnv::for_format(format);
+        env.set_rounding(rnd);
+        assert_eq!(
+            encode(&mut env, &a, format, DecimalEncoding::Dpd),
+            dpd,
+            "{}",
+            line
+        );
+        assert_eq!(
+            encode(&mut env, &a, format, DecimalEncoding::Bid),
+            bid,
+            "{}",
+            line
+        );
+        /* the rounded value is decoded from both encodings */
+        let r = env.round(&a);
+        assert!(
+            same(&decode(dpd, format, DecimalEncoding::Dpd), &r),
+            "{}",
+            line
+        );
+        assert!(
+            same(&decode(bid, format, DecimalEncoding::Bid), &r),
+            "{}",
+            line
+        );
+        count += 1;
+    }
+    assert!(count > 5000);
+}
+
+#[test]
+fn decimal_special_encodings() {
+    let dpd = DecimalEncoding::Dpd;
+    let bid = DecimalEncoding::Bid;
+    let mut env = BigDecimalEnv::new(40);
+    let max32 = env.parse("9.999999e96").unwrap();
+    assert_eq!(env.encode_decimal32(&max32, dpd), 0x77f3fcff);
+    assert_eq!(env.encode_decimal32(&max32, bid), 0x77f8967f);
+    let max64 = env.parse("9.999999999999999e384").unwrap();
+    assert_eq!(env.encode_decimal64(&max64, dpd), 0x77fcff3fcff3fcff);
+    assert_eq!(env.encode_decimal64(&max64, bid), 0x77fb86f26fc0ffff);
+    let min32 = env.parse("1e-101").unwrap();
+    assert_eq!(env.encode_decimal32(&min32, dpd), 1);
+    assert_eq!(env.encode_decimal32(&min32, bid), 1);
+    assert_eq!(env.encode_decimal32(&BigDecimal::nan(), dpd), 0x7c000000);
+    assert_eq!(
+        env.encode_decimal64(&BigDecimal::infinity(), bid),
+        0x7800000000000000
+    );
+    assert_eq!(
+        env.encode_decimal128(&BigDecimal::neg_infinity(), dpd),
+        0xf8000000000000000000000000000000
+    );
+    /* non-canonical declets and coefficients */
+    assert!(BigDecimal::decode_decimal32(0x225003ff, dpd) == env.parse("999").unwrap());
+    assert!(BigDecimal::decode_decimal32(0x6cb89680, bid).is_zero());
+}
+
+#[test]
+fn env_limits() {
+    let mut env = BigDecimalEnv::new(0);
+    assert_eq!(env.prec(), 1);
+    env.set_prec(u64::MAX);
+    assert_eq!(env.prec(), BigDecimalEnv::PREC_MAX);
+}
@@ -0,0 +1,200 @@
+use slimjs::bigfloat::{
+    clear_cache, memory_usage, set_memory_limit, BigFloat, BigFloatEnv, Rounding, BF_ST_INEXACT,
+    BF_ST_MEM_ERROR,
+};
+
+mod common;
+use common::Lcg;
+
+/* random number of exactly 'prec' bits with an exponent between -emax
+and emax */
+fn random_number(rng: &mut Lcg, env: &mut BigFloatEnv, emax: i64) -> BigFloat {
+    let prec = env.prec() as usize;
+    let mut digits = String::from("1");
+    while digits.len() < prec - 1 {
+        digits.push(if rng.next() & 1 != 0 { '1' } else { '0' });
+    }
+    digits.push('1');
+    let e = (rng.next() % (2 * emax as u64 + 1)) as i64 - emax - prec as i64;
+    let sign = if rng.next() & 1 != 0 { "-" } else { "" };
+    env.parse(&format!("{}{}p{}", sign, digits, e), 2).unwrap()
+}
+
+#[test]
+fn env_limits() {
+    let mut env = BigFloatEnv::new(0);
+    assert_eq!(env.prec(), BigFloatEnv::PREC_MIN);
+    env.set_prec(u64::MAX);
+    assert_eq!(env.prec(), BigFloatEnv::PREC_MAX);
+    env.set_exp_bits(0);
+    assert_eq!(env.exp_bits(), BigFloatEnv::EXP_BITS_MIN);
+    env.set_exp_bits(1000);
+    assert_eq!(env.exp_bits(), BigFloatEnv::EXP_BITS_MAX);
+    env.set_exp_bits(11);
+    assert_eq!(env.exp_bits(), 11);
+}
+
+#[test]
+fn string_round_trip() {
+    let mut rng = Lcg(1);
+    for &prec in &[113u64, 200, 1000, 5000] {
+        let mut env = BigFloatEnv::new(prec);
+        for _ in 0..200 {
+            let a = random_number(&mut rng, &mut env, 10000);
+            for &radix in &[2u32, 7, 10, 16, 36] {
+                let s = a.to_string_radix(radix);
+                env.clear_status();
+                let b = env.parse(&s, radix).unwrap();
+                assert!(a == b, "prec={} radix={} {}", prec, radix, s);
+            }
+        }
+    }
+}
+
+#[test]
+fn f64_round_trip() {
+    let mut rng = Lcg(2);
+    let mut env = BigFloatEnv::new(53);
+    env.set_exp_bits(11);
+    env.set_subnormal(true);
+    for _ in 0..10000 {
+        let d = f64::from_bits(rng.next() << 11 ^ rng.next());
+        if !d.is_finite() {
+            continue;
+        }
+        let a = BigFloat::from(d);
+        assert_eq!(a.to_f64(), d);
+        let s = format!("{:e}", d);
+        assert_eq!(env.parse(&s, 10).unwrap().to_f64(), d, "{}", s);
+    }
+}
+
+#[test]
+fn arithmetic_rounding() {
+    let mut rng = Lcg(3);
+    let roundings = [
+        Rounding::NearestEven,
+        Rounding::TowardZero,
+        Rounding::Down,
+        Rounding::Up,
+        Rounding::NearestAway,
+        Rounding::AwayFromZero,
+    ];
+    for &prec in &[24u64, 53, 113, 300] {
+        let mut env = BigFloatEnv::new(prec);
+        let mut exact = BigFloatEnv::new(2 * prec + 512);
+        for _ in 0..200 {
+            let a = random_number(&mut rng, &mut env, 100);
+            let b = random_number(&mut rng, &mut env, 100);
+            /* the exact product and sum fit in 2 * prec + 512 bits */
+            let p = exact.mul(&a, &b);
+            let s = exact.add(&a, &b);
+            assert_eq!(exact.status() & BF_ST_INEXACT, 0);
+            for &rnd in &roundings {
+                env.set_rounding(rnd);
+                let r = env.mul(&a, &b);
+                assert!(r == env.round(&p), "mul prec={} {:?}", prec, rnd);
+                let r = env.add(&a, &b);
+                assert!(r == env.round(&s), "add prec={} {:?}", prec, rnd);
+                /* the quotient is checked with the remainder */
+                let q = env.div(&a, &b);
+                let qb = exact.mul(&q, &b);
+                let t = exact.sub(&a, &qb);
+                let below = match rnd {
+                    Rounding::Down => true,
+                    Rounding::Up => false,
+                    Rounding::TowardZero => !q.is_sign_negative(),
+                    Rounding::AwayFromZero => q.is_sign_negative(),
+                    _ => continue,
+                };
+                /* a - q * b has the sign of b when q is below a / b */
+                if !t.is_zero() {
+                    assert_eq!(
+                        t.is_sign_negative() == b.is_sign_negative(),
+                        below,
+                        "div prec={} {:?}",
+                        prec,
+                        rnd
+                    );
+                }
+            }
+        }
+    }
+}
+
+/* make each allocation of 'f' fail in turn: the result must be either
+NaN with BF_ST_MEM_ERROR or the result without limit. Return the
+number of failures. */
+fn check_memory_errors(env: &mut BigFloatEnv, f: impl Fn(&mut BigFloatEnv) -> BigFloat) -> usize {
+    clear_cache();
+    env.clear_status();
+    let r = f(env);
+    assert_eq!(env.status() & BF_ST_MEM_ERROR, 0);
+    let mut nb_errors = 0;
+    let mut extra = 1024;
+    loop {
+        clear_cache();
+        set_memory_limit(Some(memory_usage().current + extra));
+        env.clear_status();
+        let r1 = f(env);
+        set_memory_limit(None);
+        if env.status() & BF_ST_MEM_ERROR == 0 {
+            assert!(r1 == r);
+            return nb_errors;
+        }
+        assert!(r1.is_nan());
+        nb_errors += 1;
+        extra += extra / 4;
+    }
+}
+
+#[test]
+fn memory_limit() {
+    /* the operands are large enough for the NTT multiplication */
+    let mut rng = Lcg(5);
+    let mut env = BigFloatEnv::new(300_000);
+    let a = random_number(&mut rng, &mut env, 10);
+    let b = random_number(&mut rng, &mut env, 10);
+    let mut env = BigFloatEnv::new(600_000);
+    assert!(check_memory_errors(&mut env, |env| env.mul(&a, &b)) > 10);
+
+    /* functions evaluated with Ziv's loop */
+    let mut env = BigFloatEnv::new(5000);
+    let x = BigFloat::from(0.75);
+    assert!(check_memory_errors(&mut env, |env| env.exp(&x)) > 0);
+    assert!(check_memory_errors(&mut env, |env| env.atan(&x)) > 0);
+}
+
+#[cfg(feature = "parallel-ntt")]
+#[test]
+fn parallel_multiplication() {
+    use rayon::prelude::*;
+    use slimjs::bigfloat::set_parallel_multiplication;
+
+    /* the operands are large enough for the parallel transforms and the
+    product is exact */
+    let mut rng = Lcg(7);
+    let mut env = BigFloatEnv::new(5_000_000);
+    let a = random_number(&mut rng, &mut env, 10);
+    let b = random_number(&mut rng, &mut env, 10);
+    let mut env = BigFloatEnv::new(10_000_000);
+    let p = env.mul(&a, &b);
+    set_parallel_multiplication(false);
+    let q = env.mul(&a, &b);
+    set_parallel_multiplication(true);
+    assert!(p == q);
+    assert_eq!(env.status(), 0);
+
+    /* the threads waiting for the jobs of a multiplication run other
+    multiplications and clear their caches meanwhile */
+    let v: Vec<BigFloat> = (0..8)
+        .into_par_iter()
+        .map(|_| {
+            let mut env = BigFloatEnv::new(10_000_000);
+            let r = env.mul(&a, &b);
+            clear_cache();
+            r
+        })
+        .collect();
+    assert!(v.iter().all(|r| *r == q));
+}
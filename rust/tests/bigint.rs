@@ -0,0 +1,170 @@
+/* the reference values were computed with the integers of Python */
+use slimjs::bigint::BigInt;
+
+mod common;
+use common::Lcg;
+
+fn random_bigint(rng: &mut Lcg, max_bits: u64) -> BigInt {
+    let bits = rng.next() % max_bits + 1;
+    let mut r = BigInt::zero();
+    for _ in 0..(bits + 52) / 53 {
+        r = (r << 53) + BigInt::from(rng.next());
+    }
+    r >>= (bits + 52) / 53 * 53 - bits;
+    if rng.next() & 1 != 0 {
+        -r
+    } else {
+        r
+    }
+}
+
+fn int(s: &str) -> BigInt {
+    s.parse().unwrap()
+}
+
+/* Euclid's algorithm with the remainder only */
+fn gcd_ref(a: &BigInt, b: &BigInt) -> BigInt {
+    let mut a = a.abs();
+    let mut b = b.abs();
+    while !b.is_zero() {
+        let r = &a % &b;
+        a = std::mem::replace(&mut b, r);
+    }
+    a
+}
+
+#[test]
+fn gcd() {
+    let a = int("116686935812810002198042058094993508437917705574768085478345565676920661994165617554870319735620439534579128332098981605517635354624");
+    let b = int("38252774134585688522090342541133260614812127287557391113252595515352401054014969218469880924318807883776");
+    assert_eq!(
+        a.gcd(&b),
+        int(
+            "1153617588319010271378133306175011326520419737189530113840977835459429144159137562624"
+        )
+    );
+    assert_eq!(BigInt::zero().gcd(&BigInt::zero()), BigInt::zero());
+    assert_eq!(BigInt::from(-12).gcd(&BigInt::zero()), BigInt::from(12));
+
+    let mut rng = Lcg(1);
+    for i in 0..300 {
+        let k = random_bigint(&mut rng, 200);
+        let a = &random_bigint(&mut rng, 2000) * &k;
+        /* close operands exercise the Lehmer steps with q != 0 */
+        let b = if i % 3 == 0 {
+            &a + &random_bigint(&mut rng, 100)
+        } else {
+            &random_bigint(&mut rng, 2000) * &k
+        };
+        let g = a.gcd(&b);
+        assert_eq!(g, gcd_ref(&a, &b));
+        assert_eq!(b.gcd(&a), g);
+    }
+    /* consecutive Fibonacci numbers: only quotients equal to 1 */
+    let (mut f0, mut f1) = (BigInt::zero(), BigInt::one());
+    for _ in 0..3000 {
+        let f = &f0 + &f1;
+        f0 = std::mem::replace(&mut f1, f);
+    }
+    assert_eq!(f0.gcd(&f1), BigInt::one());
+}
+
+#[test]
+fn modpow() {
+    let p = (BigInt::one() << 255) - BigInt::from(19);
+    let e = BigInt::from(10).pow(40) + BigInt::from(7);
+    assert_eq!(
+        BigInt::from(3).modpow(&e, &p).unwrap(),
+        int("11306134312905107978177136053487146481167995017258987163935575453434518412241")
+    );
+    assert_eq!(
+        BigInt::from(12345678901234567890u64)
+            .modpow(&BigInt::from(-1), &p)
+            .unwrap(),
+        int("55335905831616906258934597032528017175332098981730720091099231045619009907308")
+    );
+    let m = BigInt::from(10).pow(30) + BigInt::from(57);
+    let e = (BigInt::one() << 70) + BigInt::from(3);
+    assert_eq!(
+        BigInt::from(-5).modpow(&e, &m).unwrap(),
+        int("496525086938579735556855810408")
+    );
+    /* Fermat: 2^127 - 1 and 2^521 - 1 are prime */
+    for &n in &[127u64, 521] {
+        let p = (BigInt::one() << n) - BigInt::one();
+        let e = &p - &BigInt::one();
+        assert_eq!(BigInt::from(3).modpow(&e, &p).unwrap(), BigInt::one());
+    }
+    assert_eq!(
+        BigInt::from(7).modpow(&BigInt::zero(), &BigInt::one()),
+        Some(BigInt::zero())
+    );
+    assert_eq!(
+        BigInt::from(7).modpow(&BigInt::one(), &BigInt::zero()),
+        None
+    );
+    assert_eq!(
+        BigInt::from(2).modpow(&BigInt::from(-1), &BigInt::from(4)),
+        None
+    );
+
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let a = random_bigint(&mut rng, 300);
+        let m = random_bigint(&mut rng, 300);
+        if m.is_zero() {
+            continue;
+        }
+        let e = rng.next() % 200;
+        let r = a.modpow(&BigInt::from(e), &m).unwrap();
+        assert_eq!(r, a.pow(e).rem_euclid(&m));
+    }
+}
+
+#[test]
+fn radix() {
+    let n = BigInt::from(7).pow(200);
+    assert_eq!(n.to_string_radix(36), "8os488zanz3dak61vn5tbpltrle4vzau2zjw5e9edh9yaq19zuw1bis65u5nktubp5iay5kuqfv88aihkizw273ngty2lyfz75nu5yhb6g3pd");
+    let s = n.to_string_radix(3);
+    assert_eq!(s.len(), 355);
+    assert!(s.starts_with("1022111002201201220001010010010110120210"));
+    assert_eq!(
+        (BigInt::one() << 128).to_string(),
+        "340282366920938463463374607431768211456"
+    );
+    assert_eq!(BigInt::from(-255).to_string_radix(16), "-ff");
+    assert_eq!(BigInt::zero().to_string_radix(2), "0");
+    assert!(BigInt::from_str_radix("12a", 10).is_err());
+    assert!(BigInt::from_str_radix("", 10).is_err());
+    assert!(BigInt::from_str_radix("-", 10).is_err());
+    assert_eq!(
+        BigInt::from_str_radix("+zz", 36).unwrap(),
+        BigInt::from(1295)
+    );
+
+    let mut rng = Lcg(3);
+    for _ in 0..200 {
+        let a = random_bigint(&mut rng, 3000);
+        for radix in 2..=36 {
+            let s = a.to_string_radix(radix);
+            assert_eq!(BigInt::from_str_radix(&s, radix).unwrap(), a);
+        }
+        let v = rng.next() as u128 * rng.next() as u128;
+        assert_eq!(BigInt::from(v).to_string(), v.to_string());
+        assert_eq!(format!("{:x}", BigInt::from(v)), format!("{:x}", v));
+    }
+}
+
+#[test]
+fn shift() {
+    let a = BigInt::from(-3);
+    assert_eq!(&a << 100, &a * &BigInt::from(2).pow(100));
+    assert_eq!(&(&a << 100) >> 100, a);
+    assert_eq!(&a >> 1, BigInt::from(-2));
+    assert_eq!(a.checked_shl(1 << 40).unwrap().bits(), (1 << 40) + 2);
+    assert!(a.checked_shl(1 << 62).is_none());
+    assert!(a.checked_shl(u64::MAX).is_none());
+    assert_eq!(BigInt::zero().checked_shl(u64::MAX), Some(BigInt::zero()));
+    let r = std::panic::catch_unwind(|| BigInt::one() << u64::MAX);
+    assert!(r.is_err());
+}
@@ -0,0 +1,210 @@
+use slimjs::bigfloat::{BigFloat, BigFloatEnv, Rounding, BF_ST_INEXACT};
+use slimjs::bigint::BigInt;
+use slimjs::bigrational::BigRational;
+
+mod common;
+use common::Lcg;
+
+fn q(s: &str) -> BigRational {
+    s.parse().unwrap()
+}
+
+fn int(v: i64) -> BigInt {
+    BigInt::from(v)
+}
+
+fn random_rational(rng: &mut Lcg, max: u64) -> BigRational {
+    let num = rng.below(2 * max + 1) as i64 - max as i64;
+    let den = rng.below(max) as i64 + 1;
+    BigRational::new(int(num), int(den))
+}
+
+fn is_normalized(a: &BigRational) -> bool {
+    a.denom().is_positive() && a.numer().gcd(a.denom()) == BigInt::one()
+}
+
+#[test]
+fn normalization() {
+    assert_eq!(BigRational::new(int(6), int(-4)).to_string(), "-3/2");
+    assert_eq!(BigRational::new(int(-6), int(-4)).to_string(), "3/2");
+    assert_eq!(BigRational::new(int(0), int(-4)).denom(), &int(1));
+    assert!(q("4/2").is_integer());
+
+    let mut rng = Lcg(1);
+    for _ in 0..2000 {
+        let a = random_rational(&mut rng, 1000);
+        let b = random_rational(&mut rng, 1000);
+        let r = [
+            &a + &b,
+            &a - &b,
+            &a * &b,
+            &a % &b.abs().max(BigRational::one()),
+        ];
+        for c in &r {
+            assert!(is_normalized(c), "{} {}", a, b);
+        }
+        assert_eq!(&(&a + &b) - &b, a);
+        if !b.is_zero() {
+            let c = &a / &b;
+            assert!(is_normalized(&c));
+            assert_eq!(&c * &b, a);
+            assert!(is_normalized(&b.recip()));
+        }
+        /* the sum of opposite numbers is 0/1 */
+        assert_eq!((&a + &-&a).denom(), &int(1));
+    }
+}
+
+#[test]
+fn signs() {
+    assert_eq!(q("-7/2").floor(), int(-4));
+    assert_eq!(q("-7/2").ceil(), int(-3));
+    assert_eq!(q("-7/2").trunc(), int(-3));
+    assert_eq!(q("-7/2").round(), int(-4));
+    assert_eq!(q("7/2").round(), int(4));
+    assert_eq!(q("-7/2").fract(), q("-1/2"));
+    /* the remainder has the sign of the dividend */
+    assert_eq!(q("7/2") % q("-1"), q("1/2"));
+    assert_eq!(q("-7/2") % q("1"), q("-1/2"));
+    assert_eq!(q("-2/3").recip(), q("-3/2"));
+    assert_eq!(q("-2/3").pow(-3), q("-27/8"));
+    assert_eq!(q("-2/3").pow(2), q("4/9"));
+    assert_eq!(q("-2/3").abs(), q("2/3"));
+    assert_eq!(q("-2/3").signum(), -1);
+    assert_eq!(q("0").signum(), 0);
+    assert!(q("0").checked_recip().is_none());
+    assert!(q("-1/2") < q("-1/3") && q("-1/3") < q("0") && q("0") < q("1/3"));
+    assert!("1/-2".parse::<BigRational>().is_err());
+    assert!("-1/0".parse::<BigRational>().is_err());
+    assert_eq!(
+        BigRational::from_str_radix("-ff/10", 16).unwrap(),
+        q("-255/16")
+    );
+    assert_eq!(format!("{:>6}", q("-1/2")), "  -1/2");
+    assert_eq!(format!("{:+}", q("1/2")), "+1/2");
+
+    let mut rng = Lcg(2);
+    for _ in 0..1000 {
+        let a = random_rational(&mut rng, 1000);
+        let (fl, ce, tr) = (a.floor(), a.ceil(), a.trunc());
+        assert!(BigRational::from(&fl) <= a && a <= BigRational::from(&ce));
+        assert!(&ce - &fl == int(if a.is_integer() { 0 } else { 1 }));
+        assert_eq!(tr, if a.is_negative() { ce } else { fl });
+        assert_eq!(&BigRational::from(&tr) + &a.fract(), a);
+        assert_eq!((-&a).round(), -a.round());
+    }
+}
+
+/* the best approximation by brute force: the distance to the closest
+fraction with a denominator of at most 'max_den' */
+fn best_distance(x: &BigRational, max_den: i64) -> BigRational {
+    let mut best: Option<BigRational> = None;
+    for d in 1..=max_den {
+        let xd = x * &BigRational::from(d);
+        for n in [xd.floor(), xd.ceil()].iter() {
+            let dist = (&BigRational::new(n.clone(), int(d)) - x).abs();
+            if !matches!(&best, Some(b) if *b <= dist) {
+                best = Some(dist);
+            }
+        }
+    }
+    best.unwrap()
+}
+
+#[test]
+fn approximate() {
+    let pi = BigRational::from_f64(std::f64::consts::PI).unwrap();
+    assert_eq!(pi.approximate(&int(10)), q("22/7"));
+    assert_eq!(pi.approximate(&int(1000)), q("355/113"));
+    /* a semiconvergent between 22/7 and 355/113 */
+    assert_eq!(pi.approximate(&int(100)), q("311/99"));
+    assert_eq!(q("-355/113").approximate(&int(10)), q("-22/7"));
+    assert_eq!(q("3/7").approximate(&int(7)), q("3/7"));
+    /* k = a / 2: the semiconvergent 1/4 between the convergents 0/1 and
+    1/3 is closer to 2/7 = [0; 3, 2], farther from 5/17 = [0; 3, 2, 2]
+    and at the same distance from 7/24 = [0; 3, 2, 3], where the
+    convergent is returned */
+    assert_eq!(q("2/7").approximate(&int(4)), q("1/4"));
+    assert_eq!(q("5/17").approximate(&int(4)), q("1/3"));
+    assert_eq!(q("7/24").approximate(&int(4)), q("1/3"));
+    assert_eq!(q("-7/24").approximate(&int(4)), q("-1/3"));
+
+    let mut rng = Lcg(3);
+    for _ in 0..300 {
+        let x = random_rational(&mut rng, 1000);
+        let max_den = rng.below(60) as i64 + 1;
+        let r = x.approximate(&int(max_den));
+        assert!(*r.denom() <= int(max_den), "{} {}", x, max_den);
+        assert_eq!(
+            (&r - &x).abs(),
+            best_distance(&x, max_den),
+            "{} {}",
+            x,
+            max_den
+        );
+    }
+}
+
+const ROUNDINGS: [Rounding; 6] = [
+    Rounding::NearestEven,
+    Rounding::TowardZero,
+    Rounding::Down,
+    Rounding::Up,
+    Rounding::NearestAway,
+    Rounding::AwayFromZero,
+];
+
+#[test]
+fn bigfloat_round_trip() {
+    let mut rng = Lcg(4);
+    /* the finite BigFloat numbers are converted exactly */
+    for _ in 0..1000 {
+        let d = f64::from_bits(rng.next() << 11 ^ rng.next());
+        if !d.is_finite() {
+            continue;
+        }
+        let a = BigRational::from_f64(d).unwrap();
+        assert!(is_normalized(&a));
+        for &rnd in &ROUNDINGS {
+            let mut env = BigFloatEnv::new(53);
+            env.set_rounding(rnd);
+            let b = a.to_bigfloat(&mut env);
+            assert_eq!(env.status(), 0);
+            assert!(b == BigFloat::from(d), "{:e} {:?}", d, rnd);
+        }
+        assert_eq!(a.to_f64(), d);
+    }
+    assert!(BigRational::from_f64(f64::NAN).is_none());
+    assert!(BigRational::from_f64(f64::NEG_INFINITY).is_none());
+
+    /* the other numbers are rounded in the direction of the rounding
+    mode */
+    for _ in 0..300 {
+        let a = random_rational(&mut rng, 1 << 40);
+        let mut r = Vec::new();
+        let mut status = 0;
+        for &rnd in &ROUNDINGS {
+            let mut env = BigFloatEnv::new(24);
+            env.set_rounding(rnd);
+            let b = a.to_bigfloat(&mut env);
+            status |= env.status();
+            r.push(BigRational::from_bigfloat(&b).unwrap());
+        }
+        if r[0] == a {
+            continue;
+        }
+        assert_eq!(status, BF_ST_INEXACT);
+        let (down, up) = (&r[2], &r[3]);
+        assert!(*down < a && a < *up, "{}", a);
+        let toward_zero = if a.is_negative() { up } else { down };
+        let away = if a.is_negative() { down } else { up };
+        assert_eq!(r[1], *toward_zero);
+        assert_eq!(r[5], *away);
+        if &a - down == up - &a {
+            continue;
+        }
+        let nearest = if &a - down < up - &a { down } else { up };
+        assert_eq!(r[0], *nearest);
+        assert_eq!(r[4], *nearest);
+    }
+}
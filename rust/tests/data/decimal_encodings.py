@@ -0,0 +1,129 @@
+#!/usr/bin/env python3
+# Generate decimal_encodings.txt: IEEE 754-2008 decimal32/64/128 roundings
+# and encodings computed with the decimal module of Python and the DPD
+# equations of the standard.
+#
+# Each line is: format rounding input dpd bid
+# with the encodings in hexadecimal. The encoded member of the cohort is
+# the one with the smallest coefficient.
+import decimal
+import random
+
+FORMATS = {
+    # name: (bits, digits, emax)
+    'decimal32': (32, 7, 96),
+    'decimal64': (64, 16, 384),
+    'decimal128': (128, 34, 6144),
+}
+
+ROUNDINGS = {
+    'NearestEven': decimal.ROUND_HALF_EVEN,
+    'TowardZero': decimal.ROUND_DOWN,
+    'Down': decimal.ROUND_FLOOR,
+    'Up': decimal.ROUND_CEILING,
+    'NearestAway': decimal.ROUND_HALF_UP,
+    'AwayFromZero': decimal.ROUND_UP,
+}
+
+
+def declet(d):
+    """DPD encoding of 3 digits with the equations of IEEE 754-2008 3.5.2"""
+    a, b, c, dd = [(d // 100) >> i & 1 for i in (3, 2, 1, 0)]
+    e, f, g, h = [(d // 10 % 10) >> i & 1 for i in (3, 2, 1, 0)]
+    i, j, k, m = [(d % 10) >> n & 1 for n in (3, 2, 1, 0)]
+    na, ne, ni = 1 - a, 1 - e, 1 - i
+    p = b | (a & j) | (a & f & i)
+    q = c | (a & k) | (a & g & i)
+    r = dd
+    s = (f & (na | ni)) | (na & e & j) | (e & i)
+    t = g | (na & e & k) | (a & i)
+    u = h
+    v = a | e | i
+    w = a | (e & i) | (ne & j)
+    x = e | (a & i) | (na & k)
+    y = m
+    bits = 0
+    for z in (p, q, r, s, t, u, v, w, x, y):
+        bits = bits << 1 | z
+    return bits
+
+
+def encode(x, fmt):
+    k, p, emax = FORMATS[fmt]
+    t = (p - 1) // 3 * 10
+    w = k - 6 - t
+    q_min = 1 - emax - p + 1
+    q_max = emax - p + 1
+    sign = (1 << (k - 1)) if x.is_signed() else 0
+    if x.is_nan():
+        return 0x1f << (k - 6), 0x1f << (k - 6)
+    if x.is_infinite():
+        return sign | 0x1e << (k - 6), sign | 0x1e << (k - 6)
+    _, digits, exp = x.as_tuple()
+    c = int(''.join(map(str, digits)))
+    q = exp
+    if c == 0:
+        q = 0
+    else:
+        while c % 10 == 0:
+            c //= 10
+            q += 1
+    if q > q_max:
+        c *= 10 ** (q - q_max)
+        q = q_max
+    assert q >= q_min and c < 10 ** p
+    e = q - q_min
+    # BID
+    if c >> (t + 3) == 0:
+        bid = sign | e << (t + 3) | c
+    else:
+        bid = sign | 3 << (k - 3) | e << (t + 1) | (c & ((1 << (t + 1)) - 1))
+    # DPD
+    d0 = c // 10 ** (p - 1)
+    rest = c % 10 ** (p - 1)
+    trailing = 0
+    for n in range(t // 10):
+        trailing |= declet(rest % 1000) << (10 * n)
+        rest //= 1000
+    e_top = e >> w
+    g = (e_top << 3 | d0) if d0 < 8 else (0x18 | e_top << 1 | (d0 & 1))
+    dpd = sign | g << (k - 6) | (e & ((1 << w) - 1)) << t | trailing
+    return dpd, bid
+
+
+def random_input(rnd, fmt):
+    k, p, emax = FORMATS[fmt]
+    n = rnd.choice([1, 2, 3, p - 1, p, p + 1, p + 5, 2 * p])
+    digits = ''.join(rnd.choice('0123456789') for _ in range(n))
+    kind = rnd.random()
+    if kind < 0.1:
+        # subnormal range
+        exp = 1 - emax - p - rnd.randrange(p + 3)
+    elif kind < 0.2:
+        # overflow range
+        exp = emax - n + rnd.randrange(-2, 3)
+    else:
+        exp = rnd.randrange(-2 * p, 2 * p)
+    sign = rnd.choice(['', '-'])
+    return '%s%se%d' % (sign, digits.lstrip('0') or '0', exp)
+
+
+def main():
+    rnd = random.Random(754)
+    out = open('decimal_encodings.txt', 'w')
+    out.write('# generated by decimal_encodings.py\n')
+    for fmt, (k, p, emax) in FORMATS.items():
+        inputs = ['0', '-0', '1', '-7.50', '9' * p + 'e' + str(emax - p + 1),
+                  '1e' + str(1 - emax - p + 1), '1e' + str(1 - emax),
+                  'Inf', '-Inf']
+        inputs += [random_input(rnd, fmt) for _ in range(300)]
+        for name, mode in ROUNDINGS.items():
+            ctx = decimal.Context(prec=p, Emax=emax, Emin=1 - emax, clamp=1,
+                                  rounding=mode, traps=[])
+            for s in inputs:
+                x = ctx.create_decimal(s)
+                dpd, bid = encode(x, fmt)
+                out.write('%s %s %s %x %x\n' % (fmt, name, s, dpd, bid))
+
+
+main()
@@ -0,0 +1,117 @@
+#!/usr/bin/env python3
+# Generate transcendental.txt: correctly rounded values of the
+# transcendental and special functions computed with mpmath.
+#
+# Each line is: function prec rounding x [y] result
+# with the numbers written as hexadecimal integers with a binary
+# exponent ('p'). The reference is evaluated at two higher precisions
+# and the inputs for which the two roundings differ are skipped. The
+# results which are exact at the higher precision are kept exact.
+import random
+
+import mpmath
+from mpmath.libmp import mpf_pos
+
+ROUNDINGS = {
+    # name: mpmath rounding
+    'NearestEven': 'n',
+    'TowardZero': 'd',
+    'Down': 'f',
+    'Up': 'c',
+    'AwayFromZero': 'u',
+}
+
+PRECS = [24, 53, 113, 237]
+
+FUNCS = {
+    # name: (mpmath function, argument range, exponent range)
+    'expm1': (mpmath.expm1, None, (-40, 6)),
+    'log1p': (mpmath.log1p, lambda x: x > -1, (-40, 8)),
+    'log2': (lambda x: mpmath.log(x, 2), lambda x: x > 0, (-100, 100)),
+    'log10': (mpmath.log10, lambda x: x > 0, (-100, 100)),
+    'cbrt': (lambda x: mpmath.sign(x) * mpmath.cbrt(abs(x)), None, (-100, 100)),
+    'sinh': (mpmath.sinh, None, (-30, 6)),
+    'cosh': (mpmath.cosh, None, (-30, 6)),
+    'tanh': (mpmath.tanh, None, (-30, 5)),
+    'asinh': (mpmath.asinh, None, (-30, 30)),
+    'acosh': (mpmath.acosh, lambda x: x > 1, (0, 30)),
+    'atanh': (mpmath.atanh, lambda x: abs(x) < 1, (-30, 0)),
+    'hypot': (mpmath.hypot, None, (-50, 50)),
+    'gamma': (mpmath.gamma, lambda x: x > -30, (-10, 5)),
+    'lgamma': (lambda x: mpmath.log(abs(mpmath.gamma(x))),
+               lambda x: x > -30, (-10, 7)),
+    'erf': (mpmath.erf, None, (-30, 3)),
+    'erfc': (mpmath.erfc, lambda x: x < 25, (-30, 5)),
+    'zeta': (mpmath.zeta, lambda x: x != 1 and x > -40, (-10, 6)),
+}
+
+# exact results and special points
+EXTRA = {
+    'cbrt': ['27', '-8'],
+    'gamma': ['5', '1', '-2.5', '0.5'],
+    'lgamma': ['1', '2', '-0.5'],
+    'zeta': ['2', '-1', '0.5', '-7'],
+    'log2': ['1024', '3'],
+    'log10': ['10', '1000'],
+    'hypot': [('3', '4')],
+}
+
+
+def fmt(x):
+    sign, man, exp, _ = x._mpf_
+    if man == 0:
+        return '-0' if sign else '0'
+    return '%s%xp%d' % ('-' if sign else '', man, exp)
+
+
+def random_input(rnd, prec, erange):
+    man = rnd.getrandbits(prec - 1) | 1 << (prec - 1)
+    e = rnd.randint(*erange) - prec
+    x = mpmath.mpf(man) * mpmath.mpf(2) ** e
+    return -x if rnd.random() < 0.5 else x
+
+
+def reference(f, args, prec, rnd):
+    r = []
+    for extra in (100, 300):
+        with mpmath.workprec(prec + extra):
+            v = f(*args)
+        # the exact results are recognized by their short mantissa
+        t = mpf_pos(v._mpf_, prec + extra - 50, 'n')
+        if t[3] <= prec:
+            v = mpmath.mpf(t)
+        r.append(mpf_pos(v._mpf_, prec, rnd))
+    if r[0] != r[1]:
+        return None
+    return mpmath.mpf(r[0])
+
+
+def main():
+    rnd = random.Random(2024)
+    mpmath.mp.prec = 1000
+    out = open('transcendental.txt', 'w')
+    out.write('# generated by transcendental.py\n')
+    for name, (f, domain, erange) in FUNCS.items():
+        nargs = 2 if name == 'hypot' else 1
+        for prec in PRECS:
+            inputs = []
+            for s in EXTRA.get(name, []):
+                if isinstance(s, tuple):
+                    inputs.append(tuple(mpmath.mpf(v) for v in s))
+                else:
+                    inputs.append((mpmath.mpf(s),))
+            while len(inputs) < 12:
+                args = tuple(random_input(rnd, prec, erange)
+                             for _ in range(nargs))
+                if domain is None or domain(args[0]):
+                    inputs.append(args)
+            for args in inputs:
+                for rname, mode in ROUNDINGS.items():
+                    r = reference(f, args, prec, mode)
+                    if r is None:
+                        continue
+                    out.write('%s %d %s %s %s\n' % (
+                        name, prec, rname, ' '.join(map(fmt, args)), fmt(r)))
+
+
+main()
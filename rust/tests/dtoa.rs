@@ -0,0 +1,218 @@
+/* the conversions of the JS numbers are compared with the ones of
+libbf in the float64 format */
+use slimjs::bigfloat::{BigFloat, BigFloatEnv, Rounding};
+use slimjs::bigint::BigInt;
+use slimjs::dtoa::{
+    atod, to_exponential, to_fixed, to_precision, to_string, ATOD_ACCEPT_UNDERSCORES, ATOD_INT_ONLY,
+};
+
+mod common;
+use common::Lcg;
+
+fn random_f64(rng: &mut Lcg) -> f64 {
+    loop {
+        let d = f64::from_bits(rng.next() << 11 ^ rng.next());
+        if d.is_finite() {
+            return d;
+        }
+    }
+}
+
+/* libbf environment with the float64 format */
+fn f64_env() -> BigFloatEnv {
+    let mut env = BigFloatEnv::new(53);
+    env.set_exp_bits(11);
+    env.set_subnormal(true);
+    env
+}
+
+/* the decimal strings compared here are exact at this precision */
+fn exact(s: &str) -> BigFloat {
+    BigFloatEnv::new(8000).parse(s, 10).unwrap()
+}
+
+/* number of significant digits of a decimal string */
+fn significant_digits(s: &str) -> usize {
+    let mantissa = s.split('e').next().unwrap();
+    let digits: String = mantissa.chars().filter(|c| c.is_ascii_digit()).collect();
+    digits.trim_start_matches('0').trim_end_matches('0').len()
+}
+
+/* insert random separators between the digits, but not after a
+leading zero */
+fn add_underscores(rng: &mut Lcg, s: &str, radix: u32) -> String {
+    let mut r = String::new();
+    let mut last = ' ';
+    for (i, c) in s.chars().enumerate() {
+        if last.is_digit(radix)
+            && c.is_digit(radix)
+            && !(i == 1 && last == '0')
+            && rng.below(8) == 0
+        {
+            r.push('_');
+        }
+        r.push(c);
+        last = c;
+    }
+    r
+}
+
+#[test]
+fn dtoa_vs_libbf() {
+    /* the shortest representation at the float64 precision */
+    let mut env = BigFloatEnv::thread_default();
+    env.set_prec(53);
+    BigFloatEnv::set_thread_default(env);
+    let mut rng = Lcg(1);
+    for _ in 0..3000 {
+        let d = random_f64(&mut rng);
+        let s = to_string(d);
+        assert_eq!(s.parse::<f64>(), Ok(d), "{}", s);
+        if d.is_normal() {
+            let t = BigFloat::from(d).to_string_radix(10);
+            assert_eq!(
+                significant_digits(&s),
+                significant_digits(&t),
+                "{} {}",
+                s,
+                t
+            );
+        }
+
+        let a = BigFloat::from(d);
+        let n = rng.below(101);
+        if d.abs() < 1e21 {
+            let t = a.to_fixed(n, Rounding::NearestAway);
+            assert!(exact(&to_fixed(d, n as u32)) == exact(&t), "{} {}", d, n);
+        }
+        let t = a.to_exponential(n, Rounding::NearestAway);
+        assert!(
+            exact(&to_exponential(d, Some(n as u32))) == exact(&t),
+            "{} {}",
+            d,
+            n
+        );
+        let n = n.max(1);
+        let t = a.to_precision(n, Rounding::NearestAway);
+        assert!(
+            exact(&to_precision(d, n as u32)) == exact(&t),
+            "{} {}",
+            d,
+            n
+        );
+    }
+    /* ties */
+    assert_eq!(&*to_fixed(0.5, 0), "1");
+    assert_eq!(&*to_fixed(2.5, 0), "3");
+    assert_eq!(&*to_fixed(1.25, 1), "1.3");
+    assert_eq!(&*to_precision(1.5, 1), "2");
+    assert_eq!(&*to_exponential(12.5, Some(1)), "1.3e+1");
+    assert_eq!(&*to_string(5e-324), "5e-324");
+    assert_eq!(&*to_string(1e21), "1e+21");
+}
+
+fn check_parse(s: &str, radix: u32, flags: u32) {
+    let (d, len) = atod(s, radix, flags).unwrap();
+    assert_eq!(len, s.len(), "{}", s);
+    let t = s.replace('_', "");
+    let r = f64_env().parse(&t, radix).unwrap().to_f64();
+    assert_eq!(d.to_bits(), r.to_bits(), "radix={} {}", radix, s);
+}
+
+#[test]
+fn atod_decimal_vs_libbf() {
+    let mut rng = Lcg(2);
+    for _ in 0..3000 {
+        let n = [1, 5, 17, 20, 40, 300, 900][rng.below(7) as usize];
+        let mut s: String = (0..n)
+            .map(|_| (b'0' + rng.below(10) as u8) as char)
+            .collect();
+        let point = rng.below(n as u64 + 1) as usize;
+        if point < n {
+            s.insert(point, '.');
+        }
+        let e = rng.below(800) as i64 - 400 - n as i64 / 2;
+        let s = format!("{}e{}", s, e);
+        let s = add_underscores(&mut rng, &s, 10);
+        check_parse(&s, 10, ATOD_ACCEPT_UNDERSCORES);
+    }
+    /* the middle of two consecutive numbers, written exactly, and the
+    numbers just above */
+    let mut env = BigFloatEnv::new(64);
+    for _ in 0..500 {
+        let d = random_f64(&mut rng).abs();
+        if d == f64::MAX {
+            continue;
+        }
+        let next = f64::from_bits(d.to_bits() + 1);
+        let m = env.add(&BigFloat::from(d), &BigFloat::from(next));
+        let m = env.div(&m, &BigFloat::from(2.0));
+        let s = m.to_fixed(1100, Rounding::NearestEven);
+        let s = s.trim_end_matches('0');
+        check_parse(s, 10, 0);
+        check_parse(&format!("{}1", s), 10, 0);
+        check_parse(&format!("{}00000000001e-3", s), 10, 0);
+        let s = add_underscores(&mut rng, s, 10);
+        check_parse(&s, 10, ATOD_ACCEPT_UNDERSCORES);
+    }
+    check_parse("0.000_000_1e1_0", 10, ATOD_ACCEPT_UNDERSCORES);
+    check_parse("1e400", 10, 0);
+    check_parse("1e-400", 10, 0);
+    /* the exponents larger than the ones of libbf */
+    assert_eq!(atod("0e99999999999999999999", 10, 0), Some((0.0, 22)));
+    assert_eq!(atod("1e-99999999999999999999", 10, 0), Some((0.0, 23)));
+    assert_eq!(
+        atod("1e99999999999999999999", 10, 0),
+        Some((f64::INFINITY, 22))
+    );
+}
+
+#[test]
+fn atod_radix_vs_libbf() {
+    let mut rng = Lcg(3);
+    for _ in 0..3000 {
+        let radix = rng.below(35) as u32 + 2;
+        let n = rng.below(700) + 1;
+        let s: String = (0..n)
+            .map(|_| std::char::from_digit(rng.below(radix as u64) as u32, radix).unwrap())
+            .collect();
+        check_parse(&s, radix, ATOD_INT_ONLY);
+        let s = add_underscores(&mut rng, &s, radix);
+        check_parse(&s, radix, ATOD_INT_ONLY | ATOD_ACCEPT_UNDERSCORES);
+    }
+    /* the integers around the middle of two consecutive numbers */
+    for _ in 0..1000 {
+        let e = rng.below(1000) + 1;
+        let mant = BigInt::from(rng.next() | 1 << 52);
+        let m = &(&(&mant << 1) + &BigInt::one()) << (e - 1);
+        let radix = [3, 5, 6, 7, 10, 12, 36][rng.below(7) as usize];
+        for k in [-1, 0, 1] {
+            let v = &m + &BigInt::from(k);
+            check_parse(&v.to_string_radix(radix), radix, ATOD_INT_ONLY);
+        }
+    }
+}
+
+#[test]
+fn subnormal_decimal_ties() {
+    /* bf_mul_pow_radix() rounded the decimal numbers in the subnormal
+    range at the full precision before rounding them to the subnormal
+    precision, so the ties were rounded twice */
+    let mut rng = Lcg(11);
+    let mut env = f64_env();
+    let pow5 = BigInt::from(5u64).pow(1075);
+    for i in 0..200 {
+        let m = if i < 100 { i } else { rng.below(1 << 52) };
+        /* exactly between m * 2^-1074 and (m + 1) * 2^-1074 */
+        let tie = (BigInt::from(2 * m + 1) * &pow5).to_string();
+        for s in &[
+            format!("{}e-1075", tie),
+            format!("{}1e-1076", tie),
+            format!("{}4999e-1078", &tie[..tie.len() - 1]),
+        ] {
+            let expected: f64 = s.parse().unwrap();
+            let r = env.parse(s, 10).unwrap();
+            assert_eq!(r.to_f64().to_bits(), expected.to_bits(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use slimjs::bigfloat::{BigFloat, BigFloatEnv};
+use slimjs::interval::{Interval, IntervalEnv};
+
+mod common;
+use common::Lcg;
+
+/* random 53 bit number in [-8, 8] */
+fn random_number(rng: &mut Lcg) -> BigFloat {
+    let m = rng.next() as i64 - (1 << 52);
+    BigFloat::from(m as f64 / (1u64 << 49) as f64)
+}
+
+fn random_interval(rng: &mut Lcg) -> Interval {
+    let a = random_number(rng);
+    let b = random_number(rng);
+    if a <= b {
+        Interval::new(a, b)
+    } else {
+        Interval::new(b, a)
+    }
+}
+
+/* the bounds of 'a' and points inside it */
+fn sample_points(rng: &mut Lcg, a: &Interval) -> Vec<BigFloat> {
+    let mut env = BigFloatEnv::new(200);
+    let mut v = vec![a.lo().clone(), a.hi().clone()];
+    let w = env.sub(a.hi(), a.lo());
+    for _ in 0..4 {
+        let t = BigFloat::from(rng.next() as f64 / (1u64 << 53) as f64);
+        let x = env.mul(&w, &t);
+        v.push(env.add(a.lo(), &x));
+    }
+    v
+}
+
+/* the results of the operations on points of the operands, computed
+with 1000 bits, must be in the 53 bit result intervals */
+#[test]
+fn containment() {
+    let mut rng = Lcg(11);
+    let mut ienv = IntervalEnv::new(&BigFloatEnv::new(53));
+    let mut env = BigFloatEnv::new(1000);
+    for _ in 0..200 {
+        let a = random_interval(&mut rng);
+        let b = random_interval(&mut rng);
+        let xs = sample_points(&mut rng, &a);
+        let ys = sample_points(&mut rng, &b);
+
+        let add = ienv.add(&a, &b);
+        let sub = ienv.sub(&a, &b);
+        let mul = ienv.mul(&a, &b);
+        let div = ienv.div(&a, &b);
+        for x in &xs {
+            for y in &ys {
+                assert!(add.contains(&env.add(x, y)), "{} + {}", a, b);
+                assert!(sub.contains(&env.sub(x, y)), "{} - {}", a, b);
+                assert!(mul.contains(&env.mul(x, y)), "{} * {}", a, b);
+                if !y.is_zero() {
+                    assert!(div.contains(&env.div(x, y)), "{} / {}", a, b);
+                }
+            }
+        }
+
+        let exp = ienv.exp(&a);
+        let sin = ienv.sin(&a);
+        let cos = ienv.cos(&a);
+        for x in &xs {
+            assert!(exp.contains(&env.exp(x)), "exp {}", a);
+            assert!(sin.contains(&env.sin(x)), "sin {}", a);
+            assert!(cos.contains(&env.cos(x)), "cos {}", a);
+        }
+
+        /* the part of the operand below 0 is ignored */
+        let sqrt = ienv.sqrt(&a);
+        let log = ienv.log(&a);
+        for x in xs.iter().filter(|x| !x.is_sign_negative()) {
+            assert!(sqrt.contains(&env.sqrt(x)), "sqrt {}", a);
+            if !x.is_zero() {
+                assert!(log.contains(&env.log(x)), "log {}", a);
+            }
+        }
+        assert_eq!(sqrt.is_nan(), a.hi().is_sign_negative());
+        assert_eq!(log.is_nan(), a.hi().is_sign_negative());
+    }
+}
+
+fn interval(lo: f64, hi: f64) -> Interval {
+    Interval::new(BigFloat::from(lo), BigFloat::from(hi))
+}
+
+#[test]
+fn division_by_zero() {
+    let mut ienv = IntervalEnv::new(&BigFloatEnv::new(53));
+    let a = interval(1.0, 2.0);
+    let zero = interval(0.0, 0.0);
+    for b in &[interval(-1.0, 1.0), interval(0.0, 1.0), interval(-1.0, 0.0)] {
+        let q = ienv.div(&a, b);
+        assert!(*q.lo() == BigFloat::neg_infinity() && *q.hi() == BigFloat::infinity());
+        assert!(ienv.div(&zero, b) == zero);
+    }
+    assert!(ienv.div(&a, &zero).is_nan());
+    assert!(ienv.div(&zero, &zero).is_nan());
+    /* the NaN interval is propagated */
+    let q = ienv.div(&a, &zero);
+    assert!(ienv.add(&q, &a).is_nan());
+    assert!(ienv.div(&Interval::nan(), &a).is_nan());
+    /* an infinite divisor bound */
+    let q = ienv.div(&a, &Interval::new(BigFloat::from(2), BigFloat::infinity()));
+    assert!(q.lo().is_zero() && *q.hi() == BigFloat::from(1));
+}
+
+#[test]
+fn trigonometric_extrema() {
+    let mut ienv = IntervalEnv::new(&BigFloatEnv::new(53));
+    let mut env = BigFloatEnv::new(53);
+    let one = BigFloat::from(1);
+    let minus_one = BigFloat::from(-1);
+
+    /* pi/2 in [1, 2]: maximum of sin */
+    let r = ienv.sin(&interval(1.0, 2.0));
+    assert!(*r.hi() == one && *r.lo() < one && r.lo().is_finite());
+    /* no extremum of sin in [2, 4], pi is not one */
+    let r = ienv.sin(&interval(2.0, 4.0));
+    assert!(*r.hi() < one && *r.lo() > minus_one);
+    assert!(r.contains(&BigFloat::zero()));
+    /* pi in [3, 3.5]: minimum of cos */
+    let r = ienv.cos(&interval(3.0, 3.5));
+    assert!(*r.lo() == minus_one && *r.hi() < BigFloat::from(-0.9));
+    /* 0 in [-0.5, 0.5]: maximum of cos */
+    let r = ienv.cos(&interval(-0.5, 0.5));
+    assert!(*r.hi() == one && *r.lo() > BigFloat::from(0.8));
+    /* both extrema */
+    let r = ienv.sin(&interval(1.0, 5.0));
+    assert!(*r.lo() == minus_one && *r.hi() == one);
+    let r = ienv.cos(&Interval::entire());
+    assert!(*r.lo() == minus_one && *r.hi() == one);
+
+    /* the tightest interval around pi contains a zero of sin and the
+    minimum of cos */
+    let pi = ienv.const_pi();
+    let r = ienv.sin(&pi);
+    assert!(*r.lo() < BigFloat::zero() && *r.hi() > BigFloat::zero());
+    assert!(r.hi().abs() < BigFloat::from(1e-15) && r.lo().abs() < BigFloat::from(1e-15));
+    let r = ienv.cos(&pi);
+    assert!(*r.lo() == minus_one && *r.hi() > minus_one);
+
+    /* a large argument: the multiples of pi are computed with enough
+    precision */
+    let x = BigFloat::from(1e22);
+    let r = ienv.sin(&Interval::point(&x));
+    let mut env1 = BigFloatEnv::new(1000);
+    let s = env1.sin(&x);
+    assert!(r.contains(&s));
+    assert!(*r.lo() == env.round(&s) || *r.hi() == env.round(&s));
+    assert!(*r.hi() < one && *r.lo() > minus_one);
+}
+
+#[test]
+fn const_enclosures() {
+    let mut env = BigFloatEnv::new(2000);
+    let pi = env.const_pi();
+    let log2 = env.const_log2();
+    let tenth = env.parse("0.1", 10).unwrap();
+    for &prec in &[24u64, 53, 113, 1000] {
+        let mut ienv = IntervalEnv::new(&BigFloatEnv::new(prec));
+        /* the bounds are consecutive numbers of 'prec' bits */
+        let check = |r: Interval, x: &BigFloat, ulp_expn: i64| {
+            assert!(r.contains(x) && *r.lo() < *r.hi(), "prec={}", prec);
+            let mut env = BigFloatEnv::new(prec + 10);
+            let ulp = env.parse(&format!("1p{}", ulp_expn), 2).unwrap();
+            assert!(env.sub(r.hi(), r.lo()) == ulp, "prec={}", prec);
+        };
+        /* pi is in [2, 4), log(2) in [1/2, 1) and 0.1 in [1/16, 1/8) */
+        check(ienv.const_pi(), &pi, 2 - prec as i64);
+        check(ienv.const_log2(), &log2, -(prec as i64));
+        check(ienv.parse("0.1", 10).unwrap(), &tenth, -3 - prec as i64);
+        let one = ienv.parse("1", 10).unwrap();
+        assert!(one == Interval::point(&BigFloat::from(1)));
+    }
+}
@@ -0,0 +1,156 @@
+use slimjs::bigfloat::{BigFloat, BigFloatEnv};
+use slimjs::bigrational::BigRational;
+use slimjs::matrix::Matrix;
+
+mod common;
+use common::Lcg;
+
+type Mat = Matrix<BigRational>;
+
+fn q(v: i64) -> BigRational {
+    BigRational::from(v)
+}
+
+fn mat(rows: &[&[i64]]) -> Mat {
+    Matrix::from_rows(
+        rows.iter()
+            .map(|r| r.iter().map(|&c| q(c)).collect())
+            .collect(),
+    )
+}
+
+fn hilbert(n: usize) -> Mat {
+    let mut h = Matrix::zero(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            h[(i, j)] = BigRational::new(1.into(), (i as i64 + j as i64 + 1).into());
+        }
+    }
+    h
+}
+
+fn random_matrix(rng: &mut Lcg, rows: usize, cols: usize) -> Mat {
+    let data = (0..rows * cols)
+        .map(|_| {
+            BigRational::new(
+                (rng.below(41) as i64 - 20).into(),
+                (rng.below(5) as i64 + 1).into(),
+            )
+        })
+        .collect();
+    Matrix::from_vec(rows, cols, data)
+}
+
+/* the rows of 'a' in the order of 'perm' */
+fn permute(a: &Mat, perm: &[usize]) -> Mat {
+    Matrix::from_rows(perm.iter().map(|&i| a.row(i).to_vec()).collect())
+}
+
+#[test]
+fn rational_kernels() {
+    /* the Hilbert matrices have known determinants and inverses with
+    integer entries whose sum is n^2 */
+    let h = hilbert(6);
+    assert_eq!(
+        h.det(),
+        BigRational::new(1.into(), 186313420339200000i64.into())
+    );
+    let inv = h.inverse().unwrap();
+    assert!(inv.as_slice().iter().all(|c| c.is_integer()));
+    assert_eq!(inv[(0, 0)], q(36));
+    assert_eq!(inv[(5, 5)], q(698544));
+    assert_eq!(inv.as_slice().iter().fold(q(0), |s, c| &s + c), q(36));
+    assert_eq!(&h * &inv, Matrix::identity(6));
+    assert_eq!(inv.det(), h.det().recip());
+
+    let a = mat(&[&[2, 1, 1], &[4, -6, 0], &[-2, 7, 2]]);
+    assert_eq!(a.det(), q(-16));
+    let b = mat(&[&[5], &[-2], &[9]]);
+    assert_eq!(a.solve(&b).unwrap(), mat(&[&[1], &[1], &[2]]));
+    /* the first pivot is zero */
+    let p = mat(&[&[0, 1], &[1, 0]]);
+    assert_eq!(p.det(), q(-1));
+    let lu = p.lu().unwrap();
+    assert_eq!(lu.perm(), [1, 0]);
+    assert_eq!(lu.det(), q(-1));
+    /* singular matrices */
+    let s = mat(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+    assert_eq!(s.det(), q(0));
+    assert!(s.lu().is_none() && s.inverse().is_none() && s.solve(&b).is_none());
+    assert_eq!(Mat::zero(0, 0).det(), q(1));
+
+    let mut rng = Lcg(1);
+    for _ in 0..50 {
+        let n = rng.below(6) as usize + 1;
+        let a = random_matrix(&mut rng, n, n);
+        let b = random_matrix(&mut rng, n, n);
+        let c = random_matrix(&mut rng, n, 2);
+        assert_eq!((&a * &b).det(), &a.det() * &b.det());
+        assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+        assert_eq!(&(&a + &b) - &b, a);
+        if let Some(lu) = a.lu() {
+            /* P * A = L * U exactly */
+            assert_eq!(permute(&a, lu.perm()), &lu.l() * &lu.u());
+            assert_eq!(lu.det(), a.det());
+            let x = a.solve(&c).unwrap();
+            assert_eq!(&a * &x, c);
+            assert_eq!(lu.solve(&c), x);
+            assert_eq!(&a * &a.inverse().unwrap(), Matrix::identity(n));
+        } else {
+            assert_eq!(a.det(), q(0));
+        }
+    }
+}
+
+#[test]
+fn bigfloat_kernels() {
+    let mut env = BigFloatEnv::new(53);
+    let mut rng = Lcg(2);
+    for _ in 0..30 {
+        let n = rng.below(8) as usize + 1;
+        let a = random_matrix(&mut rng, n, n);
+        let b = random_matrix(&mut rng, n, 3);
+        let fa = a.to_bigfloat(&mut env);
+        let fb = b.to_bigfloat(&mut env);
+        let exact = |m: &Matrix<BigFloat>| {
+            Matrix::from_vec(
+                m.rows(),
+                m.cols(),
+                m.as_slice()
+                    .iter()
+                    .map(|c| BigRational::from_bigfloat(c).unwrap())
+                    .collect(),
+            )
+        };
+        /* the dot products are rounded once */
+        let r = (&exact(&fa) * &exact(&fb)).to_bigfloat(&mut env);
+        assert!(fa.mul(&fb, &mut env) == r);
+        assert!(fa.add(&fa, &mut env) == (&exact(&fa) + &exact(&fa)).to_bigfloat(&mut env));
+
+        /* the determinant and the solution are close to the exact values
+        of the rounded matrix */
+        let ea = exact(&fa);
+        let det = ea.det();
+        if det.is_zero() {
+            continue;
+        }
+        let fdet = BigRational::from_bigfloat(&fa.det(&mut env)).unwrap();
+        let tol = BigRational::new(1.into(), (1i64 << 40).into());
+        assert!((&fdet - &det).abs() <= &det.abs() * &tol, "{}", a);
+        let x = ea.solve(&exact(&fb)).unwrap();
+        let fx = exact(&fa.solve(&fb, &mut env).unwrap());
+        let scale = x.as_slice().iter().fold(q(1), |m, c| m.max(c.abs()));
+        for (c, fc) in x.as_slice().iter().zip(fx.as_slice()) {
+            assert!((c - fc).abs() <= &scale * &tol, "{}", a);
+        }
+    }
+
+    /* exact determinants of the permutation and triangular matrices */
+    let p = mat(&[&[0, 0, 1], &[1, 0, 0], &[0, 1, 0]]).to_bigfloat(&mut env);
+    assert!(p.det(&mut env) == BigFloat::from(1));
+    let t = mat(&[&[2, 5, 7], &[0, -3, 1], &[0, 0, 4]]).to_bigfloat(&mut env);
+    assert!(t.det(&mut env) == BigFloat::from(-24));
+    let s = mat(&[&[1, 2], &[2, 4]]).to_bigfloat(&mut env);
+    assert!(s.lu(&mut env).is_none());
+    assert!(s.det(&mut env).is_zero());
+}
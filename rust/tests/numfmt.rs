@@ -0,0 +1,145 @@
+use slimjs::bigdecimal::{BigDecimal, BigDecimalEnv};
+use slimjs::bigfloat::{BigFloat, BigFloatEnv, Rounding};
+use slimjs::numfmt::{Notation, NumberFormat};
+
+mod common;
+use common::Lcg;
+
+/* random f64 number between 2^-100 and 2^100 */
+fn random_number(rng: &mut Lcg) -> BigFloat {
+    let e = rng.below(201) + 1023 - 100;
+    let sign = rng.below(2) << 63;
+    BigFloat::from(f64::from_bits(
+        sign | e << 52 | rng.next() & ((1 << 52) - 1),
+    ))
+}
+
+fn bf(s: &str) -> BigFloat {
+    s.parse().unwrap()
+}
+
+/* formats with a separator which is not a digit in 'radix' */
+fn formats(radix: u32) -> Vec<NumberFormat> {
+    let f = NumberFormat::new().radix(radix);
+    let group = if radix <= 10 { ',' } else { '_' };
+    let mut v = Vec::new();
+    for &notation in &[
+        Notation::Standard,
+        Notation::Scientific,
+        Notation::Engineering,
+    ] {
+        let f = f.clone().notation(notation);
+        v.push(f.clone());
+        v.push(f.clone().grouping(group, 3));
+        v.push(f.clone().grouping(group, 3).secondary_grouping(2));
+        v.push(f.clone().grouping(' ', 4).decimal_separator(','));
+        v.push(f.clone().plus_sign(true).uppercase(true));
+        v.push(f.clone().min_integer_digits(5));
+        v.push(f.clone().exponent_marker("*^"));
+    }
+    v
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = Lcg(1);
+    let mut env = BigFloatEnv::thread_default();
+    for &radix in &[2u32, 3, 8, 10, 16, 36] {
+        let formats = formats(radix);
+        for _ in 0..100 {
+            let a = random_number(&mut rng);
+            for f in &formats {
+                /* the shortest representation is parsed back exactly */
+                let s = f.format(&a);
+                let b = f.parse(&s, &mut env).unwrap();
+                assert!(a == b, "radix={} {:?} {}", radix, f, s);
+            }
+        }
+    }
+    let f = NumberFormat::new();
+    for a in &[
+        BigFloat::zero(),
+        BigFloat::neg_zero(),
+        BigFloat::infinity(),
+        BigFloat::neg_infinity(),
+        BigFloat::nan(),
+    ] {
+        let b = f.parse(&f.format(&a), &mut env).unwrap();
+        assert_eq!(b.to_string(), a.to_string());
+        assert_eq!(b.is_sign_negative(), a.is_sign_negative());
+    }
+}
+
+#[test]
+fn round_trip_rounded() {
+    /* with 'n' fraction digits, the result rounded down is at most
+    radix^-n below the number */
+    let mut rng = Lcg(2);
+    let mut env = BigFloatEnv::new(200);
+    for &radix in &[2u32, 7, 10, 16] {
+        for &n in &[0u64, 1, 5, 20] {
+            let f = NumberFormat::new()
+                .radix(radix)
+                .grouping('_', 3)
+                .fraction_digits(0, n)
+                .rounding(Rounding::Down);
+            let marker = if radix == 10 { 'e' } else { '@' };
+            let ulp = env.parse(&format!("1{}-{}", marker, n), radix).unwrap();
+            for _ in 0..100 {
+                let a = random_number(&mut rng);
+                let b = f.parse(&f.format(&a), &mut env).unwrap();
+                let d = env.sub(&a, &b);
+                assert!(
+                    b <= a && d < ulp,
+                    "radix={} n={} {}",
+                    radix,
+                    n,
+                    f.format(&a)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn grouping() {
+    let f = NumberFormat::new().grouping(',', 3);
+    assert_eq!(f.format(&bf("1234567.891")), "1,234,567.891");
+    assert_eq!(f.format(&bf("-1234")), "-1,234");
+    assert_eq!(f.format(&bf("123")), "123");
+    let f2 = f.clone().secondary_grouping(2);
+    assert_eq!(f2.format(&bf("1234567")), "12,34,567");
+    let f3 = NumberFormat::new()
+        .radix(16)
+        .uppercase(true)
+        .grouping('_', 4);
+    assert_eq!(f3.format(&bf("4294967295")), "FFFF_FFFF");
+    let f4 = NumberFormat::new().decimal_separator(',').grouping('.', 3);
+    assert_eq!(f4.format(&bf("1234567.25")), "1.234.567,25");
+
+    let mut env = BigFloatEnv::thread_default();
+    assert!(f.parse("1,234,567.5", &mut env).unwrap() == bf("1234567.5"));
+    assert!(f4.parse("1.234.567,5", &mut env).unwrap() == bf("1234567.5"));
+    /* the separators must be between two digits of the integer part */
+    assert!(f.parse(",123", &mut env).is_err());
+    assert!(f.parse("123,", &mut env).is_err());
+    assert!(f.parse("1,,234", &mut env).is_err());
+    assert!(f.parse("1.234,5", &mut env).is_err());
+}
+
+#[test]
+fn decimal_round_trip() {
+    let mut rng = Lcg(3);
+    let mut env = BigDecimalEnv::new(30);
+    let f = NumberFormat::new().grouping(',', 3);
+    let g = NumberFormat::new().notation(Notation::Engineering);
+    for _ in 0..200 {
+        let digits = rng.below(1_000_000_000_000);
+        let e = rng.below(41) as i64 - 20;
+        let a: BigDecimal = format!("{}e{}", digits, e).parse().unwrap();
+        for f in &[&f, &g] {
+            let s = f.format_decimal(&a);
+            assert!(f.parse_decimal(&s, &mut env).unwrap() == a, "{}", s);
+        }
+    }
+}
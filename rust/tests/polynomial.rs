@@ -0,0 +1,196 @@
+use slimjs::bigcomplex::{BigComplex, ComplexEnv};
+use slimjs::bigfloat::{BigFloat, BigFloatEnv};
+use slimjs::bigint::BigInt;
+use slimjs::bigrational::BigRational;
+use slimjs::polynomial::Polynomial;
+
+mod common;
+use common::Lcg;
+
+type Poly = Polynomial<BigRational>;
+
+fn q(v: i64) -> BigRational {
+    BigRational::from(v)
+}
+
+fn poly(coefs: &[i64]) -> Poly {
+    Polynomial::new(coefs.iter().map(|&c| q(c)).collect())
+}
+
+fn random_poly(rng: &mut Lcg, len: u64, bits: u32) -> Poly {
+    let n = rng.below(len + 1);
+    Polynomial::new(
+        (0..n)
+            .map(|_| {
+                let num = BigInt::from(rng.next() as i64 - (1 << 52)) << bits as u64;
+                let den = BigInt::from(rng.below(1000) as i64 + 1);
+                BigRational::new(num, den)
+            })
+            .collect(),
+    )
+}
+
+/* product with the schoolbook method */
+fn school(a: &Poly, b: &Poly) -> Poly {
+    if a.is_zero() || b.is_zero() {
+        return Polynomial::zero();
+    }
+    let (a, b) = (a.coefs(), b.coefs());
+    let mut r = vec![q(0); a.len() + b.len() - 1];
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            r[i + j] = &r[i + j] + &(&a[i] * &b[j]);
+        }
+    }
+    Polynomial::new(r)
+}
+
+#[test]
+fn products() {
+    /* the coefficients of (x + 1)^n are the binomial coefficients */
+    let n = 40;
+    let mut p = poly(&[1]);
+    for _ in 0..n {
+        p = &p * &poly(&[1, 1]);
+    }
+    let mut binomial = BigInt::one();
+    for k in 0..=n {
+        assert_eq!(p.coef(k), BigRational::from(&binomial), "{}", k);
+        binomial = &(&binomial * &BigInt::from((n - k) as i64)) / &BigInt::from(k as i64 + 1);
+    }
+    assert_eq!(p.degree(), Some(n));
+    assert!((&p * &Poly::zero()).is_zero());
+
+    let mut rng = Lcg(1);
+    for &(len, bits) in &[(5, 0), (20, 100), (300, 10)] {
+        for _ in 0..5 {
+            let a = random_poly(&mut rng, len, bits);
+            let b = random_poly(&mut rng, len, bits);
+            assert_eq!(&a * &b, school(&a, &b));
+            assert_eq!(&a * &a, school(&a, &a));
+        }
+    }
+
+    /* the BigFloat coefficients are correctly rounded */
+    let mut env = BigFloatEnv::new(53);
+    for _ in 0..20 {
+        let a = random_poly(&mut rng, 30, 0);
+        let b = random_poly(&mut rng, 30, 0);
+        let fa = a.to_bigfloat(&mut env);
+        let fb = b.to_bigfloat(&mut env);
+        let exact = |p: &Polynomial<BigFloat>| {
+            Polynomial::new(
+                p.coefs()
+                    .iter()
+                    .map(|c| BigRational::from_bigfloat(c).unwrap())
+                    .collect(),
+            )
+        };
+        let r = school(&exact(&fa), &exact(&fb)).to_bigfloat(&mut env);
+        assert!(fa.mul(&fb, &mut env) == r);
+    }
+}
+
+#[test]
+fn division() {
+    let (qt, r) = poly(&[-1, 0, 0, 0, 0, 1]).div_rem(&poly(&[-1, 1]));
+    assert_eq!(qt, poly(&[1, 1, 1, 1, 1]));
+    assert!(r.is_zero());
+    /* (x^3 + 2x + 1) = (2x^2 + 1) x/2 + 3x/2 + 1 */
+    let (qt, r) = poly(&[1, 2, 0, 1]).div_rem(&poly(&[1, 0, 2]));
+    assert_eq!(qt, Polynomial::new(vec![q(0), "1/2".parse().unwrap()]));
+    assert_eq!(r, Polynomial::new(vec![q(1), "3/2".parse().unwrap()]));
+    let (qt, r) = poly(&[1, 2]).div_rem(&poly(&[0, 0, 1]));
+    assert!(qt.is_zero() && r == poly(&[1, 2]));
+    assert!(poly(&[1, 2]).checked_div_rem(&Poly::zero()).is_none());
+
+    let mut rng = Lcg(2);
+    for _ in 0..100 {
+        let a = random_poly(&mut rng, 20, 0);
+        let b = random_poly(&mut rng, 10, 0);
+        if b.is_zero() {
+            continue;
+        }
+        let (qt, r) = a.div_rem(&b);
+        assert_eq!(&(&qt * &b) + &r, a);
+        assert!(r.degree() < b.degree());
+        assert_eq!(&a / &b, qt);
+        assert_eq!(&a % &b, r);
+    }
+}
+
+#[test]
+fn gcd_derivative_eval() {
+    let x1 = poly(&[-1, 1]);
+    let h = poly(&[1, 0, 1]);
+    let a = &(&(&x1 * &x1) * &poly(&[2, 1])) * &h;
+    let b = &(&(&x1 * &poly(&[3, 1])) * &h) * &poly(&[7]);
+    /* the gcd is monic */
+    assert_eq!(a.gcd(&b), &x1 * &h);
+    assert_eq!(a.gcd(&Poly::zero()), a);
+    assert_eq!(
+        poly(&[2, 4]).gcd(&Poly::zero()),
+        Polynomial::new(vec!["1/2".parse().unwrap(), q(1)])
+    );
+    assert!(Poly::zero().gcd(&Poly::zero()).is_zero());
+    assert_eq!(poly(&[2, 4]).gcd(&poly(&[3])), poly(&[1]));
+
+    /* x^3 - 2x */
+    let p = poly(&[0, -2, 0, 1]);
+    assert_eq!(p.derivative(), poly(&[-2, 0, 3]));
+    assert!(poly(&[5]).derivative().is_zero());
+    assert_eq!(p.eval(&"1/2".parse().unwrap()), "-7/8".parse().unwrap());
+    assert_eq!(p.eval(&q(-3)), q(-21));
+    assert_eq!(Poly::zero().eval(&q(3)), q(0));
+}
+
+/* |d| <= 2^e */
+fn is_small(d: &BigFloat, e: i64) -> bool {
+    d.abs() <= BigFloat::from(2f64.powi(e as i32))
+}
+
+fn roots(p: &Poly, prec: u64) -> Vec<BigComplex> {
+    p.roots(&mut BigFloatEnv::new(prec))
+}
+
+#[test]
+fn roots_exact() {
+    let mut env = BigFloatEnv::new(53);
+    /* the roots of the degree 1 factors are correctly rounded and
+    repeated with their multiplicity */
+    let r = roots(&poly(&[-1, 3]), 53);
+    assert!(r.len() == 1 && *r[0].re() == env.div(&BigFloat::from(1), &BigFloat::from(3)));
+    let r = roots(&(&(&poly(&[-1, 1]) * &poly(&[-1, 1])) * &poly(&[2, 1])), 53);
+    let re: Vec<f64> = r.iter().map(|z| z.re().to_f64()).collect();
+    assert_eq!(re, [-2.0, 1.0, 1.0]);
+    assert!(r.iter().all(|z| z.im().is_zero()));
+    assert!(roots(&Poly::zero(), 53).is_empty());
+    assert!(roots(&poly(&[3]), 53).is_empty());
+
+    /* x^2 + 1: the real parts below the accuracy are zero */
+    let r = roots(&poly(&[1, 0, 1]), 53);
+    assert!(r == [-BigComplex::i(), BigComplex::i()]);
+
+    /* x^2 - 2 and x^3 - 2 */
+    for &prec in &[53, 200] {
+        let mut env = BigFloatEnv::new(prec + 10);
+        let sqrt2 = env.sqrt(&BigFloat::from(2));
+        let r = roots(&poly(&[-2, 0, 1]), prec);
+        assert_eq!(r.len(), 2);
+        let d = env.sub(r[1].re(), &sqrt2);
+        assert!(is_small(&d, 1 - prec as i64), "{}", prec);
+        assert!(r[0] == -&r[1]);
+
+        let r = roots(&poly(&[-2, 0, 0, 1]), prec);
+        let mut cenv = ComplexEnv::new(&env);
+        for z in &r {
+            /* z^3 = 2 */
+            let z2 = cenv.mul(z, z);
+            let z3 = cenv.mul(&z2, z);
+            let d = env.sub(z3.re(), &BigFloat::from(2));
+            assert!(is_small(&d, 3 - prec as i64), "{}", prec);
+            assert!(is_small(z3.im(), 3 - prec as i64), "{}", prec);
+        }
+        assert!(r[0].im() == &-r[1].im() && r[2].im().is_zero());
+    }
+}
@@ -0,0 +1,72 @@
+/* correct rounding of the transcendental and special functions:
+tests/data/transcendental.txt is generated by
+tests/data/transcendental.py with mpmath */
+use slimjs::bigfloat::{BigFloat, BigFloatEnv, Rounding};
+
+fn rounding(name: &str) -> Rounding {
+    match name {
+        "NearestEven" => Rounding::NearestEven,
+        "TowardZero" => Rounding::TowardZero,
+        "Down" => Rounding::Down,
+        "Up" => Rounding::Up,
+        "AwayFromZero" => Rounding::AwayFromZero,
+        _ => panic!("unknown rounding {}", name),
+    }
+}
+
+fn eval(env: &mut BigFloatEnv, name: &str, args: &[BigFloat]) -> BigFloat {
+    let x = &args[0];
+    match name {
+        "expm1" => env.expm1(x),
+        "log1p" => env.log1p(x),
+        "log2" => env.log2(x),
+        "log10" => env.log10(x),
+        "cbrt" => env.cbrt(x),
+        "sinh" => env.sinh(x),
+        "cosh" => env.cosh(x),
+        "tanh" => env.tanh(x),
+        "asinh" => env.asinh(x),
+        "acosh" => env.acosh(x),
+        "atanh" => env.atanh(x),
+        "hypot" => env.hypot(x, &args[1]),
+        "gamma" => env.gamma(x),
+        "lgamma" => env.lgamma(x),
+        "erf" => env.erf(x),
+        "erfc" => env.erfc(x),
+        "zeta" => env.zeta(x),
+        _ => panic!("unknown function {}", name),
+    }
+}
+
+#[test]
+fn correct_rounding() {
+    let data = include_str!("data/transcendental.txt");
+    /* the numbers of the file are exact at this precision */
+    let mut parse_env = BigFloatEnv::new(1024);
+    let mut count = 0;
+    for line in data.lines() {
+        if line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(' ').collect();
+        let name = fields[0];
+        let prec: u64 = fields[1].parse().unwrap();
+        let args: Vec<BigFloat> = fields[3..fields.len() - 1]
+            .iter()
+            .map(|s| parse_env.parse(s, 16).unwrap())
+            .collect();
+        let expected = parse_env.parse(fields[fields.len() - 1], 16).unwrap();
+
+        let mut env = BigFloatEnv::new(prec);
+        env.set_rounding(rounding(fields[2]));
+        let r = eval(&mut env, name, &args);
+        assert!(
+            r == expected && r.is_sign_negative() == expected.is_sign_negative(),
+            "{}: {}",
+            line,
+            r.to_string_radix(16)
+        );
+        count += 1;
+    }
+    assert!(count > 4000);
+}
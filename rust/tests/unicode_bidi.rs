@@ -0,0 +1,148 @@
+/* UAX #9 conformance: tests/data/BidiCharacterTest.txt and
+tests/data/BidiTest.txt are the BidiCharacterTest.txt and BidiTest.txt
+files of the Unicode 8.0.0 UCD */
+use slimjs::unicode_bidi::{bidi_reorder, BidiParagraph};
+
+fn parse_hex(s: &str) -> Vec<u32> {
+    s.split_whitespace()
+        .map(|v| u32::from_str_radix(v, 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn bidi_character_test() {
+    let data = include_str!("data/BidiCharacterTest.txt");
+    let mut count = 0;
+    for (line_num, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let text = parse_hex(fields[0]);
+        let level = match fields[1] {
+            "0" => Some(0),
+            "1" => Some(1),
+            _ => None,
+        };
+        let para_level: u8 = fields[2].parse().unwrap();
+        let expected_levels: Vec<Option<u8>> = fields[3]
+            .split_whitespace()
+            .map(|v| {
+                if v == "x" {
+                    None
+                } else {
+                    Some(v.parse().unwrap())
+                }
+            })
+            .collect();
+        let expected_order: Vec<usize> = fields[4]
+            .split_whitespace()
+            .map(|v| v.parse().unwrap())
+            .collect();
+
+        let para = BidiParagraph::new(&text, level);
+        assert_eq!(para.level, para_level, "line {}", line_num + 1);
+        let levels = para.line_levels(0, text.len());
+        for (i, l) in expected_levels.iter().enumerate() {
+            if let Some(l) = l {
+                assert_eq!(levels[i], *l, "line {}: level of {}", line_num + 1, i);
+            }
+        }
+        /* the characters removed by X9 are not in the expected order */
+        let order: Vec<usize> = para
+            .visual_order(0, text.len())
+            .into_iter()
+            .filter(|&i| expected_levels[i].is_some())
+            .collect();
+        assert_eq!(order, expected_order, "line {}", line_num + 1);
+        count += 1;
+    }
+    assert!(count > 90000);
+}
+
+/* a character of each Bidi_Class for BidiTest.txt */
+fn bidi_class_char(name: &str) -> u32 {
+    match name {
+        "L" => 0x41,
+        "R" => 0x5d0,
+        "AL" => 0x627,
+        "EN" => 0x30,
+        "ES" => 0x2b,
+        "ET" => 0x24,
+        "AN" => 0x660,
+        "CS" => 0x2c,
+        "NSM" => 0x300,
+        "BN" => 0xad,
+        "B" => 0x2029,
+        "S" => 0x09,
+        "WS" => 0x20,
+        "ON" => 0x21,
+        "LRE" => 0x202a,
+        "RLE" => 0x202b,
+        "PDF" => 0x202c,
+        "LRO" => 0x202d,
+        "RLO" => 0x202e,
+        "LRI" => 0x2066,
+        "RLI" => 0x2067,
+        "FSI" => 0x2068,
+        "PDI" => 0x2069,
+        _ => panic!("unknown class {}", name),
+    }
+}
+
+#[test]
+fn bidi_test() {
+    let data = include_str!("data/BidiTest.txt");
+    let mut expected_levels: Vec<Option<u8>> = Vec::new();
+    let mut expected_order: Vec<usize> = Vec::new();
+    let mut count = 0;
+    for (line_num, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if let Some(v) = line.strip_prefix("@Levels:") {
+            expected_levels = v.split_whitespace().map(|v| v.parse().ok()).collect();
+            continue;
+        }
+        if let Some(v) = line.strip_prefix("@Reorder:") {
+            expected_order = v.split_whitespace().map(|v| v.parse().unwrap()).collect();
+            continue;
+        }
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let text: Vec<u32> = fields[0].split_whitespace().map(bidi_class_char).collect();
+        let bitset: u32 = fields[1].trim().parse().unwrap();
+        /* auto, LTR and RTL paragraph levels */
+        for &(bit, level) in &[(1, None), (2, Some(0)), (4, Some(1))] {
+            if bitset & bit == 0 {
+                continue;
+            }
+            let para = BidiParagraph::new(&text, level);
+            let levels = para.line_levels(0, text.len());
+            for (i, l) in expected_levels.iter().enumerate() {
+                if let Some(l) = l {
+                    assert_eq!(levels[i], *l, "line {}: level of {}", line_num + 1, i);
+                }
+            }
+            let order: Vec<usize> = para
+                .visual_order(0, text.len())
+                .into_iter()
+                .filter(|&i| expected_levels[i].is_some())
+                .collect();
+            assert_eq!(order, expected_order, "line {}", line_num + 1);
+            count += 1;
+        }
+    }
+    assert!(count > 700000);
+}
+
+#[test]
+fn bidi_reorder_str() {
+    assert_eq!(
+        bidi_reorder("abc \u{5d0}\u{5d1}\u{5d2}", None),
+        "abc \u{5d2}\u{5d1}\u{5d0}"
+    );
+    assert_eq!(bidi_reorder("\u{5d0}\u{5d1} 12", None), "12 \u{5d1}\u{5d0}");
+    assert_eq!(bidi_reorder("abc", Some(1)), "abc");
+}
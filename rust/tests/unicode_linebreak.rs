@@ -0,0 +1,170 @@
+/* UAX #14 conformance: tests/data/LineBreakTest.txt is the
+LineBreakTest.txt file of the Unicode 10.0.0 UCD. It is older than the
+tables and uses the number tailoring of example 7 of UAX #14, so the
+boundaries decided by a rule which differs from the Unicode 13.0.0
+default rules are checked against the 13.0.0 rules instead. */
+use slimjs::unicode_linebreak::{
+    line_breaks, unicode_get_line_break, wrap_lines, BreakOpportunity, LB_AL, LB_BK, LB_CB, LB_CL,
+    LB_CM, LB_CP, LB_CR, LB_HL, LB_IN, LB_LF, LB_NL, LB_NU, LB_OP, LB_PO, LB_PR, LB_SP, LB_ZW,
+    LB_ZWJ,
+};
+use slimjs::unicode_width::{
+    unicode_east_asian_width, UNICODE_EAW_F, UNICODE_EAW_H, UNICODE_EAW_W,
+};
+
+fn is_east_asian(c: u32) -> bool {
+    matches!(
+        unicode_east_asian_width(c),
+        UNICODE_EAW_F | UNICODE_EAW_W | UNICODE_EAW_H
+    )
+}
+
+fn is_break_class(cl: u32) -> bool {
+    matches!(cl, LB_BK | LB_CR | LB_LF | LB_NL | LB_SP | LB_ZW)
+}
+
+/* Return the Unicode 13.0.0 result for the boundary before text[i] if
+it is decided by a rule which changed since Unicode 10.0.0 with the
+number tailoring, or None if the test file result applies. */
+fn changed_rule(text: &[u32], i: usize) -> Option<bool> {
+    let next = unicode_get_line_break(text[i]);
+    let last = unicode_get_line_break(text[i - 1]);
+    /* the combining marks are attached to their base (LB9) */
+    let j = text[..i]
+        .iter()
+        .rposition(|&c| !matches!(unicode_get_line_break(c), LB_CM | LB_ZWJ));
+    let prev = match j {
+        /* LB10: isolated combining marks are treated as AL */
+        Some(j) if j + 1 == i || !is_break_class(unicode_get_line_break(text[j])) => {
+            unicode_get_line_break(text[j])
+        }
+        _ => LB_AL,
+    };
+    if is_break_class(next) || is_break_class(prev) {
+        /* LB4 to LB8 are unchanged */
+        return None;
+    }
+    if last == LB_ZWJ {
+        /* LB8a: ZWJ × */
+        return Some(false);
+    }
+    if next == LB_IN && prev != LB_CB {
+        /* LB22: × IN */
+        return Some(false);
+    }
+    if (matches!(prev, LB_AL | LB_HL | LB_NU) && next == LB_OP && is_east_asian(text[i]))
+        || (prev == LB_CP
+            && is_east_asian(text[j.unwrap()])
+            && matches!(next, LB_AL | LB_HL | LB_NU))
+    {
+        /* LB30 no longer applies to the East Asian parentheses */
+        return Some(true);
+    }
+    if (matches!(prev, LB_CL | LB_CP) && matches!(next, LB_PR | LB_PO))
+        || (matches!(prev, LB_PR | LB_PO) && next == LB_OP)
+    {
+        /* LB25: the tailored rules only apply before or after a number */
+        return Some(false);
+    }
+    None
+}
+
+fn breaks(s: &str) -> Vec<usize> {
+    line_breaks(s).map(|(pos, _)| pos).collect()
+}
+
+#[test]
+fn line_break_test() {
+    let data = include_str!("data/LineBreakTest.txt");
+    let mut count = 0;
+    let mut nb_changed = 0;
+    for (line_num, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut text = Vec::new();
+        let mut s = String::new();
+        let mut expected = Vec::new();
+        for field in line.split_whitespace() {
+            match field {
+                "÷" => expected.push(s.len()),
+                "×" => {}
+                _ => {
+                    let c = u32::from_str_radix(field, 16).unwrap();
+                    text.push(c);
+                    s.push(char::from_u32(c).unwrap());
+                }
+            }
+        }
+        /* no break opportunity at the start of the text */
+        expected.retain(|&pos| pos != 0);
+        let mut pos = 0;
+        let mut expected1 = Vec::new();
+        for i in 1..=text.len() {
+            pos += char::from_u32(text[i - 1]).unwrap().len_utf8();
+            let rule = if i < text.len() {
+                changed_rule(&text, i)
+            } else {
+                None
+            };
+            let is_break = match rule {
+                Some(is_break) => {
+                    nb_changed += 1;
+                    is_break
+                }
+                None => expected.contains(&pos),
+            };
+            if is_break {
+                expected1.push(pos);
+            }
+        }
+        assert_eq!(breaks(&s), expected1, "line {}", line_num + 1);
+        count += 1;
+    }
+    assert!(nb_changed > 0);
+    assert_eq!(count, 7282);
+}
+
+#[test]
+fn changed_rules() {
+    /* LB8a: ZWJ × */
+    assert_eq!(breaks("。\u{200d}あ"), [9]);
+    assert_eq!(breaks("。あ"), [3, 6]);
+    /* LB22: × IN */
+    assert_eq!(breaks("$\u{2024}"), [4]);
+    assert_eq!(breaks("\u{fffc}\u{2024}"), [3, 6]);
+    /* LB30: only for the parentheses which are not East Asian */
+    assert_eq!(breaks("a("), [2]);
+    assert_eq!(breaks("a\u{ff08}"), [1, 4]);
+    assert_eq!(breaks("\u{ff09}a"), [3, 4]);
+    /* LB25 */
+    assert_eq!(breaks(")$"), [2]);
+    assert_eq!(breaks("}%"), [2]);
+    assert_eq!(breaks("$("), [2]);
+}
+
+#[test]
+fn complex_context() {
+    /* Thai: before a leading vowel and after SARA A or SARA AM */
+    assert_eq!(breaks("ภาษาไทย"), [12, 21]);
+    assert_eq!(breaks("ประเทศไทย"), [9, 18, 27]);
+    assert_eq!(breaks("กำลัง"), [6, 15]);
+    /* not before the repetition mark nor between two leading vowels */
+    assert_eq!(breaks("เด็กๆ เล่น"), [16, 28]);
+    assert_eq!(breaks("กเเก"), [3, 12]);
+    /* Lao */
+    assert_eq!(breaks("ໄປເຮືອນ"), [6, 21]);
+    assert_eq!(breaks("ມະລິ"), [6, 12]);
+    /* no rule for the other scripts */
+    assert_eq!(breaks("ភាសាខ្មែរ"), [27]);
+
+    assert_eq!(
+        line_breaks("ไปเรียน").collect::<Vec<_>>(),
+        [
+            (6, BreakOpportunity::Allowed),
+            (21, BreakOpportunity::Mandatory)
+        ]
+    );
+    assert_eq!(wrap_lines("ภาษาไทย", 4), ["ภาษา", "ไทย"]);
+}
@@ -0,0 +1,70 @@
+/* character names and formal name aliases */
+use slimjs::unicode_names::{char_from_name, char_name};
+
+#[test]
+fn names() {
+    assert_eq!(char_name('A' as u32).unwrap(), "LATIN CAPITAL LETTER A");
+    assert_eq!(char_name(0x1f600).unwrap(), "GRINNING FACE");
+    assert_eq!(char_name(0xac00).unwrap(), "HANGUL SYLLABLE GA");
+    assert_eq!(char_name(0xd7a3).unwrap(), "HANGUL SYLLABLE HIH");
+    assert_eq!(char_name(0x4e00).unwrap(), "CJK UNIFIED IDEOGRAPH-4E00");
+    assert_eq!(char_name(0x17000).unwrap(), "TANGUT IDEOGRAPH-17000");
+    assert_eq!(
+        char_name(0xfa0e).unwrap(),
+        "CJK COMPATIBILITY IDEOGRAPH-FA0E"
+    );
+    /* the aliases are not names */
+    assert_eq!(char_name(0x1a2).unwrap(), "LATIN CAPITAL LETTER OI");
+    for &c in &[0, 0x85, 0x378, 0xe000, 0xd800, 0x10ffff] {
+        assert_eq!(char_name(c), None, "U+{:04X}", c);
+    }
+}
+
+#[test]
+fn from_name() {
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER A"), Some(0x41));
+    assert_eq!(char_from_name(" latin_small_letter_a "), Some(0x61));
+    assert_eq!(char_from_name("Latin  Small Letter A"), Some(0x61));
+    assert_eq!(char_from_name("hangul syllable gag"), Some(0xac01));
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-4E00"), Some(0x4e00));
+    assert_eq!(char_from_name("cjk unified ideograph-20000"), Some(0x20000));
+    /* the hexadecimal value must be in the range of the prefix */
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-0041"), None);
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-+4E00"), None);
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH-+4E0"), None);
+    assert_eq!(char_from_name("CJK UNIFIED IDEOGRAPH- 4E00"), None);
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER"), None);
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER É"), None);
+    assert_eq!(char_from_name(""), None);
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(char_from_name("NULL"), Some(0));
+    assert_eq!(char_from_name("line feed"), Some(0xa));
+    assert_eq!(char_from_name("LF"), Some(0xa));
+    assert_eq!(char_from_name("NEXT LINE"), Some(0x85));
+    assert_eq!(char_from_name("ZWJ"), Some(0x200d));
+    assert_eq!(char_from_name("BYTE ORDER MARK"), Some(0xfeff));
+    assert_eq!(char_from_name("VS17"), Some(0xe0100));
+    /* corrections */
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER GHA"), Some(0x1a2));
+    assert_eq!(char_from_name("LATIN CAPITAL LETTER OI"), Some(0x1a2));
+    assert_eq!(
+        char_from_name("PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET"),
+        Some(0xfe18)
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut count = 0;
+    for c in 0..=0x10ffff {
+        if let Some(name) = char_name(c) {
+            assert_eq!(char_from_name(&name), Some(c), "{}", name);
+            count += 1;
+        }
+    }
+    /* graphic and format characters of Unicode 13.0.0 */
+    assert_eq!(count, 143_859);
+}
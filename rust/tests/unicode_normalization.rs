@@ -0,0 +1,135 @@
+/* Normalization conformance: tests/data/NormalizationTest.txt is the
+NormalizationTest.txt file of the UCD. It is more recent than the
+tables, so the lines containing characters which are not assigned in
+the tables are skipped. */
+use slimjs::unicode_ident::{validate_identifier, IDENT_FLAG_NFKC, IDENT_PROFILE_UAX31};
+use slimjs::unicode_normalization::{
+    compose, decompose, unicode_get_cc, UnicodeDecompositionEnum, DECOMP_CANONICAL,
+    DECOMP_COMPATIBILITY,
+};
+use slimjs::unicode_property::{general_category, GeneralCategory};
+
+fn parse_hex(s: &str) -> Vec<u32> {
+    s.split_whitespace()
+        .map(|v| u32::from_str_radix(v, 16).unwrap())
+        .collect()
+}
+
+/* decomposition followed by the canonical ordering */
+fn nfd(src: &[u32], decomp_type: UnicodeDecompositionEnum) -> Vec<u32> {
+    let mut buf: Vec<u32> = Vec::new();
+    for &c in src {
+        buf.extend(decompose(c, decomp_type));
+    }
+    let mut i = 0;
+    while i < buf.len() {
+        if unicode_get_cc(buf[i]) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < buf.len() && unicode_get_cc(buf[i]) != 0 {
+            i += 1;
+        }
+        /* stable sort */
+        buf[start..i].sort_by_key(|&c| unicode_get_cc(c));
+    }
+    buf
+}
+
+/* canonical composition */
+fn nfc(src: &[u32], decomp_type: UnicodeDecompositionEnum) -> Vec<u32> {
+    let mut buf: Vec<u32> = Vec::new();
+    let mut starter: Option<usize> = None;
+    let mut last_cc = 0;
+    for c in nfd(src, decomp_type) {
+        let cc = unicode_get_cc(c);
+        if let Some(pos) = starter {
+            let is_blocked = buf.len() > pos + 1 && (last_cc == 0 || last_cc >= cc);
+            if !is_blocked {
+                if let Some(p) = compose(buf[pos], c) {
+                    buf[pos] = p;
+                    continue;
+                }
+            }
+        }
+        if cc == 0 {
+            starter = Some(buf.len());
+        }
+        last_cc = cc;
+        buf.push(c);
+    }
+    buf
+}
+
+fn to_string(buf: &[u32]) -> String {
+    buf.iter().map(|&c| char::from_u32(c).unwrap()).collect()
+}
+
+#[test]
+fn normalization_test() {
+    let data = include_str!("data/NormalizationTest.txt");
+    let mut count = 0;
+    for (line_num, line) in data.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() || line.starts_with('@') {
+            continue;
+        }
+        let c: Vec<Vec<u32>> = line.split(';').take(5).map(parse_hex).collect();
+        if c.iter()
+            .flatten()
+            .any(|&v| general_category(v) == GeneralCategory::Cn)
+        {
+            continue;
+        }
+        let line_num = line_num + 1;
+        for i in 0..3 {
+            assert_eq!(nfc(&c[i], DECOMP_CANONICAL), c[1], "NFC line {}", line_num);
+            assert_eq!(nfd(&c[i], DECOMP_CANONICAL), c[2], "NFD line {}", line_num);
+        }
+        for i in 3..5 {
+            assert_eq!(nfc(&c[i], DECOMP_CANONICAL), c[3], "NFC line {}", line_num);
+            assert_eq!(nfd(&c[i], DECOMP_CANONICAL), c[4], "NFD line {}", line_num);
+        }
+        for i in 0..5 {
+            assert_eq!(
+                nfc(&c[i], DECOMP_COMPATIBILITY),
+                c[3],
+                "NFKC line {}",
+                line_num
+            );
+            assert_eq!(
+                nfd(&c[i], DECOMP_COMPATIBILITY),
+                c[4],
+                "NFKD line {}",
+                line_num
+            );
+            /* the identifiers are validated after the NFKC normalization */
+            if let Ok(s) =
+                validate_identifier(&to_string(&c[i]), IDENT_PROFILE_UAX31, IDENT_FLAG_NFKC)
+            {
+                assert_eq!(s, to_string(&c[3]), "identifier line {}", line_num);
+            }
+        }
+        count += 1;
+    }
+    assert!(count > 18000);
+}
+
+#[test]
+fn compose_primitives() {
+    assert_eq!(decompose(0xc5, DECOMP_CANONICAL), vec![0x41, 0x30a]);
+    assert_eq!(decompose(0xfb01, DECOMP_CANONICAL), vec![0xfb01]);
+    assert_eq!(decompose(0xfb01, DECOMP_COMPATIBILITY), vec![0x66, 0x69]);
+    assert_eq!(
+        decompose(0xd4db, DECOMP_CANONICAL),
+        vec![0x1111, 0x1171, 0x11b6]
+    );
+    assert_eq!(compose(0x41, 0x30a), Some(0xc5));
+    assert_eq!(compose(0x1111, 0x1171), Some(0xd4cc));
+    assert_eq!(compose(0xd4cc, 0x11b6), Some(0xd4db));
+    /* composition exclusion */
+    assert_eq!(compose(0x915, 0x93c), None);
+    assert_eq!(unicode_get_cc(0x301), 230);
+    assert_eq!(unicode_get_cc(0x41), 0);
+}
@@ -0,0 +1,129 @@
+/* the numbers of code points are the totals given in the Unicode
+13.0.0 data files */
+use slimjs::unicode_property::{general_category, has_property, GeneralCategory, Property};
+
+fn count(prop: Property) -> usize {
+    (0..=0x10ffff).filter(|&c| has_property(c, prop)).count()
+}
+
+#[test]
+fn derived_core_properties() {
+    assert_eq!(count(Property::Alphabetic), 132875);
+    assert_eq!(count(Property::Lowercase), 2344);
+    assert_eq!(count(Property::Uppercase), 1911);
+    assert_eq!(count(Property::Cased), 4286);
+    assert_eq!(count(Property::ID_Start), 131482);
+    assert_eq!(count(Property::ID_Continue), 134434);
+    assert_eq!(count(Property::XID_Start), 131459);
+    assert_eq!(count(Property::Default_Ignorable_Code_Point), 4173);
+
+    /* Other_Lowercase, Other_Uppercase and Other_Alphabetic */
+    assert!(has_property(0xaa, Property::Lowercase));
+    assert!(has_property(0x2160, Property::Uppercase));
+    assert!(has_property(0x345, Property::Alphabetic));
+    assert!(has_property(0x345, Property::Lowercase));
+    /* Other_ID_Start and the exclusions of XID_Start */
+    assert!(has_property(0x2118, Property::ID_Start));
+    assert!(has_property(0x37a, Property::ID_Start));
+    assert!(!has_property(0x37a, Property::XID_Start));
+    assert!(has_property(0xb7, Property::ID_Continue));
+    assert!(!has_property(0xb7, Property::ID_Start));
+    assert!(has_property(0x200d, Property::Default_Ignorable_Code_Point));
+    assert!(has_property(
+        0xe0001,
+        Property::Default_Ignorable_Code_Point
+    ));
+}
+
+#[test]
+fn prop_list() {
+    assert_eq!(count(Property::White_Space), 25);
+    assert_eq!(count(Property::Pattern_White_Space), 11);
+    assert_eq!(count(Property::Noncharacter_Code_Point), 66);
+    assert_eq!(count(Property::Regional_Indicator), 26);
+    assert_eq!(count(Property::Join_Control), 2);
+    assert_eq!(count(Property::ASCII_Hex_Digit), 22);
+    assert_eq!(count(Property::Hex_Digit), 44);
+    assert_eq!(count(Property::Variation_Selector), 259);
+    assert_eq!(count(Property::Bidi_Control), 12);
+
+    assert!(has_property(0x85, Property::White_Space));
+    assert!(!has_property(0x200b, Property::White_Space));
+    assert!(has_property(0x200e, Property::Pattern_White_Space));
+    assert!(has_property(0xfdef, Property::Noncharacter_Code_Point));
+    assert!(has_property(0x10ffff, Property::Noncharacter_Code_Point));
+    assert!(has_property(0xff21, Property::Hex_Digit));
+    assert!(!has_property(0xff21, Property::ASCII_Hex_Digit));
+    assert!(has_property(0x61c, Property::Bidi_Control));
+    assert!(has_property(0x2e3a, Property::Dash));
+    assert!(has_property(0x300c, Property::Quotation_Mark));
+    assert!(has_property(0x3007, Property::Ideographic));
+    assert!(!has_property(0x3007, Property::Unified_Ideograph));
+    assert!(has_property(0x2ff2, Property::IDS_Trinary_Operator));
+    assert!(!has_property(0xe33, Property::Logical_Order_Exception));
+    assert!(has_property(0xe40, Property::Logical_Order_Exception));
+    assert!(has_property(0x69, Property::Soft_Dotted));
+    assert!(has_property(0x149, Property::Deprecated));
+}
+
+#[test]
+fn emoji_properties() {
+    assert_eq!(count(Property::Emoji), 1367);
+    assert_eq!(count(Property::Extended_Pictographic), 3537);
+    assert_eq!(count(Property::Emoji_Modifier), 5);
+
+    /* the digits and '#' are Emoji, but not Emoji_Presentation */
+    assert!(has_property('#' as u32, Property::Emoji));
+    assert!(!has_property('#' as u32, Property::Emoji_Presentation));
+    assert!(has_property('#' as u32, Property::Emoji_Component));
+    assert!(has_property(0x1f600, Property::Emoji_Presentation));
+    assert!(has_property(0x1f44d, Property::Emoji_Modifier_Base));
+    assert!(has_property(0x1f3fb, Property::Emoji_Modifier));
+    assert!(has_property(0xa9, Property::Extended_Pictographic));
+    /* reserved for future emoji */
+    assert!(has_property(0x1fffd, Property::Extended_Pictographic));
+    assert!(!has_property(0x1fffd, Property::Emoji));
+}
+
+#[test]
+fn derived_properties() {
+    assert_eq!(count(Property::Any), 0x110000);
+    assert_eq!(count(Property::ASCII), 128);
+    /* the private use characters and the surrogates are assigned */
+    assert_eq!(count(Property::Assigned), 283440);
+    assert!(has_property(0xe000, Property::Assigned));
+    assert!(has_property(0xd800, Property::Assigned));
+    assert!(!has_property(0x378, Property::Assigned));
+    assert!(!has_property(0x110000, Property::Any));
+
+    assert!(has_property('A' as u32, Property::Changes_When_Lowercased));
+    assert!(!has_property('a' as u32, Property::Changes_When_Lowercased));
+    assert!(has_property(0xdf, Property::Changes_When_Uppercased));
+    assert!(has_property(0xdf, Property::Changes_When_Casefolded));
+    assert!(!has_property(0xdf, Property::Changes_When_Lowercased));
+    assert!(has_property(0x1c4, Property::Changes_When_Titlecased));
+    assert!(has_property(0xad, Property::Changes_When_NFKC_Casefolded));
+    assert!(has_property(0x2b, Property::Math));
+    assert!(has_property(0x5e, Property::Math));
+    assert!(has_property(0x301, Property::Grapheme_Extend));
+    assert!(has_property(0x200c, Property::Grapheme_Extend));
+    assert!(!has_property(0x301, Property::Grapheme_Base));
+    assert!(has_property(0x5e, Property::Case_Ignorable));
+
+    /* inclusions between the properties */
+    for c in 0..=0x10ffff {
+        let gc = general_category(c);
+        if gc == GeneralCategory::Lu {
+            assert!(has_property(c, Property::Uppercase), "{:x}", c);
+        }
+        if has_property(c, Property::Uppercase) || has_property(c, Property::Lowercase) {
+            assert!(has_property(c, Property::Cased), "{:x}", c);
+        }
+        if has_property(c, Property::XID_Start) {
+            assert!(has_property(c, Property::ID_Start), "{:x}", c);
+        }
+        if has_property(c, Property::ID_Start) {
+            assert!(has_property(c, Property::ID_Continue), "{:x}", c);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use slimjs::unicode_script::{
+    script_extensions_of, script_from_name, script_name, script_of, script_runs, script_short_name,
+    UNICODE_SCRIPT_Common, UNICODE_SCRIPT_Cyrillic, UNICODE_SCRIPT_Han, UNICODE_SCRIPT_Hebrew,
+    UNICODE_SCRIPT_Hiragana, UNICODE_SCRIPT_Inherited, UNICODE_SCRIPT_Katakana,
+    UNICODE_SCRIPT_Latin, UnicodeScriptEnum,
+};
+
+fn runs(s: &str) -> Vec<(&str, UnicodeScriptEnum)> {
+    script_runs(s)
+        .map(|r| (&s[r.start..r.end], r.script))
+        .collect()
+}
+
+#[test]
+fn script_properties() {
+    assert_eq!(script_of('a' as u32), UNICODE_SCRIPT_Latin);
+    assert_eq!(script_of('1' as u32), UNICODE_SCRIPT_Common);
+    assert_eq!(script_of(0x301), UNICODE_SCRIPT_Inherited);
+    /* the prolonged sound mark is Common with the Hiragana and Katakana
+    extensions */
+    assert_eq!(script_of(0x30fc), UNICODE_SCRIPT_Common);
+    assert_eq!(
+        script_extensions_of(0x30fc),
+        [UNICODE_SCRIPT_Hiragana, UNICODE_SCRIPT_Katakana]
+    );
+    assert_eq!(script_name(UNICODE_SCRIPT_Cyrillic), Some("Cyrillic"));
+    assert_eq!(script_short_name(UNICODE_SCRIPT_Cyrillic), Some("Cyrl"));
+    assert_eq!(script_from_name("Cyrl"), Some(UNICODE_SCRIPT_Cyrillic));
+    assert_eq!(script_from_name("Hebrew"), Some(UNICODE_SCRIPT_Hebrew));
+    assert_eq!(script_from_name("Qaai"), Some(UNICODE_SCRIPT_Inherited));
+    assert_eq!(script_from_name("Klingon"), None);
+}
+
+#[test]
+fn common_and_inherited() {
+    assert_eq!(runs(""), []);
+    assert_eq!(runs("123 !"), [("123 !", UNICODE_SCRIPT_Common)]);
+    /* the Common and Inherited characters take the script of the run
+    they are in, the leading ones included */
+    assert_eq!(runs("abc 123"), [("abc 123", UNICODE_SCRIPT_Latin)]);
+    assert_eq!(runs("123 abc"), [("123 abc", UNICODE_SCRIPT_Latin)]);
+    assert_eq!(runs("\u{301}abc"), [("\u{301}abc", UNICODE_SCRIPT_Latin)]);
+    assert_eq!(runs("abc\u{301}"), [("abc\u{301}", UNICODE_SCRIPT_Latin)]);
+    /* ... or of the previous run at a script change */
+    assert_eq!(
+        runs("abc, Привет!"),
+        [
+            ("abc, ", UNICODE_SCRIPT_Latin),
+            ("Привет!", UNICODE_SCRIPT_Cyrillic)
+        ]
+    );
+    assert_eq!(
+        runs("ab\u{5d0}\u{5b7}"),
+        [
+            ("ab", UNICODE_SCRIPT_Latin),
+            ("\u{5d0}\u{5b7}", UNICODE_SCRIPT_Hebrew)
+        ]
+    );
+}
+
+#[test]
+fn script_extensions() {
+    /* U+3002 and U+30FC can be written with several scripts */
+    assert_eq!(
+        runs("漢字。かな"),
+        [
+            ("漢字。", UNICODE_SCRIPT_Han),
+            ("かな", UNICODE_SCRIPT_Hiragana)
+        ]
+    );
+    assert_eq!(
+        runs("a。あ"),
+        [
+            ("a", UNICODE_SCRIPT_Latin),
+            ("。あ", UNICODE_SCRIPT_Hiragana)
+        ]
+    );
+    assert_eq!(runs("カー"), [("カー", UNICODE_SCRIPT_Katakana)]);
+    assert_eq!(runs("あー"), [("あー", UNICODE_SCRIPT_Hiragana)]);
+    /* the Inherited voiced sound mark has the Hiragana and Katakana
+    extensions */
+    assert_eq!(
+        runs("a\u{3099}"),
+        [
+            ("a", UNICODE_SCRIPT_Latin),
+            ("\u{3099}", UNICODE_SCRIPT_Hiragana)
+        ]
+    );
+}
+
+#[test]
+fn paired_brackets() {
+    /* a closing bracket takes the script of its opening bracket */
+    assert_eq!(
+        runs("Привет (abc) мир"),
+        [
+            ("Привет (", UNICODE_SCRIPT_Cyrillic),
+            ("abc", UNICODE_SCRIPT_Latin),
+            (") мир", UNICODE_SCRIPT_Cyrillic)
+        ]
+    );
+    assert_eq!(
+        runs("abc (Привет) def"),
+        [
+            ("abc (", UNICODE_SCRIPT_Latin),
+            ("Привет", UNICODE_SCRIPT_Cyrillic),
+            (") def", UNICODE_SCRIPT_Latin)
+        ]
+    );
+    /* both brackets in the same run */
+    assert_eq!(
+        runs("(abc) Привет"),
+        [
+            ("(abc) ", UNICODE_SCRIPT_Latin),
+            ("Привет", UNICODE_SCRIPT_Cyrillic)
+        ]
+    );
+    /* an unmatched closing bracket is an ordinary Common character */
+    assert_eq!(
+        runs("abc) Привет"),
+        [
+            ("abc) ", UNICODE_SCRIPT_Latin),
+            ("Привет", UNICODE_SCRIPT_Cyrillic)
+        ]
+    );
+}
@@ -0,0 +1,119 @@
+use slimjs::unicode_security::{
+    are_confusable, confusable_type, has_whole_script_confusable, is_identifier_allowed,
+    restriction_level, skeleton, CONFUSABLE_MIXED_SCRIPT, CONFUSABLE_SINGLE_SCRIPT,
+    CONFUSABLE_WHOLE_SCRIPT, RESTRICTION_LEVEL_ASCII_ONLY, RESTRICTION_LEVEL_HIGHLY_RESTRICTIVE,
+    RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE, RESTRICTION_LEVEL_MODERATELY_RESTRICTIVE,
+    RESTRICTION_LEVEL_SINGLE_SCRIPT, RESTRICTION_LEVEL_UNRESTRICTED,
+};
+
+#[test]
+fn skeletons() {
+    /* prototypes of one or several characters */
+    assert_eq!(skeleton("0"), "O");
+    assert_eq!(skeleton("1"), "l");
+    assert_eq!(skeleton("I"), "l");
+    assert_eq!(skeleton("m"), "rn");
+    assert_eq!(skeleton("\u{fb01}"), "fi");
+    assert_eq!(skeleton("\u{1c9}"), "lj");
+    assert_eq!(skeleton("\u{2169}"), "X");
+    assert_eq!(skeleton("\u{210c}"), "H");
+    /* other scripts */
+    assert_eq!(skeleton("\u{13aa}"), "A");
+    assert_eq!(skeleton("\u{a4da}"), "C");
+    assert_eq!(skeleton("\u{455}\u{441}\u{43e}\u{440}\u{435}"), "scope");
+    assert_eq!(
+        skeleton("\u{ff53}\u{ff43}\u{ff4f}\u{ff50}\u{ff45}"),
+        "scope"
+    );
+    /* the characters without prototype are kept */
+    assert_eq!(skeleton("paypal"), "paypal");
+    assert_eq!(skeleton(""), "");
+    /* the skeleton is in NFD */
+    assert_eq!(skeleton("\u{e9}"), "e\u{301}");
+    assert_eq!(skeleton("e\u{301}"), "e\u{301}");
+}
+
+#[test]
+fn confusables() {
+    assert!(are_confusable("rn", "m"));
+    assert!(are_confusable("l", "1"));
+    assert!(are_confusable("\u{e9}", "e\u{301}"));
+    assert!(!are_confusable("abc", "abd"));
+    assert!(!are_confusable("a", "A"));
+
+    /* the resolved script sets intersect: Common and Latin */
+    assert_eq!(confusable_type("l", "1"), Some(CONFUSABLE_SINGLE_SCRIPT));
+    assert_eq!(confusable_type("rn", "m"), Some(CONFUSABLE_SINGLE_SCRIPT));
+    /* Latin and Cyrillic */
+    let cyrillic_scope = "\u{455}\u{441}\u{43e}\u{440}\u{435}";
+    assert_eq!(
+        confusable_type("scope", cyrillic_scope),
+        Some(CONFUSABLE_WHOLE_SCRIPT)
+    );
+    assert_eq!(
+        confusable_type(cyrillic_scope, "scope"),
+        Some(CONFUSABLE_WHOLE_SCRIPT)
+    );
+    /* a Cyrillic 'а' in a Latin string */
+    assert_eq!(
+        confusable_type("p\u{430}ypal", "paypal"),
+        Some(CONFUSABLE_MIXED_SCRIPT)
+    );
+    assert_eq!(
+        confusable_type("\u{440}\u{430}\u{443}\u{440}\u{430}l", "paypal"),
+        Some(CONFUSABLE_MIXED_SCRIPT)
+    );
+    assert_eq!(confusable_type("abc", "abd"), None);
+}
+
+#[test]
+fn whole_script_confusables() {
+    assert!(has_whole_script_confusable("scope"));
+    assert!(has_whole_script_confusable(
+        "\u{455}\u{441}\u{43e}\u{440}\u{435}"
+    ));
+    /* no confusable of the Hiragana letter A */
+    assert!(!has_whole_script_confusable("\u{3042}"));
+    /* the mixed-script strings have none */
+    assert!(!has_whole_script_confusable("p\u{430}ypal"));
+    /* only Common characters */
+    assert!(!has_whole_script_confusable("123"));
+}
+
+#[test]
+fn restriction_levels() {
+    assert!(is_identifier_allowed('a' as u32));
+    assert!(is_identifier_allowed(0x3042));
+    assert!(!is_identifier_allowed(0x200b));
+    assert!(!is_identifier_allowed(0x2169));
+
+    assert_eq!(restriction_level("abc"), RESTRICTION_LEVEL_ASCII_ONLY);
+    assert_eq!(
+        restriction_level("\u{e9}t\u{e9}"),
+        RESTRICTION_LEVEL_SINGLE_SCRIPT
+    );
+    assert_eq!(
+        restriction_level("\u{455}\u{441}\u{43e}\u{440}\u{435}"),
+        RESTRICTION_LEVEL_SINGLE_SCRIPT
+    );
+    assert_eq!(
+        restriction_level("abc\u{6f22}\u{5b57}"),
+        RESTRICTION_LEVEL_HIGHLY_RESTRICTIVE
+    );
+    assert_eq!(
+        restriction_level("abc\u{627}\u{628}"),
+        RESTRICTION_LEVEL_MODERATELY_RESTRICTIVE
+    );
+    assert_eq!(
+        restriction_level("abc\u{3b1}\u{3b2}"),
+        RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE
+    );
+    assert_eq!(
+        restriction_level("p\u{430}ypal"),
+        RESTRICTION_LEVEL_MINIMALLY_RESTRICTIVE
+    );
+    assert_eq!(
+        restriction_level("a\u{200b}"),
+        RESTRICTION_LEVEL_UNRESTRICTED
+    );
+}
@@ -0,0 +1,172 @@
+/* conversions between UTF-8, UTF-16, WTF-8 and Latin-1 */
+use slimjs::unicode_transcode::{
+    decode_wtf8_char, encode_wtf8_char, latin1_to_utf16, latin1_to_utf8, utf16_from_bytes,
+    utf16_is_latin1, utf16_to_bytes, utf16_to_latin1, utf16_to_string, utf16_to_utf8,
+    utf16_to_wtf8, utf8_to_latin1, utf8_to_utf16, wtf8_to_utf16, wtf8_to_utf8, wtf8_to_utf8_lossy,
+    TranscodeError, SURROGATE_ERROR, SURROGATE_PRESERVE, SURROGATE_REPLACE,
+};
+
+/* strings long enough for the ASCII fast paths */
+const SAMPLES: &[&str] = &[
+    "",
+    "a",
+    "hello, world: 0123456789",
+    "été à Noël, ça coûte 10 €",
+    "日本語のテキストと English text",
+    "emoji \u{1f600}\u{1f468}\u{200d}\u{1f469} and \u{10ffff}",
+    "\u{7f}\u{80}\u{7ff}\u{800}\u{ffff}\u{10000}",
+];
+
+#[test]
+fn round_trip() {
+    for s in SAMPLES {
+        let utf16: Vec<u16> = s.encode_utf16().collect();
+        for &policy in &[SURROGATE_ERROR, SURROGATE_REPLACE, SURROGATE_PRESERVE] {
+            assert_eq!(utf8_to_utf16(s.as_bytes(), policy).unwrap(), utf16);
+            assert_eq!(utf16_to_utf8(&utf16, policy).unwrap(), s.as_bytes());
+            assert_eq!(utf16_to_string(&utf16, policy).unwrap(), *s);
+        }
+        assert_eq!(utf16_to_wtf8(&utf16), s.as_bytes());
+        assert_eq!(wtf8_to_utf16(s.as_bytes()).unwrap(), utf16);
+        assert_eq!(wtf8_to_utf8(s.as_bytes()).unwrap(), *s);
+        assert_eq!(wtf8_to_utf8_lossy(s.as_bytes()), *s);
+        for &big_endian in &[false, true] {
+            let bytes = utf16_to_bytes(&utf16, big_endian);
+            assert_eq!(utf16_from_bytes(&bytes, big_endian).unwrap(), utf16);
+        }
+    }
+    assert_eq!(utf16_to_bytes(&[0x1234], false), [0x34, 0x12]);
+    assert_eq!(utf16_to_bytes(&[0x1234], true), [0x12, 0x34]);
+    assert_eq!(
+        utf16_from_bytes(&[1, 2, 3], false),
+        Err(TranscodeError::TruncatedInput(2))
+    );
+}
+
+#[test]
+fn code_points() {
+    let mut buf = [0; 4];
+    for c in (0..0x110000)
+        .step_by(7)
+        .chain([0x7f, 0x80, 0x7ff, 0x800, 0xffff, 0x10000])
+    {
+        let len = encode_wtf8_char(&mut buf, c);
+        assert_eq!(decode_wtf8_char(&buf[..len]), Some((c, len)));
+        if let Some(ch) = char::from_u32(c) {
+            assert_eq!(&buf[..len], ch.to_string().as_bytes());
+        }
+    }
+    assert_eq!(encode_wtf8_char(&mut buf, 0x110000), 0);
+    assert_eq!(decode_wtf8_char(b""), None);
+    assert_eq!(decode_wtf8_char(b"\xe2\x82"), None);
+    assert_eq!(decode_wtf8_char(b"\xc0\x80"), None);
+    assert_eq!(decode_wtf8_char(b"\xf4\x90\x80\x80"), None);
+    assert_eq!(decode_wtf8_char(b"\xed\xa0\x80"), Some((0xd800, 3)));
+}
+
+#[test]
+fn lone_surrogates() {
+    /* "a" U+D800 "b" U+DC00 "é" */
+    let utf16 = [0x61, 0xd800, 0x62, 0xdc00, 0xe9];
+    let wtf8 = b"a\xed\xa0\x80b\xed\xb0\x80\xc3\xa9";
+    assert_eq!(utf16_to_wtf8(&utf16), wtf8);
+    assert_eq!(wtf8_to_utf16(wtf8).unwrap(), utf16);
+    assert_eq!(
+        utf16_to_utf8(&utf16, SURROGATE_ERROR),
+        Err(TranscodeError::LoneSurrogate(1))
+    );
+    assert_eq!(
+        utf16_to_utf8(&utf16, SURROGATE_REPLACE).unwrap(),
+        "a\u{fffd}b\u{fffd}é".as_bytes()
+    );
+    assert_eq!(
+        utf16_to_string(&utf16, SURROGATE_PRESERVE).unwrap(),
+        "a\u{fffd}b\u{fffd}é"
+    );
+    assert_eq!(
+        utf8_to_utf16(wtf8, SURROGATE_ERROR),
+        Err(TranscodeError::LoneSurrogate(1))
+    );
+    assert_eq!(
+        utf8_to_utf16(wtf8, SURROGATE_REPLACE).unwrap(),
+        [0x61, 0xfffd, 0x62, 0xfffd, 0xe9]
+    );
+    assert_eq!(wtf8_to_utf8(wtf8), Err(TranscodeError::LoneSurrogate(1)));
+    assert_eq!(wtf8_to_utf8_lossy(wtf8), "a\u{fffd}b\u{fffd}é");
+
+    /* a pair is a single code point, not two surrogates */
+    assert_eq!(utf16_to_wtf8(&[0xd83d, 0xde00]), "\u{1f600}".as_bytes());
+    /* a trail surrogate before a lead surrogate is not a pair */
+    assert_eq!(
+        utf16_to_wtf8(&[0xdc00, 0xd800]),
+        b"\xed\xb0\x80\xed\xa0\x80"
+    );
+    assert_eq!(
+        wtf8_to_utf16(b"\xed\xb0\x80\xed\xa0\x80").unwrap(),
+        [0xdc00, 0xd800]
+    );
+    /* at the end of the string */
+    assert_eq!(utf16_to_wtf8(&[0x61, 0xd800]), b"a\xed\xa0\x80");
+    assert_eq!(wtf8_to_utf16(b"a\xed\xa0\x80").unwrap(), [0x61, 0xd800]);
+}
+
+#[test]
+fn encoded_surrogate_pair() {
+    /* U+1F600 encoded as two surrogates (CESU-8) is not WTF-8 */
+    let cesu8 = b"ab\xed\xa0\xbd\xed\xb8\x80";
+    assert_eq!(wtf8_to_utf16(cesu8), Err(TranscodeError::InvalidUtf8(5)));
+    assert_eq!(
+        utf8_to_utf16(cesu8, SURROGATE_PRESERVE).unwrap(),
+        [0x61, 0x62, 0xd83d, 0xfffd]
+    );
+    assert_eq!(
+        utf8_to_utf16(cesu8, SURROGATE_REPLACE).unwrap(),
+        [0x61, 0x62, 0xfffd, 0xfffd]
+    );
+    assert_eq!(wtf8_to_utf8(cesu8), Err(TranscodeError::LoneSurrogate(2)));
+    assert_eq!(wtf8_to_utf8_lossy(cesu8), "ab\u{fffd}\u{fffd}");
+    /* the lead surrogates may follow each other */
+    assert_eq!(
+        wtf8_to_utf16(b"\xed\xa0\xbd\xed\xa0\xbd").unwrap(),
+        [0xd83d, 0xd83d]
+    );
+}
+
+#[test]
+fn invalid_utf8() {
+    /* maximal subparts: truncated sequence, invalid lead byte,
+    overlong encoding */
+    let src = b"abc\xe2\x82 \xff\xc0\xafx\xf0\x9f\x98";
+    assert_eq!(
+        utf8_to_utf16(src, SURROGATE_ERROR),
+        Err(TranscodeError::InvalidUtf8(3))
+    );
+    assert_eq!(wtf8_to_utf16(src), Err(TranscodeError::InvalidUtf8(3)));
+    assert_eq!(wtf8_to_utf8(src), Err(TranscodeError::InvalidUtf8(3)));
+    let lossy = "abc\u{fffd} \u{fffd}\u{fffd}\u{fffd}x\u{fffd}";
+    assert_eq!(wtf8_to_utf8_lossy(src), lossy);
+    assert_eq!(wtf8_to_utf8_lossy(src), String::from_utf8_lossy(src));
+    assert_eq!(
+        utf8_to_utf16(src, SURROGATE_REPLACE).unwrap(),
+        lossy.encode_utf16().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn latin1() {
+    let latin1 = b"caf\xe9 \xa0\xff";
+    assert_eq!(latin1_to_utf8(latin1), "café \u{a0}ÿ");
+    let utf16 = latin1_to_utf16(latin1);
+    assert!(utf16_is_latin1(&utf16));
+    assert_eq!(utf16_to_latin1(&utf16).unwrap(), latin1);
+    assert_eq!(utf8_to_latin1("café \u{a0}ÿ").unwrap(), latin1);
+    assert!(!utf16_is_latin1(&[0x61, 0x100]));
+    assert_eq!(
+        utf16_to_latin1(&[0x61, 0x100]),
+        Err(TranscodeError::Unrepresentable(1))
+    );
+    assert_eq!(
+        utf8_to_latin1("ab€"),
+        Err(TranscodeError::Unrepresentable(2))
+    );
+}
@@ -0,0 +1,90 @@
+/* display width of the characters and of the strings (UAX #11 and
+UTS #51) */
+use slimjs::unicode_width::{
+    display_width, display_width_cjk, unicode_char_width, unicode_east_asian_width, UNICODE_EAW_Na,
+    UNICODE_EAW_A, UNICODE_EAW_F, UNICODE_EAW_H, UNICODE_EAW_N, UNICODE_EAW_W,
+};
+
+#[test]
+fn east_asian_width() {
+    assert_eq!(unicode_east_asian_width('a' as u32), UNICODE_EAW_Na);
+    assert_eq!(unicode_east_asian_width(0xb1), UNICODE_EAW_A);
+    assert_eq!(unicode_east_asian_width(0x3b1), UNICODE_EAW_A);
+    assert_eq!(unicode_east_asian_width(0x5d0), UNICODE_EAW_N);
+    assert_eq!(unicode_east_asian_width(0x4e00), UNICODE_EAW_W);
+    assert_eq!(unicode_east_asian_width(0xff21), UNICODE_EAW_F);
+    assert_eq!(unicode_east_asian_width(0xff76), UNICODE_EAW_H);
+    /* default values of the unassigned code points */
+    assert_eq!(unicode_east_asian_width(0x3fffd), UNICODE_EAW_W);
+    assert_eq!(unicode_east_asian_width(0xe0080), UNICODE_EAW_N);
+    assert_eq!(unicode_east_asian_width(0x10fffd), UNICODE_EAW_A);
+}
+
+#[test]
+fn control_characters() {
+    assert_eq!(unicode_char_width(0, false), Some(0));
+    for c in (1..0x20).chain(0x7f..0xa0) {
+        assert_eq!(unicode_char_width(c, false), None, "U+{:04X}", c);
+    }
+    assert_eq!(unicode_char_width(0x20, false), Some(1));
+    assert_eq!(unicode_char_width(0xa0, false), Some(1));
+    assert_eq!(display_width("a\tb\r\n\u{85}"), 2);
+}
+
+#[test]
+fn ambiguous_width() {
+    for &c in &[0xb1, 0x3b1, 0x416, 0x2460, 0x25cb] {
+        assert_eq!(unicode_char_width(c, false), Some(1), "U+{:04X}", c);
+        assert_eq!(unicode_char_width(c, true), Some(2), "U+{:04X}", c);
+    }
+    assert_eq!(display_width("α±β"), 3);
+    assert_eq!(display_width_cjk("α±β"), 6);
+    /* the other widths do not depend on the context */
+    assert_eq!(display_width_cjk("aｶ漢Ａ"), 6);
+    assert_eq!(display_width("aｶ漢Ａ"), 6);
+}
+
+#[test]
+fn combining_marks() {
+    assert_eq!(unicode_char_width(0x301, false), Some(0));
+    assert_eq!(unicode_char_width(0x20dd, false), Some(0));
+    /* format characters and default ignorable code points */
+    assert_eq!(unicode_char_width(0x200b, false), Some(0));
+    assert_eq!(unicode_char_width(0x2060, false), Some(0));
+    assert_eq!(unicode_char_width(0xe0001, false), Some(0));
+    /* except the soft hyphen and the prepended concatenation marks */
+    assert_eq!(unicode_char_width(0xad, false), Some(1));
+    assert_eq!(unicode_char_width(0x600, false), Some(1));
+    assert_eq!(display_width("e\u{301}\u{327}"), 1);
+    assert_eq!(display_width("\u{915}\u{94d}\u{937}"), 2);
+    /* Hangul syllable written with conjoining jamos */
+    assert_eq!(unicode_char_width(0x1100, false), Some(2));
+    assert_eq!(unicode_char_width(0x1161, false), Some(0));
+    assert_eq!(unicode_char_width(0x11ab, false), Some(0));
+    assert_eq!(display_width("\u{1112}\u{1161}\u{11ab}"), 2);
+    assert_eq!(display_width("한국어"), 6);
+}
+
+#[test]
+fn emoji() {
+    assert_eq!(unicode_char_width(0x1f600, false), Some(2));
+    assert_eq!(display_width("\u{1f600}"), 2);
+    /* text and emoji presentation selectors */
+    assert_eq!(display_width("\u{2764}"), 1);
+    assert_eq!(display_width("\u{2764}\u{fe0f}"), 2);
+    assert_eq!(display_width("\u{231a}"), 2);
+    assert_eq!(display_width("\u{231a}\u{fe0e}"), 1);
+    /* the selectors have no effect on the other characters */
+    assert_eq!(display_width("a\u{fe0f}"), 1);
+    assert_eq!(display_width("\u{4e00}\u{fe0e}"), 2);
+    /* skin tone modifier */
+    assert_eq!(display_width("\u{1f44d}\u{1f3fd}"), 2);
+    assert_eq!(display_width("a\u{1f3fd}"), 3);
+    /* ZWJ sequences */
+    assert_eq!(
+        display_width("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"),
+        2
+    );
+    assert_eq!(display_width("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"), 2);
+    assert_eq!(display_width("a\u{200d}\u{1f600}"), 3);
+}
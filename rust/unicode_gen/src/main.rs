@@ -0,0 +1,2410 @@
+/*
+ * Generation of the Unicode tables of libunicode.rs
+ *
+ * Copyright (c) 2017-2018 Fabrice Bellard
+ * Copyright (c) 2017-2018 Charlie Gordon
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a copy
+ * of this software and associated documentation files (the "Software"), to deal
+ * in the Software without restriction, including without limitation the rights
+ * to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+ * copies of the Software, and to permit persons to whom the Software is
+ * furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL
+ * THE AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+ * LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+ * OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN
+ * THE SOFTWARE.
+ */
+
+/* Port of unicode_gen.c. It reads the text files of the Unicode
+Character Database from a local directory and writes
+libunicode_table.rs, which is included by libunicode.rs. The
+encodings are the same as the ones of the C generator so that both
+produce the same table contents from the same files.
+
+It also writes the tables of the Unicode modules which have no C
+counterpart: unicode_width_table.rs, unicode_bidi_table.rs,
+unicode_names_table.rs, unicode_security_table.rs and
+unicode_linebreak_table.rs.
+
+usage: unicode_gen unicode_db_path output_dir
+
+The input files are in unicode_gen/unicode. The ones which are not
+vendored can be fetched with unicode_gen/unicode_download.sh
+(Unicode 13.0.0). The following files are used: UnicodeData.txt,
+SpecialCasing.txt, CaseFolding.txt, CompositionExclusions.txt,
+DerivedCoreProperties.txt, DerivedNormalizationProps.txt,
+PropList.txt, Scripts.txt, ScriptExtensions.txt, emoji-data.txt,
+EastAsianWidth.txt, BidiBrackets.txt, LineBreak.txt, confusables.txt,
+IdentifierStatus.txt and NameAliases.txt. They are all checked before
+the generation: a missing file or a file of another Unicode version is
+an error. */
+#![allow(non_upper_case_globals)]
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::process::exit;
+
+mod unicode_gen_def;
+
+use unicode_gen_def::*;
+
+const CHARCODE_MAX: usize = 0x10ffff;
+const CC_LEN_MAX: usize = 3;
+
+const UNICODE_VERSION: &str = "13.0.0";
+
+const INPUT_FILES: &[&str] = &[
+    "UnicodeData.txt",
+    "SpecialCasing.txt",
+    "CaseFolding.txt",
+    "CompositionExclusions.txt",
+    "DerivedCoreProperties.txt",
+    "DerivedNormalizationProps.txt",
+    "PropList.txt",
+    "Scripts.txt",
+    "ScriptExtensions.txt",
+    "emoji-data.txt",
+    "EastAsianWidth.txt",
+    "BidiBrackets.txt",
+    "LineBreak.txt",
+    "confusables.txt",
+    "IdentifierStatus.txt",
+    "NameAliases.txt",
+];
+
+fn fatal(msg: &str) -> ! {
+    eprintln!("unicode_gen: {}", msg);
+    exit(1);
+}
+
+fn read_file(filename: &str) -> String {
+    match fs::read(filename) {
+        /* some files of the security data start with a BOM */
+        Ok(buf) => String::from_utf8_lossy(&buf)
+            .trim_start_matches('\u{feff}')
+            .to_string(),
+        Err(err) => fatal(&format!("{}: {}", filename, err)),
+    }
+}
+
+/* return the version given in the header of a data file, e.g.
+"# Scripts-13.0.0.txt" or "# Version: 13.0.0". UnicodeData.txt has no
+header. */
+fn get_file_version(buf: &str) -> Option<&str> {
+    for line in buf.lines().take(10) {
+        let line = match line.strip_prefix('#') {
+            Some(line) => line.trim(),
+            None => break,
+        };
+        if let Some(v) = line.strip_prefix("Version:") {
+            return Some(v.trim());
+        }
+        if let Some(name) = line.strip_suffix(".txt") {
+            if let Some(i) = name.rfind('-') {
+                return Some(&name[i + 1..]);
+            }
+        }
+    }
+    None
+}
+
+/* check that all the input files are present and have the expected
+version before generating anything */
+fn check_input_files(unicode_db_path: &str) {
+    let mut missing = Vec::new();
+    for name in INPUT_FILES {
+        let filename = format!("{}/{}", unicode_db_path, name);
+        let buf = match fs::read(&filename) {
+            Ok(buf) => buf,
+            Err(_) => {
+                missing.push(*name);
+                continue;
+            }
+        };
+        let buf = String::from_utf8_lossy(&buf);
+        if let Some(version) = get_file_version(buf.trim_start_matches('\u{feff}')) {
+            if version != UNICODE_VERSION {
+                fatal(&format!(
+                    "{}: version {} instead of {}",
+                    filename, version, UNICODE_VERSION
+                ));
+            }
+        }
+    }
+    if !missing.is_empty() {
+        fatal(&format!(
+            "missing input files in {}: {} (see unicode_download.sh)",
+            unicode_db_path,
+            missing.join(", ")
+        ));
+    }
+}
+
+/* return the field 'n' of a ';' separated line, up to the next ';' */
+fn get_field(line: &str, n: usize) -> Option<&str> {
+    line.split(';').nth(n)
+}
+
+/* parse the hexadecimal number at the start of 's' (like strtoul(), 0
+if none) and return it with the rest of the string */
+fn parse_hex(s: &str) -> (u32, &str) {
+    let s = s.trim_start();
+    let end = s
+        .find(|ch: char| !ch.is_ascii_hexdigit())
+        .unwrap_or(s.len());
+    (u32::from_str_radix(&s[..end], 16).unwrap_or(0), &s[end..])
+}
+
+/* parse a list of space separated hexadecimal numbers */
+fn parse_hex_list(s: &str) -> Vec<u32> {
+    s.split_whitespace()
+        .map_while(|w| u32::from_str_radix(w, 16).ok())
+        .collect()
+}
+
+/* the names may contain aliases separated by commas */
+fn find_name(tab: &[&str], name: &str) -> Option<usize> {
+    tab.iter().position(|s| s.split(',').any(|s1| s1 == name))
+}
+
+#[derive(Clone, Default)]
+struct CCInfo {
+    /* case conv */
+    u_len: usize,
+    l_len: usize,
+    u_data: [u32; CC_LEN_MAX],
+    l_data: [u32; CC_LEN_MAX],
+    f_code: u32,
+
+    combining_class: u8,
+    is_compat: bool,
+    is_excluded: bool,
+    general_category: u8,
+    script: u8,
+    script_ext: Vec<u8>,
+    prop_bitmap_tab: [u32; 3],
+    bidi_class: u8,
+    eaw: u8,
+    line_break: u8,
+    is_identifier_allowed: bool,
+    /* decomposition */
+    decomp_data: Vec<u32>,
+}
+
+struct UnicodeDB {
+    tab: Vec<CCInfo>,
+    /* character names, sorted by code point */
+    names: Vec<(u32, String)>,
+    /* ranges named with their hexadecimal value: (first, last, prefix) */
+    name_ranges: Vec<(u32, u32, String)>,
+    /* formal name aliases: (code point, alias) */
+    name_aliases: Vec<(u32, String)>,
+    /* (opening bracket, closing bracket) */
+    bidi_brackets: Vec<(u32, u32)>,
+    /* (character, prototype) */
+    confusables: Vec<(u32, Vec<u32>)>,
+}
+
+impl UnicodeDB {
+    fn new() -> Self {
+        /* two more entries so that the case runs can look ahead */
+        UnicodeDB {
+            tab: vec![CCInfo::default(); CHARCODE_MAX + 3],
+            names: Vec::new(),
+            name_ranges: Vec::new(),
+            name_aliases: Vec::new(),
+            bidi_brackets: Vec::new(),
+            confusables: Vec::new(),
+        }
+    }
+
+    fn get_prop(&self, c: usize, prop_idx: usize) -> bool {
+        (self.tab[c].prop_bitmap_tab[prop_idx >> 5] >> (prop_idx & 0x1f)) & 1 != 0
+    }
+
+    fn set_prop(&mut self, c: usize, prop_idx: usize, val: bool) {
+        let mask = 1u32 << (prop_idx & 0x1f);
+        if val {
+            self.tab[c].prop_bitmap_tab[prop_idx >> 5] |= mask;
+        } else {
+            self.tab[c].prop_bitmap_tab[prop_idx >> 5] &= !mask;
+        }
+    }
+}
+
+/* iterate over the non comment lines */
+fn data_lines(buf: &str) -> impl Iterator<Item = &str> {
+    buf.lines().filter(|line| {
+        let p = line.trim_start();
+        !p.starts_with('#')
+    })
+}
+
+/* iterate over the lines of the form 'c0[..c1] ; value' and return
+the code range with the text after the first ';' */
+fn range_lines(buf: &str) -> impl Iterator<Item = (usize, usize, &str)> {
+    buf.lines().filter_map(|line| {
+        let p = line.trim_start();
+        if p.is_empty() || p.starts_with('#') || p.starts_with('@') {
+            return None;
+        }
+        let (c0, p) = parse_hex(p);
+        let (c1, p) = match p.strip_prefix("..") {
+            Some(p1) => parse_hex(p1),
+            None => (c0, p),
+        };
+        assert!(c1 as usize <= CHARCODE_MAX);
+        let p = p.trim_start_matches([' ', '\t']);
+        let p = p.strip_prefix(';')?;
+        Some((c0 as usize, c1 as usize, p))
+    })
+}
+
+/* first word of a property value, up to a space, a tab or a comment */
+fn first_word(p: &str) -> &str {
+    let p = p.trim_start_matches([' ', '\t']);
+    let end = p.find([' ', '\t', '#']).unwrap_or(p.len());
+    &p[..end]
+}
+
+/* ranges of UnicodeData.txt whose characters are named with their
+hexadecimal value (rule NR2) and the prefix of their names */
+const name_range_prefix_table: [(&str, &str); 2] = [
+    ("<CJK Ideograph", "CJK UNIFIED IDEOGRAPH-"),
+    ("<Tangut Ideograph", "TANGUT IDEOGRAPH-"),
+];
+
+fn parse_unicode_data(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+    let mut last_code = 0;
+
+    for line in data_lines(&buf) {
+        let code = parse_hex(get_field(line, 0).unwrap()).0 as usize;
+        assert!(code <= CHARCODE_MAX);
+        let ci = &mut db.tab[code];
+
+        if let Some(p) = get_field(line, 12).filter(|p| !p.is_empty()) {
+            let uc = parse_hex(p).0;
+            if uc > 0 {
+                assert!(ci.u_len == 0);
+                ci.u_len = 1;
+                ci.u_data[0] = uc;
+            }
+        }
+        if let Some(p) = get_field(line, 13).filter(|p| !p.is_empty()) {
+            let lc = parse_hex(p).0;
+            if lc > 0 {
+                assert!(ci.l_len == 0);
+                ci.l_len = 1;
+                ci.l_data[0] = lc;
+            }
+        }
+
+        let gc = get_field(line, 2).unwrap_or("");
+        match find_name(unicode_gc_name, gc) {
+            Some(i) => ci.general_category = i as u8,
+            None => fatal(&format!("General category '{}' not found", gc)),
+        }
+
+        let bc = get_field(line, 4).unwrap_or("");
+        match find_name(unicode_bidi_class_name, bc) {
+            Some(i) => ci.bidi_class = i as u8,
+            None => fatal(&format!("Bidi class '{}' not found", bc)),
+        }
+
+        if let Some(p) = get_field(line, 3).filter(|p| !p.is_empty()) {
+            let cc: u32 = p.trim().parse().unwrap_or(0);
+            if cc != 0 {
+                ci.combining_class = cc as u8;
+            }
+        }
+
+        if let Some(mut p) = get_field(line, 5).filter(|p| !p.is_empty()) {
+            ci.is_compat = false;
+            if p.starts_with('<') {
+                p = match p.find('>') {
+                    Some(pos) => &p[pos + 1..],
+                    None => "",
+                };
+                ci.is_compat = true;
+            }
+            ci.decomp_data = parse_hex_list(p);
+        }
+
+        if get_field(line, 9).is_some_and(|p| p.starts_with('Y')) {
+            db.set_prop(code, Prop::Bidi_Mirrored as usize, true);
+        }
+
+        /* handle ranges */
+        let name = get_field(line, 1).unwrap_or("");
+        if name.contains(" Last>") {
+            let ci = db.tab[code].clone();
+            assert!(ci.decomp_data.is_empty());
+            assert!(ci.script_ext.is_empty());
+            for i in last_code + 1..code {
+                db.tab[i] = ci.clone();
+            }
+            if let Some((_, prefix)) = name_range_prefix_table
+                .iter()
+                .find(|(range_name, _)| name.starts_with(range_name))
+            {
+                db.name_ranges
+                    .push((last_code as u32, code as u32, prefix.to_string()));
+            }
+        } else if !name.starts_with('<') {
+            db.names.push((code as u32, name.to_string()));
+        }
+        last_code = code;
+    }
+}
+
+fn parse_special_casing(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for line in data_lines(&buf) {
+        let code = match get_field(line, 0) {
+            Some(p) if !p.trim().is_empty() => parse_hex(p).0 as usize,
+            _ => continue,
+        };
+        assert!(code <= CHARCODE_MAX);
+
+        if let Some(pos) = line.match_indices(';').nth(3).map(|(pos, _)| pos) {
+            /* locale dependent casing */
+            let p = line[pos + 1..].trim_start();
+            if !p.is_empty() && !p.starts_with('#') {
+                continue;
+            }
+        }
+
+        let ci = &mut db.tab[code];
+        if let Some(p) = get_field(line, 1).filter(|p| !p.is_empty()) {
+            let tab = parse_hex_list(p);
+            assert!(tab.len() <= CC_LEN_MAX);
+            ci.l_len = tab.len();
+            ci.l_data[..tab.len()].copy_from_slice(&tab);
+            if ci.l_len == 1 && ci.l_data[0] as usize == code {
+                ci.l_len = 0;
+            }
+        }
+
+        if let Some(p) = get_field(line, 3).filter(|p| !p.is_empty()) {
+            let tab = parse_hex_list(p);
+            assert!(tab.len() <= CC_LEN_MAX);
+            ci.u_len = tab.len();
+            ci.u_data[..tab.len()].copy_from_slice(&tab);
+            if ci.u_len == 1 && ci.u_data[0] as usize == code {
+                ci.u_len = 0;
+            }
+        }
+    }
+}
+
+fn parse_case_folding(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for line in data_lines(&buf) {
+        let code = match get_field(line, 0) {
+            Some(p) if !p.trim().is_empty() => parse_hex(p).0 as usize,
+            _ => continue,
+        };
+        assert!(code <= CHARCODE_MAX);
+        /* only the common and simple foldings are used */
+        match get_field(line, 1).map(|p| p.trim_start()) {
+            Some(p) if p.starts_with('C') || p.starts_with('S') => {}
+            _ => continue,
+        }
+        let ci = &mut db.tab[code];
+        assert!(ci.f_code == 0);
+        ci.f_code = parse_hex(get_field(line, 2).unwrap()).0;
+        assert!(ci.f_code != 0 && ci.f_code as usize != code);
+    }
+}
+
+fn parse_composition_exclusions(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for line in buf.lines() {
+        let p = line.trim_start();
+        if p.is_empty() || p.starts_with('#') || p.starts_with('@') {
+            continue;
+        }
+        let c0 = parse_hex(p).0 as usize;
+        assert!(c0 > 0 && c0 <= CHARCODE_MAX);
+        db.tab[c0].is_excluded = true;
+    }
+}
+
+fn parse_derived_core_properties(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        let name = first_word(p);
+        let i = match find_name(unicode_prop_name, name) {
+            Some(i) => i,
+            None if name == "Grapheme_Link" => continue,
+            None => fatal(&format!("Property not found: {}", name)),
+        };
+        for c in c0..=c1 {
+            db.set_prop(c, i, true);
+        }
+    }
+}
+
+fn parse_derived_norm_properties(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        if first_word(p) == "Changes_When_NFKC_Casefolded" {
+            for c in c0..=c1 {
+                db.set_prop(c, Prop::Changes_When_NFKC_Casefolded as usize, true);
+            }
+        }
+    }
+}
+
+fn parse_prop_list(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        let name = first_word(p);
+        let i = match find_name(unicode_prop_name, name) {
+            Some(i) => i,
+            None => fatal(&format!("Property not found: {}", name)),
+        };
+        for c in c0..=c1 {
+            db.set_prop(c, i, true);
+        }
+    }
+}
+
+fn parse_scripts(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        let name = first_word(p);
+        let i = match find_name(unicode_script_name, name) {
+            Some(i) => i,
+            None => fatal(&format!("Unknown script: '{}'", name)),
+        };
+        for c in c0..=c1 {
+            db.tab[c].script = i as u8;
+        }
+    }
+}
+
+fn parse_script_extensions(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        let p = p.split('#').next().unwrap();
+        let mut script_ext = Vec::new();
+        for name in p.split_whitespace() {
+            match find_name(unicode_script_short_name, name) {
+                Some(i) => script_ext.push(i as u8),
+                None => fatal(&format!("Script not found: {}", name)),
+            }
+        }
+        assert!(script_ext.len() < 255);
+        for c in c0..=c1 {
+            db.tab[c].script_ext = script_ext.clone();
+        }
+    }
+}
+
+/* the unassigned code points of these ranges default to W (header of
+EastAsianWidth.txt), the other ones to N */
+const eaw_wide_default_ranges: [(usize, usize); 5] = [
+    (0x3400, 0x4dbf),
+    (0x4e00, 0x9fff),
+    (0xf900, 0xfaff),
+    (0x20000, 0x2fffd),
+    (0x30000, 0x3fffd),
+];
+
+fn parse_east_asian_width(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for &(c0, c1) in &eaw_wide_default_ranges {
+        for c in c0..=c1 {
+            db.tab[c].eaw = EAW::W as u8;
+        }
+    }
+    for (c0, c1, p) in range_lines(&buf) {
+        let name = first_word(p);
+        let i = match find_name(unicode_eaw_name, name) {
+            Some(i) => i,
+            None => fatal(&format!("East Asian width not found: {}", name)),
+        };
+        for c in c0..=c1 {
+            db.tab[c].eaw = i as u8;
+        }
+    }
+}
+
+fn parse_bidi_brackets(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        assert!(c0 == c1);
+        let c2 = parse_hex(get_field(p, 0).unwrap()).0;
+        /* only the opening brackets are stored */
+        if get_field(p, 1).map(first_word) == Some("o") {
+            db.bidi_brackets.push((c0 as u32, c2));
+        }
+    }
+}
+
+/* the unassigned code points of these ranges default to ID or PR
+(header of LineBreak.txt), the other ones to XX */
+const line_break_default_ranges: [(usize, usize, LineBreak); 8] = [
+    (0x3400, 0x4dbf, LineBreak::ID),
+    (0x4e00, 0x9fff, LineBreak::ID),
+    (0xf900, 0xfaff, LineBreak::ID),
+    (0x20000, 0x2fffd, LineBreak::ID),
+    (0x30000, 0x3fffd, LineBreak::ID),
+    (0x1f000, 0x1faff, LineBreak::ID),
+    (0x1fc00, 0x1fffd, LineBreak::ID),
+    (0x20a0, 0x20cf, LineBreak::PR),
+];
+
+fn parse_line_break(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for &(c0, c1, v) in &line_break_default_ranges {
+        for c in c0..=c1 {
+            db.tab[c].line_break = v as u8;
+        }
+    }
+    for (c0, c1, p) in range_lines(&buf) {
+        let name = first_word(p);
+        let i = match find_name(unicode_line_break_name, name) {
+            Some(i) => i,
+            None => fatal(&format!("Line break class not found: {}", name)),
+        };
+        for c in c0..=c1 {
+            db.tab[c].line_break = i as u8;
+        }
+    }
+}
+
+fn parse_confusables(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        assert!(c0 == c1);
+        let proto = parse_hex_list(get_field(p, 0).unwrap());
+        assert!(!proto.is_empty());
+        db.confusables.push((c0 as u32, proto));
+    }
+}
+
+fn parse_name_aliases(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        assert!(c0 == c1);
+        let alias = get_field(p, 0).unwrap();
+        assert!(alias.is_ascii() && !alias.is_empty());
+        db.name_aliases.push((c0 as u32, alias.to_string()));
+    }
+}
+
+fn parse_identifier_status(db: &mut UnicodeDB, filename: &str) {
+    let buf = read_file(filename);
+
+    for (c0, c1, p) in range_lines(&buf) {
+        let is_allowed = match first_word(p) {
+            "Allowed" => true,
+            "Restricted" => false,
+            name => fatal(&format!("Identifier status not found: {}", name)),
+        };
+        for c in c0..=c1 {
+            db.tab[c].is_identifier_allowed = is_allowed;
+        }
+    }
+}
+
+fn is_complicated_case(ci: &CCInfo) -> bool {
+    ci.u_len > 1
+        || ci.l_len > 1
+        || (ci.u_len > 0 && ci.l_len > 0)
+        || (ci.f_code != 0) as usize != ci.l_len
+        || (ci.f_code != 0 && ci.l_data[0] != ci.f_code)
+}
+
+const RUN_TYPE_U: u32 = 0;
+const RUN_TYPE_L: u32 = 1;
+const RUN_TYPE_UF: u32 = 2;
+const RUN_TYPE_LF: u32 = 3;
+const RUN_TYPE_UL: u32 = 4;
+const RUN_TYPE_LSU: u32 = 5;
+const RUN_TYPE_U2L_399_EXT2: u32 = 6;
+const RUN_TYPE_UF_D20: u32 = 7;
+const RUN_TYPE_UF_D1_EXT: u32 = 8;
+const RUN_TYPE_U_EXT: u32 = 9;
+const RUN_TYPE_LF_EXT: u32 = 10;
+const RUN_TYPE_U_EXT2: u32 = 11;
+const RUN_TYPE_L_EXT2: u32 = 12;
+const RUN_TYPE_U_EXT3: u32 = 13;
+
+#[derive(Clone, Default)]
+struct TableEntry {
+    code: u32,
+    len: u32,
+    type_0: u32,
+    data: u32,
+    ext_data: Vec<u32>,
+    data_index: u32, /* 'data' coming from the table */
+}
+
+/* code (17), len (7), type (4) */
+
+fn find_run_type(tab: &[CCInfo], code: usize) -> TableEntry {
+    let mut te = TableEntry {
+        code: code as u32,
+        ..Default::default()
+    };
+    let ci = &tab[code];
+    let ci1 = &tab[code + 1];
+    let ci2 = &tab[code + 2];
+    let c = code as u32;
+
+    if ci.l_len == 1
+        && ci.l_data[0] == c + 2
+        && ci.f_code == ci.l_data[0]
+        && ci.u_len == 0
+        && ci1.l_len == 1
+        && ci1.l_data[0] == c + 2
+        && ci1.f_code == ci1.l_data[0]
+        && ci1.u_len == 1
+        && ci1.u_data[0] == c
+        && ci2.l_len == 0
+        && ci2.f_code == 0
+        && ci2.u_len == 1
+        && ci2.u_data[0] == c
+    {
+        te.len = 3;
+        te.type_0 = RUN_TYPE_LSU;
+        return te;
+    }
+
+    if is_complicated_case(ci) {
+        let mut len = 1;
+        while code + len <= CHARCODE_MAX {
+            let ci1 = &tab[code + len];
+            if ci1.u_len != 1
+                || ci1.u_data[0] != ci.u_data[0] + len as u32
+                || ci1.l_len != 0
+                || ci1.f_code != ci1.u_data[0]
+            {
+                break;
+            }
+            len += 1;
+        }
+        if len > 1 {
+            te.len = len as u32;
+            te.type_0 = RUN_TYPE_UF;
+            te.data = ci.u_data[0];
+            return te;
+        }
+
+        if ci.u_len == 2 && ci.u_data[1] == 0x399 && ci.f_code == 0 && ci.l_len == 0 {
+            let mut len = 1;
+            while code + len <= CHARCODE_MAX {
+                let ci1 = &tab[code + len];
+                if !(ci1.u_len == 2
+                    && ci1.u_data[1] == 0x399
+                    && ci1.u_data[0] == ci.u_data[0] + len as u32
+                    && ci1.f_code == 0
+                    && ci1.l_len == 0)
+                {
+                    break;
+                }
+                len += 1;
+            }
+            te.len = len as u32;
+            te.type_0 = RUN_TYPE_U_EXT2;
+            te.ext_data = vec![ci.u_data[0], ci.u_data[1]];
+            return te;
+        }
+
+        if ci.u_len == 2 && ci.u_data[1] == 0x399 && ci.l_len == 1 && ci.f_code == ci.l_data[0] {
+            let mut len = 1;
+            while code + len <= CHARCODE_MAX {
+                let ci1 = &tab[code + len];
+                if !(ci1.u_len == 2
+                    && ci1.u_data[1] == 0x399
+                    && ci1.u_data[0] == ci.u_data[0] + len as u32
+                    && ci1.l_len == 1
+                    && ci1.l_data[0] == ci.l_data[0] + len as u32
+                    && ci1.f_code == ci1.l_data[0])
+                {
+                    break;
+                }
+                len += 1;
+            }
+            te.len = len as u32;
+            te.type_0 = RUN_TYPE_U2L_399_EXT2;
+            te.ext_data = vec![ci.u_data[0], ci.l_data[0]];
+            return te;
+        }
+
+        if ci.l_len == 1 && ci.u_len == 0 && ci.f_code == 0 {
+            let mut len = 1;
+            while code + len <= CHARCODE_MAX {
+                let ci1 = &tab[code + len];
+                if !(ci1.l_len == 1
+                    && ci1.l_data[0] == ci.l_data[0] + len as u32
+                    && ci1.u_len == 0
+                    && ci1.f_code == 0)
+                {
+                    break;
+                }
+                len += 1;
+            }
+            te.len = len as u32;
+            te.type_0 = RUN_TYPE_L;
+            te.data = ci.l_data[0];
+            return te;
+        }
+
+        te.len = 1;
+        if ci.l_len == 0
+            && ci.u_len == 1
+            && ci.u_data[0] < 0x1000
+            && ci.f_code == ci.u_data[0] + 0x20
+        {
+            te.type_0 = RUN_TYPE_UF_D20;
+            te.data = ci.u_data[0];
+        } else if ci.l_len == 0 && ci.u_len == 1 && ci.f_code == ci.u_data[0] + 1 {
+            te.type_0 = RUN_TYPE_UF_D1_EXT;
+            te.ext_data = vec![ci.u_data[0]];
+        } else if ci.l_len == 2 && ci.u_len == 0 && ci.f_code == 0 {
+            te.type_0 = RUN_TYPE_L_EXT2;
+            te.ext_data = ci.l_data[..2].to_vec();
+        } else if ci.u_len == 2 && ci.l_len == 0 && ci.f_code == 0 {
+            te.type_0 = RUN_TYPE_U_EXT2;
+            te.ext_data = ci.u_data[..2].to_vec();
+        } else if ci.u_len == 3 && ci.l_len == 0 && ci.f_code == 0 {
+            te.type_0 = RUN_TYPE_U_EXT3;
+            te.ext_data = ci.u_data[..3].to_vec();
+        } else {
+            fatal(&format!("{:05x}: unsupported encoding case", code));
+        }
+    } else {
+        /* look for a run of identical conversions */
+        let mut len = 0;
+        loop {
+            if code >= CHARCODE_MAX || len >= 126 {
+                break;
+            }
+            let ci = &tab[code + len];
+            let ci1 = &tab[code + len + 1];
+            if is_complicated_case(ci) || is_complicated_case(ci1) {
+                break;
+            }
+            if ci.l_len != 1 || ci.l_data[0] != (code + len + 1) as u32 {
+                break;
+            }
+            if ci1.u_len != 1 || ci1.u_data[0] != (code + len) as u32 {
+                break;
+            }
+            len += 2;
+        }
+        if len > 0 {
+            te.len = len as u32;
+            te.type_0 = RUN_TYPE_UL;
+            return te;
+        }
+
+        let is_lower = ci.l_len > 0;
+        let mut len = 1;
+        while code + len <= CHARCODE_MAX {
+            let ci1 = &tab[code + len];
+            if is_complicated_case(ci1) {
+                break;
+            }
+            if is_lower {
+                if ci1.l_len != 1 || ci1.l_data[0] != ci.l_data[0] + len as u32 {
+                    break;
+                }
+            } else if ci1.u_len != 1 || ci1.u_data[0] != ci.u_data[0] + len as u32 {
+                break;
+            }
+            len += 1;
+        }
+        te.len = len as u32;
+        if is_lower {
+            te.type_0 = RUN_TYPE_LF;
+            te.data = ci.l_data[0];
+        } else {
+            te.type_0 = RUN_TYPE_U;
+            te.data = ci.u_data[0];
+        }
+    }
+    te
+}
+
+struct CaseConvTable {
+    conv_table: Vec<TableEntry>,
+    ext_data: Vec<u32>,
+}
+
+impl CaseConvTable {
+    fn find_ext_data_index(&mut self, data: u32) -> u32 {
+        match self.ext_data.iter().position(|&v| v == data) {
+            Some(i) => i as u32,
+            None => {
+                self.ext_data.push(data);
+                (self.ext_data.len() - 1) as u32
+            }
+        }
+    }
+}
+
+fn build_conv_table(db: &UnicodeDB) -> CaseConvTable {
+    let tab = &db.tab;
+    let mut conv_table = Vec::new();
+    let mut code = 0;
+    while code <= CHARCODE_MAX {
+        let ci = &tab[code];
+        if ci.u_len == 0 && ci.l_len == 0 && ci.f_code == 0 {
+            code += 1;
+            continue;
+        }
+        let te = find_run_type(tab, code);
+        assert!(te.len <= 127);
+        code += te.len as usize;
+        conv_table.push(te);
+    }
+
+    /* find the data index */
+    for i in 0..conv_table.len() {
+        let type_0 = conv_table[i].type_0;
+        match type_0 {
+            RUN_TYPE_U | RUN_TYPE_L | RUN_TYPE_UF | RUN_TYPE_LF => {
+                let data = conv_table[i].data;
+                match (0..conv_table.len()).find(|&j| conv_table[j].code == data) {
+                    Some(data_index) => conv_table[i].data_index = data_index as u32,
+                    None => {
+                        let te = &mut conv_table[i];
+                        te.type_0 = match type_0 {
+                            RUN_TYPE_U => RUN_TYPE_U_EXT,
+                            RUN_TYPE_LF => RUN_TYPE_LF_EXT,
+                            _ => fatal(&format!("{:05x}: index not found", te.code)),
+                        };
+                        te.ext_data = vec![te.data];
+                    }
+                }
+            }
+            RUN_TYPE_UF_D20 => {
+                conv_table[i].data_index = conv_table[i].data;
+            }
+            _ => {}
+        }
+    }
+
+    let mut ct = CaseConvTable {
+        conv_table,
+        ext_data: Vec::new(),
+    };
+
+    /* find the data index for ext_data */
+    for i in 0..ct.conv_table.len() {
+        if ct.conv_table[i].type_0 == RUN_TYPE_U_EXT3 {
+            let mut v = 0;
+            for j in 0..3 {
+                let p = ct.find_ext_data_index(ct.conv_table[i].ext_data[j]);
+                assert!(p < 16);
+                v = (v << 4) | p;
+            }
+            ct.conv_table[i].data_index = v;
+        }
+    }
+
+    for i in 0..ct.conv_table.len() {
+        let type_0 = ct.conv_table[i].type_0;
+        if type_0 == RUN_TYPE_L_EXT2 || type_0 == RUN_TYPE_U_EXT2 || type_0 == RUN_TYPE_U2L_399_EXT2
+        {
+            let mut v = 0;
+            for j in 0..2 {
+                let p = ct.find_ext_data_index(ct.conv_table[i].ext_data[j]);
+                assert!(p < 64);
+                v = (v << 6) | p;
+            }
+            ct.conv_table[i].data_index = v;
+        }
+    }
+
+    for i in 0..ct.conv_table.len() {
+        let type_0 = ct.conv_table[i].type_0;
+        if type_0 == RUN_TYPE_UF_D1_EXT || type_0 == RUN_TYPE_U_EXT || type_0 == RUN_TYPE_LF_EXT {
+            ct.conv_table[i].data_index = ct.find_ext_data_index(ct.conv_table[i].ext_data[0]);
+        }
+    }
+    ct
+}
+
+/* output of the tables. The arrays are laid out as rustfmt would do
+it so that the file is stable under rustfmt. */
+const MAX_WIDTH: usize = 100;
+const ARRAY_WIDTH: usize = 60;
+
+fn dump_array(f: &mut String, cname: &str, ty: &str, tab: &[u32]) {
+    let (digits, per_line) = match ty {
+        "u8" => (2, 16),
+        "u16" => (4, 12),
+        _ => (8, 8),
+    };
+    let decl = format!("static {}: [{}; {}] =", cname, ty, tab.len());
+    let items: Vec<String> = tab
+        .iter()
+        .map(|v| format!("0x{:01$x}", v, digits))
+        .collect();
+    let short = format!("[{}];", items.join(", "));
+    if short.len() - 1 > ARRAY_WIDTH {
+        writeln!(f, "{} [", decl).unwrap();
+        for line in items.chunks(per_line) {
+            writeln!(f, "    {},", line.join(", ")).unwrap();
+        }
+        f.push_str("];\n\n");
+    } else if decl.len() + 1 + short.len() <= MAX_WIDTH {
+        writeln!(f, "{} {}\n", decl, short).unwrap();
+    } else {
+        writeln!(f, "{}\n    {}\n", decl, short).unwrap();
+    }
+}
+
+fn dump_byte_table(f: &mut String, cname: &str, tab: &[u8]) {
+    let tab: Vec<u32> = tab.iter().map(|&v| v as u32).collect();
+    dump_array(f, cname, "u8", &tab);
+}
+
+fn dump_case_conv_table(f: &mut String, ct: &CaseConvTable) {
+    let tab1: Vec<u32> = ct
+        .conv_table
+        .iter()
+        .map(|te| {
+            (te.code << (32 - 17))
+                | (te.len << (32 - 17 - 7))
+                | (te.type_0 << (32 - 17 - 7 - 4))
+                | (te.data_index >> 8)
+        })
+        .collect();
+    dump_array(f, "case_conv_table1", "u32", &tab1);
+
+    let tab2: Vec<u32> = ct
+        .conv_table
+        .iter()
+        .map(|te| te.data_index & 0xff)
+        .collect();
+    dump_array(f, "case_conv_table2", "u8", &tab2);
+
+    dump_array(f, "case_conv_ext", "u16", &ct.ext_data);
+}
+
+fn compute_internal_props(db: &mut UnicodeDB) {
+    for i in 0..=CHARCODE_MAX {
+        let ci = &db.tab[i];
+        let has_ul = ci.u_len != 0 || ci.l_len != 0 || ci.f_code != 0;
+        let has_u = ci.u_len != 0;
+        let has_f = ci.f_code != 0;
+        if has_ul {
+            assert!(db.get_prop(i, Prop::Cased as usize));
+        } else {
+            db.set_prop(
+                i,
+                Prop::Cased1 as usize,
+                db.get_prop(i, Prop::Cased as usize),
+            );
+        }
+        db.set_prop(
+            i,
+            Prop::ID_Continue1 as usize,
+            db.get_prop(i, Prop::ID_Continue as usize) && !db.get_prop(i, Prop::ID_Start as usize),
+        );
+        db.set_prop(
+            i,
+            Prop::XID_Start1 as usize,
+            db.get_prop(i, Prop::ID_Start as usize) ^ db.get_prop(i, Prop::XID_Start as usize),
+        );
+        db.set_prop(
+            i,
+            Prop::XID_Continue1 as usize,
+            db.get_prop(i, Prop::ID_Continue as usize)
+                ^ db.get_prop(i, Prop::XID_Continue as usize),
+        );
+        db.set_prop(
+            i,
+            Prop::Changes_When_Titlecased1 as usize,
+            db.get_prop(i, Prop::Changes_When_Titlecased as usize) ^ has_u,
+        );
+        db.set_prop(
+            i,
+            Prop::Changes_When_Casefolded1 as usize,
+            db.get_prop(i, Prop::Changes_When_Casefolded as usize) ^ has_f,
+        );
+        /* XXX: reduce table size (438 bytes) */
+        db.set_prop(
+            i,
+            Prop::Changes_When_NFKC_Casefolded1 as usize,
+            db.get_prop(i, Prop::Changes_When_NFKC_Casefolded as usize) ^ has_f,
+        );
+    }
+}
+
+fn put_le24(dbuf: &mut Vec<u8>, v: u32) {
+    dbuf.push(v as u8);
+    dbuf.push((v >> 8) as u8);
+    dbuf.push((v >> 16) as u8);
+}
+
+const PROP_BLOCK_LEN: usize = 32;
+
+fn build_prop_table(f: &mut String, db: &UnicodeDB, prop_index: usize, add_index: bool) {
+    let name = unicode_prop_name[prop_index];
+    build_bool_table(f, &format!("unicode_prop_{}", name), add_index, |c| {
+        db.get_prop(c, prop_index)
+    });
+}
+
+/* table of the code points for which 'get' returns true */
+fn build_bool_table(f: &mut String, cname: &str, add_index: bool, get: impl Fn(usize) -> bool) {
+    let mut buf = Vec::new();
+    let mut i = 0;
+    while i <= CHARCODE_MAX {
+        let v = get(i);
+        let mut j = i + 1;
+        while j <= CHARCODE_MAX && get(j) == v {
+            j += 1;
+        }
+        if j == CHARCODE_MAX + 1 && !v {
+            break; /* no need to encode last zero run */
+        }
+        buf.push((j - i - 1) as u32);
+        i = j;
+    }
+
+    let mut dbuf = Vec::new();
+    let mut dbuf2 = Vec::new();
+
+    /* the first value is assumed to be 0 */
+    assert!(!get(0));
+
+    let mut block_end_pos = PROP_BLOCK_LEN;
+    let mut i = 0;
+    let mut code = 0;
+    let mut bit = 0;
+    while i < buf.len() {
+        /* the index entries must start with a zero run: when the
+        block end is reached in a one run, the next run is encoded
+        alone so that the bit returns to zero */
+        let need_index = add_index && dbuf.len() >= block_end_pos;
+        if need_index && bit == 0 {
+            let offset = dbuf.len() - block_end_pos;
+            assert!(offset <= 7);
+            put_le24(&mut dbuf2, code | ((offset as u32) << 21));
+            block_end_pos += PROP_BLOCK_LEN;
+        }
+        let can_pair = !(need_index && bit == 1);
+
+        let v = buf[i];
+        code += v + 1;
+        bit ^= 1;
+        if can_pair && v < 8 && i + 1 < buf.len() && buf[i + 1] < 8 {
+            code += buf[i + 1] + 1;
+            bit ^= 1;
+            dbuf.push(((v << 3) | buf[i + 1]) as u8);
+            i += 2;
+        } else if v < 128 {
+            dbuf.push((0x80 + v) as u8);
+            i += 1;
+        } else if v < (1 << 13) {
+            dbuf.push((0x40 + (v >> 8)) as u8);
+            dbuf.push(v as u8);
+            i += 1;
+        } else {
+            assert!(v < (1 << 21));
+            dbuf.push((0x60 + (v >> 16)) as u8);
+            dbuf.push((v >> 8) as u8);
+            dbuf.push(v as u8);
+            i += 1;
+        }
+    }
+
+    if add_index {
+        /* last index entry */
+        put_le24(&mut dbuf2, code);
+    }
+
+    dump_byte_table(f, &format!("{}_table", cname), &dbuf);
+    if add_index {
+        dump_byte_table(f, &format!("{}_index", cname), &dbuf2);
+    }
+}
+
+fn build_flags_tables(f: &mut String, db: &UnicodeDB) {
+    build_prop_table(f, db, Prop::Cased1 as usize, true);
+    build_prop_table(f, db, Prop::Case_Ignorable as usize, true);
+    build_prop_table(f, db, Prop::ID_Start as usize, true);
+    build_prop_table(f, db, Prop::ID_Continue1 as usize, true);
+}
+
+/* generate a sequence of strings terminated by an empty string */
+fn dump_name_table(f: &mut String, cname: &str, tab_name: &[&str], tab_short_name: &[&str]) {
+    let mut len = 1;
+    let mut body = String::new();
+    for (name, short_name) in tab_name.iter().zip(tab_short_name) {
+        let mut s = name.to_string();
+        if !short_name.is_empty() {
+            s.push(',');
+            s.push_str(short_name);
+        }
+        len += s.len() + 1;
+        writeln!(body, "    {}\\0\\", s).unwrap();
+    }
+    writeln!(f, "static {}: [u8; {}] = *b\"\\", cname, len).unwrap();
+    f.push_str(&body);
+    f.push_str("    \\0\";\n\n");
+}
+
+fn dump_enum(f: &mut String, ename: &str, prefix: &str, tab_name: &[&str]) {
+    writeln!(f, "pub type {} = u32;", ename).unwrap();
+    for (i, name) in tab_name.iter().enumerate() {
+        writeln!(f, "pub const {}_{}: {} = {};", prefix, name, ename, i).unwrap();
+    }
+    writeln!(
+        f,
+        "pub const {}_COUNT: {} = {};\n",
+        prefix,
+        ename,
+        tab_name.len()
+    )
+    .unwrap();
+}
+
+const GC_BLOCK_LEN: usize = 32;
+
+fn build_general_category_table(f: &mut String, db: &UnicodeDB) {
+    dump_enum(f, "UnicodeGCEnum", "UNICODE_GC", unicode_gc_name);
+    dump_name_table(
+        f,
+        "unicode_gc_name_table",
+        unicode_gc_name,
+        unicode_gc_short_name,
+    );
+
+    let tab: Vec<u8> = db.tab[..=CHARCODE_MAX]
+        .iter()
+        .map(|ci| ci.general_category)
+        .collect();
+    build_enum_table(f, "unicode_gc", &tab, 5, true);
+}
+
+/* run length encoding of the values of 'tab' on 'value_bits' bits
+(less than 2^value_bits - 1), the other bits of the first byte
+containing the run length, with an index entry every GC_BLOCK_LEN
+bytes. If 'compress_case' is true, the alternating Lu/Ll runs are
+stored with the value 31. */
+fn build_enum_table(f: &mut String, cname: &str, tab: &[u8], value_bits: u32, compress_case: bool) {
+    let run_max = (1 << (8 - value_bits)) - 1;
+    let mut dbuf = Vec::new();
+    let mut dbuf1 = Vec::new();
+    let mut block_end_pos = GC_BLOCK_LEN;
+    let mut i = 0;
+    while i <= CHARCODE_MAX {
+        let mut v = tab[i] as u32;
+        assert!(v < (1 << value_bits) - 1);
+        let mut j = i + 1;
+        while j <= CHARCODE_MAX && tab[j] as u32 == v {
+            j += 1;
+        }
+        let mut n = j - i;
+        /* compress Lu/Ll runs */
+        if compress_case && v == GCat::Lu as u32 {
+            let mut n1 = 1;
+            while i + n1 <= CHARCODE_MAX && tab[i + n1] as u32 == v + (n1 as u32 & 1) {
+                n1 += 1;
+            }
+            if n1 > n {
+                v = 31;
+                n = n1;
+            }
+        }
+        n -= 1;
+        /* add an entry to the index if necessary */
+        if dbuf.len() >= block_end_pos {
+            let offset = dbuf.len() - block_end_pos;
+            assert!(offset <= 7);
+            put_le24(&mut dbuf1, i as u32 | (offset << 21) as u32);
+            block_end_pos += GC_BLOCK_LEN;
+        }
+        if n < run_max {
+            dbuf.push((n << value_bits | v as usize) as u8);
+        } else if n < run_max + 128 {
+            let n1 = n - run_max;
+            dbuf.push((run_max << value_bits | v as usize) as u8);
+            dbuf.push(n1 as u8);
+        } else if n < run_max + 128 + (1 << 14) {
+            let n1 = n - (run_max + 128);
+            dbuf.push((run_max << value_bits | v as usize) as u8);
+            dbuf.push(((n1 >> 8) + 128) as u8);
+            dbuf.push(n1 as u8);
+        } else {
+            let n1 = n - (run_max + 128 + (1 << 14));
+            assert!(n1 < (1 << 22));
+            dbuf.push((run_max << value_bits | v as usize) as u8);
+            dbuf.push(((n1 >> 16) + 128 + 64) as u8);
+            dbuf.push((n1 >> 8) as u8);
+            dbuf.push(n1 as u8);
+        }
+        i += n + 1;
+    }
+
+    /* last index entry */
+    put_le24(&mut dbuf1, i as u32);
+
+    dump_byte_table(f, &format!("{}_table", cname), &dbuf);
+    dump_byte_table(f, &format!("{}_index", cname), &dbuf1);
+}
+
+fn build_script_table(f: &mut String, db: &UnicodeDB) {
+    let tab = &db.tab;
+    dump_enum(
+        f,
+        "UnicodeScriptEnum",
+        "UNICODE_SCRIPT",
+        unicode_script_name,
+    );
+    dump_name_table(
+        f,
+        "unicode_script_name_table",
+        &unicode_script_name[1..],
+        &unicode_script_short_name[1..],
+    );
+
+    let mut dbuf = Vec::new();
+    let mut i = 0;
+    while i <= CHARCODE_MAX {
+        let v = tab[i].script;
+        let mut j = i + 1;
+        while j <= CHARCODE_MAX && tab[j].script == v {
+            j += 1;
+        }
+        if v == 0 && j == CHARCODE_MAX + 1 {
+            break;
+        }
+        let n = j - i - 1;
+        let type_0 = if v == 0 { 0 } else { 0x80 };
+        if n < 96 {
+            dbuf.push(n as u8 | type_0);
+        } else if n < 96 + (1 << 12) {
+            let n1 = n - 96;
+            dbuf.push(((n1 >> 8) + 96) as u8 | type_0);
+            dbuf.push(n1 as u8);
+        } else {
+            let n1 = n - (96 + (1 << 12));
+            assert!(n1 < (1 << 20));
+            dbuf.push(((n1 >> 16) + 112) as u8 | type_0);
+            dbuf.push((n1 >> 8) as u8);
+            dbuf.push(n1 as u8);
+        }
+        if type_0 != 0 {
+            dbuf.push(v);
+        }
+        i = j;
+    }
+
+    dump_byte_table(f, "unicode_script_table", &dbuf);
+}
+
+fn build_script_ext_table(f: &mut String, db: &UnicodeDB) {
+    let tab = &db.tab;
+    let mut dbuf = Vec::new();
+    let mut i = 0;
+    while i <= CHARCODE_MAX {
+        let script_ext = &tab[i].script_ext;
+        let mut j = i + 1;
+        while j <= CHARCODE_MAX && tab[j].script_ext == *script_ext {
+            j += 1;
+        }
+        let n = j - i - 1;
+        if n < 128 {
+            dbuf.push(n as u8);
+        } else if n < 128 + (1 << 14) {
+            let n1 = n - 128;
+            dbuf.push(((n1 >> 8) + 128) as u8);
+            dbuf.push(n1 as u8);
+        } else {
+            let n1 = n - (128 + (1 << 14));
+            assert!(n1 < (1 << 22));
+            dbuf.push(((n1 >> 16) + 128 + 64) as u8);
+            dbuf.push((n1 >> 8) as u8);
+            dbuf.push(n1 as u8);
+        }
+        dbuf.push(script_ext.len() as u8);
+        dbuf.extend_from_slice(script_ext);
+        i = j;
+    }
+
+    dump_byte_table(f, "unicode_script_ext_table", &dbuf);
+}
+
+/* the following properties are synthetized so no table is necessary */
+const PROP_TABLE_COUNT: usize = Prop::ASCII as usize;
+
+fn build_prop_list_table(f: &mut String, db: &UnicodeDB) {
+    for i in 0..PROP_TABLE_COUNT {
+        if i == Prop::ID_Start as usize
+            || i == Prop::Case_Ignorable as usize
+            || i == Prop::ID_Continue1 as usize
+        {
+            /* already generated */
+        } else {
+            build_prop_table(f, db, i, true);
+        }
+    }
+
+    dump_enum(f, "UnicodePropertyEnum", "UNICODE_PROP", unicode_prop_name);
+
+    let i = Prop::ASCII_Hex_Digit as usize;
+    let j = Prop::XID_Start as usize + 1;
+    dump_name_table(
+        f,
+        "unicode_prop_name_table",
+        &unicode_prop_name[i..j],
+        &unicode_prop_short_name[i..j],
+    );
+
+    writeln!(
+        f,
+        "static unicode_prop_table: [&[u8]; {}] = [",
+        PROP_TABLE_COUNT
+    )
+    .unwrap();
+    for name in &unicode_prop_name[..PROP_TABLE_COUNT] {
+        writeln!(f, "    &unicode_prop_{}_table,", name).unwrap();
+    }
+    f.push_str("];\n\n");
+
+    writeln!(
+        f,
+        "static unicode_prop_index_table: [&[u8]; {}] = [",
+        PROP_TABLE_COUNT
+    )
+    .unwrap();
+    for name in &unicode_prop_name[..PROP_TABLE_COUNT] {
+        writeln!(f, "    &unicode_prop_{}_index,", name).unwrap();
+    }
+    f.push_str("];\n");
+}
+
+const CC_BLOCK_LEN: usize = 32;
+
+fn build_cc_table(f: &mut String, db: &UnicodeDB) {
+    let tab = &db.tab;
+    let mut dbuf = Vec::new();
+    let mut dbuf1 = Vec::new();
+    let mut block_end_pos = CC_BLOCK_LEN;
+    let mut i = 0;
+    while i <= CHARCODE_MAX {
+        let cc = tab[i].combining_class as usize;
+        /* check increasing values */
+        let mut n = 1;
+        while i + n <= CHARCODE_MAX && tab[i + n].combining_class as usize == cc + n {
+            n += 1;
+        }
+        let mut type_0;
+        if n >= 2 {
+            type_0 = 1;
+        } else {
+            type_0 = 0;
+            n = 1;
+            while i + n <= CHARCODE_MAX && tab[i + n].combining_class as usize == cc {
+                n += 1;
+            }
+        }
+        /* no need to encode the last run */
+        if cc == 0 && i + n - 1 == CHARCODE_MAX {
+            break;
+        }
+        if type_0 == 0 {
+            if cc == 0 {
+                type_0 = 2;
+            } else if cc == 230 {
+                type_0 = 3;
+            }
+        }
+        let mut n1 = n - 1;
+
+        /* add an entry to the index if necessary */
+        if dbuf.len() >= block_end_pos {
+            put_le24(
+                &mut dbuf1,
+                i as u32 | ((dbuf.len() - block_end_pos) << 21) as u32,
+            );
+            block_end_pos += CC_BLOCK_LEN;
+        }
+        if n1 < 48 {
+            dbuf.push((n1 | (type_0 << 6)) as u8);
+        } else if n1 < 48 + (1 << 11) {
+            n1 -= 48;
+            dbuf.push((((n1 >> 8) + 48) | (type_0 << 6)) as u8);
+            dbuf.push(n1 as u8);
+        } else {
+            n1 -= 48 + (1 << 11);
+            assert!(n1 < (1 << 20));
+            dbuf.push((((n1 >> 16) + 56) | (type_0 << 6)) as u8);
+            dbuf.push((n1 >> 8) as u8);
+            dbuf.push(n1 as u8);
+        }
+        if type_0 == 0 || type_0 == 1 {
+            dbuf.push(cc as u8);
+        }
+        i += n;
+    }
+
+    /* last index entry */
+    put_le24(&mut dbuf1, i as u32);
+
+    dump_byte_table(f, "unicode_cc_table", &dbuf);
+    dump_byte_table(f, "unicode_cc_index", &dbuf1);
+}
+
+/* maximum length of decomposition: 18 chars (1), then 8 */
+const DECOMP_TYPE_C1: u8 = 0; /* 16 bit char */
+const DECOMP_TYPE_L1: u8 = 1; /* 16 bit char table */
+const DECOMP_TYPE_L7: u8 = 7;
+const DECOMP_TYPE_LL1: u8 = 8; /* 18 bit char table */
+const DECOMP_TYPE_LL2: u8 = 9;
+const DECOMP_TYPE_S1: u8 = 10; /* 8 bit char table */
+const DECOMP_TYPE_S5: u8 = 14;
+const DECOMP_TYPE_I1: u8 = 15; /* increment 16 bit char value */
+const DECOMP_TYPE_I2_0: u8 = 16;
+const DECOMP_TYPE_I3_1: u8 = 18;
+const DECOMP_TYPE_I4_1: u8 = 20;
+const DECOMP_TYPE_I4_2: u8 = 21;
+const DECOMP_TYPE_B1: u8 = 22; /* 16 bit base + 8 bit offset */
+const DECOMP_TYPE_B18: u8 = 30;
+const DECOMP_TYPE_LS2: u8 = 31;
+const DECOMP_TYPE_PAT3: u8 = 32;
+const DECOMP_TYPE_S2_UL: u8 = 33;
+const DECOMP_TYPE_LS2_UL: u8 = 34;
+
+/* first type and index of the incremented char for each length */
+const decomp_incr_tab: [(u8, &[usize]); 4] = [
+    (DECOMP_TYPE_I1, &[0]),
+    (DECOMP_TYPE_I2_0, &[0, 1]),
+    (DECOMP_TYPE_I3_1, &[1, 2]),
+    (DECOMP_TYPE_I4_1, &[1, 2]),
+];
+
+/*
+  entry size:
+  type   bits
+  code   18
+  len    7
+  compat 1
+  type   5
+  index  16
+  total  47
+*/
+
+#[derive(Clone, Copy, Default)]
+struct DecompEntry {
+    code: u32,
+    len: u8,
+    type_0: u8,
+    c_len: u8,
+    c_min: u16,
+    data_index: u16,
+    cost: i32, /* size in bytes from this entry to the end */
+}
+
+fn get_decomp_run_size(de: &DecompEntry) -> i32 {
+    let len = de.len as i32;
+    let c_len = de.c_len as i32;
+    let mut s = 6;
+    if de.type_0 == DECOMP_TYPE_C1 {
+        /* nothing more */
+    } else if de.type_0 <= DECOMP_TYPE_L7 {
+        s += len * c_len * 2;
+    } else if de.type_0 <= DECOMP_TYPE_LL2 {
+        /* 18 bits per char */
+        s += (len * c_len * 18 + 7) / 8;
+    } else if de.type_0 <= DECOMP_TYPE_S5 {
+        s += len * c_len;
+    } else if de.type_0 <= DECOMP_TYPE_I4_2 {
+        s += c_len * 2;
+    } else if de.type_0 <= DECOMP_TYPE_B18 {
+        s += 2 + len * c_len;
+    } else if de.type_0 <= DECOMP_TYPE_LS2 {
+        s += len * 3;
+    } else if de.type_0 <= DECOMP_TYPE_PAT3 {
+        s += 4 + len * 2;
+    } else if de.type_0 <= DECOMP_TYPE_S2_UL {
+        s += len;
+    } else if de.type_0 <= DECOMP_TYPE_LS2_UL {
+        s += (len / 2) * 3;
+    } else {
+        unreachable!();
+    }
+    s
+}
+
+const unicode_short_table: [u32; 2] = [0x2044, 0x2215];
+
+fn get_short_code(c: u32) -> Option<u8> {
+    if c < 0x80 {
+        Some(c as u8)
+    } else if (0x300..0x350).contains(&c) {
+        Some((c - 0x300 + 0x80) as u8)
+    } else {
+        unicode_short_table
+            .iter()
+            .position(|&v| v == c)
+            .map(|i| (i + 0x80 + 0x50) as u8)
+    }
+}
+
+fn is_short(code: u32) -> bool {
+    get_short_code(code).is_some()
+}
+
+fn is_short_tab(tab: &[u32]) -> bool {
+    tab.iter().all(|&c| is_short(c))
+}
+
+fn is_16bit(tab: &[u32]) -> bool {
+    tab.iter().all(|&c| c <= 0xffff)
+}
+
+fn to_lower_simple(c: u32) -> u32 {
+    /* Latin1 and Cyrillic */
+    if c < 0x100 || (0x410..=0x42f).contains(&c) {
+        c + 0x20
+    } else {
+        c + 1
+    }
+}
+
+/* select best encoding with dynamic programming */
+fn find_decomp_run(tab_de: &mut [DecompEntry], tab: &[CCInfo], i: usize) {
+    let ci = &tab[i];
+    let l = ci.decomp_data.len();
+    if l == 0 {
+        tab_de[i].cost = tab_de[i + 1].cost;
+        return;
+    }
+
+    /* the offset for the compose table has only 6 bits, so we must
+    limit if it can be used by the compose table */
+    let len_max = if !ci.is_compat && !ci.is_excluded && l == 2 {
+        64
+    } else {
+        127
+    };
+
+    tab_de[i].cost = i32::MAX;
+    let mut de = DecompEntry {
+        code: i as u32,
+        c_len: l as u8,
+        ..Default::default()
+    };
+    /* keep the entry 'de' of length 'n' if it is cheaper */
+    let try_entry = |tab_de: &mut [DecompEntry], de: &mut DecompEntry, n: usize| {
+        de.len = n as u8;
+        de.cost = get_decomp_run_size(de) + tab_de[i + n].cost;
+        if de.cost < tab_de[i].cost {
+            tab_de[i] = *de;
+        }
+    };
+
+    if !is_16bit(&ci.decomp_data) {
+        assert!(l <= 2);
+
+        de.type_0 = DECOMP_TYPE_LL1 + l as u8 - 1;
+        let mut n = 1;
+        loop {
+            try_entry(tab_de, &mut de, n);
+            if !(i + n <= CHARCODE_MAX && n < len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            /* Note: we accept a hole */
+            if !(ci1.decomp_data.is_empty()
+                || (ci1.decomp_data.len() == l && ci1.is_compat == ci.is_compat))
+            {
+                break;
+            }
+            n += 1;
+        }
+        return;
+    }
+
+    if l <= 7 {
+        let mut n = 1;
+        loop {
+            de.type_0 = if l == 1 && n == 1 {
+                DECOMP_TYPE_C1
+            } else {
+                DECOMP_TYPE_L1 + l as u8 - 1
+            };
+            try_entry(tab_de, &mut de, n);
+            if !(i + n <= CHARCODE_MAX && n < len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            /* Note: we accept a hole */
+            if !(ci1.decomp_data.is_empty()
+                || (ci1.decomp_data.len() == l
+                    && ci1.is_compat == ci.is_compat
+                    && is_16bit(&ci1.decomp_data)))
+            {
+                break;
+            }
+            n += 1;
+        }
+    }
+
+    if l <= 8 || l == 18 {
+        let mut c_min = -1;
+        let mut c_max = -1;
+        let mut n = 1;
+        loop {
+            for &c in &tab[i + n - 1].decomp_data {
+                let c = c as i32;
+                if c == 0x20 {
+                    /* we accept space for Arabic */
+                } else if c_min == -1 {
+                    c_min = c;
+                    c_max = c;
+                } else {
+                    c_min = c_min.min(c);
+                    c_max = c_max.max(c);
+                }
+            }
+            if c_max - c_min > 254 {
+                break;
+            }
+            de.type_0 = if l == 18 {
+                DECOMP_TYPE_B18
+            } else {
+                DECOMP_TYPE_B1 + l as u8 - 1
+            };
+            de.c_min = c_min as u16;
+            try_entry(tab_de, &mut de, n);
+            if !(i + n <= CHARCODE_MAX && n < len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            if !(ci1.decomp_data.len() == l && ci1.is_compat == ci.is_compat) {
+                break;
+            }
+            n += 1;
+        }
+    }
+
+    /* find an ascii run */
+    if l <= 5 && is_short_tab(&ci.decomp_data) {
+        de.type_0 = DECOMP_TYPE_S1 + l as u8 - 1;
+        let mut n = 1;
+        loop {
+            try_entry(tab_de, &mut de, n);
+            if !(i + n <= CHARCODE_MAX && n < len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            /* Note: we accept a hole */
+            if !(ci1.decomp_data.is_empty()
+                || (ci1.decomp_data.len() == l
+                    && ci1.is_compat == ci.is_compat
+                    && is_short_tab(&ci1.decomp_data)))
+            {
+                break;
+            }
+            n += 1;
+        }
+    }
+
+    /* check if a single char is increasing */
+    if l <= 4 {
+        let (type_0, idx_tab) = decomp_incr_tab[l - 1];
+        for (idx1, &idx) in idx_tab.iter().enumerate() {
+            de.type_0 = type_0 + idx1 as u8;
+            let mut n = 1;
+            loop {
+                try_entry(tab_de, &mut de, n);
+                if !(i + n <= CHARCODE_MAX && n < len_max) {
+                    break;
+                }
+                let ci1 = &tab[i + n];
+                if !(ci1.decomp_data.len() == l && ci1.is_compat == ci.is_compat) {
+                    break;
+                }
+                let is_incr = (0..l).all(|j| {
+                    let c = ci.decomp_data[j] + if j == idx { n as u32 } else { 0 };
+                    ci1.decomp_data[j] == c
+                });
+                if !is_incr {
+                    break;
+                }
+                n += 1;
+            }
+        }
+    }
+
+    if l == 3 {
+        de.type_0 = DECOMP_TYPE_PAT3;
+        let mut n = 1;
+        loop {
+            try_entry(tab_de, &mut de, n);
+            if !(i + n <= CHARCODE_MAX && n < len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            if !(ci1.decomp_data.len() == l
+                && ci1.is_compat == ci.is_compat
+                && ci1.decomp_data[1] <= 0xffff
+                && ci1.decomp_data[0] == ci.decomp_data[0]
+                && ci1.decomp_data[l - 1] == ci.decomp_data[l - 1])
+            {
+                break;
+            }
+            n += 1;
+        }
+    }
+
+    if l == 2 && is_short(ci.decomp_data[1]) {
+        de.type_0 = DECOMP_TYPE_LS2;
+        let mut n = 1;
+        loop {
+            try_entry(tab_de, &mut de, n);
+            if !(i + n <= CHARCODE_MAX && n < len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            if !(ci1.decomp_data.is_empty()
+                || (ci1.decomp_data.len() == l
+                    && ci1.is_compat == ci.is_compat
+                    && ci1.decomp_data[0] <= 0xffff
+                    && is_short(ci1.decomp_data[1])))
+            {
+                break;
+            }
+            n += 1;
+        }
+    }
+
+    if l == 2 {
+        let mut n = 0;
+        let mut is_16bit = false;
+        loop {
+            if !(i + n < CHARCODE_MAX && n + 2 <= len_max) {
+                break;
+            }
+            let ci1 = &tab[i + n];
+            if !(ci1.decomp_data.len() == l
+                && ci1.is_compat == ci.is_compat
+                && is_short(ci1.decomp_data[1]))
+            {
+                break;
+            }
+            if !is_16bit && !is_short(ci1.decomp_data[0]) {
+                is_16bit = true;
+            }
+            let ci2 = &tab[i + n + 1];
+            if !(ci2.decomp_data.len() == l
+                && ci2.is_compat == ci.is_compat
+                && ci2.decomp_data[0] == to_lower_simple(ci1.decomp_data[0])
+                && ci2.decomp_data[1] == ci1.decomp_data[1])
+            {
+                break;
+            }
+            n += 2;
+            de.type_0 = DECOMP_TYPE_S2_UL + is_16bit as u8;
+            try_entry(tab_de, &mut de, n);
+        }
+    }
+}
+
+fn put16(data_buf: &mut Vec<u8>, c: u32) {
+    data_buf.push(c as u8);
+    data_buf.push((c >> 8) as u8);
+}
+
+fn add_decomp_data(data_buf: &mut Vec<u8>, de: &mut DecompEntry, tab: &[CCInfo]) {
+    let code = de.code as usize;
+    let len = de.len as usize;
+    let c_len = de.c_len as usize;
+    /* decomposition of the j-th char of code + i, 0 for a hole */
+    let get = |i: usize, j: usize| tab[code + i].decomp_data.get(j).copied().unwrap_or(0);
+    let short = |c: u32| get_short_code(c).expect("short code");
+
+    de.data_index = data_buf.len() as u16;
+    if de.type_0 == DECOMP_TYPE_C1 {
+        assert!(tab[code].decomp_data.len() == 1);
+        de.data_index = tab[code].decomp_data[0] as u16;
+    } else if de.type_0 <= DECOMP_TYPE_L7 {
+        for i in 0..len {
+            for j in 0..c_len {
+                put16(data_buf, get(i, j));
+            }
+        }
+    } else if de.type_0 <= DECOMP_TYPE_LL2 {
+        let n = (len * c_len * 18).div_ceil(8);
+        let p = len * c_len * 2;
+        let idx = data_buf.len();
+        data_buf.resize(idx + n, 0);
+        let mut k = 0;
+        for i in 0..len {
+            for j in 0..c_len {
+                let c = get(i, j);
+                data_buf[idx + k * 2] = c as u8;
+                data_buf[idx + k * 2 + 1] = (c >> 8) as u8;
+                data_buf[idx + p + k / 4] |= ((c >> 16) << ((k % 4) * 2)) as u8;
+                k += 1;
+            }
+        }
+    } else if de.type_0 <= DECOMP_TYPE_S5 {
+        for i in 0..len {
+            for j in 0..c_len {
+                data_buf.push(short(get(i, j)));
+            }
+        }
+    } else if de.type_0 <= DECOMP_TYPE_I4_2 {
+        assert!(tab[code].decomp_data.len() == c_len);
+        for j in 0..c_len {
+            put16(data_buf, get(0, j));
+        }
+    } else if de.type_0 <= DECOMP_TYPE_B18 {
+        put16(data_buf, de.c_min as u32);
+        for i in 0..len {
+            assert!(tab[code + i].decomp_data.len() == c_len);
+            for j in 0..c_len {
+                let c = get(i, j);
+                if c == 0x20 {
+                    data_buf.push(0xff);
+                } else {
+                    let c = c.wrapping_sub(de.c_min as u32);
+                    assert!(c <= 254);
+                    data_buf.push(c as u8);
+                }
+            }
+        }
+    } else if de.type_0 <= DECOMP_TYPE_LS2 {
+        assert!(c_len == 2);
+        for i in 0..len {
+            put16(data_buf, get(i, 0));
+            data_buf.push(short(get(i, 1)));
+        }
+    } else if de.type_0 <= DECOMP_TYPE_PAT3 {
+        assert!(tab[code].decomp_data.len() == 3);
+        put16(data_buf, get(0, 0));
+        put16(data_buf, get(0, 2));
+        for i in 0..len {
+            assert!(tab[code + i].decomp_data.len() == 3);
+            put16(data_buf, get(i, 1));
+        }
+    } else if de.type_0 <= DECOMP_TYPE_S2_UL {
+        for i in (0..len).step_by(2) {
+            data_buf.push(short(get(i, 0)));
+            data_buf.push(short(get(i, 1)));
+        }
+    } else if de.type_0 <= DECOMP_TYPE_LS2_UL {
+        for i in (0..len).step_by(2) {
+            put16(data_buf, get(i, 0));
+            data_buf.push(short(get(i, 1)));
+        }
+    } else {
+        unreachable!();
+    }
+}
+
+fn build_decompose_table(f: &mut String, db: &UnicodeDB) {
+    let tab = &db.tab;
+    let mut tab_de = vec![DecompEntry::default(); CHARCODE_MAX + 2];
+
+    for i in (0..=CHARCODE_MAX).rev() {
+        find_decomp_run(&mut tab_de, tab, i);
+    }
+
+    /* build the data buffer */
+    let mut data_buf = Vec::new();
+    let mut entries = Vec::new();
+    let mut i = 0;
+    while i <= CHARCODE_MAX {
+        let de = &mut tab_de[i];
+        if de.len != 0 {
+            add_decomp_data(&mut data_buf, de, tab);
+            entries.push(*de);
+            i += de.len as usize;
+        } else {
+            i += 1;
+        }
+    }
+
+    let tab1: Vec<u32> = entries
+        .iter()
+        .map(|de| {
+            (de.code << (32 - 18))
+                | ((de.len as u32) << (32 - 18 - 7))
+                | ((de.type_0 as u32) << (32 - 18 - 7 - 6))
+                | tab[de.code as usize].is_compat as u32
+        })
+        .collect();
+    dump_array(f, "unicode_decomp_table1", "u32", &tab1);
+
+    let tab2: Vec<u32> = entries.iter().map(|de| de.data_index as u32).collect();
+    dump_array(f, "unicode_decomp_table2", "u16", &tab2);
+
+    dump_byte_table(f, "unicode_decomp_data", &data_buf);
+
+    build_compose_table(f, db, &entries);
+}
+
+/* position of 'c' in the decomposition entries: index of the entry
+(10 bits) and offset in the entry (6 bits) */
+fn get_decomp_pos(entries: &[DecompEntry], c: u32) -> Option<u32> {
+    let k = entries.partition_point(|de| de.code <= c).checked_sub(1)?;
+    let de = &entries[k];
+    if c >= de.code + de.len as u32 {
+        return None;
+    }
+    let v = c - de.code;
+    assert!(v < 64);
+    let v = v | (k as u32) << 6;
+    assert!(v < 65536);
+    Some(v)
+}
+
+fn build_compose_table(f: &mut String, db: &UnicodeDB, entries: &[DecompEntry]) {
+    let mut tab_ce = Vec::new();
+    for (i, ci) in db.tab[..=CHARCODE_MAX].iter().enumerate() {
+        if ci.decomp_data.len() == 2 && !ci.is_compat && !ci.is_excluded {
+            tab_ce.push((ci.decomp_data[0], ci.decomp_data[1], i as u32));
+        }
+    }
+    tab_ce.sort_by_key(|ce| (ce.0, ce.1));
+
+    let tab: Vec<u32> = tab_ce
+        .iter()
+        .map(|ce| match get_decomp_pos(entries, ce.2) {
+            Some(v) => v,
+            None => fatal(&format!("entry for c={:04x} not found", ce.2)),
+        })
+        .collect();
+    dump_array(f, "unicode_comp_table", "u16", &tab);
+}
+
+/* Zero_Width is not a Unicode property: it contains the characters
+which take no cell in a terminal (unicode_width.rs) */
+fn is_zero_width(db: &UnicodeDB, c: usize) -> bool {
+    let gc = db.tab[c].general_category;
+    let is_mark_or_format = gc == GCat::Mn as u8 || gc == GCat::Me as u8 || gc == GCat::Cf as u8;
+    let is_hangul_vowel_or_final = (0x1160..=0x11ff).contains(&c) || (0xd7b0..=0xd7ff).contains(&c);
+    /* the soft hyphen is default ignorable but it is displayed at
+    the end of a line */
+    c != 0xad
+        && ((is_mark_or_format && !db.get_prop(c, Prop::Prepended_Concatenation_Mark as usize))
+            || (is_hangul_vowel_or_final && gc != GCat::Cn as u8)
+            || db.get_prop(c, Prop::Default_Ignorable_Code_Point as usize))
+}
+
+fn build_width_tables(f: &mut String, db: &UnicodeDB) {
+    dump_enum(f, "UnicodeEAWEnum", "UNICODE_EAW", unicode_eaw_name);
+
+    let tab: Vec<u8> = db.tab[..=CHARCODE_MAX].iter().map(|ci| ci.eaw).collect();
+    build_enum_table(f, "unicode_eaw", &tab, 5, false);
+
+    build_bool_table(f, "unicode_prop_Zero_Width", true, |c| is_zero_width(db, c));
+}
+
+/* Bidi_Class of the unassigned code points of these ranges (header of
+DerivedBidiClass.txt). The other unassigned code points are BN if
+they are default ignorable or noncharacters and L otherwise. */
+const bidi_class_default_ranges: [(usize, usize, BidiClass); 22] = [
+    (0x0590, 0x05ff, BidiClass::R),
+    (0x0600, 0x07bf, BidiClass::AL),
+    (0x07c0, 0x085f, BidiClass::R),
+    (0x0860, 0x08ff, BidiClass::AL),
+    (0x20a0, 0x20cf, BidiClass::ET),
+    (0xfb1d, 0xfb4f, BidiClass::R),
+    (0xfb50, 0xfdcf, BidiClass::AL),
+    (0xfdf0, 0xfdff, BidiClass::AL),
+    (0xfe70, 0xfeff, BidiClass::AL),
+    (0x10800, 0x10cff, BidiClass::R),
+    (0x10d00, 0x10d3f, BidiClass::AL),
+    (0x10d40, 0x10ebf, BidiClass::R),
+    (0x10f00, 0x10f2f, BidiClass::R),
+    (0x10f30, 0x10f6f, BidiClass::AL),
+    (0x10f70, 0x10fff, BidiClass::R),
+    (0x1e800, 0x1ec6f, BidiClass::R),
+    (0x1ec70, 0x1ecbf, BidiClass::AL),
+    (0x1ecc0, 0x1ecff, BidiClass::R),
+    (0x1ed00, 0x1ed4f, BidiClass::AL),
+    (0x1ed50, 0x1edff, BidiClass::R),
+    (0x1ee00, 0x1eeff, BidiClass::AL),
+    (0x1ef00, 0x1efff, BidiClass::R),
+];
+
+fn build_bidi_tables(f: &mut String, db: &UnicodeDB) {
+    dump_enum(f, "UnicodeBidiClassEnum", "BIDI", unicode_bidi_class_name);
+    dump_name_table(
+        f,
+        "unicode_bidi_class_name_table",
+        unicode_bidi_class_name,
+        unicode_bidi_class_long_name,
+    );
+
+    let mut tab: Vec<u8> = db.tab[..=CHARCODE_MAX]
+        .iter()
+        .map(|ci| ci.bidi_class)
+        .collect();
+    let is_unassigned = |c: usize| db.tab[c].general_category == GCat::Cn as u8;
+    for &(c0, c1, v) in &bidi_class_default_ranges {
+        for c in (c0..=c1).filter(|&c| is_unassigned(c)) {
+            tab[c] = v as u8;
+        }
+    }
+    for c in (0..=CHARCODE_MAX).filter(|&c| is_unassigned(c)) {
+        if db.get_prop(c, Prop::Default_Ignorable_Code_Point as usize)
+            || db.get_prop(c, Prop::Noncharacter_Code_Point as usize)
+        {
+            tab[c] = BidiClass::BN as u8;
+        }
+    }
+    build_enum_table(f, "unicode_bidi_class", &tab, 5, false);
+
+    writeln!(
+        f,
+        "static unicode_bidi_bracket_table: [[u16; 2]; {}] = [",
+        db.bidi_brackets.len()
+    )
+    .unwrap();
+    for &(c0, c1) in &db.bidi_brackets {
+        assert!(c0 <= 0xffff && c1 <= 0xffff);
+        writeln!(f, "    [0x{:04x}, 0x{:04x}],", c0, c1).unwrap();
+    }
+    f.push_str("];\n\n");
+}
+
+fn build_line_break_tables(f: &mut String, db: &UnicodeDB) {
+    dump_enum(f, "UnicodeLineBreakEnum", "LB", unicode_line_break_name);
+    dump_name_table(
+        f,
+        "unicode_line_break_name_table",
+        unicode_line_break_name,
+        unicode_line_break_long_name,
+    );
+
+    /* the Hangul LV syllables (H2) are stored as H3 so that the
+    syllables make a single run */
+    let tab: Vec<u8> = db.tab[..=CHARCODE_MAX]
+        .iter()
+        .map(|ci| {
+            if ci.line_break == LineBreak::H2 as u8 {
+                LineBreak::H3 as u8
+            } else {
+                ci.line_break
+            }
+        })
+        .collect();
+    build_enum_table(f, "unicode_line_break", &tab, 6, false);
+}
+
+/* maximum number of names in an entry of unicode_name_block_table */
+const NAME_BLOCK_LEN: u32 = 32;
+/* words with a 1 byte code in unicode_name_data */
+const NAME_WORD_SHORT_COUNT: usize = 0xc0;
+
+fn build_name_tables(f: &mut String, db: &UnicodeDB) {
+    /* the names ending with their hexadecimal value are stored as
+    ranges */
+    let mut names = Vec::new();
+    let mut hex_ranges: Vec<(u32, u32, String)> = Vec::new();
+    for (c, name) in &db.names {
+        let prefix = name
+            .strip_suffix(format!("{:04X}", c).as_str())
+            .filter(|prefix| prefix.ends_with('-'));
+        match (prefix, hex_ranges.last_mut()) {
+            (Some(prefix), Some(r)) if r.1 + 1 == *c && r.2 == prefix => r.1 = *c,
+            (Some(prefix), _) => hex_ranges.push((*c, *c, prefix.to_string())),
+            (None, _) => names.push((*c, name.as_str())),
+        }
+    }
+    hex_ranges.extend(db.name_ranges.iter().cloned());
+    hex_ranges.sort();
+
+    /* the words taking the most space come first so that they get a
+    1 byte code */
+    let mut word_count: HashMap<&str, usize> = HashMap::new();
+    for (_, name) in &names {
+        assert!(name.is_ascii());
+        for w in name.split(' ') {
+            *word_count.entry(w).or_insert(0) += 1;
+        }
+    }
+    let mut words: Vec<&str> = word_count.keys().cloned().collect();
+    words.sort_by_key(|w| (Reverse(word_count[w] * w.len()), *w));
+    let word_index: HashMap<&str, usize> = words.iter().enumerate().map(|(i, w)| (*w, i)).collect();
+    assert!(words.len() < NAME_WORD_SHORT_COUNT + ((0x100 - NAME_WORD_SHORT_COUNT) << 8));
+
+    let mut lexicon = Vec::new();
+    let mut lexicon_index = Vec::new();
+    for (i, w) in words.iter().enumerate() {
+        if i % 16 == 0 {
+            lexicon_index.push(lexicon.len() as u32);
+        }
+        lexicon.extend_from_slice(w.as_bytes());
+        *lexicon.last_mut().unwrap() |= 0x80;
+    }
+
+    /* each name is encoded as the number of leading words shared with
+    the previous name of the block, at most 15, followed by the
+    indexes of the other words. At least one word is encoded. */
+    let mut data = Vec::new();
+    let mut blocks: Vec<(u32, u32, u32)> = Vec::new();
+    let mut prev: Vec<usize> = Vec::new();
+    for &(c, name) in &names {
+        match blocks.last_mut() {
+            Some((first, count, _)) if *first + *count == c && *count < NAME_BLOCK_LEN => {
+                *count += 1
+            }
+            _ => {
+                blocks.push((c, 1, data.len() as u32));
+                prev.clear();
+            }
+        }
+        let w: Vec<usize> = name.split(' ').map(|w| word_index[w]).collect();
+        let mut shared = 0;
+        while shared < 15
+            && shared + 1 < w.len()
+            && shared < prev.len()
+            && prev[shared] == w[shared]
+        {
+            shared += 1;
+        }
+        let n = w.len() - shared;
+        if n < 15 {
+            data.push((shared << 4 | n) as u8);
+        } else {
+            assert!(n - 15 < 256);
+            data.push((shared << 4 | 15) as u8);
+            data.push((n - 15) as u8);
+        }
+        for &idx in &w[shared..] {
+            if idx < NAME_WORD_SHORT_COUNT {
+                data.push(idx as u8);
+            } else {
+                let idx = idx - NAME_WORD_SHORT_COUNT;
+                data.push((NAME_WORD_SHORT_COUNT + (idx >> 8)) as u8);
+                data.push(idx as u8);
+            }
+        }
+        prev = w;
+    }
+
+    /* the names of unicode_name_block_table sorted in byte order,
+    as (block index << 5) | (index in the block) */
+    assert!(blocks.len() << NAME_BLOCK_LEN.trailing_zeros() <= 0x10000);
+    let mut sorted: Vec<(&str, u32)> = Vec::new();
+    for (k, &(first, count, _)) in blocks.iter().enumerate() {
+        let start = names.partition_point(|&(c, _)| c < first);
+        for (i, &(_, name)) in names[start..start + count as usize].iter().enumerate() {
+            sorted.push((name, (k as u32) << NAME_BLOCK_LEN.trailing_zeros() | i as u32));
+        }
+    }
+    sorted.sort();
+    let sorted: Vec<u32> = sorted.iter().map(|&(_, v)| v).collect();
+
+    /* only the aliases of the assigned code points are kept */
+    let mut aliases: Vec<(&str, u32)> = db
+        .name_aliases
+        .iter()
+        .filter(|(c, _)| db.tab[*c as usize].general_category != GCat::Cn as u8)
+        .map(|(c, alias)| (alias.as_str(), *c))
+        .collect();
+    aliases.sort();
+
+    dump_byte_table(f, "unicode_name_lexicon", &lexicon);
+    dump_array(f, "unicode_name_lexicon_index", "u32", &lexicon_index);
+    dump_byte_table(f, "unicode_name_data", &data);
+
+    writeln!(
+        f,
+        "static unicode_name_block_table: [[u32; 2]; {}] = [",
+        blocks.len()
+    )
+    .unwrap();
+    for &(first, count, offset) in &blocks {
+        writeln!(f, "    [0x{:x}, {}],", first | (count - 1) << 21, offset).unwrap();
+    }
+    f.push_str("];\n\n");
+
+    writeln!(
+        f,
+        "static unicode_name_hex_range_table: [(u32, u32, &str); {}] = [",
+        hex_ranges.len()
+    )
+    .unwrap();
+    for (first, last, prefix) in &hex_ranges {
+        writeln!(f, "    (0x{:05x}, 0x{:05x}, \"{}\"),", first, last, prefix).unwrap();
+    }
+    f.push_str("];\n\n");
+
+    dump_array(f, "unicode_name_sorted_table", "u16", &sorted);
+
+    writeln!(
+        f,
+        "static unicode_name_alias_table: [(&str, u32); {}] = [",
+        aliases.len()
+    )
+    .unwrap();
+    for (alias, c) in &aliases {
+        writeln!(f, "    (\"{}\", 0x{:05x}),", alias, c).unwrap();
+    }
+    f.push_str("];\n\n");
+}
+
+/* number of bits of the offsets of confusable_index */
+const CONFUSABLE_OFFSET_BITS: u32 = 14;
+
+fn build_security_tables(f: &mut String, db: &UnicodeDB) {
+    let is_assigned = |c: u32| db.tab[c as usize].general_category != GCat::Cn as u8;
+
+    let mut confusables: Vec<&(u32, Vec<u32>)> = db
+        .confusables
+        .iter()
+        .filter(|(c, _)| is_assigned(*c))
+        .collect();
+    confusables.sort();
+    let mut index = Vec::new();
+    let mut data = Vec::new();
+    for (c, proto) in confusables {
+        index.push(c << CONFUSABLE_OFFSET_BITS | data.len() as u32);
+        for &c1 in proto {
+            let mut buf = [0; 2];
+            let ch = char::from_u32(c1).unwrap();
+            data.extend(ch.encode_utf16(&mut buf).iter().map(|&v| v as u32));
+        }
+    }
+    assert!(data.len() < 1 << CONFUSABLE_OFFSET_BITS);
+    /* the last entry only gives the end of the data */
+    index.push(u32::MAX << CONFUSABLE_OFFSET_BITS | data.len() as u32);
+    dump_array(f, "confusable_index", "u32", &index);
+    dump_array(f, "confusable_data", "u16", &data);
+
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for c in 0..=CHARCODE_MAX as u32 {
+        if db.tab[c as usize].is_identifier_allowed && is_assigned(c) {
+            match ranges.last_mut() {
+                Some(r) if r.1 + 1 == c => r.1 = c,
+                _ => ranges.push((c, c)),
+            }
+        }
+    }
+    writeln!(
+        f,
+        "static identifier_allowed_table: [[u32; 2]; {}] = [",
+        ranges.len()
+    )
+    .unwrap();
+    for &(c0, c1) in &ranges {
+        writeln!(f, "    [0x{:05x}, 0x{:05x}],", c0, c1).unwrap();
+    }
+    f.push_str("];\n\n");
+}
+
+fn write_table_file(dir: &str, name: &str, body: &str) {
+    let filename = format!("{}/{}", dir, name);
+    let mut f = String::from(
+        "/* Compressed unicode tables */\n\
+         /* Automatically generated file - do not edit */\n\
+         \n",
+    );
+    f.push_str(body.trim_end());
+    f.push('\n');
+    if let Err(err) = fs::write(&filename, f) {
+        fatal(&format!("{}: {}", filename, err));
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 3 {
+        println!(
+            "usage: {} unicode_db_path output_dir\n\
+             \n\
+             Generate the Rust Unicode tables from the Unicode Character Database files",
+            args[0]
+        );
+        exit(1);
+    }
+    let unicode_db_path = &args[1];
+    let outdir = &args[2];
+    let filename = |name: &str| format!("{}/{}", unicode_db_path, name);
+
+    check_input_files(unicode_db_path);
+    let mut db = UnicodeDB::new();
+    parse_unicode_data(&mut db, &filename("UnicodeData.txt"));
+    parse_special_casing(&mut db, &filename("SpecialCasing.txt"));
+    parse_case_folding(&mut db, &filename("CaseFolding.txt"));
+    parse_composition_exclusions(&mut db, &filename("CompositionExclusions.txt"));
+    parse_derived_core_properties(&mut db, &filename("DerivedCoreProperties.txt"));
+    parse_derived_norm_properties(&mut db, &filename("DerivedNormalizationProps.txt"));
+    parse_prop_list(&mut db, &filename("PropList.txt"));
+    parse_scripts(&mut db, &filename("Scripts.txt"));
+    parse_script_extensions(&mut db, &filename("ScriptExtensions.txt"));
+    parse_prop_list(&mut db, &filename("emoji-data.txt"));
+    parse_east_asian_width(&mut db, &filename("EastAsianWidth.txt"));
+    parse_bidi_brackets(&mut db, &filename("BidiBrackets.txt"));
+    parse_line_break(&mut db, &filename("LineBreak.txt"));
+    parse_confusables(&mut db, &filename("confusables.txt"));
+    parse_identifier_status(&mut db, &filename("IdentifierStatus.txt"));
+    parse_name_aliases(&mut db, &filename("NameAliases.txt"));
+
+    let ct = build_conv_table(&db);
+
+    let mut f = String::new();
+    dump_case_conv_table(&mut f, &ct);
+    compute_internal_props(&mut db);
+    build_flags_tables(&mut f, &db);
+    build_cc_table(&mut f, &db);
+    build_decompose_table(&mut f, &db);
+    build_general_category_table(&mut f, &db);
+    build_script_table(&mut f, &db);
+    build_script_ext_table(&mut f, &db);
+    build_prop_list_table(&mut f, &db);
+    write_table_file(outdir, "libunicode_table.rs", &f);
+
+    let mut f = String::new();
+    build_width_tables(&mut f, &db);
+    write_table_file(outdir, "unicode_width_table.rs", &f);
+
+    let mut f = String::new();
+    build_bidi_tables(&mut f, &db);
+    write_table_file(outdir, "unicode_bidi_table.rs", &f);
+
+    let mut f = String::new();
+    build_name_tables(&mut f, &db);
+    write_table_file(outdir, "unicode_names_table.rs", &f);
+
+    let mut f = String::new();
+    build_security_tables(&mut f, &db);
+    write_table_file(outdir, "unicode_security_table.rs", &f);
+
+    let mut f = String::new();
+    build_line_break_tables(&mut f, &db);
+    write_table_file(outdir, "unicode_linebreak_table.rs", &f);
+}
@@ -0,0 +1,397 @@
+/*
+ * Names of the general categories, scripts, properties, East Asian
+ * widths, bidirectional classes and line break classes handled by the
+ * table generator, in the order of the generated enums. This is the
+ * counterpart of unicode_gen_def.h.
+ */
+
+macro_rules! def_enum {
+    ($name:ident, $names:ident, $short_names:ident; $($id:ident => $str:expr,)*) => {
+        #[allow(dead_code, non_camel_case_types, clippy::enum_variant_names, clippy::upper_case_acronyms)]
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum $name {
+            $($id,)*
+        }
+
+        pub static $names: &[&str] = &[$(stringify!($id),)*];
+        #[allow(dead_code)]
+        pub static $short_names: &[&str] = &[$($str,)*];
+    };
+}
+
+def_enum! {
+    GCat, unicode_gc_name, unicode_gc_short_name;
+
+    Cn => "Unassigned", /* must be zero */
+    Lu => "Uppercase_Letter",
+    Ll => "Lowercase_Letter",
+    Lt => "Titlecase_Letter",
+    Lm => "Modifier_Letter",
+    Lo => "Other_Letter",
+    Mn => "Nonspacing_Mark",
+    Mc => "Spacing_Mark",
+    Me => "Enclosing_Mark",
+    Nd => "Decimal_Number,digit",
+    Nl => "Letter_Number",
+    No => "Other_Number",
+    Sm => "Math_Symbol",
+    Sc => "Currency_Symbol",
+    Sk => "Modifier_Symbol",
+    So => "Other_Symbol",
+    Pc => "Connector_Punctuation",
+    Pd => "Dash_Punctuation",
+    Ps => "Open_Punctuation",
+    Pe => "Close_Punctuation",
+    Pi => "Initial_Punctuation",
+    Pf => "Final_Punctuation",
+    Po => "Other_Punctuation",
+    Zs => "Space_Separator",
+    Zl => "Line_Separator",
+    Zp => "Paragraph_Separator",
+    Cc => "Control,cntrl",
+    Cf => "Format",
+    Cs => "Surrogate",
+    Co => "Private_Use",
+    /* synthetic properties */
+    LC => "Cased_Letter",
+    L => "Letter",
+    M => "Mark,Combining_Mark",
+    N => "Number",
+    S => "Symbol",
+    P => "Punctuation,punct",
+    Z => "Separator",
+    C => "Other",
+}
+
+def_enum! {
+    Script, unicode_script_name, unicode_script_short_name;
+
+    /* scripts aliases names in PropertyValueAliases.txt */
+    Unknown => "Zzzz",
+    Adlam => "Adlm",
+    Ahom => "Ahom",
+    Anatolian_Hieroglyphs => "Hluw",
+    Arabic => "Arab",
+    Armenian => "Armn",
+    Avestan => "Avst",
+    Balinese => "Bali",
+    Bamum => "Bamu",
+    Bassa_Vah => "Bass",
+    Batak => "Batk",
+    Bengali => "Beng",
+    Bhaiksuki => "Bhks",
+    Bopomofo => "Bopo",
+    Brahmi => "Brah",
+    Braille => "Brai",
+    Buginese => "Bugi",
+    Buhid => "Buhd",
+    Canadian_Aboriginal => "Cans",
+    Carian => "Cari",
+    Caucasian_Albanian => "Aghb",
+    Chakma => "Cakm",
+    Cham => "Cham",
+    Cherokee => "Cher",
+    Chorasmian => "Chrs",
+    Common => "Zyyy",
+    Coptic => "Copt,Qaac",
+    Cuneiform => "Xsux",
+    Cypriot => "Cprt",
+    Cyrillic => "Cyrl",
+    Deseret => "Dsrt",
+    Devanagari => "Deva",
+    Dives_Akuru => "Diak",
+    Dogra => "Dogr",
+    Duployan => "Dupl",
+    Egyptian_Hieroglyphs => "Egyp",
+    Elbasan => "Elba",
+    Elymaic => "Elym",
+    Ethiopic => "Ethi",
+    Georgian => "Geor",
+    Glagolitic => "Glag",
+    Gothic => "Goth",
+    Grantha => "Gran",
+    Greek => "Grek",
+    Gujarati => "Gujr",
+    Gunjala_Gondi => "Gong",
+    Gurmukhi => "Guru",
+    Han => "Hani",
+    Hangul => "Hang",
+    Hanifi_Rohingya => "Rohg",
+    Hanunoo => "Hano",
+    Hatran => "Hatr",
+    Hebrew => "Hebr",
+    Hiragana => "Hira",
+    Imperial_Aramaic => "Armi",
+    Inherited => "Zinh,Qaai",
+    Inscriptional_Pahlavi => "Phli",
+    Inscriptional_Parthian => "Prti",
+    Javanese => "Java",
+    Kaithi => "Kthi",
+    Kannada => "Knda",
+    Katakana => "Kana",
+    Kayah_Li => "Kali",
+    Kharoshthi => "Khar",
+    Khmer => "Khmr",
+    Khojki => "Khoj",
+    Khitan_Small_Script => "Kits",
+    Khudawadi => "Sind",
+    Lao => "Laoo",
+    Latin => "Latn",
+    Lepcha => "Lepc",
+    Limbu => "Limb",
+    Linear_A => "Lina",
+    Linear_B => "Linb",
+    Lisu => "Lisu",
+    Lycian => "Lyci",
+    Lydian => "Lydi",
+    Makasar => "Maka",
+    Mahajani => "Mahj",
+    Malayalam => "Mlym",
+    Mandaic => "Mand",
+    Manichaean => "Mani",
+    Marchen => "Marc",
+    Masaram_Gondi => "Gonm",
+    Medefaidrin => "Medf",
+    Meetei_Mayek => "Mtei",
+    Mende_Kikakui => "Mend",
+    Meroitic_Cursive => "Merc",
+    Meroitic_Hieroglyphs => "Mero",
+    Miao => "Plrd",
+    Modi => "Modi",
+    Mongolian => "Mong",
+    Mro => "Mroo",
+    Multani => "Mult",
+    Myanmar => "Mymr",
+    Nabataean => "Nbat",
+    Nandinagari => "Nand",
+    New_Tai_Lue => "Talu",
+    Newa => "Newa",
+    Nko => "Nkoo",
+    Nushu => "Nshu",
+    Nyiakeng_Puachue_Hmong => "Hmnp",
+    Ogham => "Ogam",
+    Ol_Chiki => "Olck",
+    Old_Hungarian => "Hung",
+    Old_Italic => "Ital",
+    Old_North_Arabian => "Narb",
+    Old_Permic => "Perm",
+    Old_Persian => "Xpeo",
+    Old_Sogdian => "Sogo",
+    Old_South_Arabian => "Sarb",
+    Old_Turkic => "Orkh",
+    Oriya => "Orya",
+    Osage => "Osge",
+    Osmanya => "Osma",
+    Pahawh_Hmong => "Hmng",
+    Palmyrene => "Palm",
+    Pau_Cin_Hau => "Pauc",
+    Phags_Pa => "Phag",
+    Phoenician => "Phnx",
+    Psalter_Pahlavi => "Phlp",
+    Rejang => "Rjng",
+    Runic => "Runr",
+    Samaritan => "Samr",
+    Saurashtra => "Saur",
+    Sharada => "Shrd",
+    Shavian => "Shaw",
+    Siddham => "Sidd",
+    SignWriting => "Sgnw",
+    Sinhala => "Sinh",
+    Sogdian => "Sogd",
+    Sora_Sompeng => "Sora",
+    Soyombo => "Soyo",
+    Sundanese => "Sund",
+    Syloti_Nagri => "Sylo",
+    Syriac => "Syrc",
+    Tagalog => "Tglg",
+    Tagbanwa => "Tagb",
+    Tai_Le => "Tale",
+    Tai_Tham => "Lana",
+    Tai_Viet => "Tavt",
+    Takri => "Takr",
+    Tamil => "Taml",
+    Tangut => "Tang",
+    Telugu => "Telu",
+    Thaana => "Thaa",
+    Thai => "Thai",
+    Tibetan => "Tibt",
+    Tifinagh => "Tfng",
+    Tirhuta => "Tirh",
+    Ugaritic => "Ugar",
+    Vai => "Vaii",
+    Wancho => "Wcho",
+    Warang_Citi => "Wara",
+    Yezidi => "Yezi",
+    Yi => "Yiii",
+    Zanabazar_Square => "Zanb",
+}
+
+def_enum! {
+    Prop, unicode_prop_name, unicode_prop_short_name;
+
+    /* Prop list not exported to regexp */
+    Hyphen => "",
+    Other_Math => "",
+    Other_Alphabetic => "",
+    Other_Lowercase => "",
+    Other_Uppercase => "",
+    Other_Grapheme_Extend => "",
+    Other_Default_Ignorable_Code_Point => "",
+    Other_ID_Start => "",
+    Other_ID_Continue => "",
+    Prepended_Concatenation_Mark => "",
+    /* additional computed properties for smaller tables */
+    ID_Continue1 => "",
+    XID_Start1 => "",
+    XID_Continue1 => "",
+    Changes_When_Titlecased1 => "",
+    Changes_When_Casefolded1 => "",
+    Changes_When_NFKC_Casefolded1 => "",
+
+    /* Prop list exported to JS */
+    ASCII_Hex_Digit => "AHex",
+    Bidi_Control => "Bidi_C",
+    Dash => "",
+    Deprecated => "Dep",
+    Diacritic => "Dia",
+    Extender => "Ext",
+    Hex_Digit => "Hex",
+    IDS_Binary_Operator => "IDSB",
+    IDS_Trinary_Operator => "IDST",
+    Ideographic => "Ideo",
+    Join_Control => "Join_C",
+    Logical_Order_Exception => "LOE",
+    Noncharacter_Code_Point => "NChar",
+    Pattern_Syntax => "Pat_Syn",
+    Pattern_White_Space => "Pat_WS",
+    Quotation_Mark => "QMark",
+    Radical => "",
+    Regional_Indicator => "RI",
+    Sentence_Terminal => "STerm",
+    Soft_Dotted => "SD",
+    Terminal_Punctuation => "Term",
+    Unified_Ideograph => "UIdeo",
+    Variation_Selector => "VS",
+    White_Space => "space",
+    Bidi_Mirrored => "Bidi_M",
+    Emoji => "",
+    Emoji_Component => "EComp",
+    Emoji_Modifier => "EMod",
+    Emoji_Modifier_Base => "EBase",
+    Emoji_Presentation => "EPres",
+    Extended_Pictographic => "ExtPict",
+    Default_Ignorable_Code_Point => "DI",
+    ID_Start => "IDS",
+    Case_Ignorable => "CI",
+
+    /* other binary properties */
+    ASCII => "",
+    Alphabetic => "Alpha",
+    Any => "",
+    Assigned => "",
+    Cased => "",
+    Changes_When_Casefolded => "CWCF",
+    Changes_When_Casemapped => "CWCM",
+    Changes_When_Lowercased => "CWL",
+    Changes_When_NFKC_Casefolded => "CWKCF",
+    Changes_When_Titlecased => "CWT",
+    Changes_When_Uppercased => "CWU",
+    Grapheme_Base => "Gr_Base",
+    Grapheme_Extend => "Gr_Ext",
+    ID_Continue => "IDC",
+    Lowercase => "Lower",
+    Math => "",
+    Uppercase => "Upper",
+    XID_Continue => "XIDC",
+    XID_Start => "XIDS",
+
+    /* internal tables with index */
+    Cased1 => "",
+}
+
+def_enum! {
+    EAW, unicode_eaw_name, unicode_eaw_long_name;
+
+    N => "Neutral", /* must be zero */
+    A => "Ambiguous",
+    H => "Halfwidth",
+    F => "Fullwidth",
+    Na => "Narrow",
+    W => "Wide",
+}
+
+def_enum! {
+    BidiClass, unicode_bidi_class_name, unicode_bidi_class_long_name;
+
+    L => "Left_To_Right", /* must be zero */
+    R => "Right_To_Left",
+    AL => "Arabic_Letter",
+    EN => "European_Number",
+    ES => "European_Separator",
+    ET => "European_Terminator",
+    AN => "Arabic_Number",
+    CS => "Common_Separator",
+    NSM => "Nonspacing_Mark",
+    BN => "Boundary_Neutral",
+    B => "Paragraph_Separator",
+    S => "Segment_Separator",
+    WS => "White_Space",
+    ON => "Other_Neutral",
+    LRE => "Left_To_Right_Embedding",
+    LRO => "Left_To_Right_Override",
+    RLE => "Right_To_Left_Embedding",
+    RLO => "Right_To_Left_Override",
+    PDF => "Pop_Directional_Format",
+    LRI => "Left_To_Right_Isolate",
+    RLI => "Right_To_Left_Isolate",
+    FSI => "First_Strong_Isolate",
+    PDI => "Pop_Directional_Isolate",
+}
+
+def_enum! {
+    LineBreak, unicode_line_break_name, unicode_line_break_long_name;
+
+    XX => "Unknown", /* must be zero */
+    BK => "Mandatory_Break",
+    CR => "Carriage_Return",
+    LF => "Line_Feed",
+    CM => "Combining_Mark",
+    NL => "Next_Line",
+    SG => "Surrogate",
+    WJ => "Word_Joiner",
+    ZW => "ZWSpace",
+    GL => "Glue",
+    SP => "Space",
+    ZWJ => "",
+    B2 => "Break_Both",
+    BA => "Break_After",
+    BB => "Break_Before",
+    HY => "Hyphen",
+    CB => "Contingent_Break",
+    CL => "Close_Punctuation",
+    CP => "Close_Parenthesis",
+    EX => "Exclamation",
+    IN => "Inseparable,Inseperable",
+    NS => "Nonstarter",
+    OP => "Open_Punctuation",
+    QU => "Quotation",
+    IS => "Infix_Numeric",
+    NU => "Numeric",
+    PO => "Postfix_Numeric",
+    PR => "Prefix_Numeric",
+    SY => "Break_Symbols",
+    AI => "Ambiguous",
+    AL => "Alphabetic",
+    CJ => "Conditional_Japanese_Starter",
+    EB => "E_Base",
+    EM => "E_Modifier",
+    H2 => "",
+    H3 => "",
+    HL => "Hebrew_Letter",
+    ID => "Ideographic",
+    JL => "",
+    JV => "",
+    JT => "",
+    RI => "Regional_Indicator",
+    SA => "Complex_Context",
+}
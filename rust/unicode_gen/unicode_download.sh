@@ -0,0 +1,33 @@
+#!/bin/sh
+# Fetch the Unicode 13.0.0 data files read by unicode_gen into
+# unicode/. The vendored files are kept.
+set -e
+
+cd "$(dirname "$0")"
+
+url="https://www.unicode.org/Public/13.0.0/ucd"
+security_url="https://www.unicode.org/Public/security/13.0.0"
+
+files="CaseFolding.txt DerivedNormalizationProps.txt PropList.txt \
+SpecialCasing.txt CompositionExclusions.txt ScriptExtensions.txt \
+UnicodeData.txt DerivedCoreProperties.txt Scripts.txt \
+EastAsianWidth.txt BidiBrackets.txt LineBreak.txt NameAliases.txt \
+emoji/emoji-data.txt"
+
+security_files="confusables.txt IdentifierStatus.txt"
+
+mkdir -p unicode
+
+fetch() {
+    g="unicode/$(basename "$2")"
+    if [ ! -f "$g" ]; then
+        wget "$1/$2" -O "$g"
+    fi
+}
+
+for f in $files; do
+    fetch "$url" "$f"
+done
+for f in $security_files; do
+    fetch "$security_url" "$f"
+done